@@ -14,7 +14,6 @@ use flowy_core::get_client_server_configuration;
 use flowy_core::*;
 use flowy_notification::register_notification_sender;
 use lazy_static::lazy_static;
-use lib_dispatch::prelude::ToBytes;
 use lib_dispatch::prelude::*;
 use parking_lot::RwLock;
 use std::{ffi::CStr, os::raw::c_char};
@@ -100,7 +99,7 @@ async fn post_to_flutter(response: AFPluginEventResponse, port: i64) {
   match isolate
     .catch_unwind(async {
       let ffi_resp = FFIResponse::from(response);
-      ffi_resp.into_bytes().unwrap().to_vec()
+      ffi_resp.encode().await
     })
     .await
   {
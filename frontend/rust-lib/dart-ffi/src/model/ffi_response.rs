@@ -1,5 +1,7 @@
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
-use lib_dispatch::prelude::{AFPluginEventResponse, Payload, StatusCode};
+use lib_dispatch::prelude::{
+  AFPluginEventResponse, Payload, StatusCode, ToBytes, LARGE_PAYLOAD_THRESHOLD,
+};
 
 #[derive(ProtoBuf_Enum, Clone, Copy)]
 pub enum FFIStatusCode {
@@ -23,10 +25,31 @@ pub struct FFIResponse {
   code: FFIStatusCode,
 }
 
+impl FFIResponse {
+  /// Encodes the response that is posted to Dart. The responses whose payload is over the
+  /// [LARGE_PAYLOAD_THRESHOLD] are encoded on the blocking thread pool, so they don't stall the
+  /// runtime that runs the events.
+  pub async fn encode(self) -> Vec<u8> {
+    if self.payload.len() < LARGE_PAYLOAD_THRESHOLD {
+      return self.encode_inline();
+    }
+    tokio::task::spawn_blocking(move || self.encode_inline())
+      .await
+      .unwrap()
+  }
+
+  fn encode_inline(self) -> Vec<u8> {
+    // Takes the buffer of the encoded bytes instead of copying it
+    Vec::from(self.into_bytes().unwrap())
+  }
+}
+
 impl std::convert::From<AFPluginEventResponse> for FFIResponse {
   fn from(resp: AFPluginEventResponse) -> Self {
+    // Reuses the buffer of the encoded payload instead of copying it, which matters when the
+    // payload is large.
     let payload = match resp.payload {
-      Payload::Bytes(bytes) => bytes.to_vec(),
+      Payload::Bytes(bytes) => Vec::from(bytes),
       Payload::None => vec![],
     };
 
@@ -0,0 +1,58 @@
+use flowy_core::{get_client_server_configuration, AppFlowyCore, AppFlowyCoreConfig, DEFAULT_NAME};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use lib_dispatch::prelude::{AFPluginDispatcher, AFPluginFromBytes, AFPluginRequest, ToBytes};
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+/// Runs the headless [AppFlowyCore] on top of the same storage directory as the app. All the
+/// operations are sent as events through the dispatcher, so the CLI goes through exactly the
+/// same code paths as the app does.
+pub struct CliClient {
+  core: AppFlowyCore,
+}
+
+impl CliClient {
+  pub fn open(storage_path: &str) -> FlowyResult<Self> {
+    let server_config = get_client_server_configuration().map_err(internal_error)?;
+    let config = AppFlowyCoreConfig::new(storage_path, DEFAULT_NAME.to_string(), server_config)
+      .log_filter("error", vec!["flowy_cli".to_string()]);
+    let core = AppFlowyCore::new(config);
+    if core.user_session.user_id().is_err() {
+      return Err(FlowyError::unauthorized().context(format!(
+        "No user is signed in, open {} with AppFlowy first",
+        storage_path
+      )));
+    }
+    Ok(Self { core })
+  }
+
+  pub fn core(&self) -> &AppFlowyCore {
+    &self.core
+  }
+
+  pub fn send<E, P, R>(&self, event: E, payload: P) -> FlowyResult<R>
+  where
+    E: Eq + Hash + Debug + Clone + Display,
+    P: ToBytes,
+    R: AFPluginFromBytes,
+  {
+    let bytes = payload.into_bytes().map_err(internal_error)?;
+    self.dispatch(AFPluginRequest::new(event).payload(bytes))
+  }
+
+  pub fn send_without_payload<E, R>(&self, event: E) -> FlowyResult<R>
+  where
+    E: Eq + Hash + Debug + Clone + Display,
+    R: AFPluginFromBytes,
+  {
+    self.dispatch(AFPluginRequest::new(event))
+  }
+
+  fn dispatch<R>(&self, request: AFPluginRequest) -> FlowyResult<R>
+  where
+    R: AFPluginFromBytes,
+  {
+    let response = AFPluginDispatcher::sync_send(self.core.dispatcher(), request);
+    response.parse::<R, FlowyError>().map_err(internal_error)?
+  }
+}
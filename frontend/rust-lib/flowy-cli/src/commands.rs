@@ -0,0 +1,200 @@
+use crate::client::CliClient;
+use flowy_database::entities::{DatabasePB, DatabaseViewIdPB};
+use flowy_database::event_map::DatabaseEvent;
+use flowy_database::services::import::run_database_import;
+use flowy_document::entities::{ExportDataPB, ExportPayloadPB, ExportType};
+use flowy_document::event_map::DocumentEvent;
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_folder::entities::{
+  ImportPayloadPB, ImportTypePB, RepeatedSearchResultPB, SearchPayloadPB, ViewLayoutTypePB, ViewPB,
+  WorkspacePB, WorkspaceSettingPB,
+};
+use flowy_folder::event_map::FolderEvent;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+/// How often the CLI checks whether an import that is run by the core has finished.
+const IMPORT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Prints the apps of the current workspace and their views, one view per line.
+pub fn list_views(client: &CliClient) -> FlowyResult<()> {
+  let workspace = read_current_workspace(client)?;
+  for app in workspace.apps.items {
+    println!("{}\t{}", app.id, app.name);
+    for view in app.belongings.items {
+      println!("  {}\t{:?}\t{}", view.id, view.layout, view.name);
+    }
+  }
+  Ok(())
+}
+
+/// Exports the document to the `output` file, or prints it if there is no `output`.
+pub fn export_view(
+  client: &CliClient,
+  view_id: &str,
+  export_type: ExportType,
+  output: Option<&str>,
+) -> FlowyResult<()> {
+  let view = find_view(client, view_id)?;
+  if view.layout != ViewLayoutTypePB::Document {
+    return Err(FlowyError::invalid_data().context(format!(
+      "Only the documents can be exported, {} is a {:?}",
+      view.name, view.layout
+    )));
+  }
+
+  let payload = ExportPayloadPB {
+    view_id: view.id,
+    export_type,
+    document_version: client.core().config.document.version.clone(),
+  };
+  let export_data: ExportDataPB = client.send(DocumentEvent::ExportDocument, payload)?;
+  match output {
+    None => println!("{}", export_data.data),
+    Some(output) => std::fs::write(output, export_data.data)?,
+  }
+  Ok(())
+}
+
+/// Imports the CSV file as a grid into the app, the first app of the current workspace is used
+/// if there is no `app_id`. It waits until all the records of the file are imported, or returns
+/// an error if the import fails or doesn't finish within the `timeout`.
+pub fn import_csv(
+  client: &CliClient,
+  file_path: &str,
+  app_id: Option<String>,
+  name: Option<String>,
+  timeout: Duration,
+) -> FlowyResult<ViewPB> {
+  let belong_to_id = match app_id {
+    Some(app_id) => app_id,
+    None => read_current_workspace(client)?
+      .apps
+      .items
+      .into_iter()
+      .next()
+      .map(|app| app.id)
+      .ok_or_else(|| FlowyError::record_not_found().context("The workspace has no app"))?,
+  };
+  let name = name.unwrap_or_else(|| {
+    std::path::Path::new(file_path)
+      .file_stem()
+      .map(|stem| stem.to_string_lossy().to_string())
+      .unwrap_or_default()
+  });
+
+  let payload = ImportPayloadPB {
+    belong_to_id,
+    name,
+    file_path: file_path.to_owned(),
+    import_type: ImportTypePB::CSV,
+    passphrase: None,
+  };
+  let view: ViewPB = client.send(FolderEvent::ImportData, payload)?;
+
+  wait_for_import(client, &view.id, timeout)?;
+  Ok(view)
+}
+
+/// The records are imported in the background, the checkpoint of the import is removed once all
+/// of them are committed. The import is run here as well, so its error is reported. Running it
+/// does nothing while the core is still running the same import, the checkpoint is polled then.
+fn wait_for_import(client: &CliClient, view_id: &str, timeout: Duration) -> FlowyResult<()> {
+  let deadline = Instant::now() + timeout;
+  let timed_out = || {
+    FlowyError::internal().context(format!(
+      "The import of {} didn't finish within {} seconds",
+      view_id,
+      timeout.as_secs()
+    ))
+  };
+
+  let checkpoints = client.core().database_manager.import_checkpoints();
+  while checkpoints.get_with_view(view_id)?.is_some() {
+    let remaining = deadline
+      .checked_duration_since(Instant::now())
+      .ok_or_else(timed_out)?;
+
+    let (tx, rx) = mpsc::channel();
+    let database_manager = client.core().database_manager.clone();
+    let owned_view_id = view_id.to_owned();
+    client.core().event_dispatcher.spawn(async move {
+      let _ = tx.send(run_database_import(&owned_view_id, database_manager).await);
+    });
+    rx.recv_timeout(remaining).map_err(|_| timed_out())??;
+
+    if checkpoints.get_with_view(view_id)?.is_some() {
+      std::thread::sleep(IMPORT_POLL_INTERVAL.min(remaining));
+    }
+  }
+  Ok(())
+}
+
+/// Prints the views that match the query, one result per line.
+pub fn search(client: &CliClient, query: String, limit: i64) -> FlowyResult<()> {
+  let results: RepeatedSearchResultPB =
+    client.send(FolderEvent::Search, SearchPayloadPB { query, limit })?;
+  for result in results.items {
+    println!("{}\t{}\t{}", result.view_id, result.name, result.preview);
+  }
+  Ok(())
+}
+
+/// Opens every view of the current workspace and reports the ones that can't be loaded.
+/// Returns the number of the broken views.
+pub fn check_integrity(client: &CliClient) -> FlowyResult<usize> {
+  let workspace = read_current_workspace(client)?;
+  let mut broken_views = 0;
+  for view in workspace
+    .apps
+    .items
+    .into_iter()
+    .flat_map(|app| app.belongings.items)
+  {
+    let result = match view.layout {
+      ViewLayoutTypePB::Document => client
+        .send::<_, _, ExportDataPB>(
+          DocumentEvent::ExportDocument,
+          ExportPayloadPB {
+            view_id: view.id.clone(),
+            export_type: ExportType::Text,
+            document_version: client.core().config.document.version.clone(),
+          },
+        )
+        .map(|_| ()),
+      ViewLayoutTypePB::Grid | ViewLayoutTypePB::Board | ViewLayoutTypePB::Calendar => client
+        .send::<_, _, DatabasePB>(
+          DatabaseEvent::GetDatabase,
+          DatabaseViewIdPB {
+            value: view.id.clone(),
+          },
+        )
+        .map(|_| ()),
+    };
+
+    match result {
+      Ok(_) => println!("ok\t{}\t{}", view.id, view.name),
+      Err(err) => {
+        broken_views += 1;
+        println!("broken\t{}\t{}\t{}", view.id, view.name, err);
+      },
+    }
+  }
+  Ok(broken_views)
+}
+
+fn read_current_workspace(client: &CliClient) -> FlowyResult<WorkspacePB> {
+  let setting: WorkspaceSettingPB =
+    client.send_without_payload(FolderEvent::ReadCurrentWorkspace)?;
+  Ok(setting.workspace)
+}
+
+fn find_view(client: &CliClient, view_id: &str) -> FlowyResult<ViewPB> {
+  read_current_workspace(client)?
+    .apps
+    .items
+    .into_iter()
+    .flat_map(|app| app.belongings.items)
+    .find(|view| view.id == view_id)
+    .ok_or_else(|| FlowyError::record_not_found().context(format!("Can't find view {}", view_id)))
+}
@@ -0,0 +1,177 @@
+mod client;
+mod commands;
+
+use crate::client::CliClient;
+use clap::{Parser, Subcommand, ValueEnum};
+use flowy_core::backup::{
+  backup_storage, backup_storage_to_archive, restore_storage, restore_storage_from_archive,
+};
+use flowy_document::entities::ExportType;
+use flowy_error::{FlowyError, FlowyResult};
+use std::path::Path;
+use std::time::Duration;
+
+/// Scriptable access to the data of AppFlowy. The app should be closed while running the
+/// commands on its storage directory.
+#[derive(Parser)]
+#[command(name = "appflowy-cli", version)]
+struct Cli {
+  /// The storage directory of AppFlowy
+  #[arg(short, long, env = "APPFLOWY_STORAGE_PATH")]
+  path: String,
+
+  #[command(subcommand)]
+  command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+  /// List the apps and the views of the current workspace
+  Views,
+  /// Export a document
+  Export {
+    view_id: String,
+    #[arg(short, long, value_enum, default_value_t = ExportFormat::Markdown)]
+    format: ExportFormat,
+    /// The file to write to, the document is printed if it's not set
+    #[arg(short, long)]
+    output: Option<String>,
+  },
+  /// Import a CSV file as a grid
+  Import {
+    file_path: String,
+    /// The app the grid is added to, the first app of the workspace is used if it's not set
+    #[arg(long)]
+    app_id: Option<String>,
+    /// The name of the grid, the file name is used if it's not set
+    #[arg(long)]
+    name: Option<String>,
+    /// The number of seconds to wait for all the records of the file to be imported
+    #[arg(long, default_value_t = 600)]
+    timeout: u64,
+  },
+  /// Search the views by their names and contents
+  Search {
+    query: String,
+    #[arg(short, long, default_value_t = 0)]
+    limit: i64,
+  },
+  /// Open every view of the current workspace and report the broken ones
+  Check,
+  /// Pack the storage directory into an encrypted archive file, or copy it into an empty
+  /// directory without encryption
+  Backup {
+    backup_path: String,
+    /// Encrypt the archive with the key derived from the passphrase
+    #[arg(long, env = "APPFLOWY_ARCHIVE_PASSPHRASE")]
+    passphrase: Option<String>,
+    /// Copy the storage directory as it is, the backup is not encrypted
+    #[arg(long, conflicts_with = "passphrase")]
+    plain: bool,
+  },
+  /// Replace the storage directory with a backup directory or archive file
+  Restore {
+    backup_path: String,
+    /// The passphrase of the encrypted archive
+    #[arg(long, env = "APPFLOWY_ARCHIVE_PASSPHRASE")]
+    passphrase: Option<String>,
+  },
+}
+
+#[derive(Clone, ValueEnum)]
+enum ExportFormat {
+  Text,
+  Markdown,
+}
+
+impl std::convert::From<ExportFormat> for ExportType {
+  fn from(format: ExportFormat) -> Self {
+    match format {
+      ExportFormat::Text => ExportType::Text,
+      ExportFormat::Markdown => ExportType::Markdown,
+    }
+  }
+}
+
+fn main() {
+  let cli = Cli::parse();
+  match run(cli) {
+    Ok(true) => {},
+    Ok(false) => std::process::exit(1),
+    Err(err) => {
+      eprintln!("{}", err);
+      std::process::exit(1);
+    },
+  }
+}
+
+/// Returns false if the command finished but found problems, e.g. broken views.
+fn run(cli: Cli) -> FlowyResult<bool> {
+  match cli.command {
+    // The backup commands work on the files directly, the core must not be running.
+    Command::Backup {
+      backup_path,
+      passphrase,
+      plain,
+    } => match passphrase {
+      Some(passphrase) => backup_storage_to_archive(&cli.path, &backup_path, &passphrase)?,
+      None if plain => backup_storage(&cli.path, &backup_path)?,
+      None => {
+        return Err(FlowyError::invalid_passphrase().context(
+          "The backup is encrypted, set --passphrase or APPFLOWY_ARCHIVE_PASSPHRASE. Use --plain to copy the storage directory without encryption",
+        ))
+      },
+    },
+    Command::Restore {
+      backup_path,
+      passphrase,
+    } => {
+      if Path::new(&backup_path).is_file() {
+        restore_storage_from_archive(&backup_path, &cli.path, passphrase.as_deref())?
+      } else {
+        restore_storage(&backup_path, &cli.path)?
+      }
+    },
+    command => {
+      let client = CliClient::open(&cli.path)?;
+      return run_with_client(&client, command);
+    },
+  }
+  Ok(true)
+}
+
+fn run_with_client(client: &CliClient, command: Command) -> FlowyResult<bool> {
+  match command {
+    Command::Views => commands::list_views(client)?,
+    Command::Export {
+      view_id,
+      format,
+      output,
+    } => commands::export_view(client, &view_id, format.into(), output.as_deref())?,
+    Command::Import {
+      file_path,
+      app_id,
+      name,
+      timeout,
+    } => {
+      let timeout = Duration::from_secs(timeout);
+      let view = commands::import_csv(client, &file_path, app_id, name, timeout)?;
+      println!("{}\t{}", view.id, view.name);
+    },
+    Command::Search { query, limit } => commands::search(client, query, limit)?,
+    Command::Check => {
+      let broken_views = commands::check_integrity(client)?;
+      if broken_views > 0 {
+        eprintln!("{} broken view(s)", broken_views);
+        return Ok(false);
+      }
+    },
+    // The backup commands are run by [run] without opening the core.
+    Command::Backup { .. } | Command::Restore { .. } => {
+      return Err(
+        FlowyError::internal().context("The backup commands can't run while the core is open"),
+      );
+    },
+  }
+  Ok(true)
+}
@@ -91,6 +91,17 @@ impl DatabaseBlockRevisionPad {
     })
   }
 
+  /// Appends the rows in one changeset.
+  pub fn add_row_revs(
+    &mut self,
+    row_revs: Vec<RowRevision>,
+  ) -> SyncResult<Option<DatabaseBlockRevisionChangeset>> {
+    self.modify(|rows| {
+      rows.extend(row_revs.into_iter().map(Arc::new));
+      Ok(Some(()))
+    })
+  }
+
   pub fn delete_rows(
     &mut self,
     row_ids: Vec<Cow<'_, String>>,
@@ -197,6 +208,12 @@ impl DatabaseBlockRevisionPad {
           })
       }
 
+      if is_changed.is_some() {
+        if let Some(modified_at) = changeset.modified_at {
+          row.modified_at = modified_at;
+        }
+      }
+
       Ok(is_changed)
     })
   }
@@ -223,6 +240,45 @@ impl DatabaseBlockRevisionPad {
     })
   }
 
+  /// Removes the cells of the fields from all the rows of the block.
+  pub fn remove_cells(
+    &mut self,
+    field_ids: &[String],
+  ) -> SyncResult<Option<DatabaseBlockRevisionChangeset>> {
+    self.modify(|rows| {
+      let mut is_changed = None;
+      for row_rev in rows.iter_mut() {
+        if field_ids
+          .iter()
+          .any(|field_id| row_rev.cells.contains_key(field_id))
+        {
+          let row_rev = Arc::make_mut(row_rev);
+          for field_id in field_ids {
+            row_rev.cells.shift_remove(field_id);
+          }
+          is_changed = Some(());
+        }
+      }
+      Ok(is_changed)
+    })
+  }
+
+  /// Changes the id of the row at `index`. The rows that have the same id can't be told apart
+  /// by their id, so the row is identified by its position.
+  pub fn remap_row_id(
+    &mut self,
+    index: usize,
+    new_row_id: String,
+  ) -> SyncResult<Option<DatabaseBlockRevisionChangeset>> {
+    self.modify(|rows| match rows.get_mut(index) {
+      None => Err(SyncError::out_of_bound()),
+      Some(row_rev) => {
+        Arc::make_mut(row_rev).id = new_row_id;
+        Ok(Some(()))
+      },
+    })
+  }
+
   pub fn modify<F>(&mut self, f: F) -> SyncResult<Option<DatabaseBlockRevisionChangeset>>
   where
     F: for<'a> FnOnce(&'a mut Vec<Arc<RowRevision>>) -> SyncResult<Option<()>>,
@@ -334,6 +390,8 @@ mod tests {
       cells: Default::default(),
       height: 0,
       visibility: false,
+      created_at: 0,
+      modified_at: 0,
     };
 
     let change = pad.add_row_rev(row.clone(), None).unwrap().unwrap();
@@ -377,6 +435,20 @@ mod tests {
     assert_eq!(*pad.rows[2], row_3);
   }
 
+  #[test]
+  fn block_meta_remap_row_id() {
+    let mut pad = test_pad();
+    let row_1 = test_row_rev("1", &pad);
+    let _ = pad.add_row_rev(row_1.clone(), None).unwrap().unwrap();
+    let _ = pad.add_row_rev(row_1, None).unwrap().unwrap();
+
+    let _ = pad.remap_row_id(1, "2".to_string()).unwrap().unwrap();
+    assert_eq!(pad.rows[0].id, "1");
+    assert_eq!(pad.rows[1].id, "2");
+    assert_eq!(pad.index_of_row("2"), Some(1));
+    assert!(pad.remap_row_id(2, "3".to_string()).is_err());
+  }
+
   fn test_row_rev(id: &str, pad: &DatabaseBlockRevisionPad) -> RowRevision {
     RowRevision {
       id: id.to_string(),
@@ -384,6 +456,8 @@ mod tests {
       cells: Default::default(),
       height: 0,
       visibility: false,
+      created_at: 0,
+      modified_at: 0,
     }
   }
 
@@ -435,6 +509,8 @@ mod tests {
       cells: Default::default(),
       height: 0,
       visibility: false,
+      created_at: 0,
+      modified_at: 0,
     };
 
     let _ = pad.add_row_rev(row.clone(), None).unwrap().unwrap();
@@ -459,12 +535,15 @@ mod tests {
       cells: Default::default(),
       height: 0,
       visibility: false,
+      created_at: 0,
+      modified_at: 0,
     };
 
     let changeset = RowChangeset {
       row_id: row.id.clone(),
       height: Some(100),
       visibility: Some(true),
+      modified_at: None,
       cell_by_field_id: Default::default(),
     };
 
@@ -2,7 +2,7 @@ use crate::errors::{internal_sync_error, SyncError, SyncResult};
 use crate::util::cal_diff;
 use database_model::{
   gen_block_id, gen_database_id, DatabaseBlockMetaRevision, DatabaseBlockMetaRevisionChangeset,
-  DatabaseRevision, FieldRevision, FieldTypeRevision,
+  DatabaseRevision, FieldRevision, FieldTypeRevision, TrashedFieldRevision,
 };
 use flowy_sync::util::make_operations_from_revisions;
 use lib_infra::util::md5;
@@ -105,6 +105,34 @@ impl DatabaseRevisionPad {
     })
   }
 
+  /// Appends the fields in one changeset. The fields that already exist are ignored.
+  pub fn create_field_revs(
+    &mut self,
+    new_field_revs: Vec<FieldRevision>,
+  ) -> SyncResult<Option<DatabaseRevisionChangeset>> {
+    self.modify_database(|grid_meta| {
+      let mut is_changed = false;
+      for new_field_rev in new_field_revs {
+        if grid_meta
+          .fields
+          .iter()
+          .any(|field_rev| field_rev.id == new_field_rev.id)
+        {
+          tracing::error!("Duplicate grid field");
+          continue;
+        }
+        grid_meta.fields.push(Arc::new(new_field_rev));
+        is_changed = true;
+      }
+
+      if is_changed {
+        Ok(Some(()))
+      } else {
+        Ok(None)
+      }
+    })
+  }
+
   pub fn delete_field_rev(
     &mut self,
     field_id: &str,
@@ -128,6 +156,95 @@ impl DatabaseRevisionPad {
     })
   }
 
+  /// Moves the field to the trash of the database in one change, so the field is either deleted
+  /// and trashed or left as it was.
+  pub fn trash_field_rev(
+    &mut self,
+    field_id: &str,
+    grouped_view_ids: Vec<String>,
+    deleted_at: i64,
+  ) -> SyncResult<Option<DatabaseRevisionChangeset>> {
+    self.modify_database(|database| {
+      match database
+        .fields
+        .iter()
+        .position(|field| field.id == field_id)
+      {
+        None => Ok(None),
+        Some(index) => {
+          if database.fields[index].is_primary {
+            return Err(SyncError::can_not_delete_primary_field());
+          }
+          let field = database.fields.remove(index);
+          database
+            .trashed_fields
+            .retain(|trashed_field| trashed_field.field.id != field_id);
+          database.trashed_fields.push(Arc::new(TrashedFieldRevision {
+            field: field.as_ref().clone(),
+            index,
+            deleted_at,
+            grouped_view_ids,
+          }));
+          Ok(Some(()))
+        },
+      }
+    })
+  }
+
+  /// Moves the field out of the trash and inserts it back at the position it was deleted from.
+  pub fn restore_trashed_field_rev(
+    &mut self,
+    field_id: &str,
+  ) -> SyncResult<Option<DatabaseRevisionChangeset>> {
+    self.modify_database(|database| {
+      match database
+        .trashed_fields
+        .iter()
+        .position(|trashed_field| trashed_field.field.id == field_id)
+      {
+        None => Ok(None),
+        Some(position) => {
+          let trashed_field = database.trashed_fields.remove(position);
+          let index = trashed_field.index.min(database.fields.len());
+          database
+            .fields
+            .insert(index, Arc::new(trashed_field.field.clone()));
+          Ok(Some(()))
+        },
+      }
+    })
+  }
+
+  pub fn remove_trashed_field_revs(
+    &mut self,
+    field_ids: &[String],
+  ) -> SyncResult<Option<DatabaseRevisionChangeset>> {
+    self.modify_database(|database| {
+      let len = database.trashed_fields.len();
+      database
+        .trashed_fields
+        .retain(|trashed_field| !field_ids.contains(&trashed_field.field.id));
+      if database.trashed_fields.len() == len {
+        Ok(None)
+      } else {
+        Ok(Some(()))
+      }
+    })
+  }
+
+  pub fn get_trashed_field_rev(&self, field_id: &str) -> Option<Arc<TrashedFieldRevision>> {
+    self
+      .database_rev
+      .trashed_fields
+      .iter()
+      .find(|trashed_field| trashed_field.field.id == field_id)
+      .cloned()
+  }
+
+  pub fn get_trashed_field_revs(&self) -> Vec<Arc<TrashedFieldRevision>> {
+    self.database_rev.trashed_fields.clone()
+  }
+
   pub fn duplicate_field_rev(
     &mut self,
     field_id: &str,
@@ -1,10 +1,12 @@
 use crate::errors::{internal_sync_error, SyncError, SyncResult};
 use crate::util::cal_diff;
 use database_model::{
-  DatabaseViewRevision, FieldRevision, FieldTypeRevision, FilterRevision,
-  GroupConfigurationRevision, LayoutRevision, SortRevision,
+  gen_database_filter_preset_id, AutomationRevision, DatabaseViewRevision, FieldRevision,
+  FieldTypeRevision, FilterPresetRevision, FilterRevision, GroupConfigurationRevision,
+  LayoutRevision, SortRevision,
 };
 use flowy_sync::util::make_operations_from_revisions;
+use indexmap::IndexMap;
 use lib_infra::util::md5;
 use lib_ot::core::{DeltaBuilder, DeltaOperations, EmptyAttributes, OperationTransform};
 use revision_model::Revision;
@@ -276,6 +278,7 @@ impl DatabaseViewRevisionPad {
         let filter = Arc::make_mut(filter);
         filter.condition = filter_rev.condition;
         filter.content = filter_rev.content;
+        filter.case_sensitive = filter_rev.case_sensitive;
         Ok(Some(()))
       } else {
         Ok(None)
@@ -300,6 +303,147 @@ impl DatabaseViewRevisionPad {
     })
   }
 
+  /// Replaces all the filters of the view in one change, e.g. when a filter preset is applied.
+  pub fn replace_filters(
+    &mut self,
+    filter_revs: Vec<FilterRevision>,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    self.modify(|view| {
+      view.filters.clear();
+      for filter_rev in filter_revs {
+        let field_id = filter_rev.field_id.clone();
+        let field_type = filter_rev.field_type;
+        view.filters.add_object(&field_id, &field_type, filter_rev);
+      }
+      Ok(Some(()))
+    })
+  }
+
+  pub fn get_filter_presets(&self) -> Vec<FilterPresetRevision> {
+    self.view.filter_presets.clone()
+  }
+
+  pub fn get_filter_preset(&self, preset_id: &str) -> Option<FilterPresetRevision> {
+    self
+      .view
+      .filter_presets
+      .iter()
+      .find(|preset| preset.id == preset_id)
+      .cloned()
+  }
+
+  /// Saves the `filter_revs` as the preset with the `name`. The filters of the preset with the
+  /// same name are replaced, otherwise a new preset is created. Returns the saved preset.
+  pub fn save_filter_preset(
+    &mut self,
+    name: &str,
+    filter_revs: Vec<FilterRevision>,
+  ) -> SyncResult<(Option<DatabaseViewRevisionChangeset>, FilterPresetRevision)> {
+    let mut saved_preset = FilterPresetRevision::default();
+    let changeset = self.modify(|view| {
+      match view
+        .filter_presets
+        .iter_mut()
+        .find(|preset| preset.name == name)
+      {
+        Some(preset) => {
+          preset.filters = filter_revs;
+          saved_preset = preset.clone();
+        },
+        None => {
+          saved_preset = FilterPresetRevision {
+            id: gen_database_filter_preset_id(),
+            name: name.to_owned(),
+            filters: filter_revs,
+          };
+          view.filter_presets.push(saved_preset.clone());
+        },
+      }
+      Ok(Some(()))
+    })?;
+    Ok((changeset, saved_preset))
+  }
+
+  pub fn delete_filter_preset(
+    &mut self,
+    preset_id: &str,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    self.modify(|view| {
+      let number_of_presets = view.filter_presets.len();
+      view.filter_presets.retain(|preset| preset.id != preset_id);
+      if view.filter_presets.len() == number_of_presets {
+        Ok(None)
+      } else {
+        Ok(Some(()))
+      }
+    })
+  }
+
+  pub fn get_automations(&self) -> Vec<AutomationRevision> {
+    self.view.automations.clone()
+  }
+
+  /// Saves the `automation`. The automation with the same id is replaced, otherwise it's added.
+  pub fn save_automation(
+    &mut self,
+    automation: AutomationRevision,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    self.modify(|view| {
+      match view
+        .automations
+        .iter_mut()
+        .find(|existing| existing.id == automation.id)
+      {
+        Some(existing) => {
+          if *existing == automation {
+            return Ok(None);
+          }
+          *existing = automation;
+        },
+        None => view.automations.push(automation),
+      }
+      Ok(Some(()))
+    })
+  }
+
+  /// Replaces the fired dates of the automation, see [AutomationRevision::fired_dates].
+  pub fn set_automation_fired_dates(
+    &mut self,
+    automation_id: &str,
+    fired_dates: IndexMap<String, String>,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    self.modify(|view| {
+      match view
+        .automations
+        .iter_mut()
+        .find(|automation| automation.id == automation_id)
+      {
+        Some(automation) if automation.fired_dates != fired_dates => {
+          automation.fired_dates = fired_dates;
+          Ok(Some(()))
+        },
+        _ => Ok(None),
+      }
+    })
+  }
+
+  pub fn delete_automation(
+    &mut self,
+    automation_id: &str,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    self.modify(|view| {
+      let number_of_automations = view.automations.len();
+      view
+        .automations
+        .retain(|automation| automation.id != automation_id);
+      if view.automations.len() == number_of_automations {
+        Ok(None)
+      } else {
+        Ok(Some(()))
+      }
+    })
+  }
+
   /// Returns the settings for the given layout. If it's not exists then will return the
   /// default settings for the given layout.
   /// Each [database view](https://appflowy.gitbook.io/docs/essential-documentation/contribute-to-appflowy/architecture/frontend/database-view) has its own settings.
@@ -327,6 +471,41 @@ impl DatabaseViewRevisionPad {
     })
   }
 
+  pub fn get_swimlane_configuration(&self) -> Option<Arc<GroupConfigurationRevision>> {
+    self.view.swimlane.clone().map(Arc::new)
+  }
+
+  /// Replaces the group configuration of the swimlanes. Passing None removes the swimlanes.
+  pub fn update_swimlane_configuration(
+    &mut self,
+    configuration: Option<GroupConfigurationRevision>,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    self.modify(|view| {
+      if view.swimlane.is_none() && configuration.is_none() {
+        return Ok(None);
+      }
+      view.swimlane = configuration;
+      Ok(Some(()))
+    })
+  }
+
+  pub fn get_description(&self) -> String {
+    self.view.description.clone()
+  }
+
+  pub fn update_description(
+    &mut self,
+    description: String,
+  ) -> SyncResult<Option<DatabaseViewRevisionChangeset>> {
+    self.modify(|view| {
+      if view.description == description {
+        return Ok(None);
+      }
+      view.description = description;
+      Ok(Some(()))
+    })
+  }
+
   pub fn json_str(&self) -> SyncResult<String> {
     make_database_view_rev_json_str(&self.view)
   }
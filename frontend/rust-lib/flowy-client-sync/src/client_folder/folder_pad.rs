@@ -4,12 +4,16 @@ use crate::{
   client_folder::builder::FolderPadBuilder,
   errors::{SyncError, SyncResult},
 };
-use folder_model::{AppRevision, FolderRevision, TrashRevision, ViewRevision, WorkspaceRevision};
+use folder_model::{
+  AppRevision, FolderRevision, TrashRevision, ViewRevision, WorkspaceRevision,
+  WorkspaceSettingsRevision,
+};
 use lib_infra::util::md5;
 use lib_infra::util::move_vec_element;
 use lib_ot::core::*;
 use revision_model::Revision;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 pub type FolderOperations = DeltaOperations<EmptyAttributes>;
@@ -117,6 +121,20 @@ impl FolderPad {
     })
   }
 
+  pub fn update_workspace_settings(
+    &mut self,
+    workspace_id: &str,
+    settings: WorkspaceSettingsRevision,
+  ) -> SyncResult<Option<FolderChangeset>> {
+    self.with_workspace(workspace_id, |workspace| {
+      if workspace.settings == settings {
+        return Ok(None);
+      }
+      workspace.settings = settings;
+      Ok(Some(()))
+    })
+  }
+
   pub fn read_workspaces(
     &self,
     workspace_id: Option<String>,
@@ -149,6 +167,16 @@ impl FolderPad {
     }
   }
 
+  /// Returns the settings of the workspaces, keyed by the workspace id.
+  pub fn read_workspace_settings(&self) -> HashMap<String, WorkspaceSettingsRevision> {
+    self
+      .folder_rev
+      .workspaces
+      .iter()
+      .map(|workspace| (workspace.id.clone(), workspace.settings.clone()))
+      .collect()
+  }
+
   #[tracing::instrument(level = "trace", skip(self), err)]
   pub fn delete_workspace(&mut self, workspace_id: &str) -> SyncResult<Option<FolderChangeset>> {
     self.modify_workspaces(|workspaces| {
@@ -281,6 +309,69 @@ impl FolderPad {
     })
   }
 
+  /// Records that the content of the view was edited by the user at `modified_time`.
+  pub fn update_view_last_edited(
+    &mut self,
+    view_id: &str,
+    modified_time: i64,
+    edited_by: &str,
+  ) -> SyncResult<Option<FolderChangeset>> {
+    let view = self.read_view(view_id)?;
+    self.with_view(&view.app_id, view_id, |view| {
+      view.modified_time = modified_time;
+      view.last_edited_by = edited_by.to_owned();
+      Ok(Some(()))
+    })
+  }
+
+  /// Sets the slug of the view, an empty slug removes it. The uniqueness of the slug is checked
+  /// by the caller.
+  pub fn update_view_slug(
+    &mut self,
+    view_id: &str,
+    slug: &str,
+  ) -> SyncResult<Option<FolderChangeset>> {
+    let view = self.read_view(view_id)?;
+    self.with_view(&view.app_id, view_id, |view| {
+      if view.slug == slug {
+        return Ok(None);
+      }
+      view.slug = slug.to_owned();
+      Ok(Some(()))
+    })
+  }
+
+  /// Sets the permission of the view, 0 means that the view is editable.
+  pub fn update_view_permission(
+    &mut self,
+    view_id: &str,
+    permission: i64,
+  ) -> SyncResult<Option<FolderChangeset>> {
+    let view = self.read_view(view_id)?;
+    self.with_view(&view.app_id, view_id, |view| {
+      if view.permission == permission {
+        return Ok(None);
+      }
+      view.permission = permission;
+      Ok(Some(()))
+    })
+  }
+
+  pub fn update_view_state(
+    &mut self,
+    view_id: &str,
+    state: HashMap<String, String>,
+  ) -> SyncResult<Option<FolderChangeset>> {
+    let view = self.read_view(view_id)?;
+    self.with_view(&view.app_id, view_id, |view| {
+      if view.state == state {
+        return Ok(None);
+      }
+      view.state = state;
+      Ok(Some(()))
+    })
+  }
+
   #[tracing::instrument(level = "trace", skip(self), err)]
   pub fn delete_view(
     &mut self,
@@ -527,7 +618,10 @@ mod tests {
   use crate::client_folder::folder_pad::FolderPad;
   use crate::client_folder::{FolderOperations, FolderOperationsBuilder};
   use chrono::Utc;
-  use folder_model::{AppRevision, FolderRevision, TrashRevision, ViewRevision, WorkspaceRevision};
+  use folder_model::{
+    AppRevision, FolderRevision, TrashRevision, ViewRevision, WorkspaceRevision,
+    WorkspaceSettingsRevision,
+  };
   use lib_ot::core::OperationTransform;
   use serde::Deserialize;
 
@@ -597,6 +691,31 @@ mod tests {
     );
   }
 
+  #[test]
+  fn folder_update_workspace_settings() {
+    let (mut folder, initial_operation, workspace) = test_folder();
+    let settings = WorkspaceSettingsRevision {
+      trash_retention_days: 30,
+      ..Default::default()
+    };
+    let operations = folder
+      .update_workspace_settings(&workspace.id, settings.clone())
+      .unwrap()
+      .unwrap()
+      .operations;
+
+    let folder_from_operations = make_folder_from_operations(initial_operation, vec![operations]);
+    assert_folder_equal(
+      &folder,
+      &folder_from_operations,
+      r#"{"workspaces":[{"id":"1","name":"😁 my first workspace","desc":"","apps":[],"modified_time":0,"create_time":0,"settings":{"default_database_layout":0,"default_date_format":null,"default_time_format":null,"default_number_format":null,"trash_retention_days":30,"default_member_permission":0,"default_view_layout":0,"quick_create_location":0,"inbox_app_id":null,"first_day_of_week":0,"working_days":null}}],"trash":[]}"#,
+    );
+    assert!(folder
+      .update_workspace_settings(&workspace.id, settings)
+      .unwrap()
+      .is_none());
+  }
+
   #[test]
   fn folder_add_app() {
     let (folder, initial_operations, _app) = test_app_folder();
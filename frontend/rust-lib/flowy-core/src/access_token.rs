@@ -0,0 +1,52 @@
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_sqlite::kv::KV;
+use lib_dispatch::prelude::PermissionScope;
+use sha2::{Digest, Sha256};
+
+const ACCESS_TOKEN_PREFIX: &str = "access_token";
+const TOKEN_LEN: usize = 32;
+
+/// Issues a personal access token that grants the `scopes`. The token is only returned here,
+/// the [KV] store keeps its SHA-256 digest with the scopes.
+pub fn issue_access_token(scopes: &[PermissionScope]) -> FlowyResult<String> {
+  if scopes.is_empty() {
+    return Err(FlowyError::invalid_data().context("The access token must grant a scope"));
+  }
+
+  let token = rand::random::<[u8; TOKEN_LEN]>()
+    .iter()
+    .map(|byte| format!("{:02x}", byte))
+    .collect::<String>();
+  let scopes = scopes
+    .iter()
+    .map(|scope| scope.to_string())
+    .collect::<Vec<_>>()
+    .join(",");
+  KV::set_str(&access_token_key(&token), scopes);
+  Ok(token)
+}
+
+/// Revokes the token, the requests that carry it are rejected from then on.
+pub fn revoke_access_token(token: &str) {
+  let _ = KV::remove(&access_token_key(token));
+}
+
+/// Returns the scopes that are granted by the token, or None if the token is unknown or
+/// revoked.
+pub fn access_token_scopes(token: &str) -> Option<Vec<PermissionScope>> {
+  let scopes = KV::get_str(&access_token_key(token))?;
+  Some(
+    scopes
+      .split(',')
+      .filter_map(PermissionScope::parse)
+      .collect(),
+  )
+}
+
+fn access_token_key(token: &str) -> String {
+  let digest = Sha256::digest(token.as_bytes())
+    .iter()
+    .map(|byte| format!("{:02x}", byte))
+    .collect::<String>();
+  format!("{}:{}", ACCESS_TOKEN_PREFIX, digest)
+}
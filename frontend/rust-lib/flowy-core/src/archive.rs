@@ -0,0 +1,514 @@
+use aes_gcm::aead::generic_array::GenericArray;
+use aes_gcm::aead::stream::{DecryptorBE32, EncryptorBE32};
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Nonce};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use sha2::Sha256;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+const MAGIC: &[u8; 4] = b"AFAR";
+/// The archives of the first version encrypted the whole payload at once. They're still read,
+/// but in memory.
+const VERSION_1: u8 = 1;
+/// The payload is encrypted in chunks, so the archive is written and read without loading the
+/// whole directory into memory.
+const VERSION: u8 = 2;
+const PLAIN: u8 = 0;
+const AES_256_GCM: u8 = 1;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+/// The counter of the chunk and the flag of the last chunk take the other 5 bytes of the nonce.
+const STREAM_NONCE_LEN: usize = 7;
+const TAG_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+const CHUNK_LEN: usize = 64 * 1024;
+const NEXT_CHUNK: u8 = 0;
+const LAST_CHUNK: u8 = 1;
+/// The paths are relative to the packed directory, the longer ones are rejected instead of
+/// allocating whatever the archive says.
+const MAX_PATH_LEN: u32 = 4096;
+/// The iterations of PBKDF2 are saved in the archive, so it can be increased without breaking
+/// the archives that were created before.
+const KEY_ITERATIONS: u32 = 310_000;
+/// The range of the iterations that are accepted when reading an archive. A crafted archive
+/// could otherwise make the key derivation run for hours, or use a trivially weak key.
+const MIN_KEY_ITERATIONS: u32 = 10_000;
+const MAX_KEY_ITERATIONS: u32 = 10_000_000;
+
+/// Packs all the files of the directory into a single archive file. The archive is encrypted
+/// with AES-256-GCM if the `passphrase` is set, the key is derived from the passphrase by
+/// PBKDF2-HMAC-SHA256 with a random salt.
+///
+/// The layout of the archive:
+/// magic(4) | version(1) | cipher(1) | [iterations(4) | salt(16) | nonce(7)] | payload
+///
+/// The files are streamed into the archive. The encrypted payload is split into the chunks of
+/// [CHUNK_LEN] bytes: flag(1) | len(4) | ciphertext. The flag of the last chunk is part of its
+/// nonce, so a truncated archive can't pass as a complete one.
+pub(crate) fn write_archive(
+  dir: &Path,
+  archive_path: &Path,
+  passphrase: Option<&str>,
+) -> FlowyResult<()> {
+  let result = File::create(archive_path)
+    .map_err(FlowyError::from)
+    .and_then(|file| {
+      let mut writer = BufWriter::new(file);
+      writer.write_all(MAGIC)?;
+      writer.write_all(&[VERSION])?;
+      let mut payload = match passphrase {
+        None => {
+          writer.write_all(&[PLAIN])?;
+          PayloadWriter::new(writer, None)
+        },
+        Some(passphrase) => {
+          let salt = rand::random::<[u8; SALT_LEN]>();
+          let nonce = rand::random::<[u8; STREAM_NONCE_LEN]>();
+          let cipher = make_cipher(passphrase, &salt, KEY_ITERATIONS)?;
+          writer.write_all(&[AES_256_GCM])?;
+          writer.write_all(&KEY_ITERATIONS.to_le_bytes())?;
+          writer.write_all(&salt)?;
+          writer.write_all(&nonce)?;
+          let encryptor = EncryptorBE32::from_aead(cipher, GenericArray::from_slice(&nonce));
+          PayloadWriter::new(writer, Some(encryptor))
+        },
+      };
+      pack_dir(dir, dir, &mut payload)?;
+      payload.finish()
+    });
+  if result.is_err() {
+    let _ = fs::remove_file(archive_path);
+  }
+  result
+}
+
+/// Unpacks the archive that was created by [write_archive] into the directory. Returns the
+/// [FlowyError::invalid_passphrase] error if the archive is encrypted and the `passphrase` is
+/// missing or wrong.
+pub(crate) fn read_archive(
+  archive_path: &Path,
+  dir: &Path,
+  passphrase: Option<&str>,
+) -> FlowyResult<()> {
+  let mut reader = BufReader::new(File::open(archive_path)?);
+  let mut magic = [0u8; 4];
+  read_exact(&mut reader, &mut magic)?;
+  if &magic != MAGIC {
+    return Err(FlowyError::invalid_data().context("The file is not an AppFlowy archive"));
+  }
+  let version = read_u8(&mut reader)?;
+  let cipher = read_u8(&mut reader)?;
+  match (version, cipher) {
+    (VERSION_1, _) => read_archive_v1(reader, cipher, dir, passphrase),
+    (VERSION, PLAIN) => unpack_dir(&mut PayloadReader::new(reader, None), dir),
+    (VERSION, AES_256_GCM) => {
+      let (cipher, nonce) = read_cipher(&mut reader, passphrase, STREAM_NONCE_LEN)?;
+      let decryptor = DecryptorBE32::from_aead(cipher, GenericArray::from_slice(&nonce));
+      unpack_dir(&mut PayloadReader::new(reader, Some(decryptor)), dir)
+    },
+    (VERSION, cipher) => {
+      Err(FlowyError::invalid_data().context(format!("Unsupported cipher: {}", cipher)))
+    },
+    (version, _) => {
+      Err(FlowyError::invalid_data().context(format!("Unsupported archive version: {}", version)))
+    },
+  }
+}
+
+fn read_archive_v1<R: Read>(
+  mut reader: R,
+  cipher: u8,
+  dir: &Path,
+  passphrase: Option<&str>,
+) -> FlowyResult<()> {
+  let payload = match cipher {
+    PLAIN => {
+      let mut payload = vec![];
+      reader.read_to_end(&mut payload)?;
+      payload
+    },
+    AES_256_GCM => {
+      let (cipher, nonce) = read_cipher(&mut reader, passphrase, NONCE_LEN)?;
+      let mut ciphertext = vec![];
+      reader.read_to_end(&mut ciphertext)?;
+      // The authentication tag doesn't match if the key is derived from another passphrase.
+      cipher
+        .decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref())
+        .map_err(|_| FlowyError::invalid_passphrase())?
+    },
+    cipher => {
+      return Err(FlowyError::invalid_data().context(format!("Unsupported cipher: {}", cipher)))
+    },
+  };
+  unpack_dir(&mut PayloadReader::new(payload.as_slice(), None), dir)
+}
+
+/// Reads the parameters of the key derivation and the nonce that follow the cipher, and derives
+/// the key from the passphrase.
+fn read_cipher<R: Read>(
+  reader: &mut R,
+  passphrase: Option<&str>,
+  nonce_len: usize,
+) -> FlowyResult<(Aes256Gcm, Vec<u8>)> {
+  let passphrase = passphrase.ok_or_else(|| {
+    FlowyError::invalid_passphrase().context("The archive is encrypted, the passphrase is required")
+  })?;
+  let iterations = read_u32(reader)?;
+  if !(MIN_KEY_ITERATIONS..=MAX_KEY_ITERATIONS).contains(&iterations) {
+    return Err(FlowyError::invalid_data().context(format!(
+      "Unsupported key iterations of the archive: {}",
+      iterations
+    )));
+  }
+  let mut salt = [0u8; SALT_LEN];
+  read_exact(reader, &mut salt)?;
+  let mut nonce = vec![0u8; nonce_len];
+  read_exact(reader, &mut nonce)?;
+  let cipher = make_cipher(passphrase, &salt, iterations)?;
+  Ok((cipher, nonce))
+}
+
+fn make_cipher(passphrase: &str, salt: &[u8], iterations: u32) -> FlowyResult<Aes256Gcm> {
+  let mut key = [0u8; KEY_LEN];
+  pbkdf2::pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), salt, iterations, &mut key);
+  Aes256Gcm::new_from_slice(&key).map_err(internal_error)
+}
+
+/// Each file is saved as: path_len(4) | path | data_len(8) | data. The path is relative to the
+/// root directory and uses '/' as the separator.
+fn pack_dir<W: Write>(root: &Path, dir: &Path, payload: &mut PayloadWriter<W>) -> FlowyResult<()> {
+  for entry in fs::read_dir(dir)? {
+    let entry = entry?;
+    let path = entry.path();
+    if entry.file_type()?.is_dir() {
+      pack_dir(root, &path, payload)?;
+      continue;
+    }
+
+    let relative_path = path
+      .strip_prefix(root)
+      .map_err(internal_error)?
+      .components()
+      .map(|component| component.as_os_str().to_string_lossy())
+      .collect::<Vec<_>>()
+      .join("/");
+    let mut file = File::open(&path)?;
+    let data_len = file.metadata()?.len();
+    payload.write(&(relative_path.len() as u32).to_le_bytes())?;
+    payload.write(relative_path.as_bytes())?;
+    payload.write(&data_len.to_le_bytes())?;
+
+    let mut buf = vec![0u8; CHUNK_LEN];
+    let mut remaining = data_len;
+    while remaining > 0 {
+      let len = (remaining as usize).min(buf.len());
+      file.read_exact(&mut buf[..len]).map_err(|e| {
+        FlowyError::internal().context(format!("Read {} failed: {}", path.display(), e))
+      })?;
+      payload.write(&buf[..len])?;
+      remaining -= len as u64;
+    }
+  }
+  Ok(())
+}
+
+fn unpack_dir<R: BufRead>(payload: &mut PayloadReader<R>, dir: &Path) -> FlowyResult<()> {
+  fs::create_dir_all(dir)?;
+  while !payload.is_end()? {
+    let mut path_len = [0u8; 4];
+    payload.read_exact(&mut path_len)?;
+    let path_len = u32::from_le_bytes(path_len);
+    if path_len > MAX_PATH_LEN {
+      return Err(FlowyError::invalid_data().context("Invalid path in the archive"));
+    }
+    let mut relative_path = vec![0u8; path_len as usize];
+    payload.read_exact(&mut relative_path)?;
+    let relative_path =
+      String::from_utf8(relative_path).map_err(|e| FlowyError::invalid_data().context(e))?;
+    let mut data_len = [0u8; 8];
+    payload.read_exact(&mut data_len)?;
+    let data_len = u64::from_le_bytes(data_len);
+
+    let path = safe_join(dir, &relative_path)?;
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+    let mut file = BufWriter::new(File::create(path)?);
+    payload.copy_to(&mut file, data_len)?;
+    file.flush()?;
+  }
+  Ok(())
+}
+
+/// Rejects the paths that would escape the directory, e.g. "../kv.db".
+fn safe_join(dir: &Path, relative_path: &str) -> FlowyResult<PathBuf> {
+  let relative_path = Path::new(relative_path);
+  if relative_path
+    .components()
+    .any(|component| !matches!(component, Component::Normal(_)))
+  {
+    return Err(FlowyError::invalid_data().context(format!(
+      "Invalid path in the archive: {}",
+      relative_path.display()
+    )));
+  }
+  Ok(dir.join(relative_path))
+}
+
+/// Writes the payload of the archive. The payload is encrypted in chunks if the encryptor is
+/// set, otherwise it's written as it is.
+struct PayloadWriter<W: Write> {
+  writer: W,
+  encryptor: Option<EncryptorBE32<Aes256Gcm>>,
+  chunk: Vec<u8>,
+}
+
+impl<W: Write> PayloadWriter<W> {
+  fn new(writer: W, encryptor: Option<EncryptorBE32<Aes256Gcm>>) -> Self {
+    Self {
+      writer,
+      encryptor,
+      chunk: Vec::with_capacity(CHUNK_LEN),
+    }
+  }
+
+  fn write(&mut self, mut bytes: &[u8]) -> FlowyResult<()> {
+    let encryptor = match self.encryptor.as_mut() {
+      None => {
+        self.writer.write_all(bytes)?;
+        return Ok(());
+      },
+      Some(encryptor) => encryptor,
+    };
+    while !bytes.is_empty() {
+      let len = (CHUNK_LEN - self.chunk.len()).min(bytes.len());
+      self.chunk.extend_from_slice(&bytes[..len]);
+      bytes = &bytes[len..];
+      if self.chunk.len() == CHUNK_LEN {
+        let ciphertext = encryptor
+          .encrypt_next(self.chunk.as_slice())
+          .map_err(|_| FlowyError::internal().context("Encrypt the archive failed"))?;
+        write_chunk(&mut self.writer, NEXT_CHUNK, &ciphertext)?;
+        self.chunk.clear();
+      }
+    }
+    Ok(())
+  }
+
+  /// Writes the last chunk, which might be empty, and flushes the writer.
+  fn finish(mut self) -> FlowyResult<()> {
+    if let Some(encryptor) = self.encryptor.take() {
+      let ciphertext = encryptor
+        .encrypt_last(self.chunk.as_slice())
+        .map_err(|_| FlowyError::internal().context("Encrypt the archive failed"))?;
+      write_chunk(&mut self.writer, LAST_CHUNK, &ciphertext)?;
+    }
+    self.writer.flush()?;
+    Ok(())
+  }
+}
+
+fn write_chunk<W: Write>(writer: &mut W, flag: u8, ciphertext: &[u8]) -> io::Result<()> {
+  writer.write_all(&[flag])?;
+  writer.write_all(&(ciphertext.len() as u32).to_le_bytes())?;
+  writer.write_all(ciphertext)
+}
+
+/// Reads the payload that was written by [PayloadWriter]. Only one chunk of the encrypted
+/// payload is kept in memory.
+struct PayloadReader<R: BufRead> {
+  reader: R,
+  is_encrypted: bool,
+  /// It's None after the last chunk was read
+  decryptor: Option<DecryptorBE32<Aes256Gcm>>,
+  chunk: Vec<u8>,
+  offset: usize,
+}
+
+impl<R: BufRead> PayloadReader<R> {
+  fn new(reader: R, decryptor: Option<DecryptorBE32<Aes256Gcm>>) -> Self {
+    Self {
+      reader,
+      is_encrypted: decryptor.is_some(),
+      decryptor,
+      chunk: vec![],
+      offset: 0,
+    }
+  }
+
+  /// Returns the bytes that can be read without reading the next chunk. It's empty at the end
+  /// of the payload.
+  fn fill(&mut self) -> FlowyResult<&[u8]> {
+    if !self.is_encrypted {
+      return Ok(self.reader.fill_buf()?);
+    }
+
+    while self.offset == self.chunk.len() {
+      let mut decryptor = match self.decryptor.take() {
+        None => return Ok(&[]),
+        Some(decryptor) => decryptor,
+      };
+      let flag = read_u8(&mut self.reader)?;
+      let len = read_u32(&mut self.reader)? as usize;
+      if len > CHUNK_LEN + TAG_LEN {
+        return Err(FlowyError::invalid_data().context("Invalid chunk of the archive"));
+      }
+      let mut ciphertext = vec![0u8; len];
+      read_exact(&mut self.reader, &mut ciphertext)?;
+      // The authentication tag doesn't match if the key is derived from another passphrase.
+      self.chunk = match flag {
+        NEXT_CHUNK => {
+          let chunk = decryptor
+            .decrypt_next(ciphertext.as_slice())
+            .map_err(|_| FlowyError::invalid_passphrase())?;
+          self.decryptor = Some(decryptor);
+          chunk
+        },
+        LAST_CHUNK => decryptor
+          .decrypt_last(ciphertext.as_slice())
+          .map_err(|_| FlowyError::invalid_passphrase())?,
+        _ => return Err(FlowyError::invalid_data().context("Invalid chunk of the archive")),
+      };
+      self.offset = 0;
+    }
+    Ok(&self.chunk[self.offset..])
+  }
+
+  fn consume(&mut self, len: usize) {
+    if self.is_encrypted {
+      self.offset += len;
+    } else {
+      self.reader.consume(len);
+    }
+  }
+
+  fn is_end(&mut self) -> FlowyResult<bool> {
+    Ok(self.fill()?.is_empty())
+  }
+
+  fn read_exact(&mut self, buf: &mut [u8]) -> FlowyResult<()> {
+    let mut filled = 0;
+    while filled < buf.len() {
+      let len = {
+        let bytes = self.fill()?;
+        if bytes.is_empty() {
+          return Err(truncated_error());
+        }
+        let len = bytes.len().min(buf.len() - filled);
+        buf[filled..filled + len].copy_from_slice(&bytes[..len]);
+        len
+      };
+      self.consume(len);
+      filled += len;
+    }
+    Ok(())
+  }
+
+  fn copy_to<W: Write>(&mut self, writer: &mut W, len: u64) -> FlowyResult<()> {
+    let mut remaining = len;
+    while remaining > 0 {
+      let len = {
+        let bytes = self.fill()?;
+        if bytes.is_empty() {
+          return Err(truncated_error());
+        }
+        let len = (remaining.min(bytes.len() as u64)) as usize;
+        writer.write_all(&bytes[..len])?;
+        len
+      };
+      self.consume(len);
+      remaining -= len as u64;
+    }
+    Ok(())
+  }
+}
+
+fn read_exact<R: Read>(reader: &mut R, buf: &mut [u8]) -> FlowyResult<()> {
+  reader.read_exact(buf).map_err(|e| match e.kind() {
+    io::ErrorKind::UnexpectedEof => truncated_error(),
+    _ => e.into(),
+  })
+}
+
+fn read_u8<R: Read>(reader: &mut R) -> FlowyResult<u8> {
+  let mut buf = [0u8; 1];
+  read_exact(reader, &mut buf)?;
+  Ok(buf[0])
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> FlowyResult<u32> {
+  let mut buf = [0u8; 4];
+  read_exact(reader, &mut buf)?;
+  Ok(u32::from_le_bytes(buf))
+}
+
+fn truncated_error() -> FlowyError {
+  FlowyError::invalid_data().context("The archive is truncated")
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use flowy_error::ErrorCode;
+
+  fn temp_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("appflowy_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    let _ = fs::remove_file(&path);
+    path
+  }
+
+  #[test]
+  fn write_and_read_archive_test() {
+    let dir = temp_path("archive_dir");
+    fs::create_dir_all(dir.join("views/0")).unwrap();
+    // The file spans a few chunks
+    let data = (0..3 * CHUNK_LEN + 7)
+      .map(|i| (i % 251) as u8)
+      .collect::<Vec<u8>>();
+    fs::write(dir.join("views/0/data"), &data).unwrap();
+    fs::write(dir.join("empty"), "").unwrap();
+
+    for passphrase in [None, Some("secret")] {
+      let archive_path = temp_path("archive_file");
+      let unpacked_dir = temp_path("archive_unpacked");
+      write_archive(&dir, &archive_path, passphrase).unwrap();
+      read_archive(&archive_path, &unpacked_dir, passphrase).unwrap();
+      assert_eq!(fs::read(unpacked_dir.join("views/0/data")).unwrap(), data);
+      assert!(fs::read(unpacked_dir.join("empty")).unwrap().is_empty());
+
+      let _ = fs::remove_dir_all(&unpacked_dir);
+      let _ = fs::remove_file(&archive_path);
+    }
+    let _ = fs::remove_dir_all(&dir);
+  }
+
+  #[test]
+  fn read_encrypted_archive_error_test() {
+    let dir = temp_path("encrypted_dir");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("data"), vec![1u8; CHUNK_LEN + 1]).unwrap();
+    let archive_path = temp_path("encrypted_file");
+    write_archive(&dir, &archive_path, Some("secret")).unwrap();
+
+    let unpacked_dir = temp_path("encrypted_unpacked");
+    for passphrase in [None, Some("wrong")] {
+      let err = read_archive(&archive_path, &unpacked_dir, passphrase).unwrap_err();
+      assert_eq!(err.code, ErrorCode::InvalidPassphrase.value());
+    }
+
+    // Dropping the last chunk must not pass as a complete archive
+    let archive = fs::read(&archive_path).unwrap();
+    let last_chunk_len = 1 + 4 + 1 + TAG_LEN;
+    fs::write(&archive_path, &archive[..archive.len() - last_chunk_len]).unwrap();
+    let err = read_archive(&archive_path, &unpacked_dir, Some("secret")).unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidData.value());
+
+    let _ = fs::remove_dir_all(&unpacked_dir);
+    let _ = fs::remove_dir_all(&dir);
+    let _ = fs::remove_file(&archive_path);
+  }
+}
@@ -0,0 +1,245 @@
+use crate::archive::{read_archive, write_archive};
+use flowy_error::{FlowyError, FlowyResult};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The key-value store lives in the root of every storage directory, it's used to tell
+/// whether a directory is an AppFlowy storage.
+const KV_DB_FILE: &str = "kv.db";
+
+/// Copies the whole storage directory, including the databases of all the users, into
+/// `backup_path`. The [AppFlowyCore](crate::AppFlowyCore) that uses the `storage_path` should
+/// not be running, otherwise the backup might contain half-written databases.
+pub fn backup_storage(storage_path: &str, backup_path: &str) -> FlowyResult<()> {
+  let storage_path = Path::new(storage_path);
+  check_storage_dir(storage_path)?;
+
+  let backup_path = Path::new(backup_path);
+  if backup_path.exists() && fs::read_dir(backup_path)?.next().is_some() {
+    return Err(FlowyError::invalid_data().context(format!(
+      "The backup directory {} is not empty",
+      backup_path.display()
+    )));
+  }
+  copy_dir(storage_path, backup_path)
+}
+
+/// Replaces the content of the storage directory with the backup that was created by
+/// [backup_storage]. The current content is removed, so it should be backed up first if it
+/// needs to be kept. The backup is copied next to the storage directory first, so the storage
+/// directory is left untouched if the copy fails.
+pub fn restore_storage(backup_path: &str, storage_path: &str) -> FlowyResult<()> {
+  let backup_path = Path::new(backup_path);
+  check_storage_dir(backup_path)?;
+
+  let restoring_path = prepare_restoring_dir(storage_path)?;
+  if let Err(err) = copy_dir(backup_path, &restoring_path) {
+    let _ = fs::remove_dir_all(&restoring_path);
+    return Err(err);
+  }
+  replace_storage_dir(&restoring_path, storage_path)
+}
+
+/// Packs the storage directory into a single archive file that is encrypted with the key
+/// derived from the `passphrase`.
+pub fn backup_storage_to_archive(
+  storage_path: &str,
+  archive_path: &str,
+  passphrase: &str,
+) -> FlowyResult<()> {
+  let storage_path = Path::new(storage_path);
+  check_storage_dir(storage_path)?;
+
+  write_archive(storage_path, Path::new(archive_path), Some(passphrase))
+}
+
+/// Replaces the content of the storage directory with the archive that was created by
+/// [backup_storage_to_archive]. The storage directory is left untouched if the archive can't
+/// be read, e.g. the passphrase is wrong.
+pub fn restore_storage_from_archive(
+  archive_path: &str,
+  storage_path: &str,
+  passphrase: Option<&str>,
+) -> FlowyResult<()> {
+  let restoring_path = prepare_restoring_dir(storage_path)?;
+  let result = read_archive(Path::new(archive_path), &restoring_path, passphrase)
+    .and_then(|_| check_storage_dir(&restoring_path));
+  if let Err(err) = result {
+    let _ = fs::remove_dir_all(&restoring_path);
+    return Err(err);
+  }
+  replace_storage_dir(&restoring_path, storage_path)
+}
+
+/// Returns the empty directory next to the storage directory that the backup is restored into
+/// before it replaces the storage directory.
+fn prepare_restoring_dir(storage_path: &str) -> FlowyResult<PathBuf> {
+  let restoring_path = PathBuf::from(format!("{}.restoring", storage_path.trim_end_matches('/')));
+  if restoring_path.exists() {
+    fs::remove_dir_all(&restoring_path)?;
+  }
+  Ok(restoring_path)
+}
+
+/// Moves the restored directory into the place of the storage directory. The current storage
+/// directory is renamed aside first and only removed after the restored one is in place, so
+/// it's put back if the rename fails.
+fn replace_storage_dir(restoring_path: &Path, storage_path: &str) -> FlowyResult<()> {
+  let storage_path = storage_path.trim_end_matches('/');
+  if !Path::new(storage_path).exists() {
+    fs::rename(restoring_path, storage_path)?;
+    return Ok(());
+  }
+
+  let replaced_path = PathBuf::from(format!("{}.replaced", storage_path));
+  if replaced_path.exists() {
+    fs::remove_dir_all(&replaced_path)?;
+  }
+  fs::rename(storage_path, &replaced_path)?;
+  if let Err(err) = fs::rename(restoring_path, storage_path) {
+    let _ = fs::rename(&replaced_path, storage_path);
+    let _ = fs::remove_dir_all(restoring_path);
+    return Err(err.into());
+  }
+  fs::remove_dir_all(&replaced_path)?;
+  Ok(())
+}
+
+fn check_storage_dir(path: &Path) -> FlowyResult<()> {
+  if !path.join(KV_DB_FILE).is_file() {
+    return Err(FlowyError::invalid_data().context(format!(
+      "{} is not an AppFlowy storage directory",
+      path.display()
+    )));
+  }
+  Ok(())
+}
+
+fn copy_dir(from: &Path, to: &Path) -> FlowyResult<()> {
+  fs::create_dir_all(to)?;
+  for entry in fs::read_dir(from)? {
+    let entry = entry?;
+    let target = to.join(entry.file_name());
+    if entry.file_type()?.is_dir() {
+      copy_dir(&entry.path(), &target)?;
+    } else {
+      fs::copy(entry.path(), target)?;
+    }
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use flowy_error::ErrorCode;
+
+  fn temp_dir(name: &str) -> String {
+    let path = std::env::temp_dir().join(format!("appflowy_{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&path);
+    path.to_str().unwrap().to_owned()
+  }
+
+  #[test]
+  fn backup_and_restore_storage_test() {
+    let storage_path = temp_dir("storage");
+    let backup_path = temp_dir("backup");
+    fs::create_dir_all(Path::new(&storage_path).join("user")).unwrap();
+    fs::write(Path::new(&storage_path).join(KV_DB_FILE), "kv").unwrap();
+    fs::write(
+      Path::new(&storage_path).join("user/flowy-database.db"),
+      "v1",
+    )
+    .unwrap();
+
+    backup_storage(&storage_path, &backup_path).unwrap();
+    // The backup directory is not empty anymore
+    assert!(backup_storage(&storage_path, &backup_path).is_err());
+
+    fs::write(
+      Path::new(&storage_path).join("user/flowy-database.db"),
+      "v2",
+    )
+    .unwrap();
+    fs::write(Path::new(&storage_path).join("new_file"), "").unwrap();
+    restore_storage(&backup_path, &storage_path).unwrap();
+    assert_eq!(
+      fs::read_to_string(Path::new(&storage_path).join("user/flowy-database.db")).unwrap(),
+      "v1"
+    );
+    assert!(!Path::new(&storage_path).join("new_file").exists());
+    // The directories used while restoring are removed
+    assert!(!Path::new(&format!("{}.restoring", storage_path)).exists());
+    assert!(!Path::new(&format!("{}.replaced", storage_path)).exists());
+
+    let _ = fs::remove_dir_all(&storage_path);
+    let _ = fs::remove_dir_all(&backup_path);
+  }
+
+  #[test]
+  fn backup_and_restore_encrypted_archive_test() {
+    let storage_path = temp_dir("archive_storage");
+    let archive_path = format!("{}.archive", temp_dir("archive"));
+    fs::create_dir_all(Path::new(&storage_path).join("user")).unwrap();
+    fs::write(Path::new(&storage_path).join(KV_DB_FILE), "kv").unwrap();
+    fs::write(
+      Path::new(&storage_path).join("user/flowy-database.db"),
+      "v1",
+    )
+    .unwrap();
+    backup_storage_to_archive(&storage_path, &archive_path, "secret").unwrap();
+
+    fs::write(
+      Path::new(&storage_path).join("user/flowy-database.db"),
+      "v2",
+    )
+    .unwrap();
+    for passphrase in [None, Some("wrong")] {
+      let err = restore_storage_from_archive(&archive_path, &storage_path, passphrase).unwrap_err();
+      assert_eq!(err.code, ErrorCode::InvalidPassphrase.value());
+    }
+    // The storage is not touched if the passphrase is wrong
+    assert_eq!(
+      fs::read_to_string(Path::new(&storage_path).join("user/flowy-database.db")).unwrap(),
+      "v2"
+    );
+
+    restore_storage_from_archive(&archive_path, &storage_path, Some("secret")).unwrap();
+    assert_eq!(
+      fs::read_to_string(Path::new(&storage_path).join("user/flowy-database.db")).unwrap(),
+      "v1"
+    );
+
+    let _ = fs::remove_dir_all(&storage_path);
+    let _ = fs::remove_file(&archive_path);
+  }
+
+  #[test]
+  fn restore_archive_with_invalid_iterations_test() {
+    let storage_path = temp_dir("iterations_storage");
+    let archive_path = format!("{}.archive", temp_dir("iterations"));
+    fs::create_dir_all(&storage_path).unwrap();
+    fs::write(Path::new(&storage_path).join(KV_DB_FILE), "kv").unwrap();
+    backup_storage_to_archive(&storage_path, &archive_path, "secret").unwrap();
+
+    // The iterations follow the magic, the version and the cipher
+    let mut archive = fs::read(&archive_path).unwrap();
+    archive[6..10].copy_from_slice(&u32::MAX.to_le_bytes());
+    fs::write(&archive_path, archive).unwrap();
+    let err =
+      restore_storage_from_archive(&archive_path, &storage_path, Some("secret")).unwrap_err();
+    assert_eq!(err.code, ErrorCode::InvalidData.value());
+
+    let _ = fs::remove_dir_all(&storage_path);
+    let _ = fs::remove_file(&archive_path);
+  }
+
+  #[test]
+  fn restore_from_invalid_backup_test() {
+    let storage_path = temp_dir("invalid_storage");
+    let backup_path = temp_dir("invalid_backup");
+    fs::create_dir_all(&backup_path).unwrap();
+    assert!(restore_storage(&backup_path, &storage_path).is_err());
+    let _ = fs::remove_dir_all(&backup_path);
+  }
+}
@@ -11,7 +11,7 @@ use flowy_sqlite::ConnectionPool;
 use flowy_user::services::UserSession;
 use futures_core::future::BoxFuture;
 use lib_infra::future::BoxResultFuture;
-use lib_ws::{WSChannel, WSMessageReceiver, WebSocketRawMessage};
+use lib_ws::{WSChannel, WSConnectState, WSMessageReceiver, WebSocketRawMessage};
 use std::{convert::TryInto, path::Path, sync::Arc};
 use ws_model::ws_revision::ClientRevisionWSData;
 
@@ -101,6 +101,11 @@ impl RevisionWebSocket for DocumentRevisionWebSocket {
     let ws_conn = self.0.clone();
     Box::pin(async move { ws_conn.subscribe_websocket_state().await })
   }
+
+  fn connect_state(&self) -> BoxFuture<WSConnectState> {
+    let ws_conn = self.0.clone();
+    Box::pin(async move { ws_conn.websocket_state().await })
+  }
 }
 
 struct DocumentWSMessageReceiverImpl(Arc<DocumentManager>);
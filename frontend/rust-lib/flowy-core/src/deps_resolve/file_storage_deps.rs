@@ -0,0 +1,65 @@
+use crate::FlowyError;
+use flowy_user::services::UserSession;
+use lib_infra::id_gen::gen_id;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+pub struct FileStorageDepsResolver();
+impl FileStorageDepsResolver {
+  pub fn resolve(user_session: Arc<UserSession>) -> Arc<FileStorageService> {
+    Arc::new(FileStorageService { user_session })
+  }
+}
+
+/// Stores the files of the current user, e.g. the files that are attached to the cells of the
+/// databases. The files are copied into the `files` directory under the user's directory and
+/// named by their ids.
+pub struct FileStorageService {
+  user_session: Arc<UserSession>,
+}
+
+impl FileStorageService {
+  /// Copies the file into the storage. Returns the id and the size in bytes of the stored file.
+  /// The id keeps the extension of the file.
+  pub fn store_file(&self, file_path: &str) -> Result<(String, u64), FlowyError> {
+    let file_id = match Path::new(file_path).extension() {
+      None => gen_id(),
+      Some(extension) => format!("{}.{}", gen_id(), extension.to_string_lossy()),
+    };
+    let size = std::fs::copy(file_path, self.make_file_path(&file_id)?)?;
+    Ok((file_id, size))
+  }
+
+  /// Deletes the file from the storage. It's not an error if the file doesn't exist.
+  pub fn delete_file(&self, file_id: &str) -> Result<(), FlowyError> {
+    match std::fs::remove_file(self.make_file_path(file_id)?) {
+      Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+      _ => Ok(()),
+    }
+  }
+
+  pub fn file_path(&self, file_id: &str) -> Result<String, FlowyError> {
+    Ok(self.make_file_path(file_id)?.to_string_lossy().to_string())
+  }
+
+  fn make_file_path(&self, file_id: &str) -> Result<PathBuf, FlowyError> {
+    // The ids come from the cells, which might be edited by other clients
+    if file_id.is_empty() || file_id.contains(['/', '\\']) || file_id.contains("..") {
+      let msg = format!("Invalid file id: {}", file_id);
+      return Err(FlowyError::invalid_data().context(msg));
+    }
+    Ok(self.files_dir()?.join(file_id))
+  }
+
+  fn files_dir(&self) -> Result<PathBuf, FlowyError> {
+    let user_dir = self
+      .user_session
+      .user_dir()
+      .map_err(|e| FlowyError::unauthorized().context(e))?;
+    let files_dir = Path::new(&user_dir).join("files");
+    if !files_dir.exists() {
+      std::fs::create_dir_all(&files_dir)?;
+    }
+    Ok(files_dir)
+  }
+}
@@ -5,12 +5,24 @@ use database_model::BuildDatabaseContext;
 use flowy_client_ws::FlowyWebSocketConnect;
 use flowy_database::entities::LayoutTypePB;
 use flowy_database::manager::{create_new_database, link_existing_database, DatabaseManager};
+use flowy_database::notification::DatabaseNotification;
+use flowy_database::services::import::{
+  create_database_from_csv, create_database_from_trello, run_database_import,
+};
 use flowy_database::util::{make_default_board, make_default_calendar, make_default_grid};
+#[cfg(feature = "dev")]
+use flowy_database::util::{synthetic_text, SyntheticGrid};
 use flowy_document::editor::make_transaction_from_document_content;
+use flowy_document::notification::DocumentNotification;
 use flowy_document::DocumentManager;
+use flowy_error::FlowyResult;
 
-use flowy_folder::entities::{ViewDataFormatPB, ViewLayoutTypePB, ViewPB};
-use flowy_folder::manager::{ViewDataProcessor, ViewDataProcessorMap};
+use flowy_folder::entities::{
+  ImportTypePB, ViewDataFormatPB, ViewLayoutTypePB, ViewPB, WorkspaceQuota,
+};
+use flowy_folder::manager::{
+  ViewArchiver, ViewDataMemoryUsage, ViewDataProcessor, ViewDataProcessorMap,
+};
 use flowy_folder::{
   errors::{internal_error, FlowyError},
   event_map::{FolderCouldServiceV1, WorkspaceDatabase, WorkspaceUser},
@@ -18,15 +30,22 @@ use flowy_folder::{
 };
 use flowy_net::ClientServerConfiguration;
 use flowy_net::{http_server::folder::FolderHttpCloudService, local_server::LocalServer};
+use flowy_notification::entities::SubscribeObject;
+use flowy_notification::{
+  register_notification_sender, unregister_notification_sender, NotificationSender,
+};
 use flowy_revision::{RevisionWebSocket, WSStateReceiver};
+use flowy_task::TaskDispatcher;
 use flowy_user::services::UserSession;
 use futures_core::future::BoxFuture;
 use lib_infra::future::{BoxResultFuture, FutureResult};
-use lib_ws::{WSChannel, WSMessageReceiver, WebSocketRawMessage};
+use lib_ws::{WSChannel, WSConnectState, WSMessageReceiver, WebSocketRawMessage};
 use revision_model::Revision;
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::path::Path;
 use std::{convert::TryInto, sync::Arc};
+use tokio::sync::{mpsc, RwLock};
 use ws_model::ws_revision::ClientRevisionWSData;
 
 pub struct FolderDepsResolver();
@@ -38,6 +57,8 @@ impl FolderDepsResolver {
     ws_conn: &Arc<FlowyWebSocketConnect>,
     text_block_manager: &Arc<DocumentManager>,
     database_manager: &Arc<DatabaseManager>,
+    task_scheduler: Arc<RwLock<TaskDispatcher>>,
+    quota: WorkspaceQuota,
   ) -> Arc<FolderManager> {
     let user: Arc<dyn WorkspaceUser> = Arc::new(WorkspaceUserImpl(user_session.clone()));
     let database: Arc<dyn WorkspaceDatabase> = Arc::new(WorkspaceDatabaseImpl(user_session));
@@ -56,6 +77,9 @@ impl FolderDepsResolver {
         database,
         view_data_processor,
         web_socket,
+        task_scheduler,
+        quota,
+        Arc::new(FolderArchiverImpl()),
       )
       .await,
     );
@@ -69,10 +93,81 @@ impl FolderDepsResolver {
 
     let receiver = Arc::new(FolderWSMessageReceiverImpl(folder_manager.clone()));
     ws_conn.add_ws_message_receiver(receiver).unwrap();
+    listen_on_view_content_changed(&folder_manager, user);
     folder_manager
   }
 }
 
+/// The search index of the folder needs to be updated when the document or the database was
+/// changed. These changes are observed from the notifications. The observer is unregistered
+/// once the folder manager was dropped.
+fn listen_on_view_content_changed(
+  folder_manager: &Arc<FolderManager>,
+  user: Arc<dyn WorkspaceUser>,
+) {
+  let (tx, mut rx) = mpsc::unbounded_channel();
+  let sender_id = register_notification_sender(ViewContentChangedObserver(tx));
+
+  let folder_manager = Arc::downgrade(folder_manager);
+  tokio::spawn(async move {
+    while let Some(change) = rx.recv().await {
+      let folder_manager = match folder_manager.upgrade() {
+        None => break,
+        Some(folder_manager) => folder_manager,
+      };
+      // The local edits are made by the current user. The author of the remote revisions is
+      // unknown, so the last editor recorded by the other device is kept.
+      let edited_by = match change.is_remote {
+        false => user.user_id().ok(),
+        true => None,
+      };
+      folder_manager
+        .did_update_view_content(&change.view_id, edited_by.as_deref())
+        .await;
+    }
+    unregister_notification_sender(sender_id);
+  });
+}
+
+struct ViewContentChange {
+  view_id: String,
+  is_remote: bool,
+}
+
+struct ViewContentChangedObserver(mpsc::UnboundedSender<ViewContentChange>);
+impl NotificationSender for ViewContentChangedObserver {
+  fn send_subject(&self, subject: SubscribeObject) -> Result<(), String> {
+    let is_remote = match subject.source.as_str() {
+      flowy_document::notification::OBSERVABLE_CATEGORY => {
+        if subject.ty == DocumentNotification::DidUpdateDocument as i32 {
+          Some(false)
+        } else if subject.ty == DocumentNotification::DidReceiveRemoteRevisions as i32 {
+          Some(true)
+        } else {
+          None
+        }
+      },
+      // The database is not synced with the other devices, all its changes are local.
+      flowy_database::notification::OBSERVABLE_CATEGORY => {
+        let is_content_changed = subject.ty == DatabaseNotification::DidUpdateViewRows as i32
+          || subject.ty == DatabaseNotification::DidUpdateFields as i32;
+        is_content_changed.then_some(false)
+      },
+      _ => None,
+    };
+
+    if let Some(is_remote) = is_remote {
+      // The receiver is gone if the folder manager was dropped, the observer is being
+      // unregistered then.
+      let _ = self.0.send(ViewContentChange {
+        view_id: subject.id,
+        is_remote,
+      });
+    }
+    Ok(())
+  }
+}
+
 fn make_view_data_processor(
   document_manager: Arc<DocumentManager>,
   database_manager: Arc<DatabaseManager>,
@@ -123,6 +218,34 @@ impl WorkspaceUser for WorkspaceUserImpl {
       .token()
       .map_err(|e| FlowyError::internal().context(e))
   }
+
+  fn user_dir(&self) -> Result<String, FlowyError> {
+    self
+      .0
+      .user_dir()
+      .map_err(|e| FlowyError::internal().context(e))
+  }
+}
+
+struct FolderArchiverImpl();
+impl ViewArchiver for FolderArchiverImpl {
+  fn write_archive(
+    &self,
+    dir: &Path,
+    archive_path: &Path,
+    passphrase: Option<&str>,
+  ) -> FlowyResult<()> {
+    crate::archive::write_archive(dir, archive_path, passphrase)
+  }
+
+  fn read_archive(
+    &self,
+    archive_path: &Path,
+    dir: &Path,
+    passphrase: Option<&str>,
+  ) -> FlowyResult<()> {
+    crate::archive::read_archive(archive_path, dir, passphrase)
+  }
 }
 
 struct FolderRevisionWebSocket(Arc<FlowyWebSocketConnect>);
@@ -150,6 +273,11 @@ impl RevisionWebSocket for FolderRevisionWebSocket {
     let ws_conn = self.0.clone();
     Box::pin(async move { ws_conn.subscribe_websocket_state().await })
   }
+
+  fn connect_state(&self) -> BoxFuture<WSConnectState> {
+    let ws_conn = self.0.clone();
+    Box::pin(async move { ws_conn.websocket_state().await })
+  }
 }
 
 struct FolderWSMessageReceiverImpl(Arc<FolderManager>);
@@ -176,6 +304,15 @@ impl ViewDataProcessor for DocumentViewDataProcessor {
     })
   }
 
+  fn delete_view(&self, view_id: &str) -> FutureResult<(), FlowyError> {
+    let manager = self.0.clone();
+    let view_id = view_id.to_string();
+    FutureResult::new(async move {
+      manager.did_delete_document(&view_id).await?;
+      Ok(())
+    })
+  }
+
   fn get_view_data(&self, view: &ViewPB) -> FutureResult<Bytes, FlowyError> {
     let view_id = view.id.clone();
     let manager = self.0.clone();
@@ -186,6 +323,16 @@ impl ViewDataProcessor for DocumentViewDataProcessor {
     })
   }
 
+  fn get_view_search_content(&self, view_id: &str) -> FutureResult<String, FlowyError> {
+    let view_id = view_id.to_string();
+    let manager = self.0.clone();
+    FutureResult::new(async move {
+      let editor = manager.open_document_editor(view_id).await?;
+      let document_content = editor.export().await?;
+      Ok(text_from_document_content(&document_content))
+    })
+  }
+
   fn create_view_with_build_in_data(
     &self,
     user_id: &str,
@@ -236,11 +383,64 @@ impl ViewDataProcessor for DocumentViewDataProcessor {
     })
   }
 
+  fn import_from_file_path(
+    &self,
+    _view_id: &str,
+    _name: &str,
+    _file_path: &str,
+    import_type: ImportTypePB,
+  ) -> FutureResult<(), FlowyError> {
+    FutureResult::new(async move {
+      Err(FlowyError::internal().context(format!("Can't import {:?} as a document", import_type)))
+    })
+  }
+
+  #[cfg(feature = "dev")]
+  fn create_view_with_synthetic_data(
+    &self,
+    _user_id: &str,
+    view_id: &str,
+    _name: &str,
+    layout: ViewLayoutTypePB,
+    size: usize,
+  ) -> FutureResult<(), FlowyError> {
+    debug_assert_eq!(layout, ViewLayoutTypePB::Document);
+    let text = (0..size)
+      .map(synthetic_text)
+      .collect::<Vec<String>>()
+      .join("\n");
+    let content = self.0.document_content_from_text(&text);
+    let revision = Revision::initial_revision(view_id, Bytes::from(content));
+    let view_id = view_id.to_string();
+    let manager = self.0.clone();
+    FutureResult::new(async move {
+      manager.create_document(view_id, vec![revision]).await?;
+      Ok(())
+    })
+  }
+
   fn data_types(&self) -> Vec<ViewDataFormatPB> {
     vec![ViewDataFormatPB::DeltaFormat, ViewDataFormatPB::NodeFormat]
   }
+
+  fn number_of_unacked_revisions(&self) -> FutureResult<usize, FlowyError> {
+    let manager = self.0.clone();
+    FutureResult::new(async move { Ok(manager.number_of_unacked_revisions().await) })
+  }
+
+  fn memory_usage(&self) -> FutureResult<ViewDataMemoryUsage, FlowyError> {
+    let manager = self.0.clone();
+    FutureResult::new(async move {
+      Ok(ViewDataMemoryUsage {
+        open_document_bytes: manager.open_document_bytes().await,
+        ..Default::default()
+      })
+    })
+  }
 }
 
+/// The document manager creates the documents of the rows, e.g. the descriptions of the cards
+/// that are imported from Trello.
 struct DatabaseViewDataProcessor(Arc<DatabaseManager>);
 impl ViewDataProcessor for DatabaseViewDataProcessor {
   fn close_view(&self, view_id: &str) -> FutureResult<(), FlowyError> {
@@ -252,6 +452,39 @@ impl ViewDataProcessor for DatabaseViewDataProcessor {
     })
   }
 
+  /// Deletes the data of the view. The database is deleted too if no other view refers to it.
+  fn delete_view(&self, view_id: &str) -> FutureResult<(), FlowyError> {
+    let database_manager = self.0.clone();
+    let view_id = view_id.to_string();
+    FutureResult::new(async move {
+      let database_id = database_manager.get_database_id_with_view(&view_id)?;
+      let is_last_view = database_manager
+        .get_database_ref_views(&database_id)
+        .await?
+        .iter()
+        .all(|view_ref| view_ref.view_id == view_id);
+      // The relation fields of the other databases might refuse to lose their linked rows
+      if is_last_view {
+        database_manager
+          .check_relation_dependents(&database_id, None)
+          .await?;
+      }
+
+      database_manager.delete_database_view(&view_id).await?;
+      if database_manager
+        .get_database_ref_views(&database_id)
+        .await?
+        .is_empty()
+      {
+        database_manager
+          .unlink_relation_dependents(&database_id, None)
+          .await?;
+        database_manager.delete_database(&database_id).await?;
+      }
+      Ok(())
+    })
+  }
+
   fn get_view_data(&self, view: &ViewPB) -> FutureResult<Bytes, FlowyError> {
     let database_manager = self.0.clone();
     let view_id = view.id.clone();
@@ -262,6 +495,16 @@ impl ViewDataProcessor for DatabaseViewDataProcessor {
     })
   }
 
+  fn get_view_search_content(&self, view_id: &str) -> FutureResult<String, FlowyError> {
+    let database_manager = self.0.clone();
+    let view_id = view_id.to_string();
+    FutureResult::new(async move {
+      let editor = database_manager.open_database_view(&view_id).await?;
+      let display_strs = editor.get_all_cell_display_strs(&view_id).await?;
+      Ok(display_strs.join("\n"))
+    })
+  }
+
   /// Create a database view with build-in data.
   /// If the ext contains the {"database_id": "xx"}, then it will link to
   /// the existing database. The data of the database will be shared within
@@ -307,7 +550,8 @@ impl ViewDataProcessor for DatabaseViewDataProcessor {
   /// Create a database view with custom data.
   /// If the ext contains the {"database_id": "xx"}, then it will link
   /// to the existing database. The data of the database will be shared
-  /// within these references views.
+  /// within these references views, and the rows of the custom data are
+  /// appended to the existing database.
   fn create_view_with_custom_data(
     &self,
     _user_id: &str,
@@ -325,18 +569,142 @@ impl ViewDataProcessor for DatabaseViewDataProcessor {
       None => FutureResult::new(async move {
         let bytes = Bytes::from(data);
         let build_context = BuildDatabaseContext::try_from(bytes)?;
-        let _ = create_new_database(&view_id, name, layout, database_manager, build_context).await;
-        Ok(())
+        create_new_database(&view_id, name, layout, database_manager, build_context).await
       }),
       Some(database_id) => FutureResult::new(async move {
+        if !data.is_empty() {
+          let build_context = BuildDatabaseContext::try_from(Bytes::from(data))?;
+          database_manager
+            .append_rows_to_database(&database_id, build_context)
+            .await?;
+        }
         link_existing_database(&view_id, name, &database_id, layout, database_manager).await
       }),
     }
   }
 
+  /// Create a grid with the header of the CSV file, or a board with the lists and the labels of
+  /// the Trello board, and import the records or the cards in the background. The progress of
+  /// the import is saved in a checkpoint after each chunk of records, so the import resumes from
+  /// there if the app is killed in the middle. The descriptions of the Trello cards are written
+  /// into the documents of the rows.
+  fn import_from_file_path(
+    &self,
+    view_id: &str,
+    name: &str,
+    file_path: &str,
+    import_type: ImportTypePB,
+  ) -> FutureResult<(), FlowyError> {
+    let view_id = view_id.to_string();
+    let name = name.to_string();
+    let file_path = file_path.to_string();
+    let database_manager = self.0.clone();
+    FutureResult::new(async move {
+      match import_type {
+        ImportTypePB::CSV => {
+          create_database_from_csv(&view_id, name, &file_path, database_manager.clone()).await?;
+        },
+        ImportTypePB::TrelloJSON => {
+          create_database_from_trello(&view_id, name, &file_path, database_manager.clone()).await?;
+        },
+        ImportTypePB::Archive => {
+          return Err(FlowyError::internal().context("The archive is imported by the folder"));
+        },
+      }
+      tokio::spawn(async move {
+        if let Err(err) = run_database_import(&view_id, database_manager).await {
+          tracing::error!("Import {} failed: {:?}", file_path, err);
+        }
+      });
+      Ok(())
+    })
+  }
+
+  #[cfg(feature = "dev")]
+  fn create_view_with_synthetic_data(
+    &self,
+    _user_id: &str,
+    view_id: &str,
+    name: &str,
+    layout: ViewLayoutTypePB,
+    size: usize,
+  ) -> FutureResult<(), FlowyError> {
+    let view_id = view_id.to_string();
+    let name = name.to_string();
+    let database_manager = self.0.clone();
+    let layout = layout_type_from_view_layout(layout);
+    FutureResult::new(async move {
+      let synthetic_grid = SyntheticGrid::new();
+      create_new_database(
+        &view_id,
+        name,
+        layout,
+        database_manager.clone(),
+        synthetic_grid.build_context(),
+      )
+      .await?;
+
+      let editor = database_manager.open_database_view(&view_id).await?;
+      let result = synthetic_grid.insert_rows(&editor, &view_id, size).await;
+      database_manager.close_database_view(&view_id).await?;
+      result
+    })
+  }
+
   fn data_types(&self) -> Vec<ViewDataFormatPB> {
     vec![ViewDataFormatPB::DatabaseFormat]
   }
+
+  fn memory_usage(&self) -> FutureResult<ViewDataMemoryUsage, FlowyError> {
+    let database_manager = self.0.clone();
+    FutureResult::new(async move {
+      Ok(ViewDataMemoryUsage {
+        cell_data_cache_bytes: database_manager.cell_data_cache_bytes().await,
+        filter_result_bytes: database_manager.filter_result_bytes().await,
+        ..Default::default()
+      })
+    })
+  }
+
+  fn number_of_unacked_revisions(&self) -> FutureResult<usize, FlowyError> {
+    let database_manager = self.0.clone();
+    FutureResult::new(async move { Ok(database_manager.number_of_unacked_revisions().await) })
+  }
+
+  fn trim_caches(&self) -> FutureResult<(), FlowyError> {
+    let database_manager = self.0.clone();
+    FutureResult::new(async move {
+      database_manager.trim_caches().await;
+      Ok(())
+    })
+  }
+}
+
+/// Extracts the plain text from the document content. Both the delta document and the node
+/// document keep their text in the `insert` of the operations.
+fn text_from_document_content(content: &str) -> String {
+  fn collect_text(value: &serde_json::Value, texts: &mut Vec<String>) {
+    match value {
+      serde_json::Value::Array(values) => {
+        let text = values
+          .iter()
+          .flat_map(|value| value.get("insert").and_then(|insert| insert.as_str()))
+          .collect::<String>();
+        if !text.is_empty() {
+          texts.push(text);
+        }
+        values.iter().for_each(|value| collect_text(value, texts));
+      },
+      serde_json::Value::Object(map) => map.values().for_each(|value| collect_text(value, texts)),
+      _ => {},
+    }
+  }
+
+  let mut texts = vec![];
+  if let Ok(value) = serde_json::from_str::<serde_json::Value>(content) {
+    collect_text(&value, &mut texts);
+  }
+  texts.join("\n")
 }
 
 pub fn layout_type_from_view_layout(layout: ViewLayoutTypePB) -> LayoutTypePB {
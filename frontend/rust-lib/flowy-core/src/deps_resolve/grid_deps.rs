@@ -1,15 +1,24 @@
+use crate::deps_resolve::FileStorageService;
 use crate::FlowyError;
 use bytes::Bytes;
 use flowy_client_ws::FlowyWebSocketConnect;
-use flowy_database::manager::{DatabaseManager, DatabaseUser};
+use flowy_database::manager::{
+  DatabaseConfig, DatabaseFileStorage, DatabaseGeocoder, DatabaseInbox, DatabaseInboxKind,
+  DatabaseInboxNotification, DatabaseManager, DatabaseRowDocuments, DatabaseUser,
+};
+use flowy_database::services::field::Location;
 use flowy_database::services::persistence::DatabaseDBConnection;
+use flowy_document::DocumentManager;
+use flowy_net::geocoding::GeocodingProvider;
 use flowy_revision::{RevisionWebSocket, WSStateReceiver};
 use flowy_sqlite::ConnectionPool;
 use flowy_task::TaskDispatcher;
-use flowy_user::services::UserSession;
+use flowy_user::entities::{AddInboxNotificationParams, InboxNotificationKindPB};
+use flowy_user::services::{InboxService, UserSession};
 use futures_core::future::BoxFuture;
-use lib_infra::future::BoxResultFuture;
-use lib_ws::{WSChannel, WebSocketRawMessage};
+use lib_infra::future::{BoxResultFuture, FutureResult};
+use lib_ws::{WSChannel, WSConnectState, WebSocketRawMessage};
+use revision_model::Revision;
 use std::convert::TryInto;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -22,15 +31,26 @@ impl DatabaseDepsResolver {
     ws_conn: Arc<FlowyWebSocketConnect>,
     user_session: Arc<UserSession>,
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
+    file_storage: Arc<FileStorageService>,
+    document_manager: Arc<DocumentManager>,
+    geocoding_provider: Option<Arc<dyn GeocodingProvider>>,
+    database_config: &DatabaseConfig,
   ) -> Arc<DatabaseManager> {
     let user = Arc::new(GridUserImpl(user_session.clone()));
     let rev_web_socket = Arc::new(GridRevisionWebSocket(ws_conn));
-    Arc::new(DatabaseManager::new(
+    let database_manager = Arc::new(DatabaseManager::new(
       user,
       rev_web_socket,
       task_scheduler,
-      Arc::new(DatabaseDBConnectionImpl(user_session)),
-    ))
+      Arc::new(DatabaseDBConnectionImpl(user_session.clone())),
+      Arc::new(DatabaseFileStorageImpl(file_storage)),
+      Arc::new(DatabaseGeocoderImpl(geocoding_provider)),
+      Arc::new(DatabaseRowDocumentsImpl(document_manager)),
+      Arc::new(DatabaseInboxImpl(InboxService::new(user_session.clone()))),
+      database_config.clone(),
+    ));
+    database_manager.start_cell_calculation().await;
+    database_manager
   }
 }
 
@@ -44,12 +64,103 @@ impl DatabaseDBConnection for DatabaseDBConnectionImpl {
   }
 }
 
+struct DatabaseFileStorageImpl(Arc<FileStorageService>);
+impl DatabaseFileStorage for DatabaseFileStorageImpl {
+  fn store_file(&self, file_path: &str) -> Result<(String, u64), FlowyError> {
+    self.0.store_file(file_path)
+  }
+
+  fn delete_file(&self, file_id: &str) -> Result<(), FlowyError> {
+    self.0.delete_file(file_id)
+  }
+
+  fn file_path(&self, file_id: &str) -> Result<String, FlowyError> {
+    self.0.file_path(file_id)
+  }
+}
+
+struct DatabaseGeocoderImpl(Option<Arc<dyn GeocodingProvider>>);
+impl DatabaseGeocoder for DatabaseGeocoderImpl {
+  fn geocode(&self, address: &str) -> FutureResult<Option<Location>, FlowyError> {
+    let provider = self.0.clone();
+    let address = address.to_owned();
+    FutureResult::new(async move {
+      let provider = provider
+        .ok_or_else(|| FlowyError::internal().context("No geocoding provider is configured"))?;
+      let location = provider.geocode(&address).await?.map(|location| Location {
+        latitude: location.latitude,
+        longitude: location.longitude,
+        address: location.address,
+      });
+      Ok(location)
+    })
+  }
+}
+
+struct DatabaseRowDocumentsImpl(Arc<DocumentManager>);
+impl DatabaseRowDocuments for DatabaseRowDocumentsImpl {
+  fn duplicate_document(
+    &self,
+    document_id: &str,
+    new_document_id: &str,
+  ) -> FutureResult<(), FlowyError> {
+    let document_manager = self.0.clone();
+    let document_id = document_id.to_owned();
+    let new_document_id = new_document_id.to_owned();
+    FutureResult::new(async move {
+      document_manager
+        .duplicate_document(&document_id, &new_document_id)
+        .await?;
+      Ok(())
+    })
+  }
+
+  fn create_document_from_text(
+    &self,
+    document_id: &str,
+    text: &str,
+  ) -> FutureResult<(), FlowyError> {
+    let document_manager = self.0.clone();
+    let document_id = document_id.to_owned();
+    let content = document_manager.document_content_from_text(text);
+    FutureResult::new(async move {
+      let revision = Revision::initial_revision(&document_id, Bytes::from(content));
+      document_manager
+        .create_document(&document_id, vec![revision])
+        .await?;
+      Ok(())
+    })
+  }
+}
+
+struct DatabaseInboxImpl(InboxService);
+impl DatabaseInbox for DatabaseInboxImpl {
+  fn add_notification(&self, notification: DatabaseInboxNotification) -> Result<(), FlowyError> {
+    let kind = match notification.kind {
+      DatabaseInboxKind::Reminder => InboxNotificationKindPB::Reminder,
+      DatabaseInboxKind::Mention => InboxNotificationKindPB::Mention,
+      DatabaseInboxKind::Share => InboxNotificationKindPB::Share,
+    };
+    self.0.add_notification(AddInboxNotificationParams {
+      kind,
+      title: notification.title,
+      body: notification.body,
+      object_id: notification.object_id,
+    })?;
+    Ok(())
+  }
+}
+
 struct GridUserImpl(Arc<UserSession>);
 impl DatabaseUser for GridUserImpl {
   fn user_id(&self) -> Result<String, FlowyError> {
     self.0.user_id()
   }
 
+  fn user_name(&self) -> Result<String, FlowyError> {
+    self.0.user_name()
+  }
+
   fn token(&self) -> Result<String, FlowyError> {
     self.0.token()
   }
@@ -86,4 +197,9 @@ impl RevisionWebSocket for GridRevisionWebSocket {
     let ws_conn = self.0.clone();
     Box::pin(async move { ws_conn.subscribe_websocket_state().await })
   }
+
+  fn connect_state(&self) -> BoxFuture<WSConnectState> {
+    let ws_conn = self.0.clone();
+    Box::pin(async move { ws_conn.websocket_state().await })
+  }
 }
@@ -1,10 +1,12 @@
 mod document_deps;
+mod file_storage_deps;
 mod folder_deps;
 mod grid_deps;
 mod user_deps;
 mod util;
 
 pub use document_deps::*;
+pub use file_storage_deps::*;
 pub use folder_deps::*;
 pub use grid_deps::*;
 pub use user_deps::*;
@@ -0,0 +1,295 @@
+use flowy_database::event_map::DatabaseEvent;
+use flowy_document::event_map::DocumentEvent;
+use flowy_error::FlowyError;
+use flowy_folder::event_map::FolderEvent;
+use flowy_net::event_map::NetworkEvent;
+use flowy_user::event_map::UserEvent;
+use futures_core::future::BoxFuture;
+use lib_dispatch::prelude::{
+  AFPluginEvent, AFPluginInterceptor, AFPluginRequest, DispatchError, PermissionScope, ScopeAccess,
+};
+use std::collections::{HashMap, HashSet};
+use strum::IntoEnumIterator;
+
+pub const DATABASE_SCOPE_RESOURCE: &str = "database";
+pub const DOCUMENT_SCOPE_RESOURCE: &str = "document";
+pub const WORKSPACE_SCOPE_RESOURCE: &str = "workspace";
+
+/// [EventScopeInterceptor] limits the scoped requests, e.g. the requests authenticated with a
+/// personal access token, to the events that are granted by their scopes. The requests without
+/// scopes are sent by the app and they are passed through.
+///
+/// Each event requires one scope. The events that are not assigned a scope are rejected for
+/// all the scoped requests.
+pub struct EventScopeInterceptor {
+  scope_by_event: HashMap<AFPluginEvent, PermissionScope>,
+  /// The events that change the databases, the documents or the workspace
+  mutating_events: HashSet<AFPluginEvent>,
+}
+
+impl EventScopeInterceptor {
+  pub fn new() -> Self {
+    let mut scope_by_event = HashMap::new();
+    for event in DatabaseEvent::iter() {
+      scope_by_event.insert(event.into(), database_event_scope(&event));
+    }
+    for event in DocumentEvent::iter() {
+      scope_by_event.insert(event.into(), document_event_scope(&event));
+    }
+    for event in FolderEvent::iter() {
+      scope_by_event.insert(event.into(), folder_event_scope(&event));
+    }
+    // The user and the network events are left out, they change the app instead of the data
+    let mutating_events = scope_by_event
+      .iter()
+      .filter(|(_, scope)| scope.access != ScopeAccess::Read)
+      .map(|(event, _)| event.clone())
+      .collect::<HashSet<AFPluginEvent>>();
+    // The user and the network events change the whole app, e.g. signing out
+    for event in UserEvent::iter() {
+      scope_by_event.insert(
+        event.into(),
+        PermissionScope::admin(WORKSPACE_SCOPE_RESOURCE),
+      );
+    }
+    for event in NetworkEvent::iter() {
+      scope_by_event.insert(
+        event.into(),
+        PermissionScope::admin(WORKSPACE_SCOPE_RESOURCE),
+      );
+    }
+    Self {
+      scope_by_event,
+      mutating_events,
+    }
+  }
+
+  pub fn required_scope(&self, event: &AFPluginEvent) -> Option<&PermissionScope> {
+    self.scope_by_event.get(event)
+  }
+
+  /// Returns true if the event changes the databases, the documents or the workspace, i.e. it
+  /// requires more than the read scope.
+  pub fn is_mutating_event(&self, event: &AFPluginEvent) -> bool {
+    self.mutating_events.contains(event)
+  }
+
+  fn check_request(&self, request: &AFPluginRequest) -> Result<(), FlowyError> {
+    let scopes = match request.get_scopes() {
+      None => return Ok(()),
+      Some(scopes) => scopes,
+    };
+    match self.required_scope(&request.event) {
+      None => Err(FlowyError::permission_denied().context(format!(
+        "{} can't be dispatched by a scoped request",
+        request.event.0
+      ))),
+      Some(required) => {
+        if scopes.iter().any(|scope| scope.grants(required)) {
+          Ok(())
+        } else {
+          Err(FlowyError::permission_denied().context(format!(
+            "{} requires the {} scope",
+            request.event.0, required
+          )))
+        }
+      },
+    }
+  }
+}
+
+impl Default for EventScopeInterceptor {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl AFPluginInterceptor for EventScopeInterceptor {
+  fn intercept(&self, request: &AFPluginRequest) -> BoxFuture<'static, Result<(), DispatchError>> {
+    let result = self.check_request(request);
+    Box::pin(async move { result.map_err(|e| e.into()) })
+  }
+}
+
+/// The events that only read the database require `read:database`, others require
+/// `write:database`.
+///
+/// The scope functions list every event without a wildcard, so a new event fails to compile
+/// until its scope is chosen, instead of silently requiring the write scope.
+fn database_event_scope(event: &DatabaseEvent) -> PermissionScope {
+  match event {
+    DatabaseEvent::GetDatabase
+    | DatabaseEvent::GetDatabaseSetting
+    | DatabaseEvent::GetAllFilters
+    | DatabaseEvent::GetFilterPresets
+    | DatabaseEvent::GetFilteredRowCount
+    | DatabaseEvent::GetAutomations
+    | DatabaseEvent::GetAutomationActivities
+    | DatabaseEvent::GetAllSorts
+    | DatabaseEvent::GetFields
+    | DatabaseEvent::GetTypeOption
+    | DatabaseEvent::GetSelectOptionCellData
+    | DatabaseEvent::GetAvailableSelectOptions
+    | DatabaseEvent::GetSelectOptionUsage
+    | DatabaseEvent::ExportSelectOptions
+    | DatabaseEvent::GetRow
+    | DatabaseEvent::GetTrashedRows
+    | DatabaseEvent::GetRowDisplayValues
+    | DatabaseEvent::GetCell
+    | DatabaseEvent::GetCellByAddress
+    | DatabaseEvent::GetAdjacentCell
+    | DatabaseEvent::GetInvalidCells
+    | DatabaseEvent::GetRowDiagnostics
+    | DatabaseEvent::GetGroups
+    | DatabaseEvent::GetGroup
+    | DatabaseEvent::GetDatabases
+    | DatabaseEvent::GetLayoutSetting
+    | DatabaseEvent::GetAllCalendarEvents
+    | DatabaseEvent::GetCalendarEvent
+    | DatabaseEvent::GetViewDescription
+    | DatabaseEvent::GetSwimlanes
+    | DatabaseEvent::GetTextCell
+    | DatabaseEvent::GetRowBacklinks
+    | DatabaseEvent::GetRowShares
+    | DatabaseEvent::GetSharedRow
+    | DatabaseEvent::GetRelatedRows
+    | DatabaseEvent::SubscribeDatabaseChanges
+    | DatabaseEvent::UnsubscribeDatabaseChanges
+    | DatabaseEvent::GetRowIdCollisions
+    | DatabaseEvent::ResolveAttachment
+    | DatabaseEvent::ExportBoardLayout
+    | DatabaseEvent::GetChecklistReminders
+    | DatabaseEvent::GetGroupProgress
+    | DatabaseEvent::GetDatabaseSchema
+    | DatabaseEvent::GetTrashedFields
+    | DatabaseEvent::GetTimeTrackingSum
+    | DatabaseEvent::GetChecklistCellData
+    | DatabaseEvent::CompareDatabaseSchema
+    | DatabaseEvent::GetRelationDependents => PermissionScope::read(DATABASE_SCOPE_RESOURCE),
+    DatabaseEvent::UpdateDatabaseSetting
+    | DatabaseEvent::DeleteAllSorts
+    | DatabaseEvent::UpdateField
+    | DatabaseEvent::UpdateFieldTypeOption
+    | DatabaseEvent::DeleteField
+    | DatabaseEvent::UpdateFieldType
+    | DatabaseEvent::DuplicateField
+    | DatabaseEvent::MoveField
+    | DatabaseEvent::CreateTypeOption
+    | DatabaseEvent::CreateFields
+    | DatabaseEvent::CreateSelectOption
+    | DatabaseEvent::UpdateSelectOption
+    | DatabaseEvent::CleanupSelectOptions
+    | DatabaseEvent::ImportSelectOptions
+    | DatabaseEvent::UpdateSelectOptionColor
+    | DatabaseEvent::MoveSelectOption
+    | DatabaseEvent::MergeSelectOptions
+    | DatabaseEvent::CreateRow
+    | DatabaseEvent::DeleteRow
+    | DatabaseEvent::DuplicateRow
+    | DatabaseEvent::MoveRow
+    | DatabaseEvent::RestoreRow
+    | DatabaseEvent::UpdateCell
+    | DatabaseEvent::UpdateSelectOptionCell
+    | DatabaseEvent::RepairInvalidCells
+    | DatabaseEvent::UpdateDateCell
+    | DatabaseEvent::MoveGroup
+    | DatabaseEvent::MoveGroupRow
+    | DatabaseEvent::GroupByField
+    | DatabaseEvent::SetLayoutSetting
+    | DatabaseEvent::MoveCalendarEvent
+    | DatabaseEvent::ScheduleRow
+    | DatabaseEvent::UpdateFrozenColumnCount
+    | DatabaseEvent::UpdateViewDescription
+    | DatabaseEvent::SetSwimlaneField
+    | DatabaseEvent::ShareRow
+    | DatabaseEvent::RevokeRowShare
+    | DatabaseEvent::UpdateRelationCell
+    | DatabaseEvent::RepairRowIdCollisions
+    | DatabaseEvent::UpdatePersonCell
+    | DatabaseEvent::AttachFile
+    | DatabaseEvent::RemoveAttachment
+    | DatabaseEvent::SetChecklistItemDue
+    | DatabaseEvent::RestoreField
+    | DatabaseEvent::UpdateTimeTrackingCell
+    | DatabaseEvent::UpdateChecklistCell
+    | DatabaseEvent::MoveChecklistItem
+    | DatabaseEvent::UpdateLocationCell
+    | DatabaseEvent::RecomputeView
+    | DatabaseEvent::MergeDatabases
+    | DatabaseEvent::SaveFilterPreset
+    | DatabaseEvent::ApplyFilterPreset
+    | DatabaseEvent::DeleteFilterPreset
+    | DatabaseEvent::SaveAutomation
+    | DatabaseEvent::DeleteAutomation
+    | DatabaseEvent::InsertSessionFilter
+    | DatabaseEvent::SaveSessionFilter => PermissionScope::write(DATABASE_SCOPE_RESOURCE),
+  }
+}
+
+fn document_event_scope(event: &DocumentEvent) -> PermissionScope {
+  match event {
+    DocumentEvent::GetDocument
+    | DocumentEvent::ExportDocument
+    | DocumentEvent::PreviewGarbageCollection
+    | DocumentEvent::GetPrintPagination
+    | DocumentEvent::GetDocumentLayoutSummary => PermissionScope::read(DOCUMENT_SCOPE_RESOURCE),
+    DocumentEvent::ApplyEdit => PermissionScope::write(DOCUMENT_SCOPE_RESOURCE),
+  }
+}
+
+/// The events that manage the workspaces or the permissions of the views require
+/// `admin:workspace`.
+fn folder_event_scope(event: &FolderEvent) -> PermissionScope {
+  match event {
+    FolderEvent::CreateWorkspace
+    | FolderEvent::DeleteWorkspace
+    | FolderEvent::OpenWorkspace
+    | FolderEvent::UpdateWorkspaceSettings
+    | FolderEvent::UpdateViewPermission
+    | FolderEvent::GenerateSyntheticWorkspace
+    | FolderEvent::DeleteAllTrash => PermissionScope::admin(WORKSPACE_SCOPE_RESOURCE),
+    FolderEvent::ReadCurrentWorkspace
+    | FolderEvent::ReadWorkspaces
+    | FolderEvent::ReadWorkspaceApps
+    | FolderEvent::ReadWorkspaceSettings
+    | FolderEvent::ReadApp
+    | FolderEvent::ReadView
+    | FolderEvent::ReadChildViews
+    | FolderEvent::ResolveViewSlug
+    | FolderEvent::CopyLink
+    | FolderEvent::ReadViewState
+    | FolderEvent::ReadViewNameSetting
+    | FolderEvent::ReadDuplicateViewNames
+    | FolderEvent::ReadViewPermission
+    | FolderEvent::Search
+    | FolderEvent::QuickSwitch
+    | FolderEvent::ReadViewAliases
+    | FolderEvent::GetQuotaUsage
+    | FolderEvent::GetWorkspaceHealth
+    | FolderEvent::GetMemoryReport
+    | FolderEvent::ExportData
+    | FolderEvent::ReadTrash => PermissionScope::read(WORKSPACE_SCOPE_RESOURCE),
+    FolderEvent::CreateApp
+    | FolderEvent::DeleteApp
+    | FolderEvent::UpdateApp
+    | FolderEvent::CreateView
+    | FolderEvent::UpdateView
+    | FolderEvent::DeleteView
+    | FolderEvent::DuplicateView
+    | FolderEvent::CloseView
+    | FolderEvent::UpdateViewSlug
+    | FolderEvent::QuickCreateView
+    | FolderEvent::SetLatestView
+    | FolderEvent::UpdateViewState
+    | FolderEvent::MoveItem
+    | FolderEvent::ImportData
+    | FolderEvent::UpdateViewNameSetting
+    | FolderEvent::RenameDuplicateViews
+    | FolderEvent::RebuildSearchIndex
+    | FolderEvent::UpdateViewAliases
+    | FolderEvent::TrimCaches
+    | FolderEvent::PutbackTrash
+    | FolderEvent::DeleteTrash
+    | FolderEvent::RestoreAllTrash => PermissionScope::write(WORKSPACE_SCOPE_RESOURCE),
+  }
+}
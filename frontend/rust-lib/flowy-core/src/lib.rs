@@ -1,26 +1,45 @@
+pub mod access_token;
+mod archive;
+pub mod backup;
 mod deps_resolve;
+pub mod event_scope;
 pub mod module;
+pub mod readiness;
 use crate::deps_resolve::*;
-use flowy_client_ws::{listen_on_websocket, FlowyWebSocketConnect, NetworkType};
-use flowy_database::manager::DatabaseManager;
+use crate::event_scope::EventScopeInterceptor;
+use crate::readiness::{wait_until_initialized, ReadinessInterceptor, SubsystemInitializer};
+use flowy_client_ws::{listen_on_websocket, FlowyRawWebSocket, FlowyWebSocketConnect, NetworkType};
+use flowy_database::manager::{
+  DatabaseConfig, DatabaseManager, DatabaseQuotaChecker, DatabaseViewNames,
+};
+use flowy_database::services::cell::CellSizeLimits;
+use flowy_database::services::import::resume_database_imports;
+use flowy_database::services::setting::WeekSettings;
 use flowy_document::entities::DocumentVersionPB;
 use flowy_document::{DocumentConfig, DocumentManager};
 use flowy_error::FlowyResult;
-use flowy_folder::entities::{ViewDataFormatPB, ViewLayoutTypePB};
+use flowy_folder::entities::{
+  ViewDataFormatPB, ViewLayoutTypePB, WorkspaceQuota, WorkspaceSettingsPB,
+};
 use flowy_folder::{errors::FlowyError, manager::FolderManager};
+use flowy_net::entities::CoreSubsystemPB;
+use flowy_net::geocoding::GeocodingProvider;
 pub use flowy_net::get_client_server_configuration;
 use flowy_net::local_server::LocalServer;
+use flowy_net::readiness::CoreReadiness;
+use flowy_net::sync_statistics::{MeteredWebSocket, SyncStatistics};
 use flowy_net::ClientServerConfiguration;
 use flowy_task::{TaskDispatcher, TaskRunner};
 use flowy_user::event_map::UserStatusCallback;
-use flowy_user::services::{UserSession, UserSessionConfig};
+use flowy_user::services::{InboxService, MacroService, UserSession, UserSessionConfig};
 use lib_dispatch::prelude::*;
 use lib_dispatch::runtime::tokio_default_runtime;
+use lib_ws::WSController;
 
 use flowy_database::entities::LayoutTypePB;
 use lib_infra::future::{to_fut, Fut};
-use module::make_plugins;
 pub use module::*;
+use module::{make_plugins, make_view_permission_interceptor};
 use std::time::Duration;
 use std::{
   fmt,
@@ -33,6 +52,12 @@ use tokio::sync::{broadcast, RwLock};
 use user_model::UserProfile;
 
 static INIT_LOG: AtomicBool = AtomicBool::new(false);
+const DEVICE_ID_KEY: &str = "device_id";
+/// How often the sync statistics are sent to the frontend while they keep changing
+const SYNC_STATISTICS_NOTIFY_INTERVAL: Duration = Duration::from_secs(5);
+/// How often the rows and the fields that outlived the retention period of the trash are purged.
+/// The rows are also purged when the user signs in, and the fields when their databases open.
+const TRASH_PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
 
 /// This name will be used as to identify the current [AppFlowyCore] instance.
 /// Don't change this.
@@ -47,6 +72,18 @@ pub struct AppFlowyCoreConfig {
   log_filter: String,
   server_config: ClientServerConfiguration,
   pub document: DocumentConfig,
+  pub database: DatabaseConfig,
+  /// The local quota of the workspace, it's overridden by the quota from the cloud service.
+  pub quota: WorkspaceQuota,
+  /// The number of the latest dispatched events that will be kept for debugging. Zero means
+  /// the event audit log is disabled.
+  event_audit_capacity: usize,
+  /// Records all the dispatched events and their responses, it's used by the integration tests
+  /// to replay a session.
+  event_recorder: Option<EventRecorder>,
+  /// Resolves the addresses typed into the location cells. No address leaves the device
+  /// unless a provider is configured.
+  geocoding_provider: Option<Arc<dyn GeocodingProvider>>,
 }
 
 impl fmt::Debug for AppFlowyCoreConfig {
@@ -55,6 +92,11 @@ impl fmt::Debug for AppFlowyCoreConfig {
       .field("storage_path", &self.storage_path)
       .field("server-config", &self.server_config)
       .field("document-config", &self.document)
+      .field("database-config", &self.database)
+      .field("quota", &self.quota)
+      .field("event-audit-capacity", &self.event_audit_capacity)
+      .field("event-recorder", &self.event_recorder.is_some())
+      .field("geocoding-provider", &self.geocoding_provider.is_some())
       .finish()
   }
 }
@@ -67,14 +109,73 @@ impl AppFlowyCoreConfig {
       log_filter: create_log_filter("info".to_owned(), vec![]),
       server_config,
       document: DocumentConfig::default(),
+      database: DatabaseConfig::default(),
+      quota: WorkspaceQuota::default(),
+      event_audit_capacity: 0,
+      event_recorder: None,
+      geocoding_provider: None,
     }
   }
 
+  /// Keeps the last `capacity` dispatched events in memory, they can be dumped by
+  /// the `DumpEventAuditLog` event.
+  pub fn with_event_audit_log(mut self, capacity: usize) -> Self {
+    self.event_audit_capacity = capacity;
+    self
+  }
+
+  /// Records the dispatched events into the `recorder`, they can be replayed against another
+  /// [AppFlowyCore] by the `EventReplayer`.
+  pub fn with_event_recorder(mut self, recorder: EventRecorder) -> Self {
+    self.event_recorder = Some(recorder);
+    self
+  }
+
+  /// Sets the provider used to geocode the addresses of the location cells, for example
+  /// the `NominatimGeocodingProvider`.
+  pub fn with_geocoding_provider(mut self, provider: Arc<dyn GeocodingProvider>) -> Self {
+    self.geocoding_provider = Some(provider);
+    self
+  }
+
   pub fn with_document_version(mut self, version: DocumentVersionPB) -> Self {
     self.document.version = version;
     self
   }
 
+  /// Sets how long the data of the document is kept after its view was permanently deleted.
+  pub fn with_document_gc_retention(mut self, retention: Duration) -> Self {
+    self.document.gc_retention = retention;
+    self
+  }
+
+  /// Overrides the default size limits of the text, url and checklist cells.
+  pub fn with_cell_size_limits(mut self, cell_size_limits: CellSizeLimits) -> Self {
+    self.database.cell_size_limits = cell_size_limits;
+    self
+  }
+
+  /// Sets how long the deleted rows are kept in the trash of the database.
+  pub fn with_row_trash_retention(mut self, retention: Duration) -> Self {
+    self.database.row_trash_retention = retention;
+    self
+  }
+
+  /// Sets how long the deleted fields and their cells are kept in the trash of the database.
+  pub fn with_field_trash_retention(mut self, retention: Duration) -> Self {
+    self.database.field_trash_retention = retention;
+    self
+  }
+
+  /// Limits the attachment size, the number of rows of each database and the storage size of
+  /// the workspace. The limits are enforced unless the cloud service provides its own quota.
+  pub fn with_workspace_quota(mut self, quota: WorkspaceQuota) -> Self {
+    self.database.max_rows_per_database = quota.max_rows_per_database;
+    self.database.max_attachment_size = quota.max_attachment_size;
+    self.quota = quota;
+    self
+  }
+
   pub fn log_filter(mut self, level: &str, with_crates: Vec<String>) -> Self {
     self.log_filter = create_log_filter(level.to_owned(), with_crates);
     self
@@ -124,10 +225,13 @@ pub struct AppFlowyCore {
   pub document_manager: Arc<DocumentManager>,
   pub folder_manager: Arc<FolderManager>,
   pub database_manager: Arc<DatabaseManager>,
+  pub file_storage: Arc<FileStorageService>,
   pub event_dispatcher: Arc<AFPluginDispatcher>,
   pub ws_conn: Arc<FlowyWebSocketConnect>,
   pub local_server: Option<Arc<LocalServer>>,
   pub task_dispatcher: Arc<RwLock<TaskDispatcher>>,
+  pub readiness: CoreReadiness,
+  subsystem_initializer: Arc<dyn SubsystemInitializer>,
 }
 
 impl AppFlowyCore {
@@ -137,16 +241,22 @@ impl AppFlowyCore {
 
     init_log(&config);
     init_kv(&config.storage_path);
+    init_device_id();
     tracing::debug!("🔥 {:?}", config);
     let runtime = tokio_default_runtime().unwrap();
     let task_scheduler = TaskDispatcher::new(Duration::from_secs(2));
     let task_dispatcher = Arc::new(RwLock::new(task_scheduler));
     runtime.spawn(TaskRunner::run(task_dispatcher.clone()));
 
-    let (local_server, ws_conn) = mk_local_server(&config.server_config);
-    let (user_session, document_manager, folder_manager, local_server, database_manager) = runtime
+    let sync_statistics = SyncStatistics::new();
+    let readiness = CoreReadiness::new();
+    let (local_server, ws_conn) = mk_local_server(&config.server_config, &sync_statistics);
+    // Only the managers are built here. Building them doesn't touch the storage of the user,
+    // which is opened when the user session gets initialized in the background.
+    let (user_session, document_manager, folder_manager, database_manager, file_storage) = runtime
       .block_on(async {
         let user_session = mk_user_session(&config, &local_server, &config.server_config);
+        let file_storage = FileStorageDepsResolver::resolve(user_session.clone());
         let document_manager = DocumentDepsResolver::resolve(
           local_server.clone(),
           ws_conn.clone(),
@@ -159,6 +269,10 @@ impl AppFlowyCore {
           ws_conn.clone(),
           user_session.clone(),
           task_dispatcher.clone(),
+          file_storage.clone(),
+          document_manager.clone(),
+          config.geocoding_provider.clone(),
+          &config.database,
         )
         .await;
 
@@ -169,47 +283,86 @@ impl AppFlowyCore {
           &ws_conn,
           &document_manager,
           &database_manager,
+          task_dispatcher.clone(),
+          config.quota.clone(),
         )
         .await;
-
-        if let Some(local_server) = local_server.as_ref() {
-          local_server.run();
-        }
-        ws_conn.init().await;
+        database_manager.set_view_names(Arc::new(DatabaseViewNamesImpl(folder_manager.clone())));
         (
           user_session,
           document_manager,
           folder_manager,
-          local_server,
           database_manager,
+          file_storage,
         )
       });
 
-    let user_status_listener = UserStatusListener {
+    let user_status_listener = Arc::new(UserStatusListener {
       document_manager: document_manager.clone(),
       folder_manager: folder_manager.clone(),
       database_manager: database_manager.clone(),
       ws_conn: ws_conn.clone(),
+      readiness: readiness.clone(),
       config: config.clone(),
-    };
+      signed_in_user: Default::default(),
+    });
     let user_status_callback = UserStatusCallbackImpl {
-      listener: Arc::new(user_status_listener),
+      listener: user_status_listener,
     };
-    let cloned_user_session = user_session.clone();
-    runtime.block_on(async move {
-      cloned_user_session.clone().init(user_status_callback).await;
-    });
-
-    let event_dispatcher = Arc::new(AFPluginDispatcher::construct(runtime, || {
+    let subsystem_initializer: Arc<dyn SubsystemInitializer> =
+      Arc::new(user_status_callback.clone());
+    runtime.spawn(bootstrap(
+      user_session.clone(),
+      user_status_callback,
+      local_server.clone(),
+      ws_conn.clone(),
+      readiness.clone(),
+    ));
+
+    let event_audit_log = EventAuditLog::new(config.event_audit_capacity);
+    let macro_service = Arc::new(MacroService::new(user_session.clone()));
+    let inbox_service = Arc::new(InboxService::new(user_session.clone()));
+    flowy_notification::set_notification_filter(inbox_service.notification_filter());
+    let event_scope = Arc::new(EventScopeInterceptor::new());
+    let mut event_dispatcher = AFPluginDispatcher::construct(runtime, || {
       make_plugins(
         &ws_conn,
         &folder_manager,
         &database_manager,
         &user_session,
+        &macro_service,
+        &inbox_service,
         &document_manager,
+        &event_audit_log,
+        &sync_statistics,
+        &readiness,
       )
-    }));
-    _start_listening(&event_dispatcher, &ws_conn, &folder_manager);
+    })
+    .interceptors(vec![
+      // The scoped requests are rejected before any other interceptor sees them.
+      event_scope.clone(),
+      // The interceptors below read the managers, so they run after the managers are ready.
+      Arc::new(ReadinessInterceptor::new(
+        readiness.clone(),
+        subsystem_initializer.clone(),
+      )),
+      Arc::new(make_view_permission_interceptor(&folder_manager)),
+      // Only the events that passed the interceptors above and succeeded are recorded.
+      Arc::new(macro_service.recorder(move |event| event_scope.is_mutating_event(event))),
+    ])
+    .audit_log(event_audit_log.clone());
+    if let Some(recorder) = config.event_recorder.clone() {
+      event_dispatcher = event_dispatcher.recorder(recorder);
+    }
+    let event_dispatcher = Arc::new(event_dispatcher);
+    macro_service.set_dispatcher(&event_dispatcher);
+    _start_listening(
+      &event_dispatcher,
+      &ws_conn,
+      &folder_manager,
+      &database_manager,
+      &sync_statistics,
+    );
 
     Self {
       config,
@@ -217,26 +370,85 @@ impl AppFlowyCore {
       document_manager,
       folder_manager,
       database_manager,
+      file_storage,
       event_dispatcher,
       ws_conn,
       local_server,
       task_dispatcher,
+      readiness,
+      subsystem_initializer,
     }
   }
 
   pub fn dispatcher(&self) -> Arc<AFPluginDispatcher> {
     self.event_dispatcher.clone()
   }
+
+  /// The entry point of the requests that are authenticated with a personal access token, see
+  /// [access_token::issue_access_token]. The request can only dispatch the events that are
+  /// granted by the scopes of the token, it's rejected if the token is unknown or revoked.
+  pub async fn dispatch_with_token(
+    &self,
+    token: &str,
+    request: AFPluginRequest,
+  ) -> AFPluginEventResponse {
+    match access_token::access_token_scopes(token) {
+      None => DispatchError::from(
+        FlowyError::unauthorized().context("The access token is unknown or revoked"),
+      )
+      .into(),
+      Some(scopes) => {
+        AFPluginDispatcher::async_send(self.dispatcher(), request.scopes(scopes)).await
+      },
+    }
+  }
+
+  /// Waits until the subsystem finished its initialization, the manager of the subsystem is
+  /// initialized if it wasn't yet. The events wait for their subsystems by themselves, it's only
+  /// needed before calling the managers directly.
+  pub async fn wait_until_ready(&self, subsystem: CoreSubsystemPB) {
+    wait_until_initialized(&self.readiness, &self.subsystem_initializer, subsystem).await
+  }
+}
+
+/// Initializes the core in the background, in stages:
+/// 1. The user session is loaded, the user events are served from then on.
+/// 2. The web socket connects in the background, see [UserStatusListener::connect_ws].
+/// 3. The managers are initialized lazily by the first event of their subsystem, see
+/// [UserStatusListener::initialize_subsystem].
+/// 4. The background jobs of the signed in user start, see
+/// [UserStatusListener::start_background_jobs].
+///
+/// The user is marked as ready at the end anyway, so its events don't wait forever when no user
+/// is signed in.
+async fn bootstrap(
+  user_session: Arc<UserSession>,
+  user_status_callback: UserStatusCallbackImpl,
+  local_server: Option<Arc<LocalServer>>,
+  ws_conn: Arc<FlowyWebSocketConnect>,
+  readiness: CoreReadiness,
+) {
+  if let Some(local_server) = local_server.as_ref() {
+    local_server.run();
+  }
+  ws_conn.init().await;
+  user_session.init(user_status_callback).await;
+  readiness.mark_ready(CoreSubsystemPB::User);
 }
 
 fn _start_listening(
   event_dispatcher: &AFPluginDispatcher,
   ws_conn: &Arc<FlowyWebSocketConnect>,
   folder_manager: &Arc<FolderManager>,
+  database_manager: &Arc<DatabaseManager>,
+  sync_statistics: &SyncStatistics,
 ) {
   let subscribe_network_type = ws_conn.subscribe_network_ty();
+  let sync_statistics = sync_statistics.clone();
+  let subscribe_workspace_settings = folder_manager.subscribe_workspace_settings();
+  let database_manager = database_manager.clone();
   let folder_manager = folder_manager.clone();
-  let cloned_folder_manager = folder_manager;
+  let cloned_folder_manager = folder_manager.clone();
   let ws_conn = ws_conn.clone();
 
   event_dispatcher.spawn(async move {
@@ -246,19 +458,42 @@ fn _start_listening(
   event_dispatcher.spawn(async move {
     _listen_network_status(subscribe_network_type, cloned_folder_manager).await;
   });
+
+  let cloned_database_manager = database_manager.clone();
+  event_dispatcher.spawn(async move {
+    _listen_workspace_settings(
+      subscribe_workspace_settings,
+      folder_manager,
+      cloned_database_manager,
+    )
+    .await;
+  });
+
+  event_dispatcher.spawn(async move {
+    _purge_trash(database_manager).await;
+  });
+
+  event_dispatcher.spawn(async move {
+    _notify_sync_statistics(sync_statistics).await;
+  });
 }
 
+/// The messages of the web socket are counted by the [SyncStatistics] in both cases.
 fn mk_local_server(
   server_config: &ClientServerConfiguration,
+  sync_statistics: &SyncStatistics,
 ) -> (Option<Arc<LocalServer>>, Arc<FlowyWebSocketConnect>) {
   let ws_addr = server_config.ws_addr();
   if cfg!(feature = "http_sync") {
-    let ws_conn = Arc::new(FlowyWebSocketConnect::new(ws_addr));
+    let ws: Arc<dyn FlowyRawWebSocket> = Arc::new(Arc::new(WSController::new()));
+    let metered_ws = Arc::new(MeteredWebSocket::new(ws, sync_statistics.clone()));
+    let ws_conn = Arc::new(FlowyWebSocketConnect::from_local(ws_addr, metered_ws));
     (None, ws_conn)
   } else {
     let context = flowy_net::local_server::build_server(server_config);
     let local_ws = Arc::new(context.local_ws);
-    let ws_conn = Arc::new(FlowyWebSocketConnect::from_local(ws_addr, local_ws));
+    let metered_ws = Arc::new(MeteredWebSocket::new(local_ws, sync_statistics.clone()));
+    let ws_conn = Arc::new(FlowyWebSocketConnect::from_local(ws_addr, metered_ws));
     (Some(Arc::new(context.local_server)), ws_conn)
   }
 }
@@ -272,6 +507,58 @@ async fn _listen_network_status(
   }
 }
 
+/// Applies the week settings of the current workspace whenever the settings of a workspace are
+/// updated or another workspace is opened. The settings are re-read instead of taking the
+/// received ones, because they may belong to a workspace that isn't the current one.
+async fn _listen_workspace_settings(
+  mut subscribe: broadcast::Receiver<WorkspaceSettingsPB>,
+  folder_manager: Arc<FolderManager>,
+  database_manager: Arc<DatabaseManager>,
+) {
+  loop {
+    match subscribe.recv().await {
+      Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => {
+        match folder_manager.get_workspace_settings().await {
+          Ok(settings) => {
+            database_manager
+              .set_week_settings(WeekSettings::new(
+                settings.first_day_of_week,
+                settings.working_days,
+              ))
+              .await
+          },
+          Err(err) => tracing::error!("Read the workspace settings failed: {:?}", err),
+        }
+      },
+      Err(broadcast::error::RecvError::Closed) => break,
+    }
+  }
+}
+
+async fn _notify_sync_statistics(sync_statistics: SyncStatistics) {
+  let mut interval = tokio::time::interval(SYNC_STATISTICS_NOTIFY_INTERVAL);
+  loop {
+    interval.tick().await;
+    sync_statistics.notify_if_changed();
+  }
+}
+
+async fn _purge_trash(database_manager: Arc<DatabaseManager>) {
+  let mut interval = tokio::time::interval(TRASH_PURGE_INTERVAL);
+  // The first tick completes immediately, the trash is purged by the background jobs of the
+  // signed in user, see [UserStatusListener::start_background_jobs]
+  interval.tick().await;
+  loop {
+    interval.tick().await;
+    if let Err(err) = database_manager.purge_row_trash().await {
+      tracing::error!("Purge the trashed rows failed: {:?}", err);
+    }
+    if let Err(err) = database_manager.purge_field_trash().await {
+      tracing::error!("Purge the trashed fields failed: {:?}", err);
+    }
+  }
+}
+
 fn init_kv(root: &str) {
   match flowy_sqlite::kv::KV::init(root) {
     Ok(_) => {},
@@ -279,6 +566,20 @@ fn init_kv(root: &str) {
   }
 }
 
+/// The device id is generated once and kept in the kv store. It's mixed into the ids of the
+/// rows and the views, so the ids created offline on different devices are unlikely to collide.
+fn init_device_id() {
+  let device_id = match flowy_sqlite::kv::KV::get_str(DEVICE_ID_KEY) {
+    Some(device_id) => device_id,
+    None => {
+      let device_id = lib_infra::id_gen::gen_id();
+      flowy_sqlite::kv::KV::set_str(DEVICE_ID_KEY, device_id.clone());
+      device_id
+    },
+  };
+  lib_infra::id_gen::set_device_id(&device_id);
+}
+
 fn init_log(config: &AppFlowyCoreConfig) {
   if !INIT_LOG.load(Ordering::SeqCst) {
     INIT_LOG.store(true, Ordering::SeqCst);
@@ -299,19 +600,93 @@ fn mk_user_session(
   Arc::new(UserSession::new(user_config, cloud_service))
 }
 
+/// The managers of the subsystems that are initialized for the signed in user
+const MANAGER_SUBSYSTEMS: [CoreSubsystemPB; 3] = [
+  CoreSubsystemPB::Folder,
+  CoreSubsystemPB::Document,
+  CoreSubsystemPB::Database,
+];
+
+/// The token and the id of the signed in user
+type SignedInUser = (String, String);
+
 struct UserStatusListener {
   document_manager: Arc<DocumentManager>,
   folder_manager: Arc<FolderManager>,
   database_manager: Arc<DatabaseManager>,
   ws_conn: Arc<FlowyWebSocketConnect>,
+  readiness: CoreReadiness,
   config: AppFlowyCoreConfig,
+  /// None if no user is signed in. The lock is held while the managers are initialized, so each
+  /// of them is initialized once.
+  signed_in_user: tokio::sync::Mutex<Option<SignedInUser>>,
 }
 
 impl UserStatusListener {
+  /// The managers are initialized by the first event of their subsystem, see
+  /// [UserStatusListener::initialize_subsystem], or by the background jobs that start after the
+  /// sign in.
   async fn did_sign_in(&self, token: &str, user_id: &str) -> FlowyResult<()> {
+    let mut signed_in_user = self.signed_in_user.lock().await;
+    let new_user = (token.to_owned(), user_id.to_owned());
+    if signed_in_user.as_ref() != Some(&new_user) {
+      // The subsystems may be marked as ready while no user was signed in
+      for subsystem in MANAGER_SUBSYSTEMS {
+        self.readiness.reset(subsystem);
+      }
+      *signed_in_user = Some(new_user);
+    }
+    self.readiness.mark_ready(CoreSubsystemPB::User);
+    self.connect_ws(token, user_id);
+    Ok(())
+  }
+
+  /// Initializes the manager of the subsystem for the signed in user and marks the subsystem as
+  /// ready. It does nothing if the subsystem is ready already.
+  async fn initialize_subsystem(&self, subsystem: CoreSubsystemPB) {
+    let signed_in_user = self.signed_in_user.lock().await;
+    if self.readiness.is_ready(subsystem) {
+      return;
+    }
+    if let Some((token, user_id)) = signed_in_user.as_ref() {
+      if let Err(err) = self.initialize_manager(subsystem, token, user_id).await {
+        tracing::error!("Initialize {:?} failed: {:?}", subsystem, err);
+      }
+    }
+    // The events of the subsystem fail by themselves if no user is signed in or the
+    // initialization failed
+    self.readiness.mark_ready(subsystem);
+  }
+
+  async fn initialize_manager(
+    &self,
+    subsystem: CoreSubsystemPB,
+    token: &str,
+    user_id: &str,
+  ) -> FlowyResult<()> {
+    match subsystem {
+      CoreSubsystemPB::Folder => self.initialize_folder(token, user_id).await,
+      CoreSubsystemPB::Document => self.document_manager.initialize(user_id).await,
+      CoreSubsystemPB::Database => {
+        // The databases are listed from the current workspace
+        if !self.readiness.is_ready(CoreSubsystemPB::Folder) {
+          self.initialize_folder(token, user_id).await?;
+          self.readiness.mark_ready(CoreSubsystemPB::Folder);
+        }
+        self.initialize_database(token, user_id).await
+      },
+      CoreSubsystemPB::User | CoreSubsystemPB::WebSocket => Ok(()),
+    }
+  }
+
+  async fn initialize_folder(&self, token: &str, user_id: &str) -> FlowyResult<()> {
     self.folder_manager.initialize(user_id, token).await?;
-    self.document_manager.initialize(user_id).await?;
+    self.apply_workspace_quota();
+    self.apply_workspace_week_settings().await;
+    Ok(())
+  }
 
+  async fn initialize_database(&self, token: &str, user_id: &str) -> FlowyResult<()> {
     let cloned_folder_manager = self.folder_manager.clone();
     let get_views_fn = to_fut(async move {
       cloned_folder_manager
@@ -341,14 +716,61 @@ impl UserStatusListener {
       .database_manager
       .initialize(user_id, token, get_views_fn)
       .await?;
-    self
-      .ws_conn
-      .start(token.to_owned(), user_id.to_owned())
-      .await?;
+    let database_manager = self.database_manager.clone();
+    tokio::spawn(async move {
+      if let Err(err) = resume_database_imports(database_manager).await {
+        tracing::error!("Resume the imports failed: {:?}", err);
+      }
+    });
     Ok(())
   }
 
+  /// Starts the jobs that don't wait for the events of their subsystems: the search index is
+  /// rebuilt if it's empty, the deleted documents are collected, the trashed rows are purged and
+  /// the automations start firing. The managers of the subsystems are initialized first if no
+  /// event did it yet.
+  async fn start_background_jobs(&self) {
+    if self.signed_in_user.lock().await.is_none() {
+      return;
+    }
+    for subsystem in MANAGER_SUBSYSTEMS {
+      self.initialize_subsystem(subsystem).await;
+    }
+
+    self.folder_manager.start_search_index().await;
+    if let Err(err) = self.document_manager.collect_garbage().await {
+      tracing::error!("Collect the deleted documents failed: {:?}", err);
+    }
+    if let Err(err) = self.database_manager.purge_row_trash().await {
+      tracing::error!("Purge the trashed rows failed: {:?}", err);
+    }
+    self.database_manager.start_automation_scheduler().await;
+  }
+
+  /// Connects the web socket in the background, the local data can be used without waiting
+  /// for the connection.
+  fn connect_ws(&self, token: &str, user_id: &str) {
+    let ws_conn = self.ws_conn.clone();
+    let readiness = self.readiness.clone();
+    let token = token.to_owned();
+    let user_id = user_id.to_owned();
+    tokio::spawn(async move {
+      if let Err(err) = ws_conn.start(token, user_id).await {
+        tracing::error!("Connect the web socket failed: {:?}", err);
+      }
+      readiness.mark_ready(CoreSubsystemPB::WebSocket);
+    });
+  }
+
+  /// The managers of a new user are initialized right away, the default workspace is created
+  /// for the user.
   async fn did_sign_up(&self, user_profile: &UserProfile) -> FlowyResult<()> {
+    let mut signed_in_user = self.signed_in_user.lock().await;
+    for subsystem in MANAGER_SUBSYSTEMS {
+      self.readiness.reset(subsystem);
+    }
+    *signed_in_user = Some((user_profile.token.clone(), user_profile.id.clone()));
+
     let view_data_type = match self.config.document.version {
       DocumentVersionPB::V0 => ViewDataFormatPB::DeltaFormat,
       DocumentVersionPB::V1 => ViewDataFormatPB::NodeFormat,
@@ -357,46 +779,129 @@ impl UserStatusListener {
       .folder_manager
       .initialize_with_new_user(&user_profile.id, &user_profile.token, view_data_type)
       .await?;
+    self.apply_workspace_quota();
+    self.apply_workspace_week_settings().await;
+    self.readiness.mark_ready(CoreSubsystemPB::Folder);
     self
       .document_manager
       .initialize_with_new_user(&user_profile.id, &user_profile.token)
       .await?;
+    self.readiness.mark_ready(CoreSubsystemPB::Document);
 
     self
       .database_manager
       .initialize_with_new_user(&user_profile.id, &user_profile.token)
       .await?;
+    self.readiness.mark_ready(CoreSubsystemPB::Database);
 
-    self
-      .ws_conn
-      .start(user_profile.token.clone(), user_profile.id.clone())
-      .await?;
+    self.readiness.mark_ready(CoreSubsystemPB::User);
+    self.connect_ws(&user_profile.token, &user_profile.id);
     Ok(())
   }
 
+  /// The managers are initialized again for the next user that signs in.
   async fn did_expired(&self, _token: &str, user_id: &str) -> FlowyResult<()> {
+    let mut signed_in_user = self.signed_in_user.lock().await;
+    *signed_in_user = None;
+    for subsystem in MANAGER_SUBSYSTEMS {
+      self.readiness.reset(subsystem);
+    }
+    self.readiness.reset(CoreSubsystemPB::WebSocket);
     self.folder_manager.clear(user_id).await;
+    self.database_manager.clear(user_id).await;
     self.ws_conn.stop().await;
     Ok(())
   }
+
+  /// The quota of the workspace is read by the folder when it gets initialized, the database
+  /// enforces its row limit and checks the files that get attached to the cells.
+  fn apply_workspace_quota(&self) {
+    let quota = self.folder_manager.get_workspace_quota();
+    self
+      .database_manager
+      .set_max_rows_per_database(quota.max_rows_per_database);
+    self
+      .database_manager
+      .set_max_attachment_size(quota.max_attachment_size);
+    self
+      .database_manager
+      .set_quota_checker(Arc::new(DatabaseQuotaCheckerImpl(
+        self.folder_manager.clone(),
+      )));
+  }
+
+  /// The calendars and the date filters of the databases start the weeks on the first day of
+  /// the week of the workspace.
+  async fn apply_workspace_week_settings(&self) {
+    match self.folder_manager.get_workspace_settings().await {
+      Ok(settings) => {
+        self
+          .database_manager
+          .set_week_settings(WeekSettings::new(
+            settings.first_day_of_week,
+            settings.working_days,
+          ))
+          .await
+      },
+      Err(err) => tracing::error!("Read the workspace settings failed: {:?}", err),
+    }
+  }
+}
+
+struct DatabaseQuotaCheckerImpl(Arc<FolderManager>);
+impl DatabaseQuotaChecker for DatabaseQuotaCheckerImpl {
+  fn check_workspace_size(&self) -> Result<(), FlowyError> {
+    self.0.check_workspace_size()
+  }
+
+  fn did_store_file(&self, file_size: u64) {
+    self.0.did_store_file(file_size)
+  }
 }
 
+struct DatabaseViewNamesImpl(Arc<FolderManager>);
+impl DatabaseViewNames for DatabaseViewNamesImpl {
+  fn get_view_name(&self, view_id: &str) -> Fut<Option<String>> {
+    let folder_manager = self.0.clone();
+    let view_id = view_id.to_owned();
+    to_fut(async move { folder_manager.get_view_name(&view_id).await })
+  }
+}
+
+#[derive(Clone)]
 struct UserStatusCallbackImpl {
   listener: Arc<UserStatusListener>,
 }
 
+impl SubsystemInitializer for UserStatusCallbackImpl {
+  fn initialize(&self, subsystem: CoreSubsystemPB) -> Fut<()> {
+    let listener = self.listener.clone();
+    to_fut(async move { listener.initialize_subsystem(subsystem).await })
+  }
+}
+
 impl UserStatusCallback for UserStatusCallbackImpl {
   fn did_sign_in(&self, token: &str, user_id: &str) -> Fut<FlowyResult<()>> {
     let listener = self.listener.clone();
     let token = token.to_owned();
     let user_id = user_id.to_owned();
-    to_fut(async move { listener.did_sign_in(&token, &user_id).await })
+    to_fut(async move {
+      listener.did_sign_in(&token, &user_id).await?;
+      // The sign in doesn't wait for the managers that the jobs initialize
+      tokio::spawn(async move { listener.start_background_jobs().await });
+      Ok(())
+    })
   }
 
   fn did_sign_up(&self, user_profile: &UserProfile) -> Fut<FlowyResult<()>> {
     let listener = self.listener.clone();
     let user_profile = user_profile.clone();
-    to_fut(async move { listener.did_sign_up(&user_profile).await })
+    to_fut(async move {
+      listener.did_sign_up(&user_profile).await?;
+      // The managers of the new user are initialized already
+      listener.start_background_jobs().await;
+      Ok(())
+    })
   }
 
   fn did_expired(&self, token: &str, user_id: &str) -> Fut<FlowyResult<()>> {
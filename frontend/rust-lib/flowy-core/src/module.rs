@@ -1,9 +1,22 @@
 use flowy_client_ws::FlowyWebSocketConnect;
+use flowy_database::entities::{
+  CellChangesetPB, CreateFieldPayloadPB, CreateFieldsPayloadPB, CreateRowPayloadPB,
+  DeleteFieldPayloadPB, FieldChangesetPB, MoveGroupRowPayloadPB, MoveRowPayloadPB, RowIdPB,
+};
+use flowy_database::event_map::DatabaseEvent;
 use flowy_database::manager::DatabaseManager;
+use flowy_database::services::field::{
+  ChecklistCellChangesetPB, DateChangesetPB, SelectOptionCellChangesetPB,
+};
+use flowy_document::entities::EditPayloadPB;
+use flowy_document::event_map::DocumentEvent;
 use flowy_document::DocumentManager;
 use flowy_folder::manager::FolderManager;
-use flowy_user::services::UserSession;
-use lib_dispatch::prelude::AFPlugin;
+use flowy_folder::services::permission::interceptor::{parse_payload, ViewPermissionInterceptor};
+use flowy_net::readiness::CoreReadiness;
+use flowy_net::sync_statistics::SyncStatistics;
+use flowy_user::services::{InboxService, MacroService, UserSession};
+use lib_dispatch::prelude::{AFPlugin, AFPluginFromBytes, EventAuditLog, Payload};
 use std::sync::Arc;
 
 pub fn make_plugins(
@@ -11,11 +24,25 @@ pub fn make_plugins(
   folder_manager: &Arc<FolderManager>,
   grid_manager: &Arc<DatabaseManager>,
   user_session: &Arc<UserSession>,
+  macro_service: &Arc<MacroService>,
+  inbox_service: &Arc<InboxService>,
   document_manager: &Arc<DocumentManager>,
+  event_audit_log: &EventAuditLog,
+  sync_statistics: &SyncStatistics,
+  readiness: &CoreReadiness,
 ) -> Vec<AFPlugin> {
-  let user_plugin = flowy_user::event_map::init(user_session.clone());
+  let user_plugin = flowy_user::event_map::init(
+    user_session.clone(),
+    macro_service.clone(),
+    inbox_service.clone(),
+  );
   let folder_plugin = flowy_folder::event_map::init(folder_manager.clone());
-  let network_plugin = flowy_net::event_map::init(ws_conn.clone());
+  let network_plugin = flowy_net::event_map::init(
+    ws_conn.clone(),
+    event_audit_log.clone(),
+    sync_statistics.clone(),
+    readiness.clone(),
+  );
   let grid_plugin = flowy_database::event_map::init(grid_manager.clone());
   let document_plugin = flowy_document::event_map::init(document_manager.clone());
   vec![
@@ -26,3 +53,62 @@ pub fn make_plugins(
     document_plugin,
   ]
 }
+
+/// The mutating events of the database and the document are guarded by the permission of the
+/// view that they belong to.
+pub fn make_view_permission_interceptor(
+  folder_manager: &Arc<FolderManager>,
+) -> ViewPermissionInterceptor {
+  ViewPermissionInterceptor::new(folder_manager)
+    .guard(DatabaseEvent::UpdateCell, |payload| {
+      view_id_from::<CellChangesetPB>(payload, |payload| payload.view_id)
+    })
+    .guard(DatabaseEvent::UpdateSelectOptionCell, |payload| {
+      view_id_from::<SelectOptionCellChangesetPB>(payload, |payload| {
+        payload.cell_identifier.view_id
+      })
+    })
+    .guard(DatabaseEvent::UpdateDateCell, |payload| {
+      view_id_from::<DateChangesetPB>(payload, |payload| payload.cell_path.view_id)
+    })
+    .guard(DatabaseEvent::UpdateChecklistCell, |payload| {
+      view_id_from::<ChecklistCellChangesetPB>(payload, |payload| payload.cell_id.view_id)
+    })
+    .guard(DatabaseEvent::CreateRow, |payload| {
+      view_id_from::<CreateRowPayloadPB>(payload, |payload| payload.view_id)
+    })
+    .guard(DatabaseEvent::DeleteRow, |payload| {
+      view_id_from::<RowIdPB>(payload, |payload| payload.view_id)
+    })
+    .guard(DatabaseEvent::DuplicateRow, |payload| {
+      view_id_from::<RowIdPB>(payload, |payload| payload.view_id)
+    })
+    .guard(DatabaseEvent::MoveRow, |payload| {
+      view_id_from::<MoveRowPayloadPB>(payload, |payload| payload.view_id)
+    })
+    .guard(DatabaseEvent::MoveGroupRow, |payload| {
+      view_id_from::<MoveGroupRowPayloadPB>(payload, |payload| payload.view_id)
+    })
+    .guard(DatabaseEvent::CreateField, |payload| {
+      view_id_from::<CreateFieldPayloadPB>(payload, |payload| payload.view_id)
+    })
+    .guard(DatabaseEvent::CreateFields, |payload| {
+      view_id_from::<CreateFieldsPayloadPB>(payload, |payload| payload.view_id)
+    })
+    .guard(DatabaseEvent::UpdateField, |payload| {
+      view_id_from::<FieldChangesetPB>(payload, |payload| payload.view_id)
+    })
+    .guard(DatabaseEvent::DeleteField, |payload| {
+      view_id_from::<DeleteFieldPayloadPB>(payload, |payload| payload.view_id)
+    })
+    .guard(DocumentEvent::ApplyEdit, |payload| {
+      view_id_from::<EditPayloadPB>(payload, |payload| payload.doc_id)
+    })
+}
+
+fn view_id_from<T>(payload: &Payload, f: impl FnOnce(T) -> String) -> Vec<String>
+where
+  T: AFPluginFromBytes,
+{
+  parse_payload::<T>(payload).map(f).into_iter().collect()
+}
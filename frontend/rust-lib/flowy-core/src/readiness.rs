@@ -0,0 +1,93 @@
+use flowy_database::event_map::DatabaseEvent;
+use flowy_document::event_map::DocumentEvent;
+use flowy_folder::event_map::FolderEvent;
+use flowy_net::entities::CoreSubsystemPB;
+use flowy_net::readiness::CoreReadiness;
+use flowy_user::event_map::UserEvent;
+use futures_core::future::BoxFuture;
+use lib_dispatch::prelude::{AFPluginEvent, AFPluginInterceptor, AFPluginRequest, DispatchError};
+use lib_infra::future::Fut;
+use std::collections::HashMap;
+use std::sync::Arc;
+use strum::IntoEnumIterator;
+
+/// Initializes the manager of a subsystem for the signed in user. The subsystem must be marked
+/// as ready afterwards, even if the initialization failed or no user is signed in, so its events
+/// don't wait forever.
+pub trait SubsystemInitializer: Send + Sync + 'static {
+  fn initialize(&self, subsystem: CoreSubsystemPB) -> Fut<()>;
+}
+
+/// Waits until the subsystem is ready. The managers are initialized when they are needed for the
+/// first time, after the user session is loaded.
+pub async fn wait_until_initialized(
+  readiness: &CoreReadiness,
+  initializer: &Arc<dyn SubsystemInitializer>,
+  subsystem: CoreSubsystemPB,
+) {
+  if readiness.is_ready(subsystem) {
+    return;
+  }
+  match subsystem {
+    CoreSubsystemPB::User | CoreSubsystemPB::WebSocket => {},
+    CoreSubsystemPB::Folder | CoreSubsystemPB::Document | CoreSubsystemPB::Database => {
+      readiness.wait_until_ready(CoreSubsystemPB::User).await;
+      initializer.initialize(subsystem).await;
+    },
+  }
+  readiness.wait_until_ready(subsystem).await;
+}
+
+/// [ReadinessInterceptor] holds back the events of a subsystem until the subsystem finished
+/// its initialization, so the core can be created without waiting for the initializations. The
+/// first event of a manager's subsystem initializes the manager.
+///
+/// The network events don't wait, they are served by the state that is ready when the core is
+/// created.
+pub struct ReadinessInterceptor {
+  readiness: CoreReadiness,
+  initializer: Arc<dyn SubsystemInitializer>,
+  subsystem_by_event: HashMap<AFPluginEvent, CoreSubsystemPB>,
+}
+
+impl ReadinessInterceptor {
+  pub fn new(readiness: CoreReadiness, initializer: Arc<dyn SubsystemInitializer>) -> Self {
+    let mut subsystem_by_event = HashMap::new();
+    for event in UserEvent::iter() {
+      subsystem_by_event.insert(event.into(), CoreSubsystemPB::User);
+    }
+    for event in FolderEvent::iter() {
+      subsystem_by_event.insert(event.into(), CoreSubsystemPB::Folder);
+    }
+    for event in DocumentEvent::iter() {
+      subsystem_by_event.insert(event.into(), CoreSubsystemPB::Document);
+    }
+    for event in DatabaseEvent::iter() {
+      subsystem_by_event.insert(event.into(), CoreSubsystemPB::Database);
+    }
+    Self {
+      readiness,
+      initializer,
+      subsystem_by_event,
+    }
+  }
+}
+
+impl AFPluginInterceptor for ReadinessInterceptor {
+  fn intercept(&self, request: &AFPluginRequest) -> BoxFuture<'static, Result<(), DispatchError>> {
+    let subsystem = self
+      .subsystem_by_event
+      .get(&request.event)
+      .copied()
+      .filter(|subsystem| !self.readiness.is_ready(*subsystem));
+    let readiness = self.readiness.clone();
+    let initializer = self.initializer.clone();
+    Box::pin(async move {
+      if let Some(subsystem) = subsystem {
+        tracing::trace!("Wait until {:?} is ready", subsystem);
+        wait_until_initialized(&readiness, &initializer, subsystem).await;
+      }
+      Ok(())
+    })
+  }
+}
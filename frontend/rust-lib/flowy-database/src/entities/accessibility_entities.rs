@@ -0,0 +1,102 @@
+use crate::entities::FieldType;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+/// The semantic role of a cell, it tells the screen readers how to announce the cell and which
+/// actions it supports. The roles are shared by all the platforms, so they don't need to map the
+/// field types on their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum AccessibilityRolePB {
+  Text = 0,
+  Number = 1,
+  Date = 2,
+  /// A single choice among the options
+  Option = 3,
+  /// A list of items, e.g. the options of the multi select or the attached files
+  List = 4,
+  Checkbox = 5,
+  Link = 6,
+  ProgressBar = 7,
+  Timer = 8,
+}
+
+impl std::default::Default for AccessibilityRolePB {
+  fn default() -> Self {
+    AccessibilityRolePB::Text
+  }
+}
+
+impl std::convert::From<&FieldType> for AccessibilityRolePB {
+  fn from(field_type: &FieldType) -> Self {
+    match field_type {
+      FieldType::RichText | FieldType::Formula | FieldType::Rollup | FieldType::Location => {
+        AccessibilityRolePB::Text
+      },
+      FieldType::Number | FieldType::Duration | FieldType::AutoNumber => {
+        AccessibilityRolePB::Number
+      },
+      FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
+        AccessibilityRolePB::Date
+      },
+      FieldType::SingleSelect => AccessibilityRolePB::Option,
+      FieldType::MultiSelect | FieldType::Person | FieldType::Attachment | FieldType::Relation => {
+        AccessibilityRolePB::List
+      },
+      FieldType::Checkbox => AccessibilityRolePB::Checkbox,
+      FieldType::URL | FieldType::Phone | FieldType::Email => AccessibilityRolePB::Link,
+      FieldType::Checklist | FieldType::Progress => AccessibilityRolePB::ProgressBar,
+      FieldType::TimeTracking => AccessibilityRolePB::Timer,
+    }
+  }
+}
+
+/// The state of a cell that the screen readers announce along with its value. The platforms
+/// translate it into the sentence of the user's language, e.g. "Done: not checked".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum CellAccessibilityStatePB {
+  /// The value of the cell is announced
+  Filled = 0,
+  Empty = 1,
+  Checked = 2,
+  Unchecked = 3,
+}
+
+impl std::default::Default for CellAccessibilityStatePB {
+  fn default() -> Self {
+    CellAccessibilityStatePB::Empty
+  }
+}
+
+/// What the screen readers announce for a cell, generated from the same display string as the
+/// exported cells.
+#[derive(Debug, Default, Clone, PartialEq, Eq, ProtoBuf)]
+pub struct CellAccessibilityPB {
+  /// The name of the field
+  #[pb(index = 1)]
+  pub label: String,
+
+  /// The display string of the cell, empty if the cell is empty
+  #[pb(index = 2)]
+  pub value: String,
+
+  #[pb(index = 4)]
+  pub role: AccessibilityRolePB,
+
+  /// The cells of the computed fields can't be edited
+  #[pb(index = 5)]
+  pub is_read_only: bool,
+
+  #[pb(index = 6)]
+  pub state: CellAccessibilityStatePB,
+
+  /// Whether the cell is announced when the whole row is announced. The empty cells are
+  /// skipped, except the primary one.
+  #[pb(index = 7)]
+  pub is_announced_in_row: bool,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, ProtoBuf)]
+pub struct RowAccessibilityPB {
+  /// The cells of the visible fields in the order of the fields, the primary cell first.
+  #[pb(index = 2)]
+  pub cells: Vec<CellAccessibilityPB>,
+}
@@ -0,0 +1,276 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::services::persistence::automation_activity::AutomationActivity;
+use database_model::{AutomationActionRevision, AutomationRevision, AutomationTriggerRevision};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum AutomationTriggerTypePB {
+  /// Fires when the date of the date field arrives
+  DateArrived = 0,
+}
+
+impl std::default::Default for AutomationTriggerTypePB {
+  fn default() -> Self {
+    AutomationTriggerTypePB::DateArrived
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum AutomationActionTypePB {
+  /// Moves the row to the group of the grouping field of the view
+  MoveToGroup = 0,
+}
+
+impl std::default::Default for AutomationActionTypePB {
+  fn default() -> Self {
+    AutomationActionTypePB::MoveToGroup
+  }
+}
+
+/// [AutomationPB] fires its action once for each row that matches its trigger, e.g. "when the
+/// due date is today, move the row to the 'Doing' group".
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct AutomationPB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub trigger_type: AutomationTriggerTypePB,
+
+  /// The date field of the [AutomationTriggerTypePB::DateArrived] trigger
+  #[pb(index = 4)]
+  pub field_id: String,
+
+  /// Fires the action the given number of days before the date arrives
+  #[pb(index = 5)]
+  pub days_before: i64,
+
+  #[pb(index = 6)]
+  pub action_type: AutomationActionTypePB,
+
+  /// The group of the [AutomationActionTypePB::MoveToGroup] action
+  #[pb(index = 7)]
+  pub group_id: String,
+
+  #[pb(index = 8)]
+  pub is_enabled: bool,
+}
+
+impl std::convert::From<&AutomationRevision> for AutomationPB {
+  fn from(rev: &AutomationRevision) -> Self {
+    let mut automation = Self {
+      id: rev.id.clone(),
+      name: rev.name.clone(),
+      is_enabled: rev.is_enabled,
+      ..Default::default()
+    };
+    match &rev.trigger {
+      AutomationTriggerRevision::DateArrived {
+        field_id,
+        days_before,
+      } => {
+        automation.trigger_type = AutomationTriggerTypePB::DateArrived;
+        automation.field_id = field_id.clone();
+        automation.days_before = *days_before;
+      },
+    }
+    match &rev.action {
+      AutomationActionRevision::MoveToGroup { group_id } => {
+        automation.action_type = AutomationActionTypePB::MoveToGroup;
+        automation.group_id = group_id.clone();
+      },
+    }
+    automation
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedAutomationPB {
+  #[pb(index = 1)]
+  pub items: Vec<AutomationPB>,
+}
+
+impl std::convert::From<Vec<AutomationRevision>> for RepeatedAutomationPB {
+  fn from(revs: Vec<AutomationRevision>) -> Self {
+    RepeatedAutomationPB {
+      items: revs.iter().map(AutomationPB::from).collect(),
+    }
+  }
+}
+
+/// Saves the automation of the view. A new automation is created if the `automation_id` is
+/// None, otherwise the automation with the id is replaced.
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct SaveAutomationPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2, one_of)]
+  pub automation_id: Option<String>,
+
+  #[pb(index = 3)]
+  pub name: String,
+
+  #[pb(index = 4)]
+  pub trigger_type: AutomationTriggerTypePB,
+
+  #[pb(index = 5)]
+  pub field_id: String,
+
+  #[pb(index = 6)]
+  pub days_before: i64,
+
+  #[pb(index = 7)]
+  pub action_type: AutomationActionTypePB,
+
+  #[pb(index = 8)]
+  pub group_id: String,
+
+  #[pb(index = 9)]
+  pub is_enabled: bool,
+}
+
+pub struct SaveAutomationParams {
+  pub view_id: String,
+  pub automation_id: Option<String>,
+  pub name: String,
+  pub trigger: AutomationTriggerRevision,
+  pub action: AutomationActionRevision,
+  pub is_enabled: bool,
+}
+
+impl TryInto<SaveAutomationParams> for SaveAutomationPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<SaveAutomationParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+      .0;
+    let trigger = match self.trigger_type {
+      AutomationTriggerTypePB::DateArrived => {
+        let field_id = NotEmptyStr::parse(self.field_id)
+          .map_err(|_| ErrorCode::FieldIdIsEmpty)?
+          .0;
+        if self.days_before < 0 {
+          return Err(ErrorCode::InvalidData);
+        }
+        AutomationTriggerRevision::DateArrived {
+          field_id,
+          days_before: self.days_before,
+        }
+      },
+    };
+    let action = match self.action_type {
+      AutomationActionTypePB::MoveToGroup => {
+        let group_id = NotEmptyStr::parse(self.group_id)
+          .map_err(|_| ErrorCode::GroupIdIsEmpty)?
+          .0;
+        AutomationActionRevision::MoveToGroup { group_id }
+      },
+    };
+    Ok(SaveAutomationParams {
+      view_id,
+      automation_id: self.automation_id,
+      name: self.name.trim().to_owned(),
+      trigger,
+      action,
+      is_enabled: self.is_enabled,
+    })
+  }
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct AutomationIdPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub automation_id: String,
+}
+
+pub struct AutomationIdParams {
+  pub view_id: String,
+  pub automation_id: String,
+}
+
+impl TryInto<AutomationIdParams> for AutomationIdPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<AutomationIdParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+      .0;
+    let automation_id = NotEmptyStr::parse(self.automation_id)
+      .map_err(|_| ErrorCode::UnexpectedEmptyString)?
+      .0;
+    Ok(AutomationIdParams {
+      view_id,
+      automation_id,
+    })
+  }
+}
+
+/// [AutomationActivityPB] records an execution of the automation on a row.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct AutomationActivityPB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub automation_id: String,
+
+  #[pb(index = 3)]
+  pub view_id: String,
+
+  #[pb(index = 4)]
+  pub row_id: String,
+
+  /// The date that triggered the automation, in the form of `%Y-%m-%d`
+  #[pb(index = 5)]
+  pub trigger_date: String,
+
+  /// Set if the action failed
+  #[pb(index = 6, one_of)]
+  pub error: Option<String>,
+
+  /// The timestamp in seconds when the automation was executed
+  #[pb(index = 7)]
+  pub executed_at: i64,
+}
+
+impl std::convert::From<AutomationActivity> for AutomationActivityPB {
+  fn from(activity: AutomationActivity) -> Self {
+    Self {
+      id: activity.id,
+      automation_id: activity.automation_id,
+      view_id: activity.view_id,
+      row_id: activity.row_id,
+      trigger_date: activity.trigger_date,
+      error: Some(activity.error).filter(|error| !error.is_empty()),
+      executed_at: activity.executed_at,
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedAutomationActivityPB {
+  #[pb(index = 1)]
+  pub items: Vec<AutomationActivityPB>,
+}
+
+impl std::convert::From<Vec<AutomationActivity>> for RepeatedAutomationActivityPB {
+  fn from(activities: Vec<AutomationActivity>) -> Self {
+    Self {
+      items: activities
+        .into_iter()
+        .map(AutomationActivityPB::from)
+        .collect(),
+    }
+  }
+}
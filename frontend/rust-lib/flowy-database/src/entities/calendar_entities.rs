@@ -19,6 +19,14 @@ pub struct CalendarLayoutSettingsPB {
 
   #[pb(index = 5)]
   pub show_week_numbers: bool,
+
+  /// Whether the rows with an empty date cell are returned as unscheduled events
+  #[pb(index = 6)]
+  pub show_unscheduled_events: bool,
+
+  /// Whether the checklist items that have a due date are returned as events
+  #[pb(index = 7)]
+  pub show_checklist_items: bool,
 }
 
 impl std::convert::From<CalendarLayoutSettingsPB> for CalendarLayoutSetting {
@@ -29,6 +37,8 @@ impl std::convert::From<CalendarLayoutSettingsPB> for CalendarLayoutSetting {
       show_weekends: pb.show_weekends,
       show_week_numbers: pb.show_week_numbers,
       layout_field_id: pb.layout_field_id,
+      show_unscheduled_events: pb.show_unscheduled_events,
+      show_checklist_items: pb.show_checklist_items,
     }
   }
 }
@@ -41,6 +51,8 @@ impl std::convert::From<CalendarLayoutSetting> for CalendarLayoutSettingsPB {
       first_day_of_week: params.first_day_of_week,
       show_weekends: params.show_weekends,
       show_week_numbers: params.show_week_numbers,
+      show_unscheduled_events: params.show_unscheduled_events,
+      show_checklist_items: params.show_checklist_items,
     }
   }
 }
@@ -82,12 +94,30 @@ pub struct CalendarEventRequestPB {
   // is not supported
   #[pb(index = 2)]
   pub month: String,
+
+  /// The timestamp in seconds of the beginning of the requested window. The recurring dates are
+  /// only expanded into their occurrences if both the `start` and the `end` are set.
+  #[pb(index = 3, one_of)]
+  pub start: Option<i64>,
+
+  /// The timestamp in seconds of the end of the requested window, inclusive.
+  #[pb(index = 4, one_of)]
+  pub end: Option<i64>,
+
+  /// The timestamp in seconds of a day. If it's set, the requested window is the week that
+  /// the day is in instead of the `start` and the `end`. The week starts on the first day of
+  /// the week of the workspace.
+  #[pb(index = 5, one_of)]
+  pub week_of: Option<i64>,
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct CalendarEventRequestParams {
   pub view_id: String,
   pub month: String,
+  /// The start and the end of the requested window
+  pub window: Option<(i64, i64)>,
+  pub week_of: Option<i64>,
 }
 
 impl TryInto<CalendarEventRequestParams> for CalendarEventRequestPB {
@@ -95,9 +125,16 @@ impl TryInto<CalendarEventRequestParams> for CalendarEventRequestPB {
 
   fn try_into(self) -> Result<CalendarEventRequestParams, Self::Error> {
     let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let window = match (self.start, self.end) {
+      (Some(start), Some(end)) if start > end => return Err(ErrorCode::InvalidData),
+      (Some(start), Some(end)) => Some((start, end)),
+      _ => None,
+    };
     Ok(CalendarEventRequestParams {
       view_id: view_id.0,
       month: self.month,
+      window,
+      week_of: self.week_of,
     })
   }
 }
@@ -115,6 +152,21 @@ pub struct CalendarEventPB {
 
   #[pb(index = 4)]
   pub timestamp: i64,
+
+  /// The date cell of the row is empty, the event is shown in the unscheduled lane. The
+  /// `timestamp` is zero in this case.
+  #[pb(index = 5)]
+  pub is_unscheduled: bool,
+
+  /// The event is the checklist item with this id. The `date_field_id` is the id of the
+  /// checklist field and the `timestamp` is the due date of the item.
+  #[pb(index = 6, one_of)]
+  pub checklist_item_id: Option<String>,
+
+  /// The date cell of the row repeats. The `timestamp` is the occurrence within the requested
+  /// window, or the first occurrence if no window was requested.
+  #[pb(index = 7)]
+  pub is_recurring: bool,
 }
 
 #[derive(Debug, Clone, Default, ProtoBuf)]
@@ -134,3 +186,39 @@ pub struct MoveCalendarEventPB {
   #[pb(index = 3)]
   pub timestamp: i64,
 }
+
+/// [ScheduleRowPayloadPB] is used to write the date cell of the row when its card is dropped
+/// onto a day of the calendar.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct ScheduleRowPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  /// The timestamp in seconds of the day
+  #[pb(index = 3)]
+  pub timestamp: i64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleRowParams {
+  pub view_id: String,
+  pub row_id: String,
+  pub timestamp: i64,
+}
+
+impl TryInto<ScheduleRowParams> for ScheduleRowPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ScheduleRowParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    let row_id = NotEmptyStr::parse(self.row_id).map_err(|_| ErrorCode::RowIdIsEmpty)?;
+    Ok(ScheduleRowParams {
+      view_id: view_id.0,
+      row_id: row_id.0,
+      timestamp: self.timestamp,
+    })
+  }
+}
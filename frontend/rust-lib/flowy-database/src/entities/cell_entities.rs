@@ -1,7 +1,7 @@
 use crate::entities::parser::NotEmptyStr;
-use crate::entities::FieldType;
+use crate::entities::{CellAccessibilityPB, FieldType};
 use database_model::{CellRevision, RowChangeset};
-use flowy_derive::ProtoBuf;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
 use std::collections::HashMap;
 
@@ -91,6 +91,14 @@ pub struct CellPB {
   /// the field_type will be None if the field with field_id is not found
   #[pb(index = 4, one_of)]
   pub field_type: Option<FieldType>,
+
+  /// The cell can't be edited while it's in conflict
+  #[pb(index = 5)]
+  pub is_conflicted: bool,
+
+  /// The text summary and the role of the cell for the screen readers
+  #[pb(index = 6, one_of)]
+  pub accessibility: Option<CellAccessibilityPB>,
 }
 
 impl CellPB {
@@ -100,6 +108,8 @@ impl CellPB {
       row_id: row_id.to_string(),
       data,
       field_type: Some(field_type),
+      is_conflicted: false,
+      accessibility: None,
     }
   }
 
@@ -109,6 +119,8 @@ impl CellPB {
       row_id: row_id.to_owned(),
       data: vec![],
       field_type: None,
+      is_conflicted: false,
+      accessibility: None,
     }
   }
 }
@@ -152,6 +164,12 @@ pub struct CellChangesetPB {
 
   #[pb(index = 4)]
   pub type_cell_data: String,
+
+  /// The data of the cell that the changeset was made from. If the cell was changed in the
+  /// meantime, the changeset conflicts with that change. It's rejected and the cell is marked as
+  /// conflicted until the [ResolveCellConflict] event is sent.
+  #[pb(index = 5, one_of)]
+  pub base_type_cell_data: Option<String>,
 }
 
 impl std::convert::From<CellChangesetPB> for RowChangeset {
@@ -167,7 +185,177 @@ impl std::convert::From<CellChangesetPB> for RowChangeset {
       row_id: changeset.row_id,
       height: None,
       visibility: None,
+      modified_at: None,
       cell_by_field_id,
     }
   }
 }
+
+/// Addresses a cell by its position in the view. The `row_index` is the index of the row after
+/// the rows of the view are filtered and sorted. The `field_index` is the index of the field
+/// within the visible fields.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct CellAddressPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_index: i32,
+
+  #[pb(index = 3)]
+  pub field_index: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CellAddressParams {
+  pub view_id: String,
+  pub row_index: usize,
+  pub field_index: usize,
+}
+
+impl TryInto<CellAddressParams> for CellAddressPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<CellAddressParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    if self.row_index < 0 || self.field_index < 0 {
+      return Err(ErrorCode::OutOfBounds);
+    }
+    Ok(CellAddressParams {
+      view_id: view_id.0,
+      row_index: self.row_index as usize,
+      field_index: self.field_index as usize,
+    })
+  }
+}
+
+impl CellAddressParams {
+  /// Returns the address of the cell next to this cell in the `direction`. The `Next` and
+  /// `Previous` directions wrap around to the adjacent row. The address stays the same if there
+  /// is no cell in the `direction`.
+  pub fn adjacent(
+    &self,
+    direction: &CellMoveDirectionPB,
+    number_of_rows: usize,
+    number_of_fields: usize,
+  ) -> Self {
+    let last_row_index = number_of_rows.saturating_sub(1);
+    let last_field_index = number_of_fields.saturating_sub(1);
+    let (row_index, field_index) = match direction {
+      CellMoveDirectionPB::Left => (self.row_index, self.field_index.saturating_sub(1)),
+      CellMoveDirectionPB::Right => (self.row_index, (self.field_index + 1).min(last_field_index)),
+      CellMoveDirectionPB::Up => (self.row_index.saturating_sub(1), self.field_index),
+      CellMoveDirectionPB::Down => ((self.row_index + 1).min(last_row_index), self.field_index),
+      CellMoveDirectionPB::Next => {
+        if self.field_index < last_field_index {
+          (self.row_index, self.field_index + 1)
+        } else if self.row_index < last_row_index {
+          (self.row_index + 1, 0)
+        } else {
+          (self.row_index, self.field_index)
+        }
+      },
+      CellMoveDirectionPB::Previous => {
+        if self.field_index > 0 {
+          (self.row_index, self.field_index - 1)
+        } else if self.row_index > 0 {
+          (self.row_index - 1, last_field_index)
+        } else {
+          (self.row_index, self.field_index)
+        }
+      },
+    };
+
+    Self {
+      view_id: self.view_id.clone(),
+      row_index,
+      field_index,
+    }
+  }
+}
+
+impl std::convert::From<CellAddressParams> for CellAddressPB {
+  fn from(params: CellAddressParams) -> Self {
+    Self {
+      view_id: params.view_id,
+      row_index: params.row_index as i32,
+      field_index: params.field_index as i32,
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum CellMoveDirectionPB {
+  Left = 0,
+  Right = 1,
+  Up = 2,
+  Down = 3,
+  /// Moves to the next cell in the row, or the first cell of the next row.
+  Next = 4,
+  /// Moves to the previous cell in the row, or the last cell of the previous row.
+  Previous = 5,
+}
+
+impl std::default::Default for CellMoveDirectionPB {
+  fn default() -> Self {
+    CellMoveDirectionPB::Next
+  }
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct MoveCellAddressPayloadPB {
+  #[pb(index = 1)]
+  pub address: CellAddressPB,
+
+  #[pb(index = 2)]
+  pub direction: CellMoveDirectionPB,
+}
+
+/// The cell and its address. The `cell` carries the row id and the field id that can be used to
+/// update the cell.
+#[derive(Debug, Default, ProtoBuf)]
+pub struct AddressedCellPB {
+  #[pb(index = 1)]
+  pub address: CellAddressPB,
+
+  #[pb(index = 2)]
+  pub cell: CellPB,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum InvalidCellReasonPB {
+  /// The cell can't be parsed as the type cell data, e.g. it was saved without its field type.
+  Corrupted = 0,
+  /// The cell data can't be decoded by the type option of its field type.
+  Undecodable = 1,
+  /// The cell data is decodable, but it's encoded in a format that was used by an older
+  /// version, e.g. the timestamp of the date cell is saved as a plain integer.
+  StaleEncoding = 2,
+}
+
+impl std::default::Default for InvalidCellReasonPB {
+  fn default() -> Self {
+    InvalidCellReasonPB::Corrupted
+  }
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct InvalidCellPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub reason: InvalidCellReasonPB,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct InvalidCellsPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub items: Vec<InvalidCellPB>,
+}
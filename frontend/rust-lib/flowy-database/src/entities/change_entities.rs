@@ -0,0 +1,77 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use lib_infra::util::timestamp;
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum DatabaseChangeTypePB {
+  RowCreated = 0,
+  RowUpdated = 1,
+  RowDeleted = 2,
+  FieldCreated = 3,
+  FieldUpdated = 4,
+  FieldDeleted = 5,
+}
+
+impl std::default::Default for DatabaseChangeTypePB {
+  fn default() -> Self {
+    DatabaseChangeTypePB::RowUpdated
+  }
+}
+
+/// [DatabaseChangePB] describes a change of the rows or the fields of the database. The changes
+/// are published to the consumers outside the database, e.g. the automations or the plugins.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct DatabaseChangePB {
+  #[pb(index = 1)]
+  pub database_id: String,
+
+  #[pb(index = 2)]
+  pub ty: DatabaseChangeTypePB,
+
+  /// The id of the changed row, it's empty if the change is a field change.
+  #[pb(index = 3)]
+  pub row_id: String,
+
+  /// The id of the changed field, or the fields of the updated cells if the change is a
+  /// [DatabaseChangeTypePB::RowUpdated].
+  #[pb(index = 4)]
+  pub field_ids: Vec<String>,
+
+  /// The timestamp in seconds when the change happened
+  #[pb(index = 5)]
+  pub timestamp: i64,
+}
+
+impl DatabaseChangePB {
+  pub fn row(
+    database_id: &str,
+    ty: DatabaseChangeTypePB,
+    row_id: &str,
+    field_ids: Vec<String>,
+  ) -> Self {
+    Self {
+      database_id: database_id.to_owned(),
+      ty,
+      row_id: row_id.to_owned(),
+      field_ids,
+      timestamp: timestamp(),
+    }
+  }
+
+  pub fn field(database_id: &str, ty: DatabaseChangeTypePB, field_id: &str) -> Self {
+    Self {
+      database_id: database_id.to_owned(),
+      ty,
+      row_id: "".to_owned(),
+      field_ids: vec![field_id.to_owned()],
+      timestamp: timestamp(),
+    }
+  }
+}
+
+/// The changes of the subscribed database are sent as the
+/// [DatabaseNotification::DidReceiveDatabaseChange] notification whose id is the `database_id`.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct DatabaseChangeSubscriptionPB {
+  #[pb(index = 1)]
+  pub database_id: String,
+}
@@ -1,6 +1,7 @@
 use crate::entities::parser::NotEmptyStr;
-use crate::entities::{FieldIdPB, LayoutTypePB, RowPB};
-use flowy_derive::ProtoBuf;
+use crate::entities::{FieldIdPB, FieldType, LayoutTypePB, RowPB};
+use database_model::FieldRevision;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
 
 /// [DatabasePB] describes how many fields and blocks the grid has
@@ -118,6 +119,10 @@ pub struct MoveGroupRowPayloadPB {
 
   #[pb(index = 4, one_of)]
   pub to_row_id: Option<String>,
+
+  /// The swimlane that the row is moved to. The row stays in its swimlane if it's None.
+  #[pb(index = 5, one_of)]
+  pub to_swimlane_id: Option<String>,
 }
 
 pub struct MoveGroupRowParams {
@@ -125,6 +130,7 @@ pub struct MoveGroupRowParams {
   pub from_row_id: String,
   pub to_group_id: String,
   pub to_row_id: Option<String>,
+  pub to_swimlane_id: Option<String>,
 }
 
 impl TryInto<MoveGroupRowParams> for MoveGroupRowPayloadPB {
@@ -145,11 +151,21 @@ impl TryInto<MoveGroupRowParams> for MoveGroupRowPayloadPB {
       ),
     };
 
+    let to_swimlane_id = match self.to_swimlane_id {
+      None => None,
+      Some(to_swimlane_id) => Some(
+        NotEmptyStr::parse(to_swimlane_id)
+          .map_err(|_| ErrorCode::GroupIdIsEmpty)?
+          .0,
+      ),
+    };
+
     Ok(MoveGroupRowParams {
       view_id: view_id.0,
       from_row_id: from_row_id.0,
       to_group_id: to_group_id.0,
       to_row_id,
+      to_swimlane_id,
     })
   }
 }
@@ -169,6 +185,113 @@ pub struct RepeatedDatabaseDescriptionPB {
   pub items: Vec<DatabaseDescriptionPB>,
 }
 
+/// The name and the type of a field, e.g. a field of a template or a column of an imported file.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct FieldSchemaPB {
+  #[pb(index = 1)]
+  pub name: String,
+
+  /// None matches any type, e.g. the columns of a CSV file don't have types.
+  #[pb(index = 2, one_of)]
+  pub field_type: Option<FieldType>,
+}
+
+impl std::convert::From<&FieldRevision> for FieldSchemaPB {
+  fn from(field_rev: &FieldRevision) -> Self {
+    Self {
+      name: field_rev.name.clone(),
+      field_type: Some(field_rev.ty.into()),
+    }
+  }
+}
+
+/// Finds the databases that have the same fields as the data that is about to be created, so
+/// that the data can be linked into one of them instead of creating another copy.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct CompareDatabaseSchemaPayloadPB {
+  #[pb(index = 1)]
+  pub fields: Vec<FieldSchemaPB>,
+}
+
+/// Merges the rows of the source database into the target database, e.g. after importing the
+/// same data twice created two copies of a database.
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct MergeDatabasesPayloadPB {
+  #[pb(index = 1)]
+  pub target_view_id: String,
+
+  #[pb(index = 2)]
+  pub source_view_id: String,
+
+  /// Moves the rows of the source database to the trash after they are merged
+  #[pb(index = 3)]
+  pub trash_source: bool,
+}
+
+pub struct MergeDatabasesParams {
+  pub target_view_id: String,
+  pub source_view_id: String,
+  pub trash_source: bool,
+}
+
+impl TryInto<MergeDatabasesParams> for MergeDatabasesPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<MergeDatabasesParams, Self::Error> {
+    let target_view_id =
+      NotEmptyStr::parse(self.target_view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+    let source_view_id =
+      NotEmptyStr::parse(self.source_view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+    Ok(MergeDatabasesParams {
+      target_view_id: target_view_id.0,
+      source_view_id: source_view_id.0,
+      trash_source: self.trash_source,
+    })
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum DatabaseMergeConflictKindPB {
+  /// The target database doesn't have a field with the same name, the cells are dropped
+  MissingField = 0,
+  /// The target database has a field with the same name but of another type, the cells are
+  /// dropped
+  FieldTypeMismatch = 1,
+}
+
+impl std::default::Default for DatabaseMergeConflictKindPB {
+  fn default() -> Self {
+    DatabaseMergeConflictKindPB::MissingField
+  }
+}
+
+/// A field of the source database whose cells couldn't be merged.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ProtoBuf)]
+pub struct DatabaseMergeConflictPB {
+  #[pb(index = 1)]
+  pub field_id: String,
+
+  #[pb(index = 2)]
+  pub field_name: String,
+
+  #[pb(index = 3)]
+  pub kind: DatabaseMergeConflictKindPB,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct DatabaseMergeResultPB {
+  /// The number of the rows that are appended to the target database
+  #[pb(index = 1)]
+  pub merged_rows: i64,
+
+  /// The number of the select options that are added to the fields of the target database
+  #[pb(index = 2)]
+  pub added_options: i64,
+
+  #[pb(index = 3)]
+  pub conflicts: Vec<DatabaseMergeConflictPB>,
+}
+
 #[derive(Debug, Clone, Default, ProtoBuf)]
 pub struct DatabaseGroupIdPB {
   #[pb(index = 1)]
@@ -203,3 +326,26 @@ pub struct DatabaseLayoutIdPB {
   #[pb(index = 2)]
   pub layout: LayoutTypePB,
 }
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct DatabaseSchemaPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// Returns the OpenAPI document that wraps the schema too
+  #[pb(index = 2)]
+  pub include_openapi: bool,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct DatabaseSchemaPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// The JSON Schema that describes the fields of the database
+  #[pb(index = 2)]
+  pub json_schema: String,
+
+  #[pb(index = 3, one_of)]
+  pub openapi: Option<String>,
+}
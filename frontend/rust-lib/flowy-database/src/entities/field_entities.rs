@@ -179,6 +179,50 @@ impl TryInto<CreateFieldParams> for CreateFieldPayloadPB {
   }
 }
 
+/// Defines a field that is created by [CreateFieldsPayloadPB]
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct FieldDefinitionPB {
+  /// The field is named `Property {n}` if the name is empty
+  #[pb(index = 1)]
+  pub name: String,
+
+  #[pb(index = 2)]
+  pub field_type: FieldType,
+
+  #[pb(index = 3, one_of)]
+  pub type_option_data: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct CreateFieldsPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub fields: Vec<FieldDefinitionPB>,
+}
+
+pub struct CreateFieldsParams {
+  pub view_id: String,
+  pub fields: Vec<FieldDefinitionPB>,
+}
+
+impl TryInto<CreateFieldsParams> for CreateFieldsPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<CreateFieldsParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+    if self.fields.is_empty() {
+      return Err(ErrorCode::InvalidData);
+    }
+
+    Ok(CreateFieldsParams {
+      view_id: view_id.0,
+      fields: self.fields,
+    })
+  }
+}
+
 #[derive(Debug, Default, ProtoBuf)]
 pub struct UpdateFieldTypePayloadPB {
   #[pb(index = 1)]
@@ -494,6 +538,20 @@ pub enum FieldType {
   Checkbox = 5,
   URL = 6,
   Checklist = 7,
+  Relation = 8,
+  Rollup = 9,
+  Formula = 10,
+  CreatedTime = 11,
+  LastEditedTime = 12,
+  Person = 13,
+  Attachment = 14,
+  Progress = 15,
+  Phone = 16,
+  Email = 17,
+  Duration = 18,
+  AutoNumber = 19,
+  TimeTracking = 20,
+  Location = 21,
 }
 
 pub const RICH_TEXT_FIELD: FieldType = FieldType::RichText;
@@ -504,6 +562,20 @@ pub const MULTI_SELECT_FIELD: FieldType = FieldType::MultiSelect;
 pub const CHECKBOX_FIELD: FieldType = FieldType::Checkbox;
 pub const URL_FIELD: FieldType = FieldType::URL;
 pub const CHECKLIST_FIELD: FieldType = FieldType::Checklist;
+pub const RELATION_FIELD: FieldType = FieldType::Relation;
+pub const ROLLUP_FIELD: FieldType = FieldType::Rollup;
+pub const FORMULA_FIELD: FieldType = FieldType::Formula;
+pub const CREATED_TIME_FIELD: FieldType = FieldType::CreatedTime;
+pub const LAST_EDITED_TIME_FIELD: FieldType = FieldType::LastEditedTime;
+pub const PERSON_FIELD: FieldType = FieldType::Person;
+pub const ATTACHMENT_FIELD: FieldType = FieldType::Attachment;
+pub const PROGRESS_FIELD: FieldType = FieldType::Progress;
+pub const PHONE_FIELD: FieldType = FieldType::Phone;
+pub const EMAIL_FIELD: FieldType = FieldType::Email;
+pub const DURATION_FIELD: FieldType = FieldType::Duration;
+pub const AUTO_NUMBER_FIELD: FieldType = FieldType::AutoNumber;
+pub const TIME_TRACKING_FIELD: FieldType = FieldType::TimeTracking;
+pub const LOCATION_FIELD: FieldType = FieldType::Location;
 
 impl std::default::Default for FieldType {
   fn default() -> Self {
@@ -530,7 +602,7 @@ impl FieldType {
 
   pub fn default_cell_width(&self) -> i32 {
     match self {
-      FieldType::DateTime => 180,
+      FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => 180,
       _ => 150,
     }
   }
@@ -571,8 +643,73 @@ impl FieldType {
     self == &CHECKLIST_FIELD
   }
 
+  pub fn is_relation(&self) -> bool {
+    self == &RELATION_FIELD
+  }
+
+  pub fn is_rollup(&self) -> bool {
+    self == &ROLLUP_FIELD
+  }
+
+  pub fn is_formula(&self) -> bool {
+    self == &FORMULA_FIELD
+  }
+
+  pub fn is_created_time(&self) -> bool {
+    self == &CREATED_TIME_FIELD
+  }
+
+  pub fn is_last_edited_time(&self) -> bool {
+    self == &LAST_EDITED_TIME_FIELD
+  }
+
+  pub fn is_person(&self) -> bool {
+    self == &PERSON_FIELD
+  }
+
+  pub fn is_attachment(&self) -> bool {
+    self == &ATTACHMENT_FIELD
+  }
+
+  pub fn is_progress(&self) -> bool {
+    self == &PROGRESS_FIELD
+  }
+
+  pub fn is_phone(&self) -> bool {
+    self == &PHONE_FIELD
+  }
+
+  pub fn is_email(&self) -> bool {
+    self == &EMAIL_FIELD
+  }
+
+  pub fn is_duration(&self) -> bool {
+    self == &DURATION_FIELD
+  }
+
+  pub fn is_auto_number(&self) -> bool {
+    self == &AUTO_NUMBER_FIELD
+  }
+
+  pub fn is_time_tracking(&self) -> bool {
+    self == &TIME_TRACKING_FIELD
+  }
+
+  pub fn is_location(&self) -> bool {
+    self == &LOCATION_FIELD
+  }
+
+  /// The cells of the computed fields are written by the application instead of the user.
+  pub fn is_computed(&self) -> bool {
+    self.is_created_time() || self.is_last_edited_time() || self.is_formula() || self.is_rollup()
+  }
+
   pub fn can_be_group(&self) -> bool {
-    self.is_select_option() || self.is_checkbox() || self.is_url()
+    self.is_select_option()
+      || self.is_checkbox()
+      || self.is_url()
+      || self.is_person()
+      || self.is_date()
   }
 }
 
@@ -605,6 +742,20 @@ impl std::convert::From<FieldTypeRevision> for FieldType {
       5 => FieldType::Checkbox,
       6 => FieldType::URL,
       7 => FieldType::Checklist,
+      8 => FieldType::Relation,
+      9 => FieldType::Rollup,
+      10 => FieldType::Formula,
+      11 => FieldType::CreatedTime,
+      12 => FieldType::LastEditedTime,
+      13 => FieldType::Person,
+      14 => FieldType::Attachment,
+      15 => FieldType::Progress,
+      16 => FieldType::Phone,
+      17 => FieldType::Email,
+      18 => FieldType::Duration,
+      19 => FieldType::AutoNumber,
+      20 => FieldType::TimeTracking,
+      21 => FieldType::Location,
       _ => {
         tracing::error!("Can't convert FieldTypeRevision: {} to FieldType", ty);
         FieldType::RichText
@@ -665,7 +816,53 @@ impl TryInto<FieldIdParams> for DeleteFieldPayloadPB {
   }
 }
 
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RestoreFieldPayloadPB {
+  #[pb(index = 1)]
+  pub field_id: String,
+
+  #[pb(index = 2)]
+  pub view_id: String,
+}
+
+impl TryInto<FieldIdParams> for RestoreFieldPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<FieldIdParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    Ok(FieldIdParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+    })
+  }
+}
+
 pub struct FieldIdParams {
   pub field_id: String,
   pub view_id: String,
 }
+
+/// [TrashedFieldPB] describes a field that was moved to the trash of the database. Its cells
+/// are kept until the field gets purged.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct TrashedFieldPB {
+  #[pb(index = 1)]
+  pub field: FieldPB,
+
+  /// The timestamp in seconds when the field was moved to the trash
+  #[pb(index = 2)]
+  pub deleted_at: i64,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedTrashedFieldPB {
+  #[pb(index = 1)]
+  pub items: Vec<TrashedFieldPB>,
+}
+
+impl std::convert::From<Vec<TrashedFieldPB>> for RepeatedTrashedFieldPB {
+  fn from(items: Vec<TrashedFieldPB>) -> Self {
+    Self { items }
+  }
+}
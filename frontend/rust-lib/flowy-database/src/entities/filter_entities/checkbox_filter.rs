@@ -1,3 +1,4 @@
+use crate::entities::{FilterContent, FilterDataPB};
 use crate::services::filter::FromFilterString;
 use database_model::FilterRevision;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
@@ -60,3 +61,13 @@ impl std::convert::From<&FilterRevision> for CheckboxFilterPB {
     }
   }
 }
+
+impl FilterDataPB for CheckboxFilterPB {
+  fn into_filter_content(self) -> Result<FilterContent, ErrorCode> {
+    Ok(FilterContent {
+      condition: self.condition as u8,
+      content: "".to_owned(),
+      case_sensitive: false,
+    })
+  }
+}
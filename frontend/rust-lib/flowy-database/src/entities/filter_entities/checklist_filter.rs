@@ -1,3 +1,4 @@
+use crate::entities::{FilterContent, FilterDataPB};
 use crate::services::filter::FromFilterString;
 use database_model::FilterRevision;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
@@ -7,6 +8,11 @@ use flowy_error::ErrorCode;
 pub struct ChecklistFilterPB {
   #[pb(index = 1)]
   pub condition: ChecklistFilterConditionPB,
+
+  /// The percentage, from 0 to 100, of the [ChecklistFilterConditionPB::IsCompleteAbove] and
+  /// [ChecklistFilterConditionPB::IsCompleteBelow] conditions
+  #[pb(index = 2)]
+  pub percentage: i64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
@@ -14,6 +20,23 @@ pub struct ChecklistFilterPB {
 pub enum ChecklistFilterConditionPB {
   IsComplete = 0,
   IsIncomplete = 1,
+  /// The row has an unchecked item whose due date has passed
+  HasOverdueItems = 2,
+  /// More than `percentage`% of the items are checked
+  IsCompleteAbove = 3,
+  /// Less than `percentage`% of the items are checked
+  IsCompleteBelow = 4,
+  /// The checklist has at least one item that is not checked
+  HasUncheckedItems = 5,
+}
+
+impl ChecklistFilterConditionPB {
+  pub fn is_percentage(&self) -> bool {
+    matches!(
+      self,
+      ChecklistFilterConditionPB::IsCompleteAbove | ChecklistFilterConditionPB::IsCompleteBelow
+    )
+  }
 }
 
 impl std::convert::From<ChecklistFilterConditionPB> for u32 {
@@ -35,6 +58,10 @@ impl std::convert::TryFrom<u8> for ChecklistFilterConditionPB {
     match value {
       0 => Ok(ChecklistFilterConditionPB::IsComplete),
       1 => Ok(ChecklistFilterConditionPB::IsIncomplete),
+      2 => Ok(ChecklistFilterConditionPB::HasOverdueItems),
+      3 => Ok(ChecklistFilterConditionPB::IsCompleteAbove),
+      4 => Ok(ChecklistFilterConditionPB::IsCompleteBelow),
+      5 => Ok(ChecklistFilterConditionPB::HasUncheckedItems),
       _ => Err(ErrorCode::InvalidData),
     }
   }
@@ -48,6 +75,7 @@ impl FromFilterString for ChecklistFilterPB {
     ChecklistFilterPB {
       condition: ChecklistFilterConditionPB::try_from(filter_rev.condition)
         .unwrap_or(ChecklistFilterConditionPB::IsIncomplete),
+      percentage: filter_rev.content.parse::<i64>().unwrap_or_default(),
     }
   }
 }
@@ -57,6 +85,24 @@ impl std::convert::From<&FilterRevision> for ChecklistFilterPB {
     ChecklistFilterPB {
       condition: ChecklistFilterConditionPB::try_from(rev.condition)
         .unwrap_or(ChecklistFilterConditionPB::IsIncomplete),
+      percentage: rev.content.parse::<i64>().unwrap_or_default(),
+    }
+  }
+}
+
+impl FilterDataPB for ChecklistFilterPB {
+  fn into_filter_content(self) -> Result<FilterContent, ErrorCode> {
+    let mut content = "".to_owned();
+    if self.condition.is_percentage() {
+      if !(0..=100).contains(&self.percentage) {
+        return Err(ErrorCode::InvalidData);
+      }
+      content = self.percentage.to_string();
     }
+    Ok(FilterContent {
+      condition: self.condition as u8,
+      content,
+      case_sensitive: false,
+    })
   }
 }
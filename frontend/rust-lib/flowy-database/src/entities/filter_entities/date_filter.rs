@@ -1,3 +1,4 @@
+use crate::entities::{FilterContent, FilterDataPB};
 use crate::services::filter::FromFilterString;
 use database_model::FilterRevision;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
@@ -18,6 +19,16 @@ pub struct DateFilterPB {
 
   #[pb(index = 4, one_of)]
   pub timestamp: Option<i64>,
+
+  /// The number of days from today that is used by the `DaysFromToday` conditions. It's negative
+  /// for the days in the past.
+  #[pb(index = 5, one_of)]
+  pub days: Option<i64>,
+
+  /// The first day of the week of the `DateIsThisWeek` condition, from 0 for Sunday to 6 for
+  /// Saturday. The first day of the week of the workspace is used if it's None.
+  #[pb(index = 6, one_of)]
+  pub first_day_of_week: Option<i32>,
 }
 
 #[derive(Deserialize, Serialize, Default, Clone, Debug)]
@@ -25,6 +36,10 @@ pub struct DateFilterContentPB {
   pub start: Option<i64>,
   pub end: Option<i64>,
   pub timestamp: Option<i64>,
+  #[serde(default)]
+  pub days: Option<i64>,
+  #[serde(default)]
+  pub first_day_of_week: Option<i32>,
 }
 
 impl ToString for DateFilterContentPB {
@@ -52,6 +67,10 @@ pub enum DateFilterConditionPB {
   DateWithIn = 5,
   DateIsEmpty = 6,
   DateIsNotEmpty = 7,
+  DaysFromTodayIs = 8,
+  DaysFromTodayLessThan = 9,
+  DaysFromTodayGreaterThan = 10,
+  DateIsThisWeek = 11,
 }
 
 impl std::convert::From<DateFilterConditionPB> for u32 {
@@ -77,6 +96,11 @@ impl std::convert::TryFrom<u8> for DateFilterConditionPB {
       4 => Ok(DateFilterConditionPB::DateOnOrAfter),
       5 => Ok(DateFilterConditionPB::DateWithIn),
       6 => Ok(DateFilterConditionPB::DateIsEmpty),
+      7 => Ok(DateFilterConditionPB::DateIsNotEmpty),
+      8 => Ok(DateFilterConditionPB::DaysFromTodayIs),
+      9 => Ok(DateFilterConditionPB::DaysFromTodayLessThan),
+      10 => Ok(DateFilterConditionPB::DaysFromTodayGreaterThan),
+      11 => Ok(DateFilterConditionPB::DateIsThisWeek),
       _ => Err(ErrorCode::InvalidData),
     }
   }
@@ -97,6 +121,8 @@ impl FromFilterString for DateFilterPB {
       filter.start = content.start;
       filter.end = content.end;
       filter.timestamp = content.timestamp;
+      filter.days = content.days;
+      filter.first_day_of_week = content.first_day_of_week;
     };
 
     filter
@@ -115,8 +141,27 @@ impl std::convert::From<&FilterRevision> for DateFilterPB {
       filter.start = content.start;
       filter.end = content.end;
       filter.timestamp = content.timestamp;
+      filter.days = content.days;
+      filter.first_day_of_week = content.first_day_of_week;
     };
 
     filter
   }
 }
+
+impl FilterDataPB for DateFilterPB {
+  fn into_filter_content(self) -> Result<FilterContent, ErrorCode> {
+    let content = DateFilterContentPB {
+      start: self.start,
+      end: self.end,
+      timestamp: self.timestamp,
+      days: self.days,
+      first_day_of_week: self.first_day_of_week,
+    };
+    Ok(FilterContent {
+      condition: self.condition as u8,
+      content: content.to_string(),
+      case_sensitive: false,
+    })
+  }
+}
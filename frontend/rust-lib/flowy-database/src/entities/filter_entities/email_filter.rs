@@ -0,0 +1,125 @@
+use crate::entities::{
+  FieldType, FilterContent, FilterDataPB, TextFilterConditionPB, TextFilterPB,
+};
+use crate::services::filter::FromFilterString;
+use database_model::FilterRevision;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct EmailFilterPB {
+  #[pb(index = 1)]
+  pub condition: EmailFilterConditionPB,
+
+  #[pb(index = 2)]
+  pub content: String,
+}
+
+/// The conditions of the text filter, and whether the address of the cell is valid. The cells
+/// that are converted from the text cells may keep the invalid addresses.
+///
+/// The values of the conditions of the address are not used by the text filter, so a filter
+/// keeps its meaning when the field is converted between a text and an email field.
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum EmailFilterConditionPB {
+  Is = 0,
+  IsNot = 1,
+  Contains = 2,
+  DoesNotContain = 3,
+  StartsWith = 4,
+  EndsWith = 5,
+  EmailIsEmpty = 6,
+  EmailIsNotEmpty = 7,
+  EmailIsValid = 10,
+  EmailIsInvalid = 11,
+}
+
+/// The values of [EmailFilterConditionPB::EmailIsValid] and [EmailFilterConditionPB::EmailIsInvalid]
+/// in the older versions. They are the values of the pattern conditions of the text filter, so
+/// they are only read from the filters that were made for an email field.
+const LEGACY_EMAIL_IS_VALID: u8 = 8;
+const LEGACY_EMAIL_IS_INVALID: u8 = 9;
+
+impl EmailFilterPB {
+  /// Returns the text filter of the conditions that compare the text of the address.
+  pub fn text_filter(&self) -> Option<TextFilterPB> {
+    let condition = match self.condition {
+      EmailFilterConditionPB::EmailIsValid | EmailFilterConditionPB::EmailIsInvalid => return None,
+      _ => TextFilterConditionPB::try_from(self.condition.clone() as u8).ok()?,
+    };
+    Some(TextFilterPB {
+      condition,
+      content: self.content.clone(),
+      case_sensitive: false,
+    })
+  }
+}
+
+impl std::convert::From<EmailFilterConditionPB> for u32 {
+  fn from(value: EmailFilterConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::default::Default for EmailFilterConditionPB {
+  fn default() -> Self {
+    EmailFilterConditionPB::Is
+  }
+}
+
+impl std::convert::TryFrom<u8> for EmailFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    match value {
+      0 => Ok(EmailFilterConditionPB::Is),
+      1 => Ok(EmailFilterConditionPB::IsNot),
+      2 => Ok(EmailFilterConditionPB::Contains),
+      3 => Ok(EmailFilterConditionPB::DoesNotContain),
+      4 => Ok(EmailFilterConditionPB::StartsWith),
+      5 => Ok(EmailFilterConditionPB::EndsWith),
+      6 => Ok(EmailFilterConditionPB::EmailIsEmpty),
+      7 => Ok(EmailFilterConditionPB::EmailIsNotEmpty),
+      10 => Ok(EmailFilterConditionPB::EmailIsValid),
+      11 => Ok(EmailFilterConditionPB::EmailIsInvalid),
+      _ => Err(ErrorCode::InvalidData),
+    }
+  }
+}
+
+impl FromFilterString for EmailFilterPB {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    EmailFilterPB::from(filter_rev)
+  }
+}
+
+impl std::convert::From<&FilterRevision> for EmailFilterPB {
+  fn from(rev: &FilterRevision) -> Self {
+    let is_email_filter = FieldType::from(rev.field_type) == FieldType::Email;
+    let condition = match rev.condition {
+      LEGACY_EMAIL_IS_VALID if is_email_filter => EmailFilterConditionPB::EmailIsValid,
+      LEGACY_EMAIL_IS_INVALID if is_email_filter => EmailFilterConditionPB::EmailIsInvalid,
+      condition => {
+        EmailFilterConditionPB::try_from(condition).unwrap_or(EmailFilterConditionPB::Is)
+      },
+    };
+    EmailFilterPB {
+      condition,
+      content: rev.content.clone(),
+    }
+  }
+}
+
+impl FilterDataPB for EmailFilterPB {
+  fn into_filter_content(self) -> Result<FilterContent, ErrorCode> {
+    Ok(FilterContent {
+      condition: self.condition as u8,
+      content: self.content,
+      case_sensitive: false,
+    })
+  }
+}
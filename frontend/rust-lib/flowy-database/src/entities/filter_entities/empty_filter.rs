@@ -0,0 +1,45 @@
+use flowy_derive::ProtoBuf_Enum;
+use flowy_error::ErrorCode;
+
+/// The conditions that the filter of every field type supports. Their values don't overlap the
+/// conditions of the field types, so the condition of a filter is either one of them or one of
+/// the conditions of its field type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum EmptyFilterConditionPB {
+  CellIsEmpty = 100,
+  CellIsNotEmpty = 101,
+}
+
+impl EmptyFilterConditionPB {
+  pub fn is_visible(&self, is_cell_empty: bool) -> bool {
+    match self {
+      EmptyFilterConditionPB::CellIsEmpty => is_cell_empty,
+      EmptyFilterConditionPB::CellIsNotEmpty => !is_cell_empty,
+    }
+  }
+}
+
+impl std::convert::From<EmptyFilterConditionPB> for u32 {
+  fn from(value: EmptyFilterConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::default::Default for EmptyFilterConditionPB {
+  fn default() -> Self {
+    EmptyFilterConditionPB::CellIsEmpty
+  }
+}
+
+impl std::convert::TryFrom<u8> for EmptyFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    match value {
+      100 => Ok(EmptyFilterConditionPB::CellIsEmpty),
+      101 => Ok(EmptyFilterConditionPB::CellIsNotEmpty),
+      _ => Err(ErrorCode::InvalidData),
+    }
+  }
+}
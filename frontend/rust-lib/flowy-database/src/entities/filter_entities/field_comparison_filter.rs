@@ -0,0 +1,86 @@
+use database_model::FilterRevision;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+use std::cmp::Ordering;
+
+/// Compares the cell with the cell of another field in the same row, e.g. the rows whose due
+/// date is before their completed date. Both the fields must have the same field type, the cells
+/// are compared like the sorts compare them.
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct FieldComparisonPB {
+  #[pb(index = 1)]
+  pub condition: FieldComparisonConditionPB,
+
+  #[pb(index = 2)]
+  pub other_field_id: String,
+}
+
+impl FieldComparisonPB {
+  /// Returns None if the filter doesn't compare the fields.
+  pub fn from_filter_rev(filter_rev: &FilterRevision) -> Option<Self> {
+    let condition = FieldComparisonConditionPB::try_from(filter_rev.condition).ok()?;
+    let other_field_id = filter_rev.other_field_id.clone()?;
+    Some(Self {
+      condition,
+      other_field_id,
+    })
+  }
+}
+
+/// The conditions that compare two fields. Like the [EmptyFilterConditionPB], their values
+/// don't overlap the conditions of the field types.
+///
+/// [EmptyFilterConditionPB]: crate::entities::EmptyFilterConditionPB
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum FieldComparisonConditionPB {
+  Equal = 110,
+  NotEqual = 111,
+  LessThan = 112,
+  GreaterThan = 113,
+  LessThanOrEqualTo = 114,
+  GreaterThanOrEqualTo = 115,
+}
+
+impl FieldComparisonConditionPB {
+  /// Returns true if the cell is visible when it's compared with the cell of the other field
+  /// and the result is the `ordering`.
+  pub fn is_visible(&self, ordering: Ordering) -> bool {
+    match self {
+      FieldComparisonConditionPB::Equal => ordering == Ordering::Equal,
+      FieldComparisonConditionPB::NotEqual => ordering != Ordering::Equal,
+      FieldComparisonConditionPB::LessThan => ordering == Ordering::Less,
+      FieldComparisonConditionPB::GreaterThan => ordering == Ordering::Greater,
+      FieldComparisonConditionPB::LessThanOrEqualTo => ordering != Ordering::Greater,
+      FieldComparisonConditionPB::GreaterThanOrEqualTo => ordering != Ordering::Less,
+    }
+  }
+}
+
+impl std::convert::From<FieldComparisonConditionPB> for u32 {
+  fn from(value: FieldComparisonConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::default::Default for FieldComparisonConditionPB {
+  fn default() -> Self {
+    FieldComparisonConditionPB::Equal
+  }
+}
+
+impl std::convert::TryFrom<u8> for FieldComparisonConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    match value {
+      110 => Ok(FieldComparisonConditionPB::Equal),
+      111 => Ok(FieldComparisonConditionPB::NotEqual),
+      112 => Ok(FieldComparisonConditionPB::LessThan),
+      113 => Ok(FieldComparisonConditionPB::GreaterThan),
+      114 => Ok(FieldComparisonConditionPB::LessThanOrEqualTo),
+      115 => Ok(FieldComparisonConditionPB::GreaterThanOrEqualTo),
+      _ => Err(ErrorCode::InvalidData),
+    }
+  }
+}
@@ -0,0 +1,101 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::FilterPB;
+use database_model::FilterPresetRevision;
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+
+/// [FilterPresetPB] is a named set of filters of the database view. Applying it replaces the
+/// filters of the view.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct FilterPresetPB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub filters: Vec<FilterPB>,
+}
+
+impl std::convert::From<&FilterPresetRevision> for FilterPresetPB {
+  fn from(rev: &FilterPresetRevision) -> Self {
+    Self {
+      id: rev.id.clone(),
+      name: rev.name.clone(),
+      filters: rev.filters.iter().map(FilterPB::from).collect(),
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct RepeatedFilterPresetPB {
+  #[pb(index = 1)]
+  pub items: Vec<FilterPresetPB>,
+}
+
+impl std::convert::From<Vec<FilterPresetRevision>> for RepeatedFilterPresetPB {
+  fn from(revs: Vec<FilterPresetRevision>) -> Self {
+    RepeatedFilterPresetPB {
+      items: revs.iter().map(FilterPresetPB::from).collect(),
+    }
+  }
+}
+
+/// Saves the current filters of the view as the preset with the `name`. The preset with the
+/// same name is overwritten.
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct SaveFilterPresetPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+}
+
+pub struct SaveFilterPresetParams {
+  pub view_id: String,
+  pub name: String,
+}
+
+impl TryInto<SaveFilterPresetParams> for SaveFilterPresetPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<SaveFilterPresetParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+      .0;
+    let name = NotEmptyStr::parse(self.name.trim().to_owned())
+      .map_err(|_| ErrorCode::UnexpectedEmptyString)?
+      .0;
+    Ok(SaveFilterPresetParams { view_id, name })
+  }
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct FilterPresetIdPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub preset_id: String,
+}
+
+pub struct FilterPresetIdParams {
+  pub view_id: String,
+  pub preset_id: String,
+}
+
+impl TryInto<FilterPresetIdParams> for FilterPresetIdPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<FilterPresetIdParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+      .0;
+    let preset_id = NotEmptyStr::parse(self.preset_id)
+      .map_err(|_| ErrorCode::UnexpectedEmptyString)?
+      .0;
+    Ok(FilterPresetIdParams { view_id, preset_id })
+  }
+}
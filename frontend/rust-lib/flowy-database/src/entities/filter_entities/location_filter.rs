@@ -0,0 +1,119 @@
+use crate::entities::{FilterContent, FilterDataPB};
+use crate::services::filter::FromFilterString;
+use database_model::FilterRevision;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct LocationFilterPB {
+  #[pb(index = 1)]
+  pub condition: LocationFilterConditionPB,
+
+  /// The latitude of the center, used by the distance conditions
+  #[pb(index = 2)]
+  pub latitude: f64,
+
+  /// The longitude of the center, used by the distance conditions
+  #[pb(index = 3)]
+  pub longitude: f64,
+
+  /// The radius around the center, in kilometers
+  #[pb(index = 4)]
+  pub distance_km: f64,
+}
+
+#[derive(Deserialize, Serialize, Default, Clone, Debug)]
+pub struct LocationFilterContentPB {
+  pub latitude: f64,
+  pub longitude: f64,
+  pub distance_km: f64,
+}
+
+impl ToString for LocationFilterContentPB {
+  fn to_string(&self) -> String {
+    serde_json::to_string(self).unwrap()
+  }
+}
+
+impl FromStr for LocationFilterContentPB {
+  type Err = serde_json::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    serde_json::from_str(s)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum LocationFilterConditionPB {
+  /// The location is within the `distance_km` of the center
+  LocationWithin = 0,
+  LocationNotWithin = 1,
+  LocationIsEmpty = 2,
+  LocationIsNotEmpty = 3,
+}
+
+impl std::convert::From<LocationFilterConditionPB> for u32 {
+  fn from(value: LocationFilterConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::default::Default for LocationFilterConditionPB {
+  fn default() -> Self {
+    LocationFilterConditionPB::LocationWithin
+  }
+}
+
+impl std::convert::TryFrom<u8> for LocationFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    match value {
+      0 => Ok(LocationFilterConditionPB::LocationWithin),
+      1 => Ok(LocationFilterConditionPB::LocationNotWithin),
+      2 => Ok(LocationFilterConditionPB::LocationIsEmpty),
+      3 => Ok(LocationFilterConditionPB::LocationIsNotEmpty),
+      _ => Err(ErrorCode::InvalidData),
+    }
+  }
+}
+
+impl FromFilterString for LocationFilterPB {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    LocationFilterPB::from(filter_rev)
+  }
+}
+
+impl std::convert::From<&FilterRevision> for LocationFilterPB {
+  fn from(rev: &FilterRevision) -> Self {
+    let content = LocationFilterContentPB::from_str(&rev.content).unwrap_or_default();
+    LocationFilterPB {
+      condition: LocationFilterConditionPB::try_from(rev.condition)
+        .unwrap_or(LocationFilterConditionPB::LocationWithin),
+      latitude: content.latitude,
+      longitude: content.longitude,
+      distance_km: content.distance_km,
+    }
+  }
+}
+
+impl FilterDataPB for LocationFilterPB {
+  fn into_filter_content(self) -> Result<FilterContent, ErrorCode> {
+    let content = LocationFilterContentPB {
+      latitude: self.latitude,
+      longitude: self.longitude,
+      distance_km: self.distance_km,
+    };
+    Ok(FilterContent {
+      condition: self.condition as u8,
+      content: content.to_string(),
+      case_sensitive: false,
+    })
+  }
+}
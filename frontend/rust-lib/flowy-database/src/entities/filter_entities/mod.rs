@@ -1,8 +1,15 @@
 mod checkbox_filter;
 mod checklist_filter;
 mod date_filter;
+mod email_filter;
+mod empty_filter;
+mod field_comparison_filter;
 mod filter_changeset;
+mod filter_preset;
+mod location_filter;
 mod number_filter;
+mod person_filter;
+mod relation_filter;
 mod select_option_filter;
 mod text_filter;
 mod util;
@@ -10,8 +17,15 @@ mod util;
 pub use checkbox_filter::*;
 pub use checklist_filter::*;
 pub use date_filter::*;
+pub use email_filter::*;
+pub use empty_filter::*;
+pub use field_comparison_filter::*;
 pub use filter_changeset::*;
+pub use filter_preset::*;
+pub use location_filter::*;
 pub use number_filter::*;
+pub use person_filter::*;
+pub use relation_filter::*;
 pub use select_option_filter::*;
 pub use text_filter::*;
 pub use util::*;
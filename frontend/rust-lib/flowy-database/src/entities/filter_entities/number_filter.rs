@@ -1,7 +1,10 @@
+use crate::entities::{FilterContent, FilterDataPB};
 use crate::services::filter::FromFilterString;
 use database_model::FilterRevision;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
 
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct NumberFilterPB {
@@ -10,6 +13,47 @@ pub struct NumberFilterPB {
 
   #[pb(index = 2)]
   pub content: String,
+
+  /// The upper bound of the [NumberFilterConditionPB::NumberIsBetween] and the
+  /// [NumberFilterConditionPB::NumberIsNotBetween] conditions, the `content` is the lower bound.
+  #[pb(index = 3, one_of)]
+  pub end_content: Option<String>,
+}
+
+/// The content of the filter revision for the conditions that have two bounds.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NumberRangeContentPB {
+  pub start: String,
+  pub end: String,
+}
+
+impl ToString for NumberRangeContentPB {
+  fn to_string(&self) -> String {
+    serde_json::to_string(self).unwrap()
+  }
+}
+
+impl FromStr for NumberRangeContentPB {
+  type Err = serde_json::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    serde_json::from_str(s)
+  }
+}
+
+impl NumberFilterPB {
+  /// Returns the content that is saved in the filter revision.
+  pub fn to_filter_content(&self) -> String {
+    if self.condition.is_range() {
+      NumberRangeContentPB {
+        start: self.content.clone(),
+        end: self.end_content.clone().unwrap_or_default(),
+      }
+      .to_string()
+    } else {
+      self.content.clone()
+    }
+  }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
@@ -23,6 +67,19 @@ pub enum NumberFilterConditionPB {
   LessThanOrEqualTo = 5,
   NumberIsEmpty = 6,
   NumberIsNotEmpty = 7,
+  /// Both the bounds are included
+  NumberIsBetween = 8,
+  NumberIsNotBetween = 9,
+}
+
+impl NumberFilterConditionPB {
+  /// Returns true if the condition compares the cells with two bounds.
+  pub fn is_range(&self) -> bool {
+    matches!(
+      self,
+      NumberFilterConditionPB::NumberIsBetween | NumberFilterConditionPB::NumberIsNotBetween
+    )
+  }
 }
 
 impl std::default::Default for NumberFilterConditionPB {
@@ -49,6 +106,8 @@ impl std::convert::TryFrom<u8> for NumberFilterConditionPB {
       5 => Ok(NumberFilterConditionPB::LessThanOrEqualTo),
       6 => Ok(NumberFilterConditionPB::NumberIsEmpty),
       7 => Ok(NumberFilterConditionPB::NumberIsNotEmpty),
+      8 => Ok(NumberFilterConditionPB::NumberIsBetween),
+      9 => Ok(NumberFilterConditionPB::NumberIsNotBetween),
       _ => Err(ErrorCode::InvalidData),
     }
   }
@@ -59,19 +118,36 @@ impl FromFilterString for NumberFilterPB {
   where
     Self: Sized,
   {
-    NumberFilterPB {
-      condition: NumberFilterConditionPB::try_from(filter_rev.condition)
-        .unwrap_or(NumberFilterConditionPB::Equal),
-      content: filter_rev.content.clone(),
-    }
+    NumberFilterPB::from(filter_rev)
   }
 }
 impl std::convert::From<&FilterRevision> for NumberFilterPB {
   fn from(rev: &FilterRevision) -> Self {
+    let condition =
+      NumberFilterConditionPB::try_from(rev.condition).unwrap_or(NumberFilterConditionPB::Equal);
+    if condition.is_range() {
+      let content = NumberRangeContentPB::from_str(&rev.content).unwrap_or_default();
+      return NumberFilterPB {
+        condition,
+        content: content.start,
+        end_content: Some(content.end),
+      };
+    }
     NumberFilterPB {
-      condition: NumberFilterConditionPB::try_from(rev.condition)
-        .unwrap_or(NumberFilterConditionPB::Equal),
+      condition,
       content: rev.content.clone(),
+      end_content: None,
     }
   }
 }
+
+impl FilterDataPB for NumberFilterPB {
+  fn into_filter_content(self) -> Result<FilterContent, ErrorCode> {
+    let content = self.to_filter_content();
+    Ok(FilterContent {
+      condition: self.condition as u8,
+      content,
+      case_sensitive: false,
+    })
+  }
+}
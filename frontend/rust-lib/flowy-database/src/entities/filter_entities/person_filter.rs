@@ -0,0 +1,79 @@
+use crate::entities::{FilterContent, FilterDataPB};
+use crate::services::field::PersonCellData;
+use crate::services::filter::FromFilterString;
+use database_model::FilterRevision;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct PersonFilterPB {
+  #[pb(index = 1)]
+  pub condition: PersonFilterConditionPB,
+
+  /// The members that the [PersonFilterConditionPB::Is] and [PersonFilterConditionPB::IsNot]
+  /// look for
+  #[pb(index = 2)]
+  pub user_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum PersonFilterConditionPB {
+  Is = 0,
+  IsNot = 1,
+  ContainsCurrentUser = 2,
+}
+
+impl std::convert::From<PersonFilterConditionPB> for u32 {
+  fn from(value: PersonFilterConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::default::Default for PersonFilterConditionPB {
+  fn default() -> Self {
+    PersonFilterConditionPB::Is
+  }
+}
+
+impl std::convert::TryFrom<u8> for PersonFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    match value {
+      0 => Ok(PersonFilterConditionPB::Is),
+      1 => Ok(PersonFilterConditionPB::IsNot),
+      2 => Ok(PersonFilterConditionPB::ContainsCurrentUser),
+      _ => Err(ErrorCode::InvalidData),
+    }
+  }
+}
+
+impl FromFilterString for PersonFilterPB {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    PersonFilterPB::from(filter_rev)
+  }
+}
+
+impl std::convert::From<&FilterRevision> for PersonFilterPB {
+  fn from(rev: &FilterRevision) -> Self {
+    PersonFilterPB {
+      condition: PersonFilterConditionPB::try_from(rev.condition)
+        .unwrap_or(PersonFilterConditionPB::Is),
+      user_ids: PersonCellData::from(rev.content.clone()).user_ids,
+    }
+  }
+}
+
+impl FilterDataPB for PersonFilterPB {
+  fn into_filter_content(self) -> Result<FilterContent, ErrorCode> {
+    Ok(FilterContent {
+      condition: self.condition as u8,
+      content: PersonCellData::from(self.user_ids).to_string(),
+      case_sensitive: false,
+    })
+  }
+}
@@ -0,0 +1,78 @@
+use crate::entities::{FilterContent, FilterDataPB};
+use crate::services::field::RelationCellData;
+use crate::services::filter::FromFilterString;
+use database_model::FilterRevision;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::ErrorCode;
+
+#[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
+pub struct RelationFilterPB {
+  #[pb(index = 1)]
+  pub condition: RelationFilterConditionPB,
+
+  /// The [RelationFilterConditionPB::Contains] shows the cells that link any of these rows
+  #[pb(index = 2)]
+  pub row_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum RelationFilterConditionPB {
+  IsEmpty = 0,
+  IsNotEmpty = 1,
+  Contains = 2,
+}
+
+impl std::convert::From<RelationFilterConditionPB> for u32 {
+  fn from(value: RelationFilterConditionPB) -> Self {
+    value as u32
+  }
+}
+
+impl std::default::Default for RelationFilterConditionPB {
+  fn default() -> Self {
+    RelationFilterConditionPB::IsNotEmpty
+  }
+}
+
+impl std::convert::TryFrom<u8> for RelationFilterConditionPB {
+  type Error = ErrorCode;
+
+  fn try_from(value: u8) -> Result<Self, Self::Error> {
+    match value {
+      0 => Ok(RelationFilterConditionPB::IsEmpty),
+      1 => Ok(RelationFilterConditionPB::IsNotEmpty),
+      2 => Ok(RelationFilterConditionPB::Contains),
+      _ => Err(ErrorCode::InvalidData),
+    }
+  }
+}
+
+impl FromFilterString for RelationFilterPB {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    RelationFilterPB::from(filter_rev)
+  }
+}
+
+impl std::convert::From<&FilterRevision> for RelationFilterPB {
+  fn from(rev: &FilterRevision) -> Self {
+    RelationFilterPB {
+      condition: RelationFilterConditionPB::try_from(rev.condition)
+        .unwrap_or(RelationFilterConditionPB::IsNotEmpty),
+      row_ids: RelationCellData::from(rev.content.clone()).row_ids,
+    }
+  }
+}
+
+impl FilterDataPB for RelationFilterPB {
+  fn into_filter_content(self) -> Result<FilterContent, ErrorCode> {
+    Ok(FilterContent {
+      condition: self.condition as u8,
+      content: RelationCellData::from(self.row_ids).to_string(),
+      case_sensitive: false,
+    })
+  }
+}
@@ -1,4 +1,5 @@
-use crate::services::field::SelectOptionIds;
+use crate::entities::{FilterContent, FilterDataPB};
+use crate::services::field::{SelectOptionIds, SelectOptionPB};
 use crate::services::filter::FromFilterString;
 use database_model::FilterRevision;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
@@ -13,6 +14,18 @@ pub struct SelectOptionFilterPB {
   pub option_ids: Vec<String>,
 }
 
+impl SelectOptionFilterPB {
+  /// Removes the ids of the options that are not in the `options` anymore, e.g. the options
+  /// were deleted from the field. Returns true if any id was removed.
+  pub fn retain_options(&mut self, options: &[SelectOptionPB]) -> bool {
+    let len = self.option_ids.len();
+    self
+      .option_ids
+      .retain(|option_id| options.iter().any(|option| &option.id == option_id));
+    self.option_ids.len() != len
+  }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
 #[repr(u8)]
 pub enum SelectOptionConditionPB {
@@ -71,3 +84,13 @@ impl std::convert::From<&FilterRevision> for SelectOptionFilterPB {
     }
   }
 }
+
+impl FilterDataPB for SelectOptionFilterPB {
+  fn into_filter_content(self) -> Result<FilterContent, ErrorCode> {
+    Ok(FilterContent {
+      condition: self.condition as u8,
+      content: SelectOptionIds::from(self.option_ids).to_string(),
+      case_sensitive: false,
+    })
+  }
+}
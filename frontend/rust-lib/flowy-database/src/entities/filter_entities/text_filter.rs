@@ -1,3 +1,4 @@
+use crate::entities::{FilterContent, FilterDataPB};
 use crate::services::filter::FromFilterString;
 use database_model::FilterRevision;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
@@ -10,6 +11,11 @@ pub struct TextFilterPB {
 
   #[pb(index = 2)]
   pub content: String,
+
+  /// The text is compared without the case unless it's set, including the regex and the
+  /// wildcard patterns.
+  #[pb(index = 3)]
+  pub case_sensitive: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
@@ -23,6 +29,11 @@ pub enum TextFilterConditionPB {
   EndsWith = 5,
   TextIsEmpty = 6,
   TextIsNotEmpty = 7,
+  /// The content is a regular expression that matches any part of the text
+  MatchesRegex = 8,
+  /// The content is a pattern that matches the whole text, `*` matches any sequence of
+  /// characters and `?` matches a single character.
+  MatchesWildcard = 9,
 }
 
 impl std::convert::From<TextFilterConditionPB> for u32 {
@@ -50,6 +61,8 @@ impl std::convert::TryFrom<u8> for TextFilterConditionPB {
       5 => Ok(TextFilterConditionPB::EndsWith),
       6 => Ok(TextFilterConditionPB::TextIsEmpty),
       7 => Ok(TextFilterConditionPB::TextIsNotEmpty),
+      8 => Ok(TextFilterConditionPB::MatchesRegex),
+      9 => Ok(TextFilterConditionPB::MatchesWildcard),
       _ => Err(ErrorCode::InvalidData),
     }
   }
@@ -64,6 +77,7 @@ impl FromFilterString for TextFilterPB {
       condition: TextFilterConditionPB::try_from(filter_rev.condition)
         .unwrap_or(TextFilterConditionPB::Is),
       content: filter_rev.content.clone(),
+      case_sensitive: filter_rev.case_sensitive,
     }
   }
 }
@@ -74,6 +88,17 @@ impl std::convert::From<&FilterRevision> for TextFilterPB {
       condition: TextFilterConditionPB::try_from(rev.condition)
         .unwrap_or(TextFilterConditionPB::Is),
       content: rev.content.clone(),
+      case_sensitive: rev.case_sensitive,
     }
   }
 }
+
+impl FilterDataPB for TextFilterPB {
+  fn into_filter_content(self) -> Result<FilterContent, ErrorCode> {
+    Ok(FilterContent {
+      condition: self.condition as u8,
+      content: self.content,
+      case_sensitive: self.case_sensitive,
+    })
+  }
+}
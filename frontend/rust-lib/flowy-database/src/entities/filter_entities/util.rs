@@ -1,17 +1,40 @@
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::{
-  CheckboxFilterPB, ChecklistFilterPB, DateFilterContentPB, DateFilterPB, FieldType,
-  NumberFilterPB, SelectOptionFilterPB, TextFilterPB,
+  EmptyFilterConditionPB, FieldComparisonConditionPB, FieldComparisonPB, FieldType,
 };
-use crate::services::field::SelectOptionIds;
+use crate::services::field::get_type_option_plugin;
 use crate::services::filter::FilterType;
 use bytes::Bytes;
 use database_model::{FieldRevision, FieldTypeRevision, FilterRevision};
 use flowy_derive::ProtoBuf;
 use flowy_error::ErrorCode;
+use protobuf::ProtobufError;
 use std::convert::TryInto;
 use std::sync::Arc;
 
+/// The filter data of a field type that is sent to the client, e.g. the [TextFilterPB] of the
+/// text field. It's registered with the type option of the field type, see
+/// [crate::services::field::TypeOptionRegistry].
+///
+/// [TextFilterPB]: crate::entities::TextFilterPB
+pub trait FilterDataPB:
+  for<'a> From<&'a FilterRevision>
+  + TryInto<Bytes, Error = ProtobufError>
+  + TryFrom<Bytes, Error = ProtobufError>
+{
+  /// Returns the condition and the content that the filter is saved with.
+  fn into_filter_content(self) -> Result<FilterContent, ErrorCode>;
+}
+
+/// The condition and the content of the [FilterRevision] that is parsed from the
+/// [FilterDataPB].
+#[derive(Debug, Default)]
+pub struct FilterContent {
+  pub condition: u8,
+  pub content: String,
+  pub case_sensitive: bool,
+}
+
 #[derive(Eq, PartialEq, ProtoBuf, Debug, Default, Clone)]
 pub struct FilterPB {
   #[pb(index = 1)]
@@ -25,26 +48,36 @@ pub struct FilterPB {
 
   #[pb(index = 4)]
   pub data: Vec<u8>,
+
+  /// Set if the filter uses a condition that works for every field type, the `data` is the
+  /// default filter of the field type then.
+  #[pb(index = 5, one_of)]
+  pub empty_condition: Option<EmptyFilterConditionPB>,
+
+  /// Set if the filter compares the field with another field, the `data` is the default filter
+  /// of the field type then.
+  #[pb(index = 6, one_of)]
+  pub comparison: Option<FieldComparisonPB>,
+
+  /// Set if the filter is only applied for the current session, it's not saved in the view
+  /// until it gets saved explicitly.
+  #[pb(index = 7)]
+  pub is_session: bool,
 }
 
 impl std::convert::From<&FilterRevision> for FilterPB {
   fn from(rev: &FilterRevision) -> Self {
-    let field_type: FieldType = rev.field_type.into();
-    let bytes: Bytes = match field_type {
-      FieldType::RichText => TextFilterPB::from(rev).try_into().unwrap(),
-      FieldType::Number => NumberFilterPB::from(rev).try_into().unwrap(),
-      FieldType::DateTime => DateFilterPB::from(rev).try_into().unwrap(),
-      FieldType::SingleSelect => SelectOptionFilterPB::from(rev).try_into().unwrap(),
-      FieldType::MultiSelect => SelectOptionFilterPB::from(rev).try_into().unwrap(),
-      FieldType::Checklist => ChecklistFilterPB::from(rev).try_into().unwrap(),
-      FieldType::Checkbox => CheckboxFilterPB::from(rev).try_into().unwrap(),
-      FieldType::URL => TextFilterPB::from(rev).try_into().unwrap(),
-    };
+    let data = get_type_option_plugin(rev.field_type)
+      .map(|plugin| plugin.filter_data(rev))
+      .unwrap_or_default();
     Self {
       id: rev.id.clone(),
       field_id: rev.field_id.clone(),
       field_type: rev.field_type.into(),
-      data: bytes.to_vec(),
+      data,
+      empty_condition: EmptyFilterConditionPB::try_from(rev.condition).ok(),
+      comparison: FieldComparisonPB::from_filter_rev(rev),
+      is_session: false,
     }
   }
 }
@@ -119,6 +152,36 @@ pub struct DeleteFilterParams {
   pub filter_id: String,
 }
 
+/// Identifies the session filter of the view, e.g. to save it in the view.
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct SessionFilterIdPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub filter_id: String,
+}
+
+impl TryInto<SessionFilterIdParams> for SessionFilterIdPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<SessionFilterIdParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?
+      .0;
+    let filter_id = NotEmptyStr::parse(self.filter_id)
+      .map_err(|_| ErrorCode::FilterIdIsEmpty)?
+      .0;
+    Ok(SessionFilterIdParams { view_id, filter_id })
+  }
+}
+
+#[derive(Debug)]
+pub struct SessionFilterIdParams {
+  pub view_id: String,
+  pub filter_id: String,
+}
+
 #[derive(ProtoBuf, Debug, Default, Clone)]
 pub struct AlterFilterPayloadPB {
   #[pb(index = 1)]
@@ -136,6 +199,14 @@ pub struct AlterFilterPayloadPB {
 
   #[pb(index = 5)]
   pub view_id: String,
+
+  /// The condition that works for every field type, the `data` is ignored if it's set
+  #[pb(index = 6, one_of)]
+  pub empty_condition: Option<EmptyFilterConditionPB>,
+
+  /// Compares the field with another field of the same type, the `data` is ignored if it's set
+  #[pb(index = 7, one_of)]
+  pub comparison: Option<FieldComparisonPB>,
 }
 
 impl AlterFilterPayloadPB {
@@ -152,6 +223,44 @@ impl AlterFilterPayloadPB {
       field_type: field_rev.ty.into(),
       filter_id: None,
       data: data.to_vec(),
+      empty_condition: None,
+      comparison: None,
+    }
+  }
+
+  pub fn new_empty_filter(
+    view_id: &str,
+    field_rev: &FieldRevision,
+    condition: EmptyFilterConditionPB,
+  ) -> Self {
+    Self {
+      view_id: view_id.to_owned(),
+      field_id: field_rev.id.clone(),
+      field_type: field_rev.ty.into(),
+      filter_id: None,
+      data: vec![],
+      empty_condition: Some(condition),
+      comparison: None,
+    }
+  }
+
+  pub fn new_field_comparison(
+    view_id: &str,
+    field_rev: &FieldRevision,
+    condition: FieldComparisonConditionPB,
+    other_field_id: &str,
+  ) -> Self {
+    Self {
+      view_id: view_id.to_owned(),
+      field_id: field_rev.id.clone(),
+      field_type: field_rev.ty.into(),
+      filter_id: None,
+      data: vec![],
+      empty_condition: None,
+      comparison: Some(FieldComparisonPB {
+        condition,
+        other_field_id: other_field_id.to_owned(),
+      }),
     }
   }
 }
@@ -175,42 +284,42 @@ impl TryInto<AlterFilterParams> for AlterFilterPayloadPB {
           .0,
       ),
     };
-    let condition;
-    let mut content = "".to_string();
-    let bytes: &[u8] = self.data.as_ref();
-
-    match self.field_type {
-      FieldType::RichText | FieldType::URL => {
-        let filter = TextFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
-        condition = filter.condition as u8;
-        content = filter.content;
-      },
-      FieldType::Checkbox => {
-        let filter = CheckboxFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
-        condition = filter.condition as u8;
-      },
-      FieldType::Number => {
-        let filter = NumberFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
-        condition = filter.condition as u8;
-        content = filter.content;
-      },
-      FieldType::DateTime => {
-        let filter = DateFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
-        condition = filter.condition as u8;
-        content = DateFilterContentPB {
-          start: filter.start,
-          end: filter.end,
-          timestamp: filter.timestamp,
-        }
-        .to_string();
-      },
-      FieldType::SingleSelect | FieldType::MultiSelect | FieldType::Checklist => {
-        let filter = SelectOptionFilterPB::try_from(bytes).map_err(|_| ErrorCode::ProtobufSerde)?;
-        condition = filter.condition as u8;
-        content = SelectOptionIds::from(filter.option_ids).to_string();
-      },
+    if let Some(empty_condition) = self.empty_condition {
+      return Ok(AlterFilterParams {
+        view_id,
+        field_id,
+        filter_id,
+        field_type: self.field_type.into(),
+        condition: empty_condition as u8,
+        content: "".to_owned(),
+        case_sensitive: false,
+        other_field_id: None,
+      });
+    }
+
+    if let Some(comparison) = self.comparison {
+      let other_field_id = NotEmptyStr::parse(comparison.other_field_id)
+        .map_err(|_| ErrorCode::FieldIdIsEmpty)?
+        .0;
+      return Ok(AlterFilterParams {
+        view_id,
+        field_id,
+        filter_id,
+        field_type: self.field_type.into(),
+        condition: comparison.condition as u8,
+        content: "".to_owned(),
+        case_sensitive: false,
+        other_field_id: Some(other_field_id),
+      });
     }
 
+    let plugin = get_type_option_plugin(&self.field_type).ok_or(ErrorCode::InvalidData)?;
+    let FilterContent {
+      condition,
+      content,
+      case_sensitive,
+    } = plugin.filter_content(Bytes::from(self.data))?;
+
     Ok(AlterFilterParams {
       view_id,
       field_id,
@@ -218,6 +327,8 @@ impl TryInto<AlterFilterParams> for AlterFilterPayloadPB {
       field_type: self.field_type.into(),
       condition,
       content,
+      case_sensitive,
+      other_field_id: None,
     })
   }
 }
@@ -231,4 +342,7 @@ pub struct AlterFilterParams {
   pub field_type: FieldTypeRevision,
   pub condition: u8,
   pub content: String,
+  pub case_sensitive: bool,
+  /// Set if the filter compares the field with this field, see [FieldComparisonPB].
+  pub other_field_id: Option<String>,
 }
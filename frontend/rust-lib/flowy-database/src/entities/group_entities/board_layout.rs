@@ -0,0 +1,11 @@
+use flowy_derive::ProtoBuf;
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct ExportedBoardLayoutPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// The JSON of the [BoardLayout](crate::services::group::BoardLayout)
+  #[pb(index = 2)]
+  pub json: String,
+}
@@ -1,5 +1,6 @@
 use crate::entities::parser::NotEmptyStr;
 use crate::entities::{FieldType, RowPB};
+use crate::services::field::SelectOptionColorPB;
 use crate::services::group::Group;
 use database_model::{FieldTypeRevision, GroupConfigurationRevision};
 use flowy_derive::ProtoBuf;
@@ -63,6 +64,11 @@ pub struct GroupPB {
 
   #[pb(index = 6)]
   pub is_visible: bool,
+
+  /// The color of the select option that the group is generated from, None for the other
+  /// field types
+  #[pb(index = 7, one_of)]
+  pub color: Option<SelectOptionColorPB>,
 }
 
 impl std::convert::From<Group> for GroupPB {
@@ -74,6 +80,7 @@ impl std::convert::From<Group> for GroupPB {
       rows: group.rows,
       is_default: group.is_default,
       is_visible: group.is_visible,
+      color: group.color,
     }
   }
 }
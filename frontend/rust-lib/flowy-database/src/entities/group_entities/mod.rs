@@ -1,7 +1,11 @@
+mod board_layout;
 mod configuration;
 mod group;
 mod group_changeset;
+mod swimlane;
 
+pub use board_layout::*;
 pub use configuration::*;
 pub use group::*;
 pub use group_changeset::*;
+pub use swimlane::*;
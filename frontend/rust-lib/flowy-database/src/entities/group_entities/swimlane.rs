@@ -0,0 +1,82 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::RowPB;
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+use std::convert::TryInto;
+
+/// The rows of a board that are in both the group (the column) and the swimlane (the row of
+/// the board).
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct SwimlaneCellPB {
+  #[pb(index = 1)]
+  pub group_id: String,
+
+  #[pb(index = 2)]
+  pub rows: Vec<RowPB>,
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct SwimlanePB {
+  #[pb(index = 1)]
+  pub swimlane_id: String,
+
+  #[pb(index = 2)]
+  pub desc: String,
+
+  #[pb(index = 3)]
+  pub is_default: bool,
+
+  /// The cells are in the same order as the groups of the board
+  #[pb(index = 4)]
+  pub cells: Vec<SwimlaneCellPB>,
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct SwimlaneBoardPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub group_field_id: String,
+
+  /// Empty if the board has no swimlanes
+  #[pb(index = 3)]
+  pub swimlane_field_id: String,
+
+  #[pb(index = 4)]
+  pub swimlanes: Vec<SwimlanePB>,
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct SwimlaneFieldPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// Removes the swimlanes if it's None
+  #[pb(index = 2, one_of)]
+  pub field_id: Option<String>,
+}
+
+impl TryInto<SwimlaneFieldParams> for SwimlaneFieldPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<SwimlaneFieldParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    let field_id = match self.field_id {
+      None => None,
+      Some(field_id) => Some(
+        NotEmptyStr::parse(field_id)
+          .map_err(|_| ErrorCode::FieldIdIsEmpty)?
+          .0,
+      ),
+    };
+    Ok(SwimlaneFieldParams { view_id, field_id })
+  }
+}
+
+pub struct SwimlaneFieldParams {
+  pub view_id: String,
+  pub field_id: Option<String>,
+}
@@ -1,5 +1,8 @@
+mod accessibility_entities;
+mod automation_entities;
 mod calendar_entities;
 mod cell_entities;
+mod change_entities;
 mod database_entities;
 mod field_entities;
 pub mod filter_entities;
@@ -10,8 +13,11 @@ pub mod setting_entities;
 mod sort_entities;
 mod view_entities;
 
+pub use accessibility_entities::*;
+pub use automation_entities::*;
 pub use calendar_entities::*;
 pub use cell_entities::*;
+pub use change_entities::*;
 pub use database_entities::*;
 pub use database_entities::*;
 pub use field_entities::*;
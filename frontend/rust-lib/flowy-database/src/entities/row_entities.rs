@@ -1,7 +1,8 @@
 use crate::entities::parser::NotEmptyStr;
+use crate::entities::{FieldType, RowAccessibilityPB};
 
 use database_model::RowRevision;
-use flowy_derive::ProtoBuf;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -17,6 +18,11 @@ pub struct RowPB {
 
   #[pb(index = 3)]
   pub height: i32,
+
+  /// The text summary of the row for the screen readers. It's only included when the row is
+  /// read by the [DatabaseEvent::GetRow] event.
+  #[pb(index = 4, one_of)]
+  pub accessibility: Option<RowAccessibilityPB>,
 }
 
 impl RowPB {
@@ -35,6 +41,7 @@ impl std::convert::From<&RowRevision> for RowPB {
       block_id: rev.block_id.clone(),
       id: rev.id.clone(),
       height: rev.height,
+      accessibility: None,
     }
   }
 }
@@ -45,6 +52,7 @@ impl std::convert::From<&mut RowRevision> for RowPB {
       block_id: rev.block_id.clone(),
       id: rev.id.clone(),
       height: rev.height,
+      accessibility: None,
     }
   }
 }
@@ -55,6 +63,7 @@ impl std::convert::From<&Arc<RowRevision>> for RowPB {
       block_id: rev.block_id.clone(),
       id: rev.id.clone(),
       height: rev.height,
+      accessibility: None,
     }
   }
 }
@@ -184,6 +193,10 @@ pub struct CreateRowPayloadPB {
 
   #[pb(index = 4, one_of)]
   pub data: Option<RowDataPB>,
+
+  /// The swimlane of the board that the row is created in
+  #[pb(index = 5, one_of)]
+  pub swimlane_id: Option<String>,
 }
 
 #[derive(ProtoBuf, Default)]
@@ -197,6 +210,7 @@ pub struct CreateRowParams {
   pub view_id: String,
   pub start_row_id: Option<String>,
   pub group_id: Option<String>,
+  pub swimlane_id: Option<String>,
   pub cell_data_by_field_id: Option<HashMap<String, String>>,
 }
 
@@ -218,7 +232,247 @@ impl TryInto<CreateRowParams> for CreateRowPayloadPB {
       view_id: view_id.0,
       start_row_id,
       group_id: self.group_id,
+      swimlane_id: self.swimlane_id,
       cell_data_by_field_id: self.data.map(|data| data.cell_data_by_field_id),
     })
   }
 }
+
+/// [TrashedRowPB] describes a row that was moved to the trash of the database.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct TrashedRowPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  /// The display string of the row's primary cell
+  #[pb(index = 2)]
+  pub primary_cell: String,
+
+  /// The timestamp in seconds when the row was moved to the trash
+  #[pb(index = 3)]
+  pub deleted_at: i64,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedTrashedRowPB {
+  #[pb(index = 1)]
+  pub items: Vec<TrashedRowPB>,
+}
+
+impl std::convert::From<Vec<TrashedRowPB>> for RepeatedTrashedRowPB {
+  fn from(items: Vec<TrashedRowPB>) -> Self {
+    Self { items }
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowDisplayValuesPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// The ids of the rows, all the rows of the database are returned if it's empty.
+  #[pb(index = 2)]
+  pub row_ids: Vec<String>,
+}
+
+pub struct RowDisplayValuesParams {
+  pub view_id: String,
+  pub row_ids: Vec<String>,
+}
+
+impl TryInto<RowDisplayValuesParams> for RowDisplayValuesPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<RowDisplayValuesParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::ViewIdIsInvalid)?;
+    Ok(RowDisplayValuesParams {
+      view_id: view_id.0,
+      row_ids: self.row_ids,
+    })
+  }
+}
+
+/// [RowDisplayValuePB] carries the display string of the row's primary cell, it can be used as
+/// the name of the row.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowDisplayValuePB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub display_value: String,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedRowDisplayValuePB {
+  #[pb(index = 1)]
+  pub items: Vec<RowDisplayValuePB>,
+}
+
+impl std::convert::From<Vec<RowDisplayValuePB>> for RepeatedRowDisplayValuePB {
+  fn from(items: Vec<RowDisplayValuePB>) -> Self {
+    Self { items }
+  }
+}
+
+/// [RowBacklinkPB] describes the text cell of a row that mentions another row, it's shown as
+/// "mentioned in" of the mentioned row.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowBacklinkPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  /// The display string of the primary cell of the row that contains the mention
+  #[pb(index = 3)]
+  pub display_value: String,
+
+  /// The database of the row that contains the mention, the row might be mentioned in the
+  /// other databases
+  #[pb(index = 4)]
+  pub database_id: String,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedRowBacklinkPB {
+  #[pb(index = 1)]
+  pub items: Vec<RowBacklinkPB>,
+}
+
+impl std::convert::From<Vec<RowBacklinkPB>> for RepeatedRowBacklinkPB {
+  fn from(items: Vec<RowBacklinkPB>) -> Self {
+    Self { items }
+  }
+}
+
+/// [RowSharePB] describes a read-only link of a row. The link stays active until it's revoked by
+/// the [RevokeRowShare] event. The shares are kept on this device, so the link is only resolved
+/// by the [GetSharedRow] event of this device, it's not published to the cloud.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowSharePB {
+  #[pb(index = 1)]
+  pub share_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  pub link: String,
+
+  /// The timestamp in seconds when the row was shared
+  #[pb(index = 4)]
+  pub created_at: i64,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RepeatedRowSharePB {
+  #[pb(index = 1)]
+  pub items: Vec<RowSharePB>,
+}
+
+impl std::convert::From<Vec<RowSharePB>> for RepeatedRowSharePB {
+  fn from(items: Vec<RowSharePB>) -> Self {
+    Self { items }
+  }
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowShareIdPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub share_id: String,
+}
+
+pub struct RowShareIdParams {
+  pub view_id: String,
+  pub share_id: String,
+}
+
+impl TryInto<RowShareIdParams> for RowShareIdPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<RowShareIdParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+    let share_id =
+      NotEmptyStr::parse(self.share_id).map_err(|_| ErrorCode::UnexpectedEmptyString)?;
+    Ok(RowShareIdParams {
+      view_id: view_id.0,
+      share_id: share_id.0,
+    })
+  }
+}
+
+/// [SharedRowPB] is the read-only content of the row that the link of [RowSharePB] points to.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SharedRowPB {
+  #[pb(index = 1)]
+  pub share: RowSharePB,
+
+  /// The visible fields of the row in the order of the fields
+  #[pb(index = 2)]
+  pub cells: Vec<SharedCellPB>,
+
+  /// The id of the document of the row, None if the row has no document
+  #[pb(index = 3, one_of)]
+  pub document_id: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SharedCellPB {
+  #[pb(index = 1)]
+  pub field_id: String,
+
+  #[pb(index = 2)]
+  pub field_name: String,
+
+  #[pb(index = 3)]
+  pub field_type: FieldType,
+
+  #[pb(index = 4)]
+  pub display_value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum RowIdCollisionReasonPB {
+  /// The id is used by another row of the database.
+  DuplicatedInDatabase = 0,
+  /// The id is used by a row of another database, so the row can't be found by its id.
+  UsedByOtherDatabase = 1,
+}
+
+impl std::default::Default for RowIdCollisionReasonPB {
+  fn default() -> Self {
+    RowIdCollisionReasonPB::DuplicatedInDatabase
+  }
+}
+
+/// The row whose id collides with another row. The rows created offline by the older versions
+/// might have the same id, see [gen_row_id](database_model::gen_row_id).
+#[derive(Debug, Clone, Default, PartialEq, Eq, ProtoBuf)]
+pub struct RowIdCollisionPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub block_id: String,
+
+  /// The index of the row in its block. The rows with the same id are told apart by it.
+  #[pb(index = 3)]
+  pub index: i32,
+
+  #[pb(index = 4)]
+  pub reason: RowIdCollisionReasonPB,
+}
+
+#[derive(Debug, Clone, Default, ProtoBuf)]
+pub struct RowIdCollisionsPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub items: Vec<RowIdCollisionPB>,
+}
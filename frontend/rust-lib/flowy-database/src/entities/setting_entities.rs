@@ -2,10 +2,11 @@ use crate::entities::parser::NotEmptyStr;
 use crate::entities::{
   AlterFilterParams, AlterFilterPayloadPB, AlterSortParams, AlterSortPayloadPB,
   CalendarLayoutSettingsPB, DeleteFilterParams, DeleteFilterPayloadPB, DeleteGroupParams,
-  DeleteGroupPayloadPB, DeleteSortParams, DeleteSortPayloadPB, InsertGroupParams,
-  InsertGroupPayloadPB, RepeatedFilterPB, RepeatedGroupConfigurationPB, RepeatedSortPB,
+  DeleteGroupPayloadPB, DeleteSortParams, DeleteSortPayloadPB, GroupConfigurationPB,
+  InsertGroupParams, InsertGroupPayloadPB, RepeatedFilterPB, RepeatedGroupConfigurationPB,
+  RepeatedSortPB,
 };
-use database_model::{CalendarLayoutSetting, LayoutRevision};
+use database_model::{CalendarLayoutSetting, GridLayoutSetting, LayoutRevision};
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::ErrorCode;
 use std::convert::TryInto;
@@ -28,6 +29,13 @@ pub struct DatabaseViewSettingPB {
 
   #[pb(index = 5)]
   pub sorts: RepeatedSortPB,
+
+  #[pb(index = 6)]
+  pub description: String,
+
+  /// The group configuration of the swimlanes. None if the board has no swimlanes.
+  #[pb(index = 7, one_of)]
+  pub swimlane_configuration: Option<GroupConfigurationPB>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum, EnumIter)]
@@ -195,6 +203,9 @@ impl TryInto<UpdateLayoutSettingParams> for UpdateLayoutSettingPB {
 pub struct LayoutSettingPB {
   #[pb(index = 1, one_of)]
   pub calendar: Option<CalendarLayoutSettingsPB>,
+
+  #[pb(index = 2, one_of)]
+  pub grid: Option<GridLayoutSettingPB>,
 }
 
 impl LayoutSettingPB {
@@ -207,6 +218,7 @@ impl std::convert::From<LayoutSettingParams> for LayoutSettingPB {
   fn from(params: LayoutSettingParams) -> Self {
     Self {
       calendar: params.calendar.map(|calender| calender.into()),
+      grid: params.grid.map(|grid| grid.into()),
     }
   }
 }
@@ -215,6 +227,7 @@ impl std::convert::From<LayoutSettingPB> for LayoutSettingParams {
   fn from(params: LayoutSettingPB) -> Self {
     Self {
       calendar: params.calendar.map(|calender| calender.into()),
+      grid: params.grid.map(|grid| grid.into()),
     }
   }
 }
@@ -222,4 +235,90 @@ impl std::convert::From<LayoutSettingPB> for LayoutSettingParams {
 #[derive(Debug, Default, Clone)]
 pub struct LayoutSettingParams {
   pub calendar: Option<CalendarLayoutSetting>,
+  pub grid: Option<GridLayoutSetting>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Default, ProtoBuf)]
+pub struct GridLayoutSettingPB {
+  /// The number of the leading columns that stay visible when the grid is scrolled horizontally
+  #[pb(index = 1)]
+  pub frozen_column_count: i32,
+}
+
+impl std::convert::From<GridLayoutSettingPB> for GridLayoutSetting {
+  fn from(pb: GridLayoutSettingPB) -> Self {
+    GridLayoutSetting {
+      frozen_column_count: pb.frozen_column_count,
+    }
+  }
+}
+
+impl std::convert::From<GridLayoutSetting> for GridLayoutSettingPB {
+  fn from(setting: GridLayoutSetting) -> Self {
+    GridLayoutSettingPB {
+      frozen_column_count: setting.frozen_column_count,
+    }
+  }
+}
+
+#[derive(Debug, Default, ProtoBuf)]
+pub struct FrozenColumnCountPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub frozen_column_count: i32,
+}
+
+pub struct FrozenColumnCountParams {
+  pub view_id: String,
+  pub frozen_column_count: i32,
+}
+
+impl TryInto<FrozenColumnCountParams> for FrozenColumnCountPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<FrozenColumnCountParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    if self.frozen_column_count < 0 {
+      return Err(ErrorCode::InvalidData);
+    }
+
+    Ok(FrozenColumnCountParams {
+      view_id,
+      frozen_column_count: self.frozen_column_count,
+    })
+  }
+}
+
+/// The description of the database view, it's shown in the header of the view.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct DatabaseViewDescriptionPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// The rich text serialized by the client. An empty string means no description.
+  #[pb(index = 2)]
+  pub description: String,
+}
+
+pub struct DatabaseViewDescriptionParams {
+  pub view_id: String,
+  pub description: String,
+}
+
+impl TryInto<DatabaseViewDescriptionParams> for DatabaseViewDescriptionPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<DatabaseViewDescriptionParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id)
+      .map_err(|_| ErrorCode::ViewIdIsInvalid)?
+      .0;
+    Ok(DatabaseViewDescriptionParams {
+      view_id,
+      description: self.description,
+    })
+  }
 }
@@ -1,5 +1,7 @@
-use crate::entities::{InsertedRowPB, UpdatedRowPB};
-use flowy_derive::ProtoBuf;
+use crate::entities::{FilterPB, InsertedRowPB, SortPB, UpdatedRowPB};
+use crate::services::filter::FilteredRowCount;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::time::Duration;
 
 #[derive(Debug, Default, Clone, ProtoBuf)]
 pub struct RowsVisibilityChangesetPB {
@@ -11,6 +13,33 @@ pub struct RowsVisibilityChangesetPB {
 
   #[pb(index = 6)]
   pub invisible_rows: Vec<String>,
+
+  #[pb(index = 7)]
+  pub row_count: FilteredRowCountPB,
+}
+
+/// The number of the visible and the hidden rows of the view after filtering, e.g. to show
+/// "42 of 300 rows" without loading the rows.
+#[derive(Debug, Default, Clone, PartialEq, Eq, ProtoBuf)]
+pub struct FilteredRowCountPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub visible_row_count: i32,
+
+  #[pb(index = 3)]
+  pub hidden_row_count: i32,
+}
+
+impl FilteredRowCountPB {
+  pub fn new(view_id: &str, row_count: &FilteredRowCount) -> Self {
+    Self {
+      view_id: view_id.to_owned(),
+      visible_row_count: row_count.visible as i32,
+      hidden_row_count: row_count.hidden as i32,
+    }
+  }
 }
 
 #[derive(Debug, Default, Clone, ProtoBuf)]
@@ -66,3 +95,96 @@ impl RowsChangesetPB {
     }
   }
 }
+
+/// Explains why the row is hidden in the view or positioned where it is.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RowDiagnosticsPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub row_id: String,
+
+  #[pb(index = 3)]
+  pub is_visible: bool,
+
+  /// The index of the row after applying the filters and sorts. None if the row is hidden.
+  #[pb(index = 4, one_of)]
+  pub index: Option<i32>,
+
+  #[pb(index = 5)]
+  pub filters: Vec<FilterDiagnosticPB>,
+
+  /// The sorts in the order that they are applied, each of them is applied to the rows sorted by
+  /// the previous ones.
+  #[pb(index = 6)]
+  pub sorts: Vec<SortDiagnosticPB>,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct FilterDiagnosticPB {
+  #[pb(index = 1)]
+  pub filter: FilterPB,
+
+  /// False if the row is hidden by this filter
+  #[pb(index = 2)]
+  pub is_visible: bool,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct SortDiagnosticPB {
+  #[pb(index = 1)]
+  pub sort: SortPB,
+
+  /// The content of the row's cell that is used as the sort key
+  #[pb(index = 2)]
+  pub cell_content: String,
+}
+
+/// The result of running the pipelines of the view again from scratch.
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RecomputeViewResultPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// The stages in the order that they were run
+  #[pb(index = 2)]
+  pub stages: Vec<RecomputeStagePB>,
+
+  #[pb(index = 3)]
+  pub elapsed_micros: i64,
+}
+
+#[derive(Debug, Default, Clone, ProtoBuf)]
+pub struct RecomputeStagePB {
+  #[pb(index = 1)]
+  pub stage: RecomputeStageTypePB,
+
+  #[pb(index = 2)]
+  pub elapsed_micros: i64,
+}
+
+impl RecomputeStagePB {
+  pub fn new(stage: RecomputeStageTypePB, elapsed: Duration) -> Self {
+    Self {
+      stage,
+      elapsed_micros: elapsed.as_micros() as i64,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum RecomputeStageTypePB {
+  /// The rollup and the formula cells of the database
+  Calculation = 0,
+  Filter = 1,
+  Sort = 2,
+  /// The groups and the swimlanes of the board
+  Group = 3,
+}
+
+impl std::default::Default for RecomputeStageTypePB {
+  fn default() -> Self {
+    RecomputeStageTypePB::Calculation
+  }
+}
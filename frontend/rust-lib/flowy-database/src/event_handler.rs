@@ -3,13 +3,26 @@ use crate::manager::DatabaseManager;
 use crate::services::cell::{FromCellString, ToCellChangesetString, TypeCellData};
 use crate::services::field::{
   default_type_option_builder_from_type, select_type_option_from_field_rev,
-  type_option_builder_from_json_str, DateCellChangeset, DateChangesetPB, SelectOptionCellChangeset,
+  type_option_builder_from_json_str, AttachFileParams, AttachFilePayloadPB, AttachmentIdPB,
+  AttachmentIdParams, AttachmentPB, ChecklistCellChangesetPB, ChecklistCellChangesetParams,
+  ChecklistCellDataPB, ChecklistItemDueChangeset, ChecklistItemDueChangesetPB, DateCellChangeset,
+  DateCellDataPB, DateCellDataParser, DateChangesetPB, ExportSelectOptionsPayloadPB,
+  GroupProgressPayloadPB, ImportSelectOptionsParams, ImportSelectOptionsPayloadPB,
+  LocationCellChangesetPB, LocationCellChangesetParams, MergeSelectOptionsParams,
+  MergeSelectOptionsPayloadPB, MoveChecklistItemParams, MoveChecklistItemPayloadPB,
+  MoveSelectOptionParams, MoveSelectOptionPayloadPB, PersonCellChangeset, PersonCellChangesetPB,
+  PersonCellChangesetParams, RelationCellChangeset, RelationCellChangesetPB,
+  RelationCellChangesetParams, RelationDependentsParams, RelationDependentsPayloadPB,
+  RepeatedChecklistReminderPB, RepeatedGroupProgressPB, RepeatedRelationDependentPB,
+  RepeatedSelectOptionPB, ResolvedAttachmentPB, SelectOptionCellChangeset,
   SelectOptionCellChangesetPB, SelectOptionCellChangesetParams, SelectOptionCellDataPB,
   SelectOptionChangeset, SelectOptionChangesetPB, SelectOptionIds, SelectOptionPB,
+  SelectOptionUsagePB, SelectOptionUsagePayloadPB, TextCellDataPB, TimeTrackingCellChangesetPB,
+  TimeTrackingCellChangesetParams, TimeTrackingSumPB, TimeTrackingSumPayloadPB,
+  UpdateSelectOptionColorParams, UpdateSelectOptionColorPayloadPB,
 };
-use crate::services::row::make_row_from_row_rev;
-use database_model::FieldRevision;
-use flowy_error::{ErrorCode, FlowyError, FlowyResult};
+use database_model::{FieldRevision, GridLayoutSetting};
+use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
 use lib_dispatch::prelude::{data_result_ok, AFPluginData, AFPluginState, DataResult};
 use std::sync::Arc;
 
@@ -81,6 +94,72 @@ pub(crate) async fn get_all_filters_handler(
   data_result_ok(filters)
 }
 
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_filter_presets_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedFilterPresetPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let editor = manager.open_database_view(view_id.as_ref()).await?;
+  let presets = editor.get_filter_presets(view_id.as_ref()).await?;
+  data_result_ok(presets)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn save_filter_preset_handler(
+  data: AFPluginData<SaveFilterPresetPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<FilterPresetPB, FlowyError> {
+  let params: SaveFilterPresetParams = data.into_inner().try_into()?;
+  let editor = manager.open_database_view(&params.view_id).await?;
+  let preset = editor.save_filter_preset(params).await?;
+  data_result_ok(preset)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn apply_filter_preset_handler(
+  data: AFPluginData<FilterPresetIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: FilterPresetIdParams = data.into_inner().try_into()?;
+  let editor = manager.open_database_view(&params.view_id).await?;
+  editor.apply_filter_preset(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn delete_filter_preset_handler(
+  data: AFPluginData<FilterPresetIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: FilterPresetIdParams = data.into_inner().try_into()?;
+  let editor = manager.open_database_view(&params.view_id).await?;
+  editor.delete_filter_preset(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn insert_session_filter_handler(
+  data: AFPluginData<AlterFilterPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: AlterFilterParams = data.into_inner().try_into()?;
+  let editor = manager.open_database_view(&params.view_id).await?;
+  editor.insert_session_filter(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn save_session_filter_handler(
+  data: AFPluginData<SessionFilterIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: SessionFilterIdParams = data.into_inner().try_into()?;
+  let editor = manager.open_database_view(&params.view_id).await?;
+  editor.save_session_filter(params).await?;
+  Ok(())
+}
+
 #[tracing::instrument(level = "trace", skip(data, manager), err)]
 pub(crate) async fn get_all_sorts_handler(
   data: AFPluginData<DatabaseViewIdPB>,
@@ -162,6 +241,28 @@ pub(crate) async fn delete_field_handler(
   Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn restore_field_handler(
+  data: AFPluginData<RestoreFieldPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<FieldPB, FlowyError> {
+  let params: FieldIdParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let field = editor.restore_field(&params.field_id).await?;
+  data_result_ok(field)
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_trashed_fields_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedTrashedFieldPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let editor = manager.get_database_editor(view_id.as_ref()).await?;
+  let trashed_fields = editor.get_trashed_fields().await;
+  data_result_ok(RepeatedTrashedFieldPB::from(trashed_fields))
+}
+
 #[tracing::instrument(level = "debug", skip(data, manager), err)]
 pub(crate) async fn switch_to_field_handler(
   data: AFPluginData<UpdateFieldTypePayloadPB>,
@@ -249,6 +350,22 @@ pub(crate) async fn create_field_type_option_data_handler(
   })
 }
 
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn create_fields_handler(
+  data: AFPluginData<CreateFieldsPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedFieldPB, FlowyError> {
+  let params: CreateFieldsParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let items = editor
+    .create_new_field_revs(params.fields)
+    .await?
+    .into_iter()
+    .map(FieldPB::from)
+    .collect::<Vec<FieldPB>>();
+  data_result_ok(items.into())
+}
+
 #[tracing::instrument(level = "trace", skip(data, manager), err)]
 pub(crate) async fn move_field_handler(
   data: AFPluginData<MoveFieldPayloadPB>,
@@ -287,11 +404,7 @@ pub(crate) async fn get_row_handler(
 ) -> DataResult<OptionalRowPB, FlowyError> {
   let params: RowIdParams = data.into_inner().try_into()?;
   let editor = manager.get_database_editor(&params.view_id).await?;
-  let row = editor
-    .get_row_rev(&params.row_id)
-    .await?
-    .map(make_row_from_row_rev);
-
+  let row = editor.get_row_with_accessibility(&params.row_id).await?;
   data_result_ok(OptionalRowPB { row })
 }
 
@@ -301,11 +414,43 @@ pub(crate) async fn delete_row_handler(
   manager: AFPluginState<Arc<DatabaseManager>>,
 ) -> Result<(), FlowyError> {
   let params: RowIdParams = data.into_inner().try_into()?;
-  let editor = manager.get_database_editor(&params.view_id).await?;
-  editor.delete_row(&params.row_id).await?;
+  manager.delete_row(&params.view_id, &params.row_id).await?;
   Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn restore_row_handler(
+  data: AFPluginData<RowIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RowPB, FlowyError> {
+  let params: RowIdParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let row = editor.restore_row(&params.view_id, &params.row_id).await?;
+  data_result_ok(row)
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_trashed_rows_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedTrashedRowPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let editor = manager.get_database_editor(view_id.as_ref()).await?;
+  let trashed_rows = editor.get_trashed_rows().await?;
+  data_result_ok(RepeatedTrashedRowPB::from(trashed_rows))
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_row_display_values_handler(
+  data: AFPluginData<RowDisplayValuesPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedRowDisplayValuePB, FlowyError> {
+  let params: RowDisplayValuesParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let display_values = editor.get_row_display_values(params.row_ids).await?;
+  data_result_ok(RepeatedRowDisplayValuePB::from(display_values))
+}
+
 #[tracing::instrument(level = "debug", skip(data, manager), err)]
 pub(crate) async fn duplicate_row_handler(
   data: AFPluginData<RowIdPB>,
@@ -354,6 +499,228 @@ pub(crate) async fn get_cell_handler(
   }
 }
 
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_text_cell_handler(
+  data: AFPluginData<CellIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<TextCellDataPB, FlowyError> {
+  let params: CellIdParams = data.into_inner().try_into()?;
+  let text_cell = manager.get_text_cell_data(&params).await?;
+  data_result_ok(text_cell)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn share_row_handler(
+  data: AFPluginData<RowIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RowSharePB, FlowyError> {
+  let params: RowIdParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let share = editor.share_row(&params.view_id, &params.row_id).await?;
+  data_result_ok(share)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_row_shares_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedRowSharePB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let editor = manager.get_database_editor(view_id.as_ref()).await?;
+  let shares = editor.get_row_shares().await?;
+  data_result_ok(shares.into())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn revoke_row_share_handler(
+  data: AFPluginData<RowShareIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: RowShareIdParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  editor.revoke_row_share(&params.share_id)?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_shared_row_handler(
+  data: AFPluginData<RowShareIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<SharedRowPB, FlowyError> {
+  let params: RowShareIdParams = data.into_inner().try_into()?;
+  let shared_row = manager
+    .get_shared_row(&params.view_id, &params.share_id)
+    .await?;
+  data_result_ok(shared_row)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_row_backlinks_handler(
+  data: AFPluginData<RowIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedRowBacklinkPB, FlowyError> {
+  let params: RowIdParams = data.into_inner().try_into()?;
+  let backlinks = manager.get_row_backlinks(&params.row_id).await?;
+  data_result_ok(backlinks.into())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_cell_by_address_handler(
+  data: AFPluginData<CellAddressPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<AddressedCellPB, FlowyError> {
+  let params: CellAddressParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let cell = editor.get_cell_by_address(params).await?;
+  data_result_ok(cell)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_adjacent_cell_handler(
+  data: AFPluginData<MoveCellAddressPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<AddressedCellPB, FlowyError> {
+  let payload = data.into_inner();
+  let params: CellAddressParams = payload.address.try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let cell = editor.get_adjacent_cell(params, payload.direction).await?;
+  data_result_ok(cell)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_invalid_cells_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<InvalidCellsPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let editor = manager.get_database_editor(view_id.as_ref()).await?;
+  let items = editor.get_invalid_cells().await?;
+  data_result_ok(InvalidCellsPB {
+    view_id: view_id.value,
+    items,
+  })
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn repair_invalid_cells_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<InvalidCellsPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let editor = manager.get_database_editor(view_id.as_ref()).await?;
+  let items = editor.repair_invalid_cells().await?;
+  data_result_ok(InvalidCellsPB {
+    view_id: view_id.value,
+    items,
+  })
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_row_id_collisions_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RowIdCollisionsPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let editor = manager.get_database_editor(view_id.as_ref()).await?;
+  let items = editor.get_row_id_collisions().await?;
+  data_result_ok(RowIdCollisionsPB {
+    view_id: view_id.value,
+    items,
+  })
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn repair_row_id_collisions_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RowIdCollisionsPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let items = manager.repair_row_id_collisions(view_id.as_ref()).await?;
+  data_result_ok(RowIdCollisionsPB {
+    view_id: view_id.value,
+    items,
+  })
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_row_diagnostics_handler(
+  data: AFPluginData<RowIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RowDiagnosticsPB, FlowyError> {
+  let params: RowIdParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let diagnostics = editor
+    .get_row_diagnostics(&params.view_id, &params.row_id)
+    .await?;
+  data_result_ok(diagnostics)
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_filtered_row_count_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<FilteredRowCountPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let editor = manager.get_database_editor(view_id.as_ref()).await?;
+  let row_count = editor.get_filtered_row_count(view_id.as_ref()).await?;
+  data_result_ok(row_count)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_automations_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedAutomationPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let editor = manager.open_database_view(view_id.as_ref()).await?;
+  let automations = editor.get_automations(view_id.as_ref()).await?;
+  data_result_ok(automations)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn save_automation_handler(
+  data: AFPluginData<SaveAutomationPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<AutomationPB, FlowyError> {
+  let params: SaveAutomationParams = data.into_inner().try_into()?;
+  let automation = manager.save_automation(params).await?;
+  data_result_ok(automation)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn delete_automation_handler(
+  data: AFPluginData<AutomationIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: AutomationIdParams = data.into_inner().try_into()?;
+  manager.delete_automation(params).await?;
+  Ok(())
+}
+
+/// The max number of the activities that are returned
+const MAX_AUTOMATION_ACTIVITIES: i64 = 100;
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_automation_activities_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedAutomationActivityPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let activities =
+    manager.get_automation_activities(view_id.as_ref(), MAX_AUTOMATION_ACTIVITIES)?;
+  data_result_ok(activities.into())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn recompute_view_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RecomputeViewResultPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let result = manager.recompute_view(view_id.as_ref()).await?;
+  data_result_ok(result)
+}
+
 #[tracing::instrument(level = "trace", skip_all, err)]
 pub(crate) async fn update_cell_handler(
   data: AFPluginData<CellChangesetPB>,
@@ -362,15 +729,27 @@ pub(crate) async fn update_cell_handler(
   let changeset: CellChangesetPB = data.into_inner();
   let editor = manager.get_database_editor(&changeset.view_id).await?;
   editor
-    .update_cell_with_changeset(
+    .update_cell_from_base(
       &changeset.row_id,
       &changeset.field_id,
+      changeset.base_type_cell_data,
       changeset.type_cell_data,
     )
     .await?;
   Ok(())
 }
 
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn resolve_cell_conflict_handler(
+  data: AFPluginData<CellIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: CellIdParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  editor.resolve_cell_conflict(&params.row_id, &params.field_id);
+  Ok(())
+}
+
 #[tracing::instrument(level = "trace", skip_all, err)]
 pub(crate) async fn new_select_option_handler(
   data: AFPluginData<CreateSelectOptionPayloadPB>,
@@ -485,6 +864,121 @@ pub(crate) async fn get_select_option_handler(
   }
 }
 
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_available_select_options_handler(
+  data: AFPluginData<CellIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<SelectOptionCellDataPB, FlowyError> {
+  let params: CellIdParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let cell_data = editor
+    .get_available_select_options(&params.row_id, &params.field_id)
+    .await?;
+  data_result_ok(cell_data)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_select_option_usage_handler(
+  data: AFPluginData<SelectOptionUsagePayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<SelectOptionUsagePB, FlowyError> {
+  let params: FieldIdParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let usage = editor.get_select_option_usage(&params.field_id).await?;
+  data_result_ok(usage)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn cleanup_select_options_handler(
+  data: AFPluginData<SelectOptionUsagePayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<SelectOptionUsagePB, FlowyError> {
+  let params: FieldIdParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let usage = editor
+    .cleanup_select_options(&params.view_id, &params.field_id)
+    .await?;
+  data_result_ok(usage)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn export_select_options_handler(
+  data: AFPluginData<ExportSelectOptionsPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedSelectOptionPB, FlowyError> {
+  let params: FieldIdParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let items = editor.get_select_options(&params.field_id).await?;
+  data_result_ok(RepeatedSelectOptionPB { items })
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn import_select_options_handler(
+  data: AFPluginData<ImportSelectOptionsPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedSelectOptionPB, FlowyError> {
+  let params: ImportSelectOptionsParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let items = editor
+    .import_select_options(&params.view_id, &params.field_id, params.options)
+    .await?;
+  data_result_ok(RepeatedSelectOptionPB { items })
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn update_select_option_color_handler(
+  data: AFPluginData<UpdateSelectOptionColorPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<SelectOptionPB, FlowyError> {
+  let params: UpdateSelectOptionColorParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let option = editor
+    .update_select_option_color(
+      &params.view_id,
+      &params.field_id,
+      &params.option_id,
+      params.color,
+    )
+    .await?;
+  data_result_ok(option)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn move_select_option_handler(
+  data: AFPluginData<MoveSelectOptionPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedSelectOptionPB, FlowyError> {
+  let params: MoveSelectOptionParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let items = editor
+    .move_select_option(
+      &params.view_id,
+      &params.field_id,
+      &params.from_option_id,
+      &params.to_option_id,
+    )
+    .await?;
+  data_result_ok(RepeatedSelectOptionPB { items })
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn merge_select_options_handler(
+  data: AFPluginData<MergeSelectOptionsPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedSelectOptionPB, FlowyError> {
+  let params: MergeSelectOptionsParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let items = editor
+    .merge_select_options_into(
+      &params.view_id,
+      &params.field_id,
+      &params.target_option_id,
+      params.merged_option_ids,
+    )
+    .await?;
+  data_result_ok(RepeatedSelectOptionPB { items })
+}
+
 #[tracing::instrument(level = "trace", skip_all, err)]
 pub(crate) async fn update_select_option_cell_handler(
   data: AFPluginData<SelectOptionCellChangesetPB>,
@@ -497,10 +991,13 @@ pub(crate) async fn update_select_option_cell_handler(
   let changeset = SelectOptionCellChangeset {
     insert_option_ids: params.insert_option_ids,
     delete_option_ids: params.delete_option_ids,
+    insert_option_names: params.insert_option_names,
+    parent_option_ids: None,
   };
 
   editor
-    .update_cell_with_changeset(
+    .update_select_option_cell(
+      &params.cell_identifier.view_id,
       &params.cell_identifier.row_id,
       &params.cell_identifier.field_id,
       changeset,
@@ -509,11 +1006,145 @@ pub(crate) async fn update_select_option_cell_handler(
   Ok(())
 }
 
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn subscribe_database_changes_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<DatabaseChangeSubscriptionPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let database_id = manager.notify_database_changes(view_id.as_ref())?;
+  data_result_ok(DatabaseChangeSubscriptionPB { database_id })
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn unsubscribe_database_changes_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  manager.stop_notifying_database_changes(view_id.as_ref())?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_relation_cell_handler(
+  data: AFPluginData<RelationCellChangesetPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: RelationCellChangesetParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.cell_id.view_id).await?;
+  let changeset = RelationCellChangeset {
+    inserted_row_ids: params.inserted_row_ids,
+    removed_row_ids: params.removed_row_ids,
+  };
+  editor
+    .update_cell_with_changeset(&params.cell_id.row_id, &params.cell_id.field_id, changeset)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_relation_dependents_handler(
+  data: AFPluginData<RelationDependentsPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedRelationDependentPB, FlowyError> {
+  let params: RelationDependentsParams = data.into_inner().try_into()?;
+  let database_id = manager.get_database_id_with_view(&params.view_id)?;
+  let dependents = manager
+    .get_relation_dependents(&database_id, params.row_id.as_deref())
+    .await?;
+  data_result_ok(dependents.into())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_time_tracking_cell_handler(
+  data: AFPluginData<TimeTrackingCellChangesetPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: TimeTrackingCellChangesetParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.cell_id.view_id).await?;
+  editor
+    .update_cell_with_changeset(
+      &params.cell_id.row_id,
+      &params.cell_id.field_id,
+      params.changeset,
+    )
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_location_cell_handler(
+  data: AFPluginData<LocationCellChangesetPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: LocationCellChangesetParams = data.into_inner().try_into()?;
+  manager.update_location_cell(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_person_cell_handler(
+  data: AFPluginData<PersonCellChangesetPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: PersonCellChangesetParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.cell_id.view_id).await?;
+  let changeset = PersonCellChangeset {
+    inserted_user_ids: params.inserted_user_ids,
+    removed_user_ids: params.removed_user_ids,
+  };
+  editor
+    .update_cell_with_changeset(&params.cell_id.row_id, &params.cell_id.field_id, changeset)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn attach_file_handler(
+  data: AFPluginData<AttachFilePayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<AttachmentPB, FlowyError> {
+  let params: AttachFileParams = data.into_inner().try_into()?;
+  let attachment = manager.attach_file(params).await?;
+  data_result_ok(attachment)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn remove_attachment_handler(
+  data: AFPluginData<AttachmentIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: AttachmentIdParams = data.into_inner().try_into()?;
+  manager.remove_attachment(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn resolve_attachment_handler(
+  data: AFPluginData<AttachmentIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<ResolvedAttachmentPB, FlowyError> {
+  let params: AttachmentIdParams = data.into_inner().try_into()?;
+  let resolved_attachment = manager.resolve_attachment(params).await?;
+  data_result_ok(resolved_attachment)
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_related_rows_handler(
+  data: AFPluginData<CellIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedRowDisplayValuePB, FlowyError> {
+  let params: CellIdParams = data.into_inner().try_into()?;
+  let related_rows = manager.get_related_rows(&params).await?;
+  data_result_ok(RepeatedRowDisplayValuePB::from(related_rows))
+}
+
 #[tracing::instrument(level = "trace", skip_all, err)]
 pub(crate) async fn update_date_cell_handler(
   data: AFPluginData<DateChangesetPB>,
   manager: AFPluginState<Arc<DatabaseManager>>,
-) -> Result<(), FlowyError> {
+) -> DataResult<DateCellDataPB, FlowyError> {
   let data = data.into_inner();
   let cell_path: CellIdParams = data.cell_path.try_into()?;
   let cell_changeset = DateCellChangeset {
@@ -521,13 +1152,27 @@ pub(crate) async fn update_date_cell_handler(
     time: data.time,
     include_time: data.include_time,
     is_utc: data.is_utc,
+    text: data.text,
+    locale: data.locale,
+    recurrence: data.recurrence,
+    clear_recurrence: data.clear_recurrence,
   };
 
   let editor = manager.get_database_editor(&cell_path.view_id).await?;
   editor
-    .update_cell(cell_path.row_id, cell_path.field_id, cell_changeset)
+    .update_cell(
+      cell_path.row_id.clone(),
+      cell_path.field_id.clone(),
+      cell_changeset,
+    )
     .await?;
-  Ok(())
+
+  // Returns the saved date, so the user can confirm how the text was interpreted.
+  let date_cell_data = match editor.get_cell_protobuf(&cell_path).await {
+    None => DateCellDataPB::default(),
+    Some(cell_data) => cell_data.parser::<DateCellDataParser>()?,
+  };
+  data_result_ok(date_cell_data)
 }
 
 #[tracing::instrument(level = "trace", skip_all, err)]
@@ -574,6 +1219,149 @@ pub(crate) async fn move_group_row_handler(
   Ok(())
 }
 
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn get_swimlanes_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<SwimlaneBoardPB, FlowyError> {
+  let params: DatabaseViewIdPB = data.into_inner();
+  let editor = manager.get_database_editor(&params.value).await?;
+  let board = editor.get_swimlanes(&params.value).await?;
+  data_result_ok(board)
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn set_swimlane_field_handler(
+  data: AFPluginData<SwimlaneFieldPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let params: SwimlaneFieldParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(params.view_id.as_ref()).await?;
+  editor.set_swimlane_field(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn export_board_layout_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<ExportedBoardLayoutPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let layout = manager.get_board_layout(view_id.as_ref()).await?;
+  let json = serde_json::to_string_pretty(&layout).map_err(internal_error)?;
+  data_result_ok(ExportedBoardLayoutPB {
+    view_id: view_id.value,
+    json,
+  })
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_database_schema_handler(
+  data: AFPluginData<DatabaseSchemaPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<DatabaseSchemaPB, FlowyError> {
+  let payload = data.into_inner();
+  let editor = manager.open_database_view(&payload.view_id).await?;
+  let (json_schema, openapi) = editor
+    .get_database_schema(&payload.view_id, payload.include_openapi)
+    .await?;
+  let json_schema = serde_json::to_string_pretty(&json_schema).map_err(internal_error)?;
+  let openapi = match openapi {
+    None => None,
+    Some(openapi) => Some(serde_json::to_string_pretty(&openapi).map_err(internal_error)?),
+  };
+  data_result_ok(DatabaseSchemaPB {
+    view_id: payload.view_id,
+    json_schema,
+    openapi,
+  })
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn set_checklist_item_due_handler(
+  data: AFPluginData<ChecklistItemDueChangesetPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let changeset: ChecklistItemDueChangeset = data.into_inner().try_into()?;
+  let editor = manager
+    .get_database_editor(&changeset.cell_id.view_id)
+    .await?;
+  editor.set_checklist_item_due(changeset).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_checklist_reminders_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedChecklistReminderPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let editor = manager.open_database_view(view_id.as_ref()).await?;
+  let reminders = editor.get_checklist_reminders(view_id.as_ref()).await?;
+  data_result_ok(RepeatedChecklistReminderPB { items: reminders })
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_checklist_cell_data_handler(
+  data: AFPluginData<CellIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<ChecklistCellDataPB, FlowyError> {
+  let params: CellIdParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.view_id).await?;
+  let cell_data = editor
+    .get_checklist_cell_data(&params.row_id, &params.field_id)
+    .await?;
+  data_result_ok(cell_data)
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn update_checklist_cell_handler(
+  data: AFPluginData<ChecklistCellChangesetPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: ChecklistCellChangesetParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.cell_id.view_id).await?;
+  editor.update_checklist_cell(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip_all, err)]
+pub(crate) async fn move_checklist_item_handler(
+  data: AFPluginData<MoveChecklistItemPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> Result<(), FlowyError> {
+  let params: MoveChecklistItemParams = data.into_inner().try_into()?;
+  let editor = manager.get_database_editor(&params.cell_id.view_id).await?;
+  editor.move_checklist_item(params).await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_group_progress_handler(
+  data: AFPluginData<GroupProgressPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedGroupProgressPB, FlowyError> {
+  let params: FieldIdParams = data.into_inner().try_into()?;
+  let editor = manager.open_database_view(&params.view_id).await?;
+  let items = editor
+    .get_group_progress(&params.view_id, &params.field_id)
+    .await?;
+  data_result_ok(RepeatedGroupProgressPB { items })
+}
+
+#[tracing::instrument(level = "trace", skip(data, manager), err)]
+pub(crate) async fn get_time_tracking_sum_handler(
+  data: AFPluginData<TimeTrackingSumPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<TimeTrackingSumPB, FlowyError> {
+  let params: FieldIdParams = data.into_inner().try_into()?;
+  let editor = manager.open_database_view(&params.view_id).await?;
+  let sum = editor
+    .get_time_tracking_sum(&params.view_id, &params.field_id)
+    .await?;
+  data_result_ok(sum)
+}
+
 #[tracing::instrument(level = "debug", skip(manager), err)]
 pub(crate) async fn get_databases_handler(
   manager: AFPluginState<Arc<DatabaseManager>>,
@@ -590,6 +1378,34 @@ pub(crate) async fn get_databases_handler(
   data_result_ok(RepeatedDatabaseDescriptionPB { items })
 }
 
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn compare_database_schema_handler(
+  data: AFPluginData<CompareDatabaseSchemaPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<RepeatedDatabaseDescriptionPB, FlowyError> {
+  let payload = data.into_inner();
+  let items = manager
+    .get_databases_with_schema(&payload.fields)
+    .await?
+    .into_iter()
+    .map(|database_info| DatabaseDescriptionPB {
+      name: database_info.name,
+      database_id: database_info.database_id,
+    })
+    .collect::<Vec<DatabaseDescriptionPB>>();
+  data_result_ok(RepeatedDatabaseDescriptionPB { items })
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn merge_databases_handler(
+  data: AFPluginData<MergeDatabasesPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<DatabaseMergeResultPB, FlowyError> {
+  let params: MergeDatabasesParams = data.into_inner().try_into()?;
+  let result = manager.merge_databases(params).await?;
+  data_result_ok(result)
+}
+
 #[tracing::instrument(level = "debug", skip(data, manager), err)]
 pub(crate) async fn set_layout_setting_handler(
   data: AFPluginData<UpdateLayoutSettingPB>,
@@ -616,6 +1432,52 @@ pub(crate) async fn get_layout_setting_handler(
   data_result_ok(layout_setting.into())
 }
 
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn update_frozen_column_count_handler(
+  data: AFPluginData<FrozenColumnCountPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let params: FrozenColumnCountParams = data.into_inner().try_into()?;
+  let database_editor = manager.get_database_editor(params.view_id.as_ref()).await?;
+  let layout_setting = LayoutSettingParams {
+    calendar: None,
+    grid: Some(GridLayoutSetting {
+      frozen_column_count: params.frozen_column_count,
+    }),
+  };
+  database_editor
+    .set_layout_setting(&params.view_id, layout_setting)
+    .await?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_view_description_handler(
+  data: AFPluginData<DatabaseViewIdPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<DatabaseViewDescriptionPB, FlowyError> {
+  let view_id: DatabaseViewIdPB = data.into_inner();
+  let database_editor = manager.get_database_editor(view_id.as_ref()).await?;
+  let description = database_editor
+    .get_view_description(view_id.as_ref())
+    .await?;
+  data_result_ok(DatabaseViewDescriptionPB {
+    view_id: view_id.value,
+    description,
+  })
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn update_view_description_handler(
+  data: AFPluginData<DatabaseViewDescriptionPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> FlowyResult<()> {
+  let params: DatabaseViewDescriptionParams = data.into_inner().try_into()?;
+  let database_editor = manager.get_database_editor(params.view_id.as_ref()).await?;
+  database_editor.update_view_description(params).await?;
+  Ok(())
+}
+
 #[tracing::instrument(level = "debug", skip(data, manager), err)]
 pub(crate) async fn get_calendar_events_handler(
   data: AFPluginData<CalendarEventRequestPB>,
@@ -623,9 +1485,18 @@ pub(crate) async fn get_calendar_events_handler(
 ) -> DataResult<RepeatedCalendarEventPB, FlowyError> {
   let params: CalendarEventRequestParams = data.into_inner().try_into()?;
   let database_editor = manager.get_database_editor(&params.view_id).await?;
-  let events = database_editor
-    .get_all_calendar_events(&params.view_id)
-    .await;
+  let events = match params.week_of {
+    Some(timestamp) => {
+      database_editor
+        .get_calendar_events_of_week(&params.view_id, timestamp)
+        .await?
+    },
+    None => {
+      database_editor
+        .get_all_calendar_events(&params.view_id, params.window)
+        .await
+    },
+  };
   data_result_ok(RepeatedCalendarEventPB { items: events })
 }
 
@@ -644,3 +1515,16 @@ pub(crate) async fn get_calendar_event_handler(
     Some(event) => data_result_ok(event),
   }
 }
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn schedule_row_handler(
+  data: AFPluginData<ScheduleRowPayloadPB>,
+  manager: AFPluginState<Arc<DatabaseManager>>,
+) -> DataResult<CalendarEventPB, FlowyError> {
+  let params: ScheduleRowParams = data.into_inner().try_into()?;
+  let database_editor = manager.get_database_editor(&params.view_id).await?;
+  let event = database_editor
+    .schedule_row(&params.view_id, &params.row_id, params.timestamp)
+    .await?;
+  data_result_ok(event)
+}
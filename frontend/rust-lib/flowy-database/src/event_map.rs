@@ -3,7 +3,7 @@ use crate::manager::DatabaseManager;
 use flowy_derive::{Flowy_Event, ProtoBuf_Enum};
 use lib_dispatch::prelude::*;
 use std::sync::Arc;
-use strum_macros::Display;
+use strum_macros::{Display, EnumIter};
 
 pub fn init(database_manager: Arc<DatabaseManager>) -> AFPlugin {
   let mut plugin = AFPlugin::new()
@@ -15,6 +15,17 @@ pub fn init(database_manager: Arc<DatabaseManager>) -> AFPlugin {
         .event(DatabaseEvent::GetDatabaseSetting, get_database_setting_handler)
         .event(DatabaseEvent::UpdateDatabaseSetting, update_database_setting_handler)
         .event(DatabaseEvent::GetAllFilters, get_all_filters_handler)
+        .event(DatabaseEvent::GetFilterPresets, get_filter_presets_handler)
+        .event(DatabaseEvent::SaveFilterPreset, save_filter_preset_handler)
+        .event(DatabaseEvent::ApplyFilterPreset, apply_filter_preset_handler)
+        .event(DatabaseEvent::DeleteFilterPreset, delete_filter_preset_handler)
+        .event(DatabaseEvent::InsertSessionFilter, insert_session_filter_handler)
+        .event(DatabaseEvent::SaveSessionFilter, save_session_filter_handler)
+        .event(DatabaseEvent::GetFilteredRowCount, get_filtered_row_count_handler)
+        .event(DatabaseEvent::GetAutomations, get_automations_handler)
+        .event(DatabaseEvent::SaveAutomation, save_automation_handler)
+        .event(DatabaseEvent::DeleteAutomation, delete_automation_handler)
+        .event(DatabaseEvent::GetAutomationActivities, get_automation_activities_handler)
         .event(DatabaseEvent::GetAllSorts, get_all_sorts_handler)
         .event(DatabaseEvent::DeleteAllSorts, delete_all_sorts_handler)
         // Field
@@ -27,20 +38,41 @@ pub fn init(database_manager: Arc<DatabaseManager>) -> AFPlugin {
         .event(DatabaseEvent::MoveField, move_field_handler)
         .event(DatabaseEvent::GetTypeOption, get_field_type_option_data_handler)
         .event(DatabaseEvent::CreateTypeOption, create_field_type_option_data_handler)
+        .event(DatabaseEvent::CreateFields, create_fields_handler)
         // Row
         .event(DatabaseEvent::CreateRow, create_row_handler)
         .event(DatabaseEvent::GetRow, get_row_handler)
         .event(DatabaseEvent::DeleteRow, delete_row_handler)
         .event(DatabaseEvent::DuplicateRow, duplicate_row_handler)
         .event(DatabaseEvent::MoveRow, move_row_handler)
+        .event(DatabaseEvent::RestoreRow, restore_row_handler)
+        .event(DatabaseEvent::GetTrashedRows, get_trashed_rows_handler)
+        .event(DatabaseEvent::GetRowDisplayValues, get_row_display_values_handler)
         // Cell
         .event(DatabaseEvent::GetCell, get_cell_handler)
         .event(DatabaseEvent::UpdateCell, update_cell_handler)
+        .event(DatabaseEvent::ResolveCellConflict, resolve_cell_conflict_handler)
+        .event(DatabaseEvent::GetCellByAddress, get_cell_by_address_handler)
+        .event(DatabaseEvent::GetAdjacentCell, get_adjacent_cell_handler)
+        .event(DatabaseEvent::GetInvalidCells, get_invalid_cells_handler)
+        .event(DatabaseEvent::RepairInvalidCells, repair_invalid_cells_handler)
+        .event(DatabaseEvent::GetRowIdCollisions, get_row_id_collisions_handler)
+        .event(DatabaseEvent::RepairRowIdCollisions, repair_row_id_collisions_handler)
+        .event(DatabaseEvent::GetRowDiagnostics, get_row_diagnostics_handler)
+        .event(DatabaseEvent::RecomputeView, recompute_view_handler)
         // SelectOption
         .event(DatabaseEvent::CreateSelectOption, new_select_option_handler)
         .event(DatabaseEvent::UpdateSelectOption, update_select_option_handler)
         .event(DatabaseEvent::GetSelectOptionCellData, get_select_option_handler)
+        .event(DatabaseEvent::GetAvailableSelectOptions, get_available_select_options_handler)
         .event(DatabaseEvent::UpdateSelectOptionCell, update_select_option_cell_handler)
+        .event(DatabaseEvent::GetSelectOptionUsage, get_select_option_usage_handler)
+        .event(DatabaseEvent::CleanupSelectOptions, cleanup_select_options_handler)
+        .event(DatabaseEvent::ExportSelectOptions, export_select_options_handler)
+        .event(DatabaseEvent::ImportSelectOptions, import_select_options_handler)
+        .event(DatabaseEvent::UpdateSelectOptionColor, update_select_option_color_handler)
+        .event(DatabaseEvent::MoveSelectOption, move_select_option_handler)
+        .event(DatabaseEvent::MergeSelectOptions, merge_select_options_handler)
         // Date
         .event(DatabaseEvent::UpdateDateCell, update_date_cell_handler)
         // Group
@@ -48,21 +80,59 @@ pub fn init(database_manager: Arc<DatabaseManager>) -> AFPlugin {
         .event(DatabaseEvent::MoveGroupRow, move_group_row_handler)
         .event(DatabaseEvent::GetGroups, get_groups_handler)
         .event(DatabaseEvent::GetGroup, get_group_handler)
+        .event(DatabaseEvent::GetSwimlanes, get_swimlanes_handler)
+        .event(DatabaseEvent::SetSwimlaneField, set_swimlane_field_handler)
+        .event(DatabaseEvent::ExportBoardLayout, export_board_layout_handler)
+        .event(DatabaseEvent::SetChecklistItemDue, set_checklist_item_due_handler)
+        .event(DatabaseEvent::GetChecklistReminders, get_checklist_reminders_handler)
+        .event(DatabaseEvent::GetChecklistCellData, get_checklist_cell_data_handler)
+        .event(DatabaseEvent::UpdateChecklistCell, update_checklist_cell_handler)
+        .event(DatabaseEvent::MoveChecklistItem, move_checklist_item_handler)
+        .event(DatabaseEvent::UpdateLocationCell, update_location_cell_handler)
+        .event(DatabaseEvent::GetGroupProgress, get_group_progress_handler)
+        .event(DatabaseEvent::GetDatabaseSchema, get_database_schema_handler)
+        .event(DatabaseEvent::RestoreField, restore_field_handler)
+        .event(DatabaseEvent::GetTrashedFields, get_trashed_fields_handler)
+        .event(DatabaseEvent::UpdateTimeTrackingCell, update_time_tracking_cell_handler)
+        .event(DatabaseEvent::GetTimeTrackingSum, get_time_tracking_sum_handler)
         // Database
         .event(DatabaseEvent::GetDatabases, get_databases_handler)
+        .event(DatabaseEvent::CompareDatabaseSchema, compare_database_schema_handler)
+        .event(DatabaseEvent::MergeDatabases, merge_databases_handler)
         // Calendar
         .event(DatabaseEvent::GetAllCalendarEvents, get_calendar_events_handler)
         .event(DatabaseEvent::GetCalendarEvent, get_calendar_event_handler)
+        .event(DatabaseEvent::ScheduleRow, schedule_row_handler)
         // Layout setting
         .event(DatabaseEvent::SetLayoutSetting, set_layout_setting_handler)
-        .event(DatabaseEvent::GetLayoutSetting, get_layout_setting_handler);
+        .event(DatabaseEvent::GetLayoutSetting, get_layout_setting_handler)
+        .event(DatabaseEvent::UpdateFrozenColumnCount, update_frozen_column_count_handler)
+        .event(DatabaseEvent::GetViewDescription, get_view_description_handler)
+        .event(DatabaseEvent::UpdateViewDescription, update_view_description_handler)
+        .event(DatabaseEvent::GetTextCell, get_text_cell_handler)
+        .event(DatabaseEvent::GetRowBacklinks, get_row_backlinks_handler)
+        .event(DatabaseEvent::ShareRow, share_row_handler)
+        .event(DatabaseEvent::GetRowShares, get_row_shares_handler)
+        .event(DatabaseEvent::RevokeRowShare, revoke_row_share_handler)
+        .event(DatabaseEvent::GetSharedRow, get_shared_row_handler)
+        .event(DatabaseEvent::UpdateRelationCell, update_relation_cell_handler)
+        .event(DatabaseEvent::GetRelationDependents, get_relation_dependents_handler)
+        .event(DatabaseEvent::GetRelatedRows, get_related_rows_handler)
+        .event(DatabaseEvent::UpdatePersonCell, update_person_cell_handler)
+        .event(DatabaseEvent::AttachFile, attach_file_handler)
+        .event(DatabaseEvent::RemoveAttachment, remove_attachment_handler)
+        .event(DatabaseEvent::ResolveAttachment, resolve_attachment_handler)
+        .event(DatabaseEvent::SubscribeDatabaseChanges, subscribe_database_changes_handler)
+        .event(DatabaseEvent::UnsubscribeDatabaseChanges, unsubscribe_database_changes_handler);
 
   plugin
 }
 
 /// [DatabaseEvent] defines events that are used to interact with the Grid. You could check [this](https://appflowy.gitbook.io/docs/essential-documentation/contribute-to-appflowy/architecture/backend/protobuf)
 /// out, it includes how to use these annotations: input, output, etc.
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
+#[derive(
+  Clone, Copy, PartialEq, Eq, Debug, Display, Hash, EnumIter, ProtoBuf_Enum, Flowy_Event,
+)]
 #[event_err = "FlowyError"]
 pub enum DatabaseEvent {
   /// [GetDatabase] event is used to get the [DatabasePB]
@@ -121,7 +191,8 @@ pub enum DatabaseEvent {
   UpdateFieldTypeOption = 12,
 
   /// [DeleteField] event is used to delete a Field. [DeleteFieldPayloadPB] is the context that
-  /// is used to delete the field from the Database.
+  /// is used to delete the field from the Database. The field is moved to the trash with its
+  /// cells, it can be restored by the [RestoreField] event until it gets purged.
   #[event(input = "DeleteFieldPayloadPB")]
   DeleteField = 14,
 
@@ -158,6 +229,12 @@ pub enum DatabaseEvent {
   #[event(input = "CreateFieldPayloadPB", output = "TypeOptionPB")]
   CreateTypeOption = 24,
 
+  /// [CreateFields] event creates a list of fields with their names and type options in one
+  /// revision, e.g. when importing a file or instantiating a template. The new fields are
+  /// sent in one `DidUpdateFields` notification.
+  #[event(input = "CreateFieldsPayloadPB", output = "RepeatedFieldPB")]
+  CreateFields = 25,
+
   /// [CreateSelectOption] event is used to create a new select option. Returns a [SelectOptionPB] if
   /// there are no errors.
   #[event(input = "CreateSelectOptionPayloadPB", output = "SelectOptionPB")]
@@ -178,6 +255,53 @@ pub enum DatabaseEvent {
   #[event(input = "SelectOptionChangesetPB")]
   UpdateSelectOption = 32,
 
+  /// [GetSelectOptionUsage] event is used to scan a select field for the options that are not
+  /// referenced by any cell, and for the cells that reference the deleted options.
+  #[event(input = "SelectOptionUsagePayloadPB", output = "SelectOptionUsagePB")]
+  GetSelectOptionUsage = 33,
+
+  /// [CleanupSelectOptions] event is used to delete the options that are not referenced by any
+  /// cell and remove the references to the deleted options. Returns the [SelectOptionUsagePB]
+  /// after cleaning up.
+  #[event(input = "SelectOptionUsagePayloadPB", output = "SelectOptionUsagePB")]
+  CleanupSelectOptions = 34,
+
+  /// [ExportSelectOptions] event returns the options of a select field, including their names,
+  /// colors and order.
+  #[event(
+    input = "ExportSelectOptionsPayloadPB",
+    output = "RepeatedSelectOptionPB"
+  )]
+  ExportSelectOptions = 35,
+
+  /// [ImportSelectOptions] event merges the passed in options into a select field of any
+  /// database by name. It's used to keep the same options across the databases. Returns the
+  /// options of the field after importing.
+  #[event(
+    input = "ImportSelectOptionsPayloadPB",
+    output = "RepeatedSelectOptionPB"
+  )]
+  ImportSelectOptions = 36,
+
+  /// [UpdateSelectOptionColor] event changes the color of a select option. The group of the
+  /// option is updated with the new color. Returns the option after the change.
+  #[event(input = "UpdateSelectOptionColorPayloadPB", output = "SelectOptionPB")]
+  UpdateSelectOptionColor = 37,
+
+  /// [MoveSelectOption] event moves a select option to the position of another option. Returns
+  /// the options of the field after moving.
+  #[event(input = "MoveSelectOptionPayloadPB", output = "RepeatedSelectOptionPB")]
+  MoveSelectOption = 38,
+
+  /// [MergeSelectOptions] event merges the duplicate options into the target option. The cells
+  /// of the merged options are moved to the target option, and the merged options are deleted
+  /// along with their groups. Returns the options of the field after merging.
+  #[event(
+    input = "MergeSelectOptionsPayloadPB",
+    output = "RepeatedSelectOptionPB"
+  )]
+  MergeSelectOptions = 39,
+
   #[event(input = "CreateRowPayloadPB", output = "RowPB")]
   CreateRow = 50,
 
@@ -186,6 +310,8 @@ pub enum DatabaseEvent {
   #[event(input = "RowIdPB", output = "OptionalRowPB")]
   GetRow = 51,
 
+  /// [DeleteRow] event is used to move the row to the trash of the database. The row can be
+  /// restored by the [RestoreRow] event until it gets purged after the retention period.
   #[event(input = "RowIdPB")]
   DeleteRow = 52,
 
@@ -195,6 +321,24 @@ pub enum DatabaseEvent {
   #[event(input = "MoveRowPayloadPB")]
   MoveRow = 54,
 
+  /// [RestoreRow] event is used to move the row out of the trash. The restored row is inserted
+  /// at the end of the database.
+  #[event(input = "RowIdPB", output = "RowPB")]
+  RestoreRow = 55,
+
+  /// [GetTrashedRows] event returns the rows in the trash of the database, each row carries the
+  /// display string of its primary cell.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedTrashedRowPB")]
+  GetTrashedRows = 56,
+
+  /// [GetRowDisplayValues] event returns the cached display string of the rows' primary cell.
+  /// It's used to show the name of the rows without loading their cells.
+  #[event(
+    input = "RowDisplayValuesPayloadPB",
+    output = "RepeatedRowDisplayValuePB"
+  )]
+  GetRowDisplayValues = 57,
+
   #[event(input = "CellIdPB", output = "CellPB")]
   GetCell = 70,
 
@@ -214,13 +358,45 @@ pub enum DatabaseEvent {
   /// [UpdateSelectOptionCell] event is used to update a select option cell's data. [SelectOptionCellChangesetPB]
   /// contains options that will be deleted or inserted. It can be cast to [CellChangesetPB] that
   /// will be used by the `update_cell` function.
+  ///
+  /// The options can also be inserted by their names, e.g. the pasted values. The names that
+  /// don't match any option are created if the field's `create_missing_options` is on.
   #[event(input = "SelectOptionCellChangesetPB")]
   UpdateSelectOptionCell = 72,
 
+  /// [GetCellByAddress] event is used to get the cell by its position in the view, see
+  /// [CellAddressPB] for more information.
+  #[event(input = "CellAddressPB", output = "AddressedCellPB")]
+  GetCellByAddress = 73,
+
+  /// [GetAdjacentCell] event returns the cell next to the cell at the address in the passed in
+  /// direction. It's used to move the focus between the cells by the keyboard.
+  #[event(input = "MoveCellAddressPayloadPB", output = "AddressedCellPB")]
+  GetAdjacentCell = 74,
+
+  /// [GetInvalidCells] event returns the cells whose data can't be decoded by their field's
+  /// type option, or that are stored in an outdated format. The same cells are reported
+  /// by the `DidDetectInvalidCells` notification when the database is opened.
+  #[event(input = "DatabaseViewIdPB", output = "InvalidCellsPB")]
+  GetInvalidCells = 75,
+
+  /// [RepairInvalidCells] event re-encodes the invalid cells through the type option's
+  /// transform, and returns the cells that still can't be repaired.
+  #[event(input = "DatabaseViewIdPB", output = "InvalidCellsPB")]
+  RepairInvalidCells = 76,
+
+  /// [GetRowDiagnostics] event explains why the row is hidden in the view or positioned where
+  /// it is. It returns the result of each filter, the sort keys of the row and its index.
+  #[event(input = "RowIdPB", output = "RowDiagnosticsPB")]
+  GetRowDiagnostics = 77,
+
   /// [UpdateDateCell] event is used to update a date cell's data. [DateChangesetPB]
   /// contains the date and the time string. It can be cast to [CellChangesetPB] that
   /// will be used by the `update_cell` function.
-  #[event(input = "DateChangesetPB")]
+  ///
+  /// The date can also be typed in the natural language, like "tomorrow 5pm". The saved date is
+  /// returned, so the UI can show how the text was interpreted.
+  #[event(input = "DateChangesetPB", output = "DateCellDataPB")]
   UpdateDateCell = 80,
 
   #[event(input = "DatabaseViewIdPB", output = "RepeatedGroupPB")]
@@ -256,4 +432,270 @@ pub enum DatabaseEvent {
 
   #[event(input = "MoveCalendarEventPB")]
   MoveCalendarEvent = 119,
+
+  /// [ScheduleRow] event is used to write the date cell of the row when its card is dragged
+  /// onto a day of the calendar, e.g. from the unscheduled lane.
+  #[event(input = "ScheduleRowPayloadPB", output = "CalendarEventPB")]
+  ScheduleRow = 120,
+
+  /// [UpdateFrozenColumnCount] event saves the number of the leading columns that stay visible
+  /// when the grid is scrolled horizontally. It's included in the grid's [LayoutSettingPB].
+  #[event(input = "FrozenColumnCountPayloadPB")]
+  UpdateFrozenColumnCount = 121,
+
+  /// [GetViewDescription] event returns the text that explains what the database view
+  /// represents. It's also included in the [DatabaseViewSettingPB].
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseViewDescriptionPB")]
+  GetViewDescription = 122,
+
+  /// [UpdateViewDescription] event replaces the description of the database view. The
+  /// [DatabaseNotification::DidUpdateSettings] is sent after updating.
+  #[event(input = "DatabaseViewDescriptionPB")]
+  UpdateViewDescription = 123,
+
+  /// [GetSwimlanes] event returns the rows of the board in each swimlane and group. The
+  /// swimlanes are empty if the board has no swimlane field.
+  #[event(input = "DatabaseViewIdPB", output = "SwimlaneBoardPB")]
+  GetSwimlanes = 124,
+
+  /// [SetSwimlaneField] event groups the rows of the board into the swimlanes by the field,
+  /// or removes the swimlanes if the field is None. The field can't be the grouping field.
+  #[event(input = "SwimlaneFieldPayloadPB")]
+  SetSwimlaneField = 125,
+
+  /// [GetTextCell] event returns the text of the cell with its mentions replaced by the names
+  /// of the mentioned rows or views.
+  #[event(input = "CellIdPB", output = "TextCellDataPB")]
+  GetTextCell = 126,
+
+  /// [GetRowBacklinks] event returns the text cells that mention the row in all the databases.
+  #[event(input = "RowIdPB", output = "RepeatedRowBacklinkPB")]
+  GetRowBacklinks = 127,
+
+  /// [ShareRow] event creates a read-only link of the row. The link renders the visible fields
+  /// and the document of the row until it's revoked by the [RevokeRowShare] event. The link is
+  /// only resolved on this device.
+  #[event(input = "RowIdPB", output = "RowSharePB")]
+  ShareRow = 128,
+
+  /// [GetRowShares] event returns the active links of the rows in the database.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedRowSharePB")]
+  GetRowShares = 129,
+
+  #[event(input = "RowShareIdPB")]
+  RevokeRowShare = 130,
+
+  /// [GetSharedRow] event returns the read-only content of the row that the link points to.
+  #[event(input = "RowShareIdPB", output = "SharedRowPB")]
+  GetSharedRow = 131,
+
+  /// [UpdateRelationCell] event links the rows of the related database to the relation cell,
+  /// or unlinks them from it.
+  #[event(input = "RelationCellChangesetPB")]
+  UpdateRelationCell = 132,
+
+  /// [GetRelatedRows] event returns the primary cell text of the rows that the relation cell
+  /// links. The rows that were deleted from the related database are skipped.
+  #[event(input = "CellIdPB", output = "RepeatedRowDisplayValuePB")]
+  GetRelatedRows = 133,
+
+  /// [SubscribeDatabaseChanges] event starts sending the row and field changes of the view's
+  /// database as the [DatabaseNotification::DidReceiveDatabaseChange] notifications. The
+  /// notifications are observed with the returned database id.
+  #[event(input = "DatabaseViewIdPB", output = "DatabaseChangeSubscriptionPB")]
+  SubscribeDatabaseChanges = 134,
+
+  /// [UnsubscribeDatabaseChanges] event unsubscribes from the changes of the view's database.
+  /// The notifications keep being sent until every [SubscribeDatabaseChanges] of the database
+  /// is paired with an unsubscription.
+  #[event(input = "DatabaseViewIdPB")]
+  UnsubscribeDatabaseChanges = 135,
+
+  /// [GetRowIdCollisions] event returns the rows whose ids are used by other rows, of the same
+  /// database or of another database. Such rows were created offline by the older versions.
+  #[event(input = "DatabaseViewIdPB", output = "RowIdCollisionsPB")]
+  GetRowIdCollisions = 136,
+
+  /// [RepairRowIdCollisions] event gives the colliding rows new ids, and returns the collisions
+  /// that can't be repaired.
+  #[event(input = "DatabaseViewIdPB", output = "RowIdCollisionsPB")]
+  RepairRowIdCollisions = 137,
+
+  /// [UpdatePersonCell] event assigns the members of the workspace to the person cell, or
+  /// unassigns them from it.
+  #[event(input = "PersonCellChangesetPB")]
+  UpdatePersonCell = 138,
+
+  /// [AttachFile] event copies the file into the file storage of the application and attaches
+  /// it to the attachment cell.
+  #[event(input = "AttachFilePayloadPB", output = "AttachmentPB")]
+  AttachFile = 139,
+
+  /// [RemoveAttachment] event removes the attachment from the cell. The stored file is deleted
+  /// if no other cell references it.
+  #[event(input = "AttachmentIdPB")]
+  RemoveAttachment = 140,
+
+  /// [ResolveAttachment] event returns the local path of the attached file.
+  #[event(input = "AttachmentIdPB", output = "ResolvedAttachmentPB")]
+  ResolveAttachment = 141,
+
+  /// [ExportBoardLayout] event returns the groups of the board and their cards, with the display
+  /// values of the cells and the colors of the groups, as JSON for the rendering or print tools.
+  #[event(input = "DatabaseViewIdPB", output = "ExportedBoardLayoutPB")]
+  ExportBoardLayout = 142,
+
+  /// [SetChecklistItemDue] event sets the due date of an item of the checklist cell, or removes
+  /// it if the due date is None.
+  #[event(input = "ChecklistItemDueChangesetPB")]
+  SetChecklistItemDue = 143,
+
+  /// [GetChecklistReminders] event returns the checklist items of the rows that have a due date,
+  /// sorted by their due dates.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedChecklistReminderPB")]
+  GetChecklistReminders = 144,
+
+  /// [GetGroupProgress] event returns the average of the progress cells of each group of the
+  /// board.
+  #[event(input = "GroupProgressPayloadPB", output = "RepeatedGroupProgressPB")]
+  GetGroupProgress = 145,
+
+  /// [GetDatabaseSchema] event returns the JSON Schema that describes the fields of the
+  /// database, and optionally the OpenAPI document that wraps it in its components.
+  #[event(input = "DatabaseSchemaPayloadPB", output = "DatabaseSchemaPB")]
+  GetDatabaseSchema = 146,
+
+  /// [RestoreField] event is used to move the field out of the trash. The field is inserted
+  /// back at its position, with the cells it had when it was deleted.
+  #[event(input = "RestoreFieldPayloadPB", output = "FieldPB")]
+  RestoreField = 147,
+
+  /// [GetTrashedFields] event returns the fields in the trash of the database.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedTrashedFieldPB")]
+  GetTrashedFields = 148,
+
+  /// [UpdateTimeTrackingCell] event starts or stops the timer of the time tracking cell, or
+  /// adjusts its tracked time.
+  #[event(input = "TimeTrackingCellChangesetPB")]
+  UpdateTimeTrackingCell = 149,
+
+  /// [GetTimeTrackingSum] event returns the time tracked by the rows of the view and by the rows
+  /// of each group.
+  #[event(input = "TimeTrackingSumPayloadPB", output = "TimeTrackingSumPB")]
+  GetTimeTrackingSum = 150,
+
+  /// [GetChecklistCellData] event returns the items of the checklist cell, and the percentage of
+  /// the checked items.
+  #[event(input = "CellIdPB", output = "ChecklistCellDataPB")]
+  GetChecklistCellData = 151,
+
+  /// [UpdateChecklistCell] event inserts, renames, checks and deletes the items of the checklist
+  /// cell. The items of the other rows don't change.
+  #[event(input = "ChecklistCellChangesetPB")]
+  UpdateChecklistCell = 152,
+
+  /// [MoveChecklistItem] event moves the item of the checklist cell to the position of another
+  /// item of the cell.
+  #[event(input = "MoveChecklistItemPayloadPB")]
+  MoveChecklistItem = 153,
+
+  /// [UpdateLocationCell] event sets the location of the cell by its coordinates, or by its
+  /// address that is geocoded by the geocoding provider of the application.
+  #[event(input = "LocationCellChangesetPB")]
+  UpdateLocationCell = 154,
+
+  /// [RecomputeView] event runs the calculations, the filters, the sorts and the groups of the
+  /// view again from scratch. It returns how long each stage took.
+  #[event(input = "DatabaseViewIdPB", output = "RecomputeViewResultPB")]
+  RecomputeView = 155,
+
+  /// [CompareDatabaseSchema] event returns the databases that have the same fields as a
+  /// template or an imported file, so the user can link the new data into one of them instead
+  /// of creating another copy. The view is linked by passing the `database_id` in the `ext` of
+  /// the new view, the rows of its data are appended to that database.
+  #[event(
+    input = "CompareDatabaseSchemaPayloadPB",
+    output = "RepeatedDatabaseDescriptionPB"
+  )]
+  CompareDatabaseSchema = 156,
+
+  /// [GetRelationDependents] event returns the relation cells that link the row, or the rows of
+  /// the database if no row is given. Deleting the linked rows either unlinks the cells or
+  /// fails, depending on the [RelationOnDeletePB] of each relation field.
+  #[event(
+    input = "RelationDependentsPayloadPB",
+    output = "RepeatedRelationDependentPB"
+  )]
+  GetRelationDependents = 157,
+
+  /// [GetAvailableSelectOptions] event is used by the option picker of the select cell. It's
+  /// like [GetSelectOptionCellData], except that the options of the returned
+  /// [SelectOptionCellDataPB] only contain the options that can be selected with the options
+  /// selected in the field that the select field depends on.
+  #[event(input = "CellIdPB", output = "SelectOptionCellDataPB")]
+  GetAvailableSelectOptions = 158,
+
+  /// [MergeDatabases] event appends the rows of the source database to the target database,
+  /// e.g. after an import created another copy of the database. The fields are matched by their
+  /// names and types, and the options of the select fields are unioned. The returned
+  /// [DatabaseMergeResultPB] lists the source fields whose cells couldn't be merged.
+  #[event(input = "MergeDatabasesPayloadPB", output = "DatabaseMergeResultPB")]
+  MergeDatabases = 159,
+
+  /// [GetFilterPresets] event returns the filter presets of the view.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedFilterPresetPB")]
+  GetFilterPresets = 160,
+
+  /// [SaveFilterPreset] event saves the current filters of the view as a preset. The preset
+  /// with the same name is overwritten.
+  #[event(input = "SaveFilterPresetPayloadPB", output = "FilterPresetPB")]
+  SaveFilterPreset = 161,
+
+  /// [ApplyFilterPreset] event replaces the filters of the view with the filters of the preset.
+  /// The changes are sent in a single [FilterChangesetNotificationPB].
+  #[event(input = "FilterPresetIdPB")]
+  ApplyFilterPreset = 162,
+
+  #[event(input = "FilterPresetIdPB")]
+  DeleteFilterPreset = 163,
+
+  /// [GetFilteredRowCount] event returns the number of the visible and the hidden rows of the
+  /// view. The counts are also sent with each [RowsVisibilityChangesetPB].
+  #[event(input = "DatabaseViewIdPB", output = "FilteredRowCountPB")]
+  GetFilteredRowCount = 164,
+
+  /// [GetAutomations] event returns the automations of the view.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedAutomationPB")]
+  GetAutomations = 165,
+
+  /// [SaveAutomation] event creates or replaces an automation of the view, e.g. moving the row
+  /// to a group when its due date arrives. The automations are fired by a scheduler that scans
+  /// the opened views periodically.
+  #[event(input = "SaveAutomationPayloadPB", output = "AutomationPB")]
+  SaveAutomation = 166,
+
+  /// [DeleteAutomation] event deletes the automation and its activities.
+  #[event(input = "AutomationIdPB")]
+  DeleteAutomation = 167,
+
+  /// [GetAutomationActivities] event returns the latest executions of the automations of the
+  /// view. Each new execution is also sent with the [DidExecuteAutomation] notification.
+  #[event(input = "DatabaseViewIdPB", output = "RepeatedAutomationActivityPB")]
+  GetAutomationActivities = 168,
+
+  /// [InsertSessionFilter] event applies the filter for the current session without saving it
+  /// in the view. The session filter is updated or deleted like the other filters, with the
+  /// [UpdateDatabaseSetting] event.
+  #[event(input = "AlterFilterPayloadPB")]
+  InsertSessionFilter = 169,
+
+  /// [SaveSessionFilter] event saves the session filter in the view.
+  #[event(input = "SessionFilterIdPB")]
+  SaveSessionFilter = 170,
+
+  /// [ResolveCellConflict] event resolves the conflict of the cell after the user has seen its
+  /// latest data, so the cell can be edited again. The cell is in conflict if the base data of
+  /// its [CellChangesetPB] was outdated.
+  #[event(input = "CellIdPB")]
+  ResolveCellConflict = 171,
 }
@@ -8,7 +8,7 @@ pub mod event_map;
 pub mod manager;
 
 pub mod entities;
-mod notification;
+pub mod notification;
 mod protobuf;
 pub mod services;
 pub mod util;
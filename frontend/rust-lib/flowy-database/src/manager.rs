@@ -1,55 +1,250 @@
-use crate::entities::LayoutTypePB;
+use crate::entities::{
+  AutomationIdParams, AutomationPB, CellIdParams, DatabaseChangePB, DatabaseMergeResultPB,
+  FieldSchemaPB, FieldType, InvalidCellsPB, LayoutTypePB, MergeDatabasesParams, RecomputeStagePB,
+  RecomputeStageTypePB, RecomputeViewResultPB, RowBacklinkPB, RowDisplayValuePB, RowIdCollisionPB,
+  SaveAutomationParams, SharedRowPB,
+};
+use crate::notification::{send_notification, DatabaseNotification};
+use crate::services::automation::{
+  run_automation_scheduler, AutomationController, AutomationTaskHandler,
+};
+use crate::services::calculation::{
+  run_calculation_scheduler, CalculationController, CalculationTaskHandler,
+};
+use crate::services::cell::CellSizeLimits;
 use crate::services::database::{
-  make_database_block_rev_manager, DatabaseEditor, DatabaseRefIndexerQuery,
-  DatabaseRevisionCloudService, DatabaseRevisionMergeable, DatabaseRevisionSerde,
+  fill_checklist_items, is_same_database_schema, make_database_block_rev_manager,
+  make_merged_row_rev, match_merged_fields, remap_relation_cells, union_select_options,
+  DatabaseChangeSender, DatabaseEditor, DatabaseRefIndexerQuery, DatabaseRevisionCloudService,
+  DatabaseRevisionMergeable, DatabaseRevisionSerde, RowLimit,
 };
 use crate::services::database_view::{
   make_database_view_rev_manager, make_database_view_revision_pad, DatabaseViewEditor,
 };
+use crate::services::field::{
+  attachment_ids_of_row, clear_current_user, select_type_option_from_field_rev, set_current_user,
+  AttachFileParams, AttachmentCellChangeset, AttachmentIdParams, AttachmentPB,
+  ChecklistTypeOptionPB, Location, LocationCellChangeset, LocationCellChangesetParams,
+  RelationCellChangeset, RelationDependentPB, RelationOnDeletePB, RelationTypeOptionPB,
+  ResolvedAttachmentPB, TextCellDataPB,
+};
+use crate::services::formula::FormulaController;
+use crate::services::group::BoardLayout;
+use crate::services::persistence::automation_activity::{AutomationActivities, AutomationActivity};
+use crate::services::persistence::automation_view::AutomationViews;
 use crate::services::persistence::block_index::BlockRowIndexer;
 use crate::services::persistence::database_ref::{DatabaseInfo, DatabaseRefs, DatabaseViewRef};
+use crate::services::persistence::fired_reminder::FiredReminders;
+use crate::services::persistence::import_checkpoint::ImportCheckpoints;
 use crate::services::persistence::kv::DatabaseKVPersistence;
 use crate::services::persistence::migration::DatabaseMigration;
+use crate::services::persistence::relation_link::RelationLinkIndex;
 use crate::services::persistence::rev_sqlite::{
-  SQLiteDatabaseRevisionPersistence, SQLiteDatabaseRevisionSnapshotPersistence,
+  SQLiteDatabaseBlockRevisionPersistence, SQLiteDatabaseRevisionPersistence,
+  SQLiteDatabaseRevisionSnapshotPersistence, SQLiteDatabaseViewRevisionPersistence,
 };
+use crate::services::persistence::row_display_value::RowDisplayValueStorage;
+use crate::services::persistence::row_document::RowDocumentIndex;
+use crate::services::persistence::row_mention::RowMentionIndex;
+use crate::services::persistence::row_share::RowShares;
+use crate::services::persistence::row_trash::RowTrash;
 use crate::services::persistence::DatabaseDBConnection;
-use std::collections::HashMap;
+use crate::services::rollup::RollupController;
+use crate::services::setting::{SharedWeekSettings, WeekSettings};
+use crate::services::transaction::Transaction;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::Path;
 
 use database_model::{
-  gen_database_id, BuildDatabaseContext, DatabaseRevision, DatabaseViewRevision,
+  gen_database_id, BuildDatabaseContext, DatabaseRevision, DatabaseViewRevision, FieldRevision,
+  RowRevision,
 };
 use flowy_client_sync::client_database::{
   make_database_block_operations, make_database_operations, make_database_view_operations,
+  DatabaseRevisionPad,
 };
 use flowy_error::{FlowyError, FlowyResult};
 use flowy_revision::{
   RevisionManager, RevisionPersistence, RevisionPersistenceConfiguration, RevisionWebSocket,
 };
+use flowy_revision_persistence::RevisionDiskCache;
+use flowy_sqlite::kv::KV;
 use flowy_sqlite::ConnectionPool;
 use flowy_task::TaskDispatcher;
 
-use lib_infra::future::Fut;
+use lib_infra::future::{to_fut, Fut, FutureResult};
+use lib_infra::id_gen::gen_id;
+use lib_infra::util::{md5, timestamp};
 use revision_model::Revision;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, RwLock};
+
+/// The relation links of each database are indexed when it's opened, the databases that were
+/// never opened since the index was added are indexed once. See [RelationLinkIndex].
+const RELATION_LINK_INDEX_VERSION: &str = "relation_link_index_v1";
+
+/// Like the [RELATION_LINK_INDEX_VERSION], the databases that were never opened since the
+/// [RowMentionIndex] was added are indexed once.
+const ROW_MENTION_INDEX_VERSION: &str = "row_mention_index_v1";
+
+/// The date fields of the older versions have no timezone, they are pinned to a timezone once per
+/// database when it's opened. See [DatabaseEditor::migrate_date_field_timezones].
+const DATE_TIMEZONE_MIGRATION: &str = "DATABASE_DATE_TIMEZONE_MIGRATION";
 
 pub trait DatabaseUser: Send + Sync {
   fn user_id(&self) -> Result<String, FlowyError>;
+  fn user_name(&self) -> Result<String, FlowyError>;
   fn token(&self) -> Result<String, FlowyError>;
   fn db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError>;
 }
 
+/// Stores the files that are attached to the cells. The files are copied into the storage and
+/// referenced by their ids.
+pub trait DatabaseFileStorage: Send + Sync {
+  /// Copies the file into the storage. Returns the id and the size in bytes of the stored file.
+  fn store_file(&self, file_path: &str) -> Result<(String, u64), FlowyError>;
+  fn delete_file(&self, file_id: &str) -> Result<(), FlowyError>;
+  /// Returns the local path of the stored file.
+  fn file_path(&self, file_id: &str) -> Result<String, FlowyError>;
+}
+
+/// Checks the storage quota of the workspace before a file gets attached to a cell.
+pub trait DatabaseQuotaChecker: Send + Sync {
+  /// Returns an error if the workspace reached its max size.
+  fn check_workspace_size(&self) -> Result<(), FlowyError>;
+  /// Called after the file of `file_size` bytes was stored, it's added to the size of the
+  /// workspace.
+  fn did_store_file(&self, file_size: u64);
+}
+
+/// Finds the coordinates of the addresses that are typed into the location cells.
+pub trait DatabaseGeocoder: Send + Sync {
+  /// Returns None if the address is not found.
+  fn geocode(&self, address: &str) -> FutureResult<Option<Location>, FlowyError>;
+}
+
+/// Keeps the documents of the rows, e.g. the descriptions of the imported Trello cards. The
+/// document of a row is found with [DatabaseManager::get_row_document_id].
+pub trait DatabaseRowDocuments: Send + Sync {
+  /// Copies the document to a new document with the id `new_document_id`.
+  fn duplicate_document(
+    &self,
+    document_id: &str,
+    new_document_id: &str,
+  ) -> FutureResult<(), FlowyError>;
+
+  /// Creates the document with the `text` as its content.
+  fn create_document_from_text(
+    &self,
+    document_id: &str,
+    text: &str,
+  ) -> FutureResult<(), FlowyError>;
+}
+
+/// Adds the notifications of the databases to the inbox of the current user, such as the fired
+/// reminders of the checklist items, the assignments of the user to the rows and the shares of
+/// the rows.
+pub trait DatabaseInbox: Send + Sync {
+  fn add_notification(&self, notification: DatabaseInboxNotification) -> Result<(), FlowyError>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseInboxKind {
+  Reminder,
+  Mention,
+  Share,
+}
+
+#[derive(Debug, Clone)]
+pub struct DatabaseInboxNotification {
+  pub kind: DatabaseInboxKind,
+  pub title: String,
+  pub body: String,
+  /// The id of the row, or the id of the share for the shares
+  pub object_id: String,
+}
+
+/// Reads the names of the views, which are kept in the folder.
+pub trait DatabaseViewNames: Send + Sync {
+  /// Returns None if the view is not found or it's in the trash.
+  fn get_view_name(&self, view_id: &str) -> Fut<Option<String>>;
+}
+
+#[derive(Debug, Clone)]
+pub struct DatabaseConfig {
+  pub cell_size_limits: CellSizeLimits,
+  /// The deleted rows are kept in the trash for this period before they get purged
+  pub row_trash_retention: Duration,
+  /// The deleted fields are kept in the trash for this period. Their cells are removed from the
+  /// rows when the database is opened after the period
+  pub field_trash_retention: Duration,
+  /// The max number of rows of each database. Zero means the number of rows is not limited
+  pub max_rows_per_database: usize,
+  /// The max size in bytes of the files that are attached to the cells. Zero means the size is
+  /// not limited
+  pub max_attachment_size: u64,
+}
+
+impl std::default::Default for DatabaseConfig {
+  fn default() -> Self {
+    Self {
+      cell_size_limits: CellSizeLimits::default(),
+      row_trash_retention: Duration::from_secs(30 * 24 * 60 * 60),
+      field_trash_retention: Duration::from_secs(30 * 24 * 60 * 60),
+      max_rows_per_database: 0,
+      max_attachment_size: 0,
+    }
+  }
+}
+
 pub struct DatabaseManager {
   editors_by_database_id: RwLock<HashMap<String, Arc<DatabaseEditor>>>,
   database_user: Arc<dyn DatabaseUser>,
+  file_storage: Arc<dyn DatabaseFileStorage>,
+  geocoder: Arc<dyn DatabaseGeocoder>,
+  row_documents: Arc<dyn DatabaseRowDocuments>,
+  row_document_index: Arc<RowDocumentIndex>,
+  inbox: Arc<dyn DatabaseInbox>,
+  fired_reminders: Arc<FiredReminders>,
   block_indexer: Arc<BlockRowIndexer>,
   database_refs: Arc<DatabaseRefs>,
+  import_checkpoints: Arc<ImportCheckpoints>,
+  row_trash: Arc<RowTrash>,
+  row_shares: Arc<RowShares>,
+  relation_link_index: Arc<RelationLinkIndex>,
+  row_mention_index: Arc<RowMentionIndex>,
+  row_display_value_storage: Arc<RowDisplayValueStorage>,
+  automation_activities: Arc<AutomationActivities>,
+  automation_views: Arc<AutomationViews>,
+  row_limit: RowLimit,
+  max_attachment_size: AtomicU64,
+  /// Set when the quota of the workspace is read, the files are attached without checking the
+  /// quota of the workspace until then
+  quota_checker: parking_lot::RwLock<Option<Arc<dyn DatabaseQuotaChecker>>>,
+  /// Set when the folder is created, the mentions of the views are not resolved until then
+  view_names: parking_lot::RwLock<Option<Arc<dyn DatabaseViewNames>>>,
+  week_settings: SharedWeekSettings,
+  database_change_tx: DatabaseChangeSender,
+  /// The ids of the databases whose changes are sent as notifications, with the number of their
+  /// subscribers
+  notified_database_ids: Arc<parking_lot::RwLock<HashMap<String, usize>>>,
+  is_notifying_changes: AtomicBool,
+  /// The ids of the imports that are running, used to prevent importing the same file twice
+  pub(crate) running_imports: parking_lot::Mutex<HashSet<String>>,
   #[allow(dead_code)]
   kv_persistence: Arc<DatabaseKVPersistence>,
   task_scheduler: Arc<RwLock<TaskDispatcher>>,
+  /// Set when the calculation of the rollup cells is started
+  rollup_controller: parking_lot::RwLock<Option<Arc<RollupController>>>,
+  /// Set when the calculation of the formula cells is started
+  formula_controller: parking_lot::RwLock<Option<Arc<FormulaController>>>,
+  /// Set when the automation scheduler is started
+  automation_controller: parking_lot::RwLock<Option<Arc<AutomationController>>>,
   #[allow(dead_code)]
   migration: DatabaseMigration,
+  config: DatabaseConfig,
 }
 
 impl DatabaseManager {
@@ -58,24 +253,106 @@ impl DatabaseManager {
     _rev_web_socket: Arc<dyn RevisionWebSocket>,
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
     database_db: Arc<dyn DatabaseDBConnection>,
+    file_storage: Arc<dyn DatabaseFileStorage>,
+    geocoder: Arc<dyn DatabaseGeocoder>,
+    row_documents: Arc<dyn DatabaseRowDocuments>,
+    inbox: Arc<dyn DatabaseInbox>,
+    config: DatabaseConfig,
   ) -> Self {
     let editors_by_database_id = RwLock::new(HashMap::new());
     let kv_persistence = Arc::new(DatabaseKVPersistence::new(database_db.clone()));
     let block_indexer = Arc::new(BlockRowIndexer::new(database_db.clone()));
-    let database_refs = Arc::new(DatabaseRefs::new(database_db));
+    let database_refs = Arc::new(DatabaseRefs::new(database_db.clone()));
+    let import_checkpoints = Arc::new(ImportCheckpoints::new(database_db.clone()));
+    let row_trash = Arc::new(RowTrash::new(database_db.clone()));
+    let row_shares = Arc::new(RowShares::new(database_db.clone()));
+    let relation_link_index = Arc::new(RelationLinkIndex::new(database_db.clone()));
+    let row_mention_index = Arc::new(RowMentionIndex::new(database_db.clone()));
+    let row_display_value_storage = Arc::new(RowDisplayValueStorage::new(database_db.clone()));
+    let automation_activities = Arc::new(AutomationActivities::new(database_db.clone()));
+    let automation_views = Arc::new(AutomationViews::new(database_db.clone()));
+    let fired_reminders = Arc::new(FiredReminders::new(database_db.clone()));
+    let row_document_index = Arc::new(RowDocumentIndex::new(database_db));
     let migration = DatabaseMigration::new(database_user.clone(), database_refs.clone());
+    let (database_change_tx, _) = broadcast::channel(1000);
+    let row_limit = RowLimit::new(config.max_rows_per_database);
     Self {
       editors_by_database_id,
       database_user,
+      file_storage,
+      geocoder,
+      row_documents,
+      row_document_index,
+      inbox,
+      fired_reminders,
       kv_persistence,
       block_indexer,
       database_refs,
+      import_checkpoints,
+      row_trash,
+      row_shares,
+      relation_link_index,
+      row_mention_index,
+      row_display_value_storage,
+      automation_activities,
+      automation_views,
+      row_limit,
+      max_attachment_size: AtomicU64::new(config.max_attachment_size),
+      quota_checker: Default::default(),
+      view_names: Default::default(),
+      week_settings: SharedWeekSettings::default(),
+      database_change_tx,
+      notified_database_ids: Default::default(),
+      is_notifying_changes: AtomicBool::new(false),
+      running_imports: Default::default(),
       task_scheduler,
+      rollup_controller: Default::default(),
+      formula_controller: Default::default(),
+      automation_controller: Default::default(),
       migration,
+      config,
     }
   }
 
-  pub async fn initialize_with_new_user(&self, _user_id: &str, _token: &str) -> FlowyResult<()> {
+  /// Overrides the max number of rows of each database, e.g. the quota of the workspace was
+  /// read from the cloud service. It applies to the opened databases too.
+  pub fn set_max_rows_per_database(&self, max_rows: usize) {
+    self.row_limit.set(max_rows);
+  }
+
+  /// Overrides the max size of the files that are attached to the cells, e.g. the quota of the
+  /// workspace was read from the cloud service.
+  pub fn set_max_attachment_size(&self, max_attachment_size: u64) {
+    self
+      .max_attachment_size
+      .store(max_attachment_size, Ordering::SeqCst);
+  }
+
+  /// Sets the checker of the workspace quota that is used before the files get attached.
+  pub fn set_quota_checker(&self, quota_checker: Arc<dyn DatabaseQuotaChecker>) {
+    *self.quota_checker.write() = Some(quota_checker);
+  }
+
+  /// Sets the reader of the view names that resolves the mentions of the views in the text
+  /// cells.
+  pub fn set_view_names(&self, view_names: Arc<dyn DatabaseViewNames>) {
+    *self.view_names.write() = Some(view_names);
+  }
+
+  /// Overrides the first day of the week and the working days, e.g. the settings of the
+  /// workspace were updated. It applies to the opened databases too.
+  pub async fn set_week_settings(&self, settings: WeekSettings) {
+    if self.week_settings.get() == settings {
+      return;
+    }
+    self.week_settings.set(settings);
+    for database_editor in self.get_opened_database_editors().await {
+      database_editor.did_update_week_settings().await;
+    }
+  }
+
+  pub async fn initialize_with_new_user(&self, user_id: &str, _token: &str) -> FlowyResult<()> {
+    self.set_current_user(user_id);
     Ok(())
   }
 
@@ -85,10 +362,80 @@ impl DatabaseManager {
     _token: &str,
     get_views_fn: Fut<Vec<(String, String, LayoutTypePB)>>,
   ) -> FlowyResult<()> {
+    self.set_current_user(user_id);
     self.migration.run(user_id, get_views_fn).await?;
     Ok(())
   }
 
+  /// Called when the current user signs out
+  pub async fn clear(&self, _user_id: &str) {
+    clear_current_user();
+  }
+
+  fn set_current_user(&self, user_id: &str) {
+    let name = self.database_user.user_name().unwrap_or_else(|err| {
+      tracing::error!("Read the name of the user failed: {:?}", err);
+      user_id.to_owned()
+    });
+    set_current_user(user_id, &name);
+  }
+
+  /// Permanently deletes the rows that have been in the trash longer than the retention period.
+  /// The relation cells that clear the deleted rows are unlinked from the purged rows, and the
+  /// attached files of the purged rows are deleted unless other cells of their databases still
+  /// reference them.
+  pub async fn purge_row_trash(&self) -> FlowyResult<usize> {
+    let deleted_before = timestamp() - self.config.row_trash_retention.as_secs() as i64;
+    self.purge_trashed_rows(deleted_before).await
+  }
+
+  /// Permanently deletes the fields of the opened databases that have been in the trash longer
+  /// than the retention period. The fields of the other databases are purged when the
+  /// databases are opened.
+  pub async fn purge_field_trash(&self) -> FlowyResult<usize> {
+    let deleted_before = timestamp() - self.config.field_trash_retention.as_secs() as i64;
+    let mut count = 0;
+    for database_editor in self.get_opened_database_editors().await {
+      count += database_editor.purge_trashed_fields(deleted_before).await?;
+    }
+    if count > 0 {
+      tracing::debug!("Purged {} trashed fields", count);
+    }
+    Ok(count)
+  }
+
+  /// Permanently deletes the rows that were moved to the trash before `deleted_before`, see
+  /// [DatabaseManager::purge_row_trash].
+  pub async fn purge_trashed_rows(&self, deleted_before: i64) -> FlowyResult<usize> {
+    let purged_rows = self.row_trash.purge(deleted_before)?;
+    if !purged_rows.is_empty() {
+      tracing::debug!("Purged {} trashed rows", purged_rows.len());
+    }
+
+    for purged_row in purged_rows.iter() {
+      self
+        .unlink_relation_dependents(&purged_row.database_id, Some(&purged_row.row_rev.id))
+        .await?;
+      let attachment_ids = attachment_ids_of_row(&purged_row.row_rev);
+      if attachment_ids.is_empty() {
+        continue;
+      }
+      let editor = self
+        .get_database_editor_with_database_id(&purged_row.database_id)
+        .await?;
+      for attachment_id in attachment_ids {
+        let is_referenced = match editor.as_ref() {
+          None => false,
+          Some(editor) => editor.is_attachment_referenced(&attachment_id).await?,
+        };
+        if !is_referenced {
+          self.file_storage.delete_file(&attachment_id)?;
+        }
+      }
+    }
+    Ok(purged_rows.len())
+  }
+
   #[tracing::instrument(level = "debug", skip_all, err)]
   pub async fn create_database<T: AsRef<str>>(
     &self,
@@ -108,7 +455,7 @@ impl DatabaseManager {
   }
 
   #[tracing::instrument(level = "debug", skip_all, err)]
-  async fn create_database_view<T: AsRef<str>>(
+  pub async fn create_database_view<T: AsRef<str>>(
     &self,
     view_id: T,
     revisions: Vec<Revision>,
@@ -132,6 +479,90 @@ impl DatabaseManager {
     Ok(())
   }
 
+  /// Saves the block of the new database and indexes its rows.
+  /// Creates the block and indexes its rows. The index is removed if the block can't be
+  /// created, so no row refers to a missing block.
+  pub async fn create_new_database_block(&self, block: NewDatabaseBlock) -> FlowyResult<()> {
+    let NewDatabaseBlock {
+      block_id,
+      row_ids,
+      revision,
+    } = block;
+    let mut result = row_ids
+      .iter()
+      .try_for_each(|row_id| self.block_indexer.insert(&block_id, row_id));
+    if result.is_ok() {
+      result = self.create_database_block(&block_id, vec![revision]).await;
+    }
+    if result.is_err() {
+      if let Err(err) = self.block_indexer.remove_block(&block_id) {
+        tracing::error!(
+          "Remove the index of the block {} failed: {:?}",
+          block_id,
+          err
+        );
+      }
+    }
+    result
+  }
+
+  /// Deletes the revisions of the database, its blocks with their rows and unbinds the views
+  /// that refer to it. It undoes [DatabaseManager::create_database], the database must not be
+  /// opened.
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub async fn delete_database(&self, database_id: &str) -> FlowyResult<()> {
+    let user_id = self.database_user.user_id()?;
+    let pool = self.database_user.db_pool()?;
+    let block_meta_revs = self
+      .load_database_pad(database_id)
+      .await?
+      .get_block_meta_revs();
+    for block_meta_rev in block_meta_revs {
+      self.delete_database_block(&block_meta_rev.block_id).await?;
+    }
+    for view_ref in self
+      .database_refs
+      .get_ref_views_with_database(database_id)?
+    {
+      self.database_refs.unbind(&view_ref.view_id)?;
+    }
+    self
+      .relation_link_index
+      .replace_links(database_id, None, vec![])?;
+    self
+      .row_display_value_storage
+      .delete_display_values(database_id)?;
+    SQLiteDatabaseRevisionPersistence::new(&user_id, pool)
+      .delete_revision_records(database_id, None)
+  }
+
+  /// Deletes the revisions of the block and the index of its rows. It undoes
+  /// [DatabaseManager::create_new_database_block].
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub async fn delete_database_block(&self, block_id: &str) -> FlowyResult<()> {
+    let user_id = self.database_user.user_id()?;
+    let pool = self.database_user.db_pool()?;
+    self.block_indexer.remove_block(block_id)?;
+    SQLiteDatabaseBlockRevisionPersistence::new(&user_id, pool)
+      .delete_revision_records(block_id, None)
+  }
+
+  /// Deletes the revisions of the database view and unbinds it from its database. It undoes
+  /// [DatabaseManager::create_database_view], the view must be closed.
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub async fn delete_database_view(&self, view_id: &str) -> FlowyResult<()> {
+    let user_id = self.database_user.user_id()?;
+    let pool = self.database_user.db_pool()?;
+    self.database_refs.unbind(view_id)?;
+    SQLiteDatabaseViewRevisionPersistence::new(&user_id, pool)
+      .delete_revision_records(view_id, None)
+  }
+
+  pub fn get_database_id_with_view(&self, view_id: &str) -> FlowyResult<String> {
+    let database_info = self.database_refs.get_database_with_view(view_id)?;
+    Ok(database_info.database_id)
+  }
+
   #[tracing::instrument(level = "trace", skip_all, err)]
   pub async fn open_database_view<T: AsRef<str>>(
     &self,
@@ -161,6 +592,9 @@ impl DatabaseManager {
     if let Some(database_editor) = database_editor {
       database_editor.close_view_editor(view_id).await;
       if database_editor.number_of_ref_views().await == 0 {
+        if let Err(err) = database_editor.refresh_relation_links().await {
+          tracing::error!("Index the relation links failed: {:?}", err);
+        }
         database_editor.dispose().await;
       } else {
         self
@@ -192,10 +626,346 @@ impl DatabaseManager {
     }
   }
 
+  /// Returns a receiver of the changes of the rows and the fields in all the opened databases,
+  /// e.g. for the automations. It receives the changes that happen after subscribing.
+  pub fn subscribe_database_changes(&self) -> broadcast::Receiver<DatabaseChangePB> {
+    self.database_change_tx.subscribe()
+  }
+
+  /// Starts sending the changes of the view's database as the
+  /// [DatabaseNotification::DidReceiveDatabaseChange] notifications, whose id is the returned
+  /// database id. It's used by the consumers outside the Rust side, e.g. the plugins. Each call
+  /// must be paired with a call of [Self::stop_notifying_database_changes].
+  pub fn notify_database_changes(&self, view_id: &str) -> FlowyResult<String> {
+    let database_id = self
+      .database_refs
+      .get_database_with_view(view_id)?
+      .database_id;
+    *self
+      .notified_database_ids
+      .write()
+      .entry(database_id.clone())
+      .or_default() += 1;
+    if !self.is_notifying_changes.swap(true, Ordering::SeqCst) {
+      send_database_change_notifications(
+        self.subscribe_database_changes(),
+        self.notified_database_ids.clone(),
+      );
+    }
+    Ok(database_id)
+  }
+
+  /// Unsubscribes from the changes of the view's database. The changes are no longer sent as
+  /// notifications once all the subscribers of the database have unsubscribed.
+  pub fn stop_notifying_database_changes(&self, view_id: &str) -> FlowyResult<()> {
+    let database_id = self
+      .database_refs
+      .get_database_with_view(view_id)?
+      .database_id;
+    let mut notified_database_ids = self.notified_database_ids.write();
+    if let Some(count) = notified_database_ids.get_mut(&database_id) {
+      *count -= 1;
+      if *count == 0 {
+        notified_database_ids.remove(&database_id);
+      }
+    }
+    Ok(())
+  }
+
   pub async fn get_databases(&self) -> FlowyResult<Vec<DatabaseInfo>> {
     self.database_refs.get_all_databases()
   }
 
+  /// Returns the databases that have the same fields as the `fields`, see
+  /// [is_same_database_schema]. The data of a template or an imported file can be linked into
+  /// one of them instead of creating another database.
+  pub async fn get_databases_with_schema(
+    &self,
+    fields: &[FieldSchemaPB],
+  ) -> FlowyResult<Vec<DatabaseInfo>> {
+    let mut database_infos = vec![];
+    for database_info in self.get_databases().await? {
+      let field_revs = match self.read_field_revs(&database_info.database_id).await {
+        Ok(field_revs) => field_revs,
+        Err(err) => {
+          tracing::error!(
+            "Read the fields of {} failed: {:?}",
+            database_info.database_id,
+            err
+          );
+          continue;
+        },
+      };
+      if is_same_database_schema(fields, &field_revs) {
+        database_infos.push(database_info);
+      }
+    }
+    Ok(database_infos)
+  }
+
+  /// Returns the fields of the database. They are read from the revisions if the database is not
+  /// opened, so no editor is created just to compare the schemas.
+  async fn read_field_revs(&self, database_id: &str) -> FlowyResult<Vec<Arc<FieldRevision>>> {
+    let database_editor = self
+      .editors_by_database_id
+      .read()
+      .await
+      .get(database_id)
+      .cloned();
+    if let Some(database_editor) = database_editor {
+      return database_editor.get_field_revs(None).await;
+    }
+
+    let field_revs = self
+      .load_database_pad(database_id)
+      .await?
+      .get_field_revs(None)?;
+    Ok(field_revs)
+  }
+
+  /// Builds the database from its revisions on disk without opening an editor.
+  async fn load_database_pad(&self, database_id: &str) -> FlowyResult<DatabaseRevisionPad> {
+    let pool = self.database_user.db_pool()?;
+    let revisions = self
+      .make_database_rev_manager(database_id, pool)?
+      .load_revisions()
+      .await?;
+    let database_pad = DatabaseRevisionPad::from_revisions(revisions)?;
+    Ok(database_pad)
+  }
+
+  /// Appends the rows of the source database to the target database. Each field of the source
+  /// database is merged into the field of the target database with the same name and type, see
+  /// [match_merged_fields]. The cells of the other fields are dropped and reported as the
+  /// conflicts. The options of the select fields are added to the target fields if they don't
+  /// have options with the same names. The relation cells of the merged rows link the copies of
+  /// the source rows instead, see [remap_relation_cells].
+  ///
+  /// The rows of the source database are moved to the trash if `trash_source` is true. The view
+  /// of the source database is kept, it's deleted by the folder. The appended rows and the added
+  /// options are removed again if the source rows can't be moved to the trash, so a failed merge
+  /// doesn't leave duplicated rows behind.
+  #[tracing::instrument(level = "debug", skip(self, params), err)]
+  pub async fn merge_databases(
+    &self,
+    params: MergeDatabasesParams,
+  ) -> FlowyResult<DatabaseMergeResultPB> {
+    let target_editor = self.get_database_editor(&params.target_view_id).await?;
+    let source_editor = self.get_database_editor(&params.source_view_id).await?;
+    if target_editor.database_id == source_editor.database_id {
+      return Err(FlowyError::invalid_data().context("Can't merge a database into itself"));
+    }
+
+    let source_field_revs = source_editor.get_field_revs(None).await?;
+    let source_row_revs = source_editor
+      .get_all_row_revs(&params.source_view_id)
+      .await?;
+    if params.trash_source {
+      self
+        .check_relation_dependents(&source_editor.database_id, None)
+        .await?;
+    }
+
+    let merged_rows = append_merged_rows(
+      &params.target_view_id,
+      &target_editor,
+      &source_field_revs,
+      &source_row_revs,
+    )
+    .await?;
+
+    if params.trash_source {
+      // The relation cells are unlinked from the source rows once they are purged
+      let source_row_ids = source_row_revs
+        .iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+      if let Err(err) = source_editor.delete_rows(&source_row_ids).await {
+        merged_rows
+          .undo(&params.target_view_id, &target_editor)
+          .await?;
+        return Err(err);
+      }
+    }
+
+    Ok(merged_rows.result)
+  }
+
+  /// Appends the rows of a template or an imported file to the existing database, which is
+  /// usually one of the [DatabaseManager::get_databases_with_schema]. The fields of the data are
+  /// merged like [DatabaseManager::merge_databases] does.
+  #[tracing::instrument(level = "debug", skip(self, build_context), err)]
+  pub async fn append_rows_to_database(
+    &self,
+    database_id: &str,
+    build_context: BuildDatabaseContext,
+  ) -> FlowyResult<DatabaseMergeResultPB> {
+    let view_id = match self
+      .get_database_ref_views(database_id)
+      .await?
+      .into_iter()
+      .next()
+    {
+      None => {
+        let msg = format!("The database: {} has no views", database_id);
+        return Err(FlowyError::record_not_found().context(msg));
+      },
+      Some(view_ref) => view_ref.view_id,
+    };
+    let target_editor = self.get_database_editor(&view_id).await?;
+    let row_revs = build_context
+      .blocks
+      .into_iter()
+      .flat_map(|block| block.rows)
+      .collect::<Vec<Arc<RowRevision>>>();
+    let merged_rows = append_merged_rows(
+      &view_id,
+      &target_editor,
+      &build_context.field_revs,
+      &row_revs,
+    )
+    .await?;
+    Ok(merged_rows.result)
+  }
+
+  /// Gives the colliding rows of the view's database new ids, see
+  /// [DatabaseEditor::repair_row_id_collisions]. The documents of the remapped rows are copied,
+  /// so both rows that had the same id keep the document. Returns the collisions that are left.
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub async fn repair_row_id_collisions(
+    &self,
+    view_id: &str,
+  ) -> FlowyResult<Vec<RowIdCollisionPB>> {
+    let editor = self.get_database_editor(view_id).await?;
+    for (row_id, new_row_id) in editor.repair_row_id_collisions().await? {
+      if let Some(document_id) = self.row_document_index.get_document_id(&row_id)? {
+        let new_document_id = gen_id();
+        self
+          .row_documents
+          .duplicate_document(&document_id, &new_document_id)
+          .await?;
+        self
+          .row_document_index
+          .insert(&new_row_id, &new_document_id)?;
+      }
+    }
+    editor.get_row_id_collisions().await
+  }
+
+  /// Returns the id of the document of the row, or None if the row has no document.
+  pub fn get_row_document_id(&self, row_id: &str) -> FlowyResult<Option<String>> {
+    self.row_document_index.get_document_id(row_id)
+  }
+
+  /// Returns the read-only content of the shared row with the id of its document.
+  pub async fn get_shared_row(&self, view_id: &str, share_id: &str) -> FlowyResult<SharedRowPB> {
+    let editor = self.get_database_editor(view_id).await?;
+    let mut shared_row = editor.get_shared_row(share_id).await?;
+    shared_row.document_id = self.get_row_document_id(&shared_row.share.row_id)?;
+    Ok(shared_row)
+  }
+
+  /// Binds the document to the row. The document must be created before it's bound.
+  pub fn bind_row_document(&self, row_id: &str, document_id: &str) -> FlowyResult<()> {
+    self.row_document_index.insert(row_id, document_id)
+  }
+
+  pub fn unbind_row_document(&self, row_id: &str) -> FlowyResult<()> {
+    self.row_document_index.remove(row_id)
+  }
+
+  /// Creates the document of the row with the `text` as its content and binds it to the row.
+  pub(crate) async fn create_row_document(&self, row_id: &str, text: &str) -> FlowyResult<()> {
+    let document_id = gen_id();
+    self
+      .row_documents
+      .create_document_from_text(&document_id, text)
+      .await?;
+    self.bind_row_document(row_id, &document_id)
+  }
+
+  pub fn import_checkpoints(&self) -> Arc<ImportCheckpoints> {
+    self.import_checkpoints.clone()
+  }
+
+  pub(crate) fn cell_size_limits(&self) -> &CellSizeLimits {
+    &self.config.cell_size_limits
+  }
+
+  pub(crate) fn contains_database_view(&self, view_id: &str) -> bool {
+    self.database_refs.get_database_with_view(view_id).is_ok()
+  }
+
+  /// Returns the text of the cell with the mentions replaced by the names of the rows or the
+  /// views. The names of the views are read from the folder.
+  pub async fn get_text_cell_data(&self, params: &CellIdParams) -> FlowyResult<TextCellDataPB> {
+    let editor = self.get_database_editor(&params.view_id).await?;
+    let mut view_names = HashMap::new();
+    let view_names_reader = self.view_names.read().clone();
+    if let Some(view_names_reader) = view_names_reader {
+      for view_id in editor.get_mentioned_view_ids(params).await? {
+        if let Some(name) = view_names_reader.get_view_name(&view_id).await {
+          view_names.insert(view_id, name);
+        }
+      }
+    }
+    editor
+      .get_text_cell_data(params, |view_id| view_names.get(view_id).cloned())
+      .await
+  }
+
+  /// Returns the text cells that mention the row in all the databases, which are shown as
+  /// "mentioned in" of the row.
+  pub async fn get_row_backlinks(&self, row_id: &str) -> FlowyResult<Vec<RowBacklinkPB>> {
+    self.refresh_row_mention_index().await?;
+    let mut cells_by_database_id: BTreeMap<String, Vec<(String, String)>> = BTreeMap::new();
+    for mention in self.row_mention_index.get_mentions(row_id)? {
+      cells_by_database_id
+        .entry(mention.database_id)
+        .or_default()
+        .push((mention.row_id, mention.field_id));
+    }
+
+    let mut backlinks = vec![];
+    for (database_id, mut mentioning_cells) in cells_by_database_id {
+      let editor = match self
+        .get_database_editor_with_database_id(&database_id)
+        .await?
+      {
+        None => continue,
+        Some(editor) => editor,
+      };
+      mentioning_cells.sort();
+      mentioning_cells.dedup();
+      backlinks.extend(editor.make_row_backlinks(mentioning_cells).await?);
+    }
+    Ok(backlinks)
+  }
+
+  /// Writes the changed text cells of the opened databases to the [RowMentionIndex]. The
+  /// databases that were never indexed are opened once to index them.
+  async fn refresh_row_mention_index(&self) -> FlowyResult<()> {
+    for editor in self.get_opened_database_editors().await {
+      editor.refresh_row_mentions().await?;
+    }
+
+    let user_id = self.database_user.user_id()?;
+    let key = md5(format!("{}{}", user_id, ROW_MENTION_INDEX_VERSION));
+    if KV::get_bool(&key) {
+      return Ok(());
+    }
+    for database_info in self.get_databases().await? {
+      if let Some(editor) = self
+        .get_database_editor_with_database_id(&database_info.database_id)
+        .await?
+      {
+        editor.refresh_row_mentions().await?;
+      }
+    }
+    KV::set_bool(&key, true);
+    Ok(())
+  }
+
   pub async fn get_database_ref_views(
     &self,
     database_id: &str,
@@ -203,6 +973,615 @@ impl DatabaseManager {
     self.database_refs.get_ref_views_with_database(database_id)
   }
 
+  /// Returns the primary cell text of the rows that the relation cell links, in the order they
+  /// were linked. The rows are read from the related database, the rows that were deleted from
+  /// it are skipped.
+  pub async fn get_related_rows(
+    &self,
+    params: &CellIdParams,
+  ) -> FlowyResult<Vec<RowDisplayValuePB>> {
+    let editor = self.get_database_editor(&params.view_id).await?;
+    let (type_option, cell_data) = editor
+      .get_relation_cell_data(&params.row_id, &params.field_id)
+      .await?;
+    if type_option.database_id.is_empty() || cell_data.row_ids.is_empty() {
+      return Ok(vec![]);
+    }
+
+    // Any view of the related database can open its editor
+    match self
+      .get_database_editor_with_database_id(&type_option.database_id)
+      .await?
+    {
+      None => Ok(vec![]),
+      Some(related_editor) => {
+        related_editor
+          .get_row_display_values(cell_data.row_ids)
+          .await
+      },
+    }
+  }
+
+  /// Returns the layout of the board, see [BoardLayout]. The relation cells are exported with
+  /// the titles of the rows they link instead of the row ids, so the titles of the rows of the
+  /// related databases are collected first.
+  pub async fn get_board_layout(&self, view_id: &str) -> FlowyResult<BoardLayout> {
+    let editor = self.get_database_editor(view_id).await?;
+    let related_database_ids = editor
+      .get_field_revs(None)
+      .await?
+      .iter()
+      .filter(|field_rev| FieldType::from(field_rev.ty).is_relation())
+      .map(|field_rev| RelationTypeOptionPB::from(field_rev.as_ref()).database_id)
+      .filter(|database_id| !database_id.is_empty())
+      .collect::<HashSet<String>>();
+
+    let mut related_row_titles = HashMap::new();
+    for related_database_id in related_database_ids {
+      if let Some(related_editor) = self
+        .get_database_editor_with_database_id(&related_database_id)
+        .await?
+      {
+        for row in related_editor.get_row_display_values(vec![]).await? {
+          related_row_titles.insert(row.row_id, row.display_value);
+        }
+      }
+    }
+    editor.get_board_layout(view_id, &related_row_titles).await
+  }
+
+  /// Returns the relation cells that link the row, or any row of the database if `row_id` is
+  /// None. The relation cells of the database itself are skipped when the whole database is
+  /// deleted, they are deleted along with it.
+  pub async fn get_relation_dependents(
+    &self,
+    database_id: &str,
+    row_id: Option<&str>,
+  ) -> FlowyResult<Vec<RelationDependentPB>> {
+    let dependents = self
+      .get_relation_links(database_id, row_id)
+      .await?
+      .into_iter()
+      .map(|relation_links| RelationDependentPB {
+        database_id: relation_links.editor.database_id.clone(),
+        field_id: relation_links.field_rev.id.clone(),
+        field_name: relation_links.field_rev.name.clone(),
+        on_delete: relation_links.on_delete,
+        row_ids: relation_links
+          .links
+          .into_iter()
+          .map(|(row_id, _)| row_id)
+          .collect(),
+      })
+      .collect();
+    Ok(dependents)
+  }
+
+  /// Returns [FlowyError::relation_dependents_exist] if a relation field that links the row, or
+  /// the database if `row_id` is None, refuses the deletion. See [RelationOnDeletePB].
+  pub async fn check_relation_dependents(
+    &self,
+    database_id: &str,
+    row_id: Option<&str>,
+  ) -> FlowyResult<()> {
+    let restricted_fields = self
+      .get_relation_dependents(database_id, row_id)
+      .await?
+      .into_iter()
+      .filter(|dependent| dependent.on_delete == RelationOnDeletePB::Restrict)
+      .map(|dependent| {
+        format!(
+          "{} ({} rows)",
+          dependent.field_name,
+          dependent.row_ids.len()
+        )
+      })
+      .collect::<Vec<String>>();
+    if restricted_fields.is_empty() {
+      return Ok(());
+    }
+
+    let msg = format!(
+      "Linked by the relation fields: {}",
+      restricted_fields.join(", ")
+    );
+    Err(FlowyError::relation_dependents_exist().context(msg))
+  }
+
+  /// Unlinks the row, or all the rows of the database if `row_id` is None, from the relation
+  /// cells whose field clears the deleted rows.
+  pub async fn unlink_relation_dependents(
+    &self,
+    database_id: &str,
+    row_id: Option<&str>,
+  ) -> FlowyResult<()> {
+    for relation_links in self.get_relation_links(database_id, row_id).await? {
+      if relation_links.on_delete != RelationOnDeletePB::Clear {
+        continue;
+      }
+      for (row_id, linked_row_ids) in relation_links.links {
+        relation_links
+          .editor
+          .update_cell_with_changeset(
+            &row_id,
+            &relation_links.field_rev.id,
+            RelationCellChangeset::from_removed_row_ids(linked_row_ids),
+          )
+          .await?;
+      }
+    }
+    Ok(())
+  }
+
+  /// Moves the row to the trash after checking the relation cells that link it. The cells keep
+  /// linking the row while it's in the trash, they are unlinked once it gets purged. See
+  /// [DatabaseManager::purge_row_trash].
+  pub async fn delete_row(&self, view_id: &str, row_id: &str) -> FlowyResult<()> {
+    self.delete_rows(view_id, vec![row_id.to_owned()]).await
+  }
+
+  /// Moves the rows to the trash. None of the rows is deleted if a relation field that links any
+  /// of them refuses the deletion.
+  pub async fn delete_rows(&self, view_id: &str, row_ids: Vec<String>) -> FlowyResult<()> {
+    let editor = self.get_database_editor(view_id).await?;
+    for row_id in row_ids.iter() {
+      self
+        .check_relation_dependents(&editor.database_id, Some(row_id))
+        .await?;
+    }
+    editor.delete_rows(&row_ids).await
+  }
+
+  async fn get_relation_links(
+    &self,
+    database_id: &str,
+    row_id: Option<&str>,
+  ) -> FlowyResult<Vec<RelationLinks>> {
+    self.refresh_relation_link_index().await?;
+    let linked_row_ids = row_id.map(|row_id| vec![row_id.to_owned()]);
+    let mut links_by_field: BTreeMap<(String, String), BTreeMap<String, Vec<String>>> =
+      BTreeMap::new();
+    for link in self
+      .relation_link_index
+      .get_links(database_id, linked_row_ids.as_deref())?
+    {
+      if row_id.is_none() && link.database_id == database_id {
+        continue;
+      }
+      links_by_field
+        .entry((link.database_id, link.field_id))
+        .or_default()
+        .entry(link.row_id)
+        .or_default()
+        .push(link.linked_row_id);
+    }
+
+    let mut relation_links = vec![];
+    for ((linking_database_id, field_id), links) in links_by_field {
+      let editor = match self
+        .get_database_editor_with_database_id(&linking_database_id)
+        .await?
+      {
+        None => continue,
+        Some(editor) => editor,
+      };
+      let field_rev = match editor.get_field_rev(&field_id).await {
+        Some(field_rev) if FieldType::from(field_rev.ty).is_relation() => field_rev,
+        _ => continue,
+      };
+      let type_option = RelationTypeOptionPB::from(field_rev.as_ref());
+      if type_option.database_id != database_id {
+        continue;
+      }
+      relation_links.push(RelationLinks {
+        editor,
+        field_rev,
+        on_delete: type_option.on_delete,
+        links: links.into_iter().collect(),
+      });
+    }
+    Ok(relation_links)
+  }
+
+  /// Returns the ids of the other databases whose relation cells link the rows of the database.
+  /// They are read from the [RelationLinkIndex], so the databases that aren't opened are found
+  /// without opening all of them.
+  pub(crate) async fn get_linking_database_ids(
+    &self,
+    database_id: &str,
+  ) -> FlowyResult<HashSet<String>> {
+    self.refresh_relation_link_index().await?;
+    Ok(
+      self
+        .relation_link_index
+        .get_links(database_id, None)?
+        .into_iter()
+        .map(|link| link.database_id)
+        .filter(|linking_database_id| linking_database_id != database_id)
+        .collect(),
+    )
+  }
+
+  /// Writes the changed relation cells of the opened databases to the [RelationLinkIndex]. The
+  /// databases that were never indexed are opened once to index them.
+  async fn refresh_relation_link_index(&self) -> FlowyResult<()> {
+    for editor in self.get_opened_database_editors().await {
+      editor.refresh_relation_links().await?;
+    }
+
+    let user_id = self.database_user.user_id()?;
+    let key = md5(format!("{}{}", user_id, RELATION_LINK_INDEX_VERSION));
+    if KV::get_bool(&key) {
+      return Ok(());
+    }
+    for database_info in self.get_databases().await? {
+      if let Some(editor) = self
+        .get_database_editor_with_database_id(&database_info.database_id)
+        .await?
+      {
+        editor.refresh_relation_links().await?;
+      }
+    }
+    KV::set_bool(&key, true);
+    Ok(())
+  }
+
+  /// Copies the file into the file storage and attaches it to the cell. The stored file is
+  /// deleted if the cell can't be updated.
+  pub async fn attach_file(&self, params: AttachFileParams) -> FlowyResult<AttachmentPB> {
+    let cell_id = params.cell_id;
+    let editor = self.get_database_editor(&cell_id.view_id).await?;
+    let (type_option, _) = editor
+      .get_attachment_cell_data(&cell_id.row_id, &cell_id.field_id)
+      .await?;
+    let metadata =
+      std::fs::metadata(&params.file_path).map_err(|e| FlowyError::invalid_data().context(e))?;
+    if !metadata.is_file() {
+      let msg = format!("{} is not a file", params.file_path);
+      return Err(FlowyError::invalid_data().context(msg));
+    }
+    type_option.check_file_size(metadata.len())?;
+    let max_attachment_size = self.max_attachment_size.load(Ordering::SeqCst);
+    if max_attachment_size > 0 && metadata.len() > max_attachment_size {
+      let msg = format!(
+        "The file should not be more than {} bytes, but it is {} bytes",
+        max_attachment_size,
+        metadata.len()
+      );
+      return Err(FlowyError::attachment_too_large().context(msg));
+    }
+    let quota_checker = self.quota_checker.read().clone();
+    if let Some(quota_checker) = quota_checker.as_ref() {
+      quota_checker.check_workspace_size()?;
+    }
+
+    let (file_id, size) = self.file_storage.store_file(&params.file_path)?;
+    if let Some(quota_checker) = quota_checker {
+      quota_checker.did_store_file(size);
+    }
+    let name = Path::new(&params.file_path)
+      .file_name()
+      .map(|name| name.to_string_lossy().to_string())
+      .unwrap_or_else(|| file_id.clone());
+    let attachment = AttachmentPB {
+      id: file_id,
+      name,
+      size: size as i64,
+      created_at: timestamp(),
+    };
+    let changeset = AttachmentCellChangeset::from_inserted_attachments(vec![attachment.clone()]);
+    if let Err(err) = editor
+      .update_cell_with_changeset(&cell_id.row_id, &cell_id.field_id, changeset)
+      .await
+    {
+      let _ = self.file_storage.delete_file(&attachment.id);
+      return Err(err);
+    }
+    Ok(attachment)
+  }
+
+  /// Runs the calculations, the filters, the sorts and the groups of the view again from scratch
+  /// instead of updating them incrementally. It recovers the view whose state got out of sync,
+  /// and reports how long each stage took.
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub async fn recompute_view(&self, view_id: &str) -> FlowyResult<RecomputeViewResultPB> {
+    let started_at = Instant::now();
+    let editor = self.get_database_editor(view_id).await?;
+
+    // The calculated cells are used by the filters, the sorts and the groups
+    let instant = Instant::now();
+    let rollup_controller = self.rollup_controller.read().clone();
+    if let Some(rollup_controller) = rollup_controller {
+      rollup_controller.recalculate(&editor).await?;
+    }
+    let formula_controller = self.formula_controller.read().clone();
+    if let Some(formula_controller) = formula_controller {
+      formula_controller.recalculate(&editor.database_id).await?;
+    }
+    let mut stages = vec![RecomputeStagePB::new(
+      RecomputeStageTypePB::Calculation,
+      instant.elapsed(),
+    )];
+    stages.extend(editor.recompute_view(view_id).await?);
+
+    Ok(RecomputeViewResultPB {
+      view_id: view_id.to_owned(),
+      stages,
+      elapsed_micros: started_at.elapsed().as_micros() as i64,
+    })
+  }
+
+  /// Sets the location of the cell. The address is geocoded if the coordinates are not given,
+  /// and the cell is cleared if both are empty.
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  pub async fn update_location_cell(&self, params: LocationCellChangesetParams) -> FlowyResult<()> {
+    let cell_id = params.cell_id;
+    let editor = self.get_database_editor(&cell_id.view_id).await?;
+    let changeset = match params.coordinates {
+      Some((latitude, longitude)) => LocationCellChangeset::Set {
+        latitude,
+        longitude,
+        address: params.address,
+      },
+      None if params.address.is_empty() => LocationCellChangeset::Clear,
+      None => {
+        let location = self
+          .geocoder
+          .geocode(&params.address)
+          .await?
+          .ok_or_else(|| {
+            let msg = format!("The address:{} is not found", params.address);
+            FlowyError::record_not_found().context(msg)
+          })?;
+        // Keep the address that the user typed instead of the one that the provider found
+        LocationCellChangeset::Set {
+          latitude: location.latitude,
+          longitude: location.longitude,
+          address: params.address,
+        }
+      },
+    };
+    editor
+      .update_cell_with_changeset(&cell_id.row_id, &cell_id.field_id, changeset)
+      .await
+  }
+
+  /// Removes the attachment from the cell. Its file is deleted from the file storage unless
+  /// another cell of the database, including the rows in the trash, still references it.
+  pub async fn remove_attachment(&self, params: AttachmentIdParams) -> FlowyResult<()> {
+    let cell_id = params.cell_id;
+    let editor = self.get_database_editor(&cell_id.view_id).await?;
+    let (_, cell_data) = editor
+      .get_attachment_cell_data(&cell_id.row_id, &cell_id.field_id)
+      .await?;
+    if cell_data.get_attachment(&params.attachment_id).is_none() {
+      let msg = format!("The cell has no attachment:{}", params.attachment_id);
+      return Err(FlowyError::record_not_found().context(msg));
+    }
+
+    let changeset =
+      AttachmentCellChangeset::from_removed_attachment_ids(vec![params.attachment_id.clone()]);
+    editor
+      .update_cell_with_changeset(&cell_id.row_id, &cell_id.field_id, changeset)
+      .await?;
+
+    if !editor
+      .is_attachment_referenced(&params.attachment_id)
+      .await?
+    {
+      self.file_storage.delete_file(&params.attachment_id)?;
+    }
+    Ok(())
+  }
+
+  /// Returns the attachment of the cell and the local path of its file.
+  pub async fn resolve_attachment(
+    &self,
+    params: AttachmentIdParams,
+  ) -> FlowyResult<ResolvedAttachmentPB> {
+    let cell_id = params.cell_id;
+    let editor = self.get_database_editor(&cell_id.view_id).await?;
+    let (_, cell_data) = editor
+      .get_attachment_cell_data(&cell_id.row_id, &cell_id.field_id)
+      .await?;
+    let attachment = cell_data
+      .get_attachment(&params.attachment_id)
+      .cloned()
+      .ok_or_else(|| {
+        let msg = format!("The cell has no attachment:{}", params.attachment_id);
+        FlowyError::record_not_found().context(msg)
+      })?;
+
+    let file_path = self.file_storage.file_path(&attachment.id)?;
+    if !Path::new(&file_path).exists() {
+      let msg = format!("The file of the attachment:{} is missing", attachment.id);
+      return Err(FlowyError::record_not_found().context(msg));
+    }
+    Ok(ResolvedAttachmentPB {
+      attachment,
+      file_path,
+    })
+  }
+
+  /// Returns the editor of the database. The database is opened with any of its views if it's
+  /// not opened yet, it returns None if the database has no views.
+  pub(crate) async fn get_database_editor_with_database_id(
+    &self,
+    database_id: &str,
+  ) -> FlowyResult<Option<Arc<DatabaseEditor>>> {
+    let database_editor = self
+      .editors_by_database_id
+      .read()
+      .await
+      .get(database_id)
+      .cloned();
+    if database_editor.is_some() {
+      return Ok(database_editor);
+    }
+
+    let view = self
+      .get_database_ref_views(database_id)
+      .await?
+      .into_iter()
+      .next();
+    match view {
+      None => Ok(None),
+      Some(view) => Ok(Some(self.get_database_editor(&view.view_id).await?)),
+    }
+  }
+
+  pub(crate) async fn is_database_view_opened(&self, view_id: &str) -> bool {
+    let database_info = match self.database_refs.get_database_with_view(view_id) {
+      Ok(database_info) => database_info,
+      Err(_) => return false,
+    };
+    let database_editor = self
+      .editors_by_database_id
+      .read()
+      .await
+      .get(&database_info.database_id)
+      .cloned();
+    match database_editor {
+      None => false,
+      Some(database_editor) => database_editor.is_view_open(view_id).await,
+    }
+  }
+
+  pub(crate) async fn get_opened_database_editors(&self) -> Vec<Arc<DatabaseEditor>> {
+    self
+      .editors_by_database_id
+      .read()
+      .await
+      .values()
+      .cloned()
+      .collect()
+  }
+
+  /// Returns the estimated bytes of the cell data caches of the opened databases.
+  pub async fn cell_data_cache_bytes(&self) -> usize {
+    self
+      .get_opened_database_editors()
+      .await
+      .iter()
+      .map(|editor| editor.cell_data_cache_bytes())
+      .sum()
+  }
+
+  /// Returns the estimated bytes of the filter results of the opened database views.
+  pub async fn filter_result_bytes(&self) -> usize {
+    let mut bytes = 0;
+    for editor in self.get_opened_database_editors().await {
+      bytes += editor.filter_result_bytes().await;
+    }
+    bytes
+  }
+
+  /// Returns the number of the local revisions of the opened databases that were not acked by
+  /// the server yet.
+  pub async fn number_of_unacked_revisions(&self) -> usize {
+    let mut number = 0;
+    for editor in self.get_opened_database_editors().await {
+      number += editor.number_of_unacked_revisions().await;
+    }
+    number
+  }
+
+  /// Drops the cell data caches and the display value caches of the opened databases. It's
+  /// called when the OS signals memory pressure.
+  pub async fn trim_caches(&self) {
+    for editor in self.get_opened_database_editors().await {
+      editor.trim_caches();
+    }
+  }
+
+  /// Starts calculating the rollup and the formula cells whenever the databases change. It's
+  /// called once after the manager is created.
+  pub async fn start_cell_calculation(self: &Arc<Self>) {
+    let rollup_controller = Arc::new(RollupController::new(Arc::downgrade(self)));
+    let formula_controller = Arc::new(FormulaController::new(Arc::downgrade(self)));
+    let calculation_controller = Arc::new(CalculationController::new(
+      Arc::downgrade(self),
+      self.task_scheduler.clone(),
+      rollup_controller.clone(),
+      formula_controller.clone(),
+    ));
+    self
+      .task_scheduler
+      .write()
+      .await
+      .register_handler(CalculationTaskHandler::new(calculation_controller.clone()));
+    *self.rollup_controller.write() = Some(rollup_controller);
+    *self.formula_controller.write() = Some(formula_controller);
+    run_calculation_scheduler(self.subscribe_database_changes(), calculation_controller);
+  }
+
+  /// Starts firing the automations of the database views. It's called after the user signed in,
+  /// it does nothing if the scheduler was started already.
+  pub async fn start_automation_scheduler(self: &Arc<Self>) {
+    if self.automation_controller.read().is_some() {
+      return;
+    }
+    let automation_controller = Arc::new(AutomationController::new(
+      Arc::downgrade(self),
+      self.task_scheduler.clone(),
+      self.automation_activities.clone(),
+      self.automation_views.clone(),
+      self.fired_reminders.clone(),
+      self.inbox.clone(),
+    ));
+    self
+      .task_scheduler
+      .write()
+      .await
+      .register_handler(AutomationTaskHandler::new(automation_controller.clone()));
+    *self.automation_controller.write() = Some(automation_controller.clone());
+    run_automation_scheduler(self.subscribe_database_changes(), automation_controller);
+  }
+
+  /// Fires the automations and the checklist reminders that are due at `now`, the timestamp in
+  /// seconds, and waits for them. They are fired by the automation scheduler in the background
+  /// otherwise.
+  pub async fn scan_automations_at(&self, now: i64) -> FlowyResult<()> {
+    let automation_controller = self.automation_controller.read().clone();
+    match automation_controller {
+      None => Ok(()),
+      Some(automation_controller) => automation_controller.scan(now).await,
+    }
+  }
+
+  /// Returns the latest executions of the automations of the view, the most recent comes first.
+  pub fn get_automation_activities(
+    &self,
+    view_id: &str,
+    limit: i64,
+  ) -> FlowyResult<Vec<AutomationActivity>> {
+    self.automation_activities.get_all(view_id, limit)
+  }
+
+  /// Saves the automation of the view, the view will be scanned by the automation scheduler even
+  /// if it's not opened.
+  pub async fn save_automation(&self, params: SaveAutomationParams) -> FlowyResult<AutomationPB> {
+    let view_id = params.view_id.clone();
+    let editor = self.open_database_view(&view_id).await?;
+    let automation = editor.save_automation(params).await?;
+    self.automation_views.insert(&view_id)?;
+    Ok(automation)
+  }
+
+  /// Deletes the automation of the view with its activities.
+  pub async fn delete_automation(&self, params: AutomationIdParams) -> FlowyResult<()> {
+    let view_id = params.view_id.clone();
+    let automation_id = params.automation_id.clone();
+    let editor = self.get_database_editor(&view_id).await?;
+    editor.delete_automation(params).await?;
+    if editor.get_automations(&view_id).await?.items.is_empty() {
+      self.automation_views.remove(&view_id)?;
+    }
+    self
+      .automation_activities
+      .remove_with_automation(&automation_id)
+  }
+
   async fn get_or_create_database_editor(
     &self,
     database_id: &str,
@@ -284,9 +1663,48 @@ impl DatabaseManager {
       self.block_indexer.clone(),
       self.database_refs.clone(),
       self.task_scheduler.clone(),
+      self.config.cell_size_limits.clone(),
+      self.row_limit.clone(),
+      self.week_settings.clone(),
+      self.row_trash.clone(),
+      self.row_shares.clone(),
+      self.row_mention_index.clone(),
+      self.relation_link_index.clone(),
+      self.row_display_value_storage.clone(),
+      self.inbox.clone(),
+      self.database_change_tx.clone(),
     )
     .await?;
 
+    let deleted_before = timestamp() - self.config.field_trash_retention.as_secs() as i64;
+    match database_editor.purge_trashed_fields(deleted_before).await {
+      Ok(count) if count > 0 => tracing::debug!("Purged {} trashed fields", count),
+      Ok(_) => {},
+      Err(err) => tracing::error!("Purge the trashed fields failed: {:?}", err),
+    }
+    if let Err(err) = database_editor.reconcile_auto_number_cells().await {
+      tracing::error!("Reconcile the auto number cells failed: {:?}", err);
+    }
+    let key = md5(format!(
+      "{}{}{}",
+      user_id, DATE_TIMEZONE_MIGRATION, database_id
+    ));
+    if !KV::get_bool(&key) {
+      match database_editor.migrate_date_field_timezones().await {
+        Ok(_) => KV::set_bool(&key, true),
+        Err(err) => tracing::error!("Pin the timezones of the date fields failed: {:?}", err),
+      }
+    }
+    if let Err(err) = database_editor.insert_current_member().await {
+      tracing::error!("Add the user to the person fields failed: {:?}", err);
+    }
+    if let Err(err) = database_editor.refresh_relation_links().await {
+      tracing::error!("Index the relation links failed: {:?}", err);
+    }
+    if let Err(err) = database_editor.refresh_row_mentions().await {
+      tracing::error!("Index the mentions of the rows failed: {:?}", err);
+    }
+
     let base_view_editor = DatabaseViewEditor::from_pad(
       &user_id,
       database_editor.database_view_data.clone(),
@@ -296,6 +1714,7 @@ impl DatabaseManager {
     )
     .await?;
     database_editor.open_view_editor(base_view_editor).await;
+    notify_invalid_cells(view_id, database_editor.clone());
 
     Ok(database_editor)
   }
@@ -332,6 +1751,153 @@ impl DatabaseManager {
   }
 }
 
+/// Merges the cells of the source rows into the fields of the target database and inserts them.
+/// It's shared by [DatabaseManager::merge_databases] and [DatabaseManager::append_rows_to_database].
+async fn append_merged_rows(
+  target_view_id: &str,
+  target_editor: &Arc<DatabaseEditor>,
+  source_field_revs: &[Arc<FieldRevision>],
+  source_row_revs: &[Arc<RowRevision>],
+) -> FlowyResult<DatabaseMergeResultPB> {
+  let target_field_revs = target_editor.get_field_revs(None).await?;
+  let (merged_fields, conflicts) = match_merged_fields(source_field_revs, &target_field_revs);
+  if merged_fields.is_empty() {
+    return Err(FlowyError::database_schema_not_match());
+  }
+  // Nothing is changed if the rows can't be merged
+  target_editor.check_row_limit(source_row_revs.len()).await?;
+
+  let mut option_ids_by_field_id = HashMap::new();
+  let mut added_options = 0;
+  let mut old_field_revs = vec![];
+  for merged_field in &merged_fields {
+    if !merged_field.field_type.is_select_option() {
+      continue;
+    }
+    let source_field_rev = source_field_revs
+      .iter()
+      .find(|field_rev| field_rev.id == merged_field.source_field_id);
+    let target_field_rev = target_field_revs
+      .iter()
+      .find(|field_rev| field_rev.id == merged_field.target_field_id);
+    let (source_field_rev, target_field_rev) = match (source_field_rev, target_field_rev) {
+      (Some(source_field_rev), Some(target_field_rev)) => (source_field_rev, target_field_rev),
+      _ => continue,
+    };
+    let (option_ids, new_options) = union_select_options(
+      select_type_option_from_field_rev(source_field_rev)?.options(),
+      select_type_option_from_field_rev(target_field_rev)?.options(),
+    );
+    if !new_options.is_empty() {
+      added_options += new_options.len();
+      old_field_revs.push(target_field_rev.clone());
+      target_editor
+        .modify_field_rev(target_view_id, &merged_field.target_field_id, |field_rev| {
+          let mut type_option = select_type_option_from_field_rev(field_rev)?;
+          type_option.mut_options().extend(new_options);
+          field_rev.insert_type_option(&*type_option);
+          Ok(Some(()))
+        })
+        .await?;
+    }
+    option_ids_by_field_id.insert(merged_field.target_field_id.clone(), option_ids);
+  }
+
+  // The items of the checklist are stored in the cells
+  let checklist_type_options = merged_fields
+    .iter()
+    .filter(|merged_field| merged_field.field_type.is_check_list())
+    .filter_map(|merged_field| {
+      source_field_revs
+        .iter()
+        .find(|field_rev| field_rev.id == merged_field.source_field_id)
+        .map(|field_rev| (field_rev.id.clone(), ChecklistTypeOptionPB::from(field_rev)))
+    })
+    .collect::<HashMap<String, ChecklistTypeOptionPB>>();
+
+  // The links of the relation cells can only be kept if both fields link the same database
+  let keeps_links_by_field_id = merged_fields
+    .iter()
+    .filter(|merged_field| merged_field.field_type.is_relation())
+    .filter_map(|merged_field| {
+      let source_field_rev = source_field_revs
+        .iter()
+        .find(|field_rev| field_rev.id == merged_field.source_field_id)?;
+      let target_field_rev = target_field_revs
+        .iter()
+        .find(|field_rev| field_rev.id == merged_field.target_field_id)?;
+      let keeps_links = RelationTypeOptionPB::from(source_field_rev).database_id
+        == RelationTypeOptionPB::from(target_field_rev).database_id;
+      Some((merged_field.target_field_id.clone(), keeps_links))
+    })
+    .collect::<HashMap<String, bool>>();
+
+  let block_id = target_editor.block_id().await?;
+  let mut row_revs = source_row_revs
+    .iter()
+    .map(|row_rev| {
+      let row_rev = fill_checklist_items(row_rev, &checklist_type_options);
+      make_merged_row_rev(&row_rev, &block_id, &merged_fields, &option_ids_by_field_id)
+    })
+    .collect::<Vec<RowRevision>>();
+  // The links to the source rows are replaced by the links to their copies
+  let row_ids = source_row_revs
+    .iter()
+    .zip(row_revs.iter())
+    .map(|(source_row_rev, row_rev)| (source_row_rev.id.clone(), row_rev.id.clone()))
+    .collect::<HashMap<String, String>>();
+  for row_rev in row_revs.iter_mut() {
+    remap_relation_cells(row_rev, &keeps_links_by_field_id, &row_ids);
+  }
+
+  let mut merged_rows = MergedRows {
+    result: DatabaseMergeResultPB {
+      merged_rows: row_revs.len() as i64,
+      added_options: added_options as i64,
+      conflicts,
+    },
+    row_ids: vec![],
+    old_field_revs,
+  };
+  if !row_revs.is_empty() {
+    // The rows are inserted at once, only the added options need to be removed if it fails
+    if let Err(err) = target_editor.insert_rows(row_revs).await {
+      merged_rows.undo(target_view_id, target_editor).await?;
+      return Err(err);
+    }
+  }
+  merged_rows.row_ids = row_ids.into_values().collect();
+  Ok(merged_rows)
+}
+
+/// The rows appended to the target database by [append_merged_rows].
+struct MergedRows {
+  result: DatabaseMergeResultPB,
+  row_ids: Vec<String>,
+  /// The target fields before the options of the source fields were added to them
+  old_field_revs: Vec<Arc<FieldRevision>>,
+}
+
+impl MergedRows {
+  /// Deletes the appended rows and removes the added options from the target fields.
+  async fn undo(
+    &self,
+    target_view_id: &str,
+    target_editor: &Arc<DatabaseEditor>,
+  ) -> FlowyResult<()> {
+    target_editor.discard_rows(&self.row_ids).await?;
+    for old_field_rev in &self.old_field_revs {
+      target_editor
+        .modify_field_rev(target_view_id, &old_field_rev.id, |field_rev| {
+          *field_rev = old_field_rev.as_ref().clone();
+          Ok(Some(()))
+        })
+        .await?;
+    }
+    Ok(())
+  }
+}
+
 pub async fn link_existing_database(
   view_id: &str,
   name: String,
@@ -364,13 +1930,37 @@ pub async fn link_existing_database(
   Ok(())
 }
 
-pub async fn create_new_database(
+/// The relation cells of a field that link the deleted row or the rows of the deleted database
+struct RelationLinks {
+  editor: Arc<DatabaseEditor>,
+  field_rev: Arc<FieldRevision>,
+  on_delete: RelationOnDeletePB,
+  /// The rows whose cells link the deleted rows, along with the linked rows
+  links: Vec<(String, Vec<String>)>,
+}
+
+/// The block of a new database, see [NewDatabase].
+pub struct NewDatabaseBlock {
+  pub block_id: String,
+  pub row_ids: Vec<String>,
+  pub revision: Revision,
+}
+
+/// The initial revisions of a new database. The blocks are created first, then the database
+/// and its view. Nothing is saved until they're created, see [create_new_database].
+pub struct NewDatabase {
+  pub database_id: String,
+  pub blocks: Vec<NewDatabaseBlock>,
+  pub database_revision: Revision,
+  pub view_revision: Revision,
+}
+
+pub fn make_new_database(
   view_id: &str,
   name: String,
   layout: LayoutTypePB,
-  database_manager: Arc<DatabaseManager>,
   build_context: BuildDatabaseContext,
-) -> FlowyResult<()> {
+) -> FlowyResult<NewDatabase> {
   let BuildDatabaseContext {
     field_revs,
     block_metas,
@@ -379,58 +1969,131 @@ pub async fn create_new_database(
     layout_setting,
   } = build_context;
 
-  for block_meta_data in &blocks {
-    let block_id = &block_meta_data.block_id;
-    // Indexing the block's rows
-    block_meta_data.rows.iter().for_each(|row| {
-      let _ = database_manager
-        .block_indexer
-        .insert(&row.block_id, &row.id);
-    });
-
-    // Create database's block
-    let database_block_ops = make_database_block_operations(block_meta_data);
-    let database_block_bytes = database_block_ops.json_bytes();
-    let revision = Revision::initial_revision(block_id, database_block_bytes);
-    database_manager
-      .create_database_block(&block_id, vec![revision])
-      .await?;
-  }
+  let blocks = blocks
+    .iter()
+    .map(|block_meta_data| {
+      let database_block_ops = make_database_block_operations(block_meta_data);
+      let database_block_bytes = database_block_ops.json_bytes();
+      NewDatabaseBlock {
+        block_id: block_meta_data.block_id.clone(),
+        row_ids: block_meta_data
+          .rows
+          .iter()
+          .map(|row| row.id.clone())
+          .collect(),
+        revision: Revision::initial_revision(&block_meta_data.block_id, database_block_bytes),
+      }
+    })
+    .collect::<Vec<_>>();
 
   let database_id = gen_database_id();
   let database_rev = DatabaseRevision::from_build_context(&database_id, field_revs, block_metas);
-
-  // Create database
-  tracing::trace!("Create new database: {}", database_id);
   let database_ops = make_database_operations(&database_rev);
   let database_bytes = database_ops.json_bytes();
-  let revision = Revision::initial_revision(&database_id, database_bytes);
-  database_manager
-    .create_database(&database_id, &view_id, &name, vec![revision])
-    .await?;
+  let database_revision = Revision::initial_revision(&database_id, database_bytes);
 
-  // Create database view
-  tracing::trace!("Create new database view: {}", view_id);
   let database_view = if database_view_data.is_empty() {
-    let mut database_view =
-      DatabaseViewRevision::new(database_id, view_id.to_owned(), true, name, layout.into());
+    let mut database_view = DatabaseViewRevision::new(
+      database_id.clone(),
+      view_id.to_owned(),
+      true,
+      name,
+      layout.into(),
+    );
     database_view.layout_settings = layout_setting;
     database_view
   } else {
     let mut database_view = DatabaseViewRevision::from_json(database_view_data)?;
-    database_view.database_id = database_id;
+    database_view.database_id = database_id.clone();
     // Replace the view id with the new one. This logic will be removed in the future.
     database_view.view_id = view_id.to_owned();
     database_view
   };
-
   let database_view_ops = make_database_view_operations(&database_view);
   let database_view_bytes = database_view_ops.json_bytes();
-  let revision = Revision::initial_revision(view_id, database_view_bytes);
-  database_manager
-    .create_database_view(view_id, vec![revision])
+  let view_revision = Revision::initial_revision(view_id, database_view_bytes);
+
+  Ok(NewDatabase {
+    database_id,
+    blocks,
+    database_revision,
+    view_revision,
+  })
+}
+
+/// Creates the blocks with the rows of the template, the database and its view. They're
+/// deleted if one of them fails, so the view is never saved with a half-created database.
+pub async fn create_new_database(
+  view_id: &str,
+  name: String,
+  layout: LayoutTypePB,
+  database_manager: Arc<DatabaseManager>,
+  build_context: BuildDatabaseContext,
+) -> FlowyResult<()> {
+  let mut transaction = Transaction::new(format!("Create database view {}", view_id));
+  create_new_database_in_transaction(
+    &mut transaction,
+    view_id,
+    name,
+    layout,
+    database_manager,
+    build_context,
+  )
+  .await?;
+  transaction.commit();
+  Ok(())
+}
+
+/// Runs the steps of [create_new_database] in the `transaction`, the caller commits it after
+/// adding its own steps.
+pub async fn create_new_database_in_transaction(
+  transaction: &mut Transaction,
+  view_id: &str,
+  name: String,
+  layout: LayoutTypePB,
+  database_manager: Arc<DatabaseManager>,
+  build_context: BuildDatabaseContext,
+) -> FlowyResult<()> {
+  let NewDatabase {
+    database_id,
+    blocks,
+    database_revision,
+    view_revision,
+  } = make_new_database(view_id, name.clone(), layout, build_context)?;
+
+  for block in blocks {
+    let manager = database_manager.clone();
+    let block_id = block.block_id.clone();
+    transaction
+      .step(
+        "create database block",
+        database_manager.create_new_database_block(block),
+        to_fut(async move { manager.delete_database_block(&block_id).await }),
+      )
+      .await?;
+  }
+
+  tracing::trace!("Create new database: {}", database_id);
+  let manager = database_manager.clone();
+  let cloned_database_id = database_id.clone();
+  transaction
+    .step(
+      "create database",
+      database_manager.create_database(&database_id, view_id, &name, vec![database_revision]),
+      to_fut(async move { manager.delete_database(&cloned_database_id).await }),
+    )
     .await?;
 
+  tracing::trace!("Create new database view: {}", view_id);
+  let manager = database_manager.clone();
+  let cloned_view_id = view_id.to_owned();
+  transaction
+    .step(
+      "create database view",
+      database_manager.create_database_view(view_id, vec![view_revision]),
+      to_fut(async move { manager.delete_database_view(&cloned_view_id).await }),
+    )
+    .await?;
   Ok(())
 }
 
@@ -445,3 +2108,59 @@ impl DatabaseRefIndexerQuery for Arc<DatabaseRefs> {
     (**self).get_ref_views(database_id)
   }
 }
+
+/// Validates the cells of the database in the background once it's opened, the
+/// [DatabaseNotification::DidDetectInvalidCells] notification is sent if there are invalid
+/// cells. They can be repaired by the [RepairInvalidCells](crate::event_map::DatabaseEvent)
+/// event.
+fn notify_invalid_cells(view_id: &str, database_editor: Arc<DatabaseEditor>) {
+  let view_id = view_id.to_owned();
+  tokio::spawn(async move {
+    match database_editor.get_invalid_cells().await {
+      Ok(items) if !items.is_empty() => {
+        tracing::warn!(
+          "Database view:{} has {} invalid cells",
+          view_id,
+          items.len()
+        );
+        send_notification(&view_id, DatabaseNotification::DidDetectInvalidCells)
+          .payload(InvalidCellsPB {
+            view_id: view_id.clone(),
+            items,
+          })
+          .send();
+      },
+      Ok(_) => {},
+      Err(err) => tracing::error!("Validate the cells of view:{} failed: {:?}", view_id, err),
+    }
+  });
+}
+
+fn send_database_change_notifications(
+  mut change_rx: broadcast::Receiver<DatabaseChangePB>,
+  notified_database_ids: Arc<parking_lot::RwLock<HashMap<String, usize>>>,
+) {
+  tokio::spawn(async move {
+    loop {
+      match change_rx.recv().await {
+        Ok(change) => {
+          if notified_database_ids
+            .read()
+            .contains_key(&change.database_id)
+          {
+            send_notification(
+              &change.database_id,
+              DatabaseNotification::DidReceiveDatabaseChange,
+            )
+            .payload(change)
+            .send();
+          }
+        },
+        Err(broadcast::error::RecvError::Lagged(count)) => {
+          tracing::warn!("{} database changes are not notified", count);
+        },
+        Err(broadcast::error::RecvError::Closed) => break,
+      }
+    }
+  });
+}
@@ -1,6 +1,6 @@
 use flowy_derive::ProtoBuf_Enum;
 use flowy_notification::NotificationBuilder;
-const OBSERVABLE_CATEGORY: &str = "Grid";
+pub const OBSERVABLE_CATEGORY: &str = "Grid";
 
 #[derive(ProtoBuf_Enum, Debug)]
 pub enum DatabaseNotification {
@@ -29,12 +29,20 @@ pub enum DatabaseNotification {
   DidReorderRows = 65,
   /// Trigger after editing the row that hit the sort rule
   DidReorderSingleRow = 66,
+  /// Trigger after the rows in the swimlanes of the board are changed
+  DidUpdateSwimlanes = 67,
   /// Trigger when the settings of the database are changed
   DidUpdateSettings = 70,
   // Trigger when the layout setting of the database is updated
   DidUpdateLayoutSettings = 80,
   // Trigger when the layout field of the database is changed
   DidSetNewLayoutField = 81,
+  /// Trigger after opening the database if some cells can't be read by their fields as they are
+  DidDetectInvalidCells = 90,
+  /// Trigger after the rows or the fields of the subscribed database are changed
+  DidReceiveDatabaseChange = 91,
+  /// Trigger after an automation of the view was executed on a row
+  DidExecuteAutomation = 92,
 }
 
 impl std::default::Default for DatabaseNotification {
@@ -0,0 +1,392 @@
+use crate::entities::{
+  AutomationActivityPB, DatabaseChangePB, DatabaseChangeTypePB, FieldType, MoveGroupRowParams,
+};
+use crate::manager::{
+  DatabaseInbox, DatabaseInboxKind, DatabaseInboxNotification, DatabaseManager,
+};
+use crate::notification::{send_notification, DatabaseNotification};
+use crate::services::automation::AUTOMATION_HANDLER_ID;
+use crate::services::cell::{FromCellString, TypeCellData};
+use crate::services::database::DatabaseEditor;
+use crate::services::field::{DateCellData, DateTimezone, DateTypeOptionPB};
+use crate::services::persistence::automation_activity::AutomationActivities;
+use crate::services::persistence::automation_view::AutomationViews;
+use crate::services::persistence::fired_reminder::FiredReminders;
+use chrono::{Local, NaiveDate, TimeZone};
+use database_model::{
+  AutomationActionRevision, AutomationRevision, AutomationTriggerRevision, RowRevision,
+};
+use flowy_error::FlowyResult;
+use flowy_task::{QualityOfService, Task, TaskContent, TaskDispatcher};
+use lib_infra::util::timestamp;
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+use tokio::sync::{broadcast, RwLock};
+
+/// The dates of the cells are checked against the triggers at this interval, so the automations
+/// fire shortly after their dates arrive.
+const AUTOMATION_SCAN_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The failed executions of an automation for the date of a row are retried until they fail
+/// this many times.
+const MAX_AUTOMATION_ATTEMPTS: i64 = 3;
+
+/// [AutomationController] fires the automations of the database views.
+///
+/// A scan is scheduled as a task in the [TaskDispatcher] periodically and whenever the rows of a
+/// database change. The scan fires the action of each enabled automation for the rows whose
+/// trigger date is between the day the automation was created and today, so the dates that
+/// passed while the app was closed are caught up.
+///
+/// The opened views are scanned every time. The views with automations are kept in the
+/// [AutomationViews], and the first scan of each day opens and scans the ones that are not
+/// opened, e.g. right after the app starts.
+///
+/// The fired dates are saved in the automation, which is synced with the view, so the action is
+/// not fired again for the same date of the row on any device. Each execution is also logged in
+/// the [AutomationActivities] of this device, the failed ones are retried.
+///
+/// The scan also adds the due reminders of the checklist items in the scanned databases to the
+/// [DatabaseInbox]. The fired reminders are kept in the [FiredReminders], so each reminder is
+/// only added once. The rows of the fired reminders are filtered again, so they show up in the
+/// views that only show the rows with overdue items.
+pub struct AutomationController {
+  database_manager: Weak<DatabaseManager>,
+  task_scheduler: Arc<RwLock<TaskDispatcher>>,
+  activities: Arc<AutomationActivities>,
+  automation_views: Arc<AutomationViews>,
+  fired_reminders: Arc<FiredReminders>,
+  inbox: Arc<dyn DatabaseInbox>,
+  is_scheduled: AtomicBool,
+  last_full_scan: Mutex<Option<NaiveDate>>,
+  /// Held while scanning, so the same date of a row is not fired by two scans at once
+  scan_lock: tokio::sync::Mutex<()>,
+}
+
+impl AutomationController {
+  pub(crate) fn new(
+    database_manager: Weak<DatabaseManager>,
+    task_scheduler: Arc<RwLock<TaskDispatcher>>,
+    activities: Arc<AutomationActivities>,
+    automation_views: Arc<AutomationViews>,
+    fired_reminders: Arc<FiredReminders>,
+    inbox: Arc<dyn DatabaseInbox>,
+  ) -> Self {
+    Self {
+      database_manager,
+      task_scheduler,
+      activities,
+      automation_views,
+      fired_reminders,
+      inbox,
+      is_scheduled: AtomicBool::new(false),
+      last_full_scan: Mutex::new(None),
+      scan_lock: tokio::sync::Mutex::new(()),
+    }
+  }
+
+  /// Schedules a scan of the automations. It's only scheduled once until the scan gets run.
+  pub async fn schedule_scan(&self) {
+    if self.is_scheduled.swap(true, Ordering::SeqCst) {
+      return;
+    }
+
+    let task_id = self.task_scheduler.read().await.next_task_id();
+    let task = Task::new(
+      AUTOMATION_HANDLER_ID,
+      task_id,
+      TaskContent::Text("".to_owned()),
+      QualityOfService::Background,
+    );
+    self.task_scheduler.write().await.add_task(task);
+  }
+
+  #[tracing::instrument(name = "process_automation_task", level = "trace", skip(self), err)]
+  pub(crate) async fn process(&self) -> FlowyResult<()> {
+    self.is_scheduled.store(false, Ordering::SeqCst);
+    self.scan(timestamp()).await
+  }
+
+  /// Fires the automations and the checklist reminders that are due at `now`, the timestamp in
+  /// seconds.
+  pub(crate) async fn scan(&self, now: i64) -> FlowyResult<()> {
+    let _scan_guard = self.scan_lock.lock().await;
+    let database_manager = match self.database_manager.upgrade() {
+      None => return Ok(()),
+      Some(database_manager) => database_manager,
+    };
+
+    let mut automation_view_ids = self
+      .automation_views
+      .get_all()?
+      .into_iter()
+      .collect::<HashSet<String>>();
+    let today = Local
+      .timestamp_opt(now, 0)
+      .single()
+      .map(|date_time| date_time.date_naive())
+      .unwrap_or_else(|| Local::now().date_naive());
+    let is_full_scan = self.last_full_scan.lock().replace(today) != Some(today);
+    let mut opened_view_ids = vec![];
+    if is_full_scan {
+      for view_id in automation_view_ids.clone() {
+        if database_manager.is_database_view_opened(&view_id).await {
+          continue;
+        }
+        match database_manager.open_database_view(&view_id).await {
+          Ok(_) => opened_view_ids.push(view_id),
+          Err(err) if err.is_record_not_found() => {
+            self.automation_views.remove(&view_id)?;
+            automation_view_ids.remove(&view_id);
+          },
+          Err(err) => tracing::error!("Open the view {} failed: {:?}", view_id, err),
+        }
+      }
+    }
+
+    for editor in database_manager.get_opened_database_editors().await {
+      if let Err(err) = self.fire_checklist_reminders(&editor, now).await {
+        tracing::error!("Fire the checklist reminders failed: {:?}", err);
+      }
+      for (view_id, automations) in editor.get_opened_automations().await {
+        // The automations might be synced from the other devices
+        match (
+          automations.is_empty(),
+          automation_view_ids.contains(&view_id),
+        ) {
+          (false, false) => self.automation_views.insert(&view_id)?,
+          (true, true) => self.automation_views.remove(&view_id)?,
+          _ => {},
+        }
+
+        for automation in automations
+          .iter()
+          .filter(|automation| automation.is_enabled)
+        {
+          if let Err(err) = self
+            .run_automation(&editor, &view_id, automation, now)
+            .await
+          {
+            tracing::error!("Run the automation {} failed: {:?}", automation.id, err);
+          }
+        }
+      }
+    }
+
+    for view_id in opened_view_ids {
+      if let Err(err) = database_manager.close_database_view(&view_id).await {
+        tracing::error!("Close the view {} failed: {:?}", view_id, err);
+      }
+    }
+    Ok(())
+  }
+
+  /// Adds the reminders of the unchecked checklist items that are due to the inbox, and applies
+  /// the overdue filters to their rows again.
+  async fn fire_checklist_reminders(
+    &self,
+    editor: &Arc<DatabaseEditor>,
+    now: i64,
+  ) -> FlowyResult<()> {
+    for reminder in editor.get_all_checklist_reminders().await? {
+      if reminder.is_completed || reminder.due > now {
+        continue;
+      }
+      // The reminder fires again if the due date of the item is changed
+      let reminder_id = format!("{}:{}:{}", reminder.row_id, reminder.item_id, reminder.due);
+      if self.fired_reminders.is_fired(&reminder_id)? {
+        continue;
+      }
+      editor.did_pass_due_date(&reminder.row_id).await;
+      let notification = DatabaseInboxNotification {
+        kind: DatabaseInboxKind::Reminder,
+        title: reminder.name,
+        body: editor.get_row_title(&reminder.row_id).await,
+        object_id: reminder.row_id,
+      };
+      self.inbox.add_notification(notification)?;
+      self.fired_reminders.insert(&reminder_id)?;
+    }
+    Ok(())
+  }
+
+  async fn run_automation(
+    &self,
+    editor: &Arc<DatabaseEditor>,
+    view_id: &str,
+    automation: &AutomationRevision,
+    now: i64,
+  ) -> FlowyResult<()> {
+    let (field_id, days_before) = match &automation.trigger {
+      AutomationTriggerRevision::DateArrived {
+        field_id,
+        days_before,
+      } => (field_id, *days_before),
+    };
+    // The field might be deleted or its type might be changed after the automation was saved
+    let timezone = match editor
+      .get_field_rev(field_id)
+      .await
+      .filter(|field_rev| FieldType::from(field_rev.ty) == FieldType::DateTime)
+      .and_then(|field_rev| field_rev.get_type_option::<DateTypeOptionPB>(field_rev.ty))
+    {
+      None => return Ok(()),
+      Some(type_option) => type_option.timezone(),
+    };
+
+    let mut fired_dates = automation.fired_dates.clone();
+    let mut row_ids = HashSet::new();
+    for block in editor.get_blocks(None).await? {
+      for row_rev in block.row_revs.iter() {
+        row_ids.insert(row_rev.id.clone());
+        let trigger_date = match arrived_date(
+          &timezone,
+          row_rev,
+          field_id,
+          days_before,
+          automation.created_at,
+          now,
+        ) {
+          None => continue,
+          Some(trigger_date) => trigger_date,
+        };
+        if fired_dates.get(&row_rev.id) == Some(&trigger_date) {
+          continue;
+        }
+        // The dates that were fired before they were synced with the automation
+        if self
+          .activities
+          .is_executed(&automation.id, &row_rev.id, &trigger_date)?
+        {
+          fired_dates.insert(row_rev.id.clone(), trigger_date);
+          continue;
+        }
+        if self
+          .activities
+          .number_of_failures(&automation.id, &row_rev.id, &trigger_date)?
+          >= MAX_AUTOMATION_ATTEMPTS
+        {
+          continue;
+        }
+
+        let error = execute_action(editor, view_id, &row_rev.id, &automation.action)
+          .await
+          .err()
+          .map(|err| err.to_string());
+        if error.is_none() {
+          fired_dates.insert(row_rev.id.clone(), trigger_date.clone());
+        }
+        let activity =
+          self
+            .activities
+            .add(&automation.id, view_id, &row_rev.id, &trigger_date, error)?;
+        send_notification(view_id, DatabaseNotification::DidExecuteAutomation)
+          .payload(AutomationActivityPB::from(activity))
+          .send();
+      }
+    }
+
+    // The fired dates of the deleted rows are dropped
+    fired_dates.retain(|row_id, _| row_ids.contains(row_id));
+    if fired_dates != automation.fired_dates {
+      editor
+        .set_automation_fired_dates(view_id, &automation.id, fired_dates)
+        .await?;
+    }
+    Ok(())
+  }
+}
+
+/// Returns the date of the cell in the form of `%Y-%m-%d` if its trigger date, `days_before`
+/// days before the date, is between the day the automation was created and the day of `now`.
+///
+/// The automations that were created before `created_at` was added only fire on the trigger
+/// date itself.
+fn arrived_date(
+  timezone: &DateTimezone,
+  row_rev: &RowRevision,
+  field_id: &str,
+  days_before: i64,
+  created_at: i64,
+  now: i64,
+) -> Option<String> {
+  let type_cell_data = row_rev
+    .cells
+    .get(field_id)
+    .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+    .filter(|type_cell_data| type_cell_data.is_date())?;
+  let timestamp = DateCellData::from_cell_str(&type_cell_data.cell_str)
+    .ok()?
+    .timestamp?;
+  let today = timezone.naive_local(now)?.date();
+  let trigger_days_from_today = timezone.days_between(today, timestamp)? - days_before;
+  let created_days_from_today = if created_at > 0 {
+    timezone.days_between(today, created_at)?
+  } else {
+    0
+  };
+  if trigger_days_from_today > 0 || trigger_days_from_today < created_days_from_today {
+    return None;
+  }
+  let date = timezone.naive_local(timestamp)?.date();
+  Some(date.format("%Y-%m-%d").to_string())
+}
+
+async fn execute_action(
+  editor: &Arc<DatabaseEditor>,
+  view_id: &str,
+  row_id: &str,
+  action: &AutomationActionRevision,
+) -> FlowyResult<()> {
+  match action {
+    AutomationActionRevision::MoveToGroup { group_id } => {
+      let group = editor.get_group(view_id, group_id).await?;
+      if group.rows.iter().any(|row| row.id == row_id) {
+        return Ok(());
+      }
+      editor
+        .move_group_row(MoveGroupRowParams {
+          view_id: view_id.to_owned(),
+          from_row_id: row_id.to_owned(),
+          to_group_id: group_id.clone(),
+          to_row_id: None,
+          to_swimlane_id: None,
+        })
+        .await
+    },
+  }
+}
+
+/// Scans the automations periodically and after the rows of the databases are changed, e.g.
+/// the date of a row was set to today.
+pub(crate) fn run_automation_scheduler(
+  mut change_rx: broadcast::Receiver<DatabaseChangePB>,
+  automation_controller: Arc<AutomationController>,
+) {
+  tokio::spawn(async move {
+    let mut interval = tokio::time::interval(AUTOMATION_SCAN_INTERVAL);
+    loop {
+      tokio::select! {
+        _ = interval.tick() => automation_controller.schedule_scan().await,
+        change = change_rx.recv() => match change {
+          Ok(change) => {
+            if matches!(
+              change.ty,
+              DatabaseChangeTypePB::RowCreated | DatabaseChangeTypePB::RowUpdated
+            ) {
+              automation_controller.schedule_scan().await;
+            }
+          },
+          Err(broadcast::error::RecvError::Lagged(count)) => {
+            tracing::warn!("{} database changes are dropped by the automations", count);
+            automation_controller.schedule_scan().await;
+          },
+          Err(broadcast::error::RecvError::Closed) => break,
+        },
+      }
+    }
+  });
+}
@@ -0,0 +1,7 @@
+mod controller;
+mod task;
+
+pub use controller::*;
+pub(crate) use task::*;
+
+pub(crate) const AUTOMATION_HANDLER_ID: &str = "automation";
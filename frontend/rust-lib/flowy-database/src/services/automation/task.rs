@@ -0,0 +1,37 @@
+use crate::services::automation::{AutomationController, AUTOMATION_HANDLER_ID};
+use flowy_task::{TaskContent, TaskHandler};
+use lib_infra::future::BoxResultFuture;
+use std::sync::Arc;
+
+pub(crate) struct AutomationTaskHandler {
+  automation_controller: Arc<AutomationController>,
+}
+
+impl AutomationTaskHandler {
+  pub(crate) fn new(automation_controller: Arc<AutomationController>) -> Self {
+    Self {
+      automation_controller,
+    }
+  }
+}
+
+impl TaskHandler for AutomationTaskHandler {
+  fn handler_id(&self) -> &str {
+    AUTOMATION_HANDLER_ID
+  }
+
+  fn handler_name(&self) -> &str {
+    "AutomationTaskHandler"
+  }
+
+  fn run(&self, _content: TaskContent) -> BoxResultFuture<(), anyhow::Error> {
+    let automation_controller = self.automation_controller.clone();
+    Box::pin(async move {
+      automation_controller
+        .process()
+        .await
+        .map_err(anyhow::Error::from)?;
+      Ok(())
+    })
+  }
+}
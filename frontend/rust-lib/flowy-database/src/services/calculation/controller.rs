@@ -0,0 +1,139 @@
+use crate::entities::DatabaseChangePB;
+use crate::manager::DatabaseManager;
+use crate::services::calculation::CALCULATION_HANDLER_ID;
+use crate::services::formula::FormulaController;
+use crate::services::rollup::RollupController;
+use flowy_error::FlowyResult;
+use flowy_task::{QualityOfService, Task, TaskContent, TaskDispatcher};
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+use tokio::sync::{broadcast, RwLock};
+
+/// The formulas that call `now()` are calculated again at this interval.
+const NOW_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// [CalculationController] keeps the computed cells of the opened databases up to date, the
+/// rollup cells with the [RollupController] and the formula cells with the [FormulaController].
+///
+/// Each change of a database is collected by both controllers, and a single task is scheduled
+/// in the [TaskDispatcher] for the database. A database will only be scheduled once no matter
+/// how many times it was changed before the task gets run. The task calculates the rollups
+/// first, because the formulas can refer to the rollup cells.
+pub struct CalculationController {
+  database_manager: Weak<DatabaseManager>,
+  task_scheduler: Arc<RwLock<TaskDispatcher>>,
+  rollup_controller: Arc<RollupController>,
+  formula_controller: Arc<FormulaController>,
+  scheduled_database_ids: Mutex<HashSet<String>>,
+}
+
+impl CalculationController {
+  pub(crate) fn new(
+    database_manager: Weak<DatabaseManager>,
+    task_scheduler: Arc<RwLock<TaskDispatcher>>,
+    rollup_controller: Arc<RollupController>,
+    formula_controller: Arc<FormulaController>,
+  ) -> Self {
+    Self {
+      database_manager,
+      task_scheduler,
+      rollup_controller,
+      formula_controller,
+      scheduled_database_ids: Mutex::new(HashSet::new()),
+    }
+  }
+
+  /// Schedules a task to calculate the cells that depend on the change.
+  pub async fn did_change_database(&self, change: DatabaseChangePB) {
+    self
+      .rollup_controller
+      .did_change_database(&change.database_id);
+    self.formula_controller.did_change_database(&change);
+    self.schedule(&change.database_id).await;
+  }
+
+  /// Schedules the tasks to calculate the formulas of the opened databases that call `now()`.
+  pub async fn did_pass_time(&self) {
+    for database_id in self.opened_database_ids().await {
+      self.formula_controller.did_pass_time(&database_id);
+      self.schedule(&database_id).await;
+    }
+  }
+
+  /// Schedules the tasks to calculate all the cells of the opened databases. It's called when
+  /// some of the changes were dropped, so the changed rows are unknown.
+  pub async fn did_lag(&self) {
+    for database_id in self.opened_database_ids().await {
+      self.rollup_controller.did_change_database(&database_id);
+      self.formula_controller.did_change_all_rows(&database_id);
+      self.schedule(&database_id).await;
+    }
+  }
+
+  async fn opened_database_ids(&self) -> Vec<String> {
+    match self.database_manager.upgrade() {
+      None => vec![],
+      Some(database_manager) => database_manager
+        .get_opened_database_editors()
+        .await
+        .iter()
+        .map(|editor| editor.database_id.clone())
+        .collect(),
+    }
+  }
+
+  async fn schedule(&self, database_id: &str) {
+    if !self
+      .scheduled_database_ids
+      .lock()
+      .insert(database_id.to_owned())
+    {
+      return;
+    }
+
+    let task_id = self.task_scheduler.read().await.next_task_id();
+    let task = Task::new(
+      CALCULATION_HANDLER_ID,
+      task_id,
+      TaskContent::Text(database_id.to_owned()),
+      QualityOfService::Background,
+    );
+    self.task_scheduler.write().await.add_task(task);
+  }
+
+  #[tracing::instrument(name = "process_calculation_task", level = "trace", skip(self), err)]
+  pub(crate) async fn process(&self, database_id: &str) -> FlowyResult<()> {
+    self.scheduled_database_ids.lock().remove(database_id);
+    // The formulas are calculated even if the rollups failed
+    let rollup_result = self.rollup_controller.process(database_id).await;
+    self.formula_controller.process(database_id).await?;
+    rollup_result
+  }
+}
+
+/// Schedules the calculation of the computed cells whenever a database changes, and of the
+/// formulas that call `now()` periodically. The task ends when the sender of the changes is
+/// dropped.
+pub(crate) fn run_calculation_scheduler(
+  mut change_rx: broadcast::Receiver<DatabaseChangePB>,
+  calculation_controller: Arc<CalculationController>,
+) {
+  tokio::spawn(async move {
+    let mut interval = tokio::time::interval(NOW_REFRESH_INTERVAL);
+    loop {
+      tokio::select! {
+        _ = interval.tick() => calculation_controller.did_pass_time().await,
+        change = change_rx.recv() => match change {
+          Ok(change) => calculation_controller.did_change_database(change).await,
+          Err(broadcast::error::RecvError::Lagged(count)) => {
+            tracing::warn!("{} database changes are dropped by the calculations", count);
+            calculation_controller.did_lag().await;
+          },
+          Err(broadcast::error::RecvError::Closed) => break,
+        },
+      }
+    }
+  });
+}
@@ -0,0 +1,39 @@
+use crate::services::calculation::{CalculationController, CALCULATION_HANDLER_ID};
+use flowy_task::{TaskContent, TaskHandler};
+use lib_infra::future::BoxResultFuture;
+use std::sync::Arc;
+
+pub(crate) struct CalculationTaskHandler {
+  calculation_controller: Arc<CalculationController>,
+}
+
+impl CalculationTaskHandler {
+  pub(crate) fn new(calculation_controller: Arc<CalculationController>) -> Self {
+    Self {
+      calculation_controller,
+    }
+  }
+}
+
+impl TaskHandler for CalculationTaskHandler {
+  fn handler_id(&self) -> &str {
+    CALCULATION_HANDLER_ID
+  }
+
+  fn handler_name(&self) -> &str {
+    "CalculationTaskHandler"
+  }
+
+  fn run(&self, content: TaskContent) -> BoxResultFuture<(), anyhow::Error> {
+    let calculation_controller = self.calculation_controller.clone();
+    Box::pin(async move {
+      if let TaskContent::Text(database_id) = content {
+        calculation_controller
+          .process(&database_id)
+          .await
+          .map_err(anyhow::Error::from)?;
+      }
+      Ok(())
+    })
+  }
+}
@@ -0,0 +1,67 @@
+use crate::entities::{
+  AccessibilityRolePB, CellAccessibilityPB, CellAccessibilityStatePB, FieldType, RowAccessibilityPB,
+};
+use crate::services::cell::{stringify_cell_data, TypeCellData};
+use crate::services::field::CHECK;
+use database_model::{CellRevision, FieldRevision, RowRevision};
+use std::sync::Arc;
+
+/// Generates what the screen readers announce for the cell. The value is the display string of
+/// the cell, see [stringify_cell_data], so every platform announces the same content as the one
+/// that is exported. The sentence is composed by the platforms from the label, the value and
+/// the state, so it can be translated.
+pub fn make_cell_accessibility(
+  field_rev: &FieldRevision,
+  cell_rev: Option<&CellRevision>,
+) -> CellAccessibilityPB {
+  let field_type: FieldType = field_rev.ty.into();
+  let value = cell_rev
+    .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+    .map(|type_cell_data| {
+      stringify_cell_data(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        &field_type,
+        field_rev,
+      )
+    })
+    .unwrap_or_default();
+
+  let state = match field_type {
+    FieldType::Checkbox if value == CHECK => CellAccessibilityStatePB::Checked,
+    FieldType::Checkbox => CellAccessibilityStatePB::Unchecked,
+    _ if value.is_empty() => CellAccessibilityStatePB::Empty,
+    _ => CellAccessibilityStatePB::Filled,
+  };
+  let is_empty = matches!(
+    state,
+    CellAccessibilityStatePB::Empty | CellAccessibilityStatePB::Unchecked
+  );
+  CellAccessibilityPB {
+    label: field_rev.name.clone(),
+    value,
+    role: AccessibilityRolePB::from(&field_type),
+    is_read_only: field_type.is_computed(),
+    state,
+    is_announced_in_row: field_rev.is_primary || !is_empty,
+  }
+}
+
+/// Generates what the screen readers announce for the row, i.e. its visible cells with the
+/// primary one first.
+pub fn make_row_accessibility(
+  field_revs: &[Arc<FieldRevision>],
+  row_rev: &RowRevision,
+) -> RowAccessibilityPB {
+  let mut field_revs = field_revs
+    .iter()
+    .filter(|field_rev| field_rev.visibility)
+    .collect::<Vec<&Arc<FieldRevision>>>();
+  field_revs.sort_by_key(|field_rev| !field_rev.is_primary);
+
+  let cells = field_revs
+    .into_iter()
+    .map(|field_rev| make_cell_accessibility(field_rev, row_rev.cells.get(&field_rev.id)))
+    .collect::<Vec<CellAccessibilityPB>>();
+  RowAccessibilityPB { cells }
+}
@@ -0,0 +1,43 @@
+use dashmap::DashSet;
+use flowy_error::{FlowyError, FlowyResult};
+
+/// The cells that are in conflicted state. The changesets of a conflicted cell are rejected with
+/// the `CellIsConflicted` error code until the conflict is resolved.
+#[derive(Debug, Default)]
+pub struct ConflictedCells {
+  cells: DashSet<(String, String)>,
+}
+
+impl ConflictedCells {
+  /// Returns true if the cell was not in conflicted state before
+  pub fn mark(&self, row_id: &str, field_id: &str) -> bool {
+    self.cells.insert((row_id.to_owned(), field_id.to_owned()))
+  }
+
+  /// Returns true if the cell was in conflicted state before
+  pub fn resolve(&self, row_id: &str, field_id: &str) -> bool {
+    self
+      .cells
+      .remove(&(row_id.to_owned(), field_id.to_owned()))
+      .is_some()
+  }
+
+  pub fn contains(&self, row_id: &str, field_id: &str) -> bool {
+    self
+      .cells
+      .contains(&(row_id.to_owned(), field_id.to_owned()))
+  }
+
+  /// Returns error if the cell is in conflicted state
+  pub fn check_cell(&self, row_id: &str, field_id: &str) -> FlowyResult<()> {
+    if self.contains(row_id, field_id) {
+      let msg = format!(
+        "The cell of the row:{} and the field:{} is in conflict",
+        row_id, field_id
+      );
+      Err(FlowyError::cell_is_conflicted().context(msg))
+    } else {
+      Ok(())
+    }
+  }
+}
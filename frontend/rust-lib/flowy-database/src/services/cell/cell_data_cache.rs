@@ -23,12 +23,26 @@ where
   }
 
   pub fn insert<T>(&mut self, key: &TypeValueKey, val: T) -> Option<T>
+  where
+    T: 'static + Send + Sync,
+  {
+    self.insert_with_heap_bytes(key, val, 0)
+  }
+
+  /// Inserts the value with the estimated bytes of the data it points to on the heap, e.g. the
+  /// bytes of its strings. The cache can't measure them because the values are type-erased.
+  pub fn insert_with_heap_bytes<T>(
+    &mut self,
+    key: &TypeValueKey,
+    val: T,
+    heap_bytes: usize,
+  ) -> Option<T>
   where
     T: 'static + Send + Sync,
   {
     self
       .0
-      .insert(key.clone(), TypeValue::new(val))
+      .insert(key.clone(), TypeValue::new(val, heap_bytes))
       .and_then(downcast_owned)
   }
 
@@ -70,6 +84,24 @@ where
   pub fn is_empty(&self) -> bool {
     self.0.is_empty()
   }
+
+  pub fn len(&self) -> usize {
+    self.0.len()
+  }
+
+  pub fn clear(&mut self) {
+    self.0.clear();
+  }
+
+  /// Returns the estimated bytes of the cached values, i.e. the size of the values' types plus
+  /// the heap bytes that were given when they were inserted.
+  pub fn estimated_bytes(&self) -> usize {
+    self
+      .0
+      .values()
+      .map(|type_value| std::mem::size_of::<TypeValueKey>() + type_value.size)
+      .sum()
+  }
 }
 
 fn downcast_owned<T: 'static + Send + Sync>(type_value: TypeValue) -> Option<T> {
@@ -81,16 +113,18 @@ struct TypeValue {
   boxed: Box<dyn Any + Send + Sync + 'static>,
   #[allow(dead_code)]
   ty: &'static str,
+  size: usize,
 }
 
 impl TypeValue {
-  pub fn new<T>(value: T) -> Self
+  pub fn new<T>(value: T, heap_bytes: usize) -> Self
   where
     T: Send + Sync + 'static,
   {
     Self {
       boxed: Box::new(value),
       ty: type_name::<T>(),
+      size: std::mem::size_of::<T>() + heap_bytes,
     }
   }
 }
@@ -1,11 +1,12 @@
 use crate::entities::FieldType;
-use crate::services::cell::{AtomicCellDataCache, CellProtobufBlob, TypeCellData};
+use crate::services::cell::{AtomicCellDataCache, CellProtobufBlob, CellSizeLimits, TypeCellData};
 use crate::services::field::*;
 
 use crate::services::group::make_no_status_group;
 use database_model::{CellRevision, FieldRevision};
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
 
+use std::collections::HashSet;
 use std::fmt::Debug;
 
 /// Decode the opaque cell data into readable format content
@@ -53,11 +54,14 @@ pub trait CellDataChangeset: TypeOption {
 ///         FieldType::SingleSelect => SelectOptionChangeset
 ///
 /// cell_rev: It will be None if the cell does not contain any data.
+///
+/// cell_size_limits: The cell is not limited if it's None.
 pub fn apply_cell_data_changeset<C: ToCellChangesetString, T: AsRef<FieldRevision>>(
   changeset: C,
   cell_rev: Option<CellRevision>,
   field_rev: T,
   cell_data_cache: Option<AtomicCellDataCache>,
+  cell_size_limits: Option<&CellSizeLimits>,
 ) -> Result<String, FlowyError> {
   let field_rev = field_rev.as_ref();
   let changeset = changeset.to_cell_changeset_str();
@@ -74,6 +78,9 @@ pub fn apply_cell_data_changeset<C: ToCellChangesetString, T: AsRef<FieldRevisio
     None => "".to_string(),
     Some(handler) => handler.handle_cell_changeset(changeset, type_cell_data, field_rev)?,
   };
+  if let Some(cell_size_limits) = cell_size_limits {
+    cell_size_limits.check_cell_str(&field_type, &cell_str)?;
+  }
   Ok(TypeCellData::new(cell_str, field_type).to_json())
 }
 
@@ -213,17 +220,27 @@ pub fn stringify_cell_data(
   }
 }
 
-pub fn insert_text_cell(s: String, field_rev: &FieldRevision) -> CellRevision {
-  let data = apply_cell_data_changeset(s, None, field_rev, None).unwrap();
-  CellRevision::new(data)
+/// cell_size_limits: The cell is not limited if it's None.
+pub fn insert_text_cell(
+  s: String,
+  field_rev: &FieldRevision,
+  cell_size_limits: Option<&CellSizeLimits>,
+) -> FlowyResult<CellRevision> {
+  let data = apply_cell_data_changeset(s, None, field_rev, None, cell_size_limits)?;
+  Ok(CellRevision::new(data))
 }
 
 pub fn insert_number_cell(num: i64, field_rev: &FieldRevision) -> CellRevision {
-  let data = apply_cell_data_changeset(num.to_string(), None, field_rev, None).unwrap();
+  let data = apply_cell_data_changeset(num.to_string(), None, field_rev, None, None).unwrap();
   CellRevision::new(data)
 }
 
-pub fn insert_url_cell(url: String, field_rev: &FieldRevision) -> CellRevision {
+/// cell_size_limits: The cell is not limited if it's None.
+pub fn insert_url_cell(
+  url: String,
+  field_rev: &FieldRevision,
+  cell_size_limits: Option<&CellSizeLimits>,
+) -> FlowyResult<CellRevision> {
   // checking if url is equal to group id of no status group because everywhere
   // except group of rows with empty url the group id is equal to the url
   // so then on the case that url is equal to empty url group id we should change
@@ -234,8 +251,8 @@ pub fn insert_url_cell(url: String, field_rev: &FieldRevision) -> CellRevision {
     _ => url,
   };
 
-  let data = apply_cell_data_changeset(url, None, field_rev, None).unwrap();
-  CellRevision::new(data)
+  let data = apply_cell_data_changeset(url, None, field_rev, None, cell_size_limits)?;
+  Ok(CellRevision::new(data))
 }
 
 pub fn insert_checkbox_cell(is_check: bool, field_rev: &FieldRevision) -> CellRevision {
@@ -244,7 +261,7 @@ pub fn insert_checkbox_cell(is_check: bool, field_rev: &FieldRevision) -> CellRe
   } else {
     UNCHECK.to_string()
   };
-  let data = apply_cell_data_changeset(s, None, field_rev, None).unwrap();
+  let data = apply_cell_data_changeset(s, None, field_rev, None, None).unwrap();
   CellRevision::new(data)
 }
 
@@ -254,19 +271,66 @@ pub fn insert_date_cell(date_cell_data: DateCellData, field_rev: &FieldRevision)
     time: None,
     include_time: Some(date_cell_data.include_time),
     is_utc: true,
+    text: None,
+    locale: None,
+    recurrence: None,
+    clear_recurrence: false,
   })
   .unwrap();
-  let data = apply_cell_data_changeset(cell_data, None, field_rev, None).unwrap();
+  let data = apply_cell_data_changeset(cell_data, None, field_rev, None, None).unwrap();
   CellRevision::new(data)
 }
 
+/// The options that can't be selected with the `parent_option_ids`, the options that are
+/// selected in the field that the field depends on, are dropped.
 pub fn insert_select_option_cell(
   option_ids: Vec<String>,
+  parent_option_ids: &[String],
   field_rev: &FieldRevision,
 ) -> CellRevision {
-  let changeset =
-    SelectOptionCellChangeset::from_insert_options(option_ids).to_cell_changeset_str();
-  let data = apply_cell_data_changeset(changeset, None, field_rev, None).unwrap();
+  let option_ids = match select_type_option_from_field_rev(field_rev) {
+    Ok(type_option) if type_option.dependency().is_some() => {
+      let allowed_option_ids = type_option
+        .get_allowed_options(parent_option_ids)
+        .into_iter()
+        .map(|option| option.id)
+        .collect::<HashSet<String>>();
+      option_ids
+        .into_iter()
+        .filter(|option_id| allowed_option_ids.contains(option_id))
+        .collect()
+    },
+    _ => option_ids,
+  };
+  let mut changeset = SelectOptionCellChangeset::from_insert_options(option_ids);
+  changeset.parent_option_ids = Some(parent_option_ids.to_vec());
+  let data = apply_cell_data_changeset(
+    changeset.to_cell_changeset_str(),
+    None,
+    field_rev,
+    None,
+    None,
+  )
+  .unwrap();
+  CellRevision::new(data)
+}
+
+/// The items of the checklist cell are inserted as they are, including whether they are
+/// checked.
+pub fn insert_checklist_cell(cell_data: ChecklistCellData) -> CellRevision {
+  let data = TypeCellData::new(cell_data.to_string(), FieldType::Checklist).to_json();
+  CellRevision::new(data)
+}
+
+pub fn insert_relation_cell(row_ids: Vec<String>, field_rev: &FieldRevision) -> CellRevision {
+  let changeset = RelationCellChangeset::from_inserted_row_ids(row_ids).to_cell_changeset_str();
+  let data = apply_cell_data_changeset(changeset, None, field_rev, None, None).unwrap();
+  CellRevision::new(data)
+}
+
+pub fn insert_person_cell(user_ids: Vec<String>, field_rev: &FieldRevision) -> CellRevision {
+  let changeset = PersonCellChangeset::from_inserted_user_ids(user_ids).to_cell_changeset_str();
+  let data = apply_cell_data_changeset(changeset, None, field_rev, None, None).unwrap();
   CellRevision::new(data)
 }
 
@@ -276,7 +340,7 @@ pub fn delete_select_option_cell(
 ) -> CellRevision {
   let changeset =
     SelectOptionCellChangeset::from_delete_options(option_ids).to_cell_changeset_str();
-  let data = apply_cell_data_changeset(changeset, None, field_rev, None).unwrap();
+  let data = apply_cell_data_changeset(changeset, None, field_rev, None, None).unwrap();
   CellRevision::new(data)
 }
 
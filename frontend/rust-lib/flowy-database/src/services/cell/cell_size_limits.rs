@@ -0,0 +1,146 @@
+use crate::entities::FieldType;
+use crate::services::cell::FromCellString;
+use crate::services::field::{ChecklistCellData, SelectOptionIds, URLCellData};
+use flowy_error::{FlowyError, FlowyResult};
+
+/// The size limits of the cells. A cell changeset that makes the cell exceed the limit of its
+/// field type is rejected with the `CellDataTooLarge` error code.
+#[derive(Debug, Clone)]
+pub struct CellSizeLimits {
+  /// The max number of characters of the text cell
+  pub text_max_chars: usize,
+
+  /// The max number of characters of the url cell
+  pub url_max_chars: usize,
+
+  /// The max number of items of the checklist cell
+  pub checklist_max_items: usize,
+}
+
+impl std::default::Default for CellSizeLimits {
+  fn default() -> Self {
+    Self {
+      text_max_chars: 10000,
+      url_max_chars: 10000,
+      checklist_max_items: 1000,
+    }
+  }
+}
+
+impl CellSizeLimits {
+  /// Checks the `cell_str` that is generated by applying the changeset to the cell. It returns
+  /// error if the size of the cell exceeds the limit of the `field_type`.
+  pub fn check_cell_str(&self, field_type: &FieldType, cell_str: &str) -> FlowyResult<()> {
+    let (size, limit, unit) = match field_type {
+      FieldType::RichText => (cell_str.chars().count(), self.text_max_chars, "characters"),
+      FieldType::URL => {
+        let url_cell_data = URLCellData::from_cell_str(cell_str)?;
+        (
+          url_cell_data.content.chars().count(),
+          self.url_max_chars,
+          "characters",
+        )
+      },
+      FieldType::Checklist => {
+        // The older versions stored the ids of the checked items in the cell
+        let num_of_items = match ChecklistCellData::from_cell_str(cell_str) {
+          Ok(cell_data) => cell_data.items.len(),
+          Err(_) => SelectOptionIds::from(cell_str.to_owned()).len(),
+        };
+        (num_of_items, self.checklist_max_items, "items")
+      },
+      _ => return Ok(()),
+    };
+
+    if size > limit {
+      let msg = format!(
+        "The {:?} cell should not be more than {} {}, but it is {}",
+        field_type, limit, unit, size
+      );
+      Err(FlowyError::cell_data_too_large().context(msg))
+    } else {
+      Ok(())
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::entities::FieldType;
+  use crate::services::cell::CellSizeLimits;
+  use crate::services::field::{ChecklistCellData, ChecklistItemPB, URLCellData};
+  use flowy_error::ErrorCode;
+
+  fn small_limits() -> CellSizeLimits {
+    CellSizeLimits {
+      text_max_chars: 5,
+      url_max_chars: 8,
+      checklist_max_items: 2,
+    }
+  }
+
+  #[test]
+  fn text_cell_size_limit_test() {
+    let limits = small_limits();
+    assert!(limits.check_cell_str(&FieldType::RichText, "hello").is_ok());
+    // The limit counts the characters instead of the bytes
+    assert!(limits
+      .check_cell_str(&FieldType::RichText, "你好你好你")
+      .is_ok());
+
+    let err = limits
+      .check_cell_str(&FieldType::RichText, "hello world")
+      .unwrap_err();
+    assert_eq!(err.code, ErrorCode::CellDataTooLarge.value());
+  }
+
+  #[test]
+  fn url_cell_size_limit_test() {
+    let limits = small_limits();
+    let cell_str = URLCellData::new("appflowy").to_json().unwrap();
+    assert!(limits.check_cell_str(&FieldType::URL, &cell_str).is_ok());
+
+    let cell_str = URLCellData::new("appflowy.io").to_json().unwrap();
+    let err = limits
+      .check_cell_str(&FieldType::URL, &cell_str)
+      .unwrap_err();
+    assert_eq!(err.code, ErrorCode::CellDataTooLarge.value());
+  }
+
+  #[test]
+  fn checklist_cell_size_limit_test() {
+    let limits = small_limits();
+    assert!(limits.check_cell_str(&FieldType::Checklist, "a,b").is_ok());
+    let cell_data = ChecklistCellData {
+      items: vec![ChecklistItemPB::new("a"), ChecklistItemPB::new("b")],
+    };
+    assert!(limits
+      .check_cell_str(&FieldType::Checklist, &cell_data.to_string())
+      .is_ok());
+
+    let err = limits
+      .check_cell_str(&FieldType::Checklist, "a,b,c")
+      .unwrap_err();
+    assert_eq!(err.code, ErrorCode::CellDataTooLarge.value());
+
+    let cell_data = ChecklistCellData {
+      items: vec![
+        ChecklistItemPB::new("a"),
+        ChecklistItemPB::new("b"),
+        ChecklistItemPB::new("c"),
+      ],
+    };
+    let err = limits
+      .check_cell_str(&FieldType::Checklist, &cell_data.to_string())
+      .unwrap_err();
+    assert_eq!(err.code, ErrorCode::CellDataTooLarge.value());
+  }
+
+  #[test]
+  fn other_cells_are_not_limited_test() {
+    let limits = small_limits();
+    assert!(limits
+      .check_cell_str(&FieldType::Number, "1234567890")
+      .is_ok());
+  }
+}
@@ -0,0 +1,72 @@
+use crate::entities::{FieldType, InvalidCellPB, InvalidCellReasonPB};
+use crate::services::cell::TypeCellData;
+use crate::services::field::TypeOptionCellExt;
+use database_model::{CellRevision, FieldRevision, RowRevision};
+use std::sync::Arc;
+
+/// Scans the cells of the rows for the ones that can't be read by their fields as they are. It
+/// happens if the cells were saved by an older version, e.g. before the migrations of the cell
+/// data.
+///
+/// The cells that were written by another field type are not invalid, they are transformed
+/// when reading. See [TypeOptionTransform](crate::services::field::TypeOptionTransform).
+pub fn validate_cells(
+  field_revs: &[Arc<FieldRevision>],
+  row_revs: &[Arc<RowRevision>],
+) -> Vec<InvalidCellPB> {
+  let mut invalid_cells = vec![];
+  for row_rev in row_revs {
+    for field_rev in field_revs {
+      let reason = row_rev
+        .cells
+        .get(&field_rev.id)
+        .and_then(|cell_rev| validate_cell(field_rev, cell_rev));
+      if let Some(reason) = reason {
+        invalid_cells.push(InvalidCellPB {
+          row_id: row_rev.id.clone(),
+          field_id: field_rev.id.clone(),
+          reason,
+        });
+      }
+    }
+  }
+  invalid_cells
+}
+
+pub fn validate_cell(
+  field_rev: &FieldRevision,
+  cell_rev: &CellRevision,
+) -> Option<InvalidCellReasonPB> {
+  let type_cell_data = match TypeCellData::try_from(cell_rev) {
+    Ok(type_cell_data) => type_cell_data,
+    Err(_) => return Some(InvalidCellReasonPB::Corrupted),
+  };
+  let field_type: FieldType = field_rev.ty.into();
+  if type_cell_data.field_type != field_type || type_cell_data.cell_str.is_empty() {
+    return None;
+  }
+
+  TypeOptionCellExt::new_with_cell_data_cache(field_rev, None)
+    .get_type_option_cell_data_handler(&field_type)?
+    .validate_cell_str(&type_cell_data.cell_str)
+}
+
+/// Encodes the cell data again with the type option of the field, returns the repaired type
+/// cell data. The corrupted cell data is treated as text, so it might be recovered by the
+/// transform of the type option. The cell is emptied if its data can't be decoded.
+pub fn repair_cell(field_rev: &FieldRevision, cell_rev: &CellRevision) -> String {
+  let field_type: FieldType = field_rev.ty.into();
+  let (cell_str, decoded_field_type) = match TypeCellData::try_from(cell_rev) {
+    Ok(type_cell_data) => (type_cell_data.cell_str, type_cell_data.field_type),
+    Err(_) => (cell_rev.type_cell_data.clone(), FieldType::RichText),
+  };
+  let cell_str = TypeOptionCellExt::new_with_cell_data_cache(field_rev, None)
+    .get_type_option_cell_data_handler(&field_type)
+    .and_then(|handler| {
+      handler
+        .encode_cell_str(cell_str, &decoded_field_type, field_rev)
+        .ok()
+    })
+    .unwrap_or_default();
+  TypeCellData::new(cell_str, field_type).to_json()
+}
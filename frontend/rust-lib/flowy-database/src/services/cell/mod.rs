@@ -1,7 +1,15 @@
+mod cell_accessibility;
+mod cell_conflicts;
 mod cell_data_cache;
 mod cell_operation;
+mod cell_size_limits;
+mod cell_validation;
 mod type_cell_data;
 
+pub use cell_accessibility::*;
+pub use cell_conflicts::*;
 pub use cell_data_cache::*;
 pub use cell_operation::*;
+pub use cell_size_limits::*;
+pub use cell_validation::*;
 pub use type_cell_data::*;
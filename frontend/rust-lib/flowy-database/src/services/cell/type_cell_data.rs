@@ -116,6 +116,62 @@ impl TypeCellData {
     self.field_type == FieldType::URL
   }
 
+  pub fn is_relation(&self) -> bool {
+    self.field_type == FieldType::Relation
+  }
+
+  pub fn is_rollup(&self) -> bool {
+    self.field_type == FieldType::Rollup
+  }
+
+  pub fn is_formula(&self) -> bool {
+    self.field_type == FieldType::Formula
+  }
+
+  pub fn is_created_time(&self) -> bool {
+    self.field_type == FieldType::CreatedTime
+  }
+
+  pub fn is_last_edited_time(&self) -> bool {
+    self.field_type == FieldType::LastEditedTime
+  }
+
+  pub fn is_person(&self) -> bool {
+    self.field_type == FieldType::Person
+  }
+
+  pub fn is_attachment(&self) -> bool {
+    self.field_type == FieldType::Attachment
+  }
+
+  pub fn is_progress(&self) -> bool {
+    self.field_type == FieldType::Progress
+  }
+
+  pub fn is_phone(&self) -> bool {
+    self.field_type == FieldType::Phone
+  }
+
+  pub fn is_email(&self) -> bool {
+    self.field_type == FieldType::Email
+  }
+
+  pub fn is_duration(&self) -> bool {
+    self.field_type == FieldType::Duration
+  }
+
+  pub fn is_auto_number(&self) -> bool {
+    self.field_type == FieldType::AutoNumber
+  }
+
+  pub fn is_time_tracking(&self) -> bool {
+    self.field_type == FieldType::TimeTracking
+  }
+
+  pub fn is_location(&self) -> bool {
+    self.field_type == FieldType::Location
+  }
+
   pub fn is_select_option(&self) -> bool {
     self.field_type == FieldType::MultiSelect || self.field_type == FieldType::SingleSelect
   }
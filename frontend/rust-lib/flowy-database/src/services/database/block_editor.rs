@@ -13,6 +13,7 @@ use flowy_revision::{
 use flowy_sqlite::ConnectionPool;
 use lib_infra::future::FutureResult;
 use lib_infra::retry::spawn_retry;
+use lib_infra::util::timestamp;
 use lib_ot::core::EmptyAttributes;
 use revision_model::Revision;
 use std::borrow::Cow;
@@ -57,6 +58,10 @@ impl DatabaseBlockEditor {
     self.rev_manager.close().await;
   }
 
+  pub async fn number_of_unacked_revisions(&self) -> usize {
+    self.rev_manager.number_of_unacked_revisions().await
+  }
+
   pub async fn duplicate_block(&self, duplicated_block_id: &str) -> DatabaseBlockRevision {
     self.pad.read().await.duplicate_data(duplicated_block_id)
   }
@@ -91,6 +96,20 @@ impl DatabaseBlockEditor {
     Ok((row_count, row_index))
   }
 
+  /// Appends the rows in one revision, so either all of them are created or none. Returns the
+  /// number of the rows after they are created.
+  pub(crate) async fn create_rows(&self, rows: Vec<RowRevision>) -> FlowyResult<i32> {
+    let mut row_count = 0;
+    self
+      .modify(|block_pad| {
+        let change = block_pad.add_row_revs(rows)?;
+        row_count = block_pad.number_of_rows();
+        Ok(change)
+      })
+      .await?;
+    Ok(row_count)
+  }
+
   pub async fn delete_rows(&self, ids: Vec<Cow<'_, String>>) -> FlowyResult<i32> {
     let mut row_count = 0;
     self
@@ -103,7 +122,8 @@ impl DatabaseBlockEditor {
     Ok(row_count)
   }
 
-  pub async fn update_row(&self, changeset: RowChangeset) -> FlowyResult<()> {
+  pub async fn update_row(&self, mut changeset: RowChangeset) -> FlowyResult<()> {
+    changeset.modified_at = Some(timestamp());
     self
       .modify(|block_pad| Ok(block_pad.update_row(changeset)?))
       .await?;
@@ -117,6 +137,20 @@ impl DatabaseBlockEditor {
     Ok(())
   }
 
+  pub async fn remap_row_id(&self, index: usize, new_row_id: &str) -> FlowyResult<()> {
+    self
+      .modify(|block_pad| Ok(block_pad.remap_row_id(index, new_row_id.to_owned())?))
+      .await?;
+    Ok(())
+  }
+
+  pub async fn remove_cells(&self, field_ids: &[String]) -> FlowyResult<()> {
+    self
+      .modify(|block_pad| Ok(block_pad.remove_cells(field_ids)?))
+      .await?;
+    Ok(())
+  }
+
   pub async fn index_of_row(&self, row_id: &str) -> Option<usize> {
     self.pad.read().await.index_of_row(row_id)
   }
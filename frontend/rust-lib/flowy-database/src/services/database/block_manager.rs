@@ -1,7 +1,8 @@
-use crate::entities::{CellChangesetPB, InsertedRowPB, UpdatedRowPB};
+use crate::entities::{CellChangesetPB, FieldType, InsertedRowPB, UpdatedRowPB};
 use crate::manager::DatabaseUser;
 use crate::notification::{send_notification, DatabaseNotification};
 use crate::services::database::{DatabaseBlockEditor, DatabaseBlockRevisionMergeable};
+use crate::services::field::LastEditedTimeTypeOptionPB;
 use crate::services::persistence::block_index::BlockRowIndexer;
 use crate::services::persistence::rev_sqlite::{
   SQLiteDatabaseBlockRevisionPersistence, SQLiteDatabaseRevisionSnapshotPersistence,
@@ -11,13 +12,15 @@ use dashmap::DashMap;
 use database_model::{
   DatabaseBlockMetaRevision, DatabaseBlockMetaRevisionChangeset, RowChangeset, RowRevision,
 };
+use flowy_client_sync::client_database::DatabaseRevisionPad;
 use flowy_error::FlowyResult;
 use flowy_revision::{RevisionManager, RevisionPersistence, RevisionPersistenceConfiguration};
 use flowy_sqlite::ConnectionPool;
+use lib_infra::util::timestamp;
 use std::borrow::Cow;
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::broadcast;
+use tokio::sync::{broadcast, RwLock};
 
 #[derive(Debug, Clone)]
 pub enum DatabaseBlockEvent {
@@ -43,6 +46,7 @@ pub enum DatabaseBlockEvent {
 type BlockId = String;
 pub(crate) struct DatabaseBlocks {
   user: Arc<dyn DatabaseUser>,
+  database_pad: Arc<RwLock<DatabaseRevisionPad>>,
   persistence: Arc<BlockRowIndexer>,
   block_editors: DashMap<BlockId, Arc<DatabaseBlockEditor>>,
   event_notifier: broadcast::Sender<DatabaseBlockEvent>,
@@ -51,6 +55,7 @@ pub(crate) struct DatabaseBlocks {
 impl DatabaseBlocks {
   pub(crate) async fn new(
     user: &Arc<dyn DatabaseUser>,
+    database_pad: Arc<RwLock<DatabaseRevisionPad>>,
     block_meta_revs: Vec<Arc<DatabaseBlockMetaRevision>>,
     persistence: Arc<BlockRowIndexer>,
     event_notifier: broadcast::Sender<DatabaseBlockEvent>,
@@ -59,6 +64,7 @@ impl DatabaseBlocks {
     let user = user.clone();
     let manager = Self {
       user,
+      database_pad,
       block_editors,
       persistence,
       event_notifier,
@@ -72,6 +78,19 @@ impl DatabaseBlocks {
     }
   }
 
+  pub(crate) async fn number_of_unacked_revisions(&self) -> usize {
+    let block_editors = self
+      .block_editors
+      .iter()
+      .map(|block_editor| block_editor.value().clone())
+      .collect::<Vec<_>>();
+    let mut number = 0;
+    for block_editor in block_editors {
+      number += block_editor.number_of_unacked_revisions().await;
+    }
+    number
+  }
+
   // #[tracing::instrument(level = "trace", skip(self))]
   pub(crate) async fn get_or_create_block_editor(
     &self,
@@ -129,10 +148,15 @@ impl DatabaseBlocks {
     let mut changesets = vec![];
     for (block_id, row_revs) in rows_by_block_id {
       let editor = self.get_or_create_block_editor(&block_id).await?;
-      for row_rev in row_revs {
+      for row_rev in row_revs.iter() {
         self.persistence.insert(&row_rev.block_id, &row_rev.id)?;
-        let mut row = InsertedRowPB::from(&row_rev);
-        row.index = editor.create_row(row_rev, None).await?.1;
+      }
+      // The rows of the block are created at once, they are appended after the existing rows
+      let rows = row_revs.iter().map(InsertedRowPB::from).collect::<Vec<_>>();
+      let row_count = editor.create_rows(row_revs).await?;
+      let start_index = row_count - rows.len() as i32;
+      for (offset, mut row) in rows.into_iter().enumerate() {
+        row.index = Some(start_index + offset as i32);
         let _ = self.event_notifier.send(DatabaseBlockEvent::InsertRow {
           block_id: block_id.clone(),
           row,
@@ -140,15 +164,16 @@ impl DatabaseBlocks {
       }
       changesets.push(DatabaseBlockMetaRevisionChangeset::from_row_count(
         block_id.clone(),
-        editor.number_of_rows().await,
+        row_count,
       ));
     }
 
     Ok(changesets)
   }
 
-  pub async fn update_row(&self, changeset: RowChangeset) -> FlowyResult<()> {
+  pub async fn update_row(&self, mut changeset: RowChangeset) -> FlowyResult<()> {
     let editor = self.get_editor_from_row_id(&changeset.row_id).await?;
+    self.touch_last_edited_time(&mut changeset).await;
     editor.update_row(changeset.clone()).await?;
     match editor.get_row_rev(&changeset.row_id).await? {
       None => tracing::error!(
@@ -194,6 +219,7 @@ impl DatabaseBlocks {
     }
   }
 
+  /// Deletes the rows of each block in one revision.
   pub(crate) async fn delete_rows(
     &self,
     block_rows: Vec<DatabaseBlockRow>,
@@ -203,10 +229,16 @@ impl DatabaseBlocks {
       let editor = self.get_or_create_block_editor(&block_row.block_id).await?;
       let row_ids = block_row
         .row_ids
-        .into_iter()
-        .map(Cow::Owned)
+        .iter()
+        .map(Cow::Borrowed)
         .collect::<Vec<Cow<String>>>();
       let row_count = editor.delete_rows(row_ids).await?;
+      for row_id in block_row.row_ids {
+        let _ = self.event_notifier.send(DatabaseBlockEvent::DeleteRow {
+          block_id: block_row.block_id.clone(),
+          row_id,
+        });
+      }
       let changeset =
         DatabaseBlockMetaRevisionChangeset::from_row_count(block_row.block_id, row_count);
       changesets.push(changeset);
@@ -240,6 +272,37 @@ impl DatabaseBlocks {
     Ok(())
   }
 
+  /// Returns the id of the block that the row is indexed to, it might be a block of another
+  /// database if the row id is used more than once.
+  pub(crate) fn get_indexed_block_id(&self, row_id: &str) -> Option<String> {
+    self.persistence.get_block_id(row_id).ok()
+  }
+
+  /// Gives the row at `index` of the block a new id and indexes the row with it. The row is
+  /// inserted to the views as a new row, the row that keeps the old id is unchanged.
+  pub(crate) async fn remap_row_id(
+    &self,
+    block_id: &str,
+    index: usize,
+    new_row_id: &str,
+  ) -> FlowyResult<()> {
+    let editor = self.get_or_create_block_editor(block_id).await?;
+    editor.remap_row_id(index, new_row_id).await?;
+    self.persistence.insert(block_id, new_row_id)?;
+    if let Some((index, row_rev)) = editor.get_row_rev(new_row_id).await? {
+      let row = InsertedRowPB {
+        index: Some(index as i32),
+        row: make_row_from_row_rev(row_rev),
+        is_new: false,
+      };
+      let _ = self.event_notifier.send(DatabaseBlockEvent::InsertRow {
+        block_id: block_id.to_owned(),
+        row,
+      });
+    }
+    Ok(())
+  }
+
   // This function will be moved to GridViewRevisionEditor.
   pub async fn index_of_row(&self, row_id: &str) -> Option<usize> {
     match self.get_editor_from_row_id(row_id).await {
@@ -260,7 +323,15 @@ impl DatabaseBlocks {
     editor.get_row_rev(row_id).await
   }
 
-  #[allow(dead_code)]
+  /// Removes the cells of the fields from all the rows, e.g. the fields were purged from the
+  /// trash. The rows are not notified, as the fields are not shown anymore.
+  pub(crate) async fn remove_cells(&self, field_ids: &[String]) -> FlowyResult<()> {
+    for iter in self.block_editors.iter() {
+      iter.value().remove_cells(field_ids).await?;
+    }
+    Ok(())
+  }
+
   pub async fn get_row_revs(&self) -> FlowyResult<Vec<Arc<RowRevision>>> {
     let mut row_revs = vec![];
     for iter in self.block_editors.iter() {
@@ -295,6 +366,32 @@ impl DatabaseBlocks {
     Ok(blocks)
   }
 
+  /// Sets the cells of the last edited time fields to now if the changeset edits the cells of
+  /// the row. The changes of the computed cells, e.g. the formula cells, are not edits.
+  async fn touch_last_edited_time(&self, changeset: &mut RowChangeset) {
+    let field_revs = match self.database_pad.read().await.get_field_revs(None) {
+      Ok(field_revs) => field_revs,
+      Err(_) => return,
+    };
+    let is_edited = field_revs.iter().any(|field_rev| {
+      changeset.cell_by_field_id.contains_key(&field_rev.id)
+        && !FieldType::from(field_rev.ty).is_computed()
+    });
+    if !is_edited {
+      return;
+    }
+
+    let now = timestamp();
+    for field_rev in field_revs.iter() {
+      if FieldType::from(field_rev.ty).is_last_edited_time() {
+        changeset.cell_by_field_id.insert(
+          field_rev.id.clone(),
+          LastEditedTimeTypeOptionPB::cell_rev_from_timestamp(now),
+        );
+      }
+    }
+  }
+
   async fn notify_did_update_cell(&self, changeset: CellChangesetPB) -> FlowyResult<()> {
     let id = format!("{}:{}", changeset.row_id, changeset.field_id);
     send_notification(&id, DatabaseNotification::DidUpdateCell).send();
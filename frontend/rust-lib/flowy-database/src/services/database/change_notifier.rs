@@ -0,0 +1,53 @@
+use crate::entities::{DatabaseChangePB, DatabaseChangeTypePB};
+use crate::services::database::DatabaseBlockEvent;
+use tokio::sync::broadcast;
+
+/// Publishes the changes of all the opened databases. Each consumer subscribes to it and
+/// receives the changes that happen after subscribing.
+pub type DatabaseChangeSender = broadcast::Sender<DatabaseChangePB>;
+
+/// Converts the row events of the database blocks into the database changes. The task ends
+/// when the database blocks are dropped.
+pub(crate) fn listen_on_row_changes(
+  database_id: String,
+  mut block_event_rx: broadcast::Receiver<DatabaseBlockEvent>,
+  change_tx: DatabaseChangeSender,
+) {
+  tokio::spawn(async move {
+    loop {
+      let change = match block_event_rx.recv().await {
+        Ok(DatabaseBlockEvent::InsertRow { row, .. }) => DatabaseChangePB::row(
+          &database_id,
+          DatabaseChangeTypePB::RowCreated,
+          &row.row.id,
+          vec![],
+        ),
+        Ok(DatabaseBlockEvent::UpdateRow { row, .. }) => DatabaseChangePB::row(
+          &database_id,
+          DatabaseChangeTypePB::RowUpdated,
+          &row.row.id,
+          row.field_ids,
+        ),
+        Ok(DatabaseBlockEvent::DeleteRow { row_id, .. }) => DatabaseChangePB::row(
+          &database_id,
+          DatabaseChangeTypePB::RowDeleted,
+          &row_id,
+          vec![],
+        ),
+        // Moving a row only changes the order of the rows
+        Ok(DatabaseBlockEvent::Move { .. }) => continue,
+        Err(broadcast::error::RecvError::Lagged(count)) => {
+          tracing::warn!(
+            "{} row changes of database:{} are dropped",
+            count,
+            database_id
+          );
+          continue;
+        },
+        Err(broadcast::error::RecvError::Closed) => break,
+      };
+      // The sending fails if there is no consumer, which is fine
+      let _ = change_tx.send(change);
+    }
+  });
+}
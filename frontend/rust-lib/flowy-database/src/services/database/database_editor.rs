@@ -1,15 +1,35 @@
 use crate::entities::CellIdParams;
 use crate::entities::*;
-use crate::manager::DatabaseUser;
+use crate::manager::{DatabaseInbox, DatabaseInboxKind, DatabaseInboxNotification, DatabaseUser};
 use crate::notification::{send_notification, DatabaseNotification};
 use crate::services::cell::{
-  apply_cell_data_changeset, get_type_cell_protobuf, stringify_cell_data, AnyTypeCache,
-  AtomicCellDataCache, CellProtobufBlob, ToCellChangesetString, TypeCellData,
+  apply_cell_data_changeset, get_type_cell_protobuf, make_cell_accessibility,
+  make_row_accessibility, repair_cell, stringify_cell_data, validate_cells, AnyTypeCache,
+  AtomicCellDataCache, CellDataDecoder, CellProtobufBlob, CellSizeLimits, ConflictedCells,
+  FromCellChangesetString, FromCellString, ToCellChangesetString, TypeCellData,
+};
+use crate::services::database::display_value::{self, RowDisplayValueCache};
+use crate::services::database::stale_rows::StaleRowTracker;
+use crate::services::database::{
+  listen_on_row_changes, make_database_json_schema, make_database_openapi, DatabaseBlocks,
+  DatabaseChangeSender, RowLimit,
 };
-use crate::services::database::DatabaseBlocks;
 use crate::services::field::{
-  default_type_option_builder_from_type, transform_type_option, type_option_builder_from_bytes,
-  FieldBuilder, RowSingleCellData,
+  attachment_ids_of_row, average_progress, current_user_id, default_type_option_builder_from_type,
+  format_clock, insert_current_member, make_checklist_reminders, make_select_option_usage,
+  merge_select_options, move_select_option, parse_text_mentions, pin_creator_timezone,
+  render_text_mentions, select_type_option_from_field_rev, sum_time_tracking,
+  transform_type_option, type_option_builder_from_bytes, AttachmentCellData,
+  AttachmentTypeOptionPB, AutoNumberCellData, AutoNumberTypeOptionPB, ChecklistCellChangeset,
+  ChecklistCellChangesetParams, ChecklistCellDataPB, ChecklistItemDueChangeset,
+  ChecklistReminderPB, ChecklistTypeOptionPB, CreatedTimeTypeOptionPB, DateCellChangeset,
+  FieldBuilder, GroupProgressPB, GroupTimeTrackingSumPB, LastEditedTimeTypeOptionPB,
+  MoveChecklistItemParams, NumberTypeOptionPB, PersonCellData, ProgressCellData,
+  ProgressTypeOptionPB, RelationCellData, RelationTypeOptionPB, RollupCellData, RowSingleCellData,
+  SelectOptionCellChangeset, SelectOptionCellDataPB, SelectOptionColorPB, SelectOptionIds,
+  SelectOptionPB, SelectOptionUsagePB, SelectTypeOptionSharedAction, TextCellDataPB,
+  TextMentionKindPB, TextMentionPB, TimeTrackingCellData, TimeTrackingSumPB,
+  TimeTrackingTypeOptionPB, TypeOptionCellData,
 };
 
 use crate::services::database::DatabaseViewDataImpl;
@@ -17,9 +37,18 @@ use crate::services::database_view::{
   DatabaseViewChanged, DatabaseViewData, DatabaseViewEditor, DatabaseViews,
 };
 use crate::services::filter::FilterType;
+use crate::services::group::{make_board_layout, BoardLayout};
 use crate::services::persistence::block_index::BlockRowIndexer;
 use crate::services::persistence::database_ref::DatabaseViewRef;
-use crate::services::row::{DatabaseBlockRow, DatabaseBlockRowRevision, RowRevisionBuilder};
+use crate::services::persistence::relation_link::{RelationLink, RelationLinkIndex};
+use crate::services::persistence::row_display_value::{RowDisplayValue, RowDisplayValueStorage};
+use crate::services::persistence::row_mention::{RowMention, RowMentionIndex};
+use crate::services::persistence::row_share::{RowShare, RowShares};
+use crate::services::persistence::row_trash::RowTrash;
+use crate::services::row::{
+  find_row_id_collisions, DatabaseBlockRow, DatabaseBlockRowRevision, RowRevisionBuilder,
+};
+use crate::services::setting::SharedWeekSettings;
 use bytes::Bytes;
 use database_model::*;
 use flowy_client_sync::client_database::{
@@ -34,13 +63,22 @@ use flowy_revision::{
 };
 use flowy_sqlite::ConnectionPool;
 use flowy_task::TaskDispatcher;
+use indexmap::IndexMap;
 use lib_infra::future::{to_fut, FutureResult};
+use lib_infra::util::timestamp;
 use lib_ot::core::EmptyAttributes;
 use revision_model::Revision;
-use std::collections::HashMap;
+use rust_decimal::Decimal;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::{broadcast, RwLock};
 
+/// The prefix of the read-only links of the rows, see [DatabaseEditor::share_row]
+const ROW_SHARE_LINK_PREFIX: &str = "appflowy://share/row";
+
 pub trait DatabaseRefIndexerQuery: Send + Sync + 'static {
   fn get_ref_views(&self, database_id: &str) -> FlowyResult<Vec<DatabaseViewRef>>;
 }
@@ -54,6 +92,18 @@ pub struct DatabaseEditor {
   pub database_view_data: Arc<dyn DatabaseViewData>,
   pub cell_data_cache: AtomicCellDataCache,
   database_ref_query: Arc<dyn DatabaseRefIndexerQuery>,
+  cell_size_limits: CellSizeLimits,
+  row_limit: RowLimit,
+  conflicted_cells: ConflictedCells,
+  row_trash: Arc<RowTrash>,
+  row_shares: Arc<RowShares>,
+  change_tx: DatabaseChangeSender,
+  row_display_values: RowDisplayValueCache,
+  row_mention_index: Arc<RowMentionIndex>,
+  row_mentions: StaleRowTracker,
+  relation_link_index: Arc<RelationLinkIndex>,
+  relation_links: StaleRowTracker,
+  inbox: Arc<dyn DatabaseInbox>,
 }
 
 impl Drop for DatabaseEditor {
@@ -72,21 +122,48 @@ impl DatabaseEditor {
     persistence: Arc<BlockRowIndexer>,
     database_ref_query: Arc<dyn DatabaseRefIndexerQuery>,
     task_scheduler: Arc<RwLock<TaskDispatcher>>,
+    cell_size_limits: CellSizeLimits,
+    row_limit: RowLimit,
+    week_settings: SharedWeekSettings,
+    row_trash: Arc<RowTrash>,
+    row_shares: Arc<RowShares>,
+    row_mention_index: Arc<RowMentionIndex>,
+    relation_link_index: Arc<RelationLinkIndex>,
+    row_display_value_storage: Arc<RowDisplayValueStorage>,
+    inbox: Arc<dyn DatabaseInbox>,
+    change_tx: DatabaseChangeSender,
   ) -> FlowyResult<Arc<Self>> {
     let rev_manager = Arc::new(rev_manager);
     let cell_data_cache = AnyTypeCache::<u64>::new();
 
     // Block manager
     let (block_event_tx, block_event_rx) = broadcast::channel(100);
+    let row_display_values = RowDisplayValueCache::new(database_id, row_display_value_storage);
+    let row_mentions = StaleRowTracker::new(block_event_tx.subscribe());
+    let relation_links = StaleRowTracker::new(block_event_tx.subscribe());
+    listen_on_row_changes(
+      database_id.to_owned(),
+      block_event_tx.subscribe(),
+      change_tx.clone(),
+    );
     let block_meta_revs = database_pad.read().await.get_block_meta_revs();
-    let database_blocks =
-      Arc::new(DatabaseBlocks::new(&user, block_meta_revs, persistence, block_event_tx).await?);
+    let database_blocks = Arc::new(
+      DatabaseBlocks::new(
+        &user,
+        database_pad.clone(),
+        block_meta_revs,
+        persistence,
+        block_event_tx,
+      )
+      .await?,
+    );
 
     let database_view_data = Arc::new(DatabaseViewDataImpl {
       pad: database_pad.clone(),
       blocks: database_blocks.clone(),
       task_scheduler,
       cell_data_cache: cell_data_cache.clone(),
+      week_settings,
     });
 
     // View manager
@@ -107,6 +184,18 @@ impl DatabaseEditor {
       cell_data_cache,
       database_ref_query,
       database_view_data,
+      cell_size_limits,
+      row_limit,
+      conflicted_cells: ConflictedCells::default(),
+      row_trash,
+      row_shares,
+      change_tx,
+      row_display_values,
+      row_mention_index,
+      row_mentions,
+      relation_link_index,
+      relation_links,
+      inbox,
     });
 
     Ok(editor)
@@ -134,6 +223,41 @@ impl DatabaseEditor {
   pub async fn is_view_open(&self, view_id: &str) -> bool {
     self.database_views.is_view_exist(view_id).await
   }
+
+  /// Returns the estimated bytes of the cell data cache, which is shared by all the views, and
+  /// the cached display values of the rows.
+  pub fn cell_data_cache_bytes(&self) -> usize {
+    self.cell_data_cache.read().estimated_bytes() + self.row_display_values.estimated_bytes()
+  }
+
+  pub async fn did_update_week_settings(&self) {
+    self.database_views.did_update_week_settings().await
+  }
+
+  /// A checklist item of the row passed its due date, so the overdue filters are applied to the
+  /// row again.
+  pub async fn did_pass_due_date(&self, row_id: &str) {
+    self.database_views.did_pass_due_date(row_id).await
+  }
+
+  pub async fn filter_result_bytes(&self) -> usize {
+    self.database_views.filter_result_bytes().await
+  }
+
+  /// Returns the number of the local revisions of the database, its blocks and its opened views
+  /// that were not acked by the server yet.
+  pub async fn number_of_unacked_revisions(&self) -> usize {
+    self.rev_manager.number_of_unacked_revisions().await
+      + self.database_blocks.number_of_unacked_revisions().await
+      + self.database_views.number_of_unacked_revisions().await
+  }
+
+  /// Drops the cached cell data and the cached display values of the rows, the cells will be
+  /// decoded again when they are read.
+  pub fn trim_caches(&self) {
+    self.cell_data_cache.write().clear();
+    self.row_display_values.clear();
+  }
   /// Save the type-option data to disk and send a `DatabaseNotification::DidUpdateField` notification
   /// to dart side.
   ///
@@ -195,11 +319,14 @@ impl DatabaseEditor {
     Ok(field_rev)
   }
 
-  pub async fn create_new_field_rev(&self, field_rev: FieldRevision) -> FlowyResult<()> {
+  pub async fn create_new_field_rev(&self, mut field_rev: FieldRevision) -> FlowyResult<()> {
+    insert_current_member(&mut field_rev);
+    pin_creator_timezone(&mut field_rev);
     let field_id = field_rev.id.clone();
     self
       .modify(|pad| Ok(pad.create_field_rev(field_rev, None)?))
       .await?;
+    self.fill_generated_cells(&[field_id.clone()]).await?;
     self.notify_did_insert_database_field(&field_id).await?;
 
     Ok(())
@@ -215,14 +342,59 @@ impl DatabaseEditor {
       let type_option_builder = type_option_builder_from_bytes(type_option_data, field_type);
       field_rev.insert_type_option(type_option_builder.serializer());
     }
+    insert_current_member(&mut field_rev);
+    pin_creator_timezone(&mut field_rev);
     self
       .modify(|pad| Ok(pad.create_field_rev(field_rev.clone(), None)?))
       .await?;
+    self.fill_generated_cells(&[field_rev.id.clone()]).await?;
     self.notify_did_insert_database_field(&field_rev.id).await?;
 
     Ok(field_rev)
   }
 
+  /// Creates the fields in one revision, and sends one notification that contains all of them.
+  pub async fn create_new_field_revs(
+    &self,
+    fields: Vec<FieldDefinitionPB>,
+  ) -> FlowyResult<Vec<FieldRevision>> {
+    let number_of_fields = self.database_pad.read().await.get_fields().len();
+    let field_revs = fields
+      .into_iter()
+      .enumerate()
+      .map(|(index, field)| {
+        let name = if field.name.trim().is_empty() {
+          format!("Property {}", number_of_fields + index + 1)
+        } else {
+          field.name
+        };
+        let mut field_rev = FieldBuilder::from_field_type(&field.field_type)
+          .name(&name)
+          .build();
+        if let Some(type_option_data) = field.type_option_data {
+          let type_option_builder =
+            type_option_builder_from_bytes(type_option_data, &field.field_type);
+          field_rev.insert_type_option(type_option_builder.serializer());
+        }
+        insert_current_member(&mut field_rev);
+        pin_creator_timezone(&mut field_rev);
+        field_rev
+      })
+      .collect::<Vec<FieldRevision>>();
+
+    self
+      .modify(|pad| Ok(pad.create_field_revs(field_revs.clone())?))
+      .await?;
+    let field_ids = field_revs
+      .iter()
+      .map(|field_rev| field_rev.id.clone())
+      .collect::<Vec<String>>();
+    self.fill_generated_cells(&field_ids).await?;
+    self.notify_did_insert_database_fields(&field_ids).await?;
+
+    Ok(field_revs)
+  }
+
   pub async fn contain_field(&self, field_id: &str) -> bool {
     self.database_pad.read().await.contain_field(field_id)
   }
@@ -289,16 +461,87 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  /// Moves the field to the trash of the database. Its cells are kept in the rows, so the field
+  /// can be restored by calling [DatabaseEditor::restore_field] until it gets purged. The trash
+  /// is a part of the database, so it's synced like the fields.
   pub async fn delete_field(&self, field_id: &str) -> FlowyResult<()> {
+    let grouped_view_ids = self.database_views.get_grouped_view_ids(field_id).await;
     self
-      .modify(|pad| Ok(pad.delete_field_rev(field_id)?))
+      .modify(|pad| Ok(pad.trash_field_rev(field_id, grouped_view_ids, timestamp())?))
       .await?;
+    self.row_mentions.clear();
+    self.relation_links.clear();
+    self.database_views.did_trash_field().await;
     let field_order = FieldIdPB::from(field_id);
     let notified_changeset = DatabaseFieldChangesetPB::delete(&self.database_id, vec![field_order]);
     self.notify_did_update_database(notified_changeset).await?;
+    self.send_field_change(DatabaseChangeTypePB::FieldDeleted, field_id);
     Ok(())
   }
 
+  /// Moves the field out of the trash and inserts it back at the position it was deleted from.
+  /// The filters and the sorts of the field are applied again, and the views that were grouped
+  /// by the field are grouped by it again.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn restore_field(&self, field_id: &str) -> FlowyResult<FieldPB> {
+    let trashed_field = self
+      .database_pad
+      .read()
+      .await
+      .get_trashed_field_rev(field_id)
+      .ok_or_else(|| FlowyError::record_not_found().context("The field is not in the trash"))?;
+
+    self
+      .modify(|pad| Ok(pad.restore_trashed_field_rev(field_id)?))
+      .await?;
+    self.row_mentions.clear();
+    self.relation_links.clear();
+    self.fill_generated_cells(&[field_id.to_owned()]).await?;
+    self.notify_did_insert_database_field(field_id).await?;
+    self
+      .database_views
+      .did_restore_field(field_id, &trashed_field.grouped_view_ids)
+      .await;
+    Ok(FieldPB::from(trashed_field.field.clone()))
+  }
+
+  /// Returns the fields in the trash of the database, the most recently deleted field comes
+  /// first.
+  pub async fn get_trashed_fields(&self) -> Vec<TrashedFieldPB> {
+    let mut trashed_fields = self.database_pad.read().await.get_trashed_field_revs();
+    trashed_fields.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at));
+    trashed_fields
+      .into_iter()
+      .map(|trashed_field| TrashedFieldPB {
+        field: FieldPB::from(trashed_field.field.clone()),
+        deleted_at: trashed_field.deleted_at,
+      })
+      .collect()
+  }
+
+  /// Permanently deletes the fields that were moved to the trash before `deleted_before`, and
+  /// removes their cells from the rows. Returns the number of the purged fields.
+  pub async fn purge_trashed_fields(&self, deleted_before: i64) -> FlowyResult<usize> {
+    let field_ids = self
+      .database_pad
+      .read()
+      .await
+      .get_trashed_field_revs()
+      .into_iter()
+      .filter(|trashed_field| trashed_field.deleted_at < deleted_before)
+      .map(|trashed_field| trashed_field.field.id.clone())
+      .collect::<Vec<String>>();
+    if field_ids.is_empty() {
+      return Ok(0);
+    }
+
+    self.database_blocks.remove_cells(&field_ids).await?;
+    self
+      .modify(|pad| Ok(pad.remove_trashed_field_revs(&field_ids)?))
+      .await?;
+    Ok(field_ids.len())
+  }
+
   pub async fn group_by_field(&self, view_id: &str, field_id: &str) -> FlowyResult<()> {
     self
       .database_views
@@ -352,7 +595,20 @@ impl DatabaseEditor {
         )?)
       })
       .await?;
+    if new_field_type.is_person() {
+      self.insert_current_member_into_field(field_id).await?;
+    }
+    if new_field_type.is_date() {
+      self
+        .modify(|pad| {
+          Ok(pad.modify_field(field_id, |field_rev| {
+            Ok(pin_creator_timezone(field_rev).then_some(()))
+          })?)
+        })
+        .await?;
+    }
 
+    self.fill_generated_cells(&[field_id.to_owned()]).await?;
     self.notify_did_update_database_field(field_id).await?;
 
     Ok(())
@@ -363,6 +619,9 @@ impl DatabaseEditor {
     self
       .modify(|pad| Ok(pad.duplicate_field_rev(field_id, &duplicated_field_id)?))
       .await?;
+    self
+      .fill_generated_cells(&[duplicated_field_id.clone()])
+      .await?;
 
     self
       .notify_did_insert_database_field(&duplicated_field_id)
@@ -433,6 +692,9 @@ impl DatabaseEditor {
       .database_views
       .will_create_row(&mut row_rev, &params)
       .await;
+    self
+      .assign_auto_numbers(std::slice::from_mut(&mut row_rev))
+      .await?;
 
     let row_pb = self
       .create_row_pb(row_rev, params.start_row_id.clone())
@@ -448,7 +710,9 @@ impl DatabaseEditor {
     Ok(())
   }
 
-  pub async fn insert_rows(&self, row_revs: Vec<RowRevision>) -> FlowyResult<Vec<RowPB>> {
+  pub async fn insert_rows(&self, mut row_revs: Vec<RowRevision>) -> FlowyResult<Vec<RowPB>> {
+    self.check_row_limit(row_revs.len()).await?;
+    self.assign_auto_numbers(&mut row_revs).await?;
     let block_id = self.block_id().await?;
     let mut rows_by_block_id: HashMap<String, Vec<RowRevision>> = HashMap::new();
     let mut row_orders = vec![];
@@ -504,7 +768,13 @@ impl DatabaseEditor {
     }
   }
 
+  /// Moves the row to the trash of the database. The row can be restored by calling
+  /// [DatabaseEditor::restore_row] until it gets purged.
   pub async fn delete_row(&self, row_id: &str) -> FlowyResult<()> {
+    if let Some(row_rev) = self.get_row_rev(row_id).await? {
+      self.row_trash.add(&self.database_id, &row_rev)?;
+    }
+
     let row_rev = self.database_blocks.delete_row(row_id).await?;
     tracing::trace!("Did delete row:{:?}", row_rev);
     if let Some(row_rev) = row_rev {
@@ -513,6 +783,405 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  /// Moves the row out of the trash and inserts it at the end of the database. The open views
+  /// insert the row as if it was created, e.g. into the group that matches its cell.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn restore_row(&self, view_id: &str, row_id: &str) -> FlowyResult<RowPB> {
+    let trashed_row = self
+      .row_trash
+      .get(row_id)?
+      .filter(|trashed_row| trashed_row.database_id == self.database_id)
+      .ok_or_else(|| FlowyError::record_not_found().context("The row is not in the trash"))?;
+
+    let mut row_rev = trashed_row.row_rev;
+    // The block of the row might be gone, so the row is always restored to the last block.
+    row_rev.block_id = self.block_id().await?;
+    let row_pb = self.create_row_pb(row_rev, None).await?;
+    self.row_trash.remove(row_id)?;
+    let params = CreateRowParams {
+      view_id: view_id.to_owned(),
+      start_row_id: None,
+      group_id: None,
+      swimlane_id: None,
+      cell_data_by_field_id: None,
+    };
+    self.database_views.did_create_row(&row_pb, &params).await;
+    Ok(row_pb)
+  }
+
+  /// Returns the display string of the primary cell of the row.
+  pub(crate) async fn get_row_title(&self, row_id: &str) -> String {
+    match (
+      self.get_primary_field_rev().await,
+      self.get_row_rev(row_id).await,
+    ) {
+      (Some(field_rev), Ok(Some(row_rev))) => stringify_primary_cell(&field_rev, &row_rev),
+      _ => String::new(),
+    }
+  }
+
+  /// Returns the rows in the trash of the database with the display string of their
+  /// primary cell.
+  pub async fn get_trashed_rows(&self) -> FlowyResult<Vec<TrashedRowPB>> {
+    let primary_field_rev = self.get_primary_field_rev().await;
+    let trashed_rows = self
+      .row_trash
+      .get_all(&self.database_id)?
+      .into_iter()
+      .map(|trashed_row| {
+        let primary_cell = primary_field_rev
+          .as_ref()
+          .map(|field_rev| stringify_primary_cell(field_rev, &trashed_row.row_rev))
+          .unwrap_or_default();
+        TrashedRowPB {
+          row_id: trashed_row.row_rev.id,
+          primary_cell,
+          deleted_at: trashed_row.deleted_at,
+        }
+      })
+      .collect();
+    Ok(trashed_rows)
+  }
+
+  /// Creates a read-only link of the row. Returns the active share if the row was shared
+  /// before, so sharing the row again doesn't create another link.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn share_row(&self, view_id: &str, row_id: &str) -> FlowyResult<RowSharePB> {
+    if self.get_row_rev(row_id).await?.is_none() {
+      return Err(FlowyError::record_not_found().context(format!("Row:{} not found", row_id)));
+    }
+    let share = match self
+      .row_shares
+      .get_all(&self.database_id)?
+      .into_iter()
+      .find(|share| share.row_id == row_id)
+    {
+      Some(share) => share,
+      None => {
+        let share = self.row_shares.add(&self.database_id, view_id, row_id)?;
+        let notification = DatabaseInboxNotification {
+          kind: DatabaseInboxKind::Share,
+          title: self.get_row_title(row_id).await,
+          body: "The row is shared".to_owned(),
+          object_id: share.share_id.clone(),
+        };
+        if let Err(err) = self.inbox.add_notification(notification) {
+          tracing::error!("Add the share of the row to the inbox failed: {:?}", err);
+        }
+        share
+      },
+    };
+    Ok(make_row_share_pb(share))
+  }
+
+  /// Returns the active shares of the rows in the database. The shares of the deleted rows are
+  /// skipped, they become active again if the rows are restored.
+  pub async fn get_row_shares(&self) -> FlowyResult<Vec<RowSharePB>> {
+    let mut shares = vec![];
+    for share in self.row_shares.get_all(&self.database_id)? {
+      if self.get_row_rev(&share.row_id).await?.is_some() {
+        shares.push(make_row_share_pb(share));
+      }
+    }
+    Ok(shares)
+  }
+
+  /// Revokes the share, the link stops working immediately.
+  pub fn revoke_row_share(&self, share_id: &str) -> FlowyResult<()> {
+    match self.row_shares.get(share_id)? {
+      Some(share) if share.database_id == self.database_id => {
+        self.row_shares.remove(share_id)?;
+        Ok(())
+      },
+      _ => Err(FlowyError::record_not_found().context("The share is not found")),
+    }
+  }
+
+  /// Returns the read-only content of the shared row, it's used to render the link of the row.
+  /// The document of the row is filled by [crate::manager::DatabaseManager::get_shared_row].
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn get_shared_row(&self, share_id: &str) -> FlowyResult<SharedRowPB> {
+    let share = self
+      .row_shares
+      .get(share_id)?
+      .filter(|share| share.database_id == self.database_id)
+      .ok_or_else(|| FlowyError::record_not_found().context("The share is revoked"))?;
+    let row_rev = self
+      .get_row_rev(&share.row_id)
+      .await?
+      .ok_or_else(|| FlowyError::record_not_found().context("The shared row is deleted"))?;
+
+    let cells = self
+      .get_field_revs(None)
+      .await?
+      .iter()
+      .filter(|field_rev| field_rev.visibility)
+      .map(|field_rev| {
+        let field_type: FieldType = field_rev.ty.into();
+        let display_value = row_rev
+          .cells
+          .get(&field_rev.id)
+          .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+          .map(|type_cell_data| {
+            stringify_cell_data(
+              type_cell_data.cell_str,
+              &type_cell_data.field_type,
+              &field_type,
+              field_rev,
+            )
+          })
+          .unwrap_or_default();
+        SharedCellPB {
+          field_id: field_rev.id.clone(),
+          field_name: field_rev.name.clone(),
+          field_type,
+          display_value,
+        }
+      })
+      .collect();
+    Ok(SharedRowPB {
+      share: make_row_share_pb(share),
+      cells,
+      document_id: None,
+    })
+  }
+
+  /// Returns the display string of the rows' primary cell, all the rows of the database are
+  /// returned if the `row_ids` is empty. The display strings are cached until the rows or the
+  /// primary field change.
+  pub async fn get_row_display_values(
+    &self,
+    row_ids: Vec<String>,
+  ) -> FlowyResult<Vec<RowDisplayValuePB>> {
+    let primary_field_rev = match self.get_primary_field_rev().await {
+      None => return Ok(vec![]),
+      Some(field_rev) => field_rev,
+    };
+    let row_revs = if row_ids.is_empty() {
+      self.database_blocks.get_row_revs().await?
+    } else {
+      let mut row_revs = vec![];
+      for row_id in row_ids.iter() {
+        if let Some(row_rev) = self.get_row_rev(row_id).await? {
+          row_revs.push(row_rev);
+        }
+      }
+      row_revs
+    };
+
+    let source_hashes = row_revs
+      .iter()
+      .map(|row_rev| {
+        (
+          row_rev.id.clone(),
+          display_value::source_hash(&primary_field_rev, row_rev),
+        )
+      })
+      .collect::<Vec<_>>();
+    let mut cached_display_values = self.row_display_values.get(&source_hashes);
+    let mut new_display_values = vec![];
+    let display_values = row_revs
+      .iter()
+      .zip(source_hashes)
+      .map(|(row_rev, (row_id, source_hash))| {
+        let display_value = match cached_display_values.remove(&row_id) {
+          Some(display_value) => display_value,
+          None => {
+            let display_value = stringify_primary_cell(&primary_field_rev, row_rev);
+            new_display_values.push(RowDisplayValue {
+              row_id: row_id.clone(),
+              source_hash,
+              display_value: display_value.clone(),
+            });
+            display_value
+          },
+        };
+        RowDisplayValuePB {
+          row_id,
+          display_value,
+        }
+      })
+      .collect();
+    if let Err(err) = self.row_display_values.insert(new_display_values) {
+      tracing::error!("Save the display values of the rows failed: {:?}", err);
+    }
+    Ok(display_values)
+  }
+
+  /// Returns the ids of the views that are mentioned in the text cell.
+  pub async fn get_mentioned_view_ids(&self, params: &CellIdParams) -> FlowyResult<Vec<String>> {
+    let text = self
+      .get_row_rev(&params.row_id)
+      .await?
+      .and_then(|row_rev| get_text_cell_str(&row_rev, &params.field_id))
+      .unwrap_or_default();
+    let view_ids = parse_text_mentions(&text)
+      .into_iter()
+      .filter(|mention| mention.kind == TextMentionKindPB::View)
+      .map(|mention| mention.id)
+      .collect();
+    Ok(view_ids)
+  }
+
+  /// Returns the text of the cell with the mentions replaced by the names of the rows or the
+  /// views. The rows are resolved in this database, the views are resolved by `get_view_name`.
+  pub async fn get_text_cell_data<F>(
+    &self,
+    params: &CellIdParams,
+    get_view_name: F,
+  ) -> FlowyResult<TextCellDataPB>
+  where
+    F: Fn(&str) -> Option<String>,
+  {
+    let text = self
+      .get_row_rev(&params.row_id)
+      .await?
+      .and_then(|row_rev| get_text_cell_str(&row_rev, &params.field_id))
+      .unwrap_or_default();
+    let mentions = parse_text_mentions(&text);
+    let row_ids = mentions
+      .iter()
+      .filter(|mention| mention.kind == TextMentionKindPB::Row)
+      .map(|mention| mention.id.clone())
+      .collect::<Vec<String>>();
+    let row_names = if row_ids.is_empty() {
+      HashMap::new()
+    } else {
+      self
+        .get_row_display_values(row_ids)
+        .await?
+        .into_iter()
+        .map(|display_value| (display_value.row_id, display_value.display_value))
+        .collect::<HashMap<String, String>>()
+    };
+
+    let resolve_name = |kind: &TextMentionKindPB, id: &str| match kind {
+      TextMentionKindPB::Row => row_names.get(id).cloned(),
+      TextMentionKindPB::View => get_view_name(id),
+    };
+    let text_mentions = mentions
+      .iter()
+      .map(|mention| {
+        let name = resolve_name(&mention.kind, &mention.id);
+        TextMentionPB {
+          kind: mention.kind,
+          id: mention.id.clone(),
+          is_resolved: name.is_some(),
+          name: name.unwrap_or_else(|| mention.name.clone()),
+        }
+      })
+      .collect();
+    Ok(TextCellDataPB {
+      text: render_text_mentions(&text, |mention| resolve_name(&mention.kind, &mention.id)),
+      mentions: text_mentions,
+    })
+  }
+
+  /// Returns the backlinks of the text cells of this database that mention the row, the
+  /// `mentioning_cells` are the row ids and the field ids of the cells read from the
+  /// [RowMentionIndex]. See [crate::manager::DatabaseManager::get_row_backlinks].
+  pub(crate) async fn make_row_backlinks(
+    &self,
+    mentioning_cells: Vec<(String, String)>,
+  ) -> FlowyResult<Vec<RowBacklinkPB>> {
+    let text_field_ids = self.get_text_field_ids().await?;
+    let mentioning_cells = mentioning_cells
+      .into_iter()
+      .filter(|(_, field_id)| text_field_ids.contains(field_id))
+      .collect::<Vec<(String, String)>>();
+    if mentioning_cells.is_empty() {
+      return Ok(vec![]);
+    }
+
+    let row_ids = mentioning_cells
+      .iter()
+      .map(|(row_id, _)| row_id.clone())
+      .collect::<Vec<String>>();
+    let display_values = self
+      .get_row_display_values(row_ids)
+      .await?
+      .into_iter()
+      .map(|display_value| (display_value.row_id, display_value.display_value))
+      .collect::<HashMap<String, String>>();
+    let backlinks = mentioning_cells
+      .into_iter()
+      .filter_map(|(row_id, field_id)| {
+        // The row was deleted after it was indexed
+        let display_value = display_values.get(&row_id)?.clone();
+        Some(RowBacklinkPB {
+          database_id: self.database_id.clone(),
+          row_id,
+          field_id,
+          display_value,
+        })
+      })
+      .collect();
+    Ok(backlinks)
+  }
+
+  /// Writes the mentions of the rows that were changed since the last refresh to the
+  /// [RowMentionIndex].
+  pub(crate) async fn refresh_row_mentions(&self) -> FlowyResult<()> {
+    let text_field_ids = self.get_text_field_ids().await?;
+    match self.row_mentions.take_stale_row_ids() {
+      None => {
+        let mentions = self
+          .database_blocks
+          .get_row_revs()
+          .await?
+          .iter()
+          .flat_map(|row_rev| get_row_mentions(&self.database_id, row_rev, &text_field_ids))
+          .collect::<Vec<RowMention>>();
+        self
+          .row_mention_index
+          .replace_mentions(&self.database_id, None, mentions)?;
+        self.row_mentions.did_load();
+      },
+      Some(row_ids) => {
+        if row_ids.is_empty() {
+          return Ok(());
+        }
+        let mut mentions = vec![];
+        for row_id in row_ids.iter() {
+          // The deleted rows don't mention any row until they are restored
+          if let Some(row_rev) = self.get_row_rev(row_id).await? {
+            mentions.extend(get_row_mentions(
+              &self.database_id,
+              &row_rev,
+              &text_field_ids,
+            ));
+          }
+        }
+        self
+          .row_mention_index
+          .replace_mentions(&self.database_id, Some(&row_ids), mentions)?;
+      },
+    }
+    Ok(())
+  }
+
+  async fn get_text_field_ids(&self) -> FlowyResult<Vec<String>> {
+    let field_ids = self
+      .get_field_revs(None)
+      .await?
+      .into_iter()
+      .filter(|field_rev| FieldType::from(field_rev.ty).is_text())
+      .map(|field_rev| field_rev.id.clone())
+      .collect();
+    Ok(field_ids)
+  }
+
+  async fn get_primary_field_rev(&self) -> Option<Arc<FieldRevision>> {
+    self
+      .database_pad
+      .read()
+      .await
+      .get_field_revs(None)
+      .ok()?
+      .into_iter()
+      .find(|field_rev| field_rev.is_primary)
+  }
+
   pub async fn subscribe_view_changed(
     &self,
     view_id: &str,
@@ -540,6 +1209,7 @@ impl DatabaseEditor {
         view_id: view_id.to_string(),
         start_row_id: Some(row.id.clone()),
         group_id: None,
+        swimlane_id: None,
         cell_data_by_field_id: Some(cell_data_by_field_id),
       };
 
@@ -550,13 +1220,157 @@ impl DatabaseEditor {
 
   /// Returns the cell data that encoded in protobuf.
   pub async fn get_cell(&self, params: &CellIdParams) -> Option<CellPB> {
-    let (field_type, cell_bytes) = self.get_type_cell_protobuf(params).await?;
-    Some(CellPB::new(
+    let field_rev = self.get_field_rev(&params.field_id).await?;
+    let (_, row_rev) = self
+      .database_blocks
+      .get_row_rev(&params.row_id)
+      .await
+      .ok()??;
+    let cell_rev = row_rev.cells.get(&params.field_id)?;
+    let (field_type, cell_bytes) = get_type_cell_protobuf(
+      cell_rev.type_cell_data.clone(),
+      &field_rev,
+      Some(self.cell_data_cache.clone()),
+    );
+    let mut cell = CellPB::new(
       &params.field_id,
       &params.row_id,
       field_type,
       cell_bytes.to_vec(),
-    ))
+    );
+    cell.is_conflicted = self
+      .conflicted_cells
+      .contains(&params.row_id, &params.field_id);
+    cell.accessibility = Some(make_cell_accessibility(&field_rev, Some(cell_rev)));
+    Some(cell)
+  }
+
+  /// Returns the row with the accessibility of its visible cells for the screen readers, see
+  /// [make_row_accessibility].
+  pub async fn get_row_with_accessibility(&self, row_id: &str) -> FlowyResult<Option<RowPB>> {
+    let row_rev = match self.get_row_rev(row_id).await? {
+      None => return Ok(None),
+      Some(row_rev) => row_rev,
+    };
+    let field_revs = self.get_field_revs(None).await?;
+    let mut row = RowPB::from(&row_rev);
+    row.accessibility = Some(make_row_accessibility(&field_revs, &row_rev));
+    Ok(Some(row))
+  }
+
+  /// Marks the cell as conflicted, the changesets of the cell are rejected until the conflict
+  /// is resolved by calling [Self::resolve_cell_conflict].
+  pub fn mark_cell_conflicted(&self, row_id: &str, field_id: &str) {
+    if self.conflicted_cells.mark(row_id, field_id) {
+      notify_did_update_cell_conflict(row_id, field_id);
+    }
+  }
+
+  pub fn resolve_cell_conflict(&self, row_id: &str, field_id: &str) {
+    if self.conflicted_cells.resolve(row_id, field_id) {
+      notify_did_update_cell_conflict(row_id, field_id);
+    }
+  }
+
+  /// Applies the changeset if the cell still has the `base_type_cell_data` that the changeset was
+  /// made from. Otherwise, the cell was changed in the meantime, e.g. in another view or by the
+  /// automations, and the changeset conflicts with that change. The changeset is rejected and the
+  /// cell is marked as conflicted.
+  pub async fn update_cell_from_base<T: ToCellChangesetString>(
+    &self,
+    row_id: &str,
+    field_id: &str,
+    base_type_cell_data: Option<String>,
+    cell_changeset: T,
+  ) -> FlowyResult<()> {
+    if let Some(base_type_cell_data) = base_type_cell_data {
+      let type_cell_data = self
+        .get_cell_rev(row_id, field_id)
+        .await?
+        .map(|cell_rev| cell_rev.type_cell_data)
+        .unwrap_or_default();
+      if type_cell_data != base_type_cell_data {
+        self.mark_cell_conflicted(row_id, field_id);
+      }
+    }
+    self
+      .update_cell_with_changeset(row_id, field_id, cell_changeset)
+      .await
+  }
+
+  /// Returns the cell at the `address`, see [CellAddressPB] for more information.
+  pub async fn get_cell_by_address(
+    &self,
+    address: CellAddressParams,
+  ) -> FlowyResult<AddressedCellPB> {
+    let (row_revs, field_revs) = self.get_addressable_cells(&address.view_id).await?;
+    self
+      .get_addressed_cell(address, &row_revs, &field_revs)
+      .await
+  }
+
+  /// Returns the cell next to the cell at the `address` in the `direction`. It returns the cell
+  /// at the `address` if there is no cell in the `direction`.
+  pub async fn get_adjacent_cell(
+    &self,
+    address: CellAddressParams,
+    direction: CellMoveDirectionPB,
+  ) -> FlowyResult<AddressedCellPB> {
+    let (row_revs, field_revs) = self.get_addressable_cells(&address.view_id).await?;
+    let address = address.adjacent(&direction, row_revs.len(), field_revs.len());
+    self
+      .get_addressed_cell(address, &row_revs, &field_revs)
+      .await
+  }
+
+  /// Returns the rows of the view in the order they are displayed and the visible fields.
+  async fn get_addressable_cells(
+    &self,
+    view_id: &str,
+  ) -> FlowyResult<(Vec<Arc<RowRevision>>, Vec<Arc<FieldRevision>>)> {
+    let row_revs = self.get_all_row_revs(view_id).await?;
+    let field_revs = self
+      .get_field_revs(None)
+      .await?
+      .into_iter()
+      .filter(|field_rev| field_rev.visibility)
+      .collect::<Vec<Arc<FieldRevision>>>();
+    Ok((row_revs, field_revs))
+  }
+
+  async fn get_addressed_cell(
+    &self,
+    address: CellAddressParams,
+    row_revs: &[Arc<RowRevision>],
+    field_revs: &[Arc<FieldRevision>],
+  ) -> FlowyResult<AddressedCellPB> {
+    let (row_rev, field_rev) = match (
+      row_revs.get(address.row_index),
+      field_revs.get(address.field_index),
+    ) {
+      (Some(row_rev), Some(field_rev)) => (row_rev, field_rev),
+      _ => {
+        let msg = format!(
+          "There is no cell at row {}, field {}",
+          address.row_index, address.field_index
+        );
+        return Err(FlowyError::out_of_bounds().context(msg));
+      },
+    };
+
+    let params = CellIdParams {
+      view_id: address.view_id.clone(),
+      field_id: field_rev.id.clone(),
+      row_id: row_rev.id.clone(),
+    };
+    let cell = self
+      .get_cell(&params)
+      .await
+      .unwrap_or_else(|| CellPB::empty(&params.field_id, &params.row_id));
+    Ok(AddressedCellPB {
+      address: address.into(),
+      cell,
+    })
   }
 
   /// Returns a string that represents the current field_type's cell data.
@@ -585,6 +1399,36 @@ impl DatabaseEditor {
     display_str().await.unwrap_or_default()
   }
 
+  /// Returns the display strings of all the non-empty cells in the view. For example, they
+  /// can be used to build the search index of the view.
+  pub async fn get_all_cell_display_strs(&self, view_id: &str) -> FlowyResult<Vec<String>> {
+    let field_revs = self.get_field_revs(None).await?;
+    let row_revs = self.get_all_row_revs(view_id).await?;
+    let mut display_strs = vec![];
+    for row_rev in row_revs.iter() {
+      for field_rev in field_revs.iter() {
+        let type_cell_data = match row_rev.cells.get(&field_rev.id) {
+          None => continue,
+          Some(cell_rev) => match TypeCellData::try_from(cell_rev) {
+            Ok(type_cell_data) => type_cell_data,
+            Err(_) => continue,
+          },
+        };
+        let field_type: FieldType = field_rev.ty.into();
+        let display_str = stringify_cell_data(
+          type_cell_data.cell_str,
+          &type_cell_data.field_type,
+          &field_type,
+          field_rev,
+        );
+        if !display_str.is_empty() {
+          display_strs.push(display_str);
+        }
+      }
+    }
+    Ok(display_strs)
+  }
+
   pub async fn get_cell_protobuf(&self, params: &CellIdParams) -> Option<CellProtobufBlob> {
     let (_, cell_data) = self.get_type_cell_protobuf(params).await?;
     Some(cell_data)
@@ -622,6 +1466,186 @@ impl DatabaseEditor {
     }
   }
 
+  /// Returns the type option of the relation field and the rows that the cell links. The
+  /// linked rows belong to the database of the type option.
+  pub async fn get_relation_cell_data(
+    &self,
+    row_id: &str,
+    field_id: &str,
+  ) -> FlowyResult<(RelationTypeOptionPB, RelationCellData)> {
+    let field_rev = self
+      .get_field_rev(field_id)
+      .await
+      .ok_or_else(FlowyError::field_record_not_found)?;
+    if !FieldType::from(field_rev.ty).is_relation() {
+      let msg = format!("The field:{} is not a relation field", field_id);
+      return Err(FlowyError::invalid_data().context(msg));
+    }
+
+    let cell_data = self
+      .get_cell_rev(row_id, field_id)
+      .await?
+      .and_then(|cell_rev| TypeCellData::try_from(&cell_rev).ok())
+      .filter(|type_cell_data| type_cell_data.is_relation())
+      .map(|type_cell_data| RelationCellData::from(type_cell_data.cell_str))
+      .unwrap_or_default();
+    Ok((RelationTypeOptionPB::from(&field_rev), cell_data))
+  }
+
+  /// Writes the relation cells of the rows that were changed since the last refresh to the
+  /// [RelationLinkIndex], or the cells of all the rows if the database was just opened.
+  pub(crate) async fn refresh_relation_links(&self) -> FlowyResult<()> {
+    let relation_fields = self
+      .get_field_revs(None)
+      .await?
+      .into_iter()
+      .filter(|field_rev| FieldType::from(field_rev.ty).is_relation())
+      .map(|field_rev| {
+        let type_option = RelationTypeOptionPB::from(field_rev.as_ref());
+        (field_rev.id.clone(), type_option.database_id)
+      })
+      .collect::<Vec<(String, String)>>();
+    match self.relation_links.take_stale_row_ids() {
+      None => {
+        let links = self
+          .database_blocks
+          .get_row_revs()
+          .await?
+          .iter()
+          .flat_map(|row_rev| get_row_relation_links(&self.database_id, row_rev, &relation_fields))
+          .collect::<Vec<RelationLink>>();
+        self
+          .relation_link_index
+          .replace_links(&self.database_id, None, links)?;
+        self.relation_links.did_load();
+      },
+      Some(row_ids) => {
+        if row_ids.is_empty() {
+          return Ok(());
+        }
+        let mut links = vec![];
+        for row_id in row_ids.iter() {
+          // The deleted rows don't link any row until they are restored
+          if let Some(row_rev) = self.get_row_rev(row_id).await? {
+            links.extend(get_row_relation_links(
+              &self.database_id,
+              &row_rev,
+              &relation_fields,
+            ));
+          }
+        }
+        self
+          .relation_link_index
+          .replace_links(&self.database_id, Some(&row_ids), links)?;
+      },
+    }
+    Ok(())
+  }
+
+  /// Returns the type option of the attachment field and the files that are attached to the
+  /// cell.
+  pub async fn get_attachment_cell_data(
+    &self,
+    row_id: &str,
+    field_id: &str,
+  ) -> FlowyResult<(AttachmentTypeOptionPB, AttachmentCellData)> {
+    let field_rev = self
+      .get_field_rev(field_id)
+      .await
+      .ok_or_else(FlowyError::field_record_not_found)?;
+    if !FieldType::from(field_rev.ty).is_attachment() {
+      let msg = format!("The field:{} is not an attachment field", field_id);
+      return Err(FlowyError::invalid_data().context(msg));
+    }
+
+    let cell_data = self
+      .get_cell_rev(row_id, field_id)
+      .await?
+      .and_then(|cell_rev| TypeCellData::try_from(&cell_rev).ok())
+      .filter(|type_cell_data| type_cell_data.is_attachment())
+      .and_then(|type_cell_data| AttachmentCellData::from_cell_str(&type_cell_data.cell_str).ok())
+      .unwrap_or_default();
+    Ok((AttachmentTypeOptionPB::from(&field_rev), cell_data))
+  }
+
+  /// Returns true if any cell of the database still references the file of the attachment,
+  /// e.g. the row or the field was duplicated with its attachments. The rows in the trash
+  /// keep their files until they get purged.
+  pub async fn is_attachment_referenced(&self, attachment_id: &str) -> FlowyResult<bool> {
+    let is_referenced = self
+      .database_blocks
+      .get_blocks(None)
+      .await?
+      .into_iter()
+      .flat_map(|block| block.row_revs)
+      .any(|row_rev| {
+        attachment_ids_of_row(&row_rev)
+          .iter()
+          .any(|id| id == attachment_id)
+      });
+    if is_referenced {
+      return Ok(true);
+    }
+
+    let is_trashed = self
+      .row_trash
+      .get_all(&self.database_id)?
+      .iter()
+      .any(|trashed_row| {
+        attachment_ids_of_row(&trashed_row.row_rev)
+          .iter()
+          .any(|id| id == attachment_id)
+      });
+    Ok(is_trashed)
+  }
+
+  /// Returns the values that the rollup aggregates from the cells of the field in the rows.
+  /// Each non-empty cell gives one value, it's None if the cell is not a number. The rows that
+  /// don't exist are skipped.
+  pub async fn get_rollup_values(
+    &self,
+    row_ids: &[String],
+    field_id: &str,
+  ) -> FlowyResult<Vec<Option<Decimal>>> {
+    let field_rev = self
+      .get_field_rev(field_id)
+      .await
+      .ok_or_else(FlowyError::field_record_not_found)?;
+    let field_type: FieldType = field_rev.ty.into();
+    let mut values = vec![];
+    for row_id in row_ids {
+      let type_cell_data = match self.get_cell_rev(row_id, field_id).await? {
+        None => continue,
+        Some(cell_rev) => match TypeCellData::try_from(&cell_rev) {
+          Ok(type_cell_data) => type_cell_data,
+          Err(_) => continue,
+        },
+      };
+      let display_str = stringify_cell_data(
+        type_cell_data.cell_str.clone(),
+        &type_cell_data.field_type,
+        &field_type,
+        &field_rev,
+      );
+      if display_str.is_empty() {
+        continue;
+      }
+
+      // The display string of the number might contain the currency or the unit
+      let value = match field_type {
+        FieldType::Number if type_cell_data.is_number() => *NumberTypeOptionPB::from(&field_rev)
+          .format_cell_data(&type_cell_data.cell_str)?
+          .decimal(),
+        FieldType::Rollup if type_cell_data.is_rollup() => {
+          RollupCellData::from_cell_str(&type_cell_data.cell_str)?.value
+        },
+        _ => Decimal::from_str(display_str.trim()).ok(),
+      };
+      values.push(value);
+    }
+    Ok(values)
+  }
+
   /// Returns the list of cells corresponding to the given field.
   pub async fn get_cells_for_field(
     &self,
@@ -632,6 +1656,343 @@ impl DatabaseEditor {
     view_editor.v_get_cells_for_field(field_id).await
   }
 
+  /// Returns the options of the select field that are not referenced by any cell, and the
+  /// cells that reference the options that were deleted.
+  pub async fn get_select_option_usage(&self, field_id: &str) -> FlowyResult<SelectOptionUsagePB> {
+    let field_rev = self
+      .get_field_rev(field_id)
+      .await
+      .ok_or_else(FlowyError::field_record_not_found)?;
+    let row_revs = self
+      .database_blocks
+      .get_blocks(None)
+      .await?
+      .into_iter()
+      .flat_map(|block| block.row_revs)
+      .collect::<Vec<Arc<RowRevision>>>();
+    make_select_option_usage(&field_rev, &row_revs)
+  }
+
+  /// Removes the references to the deleted options from the cells, and then deletes the options
+  /// that are not referenced by any cell. Returns the usage after cleaning up.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn cleanup_select_options(
+    &self,
+    view_id: &str,
+    field_id: &str,
+  ) -> FlowyResult<SelectOptionUsagePB> {
+    let usage = self.get_select_option_usage(field_id).await?;
+    for dangling_ref in usage.dangling_refs {
+      let cell_str = match self.get_cell_rev(&dangling_ref.row_id, field_id).await? {
+        None => continue,
+        Some(cell_rev) => TypeCellData::try_from(cell_rev)?.cell_str,
+      };
+      // The single select cell is replaced by the inserted options, so the valid options of
+      // the cell need to be inserted again.
+      let insert_option_ids = SelectOptionIds::from(cell_str)
+        .into_inner()
+        .into_iter()
+        .filter(|option_id| !dangling_ref.option_ids.contains(option_id))
+        .collect::<Vec<String>>();
+      let changeset = SelectOptionCellChangeset {
+        insert_option_ids,
+        delete_option_ids: dangling_ref.option_ids,
+        insert_option_names: vec![],
+        parent_option_ids: None,
+      };
+      self
+        .update_cell_with_changeset(&dangling_ref.row_id, field_id, changeset)
+        .await?;
+    }
+
+    if !usage.unused_options.is_empty() {
+      let unused_options = usage.unused_options;
+      self
+        .modify_field_rev(view_id, field_id, |field_rev| {
+          let mut type_option = select_type_option_from_field_rev(field_rev)?;
+          for option in unused_options {
+            type_option.delete_option(option);
+          }
+          field_rev.insert_type_option(&*type_option);
+          Ok(Some(()))
+        })
+        .await?;
+    }
+    self.get_select_option_usage(field_id).await
+  }
+
+  /// Returns the cells that can't be read by their fields as they are, see [validate_cells].
+  pub async fn get_invalid_cells(&self) -> FlowyResult<Vec<InvalidCellPB>> {
+    let field_revs = self.database_pad.read().await.get_field_revs(None)?;
+    let row_revs = self.database_blocks.get_row_revs().await?;
+    Ok(validate_cells(&field_revs, &row_revs))
+  }
+
+  /// Encodes the invalid cells again with the type option of their fields. Returns the cells
+  /// that are still invalid after repairing.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn repair_invalid_cells(&self) -> FlowyResult<Vec<InvalidCellPB>> {
+    for invalid_cell in self.get_invalid_cells().await? {
+      let field_rev = match self.get_field_rev(&invalid_cell.field_id).await {
+        None => continue,
+        Some(field_rev) => field_rev,
+      };
+      let old_row_rev = self.get_row_rev(&invalid_cell.row_id).await?;
+      let cell_rev = match self
+        .get_cell_rev(&invalid_cell.row_id, &invalid_cell.field_id)
+        .await?
+      {
+        None => continue,
+        Some(cell_rev) => cell_rev,
+      };
+      let cell_changeset = CellChangesetPB {
+        view_id: self.database_id.clone(),
+        row_id: invalid_cell.row_id.clone(),
+        field_id: invalid_cell.field_id,
+        type_cell_data: repair_cell(&field_rev, &cell_rev),
+        base_type_cell_data: None,
+      };
+      self.database_blocks.update_cell(cell_changeset).await?;
+      self
+        .database_views
+        .did_update_row(old_row_rev, &invalid_cell.row_id)
+        .await;
+    }
+    self.get_invalid_cells().await
+  }
+
+  /// Returns the rows whose ids collide with other rows, see [find_row_id_collisions].
+  pub async fn get_row_id_collisions(&self) -> FlowyResult<Vec<RowIdCollisionPB>> {
+    let blocks = self.database_blocks.get_blocks(None).await?;
+    Ok(find_row_id_collisions(&blocks, |row_id| {
+      self.database_blocks.get_indexed_block_id(row_id)
+    }))
+  }
+
+  /// Gives the colliding rows new ids that are generated by [gen_row_id]. The references to
+  /// the old ids, e.g. the relation cells, keep pointing to the row that keeps the old id. The
+  /// attachments are referenced by the cells, so they move with the remapped rows.
+  ///
+  /// If the id is used by a row of another database, the shares of the row in this database
+  /// are moved to its new id. The trashed rows whose ids are used by the live rows get new ids
+  /// too, so they can be restored. Returns the old and the new ids of the remapped rows.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn repair_row_id_collisions(&self) -> FlowyResult<Vec<(String, String)>> {
+    let mut remapped_row_ids: Vec<(String, String)> = vec![];
+    for collision in self.get_row_id_collisions().await? {
+      let new_row_id = gen_row_id();
+      tracing::info!(
+        "Remap the row:{} at {} of block:{} to {}",
+        collision.row_id,
+        collision.index,
+        collision.block_id,
+        new_row_id
+      );
+      self
+        .database_blocks
+        .remap_row_id(&collision.block_id, collision.index as usize, &new_row_id)
+        .await?;
+      let is_first_remapped = !remapped_row_ids
+        .iter()
+        .any(|(row_id, _)| row_id == &collision.row_id);
+      if collision.reason == RowIdCollisionReasonPB::UsedByOtherDatabase && is_first_remapped {
+        self
+          .row_shares
+          .update_row_id(&self.database_id, &collision.row_id, &new_row_id)?;
+      }
+      remapped_row_ids.push((collision.row_id, new_row_id));
+    }
+
+    let blocks = self.database_blocks.get_blocks(None).await?;
+    let block_ids = blocks
+      .iter()
+      .map(|block| block.block_id.clone())
+      .collect::<HashSet<String>>();
+    let row_ids = blocks
+      .iter()
+      .flat_map(|block| block.row_revs.iter().map(|row_rev| row_rev.id.clone()))
+      .collect::<HashSet<String>>();
+    for trashed_row in self.row_trash.get_all(&self.database_id)? {
+      let row_id = &trashed_row.row_rev.id;
+      let is_used_by_other_database = self
+        .database_blocks
+        .get_indexed_block_id(row_id)
+        .map(|block_id| !block_ids.contains(&block_id))
+        .unwrap_or(false);
+      if row_ids.contains(row_id) || is_used_by_other_database {
+        let new_row_id = gen_row_id();
+        tracing::info!("Remap the trashed row:{} to {}", row_id, new_row_id);
+        self.row_trash.update_row_id(row_id, &new_row_id)?;
+        remapped_row_ids.push((row_id.clone(), new_row_id));
+      }
+    }
+    Ok(remapped_row_ids)
+  }
+
+  /// Returns the options of the select field in their order.
+  pub async fn get_select_options(&self, field_id: &str) -> FlowyResult<Vec<SelectOptionPB>> {
+    let field_rev = self
+      .get_field_rev(field_id)
+      .await
+      .ok_or_else(FlowyError::field_record_not_found)?;
+    let type_option = select_type_option_from_field_rev(&field_rev)?;
+    Ok(type_option.options().clone())
+  }
+
+  /// Merges the `options` into the options of the select field by name, see
+  /// [merge_select_options] for more details. Returns the options after merging.
+  #[tracing::instrument(level = "trace", skip(self, options), err)]
+  pub async fn import_select_options(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    options: Vec<SelectOptionPB>,
+  ) -> FlowyResult<Vec<SelectOptionPB>> {
+    self
+      .modify_field_rev(view_id, field_id, |field_rev| {
+        let mut type_option = select_type_option_from_field_rev(field_rev)?;
+        merge_select_options(type_option.mut_options(), options);
+        field_rev.insert_type_option(&*type_option);
+        Ok(Some(()))
+      })
+      .await?;
+    self.get_select_options(field_id).await
+  }
+
+  /// Changes the color of the option. Returns the option after the change.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn update_select_option_color(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    option_id: &str,
+    color: SelectOptionColorPB,
+  ) -> FlowyResult<SelectOptionPB> {
+    let mut updated_option = None;
+    self
+      .modify_field_rev(view_id, field_id, |field_rev| {
+        let mut type_option = select_type_option_from_field_rev(field_rev)?;
+        let option = type_option
+          .mut_options()
+          .iter_mut()
+          .find(|option| option.id == option_id)
+          .ok_or_else(|| {
+            FlowyError::record_not_found().context(format!("Option:{} not found", option_id))
+          })?;
+        if option.color == color {
+          updated_option = Some(option.clone());
+          return Ok(None);
+        }
+        option.color = color;
+        updated_option = Some(option.clone());
+        field_rev.insert_type_option(&*type_option);
+        Ok(Some(()))
+      })
+      .await?;
+    updated_option.ok_or_else(|| FlowyError::internal().context("Update option color failed"))
+  }
+
+  /// Moves the option `from_option_id` to the position of the option `to_option_id`, see
+  /// [move_select_option]. Returns the options after moving.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn move_select_option(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    from_option_id: &str,
+    to_option_id: &str,
+  ) -> FlowyResult<Vec<SelectOptionPB>> {
+    self
+      .modify_field_rev(view_id, field_id, |field_rev| {
+        let mut type_option = select_type_option_from_field_rev(field_rev)?;
+        if !move_select_option(type_option.mut_options(), from_option_id, to_option_id) {
+          let msg = format!("Option:{} or {} not found", from_option_id, to_option_id);
+          return Err(FlowyError::record_not_found().context(msg));
+        }
+        if from_option_id == to_option_id {
+          return Ok(None);
+        }
+        field_rev.insert_type_option(&*type_option);
+        Ok(Some(()))
+      })
+      .await?;
+    self.get_select_options(field_id).await
+  }
+
+  /// Merges the `merged_option_ids` into the option `target_option_id`. The cells that reference
+  /// the merged options reference the target option instead, so the rows are moved to the group
+  /// of the target option, and then the merged options are deleted. Returns the options after
+  /// merging.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn merge_select_options_into(
+    &self,
+    view_id: &str,
+    field_id: &str,
+    target_option_id: &str,
+    merged_option_ids: Vec<String>,
+  ) -> FlowyResult<Vec<SelectOptionPB>> {
+    let options = self.get_select_options(field_id).await?;
+    let contains = |option_id: &str| options.iter().any(|option| option.id == option_id);
+    if let Some(option_id) = std::iter::once(target_option_id)
+      .chain(merged_option_ids.iter().map(|option_id| option_id.as_str()))
+      .find(|option_id| !contains(option_id))
+    {
+      let msg = format!("Option:{} not found", option_id);
+      return Err(FlowyError::record_not_found().context(msg));
+    }
+    if merged_option_ids.is_empty() {
+      return Ok(options);
+    }
+
+    let row_revs = self.database_blocks.get_row_revs().await?;
+    for row_rev in row_revs.iter() {
+      let cell_str = match row_rev.cells.get(field_id) {
+        None => continue,
+        Some(cell_rev) => TypeCellData::try_from(cell_rev)?.cell_str,
+      };
+      let option_ids = SelectOptionIds::from(cell_str).into_inner();
+      if !option_ids
+        .iter()
+        .any(|option_id| merged_option_ids.contains(option_id))
+      {
+        continue;
+      }
+      // The single select cell is replaced by the inserted options, so the other options of the
+      // cell need to be inserted again.
+      let mut insert_option_ids = option_ids
+        .into_iter()
+        .filter(|option_id| !merged_option_ids.contains(option_id))
+        .collect::<Vec<String>>();
+      if !insert_option_ids
+        .iter()
+        .any(|option_id| option_id == target_option_id)
+      {
+        insert_option_ids.push(target_option_id.to_owned());
+      }
+      let changeset = SelectOptionCellChangeset {
+        insert_option_ids,
+        delete_option_ids: merged_option_ids.clone(),
+        insert_option_names: vec![],
+        parent_option_ids: None,
+      };
+      self
+        .update_cell_with_changeset(&row_rev.id, field_id, changeset)
+        .await?;
+    }
+
+    self
+      .modify_field_rev(view_id, field_id, |field_rev| {
+        let mut type_option = select_type_option_from_field_rev(field_rev)?;
+        type_option
+          .mut_options()
+          .retain(|option| !merged_option_ids.contains(&option.id));
+        field_rev.insert_type_option(&*type_option);
+        Ok(Some(()))
+      })
+      .await?;
+    self.get_select_options(field_id).await
+  }
+
   #[tracing::instrument(level = "trace", skip_all, err)]
   pub async fn update_cell_with_changeset<T: ToCellChangesetString>(
     &self,
@@ -639,6 +2000,7 @@ impl DatabaseEditor {
     field_id: &str,
     cell_changeset: T,
   ) -> FlowyResult<()> {
+    self.conflicted_cells.check_cell(row_id, field_id)?;
     match self.database_pad.read().await.get_field_rev(field_id) {
       None => {
         let msg = format!("Field with id:{} not found", &field_id);
@@ -650,31 +2012,302 @@ impl DatabaseEditor {
           &field_id,
           cell_changeset
         );
-        let old_row_rev = self.get_row_rev(row_id).await?.clone();
-        let cell_rev = self.get_cell_rev(row_id, field_id).await?;
-        // Update the changeset.data property with the return value.
-        let type_cell_data = apply_cell_data_changeset(
-          cell_changeset,
-          cell_rev,
-          field_rev,
-          Some(self.cell_data_cache.clone()),
-        )?;
-        let cell_changeset = CellChangesetPB {
-          view_id: self.database_id.clone(),
-          row_id: row_id.to_owned(),
-          field_id: field_id.to_owned(),
-          type_cell_data,
+        let old_cell_rev = self.get_cell_rev(row_id, field_id).await?;
+        let type_cell_data = self
+          .apply_cell_changeset(row_id, field_rev, cell_changeset)
+          .await?;
+        self
+          .save_cell_data(row_id, field_id, type_cell_data)
+          .await?;
+        if FieldType::from(field_rev.ty) == FieldType::Person {
+          self
+            .notify_assigned_user(row_id, field_rev, old_cell_rev)
+            .await;
+        }
+        self.revalidate_dependent_cells(row_id, field_id).await
+      },
+    }
+  }
+
+  /// Adds a mention to the inbox if the current user was assigned to the person cell of the row.
+  async fn notify_assigned_user(
+    &self,
+    row_id: &str,
+    field_rev: &FieldRevision,
+    old_cell_rev: Option<CellRevision>,
+  ) {
+    let user_id = current_user_id();
+    let is_assigned = |cell_rev: Option<CellRevision>| {
+      cell_rev
+        .and_then(|cell_rev| TypeCellData::try_from(&cell_rev).ok())
+        .map(|type_cell_data| PersonCellData::from(type_cell_data.cell_str).user_ids)
+        .map(|user_ids| user_ids.contains(&user_id))
+        .unwrap_or(false)
+    };
+    if user_id.is_empty() || is_assigned(old_cell_rev) {
+      return;
+    }
+    let cell_rev = self
+      .get_cell_rev(row_id, &field_rev.id)
+      .await
+      .ok()
+      .flatten();
+    if !is_assigned(cell_rev) {
+      return;
+    }
+
+    let notification = DatabaseInboxNotification {
+      kind: DatabaseInboxKind::Mention,
+      title: self.get_row_title(row_id).await,
+      body: format!("You are assigned to {}", field_rev.name),
+      object_id: row_id.to_owned(),
+    };
+    if let Err(err) = self.inbox.add_notification(notification) {
+      tracing::error!("Add the assignment to the inbox failed: {:?}", err);
+    }
+  }
+
+  /// Updates the select option cell. The option names in the changeset that don't match any
+  /// option are created if the field's `create_missing_options` is on. The new options are only
+  /// saved if the changeset can be applied to the cell, so the options and the cell are saved
+  /// together.
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  pub async fn update_select_option_cell(
+    &self,
+    view_id: &str,
+    row_id: &str,
+    field_id: &str,
+    changeset: SelectOptionCellChangeset,
+  ) -> FlowyResult<()> {
+    let field_rev = self.get_field_rev(field_id).await.ok_or_else(|| {
+      FlowyError::record_not_found().context(format!("Field with id:{} not found", field_id))
+    })?;
+    let mut type_option = select_type_option_from_field_rev(&field_rev)?;
+    let new_options = type_option.insert_missing_options(&changeset.insert_option_names);
+    if new_options.is_empty() {
+      return self
+        .update_cell_with_changeset(row_id, field_id, changeset)
+        .await;
+    }
+
+    self.conflicted_cells.check_cell(row_id, field_id)?;
+    let mut new_field_rev = (*field_rev).clone();
+    new_field_rev.insert_type_option(&*type_option);
+    let type_cell_data = self
+      .apply_cell_changeset(row_id, &new_field_rev, changeset)
+      .await?;
+    self
+      .modify_field_rev(view_id, field_id, |field_rev| {
+        let mut type_option = select_type_option_from_field_rev(field_rev)?;
+        type_option.mut_options().extend(new_options);
+        field_rev.insert_type_option(&*type_option);
+        Ok(Some(()))
+      })
+      .await?;
+    self
+      .save_cell_data(row_id, field_id, type_cell_data)
+      .await?;
+    self.revalidate_dependent_cells(row_id, field_id).await
+  }
+
+  /// Removes the options of the select cells that depend on the cell of the `field_id` and
+  /// can't be selected anymore after the cell changed. The cells that depend on the updated
+  /// cells are revalidated as well.
+  async fn revalidate_dependent_cells(&self, row_id: &str, field_id: &str) -> FlowyResult<()> {
+    match self.get_field_rev(field_id).await {
+      None => return Ok(()),
+      Some(field_rev) => {
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.is_select_option() {
+          return Ok(());
+        }
+      },
+    }
+
+    let field_revs = self.get_field_revs(None).await?;
+    let mut changed_field_ids = vec![field_id.to_owned()];
+    let mut visited_field_ids = HashSet::new();
+    while let Some(parent_field_id) = changed_field_ids.pop() {
+      if !visited_field_ids.insert(parent_field_id.clone()) {
+        continue;
+      }
+      for field_rev in field_revs.iter() {
+        let type_option = match select_type_option_from_field_rev(field_rev) {
+          Ok(type_option) => type_option,
+          Err(_) => continue,
         };
-        self.database_blocks.update_cell(cell_changeset).await?;
+        if type_option
+          .dependency()
+          .map(|dependency| &dependency.field_id)
+          != Some(&parent_field_id)
+        {
+          continue;
+        }
+
+        let parent_option_ids = self
+          .get_selected_option_ids(row_id, &parent_field_id)
+          .await?;
+        let allowed_option_ids = type_option
+          .get_allowed_options(&parent_option_ids)
+          .into_iter()
+          .map(|option| option.id)
+          .collect::<HashSet<String>>();
+        let delete_option_ids = self
+          .get_selected_option_ids(row_id, &field_rev.id)
+          .await?
+          .into_iter()
+          .filter(|option_id| !allowed_option_ids.contains(option_id))
+          .collect::<Vec<String>>();
+        if delete_option_ids.is_empty() {
+          continue;
+        }
+
+        let changeset = SelectOptionCellChangeset::from_delete_options(delete_option_ids);
+        let type_cell_data = self
+          .apply_cell_changeset(row_id, field_rev.clone(), changeset)
+          .await?;
         self
-          .database_views
-          .did_update_row(old_row_rev, row_id)
-          .await;
-        Ok(())
+          .save_cell_data(row_id, &field_rev.id, type_cell_data)
+          .await?;
+        changed_field_ids.push(field_rev.id.clone());
+      }
+    }
+    Ok(())
+  }
+
+  /// Returns the cell data after applying the changeset to the cell.
+  async fn apply_cell_changeset<T: ToCellChangesetString, F: AsRef<FieldRevision>>(
+    &self,
+    row_id: &str,
+    field_rev: F,
+    cell_changeset: T,
+  ) -> FlowyResult<String> {
+    let cell_rev = self.get_cell_rev(row_id, &field_rev.as_ref().id).await?;
+    let cell_changeset = self
+      .fill_parent_option_ids(
+        row_id,
+        field_rev.as_ref(),
+        cell_changeset.to_cell_changeset_str(),
+      )
+      .await?;
+    apply_cell_data_changeset(
+      cell_changeset,
+      cell_rev,
+      field_rev,
+      Some(self.cell_data_cache.clone()),
+      Some(&self.cell_size_limits),
+    )
+  }
+
+  /// Fills the options that are selected in the parent field of the row if the select field
+  /// depends on another field, the options of the changeset are checked against them.
+  async fn fill_parent_option_ids(
+    &self,
+    row_id: &str,
+    field_rev: &FieldRevision,
+    cell_changeset: String,
+  ) -> FlowyResult<String> {
+    let field_type: FieldType = field_rev.ty.into();
+    if !field_type.is_select_option() {
+      return Ok(cell_changeset);
+    }
+    let type_option = select_type_option_from_field_rev(field_rev)?;
+    let parent_field_id = match type_option.dependency() {
+      None => return Ok(cell_changeset),
+      Some(dependency) => dependency.field_id.clone(),
+    };
+    let mut changeset = SelectOptionCellChangeset::from_changeset(cell_changeset)?;
+    changeset.parent_option_ids = Some(
+      self
+        .get_selected_option_ids(row_id, &parent_field_id)
+        .await?,
+    );
+    Ok(changeset.to_cell_changeset_str())
+  }
+
+  async fn get_selected_option_ids(
+    &self,
+    row_id: &str,
+    field_id: &str,
+  ) -> FlowyResult<Vec<String>> {
+    match self.get_cell_rev(row_id, field_id).await? {
+      None => Ok(vec![]),
+      Some(cell_rev) => {
+        let cell_str = TypeCellData::try_from(cell_rev)?.cell_str;
+        Ok(SelectOptionIds::from(cell_str).into_inner())
       },
     }
   }
 
+  /// Returns the options of the select cell that can be selected with the options selected in
+  /// the field that it depends on.
+  pub async fn get_available_select_options(
+    &self,
+    row_id: &str,
+    field_id: &str,
+  ) -> FlowyResult<SelectOptionCellDataPB> {
+    let field_rev = self.get_field_rev(field_id).await.ok_or_else(|| {
+      FlowyError::record_not_found().context(format!("Field with id:{} not found", field_id))
+    })?;
+    let type_option = select_type_option_from_field_rev(&field_rev)?;
+    let options = match type_option.dependency() {
+      None => type_option.options().clone(),
+      Some(dependency) => {
+        let parent_option_ids = self
+          .get_selected_option_ids(row_id, &dependency.field_id)
+          .await?;
+        type_option.get_allowed_options(&parent_option_ids)
+      },
+    };
+    let cell_str = match self.get_cell_rev(row_id, field_id).await? {
+      None => String::new(),
+      Some(cell_rev) => TypeCellData::try_from(cell_rev)?.cell_str,
+    };
+    let select_options = type_option
+      .get_selected_options(SelectOptionIds::from(cell_str))
+      .select_options;
+    Ok(SelectOptionCellDataPB {
+      options,
+      select_options,
+    })
+  }
+
+  async fn save_cell_data(
+    &self,
+    row_id: &str,
+    field_id: &str,
+    type_cell_data: String,
+  ) -> FlowyResult<()> {
+    let old_row_rev = self.get_row_rev(row_id).await?.clone();
+    let cell_changeset = CellChangesetPB {
+      view_id: self.database_id.clone(),
+      row_id: row_id.to_owned(),
+      field_id: field_id.to_owned(),
+      type_cell_data,
+      base_type_cell_data: None,
+    };
+    self.database_blocks.update_cell(cell_changeset).await?;
+    self
+      .database_views
+      .did_update_row(old_row_rev, row_id)
+      .await;
+    Ok(())
+  }
+
+  /// Saves the cell of the computed field, e.g. the formula or the rollup cell. The type option
+  /// of the computed field ignores the changesets, so the calculated cell is saved as it is.
+  pub(crate) async fn update_computed_cell(
+    &self,
+    row_id: &str,
+    field_rev: &FieldRevision,
+    cell_str: String,
+  ) -> FlowyResult<()> {
+    let type_cell_data = TypeCellData::new(cell_str, field_rev.ty.into()).to_json();
+    self
+      .save_cell_data(row_id, &field_rev.id, type_cell_data)
+      .await
+  }
+
   #[tracing::instrument(level = "trace", skip_all, err)]
   pub async fn update_cell<T: ToCellChangesetString>(
     &self,
@@ -711,11 +2344,62 @@ impl DatabaseEditor {
     Ok(blocks)
   }
 
-  pub async fn delete_rows(&self, block_rows: Vec<DatabaseBlockRow>) -> FlowyResult<()> {
-    let changesets = self.database_blocks.delete_rows(block_rows).await?;
-    for changeset in changesets {
+  /// Moves the rows to the trash, see [DatabaseEditor::delete_row]. The rows of each block are
+  /// deleted in one revision, and they are taken out of the trash again if the deletion fails,
+  /// so the rows are either all deleted or all kept.
+  pub async fn delete_rows(&self, row_ids: &[String]) -> FlowyResult<()> {
+    let row_revs = self.get_existing_row_revs(row_ids).await?;
+    if row_revs.is_empty() {
+      return Ok(());
+    }
+
+    self.row_trash.add_rows(&self.database_id, &row_revs)?;
+    if let Err(err) = self.delete_row_revs(&row_revs).await {
+      let row_ids = row_revs
+        .iter()
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+      self.row_trash.remove_rows(&row_ids)?;
+      return Err(err);
+    }
+    Ok(())
+  }
+
+  /// Deletes the rows without moving them to the trash, e.g. the rows that were inserted by an
+  /// operation that failed afterwards.
+  pub(crate) async fn discard_rows(&self, row_ids: &[String]) -> FlowyResult<()> {
+    let row_revs = self.get_existing_row_revs(row_ids).await?;
+    self.delete_row_revs(&row_revs).await
+  }
+
+  async fn get_existing_row_revs(&self, row_ids: &[String]) -> FlowyResult<Vec<Arc<RowRevision>>> {
+    let mut row_revs = vec![];
+    for row_id in row_ids {
+      if let Some(row_rev) = self.get_row_rev(row_id).await? {
+        row_revs.push(row_rev);
+      }
+    }
+    Ok(row_revs)
+  }
+
+  async fn delete_row_revs(&self, row_revs: &[Arc<RowRevision>]) -> FlowyResult<()> {
+    let mut row_ids_by_block_id: HashMap<String, Vec<String>> = HashMap::new();
+    for row_rev in row_revs {
+      row_ids_by_block_id
+        .entry(row_rev.block_id.clone())
+        .or_default()
+        .push(row_rev.id.clone());
+    }
+    let block_rows = row_ids_by_block_id
+      .into_iter()
+      .map(|(block_id, row_ids)| DatabaseBlockRow::new(block_id, row_ids))
+      .collect::<Vec<DatabaseBlockRow>>();
+    for changeset in self.database_blocks.delete_rows(block_rows).await? {
       self.update_block(changeset).await?;
     }
+    for row_rev in row_revs {
+      self.database_views.did_delete_row(row_rev.clone()).await;
+    }
     Ok(())
   }
 
@@ -745,16 +2429,58 @@ impl DatabaseEditor {
     self.database_views.get_setting(view_id).await
   }
 
+  pub async fn get_view_description(&self, view_id: &str) -> FlowyResult<String> {
+    self.database_views.get_description(view_id).await
+  }
+
+  pub async fn update_view_description(
+    &self,
+    params: DatabaseViewDescriptionParams,
+  ) -> FlowyResult<()> {
+    self
+      .database_views
+      .update_description(&params.view_id, params.description)
+      .await
+  }
+
+  /// Returns the filters saved in the view, followed by the session filters of the view.
   pub async fn get_all_filters(&self, view_id: &str) -> FlowyResult<Vec<FilterPB>> {
-    Ok(
-      self
-        .database_views
-        .get_all_filters(view_id)
-        .await?
-        .into_iter()
-        .map(|filter| FilterPB::from(filter.as_ref()))
-        .collect(),
-    )
+    let mut filters = self
+      .database_views
+      .get_all_filters(view_id)
+      .await?
+      .into_iter()
+      .map(|filter| FilterPB::from(filter.as_ref()))
+      .collect::<Vec<FilterPB>>();
+    let session_filters = self.database_views.get_session_filters(view_id).await?;
+    filters.extend(session_filters.into_iter().map(|filter| {
+      let mut filter = FilterPB::from(filter.as_ref());
+      filter.is_session = true;
+      filter
+    }));
+    Ok(filters)
+  }
+
+  /// Explains why the row is hidden in the view or positioned where it is.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn get_row_diagnostics(
+    &self,
+    view_id: &str,
+    row_id: &str,
+  ) -> FlowyResult<RowDiagnosticsPB> {
+    let view_editor = self.database_views.get_view_editor(view_id).await?;
+    view_editor.v_get_row_diagnostics(row_id).await
+  }
+
+  pub async fn get_filtered_row_count(&self, view_id: &str) -> FlowyResult<FilteredRowCountPB> {
+    let view_editor = self.database_views.get_view_editor(view_id).await?;
+    Ok(view_editor.v_get_filtered_row_count().await)
+  }
+
+  /// Runs the filters, the sorts and the groups of the view again from scratch.
+  pub async fn recompute_view(&self, view_id: &str) -> FlowyResult<Vec<RecomputeStagePB>> {
+    let view_editor = self.database_views.get_view_editor(view_id).await?;
+    view_editor.v_recompute().await
   }
 
   pub async fn get_filters(
@@ -775,6 +2501,68 @@ impl DatabaseEditor {
     Ok(())
   }
 
+  pub async fn insert_session_filter(&self, params: AlterFilterParams) -> FlowyResult<()> {
+    self.database_views.insert_session_filter(params).await
+  }
+
+  pub async fn save_session_filter(&self, params: SessionFilterIdParams) -> FlowyResult<()> {
+    self.database_views.save_session_filter(params).await
+  }
+
+  pub async fn get_filter_presets(&self, view_id: &str) -> FlowyResult<RepeatedFilterPresetPB> {
+    let presets = self.database_views.get_filter_presets(view_id).await?;
+    Ok(presets.into())
+  }
+
+  pub async fn save_filter_preset(
+    &self,
+    params: SaveFilterPresetParams,
+  ) -> FlowyResult<FilterPresetPB> {
+    let preset = self.database_views.save_filter_preset(params).await?;
+    Ok(FilterPresetPB::from(&preset))
+  }
+
+  pub async fn apply_filter_preset(&self, params: FilterPresetIdParams) -> FlowyResult<()> {
+    self.database_views.apply_filter_preset(params).await
+  }
+
+  pub async fn delete_filter_preset(&self, params: FilterPresetIdParams) -> FlowyResult<()> {
+    self.database_views.delete_filter_preset(params).await
+  }
+
+  pub async fn get_automations(&self, view_id: &str) -> FlowyResult<RepeatedAutomationPB> {
+    let automations = self.database_views.get_automations(view_id).await?;
+    Ok(automations.into())
+  }
+
+  pub(crate) async fn get_opened_automations(&self) -> Vec<(String, Vec<AutomationRevision>)> {
+    self.database_views.get_opened_automations().await
+  }
+
+  pub(crate) async fn set_automation_fired_dates(
+    &self,
+    view_id: &str,
+    automation_id: &str,
+    fired_dates: IndexMap<String, String>,
+  ) -> FlowyResult<()> {
+    self
+      .database_views
+      .set_automation_fired_dates(view_id, automation_id, fired_dates)
+      .await
+  }
+
+  pub async fn save_automation(&self, params: SaveAutomationParams) -> FlowyResult<AutomationPB> {
+    let automation = self.database_views.save_automation(params).await?;
+    Ok(AutomationPB::from(&automation))
+  }
+
+  pub async fn delete_automation(&self, params: AutomationIdParams) -> FlowyResult<()> {
+    self
+      .database_views
+      .delete_automation(&params.view_id, &params.automation_id)
+      .await
+  }
+
   pub async fn get_all_sorts(&self, view_id: &str) -> FlowyResult<Vec<SortPB>> {
     Ok(
       self
@@ -844,6 +2632,7 @@ impl DatabaseEditor {
       from_row_id,
       to_group_id,
       to_row_id,
+      to_swimlane_id,
     } = params;
 
     match self.database_blocks.get_row_rev(&from_row_id).await? {
@@ -857,6 +2646,7 @@ impl DatabaseEditor {
             row_rev,
             to_group_id,
             to_row_id.clone(),
+            to_swimlane_id,
             |row_changeset| {
               to_fut(async move {
                 tracing::trace!("Row data changed: {:?}", row_changeset);
@@ -868,6 +2658,7 @@ impl DatabaseEditor {
                     row_id: row_changeset.row_id.clone(),
                     field_id,
                     type_cell_data: cell_rev.type_cell_data,
+                    base_type_cell_data: None,
                   })
                   .collect::<Vec<CellChangesetPB>>();
 
@@ -953,9 +2744,60 @@ impl DatabaseEditor {
     self.database_views.load_groups(view_id).await
   }
 
-  #[tracing::instrument(level = "trace", skip_all, err)]
-  pub async fn get_group(&self, view_id: &str, group_id: &str) -> FlowyResult<GroupPB> {
-    self.database_views.get_group(view_id, group_id).await
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  pub async fn get_group(&self, view_id: &str, group_id: &str) -> FlowyResult<GroupPB> {
+    self.database_views.get_group(view_id, group_id).await
+  }
+
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  pub async fn get_swimlanes(&self, view_id: &str) -> FlowyResult<SwimlaneBoardPB> {
+    self.database_views.get_swimlanes(view_id).await
+  }
+
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  pub async fn set_swimlane_field(&self, params: SwimlaneFieldParams) -> FlowyResult<()> {
+    self.database_views.set_swimlane_field(params).await
+  }
+
+  /// Returns the layout of the board as it's shown to the user, see [BoardLayout]. The groups
+  /// and their cards come from the group controller of the view. The titles of the rows that
+  /// the relation cells link live in the related databases, they are passed in by the
+  /// [DatabaseManager](crate::manager::DatabaseManager) and keyed by row id.
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  pub async fn get_board_layout(
+    &self,
+    view_id: &str,
+    related_row_titles: &HashMap<String, String>,
+  ) -> FlowyResult<BoardLayout> {
+    let groups = self.database_views.load_groups(view_id).await?.items;
+    let field_revs = self.get_field_revs(None).await?;
+    let row_revs = self
+      .get_all_row_revs(view_id)
+      .await?
+      .into_iter()
+      .map(|row_rev| (row_rev.id.clone(), row_rev))
+      .collect::<HashMap<String, Arc<RowRevision>>>();
+    Ok(make_board_layout(
+      view_id,
+      groups,
+      &field_revs,
+      &row_revs,
+      related_row_titles,
+    ))
+  }
+
+  /// Returns the JSON Schema that describes the fields of the database, and the OpenAPI
+  /// document that wraps it if `include_openapi` is true. See [make_database_json_schema].
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn get_database_schema(
+    &self,
+    view_id: &str,
+    include_openapi: bool,
+  ) -> FlowyResult<(Value, Option<Value>)> {
+    let field_revs = self.get_field_revs(None).await?;
+    let json_schema = make_database_json_schema(view_id, &field_revs);
+    let openapi = include_openapi.then(|| make_database_openapi(view_id, &field_revs));
+    Ok((json_schema, openapi))
   }
 
   pub async fn get_layout_setting<T: Into<LayoutRevision>>(
@@ -981,10 +2823,16 @@ impl DatabaseEditor {
       .await
   }
 
-  pub async fn get_all_calendar_events(&self, view_id: &str) -> Vec<CalendarEventPB> {
+  /// Returns the events of the calendar. The recurring dates are expanded into their occurrences
+  /// within the `window` if it's set.
+  pub async fn get_all_calendar_events(
+    &self,
+    view_id: &str,
+    window: Option<(i64, i64)>,
+  ) -> Vec<CalendarEventPB> {
     match self.database_views.get_view_editor(view_id).await {
       Ok(view_editor) => view_editor
-        .v_get_all_calendar_events()
+        .v_get_all_calendar_events(window)
         .await
         .unwrap_or_default(),
       Err(err) => {
@@ -994,12 +2842,277 @@ impl DatabaseEditor {
     }
   }
 
+  /// Returns the events of the week that the `timestamp` is in, see [get_all_calendar_events].
+  pub async fn get_calendar_events_of_week(
+    &self,
+    view_id: &str,
+    timestamp: i64,
+  ) -> FlowyResult<Vec<CalendarEventPB>> {
+    let view_editor = self.database_views.get_view_editor(view_id).await?;
+    let window = view_editor.v_get_calendar_week_window(timestamp).await?;
+    Ok(
+      view_editor
+        .v_get_all_calendar_events(Some(window))
+        .await
+        .unwrap_or_default(),
+    )
+  }
+
   #[tracing::instrument(level = "trace", skip(self))]
   pub async fn get_calendar_event(&self, view_id: &str, row_id: &str) -> Option<CalendarEventPB> {
     let view_editor = self.database_views.get_view_editor(view_id).await.ok()?;
     view_editor.v_get_calendar_event(row_id).await
   }
 
+  /// Writes the `timestamp` to the date cell that the calendar is laid out by. It's used to
+  /// schedule the row when its card is dragged onto a day of the calendar.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn schedule_row(
+    &self,
+    view_id: &str,
+    row_id: &str,
+    timestamp: i64,
+  ) -> FlowyResult<CalendarEventPB> {
+    let view_editor = self.database_views.get_view_editor(view_id).await?;
+    let date_field_id = view_editor
+      .v_get_layout_settings(&LayoutRevision::Calendar)
+      .await?
+      .calendar
+      .map(|calendar_setting| calendar_setting.layout_field_id)
+      .ok_or_else(|| {
+        FlowyError::record_not_found().context("The calendar layout setting is not found")
+      })?;
+
+    let changeset = DateCellChangeset {
+      date: Some(timestamp.to_string()),
+      time: None,
+      include_time: None,
+      is_utc: true,
+      text: None,
+      locale: None,
+      recurrence: None,
+      clear_recurrence: false,
+    };
+    self
+      .update_cell_with_changeset(row_id, &date_field_id, changeset)
+      .await?;
+    view_editor
+      .v_get_calendar_event(row_id)
+      .await
+      .ok_or_else(FlowyError::record_not_found)
+  }
+
+  /// Returns the average of the progress cells of each group of the board. The groups are in
+  /// the order they are shown.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn get_group_progress(
+    &self,
+    view_id: &str,
+    field_id: &str,
+  ) -> FlowyResult<Vec<GroupProgressPB>> {
+    let field_rev = self
+      .get_field_rev(field_id)
+      .await
+      .ok_or_else(|| FlowyError::record_not_found().context("The progress field is not found"))?;
+    if !FieldType::from(field_rev.ty).is_progress() {
+      return Err(FlowyError::invalid_data().context("The field is not a progress field"));
+    }
+    let type_option = field_rev
+      .get_type_option::<ProgressTypeOptionPB>(field_rev.ty)
+      .unwrap_or_default();
+    let row_revs = self
+      .get_all_row_revs(view_id)
+      .await?
+      .into_iter()
+      .map(|row_rev| (row_rev.id.clone(), row_rev))
+      .collect::<HashMap<String, Arc<RowRevision>>>();
+
+    let groups = self.load_groups(view_id).await?.items;
+    let group_progress = groups
+      .into_iter()
+      .map(|group| {
+        let cells = group
+          .rows
+          .iter()
+          .flat_map(|row| row_revs.get(&row.id)?.cells.get(field_id))
+          .flat_map(|cell_rev| {
+            let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+            type_option
+              .decode_cell_str(
+                type_cell_data.cell_str,
+                &type_cell_data.field_type,
+                &field_rev,
+              )
+              .ok()
+          })
+          .collect::<Vec<ProgressCellData>>();
+        let (average, count) = average_progress(cells.iter());
+        GroupProgressPB {
+          group_id: group.group_id,
+          average,
+          count,
+        }
+      })
+      .collect();
+    Ok(group_progress)
+  }
+
+  /// Returns the time tracked by the rows of the view and by the rows of each group, including
+  /// the running timers.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn get_time_tracking_sum(
+    &self,
+    view_id: &str,
+    field_id: &str,
+  ) -> FlowyResult<TimeTrackingSumPB> {
+    let field_rev = self.get_field_rev(field_id).await.ok_or_else(|| {
+      FlowyError::record_not_found().context("The time tracking field is not found")
+    })?;
+    if !FieldType::from(field_rev.ty).is_time_tracking() {
+      return Err(FlowyError::invalid_data().context("The field is not a time tracking field"));
+    }
+    let type_option = field_rev
+      .get_type_option::<TimeTrackingTypeOptionPB>(field_rev.ty)
+      .unwrap_or_default();
+    let cells = self
+      .get_all_row_revs(view_id)
+      .await?
+      .into_iter()
+      .flat_map(|row_rev| {
+        let cell_rev = row_rev.cells.get(field_id)?;
+        let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+        let cell_data = type_option
+          .decode_cell_str(
+            type_cell_data.cell_str,
+            &type_cell_data.field_type,
+            &field_rev,
+          )
+          .ok()?;
+        Some((row_rev.id.clone(), cell_data))
+      })
+      .collect::<HashMap<String, TimeTrackingCellData>>();
+
+    let now = timestamp();
+    let groups = self
+      .load_groups(view_id)
+      .await?
+      .items
+      .into_iter()
+      .map(|group| {
+        let total = sum_time_tracking(group.rows.iter().flat_map(|row| cells.get(&row.id)), now);
+        GroupTimeTrackingSumPB {
+          group_id: group.group_id,
+          total,
+          formatted: format_clock(total, type_option.show_seconds),
+        }
+      })
+      .collect();
+    let total = sum_time_tracking(cells.values(), now);
+    Ok(TimeTrackingSumPB {
+      total,
+      formatted: format_clock(total, type_option.show_seconds),
+      groups,
+    })
+  }
+
+  /// Sets the due date of the item of the checklist cell, or removes it if the `due` is None.
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  pub async fn set_checklist_item_due(
+    &self,
+    changeset: ChecklistItemDueChangeset,
+  ) -> FlowyResult<()> {
+    self
+      .get_checklist_type_option(&changeset.cell_id.field_id)
+      .await?;
+    let cell_changeset = ChecklistCellChangeset::from_item_due(&changeset.item_id, changeset.due);
+    self
+      .update_cell_with_changeset(
+        &changeset.cell_id.row_id,
+        &changeset.cell_id.field_id,
+        cell_changeset,
+      )
+      .await
+  }
+
+  pub async fn get_checklist_reminders(
+    &self,
+    view_id: &str,
+  ) -> FlowyResult<Vec<ChecklistReminderPB>> {
+    let view_editor = self.database_views.get_view_editor(view_id).await?;
+    Ok(view_editor.v_get_checklist_reminders().await)
+  }
+
+  /// Returns the reminders of the checklist items in all the rows of the database.
+  pub async fn get_all_checklist_reminders(&self) -> FlowyResult<Vec<ChecklistReminderPB>> {
+    let field_revs = self.get_field_revs(None).await?;
+    let row_revs = self.database_blocks.get_row_revs().await?;
+    Ok(make_checklist_reminders(&field_revs, &row_revs))
+  }
+
+  async fn get_checklist_type_option(&self, field_id: &str) -> FlowyResult<ChecklistTypeOptionPB> {
+    let field_rev = self
+      .get_field_rev(field_id)
+      .await
+      .ok_or_else(|| FlowyError::record_not_found().context("The checklist field is not found"))?;
+    if !FieldType::from(field_rev.ty).is_check_list() {
+      return Err(FlowyError::invalid_data().context("The field is not a checklist field"));
+    }
+    Ok(
+      field_rev
+        .get_type_option::<ChecklistTypeOptionPB>(field_rev.ty)
+        .unwrap_or_default(),
+    )
+  }
+
+  /// Returns the items of the checklist cell and the percentage of the checked items.
+  pub async fn get_checklist_cell_data(
+    &self,
+    row_id: &str,
+    field_id: &str,
+  ) -> FlowyResult<ChecklistCellDataPB> {
+    let type_option = self.get_checklist_type_option(field_id).await?;
+    let cell_str = match self.get_cell_rev(row_id, field_id).await? {
+      None => "".to_owned(),
+      Some(cell_rev) => TypeCellData::try_from(cell_rev)?.cell_str,
+    };
+    let cell_data = type_option.decode_type_option_cell_str(cell_str)?;
+    Ok(cell_data.into())
+  }
+
+  /// Inserts, updates and deletes the items of the checklist cell. The items of the other rows
+  /// don't change.
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  pub async fn update_checklist_cell(
+    &self,
+    params: ChecklistCellChangesetParams,
+  ) -> FlowyResult<()> {
+    self
+      .get_checklist_type_option(&params.cell_id.field_id)
+      .await?;
+    let row_id = params.cell_id.row_id.clone();
+    let field_id = params.cell_id.field_id.clone();
+    let changeset = ChecklistCellChangeset::from(params);
+    self
+      .update_cell_with_changeset(&row_id, &field_id, changeset)
+      .await
+  }
+
+  /// Moves the item of the checklist cell to the position of another item of the cell.
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  pub async fn move_checklist_item(&self, params: MoveChecklistItemParams) -> FlowyResult<()> {
+    self
+      .get_checklist_type_option(&params.cell_id.field_id)
+      .await?;
+    if params.from_item_id == params.to_item_id {
+      return Ok(());
+    }
+    let changeset =
+      ChecklistCellChangeset::from_move_item(&params.from_item_id, &params.to_item_id);
+    self
+      .update_cell_with_changeset(&params.cell_id.row_id, &params.cell_id.field_id, changeset)
+      .await
+  }
+
   async fn create_row_rev(
     &self,
     cell_data_by_field_id: Option<HashMap<String, String>>,
@@ -1010,13 +3123,15 @@ impl DatabaseEditor {
     // insert empty row below the row whose id is upper_row_id
     let builder = match cell_data_by_field_id {
       None => RowRevisionBuilder::new(&block_id, field_revs),
-      Some(cell_data_by_field_id) => {
-        RowRevisionBuilder::new_with_data(&block_id, field_revs, cell_data_by_field_id)
-      },
+      Some(cell_data_by_field_id) => RowRevisionBuilder::new_with_data(
+        &block_id,
+        field_revs,
+        cell_data_by_field_id,
+        Some(self.cell_size_limits.clone()),
+      ),
     };
 
-    let row_rev = builder.build();
-    Ok(row_rev)
+    builder.try_build()
   }
 
   async fn create_row_pb(
@@ -1024,6 +3139,7 @@ impl DatabaseEditor {
     row_rev: RowRevision,
     start_row_id: Option<String>,
   ) -> FlowyResult<RowPB> {
+    self.check_row_limit(1).await?;
     let row_pb = RowPB::from(&row_rev);
     let block_id = row_rev.block_id.clone();
 
@@ -1039,6 +3155,267 @@ impl DatabaseEditor {
     Ok(row_pb)
   }
 
+  /// Fills the cells that are generated instead of typed, for the existing rows after the field
+  /// is created or switched to one of these fields. The cells of the new rows are set when the
+  /// rows are built or inserted.
+  /// * The cells of the created time fields and the last edited time fields are set to the
+  ///   created time and the modified time of their rows.
+  /// * The rows without a number get the next numbers of the auto number fields, in the order
+  ///   they were created.
+  async fn fill_generated_cells(&self, field_ids: &[String]) -> FlowyResult<()> {
+    let field_types = {
+      let pad = self.database_pad.read().await;
+      field_ids
+        .iter()
+        .flat_map(|field_id| {
+          let (_, field_rev) = pad.get_field_rev(field_id)?;
+          let field_type = FieldType::from(field_rev.ty);
+          if field_type.is_created_time()
+            || field_type.is_last_edited_time()
+            || field_type.is_auto_number()
+          {
+            Some((field_id.clone(), field_type))
+          } else {
+            None
+          }
+        })
+        .collect::<Vec<(String, FieldType)>>()
+    };
+    if field_types.is_empty() {
+      return Ok(());
+    }
+
+    let mut row_revs = self.database_blocks.get_row_revs().await?;
+    row_revs.sort_by_key(|row_rev| row_rev.created_at);
+    let mut changesets = row_revs
+      .iter()
+      .map(|row_rev| RowChangeset::new(row_rev.id.clone()))
+      .collect::<Vec<RowChangeset>>();
+    for (field_id, field_type) in field_types.iter() {
+      if field_type.is_auto_number() {
+        let unnumbered_changesets = row_revs
+          .iter()
+          .zip(changesets.iter_mut())
+          .filter(|(row_rev, _)| !has_auto_number(row_rev, field_id))
+          .map(|(_, changeset)| changeset)
+          .collect::<Vec<&mut RowChangeset>>();
+        let numbers = self
+          .next_auto_numbers(field_id, unnumbered_changesets.len())
+          .await?;
+        for (changeset, number) in unnumbered_changesets.into_iter().zip(numbers) {
+          changeset.cell_by_field_id.insert(
+            field_id.clone(),
+            AutoNumberTypeOptionPB::cell_rev_from_number(number),
+          );
+        }
+        continue;
+      }
+
+      for (row_rev, changeset) in row_revs.iter().zip(changesets.iter_mut()) {
+        let cell_rev = if field_type.is_created_time() {
+          CreatedTimeTypeOptionPB::cell_rev_from_timestamp(row_rev.created_at)
+        } else {
+          LastEditedTimeTypeOptionPB::cell_rev_from_timestamp(row_rev.modified_at)
+        };
+        changeset
+          .cell_by_field_id
+          .insert(field_id.clone(), cell_rev);
+      }
+    }
+
+    for changeset in changesets {
+      if !changeset.cell_by_field_id.is_empty() {
+        self.update_row(changeset).await?;
+      }
+    }
+    Ok(())
+  }
+
+  /// Numbers the rows that have no number yet, e.g. the rows of a database that was built from
+  /// a template, and the rows whose number was given to another row too. Two devices that add
+  /// rows offline reserve the same numbers, the numbers are reconciled once their revisions are
+  /// merged: the row that was created first keeps its number and the others get new ones. It's
+  /// called when the database is opened, nothing is changed if all the numbers are unique.
+  pub(crate) async fn reconcile_auto_number_cells(&self) -> FlowyResult<()> {
+    let field_ids = self.get_auto_number_field_ids().await;
+    if field_ids.is_empty() {
+      return Ok(());
+    }
+
+    let mut row_revs = self.database_blocks.get_row_revs().await?;
+    row_revs.sort_by(|left, right| (left.created_at, &left.id).cmp(&(right.created_at, &right.id)));
+    for field_id in field_ids {
+      let mut used_numbers = HashSet::new();
+      let unnumbered_row_ids = row_revs
+        .iter()
+        .filter(|row_rev| match get_auto_number(row_rev, &field_id) {
+          None => true,
+          Some(number) => !used_numbers.insert(number),
+        })
+        .map(|row_rev| row_rev.id.clone())
+        .collect::<Vec<String>>();
+      let max_number = used_numbers.into_iter().max().unwrap_or(0);
+      let next_number = self
+        .get_field_rev(&field_id)
+        .await
+        .and_then(|field_rev| field_rev.get_type_option::<AutoNumberTypeOptionPB>(field_rev.ty))
+        .map(|type_option| type_option.next_number)
+        .unwrap_or(1);
+      if unnumbered_row_ids.is_empty() && next_number > max_number {
+        continue;
+      }
+
+      // The numbers of the merged rows might be ahead of the number of the type option
+      let numbers = self
+        .reserve_auto_numbers(&field_id, max_number + 1, unnumbered_row_ids.len())
+        .await?;
+      for (row_id, number) in unnumbered_row_ids.into_iter().zip(numbers) {
+        let mut changeset = RowChangeset::new(row_id);
+        changeset.cell_by_field_id.insert(
+          field_id.clone(),
+          AutoNumberTypeOptionPB::cell_rev_from_number(number),
+        );
+        self.update_row(changeset).await?;
+      }
+    }
+    Ok(())
+  }
+
+  /// Gives the new rows the next numbers of the auto number fields. The cells that were copied
+  /// from other rows, e.g. by the duplication or the import, are replaced, so the numbers are
+  /// never used twice.
+  async fn assign_auto_numbers(&self, row_revs: &mut [RowRevision]) -> FlowyResult<()> {
+    for field_id in self.get_auto_number_field_ids().await {
+      let numbers = self.next_auto_numbers(&field_id, row_revs.len()).await?;
+      for (row_rev, number) in row_revs.iter_mut().zip(numbers) {
+        row_rev.cells.insert(
+          field_id.clone(),
+          AutoNumberTypeOptionPB::cell_rev_from_number(number),
+        );
+      }
+    }
+    Ok(())
+  }
+
+  /// Reserves the next `count` numbers of the auto number field. The number after them is saved
+  /// in the type option, so the numbers are not given again even if their rows are deleted.
+  async fn next_auto_numbers(&self, field_id: &str, count: usize) -> FlowyResult<Range<i64>> {
+    self.reserve_auto_numbers(field_id, 1, count).await
+  }
+
+  /// Same as [DatabaseEditor::next_auto_numbers], but the numbers start at `min_number` at least.
+  async fn reserve_auto_numbers(
+    &self,
+    field_id: &str,
+    min_number: i64,
+    count: usize,
+  ) -> FlowyResult<Range<i64>> {
+    let mut numbers = 0..0;
+    if count == 0 {
+      return Ok(numbers);
+    }
+
+    self
+      .modify(|pad| {
+        Ok(pad.modify_field(field_id, |field_rev| {
+          let mut type_option = field_rev
+            .get_type_option::<AutoNumberTypeOptionPB>(field_rev.ty)
+            .unwrap_or_default();
+          let start = type_option.next_number.max(min_number);
+          numbers = start..start + count as i64;
+          type_option.next_number = numbers.end;
+          field_rev.insert_type_option(&type_option);
+          Ok(Some(()))
+        })?)
+      })
+      .await?;
+    Ok(numbers)
+  }
+
+  /// Stores the timezone of the device in the date fields that have no timezone, i.e. the fields
+  /// of the older versions, so their dates keep being displayed the same when the database is
+  /// opened on a device in another timezone. The older versions didn't record who created the
+  /// fields, so the timezone of the first user who opens the database is taken. The
+  /// [DatabaseManager] runs it once per database, the fields created since then are pinned to
+  /// the timezone of their creator by [pin_creator_timezone].
+  pub(crate) async fn migrate_date_field_timezones(&self) -> FlowyResult<()> {
+    let field_ids = self
+      .database_pad
+      .read()
+      .await
+      .get_fields()
+      .iter()
+      .filter(|field_rev| pin_creator_timezone(&mut field_rev.as_ref().clone()))
+      .map(|field_rev| field_rev.id.clone())
+      .collect::<Vec<String>>();
+
+    for field_id in field_ids {
+      self
+        .modify(|pad| {
+          Ok(pad.modify_field(&field_id, |field_rev| {
+            Ok(pin_creator_timezone(field_rev).then_some(()))
+          })?)
+        })
+        .await?;
+      self.notify_did_update_database_field(&field_id).await?;
+    }
+    Ok(())
+  }
+
+  /// Inserts the signed-in user into the members of the person fields, so the user can be
+  /// assigned to their cells. See [insert_current_member].
+  pub(crate) async fn insert_current_member(&self) -> FlowyResult<()> {
+    let field_ids = self
+      .database_pad
+      .read()
+      .await
+      .get_fields()
+      .iter()
+      .filter(|field_rev| insert_current_member(&mut field_rev.as_ref().clone()))
+      .map(|field_rev| field_rev.id.clone())
+      .collect::<Vec<String>>();
+
+    for field_id in field_ids {
+      self.insert_current_member_into_field(&field_id).await?;
+      self.notify_did_update_database_field(&field_id).await?;
+    }
+    Ok(())
+  }
+
+  async fn insert_current_member_into_field(&self, field_id: &str) -> FlowyResult<()> {
+    self
+      .modify(|pad| {
+        Ok(pad.modify_field(field_id, |field_rev| {
+          Ok(insert_current_member(field_rev).then_some(()))
+        })?)
+      })
+      .await
+  }
+
+  async fn get_auto_number_field_ids(&self) -> Vec<String> {
+    self
+      .database_pad
+      .read()
+      .await
+      .get_fields()
+      .iter()
+      .filter(|field_rev| FieldType::from(field_rev.ty).is_auto_number())
+      .map(|field_rev| field_rev.id.clone())
+      .collect()
+  }
+
+  pub(crate) async fn check_row_limit(&self, num_of_new_rows: usize) -> FlowyResult<()> {
+    let num_of_rows = self
+      .database_pad
+      .read()
+      .await
+      .get_block_meta_revs()
+      .iter()
+      .map(|block| block.len() as usize)
+      .sum::<usize>();
+    self.row_limit.check(num_of_rows, num_of_new_rows)
+  }
+
   async fn modify<F>(&self, f: F) -> FlowyResult<()>
   where
     F:
@@ -1061,7 +3438,7 @@ impl DatabaseEditor {
     Ok(())
   }
 
-  async fn block_id(&self) -> FlowyResult<String> {
+  pub(crate) async fn block_id(&self) -> FlowyResult<String> {
     match self.database_pad.read().await.get_block_meta_revs().last() {
       None => Err(FlowyError::internal().context("There is no block in this database")),
       Some(database_block) => Ok(database_block.block_id.clone()),
@@ -1070,14 +3447,33 @@ impl DatabaseEditor {
 
   #[tracing::instrument(level = "trace", skip_all, err)]
   async fn notify_did_insert_database_field(&self, field_id: &str) -> FlowyResult<()> {
-    if let Some((index, field_rev)) = self.database_pad.read().await.get_field_rev(field_id) {
-      let index_field = IndexFieldPB::from_field_rev(field_rev, index);
-      if let Ok(views) = self.database_ref_query.get_ref_views(&self.database_id) {
-        for view in views {
-          let notified_changeset =
-            DatabaseFieldChangesetPB::insert(&view.view_id, vec![index_field.clone()]);
-          self.notify_did_update_database(notified_changeset).await?;
-        }
+    self
+      .notify_did_insert_database_fields(&[field_id.to_owned()])
+      .await
+  }
+
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  async fn notify_did_insert_database_fields(&self, field_ids: &[String]) -> FlowyResult<()> {
+    let index_fields = {
+      let pad = self.database_pad.read().await;
+      field_ids
+        .iter()
+        .flat_map(|field_id| pad.get_field_rev(field_id))
+        .map(|(index, field_rev)| IndexFieldPB::from_field_rev(field_rev, index))
+        .collect::<Vec<IndexFieldPB>>()
+    };
+    if index_fields.is_empty() {
+      return Ok(());
+    }
+    for index_field in index_fields.iter() {
+      self.send_field_change(DatabaseChangeTypePB::FieldCreated, &index_field.field.id);
+    }
+
+    if let Ok(views) = self.database_ref_query.get_ref_views(&self.database_id) {
+      for view in views {
+        let notified_changeset =
+          DatabaseFieldChangesetPB::insert(&view.view_id, index_fields.clone());
+        self.notify_did_update_database(notified_changeset).await?;
       }
     }
     Ok(())
@@ -1092,6 +3488,10 @@ impl DatabaseEditor {
       .get_field_rev(field_id)
       .map(|(index, field)| (index, field.clone()))
     {
+      // The field may be changed from or to a text field
+      self.row_mentions.clear();
+      // The field may be changed from or to a relation field, or link another database
+      self.relation_links.clear();
       let updated_field = FieldPB::from(field_rev);
       let notified_changeset =
         DatabaseFieldChangesetPB::update(&self.database_id, vec![updated_field.clone()]);
@@ -1100,11 +3500,19 @@ impl DatabaseEditor {
       send_notification(field_id, DatabaseNotification::DidUpdateField)
         .payload(updated_field)
         .send();
+      self.send_field_change(DatabaseChangeTypePB::FieldUpdated, field_id);
     }
 
     Ok(())
   }
 
+  fn send_field_change(&self, ty: DatabaseChangeTypePB, field_id: &str) {
+    // The sending fails if there is no consumer, which is fine
+    let _ = self
+      .change_tx
+      .send(DatabaseChangePB::field(&self.database_id, ty, field_id));
+  }
+
   async fn notify_did_update_database(
     &self,
     changeset: DatabaseFieldChangesetPB,
@@ -1121,6 +3529,101 @@ impl DatabaseEditor {
   }
 }
 
+/// Sends the notification to reload the cell, so the UI renders its conflicted state
+fn notify_did_update_cell_conflict(row_id: &str, field_id: &str) {
+  let id = format!("{}:{}", row_id, field_id);
+  send_notification(&id, DatabaseNotification::DidUpdateCell).send();
+}
+
+/// Returns the text of the cell if it's a text cell
+fn get_text_cell_str(row_rev: &RowRevision, field_id: &str) -> Option<String> {
+  let type_cell_data = TypeCellData::try_from(row_rev.cells.get(field_id)?).ok()?;
+  if type_cell_data.field_type.is_text() {
+    Some(type_cell_data.cell_str)
+  } else {
+    None
+  }
+}
+
+fn get_row_mentions(
+  database_id: &str,
+  row_rev: &RowRevision,
+  text_field_ids: &[String],
+) -> Vec<RowMention> {
+  text_field_ids
+    .iter()
+    .flat_map(|field_id| {
+      let text = get_text_cell_str(row_rev, field_id).unwrap_or_default();
+      parse_text_mentions(&text)
+        .into_iter()
+        .map(move |mention| RowMention {
+          database_id: database_id.to_owned(),
+          field_id: field_id.clone(),
+          row_id: row_rev.id.clone(),
+          mentioned_id: mention.id,
+        })
+    })
+    .collect()
+}
+
+/// Returns the rows that the relation cells of the row link, the `relation_fields` are the ids
+/// of the relation fields along with the ids of the databases they link.
+fn get_row_relation_links(
+  database_id: &str,
+  row_rev: &RowRevision,
+  relation_fields: &[(String, String)],
+) -> Vec<RelationLink> {
+  relation_fields
+    .iter()
+    .flat_map(|(field_id, linked_database_id)| {
+      row_rev
+        .cells
+        .get(field_id)
+        .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+        .filter(|type_cell_data| type_cell_data.is_relation())
+        .map(|type_cell_data| RelationCellData::from(type_cell_data.cell_str).row_ids)
+        .unwrap_or_default()
+        .into_iter()
+        .map(move |linked_row_id| RelationLink {
+          database_id: database_id.to_owned(),
+          field_id: field_id.clone(),
+          row_id: row_rev.id.clone(),
+          linked_database_id: linked_database_id.clone(),
+          linked_row_id,
+        })
+    })
+    .collect()
+}
+
+fn make_row_share_pb(share: RowShare) -> RowSharePB {
+  RowSharePB {
+    link: format!(
+      "{}/{}/{}",
+      ROW_SHARE_LINK_PREFIX, share.view_id, share.share_id
+    ),
+    share_id: share.share_id,
+    row_id: share.row_id,
+    created_at: share.created_at,
+  }
+}
+
+fn stringify_primary_cell(primary_field_rev: &FieldRevision, row_rev: &RowRevision) -> String {
+  row_rev
+    .cells
+    .get(&primary_field_rev.id)
+    .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+    .map(|type_cell_data| {
+      let field_type: FieldType = primary_field_rev.ty.into();
+      stringify_cell_data(
+        type_cell_data.cell_str,
+        &type_cell_data.field_type,
+        &field_type,
+        primary_field_rev,
+      )
+    })
+    .unwrap_or_default()
+}
+
 #[cfg(feature = "flowy_unit_test")]
 impl DatabaseEditor {
   pub fn rev_manager(&self) -> Arc<RevisionManager<Arc<ConnectionPool>>> {
@@ -1195,3 +3698,23 @@ impl JsonDeserializer for TypeOptionJsonDeserializer {
     Ok(json)
   }
 }
+
+fn has_auto_number(row_rev: &RowRevision, field_id: &str) -> bool {
+  row_rev
+    .cells
+    .get(field_id)
+    .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+    .map(|type_cell_data| type_cell_data.is_auto_number())
+    .unwrap_or(false)
+}
+
+fn get_auto_number(row_rev: &RowRevision, field_id: &str) -> Option<i64> {
+  let type_cell_data = row_rev
+    .cells
+    .get(field_id)
+    .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+    .filter(|type_cell_data| type_cell_data.is_auto_number())?;
+  AutoNumberCellData::from_cell_str(&type_cell_data.cell_str)
+    .ok()?
+    .0
+}
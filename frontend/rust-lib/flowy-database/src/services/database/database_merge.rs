@@ -0,0 +1,298 @@
+use crate::entities::{DatabaseMergeConflictKindPB, DatabaseMergeConflictPB, FieldType};
+use crate::services::cell::{insert_checklist_cell, TypeCellData};
+use crate::services::database::normalize_field_name;
+use crate::services::field::{
+  ChecklistTypeOptionPB, RelationCellData, SelectOptionIds, SelectOptionPB, TypeOptionCellData,
+};
+use database_model::{CellRevision, FieldRevision, RowRevision};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A field of the source database and the field of the target database that its cells are
+/// merged into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergedField {
+  pub source_field_id: String,
+  pub target_field_id: String,
+  pub field_type: FieldType,
+}
+
+/// Matches each field of the source database with a field of the target database that has the
+/// same name and type. The names are compared like [is_same_database_schema] does. The source
+/// fields that don't match any field are returned as the conflicts.
+pub fn match_merged_fields(
+  source_field_revs: &[Arc<FieldRevision>],
+  target_field_revs: &[Arc<FieldRevision>],
+) -> (Vec<MergedField>, Vec<DatabaseMergeConflictPB>) {
+  let mut unmatched_field_revs = target_field_revs.iter().collect::<Vec<_>>();
+  let mut merged_fields = vec![];
+  let mut conflicts = vec![];
+  for source_field_rev in source_field_revs {
+    let name = normalize_field_name(&source_field_rev.name);
+    match unmatched_field_revs.iter().position(|field_rev| {
+      field_rev.ty == source_field_rev.ty && normalize_field_name(&field_rev.name) == name
+    }) {
+      Some(position) => {
+        let target_field_rev = unmatched_field_revs.remove(position);
+        merged_fields.push(MergedField {
+          source_field_id: source_field_rev.id.clone(),
+          target_field_id: target_field_rev.id.clone(),
+          field_type: source_field_rev.ty.into(),
+        });
+      },
+      None => {
+        let kind = if target_field_revs
+          .iter()
+          .any(|field_rev| normalize_field_name(&field_rev.name) == name)
+        {
+          DatabaseMergeConflictKindPB::FieldTypeMismatch
+        } else {
+          DatabaseMergeConflictKindPB::MissingField
+        };
+        conflicts.push(DatabaseMergeConflictPB {
+          field_id: source_field_rev.id.clone(),
+          field_name: source_field_rev.name.clone(),
+          kind,
+        });
+      },
+    }
+  }
+  (merged_fields, conflicts)
+}
+
+/// Matches the `source_options` with the `target_options` by name, ignoring the case and the
+/// surrounding whitespaces. Returns the ids of the target options by the ids of the source
+/// options, and the options that need to be added to the target field for the source options
+/// that don't match any option.
+pub fn union_select_options(
+  source_options: &[SelectOptionPB],
+  target_options: &[SelectOptionPB],
+) -> (HashMap<String, String>, Vec<SelectOptionPB>) {
+  let option_name = |option: &SelectOptionPB| option.name.trim().to_lowercase();
+  let mut option_ids = HashMap::new();
+  let mut new_options: Vec<SelectOptionPB> = vec![];
+  for source_option in source_options {
+    let name = option_name(source_option);
+    let target_option = target_options
+      .iter()
+      .chain(new_options.iter())
+      .find(|option| option_name(option) == name);
+    let target_option_id = match target_option {
+      Some(target_option) => target_option.id.clone(),
+      None => {
+        let new_option =
+          SelectOptionPB::with_color(source_option.name.trim(), source_option.color.clone());
+        let option_id = new_option.id.clone();
+        new_options.push(new_option);
+        option_id
+      },
+    };
+    option_ids.insert(source_option.id.clone(), target_option_id);
+  }
+  (option_ids, new_options)
+}
+
+/// Returns a copy of the source row whose checklist cells store their items. The cells that
+/// were not edited, or that were saved by the older versions, get their items from the type
+/// option of the source field, which isn't copied to the target field. The `type_options` are
+/// keyed by the ids of the source fields.
+pub fn fill_checklist_items(
+  source_row_rev: &RowRevision,
+  type_options: &HashMap<String, ChecklistTypeOptionPB>,
+) -> RowRevision {
+  let mut row_rev = source_row_rev.clone();
+  for (field_id, type_option) in type_options {
+    let cell_str = row_rev
+      .cells
+      .get(field_id)
+      .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+      .filter(|type_cell_data| type_cell_data.is_checklist())
+      .map(|type_cell_data| type_cell_data.cell_str)
+      .unwrap_or_default();
+    if let Ok(cell_data) = type_option.decode_type_option_cell_str(cell_str) {
+      row_rev
+        .cells
+        .insert(field_id.clone(), insert_checklist_cell(cell_data));
+    }
+  }
+  row_rev
+}
+
+/// Copies the cells of the merged fields of the source row into a new row of the target
+/// database. The option ids of the select cells are replaced by the ids in the
+/// `option_ids_by_field_id`, which is keyed by the ids of the target fields.
+pub fn make_merged_row_rev(
+  source_row_rev: &RowRevision,
+  block_id: &str,
+  merged_fields: &[MergedField],
+  option_ids_by_field_id: &HashMap<String, HashMap<String, String>>,
+) -> RowRevision {
+  let mut row_rev = RowRevision::new(block_id);
+  row_rev.height = source_row_rev.height;
+  row_rev.visibility = source_row_rev.visibility;
+  row_rev.created_at = source_row_rev.created_at;
+  row_rev.modified_at = source_row_rev.modified_at;
+  for merged_field in merged_fields {
+    let cell_rev = match source_row_rev.cells.get(&merged_field.source_field_id) {
+      None => continue,
+      Some(cell_rev) => cell_rev,
+    };
+    let cell_rev = match option_ids_by_field_id.get(&merged_field.target_field_id) {
+      None => cell_rev.clone(),
+      Some(option_ids) => {
+        let cell_str = match TypeCellData::try_from(cell_rev) {
+          Ok(type_cell_data) => type_cell_data.cell_str,
+          Err(_) => continue,
+        };
+        // The ids of the deleted options are dropped
+        let select_option_ids = SelectOptionIds::from(cell_str)
+          .into_inner()
+          .into_iter()
+          .filter_map(|option_id| option_ids.get(&option_id).cloned())
+          .collect::<Vec<String>>();
+        let type_cell_data = TypeCellData::new(
+          SelectOptionIds::from(select_option_ids).to_string(),
+          merged_field.field_type.clone(),
+        );
+        CellRevision::new(type_cell_data.to_json())
+      },
+    };
+    row_rev
+      .cells
+      .insert(merged_field.target_field_id.clone(), cell_rev);
+  }
+  row_rev
+}
+
+/// Replaces the links of the relation cells of a merged row. The links to the merged source
+/// rows are replaced by the ids of their copies in the `row_ids`, which is keyed by the ids of
+/// the source rows. The other links are kept if the target field links the same database as the
+/// source field, otherwise they are dropped. The `keeps_links_by_field_id` is keyed by the ids
+/// of the target relation fields.
+pub fn remap_relation_cells(
+  row_rev: &mut RowRevision,
+  keeps_links_by_field_id: &HashMap<String, bool>,
+  row_ids: &HashMap<String, String>,
+) {
+  for (field_id, keeps_links) in keeps_links_by_field_id {
+    let cell_str = match row_rev
+      .cells
+      .get(field_id)
+      .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+    {
+      None => continue,
+      Some(type_cell_data) => type_cell_data.cell_str,
+    };
+    let linked_row_ids = RelationCellData::from(cell_str)
+      .row_ids
+      .into_iter()
+      .filter_map(|row_id| match row_ids.get(&row_id) {
+        Some(new_row_id) => Some(new_row_id.clone()),
+        None if *keeps_links => Some(row_id),
+        None => None,
+      })
+      .collect::<Vec<String>>();
+    let type_cell_data = TypeCellData::new(
+      RelationCellData::from(linked_row_ids).to_string(),
+      FieldType::Relation,
+    );
+    row_rev.cells.insert(
+      field_id.clone(),
+      CellRevision::new(type_cell_data.to_json()),
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::services::field::FieldBuilder;
+
+  fn field_rev(name: &str, field_type: FieldType) -> Arc<FieldRevision> {
+    Arc::new(
+      FieldBuilder::from_field_type(&field_type)
+        .name(name)
+        .build(),
+    )
+  }
+
+  #[test]
+  fn match_merged_fields_test() {
+    let source_field_revs = vec![
+      field_rev("Name", FieldType::RichText),
+      field_rev("Status", FieldType::SingleSelect),
+      field_rev("Done", FieldType::Checkbox),
+      field_rev("Notes", FieldType::RichText),
+    ];
+    let target_field_revs = vec![
+      field_rev("status ", FieldType::SingleSelect),
+      field_rev("NAME", FieldType::RichText),
+      field_rev("Done", FieldType::Number),
+    ];
+    let (merged_fields, conflicts) = match_merged_fields(&source_field_revs, &target_field_revs);
+    assert_eq!(merged_fields.len(), 2);
+    assert_eq!(merged_fields[0].target_field_id, target_field_revs[1].id);
+    assert_eq!(merged_fields[1].target_field_id, target_field_revs[0].id);
+
+    assert_eq!(conflicts.len(), 2);
+    assert_eq!(conflicts[0].field_name, "Done");
+    assert_eq!(
+      conflicts[0].kind,
+      DatabaseMergeConflictKindPB::FieldTypeMismatch
+    );
+    assert_eq!(conflicts[1].field_name, "Notes");
+    assert_eq!(conflicts[1].kind, DatabaseMergeConflictKindPB::MissingField);
+  }
+
+  #[test]
+  fn union_select_options_test() {
+    let source_options = vec![
+      SelectOptionPB::new("Done"),
+      SelectOptionPB::new("Blocked"),
+      SelectOptionPB::new("blocked"),
+    ];
+    let target_options = vec![SelectOptionPB::new(" done"), SelectOptionPB::new("Todo")];
+    let (option_ids, new_options) = union_select_options(&source_options, &target_options);
+    assert_eq!(new_options.len(), 1);
+    assert_eq!(new_options[0].name, "Blocked");
+    assert_eq!(option_ids[&source_options[0].id], target_options[0].id);
+    assert_eq!(option_ids[&source_options[1].id], new_options[0].id);
+    assert_eq!(option_ids[&source_options[2].id], new_options[0].id);
+  }
+
+  #[test]
+  fn remap_relation_cells_test() {
+    let relation_cell = |row_ids: Vec<&str>| {
+      let row_ids = row_ids
+        .into_iter()
+        .map(|row_id| row_id.to_owned())
+        .collect();
+      let type_cell_data = TypeCellData::new(
+        RelationCellData::from(row_ids).to_string(),
+        FieldType::Relation,
+      );
+      CellRevision::new(type_cell_data.to_json())
+    };
+    let linked_row_ids = |row_rev: &RowRevision, field_id: &str| {
+      let type_cell_data = TypeCellData::try_from(&row_rev.cells[field_id]).unwrap();
+      RelationCellData::from(type_cell_data.cell_str).row_ids
+    };
+
+    let mut row_rev = RowRevision::new("block");
+    row_rev
+      .cells
+      .insert("same".to_owned(), relation_cell(vec!["a", "x"]));
+    row_rev
+      .cells
+      .insert("other".to_owned(), relation_cell(vec!["a", "x"]));
+    let keeps_links_by_field_id =
+      HashMap::from([("same".to_owned(), true), ("other".to_owned(), false)]);
+    let row_ids = HashMap::from([("a".to_owned(), "b".to_owned())]);
+    remap_relation_cells(&mut row_rev, &keeps_links_by_field_id, &row_ids);
+
+    // The merged rows are linked by their copies, the other links are dropped if the target
+    // field links another database
+    assert_eq!(linked_row_ids(&row_rev, "same"), vec!["b", "x"]);
+    assert_eq!(linked_row_ids(&row_rev, "other"), vec!["b"]);
+  }
+}
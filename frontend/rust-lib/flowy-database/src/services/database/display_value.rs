@@ -0,0 +1,122 @@
+use crate::services::persistence::row_display_value::{RowDisplayValue, RowDisplayValueStorage};
+use database_model::{FieldRevision, RowRevision};
+use flowy_error::FlowyResult;
+use parking_lot::RwLock;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// Caches the display string of each row's primary cell, so the name of the row can be shown
+/// without decoding its cells again.
+///
+/// The display values are saved in the [RowDisplayValueStorage], so they survive the restarts of
+/// the app, and the ones that were read are kept in memory. Each display value is saved with the
+/// hash of the primary cell and the primary field it was computed from. A display value is only
+/// returned if the hash of the current cell matches, so a display value that was computed from an
+/// old revision of the row is never returned, even if it was written after the row changed.
+pub(crate) struct RowDisplayValueCache {
+  database_id: String,
+  display_values: RwLock<HashMap<String, RowDisplayValue>>,
+  storage: Arc<RowDisplayValueStorage>,
+}
+
+impl RowDisplayValueCache {
+  pub(crate) fn new(database_id: &str, storage: Arc<RowDisplayValueStorage>) -> Self {
+    Self {
+      database_id: database_id.to_owned(),
+      display_values: RwLock::new(HashMap::new()),
+      storage,
+    }
+  }
+
+  /// Returns the cached display values of the rows whose primary cell hasn't changed since their
+  /// display value was computed, keyed by the row id. The `source_hashes` are the row ids with
+  /// the [source_hash] of their current primary cell.
+  pub(crate) fn get(&self, source_hashes: &[(String, u64)]) -> HashMap<String, String> {
+    let missing_row_ids = {
+      let display_values = self.display_values.read();
+      source_hashes
+        .iter()
+        .filter(|(row_id, _)| !display_values.contains_key(row_id))
+        .map(|(row_id, _)| row_id.clone())
+        .collect::<Vec<_>>()
+    };
+    if !missing_row_ids.is_empty() {
+      match self.storage.get_display_values(&missing_row_ids) {
+        Ok(saved_display_values) => {
+          let mut display_values = self.display_values.write();
+          for saved_display_value in saved_display_values {
+            display_values
+              .entry(saved_display_value.row_id.clone())
+              .or_insert(saved_display_value);
+          }
+        },
+        Err(err) => tracing::error!("Read the display values of the rows failed: {:?}", err),
+      }
+    }
+
+    let display_values = self.display_values.read();
+    source_hashes
+      .iter()
+      .filter_map(|(row_id, source_hash)| {
+        display_values
+          .get(row_id)
+          .filter(|display_value| display_value.source_hash == *source_hash)
+          .map(|display_value| (row_id.clone(), display_value.display_value.clone()))
+      })
+      .collect()
+  }
+
+  pub(crate) fn insert(&self, new_display_values: Vec<RowDisplayValue>) -> FlowyResult<()> {
+    if new_display_values.is_empty() {
+      return Ok(());
+    }
+    {
+      let mut display_values = self.display_values.write();
+      for display_value in new_display_values.iter() {
+        display_values.insert(display_value.row_id.clone(), display_value.clone());
+      }
+    }
+    self
+      .storage
+      .save_display_values(&self.database_id, new_display_values)
+  }
+
+  /// Removes the display values from memory, they will be read from the
+  /// [RowDisplayValueStorage] again.
+  pub(crate) fn clear(&self) {
+    self.display_values.write().clear();
+  }
+
+  /// Returns the estimated bytes of the display values in memory.
+  pub(crate) fn estimated_bytes(&self) -> usize {
+    self
+      .display_values
+      .read()
+      .iter()
+      .map(|(row_id, display_value)| {
+        std::mem::size_of::<String>()
+          + std::mem::size_of::<RowDisplayValue>()
+          + row_id.capacity()
+          + display_value.row_id.capacity()
+          + display_value.display_value.capacity()
+      })
+      .sum()
+  }
+}
+
+/// Returns the hash of everything the display value of the row's primary cell is computed from:
+/// the primary field's type and type option, and the data of the cell.
+pub(crate) fn source_hash(primary_field_rev: &FieldRevision, row_rev: &RowRevision) -> u64 {
+  let mut hasher = DefaultHasher::new();
+  hasher.write(primary_field_rev.id.as_bytes());
+  hasher.write_u8(primary_field_rev.ty);
+  if let Some(type_option_str) = primary_field_rev.get_type_option_str(primary_field_rev.ty) {
+    type_option_str.hash(&mut hasher);
+  }
+  if let Some(cell_rev) = row_rev.cells.get(&primary_field_rev.id) {
+    cell_rev.type_cell_data.hash(&mut hasher);
+  }
+  hasher.finish()
+}
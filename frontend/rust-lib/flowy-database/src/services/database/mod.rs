@@ -1,10 +1,22 @@
 mod block_editor;
 mod block_manager;
+mod change_notifier;
 mod database_editor;
+mod database_merge;
+mod display_value;
 mod retry;
+mod row_limit;
+mod schema_compare;
+mod schema_export;
+mod stale_rows;
 mod trait_impl;
 
 pub use block_editor::*;
 pub use block_manager::*;
+pub use change_notifier::*;
 pub use database_editor::*;
+pub use database_merge::*;
+pub use row_limit::*;
+pub use schema_compare::*;
+pub use schema_export::*;
 pub use trait_impl::*;
@@ -0,0 +1,59 @@
+use flowy_error::{FlowyError, FlowyResult};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// The max number of rows of each database. It's shared by all the database editors, so
+/// updating the limit, e.g. after reading the quota of the workspace, applies to the opened
+/// databases too. Zero means the number of rows is not limited.
+#[derive(Debug, Clone, Default)]
+pub struct RowLimit(Arc<AtomicUsize>);
+
+impl RowLimit {
+  pub fn new(max_rows: usize) -> Self {
+    Self(Arc::new(AtomicUsize::new(max_rows)))
+  }
+
+  pub fn set(&self, max_rows: usize) {
+    self.0.store(max_rows, Ordering::SeqCst);
+  }
+
+  pub fn get(&self) -> usize {
+    self.0.load(Ordering::SeqCst)
+  }
+
+  /// Returns [FlowyError::row_limit_exceeded] if inserting `num_of_new_rows` rows into the
+  /// database that has `num_of_rows` rows exceeds the limit.
+  pub fn check(&self, num_of_rows: usize, num_of_new_rows: usize) -> FlowyResult<()> {
+    let max_rows = self.get();
+    if max_rows == 0 || num_of_rows + num_of_new_rows <= max_rows {
+      return Ok(());
+    }
+
+    let msg = format!(
+      "The database should not have more than {} rows, it has {} rows",
+      max_rows, num_of_rows
+    );
+    Err(FlowyError::row_limit_exceeded().context(msg))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::services::database::RowLimit;
+  use flowy_error::ErrorCode;
+
+  #[test]
+  fn row_limit_test() {
+    let limit = RowLimit::default();
+    assert!(limit.check(10000, 1).is_ok());
+
+    limit.set(3);
+    assert!(limit.check(2, 1).is_ok());
+    let err = limit.check(2, 2).unwrap_err();
+    assert_eq!(err.code, ErrorCode::RowLimitExceeded.value());
+
+    let cloned_limit = limit.clone();
+    cloned_limit.set(0);
+    assert!(limit.check(2, 2).is_ok());
+  }
+}
@@ -0,0 +1,125 @@
+use crate::entities::{FieldSchemaPB, FieldType};
+use database_model::FieldRevision;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Returns true if the database has the same fields as the `fields`, regardless of their order.
+/// The names are compared without the case and the surrounding whitespaces. The fields without
+/// a type match the field of any type with the same name.
+pub fn is_same_database_schema(
+  fields: &[FieldSchemaPB],
+  field_revs: &[Arc<FieldRevision>],
+) -> bool {
+  if fields.is_empty() || fields.len() != field_revs.len() {
+    return false;
+  }
+
+  let mut field_types_by_name: HashMap<String, Vec<FieldType>> = HashMap::new();
+  for field_rev in field_revs {
+    field_types_by_name
+      .entry(normalize_field_name(&field_rev.name))
+      .or_default()
+      .push(field_rev.ty.into());
+  }
+
+  // The typed fields are matched first, otherwise a field without a type might take the field
+  // that only a typed one matches.
+  let (typed_fields, untyped_fields): (Vec<&FieldSchemaPB>, Vec<&FieldSchemaPB>) =
+    fields.iter().partition(|field| field.field_type.is_some());
+  for field in typed_fields.into_iter().chain(untyped_fields) {
+    let field_types = match field_types_by_name.get_mut(&normalize_field_name(&field.name)) {
+      None => return false,
+      Some(field_types) => field_types,
+    };
+    let position = match &field.field_type {
+      None => (!field_types.is_empty()).then_some(0),
+      Some(field_type) => field_types.iter().position(|other| other == field_type),
+    };
+    match position {
+      None => return false,
+      Some(position) => {
+        field_types.remove(position);
+      },
+    }
+  }
+  true
+}
+
+pub(crate) fn normalize_field_name(name: &str) -> String {
+  name.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::services::field::FieldBuilder;
+
+  fn field_rev(name: &str, field_type: FieldType) -> Arc<FieldRevision> {
+    Arc::new(
+      FieldBuilder::from_field_type(&field_type)
+        .name(name)
+        .build(),
+    )
+  }
+
+  fn field(name: &str, field_type: Option<FieldType>) -> FieldSchemaPB {
+    FieldSchemaPB {
+      name: name.to_owned(),
+      field_type,
+    }
+  }
+
+  #[test]
+  fn same_database_schema_test() {
+    let field_revs = vec![
+      field_rev("Name", FieldType::RichText),
+      field_rev("Status", FieldType::SingleSelect),
+      field_rev("Done", FieldType::Checkbox),
+    ];
+    let fields = field_revs
+      .iter()
+      .rev()
+      .map(|field_rev| FieldSchemaPB::from(field_rev.as_ref()))
+      .collect::<Vec<FieldSchemaPB>>();
+    assert!(is_same_database_schema(&fields, &field_revs));
+
+    let fields = vec![
+      field(" name", Some(FieldType::RichText)),
+      field("STATUS", Some(FieldType::SingleSelect)),
+      field("Done", Some(FieldType::Checkbox)),
+    ];
+    assert!(is_same_database_schema(&fields, &field_revs));
+
+    // Different type
+    let fields = vec![
+      field("Name", Some(FieldType::RichText)),
+      field("Status", Some(FieldType::MultiSelect)),
+      field("Done", Some(FieldType::Checkbox)),
+    ];
+    assert!(!is_same_database_schema(&fields, &field_revs));
+
+    // Missing field
+    let fields = vec![
+      field("Name", Some(FieldType::RichText)),
+      field("Status", Some(FieldType::SingleSelect)),
+    ];
+    assert!(!is_same_database_schema(&fields, &field_revs));
+    assert!(!is_same_database_schema(&[], &[]));
+  }
+
+  #[test]
+  fn same_database_schema_without_types_test() {
+    let field_revs = vec![
+      field_rev("Date", FieldType::RichText),
+      field_rev("Date", FieldType::DateTime),
+    ];
+    let fields = vec![
+      field("Date", None),
+      field("Date", Some(FieldType::RichText)),
+    ];
+    assert!(is_same_database_schema(&fields, &field_revs));
+
+    let fields = vec![field("Date", None), field("Name", None)];
+    assert!(!is_same_database_schema(&fields, &field_revs));
+  }
+}
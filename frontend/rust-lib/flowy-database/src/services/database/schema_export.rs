@@ -0,0 +1,223 @@
+use crate::entities::FieldType;
+use crate::services::field::{select_type_option_from_field_rev, MAX_PROGRESS, MIN_PROGRESS};
+use database_model::FieldRevision;
+use serde_json::{json, Map, Value};
+use std::sync::Arc;
+
+const JSON_SCHEMA_DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+const OPENAPI_VERSION: &str = "3.1.0";
+
+/// Builds the JSON Schema that describes the fields of the database. A row is described as an
+/// object whose properties are keyed by the ids of the fields, as the names of the fields are
+/// not unique. The name and the type of each field are kept in the `title` and the
+/// `x-appflowy-field-type` of its property.
+///
+/// The database doesn't read or write the rows in this format, the schema only describes the
+/// types of the fields for the external tools that map their data to the fields. The computed
+/// fields, e.g. the formulas and the created time, are marked as `readOnly`.
+pub fn make_database_json_schema(view_id: &str, field_revs: &[Arc<FieldRevision>]) -> Value {
+  let mut schema = make_row_schema(field_revs);
+  if let Value::Object(schema) = &mut schema {
+    schema.insert("$schema".to_owned(), json!(JSON_SCHEMA_DRAFT));
+    schema.insert(
+      "$id".to_owned(),
+      json!(format!("appflowy://database/{}/row.schema.json", view_id)),
+    );
+  }
+  schema
+}
+
+/// Wraps the schema of the rows in the components of an OpenAPI document, so that it can be
+/// merged into the description of an API. OpenAPI 3.1 uses the same dialect of JSON Schema, and
+/// it allows the documents that only have components. The database isn't served by any API, so
+/// the document has no paths.
+pub fn make_database_openapi(view_id: &str, field_revs: &[Arc<FieldRevision>]) -> Value {
+  json!({
+    "openapi": OPENAPI_VERSION,
+    "info": {
+      "title": format!("Database {}", view_id),
+      "version": "1.0.0",
+    },
+    "components": {
+      "schemas": {
+        "Row": make_row_schema(field_revs),
+      },
+    },
+  })
+}
+
+fn make_row_schema(field_revs: &[Arc<FieldRevision>]) -> Value {
+  let mut properties = Map::new();
+  let mut required = vec![];
+  for field_rev in field_revs {
+    if field_rev.is_primary {
+      required.push(json!(field_rev.id));
+    }
+    properties.insert(field_rev.id.clone(), make_field_schema(field_rev));
+  }
+
+  json!({
+    "title": "Row",
+    "type": "object",
+    "properties": properties,
+    "required": required,
+    "additionalProperties": false,
+  })
+}
+
+fn make_field_schema(field_rev: &FieldRevision) -> Value {
+  let field_type: FieldType = field_rev.ty.into();
+  let mut schema = match field_type {
+    FieldType::RichText | FieldType::Phone => json!({ "type": "string" }),
+    FieldType::URL => json!({ "type": "string", "format": "uri" }),
+    FieldType::Email => json!({ "type": "string", "format": "email" }),
+    FieldType::Number => json!({ "type": "number" }),
+    FieldType::Progress => {
+      json!({ "type": "integer", "minimum": MIN_PROGRESS, "maximum": MAX_PROGRESS })
+    },
+    FieldType::Duration => json!({
+      "type": "integer",
+      "minimum": 0,
+      "description": "The number of the seconds",
+    }),
+    FieldType::TimeTracking => json!({
+      "type": "object",
+      "properties": {
+        "accumulated": {
+          "type": "integer",
+          "minimum": 0,
+          "description": "The number of the seconds of the finished runs",
+        },
+        "started_at": {
+          "type": ["integer", "null"],
+          "description": "The unix timestamp in seconds when the running timer was started",
+        },
+      },
+    }),
+    FieldType::Location => json!({
+      "type": "object",
+      "properties": {
+        "latitude": { "type": "number", "minimum": -90, "maximum": 90 },
+        "longitude": { "type": "number", "minimum": -180, "maximum": 180 },
+        "address": { "type": "string" },
+      },
+      "required": ["latitude", "longitude"],
+    }),
+    FieldType::Checkbox => json!({ "type": "boolean" }),
+    FieldType::DateTime => json!({
+      "type": "integer",
+      "description": "The unix timestamp in seconds",
+    }),
+    FieldType::AutoNumber => json!({ "type": "integer", "readOnly": true }),
+    FieldType::CreatedTime | FieldType::LastEditedTime => json!({
+      "type": "integer",
+      "description": "The unix timestamp in seconds",
+      "readOnly": true,
+    }),
+    FieldType::SingleSelect => json!({ "type": "string", "enum": option_names(field_rev) }),
+    FieldType::MultiSelect => json!({
+      "type": "array",
+      "items": { "type": "string", "enum": option_names(field_rev) },
+      "uniqueItems": true,
+    }),
+    FieldType::Checklist => json!({
+      "type": "array",
+      "items": {
+        "type": "object",
+        "properties": {
+          "name": { "type": "string" },
+          "is_checked": { "type": "boolean" },
+        },
+        "required": ["name", "is_checked"],
+      },
+    }),
+    FieldType::Relation => json!({
+      "type": "array",
+      "items": { "type": "string" },
+      "description": "The ids of the related rows",
+    }),
+    FieldType::Person => json!({
+      "type": "array",
+      "items": { "type": "string" },
+      "description": "The ids of the assigned users",
+    }),
+    FieldType::Attachment => json!({
+      "type": "array",
+      "items": { "type": "string" },
+      "description": "The names of the attached files",
+      "readOnly": true,
+    }),
+    FieldType::Rollup | FieldType::Formula => json!({
+      "type": ["string", "number", "null"],
+      "readOnly": true,
+    }),
+  };
+
+  if let Value::Object(schema) = &mut schema {
+    schema.insert("title".to_owned(), json!(field_rev.name));
+    schema.insert(
+      "x-appflowy-field-type".to_owned(),
+      json!(format!("{:?}", field_type)),
+    );
+  }
+  schema
+}
+
+fn option_names(field_rev: &FieldRevision) -> Vec<String> {
+  select_type_option_from_field_rev(field_rev)
+    .map(|type_option| {
+      type_option
+        .options()
+        .iter()
+        .map(|option| option.name.clone())
+        .collect()
+    })
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::services::field::{
+    FieldBuilder, SelectOptionPB, SingleSelectTypeOptionBuilder, URLTypeOptionBuilder,
+  };
+
+  #[test]
+  fn database_json_schema_test() {
+    let name_field = Arc::new(
+      FieldBuilder::from_field_type(&FieldType::RichText)
+        .primary(true)
+        .build(),
+    );
+    let single_select = SingleSelectTypeOptionBuilder::default()
+      .add_option(SelectOptionPB::new("Done"))
+      .add_option(SelectOptionPB::new("Doing"));
+    let status_field = Arc::new(FieldBuilder::new(single_select).name("Status").build());
+    let url_field = Arc::new(
+      FieldBuilder::new(URLTypeOptionBuilder::default())
+        .name("Link")
+        .build(),
+    );
+    let field_revs = vec![name_field.clone(), status_field.clone(), url_field.clone()];
+
+    let schema = make_database_json_schema("v1", &field_revs);
+    assert_eq!(schema["$schema"], JSON_SCHEMA_DRAFT);
+    assert_eq!(schema["required"], json!([name_field.id]));
+    let properties = &schema["properties"];
+    assert_eq!(properties[&status_field.id]["title"], "Status");
+    assert_eq!(
+      properties[&status_field.id]["enum"],
+      json!(["Done", "Doing"])
+    );
+    assert_eq!(properties[&url_field.id]["format"], "uri");
+    assert_eq!(properties[&url_field.id]["x-appflowy-field-type"], "URL");
+
+    let openapi = make_database_openapi("v1", &field_revs);
+    assert_eq!(openapi["openapi"], OPENAPI_VERSION);
+    assert!(openapi.get("paths").is_none());
+    assert_eq!(
+      openapi["components"]["schemas"]["Row"]["properties"],
+      *properties
+    );
+  }
+}
@@ -0,0 +1,75 @@
+use crate::services::database::DatabaseBlockEvent;
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::TryRecvError;
+
+/// Tracks the rows whose cells need to be written to an index again, e.g. the text cells to the
+/// `RowMentionIndex` or the relation cells to the `RelationLinkIndex`.
+///
+/// It listens to the changes of the rows. The changed rows are marked as stale, the database
+/// editor indexes them again before the index is read.
+pub(crate) struct StaleRowTracker {
+  state: Mutex<StaleRowState>,
+  block_event_rx: Mutex<broadcast::Receiver<DatabaseBlockEvent>>,
+}
+
+#[derive(Default)]
+struct StaleRowState {
+  is_loaded: bool,
+  stale_row_ids: HashSet<String>,
+}
+
+impl StaleRowTracker {
+  pub(crate) fn new(block_event_rx: broadcast::Receiver<DatabaseBlockEvent>) -> Self {
+    Self {
+      state: Mutex::new(StaleRowState::default()),
+      block_event_rx: Mutex::new(block_event_rx),
+    }
+  }
+
+  /// Returns the ids of the rows that need to be indexed again. None if all the rows need to be
+  /// indexed, e.g. the database was just opened.
+  pub(crate) fn take_stale_row_ids(&self) -> Option<Vec<String>> {
+    self.apply_row_changes();
+    let mut state = self.state.lock();
+    if !state.is_loaded {
+      return None;
+    }
+    Some(state.stale_row_ids.drain().collect())
+  }
+
+  /// Marks all the rows as indexed.
+  pub(crate) fn did_load(&self) {
+    let mut state = self.state.lock();
+    state.stale_row_ids.clear();
+    state.is_loaded = true;
+  }
+
+  /// Marks all the rows as stale, e.g. the indexed field was changed or deleted.
+  pub(crate) fn clear(&self) {
+    *self.state.lock() = StaleRowState::default();
+  }
+
+  fn apply_row_changes(&self) {
+    let mut block_event_rx = self.block_event_rx.lock();
+    loop {
+      match block_event_rx.try_recv() {
+        Ok(event) => {
+          let mut state = self.state.lock();
+          match event {
+            DatabaseBlockEvent::InsertRow { row, .. } => state.stale_row_ids.insert(row.row.id),
+            DatabaseBlockEvent::UpdateRow { row, .. } => state.stale_row_ids.insert(row.row.id),
+            DatabaseBlockEvent::DeleteRow { row_id, .. } => state.stale_row_ids.insert(row_id),
+            DatabaseBlockEvent::Move { inserted_row, .. } => {
+              state.stale_row_ids.insert(inserted_row.row.id)
+            },
+          };
+        },
+        // Some of the changes were dropped, it's unknown which rows were changed.
+        Err(TryRecvError::Lagged(_)) => self.clear(),
+        Err(TryRecvError::Empty) | Err(TryRecvError::Closed) => break,
+      }
+    }
+  }
+}
@@ -4,6 +4,7 @@ use crate::services::database::DatabaseBlocks;
 use crate::services::database_view::DatabaseViewData;
 use crate::services::field::{TypeOptionCellDataHandler, TypeOptionCellExt};
 use crate::services::row::DatabaseBlockRowRevision;
+use crate::services::setting::{SharedWeekSettings, WeekSettings};
 
 use database_model::{FieldRevision, RowRevision};
 use flowy_client_sync::client_database::DatabaseRevisionPad;
@@ -18,6 +19,7 @@ pub struct DatabaseViewDataImpl {
   pub(crate) blocks: Arc<DatabaseBlocks>,
   pub(crate) task_scheduler: Arc<RwLock<TaskDispatcher>>,
   pub(crate) cell_data_cache: AtomicCellDataCache,
+  pub(crate) week_settings: SharedWeekSettings,
 }
 
 impl DatabaseViewData for DatabaseViewDataImpl {
@@ -103,4 +105,8 @@ impl DatabaseViewData for DatabaseViewDataImpl {
     TypeOptionCellExt::new_with_cell_data_cache(field_rev, Some(self.cell_data_cache.clone()))
       .get_type_option_cell_data_handler(field_type)
   }
+
+  fn get_week_settings(&self) -> WeekSettings {
+    self.week_settings.get()
+  }
 }
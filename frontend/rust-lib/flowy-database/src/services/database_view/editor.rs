@@ -1,26 +1,32 @@
 use crate::entities::*;
 use crate::notification::{send_notification, DatabaseNotification};
-use crate::services::cell::{AtomicCellDataCache, TypeCellData};
+use crate::services::cell::{stringify_cell_data, AtomicCellDataCache, TypeCellData};
 use crate::services::database::DatabaseBlockEvent;
 use crate::services::database_view::notifier::DatabaseViewChangedNotifier;
 use crate::services::database_view::trait_impl::*;
 use crate::services::database_view::DatabaseViewChangedReceiverRunner;
-use crate::services::field::{RowSingleCellData, TypeOptionCellDataHandler};
+use crate::services::field::{
+  make_checklist_reminders, select_type_option_from_field_rev, ChecklistReminderPB, DateCellData,
+  DateTimezone, DateTypeOptionPB, RowSingleCellData, SelectOptionIds, TypeOptionCellDataHandler,
+};
 use crate::services::filter::{
-  FilterChangeset, FilterController, FilterTaskHandler, FilterType, UpdatedFilterType,
+  make_replaced_filters_notification, with_row_meta_field_revs, FilterChangeset, FilterController,
+  FilterTaskHandler, FilterType, UpdatedFilterType,
 };
 use crate::services::group::{
   default_group_configuration, find_grouping_field, make_group_controller, Group,
   GroupConfigurationReader, GroupController, MoveGroupRowContext,
 };
 use crate::services::row::DatabaseBlockRowRevision;
+use crate::services::setting::WeekSettings;
 use crate::services::sort::{
   DeletedSortType, SortChangeset, SortController, SortTaskHandler, SortType,
 };
 use database_model::{
-  gen_database_filter_id, gen_database_id, gen_database_sort_id, CalendarLayoutSetting,
-  FieldRevision, FieldTypeRevision, FilterRevision, LayoutRevision, RowChangeset, RowRevision,
-  SortRevision,
+  gen_database_automation_id, gen_database_filter_id, gen_database_id, gen_database_sort_id,
+  AutomationActionRevision, AutomationRevision, AutomationTriggerRevision, CalendarLayoutSetting,
+  FieldRevision, FieldTypeRevision, FilterPresetRevision, FilterRevision, GridLayoutSetting,
+  LayoutRevision, RowChangeset, RowRevision, SortRevision,
 };
 use flowy_client_sync::client_database::{
   make_database_view_operations, DatabaseViewRevisionChangeset, DatabaseViewRevisionPad,
@@ -29,13 +35,16 @@ use flowy_error::{FlowyError, FlowyResult};
 use flowy_revision::RevisionManager;
 use flowy_sqlite::ConnectionPool;
 use flowy_task::TaskDispatcher;
+use indexmap::IndexMap;
 use lib_infra::future::Fut;
+use lib_infra::util::timestamp;
 use nanoid::nanoid;
 use revision_model::Revision;
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::future::Future;
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::{broadcast, RwLock};
 
 pub trait DatabaseViewData: Send + Sync + 'static {
@@ -73,6 +82,9 @@ pub trait DatabaseViewData: Send + Sync + 'static {
     field_rev: &FieldRevision,
     field_type: &FieldType,
   ) -> Option<Box<dyn TypeOptionCellDataHandler>>;
+
+  /// Returns the first day of the week and the working days of the workspace
+  fn get_week_settings(&self) -> WeekSettings;
 }
 
 pub struct DatabaseViewEditor {
@@ -82,6 +94,8 @@ pub struct DatabaseViewEditor {
   rev_manager: Arc<RevisionManager<Arc<ConnectionPool>>>,
   delegate: Arc<dyn DatabaseViewData>,
   group_controller: Arc<RwLock<Box<dyn GroupController>>>,
+  /// Groups the rows into the swimlanes of the board. None if the board has no swimlanes.
+  swimlane_controller: Arc<RwLock<Option<Box<dyn GroupController>>>>,
   filter_controller: Arc<FilterController>,
   sort_controller: Arc<RwLock<SortController>>,
   pub notifier: DatabaseViewChangedNotifier,
@@ -116,8 +130,27 @@ impl DatabaseViewEditor {
     )
     .await?;
 
+    let swimlane_controller = new_swimlane_controller(
+      user_id.to_owned(),
+      view_id.clone(),
+      view_rev_pad.clone(),
+      rev_manager.clone(),
+      delegate.clone(),
+      group_controller.field_id(),
+    )
+    .await
+    .unwrap_or_else(|err| {
+      tracing::error!(
+        "Create the swimlanes of the view: {} failed: {:?}",
+        view_id,
+        err
+      );
+      None
+    });
+
     let user_id = user_id.to_owned();
     let group_controller = Arc::new(RwLock::new(group_controller));
+    let swimlane_controller = Arc::new(RwLock::new(swimlane_controller));
     let filter_controller = make_filter_controller(
       &view_id,
       delegate.clone(),
@@ -143,6 +176,7 @@ impl DatabaseViewEditor {
       rev_manager,
       delegate,
       group_controller,
+      swimlane_controller,
       filter_controller,
       sort_controller,
       notifier,
@@ -197,7 +231,7 @@ impl DatabaseViewEditor {
   pub async fn handle_block_event(&self, event: Cow<'_, DatabaseBlockEvent>) {
     let changeset = match event.into_owned() {
       DatabaseBlockEvent::InsertRow { block_id: _, row } => {
-        //
+        self.filter_controller.did_insert_row();
         RowsChangesetPB::from_insert(self.view_id.clone(), vec![row])
       },
       DatabaseBlockEvent::UpdateRow { block_id: _, row } => {
@@ -208,7 +242,7 @@ impl DatabaseViewEditor {
         block_id: _,
         row_id,
       } => {
-        //
+        self.filter_controller.did_delete_row(&row_id);
         RowsChangesetPB::from_delete(self.view_id.clone(), vec![row_id])
       },
       DatabaseBlockEvent::Move {
@@ -244,22 +278,36 @@ impl DatabaseViewEditor {
   }
 
   pub async fn v_will_create_row(&self, row_rev: &mut RowRevision, params: &CreateRowParams) {
-    if params.group_id.is_none() {
-      return;
+    if let Some(group_id) = params.group_id.as_ref() {
+      let _ = self
+        .mut_group_controller(|group_controller, field_rev| {
+          group_controller.will_create_row(row_rev, &field_rev, group_id);
+          Ok(())
+        })
+        .await;
+    }
+
+    if let Some(swimlane_id) = params.swimlane_id.as_ref() {
+      let _ = self
+        .mut_swimlane_controller(|swimlane_controller, field_rev| {
+          swimlane_controller.will_create_row(row_rev, &field_rev, swimlane_id);
+          Ok(())
+        })
+        .await;
     }
-    let group_id = params.group_id.as_ref().unwrap();
-    let _ = self
-      .mut_group_controller(|group_controller, field_rev| {
-        group_controller.will_create_row(row_rev, &field_rev, group_id);
-        Ok(())
-      })
-      .await;
   }
 
   pub async fn v_did_create_row(&self, row_pb: &RowPB, params: &CreateRowParams) {
     // Send the group notification if the current view has groups
     match params.group_id.as_ref() {
-      None => {},
+      None => {
+        // The row wasn't created in a group, e.g. it was restored from the trash, so it's
+        // inserted into the group that matches its cell.
+        if let Some((_, row_rev)) = self.delegate.get_row_rev(&row_pb.id).await {
+          self.did_update_group_row(None, &row_rev).await;
+        }
+        self.did_receive_row_changed(&row_pb.id);
+      },
       Some(group_id) => {
         let index = match params.start_row_id {
           None => Some(0),
@@ -280,6 +328,17 @@ impl DatabaseViewEditor {
         self.notify_did_update_group_rows(changeset).await;
       },
     }
+
+    if self.swimlane_controller.read().await.is_some() {
+      if let Some((_, row_rev)) = self.delegate.get_row_rev(&row_pb.id).await {
+        let _ = self
+          .mut_swimlane_controller(|swimlane_controller, field_rev| {
+            swimlane_controller.fill_groups(&[row_rev], &field_rev)
+          })
+          .await;
+      }
+      self.notify_did_update_swimlanes().await;
+    }
   }
 
   #[tracing::instrument(level = "trace", skip_all)]
@@ -297,12 +356,45 @@ impl DatabaseViewEditor {
         self.notify_did_update_group_rows(changeset).await;
       }
     }
+
+    let result = self
+      .mut_swimlane_controller(|swimlane_controller, field_rev| {
+        swimlane_controller.did_delete_delete_row(row_rev, &field_rev)
+      })
+      .await;
+    if result.is_some() {
+      self.notify_did_update_swimlanes().await;
+    }
   }
 
   pub async fn v_did_update_row(
     &self,
     old_row_rev: Option<Arc<RowRevision>>,
     row_rev: &RowRevision,
+  ) {
+    self
+      .did_update_group_row(old_row_rev.clone(), row_rev)
+      .await;
+
+    // The row may be moved to another column even if its swimlane is not changed
+    if self.swimlane_controller.read().await.is_some() {
+      let _ = self
+        .mut_swimlane_controller(|swimlane_controller, field_rev| {
+          swimlane_controller.did_update_group_row(&old_row_rev, row_rev, &field_rev)
+        })
+        .await;
+      self.notify_did_update_swimlanes().await;
+    }
+
+    self.did_receive_row_changed(&row_rev.id);
+  }
+
+  /// Moves the row to the group that matches its cell, and creates or deletes the groups if
+  /// needed.
+  async fn did_update_group_row(
+    &self,
+    old_row_rev: Option<Arc<RowRevision>>,
+    row_rev: &RowRevision,
   ) {
     let result = self
       .mut_group_controller(|group_controller, field_rev| {
@@ -333,10 +425,13 @@ impl DatabaseViewEditor {
         self.notify_did_update_group_rows(changeset).await;
       }
     }
+  }
 
+  /// Filters and sorts the row again in the background.
+  fn did_receive_row_changed(&self, row_id: &str) {
     let filter_controller = self.filter_controller.clone();
     let sort_controller = self.sort_controller.clone();
-    let row_id = row_rev.id.clone();
+    let row_id = row_id.to_owned();
     tokio::spawn(async move {
       filter_controller.did_receive_row_changed(&row_id).await;
       sort_controller
@@ -353,6 +448,7 @@ impl DatabaseViewEditor {
     row_changeset: &mut RowChangeset,
     to_group_id: &str,
     to_row_id: Option<String>,
+    to_swimlane_id: Option<String>,
   ) {
     let result = self
       .mut_group_controller(|group_controller, field_rev| {
@@ -382,7 +478,27 @@ impl DatabaseViewEditor {
         self.notify_did_update_group_rows(changeset).await;
       }
     }
+
+    if let Some(to_swimlane_id) = to_swimlane_id {
+      let _ = self
+        .mut_swimlane_controller(|swimlane_controller, field_rev| {
+          let move_row_context = MoveGroupRowContext {
+            row_rev,
+            row_changeset,
+            field_rev: field_rev.as_ref(),
+            to_group_id: &to_swimlane_id,
+            to_row_id: None,
+          };
+          swimlane_controller.move_group_row(move_row_context)
+        })
+        .await;
+    }
+
+    if self.swimlane_controller.read().await.is_some() {
+      self.notify_did_update_swimlanes().await;
+    }
   }
+
   /// Only call once after database view editor initialized
   #[tracing::instrument(level = "trace", skip(self))]
   pub async fn v_load_groups(&self) -> FlowyResult<Vec<GroupPB>> {
@@ -444,6 +560,118 @@ impl DatabaseViewEditor {
     self.group_controller.read().await.field_id().to_string()
   }
 
+  /// Returns the id of the field that groups the rows into the swimlanes
+  pub async fn swimlane_field_id(&self) -> Option<String> {
+    self
+      .swimlane_controller
+      .read()
+      .await
+      .as_ref()
+      .map(|swimlane_controller| swimlane_controller.field_id().to_string())
+  }
+
+  /// Returns the rows of the board in each swimlane and group. The board has no swimlanes if
+  /// the swimlane field is not set.
+  pub async fn v_get_swimlanes(&self) -> FlowyResult<SwimlaneBoardPB> {
+    let group_controller = self.group_controller.read().await;
+    let mut board = SwimlaneBoardPB {
+      view_id: self.view_id.clone(),
+      group_field_id: group_controller.field_id().to_owned(),
+      ..Default::default()
+    };
+
+    if let Some(swimlane_controller) = self.swimlane_controller.read().await.as_ref() {
+      let groups = group_controller.groups();
+      board.swimlane_field_id = swimlane_controller.field_id().to_owned();
+      board.swimlanes = swimlane_controller
+        .groups()
+        .into_iter()
+        .map(|swimlane| SwimlanePB {
+          swimlane_id: swimlane.id.clone(),
+          desc: swimlane.name.clone(),
+          is_default: swimlane.is_default,
+          cells: groups
+            .iter()
+            .map(|group| SwimlaneCellPB {
+              group_id: group.id.clone(),
+              rows: group
+                .rows
+                .iter()
+                .filter(|row| swimlane.contains_row(&row.id))
+                .cloned()
+                .collect(),
+            })
+            .collect(),
+        })
+        .collect();
+    }
+    Ok(board)
+  }
+
+  /// Groups the rows into the swimlanes by the field. Passing None removes the swimlanes.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn v_set_swimlane_field(&self, field_id: Option<String>) -> FlowyResult<()> {
+    match field_id {
+      None => {
+        self
+          .modify(|pad| Ok(pad.update_swimlane_configuration(None)?))
+          .await?;
+        *self.swimlane_controller.write().await = None;
+      },
+      Some(field_id) => {
+        let field_rev = self
+          .delegate
+          .get_field_rev(&field_id)
+          .await
+          .ok_or_else(|| FlowyError::record_not_found().context("Can't find the swimlane field"))?;
+        let field_type: FieldType = field_rev.ty.into();
+        if !field_type.can_be_group() {
+          return Err(
+            FlowyError::invalid_data()
+              .context(format!("Can't group the swimlanes by {}", field_type)),
+          );
+        }
+        if self.group_controller.read().await.field_id() == field_id {
+          return Err(
+            FlowyError::invalid_data()
+              .context("The swimlanes can't be grouped by the field of the groups"),
+          );
+        }
+
+        let is_same_field = self
+          .pad
+          .read()
+          .await
+          .get_swimlane_configuration()
+          .map(|configuration| configuration.field_id == field_id)
+          .unwrap_or(false);
+        if !is_same_field {
+          let configuration = default_group_configuration(&field_rev);
+          self
+            .modify(|pad| Ok(pad.update_swimlane_configuration(Some(configuration))?))
+            .await?;
+        }
+
+        let row_revs = self.delegate.get_row_revs(None).await;
+        let swimlane_controller = new_swimlane_controller_with_field_rev(
+          self.user_id.clone(),
+          self.view_id.clone(),
+          self.pad.clone(),
+          self.rev_manager.clone(),
+          self.delegate.clone(),
+          field_rev,
+          row_revs,
+        )
+        .await?;
+        *self.swimlane_controller.write().await = Some(swimlane_controller);
+      },
+    }
+
+    self.notify_did_update_setting().await;
+    self.notify_did_update_swimlanes().await;
+    Ok(())
+  }
+
   /// Initialize new group when grouping by a new field
   ///
   pub async fn v_initialize_new_group(&self, params: InsertGroupParams) -> FlowyResult<()> {
@@ -482,9 +710,61 @@ impl DatabaseViewEditor {
     make_database_view_setting(&*self.pad.read().await, &field_revs)
   }
 
+  pub async fn v_get_description(&self) -> String {
+    self.pad.read().await.get_description()
+  }
+
+  pub async fn v_update_description(&self, description: String) -> FlowyResult<()> {
+    self
+      .modify(|pad| Ok(pad.update_description(description)?))
+      .await?;
+    self.notify_did_update_setting().await;
+    Ok(())
+  }
+
+  /// Returns the sorts of the fields of the database. The sorts of the trashed fields are kept
+  /// in the view, they come back when the fields are restored.
   pub async fn v_get_all_sorts(&self) -> Vec<Arc<SortRevision>> {
     let field_revs = self.delegate.get_field_revs(None).await;
-    self.pad.read().await.get_all_sorts(&field_revs)
+    self
+      .pad
+      .read()
+      .await
+      .get_all_sorts(&field_revs)
+      .into_iter()
+      .filter(|sort_rev| {
+        field_revs
+          .iter()
+          .any(|field_rev| field_rev.id == sort_rev.field_id)
+      })
+      .collect()
+  }
+
+  /// Applies the filters and the sorts of the fields again after a field was trashed or
+  /// restored.
+  pub async fn v_did_trash_or_restore_field(&self) -> FlowyResult<()> {
+    let filter_revs = self.v_get_all_filters().await;
+    self.filter_controller.recompute(filter_revs).await?;
+    let sort_revs = self.v_get_all_sorts().await;
+    self
+      .sort_controller
+      .write()
+      .await
+      .recompute(sort_revs)
+      .await?;
+    self.notify_did_update_setting().await;
+    Ok(())
+  }
+
+  /// Returns true if the groups of the view are generated from the field
+  pub async fn v_is_grouped_by(&self, field_id: &str) -> bool {
+    self
+      .pad
+      .read()
+      .await
+      .get_all_groups()
+      .iter()
+      .any(|configuration| configuration.field_id == field_id)
   }
 
   #[tracing::instrument(level = "trace", skip(self), err)]
@@ -573,7 +853,7 @@ impl DatabaseViewEditor {
   }
 
   pub async fn v_get_all_filters(&self) -> Vec<Arc<FilterRevision>> {
-    let field_revs = self.delegate.get_field_revs(None).await;
+    let field_revs = with_row_meta_field_revs(self.delegate.get_field_revs(None).await);
     self.pad.read().await.get_all_filters(&field_revs)
   }
 
@@ -586,8 +866,154 @@ impl DatabaseViewEditor {
       .get_filters(&filter_type.field_id, &field_type_rev)
   }
 
+  pub async fn v_get_filtered_row_count(&self) -> FilteredRowCountPB {
+    let row_count = self.filter_controller.get_filtered_row_count().await;
+    FilteredRowCountPB::new(&self.view_id, &row_count)
+  }
+
+  pub fn v_filter_result_bytes(&self) -> usize {
+    self.filter_controller.estimated_bytes()
+  }
+
+  pub async fn v_number_of_unacked_revisions(&self) -> usize {
+    self.rev_manager.number_of_unacked_revisions().await
+  }
+
+  /// Returns the result of each filter and the sort keys of the row, and the index of the row
+  /// after applying them.
+  pub async fn v_get_row_diagnostics(&self, row_id: &str) -> FlowyResult<RowDiagnosticsPB> {
+    let (_, row_rev) = self
+      .delegate
+      .get_row_rev(row_id)
+      .await
+      .ok_or_else(|| FlowyError::record_not_found().context("The row is not found"))?;
+
+    let visible_by_filter_type = self
+      .filter_controller
+      .get_row_filter_results(&row_rev)
+      .await;
+    let filters = self
+      .v_get_all_filters()
+      .await
+      .into_iter()
+      .flat_map(|filter_rev| {
+        let filter_type = FilterType {
+          field_id: filter_rev.field_id.clone(),
+          field_type: filter_rev.field_type.into(),
+        };
+        let is_visible = *visible_by_filter_type.get(&filter_type)?;
+        Some(FilterDiagnosticPB {
+          filter: FilterPB::from(filter_rev.as_ref()),
+          is_visible,
+        })
+      })
+      .collect::<Vec<FilterDiagnosticPB>>();
+    let is_visible = filters.iter().all(|filter| filter.is_visible);
+
+    let mut sorts = vec![];
+    for sort_rev in self.v_get_all_sorts().await {
+      let field_type: FieldType = sort_rev.field_type.into();
+      let cell_content = match (
+        self.delegate.get_field_rev(&sort_rev.field_id).await,
+        row_rev.cells.get(&sort_rev.field_id),
+      ) {
+        (Some(field_rev), Some(cell_rev)) => match TypeCellData::try_from(cell_rev) {
+          Ok(type_cell_data) => stringify_cell_data(
+            type_cell_data.cell_str,
+            &type_cell_data.field_type,
+            &field_type,
+            &field_rev,
+          ),
+          Err(_) => "".to_string(),
+        },
+        _ => "".to_string(),
+      };
+      sorts.push(SortDiagnosticPB {
+        sort: SortPB::from(sort_rev.as_ref()),
+        cell_content,
+      });
+    }
+
+    let index = if is_visible {
+      let mut row_revs = self.delegate.get_row_revs(None).await;
+      self.v_filter_rows("", &mut row_revs).await;
+      self.v_sort_rows(&mut row_revs).await;
+      row_revs
+        .iter()
+        .position(|row_rev| row_rev.id == row_id)
+        .map(|index| index as i32)
+    } else {
+      None
+    };
+
+    Ok(RowDiagnosticsPB {
+      view_id: self.view_id.clone(),
+      row_id: row_id.to_string(),
+      is_visible,
+      index,
+      filters,
+      sorts,
+    })
+  }
+
+  /// Runs the filters, the sorts and the groups of the view again from scratch instead of
+  /// updating them incrementally. Returns how long each stage took.
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub async fn v_recompute(&self) -> FlowyResult<Vec<RecomputeStagePB>> {
+    let mut stages = vec![];
+    let instant = Instant::now();
+    let filter_revs = self.v_get_all_filters().await;
+    self.filter_controller.recompute(filter_revs).await?;
+    stages.push(RecomputeStagePB::new(
+      RecomputeStageTypePB::Filter,
+      instant.elapsed(),
+    ));
+
+    let instant = Instant::now();
+    let sort_revs = self.v_get_all_sorts().await;
+    self
+      .sort_controller
+      .write()
+      .await
+      .recompute(sort_revs)
+      .await?;
+    stages.push(RecomputeStagePB::new(
+      RecomputeStageTypePB::Sort,
+      instant.elapsed(),
+    ));
+
+    let instant = Instant::now();
+    let group_field_id = self.group_id().await;
+    self.v_update_group_setting(&group_field_id).await?;
+    if let Some(swimlane_field_id) = self.swimlane_field_id().await {
+      self.v_set_swimlane_field(Some(swimlane_field_id)).await?;
+    }
+    stages.push(RecomputeStagePB::new(
+      RecomputeStageTypePB::Group,
+      instant.elapsed(),
+    ));
+    Ok(stages)
+  }
+
   #[tracing::instrument(level = "trace", skip(self), err)]
   pub async fn v_insert_filter(&self, params: AlterFilterParams) -> FlowyResult<()> {
+    // The session filter stays unsaved when it gets updated
+    if let Some(filter_id) = params.filter_id.as_ref() {
+      if self
+        .filter_controller
+        .get_session_filter(filter_id)
+        .is_some()
+      {
+        return self.v_insert_session_filter(params).await;
+      }
+    }
+    if params.filter_id.is_none() && self.has_session_filter(&params.field_id) {
+      return Err(FlowyError::invalid_data().context(format!(
+        "The field {} already has a session filter",
+        params.field_id
+      )));
+    }
+
     let filter_type = FilterType::from(&params);
     let is_exist = params.filter_id.is_some();
     let filter_id = match params.filter_id {
@@ -600,7 +1026,11 @@ impl DatabaseViewEditor {
       field_type: params.field_type,
       condition: params.condition,
       content: params.content,
+      case_sensitive: params.case_sensitive,
+      other_field_id: params.other_field_id,
     };
+    // The invalid filter is not saved in the view
+    self.filter_controller.validate_filter(&filter_rev).await?;
     let filter_controller = self.filter_controller.clone();
     let changeset = if is_exist {
       let old_filter_type = self
@@ -619,7 +1049,7 @@ impl DatabaseViewEditor {
           old_filter_type,
           filter_type,
         )))
-        .await
+        .await?
     } else {
       self
         .modify(|pad| {
@@ -629,7 +1059,7 @@ impl DatabaseViewEditor {
         .await?;
       filter_controller
         .did_receive_changes(FilterChangeset::from_insert(filter_type))
-        .await
+        .await?
     };
     drop(filter_controller);
 
@@ -641,11 +1071,22 @@ impl DatabaseViewEditor {
 
   #[tracing::instrument(level = "trace", skip(self), err)]
   pub async fn v_delete_filter(&self, params: DeleteFilterParams) -> FlowyResult<()> {
+    if let Some((filter_type, _)) = self.filter_controller.get_session_filter(&params.filter_id) {
+      let changeset = self
+        .filter_controller
+        .remove_session_filter(&filter_type)
+        .await?;
+      if let Some(changeset) = changeset {
+        self.notify_did_update_filter(changeset).await;
+      }
+      return Ok(());
+    }
+
     let filter_type = params.filter_type;
     let changeset = self
       .filter_controller
       .did_receive_changes(FilterChangeset::from_delete(filter_type.clone()))
-      .await;
+      .await?;
 
     self
       .modify(|pad| {
@@ -664,7 +1105,257 @@ impl DatabaseViewEditor {
     Ok(())
   }
 
-  /// Returns the current calendar settings
+  pub fn v_get_session_filters(&self) -> Vec<Arc<FilterRevision>> {
+    self.filter_controller.get_session_filters()
+  }
+
+  fn has_session_filter(&self, field_id: &str) -> bool {
+    self
+      .filter_controller
+      .get_session_filters()
+      .iter()
+      .any(|filter_rev| filter_rev.field_id == field_id)
+  }
+
+  /// Applies the filter for the current session without saving it in the view. A new session
+  /// filter is created if the `filter_id` is None, otherwise the session filter with the id is
+  /// updated.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn v_insert_session_filter(&self, params: AlterFilterParams) -> FlowyResult<()> {
+    let filter_type = FilterType::from(&params);
+    let filter_id = match params.filter_id {
+      None => {
+        if self.has_session_filter(&params.field_id) {
+          return Err(FlowyError::invalid_data().context(format!(
+            "The field {} already has a session filter",
+            params.field_id
+          )));
+        }
+        gen_database_filter_id()
+      },
+      Some(filter_id) => {
+        let (old_filter_type, _) = self
+          .filter_controller
+          .get_session_filter(&filter_id)
+          .ok_or_else(|| {
+            FlowyError::record_not_found()
+              .context(format!("The session filter {} is not found", filter_id))
+          })?;
+        // The field type was changed, the filter of the old field type is not applied anymore
+        if old_filter_type != filter_type {
+          if let Some(changeset) = self
+            .filter_controller
+            .remove_session_filter(&old_filter_type)
+            .await?
+          {
+            self.notify_did_update_filter(changeset).await;
+          }
+        }
+        filter_id
+      },
+    };
+    let filter_rev = FilterRevision {
+      id: filter_id,
+      field_id: params.field_id,
+      field_type: params.field_type,
+      condition: params.condition,
+      content: params.content,
+      case_sensitive: params.case_sensitive,
+      other_field_id: params.other_field_id,
+    };
+    let changeset = self
+      .filter_controller
+      .insert_session_filter(filter_type, filter_rev)
+      .await?;
+    if let Some(changeset) = changeset {
+      self.notify_did_update_filter(changeset).await;
+    }
+    Ok(())
+  }
+
+  /// Saves the session filter in the view. The filter keeps its id, and the rows are not
+  /// filtered again because the applied filters stay the same.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn v_save_session_filter(&self, filter_id: &str) -> FlowyResult<()> {
+    let (filter_type, filter_rev) = self
+      .filter_controller
+      .get_session_filter(filter_id)
+      .ok_or_else(|| {
+        FlowyError::record_not_found()
+          .context(format!("The session filter {} is not found", filter_id))
+      })?;
+    self
+      .modify(|pad| {
+        let changeset = pad.insert_filter(&filter_rev.field_id, filter_rev.as_ref().clone())?;
+        Ok(changeset)
+      })
+      .await?;
+    self.filter_controller.take_session_filter(&filter_type);
+
+    let notification = FilterChangesetNotificationPB::from_update(
+      &self.view_id,
+      vec![UpdatedFilter {
+        filter_id: filter_rev.id.clone(),
+        filter: Some(FilterPB::from(filter_rev.as_ref())),
+      }],
+    );
+    self.notify_did_update_filter(notification).await;
+    Ok(())
+  }
+
+  pub async fn v_get_filter_presets(&self) -> Vec<FilterPresetRevision> {
+    self.pad.read().await.get_filter_presets()
+  }
+
+  /// Saves the current filters of the view as the preset with the `name`.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn v_save_filter_preset(&self, name: &str) -> FlowyResult<FilterPresetRevision> {
+    let filter_revs = self
+      .v_get_all_filters()
+      .await
+      .into_iter()
+      .map(|filter_rev| filter_rev.as_ref().clone())
+      .collect::<Vec<FilterRevision>>();
+    let mut saved_preset = None;
+    self
+      .modify(|pad| {
+        let (changeset, preset) = pad.save_filter_preset(name, filter_revs)?;
+        saved_preset = Some(preset);
+        Ok(changeset)
+      })
+      .await?;
+    saved_preset.ok_or_else(|| FlowyError::internal().context("Save the filter preset failed"))
+  }
+
+  /// Replaces the filters of the view with the filters of the preset in one change. The
+  /// changes of the filters are sent in a single notification.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn v_apply_filter_preset(&self, preset_id: &str) -> FlowyResult<()> {
+    let preset = self
+      .pad
+      .read()
+      .await
+      .get_filter_preset(preset_id)
+      .ok_or_else(|| {
+        FlowyError::record_not_found()
+          .context(format!("The filter preset {} is not found", preset_id))
+      })?;
+    // The session filters are dropped when the preset is applied
+    let mut old_filter_revs = self.v_get_all_filters().await;
+    old_filter_revs.extend(self.v_get_session_filters());
+    self
+      .modify(|pad| {
+        let changeset = pad.replace_filters(preset.filters)?;
+        Ok(changeset)
+      })
+      .await?;
+
+    let new_filter_revs = self.v_get_all_filters().await;
+    let result = self
+      .filter_controller
+      .did_replace_filters(new_filter_revs.clone())
+      .await;
+    let notification =
+      make_replaced_filters_notification(&self.view_id, &old_filter_revs, &new_filter_revs);
+    self.notify_did_update_filter(notification).await;
+    result
+  }
+
+  pub async fn v_delete_filter_preset(&self, preset_id: &str) -> FlowyResult<()> {
+    self
+      .modify(|pad| {
+        let changeset = pad.delete_filter_preset(preset_id)?;
+        Ok(changeset)
+      })
+      .await
+  }
+
+  pub async fn v_get_automations(&self) -> Vec<AutomationRevision> {
+    self.pad.read().await.get_automations()
+  }
+
+  /// Saves the automation of the view. The trigger must refer to a date field and the action
+  /// must refer to a group of the view.
+  #[tracing::instrument(level = "trace", skip(self, params), err)]
+  pub async fn v_save_automation(
+    &self,
+    params: SaveAutomationParams,
+  ) -> FlowyResult<AutomationRevision> {
+    match &params.trigger {
+      AutomationTriggerRevision::DateArrived { field_id, .. } => {
+        let field_rev = self.delegate.get_field_rev(field_id).await.ok_or_else(|| {
+          FlowyError::record_not_found().context(format!("The field {} is not found", field_id))
+        })?;
+        if FieldType::from(field_rev.ty) != FieldType::DateTime {
+          return Err(
+            FlowyError::invalid_data().context("The trigger of the automation needs a date field"),
+          );
+        }
+      },
+    }
+    match &params.action {
+      AutomationActionRevision::MoveToGroup { group_id } => {
+        let _ = self.v_get_group(group_id).await?;
+      },
+    }
+
+    // The automation keeps its creation time and the dates it fired for when it's updated
+    let existing = match params.automation_id.as_ref() {
+      None => None,
+      Some(automation_id) => self
+        .v_get_automations()
+        .await
+        .into_iter()
+        .find(|automation| &automation.id == automation_id),
+    };
+    let (created_at, fired_dates) = match existing {
+      None => (timestamp(), IndexMap::new()),
+      Some(existing) => (existing.created_at, existing.fired_dates),
+    };
+    let automation = AutomationRevision {
+      id: params
+        .automation_id
+        .unwrap_or_else(gen_database_automation_id),
+      name: params.name,
+      trigger: params.trigger,
+      action: params.action,
+      is_enabled: params.is_enabled,
+      created_at,
+      fired_dates,
+    };
+    let cloned_automation = automation.clone();
+    self
+      .modify(|pad| {
+        let changeset = pad.save_automation(cloned_automation)?;
+        Ok(changeset)
+      })
+      .await?;
+    Ok(automation)
+  }
+
+  pub async fn v_set_automation_fired_dates(
+    &self,
+    automation_id: &str,
+    fired_dates: IndexMap<String, String>,
+  ) -> FlowyResult<()> {
+    self
+      .modify(|pad| {
+        let changeset = pad.set_automation_fired_dates(automation_id, fired_dates)?;
+        Ok(changeset)
+      })
+      .await
+  }
+
+  pub async fn v_delete_automation(&self, automation_id: &str) -> FlowyResult<()> {
+    self
+      .modify(|pad| {
+        let changeset = pad.delete_automation(automation_id)?;
+        Ok(changeset)
+      })
+      .await
+  }
+
+  /// Returns the current grid or calendar settings
   #[tracing::instrument(level = "debug", skip(self), err)]
   pub async fn v_get_layout_settings(
     &self,
@@ -672,7 +1363,15 @@ impl DatabaseViewEditor {
   ) -> FlowyResult<LayoutSettingParams> {
     let mut layout_setting = LayoutSettingParams::default();
     match layout_ty {
-      LayoutRevision::Grid => {},
+      LayoutRevision::Grid => {
+        let grid = self
+          .pad
+          .read()
+          .await
+          .get_layout_setting::<GridLayoutSetting>(layout_ty)
+          .unwrap_or_default();
+        layout_setting.grid = Some(grid);
+      },
       LayoutRevision::Board => {},
       LayoutRevision::Calendar => {
         if let Some(calendar) = self
@@ -685,8 +1384,8 @@ impl DatabaseViewEditor {
           if let Some(field_rev) = self.delegate.get_field_rev(&calendar.layout_field_id).await {
             let field_type: FieldType = field_rev.ty.into();
 
-            // Check the type of field is Datetime or not
-            if field_type == FieldType::DateTime {
+            // Check the type of field is Datetime or CreatedTime
+            if field_type.is_date() || field_type.is_created_time() {
               layout_setting.calendar = Some(calendar);
             }
           }
@@ -698,8 +1397,24 @@ impl DatabaseViewEditor {
     Ok(layout_setting)
   }
 
-  /// Update the calendar settings and send the notification to refresh the UI
+  /// Update the grid or calendar settings and send the notification to refresh the UI
   pub async fn v_set_layout_settings(&self, params: LayoutSettingParams) -> FlowyResult<()> {
+    if let Some(new_grid_setting) = params.grid {
+      let layout_ty = LayoutRevision::Grid;
+      self
+        .modify(|pad| Ok(pad.set_layout_setting(&layout_ty, &new_grid_setting)?))
+        .await?;
+
+      let layout_setting_pb: LayoutSettingPB = LayoutSettingParams {
+        calendar: None,
+        grid: Some(new_grid_setting),
+      }
+      .into();
+      send_notification(&self.view_id, DatabaseNotification::DidUpdateLayoutSettings)
+        .payload(layout_setting_pb)
+        .send();
+    }
+
     // Maybe it needs no send notification to refresh the UI
     if let Some(new_calendar_setting) = params.calendar {
       if let Some(field_rev) = self
@@ -708,7 +1423,7 @@ impl DatabaseViewEditor {
         .await
       {
         let field_type: FieldType = field_rev.ty.into();
-        if field_type != FieldType::DateTime {
+        if !field_type.is_date() && !field_type.is_created_time() {
           return Err(FlowyError::unexpect_calendar_field_type());
         }
 
@@ -721,6 +1436,7 @@ impl DatabaseViewEditor {
         let new_field_id = new_calendar_setting.layout_field_id.clone();
         let layout_setting_pb: LayoutSettingPB = LayoutSettingParams {
           calendar: Some(new_calendar_setting),
+          grid: None,
         }
         .into();
 
@@ -765,21 +1481,141 @@ impl DatabaseViewEditor {
         .did_update_view_field_type_option(&field_rev)
         .await;
 
+      // Rewriting or deleting the filter refreshes it already
+      if self.v_remove_deleted_filter_options(field_id).await? {
+        return Ok(());
+      }
+
       let filter_controller = self.filter_controller.clone();
       let _ = tokio::spawn(async move {
-        if let Some(notification) = filter_controller
+        match filter_controller
           .did_receive_changes(filter_changeset)
           .await
         {
-          send_notification(&notification.view_id, DatabaseNotification::DidUpdateFilter)
-            .payload(notification)
-            .send();
+          Ok(Some(notification)) => {
+            send_notification(&notification.view_id, DatabaseNotification::DidUpdateFilter)
+              .payload(notification)
+              .send();
+          },
+          Ok(None) => {},
+          Err(err) => tracing::error!("Update the filter failed: {:?}", err),
         }
       });
     }
     Ok(())
   }
 
+  /// Removes the ids of the deleted options from the select option filter of the field, so the
+  /// filter doesn't hide the rows by the options that don't exist anymore. The filter is
+  /// deleted if none of its options is left. Returns true if the filter was rewritten or
+  /// deleted, the [DatabaseNotification::DidUpdateFilter] notification is sent in both cases.
+  pub async fn v_remove_deleted_filter_options(&self, field_id: &str) -> FlowyResult<bool> {
+    let field_rev = match self.delegate.get_field_rev(field_id).await {
+      None => return Ok(false),
+      Some(field_rev) => field_rev,
+    };
+    let field_type = FieldType::from(field_rev.ty);
+    if !matches!(field_type, FieldType::SingleSelect | FieldType::MultiSelect) {
+      return Ok(false);
+    }
+
+    let filter_type = FilterType::from(&field_rev);
+    let filter_rev = match self.v_get_filters(&filter_type).await.pop() {
+      None => return Ok(false),
+      Some(filter_rev) => filter_rev,
+    };
+    // The empty filters and the field comparisons don't refer to any option
+    if filter_rev.other_field_id.is_some()
+      || SelectOptionConditionPB::try_from(filter_rev.condition).is_err()
+    {
+      return Ok(false);
+    }
+
+    let type_option = select_type_option_from_field_rev(&field_rev)?;
+    let mut filter = SelectOptionFilterPB::from(filter_rev.as_ref());
+    if !filter.retain_options(type_option.options()) {
+      return Ok(false);
+    }
+
+    if filter.option_ids.is_empty() {
+      tracing::debug!(
+        "Delete the filter {} whose options were deleted",
+        filter_rev.id
+      );
+      self
+        .v_delete_filter(DeleteFilterParams {
+          view_id: self.view_id.clone(),
+          filter_type,
+          filter_id: filter_rev.id.clone(),
+        })
+        .await?;
+    } else {
+      self
+        .v_insert_filter(AlterFilterParams {
+          view_id: self.view_id.clone(),
+          field_id: field_rev.id.clone(),
+          filter_id: Some(filter_rev.id.clone()),
+          field_type: field_rev.ty,
+          condition: filter_rev.condition,
+          content: SelectOptionIds::from(filter.option_ids).to_string(),
+          case_sensitive: filter_rev.case_sensitive,
+          other_field_id: None,
+        })
+        .await?;
+    }
+    Ok(true)
+  }
+
+  /// Cleans the select option filters of all the fields like
+  /// [DatabaseViewEditor::v_remove_deleted_filter_options], e.g. when the view is opened after
+  /// the options were deleted in another view. A filter that fails to be cleaned doesn't stop
+  /// the others.
+  pub async fn v_remove_all_deleted_filter_options(&self) {
+    let field_ids = self
+      .v_get_all_filters()
+      .await
+      .into_iter()
+      .map(|filter_rev| filter_rev.field_id.clone())
+      .collect::<HashSet<String>>();
+    for field_id in field_ids {
+      if let Err(err) = self.v_remove_deleted_filter_options(&field_id).await {
+        tracing::error!(
+          "Remove the deleted options from the filter of the field {} failed: {:?}",
+          field_id,
+          err
+        );
+      }
+    }
+  }
+
+  /// Updates the groups after the grouping field was changed, e.g. its options were renamed,
+  /// recolored or deleted. Only the changed groups are sent, unless the type of the field was
+  /// changed, in which case the groups are generated again.
+  #[tracing::instrument(level = "debug", skip(self, old_field_rev), err)]
+  pub async fn v_did_update_group_field(
+    &self,
+    field_id: &str,
+    old_field_rev: Option<&Arc<FieldRevision>>,
+  ) -> FlowyResult<()> {
+    let field_rev = match self.delegate.get_field_rev(field_id).await {
+      None => return Ok(()),
+      Some(field_rev) => field_rev,
+    };
+    if old_field_rev.map(|old_field_rev| old_field_rev.ty) != Some(field_rev.ty) {
+      return self.v_update_group_setting(field_id).await;
+    }
+
+    let changeset = self
+      .group_controller
+      .write()
+      .await
+      .did_update_group_field(&field_rev)?;
+    if let Some(changeset) = changeset {
+      self.notify_did_update_groups(changeset).await;
+    }
+    Ok(())
+  }
+
   ///
   ///
   /// # Arguments
@@ -788,6 +1624,11 @@ impl DatabaseViewEditor {
   ///
   #[tracing::instrument(level = "debug", skip_all, err)]
   pub async fn v_update_group_setting(&self, field_id: &str) -> FlowyResult<()> {
+    // The groups and the swimlanes can't be grouped by the same field
+    if self.swimlane_field_id().await.as_deref() == Some(field_id) {
+      self.v_set_swimlane_field(None).await?;
+    }
+
     if let Some(field_rev) = self.delegate.get_field_rev(field_id).await {
       let row_revs = self.delegate.get_row_revs(None).await;
       let configuration_reader = GroupConfigurationReaderImpl {
@@ -843,37 +1684,49 @@ impl DatabaseViewEditor {
       .ok()?
       .calendar?;
 
+    // The cells of the row might be empty, e.g. the row is not scheduled yet
+    let _ = self.delegate.get_row_rev(row_id).await?;
+
     // Text
     let primary_field = self.delegate.get_primary_field_rev().await?;
-    let text_cell = get_cell_for_row(self.delegate.clone(), &primary_field.id, row_id).await?;
+    let title = get_cell_for_row(self.delegate.clone(), &primary_field.id, row_id)
+      .await
+      .and_then(|text_cell| text_cell.into_text_field_cell_data())
+      .unwrap_or_default()
+      .into();
 
     // Date
     let date_field = self
       .delegate
       .get_field_rev(&calendar_setting.layout_field_id)
       .await?;
-
-    let date_cell = get_cell_for_row(self.delegate.clone(), &date_field.id, row_id).await?;
-    let title = text_cell
-      .into_text_field_cell_data()
-      .unwrap_or_default()
-      .into();
-
-    let timestamp = date_cell
-      .into_date_field_cell_data()
-      .unwrap_or_default()
-      .timestamp
-      .unwrap_or_default();
+    let date_cell_data = get_cell_for_row(self.delegate.clone(), &date_field.id, row_id)
+      .await
+      .and_then(|date_cell| date_cell.into_date_field_cell_data());
+    let timestamp = date_cell_data
+      .as_ref()
+      .and_then(|date_cell_data| date_cell_data.timestamp);
+    let is_recurring = date_cell_data
+      .map(|date_cell_data| date_cell_data.recurrence.is_some())
+      .unwrap_or(false);
 
     Some(CalendarEventPB {
       row_id: row_id.to_string(),
       date_field_id: date_field.id.clone(),
       title,
-      timestamp,
+      timestamp: timestamp.unwrap_or_default(),
+      is_unscheduled: timestamp.is_none(),
+      checklist_item_id: None,
+      is_recurring,
     })
   }
 
-  pub async fn v_get_all_calendar_events(&self) -> Option<Vec<CalendarEventPB>> {
+  /// Returns the events of the calendar. If the `window` is set, the recurring dates are expanded
+  /// into their occurrences and the scheduled events outside of the window are skipped.
+  pub async fn v_get_all_calendar_events(
+    &self,
+    window: Option<(i64, i64)>,
+  ) -> Option<Vec<CalendarEventPB>> {
     let layout_ty = LayoutRevision::Calendar;
     let calendar_setting = self
       .v_get_layout_settings(&layout_ty)
@@ -883,52 +1736,194 @@ impl DatabaseViewEditor {
 
     // Text
     let primary_field = self.delegate.get_primary_field_rev().await?;
-    let text_cells = self.v_get_cells_for_field(&primary_field.id).await.ok()?;
+    let title_by_row_id = self
+      .v_get_cells_for_field(&primary_field.id)
+      .await
+      .ok()?
+      .into_iter()
+      .map(|text_cell| {
+        let row_id = text_cell.row_id.clone();
+        let title: String = text_cell
+          .into_text_field_cell_data()
+          .unwrap_or_default()
+          .into();
+        (row_id, title)
+      })
+      .collect::<HashMap<String, String>>();
 
     // Date
-    let timestamp_by_row_id = self
+    let date_by_row_id = self
       .v_get_cells_for_field(&calendar_setting.layout_field_id)
       .await
       .ok()?
       .into_iter()
-      .map(|date_cell| {
+      .flat_map(|date_cell| {
         let row_id = date_cell.row_id.clone();
-
-        // timestamp
-        let timestamp = date_cell
-          .into_date_field_cell_data()
-          .map(|date_cell_data| date_cell_data.timestamp.unwrap_or_default())
-          .unwrap_or_default();
-
-        (row_id, timestamp)
+        let date_cell_data = date_cell.into_date_field_cell_data()?;
+        date_cell_data.timestamp.map(|_| (row_id, date_cell_data))
       })
-      .collect::<HashMap<String, i64>>();
+      .collect::<HashMap<String, DateCellData>>();
+    // The recurring dates repeat at the same time in the timezone of the date field
+    let timezone = self
+      .get_date_field_timezone(&calendar_setting.layout_field_id)
+      .await;
+    // The days that are not working days are hidden like the weekends
+    let week_settings = self.delegate.get_week_settings();
+    let is_shown_day = |timestamp: i64| {
+      calendar_setting.show_weekends
+        || timezone
+          .naive_local(timestamp)
+          .map(|time| week_settings.is_working_day(time.date()))
+          .unwrap_or(true)
+    };
+    let is_in_window = |timestamp: i64| {
+      let is_in_window = match window {
+        None => true,
+        Some((start, end)) => start <= timestamp && timestamp <= end,
+      };
+      is_in_window && is_shown_day(timestamp)
+    };
 
+    // The rows without a date are returned as the unscheduled events
     let mut events: Vec<CalendarEventPB> = vec![];
-    for text_cell in text_cells {
-      let row_id = text_cell.row_id.clone();
-      let timestamp = timestamp_by_row_id
-        .get(&row_id)
-        .cloned()
-        .unwrap_or_default();
-
-      let title = text_cell
-        .into_text_field_cell_data()
-        .unwrap_or_default()
-        .into();
+    for row_rev in self.delegate.get_row_revs(None).await {
+      let date_cell_data = date_by_row_id.get(&row_rev.id);
+      if date_cell_data.is_none() && !calendar_setting.show_unscheduled_events {
+        continue;
+      }
 
       let event = CalendarEventPB {
-        row_id,
+        row_id: row_rev.id.clone(),
         date_field_id: calendar_setting.layout_field_id.clone(),
-        title,
-        timestamp,
+        title: title_by_row_id
+          .get(&row_rev.id)
+          .cloned()
+          .unwrap_or_default(),
+        timestamp: 0,
+        is_unscheduled: date_cell_data.is_none(),
+        checklist_item_id: None,
+        is_recurring: false,
       };
-      events.push(event);
+      match date_cell_data.map(|data| (data.timestamp.unwrap_or_default(), &data.recurrence)) {
+        None => events.push(event),
+        Some((timestamp, None)) => {
+          if is_in_window(timestamp) {
+            events.push(CalendarEventPB { timestamp, ..event });
+          }
+        },
+        Some((timestamp, Some(recurrence))) => {
+          let occurrences = match window {
+            None => vec![timestamp],
+            Some((start, end)) => {
+              recurrence.occurrences_in_timezone(&timezone, timestamp, start, end)
+            },
+          };
+          for occurrence in occurrences
+            .into_iter()
+            .filter(|occurrence| is_shown_day(*occurrence))
+          {
+            events.push(CalendarEventPB {
+              timestamp: occurrence,
+              is_recurring: true,
+              ..event.clone()
+            });
+          }
+        },
+      }
+    }
+
+    if calendar_setting.show_checklist_items {
+      for reminder in self.v_get_checklist_reminders().await {
+        if !is_in_window(reminder.due) {
+          continue;
+        }
+        events.push(CalendarEventPB {
+          row_id: reminder.row_id,
+          date_field_id: reminder.field_id,
+          title: reminder.name,
+          timestamp: reminder.due,
+          is_unscheduled: false,
+          checklist_item_id: Some(reminder.item_id),
+          is_recurring: false,
+        });
+      }
     }
 
     Some(events)
   }
 
+  /// Returns the first and the last second of the week that the `timestamp` is in. The week
+  /// starts on the first day of the week of the workspace, in the timezone of the date field of
+  /// the calendar.
+  pub async fn v_get_calendar_week_window(&self, timestamp: i64) -> FlowyResult<(i64, i64)> {
+    let calendar_setting = self
+      .v_get_layout_settings(&LayoutRevision::Calendar)
+      .await?
+      .calendar
+      .ok_or_else(|| FlowyError::record_not_found().context("The calendar setting is not found"))?;
+    let timezone = self
+      .get_date_field_timezone(&calendar_setting.layout_field_id)
+      .await;
+    let window = timezone.naive_local(timestamp).and_then(|time| {
+      let (first_day, last_day) = self.delegate.get_week_settings().week_of(time.date());
+      let start = timezone.timestamp(&first_day.and_hms_opt(0, 0, 0)?)?;
+      let end = timezone.timestamp(&last_day.and_hms_opt(23, 59, 59)?)?;
+      Some((start, end))
+    });
+    window.ok_or_else(|| FlowyError::invalid_data().context("The timestamp is out of range"))
+  }
+
+  /// The filters that check the due dates, e.g. [ChecklistFilterConditionPB::HasOverdueItems],
+  /// are evaluated with the current time, so the row is filtered again when one of its due dates
+  /// passes.
+  pub async fn v_did_pass_due_date(&self, row_id: &str) {
+    self.filter_controller.did_receive_row_changed(row_id).await;
+  }
+
+  /// Applies the updated week settings of the workspace to the date filters, and groups the rows
+  /// into the new weeks if the groups or the swimlanes are grouped by a date field.
+  pub async fn v_did_update_week_settings(&self) -> FlowyResult<()> {
+    self.filter_controller.did_update_week_settings().await;
+
+    let group_field_id = self.group_id().await;
+    if self.is_date_field(&group_field_id).await {
+      self.v_update_group_setting(&group_field_id).await?;
+    }
+
+    if let Some(swimlane_field_id) = self.swimlane_field_id().await {
+      if self.is_date_field(&swimlane_field_id).await {
+        self.v_set_swimlane_field(Some(swimlane_field_id)).await?;
+      }
+    }
+    Ok(())
+  }
+
+  async fn is_date_field(&self, field_id: &str) -> bool {
+    self
+      .delegate
+      .get_field_rev(field_id)
+      .await
+      .map(|field_rev| FieldType::from(field_rev.ty).is_date())
+      .unwrap_or(false)
+  }
+
+  async fn get_date_field_timezone(&self, field_id: &str) -> DateTimezone {
+    self
+      .delegate
+      .get_field_rev(field_id)
+      .await
+      .and_then(|field_rev| field_rev.get_type_option::<DateTypeOptionPB>(field_rev.ty))
+      .map(|type_option| type_option.timezone())
+      .unwrap_or(DateTimezone::Local)
+  }
+
+  /// Returns the reminders of the checklist items that have a due date, see [ChecklistReminderPB].
+  pub async fn v_get_checklist_reminders(&self) -> Vec<ChecklistReminderPB> {
+    let field_revs = self.delegate.get_field_revs(None).await;
+    let row_revs = self.delegate.get_row_revs(None).await;
+    make_checklist_reminders(&field_revs, &row_revs)
+  }
+
   async fn notify_did_update_setting(&self) {
     let setting = self.v_get_setting().await;
     send_notification(&self.view_id, DatabaseNotification::DidUpdateSettings)
@@ -962,6 +1957,17 @@ impl DatabaseViewEditor {
       .send();
   }
 
+  async fn notify_did_update_swimlanes(&self) {
+    match self.v_get_swimlanes().await {
+      Ok(board) => {
+        send_notification(&self.view_id, DatabaseNotification::DidUpdateSwimlanes)
+          .payload(board)
+          .send();
+      },
+      Err(err) => tracing::error!("Get the swimlanes failed: {:?}", err),
+    }
+  }
+
   async fn modify<F>(&self, f: F) -> FlowyResult<()>
   where
     F: for<'a> FnOnce(
@@ -992,6 +1998,17 @@ impl DatabaseViewEditor {
     }
   }
 
+  async fn mut_swimlane_controller<F, T>(&self, f: F) -> Option<T>
+  where
+    F: FnOnce(&mut Box<dyn GroupController>, Arc<FieldRevision>) -> FlowyResult<T>,
+  {
+    let swimlane_field_id = self.swimlane_field_id().await?;
+    let field_rev = self.delegate.get_field_rev(&swimlane_field_id).await?;
+    let mut write_guard = self.swimlane_controller.write().await;
+    let swimlane_controller = write_guard.as_mut()?;
+    f(swimlane_controller, field_rev).ok()
+  }
+
   #[allow(dead_code)]
   async fn async_mut_group_controller<F, O, T>(&self, f: F) -> Option<T>
   where
@@ -1128,6 +2145,71 @@ async fn new_group_controller_with_field_rev(
   .await
 }
 
+/// Returns the [GroupController] of the swimlanes if the view has the swimlane configuration.
+/// The swimlanes are ignored if their field was deleted or is the grouping field.
+async fn new_swimlane_controller(
+  user_id: String,
+  view_id: String,
+  view_rev_pad: Arc<RwLock<DatabaseViewRevisionPad>>,
+  rev_manager: Arc<RevisionManager<Arc<ConnectionPool>>>,
+  delegate: Arc<dyn DatabaseViewData>,
+  group_field_id: &str,
+) -> FlowyResult<Option<Box<dyn GroupController>>> {
+  let configuration = match view_rev_pad.read().await.get_swimlane_configuration() {
+    None => return Ok(None),
+    Some(configuration) => configuration,
+  };
+  if configuration.field_id == group_field_id {
+    return Ok(None);
+  }
+
+  match delegate.get_field_rev(&configuration.field_id).await {
+    None => Ok(None),
+    Some(field_rev) => {
+      let row_revs = delegate.get_row_revs(None).await;
+      let swimlane_controller = new_swimlane_controller_with_field_rev(
+        user_id,
+        view_id,
+        view_rev_pad,
+        rev_manager,
+        delegate,
+        field_rev,
+        row_revs,
+      )
+      .await?;
+      Ok(Some(swimlane_controller))
+    },
+  }
+}
+
+async fn new_swimlane_controller_with_field_rev(
+  user_id: String,
+  view_id: String,
+  view_rev_pad: Arc<RwLock<DatabaseViewRevisionPad>>,
+  rev_manager: Arc<RevisionManager<Arc<ConnectionPool>>>,
+  delegate: Arc<dyn DatabaseViewData>,
+  swimlane_field_rev: Arc<FieldRevision>,
+  row_revs: Vec<Arc<RowRevision>>,
+) -> FlowyResult<Box<dyn GroupController>> {
+  let configuration_reader = SwimlaneConfigurationReaderImpl {
+    pad: view_rev_pad.clone(),
+    view_editor_delegate: delegate,
+  };
+  let configuration_writer = SwimlaneConfigurationWriterImpl {
+    user_id,
+    rev_manager,
+    view_pad: view_rev_pad,
+  };
+  make_group_controller(
+    view_id,
+    swimlane_field_rev,
+    row_revs,
+    configuration_reader,
+    configuration_writer,
+  )
+  .await
+}
+
 async fn make_filter_controller(
   view_id: &str,
   delegate: Arc<dyn DatabaseViewData>,
@@ -1135,7 +2217,7 @@ async fn make_filter_controller(
   cell_data_cache: AtomicCellDataCache,
   pad: Arc<RwLock<DatabaseViewRevisionPad>>,
 ) -> Arc<FilterController> {
-  let field_revs = delegate.get_field_revs(None).await;
+  let field_revs = with_row_meta_field_revs(delegate.get_field_revs(None).await);
   let filter_revs = pad.read().await.get_all_filters(&field_revs);
   let task_scheduler = delegate.get_task_scheduler();
   let filter_delegate = DatabaseViewFilterDelegateImpl {
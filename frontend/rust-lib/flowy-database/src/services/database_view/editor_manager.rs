@@ -1,8 +1,9 @@
 #![allow(clippy::while_let_loop)]
 use crate::entities::{
   AlterFilterParams, AlterSortParams, CreateRowParams, DatabaseViewSettingPB, DeleteFilterParams,
-  DeleteGroupParams, DeleteSortParams, GroupPB, InsertGroupParams, LayoutSettingParams,
-  MoveGroupParams, RepeatedGroupPB, RowPB,
+  DeleteGroupParams, DeleteSortParams, FilterPresetIdParams, GroupPB, InsertGroupParams,
+  LayoutSettingParams, MoveGroupParams, RepeatedGroupPB, RowPB, SaveAutomationParams,
+  SaveFilterPresetParams, SessionFilterIdParams, SwimlaneBoardPB, SwimlaneFieldParams,
 };
 use crate::manager::DatabaseUser;
 use crate::services::cell::AtomicCellDataCache;
@@ -17,12 +18,14 @@ use crate::services::persistence::rev_sqlite::{
   SQLiteDatabaseRevisionSnapshotPersistence, SQLiteDatabaseViewRevisionPersistence,
 };
 use database_model::{
-  FieldRevision, FilterRevision, LayoutRevision, RowChangeset, RowRevision, SortRevision,
+  AutomationRevision, FieldRevision, FilterPresetRevision, FilterRevision, LayoutRevision,
+  RowChangeset, RowRevision, SortRevision,
 };
 use flowy_client_sync::client_database::DatabaseViewRevisionPad;
 use flowy_error::FlowyResult;
 use flowy_revision::{RevisionManager, RevisionPersistence, RevisionPersistenceConfiguration};
 use flowy_sqlite::ConnectionPool;
+use indexmap::IndexMap;
 use lib_infra::future::Fut;
 use std::borrow::Cow;
 use std::collections::HashMap;
@@ -69,6 +72,58 @@ impl DatabaseViews {
     }
   }
 
+  /// Returns the estimated bytes of the filter results of all the opened views.
+  pub async fn filter_result_bytes(&self) -> usize {
+    self
+      .view_editors
+      .read()
+      .await
+      .values()
+      .map(|view_editor| view_editor.v_filter_result_bytes())
+      .sum()
+  }
+
+  pub async fn number_of_unacked_revisions(&self) -> usize {
+    let view_editors = self
+      .view_editors
+      .read()
+      .await
+      .values()
+      .cloned()
+      .collect::<Vec<_>>();
+    let mut number = 0;
+    for view_editor in view_editors {
+      number += view_editor.v_number_of_unacked_revisions().await;
+    }
+    number
+  }
+
+  /// Applies the updated week settings to all the opened views.
+  pub async fn did_update_week_settings(&self) {
+    let view_editors = self
+      .view_editors
+      .read()
+      .await
+      .values()
+      .cloned()
+      .collect::<Vec<Arc<DatabaseViewEditor>>>();
+    for view_editor in view_editors {
+      if let Err(err) = view_editor.v_did_update_week_settings().await {
+        tracing::error!(
+          "Apply the week settings to the view: {} failed: {:?}",
+          view_editor.view_id,
+          err
+        );
+      }
+    }
+  }
+
+  pub async fn did_pass_due_date(&self, row_id: &str) {
+    for view_editor in self.view_editors.read().await.values() {
+      view_editor.v_did_pass_due_date(row_id).await;
+    }
+  }
+
   pub async fn number_of_views(&self) -> usize {
     self.view_editors.read().await.values().len()
   }
@@ -154,6 +209,16 @@ impl DatabaseViews {
     Ok(view_editor.v_get_setting().await)
   }
 
+  pub async fn get_description(&self, view_id: &str) -> FlowyResult<String> {
+    let view_editor = self.get_view_editor(view_id).await?;
+    Ok(view_editor.v_get_description().await)
+  }
+
+  pub async fn update_description(&self, view_id: &str, description: String) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(view_id).await?;
+    view_editor.v_update_description(description).await
+  }
+
   pub async fn get_all_filters(&self, view_id: &str) -> FlowyResult<Vec<Arc<FilterRevision>>> {
     let view_editor = self.get_view_editor(view_id).await?;
     Ok(view_editor.v_get_all_filters().await)
@@ -178,6 +243,92 @@ impl DatabaseViews {
     view_editor.v_delete_filter(params).await
   }
 
+  pub async fn get_session_filters(&self, view_id: &str) -> FlowyResult<Vec<Arc<FilterRevision>>> {
+    let view_editor = self.get_view_editor(view_id).await?;
+    Ok(view_editor.v_get_session_filters())
+  }
+
+  pub async fn insert_session_filter(&self, params: AlterFilterParams) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_insert_session_filter(params).await
+  }
+
+  pub async fn save_session_filter(&self, params: SessionFilterIdParams) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_save_session_filter(&params.filter_id).await
+  }
+
+  pub async fn get_filter_presets(&self, view_id: &str) -> FlowyResult<Vec<FilterPresetRevision>> {
+    let view_editor = self.get_view_editor(view_id).await?;
+    Ok(view_editor.v_get_filter_presets().await)
+  }
+
+  pub async fn save_filter_preset(
+    &self,
+    params: SaveFilterPresetParams,
+  ) -> FlowyResult<FilterPresetRevision> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_save_filter_preset(&params.name).await
+  }
+
+  pub async fn apply_filter_preset(&self, params: FilterPresetIdParams) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_apply_filter_preset(&params.preset_id).await
+  }
+
+  pub async fn delete_filter_preset(&self, params: FilterPresetIdParams) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_delete_filter_preset(&params.preset_id).await
+  }
+
+  pub async fn get_automations(&self, view_id: &str) -> FlowyResult<Vec<AutomationRevision>> {
+    let view_editor = self.get_view_editor(view_id).await?;
+    Ok(view_editor.v_get_automations().await)
+  }
+
+  /// Returns the automations of the opened views by the ids of the views. The views without
+  /// automations are returned too.
+  pub async fn get_opened_automations(&self) -> Vec<(String, Vec<AutomationRevision>)> {
+    let view_editors = self
+      .view_editors
+      .read()
+      .await
+      .values()
+      .cloned()
+      .collect::<Vec<Arc<DatabaseViewEditor>>>();
+    let mut automations = vec![];
+    for view_editor in view_editors {
+      let view_automations = view_editor.v_get_automations().await;
+      automations.push((view_editor.view_id.clone(), view_automations));
+    }
+    automations
+  }
+
+  pub async fn set_automation_fired_dates(
+    &self,
+    view_id: &str,
+    automation_id: &str,
+    fired_dates: IndexMap<String, String>,
+  ) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(view_id).await?;
+    view_editor
+      .v_set_automation_fired_dates(automation_id, fired_dates)
+      .await
+  }
+
+  pub async fn save_automation(
+    &self,
+    params: SaveAutomationParams,
+  ) -> FlowyResult<AutomationRevision> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_save_automation(params).await
+  }
+
+  pub async fn delete_automation(&self, view_id: &str, automation_id: &str) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(view_id).await?;
+    view_editor.v_delete_automation(automation_id).await
+  }
+
   pub async fn get_all_sorts(&self, view_id: &str) -> FlowyResult<Vec<Arc<SortRevision>>> {
     let view_editor = self.get_view_editor(view_id).await?;
     Ok(view_editor.v_get_all_sorts().await)
@@ -243,6 +394,16 @@ impl DatabaseViews {
     Ok(())
   }
 
+  pub async fn get_swimlanes(&self, view_id: &str) -> FlowyResult<SwimlaneBoardPB> {
+    let view_editor = self.get_view_editor(view_id).await?;
+    view_editor.v_get_swimlanes().await
+  }
+
+  pub async fn set_swimlane_field(&self, params: SwimlaneFieldParams) -> FlowyResult<()> {
+    let view_editor = self.get_view_editor(&params.view_id).await?;
+    view_editor.v_set_swimlane_field(params.field_id).await
+  }
+
   /// It may generate a RowChangeset when the Row was moved from one group to another.
   /// The return value, [RowChangeset], contains the changes made by the groups.
   ///
@@ -252,6 +413,7 @@ impl DatabaseViews {
     row_rev: Arc<RowRevision>,
     to_group_id: String,
     to_row_id: Option<String>,
+    to_swimlane_id: Option<String>,
     recv_row_changeset: impl FnOnce(RowChangeset) -> Fut<()>,
   ) -> FlowyResult<()> {
     let mut row_changeset = RowChangeset::new(row_rev.id.clone());
@@ -262,6 +424,7 @@ impl DatabaseViews {
         &mut row_changeset,
         &to_group_id,
         to_row_id.clone(),
+        to_swimlane_id,
       )
       .await;
 
@@ -275,6 +438,50 @@ impl DatabaseViews {
   /// Notifies the view's field type-option data is changed
   /// For the moment, only the groups will be generated after the type-option data changed. A
   /// [FieldRevision] has a property named type_options contains a list of type-option data.
+  /// Returns the ids of the opened views that are grouped by the field
+  pub async fn get_grouped_view_ids(&self, field_id: &str) -> Vec<String> {
+    let mut view_ids = vec![];
+    for view_editor in self.view_editors.read().await.values() {
+      if view_editor.v_is_grouped_by(field_id).await {
+        view_ids.push(view_editor.view_id.clone());
+      }
+    }
+    view_ids
+  }
+
+  /// Stops filtering and sorting the rows by the trashed field in the opened views.
+  pub async fn did_trash_field(&self) {
+    for view_editor in self.view_editors.read().await.values() {
+      if let Err(err) = view_editor.v_did_trash_or_restore_field().await {
+        tracing::error!(
+          "Refresh the view: {} failed: {:?}",
+          view_editor.view_id,
+          err
+        );
+      }
+    }
+  }
+
+  /// Brings back the filters and the sorts of the restored field in the opened views, and
+  /// groups the `grouped_view_ids` by the field again.
+  pub async fn did_restore_field(&self, field_id: &str, grouped_view_ids: &[String]) {
+    for view_editor in self.view_editors.read().await.values() {
+      if let Err(err) = view_editor.v_did_trash_or_restore_field().await {
+        tracing::error!(
+          "Refresh the view: {} failed: {:?}",
+          view_editor.view_id,
+          err
+        );
+      }
+      if grouped_view_ids.contains(&view_editor.view_id) && view_editor.group_id().await != field_id
+      {
+        if let Err(err) = view_editor.v_update_group_setting(field_id).await {
+          tracing::error!("Group the view: {} failed: {:?}", view_editor.view_id, err);
+        }
+      }
+    }
+  }
+
   /// # Arguments
   ///
   /// * `field_id`: the id of the field in current view
@@ -288,14 +495,46 @@ impl DatabaseViews {
   ) -> FlowyResult<()> {
     let view_editor = self.get_view_editor(view_id).await?;
     // If the id of the grouping field is equal to the updated field's id, then we need to
-    // update the group setting
+    // update the groups
     if view_editor.group_id().await == field_id {
-      view_editor.v_update_group_setting(field_id).await?;
+      view_editor
+        .v_did_update_group_field(field_id, old_field_rev.as_ref())
+        .await?;
+    }
+
+    // Regenerates the swimlanes as the groups
+    if view_editor.swimlane_field_id().await.as_deref() == Some(field_id) {
+      view_editor
+        .v_set_swimlane_field(Some(field_id.to_owned()))
+        .await?;
     }
 
     view_editor
       .v_did_update_field_type_option(field_id, old_field_rev)
       .await?;
+
+    // The filters of the other opened views may refer to the deleted options too. The views that
+    // are not opened clean their filters when they are opened, see [Self::get_view_editor].
+    let other_view_editors = self
+      .view_editors
+      .read()
+      .await
+      .values()
+      .filter(|other_view_editor| other_view_editor.view_id != view_id)
+      .cloned()
+      .collect::<Vec<_>>();
+    for other_view_editor in other_view_editors {
+      if let Err(err) = other_view_editor
+        .v_remove_deleted_filter_options(field_id)
+        .await
+      {
+        tracing::error!(
+          "Remove the deleted options from the filter of the view {} failed: {:?}",
+          other_view_editor.view_id,
+          err
+        );
+      }
+    }
     Ok(())
   }
 
@@ -306,9 +545,14 @@ impl DatabaseViews {
     }
 
     tracing::trace!("{:p} create view:{} editor", self, view_id);
-    let mut view_editors = self.view_editors.write().await;
-    let editor = Arc::new(self.make_view_editor(view_id).await?);
-    view_editors.insert(view_id.to_owned(), editor.clone());
+    let editor = {
+      let mut view_editors = self.view_editors.write().await;
+      let editor = Arc::new(self.make_view_editor(view_id).await?);
+      view_editors.insert(view_id.to_owned(), editor.clone());
+      editor
+    };
+    // The options might have been deleted while the view wasn't opened
+    editor.v_remove_all_deleted_filter_options().await;
     Ok(editor)
   }
 
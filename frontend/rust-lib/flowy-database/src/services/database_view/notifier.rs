@@ -1,5 +1,7 @@
 #![allow(clippy::while_let_loop)]
-use crate::entities::{ReorderAllRowsPB, ReorderSingleRowPB, RowsVisibilityChangesetPB};
+use crate::entities::{
+  FilteredRowCountPB, ReorderAllRowsPB, ReorderSingleRowPB, RowsVisibilityChangesetPB,
+};
 use crate::notification::{send_notification, DatabaseNotification};
 use crate::services::filter::FilterResultNotification;
 use crate::services::sort::{ReorderAllRowsResult, ReorderSingleRowResult};
@@ -35,10 +37,12 @@ impl DatabaseViewChangedReceiverRunner {
       .for_each(|changed| async {
         match changed {
           DatabaseViewChanged::FilterNotification(notification) => {
+            let row_count = FilteredRowCountPB::new(&notification.view_id, &notification.row_count);
             let changeset = RowsVisibilityChangesetPB {
               view_id: notification.view_id,
               visible_rows: notification.visible_rows,
               invisible_rows: notification.invisible_rows,
+              row_count,
             };
 
             send_notification(
@@ -1,13 +1,17 @@
-use crate::entities::{DatabaseViewSettingPB, LayoutSettingPB};
+use crate::entities::{DatabaseViewSettingPB, GroupConfigurationPB, LayoutSettingPB};
 use crate::services::database_view::{get_cells_for_field, DatabaseViewData};
 use crate::services::field::RowSingleCellData;
-use crate::services::filter::{FilterController, FilterDelegate, FilterType};
+use crate::services::filter::{
+  get_row_meta_field_rev, is_row_meta_field, row_meta_field_revs, with_row_meta_field_revs,
+  FilterController, FilterDelegate, FilterType,
+};
 use crate::services::group::{GroupConfigurationReader, GroupConfigurationWriter};
 use crate::services::row::DatabaseBlockRowRevision;
+use crate::services::setting::WeekSettings;
 use crate::services::sort::{SortDelegate, SortType};
 use bytes::Bytes;
 use database_model::{
-  CalendarLayoutSetting, FieldRevision, FieldTypeRevision, FilterRevision,
+  CalendarLayoutSetting, FieldRevision, FieldTypeRevision, FilterRevision, GridLayoutSetting,
   GroupConfigurationRevision, LayoutRevision, RowRevision, SortRevision,
 };
 use flowy_client_sync::client_database::{DatabaseViewRevisionChangeset, DatabaseViewRevisionPad};
@@ -94,6 +98,10 @@ impl GroupConfigurationReader for GroupConfigurationReaderImpl {
     let view_editor_delegate = self.view_editor_delegate.clone();
     to_fut(async move { get_cells_for_field(view_editor_delegate, &field_id).await })
   }
+
+  fn get_week_settings(&self) -> WeekSettings {
+    self.view_editor_delegate.get_week_settings()
+  }
 }
 
 pub(crate) struct GroupConfigurationWriterImpl {
@@ -128,6 +136,61 @@ impl GroupConfigurationWriter for GroupConfigurationWriterImpl {
   }
 }
 
+/// Reads the group configuration of the swimlanes, which is saved separately from the
+/// configuration of the groups.
+pub(crate) struct SwimlaneConfigurationReaderImpl {
+  pub(crate) pad: Arc<RwLock<DatabaseViewRevisionPad>>,
+  pub(crate) view_editor_delegate: Arc<dyn DatabaseViewData>,
+}
+
+impl GroupConfigurationReader for SwimlaneConfigurationReaderImpl {
+  fn get_configuration(&self) -> Fut<Option<Arc<GroupConfigurationRevision>>> {
+    let view_pad = self.pad.clone();
+    to_fut(async move { view_pad.read().await.get_swimlane_configuration() })
+  }
+
+  fn get_configuration_cells(&self, field_id: &str) -> Fut<FlowyResult<Vec<RowSingleCellData>>> {
+    let field_id = field_id.to_owned();
+    let view_editor_delegate = self.view_editor_delegate.clone();
+    to_fut(async move { get_cells_for_field(view_editor_delegate, &field_id).await })
+  }
+
+  fn get_week_settings(&self) -> WeekSettings {
+    self.view_editor_delegate.get_week_settings()
+  }
+}
+
+pub(crate) struct SwimlaneConfigurationWriterImpl {
+  pub(crate) user_id: String,
+  pub(crate) rev_manager: Arc<RevisionManager<Arc<ConnectionPool>>>,
+  pub(crate) view_pad: Arc<RwLock<DatabaseViewRevisionPad>>,
+}
+
+impl GroupConfigurationWriter for SwimlaneConfigurationWriterImpl {
+  fn save_configuration(
+    &self,
+    _field_id: &str,
+    _field_type: FieldTypeRevision,
+    group_configuration: GroupConfigurationRevision,
+  ) -> Fut<FlowyResult<()>> {
+    let user_id = self.user_id.clone();
+    let rev_manager = self.rev_manager.clone();
+    let view_pad = self.view_pad.clone();
+
+    to_fut(async move {
+      let changeset = view_pad
+        .write()
+        .await
+        .update_swimlane_configuration(Some(group_configuration))?;
+
+      if let Some(changeset) = changeset {
+        apply_change(&user_id, rev_manager, changeset).await?;
+      }
+      Ok(())
+    })
+  }
+}
+
 pub(crate) async fn apply_change(
   _user_id: &str,
   rev_manager: Arc<RevisionManager<Arc<ConnectionPool>>>,
@@ -149,7 +212,12 @@ pub fn make_database_view_setting(
   let layout_type: LayoutRevision = view_pad.layout.clone();
   let mut layout_settings = LayoutSettingPB::new();
   match layout_type {
-    LayoutRevision::Grid => {},
+    LayoutRevision::Grid => {
+      let grid = view_pad
+        .get_layout_setting::<GridLayoutSetting>(&layout_type)
+        .unwrap_or_default();
+      layout_settings.grid = Some(grid.into());
+    },
     LayoutRevision::Board => {},
     LayoutRevision::Calendar => {
       layout_settings.calendar = view_pad
@@ -158,15 +226,25 @@ pub fn make_database_view_setting(
     },
   }
 
-  let filters = view_pad.get_all_filters(field_revs);
+  let filters = view_pad.get_all_filters(&with_row_meta_field_revs(field_revs.to_vec()));
   let group_configurations = view_pad.get_groups_by_field_revs(field_revs);
   let sorts = view_pad.get_all_sorts(field_revs);
+  let swimlane_configuration = view_pad
+    .get_swimlane_configuration()
+    .filter(|configuration| {
+      field_revs
+        .iter()
+        .any(|field_rev| field_rev.id == configuration.field_id)
+    })
+    .map(|configuration| GroupConfigurationPB::from(configuration.as_ref()));
   DatabaseViewSettingPB {
     current_layout: layout_type.into(),
     layout_setting: layout_settings,
     filters: filters.into(),
     sorts: sorts.into(),
     group_configurations: group_configurations.into(),
+    description: view_pad.get_description(),
+    swimlane_configuration,
   }
 }
 
@@ -194,11 +272,29 @@ impl FilterDelegate for DatabaseViewFilterDelegateImpl {
   }
 
   fn get_field_rev(&self, field_id: &str) -> Fut<Option<Arc<FieldRevision>>> {
+    if is_row_meta_field(field_id) {
+      let field_rev = get_row_meta_field_rev(field_id);
+      return to_fut(async move { field_rev });
+    }
     self.editor_delegate.get_field_rev(field_id)
   }
 
+  /// Returns the fields including the pseudo fields of the row metadata, so the rows can be
+  /// filtered by their created time or modified time.
   fn get_field_revs(&self, field_ids: Option<Vec<String>>) -> Fut<Vec<Arc<FieldRevision>>> {
-    self.editor_delegate.get_field_revs(field_ids)
+    let editor_delegate = self.editor_delegate.clone();
+    to_fut(async move {
+      let row_meta_field_revs = row_meta_field_revs()
+        .into_iter()
+        .filter(|field_rev| match &field_ids {
+          None => true,
+          Some(field_ids) => field_ids.contains(&field_rev.id),
+        })
+        .collect::<Vec<Arc<FieldRevision>>>();
+      let mut field_revs = editor_delegate.get_field_revs(field_ids).await;
+      field_revs.extend(row_meta_field_revs);
+      field_revs
+    })
   }
 
   fn get_blocks(&self) -> Fut<Vec<DatabaseBlockRowRevision>> {
@@ -208,6 +304,10 @@ impl FilterDelegate for DatabaseViewFilterDelegateImpl {
   fn get_row_rev(&self, row_id: &str) -> Fut<Option<(usize, Arc<RowRevision>)>> {
     self.editor_delegate.get_row_rev(row_id)
   }
+
+  fn get_week_settings(&self) -> WeekSettings {
+    self.editor_delegate.get_week_settings()
+  }
 }
 
 pub(crate) struct DatabaseViewSortDelegateImpl {
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, TextFilterConditionPB, TextFilterPB};
+  use crate::services::cell::{CellDataChangeset, TypeCellData};
+  use crate::services::field::{
+    attachment_ids_of_row, AttachmentCellChangeset, AttachmentPB, AttachmentTypeOptionPB,
+    TextFilter, TypeOptionCellDataFilter,
+  };
+  use database_model::{CellRevision, RowRevision};
+  use flowy_error::ErrorCode;
+
+  fn attachment(id: &str, name: &str) -> AttachmentPB {
+    AttachmentPB {
+      id: id.to_owned(),
+      name: name.to_owned(),
+      size: 1024,
+      created_at: 1647251762,
+    }
+  }
+
+  #[test]
+  fn attachment_cell_attach_and_remove_files_test() {
+    let type_option = AttachmentTypeOptionPB::default();
+    let changeset = AttachmentCellChangeset::from_inserted_attachments(vec![
+      attachment("file_1.png", "cat.png"),
+      attachment("file_2.pdf", "report.pdf"),
+      attachment("file_1.png", "cat.png"),
+    ]);
+    let (cell_str, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+    assert_eq!(cell_data.attachments.len(), 2);
+    assert_eq!(cell_data.file_names(), "cat.png, report.pdf");
+
+    let type_cell_data = TypeCellData::new(cell_str, FieldType::Attachment);
+    let changeset =
+      AttachmentCellChangeset::from_removed_attachment_ids(vec!["file_1.png".to_owned()]);
+    let (_, cell_data) = type_option
+      .apply_changeset(changeset, Some(type_cell_data))
+      .unwrap();
+    assert_eq!(
+      cell_data.attachments,
+      vec![attachment("file_2.pdf", "report.pdf")]
+    );
+  }
+
+  #[test]
+  fn attachment_ids_of_row_test() {
+    let type_option = AttachmentTypeOptionPB::default();
+    let mut row_rev = RowRevision::new("block_1");
+    for (field_id, attachment) in [
+      ("field_1", attachment("file_1.png", "cat.png")),
+      ("field_2", attachment("file_2.pdf", "report.pdf")),
+    ] {
+      let changeset = AttachmentCellChangeset::from_inserted_attachments(vec![attachment]);
+      let (cell_str, _) = type_option.apply_changeset(changeset, None).unwrap();
+      let type_cell_data = TypeCellData::new(cell_str, FieldType::Attachment);
+      row_rev.cells.insert(
+        field_id.to_owned(),
+        CellRevision::new(type_cell_data.to_json()),
+      );
+    }
+    let text_cell_data = TypeCellData::new("file_3.png".to_owned(), FieldType::RichText);
+    row_rev.cells.insert(
+      "field_3".to_owned(),
+      CellRevision::new(text_cell_data.to_json()),
+    );
+
+    let mut attachment_ids = attachment_ids_of_row(&row_rev);
+    attachment_ids.sort();
+    assert_eq!(attachment_ids, vec!["file_1.png", "file_2.pdf"]);
+  }
+
+  #[test]
+  fn attachment_max_file_size_test() {
+    let type_option = AttachmentTypeOptionPB {
+      max_file_size: 1024,
+    };
+    assert!(type_option.check_file_size(1024).is_ok());
+    assert_eq!(
+      type_option.check_file_size(1025).unwrap_err().code,
+      ErrorCode::AttachmentTooLarge.value()
+    );
+    assert!(AttachmentTypeOptionPB::default()
+      .check_file_size(u64::MAX)
+      .is_ok());
+  }
+
+  #[test]
+  fn attachment_filter_test() {
+    let type_option = AttachmentTypeOptionPB::default();
+    let changeset =
+      AttachmentCellChangeset::from_inserted_attachments(vec![attachment("file_1.png", "cat.png")]);
+    let (_, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+    let filter = TextFilter::from(TextFilterPB {
+      condition: TextFilterConditionPB::Contains,
+      content: "CAT".to_owned(),
+      case_sensitive: false,
+    });
+    assert!(type_option.apply_filter(&filter, &FieldType::Attachment, &cell_data));
+
+    let filter = TextFilter::from(TextFilterPB {
+      condition: TextFilterConditionPB::TextIsEmpty,
+      content: "".to_owned(),
+      case_sensitive: false,
+    });
+    assert!(!type_option.apply_filter(&filter, &FieldType::Attachment, &cell_data));
+  }
+}
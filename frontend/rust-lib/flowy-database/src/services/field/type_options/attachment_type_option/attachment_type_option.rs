@@ -0,0 +1,161 @@
+use crate::entities::FieldType;
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  AttachmentCellChangeset, AttachmentCellData, AttachmentCellDataPB, BoxTypeOptionBuilder,
+  TextFilter, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+  TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::{FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct AttachmentTypeOptionBuilder(AttachmentTypeOptionPB);
+impl_into_box_type_option_builder!(AttachmentTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(AttachmentTypeOptionBuilder, AttachmentTypeOptionPB);
+
+impl AttachmentTypeOptionBuilder {
+  pub fn max_file_size(mut self, max_file_size: i64) -> Self {
+    self.0.max_file_size = max_file_size;
+    self
+  }
+}
+
+impl TypeOptionBuilder for AttachmentTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Attachment
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The attachment cell references the files that are copied into the file storage of the
+/// application. The cell only keeps the metadata of the files.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct AttachmentTypeOptionPB {
+  /// The files that are larger than this size in bytes can't be attached. Zero means the size
+  /// is not limited
+  #[pb(index = 1)]
+  pub max_file_size: i64,
+}
+impl_type_option!(AttachmentTypeOptionPB, FieldType::Attachment);
+
+impl AttachmentTypeOptionPB {
+  /// Returns [FlowyError::attachment_too_large] if the file can't be attached to the cells.
+  pub fn check_file_size(&self, file_size: u64) -> FlowyResult<()> {
+    if self.max_file_size > 0 && file_size > self.max_file_size as u64 {
+      let msg = format!(
+        "The file should not be more than {} bytes, but it is {} bytes",
+        self.max_file_size, file_size
+      );
+      return Err(FlowyError::attachment_too_large().context(msg));
+    }
+    Ok(())
+  }
+}
+
+impl TypeOption for AttachmentTypeOptionPB {
+  type CellData = AttachmentCellData;
+  type CellChangeset = AttachmentCellChangeset;
+  type CellProtobufType = AttachmentCellDataPB;
+  type CellFilter = TextFilter;
+}
+
+impl TypeOptionTransform for AttachmentTypeOptionPB {}
+
+impl TypeOptionCellData for AttachmentTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    cell_data.into()
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    AttachmentCellData::from_cell_str(&cell_str)
+  }
+}
+
+impl CellDataDecoder for AttachmentTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_attachment() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.file_names()
+  }
+}
+
+impl CellDataChangeset for AttachmentTypeOptionPB {
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let mut cell_data = match type_cell_data {
+      Some(type_cell_data) if type_cell_data.is_attachment() => {
+        AttachmentCellData::from_cell_str(&type_cell_data.cell_str).unwrap_or_default()
+      },
+      _ => AttachmentCellData::default(),
+    };
+
+    for attachment in changeset.inserted_attachments {
+      if cell_data.get_attachment(&attachment.id).is_none() {
+        cell_data.attachments.push(attachment);
+      }
+    }
+
+    cell_data
+      .attachments
+      .retain(|attachment| !changeset.removed_attachment_ids.contains(&attachment.id));
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for AttachmentTypeOptionPB {
+  /// The filter matches the names of the attached files.
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_attachment() {
+      return true;
+    }
+
+    filter.is_visible(cell_data.file_names())
+  }
+}
+
+impl TypeOptionCellDataCompare for AttachmentTypeOptionPB {
+  /// The cells are sorted by the number of the attached files.
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    cell_data
+      .attachments
+      .len()
+      .cmp(&other_cell_data.attachments.len())
+  }
+}
@@ -0,0 +1,229 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::{CellIdPB, CellIdParams};
+use crate::services::cell::{
+  CellProtobufBlobParser, DecodedCellData, FromCellChangesetString, FromCellString,
+  ToCellChangesetString, TypeCellData,
+};
+use bytes::Bytes;
+use database_model::RowRevision;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, ErrorCode, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+/// A file that is attached to the cell. The file is copied into the file storage of the
+/// application, it's stored by its id.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ProtoBuf)]
+pub struct AttachmentPB {
+  /// The id of the file in the file storage
+  #[pb(index = 1)]
+  pub id: String,
+
+  /// The name of the original file
+  #[pb(index = 2)]
+  pub name: String,
+
+  /// The size of the file in bytes
+  #[pb(index = 3)]
+  pub size: i64,
+
+  #[pb(index = 4)]
+  pub created_at: i64,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct AttachmentCellDataPB {
+  #[pb(index = 1)]
+  pub attachments: Vec<AttachmentPB>,
+}
+
+impl From<AttachmentCellData> for AttachmentCellDataPB {
+  fn from(data: AttachmentCellData) -> Self {
+    Self {
+      attachments: data.attachments,
+    }
+  }
+}
+
+impl DecodedCellData for AttachmentCellDataPB {
+  type Object = AttachmentCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.attachments.is_empty()
+  }
+}
+
+/// The attachments of the cell in the order they were attached. It's stored in the cell as a
+/// json string.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AttachmentCellData {
+  pub attachments: Vec<AttachmentPB>,
+}
+
+impl AttachmentCellData {
+  pub fn get_attachment(&self, attachment_id: &str) -> Option<&AttachmentPB> {
+    self
+      .attachments
+      .iter()
+      .find(|attachment| attachment.id == attachment_id)
+  }
+
+  /// Returns the names of the attached files, separated by commas.
+  pub fn file_names(&self) -> String {
+    self
+      .attachments
+      .iter()
+      .map(|attachment| attachment.name.clone())
+      .collect::<Vec<String>>()
+      .join(", ")
+  }
+}
+
+/// Returns the ids of the files that are attached to the cells of the row, whatever the field
+/// of the cell is.
+pub fn attachment_ids_of_row(row_rev: &RowRevision) -> Vec<String> {
+  row_rev
+    .cells
+    .values()
+    .filter_map(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+    .filter(|type_cell_data| type_cell_data.is_attachment())
+    .filter_map(|type_cell_data| AttachmentCellData::from_cell_str(&type_cell_data.cell_str).ok())
+    .flat_map(|cell_data| cell_data.attachments)
+    .map(|attachment| attachment.id)
+    .collect()
+}
+
+impl FromCellString for AttachmentCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self> {
+    if s.is_empty() {
+      return Ok(Self::default());
+    }
+    serde_json::from_str::<AttachmentCellData>(s).map_err(internal_error)
+  }
+}
+
+impl ToString for AttachmentCellData {
+  fn to_string(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
+
+impl DecodedCellData for AttachmentCellData {
+  type Object = AttachmentCellData;
+
+  fn is_empty(&self) -> bool {
+    self.attachments.is_empty()
+  }
+}
+
+pub struct AttachmentCellDataParser();
+impl CellProtobufBlobParser for AttachmentCellDataParser {
+  type Object = AttachmentCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    AttachmentCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AttachmentCellChangeset {
+  pub inserted_attachments: Vec<AttachmentPB>,
+  pub removed_attachment_ids: Vec<String>,
+}
+
+impl AttachmentCellChangeset {
+  pub fn from_inserted_attachments(attachments: Vec<AttachmentPB>) -> Self {
+    Self {
+      inserted_attachments: attachments,
+      removed_attachment_ids: vec![],
+    }
+  }
+
+  pub fn from_removed_attachment_ids(attachment_ids: Vec<String>) -> Self {
+    Self {
+      inserted_attachments: vec![],
+      removed_attachment_ids: attachment_ids,
+    }
+  }
+}
+
+impl FromCellChangesetString for AttachmentCellChangeset {
+  fn from_changeset(changeset: String) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    serde_json::from_str::<AttachmentCellChangeset>(&changeset).map_err(internal_error)
+  }
+}
+
+impl ToCellChangesetString for AttachmentCellChangeset {
+  fn to_cell_changeset_str(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
+
+/// [AttachFilePayloadPB] copies the file at `file_path` into the file storage and attaches it
+/// to the cell.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct AttachFilePayloadPB {
+  #[pb(index = 1)]
+  pub cell_id: CellIdPB,
+
+  #[pb(index = 2)]
+  pub file_path: String,
+}
+
+pub struct AttachFileParams {
+  pub cell_id: CellIdParams,
+  pub file_path: String,
+}
+
+impl TryInto<AttachFileParams> for AttachFilePayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<AttachFileParams, Self::Error> {
+    let cell_id: CellIdParams = self.cell_id.try_into()?;
+    let file_path = NotEmptyStr::parse(self.file_path).map_err(|_| ErrorCode::InvalidData)?;
+    Ok(AttachFileParams {
+      cell_id,
+      file_path: file_path.0,
+    })
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct AttachmentIdPB {
+  #[pb(index = 1)]
+  pub cell_id: CellIdPB,
+
+  #[pb(index = 2)]
+  pub attachment_id: String,
+}
+
+pub struct AttachmentIdParams {
+  pub cell_id: CellIdParams,
+  pub attachment_id: String,
+}
+
+impl TryInto<AttachmentIdParams> for AttachmentIdPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<AttachmentIdParams, Self::Error> {
+    let cell_id: CellIdParams = self.cell_id.try_into()?;
+    let attachment_id =
+      NotEmptyStr::parse(self.attachment_id).map_err(|_| ErrorCode::InvalidData)?;
+    Ok(AttachmentIdParams {
+      cell_id,
+      attachment_id: attachment_id.0,
+    })
+  }
+}
+
+/// The attachment and the local path of its file in the file storage.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct ResolvedAttachmentPB {
+  #[pb(index = 1)]
+  pub attachment: AttachmentPB,
+
+  #[pb(index = 2)]
+  pub file_path: String,
+}
@@ -0,0 +1,7 @@
+#![allow(clippy::module_inception)]
+mod attachment_tests;
+mod attachment_type_option;
+mod attachment_type_option_entities;
+
+pub use attachment_type_option::*;
+pub use attachment_type_option_entities::*;
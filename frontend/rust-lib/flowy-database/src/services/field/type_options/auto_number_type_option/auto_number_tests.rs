@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, NumberFilterConditionPB, NumberFilterPB};
+  use crate::services::cell::{CellDataChangeset, CellDataDecoder, TypeCellData};
+  use crate::services::field::{
+    AutoNumberCellData, AutoNumberTypeOptionPB, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  };
+  use std::cmp::Ordering;
+
+  #[test]
+  fn auto_number_type_option_read_only_test() {
+    let type_option = AutoNumberTypeOptionPB::default();
+    let cell_rev = AutoNumberTypeOptionPB::cell_rev_from_number(7);
+    let type_cell_data = TypeCellData::try_from(&cell_rev).unwrap();
+    let (cell_str, cell_data) = type_option
+      .apply_changeset("42".to_owned(), Some(type_cell_data))
+      .unwrap();
+    assert_eq!(cell_str, "7");
+    assert_eq!(cell_data, AutoNumberCellData(Some(7)));
+
+    // The row without a number doesn't get one by editing its cell
+    let (cell_str, _) = type_option.apply_changeset("42".to_owned(), None).unwrap();
+    assert_eq!(cell_str, "");
+  }
+
+  #[test]
+  fn auto_number_type_option_format_test() {
+    let type_option = AutoNumberTypeOptionPB {
+      prefix: "TASK-".to_owned(),
+      next_number: 1,
+    };
+    assert_eq!(
+      type_option.decode_cell_data_to_str(AutoNumberCellData(Some(12))),
+      "TASK-12"
+    );
+    assert_eq!(
+      type_option.decode_cell_data_to_str(AutoNumberCellData(None)),
+      ""
+    );
+  }
+
+  #[test]
+  fn auto_number_type_option_sort_and_filter_test() {
+    let type_option = AutoNumberTypeOptionPB::default();
+    let (first, second) = (AutoNumberCellData(Some(2)), AutoNumberCellData(Some(10)));
+    assert_eq!(type_option.apply_cmp(&first, &second), Ordering::Less);
+
+    let filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::GreaterThan,
+      content: "5".to_owned(),
+      end_content: None,
+    };
+    assert!(!type_option.apply_filter(&filter, &FieldType::AutoNumber, &first));
+    assert!(type_option.apply_filter(&filter, &FieldType::AutoNumber, &second));
+  }
+}
@@ -0,0 +1,221 @@
+use crate::entities::{FieldType, NumberFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{
+  CellDataChangeset, CellDataDecoder, CellProtobufBlobParser, DecodedCellData, FromCellString,
+  TypeCellData,
+};
+use crate::services::field::{
+  default_order, BoxTypeOptionBuilder, NumberCellData, TypeOption, TypeOptionBuilder,
+  TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{
+  CellRevision, FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer,
+};
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, FlowyResult};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// The cell of the auto number field is a number that is given to the row when it's created,
+/// or when the field is created for the existing rows. The numbers increase monotonically and
+/// are never given twice, the duplicated and the imported rows get new numbers. The cells
+/// can't be edited.
+#[derive(Clone, Debug, Serialize, Deserialize, ProtoBuf)]
+pub struct AutoNumberTypeOptionPB {
+  /// The text shown before the number, e.g. "TASK-"
+  #[pb(index = 1)]
+  #[serde(default)]
+  pub prefix: String,
+
+  /// The number that is given to the next row
+  #[pb(index = 2)]
+  pub next_number: i64,
+}
+impl_type_option!(AutoNumberTypeOptionPB, FieldType::AutoNumber);
+
+impl std::default::Default for AutoNumberTypeOptionPB {
+  fn default() -> Self {
+    Self {
+      prefix: "".to_owned(),
+      next_number: 1,
+    }
+  }
+}
+
+impl AutoNumberTypeOptionPB {
+  pub fn cell_rev_from_number(number: i64) -> CellRevision {
+    let type_cell_data = TypeCellData::new(number.to_string(), FieldType::AutoNumber);
+    CellRevision::new(type_cell_data.to_json())
+  }
+}
+
+/// The number of the auto number cell, it's None if the row has no number yet.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AutoNumberCellData(pub Option<i64>);
+
+impl FromCellString for AutoNumberCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self> {
+    Ok(Self(s.trim().parse::<i64>().ok()))
+  }
+}
+
+impl ToString for AutoNumberCellData {
+  fn to_string(&self) -> String {
+    self.0.map(|number| number.to_string()).unwrap_or_default()
+  }
+}
+
+impl DecodedCellData for AutoNumberCellData {
+  type Object = AutoNumberCellData;
+
+  fn is_empty(&self) -> bool {
+    self.0.is_none()
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct AutoNumberCellDataPB {
+  #[pb(index = 1, one_of)]
+  pub number: Option<i64>,
+
+  /// The number with the prefix of the field
+  #[pb(index = 2)]
+  pub formatted: String,
+}
+
+pub struct AutoNumberCellDataParser();
+impl CellProtobufBlobParser for AutoNumberCellDataParser {
+  type Object = AutoNumberCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    AutoNumberCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+/// The changesets are ignored, see [AutoNumberTypeOptionPB].
+pub type AutoNumberCellChangeset = String;
+
+impl TypeOption for AutoNumberTypeOptionPB {
+  type CellData = AutoNumberCellData;
+  type CellChangeset = AutoNumberCellChangeset;
+  type CellProtobufType = AutoNumberCellDataPB;
+  type CellFilter = NumberFilterPB;
+}
+
+impl TypeOptionTransform for AutoNumberTypeOptionPB {}
+
+impl TypeOptionCellData for AutoNumberTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    AutoNumberCellDataPB {
+      number: cell_data.0,
+      formatted: self.decode_cell_data_to_str(cell_data),
+    }
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    AutoNumberCellData::from_cell_str(&cell_str)
+  }
+}
+
+impl CellDataDecoder for AutoNumberTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    // The cells of other field types are replaced by the numbers of their rows
+    if !decoded_field_type.is_auto_number() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    match cell_data.0 {
+      None => "".to_owned(),
+      Some(number) => format!("{}{}", self.prefix, number),
+    }
+  }
+}
+
+impl CellDataChangeset for AutoNumberTypeOptionPB {
+  fn apply_changeset(
+    &self,
+    _changeset: <Self as TypeOption>::CellChangeset,
+    type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    // The cell is read-only, it keeps the number of the row
+    let cell_str = type_cell_data
+      .filter(|type_cell_data| type_cell_data.is_auto_number())
+      .map(|type_cell_data| type_cell_data.cell_str)
+      .unwrap_or_default();
+    let cell_data = self.decode_type_option_cell_str(cell_str.clone())?;
+    Ok((cell_str, cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for AutoNumberTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_auto_number() {
+      return true;
+    }
+
+    let number_cell_data = match cell_data.0 {
+      None => NumberCellData::default(),
+      Some(number) => NumberCellData::from_decimal(Decimal::from(number)),
+    };
+    filter.is_visible(&number_cell_data)
+  }
+}
+
+impl TypeOptionCellDataCompare for AutoNumberTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    match (cell_data.0, other_cell_data.0) {
+      (Some(left), Some(right)) => left.cmp(&right),
+      (Some(_), None) => Ordering::Greater,
+      (None, Some(_)) => Ordering::Less,
+      (None, None) => default_order(),
+    }
+  }
+}
+
+#[derive(Default)]
+pub struct AutoNumberTypeOptionBuilder(AutoNumberTypeOptionPB);
+impl_into_box_type_option_builder!(AutoNumberTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(AutoNumberTypeOptionBuilder, AutoNumberTypeOptionPB);
+
+impl AutoNumberTypeOptionBuilder {
+  pub fn prefix(mut self, prefix: &str) -> Self {
+    self.0.prefix = prefix.to_owned();
+    self
+  }
+}
+
+impl TypeOptionBuilder for AutoNumberTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::AutoNumber
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
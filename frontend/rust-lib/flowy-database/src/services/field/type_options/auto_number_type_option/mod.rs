@@ -0,0 +1,5 @@
+#![allow(clippy::module_inception)]
+mod auto_number_tests;
+mod auto_number_type_option;
+
+pub use auto_number_type_option::*;
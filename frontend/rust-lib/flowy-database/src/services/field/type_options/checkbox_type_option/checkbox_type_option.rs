@@ -4,6 +4,7 @@ use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString,
 use crate::services::field::{
   default_order, BoxTypeOptionBuilder, CheckboxCellData, TypeOption, TypeOptionBuilder,
   TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+  CHECK, UNCHECK,
 };
 use bytes::Bytes;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
@@ -92,6 +93,11 @@ impl TypeOptionCellData for CheckboxTypeOptionPB {
   ) -> FlowyResult<<Self as TypeOption>::CellData> {
     CheckboxCellData::from_cell_str(&cell_str)
   }
+
+  fn is_stale_cell_str(&self, cell_str: &str) -> bool {
+    // The older versions saved the cell string as it was typed, e.g. "true" or "1".
+    cell_str != CHECK && cell_str != UNCHECK
+  }
 }
 
 impl CellDataDecoder for CheckboxTypeOptionPB {
@@ -138,6 +144,11 @@ impl TypeOptionCellDataFilter for CheckboxTypeOptionPB {
     }
     filter.is_visible(cell_data)
   }
+
+  /// The unchecked cell is empty
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    !cell_data.is_check()
+  }
 }
 
 impl TypeOptionCellDataCompare for CheckboxTypeOptionPB {
@@ -1,8 +1,22 @@
 use crate::entities::{DateFilterConditionPB, DateFilterPB};
-use chrono::NaiveDateTime;
+use crate::services::field::DateTimezone;
+use crate::services::setting::WeekSettings;
+use chrono::NaiveDate;
 
 impl DateFilterPB {
+  /// Compares the dates in UTC, see [DateFilterPB::is_visible_in].
   pub fn is_visible<T: Into<Option<i64>>>(&self, cell_timestamp: T) -> bool {
+    self.is_visible_in(&DateTimezone::Named(chrono_tz::UTC), cell_timestamp)
+  }
+
+  /// Compares the date of the cell with the dates of the filter, both taken in the `timezone`
+  /// of the field. A cell at 23:00 in Paris is on the next day in UTC.
+  pub fn is_visible_in<T: Into<Option<i64>>>(
+    &self,
+    timezone: &DateTimezone,
+    cell_timestamp: T,
+  ) -> bool {
+    let date_of = |timestamp: i64| timezone.naive_local(timestamp).map(|time| time.date());
     match cell_timestamp.into() {
       None => DateFilterConditionPB::DateIsEmpty == self.condition,
       Some(timestamp) => {
@@ -13,11 +27,21 @@ impl DateFilterPB {
           DateFilterConditionPB::DateIsEmpty => {
             return false;
           },
+          DateFilterConditionPB::DaysFromTodayIs
+          | DateFilterConditionPB::DaysFromTodayLessThan
+          | DateFilterConditionPB::DaysFromTodayGreaterThan => {
+            return self.is_visible_by_days(timezone.days_from_today(timestamp));
+          },
+          DateFilterConditionPB::DateIsThisWeek => {
+            return match date_of(timestamp) {
+              Some(cell_date) => self.is_in_week_of(timezone.now().date(), cell_date),
+              None => false,
+            };
+          },
           _ => {},
         }
 
-        let cell_time = NaiveDateTime::from_timestamp_opt(timestamp, 0);
-        let cell_date = cell_time.map(|time| time.date());
+        let cell_date = date_of(timestamp);
         match self.timestamp {
           None => {
             if self.start.is_none() {
@@ -28,17 +52,13 @@ impl DateFilterPB {
               return true;
             }
 
-            let start_time = NaiveDateTime::from_timestamp_opt(*self.start.as_ref().unwrap(), 0);
-            let start_date = start_time.map(|time| time.date());
-
-            let end_time = NaiveDateTime::from_timestamp_opt(*self.end.as_ref().unwrap(), 0);
-            let end_date = end_time.map(|time| time.date());
+            let start_date = date_of(*self.start.as_ref().unwrap());
+            let end_date = date_of(*self.end.as_ref().unwrap());
 
             cell_date >= start_date && cell_date <= end_date
           },
           Some(timestamp) => {
-            let expected_timestamp = NaiveDateTime::from_timestamp_opt(timestamp, 0);
-            let expected_date = expected_timestamp.map(|time| time.date());
+            let expected_date = date_of(timestamp);
 
             // We assume that the cell_timestamp doesn't contain hours, just day.
             match self.condition {
@@ -54,12 +74,35 @@ impl DateFilterPB {
       },
     }
   }
+
+  fn is_in_week_of(&self, today: NaiveDate, cell_date: NaiveDate) -> bool {
+    let first_day_of_week = self.first_day_of_week.unwrap_or_default() as i64;
+    let (first_day, last_day) = WeekSettings::new(first_day_of_week, None).week_of(today);
+    first_day <= cell_date && cell_date <= last_day
+  }
+
+  fn is_visible_by_days(&self, cell_days: Option<i64>) -> bool {
+    let (cell_days, expected_days) = match (cell_days, self.days) {
+      (Some(cell_days), Some(expected_days)) => (cell_days, expected_days),
+      (_, None) => return true,
+      (None, Some(_)) => return false,
+    };
+
+    match self.condition {
+      DateFilterConditionPB::DaysFromTodayIs => cell_days == expected_days,
+      DateFilterConditionPB::DaysFromTodayLessThan => cell_days < expected_days,
+      DateFilterConditionPB::DaysFromTodayGreaterThan => cell_days > expected_days,
+      _ => true,
+    }
+  }
 }
 
 #[cfg(test)]
 mod tests {
   #![allow(clippy::all)]
   use crate::entities::{DateFilterConditionPB, DateFilterPB};
+  use crate::services::field::DateTimezone;
+  use chrono::NaiveDate;
 
   #[test]
   fn date_filter_is_test() {
@@ -68,12 +111,34 @@ mod tests {
       timestamp: Some(1668387885),
       end: None,
       start: None,
+      days: None,
+      first_day_of_week: None,
     };
 
     for (val, visible) in vec![(1668387885, true), (1647251762, false)] {
       assert_eq!(filter.is_visible(val as i64), visible);
     }
   }
+  #[test]
+  fn date_filter_is_in_timezone_test() {
+    let filter = DateFilterPB {
+      condition: DateFilterConditionPB::DateIs,
+      timestamp: Some(1647259200), // Mon Mar 14 2022 12:00:00 GMT+0000
+      end: None,
+      start: None,
+      days: None,
+      first_day_of_week: None,
+    };
+
+    // Mon Mar 14 2022 23:00:00 GMT+0000 is on Mar 15 in Shanghai
+    let cell_timestamp = 1647298800;
+    assert!(filter.is_visible(cell_timestamp));
+    let shanghai = DateTimezone::from_id("Asia/Shanghai").unwrap();
+    assert!(!filter.is_visible_in(&shanghai, cell_timestamp));
+    let new_york = DateTimezone::from_id("America/New_York").unwrap();
+    assert!(filter.is_visible_in(&new_york, cell_timestamp));
+  }
+
   #[test]
   fn date_filter_before_test() {
     let filter = DateFilterPB {
@@ -81,6 +146,8 @@ mod tests {
       timestamp: Some(1668387885),
       start: None,
       end: None,
+      days: None,
+      first_day_of_week: None,
     };
 
     for (val, visible, msg) in vec![(1668387884, false, "1"), (1647251762, true, "2")] {
@@ -95,6 +162,8 @@ mod tests {
       timestamp: Some(1668387885),
       start: None,
       end: None,
+      days: None,
+      first_day_of_week: None,
     };
 
     for (val, visible) in vec![(1668387884, true), (1668387885, true)] {
@@ -108,6 +177,8 @@ mod tests {
       timestamp: Some(1668387885),
       start: None,
       end: None,
+      days: None,
+      first_day_of_week: None,
     };
 
     for (val, visible) in vec![(1668387888, false), (1668531885, true), (0, false)] {
@@ -122,6 +193,8 @@ mod tests {
       start: Some(1668272685), // 11/13
       end: Some(1668618285),   // 11/17
       timestamp: None,
+      days: None,
+      first_day_of_week: None,
     };
 
     for (val, visible, _msg) in vec![
@@ -140,10 +213,61 @@ mod tests {
       start: None,
       end: None,
       timestamp: None,
+      days: None,
+      first_day_of_week: None,
     };
 
     for (val, visible) in vec![(None, true), (Some(123), false)] {
       assert_eq!(filter.is_visible(val), visible);
     }
   }
+
+  #[test]
+  fn date_filter_this_week_test() {
+    let mut filter = DateFilterPB {
+      condition: DateFilterConditionPB::DateIsThisWeek,
+      start: None,
+      end: None,
+      timestamp: None,
+      days: None,
+      first_day_of_week: None,
+    };
+
+    // Wednesday
+    let today = NaiveDate::from_ymd_opt(2023, 3, 15).unwrap();
+    let sunday = NaiveDate::from_ymd_opt(2023, 3, 12).unwrap();
+    let next_sunday = NaiveDate::from_ymd_opt(2023, 3, 19).unwrap();
+    assert!(filter.is_in_week_of(today, sunday));
+    assert!(!filter.is_in_week_of(today, next_sunday));
+
+    filter.first_day_of_week = Some(1);
+    assert!(!filter.is_in_week_of(today, sunday));
+    assert!(filter.is_in_week_of(today, next_sunday));
+  }
+
+  #[test]
+  fn date_filter_days_from_today_test() {
+    let mut filter = DateFilterPB {
+      condition: DateFilterConditionPB::DaysFromTodayIs,
+      start: None,
+      end: None,
+      timestamp: None,
+      days: Some(-3),
+      first_day_of_week: None,
+    };
+
+    for (val, visible) in vec![(Some(-3), true), (Some(3), false), (None, false)] {
+      assert_eq!(filter.is_visible_by_days(val), visible);
+    }
+
+    filter.condition = DateFilterConditionPB::DaysFromTodayLessThan;
+    for (val, visible) in vec![(Some(-4), true), (Some(-3), false), (Some(0), false)] {
+      assert_eq!(filter.is_visible_by_days(val), visible);
+    }
+
+    filter.condition = DateFilterConditionPB::DaysFromTodayGreaterThan;
+    for (val, visible) in vec![(Some(-4), false), (Some(-3), false), (Some(0), true)] {
+      assert_eq!(filter.is_visible_by_days(val), visible);
+    }
+  }
 }
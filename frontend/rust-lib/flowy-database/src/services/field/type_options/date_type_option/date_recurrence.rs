@@ -0,0 +1,195 @@
+use crate::services::field::{timestamp_in, DateTimezone};
+use chrono::{Datelike, Duration, Local, Months, NaiveDateTime, TimeZone};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::{FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+/// The max number of occurrences that are expanded at once, it protects the calendar from the
+/// windows that span centuries.
+const MAX_OCCURRENCES: usize = 1000;
+
+/// The max number of dates that are checked at once. The occurrences before the window are
+/// skipped, so only a few of the checked dates are not in the window.
+const MAX_ITERATIONS: usize = MAX_OCCURRENCES + 16;
+
+/// More days than the range of the dates, the occurrences after it are out of range. It keeps
+/// the [Duration] of the occurrence from overflowing.
+const MAX_DAYS: u64 = 200_000_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum RecurrenceFrequencyPB {
+  Daily = 0,
+  Weekly = 1,
+  /// Repeats on the same day of the month. The day is clamped to the last day of the shorter
+  /// months, e.g. the 31st repeats on the 30th in April.
+  Monthly = 2,
+}
+
+impl std::default::Default for RecurrenceFrequencyPB {
+  fn default() -> Self {
+    RecurrenceFrequencyPB::Weekly
+  }
+}
+
+/// Repeats the date of the cell. The date of the cell is the first occurrence. The recurrence
+/// ends at the `until` or after `count` occurrences, whichever comes first, or never if neither
+/// of them is set.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ProtoBuf)]
+pub struct RecurrenceRulePB {
+  #[pb(index = 1)]
+  pub frequency: RecurrenceFrequencyPB,
+
+  /// Repeats every `interval` days, weeks or months
+  #[pb(index = 2)]
+  pub interval: u32,
+
+  /// The timestamp in seconds of the last possible occurrence
+  #[pb(index = 3, one_of)]
+  #[serde(default)]
+  pub until: Option<i64>,
+
+  /// The number of occurrences, including the first one
+  #[pb(index = 4, one_of)]
+  #[serde(default)]
+  pub count: Option<u32>,
+}
+
+impl std::default::Default for RecurrenceRulePB {
+  fn default() -> Self {
+    Self {
+      frequency: RecurrenceFrequencyPB::default(),
+      interval: 1,
+      until: None,
+      count: None,
+    }
+  }
+}
+
+impl RecurrenceRulePB {
+  pub fn validate(&self) -> FlowyResult<()> {
+    if self.interval == 0 {
+      return Err(FlowyError::invalid_data().context("The interval of the recurrence is zero"));
+    }
+    if self.count == Some(0) {
+      return Err(FlowyError::invalid_data().context("The count of the recurrence is zero"));
+    }
+    Ok(())
+  }
+
+  /// Returns the occurrences of the date `start` within [window_start, window_end], in the local
+  /// timezone, so that the occurrences keep their time across the daylight saving changes.
+  pub fn occurrences(&self, start: i64, window_start: i64, window_end: i64) -> Vec<i64> {
+    self.occurrences_in(&Local, start, window_start, window_end)
+  }
+
+  /// Same as [RecurrenceRulePB::occurrences] but in the timezone of the date field.
+  pub fn occurrences_in_timezone(
+    &self,
+    timezone: &DateTimezone,
+    start: i64,
+    window_start: i64,
+    window_end: i64,
+  ) -> Vec<i64> {
+    match timezone {
+      DateTimezone::Local => self.occurrences_in(&Local, start, window_start, window_end),
+      DateTimezone::Named(tz) => self.occurrences_in(tz, start, window_start, window_end),
+    }
+  }
+
+  pub(crate) fn occurrences_in<Tz: TimeZone>(
+    &self,
+    tz: &Tz,
+    start: i64,
+    window_start: i64,
+    window_end: i64,
+  ) -> Vec<i64> {
+    let start_date_time = match NaiveDateTime::from_timestamp_opt(start, 0) {
+      None => return vec![],
+      Some(naive) => tz.from_utc_datetime(&naive).naive_local(),
+    };
+    let interval = self.interval.max(1) as u64;
+    let count = self.count.map(|count| count as u64).unwrap_or(u64::MAX);
+    // The occurrences start at the date of the cell and end at the `until`
+    let window_start = window_start.max(start);
+    let window_end = window_end.min(self.until.unwrap_or(i64::MAX));
+    if window_start > window_end {
+      return vec![];
+    }
+
+    let first_index = self.index_before(tz, start_date_time, start, window_start);
+    let mut occurrences = vec![];
+    for index in (first_index..count).take(MAX_ITERATIONS) {
+      let timestamp = match index
+        .checked_mul(interval)
+        .and_then(|n| self.nth(tz, start_date_time, n))
+      {
+        None => break,
+        Some(timestamp) => timestamp,
+      };
+      if timestamp > window_end {
+        break;
+      }
+      if timestamp >= window_start {
+        occurrences.push(timestamp);
+        if occurrences.len() >= MAX_OCCURRENCES {
+          break;
+        }
+      }
+    }
+    occurrences
+  }
+
+  /// Returns the index of an occurrence before the `window_start`, so the occurrences before it
+  /// are skipped. An hour of the daylight saving is the only difference from the fixed length
+  /// days, and a few days from the fixed length months, so starting one occurrence earlier is
+  /// enough.
+  fn index_before<Tz: TimeZone>(
+    &self,
+    tz: &Tz,
+    start_date_time: NaiveDateTime,
+    start: i64,
+    window_start: i64,
+  ) -> u64 {
+    let interval = self.interval.max(1) as i64;
+    let index = match self.frequency {
+      RecurrenceFrequencyPB::Daily | RecurrenceFrequencyPB::Weekly => {
+        let days = if self.frequency == RecurrenceFrequencyPB::Daily {
+          1
+        } else {
+          7
+        };
+        window_start.saturating_sub(start) / (days * 24 * 60 * 60 * interval)
+      },
+      RecurrenceFrequencyPB::Monthly => match NaiveDateTime::from_timestamp_opt(window_start, 0) {
+        None => 0,
+        Some(naive) => {
+          let window_start = tz.from_utc_datetime(&naive).naive_local();
+          let months = (window_start.year() as i64 - start_date_time.year() as i64) * 12
+            + window_start.month0() as i64
+            - start_date_time.month0() as i64;
+          months / interval
+        },
+      },
+    };
+    index.saturating_sub(1).max(0) as u64
+  }
+
+  /// Returns the timestamp of the date that is `n` days, weeks or months after the start. None
+  /// if the date is out of range.
+  fn nth<Tz: TimeZone>(&self, tz: &Tz, start: NaiveDateTime, n: u64) -> Option<i64> {
+    let days = |days_per_unit: u64| {
+      n.checked_mul(days_per_unit)
+        .filter(|days| *days <= MAX_DAYS)
+        .map(|days| Duration::days(days as i64))
+    };
+    let naive = match self.frequency {
+      RecurrenceFrequencyPB::Daily => start.checked_add_signed(days(1)?)?,
+      RecurrenceFrequencyPB::Weekly => start.checked_add_signed(days(7)?)?,
+      RecurrenceFrequencyPB::Monthly => {
+        start.checked_add_months(Months::new(u32::try_from(n).ok()?))?
+      },
+    };
+    // The time might not exist or be ambiguous on the day of the daylight saving change
+    timestamp_in(tz, &naive)
+  }
+}
@@ -1,14 +1,17 @@
 #[cfg(test)]
 mod tests {
   use crate::entities::FieldType;
-  use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+  use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
 
   use crate::services::field::{
-    DateCellChangeset, DateFormat, DateTypeOptionPB, FieldBuilder, TimeFormat, TypeOptionCellData,
+    days_between, parse_natural_date, pin_timezone, DateCellChangeset, DateCellData, DateFormat,
+    DateLocale, DateTimezone, DateTypeOptionPB, FieldBuilder, NaturalDate, RecurrenceFrequencyPB,
+    RecurrenceRulePB, TimeFormat, TypeOptionCellData,
   };
   use chrono::format::strftime::StrftimeItems;
-  use chrono::{FixedOffset, NaiveDateTime};
+  use chrono::{FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
   use database_model::FieldRevision;
+  use flowy_error::ErrorCode;
   use strum::IntoEnumIterator;
 
   #[test]
@@ -226,6 +229,89 @@ mod tests {
     );
   }
 
+  #[test]
+  fn date_type_option_timezone_test() {
+    let field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+    // Mon Mar 14 2022 09:56:02 GMT+0000
+    let mut type_option = DateTypeOptionPB::default();
+    type_option.timezone_id = "Asia/Shanghai".to_owned();
+    assert_date(
+      &type_option,
+      1647251762,
+      None,
+      "Mar 14, 2022 17:56",
+      true,
+      &field_rev,
+    );
+
+    // The daylight saving time started on Mar 13 2022 in New York
+    type_option.timezone_id = "America/New_York".to_owned();
+    assert_date(
+      &type_option,
+      1647251762,
+      None,
+      "Mar 14, 2022 05:56",
+      true,
+      &field_rev,
+    );
+
+    // The time is taken in the timezone of the field. Fri May 27 2022 00:00:00 GMT+0000 is
+    // on May 26 in New York.
+    assert_date(
+      &type_option,
+      1653609600,
+      Some("20:00".to_owned()),
+      "May 26, 2022 20:00",
+      true,
+      &field_rev,
+    );
+  }
+
+  #[test]
+  fn date_type_option_invalid_timezone_test() {
+    assert_eq!(DateTimezone::from_id("").unwrap(), DateTimezone::Local);
+    assert_eq!(
+      DateTimezone::from_id("Europe/Paris").unwrap(),
+      DateTimezone::Named(chrono_tz::Europe::Paris)
+    );
+    assert!(DateTimezone::from_id("Mars/Olympus_Mons").is_err());
+
+    let mut type_option = DateTypeOptionPB::default();
+    type_option.timezone_id = "Mars/Olympus_Mons".to_owned();
+    assert_eq!(type_option.timezone(), DateTimezone::Local);
+  }
+
+  #[test]
+  fn date_type_option_timezone_serde_test() {
+    // The type options of the older versions have no timezone
+    let json = r#"{"date_format":"Friendly","time_format":"TwentyFourHour","include_time":false}"#;
+    let type_option = serde_json::from_str::<DateTypeOptionPB>(json).unwrap();
+    assert_eq!(type_option.timezone_id, "");
+    assert_eq!(type_option.timezone(), DateTimezone::Local);
+
+    let mut type_option = DateTypeOptionPB::default();
+    type_option.timezone_id = "Europe/Paris".to_owned();
+    let json = serde_json::to_string(&type_option).unwrap();
+    let type_option = serde_json::from_str::<DateTypeOptionPB>(&json).unwrap();
+    assert_eq!(type_option.timezone_id, "Europe/Paris");
+  }
+
+  #[test]
+  fn date_type_option_pin_timezone_test() {
+    let mut field_rev = FieldBuilder::from_field_type(&FieldType::DateTime).build();
+    assert!(pin_timezone(&mut field_rev, "Europe/Paris"));
+    // The timezone of the creator is kept
+    assert!(!pin_timezone(&mut field_rev, "Asia/Shanghai"));
+    let type_option = field_rev
+      .get_type_option::<DateTypeOptionPB>(field_rev.ty)
+      .unwrap();
+    assert_eq!(type_option.timezone_id, "Europe/Paris");
+
+    // The timestamp fields have no timezone
+    let mut field_rev = FieldBuilder::from_field_type(&FieldType::CreatedTime).build();
+    assert!(!pin_timezone(&mut field_rev, "Europe/Paris"));
+  }
+
   #[test]
   fn utc_to_native_test() {
     let native_timestamp = 1647251762;
@@ -252,6 +338,352 @@ mod tests {
     assert_eq!(china_local_time, "03/14/2022 05:56 PM");
   }
 
+  #[test]
+  fn date_type_option_relative_days_test() {
+    let mut type_option = DateTypeOptionPB::default();
+    let cell_data = DateCellData {
+      timestamp: Some(Local::now().timestamp()),
+      include_time: false,
+      recurrence: None,
+    };
+    let cell_data_pb = type_option.convert_to_protobuf(cell_data.clone());
+    assert_eq!(cell_data_pb.relative_days, None);
+
+    type_option.show_relative_days = true;
+    let cell_data_pb = type_option.convert_to_protobuf(cell_data);
+    assert_eq!(cell_data_pb.relative_days, Some(0));
+  }
+
+  #[test]
+  fn days_between_test() {
+    // Mon Mar 14 2022 12:00:00 GMT+0000
+    let timestamp = 1647259200;
+    let today = NaiveDate::from_ymd_opt(2022, 3, 10).unwrap();
+    assert_eq!(days_between(today, timestamp), Some(4));
+
+    let today = NaiveDate::from_ymd_opt(2022, 3, 20).unwrap();
+    assert_eq!(days_between(today, timestamp), Some(-6));
+  }
+
+  #[test]
+  fn natural_date_test() {
+    // Wed Mar 15 2023 10:00:00
+    let now = NaiveDate::from_ymd_opt(2023, 3, 15)
+      .unwrap()
+      .and_hms_opt(10, 0, 0)
+      .unwrap();
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let time = |h, m| Some(NaiveTime::from_hms_opt(h, m, 0).unwrap());
+
+    for (text, locale, expected) in [
+      ("today", DateLocale::English, (date(2023, 3, 15), None)),
+      (
+        "Tomorrow 5pm",
+        DateLocale::English,
+        (date(2023, 3, 16), time(17, 0)),
+      ),
+      (
+        "next friday",
+        DateLocale::English,
+        (date(2023, 3, 17), None),
+      ),
+      ("wednesday", DateLocale::English, (date(2023, 3, 22), None)),
+      (
+        "the day after tomorrow at 9:30 am",
+        DateLocale::English,
+        (date(2023, 3, 17), time(9, 30)),
+      ),
+      ("in 2 weeks", DateLocale::English, (date(2023, 3, 29), None)),
+      ("12am", DateLocale::English, (date(2023, 3, 15), time(0, 0))),
+      (
+        "demain à 17h30",
+        DateLocale::French,
+        (date(2023, 3, 16), time(17, 30)),
+      ),
+      (
+        "vendredi prochain",
+        DateLocale::French,
+        (date(2023, 3, 17), None),
+      ),
+      (
+        "nächsten Montag um 8 Uhr",
+        DateLocale::German,
+        (date(2023, 3, 20), time(8, 0)),
+      ),
+      (
+        "pasado mañana a las 18:00",
+        DateLocale::Spanish,
+        (date(2023, 3, 17), time(18, 0)),
+      ),
+    ] {
+      let natural_date = parse_natural_date(text, locale, now).unwrap();
+      assert_eq!((natural_date.date, natural_date.time), expected, "{}", text);
+    }
+
+    for (text, locale) in [
+      ("", DateLocale::English),
+      ("next", DateLocale::English),
+      ("tomorrow 5", DateLocale::English),
+      ("13pm", DateLocale::English),
+      ("lunch tomorrow", DateLocale::English),
+      ("demain", DateLocale::English),
+      // The dates out of range are not dates
+      ("in 999999999 days", DateLocale::English),
+      ("in 9223372036854775807 days", DateLocale::English),
+      ("in 9223372036854775807 weeks", DateLocale::English),
+      ("in 99999999 weeks", DateLocale::English),
+    ] {
+      assert!(parse_natural_date(text, locale, now).is_none(), "{}", text);
+    }
+  }
+
+  #[test]
+  fn date_locale_from_tag_test() {
+    assert_eq!(DateLocale::from_tag("fr-FR"), DateLocale::French);
+    assert_eq!(DateLocale::from_tag("de_DE"), DateLocale::German);
+    assert_eq!(DateLocale::from_tag("ES"), DateLocale::Spanish);
+    assert_eq!(DateLocale::from_tag("ja-JP"), DateLocale::English);
+    assert_eq!(DateLocale::from_tag(""), DateLocale::English);
+  }
+
+  #[test]
+  fn date_type_option_natural_date_changeset_test() {
+    let type_option = DateTypeOptionPB::default();
+    let changeset = |text: &str| DateCellChangeset {
+      date: Some("1647251762".to_owned()),
+      time: None,
+      include_time: None,
+      is_utc: false,
+      text: Some(text.to_owned()),
+      locale: Some("en-US".to_owned()),
+      recurrence: None,
+      clear_recurrence: false,
+    };
+
+    // 2022-03-14 23:59:59, the natural dates are relative to the same day until the end
+    let now = NaiveDate::from_ymd_opt(2022, 3, 14)
+      .unwrap()
+      .and_hms_opt(23, 59, 59)
+      .unwrap();
+    let (_, cell_data) = type_option
+      .apply_changeset_at(changeset("tomorrow 5pm"), None, now)
+      .unwrap();
+    let expected = NaturalDate {
+      date: NaiveDate::from_ymd_opt(2022, 3, 15).unwrap(),
+      time: NaiveTime::from_hms_opt(17, 0, 0),
+    };
+    assert_eq!(cell_data.timestamp, Some(expected.timestamp()));
+    assert!(cell_data.include_time);
+
+    let error = type_option
+      .apply_changeset_at(changeset("sometime soon"), None, now)
+      .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidDateTimeFormat.value());
+
+    let error = type_option
+      .apply_changeset_at(changeset("in 999999999 days"), None, now)
+      .unwrap_err();
+    assert_eq!(error.code, ErrorCode::InvalidDateTimeFormat.value());
+  }
+
+  #[test]
+  fn date_recurrence_occurrences_test() {
+    let day = 24 * 60 * 60;
+    // 2023-01-31 09:00:00 UTC
+    let start = 1675155600;
+    let mut rule = RecurrenceRulePB {
+      frequency: RecurrenceFrequencyPB::Daily,
+      interval: 2,
+      until: None,
+      count: None,
+    };
+    assert_eq!(
+      rule.occurrences_in(&Utc, start, start, start + 5 * day),
+      vec![start, start + 2 * day, start + 4 * day]
+    );
+    // The occurrences before the window are skipped
+    assert_eq!(
+      rule.occurrences_in(&Utc, start, start + 999 * day, start + 1001 * day),
+      vec![start + 1000 * day]
+    );
+
+    rule.count = Some(2);
+    assert_eq!(
+      rule.occurrences_in(&Utc, start, start, start + 10 * day),
+      vec![start, start + 2 * day]
+    );
+
+    rule.frequency = RecurrenceFrequencyPB::Weekly;
+    rule.interval = 1;
+    rule.count = None;
+    rule.until = Some(start + 14 * day);
+    assert_eq!(
+      rule.occurrences_in(&Utc, start, start - 30 * day, start + 30 * day),
+      vec![start, start + 7 * day, start + 14 * day]
+    );
+
+    // The 31st is clamped to the last day of the shorter months
+    rule.frequency = RecurrenceFrequencyPB::Monthly;
+    rule.until = None;
+    rule.count = Some(3);
+    let dates = rule
+      .occurrences_in(&Utc, start, start, start + 365 * day)
+      .into_iter()
+      .map(|timestamp| {
+        NaiveDateTime::from_timestamp_opt(timestamp, 0)
+          .unwrap()
+          .format("%Y-%m-%d %H:%M")
+          .to_string()
+      })
+      .collect::<Vec<_>>();
+    assert_eq!(
+      dates,
+      vec!["2023-01-31 09:00", "2023-02-28 09:00", "2023-03-31 09:00"]
+    );
+  }
+
+  #[test]
+  fn date_recurrence_out_of_range_test() {
+    let day = 24 * 60 * 60;
+    let start = 1675155600;
+    for frequency in [
+      RecurrenceFrequencyPB::Daily,
+      RecurrenceFrequencyPB::Weekly,
+      RecurrenceFrequencyPB::Monthly,
+    ] {
+      let rule = RecurrenceRulePB {
+        frequency,
+        interval: u32::MAX,
+        until: None,
+        count: None,
+      };
+      assert_eq!(
+        rule.occurrences_in(&Utc, start, i64::MIN, i64::MAX),
+        vec![start]
+      );
+
+      let rule = RecurrenceRulePB {
+        frequency,
+        interval: 1,
+        until: None,
+        count: None,
+      };
+      assert_eq!(
+        rule.occurrences_in(&Utc, start, i64::MIN, i64::MAX).len(),
+        1000
+      );
+      assert!(rule
+        .occurrences_in(&Utc, start, i64::MAX, i64::MAX)
+        .is_empty());
+      assert!(rule
+        .occurrences_in(&Utc, start, start + day, start)
+        .is_empty());
+    }
+
+    // The monthly occurrences before the window are skipped too. The window is from
+    // 2025-01-30 to 2025-02-19.
+    let rule = RecurrenceRulePB {
+      frequency: RecurrenceFrequencyPB::Monthly,
+      interval: 1,
+      until: None,
+      count: None,
+    };
+    let window_start = start + 730 * day;
+    let occurrences = rule.occurrences_in(&Utc, start, window_start, window_start + 20 * day);
+    // 2025-01-31 09:00:00 UTC
+    assert_eq!(occurrences, vec![1738314000]);
+  }
+
+  #[test]
+  fn date_recurrence_occurrences_in_timezone_test() {
+    let week = 7 * 24 * 60 * 60;
+    // Mon Mar 07 2022 10:00:00 in New York, before the daylight saving time
+    let start = 1646665200;
+    let rule = RecurrenceRulePB {
+      frequency: RecurrenceFrequencyPB::Weekly,
+      interval: 1,
+      until: None,
+      count: Some(2),
+    };
+    let timezone = DateTimezone::from_id("America/New_York").unwrap();
+    // The next occurrence is still at 10:00 in New York, an hour earlier in UTC
+    assert_eq!(
+      rule.occurrences_in_timezone(&timezone, start, start, start + 2 * week),
+      vec![start, start + week - 60 * 60]
+    );
+
+    let timezone = DateTimezone::from_id("UTC").unwrap();
+    assert_eq!(
+      rule.occurrences_in_timezone(&timezone, start, start, start + 2 * week),
+      vec![start, start + week]
+    );
+  }
+
+  #[test]
+  fn date_type_option_recurrence_changeset_test() {
+    let type_option = DateTypeOptionPB::default();
+    let rule = RecurrenceRulePB {
+      frequency: RecurrenceFrequencyPB::Weekly,
+      interval: 1,
+      until: None,
+      count: Some(4),
+    };
+    let changeset =
+      |recurrence: Option<RecurrenceRulePB>, clear_recurrence: bool| DateCellChangeset {
+        date: None,
+        time: None,
+        include_time: None,
+        is_utc: true,
+        text: None,
+        locale: None,
+        recurrence,
+        clear_recurrence,
+      };
+
+    let cell_data = DateCellData {
+      timestamp: Some(1675155600),
+      include_time: false,
+      recurrence: None,
+    };
+    let type_cell_data = TypeCellData::new(cell_data.to_string(), FieldType::DateTime);
+    let (cell_str, cell_data) = type_option
+      .apply_changeset(changeset(Some(rule.clone()), false), Some(type_cell_data))
+      .unwrap();
+    assert_eq!(cell_data.timestamp, Some(1675155600));
+    assert_eq!(cell_data.recurrence, Some(rule.clone()));
+
+    // The rule is kept when the date changes and it's saved with the cell
+    let type_cell_data = TypeCellData::new(cell_str, FieldType::DateTime);
+    let (cell_str, cell_data) = type_option
+      .apply_changeset(
+        DateCellChangeset {
+          date: Some("1675242000".to_owned()),
+          ..changeset(None, false)
+        },
+        Some(type_cell_data),
+      )
+      .unwrap();
+    assert_eq!(cell_data.recurrence, Some(rule.clone()));
+    assert_eq!(
+      DateCellData::from_cell_str(&cell_str).unwrap().recurrence,
+      Some(rule.clone())
+    );
+
+    let type_cell_data = TypeCellData::new(cell_str, FieldType::DateTime);
+    let (_, cell_data) = type_option
+      .apply_changeset(changeset(None, true), Some(type_cell_data))
+      .unwrap();
+    assert_eq!(cell_data.recurrence, None);
+
+    let invalid_rule = RecurrenceRulePB {
+      interval: 0,
+      ..rule
+    };
+    assert!(type_option
+      .apply_changeset(changeset(Some(invalid_rule), false), None)
+      .is_err());
+  }
+
   fn assert_date<T: ToString>(
     type_option: &DateTypeOptionPB,
     timestamp: T,
@@ -265,6 +697,10 @@ mod tests {
       time: include_time_str,
       is_utc: false,
       include_time: Some(include_time),
+      text: None,
+      locale: None,
+      recurrence: None,
+      clear_recurrence: false,
     };
     let (cell_str, _) = type_option.apply_changeset(changeset, None).unwrap();
 
@@ -0,0 +1,82 @@
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use flowy_error::{FlowyError, FlowyResult};
+
+/// The timezone in which the dates of a date field are displayed, filtered and repeated. The
+/// cells always store the UTC timestamps, so changing the timezone of the field doesn't change
+/// the cells.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateTimezone {
+  /// The timezone of the device. It's used by the fields that were created by the older
+  /// versions until they get migrated when their database is opened.
+  Local,
+  Named(Tz),
+}
+
+impl DateTimezone {
+  /// Returns the timezone of the IANA `id`, e.g. "Europe/Paris". The empty id is the timezone
+  /// of the device.
+  pub fn from_id(id: &str) -> FlowyResult<Self> {
+    if id.is_empty() {
+      return Ok(DateTimezone::Local);
+    }
+    id.parse::<Tz>()
+      .map(DateTimezone::Named)
+      .map_err(|_| FlowyError::invalid_data().context(format!("Unknown timezone: {}", id)))
+  }
+
+  /// Returns the IANA id of the timezone of the device. None if it can't be read or is not
+  /// known by the timezone database.
+  pub fn local_id() -> Option<String> {
+    iana_time_zone::get_timezone()
+      .ok()
+      .filter(|id| id.parse::<Tz>().is_ok())
+  }
+
+  /// Returns the date and time of the `timestamp` in this timezone.
+  pub fn naive_local(&self, timestamp: i64) -> Option<NaiveDateTime> {
+    let utc = NaiveDateTime::from_timestamp_opt(timestamp, 0)?;
+    let naive = match self {
+      DateTimezone::Local => Local.from_utc_datetime(&utc).naive_local(),
+      DateTimezone::Named(tz) => tz.from_utc_datetime(&utc).naive_local(),
+    };
+    Some(naive)
+  }
+
+  /// Returns the timestamp of the date and time in this timezone. On the day of the daylight
+  /// saving change, the earlier one is used if the time is ambiguous and the time an hour later
+  /// is used if the time doesn't exist.
+  pub fn timestamp(&self, naive: &NaiveDateTime) -> Option<i64> {
+    match self {
+      DateTimezone::Local => timestamp_in(&Local, naive),
+      DateTimezone::Named(tz) => timestamp_in(tz, naive),
+    }
+  }
+
+  pub fn now(&self) -> NaiveDateTime {
+    self
+      .naive_local(Utc::now().timestamp())
+      .unwrap_or_else(|| Local::now().naive_local())
+  }
+
+  /// Returns the number of days from today to the date of the `timestamp`. It's negative if
+  /// the date is in the past.
+  pub fn days_from_today(&self, timestamp: i64) -> Option<i64> {
+    self.days_between(self.now().date(), timestamp)
+  }
+
+  pub(crate) fn days_between(&self, today: NaiveDate, timestamp: i64) -> Option<i64> {
+    let date = self.naive_local(timestamp)?.date();
+    Some(date.signed_duration_since(today).num_days())
+  }
+}
+
+pub(crate) fn timestamp_in<T: TimeZone>(tz: &T, naive: &NaiveDateTime) -> Option<i64> {
+  tz.from_local_datetime(naive)
+    .earliest()
+    .or_else(|| {
+      let naive = naive.checked_add_signed(Duration::hours(1))?;
+      tz.from_local_datetime(&naive).earliest()
+    })
+    .map(|date_time| date_time.timestamp())
+}
@@ -3,12 +3,12 @@ use crate::impl_type_option;
 use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
 use crate::services::field::{
   default_order, BoxTypeOptionBuilder, DateCellChangeset, DateCellData, DateCellDataPB, DateFormat,
-  TimeFormat, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
-  TypeOptionCellDataFilter, TypeOptionTransform,
+  DateTimezone, TimeFormat, TypeOption, TypeOptionBuilder, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
 };
 use bytes::Bytes;
 use chrono::format::strftime::StrftimeItems;
-use chrono::{Local, NaiveDateTime};
+use chrono::{NaiveDate, NaiveDateTime};
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
 use flowy_derive::ProtoBuf;
 use flowy_error::{ErrorCode, FlowyError, FlowyResult};
@@ -26,6 +26,18 @@ pub struct DateTypeOptionPB {
 
   #[pb(index = 3)]
   pub include_time: bool,
+
+  /// Exposes the number of days from today to the date of the cell, see
+  /// [DateCellDataPB::relative_days]. The cells are sorted by it instead of the timestamp.
+  #[pb(index = 4)]
+  #[serde(default)]
+  pub show_relative_days: bool,
+
+  /// The IANA id of the timezone that the dates are displayed, filtered and repeated in, e.g.
+  /// "Europe/Paris". Empty means the timezone of the device, see [DateTimezone].
+  #[pb(index = 5)]
+  #[serde(default)]
+  pub timezone_id: String,
 }
 impl_type_option!(DateTypeOptionPB, FieldType::DateTime);
 
@@ -50,6 +62,14 @@ impl TypeOptionCellData for DateTypeOptionPB {
   ) -> FlowyResult<<Self as TypeOption>::CellData> {
     DateCellData::from_cell_str(&cell_str)
   }
+
+  fn is_stale_cell_str(&self, cell_str: &str) -> bool {
+    // The older versions saved the timestamp as a plain integer.
+    !matches!(
+      serde_json::from_str::<serde_json::Value>(cell_str),
+      Ok(serde_json::Value::Object(_))
+    )
+  }
 }
 
 impl DateTypeOptionPB {
@@ -58,22 +78,27 @@ impl DateTypeOptionPB {
     Self::default()
   }
 
-  fn today_desc_from_timestamp(&self, cell_data: DateCellData) -> DateCellDataPB {
+  /// Returns the timezone of the field. The invalid timezone id falls back to the timezone of
+  /// the device.
+  pub fn timezone(&self) -> DateTimezone {
+    DateTimezone::from_id(&self.timezone_id).unwrap_or_else(|err| {
+      tracing::warn!("{:?}, the local timezone is used", err);
+      DateTimezone::Local
+    })
+  }
+
+  pub(crate) fn today_desc_from_timestamp(&self, cell_data: DateCellData) -> DateCellDataPB {
     let timestamp = cell_data.timestamp.unwrap_or_default();
     if timestamp == 0 {
       return DateCellDataPB::default();
     }
 
     let include_time = cell_data.include_time;
-    let native = chrono::NaiveDateTime::from_timestamp_opt(timestamp, 0);
-    if native.is_none() {
-      return DateCellDataPB::default();
-    }
-
-    // Use the local timezone to calculate the formatted date string. We can use the timezone that
-    // specified by the user in the future.
-    let offset = Local::now().offset().clone();
-    let native = chrono::DateTime::<chrono::Local>::from_utc(native.unwrap(), offset);
+    let timezone = self.timezone();
+    let native = match timezone.naive_local(timestamp) {
+      None => return DateCellDataPB::default(),
+      Some(native) => native,
+    };
     let fmt = self.date_format.format_str();
     let date = format!("{}", native.format_with_items(StrftimeItems::new(fmt)));
 
@@ -84,11 +109,19 @@ impl DateTypeOptionPB {
       "".to_string()
     };
 
+    let relative_days = if self.show_relative_days {
+      timezone.days_from_today(timestamp)
+    } else {
+      None
+    };
+
     DateCellDataPB {
       date,
       time,
       include_time,
       timestamp,
+      relative_days,
+      recurrence: cell_data.recurrence,
     }
   }
 
@@ -99,16 +132,21 @@ impl DateTypeOptionPB {
   ) -> FlowyResult<i64> {
     if let Some(time_str) = time_str.as_ref() {
       if !time_str.is_empty() {
-        let offset = Local::now().offset().clone();
         let naive_time = chrono::NaiveTime::parse_from_str(time_str, self.time_format.format_str());
 
         return match naive_time {
           Ok(naive_time) => {
-            let naive = chrono::DateTime::<Local>::from_utc(naive_date, offset)
-              .date_naive()
+            let timezone = self.timezone();
+            let naive = timezone
+              .naive_local(naive_date.timestamp())
+              .unwrap_or(naive_date)
+              .date()
               .and_time(naive_time);
-            let local = chrono::DateTime::<Local>::from_local(naive, offset);
-            Ok(local.timestamp())
+            Ok(
+              timezone
+                .timestamp(&naive)
+                .unwrap_or_else(|| naive.timestamp()),
+            )
           },
           Err(_e) => {
             let msg = format!("Parse {} failed", time_str);
@@ -122,6 +160,42 @@ impl DateTypeOptionPB {
   }
 }
 
+/// Returns the number of days from today to the date of the `timestamp` in the local timezone.
+/// It's negative if the date is in the past.
+pub fn days_from_today(timestamp: i64) -> Option<i64> {
+  DateTimezone::Local.days_from_today(timestamp)
+}
+
+pub(crate) fn days_between(today: NaiveDate, timestamp: i64) -> Option<i64> {
+  DateTimezone::Local.days_between(today, timestamp)
+}
+
+/// Stores the timezone of the device in the date field if it has none, so the dates of the field
+/// keep being displayed in the timezone of the user who created it when the database is opened on
+/// a device in another timezone. It's called when a date field is created or a field is switched
+/// to a date field. Returns false if the field isn't a date field or already has a timezone.
+pub fn pin_creator_timezone(field_rev: &mut FieldRevision) -> bool {
+  match DateTimezone::local_id() {
+    None => false,
+    Some(timezone_id) => pin_timezone(field_rev, &timezone_id),
+  }
+}
+
+pub(crate) fn pin_timezone(field_rev: &mut FieldRevision, timezone_id: &str) -> bool {
+  if FieldType::from(field_rev.ty) != FieldType::DateTime {
+    return false;
+  }
+  let mut type_option = field_rev
+    .get_type_option::<DateTypeOptionPB>(field_rev.ty)
+    .unwrap_or_default();
+  if !type_option.timezone_id.is_empty() {
+    return false;
+  }
+  type_option.timezone_id = timezone_id.to_owned();
+  field_rev.insert_type_option(&type_option);
+  true
+}
+
 impl TypeOptionTransform for DateTypeOptionPB {}
 
 impl CellDataDecoder for DateTypeOptionPB {
@@ -153,11 +227,28 @@ impl CellDataChangeset for DateTypeOptionPB {
     changeset: <Self as TypeOption>::CellChangeset,
     type_cell_data: Option<TypeCellData>,
   ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
-    let (timestamp, include_time) = match type_cell_data {
-      None => (None, false),
+    self.apply_changeset_at(changeset, type_cell_data, self.timezone().now())
+  }
+}
+
+impl DateTypeOptionPB {
+  /// Applies the changeset as if the current time in the timezone of the field is `now`. The
+  /// natural dates of the changeset, e.g. tomorrow, are relative to it.
+  pub(crate) fn apply_changeset_at(
+    &self,
+    changeset: DateCellChangeset,
+    type_cell_data: Option<TypeCellData>,
+    now: NaiveDateTime,
+  ) -> FlowyResult<(String, DateCellData)> {
+    let (timestamp, include_time, recurrence) = match type_cell_data {
+      None => (None, false, None),
       Some(type_cell_data) => {
         let cell_data = DateCellData::from_cell_str(&type_cell_data.cell_str).unwrap_or_default();
-        (cell_data.timestamp, cell_data.include_time)
+        (
+          cell_data.timestamp,
+          cell_data.include_time,
+          cell_data.recurrence,
+        )
       },
     };
 
@@ -165,6 +256,24 @@ impl CellDataChangeset for DateTypeOptionPB {
       None => include_time,
       Some(include_time) => include_time,
     };
+    let recurrence = match (&changeset.recurrence, changeset.clear_recurrence) {
+      (_, true) => None,
+      (Some(rule), false) => {
+        rule.validate()?;
+        Some(rule.clone())
+      },
+      (None, false) => recurrence,
+    };
+    let timezone = self.timezone();
+    if let Some(natural_date) = changeset.natural_date(now)? {
+      let date_cell_data = DateCellData {
+        timestamp: Some(natural_date.timestamp_in(&timezone)),
+        include_time: include_time || natural_date.time.is_some(),
+        recurrence,
+      };
+      return Ok((date_cell_data.to_string(), date_cell_data));
+    }
+
     let timestamp = match changeset.date_timestamp() {
       None => timestamp,
       Some(date_timestamp) => match (include_time, changeset.time) {
@@ -184,6 +293,7 @@ impl CellDataChangeset for DateTypeOptionPB {
     let date_cell_data = DateCellData {
       timestamp,
       include_time,
+      recurrence,
     };
     Ok((date_cell_data.to_string(), date_cell_data))
   }
@@ -200,7 +310,11 @@ impl TypeOptionCellDataFilter for DateTypeOptionPB {
       return true;
     }
 
-    filter.is_visible(cell_data.timestamp)
+    filter.is_visible_in(&self.timezone(), cell_data.timestamp)
+  }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.timestamp.is_none()
   }
 }
 
@@ -211,6 +325,16 @@ impl TypeOptionCellDataCompare for DateTypeOptionPB {
     other_cell_data: &<Self as TypeOption>::CellData,
   ) -> Ordering {
     match (cell_data.timestamp, other_cell_data.timestamp) {
+      (Some(left), Some(right)) if self.show_relative_days => {
+        let timezone = self.timezone();
+        match (
+          timezone.days_from_today(left),
+          timezone.days_from_today(right),
+        ) {
+          (Some(left), Some(right)) if left != right => left.cmp(&right),
+          _ => default_order(),
+        }
+      },
       (Some(left), Some(right)) => left.cmp(&right),
       (Some(_), None) => Ordering::Greater,
       (None, Some(_)) => Ordering::Less,
@@ -234,6 +358,16 @@ impl DateTypeOptionBuilder {
     self.0.time_format = time_format;
     self
   }
+
+  pub fn show_relative_days(mut self, show_relative_days: bool) -> Self {
+    self.0.show_relative_days = show_relative_days;
+    self
+  }
+
+  pub fn timezone_id(mut self, timezone_id: &str) -> Self {
+    self.0.timezone_id = timezone_id.to_owned();
+    self
+  }
 }
 impl TypeOptionBuilder for DateTypeOptionBuilder {
   fn field_type(&self) -> FieldType {
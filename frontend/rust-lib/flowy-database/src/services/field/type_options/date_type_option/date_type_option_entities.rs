@@ -5,9 +5,11 @@ use crate::services::cell::{
   CellProtobufBlobParser, DecodedCellData, FromCellChangesetString, FromCellString,
   ToCellChangesetString,
 };
+use crate::services::field::{parse_natural_date, DateLocale, NaturalDate, RecurrenceRulePB};
 use bytes::Bytes;
+use chrono::NaiveDateTime;
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
-use flowy_error::{internal_error, FlowyResult};
+use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
 use serde::de::Visitor;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
@@ -25,6 +27,14 @@ pub struct DateCellDataPB {
 
   #[pb(index = 4)]
   pub include_time: bool,
+
+  /// The number of days from today to the date, negative if the date is in the past. It's only
+  /// set if the `show_relative_days` of the type option is true.
+  #[pb(index = 5, one_of)]
+  pub relative_days: Option<i64>,
+
+  #[pb(index = 6, one_of)]
+  pub recurrence: Option<RecurrenceRulePB>,
 }
 
 #[derive(Clone, Debug, Default, ProtoBuf)]
@@ -43,6 +53,23 @@ pub struct DateChangesetPB {
 
   #[pb(index = 5)]
   pub is_utc: bool,
+
+  /// The date typed by the user, like "tomorrow 5pm" or "next friday". It takes precedence over
+  /// the `date` and the `time`.
+  #[pb(index = 6, one_of)]
+  pub text: Option<String>,
+
+  /// The language tag of the `text`, like "en-US". Fallback to English if it's not set.
+  #[pb(index = 7, one_of)]
+  pub locale: Option<String>,
+
+  /// Repeats the date with the rule. It replaces the current rule of the cell.
+  #[pb(index = 8, one_of)]
+  pub recurrence: Option<RecurrenceRulePB>,
+
+  /// Removes the rule of the cell, the date doesn't repeat anymore.
+  #[pb(index = 9)]
+  pub clear_recurrence: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -51,6 +78,14 @@ pub struct DateCellChangeset {
   pub time: Option<String>,
   pub include_time: Option<bool>,
   pub is_utc: bool,
+  #[serde(default)]
+  pub text: Option<String>,
+  #[serde(default)]
+  pub locale: Option<String>,
+  #[serde(default)]
+  pub recurrence: Option<RecurrenceRulePB>,
+  #[serde(default)]
+  pub clear_recurrence: bool,
 }
 
 impl DateCellChangeset {
@@ -64,6 +99,22 @@ impl DateCellChangeset {
       None
     }
   }
+
+  /// Parses the `text` written in the natural language. Returns None if there is no `text`.
+  pub fn natural_date(&self, now: NaiveDateTime) -> FlowyResult<Option<NaturalDate>> {
+    let text = match self.text.as_ref() {
+      Some(text) if !text.trim().is_empty() => text,
+      _ => return Ok(None),
+    };
+    let locale = DateLocale::from_tag(self.locale.as_deref().unwrap_or_default());
+    match parse_natural_date(text, locale, now) {
+      Some(natural_date) => Ok(Some(natural_date)),
+      None => {
+        let msg = format!("Parse {} failed", text);
+        Err(FlowyError::new(ErrorCode::InvalidDateTimeFormat, &msg))
+      },
+    }
+  }
 }
 
 impl FromCellChangesetString for DateCellChangeset {
@@ -85,6 +136,9 @@ impl ToCellChangesetString for DateCellChangeset {
 pub struct DateCellData {
   pub timestamp: Option<i64>,
   pub include_time: bool,
+  /// Repeats the date, see [RecurrenceRulePB]. None if the date doesn't repeat.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub recurrence: Option<RecurrenceRulePB>,
 }
 
 impl<'de> serde::Deserialize<'de> for DateCellData {
@@ -110,6 +164,7 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
         Ok(DateCellData {
           timestamp: Some(value),
           include_time: false,
+          recurrence: None,
         })
       }
 
@@ -126,6 +181,7 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
       {
         let mut timestamp: Option<i64> = None;
         let mut include_time: Option<bool> = None;
+        let mut recurrence: Option<RecurrenceRulePB> = None;
 
         while let Some(key) = map.next_key()? {
           match key {
@@ -135,6 +191,9 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
             "include_time" => {
               include_time = map.next_value()?;
             },
+            "recurrence" => {
+              recurrence = map.next_value()?;
+            },
             _ => {},
           }
         }
@@ -144,6 +203,7 @@ impl<'de> serde::Deserialize<'de> for DateCellData {
         Ok(DateCellData {
           timestamp,
           include_time,
+          recurrence,
         })
       }
     }
@@ -0,0 +1,383 @@
+use crate::services::field::DateTimezone;
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime};
+
+/// The languages that the dates typed by the user can be written in. For example, "tomorrow
+/// 5pm", "demain 17h" or "nächsten freitag".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateLocale {
+  English,
+  French,
+  German,
+  Spanish,
+}
+
+impl std::default::Default for DateLocale {
+  fn default() -> Self {
+    DateLocale::English
+  }
+}
+
+impl DateLocale {
+  /// Accepts the language tags like `en`, `en-US` or `fr_FR`. Fallback to English if the
+  /// language is not supported.
+  pub fn from_tag(tag: &str) -> Self {
+    let language = tag
+      .split(|c| c == '-' || c == '_')
+      .next()
+      .unwrap_or_default()
+      .to_lowercase();
+    match language.as_str() {
+      "fr" => DateLocale::French,
+      "de" => DateLocale::German,
+      "es" => DateLocale::Spanish,
+      _ => DateLocale::English,
+    }
+  }
+
+  fn keywords(&self) -> &'static DateKeywords {
+    match self {
+      DateLocale::English => &ENGLISH,
+      DateLocale::French => &FRENCH,
+      DateLocale::German => &GERMAN,
+      DateLocale::Spanish => &SPANISH,
+    }
+  }
+}
+
+struct DateKeywords {
+  today: &'static [&'static str],
+  tomorrow: &'static [&'static str],
+  day_after_tomorrow: &'static [&'static str],
+  yesterday: &'static [&'static str],
+  /// The words that are skipped, like "next" in "next friday" or "at" in "at 5pm"
+  fillers: &'static [&'static str],
+  /// The word before the number of days or weeks, like "in" in "in 3 days"
+  within: &'static [&'static str],
+  days: &'static [&'static str],
+  weeks: &'static [&'static str],
+  /// The names of the weekdays, starting from Monday
+  weekdays: [&'static [&'static str]; 7],
+  /// The separators between the hour and the minute besides the colon, like "h" in "17h30"
+  hour_separators: &'static [&'static str],
+}
+
+const MERIDIEM_AM: [&str; 2] = ["am", "a.m."];
+const MERIDIEM_PM: [&str; 2] = ["pm", "p.m."];
+
+const ENGLISH: DateKeywords = DateKeywords {
+  today: &["today", "tonight"],
+  tomorrow: &["tomorrow", "tmr"],
+  day_after_tomorrow: &["day after tomorrow", "the day after tomorrow"],
+  yesterday: &["yesterday"],
+  fillers: &["next", "this", "on", "at", "coming"],
+  within: &["in"],
+  days: &["day", "days"],
+  weeks: &["week", "weeks"],
+  weekdays: [
+    &["monday", "mon"],
+    &["tuesday", "tue", "tues"],
+    &["wednesday", "wed"],
+    &["thursday", "thu", "thur", "thurs"],
+    &["friday", "fri"],
+    &["saturday", "sat"],
+    &["sunday", "sun"],
+  ],
+  hour_separators: &[],
+};
+
+const FRENCH: DateKeywords = DateKeywords {
+  today: &["aujourd'hui", "ce soir"],
+  tomorrow: &["demain"],
+  day_after_tomorrow: &[
+    "après-demain",
+    "apres-demain",
+    "après demain",
+    "apres demain",
+  ],
+  yesterday: &["hier"],
+  fillers: &[
+    "prochain",
+    "prochaine",
+    "ce",
+    "cette",
+    "le",
+    "à",
+    "a",
+    "vers",
+  ],
+  within: &["dans"],
+  days: &["jour", "jours"],
+  weeks: &["semaine", "semaines"],
+  weekdays: [
+    &["lundi"],
+    &["mardi"],
+    &["mercredi"],
+    &["jeudi"],
+    &["vendredi"],
+    &["samedi"],
+    &["dimanche"],
+  ],
+  hour_separators: &["h"],
+};
+
+const GERMAN: DateKeywords = DateKeywords {
+  today: &["heute"],
+  tomorrow: &["morgen"],
+  day_after_tomorrow: &["übermorgen", "uebermorgen"],
+  yesterday: &["gestern"],
+  fillers: &[
+    "nächsten",
+    "nächster",
+    "nächste",
+    "naechsten",
+    "am",
+    "um",
+    "diesen",
+    "kommenden",
+  ],
+  within: &["in"],
+  days: &["tag", "tage", "tagen"],
+  weeks: &["woche", "wochen"],
+  weekdays: [
+    &["montag"],
+    &["dienstag"],
+    &["mittwoch"],
+    &["donnerstag"],
+    &["freitag"],
+    &["samstag", "sonnabend"],
+    &["sonntag"],
+  ],
+  hour_separators: &["uhr", "h"],
+};
+
+const SPANISH: DateKeywords = DateKeywords {
+  today: &["hoy", "esta noche"],
+  tomorrow: &["mañana", "manana"],
+  day_after_tomorrow: &["pasado mañana", "pasado manana"],
+  yesterday: &["ayer"],
+  fillers: &[
+    "próximo",
+    "proximo",
+    "próxima",
+    "proxima",
+    "que viene",
+    "el",
+    "este",
+    "a las",
+    "a la",
+  ],
+  within: &["en", "dentro de"],
+  days: &["día", "días", "dia", "dias"],
+  weeks: &["semana", "semanas"],
+  weekdays: [
+    &["lunes"],
+    &["martes"],
+    &["miércoles", "miercoles"],
+    &["jueves"],
+    &["viernes"],
+    &["sábado", "sabado"],
+    &["domingo"],
+  ],
+  hour_separators: &["h"],
+};
+
+/// The max number of days that a relative date can be away from today. It's more than the
+/// range of [NaiveDate], so the dates out of range are rejected by the date itself.
+const MAX_DAYS: u64 = 200_000_000;
+
+/// The date and the optional time parsed from the text typed by the user.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NaturalDate {
+  pub date: NaiveDate,
+  pub time: Option<NaiveTime>,
+}
+
+impl NaturalDate {
+  /// Returns the timestamp of the date in the local timezone. The date without time is at the
+  /// start of the day.
+  pub fn timestamp(&self) -> i64 {
+    self.timestamp_in(&DateTimezone::Local)
+  }
+
+  pub fn timestamp_in(&self, timezone: &DateTimezone) -> i64 {
+    let time = self
+      .time
+      .unwrap_or_else(|| NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+    let naive = self.date.and_time(time);
+    timezone
+      .timestamp(&naive)
+      .unwrap_or_else(|| naive.timestamp())
+  }
+}
+
+/// Parses the dates like "today", "tomorrow 5pm", "next friday" or "in 3 days at 9:30" written
+/// in the `locale`. The relative dates are resolved against `now`. Returns None if any word of
+/// the text is not recognized, so the text that is not a date is never saved as one.
+pub fn parse_natural_date(
+  text: &str,
+  locale: DateLocale,
+  now: NaiveDateTime,
+) -> Option<NaturalDate> {
+  let text = text.trim().to_lowercase().replace(',', " ");
+  let tokens = text.split_whitespace().collect::<Vec<&str>>();
+  if tokens.is_empty() {
+    return None;
+  }
+
+  let keywords = locale.keywords();
+  let today = now.date();
+  let mut date: Option<NaiveDate> = None;
+  let mut time: Option<NaiveTime> = None;
+  let mut index = 0;
+  while index < tokens.len() {
+    let rest = &tokens[index..];
+    if let Some((days, len)) = match_relative_days(rest, keywords) {
+      date = Some(add_days(today, days)?);
+      index += len;
+    } else if let Some((weekday, len)) = match_weekday(rest, keywords) {
+      let days_ahead = (weekday + 7 - today.weekday().num_days_from_monday()) % 7;
+      let days_ahead = if days_ahead == 0 { 7 } else { days_ahead };
+      date = Some(add_days(today, days_ahead as i64)?);
+      index += len;
+    } else if let Some((days, len)) = match_days_within(rest, keywords) {
+      date = Some(add_days(today, days)?);
+      index += len;
+    } else if let Some((naive_time, len)) = match_time(rest, keywords) {
+      time = Some(naive_time);
+      index += len;
+    } else if let Some(len) = match_phrase(rest, keywords.fillers) {
+      index += len;
+    } else {
+      return None;
+    }
+  }
+
+  if date.is_none() && time.is_none() {
+    return None;
+  }
+
+  Some(NaturalDate {
+    date: date.unwrap_or(today),
+    time,
+  })
+}
+
+/// Returns the number of the tokens that match the longest phrase in `phrases`.
+fn match_phrase(tokens: &[&str], phrases: &[&str]) -> Option<usize> {
+  phrases
+    .iter()
+    .map(|phrase| phrase.split_whitespace().collect::<Vec<&str>>())
+    .filter(|words| tokens.starts_with(words))
+    .map(|words| words.len())
+    .max()
+}
+
+fn match_relative_days(tokens: &[&str], keywords: &DateKeywords) -> Option<(i64, usize)> {
+  [
+    (keywords.day_after_tomorrow, 2),
+    (keywords.tomorrow, 1),
+    (keywords.today, 0),
+    (keywords.yesterday, -1),
+  ]
+  .iter()
+  .filter_map(|(phrases, days)| match_phrase(tokens, phrases).map(|len| (*days, len)))
+  .max_by_key(|(_, len)| *len)
+}
+
+fn match_weekday(tokens: &[&str], keywords: &DateKeywords) -> Option<(u32, usize)> {
+  keywords
+    .weekdays
+    .iter()
+    .enumerate()
+    .find_map(|(weekday, names)| match_phrase(tokens, names).map(|len| (weekday as u32, len)))
+}
+
+fn match_days_within(tokens: &[&str], keywords: &DateKeywords) -> Option<(i64, usize)> {
+  let within_len = match_phrase(tokens, keywords.within)?;
+  let count = tokens.get(within_len)?.parse::<i64>().ok()?;
+  let unit = &tokens[within_len + 1..];
+  if let Some(len) = match_phrase(unit, keywords.days) {
+    Some((count, within_len + 1 + len))
+  } else {
+    let len = match_phrase(unit, keywords.weeks)?;
+    Some((count.checked_mul(7)?, within_len + 1 + len))
+  }
+}
+
+/// Returns the date that is `days` after the `date`, or None if it's out of the range of the
+/// dates, e.g. for "in 999999999 days".
+fn add_days(date: NaiveDate, days: i64) -> Option<NaiveDate> {
+  // Duration::days panics if the days don't fit in its milliseconds
+  if days.unsigned_abs() > MAX_DAYS {
+    return None;
+  }
+  date.checked_add_signed(Duration::days(days))
+}
+
+/// Matches the times like "5pm", "5:30 pm", "17:00", "17h30" or "17 uhr". The bare number is
+/// not treated as a time, it's too ambiguous.
+fn match_time(tokens: &[&str], keywords: &DateKeywords) -> Option<(NaiveTime, usize)> {
+  let mut len = 1;
+  let (clock, mut is_pm) = split_meridiem(tokens[0]);
+  if is_pm.is_none() {
+    if let Some(("", next_is_pm)) = tokens.get(1).map(|token| split_meridiem(token)) {
+      is_pm = next_is_pm;
+      len += 1;
+    }
+  }
+
+  let (hour, minute, has_separator) = match clock.split_once(':') {
+    Some((hour, minute)) => (hour, minute, true),
+    None => match keywords
+      .hour_separators
+      .iter()
+      .find_map(|separator| clock.split_once(separator))
+    {
+      Some((hour, minute)) => (hour, minute, true),
+      None
+        if is_pm.is_none()
+          && tokens
+            .get(1)
+            .map_or(false, |token| keywords.hour_separators.contains(token)) =>
+      {
+        len += 1;
+        (clock, "", true)
+      },
+      None => (clock, "", false),
+    },
+  };
+  if !has_separator && is_pm.is_none() {
+    return None;
+  }
+
+  let mut hour = hour.parse::<u32>().ok()?;
+  let minute = if minute.is_empty() {
+    0
+  } else {
+    minute.parse::<u32>().ok()?
+  };
+  if let Some(is_pm) = is_pm {
+    if !(1..=12).contains(&hour) {
+      return None;
+    }
+    hour = match (is_pm, hour) {
+      (false, 12) => 0,
+      (true, 12) => 12,
+      (true, hour) => hour + 12,
+      (false, hour) => hour,
+    };
+  }
+  NaiveTime::from_hms_opt(hour, minute, 0).map(|time| (time, len))
+}
+
+/// Splits the "am" or "pm" suffix from the token. The returned flag is true if it's "pm".
+fn split_meridiem(token: &str) -> (&str, Option<bool>) {
+  for (suffixes, is_pm) in [(MERIDIEM_AM, false), (MERIDIEM_PM, true)] {
+    for suffix in suffixes {
+      if let Some(clock) = token.strip_suffix(suffix) {
+        return (clock, Some(is_pm));
+      }
+    }
+  }
+  (token, None)
+}
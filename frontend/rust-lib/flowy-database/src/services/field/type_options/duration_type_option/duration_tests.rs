@@ -0,0 +1,103 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, NumberFilterConditionPB, NumberFilterPB};
+  use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+  use crate::services::field::{
+    sum_durations, DurationCellData, DurationFormatPB, DurationTypeOptionPB,
+    TypeOptionCellDataCompare, TypeOptionCellDataFilter, MAX_DURATION_SECONDS,
+  };
+  use flowy_error::ErrorCode;
+  use std::cmp::Ordering;
+
+  #[test]
+  fn duration_cell_parse_test() {
+    let type_option = DurationTypeOptionPB::default();
+    for (changeset, expected) in [
+      ("1h 30m", "5400"),
+      ("1h30m", "5400"),
+      ("1.5h", "5400"),
+      ("90 min", "5400"),
+      ("90:00", "5400"),
+      ("1:30:00", "5400"),
+      ("2d 4h", "187200"),
+      ("45s", "45"),
+      ("5400", "5400"),
+      ("", ""),
+    ] {
+      let (cell_str, _) = type_option
+        .apply_changeset(changeset.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected, "{}", changeset);
+    }
+
+    for changeset in [
+      "abc",
+      "1h 30",
+      "-5",
+      "1:75",
+      "1::00",
+      "3 weeks",
+      // Longer than the max duration
+      "1e30",
+      "99999999999999999999d",
+      "3153600001",
+    ] {
+      let err = type_option
+        .apply_changeset(changeset.to_owned(), None)
+        .unwrap_err();
+      assert_eq!(err.code, ErrorCode::InvalidDuration.value());
+    }
+  }
+
+  #[test]
+  fn duration_cell_format_test() {
+    for (format, expected) in [
+      (DurationFormatPB::Compact, "1d 2h 30m 5s"),
+      (DurationFormatPB::Clock, "26:30:05"),
+      (DurationFormatPB::DecimalHours, "26.5h"),
+    ] {
+      let type_option = DurationTypeOptionPB { format };
+      assert_eq!(
+        type_option.decode_cell_data_to_str(DurationCellData(Some(95405))),
+        expected
+      );
+    }
+    assert_eq!(DurationFormatPB::Compact.format(0), "0s");
+    assert_eq!(DurationFormatPB::DecimalHours.format(3600), "1h");
+  }
+
+  #[test]
+  fn duration_cell_sort_and_sum_test() {
+    let type_option = DurationTypeOptionPB::default();
+    let cells = vec![
+      DurationCellData(Some(5400)),
+      DurationCellData(None),
+      DurationCellData(Some(600)),
+    ];
+    assert_eq!(sum_durations(cells.iter()), 6000);
+
+    // The total saturates instead of overflowing
+    let long_cells = vec![
+      DurationCellData(Some(i64::MAX)),
+      DurationCellData(Some(MAX_DURATION_SECONDS)),
+    ];
+    assert_eq!(sum_durations(long_cells.iter()), i64::MAX);
+    assert_eq!(
+      type_option.apply_cmp(&cells[0], &cells[2]),
+      Ordering::Greater
+    );
+    assert_eq!(type_option.apply_cmp(&cells[1], &cells[2]), Ordering::Less);
+  }
+
+  #[test]
+  fn duration_cell_filter_test() {
+    let type_option = DurationTypeOptionPB::default();
+    let filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::GreaterThan,
+      content: "1h".to_owned(),
+      end_content: None,
+    };
+    assert!(type_option.apply_filter(&filter, &FieldType::Duration, &DurationCellData(Some(5400))));
+    assert!(!type_option.apply_filter(&filter, &FieldType::Duration, &DurationCellData(Some(600))));
+  }
+}
@@ -0,0 +1,158 @@
+use crate::entities::{FieldType, NumberFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, TypeCellData};
+use crate::services::field::{
+  default_order, BoxTypeOptionBuilder, DurationCellChangeset, DurationCellData, DurationCellDataPB,
+  DurationFormatPB, NumberCellData, TypeOption, TypeOptionBuilder, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct DurationTypeOptionBuilder(DurationTypeOptionPB);
+impl_into_box_type_option_builder!(DurationTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(DurationTypeOptionBuilder, DurationTypeOptionPB);
+
+impl DurationTypeOptionBuilder {
+  pub fn format(mut self, format: DurationFormatPB) -> Self {
+    self.0.format = format;
+    self
+  }
+}
+
+impl TypeOptionBuilder for DurationTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Duration
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The duration cell saves the number of the seconds, e.g. 5400 for "1h 30m", and displays it
+/// in the format of the field.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct DurationTypeOptionPB {
+  #[pb(index = 1)]
+  #[serde(default)]
+  pub format: DurationFormatPB,
+}
+impl_type_option!(DurationTypeOptionPB, FieldType::Duration);
+
+impl TypeOption for DurationTypeOptionPB {
+  type CellData = DurationCellData;
+  type CellChangeset = DurationCellChangeset;
+  type CellProtobufType = DurationCellDataPB;
+  type CellFilter = NumberFilterPB;
+}
+
+impl TypeOptionTransform for DurationTypeOptionPB {}
+
+impl TypeOptionCellData for DurationTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    DurationCellDataPB {
+      seconds: cell_data.0,
+      formatted: self.decode_cell_data_to_str(cell_data),
+    }
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    Ok(DurationCellData::parse(&cell_str).unwrap_or_default())
+  }
+}
+
+impl CellDataDecoder for DurationTypeOptionPB {
+  /// The cells of the number and the text fields are parsed as the durations, so the field
+  /// keeps its values when its type is switched to the duration.
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_duration()
+      && !decoded_field_type.is_number()
+      && !decoded_field_type.is_text()
+    {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    match cell_data.0 {
+      None => "".to_owned(),
+      Some(seconds) => self.format.format(seconds),
+    }
+  }
+}
+
+impl CellDataChangeset for DurationTypeOptionPB {
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let cell_data = DurationCellData::parse(&changeset)?;
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for DurationTypeOptionPB {
+  /// The content of the filter is parsed as a duration, e.g. "1h", and compared with the
+  /// seconds of the cell.
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_duration() {
+      return true;
+    }
+
+    let number_cell_data = match cell_data.0 {
+      None => NumberCellData::default(),
+      Some(seconds) => NumberCellData::from_decimal(Decimal::from(seconds)),
+    };
+    let to_seconds = |content: &String| match DurationCellData::parse(content) {
+      Ok(DurationCellData(Some(seconds))) => seconds.to_string(),
+      _ => content.clone(),
+    };
+    let filter = NumberFilterPB {
+      content: to_seconds(&filter.content),
+      end_content: filter.end_content.as_ref().map(to_seconds),
+      ..filter.clone()
+    };
+    filter.is_visible(&number_cell_data)
+  }
+}
+
+impl TypeOptionCellDataCompare for DurationTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    match (cell_data.0, other_cell_data.0) {
+      (Some(left), Some(right)) => left.cmp(&right),
+      (Some(_), None) => Ordering::Greater,
+      (None, Some(_)) => Ordering::Less,
+      (None, None) => default_order(),
+    }
+  }
+}
@@ -0,0 +1,226 @@
+use crate::services::cell::{CellProtobufBlobParser, DecodedCellData, FromCellString};
+use bytes::Bytes;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+const SECONDS_PER_MINUTE: i64 = 60;
+const SECONDS_PER_HOUR: i64 = 60 * SECONDS_PER_MINUTE;
+const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+
+/// The upper bound of a duration, 100 years in seconds. The longer durations are rejected when
+/// they are parsed, so the seconds always fit in an i64.
+pub const MAX_DURATION_SECONDS: i64 = 100 * 365 * SECONDS_PER_DAY;
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct DurationCellDataPB {
+  /// The number of the seconds, it's None if the cell is empty
+  #[pb(index = 1, one_of)]
+  pub seconds: Option<i64>,
+
+  /// The duration that is formatted by the [DurationFormatPB] of the field
+  #[pb(index = 2)]
+  pub formatted: String,
+}
+
+pub struct DurationCellDataParser();
+impl CellProtobufBlobParser for DurationCellDataParser {
+  type Object = DurationCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    DurationCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum DurationFormatPB {
+  /// 1h 30m
+  Compact = 0,
+  /// 1:30:00
+  Clock = 1,
+  /// 1.5h
+  DecimalHours = 2,
+}
+
+impl std::default::Default for DurationFormatPB {
+  fn default() -> Self {
+    DurationFormatPB::Compact
+  }
+}
+
+impl DurationFormatPB {
+  pub fn format(&self, seconds: i64) -> String {
+    match self {
+      DurationFormatPB::Compact => {
+        let mut parts = vec![];
+        let mut seconds = seconds;
+        for (unit, unit_seconds) in [
+          ("d", SECONDS_PER_DAY),
+          ("h", SECONDS_PER_HOUR),
+          ("m", SECONDS_PER_MINUTE),
+          ("s", 1),
+        ] {
+          if seconds >= unit_seconds {
+            parts.push(format!("{}{}", seconds / unit_seconds, unit));
+            seconds %= unit_seconds;
+          }
+        }
+        if parts.is_empty() {
+          "0s".to_owned()
+        } else {
+          parts.join(" ")
+        }
+      },
+      DurationFormatPB::Clock => format_clock(seconds, true),
+      DurationFormatPB::DecimalHours => {
+        let hours = format!("{:.2}", seconds as f64 / SECONDS_PER_HOUR as f64);
+        let hours = hours.trim_end_matches('0').trim_end_matches('.');
+        format!("{}h", hours)
+      },
+    }
+  }
+}
+
+/// Formats the seconds as "h:mm:ss", e.g. "1:05:05", or as "h:mm" if `show_seconds` is false.
+/// The hours are not wrapped at a day.
+pub fn format_clock(seconds: i64, show_seconds: bool) -> String {
+  let seconds = seconds.max(0);
+  let hours_minutes = format!(
+    "{}:{:02}",
+    seconds / SECONDS_PER_HOUR,
+    seconds % SECONDS_PER_HOUR / SECONDS_PER_MINUTE
+  );
+  if show_seconds {
+    format!("{}:{:02}", hours_minutes, seconds % SECONDS_PER_MINUTE)
+  } else {
+    hours_minutes
+  }
+}
+
+/// The number of the seconds of the duration cell, it's None if the cell is empty.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DurationCellData(pub Option<i64>);
+
+impl DurationCellData {
+  /// Parses the typed duration. The accepted inputs are:
+  /// * the units, e.g. "1h 30m", "1.5h", "2d 4h" or "45 min"
+  /// * the clock, "mm:ss" or "h:mm:ss", e.g. "90:00" or "1:30:00"
+  /// * a number of seconds, e.g. "5400"
+  ///
+  /// Returns [FlowyError::invalid_duration] if the input is not a duration, or if it's longer
+  /// than [MAX_DURATION_SECONDS].
+  pub fn parse(s: &str) -> FlowyResult<Self> {
+    let s = s.trim();
+    if s.is_empty() {
+      return Ok(Self(None));
+    }
+
+    let seconds = if s.contains(':') {
+      parse_clock(s)
+    } else if let Ok(seconds) = s.parse::<f64>() {
+      Some(seconds)
+    } else {
+      parse_units(s)
+    };
+    match seconds {
+      Some(seconds)
+        if seconds.is_finite()
+          && seconds >= 0.0
+          && seconds.round() <= MAX_DURATION_SECONDS as f64 =>
+      {
+        Ok(Self(Some(seconds.round() as i64)))
+      },
+      _ => Err(FlowyError::invalid_duration().context(format!("Invalid duration: {}", s))),
+    }
+  }
+}
+
+fn parse_clock(s: &str) -> Option<f64> {
+  let parts = s.split(':').map(str::trim).collect::<Vec<_>>();
+  if parts.len() > 3 || parts.iter().any(|part| part.is_empty()) {
+    return None;
+  }
+  let mut seconds = 0.0;
+  for (index, part) in parts.iter().enumerate() {
+    let value = part.parse::<f64>().ok()?;
+    // The minutes and the seconds after the first part are less than 60
+    if index > 0 && value >= 60.0 {
+      return None;
+    }
+    seconds = seconds * 60.0 + value;
+  }
+  Some(seconds)
+}
+
+fn parse_units(s: &str) -> Option<f64> {
+  let mut seconds = 0.0;
+  let mut chars = s.chars().peekable();
+  let mut has_unit = false;
+  loop {
+    while chars.peek().map_or(false, |c| c.is_whitespace()) {
+      chars.next();
+    }
+    if chars.peek().is_none() {
+      break;
+    }
+
+    let mut number = String::new();
+    while let Some(c) = chars.peek().filter(|c| c.is_ascii_digit() || **c == '.') {
+      number.push(*c);
+      chars.next();
+    }
+    while chars.peek().map_or(false, |c| c.is_whitespace()) {
+      chars.next();
+    }
+    let mut unit = String::new();
+    while let Some(c) = chars.peek().filter(|c| c.is_alphabetic()) {
+      unit.push(c.to_ascii_lowercase());
+      chars.next();
+    }
+
+    let value = number.parse::<f64>().ok()?;
+    let unit_seconds = match unit.as_str() {
+      "d" | "day" | "days" => SECONDS_PER_DAY,
+      "h" | "hr" | "hrs" | "hour" | "hours" => SECONDS_PER_HOUR,
+      "m" | "min" | "mins" | "minute" | "minutes" => SECONDS_PER_MINUTE,
+      "s" | "sec" | "secs" | "second" | "seconds" => 1,
+      _ => return None,
+    };
+    seconds += value * unit_seconds as f64;
+    has_unit = true;
+  }
+  has_unit.then_some(seconds)
+}
+
+impl FromCellString for DurationCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self> {
+    Ok(Self::parse(s).unwrap_or_default())
+  }
+}
+
+impl ToString for DurationCellData {
+  fn to_string(&self) -> String {
+    self
+      .0
+      .map(|seconds| seconds.to_string())
+      .unwrap_or_default()
+  }
+}
+
+impl DecodedCellData for DurationCellData {
+  type Object = DurationCellData;
+
+  fn is_empty(&self) -> bool {
+    self.0.is_none()
+  }
+}
+
+pub type DurationCellChangeset = String;
+
+/// Returns the total of the non-empty duration cells, in seconds. The total saturates at
+/// i64::MAX instead of overflowing.
+pub fn sum_durations<'a>(cells: impl Iterator<Item = &'a DurationCellData>) -> i64 {
+  cells
+    .flat_map(|cell_data| cell_data.0)
+    .fold(0, |sum, seconds| sum.saturating_add(seconds))
+}
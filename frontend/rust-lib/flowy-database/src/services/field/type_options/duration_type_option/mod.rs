@@ -0,0 +1,7 @@
+#![allow(clippy::module_inception)]
+mod duration_tests;
+mod duration_type_option;
+mod duration_type_option_entities;
+
+pub use duration_type_option::*;
+pub use duration_type_option_entities::*;
@@ -0,0 +1,44 @@
+use crate::entities::{EmailFilterConditionPB, EmailFilterPB};
+use crate::services::field::{EmailCellData, TextFilter};
+use crate::services::filter::FromFilterString;
+use database_model::FilterRevision;
+
+/// [EmailFilter] is the [EmailFilterPB] whose text filter is made once, it's cached by the
+/// filter controller instead of being made for each cell.
+#[derive(Debug, Clone)]
+pub struct EmailFilter {
+  condition: EmailFilterConditionPB,
+  text_filter: Option<TextFilter>,
+}
+
+impl EmailFilter {
+  pub fn is_visible(&self, cell_data: &EmailCellData) -> bool {
+    match self.condition {
+      EmailFilterConditionPB::EmailIsValid => cell_data.is_valid(),
+      EmailFilterConditionPB::EmailIsInvalid => !cell_data.is_empty() && !cell_data.is_valid(),
+      _ => self
+        .text_filter
+        .as_ref()
+        .map(|text_filter| text_filter.is_visible(cell_data))
+        .unwrap_or(true),
+    }
+  }
+}
+
+impl std::convert::From<EmailFilterPB> for EmailFilter {
+  fn from(filter: EmailFilterPB) -> Self {
+    Self {
+      text_filter: filter.text_filter().map(TextFilter::from),
+      condition: filter.condition,
+    }
+  }
+}
+
+impl FromFilterString for EmailFilter {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    Self::from(EmailFilterPB::from(filter_rev))
+  }
+}
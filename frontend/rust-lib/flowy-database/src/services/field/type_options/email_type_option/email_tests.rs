@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{EmailFilterConditionPB, EmailFilterPB, FieldType};
+  use crate::services::cell::CellDataChangeset;
+  use crate::services::field::{
+    EmailCellData, EmailFilter, EmailTypeOptionPB, FieldBuilder, TypeOptionCellDataFilter,
+    TypeOptionTransform,
+  };
+  use database_model::FilterRevision;
+  use flowy_error::ErrorCode;
+
+  #[test]
+  fn email_cell_changeset_test() {
+    let type_option = EmailTypeOptionPB::default();
+    for (changeset, expected) in [
+      (" nathan@AppFlowy.io ", "nathan@appflowy.io"),
+      // Only the domain is case-insensitive
+      ("Nathan.Dev@AppFlowy.IO", "Nathan.Dev@appflowy.io"),
+      (
+        "first.last+tag@mail.example.com",
+        "first.last+tag@mail.example.com",
+      ),
+      ("", ""),
+    ] {
+      let (cell_str, _) = type_option
+        .apply_changeset(changeset.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected);
+    }
+
+    for changeset in [
+      "nathan",
+      "nathan@",
+      "@appflowy.io",
+      "nathan@appflowy",
+      "na than@appflowy.io",
+      "nathan..dev@appflowy.io",
+      "nathan@-appflowy.io",
+    ] {
+      let err = type_option
+        .apply_changeset(changeset.to_owned(), None)
+        .unwrap_err();
+      assert_eq!(err.code, ErrorCode::EmailFormatInvalid.value());
+    }
+  }
+
+  #[test]
+  fn email_cell_transform_from_text_test() {
+    let type_option = EmailTypeOptionPB::default();
+    let field_rev = FieldBuilder::from_field_type(&FieldType::RichText).build();
+    let cell_data = type_option
+      .transform_type_option_cell_str(" Nathan@AppFlowy.io", &FieldType::RichText, &field_rev)
+      .unwrap();
+    assert_eq!(cell_data.0, "Nathan@appflowy.io");
+    assert!(cell_data.is_valid());
+
+    // The invalid addresses are kept
+    let cell_data = type_option
+      .transform_type_option_cell_str("call Nathan", &FieldType::RichText, &field_rev)
+      .unwrap();
+    assert_eq!(cell_data.0, "call Nathan");
+    assert!(!cell_data.is_valid());
+
+    assert!(type_option
+      .transform_type_option_cell_str("1", &FieldType::Checkbox, &field_rev)
+      .is_none());
+  }
+
+  #[test]
+  fn email_cell_filter_test() {
+    let type_option = EmailTypeOptionPB::default();
+    let cells = [
+      EmailCellData("nathan@appflowy.io".to_owned()),
+      EmailCellData("call Nathan".to_owned()),
+      EmailCellData::default(),
+    ];
+    let visible = |condition: EmailFilterConditionPB, content: &str| {
+      let filter = EmailFilter::from(EmailFilterPB {
+        condition,
+        content: content.to_owned(),
+      });
+      cells
+        .iter()
+        .filter(|cell_data| type_option.apply_filter(&filter, &FieldType::Email, cell_data))
+        .count()
+    };
+    assert_eq!(visible(EmailFilterConditionPB::EmailIsValid, ""), 1);
+    assert_eq!(visible(EmailFilterConditionPB::EmailIsInvalid, ""), 1);
+    assert_eq!(visible(EmailFilterConditionPB::Contains, "nathan"), 2);
+    assert_eq!(visible(EmailFilterConditionPB::EndsWith, "@appflowy.io"), 1);
+    assert_eq!(visible(EmailFilterConditionPB::EmailIsEmpty, ""), 1);
+  }
+
+  #[test]
+  fn email_filter_legacy_condition_test() {
+    let filter_rev = |field_type: FieldType, condition: u8| FilterRevision {
+      id: "filter_1".to_owned(),
+      field_id: "field_1".to_owned(),
+      field_type: field_type.into(),
+      condition,
+      content: "".to_owned(),
+      case_sensitive: false,
+      other_field_id: None,
+    };
+    // The older versions saved the conditions of the address with the values of the pattern
+    // conditions of the text filter
+    let filter = EmailFilterPB::from(&filter_rev(FieldType::Email, 8));
+    assert_eq!(filter.condition, EmailFilterConditionPB::EmailIsValid);
+    let filter = EmailFilterPB::from(&filter_rev(FieldType::Email, 9));
+    assert_eq!(filter.condition, EmailFilterConditionPB::EmailIsInvalid);
+
+    let filter = EmailFilterPB::from(&filter_rev(FieldType::Email, 11));
+    assert_eq!(filter.condition, EmailFilterConditionPB::EmailIsInvalid);
+    // The regex filter of a text field doesn't become a condition of the address
+    let filter = EmailFilterPB::from(&filter_rev(FieldType::RichText, 8));
+    assert_eq!(filter.condition, EmailFilterConditionPB::Is);
+  }
+}
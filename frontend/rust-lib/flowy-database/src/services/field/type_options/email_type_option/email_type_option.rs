@@ -0,0 +1,151 @@
+use crate::entities::FieldType;
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  BoxTypeOptionBuilder, EmailCellData, EmailCellDataPB, EmailFilter, TypeOption, TypeOptionBuilder,
+  TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct EmailTypeOptionBuilder(EmailTypeOptionPB);
+impl_into_box_type_option_builder!(EmailTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(EmailTypeOptionBuilder, EmailTypeOptionPB);
+
+impl TypeOptionBuilder for EmailTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Email
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// For the moment, the `EmailTypeOptionPB` is empty. The `data` property is not
+/// used yet.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ProtoBuf)]
+pub struct EmailTypeOptionPB {
+  #[pb(index = 1)]
+  #[serde(default)]
+  data: String,
+}
+impl_type_option!(EmailTypeOptionPB, FieldType::Email);
+
+impl TypeOption for EmailTypeOptionPB {
+  type CellData = EmailCellData;
+  type CellChangeset = EmailCellChangeset;
+  type CellProtobufType = EmailCellDataPB;
+  type CellFilter = EmailFilter;
+}
+
+impl TypeOptionTransform for EmailTypeOptionPB {
+  fn transformable(&self) -> bool {
+    true
+  }
+
+  /// The text is kept as it is when the text field is converted, so the invalid addresses can
+  /// be found by the `EmailIsInvalid` filter and fixed.
+  fn transform_type_option_cell_str(
+    &self,
+    cell_str: &str,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> Option<<Self as TypeOption>::CellData> {
+    if decoded_field_type.is_text() || decoded_field_type.is_url() {
+      let address = cell_str.trim();
+      Some(EmailCellData::parse(address).unwrap_or_else(|_| EmailCellData(address.to_owned())))
+    } else {
+      None
+    }
+  }
+}
+
+impl TypeOptionCellData for EmailTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    EmailCellDataPB {
+      is_valid: cell_data.is_valid(),
+      address: cell_data.0,
+    }
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    EmailCellData::from_cell_str(&cell_str)
+  }
+}
+
+impl CellDataDecoder for EmailTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_email() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.0
+  }
+}
+
+pub type EmailCellChangeset = String;
+
+impl CellDataChangeset for EmailTypeOptionPB {
+  /// Returns [FlowyError::email_format](flowy_error::FlowyError::email_format) if the
+  /// changeset is not a valid address.
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let cell_data = EmailCellData::parse(&changeset)?;
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for EmailTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_email() {
+      return true;
+    }
+
+    filter.is_visible(cell_data)
+  }
+}
+
+impl TypeOptionCellDataCompare for EmailTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    // The local parts are case-sensitive, the addresses that only differ in their case are
+    // different addresses sorted next to each other
+    cell_data
+      .0
+      .to_lowercase()
+      .cmp(&other_cell_data.0.to_lowercase())
+      .then_with(|| cell_data.0.cmp(&other_cell_data.0))
+  }
+}
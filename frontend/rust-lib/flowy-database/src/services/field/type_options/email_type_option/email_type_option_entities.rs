@@ -0,0 +1,126 @@
+use crate::services::cell::{CellProtobufBlobParser, DecodedCellData, FromCellString};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+
+const MAX_LOCAL_PART_LEN: usize = 64;
+const MAX_ADDRESS_LEN: usize = 254;
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct EmailCellDataPB {
+  #[pb(index = 1)]
+  pub address: String,
+
+  /// False if the address was converted from a text cell without being a valid address
+  #[pb(index = 2)]
+  pub is_valid: bool,
+}
+
+impl DecodedCellData for EmailCellDataPB {
+  type Object = EmailCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.address.is_empty()
+  }
+}
+
+pub struct EmailCellDataParser();
+impl CellProtobufBlobParser for EmailCellDataParser {
+  type Object = EmailCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    EmailCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+/// The email address of the cell. The typed addresses are always valid, but the cells that are
+/// converted from the text cells keep their text even if it's not a valid address.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EmailCellData(pub String);
+
+impl EmailCellData {
+  /// Returns [FlowyError::email_format] if the `s` is not a valid address. The domain of the
+  /// address is lowercased, the local part is kept as typed since it's case-sensitive.
+  pub fn parse(s: &str) -> FlowyResult<Self> {
+    let s = s.trim();
+    if s.is_empty() {
+      return Ok(Self::default());
+    }
+    if !is_valid_email(s) {
+      return Err(FlowyError::email_format().context(format!("Invalid email address: {}", s)));
+    }
+    let (local_part, domain) = s.rsplit_once('@').unwrap();
+    Ok(Self(format!("{}@{}", local_part, domain.to_lowercase())))
+  }
+
+  pub fn is_valid(&self) -> bool {
+    is_valid_email(&self.0)
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
+
+/// Checks the common form of the addresses, `local-part@domain.tld`. The quoted local parts and
+/// the IP address domains are not supported.
+fn is_valid_email(s: &str) -> bool {
+  if s.len() > MAX_ADDRESS_LEN {
+    return false;
+  }
+  let (local_part, domain) = match s.rsplit_once('@') {
+    None => return false,
+    Some(parts) => parts,
+  };
+
+  let is_local_char = |c: char| c.is_alphanumeric() || "!#$%&'*+/=?^_`{|}~-.".contains(c);
+  let is_valid_local_part = !local_part.is_empty()
+    && local_part.len() <= MAX_LOCAL_PART_LEN
+    && local_part.chars().all(is_local_char)
+    && !local_part.starts_with('.')
+    && !local_part.ends_with('.')
+    && !local_part.contains("..");
+  if !is_valid_local_part {
+    return false;
+  }
+
+  let labels = domain.split('.').collect::<Vec<_>>();
+  let is_valid_label = |label: &&str| {
+    !label.is_empty()
+      && label.len() <= 63
+      && !label.starts_with('-')
+      && !label.ends_with('-')
+      && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+  };
+  let tld = labels.last().copied().unwrap_or_default();
+  labels.len() >= 2
+    && labels.iter().all(is_valid_label)
+    && tld.chars().count() >= 2
+    && tld.chars().all(char::is_alphabetic)
+}
+
+impl FromCellString for EmailCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self> {
+    Ok(Self(s.to_owned()))
+  }
+}
+
+impl AsRef<str> for EmailCellData {
+  fn as_ref(&self) -> &str {
+    &self.0
+  }
+}
+
+impl ToString for EmailCellData {
+  fn to_string(&self) -> String {
+    self.0.clone()
+  }
+}
+
+impl DecodedCellData for EmailCellData {
+  type Object = EmailCellData;
+
+  fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
@@ -0,0 +1,796 @@
+use chrono::format::strftime::StrftimeItems;
+use chrono::format::Item;
+use chrono::{Datelike, Months, NaiveDate, NaiveDateTime, Timelike};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::fmt::Write;
+use std::str::FromStr;
+
+const SECONDS_PER_DAY: i64 = 86400;
+
+/// The deepest nesting of the parentheses, the function calls, the negations and the operators
+/// that a formula can have. The expressions are parsed and evaluated recursively, so the limit
+/// keeps the crafted formulas from overflowing the stack.
+const MAX_FORMULA_DEPTH: usize = 128;
+
+/// The format of the dates that are converted to text.
+const FORMULA_DATE_FORMAT: &str = "%Y-%m-%d";
+const FORMULA_DATE_TIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// The error of parsing or evaluating a formula. The message is shown in the cell instead of
+/// the value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormulaError(pub String);
+
+impl FormulaError {
+  fn new<T: Into<String>>(msg: T) -> Self {
+    Self(msg.into())
+  }
+}
+
+impl std::fmt::Display for FormulaError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+pub type FormulaResult<T> = Result<T, FormulaError>;
+
+/// The value of an expression. The dates are the timestamps in seconds, they're in UTC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormulaValue {
+  Empty,
+  Number(Decimal),
+  Text(String),
+  Date(i64),
+}
+
+impl std::default::Default for FormulaValue {
+  fn default() -> Self {
+    FormulaValue::Empty
+  }
+}
+
+impl FormulaValue {
+  pub fn is_empty(&self) -> bool {
+    match self {
+      FormulaValue::Empty => true,
+      FormulaValue::Text(s) => s.is_empty(),
+      _ => false,
+    }
+  }
+
+  /// The empty value is treated as zero in the arithmetic. The text is a number if it can be
+  /// parsed as one.
+  fn to_number(&self) -> FormulaResult<Decimal> {
+    match self {
+      FormulaValue::Empty => Ok(Decimal::ZERO),
+      FormulaValue::Number(number) => Ok(*number),
+      FormulaValue::Text(s) => Decimal::from_str(s.trim())
+        .map_err(|_| FormulaError::new(format!("\"{}\" is not a number", s))),
+      FormulaValue::Date(_) => Err(FormulaError::new("A date is not a number")),
+    }
+  }
+
+  /// The text is a date if it's in the format of `2022-03-14` or `2022-03-14 10:30`.
+  fn to_date(&self) -> FormulaResult<i64> {
+    match self {
+      FormulaValue::Date(timestamp) => Ok(*timestamp),
+      FormulaValue::Text(s) => {
+        parse_date(s.trim()).ok_or_else(|| FormulaError::new(format!("\"{}\" is not a date", s)))
+      },
+      FormulaValue::Empty => Err(FormulaError::new("The date is empty")),
+      FormulaValue::Number(_) => Err(FormulaError::new("A number is not a date")),
+    }
+  }
+}
+
+impl ToString for FormulaValue {
+  fn to_string(&self) -> String {
+    match self {
+      FormulaValue::Empty => "".to_owned(),
+      FormulaValue::Number(number) => number.normalize().to_string(),
+      FormulaValue::Text(s) => s.clone(),
+      FormulaValue::Date(timestamp) => match naive_date_time(*timestamp) {
+        Ok(date_time) if date_time.num_seconds_from_midnight() == 0 => {
+          date_time.format(FORMULA_DATE_FORMAT).to_string()
+        },
+        Ok(date_time) => date_time.format(FORMULA_DATE_TIME_FORMAT).to_string(),
+        Err(_) => timestamp.to_string(),
+      },
+    }
+  }
+}
+
+/// Provides the values that the formula refers to.
+pub trait FormulaContext {
+  /// Returns the value of the cell of the field named `field_name` in the row that is being
+  /// calculated, or None if there is no such field.
+  fn field_value(&self, field_name: &str) -> Option<FormulaValue>;
+
+  /// Returns the current timestamp in seconds.
+  fn now(&self) -> i64;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormulaOperator {
+  Add,
+  Subtract,
+  Multiply,
+  Divide,
+  Concat,
+}
+
+/// The syntax tree of a formula. For example, `round({Price} * 1.2, 2) & " USD"`.
+///
+/// * The fields are referred to by their names inside the braces, `{Price}`.
+/// * The texts are quoted, `"USD"`, the quote inside the text is escaped by a backslash.
+/// * `+`, `-`, `*` and `/` calculate the numbers or the dates, `&` joins the texts.
+/// * The functions are called by their names, see [FormulaExpr::evaluate] for the list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormulaExpr {
+  Number(Decimal),
+  Text(String),
+  Field(String),
+  Negate(Box<FormulaExpr>),
+  Binary {
+    op: FormulaOperator,
+    left: Box<FormulaExpr>,
+    right: Box<FormulaExpr>,
+  },
+  Call {
+    name: String,
+    args: Vec<FormulaExpr>,
+  },
+}
+
+impl FormulaExpr {
+  pub fn parse(s: &str) -> FormulaResult<Self> {
+    let tokens = tokenize(s)?;
+    if tokens.is_empty() {
+      return Err(FormulaError::new("The formula is empty"));
+    }
+
+    let mut parser = FormulaParser {
+      tokens,
+      pos: 0,
+      depth: 0,
+    };
+    let expr = parser.parse_concat()?;
+    match parser.peek() {
+      None => Ok(expr),
+      Some(token) => Err(FormulaError::new(format!("Unexpected {}", token))),
+    }
+  }
+
+  /// Returns the names of the fields that the expression refers to, each name is returned once.
+  pub fn field_names(&self) -> Vec<String> {
+    let mut names = vec![];
+    self.collect_field_names(&mut names);
+    names
+  }
+
+  /// Returns true if the expression calls `now()`, its value changes as the time passes.
+  pub fn calls_now(&self) -> bool {
+    match self {
+      FormulaExpr::Number(_) | FormulaExpr::Text(_) | FormulaExpr::Field(_) => false,
+      FormulaExpr::Negate(expr) => expr.calls_now(),
+      FormulaExpr::Binary { left, right, .. } => left.calls_now() || right.calls_now(),
+      FormulaExpr::Call { name, args } => {
+        name.eq_ignore_ascii_case("now") || args.iter().any(|arg| arg.calls_now())
+      },
+    }
+  }
+
+  fn collect_field_names(&self, names: &mut Vec<String>) {
+    match self {
+      FormulaExpr::Number(_) | FormulaExpr::Text(_) => {},
+      FormulaExpr::Field(name) => {
+        if !names.contains(name) {
+          names.push(name.clone());
+        }
+      },
+      FormulaExpr::Negate(expr) => expr.collect_field_names(names),
+      FormulaExpr::Binary { left, right, .. } => {
+        left.collect_field_names(names);
+        right.collect_field_names(names);
+      },
+      FormulaExpr::Call { args, .. } => {
+        for arg in args {
+          arg.collect_field_names(names);
+        }
+      },
+    }
+  }
+
+  /// Evaluates the expression. The supported functions are:
+  ///
+  /// * `concat(text, ...)`, `len(text)`, `upper(text)`, `lower(text)`
+  /// * `round(number, [digits])`, `abs(number)`, `min(number, ...)`, `max(number, ...)`
+  /// * `now()`, `year(date)`, `month(date)`, `day(date)`
+  /// * `date_add(date, amount, unit)` and `date_diff(end, start, unit)`, the unit is one of
+  /// `years`, `months`, `weeks`, `days`, `hours` and `minutes`
+  /// * `format_date(date, format)`, the format is the same as the `strftime`
+  ///
+  /// The functions of the dates return empty if the date is empty.
+  pub fn evaluate(&self, context: &dyn FormulaContext) -> FormulaResult<FormulaValue> {
+    match self {
+      FormulaExpr::Number(number) => Ok(FormulaValue::Number(*number)),
+      FormulaExpr::Text(s) => Ok(FormulaValue::Text(s.clone())),
+      FormulaExpr::Field(name) => context
+        .field_value(name)
+        .ok_or_else(|| FormulaError::new(format!("The field {{{}}} doesn't exist", name))),
+      FormulaExpr::Negate(expr) => match expr.evaluate(context)? {
+        FormulaValue::Empty => Ok(FormulaValue::Empty),
+        value => Ok(FormulaValue::Number(-value.to_number()?)),
+      },
+      FormulaExpr::Binary { op, left, right } => {
+        let left = left.evaluate(context)?;
+        let right = right.evaluate(context)?;
+        evaluate_binary(*op, left, right)
+      },
+      FormulaExpr::Call { name, args } => {
+        let args = args
+          .iter()
+          .map(|arg| arg.evaluate(context))
+          .collect::<FormulaResult<Vec<FormulaValue>>>()?;
+        evaluate_function(name, args, context)
+      },
+    }
+  }
+}
+
+fn evaluate_binary(
+  op: FormulaOperator,
+  left: FormulaValue,
+  right: FormulaValue,
+) -> FormulaResult<FormulaValue> {
+  if op == FormulaOperator::Concat {
+    return Ok(FormulaValue::Text(format!(
+      "{}{}",
+      left.to_string(),
+      right.to_string()
+    )));
+  }
+
+  let overflow = || FormulaError::new("The number is too large");
+  match (op, &left, &right) {
+    (_, FormulaValue::Empty, FormulaValue::Empty) => Ok(FormulaValue::Empty),
+    // Adds or subtracts the days of the date
+    (FormulaOperator::Add, FormulaValue::Date(timestamp), _)
+    | (FormulaOperator::Add, _, FormulaValue::Date(timestamp)) => {
+      let days = if matches!(left, FormulaValue::Date(_)) {
+        right.to_number()?
+      } else {
+        left.to_number()?
+      };
+      add_days(*timestamp, days)
+    },
+    (FormulaOperator::Subtract, FormulaValue::Date(left), FormulaValue::Date(right)) => {
+      let seconds = left
+        .checked_sub(*right)
+        .ok_or_else(|| FormulaError::new("The date is out of range"))?;
+      Ok(FormulaValue::Number(
+        Decimal::from(seconds) / Decimal::from(SECONDS_PER_DAY),
+      ))
+    },
+    (FormulaOperator::Subtract, FormulaValue::Date(timestamp), _) => {
+      add_days(*timestamp, -right.to_number()?)
+    },
+    _ => {
+      let left = left.to_number()?;
+      let right = right.to_number()?;
+      let value = match op {
+        FormulaOperator::Add => left.checked_add(right).ok_or_else(overflow)?,
+        FormulaOperator::Subtract => left.checked_sub(right).ok_or_else(overflow)?,
+        FormulaOperator::Multiply => left.checked_mul(right).ok_or_else(overflow)?,
+        FormulaOperator::Divide => {
+          if right.is_zero() {
+            return Err(FormulaError::new("Division by zero"));
+          }
+          left.checked_div(right).ok_or_else(overflow)?
+        },
+        FormulaOperator::Concat => unreachable!(),
+      };
+      Ok(FormulaValue::Number(value))
+    },
+  }
+}
+
+fn evaluate_function(
+  name: &str,
+  args: Vec<FormulaValue>,
+  context: &dyn FormulaContext,
+) -> FormulaResult<FormulaValue> {
+  let expect_args = |min: usize, max: usize| {
+    if args.len() < min || args.len() > max {
+      let expected = if min == max {
+        min.to_string()
+      } else if max == usize::MAX {
+        format!("at least {}", min)
+      } else {
+        format!("{} to {}", min, max)
+      };
+      let noun = if max == 1 { "argument" } else { "arguments" };
+      return Err(FormulaError::new(format!(
+        "{}() expects {} {}, but it has {}",
+        name,
+        expected,
+        noun,
+        args.len()
+      )));
+    }
+    Ok(())
+  };
+
+  match name.to_lowercase().as_str() {
+    "concat" => Ok(FormulaValue::Text(
+      args.iter().map(|arg| arg.to_string()).collect::<String>(),
+    )),
+    "len" => {
+      expect_args(1, 1)?;
+      Ok(FormulaValue::Number(Decimal::from(
+        args[0].to_string().chars().count(),
+      )))
+    },
+    "upper" => {
+      expect_args(1, 1)?;
+      Ok(FormulaValue::Text(args[0].to_string().to_uppercase()))
+    },
+    "lower" => {
+      expect_args(1, 1)?;
+      Ok(FormulaValue::Text(args[0].to_string().to_lowercase()))
+    },
+    "round" => {
+      expect_args(1, 2)?;
+      if args[0] == FormulaValue::Empty {
+        return Ok(FormulaValue::Empty);
+      }
+      let digits = match args.get(1) {
+        None => 0,
+        Some(digits) => digits
+          .to_number()?
+          .to_u32()
+          .ok_or_else(|| FormulaError::new("The digits of round() should not be negative"))?,
+      };
+      Ok(FormulaValue::Number(args[0].to_number()?.round_dp(digits)))
+    },
+    "abs" => {
+      expect_args(1, 1)?;
+      match &args[0] {
+        FormulaValue::Empty => Ok(FormulaValue::Empty),
+        value => Ok(FormulaValue::Number(value.to_number()?.abs())),
+      }
+    },
+    "min" | "max" => {
+      expect_args(1, usize::MAX)?;
+      let numbers = args
+        .iter()
+        .filter(|arg| !arg.is_empty())
+        .map(|arg| arg.to_number())
+        .collect::<FormulaResult<Vec<Decimal>>>()?;
+      let value = if name.eq_ignore_ascii_case("min") {
+        numbers.into_iter().min()
+      } else {
+        numbers.into_iter().max()
+      };
+      Ok(value.map(FormulaValue::Number).unwrap_or_default())
+    },
+    "now" => {
+      expect_args(0, 0)?;
+      Ok(FormulaValue::Date(context.now()))
+    },
+    "year" | "month" | "day" => {
+      expect_args(1, 1)?;
+      if args[0].is_empty() {
+        return Ok(FormulaValue::Empty);
+      }
+      let date_time = naive_date_time(args[0].to_date()?)?;
+      let value = match name.to_lowercase().as_str() {
+        "year" => date_time.year() as i64,
+        "month" => date_time.month() as i64,
+        _ => date_time.day() as i64,
+      };
+      Ok(FormulaValue::Number(Decimal::from(value)))
+    },
+    "date_add" => {
+      expect_args(3, 3)?;
+      if args[0].is_empty() {
+        return Ok(FormulaValue::Empty);
+      }
+      let amount = args[1]
+        .to_number()?
+        .to_i64()
+        .ok_or_else(|| FormulaError::new("The amount of date_add() is too large"))?;
+      let unit = DateUnit::from_str(&args[2].to_string())?;
+      date_add(args[0].to_date()?, amount, unit).map(FormulaValue::Date)
+    },
+    "date_diff" => {
+      expect_args(3, 3)?;
+      if args[0].is_empty() || args[1].is_empty() {
+        return Ok(FormulaValue::Empty);
+      }
+      let unit = DateUnit::from_str(&args[2].to_string())?;
+      let diff = date_diff(args[0].to_date()?, args[1].to_date()?, unit)?;
+      Ok(FormulaValue::Number(Decimal::from(diff)))
+    },
+    "format_date" => {
+      expect_args(2, 2)?;
+      if args[0].is_empty() {
+        return Ok(FormulaValue::Empty);
+      }
+      let date_time = naive_date_time(args[0].to_date()?)?;
+      let format = args[1].to_string();
+      let items = StrftimeItems::new(&format).collect::<Vec<Item>>();
+      let mut s = String::new();
+      if items.iter().any(|item| matches!(item, Item::Error))
+        || write!(s, "{}", date_time.format_with_items(items.into_iter())).is_err()
+      {
+        return Err(FormulaError::new(format!(
+          "\"{}\" is not a valid date format",
+          format
+        )));
+      }
+      Ok(FormulaValue::Text(s))
+    },
+    _ => Err(FormulaError::new(format!("Unknown function {}()", name))),
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateUnit {
+  Years,
+  Months,
+  Weeks,
+  Days,
+  Hours,
+  Minutes,
+}
+
+impl FromStr for DateUnit {
+  type Err = FormulaError;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    match s.trim().to_lowercase().trim_end_matches('s') {
+      "year" => Ok(DateUnit::Years),
+      "month" => Ok(DateUnit::Months),
+      "week" => Ok(DateUnit::Weeks),
+      "day" => Ok(DateUnit::Days),
+      "hour" => Ok(DateUnit::Hours),
+      "minute" => Ok(DateUnit::Minutes),
+      _ => Err(FormulaError::new(format!("Unknown date unit \"{}\"", s))),
+    }
+  }
+}
+
+impl DateUnit {
+  fn seconds(&self) -> Option<i64> {
+    match self {
+      DateUnit::Years | DateUnit::Months => None,
+      DateUnit::Weeks => Some(7 * SECONDS_PER_DAY),
+      DateUnit::Days => Some(SECONDS_PER_DAY),
+      DateUnit::Hours => Some(3600),
+      DateUnit::Minutes => Some(60),
+    }
+  }
+}
+
+fn date_add(timestamp: i64, amount: i64, unit: DateUnit) -> FormulaResult<i64> {
+  let out_of_range = || FormulaError::new("The date is out of range");
+  if let Some(seconds) = unit.seconds() {
+    return amount
+      .checked_mul(seconds)
+      .and_then(|seconds| timestamp.checked_add(seconds))
+      .ok_or_else(out_of_range);
+  }
+
+  let months = if unit == DateUnit::Years {
+    amount.checked_mul(12).ok_or_else(out_of_range)?
+  } else {
+    amount
+  };
+  let abs_months = u32::try_from(months.unsigned_abs()).map_err(|_| out_of_range())?;
+  let date_time = naive_date_time(timestamp)?;
+  let date_time = if months >= 0 {
+    date_time.checked_add_months(Months::new(abs_months))
+  } else {
+    date_time.checked_sub_months(Months::new(abs_months))
+  };
+  date_time
+    .map(|date_time| date_time.timestamp())
+    .ok_or_else(out_of_range)
+}
+
+/// Returns the number of the whole units from `start` to `end`, it's negative if `end` is
+/// before `start`.
+fn date_diff(end: i64, start: i64, unit: DateUnit) -> FormulaResult<i64> {
+  if let Some(seconds) = unit.seconds() {
+    return end
+      .checked_sub(start)
+      .map(|diff| diff / seconds)
+      .ok_or_else(|| FormulaError::new("The date is out of range"));
+  }
+
+  let end_date_time = naive_date_time(end)?;
+  let start_date_time = naive_date_time(start)?;
+  let month_index =
+    |date_time: &NaiveDateTime| date_time.year() as i64 * 12 + date_time.month0() as i64;
+  let remainder =
+    |date_time: &NaiveDateTime| (date_time.day(), date_time.num_seconds_from_midnight());
+  let mut months = month_index(&end_date_time) - month_index(&start_date_time);
+  // The last month is not counted if it isn't complete
+  if months > 0 && remainder(&end_date_time) < remainder(&start_date_time) {
+    months -= 1;
+  } else if months < 0 && remainder(&end_date_time) > remainder(&start_date_time) {
+    months += 1;
+  }
+
+  match unit {
+    DateUnit::Years => Ok(months / 12),
+    _ => Ok(months),
+  }
+}
+
+fn add_days(timestamp: i64, days: Decimal) -> FormulaResult<FormulaValue> {
+  let seconds = days
+    .checked_mul(Decimal::from(SECONDS_PER_DAY))
+    .and_then(|seconds| seconds.round().to_i64())
+    .and_then(|seconds| timestamp.checked_add(seconds))
+    .ok_or_else(|| FormulaError::new("The date is out of range"))?;
+  Ok(FormulaValue::Date(seconds))
+}
+
+fn naive_date_time(timestamp: i64) -> FormulaResult<NaiveDateTime> {
+  NaiveDateTime::from_timestamp_opt(timestamp, 0)
+    .ok_or_else(|| FormulaError::new("The date is out of range"))
+}
+
+fn parse_date(s: &str) -> Option<i64> {
+  if let Ok(date_time) = NaiveDateTime::parse_from_str(s, FORMULA_DATE_TIME_FORMAT) {
+    return Some(date_time.timestamp());
+  }
+  let date = NaiveDate::parse_from_str(s, FORMULA_DATE_FORMAT).ok()?;
+  Some(date.and_hms_opt(0, 0, 0)?.timestamp())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+  Number(Decimal),
+  Text(String),
+  Field(String),
+  Ident(String),
+  Operator(FormulaOperator),
+  LeftParen,
+  RightParen,
+  Comma,
+}
+
+impl std::fmt::Display for Token {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Token::Number(number) => write!(f, "number {}", number),
+      Token::Text(s) => write!(f, "text \"{}\"", s),
+      Token::Field(name) => write!(f, "field {{{}}}", name),
+      Token::Ident(name) => write!(f, "\"{}\"", name),
+      Token::Operator(op) => {
+        let op = match op {
+          FormulaOperator::Add => "+",
+          FormulaOperator::Subtract => "-",
+          FormulaOperator::Multiply => "*",
+          FormulaOperator::Divide => "/",
+          FormulaOperator::Concat => "&",
+        };
+        write!(f, "\"{}\"", op)
+      },
+      Token::LeftParen => f.write_str("\"(\""),
+      Token::RightParen => f.write_str("\")\""),
+      Token::Comma => f.write_str("\",\""),
+    }
+  }
+}
+
+fn tokenize(s: &str) -> FormulaResult<Vec<Token>> {
+  let mut tokens = vec![];
+  let mut chars = s.chars().peekable();
+  while let Some(c) = chars.next() {
+    let token = match c {
+      c if c.is_whitespace() => continue,
+      '+' => Token::Operator(FormulaOperator::Add),
+      '-' => Token::Operator(FormulaOperator::Subtract),
+      '*' => Token::Operator(FormulaOperator::Multiply),
+      '/' => Token::Operator(FormulaOperator::Divide),
+      '&' => Token::Operator(FormulaOperator::Concat),
+      '(' => Token::LeftParen,
+      ')' => Token::RightParen,
+      ',' => Token::Comma,
+      '{' => {
+        let mut name = String::new();
+        loop {
+          match chars.next() {
+            None => return Err(FormulaError::new("Missing \"}\" after the field name")),
+            Some('}') => break,
+            Some(c) => name.push(c),
+          }
+        }
+        Token::Field(name.trim().to_owned())
+      },
+      '"' => {
+        let mut text = String::new();
+        loop {
+          match chars.next() {
+            None => return Err(FormulaError::new("Missing the closing quote of the text")),
+            Some('"') => break,
+            Some('\\') => match chars.next() {
+              None => return Err(FormulaError::new("Missing the closing quote of the text")),
+              Some(c) => text.push(c),
+            },
+            Some(c) => text.push(c),
+          }
+        }
+        Token::Text(text)
+      },
+      c if c.is_ascii_digit() || c == '.' => {
+        let mut number = c.to_string();
+        while let Some(c) = chars.peek().filter(|c| c.is_ascii_digit() || **c == '.') {
+          number.push(*c);
+          chars.next();
+        }
+        let number = Decimal::from_str(&number)
+          .map_err(|_| FormulaError::new(format!("\"{}\" is not a number", number)))?;
+        Token::Number(number)
+      },
+      c if c.is_alphabetic() || c == '_' => {
+        let mut name = c.to_string();
+        while let Some(c) = chars.peek().filter(|c| c.is_alphanumeric() || **c == '_') {
+          name.push(*c);
+          chars.next();
+        }
+        Token::Ident(name)
+      },
+      c => return Err(FormulaError::new(format!("Unexpected \"{}\"", c))),
+    };
+    tokens.push(token);
+  }
+  Ok(tokens)
+}
+
+/// A recursive descent parser. From the lowest precedence to the highest, the operators are
+/// `&`, `+ -`, `* /` and the negation.
+struct FormulaParser {
+  tokens: Vec<Token>,
+  pos: usize,
+  /// The depth of the expression that is being parsed, see [MAX_FORMULA_DEPTH]
+  depth: usize,
+}
+
+impl FormulaParser {
+  /// Parses the nested expression one level deeper. Returns an error instead of parsing it if
+  /// the formula is nested too deeply.
+  fn nested<T>(&mut self, parse: impl FnOnce(&mut Self) -> FormulaResult<T>) -> FormulaResult<T> {
+    self.enter()?;
+    let result = parse(self);
+    self.depth -= 1;
+    result
+  }
+
+  fn enter(&mut self) -> FormulaResult<()> {
+    if self.depth >= MAX_FORMULA_DEPTH {
+      return Err(FormulaError::new("The formula is nested too deeply"));
+    }
+    self.depth += 1;
+    Ok(())
+  }
+
+  fn peek(&self) -> Option<&Token> {
+    self.tokens.get(self.pos)
+  }
+
+  fn next(&mut self) -> Option<Token> {
+    let token = self.tokens.get(self.pos).cloned();
+    self.pos += 1;
+    token
+  }
+
+  fn next_operator(&mut self, operators: &[FormulaOperator]) -> Option<FormulaOperator> {
+    match self.peek() {
+      Some(Token::Operator(op)) if operators.contains(op) => {
+        let op = *op;
+        self.pos += 1;
+        Some(op)
+      },
+      _ => None,
+    }
+  }
+
+  fn parse_binary(
+    &mut self,
+    operators: &[FormulaOperator],
+    parse_operand: fn(&mut Self) -> FormulaResult<FormulaExpr>,
+  ) -> FormulaResult<FormulaExpr> {
+    let depth = self.depth;
+    let mut expr = parse_operand(self)?;
+    while let Some(op) = self.next_operator(operators) {
+      // Each operator nests the expression on its left one level deeper. The parsing stops at
+      // the first error, so the depth is only restored when the operands are parsed.
+      self.enter()?;
+      let right = parse_operand(self)?;
+      expr = FormulaExpr::Binary {
+        op,
+        left: Box::new(expr),
+        right: Box::new(right),
+      };
+    }
+    self.depth = depth;
+    Ok(expr)
+  }
+
+  fn parse_concat(&mut self) -> FormulaResult<FormulaExpr> {
+    self.parse_binary(&[FormulaOperator::Concat], Self::parse_additive)
+  }
+
+  fn parse_additive(&mut self) -> FormulaResult<FormulaExpr> {
+    self.parse_binary(
+      &[FormulaOperator::Add, FormulaOperator::Subtract],
+      Self::parse_multiplicative,
+    )
+  }
+
+  fn parse_multiplicative(&mut self) -> FormulaResult<FormulaExpr> {
+    self.parse_binary(
+      &[FormulaOperator::Multiply, FormulaOperator::Divide],
+      Self::parse_unary,
+    )
+  }
+
+  fn parse_unary(&mut self) -> FormulaResult<FormulaExpr> {
+    if self.next_operator(&[FormulaOperator::Subtract]).is_some() {
+      let expr = self.nested(Self::parse_unary)?;
+      return Ok(FormulaExpr::Negate(Box::new(expr)));
+    }
+    self.parse_primary()
+  }
+
+  fn parse_primary(&mut self) -> FormulaResult<FormulaExpr> {
+    match self.next() {
+      None => Err(FormulaError::new("The formula ends unexpectedly")),
+      Some(Token::Number(number)) => Ok(FormulaExpr::Number(number)),
+      Some(Token::Text(s)) => Ok(FormulaExpr::Text(s)),
+      Some(Token::Field(name)) => Ok(FormulaExpr::Field(name)),
+      Some(Token::LeftParen) => {
+        let expr = self.nested(Self::parse_concat)?;
+        self.expect(Token::RightParen)?;
+        Ok(expr)
+      },
+      Some(Token::Ident(name)) => {
+        self.expect(Token::LeftParen)?;
+        let mut args = vec![];
+        if self.peek() == Some(&Token::RightParen) {
+          self.pos += 1;
+        } else {
+          loop {
+            args.push(self.nested(Self::parse_concat)?);
+            match self.next() {
+              Some(Token::Comma) => continue,
+              Some(Token::RightParen) => break,
+              Some(token) => return Err(FormulaError::new(format!("Unexpected {}", token))),
+              None => return Err(FormulaError::new("Missing \")\"")),
+            }
+          }
+        }
+        Ok(FormulaExpr::Call { name, args })
+      },
+      Some(token) => Err(FormulaError::new(format!("Unexpected {}", token))),
+    }
+  }
+
+  fn expect(&mut self, expected: Token) -> FormulaResult<()> {
+    match self.next() {
+      Some(token) if token == expected => Ok(()),
+      Some(token) => Err(FormulaError::new(format!(
+        "Expected {}, but found {}",
+        expected, token
+      ))),
+      None => Err(FormulaError::new(format!("Missing {}", expected))),
+    }
+  }
+}
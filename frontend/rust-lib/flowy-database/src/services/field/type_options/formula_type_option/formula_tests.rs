@@ -0,0 +1,241 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, TextFilterConditionPB, TextFilterPB};
+  use crate::services::cell::{CellDataChangeset, DecodedCellData, FromCellString, TypeCellData};
+  use crate::services::field::{
+    FormulaCellData, FormulaContext, FormulaExpr, FormulaTypeOptionPB, FormulaValue, TextFilter,
+    TypeOptionCellData, TypeOptionCellDataFilter,
+  };
+  use rust_decimal::Decimal;
+  use std::collections::HashMap;
+
+  // 2022-03-14 10:30:00 UTC
+  const NOW: i64 = 1647253800;
+
+  struct MockFormulaContext(HashMap<String, FormulaValue>);
+
+  impl FormulaContext for MockFormulaContext {
+    fn field_value(&self, field_name: &str) -> Option<FormulaValue> {
+      self.0.get(field_name).cloned()
+    }
+
+    fn now(&self) -> i64 {
+      NOW
+    }
+  }
+
+  fn mock_context() -> MockFormulaContext {
+    let mut values = HashMap::new();
+    values.insert(
+      "Price".to_owned(),
+      FormulaValue::Number(Decimal::new(125, 1)),
+    );
+    values.insert("Amount".to_owned(), FormulaValue::Number(Decimal::from(3)));
+    values.insert("Name".to_owned(), FormulaValue::Text("Apple".to_owned()));
+    values.insert("Due Date".to_owned(), FormulaValue::Date(1647216000));
+    values.insert("Notes".to_owned(), FormulaValue::Empty);
+    values.insert("Epoch".to_owned(), FormulaValue::Date(i64::MIN));
+    MockFormulaContext(values)
+  }
+
+  fn evaluate(s: &str) -> String {
+    let context = mock_context();
+    match FormulaExpr::parse(s).and_then(|expr| expr.evaluate(&context)) {
+      Ok(value) => value.to_string(),
+      Err(err) => format!("Error: {}", err),
+    }
+  }
+
+  #[test]
+  fn formula_arithmetic_test() {
+    assert_eq!(evaluate("1 + 2 * 3"), "7");
+    assert_eq!(evaluate("(1 + 2) * 3"), "9");
+    assert_eq!(evaluate("-{Amount} + 10 / 4"), "-0.5");
+    assert_eq!(evaluate("{Price} * {Amount}"), "37.5");
+    assert_eq!(evaluate("round({Price} / {Amount}, 2)"), "4.17");
+    assert_eq!(evaluate("abs(-2) + min(4, {Amount}) + max(1, 2)"), "7");
+    assert_eq!(evaluate("{Notes} + 1"), "1");
+    assert_eq!(evaluate("1 / 0"), "Error: Division by zero");
+  }
+
+  #[test]
+  fn formula_text_test() {
+    assert_eq!(evaluate("{Name} & \": \" & {Price}"), "Apple: 12.5");
+    assert_eq!(evaluate("concat(upper({Name}), lower(\"-X\"))"), "APPLE-x");
+    assert_eq!(evaluate("len({Name}) + 1"), "6");
+    assert_eq!(evaluate("\"say \\\"hi\\\"\""), "say \"hi\"");
+    assert_eq!(evaluate("{Name} * 2"), "Error: \"Apple\" is not a number");
+  }
+
+  #[test]
+  fn formula_date_test() {
+    assert_eq!(evaluate("{Due Date}"), "2022-03-14");
+    assert_eq!(evaluate("{Due Date} + 1"), "2022-03-15");
+    assert_eq!(evaluate("now()"), "2022-03-14 10:30");
+    assert_eq!(
+      evaluate("year({Due Date}) & \"/\" & month({Due Date}) & \"/\" & day({Due Date})"),
+      "2022/3/14"
+    );
+    assert_eq!(evaluate("date_add({Due Date}, 1, \"month\")"), "2022-04-14");
+    assert_eq!(
+      evaluate("date_add(\"2022-01-31\", 1, \"months\")"),
+      "2022-02-28"
+    );
+    assert_eq!(
+      evaluate("date_add({Due Date}, -2, \"weeks\")"),
+      "2022-02-28"
+    );
+    assert_eq!(
+      evaluate("date_diff(\"2023-03-13\", {Due Date}, \"years\")"),
+      "0"
+    );
+    assert_eq!(
+      evaluate("date_diff(\"2022-05-20\", {Due Date}, \"months\")"),
+      "2"
+    );
+    assert_eq!(
+      evaluate("date_diff({Due Date}, \"2022-03-01\", \"days\")"),
+      "13"
+    );
+    assert_eq!(
+      evaluate("format_date({Due Date}, \"%d/%m/%Y\")"),
+      "14/03/2022"
+    );
+    assert_eq!(evaluate("year({Notes})"), "");
+    assert_eq!(
+      evaluate("date_add({Due Date}, 1, \"century\")"),
+      "Error: Unknown date unit \"century\""
+    );
+  }
+
+  #[test]
+  fn formula_error_test() {
+    assert_eq!(evaluate(""), "Error: The formula is empty");
+    assert_eq!(evaluate("1 +"), "Error: The formula ends unexpectedly");
+    assert_eq!(evaluate("(1 + 2"), "Error: Missing \")\"");
+    assert_eq!(evaluate("1 2"), "Error: Unexpected number 2");
+    assert_eq!(
+      evaluate("{Name"),
+      "Error: Missing \"}\" after the field name"
+    );
+    assert_eq!(evaluate("{Price} $ 2"), "Error: Unexpected \"$\"");
+    assert_eq!(
+      evaluate("{Cost} * 2"),
+      "Error: The field {Cost} doesn't exist"
+    );
+    assert_eq!(evaluate("sum(1, 2)"), "Error: Unknown function sum()");
+    assert_eq!(
+      evaluate("len(1, 2)"),
+      "Error: len() expects 1 argument, but it has 2"
+    );
+  }
+
+  #[test]
+  fn formula_out_of_range_test() {
+    let out_of_range = "Error: The date is out of range";
+    assert_eq!(
+      evaluate("{Due Date} + 79228162514264337593543950335"),
+      out_of_range
+    );
+    assert_eq!(evaluate("{Due Date} - {Epoch}"), out_of_range);
+    assert_eq!(
+      evaluate("date_diff({Due Date}, {Epoch}, \"days\")"),
+      out_of_range
+    );
+    assert_eq!(
+      evaluate("date_add({Due Date}, 9223372036854775807, \"days\")"),
+      out_of_range
+    );
+  }
+
+  #[test]
+  fn formula_depth_test() {
+    let too_deep = "Error: The formula is nested too deeply";
+    assert_eq!(evaluate(&format!("{}1", "-".repeat(10))), "1");
+    assert_eq!(evaluate(&format!("{}1", "-".repeat(10_000))), too_deep);
+    assert_eq!(
+      evaluate(&format!("{}1{}", "(".repeat(10_000), ")".repeat(10_000))),
+      too_deep
+    );
+    assert_eq!(
+      evaluate(&format!("{}1{}", "abs(".repeat(10_000), ")".repeat(10_000))),
+      too_deep
+    );
+    assert_eq!(evaluate(&format!("1{}", " + 1".repeat(100))), "101");
+    assert_eq!(evaluate(&format!("1{}", " + 1".repeat(10_000))), too_deep);
+  }
+
+  #[test]
+  fn formula_field_names_test() {
+    let expr = FormulaExpr::parse("{Price} * {Amount} + round({Price}) & { Name }").unwrap();
+    assert_eq!(expr.field_names(), vec!["Price", "Amount", "Name"]);
+    assert!(!expr.calls_now());
+    assert!(FormulaExpr::parse("date_diff(NOW(), {Due Date}, \"days\")")
+      .unwrap()
+      .calls_now());
+  }
+
+  #[test]
+  fn formula_cell_data_test() {
+    let type_option = FormulaTypeOptionPB {
+      expression: "{Price} * {Amount}".to_owned(),
+    };
+    let cell_data = type_option.calculate(&mock_context());
+    assert_eq!(cell_data.content(), "37.5");
+    assert_eq!(
+      FormulaCellData::from_cell_str(&cell_data.to_string()).unwrap(),
+      cell_data
+    );
+
+    let type_option = FormulaTypeOptionPB {
+      expression: "{Cost} * 2".to_owned(),
+    };
+    let cell_data = type_option.calculate(&mock_context());
+    assert_eq!(cell_data.content(), "");
+    assert_eq!(
+      cell_data.error.as_deref(),
+      Some("The field {Cost} doesn't exist")
+    );
+    assert_eq!(
+      FormulaCellData::from_cell_str(&cell_data.to_string()).unwrap(),
+      cell_data
+    );
+  }
+
+  #[test]
+  fn formula_cell_filter_test() {
+    let type_option = FormulaTypeOptionPB::default();
+    let cell_str =
+      FormulaCellData::from(Ok(FormulaValue::Text("Apple pie".to_owned()))).to_string();
+    let cell_data = type_option.decode_type_option_cell_str(cell_str).unwrap();
+
+    let filter = TextFilter::from(TextFilterPB {
+      condition: TextFilterConditionPB::Contains,
+      content: "apple".to_owned(),
+      case_sensitive: false,
+    });
+    assert!(type_option.apply_filter(&filter, &FieldType::Formula, &cell_data));
+
+    let filter = TextFilter::from(TextFilterPB {
+      condition: TextFilterConditionPB::TextIsEmpty,
+      content: "".to_owned(),
+      case_sensitive: false,
+    });
+    assert!(!type_option.apply_filter(&filter, &FieldType::Formula, &cell_data));
+  }
+
+  #[test]
+  fn formula_cell_read_only_test() {
+    let type_option = FormulaTypeOptionPB::default();
+    let cell_data = FormulaCellData::from(Ok(FormulaValue::Number(Decimal::from(3))));
+    let type_cell_data = TypeCellData::new(cell_data.to_string(), FieldType::Formula);
+    let changeset = FormulaCellData::from(Ok(FormulaValue::Text("Edited".to_owned()))).to_string();
+    let (_, new_cell_data) = type_option
+      .apply_changeset(changeset.clone(), Some(type_cell_data))
+      .unwrap();
+    assert_eq!(new_cell_data, cell_data);
+
+    let (_, new_cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+    assert!(new_cell_data.is_empty());
+  }
+}
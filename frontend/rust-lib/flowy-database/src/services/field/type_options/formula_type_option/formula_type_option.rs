@@ -0,0 +1,167 @@
+use crate::entities::FieldType;
+use crate::impl_type_option;
+use crate::services::cell::{
+  CellDataChangeset, CellDataDecoder, DecodedCellData, FromCellString, TypeCellData,
+};
+use crate::services::field::{
+  default_order, BoxTypeOptionBuilder, FormulaCellChangeset, FormulaCellData, FormulaCellDataPB,
+  FormulaContext, FormulaExpr, FormulaResult, FormulaValue, TextFilter, TypeOption,
+  TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct FormulaTypeOptionBuilder(FormulaTypeOptionPB);
+impl_into_box_type_option_builder!(FormulaTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(FormulaTypeOptionBuilder, FormulaTypeOptionPB);
+
+impl FormulaTypeOptionBuilder {
+  pub fn expression(mut self, expression: &str) -> Self {
+    self.0.expression = expression.to_owned();
+    self
+  }
+}
+
+impl TypeOptionBuilder for FormulaTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Formula
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The formula cell is calculated from the other cells of the same row, see [FormulaExpr] for
+/// the syntax of the expression. The value is calculated in the background whenever the cells
+/// that it refers to change, and it's saved in the cell, so it can be filtered and sorted.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct FormulaTypeOptionPB {
+  #[pb(index = 1)]
+  pub expression: String,
+}
+impl_type_option!(FormulaTypeOptionPB, FieldType::Formula);
+
+impl FormulaTypeOptionPB {
+  pub fn parse_expression(&self) -> FormulaResult<FormulaExpr> {
+    FormulaExpr::parse(&self.expression)
+  }
+
+  /// Calculates the cell of the row that the `context` provides. The cell of the blank formula
+  /// is empty.
+  pub fn calculate(&self, context: &dyn FormulaContext) -> FormulaCellData {
+    if self.expression.trim().is_empty() {
+      return FormulaCellData::default();
+    }
+    FormulaCellData::from(
+      self
+        .parse_expression()
+        .and_then(|expr| expr.evaluate(context)),
+    )
+  }
+}
+
+impl TypeOption for FormulaTypeOptionPB {
+  type CellData = FormulaCellData;
+  type CellChangeset = FormulaCellChangeset;
+  type CellProtobufType = FormulaCellDataPB;
+  type CellFilter = TextFilter;
+}
+
+impl TypeOptionTransform for FormulaTypeOptionPB {}
+
+impl TypeOptionCellData for FormulaTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    FormulaCellDataPB::from(cell_data)
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    FormulaCellData::from_cell_str(&cell_str)
+  }
+}
+
+impl CellDataDecoder for FormulaTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    // The cells of other field types are calculated again instead of being transformed
+    if !decoded_field_type.is_formula() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.content()
+  }
+}
+
+impl CellDataChangeset for FormulaTypeOptionPB {
+  fn apply_changeset(
+    &self,
+    _changeset: <Self as TypeOption>::CellChangeset,
+    type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    // The cell is read-only, it's saved by the formula calculation instead
+    let cell_data = type_cell_data
+      .filter(|type_cell_data| type_cell_data.is_formula())
+      .map(|type_cell_data| self.decode_type_option_cell_str(type_cell_data.cell_str))
+      .transpose()?
+      .unwrap_or_default();
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for FormulaTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_formula() {
+      return true;
+    }
+
+    filter.is_visible(cell_data.content())
+  }
+}
+
+impl TypeOptionCellDataCompare for FormulaTypeOptionPB {
+  /// The numbers and the dates are compared by their values, the other values are compared by
+  /// their content. The failed cells are treated as empty.
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    match (cell_data.is_empty(), other_cell_data.is_empty()) {
+      (true, true) => return default_order(),
+      (false, true) => return Ordering::Greater,
+      (true, false) => return Ordering::Less,
+      (false, false) => {},
+    }
+
+    match (&cell_data.value, &other_cell_data.value) {
+      (FormulaValue::Number(left), FormulaValue::Number(right)) => left.cmp(right),
+      (FormulaValue::Date(left), FormulaValue::Date(right)) => left.cmp(right),
+      _ => cell_data.content().cmp(&other_cell_data.content()),
+    }
+  }
+}
@@ -0,0 +1,141 @@
+use crate::services::cell::{CellProtobufBlobParser, DecodedCellData, FromCellString};
+use crate::services::field::{FormulaError, FormulaValue};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, FlowyResult};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct FormulaCellDataPB {
+  /// The calculated value, it's empty if the formula failed
+  #[pb(index = 1)]
+  pub content: String,
+
+  /// The reason why the formula failed, e.g. the referred field doesn't exist
+  #[pb(index = 2)]
+  pub error: String,
+}
+
+impl From<FormulaCellData> for FormulaCellDataPB {
+  fn from(data: FormulaCellData) -> Self {
+    Self {
+      content: data.content(),
+      error: data.error.unwrap_or_default(),
+    }
+  }
+}
+
+impl DecodedCellData for FormulaCellDataPB {
+  type Object = FormulaCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.content.is_empty()
+  }
+}
+
+/// The value that the formula calculated from the other cells of the row, or the error if the
+/// formula failed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormulaCellData {
+  pub value: FormulaValue,
+  pub error: Option<String>,
+}
+
+impl FormulaCellData {
+  /// Returns the string that is shown in the cell.
+  pub fn content(&self) -> String {
+    match self.error {
+      None => self.value.to_string(),
+      Some(_) => "".to_owned(),
+    }
+  }
+}
+
+impl From<Result<FormulaValue, FormulaError>> for FormulaCellData {
+  fn from(result: Result<FormulaValue, FormulaError>) -> Self {
+    match result {
+      Ok(value) => Self { value, error: None },
+      Err(err) => Self {
+        value: FormulaValue::Empty,
+        error: Some(err.0),
+      },
+    }
+  }
+}
+
+/// The formula cell is stored as json. The number is stored as a string to keep its precision.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+enum FormulaValueSerde {
+  Empty,
+  Number(String),
+  Text(String),
+  Date(i64),
+}
+
+#[derive(Serialize, Deserialize)]
+struct FormulaCellDataSerde {
+  value: FormulaValueSerde,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  error: Option<String>,
+}
+
+impl FromCellString for FormulaCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self> {
+    let data = match serde_json::from_str::<FormulaCellDataSerde>(s) {
+      Ok(data) => data,
+      Err(_) => return Ok(Self::default()),
+    };
+    let value = match data.value {
+      FormulaValueSerde::Empty => FormulaValue::Empty,
+      FormulaValueSerde::Number(number) => Decimal::from_str(&number)
+        .map(FormulaValue::Number)
+        .unwrap_or_default(),
+      FormulaValueSerde::Text(s) => FormulaValue::Text(s),
+      FormulaValueSerde::Date(timestamp) => FormulaValue::Date(timestamp),
+    };
+    Ok(Self {
+      value,
+      error: data.error,
+    })
+  }
+}
+
+impl ToString for FormulaCellData {
+  fn to_string(&self) -> String {
+    let value = match &self.value {
+      FormulaValue::Empty => FormulaValueSerde::Empty,
+      FormulaValue::Number(number) => FormulaValueSerde::Number(number.normalize().to_string()),
+      FormulaValue::Text(s) => FormulaValueSerde::Text(s.clone()),
+      FormulaValue::Date(timestamp) => FormulaValueSerde::Date(*timestamp),
+    };
+    let data = FormulaCellDataSerde {
+      value,
+      error: self.error.clone(),
+    };
+    serde_json::to_string(&data).unwrap()
+  }
+}
+
+impl DecodedCellData for FormulaCellData {
+  type Object = FormulaCellData;
+
+  fn is_empty(&self) -> bool {
+    self.content().is_empty()
+  }
+}
+
+pub struct FormulaCellDataParser();
+impl CellProtobufBlobParser for FormulaCellDataParser {
+  type Object = FormulaCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    FormulaCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+/// The calculated cell data, see [FormulaCellData::to_string]. The formula cells are only
+/// updated by the calculation, they can't be edited.
+pub type FormulaCellChangeset = String;
@@ -0,0 +1,9 @@
+#![allow(clippy::module_inception)]
+mod formula_expression;
+mod formula_tests;
+mod formula_type_option;
+mod formula_type_option_entities;
+
+pub use formula_expression::*;
+pub use formula_type_option::*;
+pub use formula_type_option_entities::*;
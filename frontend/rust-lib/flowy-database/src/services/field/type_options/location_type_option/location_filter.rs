@@ -0,0 +1,23 @@
+use crate::entities::{LocationFilterConditionPB, LocationFilterPB};
+use crate::services::field::LocationCellData;
+
+impl LocationFilterPB {
+  pub fn is_visible(&self, cell_data: &LocationCellData) -> bool {
+    match self.condition {
+      LocationFilterConditionPB::LocationIsEmpty => cell_data.0.is_none(),
+      LocationFilterConditionPB::LocationIsNotEmpty => cell_data.0.is_some(),
+      LocationFilterConditionPB::LocationWithin => self.is_within(cell_data),
+      // The empty cells are not within any distance
+      LocationFilterConditionPB::LocationNotWithin => {
+        cell_data.0.is_some() && !self.is_within(cell_data)
+      },
+    }
+  }
+
+  fn is_within(&self, cell_data: &LocationCellData) -> bool {
+    match &cell_data.0 {
+      None => false,
+      Some(location) => location.distance_km(self.latitude, self.longitude) <= self.distance_km,
+    }
+  }
+}
@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, LocationFilterConditionPB, LocationFilterPB};
+  use crate::services::cell::{
+    CellDataChangeset, FromCellChangesetString, ToCellChangesetString, TypeCellData,
+  };
+  use crate::services::field::{
+    haversine_distance_km, FieldBuilder, Location, LocationCellChangeset, LocationCellData,
+    LocationTypeOptionPB, RichTextTypeOptionBuilder, TypeOptionTransform,
+  };
+
+  const PARIS: (f64, f64) = (48.8566, 2.3522);
+  const LONDON: (f64, f64) = (51.5074, -0.1278);
+
+  fn location_cell(latitude: f64, longitude: f64) -> LocationCellData {
+    LocationCellData(Some(Location::new(latitude, longitude, "").unwrap()))
+  }
+
+  #[test]
+  fn location_distance_test() {
+    let distance = haversine_distance_km(PARIS.0, PARIS.1, LONDON.0, LONDON.1);
+    assert!((distance - 343.5).abs() < 1.0, "{}", distance);
+    assert_eq!(
+      haversine_distance_km(PARIS.0, PARIS.1, PARIS.0, PARIS.1),
+      0.0
+    );
+
+    // Half of the circumference of the earth
+    let distance = haversine_distance_km(0.0, 0.0, 0.0, 180.0);
+    assert!((distance - 20015.1).abs() < 1.0, "{}", distance);
+  }
+
+  #[test]
+  fn location_parse_coordinates_test() {
+    let location = Location::parse_coordinates(" 48.8584 , 2.2945").unwrap();
+    assert_eq!(location.latitude, 48.8584);
+    assert_eq!(location.longitude, 2.2945);
+    assert_eq!(location.format(2), "48.86, 2.29");
+
+    assert!(Location::parse_coordinates("Paris").is_none());
+    // The addresses that look like the coordinates without the decimal point
+    assert!(Location::parse_coordinates("10, 20").is_none());
+    assert!(Location::parse("10, 20").is_none());
+    assert!(LocationCellChangeset::from_changeset("10, 20".to_owned()).is_err());
+    assert_eq!(
+      Location::parse("Somewhere (10, 20)").unwrap(),
+      Location::new(10.0, 20.0, "Somewhere").unwrap()
+    );
+    assert!(Location::parse_coordinates("91.0, 0.0").is_none());
+    assert!(Location::parse_coordinates("0.0, 181.0").is_none());
+  }
+
+  #[test]
+  fn location_plain_str_test() {
+    let location = Location::new(48.8584, 2.2945, " Tour Eiffel ").unwrap();
+    let plain_str = location.to_plain_str();
+    assert_eq!(plain_str, "Tour Eiffel (48.8584, 2.2945)");
+    assert_eq!(Location::parse(&plain_str).unwrap(), location);
+
+    // The address may contain the parentheses
+    let location = Location::new(51.5074, -0.1278, "London (UK)").unwrap();
+    assert_eq!(Location::parse(&location.to_plain_str()).unwrap(), location);
+
+    // The coordinates are not rounded, and they are in the parentheses without the address
+    let location = Location::new(48.858370, 2.294481, "").unwrap();
+    assert_eq!(location.to_plain_str(), "(48.85837, 2.294481)");
+    assert_eq!(Location::parse(&location.to_plain_str()).unwrap(), location);
+    let location = Location::new(10.0, 20.0, "").unwrap();
+    assert_eq!(Location::parse(&location.to_plain_str()).unwrap(), location);
+    assert!(Location::parse("Tour Eiffel").is_none());
+    assert!(Location::parse("Tour Eiffel (91, 0)").is_none());
+
+    let type_option = LocationTypeOptionPB::default();
+    let changeset =
+      LocationCellChangeset::from_changeset("Tour Eiffel (48.8584, 2.2945)".to_owned()).unwrap();
+    let (_, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+    assert_eq!(cell_data.0.unwrap().address, "Tour Eiffel");
+  }
+
+  #[test]
+  fn location_changeset_test() {
+    let type_option = LocationTypeOptionPB::default();
+    let changeset = LocationCellChangeset::from_changeset("48.8584, 2.2945".to_owned()).unwrap();
+    let (cell_str, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+    assert_eq!(cell_data, location_cell(48.8584, 2.2945));
+
+    let type_cell_data = TypeCellData::new(cell_str, FieldType::Location);
+    let (_, cell_data) = type_option
+      .apply_changeset(LocationCellChangeset::Clear, Some(type_cell_data))
+      .unwrap();
+    assert_eq!(cell_data, LocationCellData(None));
+
+    let changeset = LocationCellChangeset::Set {
+      latitude: 120.0,
+      longitude: 0.0,
+      address: "".to_owned(),
+    };
+    assert!(type_option.apply_changeset(changeset, None).is_err());
+    assert!(LocationCellChangeset::from_changeset("Paris".to_owned()).is_err());
+  }
+
+  #[test]
+  fn location_changeset_string_test() {
+    let changeset = LocationCellChangeset::Set {
+      latitude: 48.8584,
+      longitude: 2.2945,
+      address: "Tour Eiffel".to_owned(),
+    };
+    assert_eq!(
+      LocationCellChangeset::from_changeset(changeset.to_cell_changeset_str()).unwrap(),
+      changeset
+    );
+    assert_eq!(
+      LocationCellChangeset::from_changeset("".to_owned()).unwrap(),
+      LocationCellChangeset::Clear
+    );
+  }
+
+  #[test]
+  fn location_filter_test() {
+    let mut filter = LocationFilterPB {
+      condition: LocationFilterConditionPB::LocationWithin,
+      latitude: PARIS.0,
+      longitude: PARIS.1,
+      distance_km: 50.0,
+    };
+    let paris = location_cell(48.8584, 2.2945);
+    let london = location_cell(LONDON.0, LONDON.1);
+    let empty = LocationCellData(None);
+    assert!(filter.is_visible(&paris));
+    assert!(!filter.is_visible(&london));
+    assert!(!filter.is_visible(&empty));
+
+    filter.condition = LocationFilterConditionPB::LocationNotWithin;
+    assert!(!filter.is_visible(&paris));
+    assert!(filter.is_visible(&london));
+    assert!(!filter.is_visible(&empty));
+
+    filter.condition = LocationFilterConditionPB::LocationIsEmpty;
+    assert!(filter.is_visible(&empty));
+    assert!(!filter.is_visible(&paris));
+
+    filter.condition = LocationFilterConditionPB::LocationIsNotEmpty;
+    assert!(!filter.is_visible(&empty));
+    assert!(filter.is_visible(&paris));
+  }
+
+  #[test]
+  fn location_transform_text_test() {
+    let type_option = LocationTypeOptionPB::default();
+    let field_rev = FieldBuilder::new(RichTextTypeOptionBuilder::default()).build();
+    assert_eq!(
+      type_option.transform_type_option_cell_str(
+        "48.8584, 2.2945",
+        &FieldType::RichText,
+        &field_rev
+      ),
+      Some(location_cell(48.8584, 2.2945))
+    );
+    assert_eq!(
+      type_option.transform_type_option_cell_str("Paris", &FieldType::RichText, &field_rev),
+      None
+    );
+  }
+}
@@ -0,0 +1,210 @@
+use crate::entities::{FieldType, LocationFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  default_order, BoxTypeOptionBuilder, Location, LocationCellChangeset, LocationCellData,
+  LocationCellDataPB, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+  TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+const DEFAULT_PRECISION: u32 = 5;
+
+#[derive(Default)]
+pub struct LocationTypeOptionBuilder(LocationTypeOptionPB);
+impl_into_box_type_option_builder!(LocationTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(LocationTypeOptionBuilder, LocationTypeOptionPB);
+
+impl LocationTypeOptionBuilder {
+  pub fn precision(mut self, precision: u32) -> Self {
+    self.0.precision = precision;
+    self
+  }
+}
+
+impl TypeOptionBuilder for LocationTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Location
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The location cell saves the coordinates of a place and its address. The cells without an
+/// address are displayed as their coordinates.
+#[derive(Debug, Clone, Serialize, Deserialize, ProtoBuf)]
+pub struct LocationTypeOptionPB {
+  /// The number of the decimal places of the displayed coordinates. Five decimal places are
+  /// about one meter.
+  #[pb(index = 1)]
+  #[serde(default = "default_precision")]
+  pub precision: u32,
+}
+impl_type_option!(LocationTypeOptionPB, FieldType::Location);
+
+fn default_precision() -> u32 {
+  DEFAULT_PRECISION
+}
+
+impl std::default::Default for LocationTypeOptionPB {
+  fn default() -> Self {
+    Self {
+      precision: DEFAULT_PRECISION,
+    }
+  }
+}
+
+impl TypeOption for LocationTypeOptionPB {
+  type CellData = LocationCellData;
+  type CellChangeset = LocationCellChangeset;
+  type CellProtobufType = LocationCellDataPB;
+  type CellFilter = LocationFilterPB;
+}
+
+impl TypeOptionTransform for LocationTypeOptionPB {
+  fn transformable(&self) -> bool {
+    true
+  }
+
+  /// The texts that are coordinates, e.g. "48.8584, 2.2945" or "Tour Eiffel (48.8584, 2.2945)",
+  /// are kept when the text field is converted. The other texts can't be converted without
+  /// geocoding them.
+  fn transform_type_option_cell_str(
+    &self,
+    cell_str: &str,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> Option<<Self as TypeOption>::CellData> {
+    if decoded_field_type.is_text() {
+      Location::parse(cell_str).map(|location| LocationCellData(Some(location)))
+    } else {
+      None
+    }
+  }
+}
+
+impl TypeOptionCellData for LocationTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    match cell_data.0 {
+      None => LocationCellDataPB {
+        is_empty: true,
+        ..Default::default()
+      },
+      Some(location) => LocationCellDataPB {
+        is_empty: false,
+        latitude: location.latitude,
+        longitude: location.longitude,
+        formatted: location.format(self.precision),
+        address: location.address,
+      },
+    }
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    LocationCellData::from_cell_str(&cell_str)
+  }
+}
+
+impl CellDataDecoder for LocationTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_location() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  /// Keeps the coordinates along with the address, so the exported cells can be imported
+  /// again without geocoding the addresses.
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data
+      .0
+      .map(|location| location.to_plain_str())
+      .unwrap_or_default()
+  }
+}
+
+impl CellDataChangeset for LocationTypeOptionPB {
+  /// Returns [FlowyError::invalid_data](flowy_error::FlowyError::invalid_data) if the
+  /// coordinates are out of range.
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let cell_data = match changeset {
+      LocationCellChangeset::Clear => LocationCellData(None),
+      LocationCellChangeset::Set {
+        latitude,
+        longitude,
+        address,
+      } => LocationCellData(Some(Location::new(latitude, longitude, &address)?)),
+    };
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for LocationTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_location() {
+      return true;
+    }
+
+    filter.is_visible(cell_data)
+  }
+}
+
+impl TypeOptionCellDataCompare for LocationTypeOptionPB {
+  /// The locations are sorted by their addresses, and then by their coordinates from north to
+  /// south and from west to east.
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    match (&cell_data.0, &other_cell_data.0) {
+      (Some(location), Some(other_location)) => location
+        .address
+        .to_lowercase()
+        .cmp(&other_location.address.to_lowercase())
+        .then_with(|| {
+          other_location
+            .latitude
+            .partial_cmp(&location.latitude)
+            .unwrap_or(Ordering::Equal)
+        })
+        .then_with(|| {
+          location
+            .longitude
+            .partial_cmp(&other_location.longitude)
+            .unwrap_or(Ordering::Equal)
+        }),
+      (Some(_), None) => Ordering::Less,
+      (None, Some(_)) => Ordering::Greater,
+      (None, None) => default_order(),
+    }
+  }
+}
@@ -0,0 +1,270 @@
+use crate::entities::{CellIdPB, CellIdParams};
+use crate::services::cell::{
+  CellProtobufBlobParser, DecodedCellData, FromCellChangesetString, FromCellString,
+  ToCellChangesetString,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+/// The mean radius of the earth, in kilometers
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+/// The place of the location cell. The coordinates are in degrees, and the address is the text
+/// that the user typed or the provider found.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+  pub latitude: f64,
+  pub longitude: f64,
+  #[serde(default)]
+  pub address: String,
+}
+
+impl Location {
+  /// Returns the location if the coordinates are on the earth, i.e. the latitude is in
+  /// [-90, 90] and the longitude is in [-180, 180].
+  pub fn new(latitude: f64, longitude: f64, address: &str) -> FlowyResult<Self> {
+    if !latitude.is_finite() || !(-90.0..=90.0).contains(&latitude) {
+      let msg = format!("The latitude:{} is out of range", latitude);
+      return Err(FlowyError::invalid_data().context(msg));
+    }
+    if !longitude.is_finite() || !(-180.0..=180.0).contains(&longitude) {
+      let msg = format!("The longitude:{} is out of range", longitude);
+      return Err(FlowyError::invalid_data().context(msg));
+    }
+    Ok(Self {
+      latitude,
+      longitude,
+      address: address.trim().to_owned(),
+    })
+  }
+
+  /// Parses the coordinates typed as "latitude, longitude", e.g. "48.8584, 2.2945". Both of
+  /// them need the decimal point, so the addresses such as "10, 20" are not taken as
+  /// coordinates.
+  pub fn parse_coordinates(s: &str) -> Option<Self> {
+    let (latitude, longitude) = s.split_once(',')?;
+    if !latitude.contains('.') || !longitude.contains('.') {
+      return None;
+    }
+    Self::parse_latitude_and_longitude(s)
+  }
+
+  fn parse_latitude_and_longitude(s: &str) -> Option<Self> {
+    let (latitude, longitude) = s.split_once(',')?;
+    let latitude = latitude.trim().parse::<f64>().ok()?;
+    let longitude = longitude.trim().parse::<f64>().ok()?;
+    Self::new(latitude, longitude, "").ok()
+  }
+
+  /// Parses the text written by [Location::to_plain_str], i.e. the coordinates in parentheses
+  /// optionally preceded by the address, e.g. "Tour Eiffel (48.8584, 2.2945)". The coordinates
+  /// typed without the parentheses are parsed by [Location::parse_coordinates].
+  pub fn parse(s: &str) -> Option<Self> {
+    let s = s.trim();
+    if let Some(location) = Self::parse_coordinates(s) {
+      return Some(location);
+    }
+    // The coordinates in the parentheses are not an address, so they don't need the decimal point
+    let (address, coordinates) = s.strip_suffix(')')?.rsplit_once('(')?;
+    let location = Self::parse_latitude_and_longitude(coordinates)?;
+    Self::new(location.latitude, location.longitude, address).ok()
+  }
+
+  /// Returns the text that keeps both the address and the coordinates, e.g. the value of the
+  /// exported cell. The coordinates are written in full instead of with the precision of the
+  /// field, so the location parsed back by [Location::parse] is the same.
+  pub fn to_plain_str(&self) -> String {
+    let coordinates = format!("({}, {})", self.latitude, self.longitude);
+    if self.address.is_empty() {
+      coordinates
+    } else {
+      format!("{} {}", self.address, coordinates)
+    }
+  }
+
+  /// Returns the great-circle distance to the coordinates, in kilometers.
+  pub fn distance_km(&self, latitude: f64, longitude: f64) -> f64 {
+    haversine_distance_km(self.latitude, self.longitude, latitude, longitude)
+  }
+
+  /// Returns the address, or the coordinates with the `precision` if the address is empty.
+  pub fn format(&self, precision: u32) -> String {
+    if !self.address.is_empty() {
+      return self.address.clone();
+    }
+    let precision = precision as usize;
+    format!(
+      "{:.*}, {:.*}",
+      precision, self.latitude, precision, self.longitude
+    )
+  }
+}
+
+/// Returns the great-circle distance between two coordinates, in kilometers, with the haversine
+/// formula.
+pub fn haversine_distance_km(lat1: f64, lng1: f64, lat2: f64, lng2: f64) -> f64 {
+  let (lat1, lat2) = (lat1.to_radians(), lat2.to_radians());
+  let delta_lat = lat2 - lat1;
+  let delta_lng = (lng2 - lng1).to_radians();
+  let a =
+    (delta_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (delta_lng / 2.0).sin().powi(2);
+  2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+}
+
+/// The data of the location cell. It's None if the cell is empty.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LocationCellData(pub Option<Location>);
+
+impl FromCellString for LocationCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self> {
+    if s.trim().is_empty() {
+      return Ok(Self::default());
+    }
+    let location = serde_json::from_str::<Location>(s).map_err(internal_error)?;
+    Ok(Self(Some(location)))
+  }
+}
+
+impl ToString for LocationCellData {
+  fn to_string(&self) -> String {
+    match &self.0 {
+      None => "".to_owned(),
+      Some(location) => serde_json::to_string(location).unwrap_or_default(),
+    }
+  }
+}
+
+impl DecodedCellData for LocationCellData {
+  type Object = LocationCellData;
+
+  fn is_empty(&self) -> bool {
+    self.0.is_none()
+  }
+}
+
+/// The changeset of the location cell. The typed changesets that are not JSON are parsed as
+/// "latitude, longitude". The addresses can't be saved without their coordinates, they are
+/// geocoded by the [DatabaseManager](crate::manager::DatabaseManager) before the changeset is
+/// applied.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum LocationCellChangeset {
+  Set {
+    latitude: f64,
+    longitude: f64,
+    #[serde(default)]
+    address: String,
+  },
+  Clear,
+}
+
+impl FromCellChangesetString for LocationCellChangeset {
+  fn from_changeset(changeset: String) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    if let Ok(changeset) = serde_json::from_str::<LocationCellChangeset>(&changeset) {
+      return Ok(changeset);
+    }
+    if changeset.trim().is_empty() {
+      return Ok(Self::Clear);
+    }
+    match Location::parse(&changeset) {
+      None => {
+        let msg = format!(
+          "{} is not a coordinate, the address must be geocoded",
+          changeset
+        );
+        Err(FlowyError::invalid_data().context(msg))
+      },
+      Some(location) => Ok(Self::Set {
+        latitude: location.latitude,
+        longitude: location.longitude,
+        address: location.address,
+      }),
+    }
+  }
+}
+
+impl ToCellChangesetString for LocationCellChangeset {
+  fn to_cell_changeset_str(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
+
+/// [LocationCellDataPB] is the location of the cell for the maps. The coordinates are zero if
+/// the cell is empty.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct LocationCellDataPB {
+  #[pb(index = 1)]
+  pub is_empty: bool,
+
+  #[pb(index = 2)]
+  pub latitude: f64,
+
+  #[pb(index = 3)]
+  pub longitude: f64,
+
+  #[pb(index = 4)]
+  pub address: String,
+
+  /// The address, or the coordinates formatted with the precision of the field
+  #[pb(index = 5)]
+  pub formatted: String,
+}
+
+pub struct LocationCellDataParser();
+impl CellProtobufBlobParser for LocationCellDataParser {
+  type Object = LocationCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    LocationCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct LocationCoordinatesPB {
+  #[pb(index = 1)]
+  pub latitude: f64,
+
+  #[pb(index = 2)]
+  pub longitude: f64,
+}
+
+/// [LocationCellChangesetPB] sets the location of the cell by its coordinates, or by its address
+/// if the coordinates are None. The address is geocoded by the geocoding provider of the
+/// application. The cell is cleared if both are empty.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct LocationCellChangesetPB {
+  #[pb(index = 1)]
+  pub cell_id: CellIdPB,
+
+  #[pb(index = 2, one_of)]
+  pub coordinates: Option<LocationCoordinatesPB>,
+
+  #[pb(index = 3)]
+  pub address: String,
+}
+
+pub struct LocationCellChangesetParams {
+  pub cell_id: CellIdParams,
+  pub coordinates: Option<(f64, f64)>,
+  pub address: String,
+}
+
+impl TryInto<LocationCellChangesetParams> for LocationCellChangesetPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<LocationCellChangesetParams, Self::Error> {
+    let cell_id: CellIdParams = self.cell_id.try_into()?;
+    Ok(LocationCellChangesetParams {
+      cell_id,
+      coordinates: self
+        .coordinates
+        .map(|coordinates| (coordinates.latitude, coordinates.longitude)),
+      address: self.address.trim().to_owned(),
+    })
+  }
+}
@@ -0,0 +1,9 @@
+#![allow(clippy::module_inception)]
+mod location_filter;
+mod location_tests;
+mod location_type_option;
+mod location_type_option_entities;
+
+pub use location_filter::*;
+pub use location_type_option::*;
+pub use location_type_option_entities::*;
@@ -1,17 +1,45 @@
+pub mod attachment_type_option;
+pub mod auto_number_type_option;
 pub mod checkbox_type_option;
 pub mod date_type_option;
+pub mod duration_type_option;
+pub mod email_type_option;
+pub mod formula_type_option;
+pub mod location_type_option;
 pub mod number_type_option;
+pub mod person_type_option;
+pub mod phone_type_option;
+pub mod progress_type_option;
+pub mod relation_type_option;
+pub mod rollup_type_option;
 pub mod selection_type_option;
 pub mod text_type_option;
+pub mod time_tracking_type_option;
+pub mod timestamp_type_option;
 mod type_option;
 mod type_option_cell;
+mod type_option_registry;
 pub mod url_type_option;
 
+pub use attachment_type_option::*;
+pub use auto_number_type_option::*;
 pub use checkbox_type_option::*;
 pub use date_type_option::*;
+pub use duration_type_option::*;
+pub use email_type_option::*;
+pub use formula_type_option::*;
+pub use location_type_option::*;
 pub use number_type_option::*;
+pub use person_type_option::*;
+pub use phone_type_option::*;
+pub use progress_type_option::*;
+pub use relation_type_option::*;
+pub use rollup_type_option::*;
 pub use selection_type_option::*;
 pub use text_type_option::*;
+pub use time_tracking_type_option::*;
+pub use timestamp_type_option::*;
 pub use type_option::*;
 pub use type_option_cell::*;
+pub use type_option_registry::*;
 pub use url_type_option::*;
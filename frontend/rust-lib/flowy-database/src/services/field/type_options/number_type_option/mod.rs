@@ -1,10 +1,12 @@
 #![allow(clippy::module_inception)]
 mod format;
 mod number_filter;
+mod number_locale;
 mod number_tests;
 mod number_type_option;
 mod number_type_option_entities;
 
 pub use format::*;
+pub use number_locale::*;
 pub use number_type_option::*;
 pub use number_type_option_entities::*;
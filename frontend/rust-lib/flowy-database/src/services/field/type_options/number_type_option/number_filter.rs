@@ -1,6 +1,6 @@
 use crate::entities::{NumberFilterConditionPB, NumberFilterPB};
 
-use crate::services::field::NumberCellData;
+use crate::services::field::{strip_currency_symbol, NumberCellData};
 
 use rust_decimal::prelude::Zero;
 use rust_decimal::Decimal;
@@ -22,6 +22,9 @@ impl NumberFilterPB {
     match num_cell_data.decimal().as_ref() {
       None => false,
       Some(cell_decimal) => {
+        if self.condition.is_range() {
+          return self.is_visible_in_range(cell_decimal);
+        }
         let decimal = Decimal::from_str(&self.content).unwrap_or_else(|_| Decimal::zero());
         match self.condition {
           NumberFilterConditionPB::Equal => cell_decimal == &decimal,
@@ -35,6 +38,47 @@ impl NumberFilterPB {
       },
     }
   }
+
+  /// The empty or invalid bound leaves that side of the range open. The bounds are swapped if
+  /// the lower one is greater than the upper one.
+  fn is_visible_in_range(&self, cell_decimal: &Decimal) -> bool {
+    let mut start = parse_filter_decimal(&self.content);
+    let mut end = self
+      .end_content
+      .as_ref()
+      .and_then(|end_content| parse_filter_decimal(end_content));
+    if let (Some(start_decimal), Some(end_decimal)) = (start, end) {
+      if start_decimal > end_decimal {
+        start = Some(end_decimal);
+        end = Some(start_decimal);
+      }
+    }
+    let is_between = start.map_or(true, |start| cell_decimal >= &start)
+      && end.map_or(true, |end| cell_decimal <= &end);
+    match self.condition {
+      NumberFilterConditionPB::NumberIsNotBetween => !is_between,
+      _ => is_between,
+    }
+  }
+}
+
+/// Parses the bound of the range, which may be formatted like the cells, e.g. `$1,000.50`.
+fn parse_filter_decimal(s: &str) -> Option<Decimal> {
+  let s = s.trim();
+  let (sign_positive, s) = match s.strip_prefix('-') {
+    Some(s) => (false, s),
+    None => (true, s),
+  };
+  let mut num_str = strip_currency_symbol(s.trim());
+  num_str.retain(|c| !c.is_whitespace() && c != ',');
+  if num_str.is_empty() {
+    return None;
+  }
+  let mut decimal = Decimal::from_str(&num_str).ok()?;
+  if !sign_positive {
+    decimal.set_sign_negative(true);
+  }
+  Some(decimal)
 }
 
 #[cfg(test)]
@@ -46,6 +90,7 @@ mod tests {
     let number_filter = NumberFilterPB {
       condition: NumberFilterConditionPB::Equal,
       content: "123".to_owned(),
+      end_content: None,
     };
 
     for (num_str, visible) in [("123", true), ("1234", false), ("", false)] {
@@ -64,6 +109,7 @@ mod tests {
     let number_filter = NumberFilterPB {
       condition: NumberFilterConditionPB::GreaterThan,
       content: "12".to_owned(),
+      end_content: None,
     };
     for (num_str, visible) in [("123", true), ("10", false), ("30", true), ("", false)] {
       let data = NumberCellData::from_format_str(num_str, true, &NumberFormat::Num).unwrap();
@@ -76,10 +122,61 @@ mod tests {
     let number_filter = NumberFilterPB {
       condition: NumberFilterConditionPB::LessThan,
       content: "100".to_owned(),
+      end_content: None,
     };
     for (num_str, visible) in [("12", true), ("1234", false), ("30", true), ("", false)] {
       let data = NumberCellData::from_format_str(num_str, true, &NumberFormat::Num).unwrap();
       assert_eq!(number_filter.is_visible(&data), visible);
     }
   }
+
+  #[test]
+  fn number_filter_between_test() {
+    let mut number_filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::NumberIsBetween,
+      content: "10".to_owned(),
+      end_content: Some("$1,000".to_owned()),
+    };
+    let format = NumberFormat::USD;
+    for (num_str, visible) in [
+      ("$10", true),
+      ("$1,000", true),
+      ("$9.99", false),
+      ("$1,000.01", false),
+      ("", false),
+    ] {
+      let data = NumberCellData::from_format_str(num_str, true, &format).unwrap();
+      assert_eq!(number_filter.is_visible(&data), visible, "{}", num_str);
+    }
+
+    // The bounds are swapped
+    number_filter.content = "1000".to_owned();
+    number_filter.end_content = Some("10".to_owned());
+    let data = NumberCellData::from_format_str("500", true, &NumberFormat::Num).unwrap();
+    assert!(number_filter.is_visible(&data));
+
+    // The empty bound is open
+    number_filter.end_content = None;
+    let data = NumberCellData::from_format_str("5000", true, &NumberFormat::Num).unwrap();
+    assert!(number_filter.is_visible(&data));
+  }
+
+  #[test]
+  fn number_filter_not_between_test() {
+    let number_filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::NumberIsNotBetween,
+      content: "1".to_owned(),
+      end_content: Some("5".to_owned()),
+    };
+    for (num_str, visible) in [
+      ("0", true),
+      ("3", false),
+      ("5", false),
+      ("6", true),
+      ("", false),
+    ] {
+      let data = NumberCellData::from_format_str(num_str, true, &NumberFormat::Num).unwrap();
+      assert_eq!(number_filter.is_visible(&data), visible);
+    }
+  }
 }
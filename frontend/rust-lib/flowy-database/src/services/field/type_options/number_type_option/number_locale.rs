@@ -0,0 +1,82 @@
+use flowy_derive::ProtoBuf_Enum;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use strum_macros::EnumIter;
+
+/// The locale that the numbers are formatted in. The numbers are saved without the separators of
+/// the locale, so the locale can be changed without touching the cells.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumIter, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum NumberLocalePB {
+  /// The numbers are formatted by their currency, see [NumberFormat::currency](crate::services::field::NumberFormat::currency)
+  Default = 0,
+  /// 1,234.56
+  EnUS = 1,
+  /// 1.234,56
+  DeDE = 2,
+  /// 1 234,56
+  FrFR = 3,
+  /// 1'234.56
+  DeCH = 4,
+}
+
+impl std::default::Default for NumberLocalePB {
+  fn default() -> Self {
+    NumberLocalePB::Default
+  }
+}
+
+impl NumberLocalePB {
+  /// Returns the decimal separator and the thousands separator of the locale, or None for the
+  /// [NumberLocalePB::Default].
+  pub fn separators(&self) -> Option<(char, char)> {
+    match self {
+      NumberLocalePB::Default => None,
+      NumberLocalePB::EnUS => Some(('.', ',')),
+      NumberLocalePB::DeDE => Some((',', '.')),
+      NumberLocalePB::FrFR => Some((',', ' ')),
+      NumberLocalePB::DeCH => Some(('.', '\'')),
+    }
+  }
+
+  /// Formats the absolute value of the decimal with the separators of the locale.
+  pub(crate) fn format_decimal(&self, decimal: Decimal) -> String {
+    let s = decimal.abs().to_string();
+    let (decimal_separator, thousands_separator) = match self.separators() {
+      None => return s,
+      Some(separators) => separators,
+    };
+    let (integer, fraction) = match s.split_once('.') {
+      None => (s.as_str(), None),
+      Some((integer, fraction)) => (integer, Some(fraction)),
+    };
+
+    let mut formatted = String::new();
+    for (index, digit) in integer.chars().enumerate() {
+      if index > 0 && (integer.len() - index) % 3 == 0 {
+        formatted.push(thousands_separator);
+      }
+      formatted.push(digit);
+    }
+    if let Some(fraction) = fraction {
+      formatted.push(decimal_separator);
+      formatted.push_str(fraction);
+    }
+    formatted
+  }
+
+  /// Converts the number typed in the locale into the number that can be parsed, e.g. "1.234,5"
+  /// becomes "1234.5" in [NumberLocalePB::DeDE]. The other characters are kept.
+  pub(crate) fn delocalize(&self, s: &str) -> String {
+    let (decimal_separator, thousands_separator) = match self.separators() {
+      None => return s.to_owned(),
+      Some(separators) => separators,
+    };
+    s.trim()
+      .chars()
+      .filter(|c| {
+        *c != thousands_separator && !(thousands_separator.is_whitespace() && c.is_whitespace())
+      })
+      .map(|c| if c == decimal_separator { '.' } else { c })
+      .collect()
+  }
+}
@@ -1,11 +1,14 @@
 #[cfg(test)]
 mod tests {
   use crate::entities::FieldType;
-  use crate::services::cell::CellDataDecoder;
-  use crate::services::field::FieldBuilder;
+  use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+  use crate::services::field::{FieldBuilder, TypeOptionCellDataCompare};
 
-  use crate::services::field::{strip_currency_symbol, NumberFormat, NumberTypeOptionPB};
+  use crate::services::field::{
+    strip_currency_symbol, NumberFormat, NumberLocalePB, NumberTypeOptionPB, NumberUnitPositionPB,
+  };
   use database_model::FieldRevision;
+  use std::cmp::Ordering;
   use strum::IntoEnumIterator;
 
   /// Testing when the input is not a number.
@@ -660,6 +663,100 @@ mod tests {
     }
   }
 
+  /// The unit is displayed next to the number but it's not saved, and it doesn't affect the order.
+  #[test]
+  fn number_type_option_unit_test() {
+    let mut type_option = NumberTypeOptionPB {
+      unit: "kg".to_owned(),
+      ..Default::default()
+    };
+    let field_type = FieldType::Number;
+    let field_rev = FieldBuilder::from_field_type(&field_type).build();
+    assert_number(&type_option, "18443", "18443 kg", &field_type, &field_rev);
+    assert_number(
+      &type_option,
+      "18443 kg",
+      "18443 kg",
+      &field_type,
+      &field_rev,
+    );
+    assert_number(&type_option, "", "", &field_type, &field_rev);
+
+    let (cell_str, cell_data) = type_option
+      .apply_changeset("9.5 kg".to_owned(), None)
+      .unwrap();
+    assert_eq!(cell_str, "9.5");
+    assert_eq!(cell_data.to_string(), "9.5 kg");
+    assert_eq!(type_option.decode_cell_data_to_str(cell_data), "9.5 kg");
+
+    let ordering = type_option.apply_cmp(&"10 kg".to_owned().into(), &"9 kg".to_owned().into());
+    assert_eq!(ordering, Ordering::Greater);
+
+    type_option.unit_position = NumberUnitPositionPB::Prefix;
+    assert_number(&type_option, "18443", "kg 18443", &field_type, &field_rev);
+
+    type_option.unit = "%".to_owned();
+    type_option.unit_position = NumberUnitPositionPB::Suffix;
+    assert_number(&type_option, "18443", "18443%", &field_type, &field_rev);
+
+    type_option.set_format(NumberFormat::USD);
+    type_option.unit = "h".to_owned();
+    assert_number(
+      &type_option,
+      "$18,443 h",
+      "$18,443 h",
+      &field_type,
+      &field_rev,
+    );
+  }
+
+  /// The numbers are typed and displayed with the separators of the locale, but they are saved
+  /// without them.
+  #[test]
+  fn number_type_option_locale_test() {
+    let mut type_option = NumberTypeOptionPB {
+      locale: NumberLocalePB::DeDE,
+      ..Default::default()
+    };
+    let (cell_str, cell_data) = type_option
+      .apply_changeset("1.234,56".to_owned(), None)
+      .unwrap();
+    assert_eq!(cell_str, "1234.56");
+    assert_eq!(cell_data.to_string(), "1.234,56");
+    assert_eq!(type_option.decode_cell_data_to_str(cell_data), "1.234,56");
+
+    let ordering = type_option.apply_cmp(&"1.000,5".to_owned().into(), &"999,5".to_owned().into());
+    assert_eq!(ordering, Ordering::Greater);
+
+    type_option.set_format(NumberFormat::EUR);
+    let (cell_str, cell_data) = type_option
+      .apply_changeset("€1.234,5".to_owned(), None)
+      .unwrap();
+    assert_eq!(cell_str, "1234.5");
+    assert_eq!(cell_data.to_string(), "€1.234,5");
+
+    type_option.precision = Some(2);
+    let (_, cell_data) = type_option
+      .apply_changeset("1234567,891".to_owned(), None)
+      .unwrap();
+    assert_eq!(cell_data.to_string(), "€1.234.567,89");
+
+    type_option.set_format(NumberFormat::Num);
+    type_option.locale = NumberLocalePB::FrFR;
+    let (cell_str, cell_data) = type_option
+      .apply_changeset("1 234,5".to_owned(), None)
+      .unwrap();
+    assert_eq!(cell_str, "1234.5");
+    assert_eq!(cell_data.to_string(), "1 234,50");
+
+    type_option.locale = NumberLocalePB::DeCH;
+    type_option.precision = Some(0);
+    let (_, cell_data) = type_option
+      .apply_changeset("-1'234'567.5".to_owned(), None)
+      .unwrap();
+    assert_eq!(cell_data.to_string(), "-1'234'568");
+  }
+
   fn assert_number(
     type_option: &NumberTypeOptionPB,
     input_str: &str,
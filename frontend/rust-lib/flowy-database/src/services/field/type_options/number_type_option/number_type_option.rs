@@ -3,16 +3,17 @@ use crate::impl_type_option;
 use crate::services::cell::{CellDataChangeset, CellDataDecoder, TypeCellData};
 use crate::services::field::type_options::number_type_option::format::*;
 use crate::services::field::{
-  BoxTypeOptionBuilder, NumberCellData, StrCellData, TypeOption, TypeOptionBuilder,
+  default_order, BoxTypeOptionBuilder, NumberCellData, StrCellData, TypeOption, TypeOptionBuilder,
   TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
 };
 use bytes::Bytes;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
 use fancy_regex::Regex;
-use flowy_derive::ProtoBuf;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use flowy_error::FlowyResult;
 use lazy_static::lazy_static;
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
+use rusty_money::Money;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::default::Default;
@@ -43,6 +44,22 @@ impl NumberTypeOptionBuilder {
     self.0.sign_positive = positive;
     self
   }
+
+  pub fn unit(mut self, unit: &str, unit_position: NumberUnitPositionPB) -> Self {
+    self.0.unit = unit.to_string();
+    self.0.unit_position = unit_position;
+    self
+  }
+
+  pub fn locale(mut self, locale: NumberLocalePB) -> Self {
+    self.0.locale = locale;
+    self
+  }
+
+  pub fn precision(mut self, precision: Option<u32>) -> Self {
+    self.0.precision = precision;
+    self
+  }
 }
 
 impl TypeOptionBuilder for NumberTypeOptionBuilder {
@@ -72,9 +89,42 @@ pub struct NumberTypeOptionPB {
 
   #[pb(index = 5)]
   pub name: String,
+
+  /// The text displayed next to the number, like "kg", "%" or "h". It's only used for display,
+  /// the cells are saved, filtered and sorted without it.
+  #[pb(index = 6)]
+  #[serde(default)]
+  pub unit: String,
+
+  #[pb(index = 7)]
+  #[serde(default)]
+  pub unit_position: NumberUnitPositionPB,
+
+  /// The separators of the numbers. The numbers are typed and displayed with them.
+  #[pb(index = 8)]
+  #[serde(default)]
+  pub locale: NumberLocalePB,
+
+  /// The number of the digits after the decimal point that are displayed. The numbers are
+  /// displayed as they are saved if it's None.
+  #[pb(index = 9, one_of)]
+  #[serde(default)]
+  pub precision: Option<u32>,
 }
 impl_type_option!(NumberTypeOptionPB, FieldType::Number);
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum NumberUnitPositionPB {
+  Suffix = 0,
+  Prefix = 1,
+}
+
+impl std::default::Default for NumberUnitPositionPB {
+  fn default() -> Self {
+    NumberUnitPositionPB::Suffix
+  }
+}
+
 impl TypeOption for NumberTypeOptionPB {
   type CellData = StrCellData;
   type CellChangeset = NumberCellChangeset;
@@ -104,6 +154,7 @@ impl NumberTypeOptionPB {
   }
 
   pub(crate) fn format_cell_data(&self, s: &str) -> FlowyResult<NumberCellData> {
+    let s = self.strip_unit(s);
     match self.format {
       NumberFormat::Num => {
         if SCIENTIFIC_NOTATION_REGEX.is_match(s).unwrap() {
@@ -129,10 +180,111 @@ impl NumberTypeOptionPB {
     }
   }
 
+  /// Removes the unit and the separators of the locale from `s`, so the number typed or displayed
+  /// in the locale can be parsed by [Self::format_cell_data].
+  pub(crate) fn normalize_str(&self, s: &str) -> String {
+    match self.locale.separators() {
+      None => self.strip_unit(s).to_string(),
+      Some(_) => self.strip_symbol(&self.locale.delocalize(self.strip_unit(s))),
+    }
+  }
+
+  /// Parses the number that is displayed by [Self::number_to_str].
+  pub(crate) fn parse_display_str(&self, s: &str) -> FlowyResult<NumberCellData> {
+    self.format_cell_data(&self.normalize_str(s))
+  }
+
+  /// Returns the display string of the number, formatted with the separators of the locale and
+  /// rounded to the precision.
+  pub(crate) fn number_to_str(&self, number_cell_data: &NumberCellData) -> String {
+    let mut decimal = match number_cell_data.decimal() {
+      None => return number_cell_data.to_string(),
+      Some(decimal) => *decimal,
+    };
+    if let Some(precision) = self.precision {
+      decimal = decimal.round_dp_with_strategy(precision, RoundingStrategy::MidpointAwayFromZero);
+      decimal.rescale(precision);
+    }
+
+    if self.locale.separators().is_none() {
+      return match (self.precision, self.format) {
+        (None, _) => number_cell_data.to_string(),
+        (Some(_), NumberFormat::Num) => decimal.to_string(),
+        (Some(_), format) => {
+          NumberCellData::from_money(Money::from_decimal(decimal, format.currency())).to_string()
+        },
+      };
+    }
+
+    let sign = if decimal.is_sign_negative() && !decimal.is_zero() {
+      "-"
+    } else {
+      ""
+    };
+    let number = self.locale.format_decimal(decimal);
+    match self.format {
+      NumberFormat::Num => format!("{}{}", sign, number),
+      format => {
+        let currency = format.currency();
+        if currency.symbol_first {
+          format!("{}{}{}", sign, currency.symbol, number)
+        } else {
+          format!("{}{}{}", sign, number, currency.symbol)
+        }
+      },
+    }
+  }
+
+  /// Removes the currency symbol of the format from either side of the number.
+  fn strip_symbol(&self, s: &str) -> String {
+    if self.format == NumberFormat::Num {
+      return s.to_owned();
+    }
+    let symbol = self.format.symbol();
+    let (sign, s) = match s.strip_prefix('-') {
+      None => ("", s),
+      Some(s) => ("-", s),
+    };
+    let s = s
+      .strip_prefix(symbol.as_str())
+      .or_else(|| s.strip_suffix(symbol.as_str()))
+      .unwrap_or(s);
+    format!("{}{}", sign, s.trim())
+  }
+
   pub fn set_format(&mut self, format: NumberFormat) {
     self.format = format;
     self.symbol = format.symbol();
   }
+
+  /// Adds the unit to the formatted number. The unit is separated by a space unless it's a
+  /// single symbol, for example "5 kg" but "5%".
+  pub(crate) fn apply_unit(&self, s: String) -> String {
+    let unit = self.unit.trim();
+    if unit.is_empty() || s.is_empty() {
+      return s;
+    }
+
+    let is_symbol = unit.chars().count() == 1 && !unit.chars().all(char::is_alphanumeric);
+    let separator = if is_symbol { "" } else { " " };
+    match self.unit_position {
+      NumberUnitPositionPB::Suffix => format!("{}{}{}", s, separator, unit),
+      NumberUnitPositionPB::Prefix => format!("{}{}{}", unit, separator, s),
+    }
+  }
+
+  /// Removes the unit from either side of `s`, so the number typed with the unit can be parsed.
+  pub(crate) fn strip_unit<'a>(&self, s: &'a str) -> &'a str {
+    let s = s.trim();
+    let unit = self.unit.trim();
+    if unit.is_empty() {
+      return s;
+    }
+    s.strip_prefix(unit)
+      .or_else(|| s.strip_suffix(unit))
+      .unwrap_or(s)
+      .trim()
+  }
 }
 
 pub(crate) fn strip_currency_symbol<T: ToString>(s: T) -> String {
@@ -160,13 +312,13 @@ impl CellDataDecoder for NumberTypeOptionPB {
     }
 
     let str_cell_data = self.decode_type_option_cell_str(cell_str)?;
-    let s = self.format_cell_data(&str_cell_data)?.to_string();
-    Ok(s.into())
+    let s = self.number_to_str(&self.format_cell_data(&str_cell_data)?);
+    Ok(self.apply_unit(s).into())
   }
 
   fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
-    match self.format_cell_data(&cell_data) {
-      Ok(cell_data) => cell_data.to_string(),
+    match self.parse_display_str(&cell_data) {
+      Ok(cell_data) => self.apply_unit(self.number_to_str(&cell_data)),
       Err(_) => "".to_string(),
     }
   }
@@ -180,15 +332,16 @@ impl CellDataChangeset for NumberTypeOptionPB {
     changeset: <Self as TypeOption>::CellChangeset,
     _type_cell_data: Option<TypeCellData>,
   ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
-    let data = changeset.trim().to_string();
+    // The number is saved without the separators of the locale
+    let data = self.normalize_str(&changeset);
     let number_cell_data = self.format_cell_data(&data)?;
+    let cell_data = self
+      .apply_unit(self.number_to_str(&number_cell_data))
+      .into();
 
     match self.format {
-      NumberFormat::Num => Ok((
-        number_cell_data.to_string(),
-        number_cell_data.to_string().into(),
-      )),
-      _ => Ok((data, number_cell_data.to_string().into())),
+      NumberFormat::Num => Ok((number_cell_data.to_string(), cell_data)),
+      _ => Ok((data, cell_data)),
     }
   }
 }
@@ -203,11 +356,19 @@ impl TypeOptionCellDataFilter for NumberTypeOptionPB {
     if !field_type.is_number() {
       return true;
     }
-    match self.format_cell_data(cell_data) {
+    match self.parse_display_str(cell_data) {
       Ok(cell_data) => filter.is_visible(&cell_data),
       Err(_) => true,
     }
   }
+
+  /// The cell that isn't a number is empty, e.g. the text left after changing the field type
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    self
+      .parse_display_str(cell_data)
+      .map(|cell_data| cell_data.is_empty())
+      .unwrap_or(true)
+  }
 }
 
 impl TypeOptionCellDataCompare for NumberTypeOptionPB {
@@ -216,7 +377,19 @@ impl TypeOptionCellDataCompare for NumberTypeOptionPB {
     cell_data: &<Self as TypeOption>::CellData,
     other_cell_data: &<Self as TypeOption>::CellData,
   ) -> Ordering {
-    cell_data.0.cmp(&other_cell_data.0)
+    // Compare the numbers instead of the display strings that might contain the unit.
+    let decimal = |cell_data: &StrCellData| {
+      self
+        .parse_display_str(cell_data)
+        .ok()
+        .and_then(|number_cell_data| *number_cell_data.decimal())
+    };
+    match (decimal(cell_data), decimal(other_cell_data)) {
+      (Some(left), Some(right)) => left.cmp(&right),
+      (Some(_), None) => Ordering::Greater,
+      (None, Some(_)) => Ordering::Less,
+      (None, None) => default_order(),
+    }
   }
 }
 impl std::default::Default for NumberTypeOptionPB {
@@ -229,6 +402,10 @@ impl std::default::Default for NumberTypeOptionPB {
       symbol,
       sign_positive: true,
       name: "Number".to_string(),
+      unit: "".to_string(),
+      unit_position: NumberUnitPositionPB::default(),
+      locale: NumberLocalePB::default(),
+      precision: None,
     }
   }
 }
@@ -0,0 +1,51 @@
+use crate::entities::{PersonFilterConditionPB, PersonFilterPB};
+use crate::services::field::{PersonCellData, PersonPB};
+use std::sync::RwLock;
+
+static CURRENT_USER: RwLock<Option<PersonPB>> = RwLock::new(None);
+
+/// Sets the user that the [PersonFilterConditionPB::ContainsCurrentUser] filters look for, and
+/// that is added to the members of the person fields. It's called when the user signs in.
+pub fn set_current_user(user_id: &str, name: &str) {
+  *CURRENT_USER.write().unwrap() = Some(PersonPB::new(user_id, name));
+}
+
+/// Called when the user signs out, so the filters don't match the previous user.
+pub fn clear_current_user() {
+  *CURRENT_USER.write().unwrap() = None;
+}
+
+pub fn current_user() -> Option<PersonPB> {
+  CURRENT_USER.read().unwrap().clone()
+}
+
+/// Returns an empty string if no user is signed in.
+pub fn current_user_id() -> String {
+  current_user().map(|person| person.id).unwrap_or_default()
+}
+
+impl PersonFilterPB {
+  pub fn is_visible(&self, cell_data: &PersonCellData, current_user_id: &str) -> bool {
+    match self.condition {
+      PersonFilterConditionPB::Is => {
+        // The filter that has no members doesn't hide any row
+        if self.user_ids.is_empty() {
+          return true;
+        }
+
+        cell_data
+          .user_ids
+          .iter()
+          .any(|user_id| self.user_ids.contains(user_id))
+      },
+      PersonFilterConditionPB::IsNot => !cell_data
+        .user_ids
+        .iter()
+        .any(|user_id| self.user_ids.contains(user_id)),
+      PersonFilterConditionPB::ContainsCurrentUser => cell_data
+        .user_ids
+        .iter()
+        .any(|user_id| user_id == current_user_id),
+    }
+  }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, PersonFilterConditionPB, PersonFilterPB};
+  use crate::services::cell::{CellDataChangeset, CellDataDecoder, TypeCellData};
+  use crate::services::field::{
+    clear_current_user, current_user_id, insert_current_member, set_current_user, FieldBuilder,
+    PersonCellChangeset, PersonCellData, PersonPB, PersonTypeOptionPB, TypeOptionCellData,
+  };
+
+  fn person_type_option() -> PersonTypeOptionPB {
+    let mut type_option = PersonTypeOptionPB::default();
+    type_option.insert_member(PersonPB::new("user_1", "Lucas"));
+    type_option.insert_member(PersonPB::new("user_2", "Nathan"));
+    type_option
+  }
+
+  #[test]
+  fn person_cell_assign_and_unassign_members_test() {
+    let type_option = person_type_option();
+    let changeset = PersonCellChangeset::from_inserted_user_ids(vec![
+      "user_2".to_owned(),
+      "user_1".to_owned(),
+      "user_2".to_owned(),
+      "not_a_member".to_owned(),
+    ]);
+    let (cell_str, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+    assert_eq!(cell_str, "user_2,user_1");
+    assert_eq!(
+      type_option.decode_cell_data_to_str(cell_data),
+      "Nathan, Lucas"
+    );
+
+    let type_cell_data = TypeCellData::new(cell_str, FieldType::Person);
+    let changeset = PersonCellChangeset::from_removed_user_ids(vec!["user_2".to_owned()]);
+    let (cell_str, _) = type_option
+      .apply_changeset(changeset, Some(type_cell_data))
+      .unwrap();
+    assert_eq!(cell_str, "user_1");
+  }
+
+  #[test]
+  fn person_cell_display_removed_member_test() {
+    let type_option = person_type_option();
+    let cell_data = PersonCellData::from("user_1,user_3".to_owned());
+    let cell_data_pb = type_option.convert_to_protobuf(cell_data.clone());
+    assert_eq!(cell_data_pb.persons[0].name, "Lucas");
+    assert_eq!(cell_data_pb.persons[1].name, "user_3");
+    assert_eq!(
+      type_option.decode_cell_data_to_str(cell_data),
+      "Lucas, user_3"
+    );
+  }
+
+  #[test]
+  fn person_filter_test() {
+    let empty = PersonCellData::default();
+    let assigned = PersonCellData::from("user_1,user_2".to_owned());
+
+    let filter = PersonFilterPB {
+      condition: PersonFilterConditionPB::Is,
+      user_ids: vec!["user_2".to_owned()],
+    };
+    assert!(!filter.is_visible(&empty, "user_1"));
+    assert!(filter.is_visible(&assigned, "user_1"));
+
+    let filter = PersonFilterPB {
+      condition: PersonFilterConditionPB::IsNot,
+      user_ids: vec!["user_2".to_owned()],
+    };
+    assert!(filter.is_visible(&empty, "user_1"));
+    assert!(!filter.is_visible(&assigned, "user_1"));
+
+    let filter = PersonFilterPB {
+      condition: PersonFilterConditionPB::ContainsCurrentUser,
+      user_ids: vec![],
+    };
+    assert!(!filter.is_visible(&empty, "user_1"));
+    assert!(filter.is_visible(&assigned, "user_1"));
+    assert!(!filter.is_visible(&assigned, "user_3"));
+  }
+
+  #[test]
+  fn person_field_insert_current_member_test() {
+    let mut field_rev = FieldBuilder::from_field_type(&FieldType::Person).build();
+    set_current_user("user_1", "Lucas");
+    assert!(insert_current_member(&mut field_rev));
+    assert!(!insert_current_member(&mut field_rev));
+
+    // The member is renamed if the user changed the name
+    set_current_user("user_1", "Lucas Xu");
+    assert!(insert_current_member(&mut field_rev));
+    let type_option = field_rev
+      .get_type_option::<PersonTypeOptionPB>(field_rev.ty)
+      .unwrap();
+    assert_eq!(
+      type_option.members,
+      vec![PersonPB::new("user_1", "Lucas Xu")]
+    );
+
+    clear_current_user();
+    assert_eq!(current_user_id(), "");
+    assert!(!insert_current_member(&mut field_rev));
+  }
+}
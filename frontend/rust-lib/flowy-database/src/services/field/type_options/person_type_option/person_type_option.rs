@@ -0,0 +1,217 @@
+use crate::entities::{FieldType, PersonFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  current_user, current_user_id, BoxTypeOptionBuilder, PersonCellChangeset, PersonCellData,
+  PersonCellDataPB, PersonPB, TypeOption, TypeOptionBuilder, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct PersonTypeOptionBuilder(PersonTypeOptionPB);
+impl_into_box_type_option_builder!(PersonTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(PersonTypeOptionBuilder, PersonTypeOptionPB);
+
+impl PersonTypeOptionBuilder {
+  pub fn add_member(mut self, member: PersonPB) -> Self {
+    self.0.insert_member(member);
+    self
+  }
+}
+
+impl TypeOptionBuilder for PersonTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Person
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The person cell assigns the members of the workspace. The members are stored by their user
+/// ids, so the cell keeps working after the members change their display names.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct PersonTypeOptionPB {
+  /// The members of the workspace that can be assigned to the cells
+  #[pb(index = 1)]
+  pub members: Vec<PersonPB>,
+}
+impl_type_option!(PersonTypeOptionPB, FieldType::Person);
+
+impl PersonTypeOptionPB {
+  /// Inserts the member, or updates its display name if the member already exists.
+  pub fn insert_member(&mut self, member: PersonPB) {
+    match self
+      .members
+      .iter_mut()
+      .find(|person| person.id == member.id)
+    {
+      None => self.members.push(member),
+      Some(person) => person.name = member.name,
+    }
+  }
+
+  pub fn get_member(&self, user_id: &str) -> Option<&PersonPB> {
+    self.members.iter().find(|person| person.id == user_id)
+  }
+
+  /// Returns the display name of the member. The members that were removed from the workspace
+  /// are displayed by their user ids.
+  pub fn display_name(&self, user_id: &str) -> String {
+    match self.get_member(user_id) {
+      None => user_id.to_owned(),
+      Some(person) => person.name.clone(),
+    }
+  }
+}
+
+/// Inserts the signed-in user into the members of the person field, or updates the display name
+/// of the user. The members of the workspace aren't stored locally, so each user adds themselves
+/// to the person fields of the databases they open. Returns false if the field isn't a person
+/// field or the member is up to date.
+pub fn insert_current_member(field_rev: &mut FieldRevision) -> bool {
+  let member = match current_user() {
+    None => return false,
+    Some(member) => member,
+  };
+  if !FieldType::from(field_rev.ty).is_person() {
+    return false;
+  }
+  let mut type_option = field_rev
+    .get_type_option::<PersonTypeOptionPB>(field_rev.ty)
+    .unwrap_or_default();
+  if type_option.get_member(&member.id) == Some(&member) {
+    return false;
+  }
+  type_option.insert_member(member);
+  field_rev.insert_type_option(&type_option);
+  true
+}
+
+impl TypeOption for PersonTypeOptionPB {
+  type CellData = PersonCellData;
+  type CellChangeset = PersonCellChangeset;
+  type CellProtobufType = PersonCellDataPB;
+  type CellFilter = PersonFilterPB;
+}
+
+impl TypeOptionTransform for PersonTypeOptionPB {}
+
+impl TypeOptionCellData for PersonTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    let persons = cell_data
+      .user_ids
+      .iter()
+      .map(|user_id| PersonPB::new(user_id, &self.display_name(user_id)))
+      .collect();
+    PersonCellDataPB { persons }
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    PersonCellData::from_cell_str(&cell_str)
+  }
+}
+
+impl CellDataDecoder for PersonTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_person() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  /// Returns the display names of the assigned members, separated by commas.
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data
+      .user_ids
+      .iter()
+      .map(|user_id| self.display_name(user_id))
+      .collect::<Vec<String>>()
+      .join(", ")
+  }
+}
+
+impl CellDataChangeset for PersonTypeOptionPB {
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let mut cell_data = match type_cell_data {
+      Some(type_cell_data) if type_cell_data.is_person() => {
+        PersonCellData::from(type_cell_data.cell_str)
+      },
+      _ => PersonCellData::default(),
+    };
+
+    // Only the members of the workspace can be assigned
+    for inserted_user_id in changeset.inserted_user_ids {
+      if self.get_member(&inserted_user_id).is_some()
+        && !cell_data.user_ids.contains(&inserted_user_id)
+      {
+        cell_data.user_ids.push(inserted_user_id);
+      }
+    }
+
+    cell_data
+      .user_ids
+      .retain(|user_id| !changeset.removed_user_ids.contains(user_id));
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for PersonTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_person() {
+      return true;
+    }
+
+    filter.is_visible(cell_data, &current_user_id())
+  }
+}
+
+impl TypeOptionCellDataCompare for PersonTypeOptionPB {
+  /// The cells are sorted by the display name of their first member.
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    let first_name = |cell_data: &PersonCellData| {
+      cell_data
+        .user_ids
+        .first()
+        .map(|user_id| self.display_name(user_id))
+    };
+    match (first_name(cell_data), first_name(other_cell_data)) {
+      (Some(name), Some(other_name)) => name.cmp(&other_name),
+      (Some(_), None) => Ordering::Greater,
+      (None, Some(_)) => Ordering::Less,
+      (None, None) => Ordering::Equal,
+    }
+  }
+}
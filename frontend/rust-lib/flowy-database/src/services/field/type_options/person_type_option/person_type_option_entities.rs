@@ -0,0 +1,181 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::{CellIdPB, CellIdParams};
+use crate::services::cell::{
+  CellProtobufBlobParser, DecodedCellData, FromCellChangesetString, FromCellString,
+  ToCellChangesetString,
+};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, ErrorCode, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+const PERSON_USER_IDS_SEPARATOR: &str = ",";
+
+/// A member of the workspace that can be assigned to the person cells.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ProtoBuf)]
+pub struct PersonPB {
+  /// The user id of the member
+  #[pb(index = 1)]
+  pub id: String,
+
+  /// The display name of the member
+  #[pb(index = 2)]
+  pub name: String,
+}
+
+impl PersonPB {
+  pub fn new(id: &str, name: &str) -> Self {
+    Self {
+      id: id.to_owned(),
+      name: name.to_owned(),
+    }
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct PersonCellDataPB {
+  #[pb(index = 1)]
+  pub persons: Vec<PersonPB>,
+}
+
+impl DecodedCellData for PersonCellDataPB {
+  type Object = PersonCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.persons.is_empty()
+  }
+}
+
+/// The user ids of the assigned members in the order they were assigned. It's stored in the
+/// cell as a string that places a comma between each id.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PersonCellData {
+  pub user_ids: Vec<String>,
+}
+
+impl From<Vec<String>> for PersonCellData {
+  fn from(user_ids: Vec<String>) -> Self {
+    let user_ids = user_ids
+      .into_iter()
+      .filter(|user_id| !user_id.is_empty())
+      .collect::<Vec<String>>();
+    Self { user_ids }
+  }
+}
+
+impl From<String> for PersonCellData {
+  fn from(s: String) -> Self {
+    let user_ids = s
+      .split(PERSON_USER_IDS_SEPARATOR)
+      .map(|user_id| user_id.to_owned())
+      .collect::<Vec<String>>();
+    Self::from(user_ids)
+  }
+}
+
+impl FromCellString for PersonCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self> {
+    Ok(Self::from(s.to_owned()))
+  }
+}
+
+impl ToString for PersonCellData {
+  fn to_string(&self) -> String {
+    self.user_ids.join(PERSON_USER_IDS_SEPARATOR)
+  }
+}
+
+impl DecodedCellData for PersonCellData {
+  type Object = PersonCellData;
+
+  fn is_empty(&self) -> bool {
+    self.user_ids.is_empty()
+  }
+}
+
+pub struct PersonCellDataParser();
+impl CellProtobufBlobParser for PersonCellDataParser {
+  type Object = PersonCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    PersonCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PersonCellChangeset {
+  pub inserted_user_ids: Vec<String>,
+  pub removed_user_ids: Vec<String>,
+}
+
+impl PersonCellChangeset {
+  pub fn from_inserted_user_ids(user_ids: Vec<String>) -> Self {
+    Self {
+      inserted_user_ids: user_ids,
+      removed_user_ids: vec![],
+    }
+  }
+
+  pub fn from_removed_user_ids(user_ids: Vec<String>) -> Self {
+    Self {
+      inserted_user_ids: vec![],
+      removed_user_ids: user_ids,
+    }
+  }
+}
+
+impl FromCellChangesetString for PersonCellChangeset {
+  fn from_changeset(changeset: String) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    serde_json::from_str::<PersonCellChangeset>(&changeset).map_err(internal_error)
+  }
+}
+
+impl ToCellChangesetString for PersonCellChangeset {
+  fn to_cell_changeset_str(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
+
+/// [PersonCellChangesetPB] assigns the members of the workspace to the cell, or unassigns them
+/// from the cell.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct PersonCellChangesetPB {
+  #[pb(index = 1)]
+  pub cell_id: CellIdPB,
+
+  #[pb(index = 2)]
+  pub inserted_user_ids: Vec<String>,
+
+  #[pb(index = 3)]
+  pub removed_user_ids: Vec<String>,
+}
+
+pub struct PersonCellChangesetParams {
+  pub cell_id: CellIdParams,
+  pub inserted_user_ids: Vec<String>,
+  pub removed_user_ids: Vec<String>,
+}
+
+impl TryInto<PersonCellChangesetParams> for PersonCellChangesetPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<PersonCellChangesetParams, Self::Error> {
+    let cell_id: CellIdParams = self.cell_id.try_into()?;
+    let parse_user_ids = |user_ids: Vec<String>| {
+      user_ids
+        .into_iter()
+        .map(|user_id| NotEmptyStr::parse(user_id).map(|user_id| user_id.0))
+        .collect::<Result<Vec<String>, _>>()
+        .map_err(|_| ErrorCode::UserIdIsEmpty)
+    };
+
+    Ok(PersonCellChangesetParams {
+      cell_id,
+      inserted_user_ids: parse_user_ids(self.inserted_user_ids)?,
+      removed_user_ids: parse_user_ids(self.removed_user_ids)?,
+    })
+  }
+}
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, TextFilterConditionPB, TextFilterPB};
+  use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+  use crate::services::field::{
+    FieldBuilder, PhoneCellData, PhoneFormatPB, PhoneTypeOptionPB, TextFilter,
+    TypeOptionCellDataFilter,
+  };
+  use flowy_error::ErrorCode;
+
+  #[test]
+  fn phone_cell_normalize_test() {
+    let type_option = PhoneTypeOptionPB::default();
+    for (changeset, expected) in [
+      ("(415) 555-2671", "+14155552671"),
+      ("+44 20 7946 0958", "+442079460958"),
+      ("0044 20 7946 0958", "+442079460958"),
+      ("415.555.2671", "+14155552671"),
+      ("", ""),
+    ] {
+      let (cell_str, _) = type_option
+        .apply_changeset(changeset.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected);
+    }
+
+    // The trunk prefix of the national numbers is dropped
+    let type_option = PhoneTypeOptionPB {
+      default_country_code: "44".to_owned(),
+      ..Default::default()
+    };
+    let (cell_str, _) = type_option
+      .apply_changeset("020 7946 0958".to_owned(), None)
+      .unwrap();
+    assert_eq!(cell_str, "+442079460958");
+
+    // The Italian numbers keep their leading zero
+    let type_option = PhoneTypeOptionPB {
+      default_country_code: "39".to_owned(),
+      ..Default::default()
+    };
+    for (changeset, expected) in [
+      ("06 1234 5678", "+390612345678"),
+      ("+39 06 1234 5678", "+390612345678"),
+      ("333 123 4567", "+393331234567"),
+    ] {
+      let (cell_str, _) = type_option
+        .apply_changeset(changeset.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected);
+    }
+  }
+
+  #[test]
+  fn phone_cell_invalid_input_test() {
+    let type_option = PhoneTypeOptionPB::default();
+    for changeset in ["call me", "555-12ab", "+1 23", "+1234567890123456"] {
+      let err = type_option
+        .apply_changeset(changeset.to_owned(), None)
+        .unwrap_err();
+      assert_eq!(err.code, ErrorCode::InvalidPhoneNumber.value());
+    }
+  }
+
+  #[test]
+  fn phone_cell_format_test() {
+    let mut type_option = PhoneTypeOptionPB::default();
+    let us_number = PhoneCellData("+14155552671".to_owned());
+    let uk_number = PhoneCellData("+442079460958".to_owned());
+    assert_eq!(
+      type_option.decode_cell_data_to_str(us_number.clone()),
+      "+1 415 555 2671"
+    );
+    assert_eq!(
+      type_option.decode_cell_data_to_str(uk_number.clone()),
+      "+44 207 946 0958"
+    );
+
+    type_option.format = PhoneFormatPB::National;
+    assert_eq!(
+      type_option.decode_cell_data_to_str(us_number.clone()),
+      "(415) 555-2671"
+    );
+    // The numbers of the other countries are displayed internationally
+    assert_eq!(
+      type_option.decode_cell_data_to_str(uk_number),
+      "+44 207 946 0958"
+    );
+
+    type_option.format = PhoneFormatPB::E164;
+    assert_eq!(
+      type_option.decode_cell_data_to_str(us_number),
+      "+14155552671"
+    );
+  }
+
+  #[test]
+  fn phone_cell_decode_and_filter_test() {
+    let type_option = PhoneTypeOptionPB::default();
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Phone).build();
+    let cell_data = type_option
+      .decode_cell_str("415 555 2671".to_owned(), &FieldType::RichText, &field_rev)
+      .unwrap();
+    assert_eq!(cell_data.0, "+14155552671");
+    let cell_data = type_option
+      .decode_cell_str("not a number".to_owned(), &FieldType::RichText, &field_rev)
+      .unwrap();
+    assert!(cell_data.is_empty());
+
+    let filter = TextFilter::from(TextFilterPB {
+      condition: TextFilterConditionPB::Contains,
+      content: "555 2671".to_owned(),
+      case_sensitive: false,
+    });
+    let cell_data = PhoneCellData("+14155552671".to_owned());
+    assert!(type_option.apply_filter(&filter, &FieldType::Phone, &cell_data));
+    assert!(!type_option.apply_filter(&filter, &FieldType::Phone, &PhoneCellData::default()));
+  }
+}
@@ -0,0 +1,156 @@
+use crate::entities::FieldType;
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  BoxTypeOptionBuilder, PhoneCellData, PhoneCellDataPB, PhoneFormatPB, TextFilter, TypeOption,
+  TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct PhoneTypeOptionBuilder(PhoneTypeOptionPB);
+impl_into_box_type_option_builder!(PhoneTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(PhoneTypeOptionBuilder, PhoneTypeOptionPB);
+
+impl PhoneTypeOptionBuilder {
+  pub fn default_country_code(mut self, country_code: &str) -> Self {
+    self.0.default_country_code = country_code.to_owned();
+    self
+  }
+
+  pub fn format(mut self, format: PhoneFormatPB) -> Self {
+    self.0.format = format;
+    self
+  }
+}
+
+impl TypeOptionBuilder for PhoneTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Phone
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The phone cell saves the number in the E.164 format, e.g. +14155552671, and displays it in
+/// the format of the field.
+#[derive(Debug, Clone, Serialize, Deserialize, ProtoBuf)]
+pub struct PhoneTypeOptionPB {
+  /// The country calling code of the numbers that are typed without one, e.g. "1"
+  #[pb(index = 1)]
+  pub default_country_code: String,
+
+  #[pb(index = 2)]
+  #[serde(default)]
+  pub format: PhoneFormatPB,
+}
+impl_type_option!(PhoneTypeOptionPB, FieldType::Phone);
+
+impl std::default::Default for PhoneTypeOptionPB {
+  fn default() -> Self {
+    Self {
+      default_country_code: "1".to_owned(),
+      format: PhoneFormatPB::default(),
+    }
+  }
+}
+
+impl TypeOption for PhoneTypeOptionPB {
+  type CellData = PhoneCellData;
+  type CellChangeset = PhoneCellChangeset;
+  type CellProtobufType = PhoneCellDataPB;
+  type CellFilter = TextFilter;
+}
+
+impl TypeOptionTransform for PhoneTypeOptionPB {}
+
+impl TypeOptionCellData for PhoneTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    PhoneCellDataPB {
+      formatted: self.decode_cell_data_to_str(cell_data.clone()),
+      number: cell_data.0,
+    }
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    PhoneCellData::from_cell_str(&cell_str)
+  }
+}
+
+impl CellDataDecoder for PhoneTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if decoded_field_type.is_phone() {
+      return self.decode_type_option_cell_str(cell_str);
+    }
+
+    // The text that isn't a phone number is dropped when the field is converted
+    if decoded_field_type.is_text() {
+      return Ok(PhoneCellData::parse(&cell_str, &self.default_country_code).unwrap_or_default());
+    }
+    Ok(Default::default())
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.format(self.format, &self.default_country_code)
+  }
+}
+
+pub type PhoneCellChangeset = String;
+
+impl CellDataChangeset for PhoneTypeOptionPB {
+  /// Returns [FlowyError::invalid_phone_number](flowy_error::FlowyError::invalid_phone_number)
+  /// if the changeset is not a phone number.
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let cell_data = PhoneCellData::parse(&changeset, &self.default_country_code)?;
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for PhoneTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_phone() {
+      return true;
+    }
+
+    // The filter matches the number as it's displayed
+    filter.is_visible(self.decode_cell_data_to_str(cell_data.clone()))
+  }
+}
+
+impl TypeOptionCellDataCompare for PhoneTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    cell_data.0.cmp(&other_cell_data.0)
+  }
+}
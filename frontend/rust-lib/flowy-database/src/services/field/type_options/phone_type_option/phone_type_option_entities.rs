@@ -0,0 +1,207 @@
+use crate::services::cell::{CellProtobufBlobParser, DecodedCellData, FromCellString};
+use bytes::Bytes;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+/// The max number of the digits of a phone number, including the country calling code.
+const MAX_DIGITS: usize = 15;
+const MIN_DIGITS: usize = 7;
+
+/// The country calling codes whose national numbers keep their leading `0` after the code, e.g.
+/// the Italian number 06 1234 5678 is +39 06 1234 5678.
+const LEADING_ZERO_COUNTRY_CODES: [&str; 3] = ["39", "225", "378"];
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct PhoneCellDataPB {
+  /// The number in the E.164 format, e.g. +14155552671
+  #[pb(index = 1)]
+  pub number: String,
+
+  /// The number that is formatted by the [PhoneFormatPB] of the field
+  #[pb(index = 2)]
+  pub formatted: String,
+}
+
+impl DecodedCellData for PhoneCellDataPB {
+  type Object = PhoneCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.number.is_empty()
+  }
+}
+
+pub struct PhoneCellDataParser();
+impl CellProtobufBlobParser for PhoneCellDataParser {
+  type Object = PhoneCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    PhoneCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum PhoneFormatPB {
+  /// +1 415 555 2671
+  International = 0,
+  /// (415) 555-2671, the numbers of the other countries are displayed internationally
+  National = 1,
+  /// +14155552671
+  E164 = 2,
+}
+
+impl std::default::Default for PhoneFormatPB {
+  fn default() -> Self {
+    PhoneFormatPB::International
+  }
+}
+
+/// The phone number in the E.164 format, it's empty if the cell is empty.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PhoneCellData(pub String);
+
+impl PhoneCellData {
+  /// Normalizes the typed number into the E.164 format. The spaces, the dashes, the dots and the
+  /// parentheses are ignored. The number without the leading `+` or `00` is a national number
+  /// of the `default_country_code`, its leading trunk prefix `0` is dropped unless the country
+  /// keeps it, e.g. Italy.
+  pub fn parse(s: &str, default_country_code: &str) -> FlowyResult<Self> {
+    let s = s.trim();
+    if s.is_empty() {
+      return Ok(Self::default());
+    }
+
+    let invalid =
+      || FlowyError::invalid_phone_number().context(format!("Invalid phone number: {}", s));
+    let mut chars = s
+      .chars()
+      .filter(|c| !matches!(c, ' ' | '-' | '.' | '(' | ')' | '/'))
+      .peekable();
+    let is_international = chars.peek() == Some(&'+');
+    if is_international {
+      chars.next();
+    }
+    let mut digits = String::new();
+    for c in chars {
+      if !c.is_ascii_digit() {
+        return Err(invalid());
+      }
+      digits.push(c);
+    }
+
+    let digits = if is_international {
+      digits
+    } else if let Some(digits) = digits.strip_prefix("00") {
+      digits.to_owned()
+    } else {
+      let country_code = default_country_code.trim_start_matches('+');
+      if country_code.is_empty() || !country_code.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+      }
+      let national = if LEADING_ZERO_COUNTRY_CODES.contains(&country_code) {
+        digits.as_str()
+      } else {
+        digits.strip_prefix('0').unwrap_or(&digits)
+      };
+      format!("{}{}", country_code, national)
+    };
+
+    if digits.starts_with('0') || !(MIN_DIGITS..=MAX_DIGITS).contains(&digits.len()) {
+      return Err(invalid());
+    }
+    Ok(Self(format!("+{}", digits)))
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+
+  /// Splits the number into the country calling code and the national number.
+  pub fn split(&self) -> Option<(&str, &str)> {
+    let digits = self.0.strip_prefix('+')?;
+    let len = country_code_len(digits);
+    Some((&digits[..len], &digits[len..]))
+  }
+
+  pub fn format(&self, format: PhoneFormatPB, default_country_code: &str) -> String {
+    let (country_code, national) = match self.split() {
+      None => return self.0.clone(),
+      Some(parts) => parts,
+    };
+    match format {
+      PhoneFormatPB::E164 => self.0.clone(),
+      PhoneFormatPB::National if country_code == default_country_code.trim_start_matches('+') => {
+        if country_code == "1" && national.len() == 10 {
+          format!(
+            "({}) {}-{}",
+            &national[..3],
+            &national[3..6],
+            &national[6..]
+          )
+        } else {
+          group_digits(national)
+        }
+      },
+      _ => format!("+{} {}", country_code, group_digits(national)),
+    }
+  }
+}
+
+/// Returns the length of the country calling code at the start of the `digits`. The codes of
+/// the North American and the Russian zones have one digit, the codes listed below have two
+/// digits and the others have three digits.
+fn country_code_len(digits: &str) -> usize {
+  const TWO_DIGIT_CODES: [&str; 44] = [
+    "20", "27", "30", "31", "32", "33", "34", "36", "39", "40", "41", "43", "44", "45", "46", "47",
+    "48", "49", "51", "52", "53", "54", "55", "56", "57", "58", "60", "61", "62", "63", "64", "65",
+    "66", "81", "82", "84", "86", "90", "91", "92", "93", "94", "95", "98",
+  ];
+  if digits.starts_with('1') || digits.starts_with('7') {
+    1
+  } else if TWO_DIGIT_CODES.iter().any(|code| digits.starts_with(code)) {
+    2
+  } else {
+    3.min(digits.len())
+  }
+}
+
+/// Groups the digits by three, the last group has up to four digits.
+fn group_digits(digits: &str) -> String {
+  let mut groups = vec![];
+  let mut rest = digits;
+  while rest.len() > 4 {
+    let (group, remaining) = rest.split_at(3);
+    groups.push(group);
+    rest = remaining;
+  }
+  if !rest.is_empty() {
+    groups.push(rest);
+  }
+  groups.join(" ")
+}
+
+impl FromCellString for PhoneCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self> {
+    Ok(Self(s.to_owned()))
+  }
+}
+
+impl AsRef<str> for PhoneCellData {
+  fn as_ref(&self) -> &str {
+    &self.0
+  }
+}
+
+impl ToString for PhoneCellData {
+  fn to_string(&self) -> String {
+    self.0.clone()
+  }
+}
+
+impl DecodedCellData for PhoneCellData {
+  type Object = PhoneCellData;
+
+  fn is_empty(&self) -> bool {
+    self.0.is_empty()
+  }
+}
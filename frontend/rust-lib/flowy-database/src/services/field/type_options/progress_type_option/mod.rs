@@ -0,0 +1,7 @@
+#![allow(clippy::module_inception)]
+mod progress_tests;
+mod progress_type_option;
+mod progress_type_option_entities;
+
+pub use progress_type_option::*;
+pub use progress_type_option_entities::*;
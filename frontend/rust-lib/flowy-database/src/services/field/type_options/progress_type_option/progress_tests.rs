@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, NumberFilterConditionPB, NumberFilterPB};
+  use crate::services::cell::{CellDataChangeset, CellDataDecoder};
+  use crate::services::field::{
+    average_progress, FieldBuilder, ProgressCellData, ProgressTypeOptionPB,
+    TypeOptionCellDataFilter,
+  };
+
+  #[test]
+  fn progress_cell_clamp_test() {
+    let type_option = ProgressTypeOptionPB::default();
+    for (changeset, expected) in [
+      ("42", "42"),
+      ("42.6%", "43"),
+      ("150", "100"),
+      ("-3", "0"),
+      ("abc", ""),
+      ("", ""),
+    ] {
+      let (cell_str, _) = type_option
+        .apply_changeset(changeset.to_owned(), None)
+        .unwrap();
+      assert_eq!(cell_str, expected);
+    }
+  }
+
+  #[test]
+  fn progress_cell_decode_to_str_test() {
+    let type_option = ProgressTypeOptionPB::default();
+    let field_rev = FieldBuilder::from_field_type(&FieldType::Progress).build();
+    let cell_data = type_option
+      .decode_cell_str("42".to_owned(), &FieldType::Progress, &field_rev)
+      .unwrap();
+    assert_eq!(type_option.decode_cell_data_to_str(cell_data), "42%");
+
+    // The number cells are read as the percentages
+    let cell_data = type_option
+      .decode_cell_str("120".to_owned(), &FieldType::Number, &field_rev)
+      .unwrap();
+    assert_eq!(type_option.decode_cell_data_to_str(cell_data), "100%");
+
+    let cell_data = type_option
+      .decode_cell_str("1647251762".to_owned(), &FieldType::DateTime, &field_rev)
+      .unwrap();
+    assert_eq!(type_option.decode_cell_data_to_str(cell_data), "");
+  }
+
+  #[test]
+  fn progress_cell_filter_and_average_test() {
+    let type_option = ProgressTypeOptionPB::default();
+    let filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::GreaterThanOrEqualTo,
+      content: "50".to_owned(),
+      end_content: None,
+    };
+    let cells = [
+      ProgressCellData(Some(20)),
+      ProgressCellData(Some(50)),
+      ProgressCellData(None),
+      ProgressCellData(Some(80)),
+    ];
+    let visible = cells
+      .iter()
+      .filter(|cell_data| type_option.apply_filter(&filter, &FieldType::Progress, cell_data))
+      .count();
+    assert_eq!(visible, 2);
+    assert_eq!(average_progress(cells.iter()), (50.0, 3));
+    assert_eq!(average_progress([ProgressCellData(None)].iter()), (0.0, 0));
+  }
+}
@@ -0,0 +1,144 @@
+use crate::entities::{FieldType, NumberFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, TypeCellData};
+use crate::services::field::{
+  default_order, BoxTypeOptionBuilder, NumberCellData, ProgressCellChangeset, ProgressCellData,
+  ProgressCellDataPB, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+  TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+#[derive(Default)]
+pub struct ProgressTypeOptionBuilder(ProgressTypeOptionPB);
+impl_into_box_type_option_builder!(ProgressTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(ProgressTypeOptionBuilder, ProgressTypeOptionPB);
+
+impl ProgressTypeOptionBuilder {
+  pub fn show_label(mut self, show_label: bool) -> Self {
+    self.0.show_label = show_label;
+    self
+  }
+}
+
+impl TypeOptionBuilder for ProgressTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Progress
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The progress cell is a percentage that is rendered as a progress bar. The values are clamped
+/// into the range of 0 to 100.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct ProgressTypeOptionPB {
+  /// Shows the percentage next to the progress bar
+  #[pb(index = 1)]
+  pub show_label: bool,
+}
+impl_type_option!(ProgressTypeOptionPB, FieldType::Progress);
+
+impl TypeOption for ProgressTypeOptionPB {
+  type CellData = ProgressCellData;
+  type CellChangeset = ProgressCellChangeset;
+  type CellProtobufType = ProgressCellDataPB;
+  type CellFilter = NumberFilterPB;
+}
+
+impl TypeOptionTransform for ProgressTypeOptionPB {}
+
+impl TypeOptionCellData for ProgressTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    cell_data.into()
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    Ok(ProgressCellData::parse(&cell_str))
+  }
+}
+
+impl CellDataDecoder for ProgressTypeOptionPB {
+  /// The cells of the number and the text fields are read as the percentages, so the field
+  /// keeps its values when its type is switched to the progress.
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_progress()
+      && !decoded_field_type.is_number()
+      && !decoded_field_type.is_text()
+    {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    match cell_data.0 {
+      None => "".to_owned(),
+      Some(value) => format!("{}%", value),
+    }
+  }
+}
+
+impl CellDataChangeset for ProgressTypeOptionPB {
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    _type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let cell_data = ProgressCellData::parse(&changeset);
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for ProgressTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_progress() {
+      return true;
+    }
+
+    let number_cell_data = match cell_data.0 {
+      None => NumberCellData::default(),
+      Some(value) => NumberCellData::from_decimal(Decimal::from(value)),
+    };
+    filter.is_visible(&number_cell_data)
+  }
+}
+
+impl TypeOptionCellDataCompare for ProgressTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    match (cell_data.0, other_cell_data.0) {
+      (Some(left), Some(right)) => left.cmp(&right),
+      (Some(_), None) => Ordering::Greater,
+      (None, Some(_)) => Ordering::Less,
+      (None, None) => default_order(),
+    }
+  }
+}
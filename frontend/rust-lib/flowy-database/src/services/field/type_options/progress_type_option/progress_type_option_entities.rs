@@ -0,0 +1,137 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::FieldIdParams;
+use crate::services::cell::{CellProtobufBlobParser, DecodedCellData, FromCellString};
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, ErrorCode, FlowyResult};
+
+pub const MIN_PROGRESS: i64 = 0;
+pub const MAX_PROGRESS: i64 = 100;
+
+/// The percentage of the progress cell, between [MIN_PROGRESS] and [MAX_PROGRESS]. The cell is
+/// empty if the value is None.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProgressCellData(pub Option<i64>);
+
+impl ProgressCellData {
+  /// Rounds the value to the nearest percentage and clamps it into the range of the progress.
+  pub fn from_value(value: f64) -> Self {
+    if value.is_nan() {
+      return Self(None);
+    }
+    Self(Some(
+      (value.round() as i64).clamp(MIN_PROGRESS, MAX_PROGRESS),
+    ))
+  }
+
+  /// Parses the value from a number, with or without the trailing "%". The cell is empty if
+  /// the string is not a number.
+  pub fn parse(s: &str) -> Self {
+    let s = s.trim();
+    let s = s.strip_suffix('%').unwrap_or(s).trim_end();
+    match s.parse::<f64>() {
+      Ok(value) => Self::from_value(value),
+      Err(_) => Self(None),
+    }
+  }
+}
+
+impl FromCellString for ProgressCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self> {
+    Ok(Self::parse(s))
+  }
+}
+
+impl ToString for ProgressCellData {
+  fn to_string(&self) -> String {
+    self.0.map(|value| value.to_string()).unwrap_or_default()
+  }
+}
+
+impl DecodedCellData for ProgressCellData {
+  type Object = ProgressCellData;
+
+  fn is_empty(&self) -> bool {
+    self.0.is_none()
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct ProgressCellDataPB {
+  #[pb(index = 1, one_of)]
+  pub value: Option<i64>,
+}
+
+impl From<ProgressCellData> for ProgressCellDataPB {
+  fn from(data: ProgressCellData) -> Self {
+    Self { value: data.0 }
+  }
+}
+
+pub struct ProgressCellDataParser();
+impl CellProtobufBlobParser for ProgressCellDataParser {
+  type Object = ProgressCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    ProgressCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+pub type ProgressCellChangeset = String;
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct GroupProgressPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// The id of the progress field
+  #[pb(index = 2)]
+  pub field_id: String,
+}
+
+impl TryInto<FieldIdParams> for GroupProgressPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<FieldIdParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    Ok(FieldIdParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+    })
+  }
+}
+
+/// [GroupProgressPB] is the average of the progress cells of the rows in the group. The empty
+/// cells are not counted.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct GroupProgressPB {
+  #[pb(index = 1)]
+  pub group_id: String,
+
+  #[pb(index = 2)]
+  pub average: f64,
+
+  /// The number of the non-empty cells
+  #[pb(index = 3)]
+  pub count: i32,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RepeatedGroupProgressPB {
+  #[pb(index = 1)]
+  pub items: Vec<GroupProgressPB>,
+}
+
+/// Returns the average of the non-empty cells and their number. The average is zero if all the
+/// cells are empty.
+pub fn average_progress<'a>(cells: impl Iterator<Item = &'a ProgressCellData>) -> (f64, i32) {
+  let (sum, count) = cells
+    .flat_map(|cell_data| cell_data.0)
+    .fold((0, 0), |(sum, count), value| (sum + value, count + 1));
+  if count == 0 {
+    (0.0, 0)
+  } else {
+    (sum as f64 / count as f64, count)
+  }
+}
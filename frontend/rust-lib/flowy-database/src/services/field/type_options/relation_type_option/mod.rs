@@ -0,0 +1,8 @@
+#![allow(clippy::module_inception)]
+mod relation_filter;
+mod relation_tests;
+mod relation_type_option;
+mod relation_type_option_entities;
+
+pub use relation_type_option::*;
+pub use relation_type_option_entities::*;
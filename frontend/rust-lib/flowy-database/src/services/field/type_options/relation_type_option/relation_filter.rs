@@ -0,0 +1,22 @@
+use crate::entities::{RelationFilterConditionPB, RelationFilterPB};
+use crate::services::field::RelationCellData;
+
+impl RelationFilterPB {
+  pub fn is_visible(&self, cell_data: &RelationCellData) -> bool {
+    match self.condition {
+      RelationFilterConditionPB::IsEmpty => cell_data.row_ids.is_empty(),
+      RelationFilterConditionPB::IsNotEmpty => !cell_data.row_ids.is_empty(),
+      RelationFilterConditionPB::Contains => {
+        // The filter that has no rows doesn't hide any row
+        if self.row_ids.is_empty() {
+          return true;
+        }
+
+        cell_data
+          .row_ids
+          .iter()
+          .any(|row_id| self.row_ids.contains(row_id))
+      },
+    }
+  }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, RelationFilterConditionPB, RelationFilterPB};
+  use crate::services::cell::{CellDataChangeset, TypeCellData};
+  use crate::services::field::{
+    RelationCellChangeset, RelationCellData, RelationOnDeletePB, RelationTypeOptionPB,
+  };
+
+  #[test]
+  fn relation_cell_link_and_unlink_rows_test() {
+    let type_option = RelationTypeOptionPB::default();
+    let changeset = RelationCellChangeset::from_inserted_row_ids(vec![
+      "row_1".to_owned(),
+      "row_2".to_owned(),
+      "row_1".to_owned(),
+    ]);
+    let (cell_str, cell_data) = type_option.apply_changeset(changeset, None).unwrap();
+    assert_eq!(cell_str, "row_1,row_2");
+    assert_eq!(cell_data.row_ids, vec!["row_1", "row_2"]);
+
+    let type_cell_data = TypeCellData::new(cell_str, FieldType::Relation);
+    let changeset = RelationCellChangeset {
+      inserted_row_ids: vec!["row_3".to_owned()],
+      removed_row_ids: vec!["row_1".to_owned()],
+    };
+    let (cell_str, _) = type_option
+      .apply_changeset(changeset, Some(type_cell_data))
+      .unwrap();
+    assert_eq!(cell_str, "row_2,row_3");
+  }
+
+  #[test]
+  fn relation_type_option_on_delete_test() {
+    // The type options that were saved before the deletion policy clear the deleted rows
+    let type_option: RelationTypeOptionPB =
+      serde_json::from_str(r#"{"database_id":"database_1"}"#).unwrap();
+    assert_eq!(type_option.database_id, "database_1");
+    assert_eq!(type_option.on_delete, RelationOnDeletePB::Clear);
+
+    let type_option = RelationTypeOptionPB {
+      database_id: "database_1".to_owned(),
+      on_delete: RelationOnDeletePB::Restrict,
+    };
+    let json = serde_json::to_string(&type_option).unwrap();
+    let type_option: RelationTypeOptionPB = serde_json::from_str(&json).unwrap();
+    assert_eq!(type_option.on_delete, RelationOnDeletePB::Restrict);
+  }
+
+  #[test]
+  fn relation_cell_ignores_cell_of_other_field_type_test() {
+    let type_option = RelationTypeOptionPB::default();
+    let type_cell_data = TypeCellData::new("row_1".to_owned(), FieldType::RichText);
+    let changeset = RelationCellChangeset::from_inserted_row_ids(vec!["row_2".to_owned()]);
+    let (cell_str, _) = type_option
+      .apply_changeset(changeset, Some(type_cell_data))
+      .unwrap();
+    assert_eq!(cell_str, "row_2");
+  }
+
+  #[test]
+  fn relation_filter_test() {
+    let empty = RelationCellData::default();
+    let linked = RelationCellData::from("row_1,row_2".to_owned());
+
+    let filter = RelationFilterPB {
+      condition: RelationFilterConditionPB::IsEmpty,
+      row_ids: vec![],
+    };
+    assert!(filter.is_visible(&empty));
+    assert!(!filter.is_visible(&linked));
+
+    let filter = RelationFilterPB {
+      condition: RelationFilterConditionPB::IsNotEmpty,
+      row_ids: vec![],
+    };
+    assert!(!filter.is_visible(&empty));
+    assert!(filter.is_visible(&linked));
+
+    let filter = RelationFilterPB {
+      condition: RelationFilterConditionPB::Contains,
+      row_ids: vec!["row_2".to_owned(), "row_3".to_owned()],
+    };
+    assert!(!filter.is_visible(&empty));
+    assert!(filter.is_visible(&linked));
+    assert!(!filter.is_visible(&RelationCellData::from("row_4".to_owned())));
+  }
+}
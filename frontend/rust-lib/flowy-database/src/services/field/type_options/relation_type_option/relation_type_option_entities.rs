@@ -0,0 +1,252 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::{CellIdPB, CellIdParams};
+use crate::services::cell::{
+  CellProtobufBlobParser, DecodedCellData, FromCellChangesetString, FromCellString,
+  ToCellChangesetString,
+};
+use bytes::Bytes;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::{internal_error, ErrorCode, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+const RELATION_ROW_IDS_SEPARATOR: &str = ",";
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RelationCellDataPB {
+  #[pb(index = 1)]
+  pub row_ids: Vec<String>,
+}
+
+impl From<RelationCellData> for RelationCellDataPB {
+  fn from(data: RelationCellData) -> Self {
+    Self {
+      row_ids: data.row_ids,
+    }
+  }
+}
+
+impl DecodedCellData for RelationCellDataPB {
+  type Object = RelationCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.row_ids.is_empty()
+  }
+}
+
+/// The ids of the linked rows in the order they were linked. It's stored in the cell as a
+/// string that places a comma between each id.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RelationCellData {
+  pub row_ids: Vec<String>,
+}
+
+impl From<Vec<String>> for RelationCellData {
+  fn from(row_ids: Vec<String>) -> Self {
+    let row_ids = row_ids
+      .into_iter()
+      .filter(|row_id| !row_id.is_empty())
+      .collect::<Vec<String>>();
+    Self { row_ids }
+  }
+}
+
+impl From<String> for RelationCellData {
+  fn from(s: String) -> Self {
+    let row_ids = s
+      .split(RELATION_ROW_IDS_SEPARATOR)
+      .map(|row_id| row_id.to_owned())
+      .collect::<Vec<String>>();
+    Self::from(row_ids)
+  }
+}
+
+impl FromCellString for RelationCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self> {
+    Ok(Self::from(s.to_owned()))
+  }
+}
+
+impl ToString for RelationCellData {
+  fn to_string(&self) -> String {
+    self.row_ids.join(RELATION_ROW_IDS_SEPARATOR)
+  }
+}
+
+impl DecodedCellData for RelationCellData {
+  type Object = RelationCellData;
+
+  fn is_empty(&self) -> bool {
+    self.row_ids.is_empty()
+  }
+}
+
+pub struct RelationCellDataParser();
+impl CellProtobufBlobParser for RelationCellDataParser {
+  type Object = RelationCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    RelationCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RelationCellChangeset {
+  pub inserted_row_ids: Vec<String>,
+  pub removed_row_ids: Vec<String>,
+}
+
+impl RelationCellChangeset {
+  pub fn from_inserted_row_ids(row_ids: Vec<String>) -> Self {
+    Self {
+      inserted_row_ids: row_ids,
+      removed_row_ids: vec![],
+    }
+  }
+
+  pub fn from_removed_row_ids(row_ids: Vec<String>) -> Self {
+    Self {
+      inserted_row_ids: vec![],
+      removed_row_ids: row_ids,
+    }
+  }
+}
+
+impl FromCellChangesetString for RelationCellChangeset {
+  fn from_changeset(changeset: String) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    serde_json::from_str::<RelationCellChangeset>(&changeset).map_err(internal_error)
+  }
+}
+
+impl ToCellChangesetString for RelationCellChangeset {
+  fn to_cell_changeset_str(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
+
+/// [RelationCellChangesetPB] links the rows of the related database to the cell, or unlinks
+/// them from the cell.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RelationCellChangesetPB {
+  #[pb(index = 1)]
+  pub cell_id: CellIdPB,
+
+  #[pb(index = 2)]
+  pub inserted_row_ids: Vec<String>,
+
+  #[pb(index = 3)]
+  pub removed_row_ids: Vec<String>,
+}
+
+pub struct RelationCellChangesetParams {
+  pub cell_id: CellIdParams,
+  pub inserted_row_ids: Vec<String>,
+  pub removed_row_ids: Vec<String>,
+}
+
+impl TryInto<RelationCellChangesetParams> for RelationCellChangesetPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<RelationCellChangesetParams, Self::Error> {
+    let cell_id: CellIdParams = self.cell_id.try_into()?;
+    let parse_row_ids = |row_ids: Vec<String>| {
+      row_ids
+        .into_iter()
+        .map(|row_id| NotEmptyStr::parse(row_id).map(|row_id| row_id.0))
+        .collect::<Result<Vec<String>, _>>()
+        .map_err(|_| ErrorCode::RowIdIsEmpty)
+    };
+
+    Ok(RelationCellChangesetParams {
+      cell_id,
+      inserted_row_ids: parse_row_ids(self.inserted_row_ids)?,
+      removed_row_ids: parse_row_ids(self.removed_row_ids)?,
+    })
+  }
+}
+
+/// [RelationOnDeletePB] keeps the relation cells consistent when the rows they link, or the
+/// database of these rows, get deleted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum RelationOnDeletePB {
+  /// Unlinks the deleted rows from the cells
+  Clear = 0,
+  /// Refuses to delete the rows that are linked by the cells
+  Restrict = 1,
+}
+
+impl std::default::Default for RelationOnDeletePB {
+  fn default() -> Self {
+    RelationOnDeletePB::Clear
+  }
+}
+
+/// Lists the relation cells of a field that link the row or the database that is about to be
+/// deleted.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RelationDependentPB {
+  #[pb(index = 1)]
+  pub database_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub field_name: String,
+
+  #[pb(index = 4)]
+  pub on_delete: RelationOnDeletePB,
+
+  /// The rows whose cells link the deleted row or the rows of the deleted database
+  #[pb(index = 5)]
+  pub row_ids: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RepeatedRelationDependentPB {
+  #[pb(index = 1)]
+  pub items: Vec<RelationDependentPB>,
+}
+
+impl std::convert::From<Vec<RelationDependentPB>> for RepeatedRelationDependentPB {
+  fn from(items: Vec<RelationDependentPB>) -> Self {
+    Self { items }
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RelationDependentsPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// The row that is about to be deleted. None means the whole database.
+  #[pb(index = 2, one_of)]
+  pub row_id: Option<String>,
+}
+
+pub struct RelationDependentsParams {
+  pub view_id: String,
+  pub row_id: Option<String>,
+}
+
+impl TryInto<RelationDependentsParams> for RelationDependentsPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<RelationDependentsParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+    let row_id = match self.row_id {
+      None => None,
+      Some(row_id) => Some(
+        NotEmptyStr::parse(row_id)
+          .map_err(|_| ErrorCode::RowIdIsEmpty)?
+          .0,
+      ),
+    };
+    Ok(RelationDependentsParams {
+      view_id: view_id.0,
+      row_id,
+    })
+  }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, NumberFilterConditionPB, NumberFilterPB};
+  use crate::services::cell::{CellDataChangeset, FromCellString, TypeCellData};
+  use crate::services::field::{
+    RollupCalculationPB, RollupCellData, RollupTypeOptionPB, TypeOptionCellDataFilter,
+  };
+  use rust_decimal::Decimal;
+
+  #[test]
+  fn rollup_calculation_test() {
+    let values = vec![
+      Some(Decimal::from(3)),
+      None,
+      Some(Decimal::from(1)),
+      Some(Decimal::from(2)),
+    ];
+    let calculate = |calculation: RollupCalculationPB| {
+      RollupCellData::from(calculation.calculate(&values)).to_string()
+    };
+    assert_eq!(calculate(RollupCalculationPB::Count), "4");
+    assert_eq!(calculate(RollupCalculationPB::Sum), "6");
+    assert_eq!(calculate(RollupCalculationPB::Min), "1");
+    assert_eq!(calculate(RollupCalculationPB::Max), "3");
+    assert_eq!(calculate(RollupCalculationPB::Average), "2");
+  }
+
+  #[test]
+  fn rollup_calculation_without_numbers_test() {
+    let values = vec![None, None];
+    assert_eq!(
+      RollupCalculationPB::Count.calculate(&values),
+      Some(Decimal::from(2))
+    );
+    assert_eq!(
+      RollupCalculationPB::Sum.calculate(&values),
+      Some(Decimal::from(0))
+    );
+    assert_eq!(RollupCalculationPB::Min.calculate(&values), None);
+    assert_eq!(RollupCalculationPB::Average.calculate(&[]), None);
+
+    let values = vec![
+      Some(Decimal::from(1)),
+      Some(Decimal::from(1)),
+      Some(Decimal::from(2)),
+    ];
+    let average = RollupCalculationPB::Average.calculate(&values);
+    assert_eq!(RollupCellData::from(average).to_string(), "1.33333333");
+  }
+
+  #[test]
+  fn rollup_calculation_overflow_test() {
+    let values = vec![Some(Decimal::MAX), Some(Decimal::from(1)), None];
+    assert_eq!(RollupCalculationPB::Sum.calculate(&values), None);
+    assert_eq!(RollupCalculationPB::Average.calculate(&values), None);
+    assert_eq!(
+      RollupCalculationPB::Max.calculate(&values),
+      Some(Decimal::MAX)
+    );
+    assert_eq!(
+      RollupCalculationPB::Count.calculate(&values),
+      Some(Decimal::from(3))
+    );
+  }
+
+  #[test]
+  fn rollup_cell_filter_test() {
+    let type_option = RollupTypeOptionPB::default();
+    let cell_data = RollupCellData::from_cell_str("12.50").unwrap();
+    assert_eq!(cell_data.to_string(), "12.5");
+    let empty_cell_data = RollupCellData::from_cell_str("").unwrap();
+
+    let filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::GreaterThan,
+      content: "10".to_owned(),
+      end_content: None,
+    };
+    assert!(type_option.apply_filter(&filter, &FieldType::Rollup, &cell_data));
+    assert!(!type_option.apply_filter(&filter, &FieldType::Rollup, &empty_cell_data));
+
+    let filter = NumberFilterPB {
+      condition: NumberFilterConditionPB::NumberIsEmpty,
+      content: "".to_owned(),
+      end_content: None,
+    };
+    assert!(!type_option.apply_filter(&filter, &FieldType::Rollup, &cell_data));
+    assert!(type_option.apply_filter(&filter, &FieldType::Rollup, &empty_cell_data));
+  }
+
+  #[test]
+  fn rollup_cell_read_only_test() {
+    let type_option = RollupTypeOptionPB::default();
+    let type_cell_data = TypeCellData::new("3".to_owned(), FieldType::Rollup);
+    let (cell_str, _) = type_option
+      .apply_changeset("12".to_owned(), Some(type_cell_data))
+      .unwrap();
+    assert_eq!(cell_str, "3");
+  }
+}
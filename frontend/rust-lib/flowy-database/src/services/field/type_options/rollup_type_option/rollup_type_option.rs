@@ -0,0 +1,204 @@
+use crate::entities::{FieldType, NumberFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  default_order, BoxTypeOptionBuilder, NumberCellData, RollupCellChangeset, RollupCellData,
+  StrCellData, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+  TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::FlowyResult;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+/// The number of the decimal places that the average is rounded to.
+const ROLLUP_AVERAGE_SCALE: u32 = 8;
+
+#[derive(Default)]
+pub struct RollupTypeOptionBuilder(RollupTypeOptionPB);
+impl_into_box_type_option_builder!(RollupTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(RollupTypeOptionBuilder, RollupTypeOptionPB);
+
+impl RollupTypeOptionBuilder {
+  pub fn relation_field_id(mut self, field_id: &str) -> Self {
+    self.0.relation_field_id = field_id.to_owned();
+    self
+  }
+
+  pub fn target_field_id(mut self, field_id: &str) -> Self {
+    self.0.target_field_id = field_id.to_owned();
+    self
+  }
+
+  pub fn calculation(mut self, calculation: RollupCalculationPB) -> Self {
+    self.0.calculation = calculation;
+    self
+  }
+}
+
+impl TypeOptionBuilder for RollupTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::Rollup
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The rollup cell aggregates a field of the rows that the relation cell of the same row links.
+/// The value is calculated in the background whenever the linked rows change, and it's saved in
+/// the cell, so it can be filtered and sorted like a number.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct RollupTypeOptionPB {
+  /// The id of the relation field that links the rows
+  #[pb(index = 1)]
+  pub relation_field_id: String,
+
+  /// The id of the field in the related database whose cells are aggregated
+  #[pb(index = 2)]
+  pub target_field_id: String,
+
+  #[pb(index = 3)]
+  pub calculation: RollupCalculationPB,
+}
+impl_type_option!(RollupTypeOptionPB, FieldType::Rollup);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ProtoBuf_Enum)]
+pub enum RollupCalculationPB {
+  Count = 0,
+  Sum = 1,
+  Min = 2,
+  Max = 3,
+  Average = 4,
+}
+
+impl std::default::Default for RollupCalculationPB {
+  fn default() -> Self {
+    RollupCalculationPB::Count
+  }
+}
+
+impl RollupCalculationPB {
+  /// Calculates the value of the rollup cell. Each item of the `values` is a non-empty cell of
+  /// the linked rows, it's None if the cell is not a number. The cells that are not numbers are
+  /// only counted. The sum and the average are None if the sum overflows.
+  pub fn calculate(&self, values: &[Option<Decimal>]) -> Option<Decimal> {
+    let numbers = values.iter().flatten().copied().collect::<Vec<Decimal>>();
+    let sum = || {
+      numbers
+        .iter()
+        .try_fold(Decimal::ZERO, |sum, number| sum.checked_add(*number))
+    };
+    match self {
+      RollupCalculationPB::Count => Some(Decimal::from(values.len())),
+      RollupCalculationPB::Sum => sum(),
+      RollupCalculationPB::Min => numbers.iter().min().copied(),
+      RollupCalculationPB::Max => numbers.iter().max().copied(),
+      RollupCalculationPB::Average => {
+        if numbers.is_empty() {
+          return None;
+        }
+        Some((sum()? / Decimal::from(numbers.len())).round_dp(ROLLUP_AVERAGE_SCALE))
+      },
+    }
+  }
+}
+
+impl TypeOption for RollupTypeOptionPB {
+  type CellData = RollupCellData;
+  type CellChangeset = RollupCellChangeset;
+  type CellProtobufType = StrCellData;
+  type CellFilter = NumberFilterPB;
+}
+
+impl TypeOptionTransform for RollupTypeOptionPB {}
+
+impl TypeOptionCellData for RollupTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    cell_data.to_string().into()
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    RollupCellData::from_cell_str(&cell_str)
+  }
+}
+
+impl CellDataDecoder for RollupTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    // The cells of other field types are calculated again instead of being transformed
+    if !decoded_field_type.is_rollup() {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.to_string()
+  }
+}
+
+impl CellDataChangeset for RollupTypeOptionPB {
+  fn apply_changeset(
+    &self,
+    _changeset: <Self as TypeOption>::CellChangeset,
+    type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    // The cell is read-only, it's saved by the rollup calculation instead
+    let cell_str = type_cell_data
+      .filter(|type_cell_data| type_cell_data.is_rollup())
+      .map(|type_cell_data| type_cell_data.cell_str)
+      .unwrap_or_default();
+    let cell_data = RollupCellData::from_cell_str(&cell_str)?;
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for RollupTypeOptionPB {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_rollup() {
+      return true;
+    }
+
+    let number_cell_data = match cell_data.value {
+      None => NumberCellData::new(),
+      Some(value) => NumberCellData::from_decimal(value),
+    };
+    filter.is_visible(&number_cell_data)
+  }
+}
+
+impl TypeOptionCellDataCompare for RollupTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    match (cell_data.value, other_cell_data.value) {
+      (Some(left), Some(right)) => left.cmp(&right),
+      (Some(_), None) => Ordering::Greater,
+      (None, Some(_)) => Ordering::Less,
+      (None, None) => default_order(),
+    }
+  }
+}
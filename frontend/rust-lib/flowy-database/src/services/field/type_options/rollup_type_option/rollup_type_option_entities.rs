@@ -0,0 +1,45 @@
+use crate::services::cell::{DecodedCellData, FromCellString};
+use flowy_error::FlowyResult;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// The value that the rollup calculated from the linked rows. It's empty if there is nothing to
+/// calculate, e.g. the average of the linked rows that have no numbers.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RollupCellData {
+  pub value: Option<Decimal>,
+}
+
+impl From<Option<Decimal>> for RollupCellData {
+  fn from(value: Option<Decimal>) -> Self {
+    Self { value }
+  }
+}
+
+impl FromCellString for RollupCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self> {
+    // The cell string that isn't a number is treated as empty
+    Ok(Self::from(Decimal::from_str(s.trim()).ok()))
+  }
+}
+
+impl ToString for RollupCellData {
+  fn to_string(&self) -> String {
+    match self.value {
+      None => "".to_owned(),
+      Some(value) => value.normalize().to_string(),
+    }
+  }
+}
+
+impl DecodedCellData for RollupCellData {
+  type Object = RollupCellData;
+
+  fn is_empty(&self) -> bool {
+    self.value.is_none()
+  }
+}
+
+/// The calculated value of the rollup cell. The rollup cells are only updated by the
+/// calculation, they can't be edited.
+pub type RollupCellChangeset = String;
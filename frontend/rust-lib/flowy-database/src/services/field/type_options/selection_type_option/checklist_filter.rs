@@ -1,40 +1,17 @@
 use crate::entities::{ChecklistFilterConditionPB, ChecklistFilterPB};
-use crate::services::field::{SelectOptionPB, SelectedSelectOptions};
 
 impl ChecklistFilterPB {
-  pub fn is_visible(
-    &self,
-    all_options: &[SelectOptionPB],
-    selected_options: &SelectedSelectOptions,
-  ) -> bool {
-    let selected_option_ids = selected_options
-      .options
-      .iter()
-      .map(|option| option.id.as_str())
-      .collect::<Vec<&str>>();
-
-    let mut all_option_ids = all_options
-      .iter()
-      .map(|option| option.id.as_str())
-      .collect::<Vec<&str>>();
-
+  /// Returns whether the cell is visible, where `percentage` is the fraction of the items that
+  /// are checked in the cell. See [ChecklistCellData::percentage_complete].
+  pub fn is_visible(&self, percentage: f64) -> bool {
     match self.condition {
-      ChecklistFilterConditionPB::IsComplete => {
-        if selected_option_ids.is_empty() {
-          return false;
-        }
-
-        all_option_ids.retain(|option_id| !selected_option_ids.contains(option_id));
-        all_option_ids.is_empty()
-      },
-      ChecklistFilterConditionPB::IsIncomplete => {
-        if selected_option_ids.is_empty() {
-          return true;
-        }
-
-        all_option_ids.retain(|option_id| !selected_option_ids.contains(option_id));
-        !all_option_ids.is_empty()
-      },
+      ChecklistFilterConditionPB::IsComplete => percentage >= 1.0,
+      ChecklistFilterConditionPB::IsIncomplete => percentage < 1.0,
+      ChecklistFilterConditionPB::IsCompleteAbove => percentage * 100.0 > self.percentage as f64,
+      ChecklistFilterConditionPB::IsCompleteBelow => percentage * 100.0 < self.percentage as f64,
+      // The due dates and the items are checked by the type option
+      ChecklistFilterConditionPB::HasOverdueItems
+      | ChecklistFilterConditionPB::HasUncheckedItems => true,
     }
   }
 }
@@ -0,0 +1,365 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::{CellIdPB, CellIdParams};
+use crate::services::cell::{
+  CellProtobufBlobParser, DecodedCellData, FromCellChangesetString, FromCellString,
+  ToCellChangesetString,
+};
+use crate::services::field::gen_option_id;
+use bytes::Bytes;
+use flowy_derive::ProtoBuf;
+use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+
+/// [ChecklistItemPB] is an item of the checklist. Each cell stores its own items, so renaming,
+/// checking or deleting an item doesn't change the other rows.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ProtoBuf)]
+pub struct ChecklistItemPB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub is_checked: bool,
+
+  /// The due timestamp in seconds
+  #[pb(index = 4, one_of)]
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub due: Option<i64>,
+}
+
+impl ChecklistItemPB {
+  pub fn new(name: &str) -> Self {
+    Self {
+      id: gen_option_id(),
+      name: name.to_owned(),
+      is_checked: false,
+      due: None,
+    }
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct ChecklistCellDataPB {
+  #[pb(index = 1)]
+  pub items: Vec<ChecklistItemPB>,
+
+  /// The fraction of the items that are checked, from 0.0 to 1.0
+  #[pb(index = 2)]
+  pub percentage: f64,
+}
+
+impl From<ChecklistCellData> for ChecklistCellDataPB {
+  fn from(data: ChecklistCellData) -> Self {
+    let percentage = data.percentage_complete();
+    Self {
+      items: data.items,
+      percentage,
+    }
+  }
+}
+
+impl DecodedCellData for ChecklistCellDataPB {
+  type Object = ChecklistCellDataPB;
+
+  fn is_empty(&self) -> bool {
+    self.items.is_empty()
+  }
+}
+
+pub struct ChecklistCellDataParser();
+impl CellProtobufBlobParser for ChecklistCellDataParser {
+  type Object = ChecklistCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    ChecklistCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+/// The items of the checklist cell in their order. It's stored in the cell as a json string.
+///
+/// The older versions stored the ids of the checked items in the cell and the items in the
+/// type option, see [ChecklistTypeOptionPB::decode_type_option_cell_str].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChecklistCellData {
+  pub items: Vec<ChecklistItemPB>,
+}
+
+impl ChecklistCellData {
+  pub fn get_item(&self, item_id: &str) -> Option<&ChecklistItemPB> {
+    self.items.iter().find(|item| item.id == item_id)
+  }
+
+  pub fn num_of_checked(&self) -> usize {
+    self.items.iter().filter(|item| item.is_checked).count()
+  }
+
+  /// Returns the fraction of the items that are checked, from 0.0 to 1.0. A checklist without
+  /// items is 0.0.
+  pub fn percentage_complete(&self) -> f64 {
+    if self.items.is_empty() {
+      return 0.0;
+    }
+    self.num_of_checked() as f64 / self.items.len() as f64
+  }
+
+  pub fn has_unchecked_items(&self) -> bool {
+    self.items.iter().any(|item| !item.is_checked)
+  }
+
+  /// Returns the items that are due before `now` and are not checked.
+  pub fn get_overdue_items(&self, now: i64) -> Vec<&ChecklistItemPB> {
+    self
+      .items
+      .iter()
+      .filter(|item| !item.is_checked)
+      .filter(|item| matches!(item.due, Some(due) if due < now))
+      .collect()
+  }
+
+  /// Returns the names of the checked items.
+  pub fn checked_item_names(&self) -> Vec<String> {
+    self
+      .items
+      .iter()
+      .filter(|item| item.is_checked)
+      .map(|item| item.name.clone())
+      .collect()
+  }
+
+  /// Applies the changeset to the items. Returns [FlowyError::record_not_found] if the
+  /// changeset updates or moves an item that doesn't exist.
+  pub fn apply_changeset(&mut self, changeset: ChecklistCellChangeset) -> FlowyResult<()> {
+    let not_found = |item_id: &str| {
+      FlowyError::record_not_found().context(format!("The checklist item:{} is not found", item_id))
+    };
+    for item in changeset.update_items.iter() {
+      if self.get_item(&item.id).is_none() {
+        return Err(not_found(&item.id));
+      }
+    }
+    for item_due in changeset.item_dues.iter() {
+      if self.get_item(&item_due.item_id).is_none() {
+        return Err(not_found(&item_due.item_id));
+      }
+    }
+
+    for name in changeset.insert_items {
+      self.items.push(ChecklistItemPB::new(&name));
+    }
+
+    // The due date of the item is set by the `item_dues`
+    for update_item in changeset.update_items {
+      if let Some(item) = self.items.iter_mut().find(|item| item.id == update_item.id) {
+        item.name = update_item.name;
+        item.is_checked = update_item.is_checked;
+      }
+    }
+
+    for item_due in changeset.item_dues {
+      if let Some(item) = self
+        .items
+        .iter_mut()
+        .find(|item| item.id == item_due.item_id)
+      {
+        item.due = item_due.due;
+      }
+    }
+
+    self
+      .items
+      .retain(|item| !changeset.delete_item_ids.contains(&item.id));
+
+    if let Some((from_item_id, to_item_id)) = changeset.move_item {
+      let from_index = self.items.iter().position(|item| item.id == from_item_id);
+      let to_index = self.items.iter().position(|item| item.id == to_item_id);
+      match (from_index, to_index) {
+        (Some(from_index), Some(to_index)) => {
+          let item = self.items.remove(from_index);
+          self.items.insert(to_index, item);
+        },
+        (None, _) => return Err(not_found(&from_item_id)),
+        (_, None) => return Err(not_found(&to_item_id)),
+      }
+    }
+    Ok(())
+  }
+}
+
+impl FromCellString for ChecklistCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self> {
+    if s.is_empty() {
+      return Ok(Self::default());
+    }
+    serde_json::from_str::<ChecklistCellData>(s).map_err(internal_error)
+  }
+}
+
+impl ToString for ChecklistCellData {
+  fn to_string(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
+
+/// The due date of the item, or None to remove it.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ChecklistItemDue {
+  pub item_id: String,
+  pub due: Option<i64>,
+}
+
+/// [ChecklistCellChangeset] inserts, updates, deletes and moves the items of the checklist cell.
+/// Updating an item renames it and checks or unchecks it.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ChecklistCellChangeset {
+  /// The names of the items to append to the checklist. The new items are unchecked.
+  #[serde(default)]
+  pub insert_items: Vec<String>,
+
+  #[serde(default)]
+  pub update_items: Vec<ChecklistItemPB>,
+
+  #[serde(default)]
+  pub delete_item_ids: Vec<String>,
+
+  #[serde(default)]
+  pub item_dues: Vec<ChecklistItemDue>,
+
+  /// Moves the first item to the position of the second item
+  #[serde(default)]
+  pub move_item: Option<(String, String)>,
+}
+
+impl ChecklistCellChangeset {
+  pub fn from_item_due(item_id: &str, due: Option<i64>) -> Self {
+    Self {
+      item_dues: vec![ChecklistItemDue {
+        item_id: item_id.to_owned(),
+        due,
+      }],
+      ..Default::default()
+    }
+  }
+
+  pub fn from_move_item(from_item_id: &str, to_item_id: &str) -> Self {
+    Self {
+      move_item: Some((from_item_id.to_owned(), to_item_id.to_owned())),
+      ..Default::default()
+    }
+  }
+}
+
+impl FromCellChangesetString for ChecklistCellChangeset {
+  fn from_changeset(changeset: String) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    serde_json::from_str::<ChecklistCellChangeset>(&changeset).map_err(internal_error)
+  }
+}
+
+impl ToCellChangesetString for ChecklistCellChangeset {
+  fn to_cell_changeset_str(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
+
+/// [ChecklistCellChangesetPB] inserts, updates and deletes the items of the checklist cell.
+/// Updating an item renames it and checks or unchecks it, its due date is set by the
+/// [ChecklistItemDueChangesetPB].
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct ChecklistCellChangesetPB {
+  #[pb(index = 1)]
+  pub cell_id: CellIdPB,
+
+  /// The names of the items to append to the checklist. The new items are unchecked.
+  #[pb(index = 2)]
+  pub insert_items: Vec<String>,
+
+  #[pb(index = 3)]
+  pub update_items: Vec<ChecklistItemPB>,
+
+  #[pb(index = 4)]
+  pub delete_item_ids: Vec<String>,
+}
+
+pub struct ChecklistCellChangesetParams {
+  pub cell_id: CellIdParams,
+  pub insert_items: Vec<String>,
+  pub update_items: Vec<ChecklistItemPB>,
+  pub delete_item_ids: Vec<String>,
+}
+
+impl From<ChecklistCellChangesetParams> for ChecklistCellChangeset {
+  fn from(params: ChecklistCellChangesetParams) -> Self {
+    Self {
+      insert_items: params.insert_items,
+      update_items: params.update_items,
+      delete_item_ids: params.delete_item_ids,
+      ..Default::default()
+    }
+  }
+}
+
+impl TryInto<ChecklistCellChangesetParams> for ChecklistCellChangesetPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ChecklistCellChangesetParams, Self::Error> {
+    let cell_id: CellIdParams = self.cell_id.try_into()?;
+    let insert_items = self
+      .insert_items
+      .into_iter()
+      .map(|name| NotEmptyStr::parse(name.trim().to_owned()).map(|name| name.0))
+      .collect::<Result<Vec<String>, _>>()
+      .map_err(|_| ErrorCode::UnexpectedEmptyString)?;
+    for item in self.update_items.iter() {
+      if item.id.is_empty() || item.name.trim().is_empty() {
+        return Err(ErrorCode::UnexpectedEmptyString);
+      }
+    }
+    Ok(ChecklistCellChangesetParams {
+      cell_id,
+      insert_items,
+      update_items: self.update_items,
+      delete_item_ids: self.delete_item_ids,
+    })
+  }
+}
+
+/// [MoveChecklistItemPayloadPB] moves the item of the checklist cell to the position of another
+/// item of the cell.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct MoveChecklistItemPayloadPB {
+  #[pb(index = 1)]
+  pub cell_id: CellIdPB,
+
+  #[pb(index = 2)]
+  pub from_item_id: String,
+
+  #[pb(index = 3)]
+  pub to_item_id: String,
+}
+
+pub struct MoveChecklistItemParams {
+  pub cell_id: CellIdParams,
+  pub from_item_id: String,
+  pub to_item_id: String,
+}
+
+impl TryInto<MoveChecklistItemParams> for MoveChecklistItemPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<MoveChecklistItemParams, Self::Error> {
+    let cell_id: CellIdParams = self.cell_id.try_into()?;
+    let from_item_id =
+      NotEmptyStr::parse(self.from_item_id).map_err(|_| ErrorCode::UnexpectedEmptyString)?;
+    let to_item_id =
+      NotEmptyStr::parse(self.to_item_id).map_err(|_| ErrorCode::UnexpectedEmptyString)?;
+    Ok(MoveChecklistItemParams {
+      cell_id,
+      from_item_id: from_item_id.0,
+      to_item_id: to_item_id.0,
+    })
+  }
+}
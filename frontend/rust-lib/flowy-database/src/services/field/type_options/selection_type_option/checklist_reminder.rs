@@ -0,0 +1,116 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::{CellIdPB, CellIdParams, FieldType};
+use crate::services::cell::TypeCellData;
+use crate::services::field::{ChecklistTypeOptionPB, TypeOptionCellData};
+use database_model::{FieldRevision, RowRevision};
+use flowy_derive::ProtoBuf;
+use flowy_error::ErrorCode;
+use std::sync::Arc;
+
+/// [ChecklistItemDueChangesetPB] sets the due date of an item of the checklist cell, or removes
+/// it if the `due` is None.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct ChecklistItemDueChangesetPB {
+  #[pb(index = 1)]
+  pub cell_id: CellIdPB,
+
+  #[pb(index = 2)]
+  pub item_id: String,
+
+  #[pb(index = 3, one_of)]
+  pub due: Option<i64>,
+}
+
+pub struct ChecklistItemDueChangeset {
+  pub cell_id: CellIdParams,
+  pub item_id: String,
+  pub due: Option<i64>,
+}
+
+impl TryInto<ChecklistItemDueChangeset> for ChecklistItemDueChangesetPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ChecklistItemDueChangeset, Self::Error> {
+    let cell_id: CellIdParams = self.cell_id.try_into()?;
+    let item_id = NotEmptyStr::parse(self.item_id).map_err(|_| ErrorCode::UnexpectedEmptyString)?;
+    Ok(ChecklistItemDueChangeset {
+      cell_id,
+      item_id: item_id.0,
+      due: self.due,
+    })
+  }
+}
+
+/// [ChecklistReminderPB] is a checklist item of a row that has a due date. The reminders of a view
+/// are returned by the `GetChecklistReminders` event, and the
+/// [AutomationController](crate::services::automation::AutomationController) adds the due ones
+/// to the inbox.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct ChecklistReminderPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub item_id: String,
+
+  #[pb(index = 4)]
+  pub name: String,
+
+  #[pb(index = 5)]
+  pub due: i64,
+
+  /// The item is checked in the row
+  #[pb(index = 6)]
+  pub is_completed: bool,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RepeatedChecklistReminderPB {
+  #[pb(index = 1)]
+  pub items: Vec<ChecklistReminderPB>,
+}
+
+/// Returns a reminder for each item that has a due date, in each of the rows. The reminders are
+/// sorted by their due dates.
+pub fn make_checklist_reminders(
+  field_revs: &[Arc<FieldRevision>],
+  row_revs: &[Arc<RowRevision>],
+) -> Vec<ChecklistReminderPB> {
+  let mut reminders = vec![];
+  for field_rev in field_revs {
+    if !FieldType::from(field_rev.ty).is_check_list() {
+      continue;
+    }
+    let type_option = ChecklistTypeOptionPB::from(field_rev.as_ref());
+    for row_rev in row_revs {
+      let cell_str = row_rev
+        .cells
+        .get(&field_rev.id)
+        .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+        .map(|type_cell_data| type_cell_data.cell_str)
+        .unwrap_or_default();
+      let cell_data = match type_option.decode_type_option_cell_str(cell_str) {
+        Ok(cell_data) => cell_data,
+        Err(_) => continue,
+      };
+
+      for item in cell_data.items {
+        if let Some(due) = item.due {
+          reminders.push(ChecklistReminderPB {
+            row_id: row_rev.id.clone(),
+            field_id: field_rev.id.clone(),
+            item_id: item.id,
+            name: item.name,
+            due,
+            is_completed: item.is_checked,
+          });
+        }
+      }
+    }
+  }
+  reminders.sort_by_key(|reminder| reminder.due);
+  reminders
+}
@@ -1,63 +1,127 @@
-use crate::entities::{ChecklistFilterPB, FieldType};
+use crate::entities::{ChecklistFilterConditionPB, ChecklistFilterPB, FieldType};
 use crate::impl_type_option;
-use crate::services::cell::{CellDataChangeset, FromCellString, TypeCellData};
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
 use crate::services::field::{
-  BoxTypeOptionBuilder, SelectOptionCellChangeset, SelectOptionCellDataPB, SelectOptionIds,
-  SelectOptionPB, SelectTypeOptionSharedAction, SelectedSelectOptions, TypeOption,
-  TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  BoxTypeOptionBuilder, ChecklistCellChangeset, ChecklistCellData, ChecklistCellDataPB,
+  ChecklistItemPB, SelectOptionIds, SelectOptionPB, TypeOption, TypeOptionBuilder,
+  TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+  SELECTION_IDS_SEPARATOR,
 };
 use bytes::Bytes;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
 use flowy_derive::ProtoBuf;
 use flowy_error::FlowyResult;
+use lib_infra::util::timestamp;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
-// Multiple select
+/// The items of the checklist are stored in each cell, see [ChecklistCellData].
 #[derive(Clone, Debug, Default, Serialize, Deserialize, ProtoBuf)]
 pub struct ChecklistTypeOptionPB {
+  /// The items of the cells that were not edited yet. The older versions stored the items of
+  /// all the cells here.
   #[pb(index = 1)]
   pub options: Vec<SelectOptionPB>,
 
   #[pb(index = 2)]
   pub disable_color: bool,
+
+  /// The due dates of the `options`. It's only read to decode the cells of the older versions,
+  /// the due dates are stored in the items of the cells.
+  #[pb(index = 3)]
+  #[serde(default)]
+  pub item_due_dates: Vec<ChecklistItemDuePB>,
 }
 impl_type_option!(ChecklistTypeOptionPB, FieldType::Checklist);
 
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ProtoBuf)]
+pub struct ChecklistItemDuePB {
+  /// The id of the option that the item is made of
+  #[pb(index = 1)]
+  pub option_id: String,
+
+  /// The due timestamp in seconds
+  #[pb(index = 2)]
+  pub due: i64,
+}
+
+impl ChecklistTypeOptionPB {
+  pub fn get_item_due(&self, option_id: &str) -> Option<i64> {
+    self
+      .item_due_dates
+      .iter()
+      .find(|item_due| item_due.option_id == option_id)
+      .map(|item_due| item_due.due)
+  }
+
+  /// Returns the items of a cell that was not edited yet, the `checked_ids` are the ids of the
+  /// checked options that the older versions stored in the cell.
+  fn template_cell_data(&self, checked_ids: &SelectOptionIds) -> ChecklistCellData {
+    let items = self
+      .options
+      .iter()
+      .map(|option| ChecklistItemPB {
+        id: option.id.clone(),
+        name: option.name.clone(),
+        is_checked: checked_ids.contains(&option.id),
+        due: self.get_item_due(&option.id),
+      })
+      .collect();
+    ChecklistCellData { items }
+  }
+}
+
 impl TypeOption for ChecklistTypeOptionPB {
-  type CellData = SelectOptionIds;
-  type CellChangeset = SelectOptionCellChangeset;
-  type CellProtobufType = SelectOptionCellDataPB;
+  type CellData = ChecklistCellData;
+  type CellChangeset = ChecklistCellChangeset;
+  type CellProtobufType = ChecklistCellDataPB;
   type CellFilter = ChecklistFilterPB;
 }
 
+impl TypeOptionTransform for ChecklistTypeOptionPB {}
+
 impl TypeOptionCellData for ChecklistTypeOptionPB {
   fn convert_to_protobuf(
     &self,
     cell_data: <Self as TypeOption>::CellData,
   ) -> <Self as TypeOption>::CellProtobufType {
-    self.get_selected_options(cell_data)
+    cell_data.into()
   }
 
+  /// The older versions stored the ids of the checked options in the cell, the cell is
+  /// decoded to the items of the type option.
   fn decode_type_option_cell_str(
     &self,
     cell_str: String,
   ) -> FlowyResult<<Self as TypeOption>::CellData> {
-    SelectOptionIds::from_cell_str(&cell_str)
+    match ChecklistCellData::from_cell_str(&cell_str) {
+      Ok(cell_data) if !cell_str.is_empty() => Ok(cell_data),
+      _ => Ok(self.template_cell_data(&SelectOptionIds::from(cell_str))),
+    }
   }
-}
 
-impl SelectTypeOptionSharedAction for ChecklistTypeOptionPB {
-  fn number_of_max_options(&self) -> Option<usize> {
-    None
+  /// The cells of the older versions are migrated to the items of the cell.
+  fn is_stale_cell_str(&self, cell_str: &str) -> bool {
+    !cell_str.is_empty() && ChecklistCellData::from_cell_str(cell_str).is_err()
   }
+}
+
+impl CellDataDecoder for ChecklistTypeOptionPB {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if !decoded_field_type.is_check_list() {
+      return Ok(self.template_cell_data(&SelectOptionIds::new()));
+    }
 
-  fn options(&self) -> &Vec<SelectOptionPB> {
-    &self.options
+    self.decode_type_option_cell_str(cell_str)
   }
 
-  fn mut_options(&mut self) -> &mut Vec<SelectOptionPB> {
-    &mut self.options
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    cell_data.checked_item_names().join(SELECTION_IDS_SEPARATOR)
   }
 }
 
@@ -67,37 +131,17 @@ impl CellDataChangeset for ChecklistTypeOptionPB {
     changeset: <Self as TypeOption>::CellChangeset,
     type_cell_data: Option<TypeCellData>,
   ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
-    let insert_option_ids = changeset
-      .insert_option_ids
-      .into_iter()
-      .filter(|insert_option_id| {
-        self
-          .options
-          .iter()
-          .any(|option| &option.id == insert_option_id)
-      })
-      .collect::<Vec<String>>();
-
-    let select_option_ids = match type_cell_data {
-      None => SelectOptionIds::from(insert_option_ids),
-      Some(type_cell_data) => {
-        let mut select_ids: SelectOptionIds = type_cell_data.cell_str.into();
-        for insert_option_id in insert_option_ids {
-          if !select_ids.contains(&insert_option_id) {
-            select_ids.push(insert_option_id);
-          }
-        }
-
-        for delete_option_id in changeset.delete_option_ids {
-          select_ids.retain(|id| id != &delete_option_id);
-        }
-
-        select_ids
+    let mut cell_data = match type_cell_data {
+      Some(type_cell_data) if type_cell_data.is_checklist() => {
+        self.decode_type_option_cell_str(type_cell_data.cell_str)?
       },
+      _ => self.template_cell_data(&SelectOptionIds::new()),
     };
-    Ok((select_option_ids.to_string(), select_option_ids))
+    cell_data.apply_changeset(changeset)?;
+    Ok((cell_data.to_string(), cell_data))
   }
 }
+
 impl TypeOptionCellDataFilter for ChecklistTypeOptionPB {
   fn apply_filter(
     &self,
@@ -108,9 +152,18 @@ impl TypeOptionCellDataFilter for ChecklistTypeOptionPB {
     if !field_type.is_check_list() {
       return true;
     }
-    let selected_options =
-      SelectedSelectOptions::from(self.get_selected_options(cell_data.clone()));
-    filter.is_visible(&self.options, &selected_options)
+    match filter.condition {
+      ChecklistFilterConditionPB::HasOverdueItems => {
+        !cell_data.get_overdue_items(timestamp()).is_empty()
+      },
+      ChecklistFilterConditionPB::HasUncheckedItems => cell_data.has_unchecked_items(),
+      _ => filter.is_visible(cell_data.percentage_complete()),
+    }
+  }
+
+  /// The cell is empty if none of the items is checked
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.num_of_checked() == 0
   }
 }
 
@@ -120,7 +173,9 @@ impl TypeOptionCellDataCompare for ChecklistTypeOptionPB {
     cell_data: &<Self as TypeOption>::CellData,
     other_cell_data: &<Self as TypeOption>::CellData,
   ) -> Ordering {
-    cell_data.len().cmp(&other_cell_data.len())
+    cell_data
+      .num_of_checked()
+      .cmp(&other_cell_data.num_of_checked())
   }
 }
 
@@ -1,14 +1,20 @@
 mod checklist_filter;
+mod checklist_items;
+mod checklist_reminder;
 mod checklist_type_option;
 mod multi_select_type_option;
 mod select_filter;
+mod select_option_dependency;
 mod select_type_option;
 mod single_select_type_option;
 mod type_option_transform;
 
 pub use checklist_type_option::*;
 pub use multi_select_type_option::*;
+pub use select_option_dependency::*;
 pub use select_type_option::*;
 pub use single_select_type_option::*;
 
 pub use checklist_filter::*;
+pub use checklist_items::*;
+pub use checklist_reminder::*;
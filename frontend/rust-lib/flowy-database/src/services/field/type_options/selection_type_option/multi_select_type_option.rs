@@ -5,8 +5,9 @@ use std::cmp::{min, Ordering};
 
 use crate::services::field::{
   default_order, BoxTypeOptionBuilder, SelectOptionCellChangeset, SelectOptionCellDataPB,
-  SelectOptionIds, SelectOptionPB, SelectTypeOptionSharedAction, SelectedSelectOptions, TypeOption,
-  TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  SelectOptionDependencyPB, SelectOptionIds, SelectOptionPB, SelectTypeOptionSharedAction,
+  SelectedSelectOptions, TypeOption, TypeOptionBuilder, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter,
 };
 use bytes::Bytes;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
@@ -22,6 +23,17 @@ pub struct MultiSelectTypeOptionPB {
 
   #[pb(index = 2)]
   pub disable_color: bool,
+
+  /// Creates the options for the names in the cell changeset that don't match any option,
+  /// e.g. the values pasted from a CSV file. Otherwise, the unknown names are dropped.
+  #[pb(index = 3)]
+  #[serde(default)]
+  pub create_missing_options: bool,
+
+  /// Restricts the options that can be selected by the options of another select field.
+  #[pb(index = 4, one_of)]
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub dependency: Option<SelectOptionDependencyPB>,
 }
 impl_type_option!(MultiSelectTypeOptionPB, FieldType::MultiSelect);
 
@@ -46,6 +58,10 @@ impl TypeOptionCellData for MultiSelectTypeOptionPB {
   ) -> FlowyResult<<Self as TypeOption>::CellData> {
     SelectOptionIds::from_cell_str(&cell_str)
   }
+
+  fn is_stale_cell_str(&self, cell_str: &str) -> bool {
+    SelectOptionIds::is_stale_cell_str(cell_str)
+  }
 }
 
 impl SelectTypeOptionSharedAction for MultiSelectTypeOptionPB {
@@ -60,6 +76,14 @@ impl SelectTypeOptionSharedAction for MultiSelectTypeOptionPB {
   fn mut_options(&mut self) -> &mut Vec<SelectOptionPB> {
     &mut self.options
   }
+
+  fn create_missing_options(&self) -> bool {
+    self.create_missing_options
+  }
+
+  fn dependency(&self) -> Option<&SelectOptionDependencyPB> {
+    self.dependency.as_ref()
+  }
 }
 
 impl CellDataChangeset for MultiSelectTypeOptionPB {
@@ -77,7 +101,9 @@ impl CellDataChangeset for MultiSelectTypeOptionPB {
           .iter()
           .any(|option| &option.id == insert_option_id)
       })
+      .chain(self.get_option_ids_by_names(&changeset.insert_option_names))
       .collect::<Vec<String>>();
+    self.check_dependency(changeset.parent_option_ids.as_deref(), &insert_option_ids)?;
 
     let select_option_ids = match type_cell_data {
       None => SelectOptionIds::from(insert_option_ids),
@@ -115,6 +141,14 @@ impl TypeOptionCellDataFilter for MultiSelectTypeOptionPB {
       SelectedSelectOptions::from(self.get_selected_options(cell_data.clone()));
     filter.is_visible(&selected_options, FieldType::MultiSelect)
   }
+
+  /// The ids of the deleted options are ignored
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    self
+      .get_selected_options(cell_data.clone())
+      .select_options
+      .is_empty()
+  }
 }
 
 impl TypeOptionCellDataCompare for MultiSelectTypeOptionPB {
@@ -309,4 +343,44 @@ mod tests {
     let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
     assert!(select_option_ids.is_empty());
   }
+
+  #[test]
+  fn multi_select_insert_option_names_test() {
+    let google = SelectOptionPB::new("Google");
+    let multi_select = MultiSelectTypeOptionBuilder::default().add_option(google.clone());
+    let field_rev = FieldBuilder::new(multi_select).name("Platform").build();
+    let mut type_option = MultiSelectTypeOptionPB::from(&field_rev);
+    let names = vec![" Google ".to_owned(), "Twitter".to_owned()];
+
+    // The unknown names are dropped by default
+    assert!(type_option.insert_missing_options(&names).is_empty());
+    let changeset = SelectOptionCellChangeset::from_insert_option_names(names.clone());
+    let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
+    assert_eq!(&*select_option_ids, &vec![google.id.clone()]);
+
+    type_option.create_missing_options = true;
+    let new_options = type_option.insert_missing_options(&names);
+    assert_eq!(new_options.len(), 1);
+    assert_eq!(new_options[0].name, "Twitter");
+    assert_eq!(type_option.options.len(), 2);
+    let changeset = SelectOptionCellChangeset::from_insert_option_names(names);
+    let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
+    assert_eq!(
+      &*select_option_ids,
+      &vec![google.id, new_options[0].id.clone()]
+    );
+  }
+
+  #[test]
+  fn multi_select_insert_duplicated_missing_options_test() {
+    let multi_select = MultiSelectTypeOptionBuilder::default();
+    let field_rev = FieldBuilder::new(multi_select).name("Platform").build();
+    let mut type_option = MultiSelectTypeOptionPB::from(&field_rev);
+    type_option.create_missing_options = true;
+
+    let names = vec!["Google".to_owned(), "".to_owned(), "Google ".to_owned()];
+    let new_options = type_option.insert_missing_options(&names);
+    assert_eq!(new_options.len(), 1);
+    assert_eq!(type_option.options, new_options);
+  }
 }
@@ -0,0 +1,91 @@
+use flowy_derive::ProtoBuf;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Restricts the options of a select field by the options that are selected in another select
+/// field of the same row, e.g. the options of the "Subcategory" depend on the "Category".
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ProtoBuf)]
+pub struct SelectOptionDependencyPB {
+  /// The id of the select field that the field depends on
+  #[pb(index = 1)]
+  pub field_id: String,
+
+  #[pb(index = 2)]
+  pub rules: Vec<SelectOptionDependencyRulePB>,
+}
+
+/// The options that can be selected when the option of the parent field is selected.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ProtoBuf)]
+pub struct SelectOptionDependencyRulePB {
+  #[pb(index = 1)]
+  pub parent_option_id: String,
+
+  #[pb(index = 2)]
+  pub option_ids: Vec<String>,
+}
+
+impl SelectOptionDependencyPB {
+  /// Returns the ids of the options that can be selected when the `parent_option_ids` are
+  /// selected in the parent field. Returns None if any option can be selected, which is the
+  /// case when none of the selected parent options has a rule.
+  pub fn allowed_option_ids(&self, parent_option_ids: &[String]) -> Option<HashSet<String>> {
+    let mut allowed_option_ids: Option<HashSet<String>> = None;
+    for rule in self
+      .rules
+      .iter()
+      .filter(|rule| parent_option_ids.contains(&rule.parent_option_id))
+    {
+      allowed_option_ids
+        .get_or_insert_with(HashSet::new)
+        .extend(rule.option_ids.iter().cloned());
+    }
+    allowed_option_ids
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn rule(parent_option_id: &str, option_ids: &[&str]) -> SelectOptionDependencyRulePB {
+    SelectOptionDependencyRulePB {
+      parent_option_id: parent_option_id.to_owned(),
+      option_ids: option_ids.iter().map(|id| id.to_string()).collect(),
+    }
+  }
+
+  #[test]
+  fn select_option_dependency_test() {
+    let dependency = SelectOptionDependencyPB {
+      field_id: "category".to_owned(),
+      rules: vec![
+        rule("fruit", &["apple", "banana"]),
+        rule("vegetable", &["carrot"]),
+        rule("nothing", &[]),
+      ],
+    };
+
+    let allowed = dependency
+      .allowed_option_ids(&["fruit".to_owned()])
+      .unwrap();
+    assert_eq!(allowed.len(), 2);
+    assert!(allowed.contains("apple"));
+
+    // The options of all the selected parent options are allowed
+    let allowed = dependency
+      .allowed_option_ids(&["fruit".to_owned(), "vegetable".to_owned()])
+      .unwrap();
+    assert_eq!(allowed.len(), 3);
+
+    assert!(dependency
+      .allowed_option_ids(&["nothing".to_owned()])
+      .unwrap()
+      .is_empty());
+
+    // No restriction if the parent cell is empty or its option doesn't have a rule
+    assert!(dependency.allowed_option_ids(&[]).is_none());
+    assert!(dependency
+      .allowed_option_ids(&["other".to_owned()])
+      .is_none());
+  }
+}
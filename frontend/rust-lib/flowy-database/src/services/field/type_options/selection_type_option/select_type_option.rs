@@ -1,21 +1,24 @@
 use crate::entities::parser::NotEmptyStr;
-use crate::entities::{CellIdPB, CellIdParams, FieldType};
+use crate::entities::{CellIdPB, CellIdParams, FieldIdParams, FieldType};
 use crate::services::cell::{
   CellDataDecoder, CellProtobufBlobParser, DecodedCellData, FromCellChangesetString,
-  FromCellString, ToCellChangesetString,
+  FromCellString, ToCellChangesetString, TypeCellData,
 };
 
 use crate::services::field::selection_type_option::type_option_transform::SelectOptionTypeOptionTransformHelper;
 use crate::services::field::{
-  CheckboxCellData, ChecklistTypeOptionPB, MultiSelectTypeOptionPB, SingleSelectTypeOptionPB,
-  TypeOption, TypeOptionCellData, TypeOptionTransform,
+  CheckboxCellData, ChecklistCellData, MultiSelectTypeOptionPB, SelectOptionDependencyPB,
+  SingleSelectTypeOptionPB, TypeOption, TypeOptionCellData, TypeOptionTransform,
 };
 use bytes::Bytes;
-use database_model::{FieldRevision, TypeOptionDataSerializer};
+use database_model::{CellRevision, FieldRevision, RowRevision, TypeOptionDataSerializer};
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
-use flowy_error::{internal_error, ErrorCode, FlowyResult};
+use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
+use indexmap::IndexMap;
 use nanoid::nanoid;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
 
 pub const SELECTION_IDS_SEPARATOR: &str = ",";
 
@@ -137,9 +140,108 @@ pub trait SelectTypeOptionSharedAction: TypeOptionDataSerializer + Send + Sync {
     }
   }
 
+  /// Returns the ids of the options whose names are in `names`. The names that don't match any
+  /// option are ignored.
+  fn get_option_ids_by_names(&self, names: &[String]) -> Vec<String> {
+    names
+      .iter()
+      .flat_map(|name| {
+        self
+          .options()
+          .iter()
+          .find(|option| option.name == name.trim())
+          .map(|option| option.id.clone())
+      })
+      .collect()
+  }
+
+  /// Creates an option for each name that doesn't match any option if the
+  /// [create_missing_options] is on. Returns the created options.
+  fn insert_missing_options(&mut self, names: &[String]) -> Vec<SelectOptionPB> {
+    let mut new_options: Vec<SelectOptionPB> = vec![];
+    if !self.create_missing_options() {
+      return new_options;
+    }
+
+    for name in names.iter().map(|name| name.trim()) {
+      if name.is_empty() || self.options().iter().any(|option| option.name == name) {
+        continue;
+      }
+      let new_option = self.create_option(name);
+      // Unlike the [insert_option], append the option to keep the order of the names
+      self.mut_options().push(new_option.clone());
+      new_options.push(new_option);
+    }
+    new_options
+  }
+
   fn options(&self) -> &Vec<SelectOptionPB>;
 
   fn mut_options(&mut self) -> &mut Vec<SelectOptionPB>;
+
+  /// Returns true if the unknown option names in the cell changeset should be created as
+  /// options instead of being dropped.
+  fn create_missing_options(&self) -> bool;
+
+  /// Returns the dependency on another select field of the database, if any.
+  fn dependency(&self) -> Option<&SelectOptionDependencyPB> {
+    None
+  }
+
+  /// Returns the options that can be selected when the `parent_option_ids` are selected in the
+  /// field that this field depends on.
+  fn get_allowed_options(&self, parent_option_ids: &[String]) -> Vec<SelectOptionPB> {
+    match self
+      .dependency()
+      .and_then(|dependency| dependency.allowed_option_ids(parent_option_ids))
+    {
+      None => self.options().clone(),
+      Some(allowed_option_ids) => self
+        .options()
+        .iter()
+        .filter(|option| allowed_option_ids.contains(&option.id))
+        .cloned()
+        .collect(),
+    }
+  }
+
+  /// Returns an error if any of the `insert_option_ids` can't be selected with the
+  /// `parent_option_ids`. The options can't be inserted if the field depends on another field
+  /// but the parent options are unknown, the writers fill them, see
+  /// [parent_option_ids_in_cells].
+  fn check_dependency(
+    &self,
+    parent_option_ids: Option<&[String]>,
+    insert_option_ids: &[String],
+  ) -> FlowyResult<()> {
+    let allowed_option_ids = match (self.dependency(), parent_option_ids) {
+      (None, _) => None,
+      (Some(dependency), Some(parent_option_ids)) => {
+        dependency.allowed_option_ids(parent_option_ids)
+      },
+      (Some(dependency), None) => {
+        if insert_option_ids.is_empty() {
+          return Ok(());
+        }
+        return Err(FlowyError::select_option_not_allowed().context(format!(
+          "The options of the parent field: {} are unknown",
+          dependency.field_id
+        )));
+      },
+    };
+    if let Some(allowed_option_ids) = allowed_option_ids {
+      if let Some(option_id) = insert_option_ids
+        .iter()
+        .find(|option_id| !allowed_option_ids.contains(*option_id))
+      {
+        return Err(
+          FlowyError::select_option_not_allowed()
+            .context(format!("The option {} is not allowed", option_id)),
+        );
+      }
+    }
+    Ok(())
+  }
 }
 
 impl<T> TypeOptionTransform for T
@@ -172,7 +274,15 @@ where
     _field_rev: &FieldRevision,
   ) -> Option<<Self as TypeOption>::CellData> {
     match decoded_field_type {
-      FieldType::SingleSelect | FieldType::MultiSelect | FieldType::Checklist => None,
+      FieldType::SingleSelect | FieldType::MultiSelect => None,
+      // The checked items are selected by their names. The older versions stored the ids of
+      // the checked options in the cell.
+      FieldType::Checklist => ChecklistCellData::from_cell_str(cell_str)
+        .ok()
+        .filter(|_| !cell_str.is_empty())
+        .map(|cell_data| {
+          SelectOptionIds::from(self.get_option_ids_by_names(&cell_data.checked_item_names()))
+        }),
       FieldType::Checkbox => match CheckboxCellData::from_cell_str(cell_str) {
         Ok(checkbox_cell_data) => {
           let cell_content = checkbox_cell_data.to_string();
@@ -228,10 +338,6 @@ pub fn select_type_option_from_field_rev(
       let type_option = MultiSelectTypeOptionPB::from(field_rev);
       Ok(Box::new(type_option))
     },
-    FieldType::Checklist => {
-      let type_option = ChecklistTypeOptionPB::from(field_rev);
-      Ok(Box::new(type_option))
-    },
     ty => {
       tracing::error!("Unsupported field type: {:?} for this handler", ty);
       Err(ErrorCode::FieldInvalidOperation.into())
@@ -239,6 +345,31 @@ pub fn select_type_option_from_field_rev(
   }
 }
 
+/// Returns the options that are selected in the parent field of the select field, see
+/// [SelectOptionDependencyPB]. It's empty if the field doesn't depend on another field or the
+/// parent cell is empty.
+pub fn parent_option_ids_in_cells(
+  field_rev: &FieldRevision,
+  cells: &IndexMap<String, CellRevision>,
+) -> Vec<String> {
+  let parent_field_id =
+    match select_type_option_from_field_rev(field_rev)
+      .ok()
+      .and_then(|type_option| {
+        type_option
+          .dependency()
+          .map(|dependency| dependency.field_id.clone())
+      }) {
+      None => return vec![],
+      Some(parent_field_id) => parent_field_id,
+    };
+  cells
+    .get(&parent_field_id)
+    .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+    .map(|type_cell_data| SelectOptionIds::from(type_cell_data.cell_str).into_inner())
+    .unwrap_or_default()
+}
+
 pub fn new_select_option_color(options: &Vec<SelectOptionPB>) -> SelectOptionColorPB {
   let mut freq: Vec<usize> = vec![0; 9];
 
@@ -281,6 +412,13 @@ impl SelectOptionIds {
   pub fn into_inner(self) -> Vec<String> {
     self.0
   }
+
+  /// The older versions might leave the empty ids in the cell string, e.g. "id1,,id2".
+  pub(crate) fn is_stale_cell_str(cell_str: &str) -> bool {
+    cell_str
+      .split(SELECTION_IDS_SEPARATOR)
+      .any(|id| id.is_empty())
+  }
 }
 
 impl FromCellString for SelectOptionIds {
@@ -302,7 +440,7 @@ impl std::convert::From<String> for SelectOptionIds {
       .split(SELECTION_IDS_SEPARATOR)
       .map(|id| id.to_string())
       .collect::<Vec<String>>();
-    Self(ids)
+    Self::from(ids)
   }
 }
 
@@ -393,12 +531,18 @@ pub struct SelectOptionCellChangesetPB {
 
   #[pb(index = 3)]
   pub delete_option_ids: Vec<String>,
+
+  /// The names of the options to insert, e.g. the pasted values. The names that don't match any
+  /// option are created if the field's [create_missing_options] is on.
+  #[pb(index = 4)]
+  pub insert_option_names: Vec<String>,
 }
 
 pub struct SelectOptionCellChangesetParams {
   pub cell_identifier: CellIdParams,
   pub insert_option_ids: Vec<String>,
   pub delete_option_ids: Vec<String>,
+  pub insert_option_names: Vec<String>,
 }
 
 impl TryInto<SelectOptionCellChangesetParams> for SelectOptionCellChangesetPB {
@@ -434,6 +578,7 @@ impl TryInto<SelectOptionCellChangesetParams> for SelectOptionCellChangesetPB {
       cell_identifier,
       insert_option_ids,
       delete_option_ids,
+      insert_option_names: self.insert_option_names,
     })
   }
 }
@@ -442,6 +587,12 @@ impl TryInto<SelectOptionCellChangesetParams> for SelectOptionCellChangesetPB {
 pub struct SelectOptionCellChangeset {
   pub insert_option_ids: Vec<String>,
   pub delete_option_ids: Vec<String>,
+  #[serde(default)]
+  pub insert_option_names: Vec<String>,
+  /// The options that are selected in the field that this field depends on. It's filled in
+  /// by the database editor from the row of the cell.
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub parent_option_ids: Option<Vec<String>>,
 }
 
 impl FromCellChangesetString for SelectOptionCellChangeset {
@@ -464,6 +615,8 @@ impl SelectOptionCellChangeset {
     SelectOptionCellChangeset {
       insert_option_ids: vec![option_id.to_string()],
       delete_option_ids: vec![],
+      insert_option_names: vec![],
+      parent_option_ids: None,
     }
   }
 
@@ -471,6 +624,8 @@ impl SelectOptionCellChangeset {
     SelectOptionCellChangeset {
       insert_option_ids: option_ids,
       delete_option_ids: vec![],
+      insert_option_names: vec![],
+      parent_option_ids: None,
     }
   }
 
@@ -478,6 +633,8 @@ impl SelectOptionCellChangeset {
     SelectOptionCellChangeset {
       insert_option_ids: vec![],
       delete_option_ids: vec![option_id.to_string()],
+      insert_option_names: vec![],
+      parent_option_ids: None,
     }
   }
 
@@ -485,6 +642,17 @@ impl SelectOptionCellChangeset {
     SelectOptionCellChangeset {
       insert_option_ids: vec![],
       delete_option_ids: option_ids,
+      insert_option_names: vec![],
+      parent_option_ids: None,
+    }
+  }
+
+  pub fn from_insert_option_names(names: Vec<String>) -> Self {
+    SelectOptionCellChangeset {
+      insert_option_ids: vec![],
+      delete_option_ids: vec![],
+      insert_option_names: names,
+      parent_option_ids: None,
     }
   }
 }
@@ -540,6 +708,366 @@ impl TryInto<SelectOptionChangeset> for SelectOptionChangesetPB {
   }
 }
 
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct SelectOptionUsagePayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+}
+
+impl TryInto<FieldIdParams> for SelectOptionUsagePayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<FieldIdParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    Ok(FieldIdParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+    })
+  }
+}
+
+/// [SelectOptionUsagePB] reports the stale data of a select field. The stale data accumulates
+/// after heavy editing or importing, and it can be removed by the [CleanupSelectOptions] event.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct SelectOptionUsagePB {
+  #[pb(index = 1)]
+  pub field_id: String,
+
+  /// The options that are not referenced by any cell.
+  #[pb(index = 2)]
+  pub unused_options: Vec<SelectOptionPB>,
+
+  /// The cells that reference the options that don't exist anymore.
+  #[pb(index = 3)]
+  pub dangling_refs: Vec<DanglingSelectOptionRefPB>,
+}
+
+impl SelectOptionUsagePB {
+  pub fn is_clean(&self) -> bool {
+    self.unused_options.is_empty() && self.dangling_refs.is_empty()
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct DanglingSelectOptionRefPB {
+  #[pb(index = 1)]
+  pub row_id: String,
+
+  /// The ids of the options that are referenced by the cell but don't exist in the field.
+  #[pb(index = 2)]
+  pub option_ids: Vec<String>,
+}
+
+/// Scans the cells of the select field for the options that are not referenced by any cell and
+/// for the references to the options that were deleted.
+pub fn make_select_option_usage(
+  field_rev: &FieldRevision,
+  row_revs: &[Arc<RowRevision>],
+) -> FlowyResult<SelectOptionUsagePB> {
+  let type_option = select_type_option_from_field_rev(field_rev)?;
+  let field_type: FieldType = field_rev.ty.into();
+  let mut referenced_option_ids = HashSet::new();
+  let mut dangling_refs = vec![];
+  for row_rev in row_revs {
+    let cell_str = match row_rev
+      .cells
+      .get(&field_rev.id)
+      .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+    {
+      // The cell data of the other field type will be transformed when reading, so it
+      // doesn't reference the options of this field.
+      Some(type_cell_data) if type_cell_data.field_type == field_type => type_cell_data.cell_str,
+      _ => continue,
+    };
+
+    let mut option_ids = vec![];
+    for option_id in SelectOptionIds::from(cell_str).into_inner() {
+      if type_option
+        .options()
+        .iter()
+        .any(|option| option.id == option_id)
+      {
+        referenced_option_ids.insert(option_id);
+      } else {
+        option_ids.push(option_id);
+      }
+    }
+
+    if !option_ids.is_empty() {
+      dangling_refs.push(DanglingSelectOptionRefPB {
+        row_id: row_rev.id.clone(),
+        option_ids,
+      });
+    }
+  }
+
+  let unused_options = type_option
+    .options()
+    .iter()
+    .filter(|option| !referenced_option_ids.contains(&option.id))
+    .cloned()
+    .collect();
+
+  Ok(SelectOptionUsagePB {
+    field_id: field_rev.id.clone(),
+    unused_options,
+    dangling_refs,
+  })
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct ExportSelectOptionsPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+}
+
+impl TryInto<FieldIdParams> for ExportSelectOptionsPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<FieldIdParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    Ok(FieldIdParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+    })
+  }
+}
+
+/// The options of a select field in their order. It's returned by the [ExportSelectOptions] event
+/// and can be imported into the other select fields by the [ImportSelectOptions] event.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct RepeatedSelectOptionPB {
+  #[pb(index = 1)]
+  pub items: Vec<SelectOptionPB>,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct ImportSelectOptionsPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  /// The options to be merged into the field. Their ids are ignored, the options are matched
+  /// by name.
+  #[pb(index = 3)]
+  pub options: Vec<SelectOptionPB>,
+}
+
+pub struct ImportSelectOptionsParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub options: Vec<SelectOptionPB>,
+}
+
+impl TryInto<ImportSelectOptionsParams> for ImportSelectOptionsPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ImportSelectOptionsParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    Ok(ImportSelectOptionsParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+      options: self.options,
+    })
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct UpdateSelectOptionColorPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub option_id: String,
+
+  #[pb(index = 4)]
+  pub color: SelectOptionColorPB,
+}
+
+pub struct UpdateSelectOptionColorParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub option_id: String,
+  pub color: SelectOptionColorPB,
+}
+
+impl TryInto<UpdateSelectOptionColorParams> for UpdateSelectOptionColorPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<UpdateSelectOptionColorParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    let option_id = NotEmptyStr::parse(self.option_id).map_err(|_| ErrorCode::OptionIdIsEmpty)?;
+    Ok(UpdateSelectOptionColorParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+      option_id: option_id.0,
+      color: self.color,
+    })
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct MoveSelectOptionPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub from_option_id: String,
+
+  /// The option is moved to the position of this option
+  #[pb(index = 4)]
+  pub to_option_id: String,
+}
+
+pub struct MoveSelectOptionParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub from_option_id: String,
+  pub to_option_id: String,
+}
+
+impl TryInto<MoveSelectOptionParams> for MoveSelectOptionPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<MoveSelectOptionParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    let from_option_id =
+      NotEmptyStr::parse(self.from_option_id).map_err(|_| ErrorCode::OptionIdIsEmpty)?;
+    let to_option_id =
+      NotEmptyStr::parse(self.to_option_id).map_err(|_| ErrorCode::OptionIdIsEmpty)?;
+    Ok(MoveSelectOptionParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+      from_option_id: from_option_id.0,
+      to_option_id: to_option_id.0,
+    })
+  }
+}
+
+/// Merges the duplicate options into the target option. The cells that reference the merged
+/// options reference the target option instead, and the merged options are deleted.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct MergeSelectOptionsPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub field_id: String,
+
+  #[pb(index = 3)]
+  pub target_option_id: String,
+
+  #[pb(index = 4)]
+  pub merged_option_ids: Vec<String>,
+}
+
+pub struct MergeSelectOptionsParams {
+  pub view_id: String,
+  pub field_id: String,
+  pub target_option_id: String,
+  pub merged_option_ids: Vec<String>,
+}
+
+impl TryInto<MergeSelectOptionsParams> for MergeSelectOptionsPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<MergeSelectOptionsParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseIdIsEmpty)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    let target_option_id =
+      NotEmptyStr::parse(self.target_option_id).map_err(|_| ErrorCode::OptionIdIsEmpty)?;
+    let merged_option_ids = self
+      .merged_option_ids
+      .into_iter()
+      .filter(|option_id| !option_id.is_empty() && option_id != &target_option_id.0)
+      .collect::<Vec<String>>();
+    Ok(MergeSelectOptionsParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+      target_option_id: target_option_id.0,
+      merged_option_ids,
+    })
+  }
+}
+
+/// Moves the option `from_option_id` to the position of the option `to_option_id`. Returns
+/// false if either of them doesn't exist.
+pub fn move_select_option(
+  options: &mut Vec<SelectOptionPB>,
+  from_option_id: &str,
+  to_option_id: &str,
+) -> bool {
+  let from_index = options
+    .iter()
+    .position(|option| option.id == from_option_id);
+  let to_index = options.iter().position(|option| option.id == to_option_id);
+  match (from_index, to_index) {
+    (Some(from_index), Some(to_index)) => {
+      if from_index != to_index {
+        let option = options.remove(from_index);
+        options.insert(to_index, option);
+      }
+      true
+    },
+    _ => false,
+  }
+}
+
+/// Merges the `imported_options` into the `options` by name, ignoring the case and the
+/// surrounding whitespaces. The merged options keep their ids, so the cells that reference them
+/// stay the same, and take the color of the imported ones. The imported options are placed
+/// first in their order, followed by the options that are only in the `options`.
+pub fn merge_select_options(
+  options: &mut Vec<SelectOptionPB>,
+  imported_options: Vec<SelectOptionPB>,
+) {
+  let is_same_name = |left: &str, right: &str| left.trim().to_lowercase() == right.to_lowercase();
+  let mut merged_options: Vec<SelectOptionPB> = Vec::with_capacity(options.len());
+  for imported_option in imported_options {
+    let name = imported_option.name.trim();
+    if name.is_empty()
+      || merged_options
+        .iter()
+        .any(|option| is_same_name(&option.name, name))
+    {
+      continue;
+    }
+
+    match options
+      .iter()
+      .position(|option| is_same_name(&option.name, name))
+    {
+      None => merged_options.push(SelectOptionPB::with_color(name, imported_option.color)),
+      Some(index) => {
+        let mut option = options.remove(index);
+        option.color = imported_option.color;
+        merged_options.push(option);
+      },
+    }
+  }
+  merged_options.append(options);
+  *options = merged_options;
+}
+
 pub struct SelectedSelectOptions {
   pub(crate) options: Vec<SelectOptionPB>,
 }
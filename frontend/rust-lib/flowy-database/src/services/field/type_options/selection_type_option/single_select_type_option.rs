@@ -8,7 +8,8 @@ use crate::services::field::{
   TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
 };
 use crate::services::field::{
-  SelectOptionCellChangeset, SelectOptionIds, SelectOptionPB, SelectTypeOptionSharedAction,
+  SelectOptionCellChangeset, SelectOptionDependencyPB, SelectOptionIds, SelectOptionPB,
+  SelectTypeOptionSharedAction,
 };
 use bytes::Bytes;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
@@ -24,6 +25,17 @@ pub struct SingleSelectTypeOptionPB {
 
   #[pb(index = 2)]
   pub disable_color: bool,
+
+  /// Creates the options for the names in the cell changeset that don't match any option,
+  /// e.g. the values pasted from a CSV file. Otherwise, the unknown names are dropped.
+  #[pb(index = 3)]
+  #[serde(default)]
+  pub create_missing_options: bool,
+
+  /// Restricts the options that can be selected by the options of another select field.
+  #[pb(index = 4, one_of)]
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub dependency: Option<SelectOptionDependencyPB>,
 }
 impl_type_option!(SingleSelectTypeOptionPB, FieldType::SingleSelect);
 
@@ -48,6 +60,10 @@ impl TypeOptionCellData for SingleSelectTypeOptionPB {
   ) -> FlowyResult<<Self as TypeOption>::CellData> {
     SelectOptionIds::from_cell_str(&cell_str)
   }
+
+  fn is_stale_cell_str(&self, cell_str: &str) -> bool {
+    SelectOptionIds::is_stale_cell_str(cell_str)
+  }
 }
 
 impl SelectTypeOptionSharedAction for SingleSelectTypeOptionPB {
@@ -62,6 +78,14 @@ impl SelectTypeOptionSharedAction for SingleSelectTypeOptionPB {
   fn mut_options(&mut self) -> &mut Vec<SelectOptionPB> {
     &mut self.options
   }
+
+  fn create_missing_options(&self) -> bool {
+    self.create_missing_options
+  }
+
+  fn dependency(&self) -> Option<&SelectOptionDependencyPB> {
+    self.dependency.as_ref()
+  }
 }
 
 impl CellDataChangeset for SingleSelectTypeOptionPB {
@@ -79,7 +103,9 @@ impl CellDataChangeset for SingleSelectTypeOptionPB {
           .iter()
           .any(|option| &option.id == insert_option_id)
       })
+      .chain(self.get_option_ids_by_names(&changeset.insert_option_names))
       .collect::<Vec<String>>();
+    self.check_dependency(changeset.parent_option_ids.as_deref(), &insert_option_ids)?;
 
     // In single select, the insert_option_ids should only contain one select option id.
     // Sometimes, the insert_option_ids may contain list of option ids. For example,
@@ -109,6 +135,14 @@ impl TypeOptionCellDataFilter for SingleSelectTypeOptionPB {
       SelectedSelectOptions::from(self.get_selected_options(cell_data.clone()));
     filter.is_visible(&selected_options, FieldType::SingleSelect)
   }
+
+  /// The ids of the deleted options are ignored
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    self
+      .get_selected_options(cell_data.clone())
+      .select_options
+      .is_empty()
+  }
 }
 
 impl TypeOptionCellDataCompare for SingleSelectTypeOptionPB {
@@ -260,4 +294,44 @@ mod tests {
     let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
     assert!(select_option_ids.is_empty());
   }
+
+  #[test]
+  fn single_select_dependency_test() {
+    let apple = SelectOptionPB::new("Apple");
+    let carrot = SelectOptionPB::new("Carrot");
+    let mut type_option = SingleSelectTypeOptionBuilder::default()
+      .add_option(apple.clone())
+      .add_option(carrot.clone())
+      .0;
+    type_option.dependency = Some(SelectOptionDependencyPB {
+      field_id: "category".to_owned(),
+      rules: vec![SelectOptionDependencyRulePB {
+        parent_option_id: "fruit".to_owned(),
+        option_ids: vec![apple.id.clone()],
+      }],
+    });
+
+    let allowed_options = type_option.get_allowed_options(&["fruit".to_owned()]);
+    assert_eq!(allowed_options, vec![apple.clone()]);
+    assert_eq!(type_option.get_allowed_options(&[]).len(), 2);
+
+    let mut changeset = SelectOptionCellChangeset::from_insert_option_id(&carrot.id);
+    changeset.parent_option_ids = Some(vec!["fruit".to_owned()]);
+    assert!(type_option.apply_changeset(changeset, None).is_err());
+
+    let mut changeset = SelectOptionCellChangeset::from_insert_option_id(&apple.id);
+    changeset.parent_option_ids = Some(vec!["fruit".to_owned()]);
+    let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
+    assert_eq!(&*select_option_ids, &vec![apple.id]);
+
+    // Any option can be selected if the parent cell is empty
+    let mut changeset = SelectOptionCellChangeset::from_insert_option_id(&carrot.id);
+    changeset.parent_option_ids = Some(vec![]);
+    let select_option_ids = type_option.apply_changeset(changeset, None).unwrap().1;
+    assert_eq!(&*select_option_ids, &vec![carrot.id.clone()]);
+
+    // The options can't be inserted if the options of the parent cell are unknown
+    let changeset = SelectOptionCellChangeset::from_insert_option_id(&carrot.id);
+    assert!(type_option.apply_changeset(changeset, None).is_err());
+  }
 }
@@ -1,6 +1,11 @@
 #![allow(clippy::module_inception)]
 mod text_filter;
+mod text_mention;
 mod text_tests;
 mod text_type_option;
+mod text_type_option_entities;
 
+pub use text_filter::*;
+pub use text_mention::*;
 pub use text_type_option::*;
+pub use text_type_option_entities::*;
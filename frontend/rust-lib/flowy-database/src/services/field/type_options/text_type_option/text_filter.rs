@@ -1,10 +1,50 @@
 use crate::entities::{TextFilterConditionPB, TextFilterPB};
+use crate::services::filter::FromFilterString;
+use database_model::FilterRevision;
+use flowy_error::{FlowyError, FlowyResult};
+use regex::{Regex, RegexBuilder};
+
+/// The max size of a compiled pattern, it protects the filter from the patterns that take too
+/// long to compile or to match.
+const MAX_PATTERN_SIZE: usize = 1 << 20;
+
+/// [TextFilter] is the [TextFilterPB] whose pattern is compiled, it's cached by the filter
+/// controller so that the pattern is compiled once instead of once per cell.
+#[derive(Debug, Clone)]
+pub struct TextFilter {
+  filter: TextFilterPB,
+  pattern: Option<Regex>,
+}
+
+impl TextFilter {
+  /// Returns an error if the content of the regex or the wildcard filter is not a valid
+  /// pattern.
+  pub fn new(filter: TextFilterPB) -> FlowyResult<Self> {
+    let case_sensitive = filter.case_sensitive;
+    let pattern = match filter.condition {
+      TextFilterConditionPB::MatchesRegex => {
+        Some(compile_pattern(&filter.content, case_sensitive)?)
+      },
+      TextFilterConditionPB::MatchesWildcard => Some(compile_pattern(
+        &wildcard_to_regex(&filter.content),
+        case_sensitive,
+      )?),
+      _ => None,
+    };
+    Ok(Self { filter, pattern })
+  }
 
-impl TextFilterPB {
   pub fn is_visible<T: AsRef<str>>(&self, cell_data: T) -> bool {
-    let cell_data = cell_data.as_ref().to_lowercase();
-    let content = &self.content.to_lowercase();
-    match self.condition {
+    let (cell_data, content) = if self.filter.case_sensitive {
+      (cell_data.as_ref().to_owned(), self.filter.content.clone())
+    } else {
+      (
+        cell_data.as_ref().to_lowercase(),
+        self.filter.content.to_lowercase(),
+      )
+    };
+    let content = &content;
+    match self.filter.condition {
       TextFilterConditionPB::Is => &cell_data == content,
       TextFilterConditionPB::IsNot => &cell_data != content,
       TextFilterConditionPB::Contains => cell_data.contains(content),
@@ -13,21 +53,84 @@ impl TextFilterPB {
       TextFilterConditionPB::EndsWith => cell_data.ends_with(content),
       TextFilterConditionPB::TextIsEmpty => cell_data.is_empty(),
       TextFilterConditionPB::TextIsNotEmpty => !cell_data.is_empty(),
+      TextFilterConditionPB::MatchesRegex | TextFilterConditionPB::MatchesWildcard => self
+        .pattern
+        .as_ref()
+        .map(|pattern| pattern.is_match(&cell_data))
+        .unwrap_or(true),
+    }
+  }
+}
+
+impl std::convert::From<TextFilterPB> for TextFilter {
+  /// The filter with an invalid pattern doesn't hide any cell.
+  fn from(filter: TextFilterPB) -> Self {
+    Self::new(filter.clone()).unwrap_or_else(|err| {
+      tracing::warn!("{:?}, the filter is ignored", err);
+      Self {
+        filter,
+        pattern: None,
+      }
+    })
+  }
+}
+
+impl FromFilterString for TextFilter {
+  fn from_filter_rev(filter_rev: &FilterRevision) -> Self
+  where
+    Self: Sized,
+  {
+    Self::from(TextFilterPB::from_filter_rev(filter_rev))
+  }
+
+  fn try_from_filter_rev(filter_rev: &FilterRevision) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    Self::new(TextFilterPB::from_filter_rev(filter_rev))
+  }
+}
+
+/// The patterns ignore the case like the other text conditions unless the filter is case
+/// sensitive.
+fn compile_pattern(pattern: &str, case_sensitive: bool) -> FlowyResult<Regex> {
+  RegexBuilder::new(pattern)
+    .case_insensitive(!case_sensitive)
+    .size_limit(MAX_PATTERN_SIZE)
+    .build()
+    .map_err(|err| {
+      FlowyError::invalid_data().context(format!("Invalid pattern {:?}: {}", pattern, err))
+    })
+}
+
+fn wildcard_to_regex(wildcard: &str) -> String {
+  let mut regex = String::from("^");
+  for c in wildcard.chars() {
+    match c {
+      '*' => regex.push_str(".*"),
+      '?' => regex.push('.'),
+      c => regex.push_str(&regex::escape(&c.to_string())),
     }
   }
+  regex.push('$');
+  regex
 }
 
 #[cfg(test)]
 mod tests {
   #![allow(clippy::all)]
   use crate::entities::{TextFilterConditionPB, TextFilterPB};
+  use crate::services::field::TextFilter;
+  use crate::services::filter::FromFilterString;
+  use database_model::FilterRevision;
 
   #[test]
   fn text_filter_equal_test() {
-    let text_filter = TextFilterPB {
+    let text_filter = TextFilter::from(TextFilterPB {
       condition: TextFilterConditionPB::Is,
       content: "appflowy".to_owned(),
-    };
+      case_sensitive: false,
+    });
 
     assert!(text_filter.is_visible("AppFlowy"));
     assert_eq!(text_filter.is_visible("appflowy"), true);
@@ -36,10 +139,11 @@ mod tests {
   }
   #[test]
   fn text_filter_start_with_test() {
-    let text_filter = TextFilterPB {
+    let text_filter = TextFilter::from(TextFilterPB {
       condition: TextFilterConditionPB::StartsWith,
       content: "appflowy".to_owned(),
-    };
+      case_sensitive: false,
+    });
 
     assert_eq!(text_filter.is_visible("AppFlowy.io"), true);
     assert_eq!(text_filter.is_visible(""), false);
@@ -48,10 +152,11 @@ mod tests {
 
   #[test]
   fn text_filter_end_with_test() {
-    let text_filter = TextFilterPB {
+    let text_filter = TextFilter::from(TextFilterPB {
       condition: TextFilterConditionPB::EndsWith,
       content: "appflowy".to_owned(),
-    };
+      case_sensitive: false,
+    });
 
     assert_eq!(text_filter.is_visible("https://github.com/appflowy"), true);
     assert_eq!(text_filter.is_visible("App"), false);
@@ -59,20 +164,22 @@ mod tests {
   }
   #[test]
   fn text_filter_empty_test() {
-    let text_filter = TextFilterPB {
+    let text_filter = TextFilter::from(TextFilterPB {
       condition: TextFilterConditionPB::TextIsEmpty,
       content: "appflowy".to_owned(),
-    };
+      case_sensitive: false,
+    });
 
     assert_eq!(text_filter.is_visible(""), true);
     assert_eq!(text_filter.is_visible("App"), false);
   }
   #[test]
   fn text_filter_contain_test() {
-    let text_filter = TextFilterPB {
+    let text_filter = TextFilter::from(TextFilterPB {
       condition: TextFilterConditionPB::Contains,
       content: "appflowy".to_owned(),
-    };
+      case_sensitive: false,
+    });
 
     assert_eq!(text_filter.is_visible("https://github.com/appflowy"), true);
     assert_eq!(text_filter.is_visible("AppFlowy"), true);
@@ -80,4 +187,91 @@ mod tests {
     assert_eq!(text_filter.is_visible(""), false);
     assert_eq!(text_filter.is_visible("github"), false);
   }
+
+  #[test]
+  fn text_filter_regex_test() {
+    let text_filter = TextFilter::new(TextFilterPB {
+      condition: TextFilterConditionPB::MatchesRegex,
+      content: r"^v\d+\.\d+".to_owned(),
+      case_sensitive: false,
+    })
+    .unwrap();
+
+    assert_eq!(text_filter.is_visible("v1.2 release"), true);
+    assert_eq!(text_filter.is_visible("V10.0"), true);
+    assert_eq!(text_filter.is_visible("release v1.2"), false);
+    assert_eq!(text_filter.is_visible(""), false);
+  }
+
+  #[test]
+  fn text_filter_wildcard_test() {
+    let text_filter = TextFilter::new(TextFilterPB {
+      condition: TextFilterConditionPB::MatchesWildcard,
+      content: "*.p?g".to_owned(),
+      case_sensitive: false,
+    })
+    .unwrap();
+
+    assert_eq!(text_filter.is_visible("cat.png"), true);
+    assert_eq!(text_filter.is_visible("CAT.JPG"), true);
+    assert_eq!(text_filter.is_visible("cat.png.zip"), false);
+    // The other characters of the regex syntax match themselves
+    assert_eq!(text_filter.is_visible("catxpng"), false);
+  }
+
+  #[test]
+  fn text_filter_case_sensitive_test() {
+    let text_filter = TextFilter::from(TextFilterPB {
+      condition: TextFilterConditionPB::Contains,
+      content: "Flowy".to_owned(),
+      case_sensitive: true,
+    });
+    assert_eq!(text_filter.is_visible("AppFlowy"), true);
+    assert_eq!(text_filter.is_visible("appflowy"), false);
+
+    let text_filter = TextFilter::from(TextFilterPB {
+      condition: TextFilterConditionPB::Is,
+      content: "AppFlowy".to_owned(),
+      case_sensitive: true,
+    });
+    assert_eq!(text_filter.is_visible("AppFlowy"), true);
+    assert_eq!(text_filter.is_visible("APPFLOWY"), false);
+
+    let text_filter = TextFilter::new(TextFilterPB {
+      condition: TextFilterConditionPB::MatchesWildcard,
+      content: "*.png".to_owned(),
+      case_sensitive: true,
+    })
+    .unwrap();
+    assert_eq!(text_filter.is_visible("cat.png"), true);
+    assert_eq!(text_filter.is_visible("CAT.PNG"), false);
+  }
+
+  #[test]
+  fn text_filter_invalid_pattern_test() {
+    let filter = TextFilterPB {
+      condition: TextFilterConditionPB::MatchesRegex,
+      content: "(unclosed".to_owned(),
+      case_sensitive: false,
+    };
+    assert!(TextFilter::new(filter.clone()).is_err());
+
+    let filter_rev = FilterRevision {
+      id: "filter_1".to_owned(),
+      field_id: "field_1".to_owned(),
+      field_type: 0,
+      condition: TextFilterConditionPB::MatchesRegex as u8,
+      content: "(unclosed".to_owned(),
+      case_sensitive: false,
+      other_field_id: None,
+    };
+    assert!(TextFilter::try_from_filter_rev(&filter_rev).is_err());
+
+    // The invalid filter doesn't hide any cell
+    assert_eq!(
+      TextFilter::from_filter_rev(&filter_rev).is_visible("text"),
+      true
+    );
+    assert_eq!(filter.is_visible("text"), true);
+  }
 }
@@ -0,0 +1,96 @@
+use crate::services::field::TextMentionKindPB;
+use std::ops::Range;
+
+/// A reference to a row or a view inside the text of a cell. It's stored in the text as
+/// `@[name](row:id)` or `@[name](view:id)`, the name is the display name at the time the
+/// mention was inserted. It's used if the row or the view can't be resolved on read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TextMention {
+  pub kind: TextMentionKindPB,
+  pub id: String,
+  pub name: String,
+  /// The byte range of the mention in the text
+  pub range: Range<usize>,
+}
+
+impl TextMentionKindPB {
+  fn scheme(&self) -> &'static str {
+    match self {
+      TextMentionKindPB::Row => "row",
+      TextMentionKindPB::View => "view",
+    }
+  }
+
+  fn from_scheme(s: &str) -> Option<Self> {
+    match s {
+      "row" => Some(TextMentionKindPB::Row),
+      "view" => Some(TextMentionKindPB::View),
+      _ => None,
+    }
+  }
+}
+
+/// Returns the string that is inserted into the text to mention the row or the view.
+pub fn make_text_mention(kind: TextMentionKindPB, id: &str, name: &str) -> String {
+  // The brackets in the name would end the mention early
+  let name = name.replace(['[', ']'], "");
+  format!("@[{}]({}:{})", name, kind.scheme(), id)
+}
+
+/// Returns the mentions in the text in the order they appear. The malformed mentions are
+/// treated as plain text.
+pub fn parse_text_mentions(text: &str) -> Vec<TextMention> {
+  let mut mentions = vec![];
+  let mut offset = 0;
+  while let Some(start) = text[offset..].find("@[").map(|index| index + offset) {
+    match parse_text_mention_at(text, start) {
+      None => offset = start + 2,
+      Some(mention) => {
+        offset = mention.range.end;
+        mentions.push(mention);
+      },
+    }
+  }
+  mentions
+}
+
+fn parse_text_mention_at(text: &str, start: usize) -> Option<TextMention> {
+  let name_start = start + 2;
+  let name_end = name_start + text[name_start..].find("](")?;
+  let target_start = name_end + 2;
+  let target_end = target_start + text[target_start..].find(')')?;
+  let (kind, id) = text[target_start..target_end].split_once(':')?;
+  let kind = TextMentionKindPB::from_scheme(kind)?;
+  let name = &text[name_start..name_end];
+  if id.is_empty() || name.contains('[') {
+    return None;
+  }
+
+  Some(TextMention {
+    kind,
+    id: id.to_owned(),
+    name: name.to_owned(),
+    range: start..target_end + 1,
+  })
+}
+
+/// Replaces each mention in the text with `@` followed by its name. The name is returned by
+/// `resolve_name`, or the stored name if it returns None.
+pub fn render_text_mentions<F>(text: &str, resolve_name: F) -> String
+where
+  F: Fn(&TextMention) -> Option<String>,
+{
+  let mut rendered = String::with_capacity(text.len());
+  let mut offset = 0;
+  for mention in parse_text_mentions(text) {
+    rendered.push_str(&text[offset..mention.range.start]);
+    rendered.push('@');
+    match resolve_name(&mention) {
+      None => rendered.push_str(&mention.name),
+      Some(name) => rendered.push_str(&name),
+    }
+    offset = mention.range.end;
+  }
+  rendered.push_str(&text[offset..]);
+  rendered
+}
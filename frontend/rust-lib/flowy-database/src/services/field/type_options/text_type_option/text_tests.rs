@@ -26,6 +26,7 @@ mod tests {
     let data = DateCellData {
       timestamp: Some(1647251762),
       include_time: true,
+      recurrence: None,
     };
 
     assert_eq!(
@@ -84,4 +85,51 @@ mod tests {
       format!("{},{}", france.name, argentina.name)
     );
   }
+
+  #[test]
+  fn text_mention_test() {
+    let row_mention = make_text_mention(TextMentionKindPB::Row, "row_1", "Task [1]");
+    let view_mention = make_text_mention(TextMentionKindPB::View, "view_1", "Roadmap");
+    let text = format!("see {} in {}", row_mention, view_mention);
+
+    let mentions = parse_text_mentions(&text);
+    assert_eq!(mentions.len(), 2);
+    assert_eq!(mentions[0].kind, TextMentionKindPB::Row);
+    assert_eq!(mentions[0].id, "row_1");
+    assert_eq!(mentions[0].name, "Task 1");
+    assert_eq!(&text[mentions[0].range.clone()], row_mention);
+    assert_eq!(mentions[1].kind, TextMentionKindPB::View);
+    assert_eq!(mentions[1].id, "view_1");
+
+    assert_eq!(
+      render_text_mentions(&text, |_| None),
+      "see @Task 1 in @Roadmap"
+    );
+    assert_eq!(
+      render_text_mentions(&text, |mention| match mention.kind {
+        TextMentionKindPB::Row => Some("Renamed task".to_owned()),
+        TextMentionKindPB::View => None,
+      }),
+      "see @Renamed task in @Roadmap"
+    );
+  }
+
+  #[test]
+  fn text_malformed_mention_test() {
+    for text in [
+      "@[name]",
+      "@[name](row:)",
+      "@[name](page:1)",
+      "@[name](row:1",
+      "email@[example].com",
+    ] {
+      assert!(parse_text_mentions(text).is_empty(), "{}", text);
+      assert_eq!(render_text_mentions(text, |_| None), text);
+    }
+
+    let text = "@[@[name](row:1)";
+    let mentions = parse_text_mentions(text);
+    assert_eq!(mentions.len(), 1);
+    assert_eq!(render_text_mentions(text, |_| None), "@[@name");
+  }
 }
@@ -1,17 +1,17 @@
-use crate::entities::{FieldType, TextFilterPB};
+use crate::entities::FieldType;
 use crate::impl_type_option;
 use crate::services::cell::{
   stringify_cell_data, CellDataChangeset, CellDataDecoder, CellProtobufBlobParser, DecodedCellData,
   FromCellString, TypeCellData,
 };
 use crate::services::field::{
-  BoxTypeOptionBuilder, TypeOption, TypeOptionBuilder, TypeOptionCellData,
-  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
+  render_text_mentions, BoxTypeOptionBuilder, TextFilter, TypeOption, TypeOptionBuilder,
+  TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
 };
 use bytes::Bytes;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
 use flowy_derive::ProtoBuf;
-use flowy_error::{FlowyError, FlowyResult};
+use flowy_error::FlowyResult;
 use protobuf::ProtobufError;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
@@ -45,7 +45,7 @@ impl TypeOption for RichTextTypeOptionPB {
   type CellData = StrCellData;
   type CellChangeset = String;
   type CellProtobufType = StrCellData;
-  type CellFilter = TextFilterPB;
+  type CellFilter = TextFilter;
 }
 
 impl TypeOptionTransform for RichTextTypeOptionPB {
@@ -113,8 +113,10 @@ impl CellDataDecoder for RichTextTypeOptionPB {
     StrCellData::from_cell_str(&cell_str)
   }
 
+  /// The mentions are shown with the names stored in the text, the database editor resolves
+  /// their current names.
   fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
-    cell_data.to_string()
+    render_text_mentions(&cell_data, |_| None)
   }
 }
 
@@ -124,12 +126,8 @@ impl CellDataChangeset for RichTextTypeOptionPB {
     changeset: <Self as TypeOption>::CellChangeset,
     _type_cell_data: Option<TypeCellData>,
   ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
-    if changeset.len() > 10000 {
-      Err(FlowyError::text_too_long().context("The len of the text should not be more than 10000"))
-    } else {
-      let text_cell_data = StrCellData(changeset);
-      Ok((text_cell_data.to_string(), text_cell_data))
-    }
+    let text_cell_data = StrCellData(changeset);
+    Ok((text_cell_data.to_string(), text_cell_data))
   }
 }
 
@@ -0,0 +1,41 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, ProtoBuf_Enum)]
+pub enum TextMentionKindPB {
+  Row = 0,
+  View = 1,
+}
+
+impl std::default::Default for TextMentionKindPB {
+  fn default() -> Self {
+    TextMentionKindPB::Row
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct TextMentionPB {
+  #[pb(index = 1)]
+  pub kind: TextMentionKindPB,
+
+  #[pb(index = 2)]
+  pub id: String,
+
+  /// The current name of the row or the view, or the name stored in the text if it can't be
+  /// found.
+  #[pb(index = 3)]
+  pub name: String,
+
+  /// False if the mentioned row or view can't be found, e.g. it was deleted.
+  #[pb(index = 4)]
+  pub is_resolved: bool,
+}
+
+/// The text cell whose mentions are replaced with the names of the rows or the views.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct TextCellDataPB {
+  #[pb(index = 1)]
+  pub text: String,
+
+  #[pb(index = 2)]
+  pub mentions: Vec<TextMentionPB>,
+}
@@ -0,0 +1,7 @@
+#![allow(clippy::module_inception)]
+mod time_tracking_tests;
+mod time_tracking_type_option;
+mod time_tracking_type_option_entities;
+
+pub use time_tracking_type_option::*;
+pub use time_tracking_type_option_entities::*;
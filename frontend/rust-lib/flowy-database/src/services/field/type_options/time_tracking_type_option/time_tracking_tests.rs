@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{FieldType, NumberFilterConditionPB, NumberFilterPB};
+  use crate::services::cell::{
+    CellDataChangeset, FromCellChangesetString, ToCellChangesetString, TypeCellData,
+  };
+  use crate::services::field::{
+    format_clock, sum_time_tracking, TimeTrackingCellChangeset, TimeTrackingCellData,
+    TimeTrackingTypeOptionPB, TypeOptionCellDataFilter, MAX_TRACKED_SECONDS,
+  };
+
+  fn apply(
+    type_option: &TimeTrackingTypeOptionPB,
+    cell_data: TimeTrackingCellData,
+    changeset: TimeTrackingCellChangeset,
+  ) -> TimeTrackingCellData {
+    let type_cell_data = TypeCellData::new(cell_data.to_string(), FieldType::TimeTracking);
+    let (_, cell_data) = type_option
+      .apply_changeset(changeset, Some(type_cell_data))
+      .unwrap();
+    cell_data
+  }
+
+  #[test]
+  fn time_tracking_start_stop_adjust_test() {
+    let type_option = TimeTrackingTypeOptionPB::default();
+    let cell_data = apply(
+      &type_option,
+      TimeTrackingCellData::default(),
+      TimeTrackingCellChangeset::Start { at: 1000 },
+    );
+    assert!(cell_data.is_running());
+    assert_eq!(cell_data.total_seconds(1600), 600);
+
+    // Starting the running timer again keeps its start
+    let cell_data = apply(
+      &type_option,
+      cell_data,
+      TimeTrackingCellChangeset::Start { at: 1300 },
+    );
+    assert_eq!(cell_data.started_at, Some(1000));
+
+    let cell_data = apply(
+      &type_option,
+      cell_data,
+      TimeTrackingCellChangeset::Stop { at: 4600 },
+    );
+    assert!(!cell_data.is_running());
+    assert_eq!(cell_data.accumulated, 3600);
+    assert_eq!(cell_data.total_seconds(9999), 3600);
+
+    let cell_data = apply(
+      &type_option,
+      cell_data,
+      TimeTrackingCellChangeset::Adjust { seconds: -600 },
+    );
+    assert_eq!(cell_data.accumulated, 3000);
+    let cell_data = apply(
+      &type_option,
+      cell_data,
+      TimeTrackingCellChangeset::Adjust { seconds: -9000 },
+    );
+    assert_eq!(cell_data.accumulated, 0);
+  }
+
+  #[test]
+  fn time_tracking_out_of_range_test() {
+    let type_option = TimeTrackingTypeOptionPB::default();
+    let cell_data = TimeTrackingCellData {
+      accumulated: MAX_TRACKED_SECONDS,
+      started_at: None,
+    };
+    for changeset in [
+      TimeTrackingCellChangeset::Adjust { seconds: 1 },
+      TimeTrackingCellChangeset::Adjust { seconds: i64::MAX },
+      TimeTrackingCellChangeset::Set { seconds: -1 },
+      TimeTrackingCellChangeset::Set { seconds: i64::MAX },
+      TimeTrackingCellChangeset::Start { at: -1 },
+    ] {
+      let type_cell_data = TypeCellData::new(cell_data.to_string(), FieldType::TimeTracking);
+      assert!(type_option
+        .apply_changeset(changeset, Some(type_cell_data))
+        .is_err());
+    }
+
+    // The timer that ran past the bound is capped when it's stopped
+    let cell_data = TimeTrackingCellData {
+      accumulated: 0,
+      started_at: Some(i64::MIN),
+    };
+    assert_eq!(cell_data.total_seconds(i64::MAX), MAX_TRACKED_SECONDS);
+    let cell_data = apply(
+      &type_option,
+      cell_data,
+      TimeTrackingCellChangeset::Stop { at: i64::MAX },
+    );
+    assert_eq!(cell_data.accumulated, MAX_TRACKED_SECONDS);
+    assert_eq!(
+      sum_time_tracking([cell_data, cell_data].iter(), 0),
+      2 * MAX_TRACKED_SECONDS
+    );
+  }
+
+  #[test]
+  fn time_tracking_changeset_string_test() {
+    let changeset = TimeTrackingCellChangeset::Adjust { seconds: 60 };
+    assert_eq!(
+      TimeTrackingCellChangeset::from_changeset(changeset.to_cell_changeset_str()).unwrap(),
+      changeset
+    );
+    assert_eq!(
+      TimeTrackingCellChangeset::from_changeset("1h 30m".to_owned()).unwrap(),
+      TimeTrackingCellChangeset::Set { seconds: 5400 }
+    );
+    assert!(TimeTrackingCellChangeset::from_changeset("abc".to_owned()).is_err());
+  }
+
+  #[test]
+  fn time_tracking_format_and_sum_test() {
+    assert_eq!(format_clock(0, false), "0:00");
+    assert_eq!(format_clock(3900, false), "1:05");
+    assert_eq!(format_clock(95405, false), "26:30");
+    assert_eq!(format_clock(3905, true), "1:05:05");
+
+    let cells = vec![
+      TimeTrackingCellData {
+        accumulated: 600,
+        started_at: None,
+      },
+      TimeTrackingCellData {
+        accumulated: 60,
+        started_at: Some(1000),
+      },
+      TimeTrackingCellData::default(),
+    ];
+    assert_eq!(sum_time_tracking(cells.iter(), 1300), 960);
+  }
+
+  #[test]
+  fn time_tracking_filter_test() {
+    let type_option = TimeTrackingTypeOptionPB::default();
+    let more_than_one_hour = NumberFilterPB {
+      condition: NumberFilterConditionPB::GreaterThan,
+      content: "1".to_owned(),
+      end_content: None,
+    };
+    let less_than_half_hour = NumberFilterPB {
+      condition: NumberFilterConditionPB::LessThan,
+      content: "0.5".to_owned(),
+      end_content: None,
+    };
+    let two_hours = TimeTrackingCellData {
+      accumulated: 7200,
+      started_at: None,
+    };
+    let ten_minutes = TimeTrackingCellData {
+      accumulated: 600,
+      started_at: None,
+    };
+    let field_type = FieldType::TimeTracking;
+    assert!(type_option.apply_filter(&more_than_one_hour, &field_type, &two_hours));
+    assert!(!type_option.apply_filter(&more_than_one_hour, &field_type, &ten_minutes));
+    assert!(type_option.apply_filter(&less_than_half_hour, &field_type, &ten_minutes));
+    assert!(!type_option.apply_filter(&less_than_half_hour, &field_type, &two_hours));
+  }
+}
@@ -0,0 +1,186 @@
+use crate::entities::{FieldType, NumberFilterPB};
+use crate::impl_type_option;
+use crate::services::cell::{
+  CellDataChangeset, CellDataDecoder, DecodedCellData, FromCellString, TypeCellData,
+};
+use crate::services::field::{
+  default_order, format_clock, BoxTypeOptionBuilder, DurationCellData, NumberCellData,
+  TimeTrackingCellChangeset, TimeTrackingCellData, TimeTrackingCellDataPB, TypeOption,
+  TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use lib_infra::util::timestamp;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+
+const SECONDS_PER_HOUR: f64 = 3600.0;
+
+#[derive(Default)]
+pub struct TimeTrackingTypeOptionBuilder(TimeTrackingTypeOptionPB);
+impl_into_box_type_option_builder!(TimeTrackingTypeOptionBuilder);
+impl_builder_from_json_str_and_from_bytes!(TimeTrackingTypeOptionBuilder, TimeTrackingTypeOptionPB);
+
+impl TimeTrackingTypeOptionBuilder {
+  pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+    self.0.show_seconds = show_seconds;
+    self
+  }
+}
+
+impl TypeOptionBuilder for TimeTrackingTypeOptionBuilder {
+  fn field_type(&self) -> FieldType {
+    FieldType::TimeTracking
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
+
+/// The time tracking cell saves the accumulated seconds and the start of the running timer. The
+/// timer is started and stopped by the [TimeTrackingCellChangeset]s, and the tracked time is
+/// displayed as "h:mm".
+#[derive(Debug, Clone, Serialize, Deserialize, Default, ProtoBuf)]
+pub struct TimeTrackingTypeOptionPB {
+  /// Displays the tracked time as "h:mm:ss" instead of "h:mm"
+  #[pb(index = 1)]
+  #[serde(default)]
+  pub show_seconds: bool,
+}
+impl_type_option!(TimeTrackingTypeOptionPB, FieldType::TimeTracking);
+
+impl TypeOption for TimeTrackingTypeOptionPB {
+  type CellData = TimeTrackingCellData;
+  type CellChangeset = TimeTrackingCellChangeset;
+  type CellProtobufType = TimeTrackingCellDataPB;
+  type CellFilter = NumberFilterPB;
+}
+
+impl TypeOptionTransform for TimeTrackingTypeOptionPB {}
+
+impl TypeOptionCellData for TimeTrackingTypeOptionPB {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    let total = cell_data.total_seconds(timestamp());
+    TimeTrackingCellDataPB {
+      accumulated: cell_data.accumulated,
+      started_at: cell_data.started_at,
+      total,
+      is_running: cell_data.is_running(),
+      formatted: format_clock(total, self.show_seconds),
+    }
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    Ok(TimeTrackingCellData::from_cell_str(&cell_str).unwrap_or_default())
+  }
+}
+
+impl CellDataDecoder for TimeTrackingTypeOptionPB {
+  /// The cells of the duration, the number and the text fields are parsed as the accumulated
+  /// time, so the field keeps its values when its type is switched to the time tracking.
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    if decoded_field_type.is_time_tracking() {
+      return self.decode_type_option_cell_str(cell_str);
+    }
+    if !decoded_field_type.is_duration()
+      && !decoded_field_type.is_number()
+      && !decoded_field_type.is_text()
+    {
+      return Ok(Default::default());
+    }
+
+    let accumulated = DurationCellData::parse(&cell_str)
+      .ok()
+      .and_then(|duration| duration.0)
+      .unwrap_or(0);
+    Ok(TimeTrackingCellData {
+      accumulated,
+      started_at: None,
+    })
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    format_clock(cell_data.total_seconds(timestamp()), self.show_seconds)
+  }
+}
+
+impl CellDataChangeset for TimeTrackingTypeOptionPB {
+  fn apply_changeset(
+    &self,
+    changeset: <Self as TypeOption>::CellChangeset,
+    type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    let mut cell_data = type_cell_data
+      .filter(|type_cell_data| type_cell_data.is_time_tracking())
+      .map(|type_cell_data| self.decode_type_option_cell_str(type_cell_data.cell_str))
+      .transpose()?
+      .unwrap_or_default();
+    cell_data.apply(&changeset)?;
+    Ok((cell_data.to_string(), cell_data))
+  }
+}
+
+impl TypeOptionCellDataFilter for TimeTrackingTypeOptionPB {
+  /// The content of the filter is the number of the hours, e.g. "1.5", and it's compared with
+  /// the tracked time including the running timer.
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if !field_type.is_time_tracking() {
+      return true;
+    }
+
+    let seconds = cell_data.total_seconds(timestamp());
+    let number_cell_data = if cell_data.is_empty() {
+      NumberCellData::default()
+    } else {
+      NumberCellData::from_decimal(Decimal::from(seconds))
+    };
+    let to_seconds = |content: &String| match content.trim().parse::<f64>() {
+      Ok(hours) if hours.is_finite() => ((hours * SECONDS_PER_HOUR).round() as i64).to_string(),
+      _ => content.clone(),
+    };
+    let filter = NumberFilterPB {
+      content: to_seconds(&filter.content),
+      end_content: filter.end_content.as_ref().map(to_seconds),
+      ..filter.clone()
+    };
+    filter.is_visible(&number_cell_data)
+  }
+}
+
+impl TypeOptionCellDataCompare for TimeTrackingTypeOptionPB {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    let now = timestamp();
+    match cell_data
+      .total_seconds(now)
+      .cmp(&other_cell_data.total_seconds(now))
+    {
+      Ordering::Equal => default_order(),
+      order => order,
+    }
+  }
+}
@@ -0,0 +1,300 @@
+use crate::entities::parser::NotEmptyStr;
+use crate::entities::{CellIdPB, CellIdParams, FieldIdParams};
+use crate::services::cell::{
+  CellProtobufBlobParser, DecodedCellData, FromCellChangesetString, FromCellString,
+  ToCellChangesetString,
+};
+use crate::services::field::DurationCellData;
+use bytes::Bytes;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use flowy_error::{internal_error, ErrorCode, FlowyError, FlowyResult};
+use lib_infra::util::timestamp;
+use serde::{Deserialize, Serialize};
+
+/// The upper bound of the tracked time, 100 years in seconds. The changesets that would track
+/// more are rejected, and the running timers are capped at it.
+pub const MAX_TRACKED_SECONDS: i64 = 100 * 365 * 24 * 60 * 60;
+
+/// The time tracked by the cell. The time of the finished runs is accumulated, and the start of
+/// the running timer is kept until the timer is stopped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeTrackingCellData {
+  /// The number of the seconds of the finished runs
+  #[serde(default)]
+  pub accumulated: i64,
+
+  /// The timestamp, in seconds, when the running timer was started. It's None if the timer is
+  /// stopped.
+  #[serde(default)]
+  pub started_at: Option<i64>,
+}
+
+impl TimeTrackingCellData {
+  pub fn is_running(&self) -> bool {
+    self.started_at.is_some()
+  }
+
+  /// Returns the tracked seconds at the time `now`, including the running timer. The total is
+  /// capped at [MAX_TRACKED_SECONDS].
+  pub fn total_seconds(&self, now: i64) -> i64 {
+    let running = self
+      .started_at
+      .map(|started_at| now.saturating_sub(started_at).max(0))
+      .unwrap_or(0);
+    self
+      .accumulated
+      .saturating_add(running)
+      .clamp(0, MAX_TRACKED_SECONDS)
+  }
+
+  /// Applies the changeset. Starting a running timer or stopping a stopped one does nothing.
+  ///
+  /// Returns [FlowyError::invalid_duration] if the timestamp of the changeset is negative, or if
+  /// the accumulated time would be out of the range 0..=[MAX_TRACKED_SECONDS]. The adjustments
+  /// that remove more than the accumulated time reset it to 0.
+  pub fn apply(&mut self, changeset: &TimeTrackingCellChangeset) -> FlowyResult<()> {
+    let out_of_range =
+      || FlowyError::invalid_duration().context(format!("Out of range: {:?}", changeset));
+    match changeset {
+      TimeTrackingCellChangeset::Start { at } | TimeTrackingCellChangeset::Stop { at }
+        if *at < 0 =>
+      {
+        return Err(out_of_range());
+      },
+      TimeTrackingCellChangeset::Start { at } => {
+        if self.started_at.is_none() {
+          self.started_at = Some(*at);
+        }
+      },
+      TimeTrackingCellChangeset::Stop { at } => {
+        // The run that was left running for longer than the bound is capped instead of
+        // rejected, otherwise the timer could never be stopped.
+        if self.started_at.is_some() {
+          self.accumulated = self.total_seconds(*at);
+          self.started_at = None;
+        }
+      },
+      TimeTrackingCellChangeset::Adjust { seconds } => {
+        let accumulated = self
+          .accumulated
+          .checked_add(*seconds)
+          .filter(|accumulated| *accumulated <= MAX_TRACKED_SECONDS)
+          .ok_or_else(out_of_range)?;
+        self.accumulated = accumulated.max(0);
+      },
+      TimeTrackingCellChangeset::Set { seconds } => {
+        if !(0..=MAX_TRACKED_SECONDS).contains(seconds) {
+          return Err(out_of_range());
+        }
+        self.accumulated = *seconds;
+      },
+    }
+    Ok(())
+  }
+}
+
+impl FromCellString for TimeTrackingCellData {
+  fn from_cell_str(s: &str) -> FlowyResult<Self> {
+    if s.trim().is_empty() {
+      return Ok(Self::default());
+    }
+    serde_json::from_str::<TimeTrackingCellData>(s).map_err(internal_error)
+  }
+}
+
+impl ToString for TimeTrackingCellData {
+  fn to_string(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
+
+impl DecodedCellData for TimeTrackingCellData {
+  type Object = TimeTrackingCellData;
+
+  fn is_empty(&self) -> bool {
+    self.accumulated == 0 && self.started_at.is_none()
+  }
+}
+
+/// The changeset of the time tracking cell. The typed changesets that are not JSON, e.g.
+/// "1h 30m", are parsed as durations and set the accumulated time.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum TimeTrackingCellChangeset {
+  /// Starts the timer at the timestamp `at`, in seconds
+  Start { at: i64 },
+  /// Stops the timer at the timestamp `at` and adds the run to the accumulated time
+  Stop { at: i64 },
+  /// Adds the seconds to the accumulated time, or removes them if negative
+  Adjust { seconds: i64 },
+  /// Replaces the accumulated time
+  Set { seconds: i64 },
+}
+
+impl TimeTrackingCellChangeset {
+  pub fn start() -> Self {
+    Self::Start { at: timestamp() }
+  }
+
+  pub fn stop() -> Self {
+    Self::Stop { at: timestamp() }
+  }
+}
+
+impl FromCellChangesetString for TimeTrackingCellChangeset {
+  fn from_changeset(changeset: String) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    if let Ok(changeset) = serde_json::from_str::<TimeTrackingCellChangeset>(&changeset) {
+      return Ok(changeset);
+    }
+    let seconds = DurationCellData::parse(&changeset)?.0.unwrap_or(0);
+    Ok(Self::Set { seconds })
+  }
+}
+
+impl ToCellChangesetString for TimeTrackingCellChangeset {
+  fn to_cell_changeset_str(&self) -> String {
+    serde_json::to_string(self).unwrap_or_default()
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct TimeTrackingCellDataPB {
+  #[pb(index = 1)]
+  pub accumulated: i64,
+
+  #[pb(index = 2, one_of)]
+  pub started_at: Option<i64>,
+
+  /// The tracked seconds at the time the cell was read, including the running timer
+  #[pb(index = 3)]
+  pub total: i64,
+
+  #[pb(index = 4)]
+  pub is_running: bool,
+
+  /// The total formatted as "h:mm", see [format_clock](crate::services::field::format_clock)
+  #[pb(index = 5)]
+  pub formatted: String,
+}
+
+pub struct TimeTrackingCellDataParser();
+impl CellProtobufBlobParser for TimeTrackingCellDataParser {
+  type Object = TimeTrackingCellDataPB;
+
+  fn parser(bytes: &Bytes) -> FlowyResult<Self::Object> {
+    TimeTrackingCellDataPB::try_from(bytes.as_ref()).map_err(internal_error)
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum TimeTrackingActionPB {
+  Start = 0,
+  Stop = 1,
+  Adjust = 2,
+}
+
+impl std::default::Default for TimeTrackingActionPB {
+  fn default() -> Self {
+    TimeTrackingActionPB::Start
+  }
+}
+
+/// [TimeTrackingCellChangesetPB] starts or stops the timer of the cell, or adjusts its tracked
+/// time by the `seconds`.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct TimeTrackingCellChangesetPB {
+  #[pb(index = 1)]
+  pub cell_id: CellIdPB,
+
+  #[pb(index = 2)]
+  pub action: TimeTrackingActionPB,
+
+  /// The seconds that are added, or removed if negative. Only used by the adjust action.
+  #[pb(index = 3)]
+  pub seconds: i64,
+}
+
+pub struct TimeTrackingCellChangesetParams {
+  pub cell_id: CellIdParams,
+  pub changeset: TimeTrackingCellChangeset,
+}
+
+impl TryInto<TimeTrackingCellChangesetParams> for TimeTrackingCellChangesetPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<TimeTrackingCellChangesetParams, Self::Error> {
+    let cell_id: CellIdParams = self.cell_id.try_into()?;
+    let changeset = match self.action {
+      TimeTrackingActionPB::Start => TimeTrackingCellChangeset::start(),
+      TimeTrackingActionPB::Stop => TimeTrackingCellChangeset::stop(),
+      TimeTrackingActionPB::Adjust => TimeTrackingCellChangeset::Adjust {
+        seconds: self.seconds,
+      },
+    };
+    Ok(TimeTrackingCellChangesetParams { cell_id, changeset })
+  }
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct TimeTrackingSumPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// The id of the time tracking field
+  #[pb(index = 2)]
+  pub field_id: String,
+}
+
+impl TryInto<FieldIdParams> for TimeTrackingSumPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<FieldIdParams, Self::Error> {
+    let view_id = NotEmptyStr::parse(self.view_id).map_err(|_| ErrorCode::DatabaseViewIdIsEmpty)?;
+    let field_id = NotEmptyStr::parse(self.field_id).map_err(|_| ErrorCode::FieldIdIsEmpty)?;
+    Ok(FieldIdParams {
+      view_id: view_id.0,
+      field_id: field_id.0,
+    })
+  }
+}
+
+/// [GroupTimeTrackingSumPB] is the time tracked by the rows in the group.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct GroupTimeTrackingSumPB {
+  #[pb(index = 1)]
+  pub group_id: String,
+
+  #[pb(index = 2)]
+  pub total: i64,
+
+  #[pb(index = 3)]
+  pub formatted: String,
+}
+
+/// [TimeTrackingSumPB] is the time tracked by all the rows of the view, the column total, and
+/// by the rows of each group. The groups are empty if the view is not grouped.
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct TimeTrackingSumPB {
+  #[pb(index = 1)]
+  pub total: i64,
+
+  #[pb(index = 2)]
+  pub formatted: String,
+
+  #[pb(index = 3)]
+  pub groups: Vec<GroupTimeTrackingSumPB>,
+}
+
+/// Returns the time tracked by the cells at the time `now`, in seconds. The sum saturates
+/// instead of overflowing.
+pub fn sum_time_tracking<'a>(
+  cells: impl Iterator<Item = &'a TimeTrackingCellData>,
+  now: i64,
+) -> i64 {
+  cells.fold(0_i64, |sum, cell_data| {
+    sum.saturating_add(cell_data.total_seconds(now))
+  })
+}
@@ -0,0 +1,5 @@
+#![allow(clippy::module_inception)]
+mod timestamp_tests;
+mod timestamp_type_option;
+
+pub use timestamp_type_option::*;
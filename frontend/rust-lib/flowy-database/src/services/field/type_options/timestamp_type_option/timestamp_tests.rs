@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+  use crate::entities::{DateFilterConditionPB, DateFilterPB, FieldType};
+  use crate::services::cell::{CellDataChangeset, CellDataDecoder, TypeCellData};
+  use crate::services::field::{
+    DateFormat, FieldBuilder, RowCreatedAt, RowModifiedAt, RowTimestampTarget, TimeFormat,
+    TimestampTypeOption, TimestampTypeOptionBuilder, TypeOptionBuilder, TypeOptionCellData,
+    TypeOptionCellDataCompare, TypeOptionCellDataFilter,
+  };
+  use database_model::{TypeOptionDataDeserializer, TypeOptionDataSerializer};
+  use std::cmp::Ordering;
+
+  // 2022-03-14 09:56:02 UTC
+  const TIMESTAMP: i64 = 1647251762;
+
+  fn timestamp_type_cell_data<T: RowTimestampTarget>(timestamp: i64) -> TypeCellData {
+    let cell_rev = TimestampTypeOption::<T>::cell_rev_from_timestamp(timestamp);
+    TypeCellData::try_from(&cell_rev).unwrap()
+  }
+
+  #[test]
+  fn timestamp_type_option_format_test() {
+    assert_format::<RowCreatedAt>();
+    assert_format::<RowModifiedAt>();
+  }
+
+  #[test]
+  fn timestamp_type_option_read_only_test() {
+    assert_read_only::<RowCreatedAt>();
+    assert_read_only::<RowModifiedAt>();
+  }
+
+  #[test]
+  fn timestamp_type_option_filter_and_sort_test() {
+    assert_filter_and_sort::<RowCreatedAt>();
+    assert_filter_and_sort::<RowModifiedAt>();
+  }
+
+  #[test]
+  fn timestamp_type_option_serde_test() {
+    assert_serde::<RowCreatedAt>();
+    assert_serde::<RowModifiedAt>();
+  }
+
+  fn assert_format<T: RowTimestampTarget>() {
+    let field_rev = FieldBuilder::from_field_type(&T::FIELD_TYPE).build();
+    let mut type_option = TimestampTypeOption::<T>::from(&field_rev);
+    type_option.date_format = DateFormat::US;
+    type_option.time_format = TimeFormat::TwentyFourHour;
+    type_option.include_time = false;
+    let type_cell_data = timestamp_type_cell_data::<T>(TIMESTAMP);
+    let cell_data = type_option
+      .decode_cell_str(type_cell_data.cell_str.clone(), &T::FIELD_TYPE, &field_rev)
+      .unwrap();
+    assert_eq!(type_option.decode_cell_data_to_str(cell_data), "2022/03/14");
+
+    // The cells of other field types are replaced by the timestamp of their rows
+    let cell_data = type_option
+      .decode_cell_str(
+        type_cell_data.cell_str.clone(),
+        &FieldType::RichText,
+        &field_rev,
+      )
+      .unwrap();
+    assert_eq!(cell_data.timestamp, None);
+
+    type_option.include_time = true;
+    let cell_data = type_option
+      .decode_type_option_cell_str(type_cell_data.cell_str)
+      .unwrap();
+    let cell_data_pb = type_option.convert_to_protobuf(cell_data);
+    assert_eq!(cell_data_pb.timestamp, TIMESTAMP);
+    assert!(cell_data_pb.include_time);
+    assert!(!cell_data_pb.time.is_empty());
+
+    // The rows without a recorded timestamp have empty cells
+    let type_cell_data = timestamp_type_cell_data::<T>(0);
+    let cell_data = type_option
+      .decode_type_option_cell_str(type_cell_data.cell_str)
+      .unwrap();
+    assert_eq!(type_option.decode_cell_data_to_str(cell_data), "");
+  }
+
+  fn assert_read_only<T: RowTimestampTarget>() {
+    let type_option = TimestampTypeOption::<T>::default();
+    let type_cell_data = timestamp_type_cell_data::<T>(TIMESTAMP);
+    let (cell_str, cell_data) = type_option
+      .apply_changeset("1".to_owned(), Some(type_cell_data.clone()))
+      .unwrap();
+    assert_eq!(cell_str, type_cell_data.cell_str);
+    assert_eq!(cell_data.timestamp, Some(TIMESTAMP));
+
+    let (_, cell_data) = type_option.apply_changeset("1".to_owned(), None).unwrap();
+    assert_eq!(cell_data.timestamp, None);
+  }
+
+  fn assert_filter_and_sort<T: RowTimestampTarget>() {
+    let type_option = TimestampTypeOption::<T>::default();
+    let cell_data = type_option
+      .decode_type_option_cell_str(timestamp_type_cell_data::<T>(TIMESTAMP).cell_str)
+      .unwrap();
+    let later_cell_data = type_option
+      .decode_type_option_cell_str(timestamp_type_cell_data::<T>(TIMESTAMP + 2 * 86400).cell_str)
+      .unwrap();
+    assert_eq!(
+      type_option.apply_cmp(&cell_data, &later_cell_data),
+      Ordering::Less
+    );
+
+    let filter = DateFilterPB {
+      condition: DateFilterConditionPB::DateAfter,
+      timestamp: Some(TIMESTAMP + 86400),
+      ..Default::default()
+    };
+    assert!(!type_option.apply_filter(&filter, &T::FIELD_TYPE, &cell_data));
+    assert!(type_option.apply_filter(&filter, &T::FIELD_TYPE, &later_cell_data));
+  }
+
+  fn assert_serde<T: RowTimestampTarget>() {
+    let builder = TimestampTypeOptionBuilder::<T>::default()
+      .time_format(TimeFormat::TwelveHour)
+      .include_time(true);
+    assert_eq!(builder.field_type(), T::FIELD_TYPE);
+
+    let serializer = builder.serializer();
+    let type_option = TimestampTypeOption::<T>::from_json_str(&serializer.json_str());
+    assert_eq!(type_option.time_format, TimeFormat::TwelveHour);
+    assert!(type_option.include_time);
+
+    let type_option = TimestampTypeOption::<T>::from_protobuf_bytes(serializer.protobuf_bytes());
+    assert_eq!(type_option.time_format, TimeFormat::TwelveHour);
+    assert!(type_option.include_time);
+  }
+}
@@ -0,0 +1,326 @@
+use crate::entities::{DateFilterPB, FieldType};
+use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
+use crate::services::field::{
+  default_order, BoxTypeOptionBuilder, DateCellData, DateCellDataPB, DateFormat, DateTypeOptionPB,
+  TimeFormat, TypeOption, TypeOptionBuilder, TypeOptionCellData, TypeOptionCellDataCompare,
+  TypeOptionCellDataFilter, TypeOptionTransform,
+};
+use bytes::Bytes;
+use database_model::{
+  CellRevision, FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer,
+};
+use flowy_derive::ProtoBuf;
+use flowy_error::FlowyResult;
+use protobuf::ProtobufError;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+/// The timestamp of the row that is shown by a [TimestampTypeOption].
+pub trait RowTimestampTarget: Clone + Debug + Default + Send + Sync + 'static {
+  const FIELD_TYPE: FieldType;
+}
+
+/// The time when the row was created. It's set when the row is created, or when the field is
+/// created for the existing rows.
+#[derive(Clone, Debug, Default)]
+pub struct RowCreatedAt;
+impl RowTimestampTarget for RowCreatedAt {
+  const FIELD_TYPE: FieldType = FieldType::CreatedTime;
+}
+
+/// The time when a cell of the row was last edited. It's updated when the cells of the row
+/// change, except the cells of the computed fields, see [FieldType::is_computed].
+#[derive(Clone, Debug, Default)]
+pub struct RowModifiedAt;
+impl RowTimestampTarget for RowModifiedAt {
+  const FIELD_TYPE: FieldType = FieldType::LastEditedTime;
+}
+
+pub type CreatedTimeTypeOptionPB = TimestampTypeOption<RowCreatedAt>;
+pub type LastEditedTimeTypeOptionPB = TimestampTypeOption<RowModifiedAt>;
+
+/// The cell of a timestamp field is the timestamp of the row that is selected by the `T`, see
+/// [RowTimestampTarget]. It can't be edited and it's formatted in the same way as the
+/// [DateTypeOptionPB]. Both timestamp fields are sent to the client as [TimestampTypeOptionPB].
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TimestampTypeOption<T: RowTimestampTarget> {
+  pub date_format: DateFormat,
+
+  pub time_format: TimeFormat,
+
+  pub include_time: bool,
+
+  #[serde(skip)]
+  target: PhantomData<fn() -> T>,
+}
+
+#[derive(Clone, Debug, Default, ProtoBuf)]
+pub struct TimestampTypeOptionPB {
+  #[pb(index = 1)]
+  pub date_format: DateFormat,
+
+  #[pb(index = 2)]
+  pub time_format: TimeFormat,
+
+  #[pb(index = 3)]
+  pub include_time: bool,
+}
+
+impl<T: RowTimestampTarget> TimestampTypeOption<T> {
+  /// Returns the cell of the row whose timestamp is the `timestamp`. The cell is empty if the
+  /// `timestamp` is 0, e.g. the row was created before its created time was recorded.
+  pub fn cell_rev_from_timestamp(timestamp: i64) -> CellRevision {
+    let cell_data = DateCellData {
+      timestamp: if timestamp == 0 {
+        None
+      } else {
+        Some(timestamp)
+      },
+      include_time: true,
+      recurrence: None,
+    };
+    let type_cell_data = TypeCellData::new(cell_data.to_string(), T::FIELD_TYPE);
+    CellRevision::new(type_cell_data.to_json())
+  }
+
+  fn date_type_option(&self) -> DateTypeOptionPB {
+    DateTypeOptionPB {
+      date_format: self.date_format,
+      time_format: self.time_format,
+      include_time: self.include_time,
+      show_relative_days: false,
+      timezone_id: "".to_owned(),
+    }
+  }
+}
+
+impl<T: RowTimestampTarget> std::convert::From<TimestampTypeOptionPB> for TimestampTypeOption<T> {
+  fn from(pb: TimestampTypeOptionPB) -> Self {
+    Self {
+      date_format: pb.date_format,
+      time_format: pb.time_format,
+      include_time: pb.include_time,
+      target: PhantomData,
+    }
+  }
+}
+
+impl<T: RowTimestampTarget> std::convert::From<TimestampTypeOption<T>> for TimestampTypeOptionPB {
+  fn from(type_option: TimestampTypeOption<T>) -> Self {
+    Self {
+      date_format: type_option.date_format,
+      time_format: type_option.time_format,
+      include_time: type_option.include_time,
+    }
+  }
+}
+
+impl<T: RowTimestampTarget> std::convert::From<&FieldRevision> for TimestampTypeOption<T> {
+  fn from(field_rev: &FieldRevision) -> Self {
+    field_rev
+      .get_type_option::<Self>(T::FIELD_TYPE.into())
+      .unwrap_or_default()
+  }
+}
+
+impl<T: RowTimestampTarget> std::convert::From<&std::sync::Arc<FieldRevision>>
+  for TimestampTypeOption<T>
+{
+  fn from(field_rev: &std::sync::Arc<FieldRevision>) -> Self {
+    Self::from(field_rev.as_ref())
+  }
+}
+
+impl<T: RowTimestampTarget> TypeOptionDataSerializer for TimestampTypeOption<T> {
+  fn json_str(&self) -> String {
+    match serde_json::to_string(&self) {
+      Ok(s) => s,
+      Err(e) => {
+        tracing::error!("Field type data serialize to json fail, error: {:?}", e);
+        serde_json::to_string(&Self::default()).unwrap()
+      },
+    }
+  }
+
+  fn protobuf_bytes(&self) -> Bytes {
+    let pb = TimestampTypeOptionPB::from(self.clone());
+    let bytes: Result<Bytes, ProtobufError> = pb.try_into();
+    bytes.unwrap()
+  }
+}
+
+impl<T: RowTimestampTarget> TypeOptionDataDeserializer for TimestampTypeOption<T> {
+  fn from_json_str(s: &str) -> Self {
+    match serde_json::from_str(s) {
+      Ok(obj) => obj,
+      Err(err) => {
+        tracing::error!(
+          "{} type option deserialize from {} failed, {:?}",
+          T::FIELD_TYPE,
+          s,
+          err
+        );
+        Self::default()
+      },
+    }
+  }
+
+  fn from_protobuf_bytes(bytes: Bytes) -> Self {
+    TimestampTypeOptionPB::try_from(bytes)
+      .map(Self::from)
+      .unwrap_or_default()
+  }
+}
+
+/// The changesets are ignored, see [TimestampTypeOption].
+pub type TimestampCellChangeset = String;
+
+impl<T: RowTimestampTarget> TypeOption for TimestampTypeOption<T> {
+  type CellData = DateCellData;
+  type CellChangeset = TimestampCellChangeset;
+  type CellProtobufType = DateCellDataPB;
+  type CellFilter = DateFilterPB;
+}
+
+impl<T: RowTimestampTarget> TypeOptionTransform for TimestampTypeOption<T> {}
+
+impl<T: RowTimestampTarget> TypeOptionCellData for TimestampTypeOption<T> {
+  fn convert_to_protobuf(
+    &self,
+    cell_data: <Self as TypeOption>::CellData,
+  ) -> <Self as TypeOption>::CellProtobufType {
+    self.date_type_option().today_desc_from_timestamp(cell_data)
+  }
+
+  fn decode_type_option_cell_str(
+    &self,
+    cell_str: String,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    // The time is shown according to the type option instead of the cell
+    let mut cell_data = DateCellData::from_cell_str(&cell_str)?;
+    cell_data.include_time = self.include_time;
+    Ok(cell_data)
+  }
+}
+
+impl<T: RowTimestampTarget> CellDataDecoder for TimestampTypeOption<T> {
+  fn decode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    _field_rev: &FieldRevision,
+  ) -> FlowyResult<<Self as TypeOption>::CellData> {
+    // The cells of other field types are replaced by the timestamp of their rows
+    if decoded_field_type != &T::FIELD_TYPE {
+      return Ok(Default::default());
+    }
+
+    self.decode_type_option_cell_str(cell_str)
+  }
+
+  fn decode_cell_data_to_str(&self, cell_data: <Self as TypeOption>::CellData) -> String {
+    let cell_data_pb = self.date_type_option().today_desc_from_timestamp(cell_data);
+    if cell_data_pb.time.is_empty() {
+      cell_data_pb.date
+    } else {
+      format!("{} {}", cell_data_pb.date, cell_data_pb.time)
+    }
+  }
+}
+
+impl<T: RowTimestampTarget> CellDataChangeset for TimestampTypeOption<T> {
+  fn apply_changeset(
+    &self,
+    _changeset: <Self as TypeOption>::CellChangeset,
+    type_cell_data: Option<TypeCellData>,
+  ) -> FlowyResult<(String, <Self as TypeOption>::CellData)> {
+    // The cell is read-only, it's only written when the timestamp of the row changes
+    let cell_str = type_cell_data
+      .filter(|type_cell_data| type_cell_data.field_type == T::FIELD_TYPE)
+      .map(|type_cell_data| type_cell_data.cell_str)
+      .unwrap_or_else(|| DateCellData::default().to_string());
+    let cell_data = self.decode_type_option_cell_str(cell_str.clone())?;
+    Ok((cell_str, cell_data))
+  }
+}
+
+impl<T: RowTimestampTarget> TypeOptionCellDataFilter for TimestampTypeOption<T> {
+  fn apply_filter(
+    &self,
+    filter: &<Self as TypeOption>::CellFilter,
+    field_type: &FieldType,
+    cell_data: &<Self as TypeOption>::CellData,
+  ) -> bool {
+    if field_type != &T::FIELD_TYPE {
+      return true;
+    }
+
+    filter.is_visible(cell_data.timestamp)
+  }
+}
+
+impl<T: RowTimestampTarget> TypeOptionCellDataCompare for TimestampTypeOption<T> {
+  fn apply_cmp(
+    &self,
+    cell_data: &<Self as TypeOption>::CellData,
+    other_cell_data: &<Self as TypeOption>::CellData,
+  ) -> Ordering {
+    match (cell_data.timestamp, other_cell_data.timestamp) {
+      (Some(left), Some(right)) => left.cmp(&right),
+      (Some(_), None) => Ordering::Greater,
+      (None, Some(_)) => Ordering::Less,
+      (None, None) => default_order(),
+    }
+  }
+}
+
+pub type CreatedTimeTypeOptionBuilder = TimestampTypeOptionBuilder<RowCreatedAt>;
+pub type LastEditedTimeTypeOptionBuilder = TimestampTypeOptionBuilder<RowModifiedAt>;
+
+#[derive(Default)]
+pub struct TimestampTypeOptionBuilder<T: RowTimestampTarget>(TimestampTypeOption<T>);
+
+impl<T: RowTimestampTarget> std::convert::From<TimestampTypeOptionBuilder<T>>
+  for BoxTypeOptionBuilder
+{
+  fn from(builder: TimestampTypeOptionBuilder<T>) -> BoxTypeOptionBuilder {
+    Box::new(builder)
+  }
+}
+
+impl<T: RowTimestampTarget> TimestampTypeOptionBuilder<T> {
+  pub fn from_protobuf_bytes(bytes: Bytes) -> Self {
+    Self(TimestampTypeOption::from_protobuf_bytes(bytes))
+  }
+
+  pub fn from_json_str(s: &str) -> Self {
+    Self(TimestampTypeOption::from_json_str(s))
+  }
+
+  pub fn date_format(mut self, date_format: DateFormat) -> Self {
+    self.0.date_format = date_format;
+    self
+  }
+
+  pub fn time_format(mut self, time_format: TimeFormat) -> Self {
+    self.0.time_format = time_format;
+    self
+  }
+
+  pub fn include_time(mut self, include_time: bool) -> Self {
+    self.0.include_time = include_time;
+    self
+  }
+}
+
+impl<T: RowTimestampTarget> TypeOptionBuilder for TimestampTypeOptionBuilder<T> {
+  fn field_type(&self) -> FieldType {
+    T::FIELD_TYPE
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.0
+  }
+}
@@ -61,6 +61,13 @@ pub trait TypeOptionCellData: TypeOption {
     &self,
     cell_str: String,
   ) -> FlowyResult<<Self as TypeOption>::CellData>;
+
+  /// Returns true if the cell string is encoded in a format that was used by an older version.
+  /// The stale cell string can still be decoded, it will be encoded again when repairing the
+  /// cells of the database.
+  fn is_stale_cell_str(&self, _cell_str: &str) -> bool {
+    false
+  }
 }
 
 pub trait TypeOptionTransform: TypeOption {
@@ -113,6 +120,14 @@ pub trait TypeOptionCellDataFilter: TypeOption + CellDataDecoder {
     field_type: &FieldType,
     cell_data: &<Self as TypeOption>::CellData,
   ) -> bool;
+
+  /// Returns true if the cell has no value, it's used by the [EmptyFilterConditionPB] that
+  /// works for every field type. The cell is empty if its display string is empty by default.
+  ///
+  /// [EmptyFilterConditionPB]: crate::entities::EmptyFilterConditionPB
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.to_string().trim().is_empty()
+  }
 }
 
 #[inline(always)]
@@ -1,13 +1,11 @@
-use crate::entities::FieldType;
+use crate::entities::{EmptyFilterConditionPB, FieldType, InvalidCellReasonPB};
 use crate::services::cell::{
   AtomicCellDataCache, AtomicCellFilterCache, CellDataChangeset, CellDataDecoder, CellProtobufBlob,
   FromCellChangesetString, FromCellString, TypeCellData,
 };
 use crate::services::field::{
-  CheckboxTypeOptionPB, ChecklistTypeOptionPB, DateTypeOptionPB, MultiSelectTypeOptionPB,
-  NumberTypeOptionPB, RichTextTypeOptionPB, SingleSelectTypeOptionPB, TypeOption,
-  TypeOptionCellData, TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
-  URLTypeOptionPB,
+  get_type_option_plugin, RichTextTypeOptionPB, TypeOption, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform,
 };
 use crate::services::filter::FilterType;
 use database_model::{FieldRevision, TypeOptionDataDeserializer, TypeOptionDataSerializer};
@@ -67,6 +65,20 @@ pub trait TypeOptionCellDataHandler {
     decoded_field_type: &FieldType,
     field_rev: &FieldRevision,
   ) -> FlowyResult<BoxCellData>;
+
+  /// Returns the reason if the cell_str that was written by the current field type can't be
+  /// decoded as it is.
+  fn validate_cell_str(&self, cell_str: &str) -> Option<InvalidCellReasonPB>;
+
+  /// Decode the cell_str to corresponding cell data, and then encode the cell data with the
+  /// current type option. The cell_str is transformed if it was written by another field type.
+  /// Returns an error if the cell_str can't be decoded.
+  fn encode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> FlowyResult<String>;
 }
 
 struct CellDataCacheKey(u64);
@@ -89,7 +101,7 @@ impl AsRef<u64> for CellDataCacheKey {
   }
 }
 
-struct TypeOptionCellDataHandlerImpl<T> {
+pub(crate) struct TypeOptionCellDataHandlerImpl<T> {
   inner: T,
   cell_data_cache: Option<AtomicCellDataCache>,
   cell_filter_cache: Option<AtomicCellFilterCache>,
@@ -151,9 +163,12 @@ where
         cell_str,
         cell_data
       );
-      cell_data_cache
-        .write()
-        .insert(key.as_ref(), cell_data.clone());
+      // The decoded data holds about as much on the heap as the cell string it's decoded from
+      cell_data_cache.write().insert_with_heap_bytes(
+        key.as_ref(),
+        cell_data.clone(),
+        cell_str.len(),
+      );
     }
     Ok(cell_data)
   }
@@ -173,7 +188,9 @@ where
         cell_str,
         cell_data
       );
-      cell_data_cache.write().insert(key.as_ref(), cell_data);
+      cell_data_cache
+        .write()
+        .insert_with_heap_bytes(key.as_ref(), cell_data, cell_str.len());
     }
   }
 }
@@ -255,6 +272,14 @@ where
   ) -> bool {
     let perform_filter = || {
       let filter_cache = self.cell_filter_cache.as_ref()?.read();
+      if let Some(condition) = filter_cache.get::<EmptyFilterConditionPB>(filter_type) {
+        let is_cell_empty = self
+          .get_decoded_cell_data(type_cell_data.cell_str, &filter_type.field_type, field_rev)
+          .map(|cell_data| self.is_cell_empty(&cell_data))
+          .unwrap_or(true);
+        return Some(condition.is_visible(is_cell_empty));
+      }
+
       let cell_filter = filter_cache.get::<<Self as TypeOption>::CellFilter>(filter_type)?;
       let cell_data = self
         .get_decoded_cell_data(type_cell_data.cell_str, &filter_type.field_type, field_rev)
@@ -300,6 +325,36 @@ where
     };
     Ok(BoxCellData::new(cell_data))
   }
+
+  fn validate_cell_str(&self, cell_str: &str) -> Option<InvalidCellReasonPB> {
+    if self
+      .decode_type_option_cell_str(cell_str.to_owned())
+      .is_err()
+    {
+      return Some(InvalidCellReasonPB::Undecodable);
+    }
+    if self.is_stale_cell_str(cell_str) {
+      return Some(InvalidCellReasonPB::StaleEncoding);
+    }
+    None
+  }
+
+  fn encode_cell_str(
+    &self,
+    cell_str: String,
+    decoded_field_type: &FieldType,
+    field_rev: &FieldRevision,
+  ) -> FlowyResult<String> {
+    if self.transformable() {
+      if let Some(cell_data) =
+        self.transform_type_option_cell_str(&cell_str, decoded_field_type, field_rev)
+      {
+        return Ok(cell_data.to_string());
+      }
+    }
+    let cell_data = self.decode_cell_str(cell_str, decoded_field_type, field_rev)?;
+    Ok(cell_data.to_string())
+  }
 }
 
 pub struct TypeOptionCellExt<'a> {
@@ -340,92 +395,18 @@ impl<'a> TypeOptionCellExt<'a> {
     }
   }
 
+  /// Returns the handler of the type option that is registered for the `field_type`, see
+  /// [TypeOptionRegistry](crate::services::field::TypeOptionRegistry).
   pub fn get_type_option_cell_data_handler(
     &self,
     field_type: &FieldType,
   ) -> Option<Box<dyn TypeOptionCellDataHandler>> {
-    match field_type {
-      FieldType::RichText => self
-        .field_rev
-        .get_type_option::<RichTextTypeOptionPB>(field_type.into())
-        .map(|type_option| {
-          TypeOptionCellDataHandlerImpl::new_with_boxed(
-            type_option,
-            self.cell_filter_cache.clone(),
-            self.cell_data_cache.clone(),
-          )
-        }),
-      FieldType::Number => self
-        .field_rev
-        .get_type_option::<NumberTypeOptionPB>(field_type.into())
-        .map(|type_option| {
-          TypeOptionCellDataHandlerImpl::new_with_boxed(
-            type_option,
-            self.cell_filter_cache.clone(),
-            self.cell_data_cache.clone(),
-          )
-        }),
-      FieldType::DateTime => self
-        .field_rev
-        .get_type_option::<DateTypeOptionPB>(field_type.into())
-        .map(|type_option| {
-          TypeOptionCellDataHandlerImpl::new_with_boxed(
-            type_option,
-            self.cell_filter_cache.clone(),
-            self.cell_data_cache.clone(),
-          )
-        }),
-      FieldType::SingleSelect => self
-        .field_rev
-        .get_type_option::<SingleSelectTypeOptionPB>(field_type.into())
-        .map(|type_option| {
-          TypeOptionCellDataHandlerImpl::new_with_boxed(
-            type_option,
-            self.cell_filter_cache.clone(),
-            self.cell_data_cache.clone(),
-          )
-        }),
-      FieldType::MultiSelect => self
-        .field_rev
-        .get_type_option::<MultiSelectTypeOptionPB>(field_type.into())
-        .map(|type_option| {
-          TypeOptionCellDataHandlerImpl::new_with_boxed(
-            type_option,
-            self.cell_filter_cache.clone(),
-            self.cell_data_cache.clone(),
-          )
-        }),
-      FieldType::Checkbox => self
-        .field_rev
-        .get_type_option::<CheckboxTypeOptionPB>(field_type.into())
-        .map(|type_option| {
-          TypeOptionCellDataHandlerImpl::new_with_boxed(
-            type_option,
-            self.cell_filter_cache.clone(),
-            self.cell_data_cache.clone(),
-          )
-        }),
-      FieldType::URL => self
-        .field_rev
-        .get_type_option::<URLTypeOptionPB>(field_type.into())
-        .map(|type_option| {
-          TypeOptionCellDataHandlerImpl::new_with_boxed(
-            type_option,
-            self.cell_filter_cache.clone(),
-            self.cell_data_cache.clone(),
-          )
-        }),
-      FieldType::Checklist => self
-        .field_rev
-        .get_type_option::<ChecklistTypeOptionPB>(field_type.into())
-        .map(|type_option| {
-          TypeOptionCellDataHandlerImpl::new_with_boxed(
-            type_option,
-            self.cell_filter_cache.clone(),
-            self.cell_data_cache.clone(),
-          )
-        }),
-    }
+    get_type_option_plugin(field_type)?.cell_data_handler(
+      self.field_rev,
+      field_type,
+      self.cell_filter_cache.clone(),
+      self.cell_data_cache.clone(),
+    )
   }
 }
 
@@ -467,23 +448,12 @@ fn get_type_option_transform_handler(
   type_option_data: &str,
   field_type: &FieldType,
 ) -> Box<dyn TypeOptionTransformHandler> {
-  match field_type {
-    FieldType::RichText => Box::new(RichTextTypeOptionPB::from_json_str(type_option_data))
-      as Box<dyn TypeOptionTransformHandler>,
-    FieldType::Number => Box::new(NumberTypeOptionPB::from_json_str(type_option_data))
-      as Box<dyn TypeOptionTransformHandler>,
-    FieldType::DateTime => Box::new(DateTypeOptionPB::from_json_str(type_option_data))
-      as Box<dyn TypeOptionTransformHandler>,
-    FieldType::SingleSelect => Box::new(SingleSelectTypeOptionPB::from_json_str(type_option_data))
-      as Box<dyn TypeOptionTransformHandler>,
-    FieldType::MultiSelect => Box::new(MultiSelectTypeOptionPB::from_json_str(type_option_data))
-      as Box<dyn TypeOptionTransformHandler>,
-    FieldType::Checkbox => Box::new(CheckboxTypeOptionPB::from_json_str(type_option_data))
-      as Box<dyn TypeOptionTransformHandler>,
-    FieldType::URL => Box::new(URLTypeOptionPB::from_json_str(type_option_data))
-      as Box<dyn TypeOptionTransformHandler>,
-    FieldType::Checklist => Box::new(ChecklistTypeOptionPB::from_json_str(type_option_data))
-      as Box<dyn TypeOptionTransformHandler>,
+  match get_type_option_plugin(field_type) {
+    Some(plugin) => plugin.transform_handler(type_option_data),
+    None => {
+      tracing::error!("The type option of {} is not registered", field_type);
+      Box::new(RichTextTypeOptionPB::from_json_str(type_option_data))
+    },
   }
 }
 
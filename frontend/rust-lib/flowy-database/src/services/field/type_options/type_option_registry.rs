@@ -0,0 +1,335 @@
+use crate::entities::{
+  CheckboxFilterPB, ChecklistFilterPB, DateFilterPB, EmailFilterPB, EmptyFilterConditionPB,
+  FieldType, FilterContent, FilterDataPB, LocationFilterPB, NumberFilterPB, PersonFilterPB,
+  RelationFilterPB, SelectOptionFilterPB, TextFilterPB,
+};
+use crate::services::cell::{
+  AtomicCellDataCache, AtomicCellFilterCache, CellDataChangeset, CellDataDecoder,
+};
+use crate::services::field::{
+  AttachmentTypeOptionPB, AutoNumberTypeOptionPB, CheckboxTypeOptionPB, ChecklistTypeOptionPB,
+  CreatedTimeTypeOptionPB, DateTypeOptionPB, DurationTypeOptionPB, EmailTypeOptionPB,
+  FormulaTypeOptionPB, LastEditedTimeTypeOptionPB, LocationTypeOptionPB, MultiSelectTypeOptionPB,
+  NumberTypeOptionPB, PersonTypeOptionPB, PhoneTypeOptionPB, ProgressTypeOptionPB,
+  RelationTypeOptionPB, RichTextTypeOptionPB, RollupTypeOptionPB, SingleSelectTypeOptionPB,
+  TimeTrackingTypeOptionPB, TypeOption, TypeOptionBuilder, TypeOptionCellData,
+  TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionCellDataHandler,
+  TypeOptionCellDataHandlerImpl, TypeOptionTransform, TypeOptionTransformHandler, URLTypeOptionPB,
+};
+use crate::services::filter::{FilterType, FromFilterString};
+use bytes::Bytes;
+use database_model::{
+  FieldRevision, FieldTypeRevision, FilterRevision, TypeOptionDataDeserializer,
+  TypeOptionDataSerializer,
+};
+use flowy_error::{ErrorCode, FlowyResult};
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+lazy_static! {
+  static ref TYPE_OPTION_REGISTRY: RwLock<TypeOptionRegistry> =
+    RwLock::new(TypeOptionRegistry::default());
+}
+
+/// Erases the type of the [TypeOption] that is registered for a field type. It creates the
+/// builders of the type option, the handlers that decode, change, filter and compare the cells of
+/// the field, and it converts the filters of the field from and to their [FilterDataPB].
+pub trait TypeOptionPlugin: Send + Sync {
+  fn default_type_option_builder(&self) -> Box<dyn TypeOptionBuilder>;
+
+  fn type_option_builder_from_json_str(&self, s: &str) -> Box<dyn TypeOptionBuilder>;
+
+  fn type_option_builder_from_bytes(&self, bytes: Bytes) -> Box<dyn TypeOptionBuilder>;
+
+  fn cell_data_handler(
+    &self,
+    field_rev: &FieldRevision,
+    field_type: &FieldType,
+    cell_filter_cache: Option<AtomicCellFilterCache>,
+    cell_data_cache: Option<AtomicCellDataCache>,
+  ) -> Option<Box<dyn TypeOptionCellDataHandler>>;
+
+  fn transform_handler(&self, type_option_data: &str) -> Box<dyn TypeOptionTransformHandler>;
+
+  /// Parses the filter of the field and caches it for the cell data handler. The filter that
+  /// can't be parsed is removed from the cache, so it doesn't hide any row.
+  fn insert_cell_filter(
+    &self,
+    filter_type: &FilterType,
+    filter_rev: &FilterRevision,
+    cell_filter_cache: &AtomicCellFilterCache,
+  ) -> FlowyResult<()>;
+
+  /// Returns an error if the filter of the field can't be parsed, e.g. its pattern is invalid.
+  fn validate_cell_filter(&self, filter_rev: &FilterRevision) -> FlowyResult<()>;
+
+  /// Returns the bytes of the [FilterDataPB] of the filter that is sent to the client.
+  fn filter_data(&self, filter_rev: &FilterRevision) -> Vec<u8>;
+
+  /// Parses the bytes of the [FilterDataPB] that is sent by the client.
+  fn filter_content(&self, bytes: Bytes) -> Result<FilterContent, ErrorCode>;
+}
+
+struct TypeOptionPluginImpl<T, F> {
+  field_type: FieldTypeRevision,
+  phantom: PhantomData<fn() -> (T, F)>,
+}
+
+impl<T, F> TypeOptionPlugin for TypeOptionPluginImpl<T, F>
+where
+  T: TypeOption
+    + CellDataDecoder
+    + CellDataChangeset
+    + TypeOptionCellData
+    + TypeOptionTransform
+    + TypeOptionCellDataFilter
+    + TypeOptionCellDataCompare
+    + TypeOptionDataSerializer
+    + TypeOptionDataDeserializer
+    + Default
+    + 'static,
+  F: FilterDataPB + 'static,
+{
+  fn default_type_option_builder(&self) -> Box<dyn TypeOptionBuilder> {
+    Box::new(RegisteredTypeOptionBuilder {
+      field_type: self.field_type,
+      type_option: T::default(),
+    })
+  }
+
+  fn type_option_builder_from_json_str(&self, s: &str) -> Box<dyn TypeOptionBuilder> {
+    Box::new(RegisteredTypeOptionBuilder {
+      field_type: self.field_type,
+      type_option: T::from_json_str(s),
+    })
+  }
+
+  fn type_option_builder_from_bytes(&self, bytes: Bytes) -> Box<dyn TypeOptionBuilder> {
+    Box::new(RegisteredTypeOptionBuilder {
+      field_type: self.field_type,
+      type_option: T::from_protobuf_bytes(bytes),
+    })
+  }
+
+  fn cell_data_handler(
+    &self,
+    field_rev: &FieldRevision,
+    field_type: &FieldType,
+    cell_filter_cache: Option<AtomicCellFilterCache>,
+    cell_data_cache: Option<AtomicCellDataCache>,
+  ) -> Option<Box<dyn TypeOptionCellDataHandler>> {
+    field_rev
+      .get_type_option::<T>(field_type.into())
+      .map(|type_option| {
+        TypeOptionCellDataHandlerImpl::new_with_boxed(
+          type_option,
+          cell_filter_cache,
+          cell_data_cache,
+        )
+      })
+  }
+
+  fn transform_handler(&self, type_option_data: &str) -> Box<dyn TypeOptionTransformHandler> {
+    Box::new(T::from_json_str(type_option_data))
+  }
+
+  fn insert_cell_filter(
+    &self,
+    filter_type: &FilterType,
+    filter_rev: &FilterRevision,
+    cell_filter_cache: &AtomicCellFilterCache,
+  ) -> FlowyResult<()> {
+    // The conditions that work for every field type are cached instead of the filter of the
+    // field type
+    if let Ok(condition) = EmptyFilterConditionPB::try_from(filter_rev.condition) {
+      cell_filter_cache.write().insert(filter_type, condition);
+      return Ok(());
+    }
+
+    match <T as TypeOption>::CellFilter::try_from_filter_rev(filter_rev) {
+      Ok(cell_filter) => {
+        cell_filter_cache.write().insert_with_heap_bytes(
+          filter_type,
+          cell_filter,
+          filter_rev.content.len(),
+        );
+        Ok(())
+      },
+      Err(err) => {
+        cell_filter_cache.write().remove(filter_type);
+        Err(err)
+      },
+    }
+  }
+
+  fn validate_cell_filter(&self, filter_rev: &FilterRevision) -> FlowyResult<()> {
+    if EmptyFilterConditionPB::try_from(filter_rev.condition).is_ok() {
+      return Ok(());
+    }
+    <T as TypeOption>::CellFilter::try_from_filter_rev(filter_rev).map(|_| ())
+  }
+
+  fn filter_data(&self, filter_rev: &FilterRevision) -> Vec<u8> {
+    let bytes: Result<Bytes, _> = F::from(filter_rev).try_into();
+    bytes.map(|bytes| bytes.to_vec()).unwrap_or_default()
+  }
+
+  fn filter_content(&self, bytes: Bytes) -> Result<FilterContent, ErrorCode> {
+    F::try_from(bytes)
+      .map_err(|_| ErrorCode::ProtobufSerde)?
+      .into_filter_content()
+  }
+}
+
+/// The [TypeOptionBuilder] of the type option that is registered for a field type.
+struct RegisteredTypeOptionBuilder<T> {
+  field_type: FieldTypeRevision,
+  type_option: T,
+}
+
+impl<T> TypeOptionBuilder for RegisteredTypeOptionBuilder<T>
+where
+  T: TypeOptionDataSerializer,
+{
+  fn field_type(&self) -> FieldType {
+    self.field_type.into()
+  }
+
+  fn serializer(&self) -> &dyn TypeOptionDataSerializer {
+    &self.type_option
+  }
+}
+
+/// Maps the field types to the type options that handle their fields, cells and filters. It's
+/// keyed on the [FieldTypeRevision] that is saved in the field, so a type option can be
+/// registered for a field type that isn't one of the built-in [FieldType]s. The built-in type
+/// options are registered by default. The type options that are compiled behind a feature flag
+/// register themselves with [register_type_option] when the application starts, and they can
+/// replace the built-in ones.
+pub struct TypeOptionRegistry {
+  plugins: HashMap<FieldTypeRevision, Arc<dyn TypeOptionPlugin>>,
+}
+
+impl TypeOptionRegistry {
+  /// Registers the type option `T` for the `field_type`, the filters of the field are sent to the
+  /// client as `F`.
+  pub fn register<T, F>(&mut self, field_type: FieldTypeRevision)
+  where
+    T: TypeOption
+      + CellDataDecoder
+      + CellDataChangeset
+      + TypeOptionCellData
+      + TypeOptionTransform
+      + TypeOptionCellDataFilter
+      + TypeOptionCellDataCompare
+      + TypeOptionDataSerializer
+      + TypeOptionDataDeserializer
+      + Default
+      + 'static,
+    F: FilterDataPB + 'static,
+  {
+    let plugin = TypeOptionPluginImpl::<T, F> {
+      field_type,
+      phantom: PhantomData,
+    };
+    self.plugins.insert(field_type, Arc::new(plugin));
+  }
+
+  pub fn get(&self, field_type: FieldTypeRevision) -> Option<Arc<dyn TypeOptionPlugin>> {
+    self.plugins.get(&field_type).cloned()
+  }
+}
+
+impl std::default::Default for TypeOptionRegistry {
+  fn default() -> Self {
+    let mut registry = Self {
+      plugins: HashMap::new(),
+    };
+    registry.register::<RichTextTypeOptionPB, TextFilterPB>(FieldType::RichText.into());
+    registry.register::<NumberTypeOptionPB, NumberFilterPB>(FieldType::Number.into());
+    registry.register::<DateTypeOptionPB, DateFilterPB>(FieldType::DateTime.into());
+    registry
+      .register::<SingleSelectTypeOptionPB, SelectOptionFilterPB>(FieldType::SingleSelect.into());
+    registry
+      .register::<MultiSelectTypeOptionPB, SelectOptionFilterPB>(FieldType::MultiSelect.into());
+    registry.register::<CheckboxTypeOptionPB, CheckboxFilterPB>(FieldType::Checkbox.into());
+    registry.register::<URLTypeOptionPB, TextFilterPB>(FieldType::URL.into());
+    registry.register::<ChecklistTypeOptionPB, ChecklistFilterPB>(FieldType::Checklist.into());
+    registry.register::<RelationTypeOptionPB, RelationFilterPB>(FieldType::Relation.into());
+    registry.register::<RollupTypeOptionPB, NumberFilterPB>(FieldType::Rollup.into());
+    registry.register::<FormulaTypeOptionPB, TextFilterPB>(FieldType::Formula.into());
+    registry.register::<CreatedTimeTypeOptionPB, DateFilterPB>(FieldType::CreatedTime.into());
+    registry.register::<LastEditedTimeTypeOptionPB, DateFilterPB>(FieldType::LastEditedTime.into());
+    registry.register::<PersonTypeOptionPB, PersonFilterPB>(FieldType::Person.into());
+    registry.register::<AttachmentTypeOptionPB, TextFilterPB>(FieldType::Attachment.into());
+    registry.register::<ProgressTypeOptionPB, NumberFilterPB>(FieldType::Progress.into());
+    registry.register::<PhoneTypeOptionPB, TextFilterPB>(FieldType::Phone.into());
+    registry.register::<EmailTypeOptionPB, EmailFilterPB>(FieldType::Email.into());
+    registry.register::<DurationTypeOptionPB, NumberFilterPB>(FieldType::Duration.into());
+    registry.register::<AutoNumberTypeOptionPB, NumberFilterPB>(FieldType::AutoNumber.into());
+    registry.register::<TimeTrackingTypeOptionPB, NumberFilterPB>(FieldType::TimeTracking.into());
+    registry.register::<LocationTypeOptionPB, LocationFilterPB>(FieldType::Location.into());
+    registry
+  }
+}
+
+/// Registers the type option `T` as the handler of the fields of the `field_type`, their filters
+/// are sent to the client as `F`. It replaces the type option that was registered for the
+/// `field_type` before.
+pub fn register_type_option<T, F>(field_type: FieldTypeRevision)
+where
+  T: TypeOption
+    + CellDataDecoder
+    + CellDataChangeset
+    + TypeOptionCellData
+    + TypeOptionTransform
+    + TypeOptionCellDataFilter
+    + TypeOptionCellDataCompare
+    + TypeOptionDataSerializer
+    + TypeOptionDataDeserializer
+    + Default
+    + 'static,
+  F: FilterDataPB + 'static,
+{
+  TYPE_OPTION_REGISTRY.write().register::<T, F>(field_type);
+}
+
+/// Returns the type option plugin that is registered for the `field_type`.
+pub fn get_type_option_plugin<T: Into<FieldTypeRevision>>(
+  field_type: T,
+) -> Option<Arc<dyn TypeOptionPlugin>> {
+  TYPE_OPTION_REGISTRY.read().get(field_type.into())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::services::field::FieldBuilder;
+  use strum::IntoEnumIterator;
+
+  #[test]
+  fn type_option_registry_test() {
+    for field_type in FieldType::iter() {
+      let plugin = get_type_option_plugin(&field_type).unwrap();
+      let field_rev = FieldBuilder::from_field_type(&field_type).build();
+      assert!(plugin
+        .cell_data_handler(&field_rev, &field_type, None, None)
+        .is_some());
+      assert_eq!(
+        plugin.default_type_option_builder().field_type(),
+        field_type
+      );
+      let filter_rev = FilterRevision {
+        id: "filter".to_owned(),
+        field_id: field_rev.id.clone(),
+        field_type: field_type.clone().into(),
+        ..Default::default()
+      };
+      let data = plugin.filter_data(&filter_rev);
+      assert!(plugin.filter_content(Bytes::from(data)).is_ok());
+    }
+  }
+}
@@ -3,8 +3,9 @@ mod tests {
   use crate::entities::FieldType;
   use crate::services::cell::CellDataChangeset;
 
+  use crate::entities::{TextFilterConditionPB, TextFilterPB};
   use crate::services::field::FieldBuilder;
-  use crate::services::field::URLTypeOptionPB;
+  use crate::services::field::{TextFilter, TypeOptionCellDataFilter, URLTypeOptionPB};
   use database_model::FieldRevision;
 
   /// The expected_str will equal to the input string, but the expected_url will be empty if there's no
@@ -150,6 +151,24 @@ mod tests {
     );
   }
 
+  #[test]
+  fn url_type_option_case_sensitive_filter_test() {
+    let type_option = URLTypeOptionPB::default();
+    let cell_data = type_option
+      .apply_changeset("https://appflowy.io/Docs".to_owned(), None)
+      .unwrap()
+      .1;
+    let filter = |case_sensitive: bool| {
+      TextFilter::from(TextFilterPB {
+        condition: TextFilterConditionPB::Contains,
+        content: "docs".to_owned(),
+        case_sensitive,
+      })
+    };
+    assert!(type_option.apply_filter(&filter(false), &FieldType::URL, &cell_data));
+    assert!(!type_option.apply_filter(&filter(true), &FieldType::URL, &cell_data));
+  }
+
   fn assert_url(
     type_option: &URLTypeOptionPB,
     input_str: &str,
@@ -1,8 +1,8 @@
-use crate::entities::{FieldType, TextFilterPB};
+use crate::entities::FieldType;
 use crate::impl_type_option;
 use crate::services::cell::{CellDataChangeset, CellDataDecoder, FromCellString, TypeCellData};
 use crate::services::field::{
-  BoxTypeOptionBuilder, TypeOption, TypeOptionBuilder, TypeOptionCellData,
+  BoxTypeOptionBuilder, TextFilter, TypeOption, TypeOptionBuilder, TypeOptionCellData,
   TypeOptionCellDataCompare, TypeOptionCellDataFilter, TypeOptionTransform, URLCellData,
   URLCellDataPB,
 };
@@ -44,7 +44,7 @@ impl TypeOption for URLTypeOptionPB {
   type CellData = URLCellData;
   type CellChangeset = URLCellChangeset;
   type CellProtobufType = URLCellDataPB;
-  type CellFilter = TextFilterPB;
+  type CellFilter = TextFilter;
 }
 
 impl TypeOptionTransform for URLTypeOptionPB {}
@@ -117,6 +117,10 @@ impl TypeOptionCellDataFilter for URLTypeOptionPB {
 
     filter.is_visible(cell_data)
   }
+
+  fn is_cell_empty(&self, cell_data: &<Self as TypeOption>::CellData) -> bool {
+    cell_data.url.trim().is_empty() && cell_data.content.trim().is_empty()
+  }
 }
 
 impl TypeOptionCellDataCompare for URLTypeOptionPB {
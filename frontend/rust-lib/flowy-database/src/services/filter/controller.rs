@@ -6,16 +6,18 @@ use crate::services::cell::{
 use crate::services::database_view::{DatabaseViewChanged, DatabaseViewChangedNotifier};
 use crate::services::field::*;
 use crate::services::filter::{
-  FilterChangeset, FilterResult, FilterResultNotification, FilterType,
+  row_meta_cell_rev, FilterChangeset, FilterResult, FilterResultNotification, FilterType,
+  FilteredRowCount, RowMetaTarget,
 };
 use crate::services::row::DatabaseBlockRowRevision;
+use crate::services::setting::WeekSettings;
 use dashmap::DashMap;
 use database_model::{CellRevision, FieldId, FieldRevision, FilterRevision, RowRevision};
-use flowy_error::FlowyResult;
+use flowy_error::{FlowyError, FlowyResult};
 use flowy_task::{QualityOfService, Task, TaskContent, TaskDispatcher};
 use lib_infra::future::Fut;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -27,12 +29,22 @@ pub trait FilterDelegate: Send + Sync + 'static {
   fn get_field_revs(&self, field_ids: Option<Vec<String>>) -> Fut<Vec<Arc<FieldRevision>>>;
   fn get_blocks(&self) -> Fut<Vec<DatabaseBlockRowRevision>>;
   fn get_row_rev(&self, rows_id: &str) -> Fut<Option<(usize, Arc<RowRevision>)>>;
+  fn get_week_settings(&self) -> WeekSettings;
 }
 
 pub trait FromFilterString {
   fn from_filter_rev(filter_rev: &FilterRevision) -> Self
   where
     Self: Sized;
+
+  /// Returns an error if the filter can't be applied, e.g. its pattern is invalid. It's called
+  /// when the filter is inserted into the cell filter cache.
+  fn try_from_filter_rev(filter_rev: &FilterRevision) -> FlowyResult<Self>
+  where
+    Self: Sized,
+  {
+    Ok(Self::from_filter_rev(filter_rev))
+  }
 }
 
 pub struct FilterController {
@@ -44,6 +56,16 @@ pub struct FilterController {
   cell_filter_cache: AtomicCellFilterCache,
   task_scheduler: Arc<RwLock<TaskDispatcher>>,
   notifier: DatabaseViewChangedNotifier,
+  /// The filters that are applied for the current session only. They are not saved in the view
+  /// and get dropped when the view is closed, unless they are saved by
+  /// [FilterController::take_session_filter].
+  session_filters: parking_lot::RwLock<HashMap<FilterType, Arc<FilterRevision>>>,
+  /// The date filters whose weeks start on the first day of the week of the workspace, because
+  /// they don't specify one. They are updated when the week settings change.
+  workspace_week_filters: parking_lot::RwLock<HashSet<FilterType>>,
+  /// The number of the visible and the hidden rows. It's counted from all the rows once and then
+  /// kept up to date as the rows are inserted, deleted or filtered.
+  row_count: parking_lot::RwLock<Option<FilteredRowCount>>,
 }
 
 impl Drop for FilterController {
@@ -74,8 +96,13 @@ impl FilterController {
       cell_filter_cache: AnyTypeCache::<FilterType>::new(),
       task_scheduler,
       notifier,
+      session_filters: Default::default(),
+      workspace_week_filters: Default::default(),
+      row_count: Default::default(),
     };
-    this.refresh_filters(filter_revs).await;
+    if let Err(err) = this.refresh_filters(filter_revs).await {
+      tracing::error!("Create filters failed: {:?}", err);
+    }
     this
   }
 
@@ -105,13 +132,15 @@ impl FilterController {
     }
     let field_rev_by_field_id = self.get_filter_revs_map().await;
     row_revs.iter().for_each(|row_rev| {
-      let _ = filter_row(
+      if let Some((_, is_visible)) = filter_row(
         row_rev,
         &self.result_by_row_id,
         &field_rev_by_field_id,
         &self.cell_data_cache,
         &self.cell_filter_cache,
-      );
+      ) {
+        self.did_change_row_visibility(is_visible);
+      }
     });
 
     row_revs.retain(|row_rev| {
@@ -123,6 +152,45 @@ impl FilterController {
     });
   }
 
+  /// Returns whether the row passes each of the filters. The result that was stored when the row
+  /// was filtered is used if it exists, otherwise the filter is applied to the row without
+  /// updating the stored result.
+  pub async fn get_row_filter_results(
+    &self,
+    row_rev: &Arc<RowRevision>,
+  ) -> HashMap<FilterType, bool> {
+    let field_rev_by_field_id = self.get_filter_revs_map().await;
+    let mut visible_by_filter_type = HashMap::new();
+    for (field_id, field_rev) in field_rev_by_field_id.iter() {
+      let filter_type = FilterType::from(field_rev);
+      if !self.cell_filter_cache.read().contains(&filter_type) {
+        continue;
+      }
+
+      let stored_is_visible = self
+        .result_by_row_id
+        .get(&row_rev.id)
+        .and_then(|result| result.visible_by_filter_id.get(&filter_type).cloned());
+      let is_visible = match stored_is_visible {
+        Some(is_visible) => Some(is_visible),
+        None => evaluate_filter(
+          &filter_type,
+          field_id,
+          field_rev,
+          row_rev,
+          &field_rev_by_field_id,
+          &self.cell_data_cache,
+          &self.cell_filter_cache,
+        ),
+      };
+
+      if let Some(is_visible) = is_visible {
+        visible_by_filter_type.insert(filter_type, is_visible);
+      }
+    }
+    visible_by_filter_type
+  }
+
   async fn get_filter_revs_map(&self) -> HashMap<String, Arc<FieldRevision>> {
     self
       .delegate
@@ -143,7 +211,11 @@ impl FilterController {
   pub async fn process(&self, predicate: &str) -> FlowyResult<()> {
     let event_type = FilterEvent::from_str(predicate).unwrap();
     match event_type {
-      FilterEvent::FilterDidChanged => self.filter_all_rows().await?,
+      FilterEvent::FilterDidChanged => self.filter_all_rows(None).await?,
+      FilterEvent::FieldFiltersDidChanged(field_ids) => {
+        let field_ids = field_ids.into_iter().collect::<HashSet<FieldId>>();
+        self.filter_all_rows(Some(&field_ids)).await?
+      },
       FilterEvent::RowDidChanged(row_id) => self.filter_row(row_id).await?,
     }
     Ok(())
@@ -161,6 +233,7 @@ impl FilterController {
         &self.cell_data_cache,
         &self.cell_filter_cache,
       ) {
+        self.did_change_row_visibility(is_visible);
         if is_visible {
           if let Some((index, row_rev)) = self.delegate.get_row_rev(&row_id).await {
             let row_pb = RowPB::from(row_rev.as_ref());
@@ -172,6 +245,7 @@ impl FilterController {
           notification.invisible_rows.push(row_id);
         }
       }
+      notification.row_count = self.get_filtered_row_count().await;
 
       let _ = self
         .notifier
@@ -180,21 +254,36 @@ impl FilterController {
     Ok(())
   }
 
-  async fn filter_all_rows(&self) -> FlowyResult<()> {
+  /// Filters all the rows again. If the `changed_field_ids` is set, only the results of the
+  /// filters of these fields are evaluated again, see [refilter_row].
+  async fn filter_all_rows(&self, changed_field_ids: Option<&HashSet<FieldId>>) -> FlowyResult<()> {
     let field_rev_by_field_id = self.get_filter_revs_map().await;
-    for block in self.delegate.get_blocks().await.into_iter() {
+    let blocks = self.delegate.get_blocks().await;
+    let mut notifications = vec![];
+    for block in blocks.iter() {
       // The row_ids contains the row that its visibility was changed.
       let mut visible_rows = vec![];
       let mut invisible_rows = vec![];
 
       for (index, row_rev) in block.row_revs.iter().enumerate() {
-        if let Some((row_id, is_visible)) = filter_row(
-          row_rev,
-          &self.result_by_row_id,
-          &field_rev_by_field_id,
-          &self.cell_data_cache,
-          &self.cell_filter_cache,
-        ) {
+        let changed = match changed_field_ids {
+          None => filter_row(
+            row_rev,
+            &self.result_by_row_id,
+            &field_rev_by_field_id,
+            &self.cell_data_cache,
+            &self.cell_filter_cache,
+          ),
+          Some(changed_field_ids) => refilter_row(
+            row_rev,
+            changed_field_ids,
+            &self.result_by_row_id,
+            &field_rev_by_field_id,
+            &self.cell_data_cache,
+            &self.cell_filter_cache,
+          ),
+        };
+        if let Some((row_id, is_visible)) = changed {
           if is_visible {
             let row_pb = RowPB::from(row_rev.as_ref());
             visible_rows.push(InsertedRowPB::with_index(row_pb, index as i32))
@@ -204,12 +293,20 @@ impl FilterController {
         }
       }
 
-      let notification = FilterResultNotification {
+      notifications.push(FilterResultNotification {
         view_id: self.view_id.clone(),
-        block_id: block.block_id,
+        block_id: block.block_id.clone(),
         invisible_rows,
         visible_rows,
-      };
+        row_count: FilteredRowCount::default(),
+      });
+    }
+
+    // The counts cover all the blocks, so they are known after every block is filtered
+    let row_count = count_filtered_rows(&blocks, &self.result_by_row_id);
+    *self.row_count.write() = Some(row_count.clone());
+    for mut notification in notifications {
+      notification.row_count = row_count.clone();
       tracing::Span::current().record("filter_result", format!("{:?}", &notification).as_str());
       let _ = self
         .notifier
@@ -218,6 +315,104 @@ impl FilterController {
     Ok(())
   }
 
+  /// Returns the number of the visible and the hidden rows of the view. The rows that haven't
+  /// been filtered yet are counted as visible.
+  pub async fn get_filtered_row_count(&self) -> FilteredRowCount {
+    if let Some(row_count) = self.row_count.read().clone() {
+      return row_count;
+    }
+    let blocks = self.delegate.get_blocks().await;
+    let row_count = count_filtered_rows(&blocks, &self.result_by_row_id);
+    *self.row_count.write() = Some(row_count.clone());
+    row_count
+  }
+
+  /// The inserted row is counted as visible until it's filtered.
+  pub fn did_insert_row(&self) {
+    if let Some(row_count) = self.row_count.write().as_mut() {
+      row_count.visible += 1;
+    }
+  }
+
+  /// Drops the filter result of the deleted row and removes it from the counts.
+  pub fn did_delete_row(&self, row_id: &str) {
+    let is_visible = self
+      .result_by_row_id
+      .remove(row_id)
+      .map(|(_, result)| result.is_visible())
+      .unwrap_or(true);
+    if let Some(row_count) = self.row_count.write().as_mut() {
+      if is_visible {
+        row_count.visible = row_count.visible.saturating_sub(1);
+      } else {
+        row_count.hidden = row_count.hidden.saturating_sub(1);
+      }
+    }
+  }
+
+  /// Moves a row between the counts after filtering made it visible or hidden.
+  fn did_change_row_visibility(&self, is_visible: bool) {
+    if let Some(row_count) = self.row_count.write().as_mut() {
+      if is_visible {
+        row_count.hidden = row_count.hidden.saturating_sub(1);
+        row_count.visible += 1;
+      } else {
+        row_count.visible = row_count.visible.saturating_sub(1);
+        row_count.hidden += 1;
+      }
+    }
+  }
+
+  /// Returns the estimated bytes of the filter results of the rows and the cached filters.
+  pub fn estimated_bytes(&self) -> usize {
+    let result_bytes = self
+      .result_by_row_id
+      .iter()
+      .map(|entry| {
+        let visible_bytes = entry
+          .value()
+          .visible_by_filter_id
+          .keys()
+          .map(|filter_type| {
+            std::mem::size_of::<FilterType>()
+              + std::mem::size_of::<bool>()
+              + filter_type.field_id.len()
+          })
+          .sum::<usize>();
+        std::mem::size_of::<RowId>() + entry.key().len() + visible_bytes
+      })
+      .sum::<usize>();
+    result_bytes + self.cell_filter_cache.read().estimated_bytes()
+  }
+
+  /// Builds the filters from the `filter_revs` again and applies them to all the rows right away
+  /// instead of scheduling a task. The cached cell data is dropped, so the cells are decoded
+  /// again.
+  pub async fn recompute(&self, mut filter_revs: Vec<Arc<FilterRevision>>) -> FlowyResult<()> {
+    self.cell_data_cache.write().clear();
+    self.cell_filter_cache.write().clear();
+    filter_revs.extend(self.get_session_filters());
+    let result = self.refresh_filters(filter_revs).await;
+    self.filter_all_rows(None).await?;
+    result
+  }
+
+  /// Replaces all the filters with the ones built from the `filter_revs`, e.g. after a filter
+  /// preset is applied. The session filters are dropped too. The rows are filtered again in the
+  /// background.
+  pub async fn did_replace_filters(
+    &self,
+    filter_revs: Vec<Arc<FilterRevision>>,
+  ) -> FlowyResult<()> {
+    self.session_filters.write().clear();
+    self.cell_filter_cache.write().clear();
+    let result = self.refresh_filters(filter_revs).await;
+    self
+      .gen_task(FilterEvent::FilterDidChanged, QualityOfService::Background)
+      .await;
+    result
+  }
+
   pub async fn did_receive_row_changed(&self, row_id: &str) {
     self
       .gen_task(
@@ -227,26 +422,34 @@ impl FilterController {
       .await
   }
 
-  #[tracing::instrument(level = "trace", skip(self))]
+  /// Returns an error if the inserted or updated filter can't be applied, the filter is ignored
+  /// until it gets fixed.
+  #[tracing::instrument(level = "trace", skip(self), err)]
   pub async fn did_receive_changes(
     &self,
     changeset: FilterChangeset,
-  ) -> Option<FilterChangesetNotificationPB> {
+  ) -> FlowyResult<Option<FilterChangesetNotificationPB>> {
     let mut notification: Option<FilterChangesetNotificationPB> = None;
+    let mut result = Ok(());
+    // Only the results of the filters of the changed fields need to be evaluated again
+    let mut changed_field_ids = vec![];
     if let Some(filter_type) = &changeset.insert_filter {
+      changed_field_ids.push(filter_type.field_id.clone());
       if let Some(filter) = self.filter_from_filter_type(filter_type).await {
         notification = Some(FilterChangesetNotificationPB::from_insert(
           &self.view_id,
           vec![filter],
         ));
       }
-      if let Some(filter_rev) = self.delegate.get_filter_rev(filter_type.clone()).await {
-        self.refresh_filters(vec![filter_rev]).await;
+      if let Some(filter_rev) = self.get_filter_rev(filter_type.clone()).await {
+        result = self.refresh_filters(vec![filter_rev]).await;
       }
     }
 
     if let Some(updated_filter_type) = changeset.update_filter {
+      changed_field_ids.push(updated_filter_type.new.field_id.clone());
       if let Some(old_filter_type) = updated_filter_type.old {
+        changed_field_ids.push(old_filter_type.field_id.clone());
         let new_filter = self.filter_from_filter_type(&updated_filter_type.new).await;
         let old_filter = self.filter_from_filter_type(&old_filter_type).await;
 
@@ -257,12 +460,8 @@ impl FilterController {
         }
 
         // Update the corresponding filter in the cache
-        if let Some(filter_rev) = self
-          .delegate
-          .get_filter_rev(updated_filter_type.new.clone())
-          .await
-        {
-          self.refresh_filters(vec![filter_rev]).await;
+        if let Some(filter_rev) = self.get_filter_rev(updated_filter_type.new.clone()).await {
+          result = self.refresh_filters(vec![filter_rev]).await;
         }
 
         if let Some(filter_id) = filter_id {
@@ -278,6 +477,7 @@ impl FilterController {
     }
 
     if let Some(filter_type) = &changeset.delete_filter {
+      changed_field_ids.push(filter_type.field_id.clone());
       if let Some(filter) = self.filter_from_filter_type(filter_type).await {
         notification = Some(FilterChangesetNotificationPB::from_delete(
           &self.view_id,
@@ -288,73 +488,265 @@ impl FilterController {
     }
 
     self
-      .gen_task(FilterEvent::FilterDidChanged, QualityOfService::Background)
+      .gen_task(
+        FilterEvent::FieldFiltersDidChanged(changed_field_ids),
+        QualityOfService::Background,
+      )
       .await;
     tracing::trace!("{:?}", notification);
-    notification
+    result.map(|_| notification)
   }
 
   async fn filter_from_filter_type(&self, filter_type: &FilterType) -> Option<FilterPB> {
+    let is_session = self.session_filters.read().contains_key(filter_type);
     self
+      .get_filter_rev(filter_type.clone())
+      .await
+      .map(|filter_rev| {
+        let mut filter = FilterPB::from(filter_rev.as_ref());
+        filter.is_session = is_session;
+        filter
+      })
+  }
+
+  /// Returns the filter of the field. The session filter is returned if the field has one,
+  /// otherwise the filter saved in the view.
+  async fn get_filter_rev(&self, filter_type: FilterType) -> Option<Arc<FilterRevision>> {
+    let session_filter_rev = self.session_filters.read().get(&filter_type).cloned();
+    match session_filter_rev {
+      Some(filter_rev) => Some(filter_rev),
+      None => self.delegate.get_filter_rev(filter_type).await,
+    }
+  }
+
+  pub fn get_session_filters(&self) -> Vec<Arc<FilterRevision>> {
+    self.session_filters.read().values().cloned().collect()
+  }
+
+  /// Returns the session filter with the `filter_id` and the type of its field.
+  pub fn get_session_filter(&self, filter_id: &str) -> Option<(FilterType, Arc<FilterRevision>)> {
+    self
+      .session_filters
+      .read()
+      .iter()
+      .find(|(_, filter_rev)| filter_rev.id == filter_id)
+      .map(|(filter_type, filter_rev)| (filter_type.clone(), filter_rev.clone()))
+  }
+
+  /// Applies the filter for the current session without saving it in the view. The session
+  /// filter of the same field is replaced. A field only has one filter, so it returns an error
+  /// if the field has a saved filter.
+  pub async fn insert_session_filter(
+    &self,
+    filter_type: FilterType,
+    filter_rev: FilterRevision,
+  ) -> FlowyResult<Option<FilterChangesetNotificationPB>> {
+    if self
       .delegate
       .get_filter_rev(filter_type.clone())
       .await
-      .map(|filter| FilterPB::from(filter.as_ref()))
+      .is_some()
+    {
+      return Err(FlowyError::invalid_data().context(format!(
+        "The field {} already has a saved filter",
+        filter_type.field_id
+      )));
+    }
+    self.validate_filter(&filter_rev).await?;
+
+    let is_exist = self
+      .session_filters
+      .write()
+      .insert(filter_type.clone(), Arc::new(filter_rev))
+      .is_some();
+    let changeset = if is_exist {
+      FilterChangeset::from_update(UpdatedFilterType::new(
+        Some(filter_type.clone()),
+        filter_type,
+      ))
+    } else {
+      FilterChangeset::from_insert(filter_type)
+    };
+    self.did_receive_changes(changeset).await
+  }
+
+  /// Stops applying the session filter of the field, the rows are filtered again in the
+  /// background.
+  pub async fn remove_session_filter(
+    &self,
+    filter_type: &FilterType,
+  ) -> FlowyResult<Option<FilterChangesetNotificationPB>> {
+    if !self.session_filters.read().contains_key(filter_type) {
+      return Ok(None);
+    }
+    // The notification is made from the filter, so it's removed after the changes are received
+    let notification = self
+      .did_receive_changes(FilterChangeset::from_delete(filter_type.clone()))
+      .await;
+    self.session_filters.write().remove(filter_type);
+    notification
+  }
+
+  /// Removes the session filter of the field without changing the applied filters, e.g. after
+  /// the filter was saved in the view.
+  pub fn take_session_filter(&self, filter_type: &FilterType) -> Option<Arc<FilterRevision>> {
+    self.session_filters.write().remove(filter_type)
   }
 
+  /// Applies the first day of the week of the workspace to the date filters that don't specify
+  /// one, the rows are filtered again in the background.
+  pub async fn did_update_week_settings(&self) {
+    let first_day_of_week = self.delegate.get_week_settings().first_day_of_week as i32;
+    let mut is_changed = false;
+    {
+      let mut cell_filter_cache = self.cell_filter_cache.write();
+      for filter_type in self.workspace_week_filters.read().iter() {
+        if let Some(date_filter) = cell_filter_cache.get_mut::<DateFilterPB>(filter_type) {
+          if date_filter.first_day_of_week != Some(first_day_of_week) {
+            date_filter.first_day_of_week = Some(first_day_of_week);
+            is_changed = true;
+          }
+        }
+      }
+    }
+
+    if is_changed {
+      self
+        .gen_task(FilterEvent::FilterDidChanged, QualityOfService::Background)
+        .await;
+    }
+  }
+
+  /// Returns the first error of the filters that can't be applied, the other filters are still
+  /// refreshed.
   #[tracing::instrument(level = "trace", skip_all)]
-  async fn refresh_filters(&self, filter_revs: Vec<Arc<FilterRevision>>) {
+  async fn refresh_filters(&self, filter_revs: Vec<Arc<FilterRevision>>) -> FlowyResult<()> {
+    let mut result = Ok(());
     for filter_rev in filter_revs {
       if let Some(field_rev) = self.delegate.get_field_rev(&filter_rev.field_id).await {
         let filter_type = FilterType::from(&field_rev);
         tracing::trace!("Create filter with type: {:?}", filter_type);
-        match &filter_type.field_type {
-          FieldType::RichText => {
-            self.cell_filter_cache.write().insert(
-              &filter_type,
-              TextFilterPB::from_filter_rev(filter_rev.as_ref()),
-            );
-          },
-          FieldType::Number => {
-            self.cell_filter_cache.write().insert(
-              &filter_type,
-              NumberFilterPB::from_filter_rev(filter_rev.as_ref()),
-            );
-          },
-          FieldType::DateTime => {
-            self.cell_filter_cache.write().insert(
-              &filter_type,
-              DateFilterPB::from_filter_rev(filter_rev.as_ref()),
-            );
-          },
-          FieldType::SingleSelect | FieldType::MultiSelect => {
-            self.cell_filter_cache.write().insert(
-              &filter_type,
-              SelectOptionFilterPB::from_filter_rev(filter_rev.as_ref()),
-            );
-          },
-          FieldType::Checkbox => {
-            self.cell_filter_cache.write().insert(
-              &filter_type,
-              CheckboxFilterPB::from_filter_rev(filter_rev.as_ref()),
-            );
-          },
-          FieldType::URL => {
-            self.cell_filter_cache.write().insert(
-              &filter_type,
-              TextFilterPB::from_filter_rev(filter_rev.as_ref()),
-            );
-          },
-          FieldType::Checklist => {
-            self.cell_filter_cache.write().insert(
-              &filter_type,
-              ChecklistFilterPB::from_filter_rev(filter_rev.as_ref()),
-            );
+        if let Some(comparison) = FieldComparisonPB::from_filter_rev(&filter_rev) {
+          let inserted = self.insert_field_comparison(&filter_type, comparison).await;
+          if result.is_ok() {
+            result = inserted;
+          }
+          continue;
+        }
+        match get_type_option_plugin(&filter_type.field_type) {
+          None => tracing::error!(
+            "The type option of {} is not registered",
+            filter_type.field_type
+          ),
+          Some(plugin) => {
+            let inserted =
+              plugin.insert_cell_filter(&filter_type, filter_rev.as_ref(), &self.cell_filter_cache);
+            // The weeks of the date filters start on the first day of the week of the
+            // workspace unless the filter specifies one
+            if let Some(date_filter) = self
+              .cell_filter_cache
+              .write()
+              .get_mut::<DateFilterPB>(&filter_type)
+            {
+              if date_filter.first_day_of_week.is_none() {
+                let first_day_of_week = self.delegate.get_week_settings().first_day_of_week;
+                date_filter.first_day_of_week = Some(first_day_of_week as i32);
+                self
+                  .workspace_week_filters
+                  .write()
+                  .insert(filter_type.clone());
+              } else {
+                self.workspace_week_filters.write().remove(&filter_type);
+              }
+            }
+            if result.is_ok() {
+              result = inserted;
+            }
           },
         }
       }
     }
+    result
+  }
+
+  /// Returns an error if the filter can't be applied, e.g. the pattern of the text filter is
+  /// invalid or the compared fields have different field types. The filters are validated
+  /// before they are saved in the view.
+  pub async fn validate_filter(&self, filter_rev: &FilterRevision) -> FlowyResult<()> {
+    let field_type = match self.delegate.get_field_rev(&filter_rev.field_id).await {
+      None => return Err(FlowyError::field_record_not_found()),
+      Some(field_rev) => FieldType::from(field_rev.ty),
+    };
+    if let Some(comparison) = FieldComparisonPB::from_filter_rev(filter_rev) {
+      return self
+        .validate_field_comparison(&filter_rev.field_id, &field_type, &comparison)
+        .await;
+    }
+    match get_type_option_plugin(&field_type) {
+      None => Ok(()),
+      Some(plugin) => plugin.validate_cell_filter(filter_rev),
+    }
+  }
+
+  /// The other field must have the same field type, so their cells can be compared by the type
+  /// option of the field.
+  async fn validate_field_comparison(
+    &self,
+    field_id: &str,
+    field_type: &FieldType,
+    comparison: &FieldComparisonPB,
+  ) -> FlowyResult<()> {
+    let other_field_type = self
+      .delegate
+      .get_field_rev(&comparison.other_field_id)
+      .await
+      .map(|field_rev| FieldType::from(field_rev.ty));
+    if other_field_type.as_ref() != Some(field_type) {
+      return Err(FlowyError::invalid_data().context(format!(
+        "The field {} can't be compared with the field {}",
+        field_id, comparison.other_field_id
+      )));
+    }
+    Ok(())
+  }
+
+  async fn insert_field_comparison(
+    &self,
+    filter_type: &FilterType,
+    comparison: FieldComparisonPB,
+  ) -> FlowyResult<()> {
+    if let Err(err) = self
+      .validate_field_comparison(&filter_type.field_id, &filter_type.field_type, &comparison)
+      .await
+    {
+      self.cell_filter_cache.write().remove(filter_type);
+      return Err(err);
+    }
+    self
+      .cell_filter_cache
+      .write()
+      .insert(filter_type, comparison);
+    Ok(())
+  }
+}
+
+fn count_filtered_rows(
+  blocks: &[DatabaseBlockRowRevision],
+  result_by_row_id: &DashMap<RowId, FilterResult>,
+) -> FilteredRowCount {
+  let mut row_count = FilteredRowCount::default();
+  for row_rev in blocks.iter().flat_map(|block| block.row_revs.iter()) {
+    let is_visible = result_by_row_id
+      .get(&row_rev.id)
+      .map(|result| result.is_visible())
+      .unwrap_or(true);
+    if is_visible {
+      row_count.visible += 1;
+    } else {
+      row_count.hidden += 1;
+    }
   }
+  row_count
 }
 
 /// Returns None if there is no change in this row after applying the filter
@@ -380,13 +772,14 @@ fn filter_row(
       continue;
     }
 
-    let cell_rev = row_rev.cells.get(field_id);
     // if the visibility of the cell_rew is changed, which means the visibility of the
     // row is changed too.
-    if let Some(is_visible) = filter_cell(
+    if let Some(is_visible) = evaluate_filter(
       &filter_type,
+      field_id,
       field_rev,
-      cell_rev,
+      row_rev,
+      field_rev_by_field_id,
       cell_data_cache,
       cell_filter_cache,
     ) {
@@ -404,6 +797,114 @@ fn filter_row(
   }
 }
 
+/// Like [filter_row], but only the results of the filters of the `changed_field_ids` are
+/// dropped and evaluated again, the results of the other filters are reused.
+///
+/// A row that is hidden by a filter of another field stays hidden no matter what the changed
+/// filters return, so they are not evaluated for this row. Their results are left missing and
+/// get evaluated once the row isn't hidden by any other filter. For the same reason, the
+/// filters are evaluated one by one until one of them hides the row.
+///
+/// Returns None if there is no change in this row after applying the filter
+#[tracing::instrument(level = "trace", skip_all)]
+fn refilter_row(
+  row_rev: &Arc<RowRevision>,
+  changed_field_ids: &HashSet<FieldId>,
+  result_by_row_id: &DashMap<RowId, FilterResult>,
+  field_rev_by_field_id: &HashMap<FieldId, Arc<FieldRevision>>,
+  cell_data_cache: &AtomicCellDataCache,
+  cell_filter_cache: &AtomicCellFilterCache,
+) -> Option<(String, bool)> {
+  let mut filter_result = result_by_row_id
+    .entry(row_rev.id.clone())
+    .or_insert_with(FilterResult::default);
+  let old_is_visible = filter_result.is_visible();
+  filter_result
+    .visible_by_filter_id
+    .retain(|filter_type, _| !changed_field_ids.contains(&filter_type.field_id));
+
+  if filter_result.is_visible() {
+    for (field_id, field_rev) in field_rev_by_field_id {
+      let filter_type = FilterType::from(field_rev);
+      if filter_result
+        .visible_by_filter_id
+        .contains_key(&filter_type)
+        || !cell_filter_cache.read().contains(&filter_type)
+      {
+        continue;
+      }
+
+      if let Some(is_visible) = evaluate_filter(
+        &filter_type,
+        field_id,
+        field_rev,
+        row_rev,
+        field_rev_by_field_id,
+        cell_data_cache,
+        cell_filter_cache,
+      ) {
+        filter_result
+          .visible_by_filter_id
+          .insert(filter_type, is_visible);
+        if !is_visible {
+          break;
+        }
+      }
+    }
+  }
+
+  let is_visible = filter_result.is_visible();
+  if old_is_visible != is_visible {
+    Some((row_rev.id.clone(), is_visible))
+  } else {
+    None
+  }
+}
+
+/// Applies the filter of the `filter_type` to the row. Returns None if the filter can't be
+/// applied, e.g. the type option of the field is not registered.
+fn evaluate_filter(
+  filter_type: &FilterType,
+  field_id: &str,
+  field_rev: &Arc<FieldRevision>,
+  row_rev: &RowRevision,
+  field_rev_by_field_id: &HashMap<FieldId, Arc<FieldRevision>>,
+  cell_data_cache: &AtomicCellDataCache,
+  cell_filter_cache: &AtomicCellFilterCache,
+) -> Option<bool> {
+  let comparison = cell_filter_cache
+    .read()
+    .get::<FieldComparisonPB>(filter_type)
+    .cloned();
+  if let Some(comparison) = comparison {
+    return compare_cells(
+      filter_type,
+      &comparison,
+      row_rev,
+      field_rev,
+      field_rev_by_field_id,
+      cell_data_cache,
+      cell_filter_cache,
+    );
+  }
+
+  // The filters on the row metadata read the timestamps of the row instead of its cells
+  let row_meta_cell_rev = filter_type
+    .row_meta()
+    .and_then(|target| row_meta_cell_rev(target, row_rev));
+  let cell_rev = match filter_type.row_meta() {
+    Some(_) => row_meta_cell_rev.as_ref(),
+    None => row_rev.cells.get(field_id),
+  };
+  filter_cell(
+    filter_type,
+    field_rev,
+    cell_rev,
+    cell_data_cache,
+    cell_filter_cache,
+  )
+}
+
 // Returns None if there is no change in this cell after applying the filter
 // Returns Some if the visibility of the cell is changed
 
@@ -437,9 +938,52 @@ fn filter_cell(
   Some(is_visible)
 }
 
+/// Compares the cell of the field with the cell of the other field in the same row, like the
+/// sorts compare the cells of a field. The row is hidden if either of the cells is empty, and
+/// no row is hidden if the other field was deleted.
+fn compare_cells(
+  filter_type: &FilterType,
+  comparison: &FieldComparisonPB,
+  row_rev: &RowRevision,
+  field_rev: &Arc<FieldRevision>,
+  field_rev_by_field_id: &HashMap<FieldId, Arc<FieldRevision>>,
+  cell_data_cache: &AtomicCellDataCache,
+  cell_filter_cache: &AtomicCellFilterCache,
+) -> Option<bool> {
+  let other_field_rev = match field_rev_by_field_id.get(&comparison.other_field_id) {
+    None => return Some(true),
+    Some(other_field_rev) => other_field_rev,
+  };
+  let cell_str = |field_rev: &FieldRevision| {
+    let cell_rev = match RowMetaTarget::from_field_id(&field_rev.id) {
+      Some(target) => row_meta_cell_rev(target, row_rev),
+      None => row_rev.cells.get(&field_rev.id).cloned(),
+    }?;
+    TypeCellData::try_from(&cell_rev)
+      .ok()
+      .map(|type_cell_data| type_cell_data.cell_str)
+      .filter(|cell_str| !cell_str.is_empty())
+  };
+  let (left_cell_str, right_cell_str) = match (cell_str(field_rev), cell_str(other_field_rev)) {
+    (Some(left_cell_str), Some(right_cell_str)) => (left_cell_str, right_cell_str),
+    _ => return Some(false),
+  };
+
+  let handler = TypeOptionCellExt::new(
+    field_rev.as_ref(),
+    Some(cell_data_cache.clone()),
+    Some(cell_filter_cache.clone()),
+  )
+  .get_type_option_cell_data_handler(&filter_type.field_type)?;
+  let ordering = handler.handle_cell_compare(&left_cell_str, &right_cell_str, field_rev.as_ref());
+  Some(comparison.condition.is_visible(ordering))
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 enum FilterEvent {
   FilterDidChanged,
+  /// The filters of these fields were inserted, updated or deleted
+  FieldFiltersDidChanged(Vec<FieldId>),
   RowDidChanged(String),
 }
 
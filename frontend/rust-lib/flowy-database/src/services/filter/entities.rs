@@ -1,6 +1,7 @@
 use crate::entities::{
   AlterFilterParams, DatabaseSettingChangesetParams, DeleteFilterParams, FieldType, InsertedRowPB,
 };
+use crate::services::filter::RowMetaTarget;
 use database_model::{FieldRevision, FieldTypeRevision};
 use std::sync::Arc;
 
@@ -76,6 +77,14 @@ pub struct FilterType {
   pub field_type: FieldType,
 }
 
+impl FilterType {
+  /// Returns the metadata of the row that the filter targets, e.g. its created time, or None if
+  /// the filter targets the cells of a regular field.
+  pub fn row_meta(&self) -> Option<RowMetaTarget> {
+    RowMetaTarget::from_field_id(&self.field_id)
+  }
+}
+
 impl From<FilterType> for FieldTypeRevision {
   fn from(filter_type: FilterType) -> Self {
     filter_type.field_type.into()
@@ -116,6 +125,9 @@ pub struct FilterResultNotification {
 
   // Indicates there will be some new rows being invisible from visible state.
   pub invisible_rows: Vec<String>,
+
+  // The number of the visible and the hidden rows of the whole view after filtering.
+  pub row_count: FilteredRowCount,
 }
 
 impl FilterResultNotification {
@@ -125,6 +137,19 @@ impl FilterResultNotification {
       block_id,
       visible_rows: vec![],
       invisible_rows: vec![],
+      row_count: FilteredRowCount::default(),
     }
   }
 }
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FilteredRowCount {
+  pub visible: usize,
+  pub hidden: usize,
+}
+
+impl FilteredRowCount {
+  pub fn total(&self) -> usize {
+    self.visible + self.hidden
+  }
+}
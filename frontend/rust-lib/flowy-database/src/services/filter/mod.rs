@@ -1,7 +1,11 @@
 mod controller;
 mod entities;
+mod preset;
+mod row_meta;
 mod task;
 
 pub use controller::*;
 pub use entities::*;
+pub use preset::*;
+pub use row_meta::*;
 pub(crate) use task::*;
@@ -0,0 +1,85 @@
+use crate::entities::{FilterChangesetNotificationPB, FilterPB, UpdatedFilter};
+use database_model::FilterRevision;
+use std::sync::Arc;
+
+/// Returns the single notification that turns the `old_filters` of the view into the
+/// `new_filters`, e.g. after a filter preset is applied. The filters are matched by their ids,
+/// the filters that are in both and didn't change are left out.
+pub fn make_replaced_filters_notification(
+  view_id: &str,
+  old_filters: &[Arc<FilterRevision>],
+  new_filters: &[Arc<FilterRevision>],
+) -> FilterChangesetNotificationPB {
+  let mut notification = FilterChangesetNotificationPB {
+    view_id: view_id.to_owned(),
+    ..Default::default()
+  };
+  for new_filter in new_filters {
+    match old_filters
+      .iter()
+      .find(|old_filter| old_filter.id == new_filter.id)
+    {
+      None => notification
+        .insert_filters
+        .push(FilterPB::from(new_filter.as_ref())),
+      Some(old_filter) => {
+        if old_filter != new_filter {
+          notification.update_filters.push(UpdatedFilter {
+            filter_id: new_filter.id.clone(),
+            filter: Some(FilterPB::from(new_filter.as_ref())),
+          });
+        }
+      },
+    }
+  }
+  notification.delete_filters = old_filters
+    .iter()
+    .filter(|old_filter| {
+      !new_filters
+        .iter()
+        .any(|new_filter| new_filter.id == old_filter.id)
+    })
+    .map(|old_filter| FilterPB::from(old_filter.as_ref()))
+    .collect();
+  notification
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::entities::FieldType;
+
+  fn filter_rev(id: &str, content: &str) -> Arc<FilterRevision> {
+    Arc::new(FilterRevision {
+      id: id.to_owned(),
+      field_id: "field".to_owned(),
+      field_type: FieldType::RichText.into(),
+      condition: 0,
+      content: content.to_owned(),
+      case_sensitive: false,
+      other_field_id: None,
+    })
+  }
+
+  #[test]
+  fn replaced_filters_notification_test() {
+    let old_filters = vec![
+      filter_rev("a", "x"),
+      filter_rev("b", "y"),
+      filter_rev("c", "z"),
+    ];
+    let new_filters = vec![
+      filter_rev("a", "x"),
+      filter_rev("b", "changed"),
+      filter_rev("d", ""),
+    ];
+    let notification = make_replaced_filters_notification("view", &old_filters, &new_filters);
+    assert_eq!(notification.view_id, "view");
+    assert_eq!(notification.insert_filters.len(), 1);
+    assert_eq!(notification.insert_filters[0].id, "d");
+    assert_eq!(notification.update_filters.len(), 1);
+    assert_eq!(notification.update_filters[0].filter_id, "b");
+    assert_eq!(notification.delete_filters.len(), 1);
+    assert_eq!(notification.delete_filters[0].id, "c");
+  }
+}
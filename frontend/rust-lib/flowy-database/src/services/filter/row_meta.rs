@@ -0,0 +1,136 @@
+use crate::entities::FieldType;
+use crate::services::cell::TypeCellData;
+use crate::services::field::{DateCellData, FieldBuilder};
+use database_model::{CellRevision, FieldRevision, RowRevision};
+use std::sync::Arc;
+
+/// The id of the pseudo field that represents the created time of the row.
+pub const ROW_CREATED_AT_FIELD_ID: &str = "row_meta_created_at";
+/// The id of the pseudo field that represents the last modified time of the row.
+pub const ROW_MODIFIED_AT_FIELD_ID: &str = "row_meta_modified_at";
+
+/// Returns the pseudo fields of the row metadata. They are not stored in the database, but can be
+/// used as [FieldType::DateTime] fields when creating filters, e.g. filtering the rows created in
+/// the last 7 days.
+pub fn row_meta_field_revs() -> Vec<Arc<FieldRevision>> {
+  vec![
+    Arc::new(make_row_meta_field_rev(
+      ROW_CREATED_AT_FIELD_ID,
+      "Created at",
+    )),
+    Arc::new(make_row_meta_field_rev(
+      ROW_MODIFIED_AT_FIELD_ID,
+      "Modified at",
+    )),
+  ]
+}
+
+/// Appends the pseudo fields of the row metadata to the fields.
+pub fn with_row_meta_field_revs(field_revs: Vec<Arc<FieldRevision>>) -> Vec<Arc<FieldRevision>> {
+  let mut field_revs = field_revs;
+  field_revs.extend(row_meta_field_revs());
+  field_revs
+}
+
+pub fn get_row_meta_field_rev(field_id: &str) -> Option<Arc<FieldRevision>> {
+  row_meta_field_revs()
+    .into_iter()
+    .find(|field_rev| field_rev.id == field_id)
+}
+
+pub fn is_row_meta_field(field_id: &str) -> bool {
+  RowMetaTarget::from_field_id(field_id).is_some()
+}
+
+/// The row metadata that a filter targets instead of a cell of the row.
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Copy)]
+pub enum RowMetaTarget {
+  CreatedAt,
+  ModifiedAt,
+}
+
+impl RowMetaTarget {
+  /// Returns the target of the pseudo field, or None if the field is a regular field.
+  pub fn from_field_id(field_id: &str) -> Option<Self> {
+    match field_id {
+      ROW_CREATED_AT_FIELD_ID => Some(RowMetaTarget::CreatedAt),
+      ROW_MODIFIED_AT_FIELD_ID => Some(RowMetaTarget::ModifiedAt),
+      _ => None,
+    }
+  }
+
+  pub fn field_id(&self) -> &'static str {
+    match self {
+      RowMetaTarget::CreatedAt => ROW_CREATED_AT_FIELD_ID,
+      RowMetaTarget::ModifiedAt => ROW_MODIFIED_AT_FIELD_ID,
+    }
+  }
+
+  /// Returns the timestamp of the row in seconds, 0 if it wasn't recorded.
+  pub fn timestamp(&self, row_rev: &RowRevision) -> i64 {
+    match self {
+      RowMetaTarget::CreatedAt => row_rev.created_at,
+      RowMetaTarget::ModifiedAt => row_rev.modified_at,
+    }
+  }
+}
+
+/// Returns the date cell of the row metadata, or None if the timestamp of the row wasn't
+/// recorded.
+pub fn row_meta_cell_rev(target: RowMetaTarget, row_rev: &RowRevision) -> Option<CellRevision> {
+  let timestamp = target.timestamp(row_rev);
+  if timestamp == 0 {
+    return None;
+  }
+
+  let cell_data = DateCellData {
+    timestamp: Some(timestamp),
+    include_time: true,
+    recurrence: None,
+  };
+  let type_cell_data = TypeCellData::new(cell_data.to_string(), FieldType::DateTime);
+  Some(CellRevision::new(type_cell_data.to_json()))
+}
+
+fn make_row_meta_field_rev(field_id: &str, name: &str) -> FieldRevision {
+  let mut field_rev = FieldBuilder::from_field_type(&FieldType::DateTime)
+    .name(name)
+    .build();
+  field_rev.id = field_id.to_owned();
+  field_rev
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::services::filter::FilterType;
+
+  #[test]
+  fn row_meta_filter_type_test() {
+    let filter_type = FilterType {
+      field_id: ROW_CREATED_AT_FIELD_ID.to_owned(),
+      field_type: FieldType::DateTime,
+    };
+    assert_eq!(filter_type.row_meta(), Some(RowMetaTarget::CreatedAt));
+
+    let field_rev = get_row_meta_field_rev(ROW_MODIFIED_AT_FIELD_ID).unwrap();
+    assert_eq!(
+      FilterType::from(&field_rev).row_meta(),
+      Some(RowMetaTarget::ModifiedAt)
+    );
+    let filter_type = FilterType {
+      field_id: "field_1".to_owned(),
+      field_type: FieldType::DateTime,
+    };
+    assert_eq!(filter_type.row_meta(), None);
+  }
+
+  #[test]
+  fn row_meta_cell_rev_test() {
+    let mut row_rev = RowRevision::new("block_1");
+    row_rev.modified_at = 0;
+    assert!(row_meta_cell_rev(RowMetaTarget::CreatedAt, &row_rev).is_some());
+    // The modified time of the row wasn't recorded
+    assert!(row_meta_cell_rev(RowMetaTarget::ModifiedAt, &row_rev).is_none());
+  }
+}
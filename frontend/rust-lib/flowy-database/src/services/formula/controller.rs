@@ -0,0 +1,414 @@
+use crate::entities::{DatabaseChangePB, DatabaseChangeTypePB, FieldType};
+use crate::manager::DatabaseManager;
+use crate::services::cell::{stringify_cell_data, FromCellString, TypeCellData};
+use crate::services::database::DatabaseEditor;
+use crate::services::field::{
+  CheckboxCellData, DateCellData, FormulaCellData, FormulaContext, FormulaError, FormulaExpr,
+  FormulaTypeOptionPB, FormulaValue, NumberTypeOptionPB, RollupCellData,
+};
+use database_model::{FieldRevision, RowRevision};
+use flowy_error::FlowyResult;
+use lib_infra::util::timestamp;
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use std::sync::{Arc, Weak};
+
+/// The dependency of the formulas that call `now()`. It's not a field id, the formulas that
+/// depend on it are calculated again as the time passes.
+const NOW_DEPENDENCY: &str = "now()";
+
+/// The rows of a database whose formula cells are waiting to be calculated.
+enum PendingFormulaRows {
+  /// All the rows, e.g. after a field changed
+  All,
+  /// The formulas of all the rows that call `now()`, directly or through other formulas
+  Now,
+  /// The ids of the rows and the fields of their changed cells. None means all the formula
+  /// cells of the row are calculated, e.g. after the row was created.
+  Rows(HashMap<String, Option<HashSet<String>>>),
+}
+
+/// [FormulaController] keeps the formula cells of the opened databases up to date.
+///
+/// The changes of a database are collected until the calculation task of the database gets run,
+/// see [CalculationController](crate::services::calculation::CalculationController). The task
+/// only calculates the formula cells of the changed rows that depend on the changed cells,
+/// directly or through other formulas. Changing a field calculates all the rows again, because
+/// the field might be renamed or its formula might be changed. Only the cells whose value
+/// changed are saved.
+pub struct FormulaController {
+  database_manager: Weak<DatabaseManager>,
+  pending_rows: Mutex<HashMap<String, PendingFormulaRows>>,
+}
+
+impl FormulaController {
+  pub(crate) fn new(database_manager: Weak<DatabaseManager>) -> Self {
+    Self {
+      database_manager,
+      pending_rows: Mutex::new(HashMap::new()),
+    }
+  }
+
+  /// Collects the rows whose formula cells depend on the change.
+  pub(crate) fn did_change_database(&self, change: &DatabaseChangePB) {
+    let mut pending_rows = self.pending_rows.lock();
+    let rows = pending_rows
+      .entry(change.database_id.clone())
+      .or_insert_with(|| PendingFormulaRows::Rows(HashMap::new()));
+    match (&change.ty, rows) {
+      (DatabaseChangeTypePB::RowDeleted, _) | (_, PendingFormulaRows::All) => {},
+      (DatabaseChangeTypePB::RowCreated, PendingFormulaRows::Rows(rows)) => {
+        rows.insert(change.row_id.clone(), None);
+      },
+      (DatabaseChangeTypePB::RowUpdated, PendingFormulaRows::Rows(rows)) => {
+        if let Some(field_ids) = rows
+          .entry(change.row_id.clone())
+          .or_insert_with(|| Some(HashSet::new()))
+        {
+          field_ids.extend(change.field_ids.iter().cloned());
+        }
+      },
+      (_, rows) => *rows = PendingFormulaRows::All,
+    }
+  }
+
+  /// Collects the formulas that call `now()`, it's called periodically while the database is
+  /// opened. The pending changed rows are calculated together with all the rows.
+  pub(crate) fn did_pass_time(&self, database_id: &str) {
+    let mut pending_rows = self.pending_rows.lock();
+    let rows = pending_rows
+      .entry(database_id.to_owned())
+      .or_insert(PendingFormulaRows::Now);
+    if matches!(rows, PendingFormulaRows::Rows(_)) {
+      *rows = PendingFormulaRows::All;
+    }
+  }
+
+  /// Collects all the rows of the database, e.g. after some of its changes were dropped.
+  pub(crate) fn did_change_all_rows(&self, database_id: &str) {
+    self
+      .pending_rows
+      .lock()
+      .insert(database_id.to_owned(), PendingFormulaRows::All);
+  }
+
+  /// Calculates the formula cells of all the rows of the database right away instead of
+  /// waiting for the calculation task.
+  pub(crate) async fn recalculate(&self, database_id: &str) -> FlowyResult<()> {
+    self.did_change_all_rows(database_id);
+    self.process(database_id).await
+  }
+
+  #[tracing::instrument(name = "process_formula_task", level = "trace", skip(self), err)]
+  pub(crate) async fn process(&self, database_id: &str) -> FlowyResult<()> {
+    let pending_rows = match self.pending_rows.lock().remove(database_id) {
+      None => return Ok(()),
+      Some(pending_rows) => pending_rows,
+    };
+    let database_manager = match self.database_manager.upgrade() {
+      None => return Ok(()),
+      Some(database_manager) => database_manager,
+    };
+    let editor = match database_manager
+      .get_database_editor_with_database_id(database_id)
+      .await?
+    {
+      None => return Ok(()),
+      Some(editor) => editor,
+    };
+
+    let field_revs = editor.get_field_revs(None).await?;
+    let graph = FormulaGraph::new(&field_revs);
+    if graph.formulas.is_empty() {
+      return Ok(());
+    }
+
+    match pending_rows {
+      PendingFormulaRows::All => {
+        for block in editor.get_blocks(None).await? {
+          for row_rev in block.row_revs.iter() {
+            calculate_row(&editor, &graph, row_rev, None).await?;
+          }
+        }
+      },
+      PendingFormulaRows::Now => {
+        let changed_field_ids = HashSet::from([NOW_DEPENDENCY.to_owned()]);
+        if graph.affected_formulas(Some(&changed_field_ids)).is_empty() {
+          return Ok(());
+        }
+        for block in editor.get_blocks(None).await? {
+          for row_rev in block.row_revs.iter() {
+            calculate_row(&editor, &graph, row_rev, Some(&changed_field_ids)).await?;
+          }
+        }
+      },
+      PendingFormulaRows::Rows(rows) => {
+        for (row_id, changed_field_ids) in rows {
+          if let Some(row_rev) = editor.get_row_rev(&row_id).await? {
+            calculate_row(&editor, &graph, &row_rev, changed_field_ids.as_ref()).await?;
+          }
+        }
+      },
+    }
+    Ok(())
+  }
+}
+
+struct FormulaField {
+  field_rev: Arc<FieldRevision>,
+  /// None if the expression is blank, the cells of the blank formula are empty
+  expr: Option<Result<FormulaExpr, FormulaError>>,
+  /// The ids of the fields that the formula refers to, and [NOW_DEPENDENCY] if it calls `now()`
+  dependencies: HashSet<String>,
+}
+
+/// The formula fields of a database in the order they're calculated. Each formula comes after
+/// the formulas it refers to, so it's calculated with their latest values.
+struct FormulaGraph<'a> {
+  /// The fields that can be referred to by their names. If several fields have the same name,
+  /// the first one is referred to.
+  fields_by_name: HashMap<&'a str, &'a Arc<FieldRevision>>,
+  formulas: Vec<FormulaField>,
+  /// The ids of the formulas that refer to themselves, directly or through other formulas.
+  circular_field_ids: HashSet<String>,
+}
+
+impl<'a> FormulaGraph<'a> {
+  fn new(field_revs: &'a [Arc<FieldRevision>]) -> Self {
+    let mut fields_by_name = HashMap::new();
+    for field_rev in field_revs {
+      fields_by_name
+        .entry(field_rev.name.as_str())
+        .or_insert(field_rev);
+    }
+
+    let mut unsorted_formulas = field_revs
+      .iter()
+      .filter(|field_rev| FieldType::from(field_rev.ty).is_formula())
+      .map(|field_rev| {
+        let type_option = FormulaTypeOptionPB::from(field_rev.as_ref());
+        let expr = if type_option.expression.trim().is_empty() {
+          None
+        } else {
+          Some(type_option.parse_expression())
+        };
+        let field_names = match &expr {
+          Some(Ok(expr)) => expr.field_names(),
+          _ => vec![],
+        };
+        let mut dependencies = field_names
+          .iter()
+          .flat_map(|name| fields_by_name.get(name.as_str()))
+          .map(|field_rev| field_rev.id.clone())
+          .collect::<HashSet<String>>();
+        if matches!(&expr, Some(Ok(expr)) if expr.calls_now()) {
+          dependencies.insert(NOW_DEPENDENCY.to_owned());
+        }
+        FormulaField {
+          field_rev: field_rev.clone(),
+          expr,
+          dependencies,
+        }
+      })
+      .collect::<Vec<FormulaField>>();
+
+    // Sorts the formulas topologically, the remaining ones are part of a cycle or depend on it
+    let mut formulas = vec![];
+    loop {
+      let unsorted_ids = unsorted_formulas
+        .iter()
+        .map(|formula| formula.field_rev.id.clone())
+        .collect::<HashSet<String>>();
+      let (ready, remaining): (Vec<FormulaField>, Vec<FormulaField>) = unsorted_formulas
+        .into_iter()
+        .partition(|formula| formula.dependencies.is_disjoint(&unsorted_ids));
+      unsorted_formulas = remaining;
+      if ready.is_empty() {
+        break;
+      }
+      formulas.extend(ready);
+    }
+    let circular_field_ids = unsorted_formulas
+      .iter()
+      .map(|formula| formula.field_rev.id.clone())
+      .collect::<HashSet<String>>();
+    formulas.extend(unsorted_formulas);
+
+    Self {
+      fields_by_name,
+      formulas,
+      circular_field_ids,
+    }
+  }
+
+  /// Returns the formulas that depend on the changed fields, directly or through other
+  /// formulas. All the formulas are returned if `changed_field_ids` is None.
+  fn affected_formulas(&self, changed_field_ids: Option<&HashSet<String>>) -> Vec<&FormulaField> {
+    let changed_field_ids = match changed_field_ids {
+      None => return self.formulas.iter().collect(),
+      Some(changed_field_ids) => changed_field_ids,
+    };
+
+    let mut affected_field_ids = changed_field_ids.clone();
+    let mut formulas = vec![];
+    for formula in self.formulas.iter() {
+      if !formula.dependencies.is_disjoint(&affected_field_ids) {
+        affected_field_ids.insert(formula.field_rev.id.clone());
+        formulas.push(formula);
+      }
+    }
+    formulas
+  }
+}
+
+/// Calculates the formula cells of the row that depend on the changed cells, and saves the
+/// cells whose value changed.
+async fn calculate_row(
+  editor: &Arc<DatabaseEditor>,
+  graph: &FormulaGraph<'_>,
+  row_rev: &RowRevision,
+  changed_field_ids: Option<&HashSet<String>>,
+) -> FlowyResult<()> {
+  let formulas = graph.affected_formulas(changed_field_ids);
+  if formulas.is_empty() {
+    return Ok(());
+  }
+
+  let mut context = RowFormulaContext {
+    values: graph
+      .fields_by_name
+      .iter()
+      .map(|(name, field_rev)| {
+        (
+          name.to_string(),
+          formula_value_from_cell(field_rev, row_rev),
+        )
+      })
+      .collect(),
+    now: timestamp(),
+  };
+  for formula in formulas {
+    let field_rev = &formula.field_rev;
+    let cell_data = if graph.circular_field_ids.contains(&field_rev.id) {
+      FormulaCellData::from(Err(FormulaError("The formula refers to itself".to_owned())))
+    } else {
+      match &formula.expr {
+        None => FormulaCellData::default(),
+        Some(Ok(expr)) => FormulaCellData::from(expr.evaluate(&context)),
+        Some(Err(err)) => FormulaCellData::from(Err(err.clone())),
+      }
+    };
+
+    // The formulas that come later refer to the latest value
+    if graph
+      .fields_by_name
+      .get(field_rev.name.as_str())
+      .map(|named_field_rev| named_field_rev.id == field_rev.id)
+      .unwrap_or(false)
+    {
+      let value = match cell_data.error {
+        None => cell_data.value.clone(),
+        Some(_) => FormulaValue::Empty,
+      };
+      context.values.insert(field_rev.name.clone(), value);
+    }
+
+    let cell_str = cell_data.to_string();
+    let old_cell_str = row_rev
+      .cells
+      .get(&field_rev.id)
+      .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+      .filter(|type_cell_data| type_cell_data.is_formula())
+      .map(|type_cell_data| type_cell_data.cell_str)
+      .unwrap_or_else(|| FormulaCellData::default().to_string());
+    if old_cell_str != cell_str {
+      editor
+        .update_computed_cell(&row_rev.id, field_rev, cell_str)
+        .await?;
+    }
+  }
+  Ok(())
+}
+
+struct RowFormulaContext {
+  values: HashMap<String, FormulaValue>,
+  now: i64,
+}
+
+impl FormulaContext for RowFormulaContext {
+  fn field_value(&self, field_name: &str) -> Option<FormulaValue> {
+    self.values.get(field_name).cloned()
+  }
+
+  fn now(&self) -> i64 {
+    self.now
+  }
+}
+
+/// Returns the value of the cell that the formula refers to. The numbers, the dates and the
+/// checkboxes keep their values, the other cells are referred to by their content. The failed
+/// formula cells are empty.
+fn formula_value_from_cell(field_rev: &FieldRevision, row_rev: &RowRevision) -> FormulaValue {
+  let field_type: FieldType = field_rev.ty.into();
+  let type_cell_data = match row_rev
+    .cells
+    .get(&field_rev.id)
+    .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+  {
+    None => return FormulaValue::Empty,
+    Some(type_cell_data) => type_cell_data,
+  };
+
+  let number_value = |number: Option<Decimal>| number.map(FormulaValue::Number).unwrap_or_default();
+  if type_cell_data.field_type == field_type {
+    let cell_str = &type_cell_data.cell_str;
+    match field_type {
+      FieldType::Number => {
+        return NumberTypeOptionPB::from(field_rev)
+          .format_cell_data(cell_str)
+          .map(|cell_data| number_value(*cell_data.decimal()))
+          .unwrap_or_default();
+      },
+      FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
+        return DateCellData::from_cell_str(cell_str)
+          .ok()
+          .and_then(|cell_data| cell_data.timestamp)
+          .map(FormulaValue::Date)
+          .unwrap_or_default();
+      },
+      FieldType::Checkbox => {
+        let is_check = CheckboxCellData::from_str(cell_str)
+          .map(|cell_data| cell_data.is_check())
+          .unwrap_or(false);
+        return FormulaValue::Number(Decimal::from(is_check as i64));
+      },
+      FieldType::Rollup => {
+        return RollupCellData::from_cell_str(cell_str)
+          .map(|cell_data| number_value(cell_data.value))
+          .unwrap_or_default();
+      },
+      FieldType::Formula => {
+        return FormulaCellData::from_cell_str(cell_str)
+          .ok()
+          .filter(|cell_data| cell_data.error.is_none())
+          .map(|cell_data| cell_data.value)
+          .unwrap_or_default();
+      },
+      _ => {},
+    }
+  }
+
+  let display_str = stringify_cell_data(
+    type_cell_data.cell_str.clone(),
+    &type_cell_data.field_type,
+    &field_type,
+    field_rev,
+  );
+  if display_str.is_empty() {
+    FormulaValue::Empty
+  } else {
+    FormulaValue::Text(display_str)
+  }
+}
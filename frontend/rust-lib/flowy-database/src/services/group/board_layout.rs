@@ -0,0 +1,183 @@
+use crate::entities::{FieldType, GroupPB};
+use crate::services::cell::{stringify_cell_data, TypeCellData};
+use crate::services::field::{
+  select_type_option_from_field_rev, RelationCellData, SelectOptionColorPB, SelectOptionIds,
+  SelectOptionPB,
+};
+use database_model::{FieldRevision, RowRevision};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The materialized state of a board: its groups in the order they are shown, and the cards of
+/// each group with the display values of their cells. It's exported as JSON for the tools that
+/// render or print the board.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardLayout {
+  pub view_id: String,
+  pub group_field_id: String,
+  pub fields: Vec<BoardLayoutField>,
+  pub groups: Vec<BoardLayoutGroup>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardLayoutField {
+  pub id: String,
+  pub name: String,
+  pub field_type: FieldType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardLayoutGroup {
+  pub id: String,
+  pub name: String,
+  /// The color of the option that the group is made of, if the board is grouped by a select
+  /// field
+  pub color: Option<SelectOptionColorPB>,
+  pub is_default: bool,
+  pub cards: Vec<BoardLayoutCard>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardLayoutCard {
+  pub row_id: String,
+  /// The display value of the primary cell
+  pub title: String,
+  /// The non-empty cells of the visible fields, in the order of the fields
+  pub cells: Vec<BoardLayoutCell>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardLayoutCell {
+  pub field_id: String,
+  pub display_value: String,
+  /// The selected options with their colors, if the field is a select field
+  pub options: Vec<SelectOptionPB>,
+}
+
+/// Builds the layout of the board from the groups of its group controller. The hidden groups
+/// and the hidden fields are left out, as they are not shown on the board. The relation cells
+/// display the titles of the linked rows, which are looked up in `related_row_titles` by row id.
+pub(crate) fn make_board_layout(
+  view_id: &str,
+  groups: Vec<GroupPB>,
+  field_revs: &[Arc<FieldRevision>],
+  row_revs: &HashMap<String, Arc<RowRevision>>,
+  related_row_titles: &HashMap<String, String>,
+) -> BoardLayout {
+  let group_field_id = groups
+    .first()
+    .map(|group| group.field_id.clone())
+    .unwrap_or_default();
+  let group_options = field_revs
+    .iter()
+    .find(|field_rev| field_rev.id == group_field_id)
+    .filter(|field_rev| FieldType::from(field_rev.ty).is_select_option())
+    .and_then(|field_rev| select_type_option_from_field_rev(field_rev).ok())
+    .map(|type_option| type_option.options().clone())
+    .unwrap_or_default();
+  let field_revs = field_revs
+    .iter()
+    .filter(|field_rev| field_rev.visibility || field_rev.is_primary)
+    .collect::<Vec<_>>();
+
+  let groups = groups
+    .into_iter()
+    .filter(|group| group.is_visible)
+    .map(|group| BoardLayoutGroup {
+      color: group_options
+        .iter()
+        .find(|option| option.id == group.group_id)
+        .map(|option| option.color.clone()),
+      cards: group
+        .rows
+        .iter()
+        .flat_map(|row| row_revs.get(&row.id))
+        .map(|row_rev| make_board_layout_card(row_rev, &field_revs, related_row_titles))
+        .collect(),
+      id: group.group_id,
+      name: group.desc,
+      is_default: group.is_default,
+    })
+    .collect();
+
+  BoardLayout {
+    view_id: view_id.to_owned(),
+    group_field_id,
+    fields: field_revs
+      .iter()
+      .map(|field_rev| BoardLayoutField {
+        id: field_rev.id.clone(),
+        name: field_rev.name.clone(),
+        field_type: field_rev.ty.into(),
+      })
+      .collect(),
+    groups,
+  }
+}
+
+fn make_board_layout_card(
+  row_rev: &RowRevision,
+  field_revs: &[&Arc<FieldRevision>],
+  related_row_titles: &HashMap<String, String>,
+) -> BoardLayoutCard {
+  let mut card = BoardLayoutCard {
+    row_id: row_rev.id.clone(),
+    title: "".to_owned(),
+    cells: vec![],
+  };
+  for field_rev in field_revs {
+    let type_cell_data = match row_rev
+      .cells
+      .get(&field_rev.id)
+      .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+    {
+      None => continue,
+      Some(type_cell_data) => type_cell_data,
+    };
+    let field_type: FieldType = field_rev.ty.into();
+    let display_value = if field_type.is_relation() && type_cell_data.is_relation() {
+      // The rows that were deleted from the related database are skipped
+      RelationCellData::from(type_cell_data.cell_str.clone())
+        .row_ids
+        .iter()
+        .flat_map(|row_id| related_row_titles.get(row_id))
+        .cloned()
+        .collect::<Vec<String>>()
+        .join(", ")
+    } else {
+      stringify_cell_data(
+        type_cell_data.cell_str.clone(),
+        &type_cell_data.field_type,
+        &field_type,
+        field_rev,
+      )
+    };
+    if field_rev.is_primary {
+      card.title = display_value;
+      continue;
+    }
+    if display_value.is_empty() {
+      continue;
+    }
+
+    let mut options = vec![];
+    if field_type.is_select_option() && type_cell_data.is_select_option() {
+      if let Ok(type_option) = select_type_option_from_field_rev(field_rev) {
+        let option_ids = SelectOptionIds::from(type_cell_data.cell_str);
+        options = type_option
+          .options()
+          .iter()
+          .filter(|option| option_ids.contains(&option.id))
+          .cloned()
+          .collect();
+      }
+    }
+    card.cells.push(BoardLayoutCell {
+      field_id: field_rev.id.clone(),
+      display_value,
+      options,
+    });
+  }
+  card
+}
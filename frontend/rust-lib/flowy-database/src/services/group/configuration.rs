@@ -1,6 +1,7 @@
 use crate::entities::{GroupChangesetPB, GroupPB, InsertedGroupPB};
 use crate::services::field::RowSingleCellData;
 use crate::services::group::{default_group_configuration, GeneratedGroupContext, Group};
+use crate::services::setting::WeekSettings;
 use database_model::{
   FieldRevision, FieldTypeRevision, GroupConfigurationContentSerde, GroupConfigurationRevision,
   GroupRevision,
@@ -16,6 +17,8 @@ use std::sync::Arc;
 pub trait GroupConfigurationReader: Send + Sync + 'static {
   fn get_configuration(&self) -> Fut<Option<Arc<GroupConfigurationRevision>>>;
   fn get_configuration_cells(&self, field_id: &str) -> Fut<FlowyResult<Vec<RowSingleCellData>>>;
+  /// Returns the week settings of the workspace, the dates are grouped by the weeks.
+  fn get_week_settings(&self) -> WeekSettings;
 }
 
 pub trait GroupConfigurationWriter: Send + Sync + 'static {
@@ -62,7 +65,6 @@ pub struct GroupContext<C> {
 
   /// A reader that implement the [GroupConfigurationReader] trait
   ///
-  #[allow(dead_code)]
   reader: Arc<dyn GroupConfigurationReader>,
 
   /// A writer that implement the [GroupConfigurationWriter] trait is used to save the
@@ -251,11 +253,13 @@ where
 
     let mut new_groups = vec![];
     let mut filter_content_map = HashMap::new();
+    let mut color_map = HashMap::new();
     group_configs.into_iter().for_each(|generate_group| {
       filter_content_map.insert(
         generate_group.group_rev.id.clone(),
         generate_group.filter_content,
       );
+      color_map.insert(generate_group.group_rev.id.clone(), generate_group.color);
       new_groups.push(generate_group.group_rev);
     });
 
@@ -317,12 +321,13 @@ where
         .get(&group_rev.id)
         .cloned()
         .unwrap_or_else(|| "".to_owned());
-      let group = Group::new(
+      let mut group = Group::new(
         group_rev.id,
         self.field_rev.id.clone(),
         group_rev.name,
         filter_content,
       );
+      group.color = color_map.get(&group.id).cloned().flatten();
       self.groups_map.insert(group.id.clone(), group);
     });
 
@@ -330,12 +335,13 @@ where
       .into_iter()
       .flat_map(|group_rev| {
         let filter_content = filter_content_map.get(&group_rev.id)?;
-        let group = Group::new(
+        let mut group = Group::new(
           group_rev.id,
           self.field_rev.id.clone(),
           group_rev.name,
           filter_content.clone(),
         );
+        group.color = color_map.get(&group.id).cloned().flatten();
         Some(GroupPB::from(group))
       })
       .collect();
@@ -355,6 +361,127 @@ where
     }
   }
 
+  /// Applies the groups that are generated from the updated grouping field, e.g. after editing
+  /// the options of a select field. Unlike [GroupContext::init_groups], the groups keep their
+  /// rows: the groups of the new options are inserted, the groups of the removed options are
+  /// deleted and the renamed or recolored groups are updated.
+  #[tracing::instrument(level = "trace", skip(self, field_rev, generated_group_context), err)]
+  pub(crate) fn update_groups(
+    &mut self,
+    field_rev: Arc<FieldRevision>,
+    generated_group_context: GeneratedGroupContext,
+  ) -> FlowyResult<Option<GroupChangesetPB>> {
+    self.field_rev = field_rev;
+    let mut changeset = GroupChangesetPB {
+      view_id: self.view_id.clone(),
+      ..Default::default()
+    };
+    let group_configs = generated_group_context.group_configs;
+
+    // The rows of the deleted groups that are not in any other group move to the no status
+    // group
+    let deleted_group_ids = self
+      .groups_map
+      .values()
+      .filter(|group| !group.is_default)
+      .filter(|group| {
+        !group_configs
+          .iter()
+          .any(|config| config.group_rev.id == group.id)
+      })
+      .map(|group| group.id.clone())
+      .collect::<Vec<String>>();
+    let mut orphan_rows = vec![];
+    for group_id in deleted_group_ids.iter() {
+      if let Some(group) = self.groups_map.shift_remove(group_id) {
+        orphan_rows.extend(group.rows);
+      }
+    }
+    orphan_rows.retain(|row| {
+      !self
+        .groups_map
+        .values()
+        .any(|group| !group.is_default && group.contains_row(&row.id))
+    });
+    if let Some(no_status_group) = self.get_mut_no_status_group() {
+      let mut is_changed = false;
+      for row in orphan_rows {
+        if !no_status_group.contains_row(&row.id) {
+          no_status_group.add_row(row);
+          is_changed = true;
+        }
+      }
+      if is_changed {
+        changeset
+          .update_groups
+          .push(GroupPB::from(no_status_group.clone()));
+      }
+    }
+    changeset.deleted_groups = deleted_group_ids;
+
+    let mut inserted_group_revs = vec![];
+    let mut renamed_group_revs = vec![];
+    for config in group_configs {
+      let group_rev = config.group_rev;
+      match self.groups_map.get_mut(&group_rev.id) {
+        Some(group) => {
+          if group.name == group_rev.name && group.color == config.color {
+            continue;
+          }
+          if group.name != group_rev.name {
+            renamed_group_revs.push(group_rev.clone());
+          }
+          group.name = group_rev.name;
+          group.color = config.color;
+          changeset.update_groups.push(GroupPB::from(group.clone()));
+        },
+        None => {
+          let mut group = Group::new(
+            group_rev.id.clone(),
+            self.field_rev.id.clone(),
+            group_rev.name.clone(),
+            config.filter_content,
+          );
+          group.color = config.color;
+          self.groups_map.insert(group.id.clone(), group.clone());
+          changeset.inserted_groups.push(InsertedGroupPB {
+            group: GroupPB::from(group),
+            index: (self.groups_map.len() - 1) as i32,
+          });
+          inserted_group_revs.push(group_rev);
+        },
+      }
+    }
+
+    let deleted_group_ids = changeset.deleted_groups.clone();
+    self.mut_configuration(|configuration| {
+      let is_changed = !deleted_group_ids.is_empty()
+        || !renamed_group_revs.is_empty()
+        || !inserted_group_revs.is_empty();
+      configuration
+        .groups
+        .retain(|group| !deleted_group_ids.contains(&group.id));
+      for renamed_group_rev in renamed_group_revs {
+        if let Some(group_rev) = configuration
+          .groups
+          .iter_mut()
+          .find(|group_rev| group_rev.id == renamed_group_rev.id)
+        {
+          group_rev.name = renamed_group_rev.name;
+        }
+      }
+      configuration.groups.extend(inserted_group_revs);
+      is_changed
+    })?;
+
+    tracing::trace!("Group changeset: {:?}", changeset);
+    if changeset.is_empty() {
+      Ok(None)
+    } else {
+      Ok(Some(changeset))
+    }
+  }
+
   #[allow(dead_code)]
   pub(crate) async fn hide_group(&mut self, group_id: &str) -> FlowyResult<()> {
     self.mut_group_rev(group_id, |group_rev| {
@@ -371,6 +498,10 @@ where
     Ok(())
   }
 
+  pub(crate) fn week_settings(&self) -> WeekSettings {
+    self.reader.get_week_settings()
+  }
+
   pub(crate) async fn get_all_cells(&self) -> Vec<RowSingleCellData> {
     self
       .reader
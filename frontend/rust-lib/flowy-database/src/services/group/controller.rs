@@ -1,5 +1,6 @@
 use crate::entities::{GroupChangesetPB, GroupRowsNotificationPB, InsertedRowPB, RowPB};
 use crate::services::cell::{get_type_cell_protobuf, CellProtobufBlobParser, DecodedCellData};
+use crate::services::field::SelectOptionColorPB;
 
 use crate::services::group::action::{
   DidMoveGroupRowResult, DidUpdateGroupRowResult, GroupControllerActions, GroupCustomize,
@@ -52,6 +53,7 @@ pub struct GeneratedGroupContext {
 pub struct GeneratedGroupConfig {
   pub group_rev: GroupRevision,
   pub filter_content: String,
+  pub color: Option<SelectOptionColorPB>,
 }
 
 pub struct MoveGroupRowContext<'a> {
@@ -299,7 +301,7 @@ where
       }
     }
 
-    match self.group_ctx.get_no_status_group() {
+    match self.group_ctx.get_mut_no_status_group() {
       None => {
         tracing::error!("Unexpected None value. It should have the no status group");
       },
@@ -307,6 +309,7 @@ where
         if !no_status_group.contains_row(&row_rev.id) {
           tracing::error!("The row: {} should be in the no status group", row_rev.id);
         }
+        no_status_group.remove_row(&row_rev.id);
         result.row_changesets = vec![GroupRowsNotificationPB::delete(
           no_status_group.id.clone(),
           vec![row_rev.id.clone()],
@@ -340,9 +343,14 @@ where
 
   fn did_update_group_field(
     &mut self,
-    _field_rev: &FieldRevision,
+    field_rev: &FieldRevision,
   ) -> FlowyResult<Option<GroupChangesetPB>> {
-    Ok(None)
+    let type_option = field_rev.get_type_option::<T>(field_rev.ty);
+    let generated_group_context = G::generate_groups(field_rev, &self.group_ctx, &type_option);
+    self.type_option = type_option;
+    self
+      .group_ctx
+      .update_groups(Arc::new(field_rev.clone()), generated_group_context)
   }
 }
 
@@ -159,11 +159,13 @@ impl GroupGenerator for CheckboxGroupGenerator {
     let check_group = GeneratedGroupConfig {
       group_rev: GroupRevision::new(CHECK.to_string(), "".to_string()),
       filter_content: CHECK.to_string(),
+      color: None,
     };
 
     let uncheck_group = GeneratedGroupConfig {
       group_rev: GroupRevision::new(UNCHECK.to_string(), "".to_string()),
       filter_content: UNCHECK.to_string(),
+      color: None,
     };
 
     GeneratedGroupContext {
@@ -0,0 +1,299 @@
+use crate::entities::{GroupPB, GroupRowsNotificationPB, InsertedGroupPB, InsertedRowPB, RowPB};
+use crate::services::cell::{get_type_cell_protobuf, insert_date_cell, DecodedCellData};
+use crate::services::field::{
+  DateCellData, DateCellDataPB, DateCellDataParser, DateTimezone, DateTypeOptionPB,
+};
+use crate::services::group::action::GroupCustomize;
+use crate::services::group::configuration::GroupContext;
+use crate::services::group::controller::{
+  GenericGroupController, GroupController, GroupGenerator, MoveGroupRowContext,
+};
+use crate::services::group::{
+  make_no_status_group, move_group_row, GeneratedGroupConfig, GeneratedGroupContext,
+};
+use crate::services::setting::WeekSettings;
+use chrono::{Duration, NaiveDate, NaiveTime};
+use database_model::{
+  CellRevision, DateGroupConfigurationRevision, FieldRevision, GroupRevision, RowRevision,
+};
+use flowy_error::FlowyResult;
+use std::collections::BTreeSet;
+
+/// The id of a date group is the first day of its week, e.g. "2023-03-13"
+const DATE_GROUP_ID_FORMAT: &str = "%Y-%m-%d";
+
+/// Groups the rows by the week of their date. The weeks start on the first day of the week of the
+/// workspace, so the groups are generated again when the week settings are updated.
+pub type DateGroupController = GenericGroupController<
+  DateGroupConfigurationRevision,
+  DateTypeOptionPB,
+  DateGroupGenerator,
+  DateCellDataParser,
+>;
+
+pub type DateGroupContext = GroupContext<DateGroupConfigurationRevision>;
+
+impl DateGroupController {
+  fn group_id(&self, cell_data: &DateCellDataPB) -> Option<String> {
+    if cell_data.is_empty() {
+      return None;
+    }
+    let start_of_week = start_of_week(
+      cell_data.timestamp,
+      &timezone_of(&self.type_option),
+      &self.group_ctx.week_settings(),
+    )?;
+    Some(start_of_week.format(DATE_GROUP_ID_FORMAT).to_string())
+  }
+}
+
+impl GroupCustomize for DateGroupController {
+  type CellData = DateCellDataPB;
+
+  fn can_group(&self, content: &str, cell_data: &Self::CellData) -> bool {
+    self.group_id(cell_data).as_deref() == Some(content)
+  }
+
+  fn create_or_delete_group_when_cell_changed(
+    &mut self,
+    row_rev: &RowRevision,
+    old_cell_data: Option<&Self::CellData>,
+    cell_data: &Self::CellData,
+  ) -> FlowyResult<(Option<InsertedGroupPB>, Option<GroupPB>)> {
+    let group_id = self.group_id(cell_data);
+    let mut inserted_group = None;
+    if let Some(group_id) = group_id.as_ref() {
+      if self.group_ctx.get_group(group_id).is_none() {
+        if let Some(group_rev) = make_week_group(group_id) {
+          let mut new_group = self.group_ctx.add_new_group(group_rev)?;
+          new_group.group.rows.push(RowPB::from(row_rev));
+          inserted_group = Some(new_group);
+        }
+      }
+    }
+
+    // Delete the group of the old week if the row was the last one in it
+    let deleted_group = old_cell_data
+      .and_then(|old_cell_data| self.group_id(old_cell_data))
+      .filter(|old_group_id| Some(old_group_id) != group_id.as_ref())
+      .and_then(|old_group_id| self.group_ctx.get_group(&old_group_id))
+      .filter(|(_, group)| group.rows.len() == 1)
+      .map(|(_, group)| group.clone());
+
+    let deleted_group = match deleted_group {
+      None => None,
+      Some(group) => {
+        self.group_ctx.delete_group(&group.id)?;
+        Some(GroupPB::from(group))
+      },
+    };
+
+    Ok((inserted_group, deleted_group))
+  }
+
+  fn add_or_remove_row_when_cell_changed(
+    &mut self,
+    row_rev: &RowRevision,
+    cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let group_id = self.group_id(cell_data);
+    let mut changesets = vec![];
+    self.group_ctx.iter_mut_status_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if Some(&group.id) == group_id.as_ref() {
+        if !group.contains_row(&row_rev.id) {
+          let row_pb = RowPB::from(row_rev);
+          changeset
+            .inserted_rows
+            .push(InsertedRowPB::new(row_pb.clone()));
+          group.add_row(row_pb);
+        }
+      } else if group.contains_row(&row_rev.id) {
+        changeset.deleted_rows.push(row_rev.id.clone());
+        group.remove_row(&row_rev.id);
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn delete_row(
+    &mut self,
+    row_rev: &RowRevision,
+    _cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    self.group_ctx.iter_mut_groups(|group| {
+      let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+      if group.contains_row(&row_rev.id) {
+        changeset.deleted_rows.push(row_rev.id.clone());
+        group.remove_row(&row_rev.id);
+      }
+
+      if !changeset.is_empty() {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn move_row(
+    &mut self,
+    _cell_data: &Self::CellData,
+    mut context: MoveGroupRowContext,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut group_changeset = vec![];
+    self.group_ctx.iter_mut_groups(|group| {
+      if let Some(changeset) = move_group_row(group, &mut context) {
+        group_changeset.push(changeset);
+      }
+    });
+    group_changeset
+  }
+
+  fn delete_group_when_move_row(
+    &mut self,
+    _row_rev: &RowRevision,
+    cell_data: &Self::CellData,
+  ) -> Option<GroupPB> {
+    let group_id = self.group_id(cell_data)?;
+    let deleted_group = match self.group_ctx.get_group(&group_id) {
+      Some((_, group)) if group.rows.len() == 1 => GroupPB::from(group.clone()),
+      _ => return None,
+    };
+    let _ = self.group_ctx.delete_group(&deleted_group.group_id);
+    Some(deleted_group)
+  }
+}
+
+impl GroupController for DateGroupController {
+  fn will_create_row(
+    &mut self,
+    row_rev: &mut RowRevision,
+    field_rev: &FieldRevision,
+    group_id: &str,
+  ) {
+    match self.group_ctx.get_group(group_id) {
+      None => tracing::warn!("Can not find the group: {}", group_id),
+      Some((_, group)) => {
+        if let Some(cell_rev) = make_date_group_cell(&group.id, row_rev, field_rev) {
+          row_rev.cells.insert(field_rev.id.clone(), cell_rev);
+        }
+      },
+    }
+  }
+
+  fn did_create_row(&mut self, row_pb: &RowPB, group_id: &str) {
+    if let Some(group) = self.group_ctx.get_mut_group(group_id) {
+      group.add_row(row_pb.clone())
+    }
+  }
+}
+
+pub struct DateGroupGenerator();
+impl GroupGenerator for DateGroupGenerator {
+  type Context = DateGroupContext;
+  type TypeOptionType = DateTypeOptionPB;
+
+  fn generate_groups(
+    field_rev: &FieldRevision,
+    group_ctx: &Self::Context,
+    type_option: &Option<Self::TypeOptionType>,
+  ) -> GeneratedGroupContext {
+    // Read all the cells for the grouping field
+    let cells = futures::executor::block_on(group_ctx.get_all_cells());
+    let timezone = timezone_of(type_option);
+    let week_settings = group_ctx.week_settings();
+
+    // The ids are formatted dates, so the weeks are sorted in chronological order
+    let group_configs = cells
+      .into_iter()
+      .flat_map(|value| value.into_date_field_cell_data())
+      .flat_map(|cell| cell.timestamp)
+      .flat_map(|timestamp| start_of_week(timestamp, &timezone, &week_settings))
+      .map(|start_of_week| start_of_week.format(DATE_GROUP_ID_FORMAT).to_string())
+      .collect::<BTreeSet<String>>()
+      .into_iter()
+      .flat_map(|group_id| {
+        Some(GeneratedGroupConfig {
+          group_rev: make_week_group(&group_id)?,
+          filter_content: group_id,
+          color: None,
+        })
+      })
+      .collect();
+
+    let no_status_group = Some(make_no_status_group(field_rev));
+    GeneratedGroupContext {
+      no_status_group,
+      group_configs,
+    }
+  }
+}
+
+/// Returns the cell that moves the date of the row to the first day of the week of the group. The
+/// time of the date is kept.
+pub fn make_date_group_cell(
+  group_id: &str,
+  row_rev: &RowRevision,
+  field_rev: &FieldRevision,
+) -> Option<CellRevision> {
+  let start_of_week = NaiveDate::parse_from_str(group_id, DATE_GROUP_ID_FORMAT).ok()?;
+  let timezone = timezone_of(&field_rev.get_type_option::<DateTypeOptionPB>(field_rev.ty));
+  let old_cell_data = row_rev
+    .cells
+    .get(&field_rev.id)
+    .and_then(|cell_rev| {
+      get_type_cell_protobuf(cell_rev.type_cell_data.clone(), field_rev, None)
+        .1
+        .parser::<DateCellDataParser>()
+        .ok()
+    })
+    .filter(|cell_data| !cell_data.is_empty());
+
+  let time = old_cell_data
+    .as_ref()
+    .and_then(|cell_data| timezone.naive_local(cell_data.timestamp))
+    .map(|date_time| date_time.time())
+    .unwrap_or_else(|| NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+  let timestamp = timezone.timestamp(&start_of_week.and_time(time))?;
+  let cell_data = DateCellData {
+    timestamp: Some(timestamp),
+    include_time: old_cell_data
+      .map(|cell_data| cell_data.include_time)
+      .unwrap_or(false),
+    recurrence: None,
+  };
+  Some(insert_date_cell(cell_data, field_rev))
+}
+
+fn make_week_group(group_id: &str) -> Option<GroupRevision> {
+  let start_of_week = NaiveDate::parse_from_str(group_id, DATE_GROUP_ID_FORMAT).ok()?;
+  let end_of_week = start_of_week + Duration::days(6);
+  let group_name = format!(
+    "{} - {}",
+    start_of_week.format(DATE_GROUP_ID_FORMAT),
+    end_of_week.format(DATE_GROUP_ID_FORMAT)
+  );
+  Some(GroupRevision::new(group_id.to_owned(), group_name))
+}
+
+/// The dates are grouped in the timezone of the field
+fn timezone_of(type_option: &Option<DateTypeOptionPB>) -> DateTimezone {
+  type_option
+    .as_ref()
+    .map(|type_option| type_option.timezone())
+    .unwrap_or(DateTimezone::Local)
+}
+
+fn start_of_week(
+  timestamp: i64,
+  timezone: &DateTimezone,
+  week_settings: &WeekSettings,
+) -> Option<NaiveDate> {
+  let date = timezone.naive_local(timestamp)?.date();
+  Some(week_settings.start_of_week(date))
+}
@@ -1,7 +1,9 @@
 mod multi_select_controller;
+mod person_controller;
 mod single_select_controller;
 mod util;
 
 pub use multi_select_controller::*;
+pub use person_controller::*;
 pub use single_select_controller::*;
 pub use util::*;
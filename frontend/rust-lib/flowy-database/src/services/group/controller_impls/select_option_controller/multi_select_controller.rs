@@ -1,5 +1,6 @@
 use crate::entities::{GroupRowsNotificationPB, RowPB};
 use crate::services::cell::insert_select_option_cell;
+use crate::services::field::parent_option_ids_in_cells;
 use crate::services::field::{
   MultiSelectTypeOptionPB, SelectOptionCellDataPB, SelectOptionCellDataParser,
 };
@@ -84,7 +85,9 @@ impl GroupController for MultiSelectGroupController {
     match self.group_ctx.get_group(group_id) {
       None => tracing::warn!("Can not find the group: {}", group_id),
       Some((_, group)) => {
-        let cell_rev = insert_select_option_cell(vec![group.id.clone()], field_rev);
+        let parent_option_ids = parent_option_ids_in_cells(field_rev, &row_rev.cells);
+        let cell_rev =
+          insert_select_option_cell(vec![group.id.clone()], &parent_option_ids, field_rev);
         row_rev.cells.insert(field_rev.id.clone(), cell_rev);
       },
     }
@@ -0,0 +1,116 @@
+use crate::entities::{GroupRowsNotificationPB, RowPB};
+use crate::services::cell::insert_person_cell;
+use crate::services::field::{PersonCellDataPB, PersonCellDataParser, PersonTypeOptionPB};
+use crate::services::group::action::GroupCustomize;
+
+use crate::services::group::controller::{
+  GenericGroupController, GroupController, GroupGenerator, MoveGroupRowContext,
+};
+use crate::services::group::controller_impls::select_option_controller::util::*;
+
+use crate::services::group::{make_no_status_group, GeneratedGroupContext};
+use database_model::{FieldRevision, RowRevision, SelectOptionGroupConfigurationRevision};
+
+// Person
+pub type PersonGroupController = GenericGroupController<
+  SelectOptionGroupConfigurationRevision,
+  PersonTypeOptionPB,
+  PersonGroupGenerator,
+  PersonCellDataParser,
+>;
+
+impl GroupCustomize for PersonGroupController {
+  type CellData = PersonCellDataPB;
+
+  fn can_group(&self, content: &str, cell_data: &PersonCellDataPB) -> bool {
+    cell_data.persons.iter().any(|person| person.id == content)
+  }
+
+  fn add_or_remove_row_when_cell_changed(
+    &mut self,
+    row_rev: &RowRevision,
+    cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    self.group_ctx.iter_mut_status_groups(|group| {
+      if let Some(changeset) = add_or_remove_person_row(group, cell_data, row_rev) {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn delete_row(
+    &mut self,
+    row_rev: &RowRevision,
+    cell_data: &Self::CellData,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut changesets = vec![];
+    self.group_ctx.iter_mut_status_groups(|group| {
+      if let Some(changeset) = remove_person_row(group, cell_data, row_rev) {
+        changesets.push(changeset);
+      }
+    });
+    changesets
+  }
+
+  fn move_row(
+    &mut self,
+    _cell_data: &Self::CellData,
+    mut context: MoveGroupRowContext,
+  ) -> Vec<GroupRowsNotificationPB> {
+    let mut group_changeset = vec![];
+    self.group_ctx.iter_mut_groups(|group| {
+      if let Some(changeset) = move_group_row(group, &mut context) {
+        group_changeset.push(changeset);
+      }
+    });
+    group_changeset
+  }
+}
+
+impl GroupController for PersonGroupController {
+  fn will_create_row(
+    &mut self,
+    row_rev: &mut RowRevision,
+    field_rev: &FieldRevision,
+    group_id: &str,
+  ) {
+    match self.group_ctx.get_group(group_id) {
+      None => tracing::warn!("Can not find the group: {}", group_id),
+      Some((_, group)) => {
+        let cell_rev = insert_person_cell(vec![group.id.clone()], field_rev);
+        row_rev.cells.insert(field_rev.id.clone(), cell_rev);
+      },
+    }
+  }
+
+  fn did_create_row(&mut self, row_pb: &RowPB, group_id: &str) {
+    if let Some(group) = self.group_ctx.get_mut_group(group_id) {
+      group.add_row(row_pb.clone())
+    }
+  }
+}
+
+/// Generates a group for each member of the workspace that can be assigned to the cells.
+pub struct PersonGroupGenerator();
+impl GroupGenerator for PersonGroupGenerator {
+  type Context = SelectOptionGroupContext;
+  type TypeOptionType = PersonTypeOptionPB;
+
+  fn generate_groups(
+    field_rev: &FieldRevision,
+    _group_ctx: &Self::Context,
+    type_option: &Option<Self::TypeOptionType>,
+  ) -> GeneratedGroupContext {
+    let group_configs = match type_option {
+      None => vec![],
+      Some(type_option) => generate_person_groups(&type_option.members),
+    };
+
+    GeneratedGroupContext {
+      no_status_group: Some(make_no_status_group(field_rev)),
+      group_configs,
+    }
+  }
+}
@@ -1,5 +1,6 @@
 use crate::entities::{GroupRowsNotificationPB, RowPB};
 use crate::services::cell::insert_select_option_cell;
+use crate::services::field::parent_option_ids_in_cells;
 use crate::services::field::{
   SelectOptionCellDataPB, SelectOptionCellDataParser, SingleSelectTypeOptionPB,
 };
@@ -85,7 +86,9 @@ impl GroupController for SingleSelectGroupController {
     match group {
       None => {},
       Some(group) => {
-        let cell_rev = insert_select_option_cell(vec![group.id.clone()], field_rev);
+        let parent_option_ids = parent_option_ids_in_cells(field_rev, &row_rev.cells);
+        let cell_rev =
+          insert_select_option_cell(vec![group.id.clone()], &parent_option_ids, field_rev);
         row_rev.cells.insert(field_rev.id.clone(), cell_rev);
       },
     }
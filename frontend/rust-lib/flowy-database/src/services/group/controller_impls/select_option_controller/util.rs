@@ -1,9 +1,14 @@
 use crate::entities::{FieldType, GroupRowsNotificationPB, InsertedRowPB, RowPB};
-use crate::services::cell::{insert_checkbox_cell, insert_select_option_cell, insert_url_cell};
-use crate::services::field::{SelectOptionCellDataPB, SelectOptionPB, CHECK};
+use crate::services::cell::{
+  insert_checkbox_cell, insert_person_cell, insert_select_option_cell, insert_url_cell,
+};
+use crate::services::field::{
+  parent_option_ids_in_cells, PersonCellDataPB, PersonPB, SelectOptionCellDataPB, SelectOptionPB,
+  CHECK,
+};
 use crate::services::group::configuration::GroupContext;
 use crate::services::group::controller::MoveGroupRowContext;
-use crate::services::group::{GeneratedGroupConfig, Group};
+use crate::services::group::{make_date_group_cell, GeneratedGroupConfig, Group};
 use database_model::{
   CellRevision, FieldRevision, GroupRevision, RowRevision, SelectOptionGroupConfigurationRevision,
 };
@@ -65,6 +70,52 @@ pub fn remove_select_option_row(
   }
 }
 
+pub fn add_or_remove_person_row(
+  group: &mut Group,
+  cell_data: &PersonCellDataPB,
+  row_rev: &RowRevision,
+) -> Option<GroupRowsNotificationPB> {
+  let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+  // The row is in the group of each member that is assigned to it
+  if cell_data.persons.iter().any(|person| person.id == group.id) {
+    if !group.contains_row(&row_rev.id) {
+      let row_pb = RowPB::from(row_rev);
+      changeset
+        .inserted_rows
+        .push(InsertedRowPB::new(row_pb.clone()));
+      group.add_row(row_pb);
+    }
+  } else if group.contains_row(&row_rev.id) {
+    changeset.deleted_rows.push(row_rev.id.clone());
+    group.remove_row(&row_rev.id);
+  }
+
+  if changeset.is_empty() {
+    None
+  } else {
+    Some(changeset)
+  }
+}
+
+pub fn remove_person_row(
+  group: &mut Group,
+  cell_data: &PersonCellDataPB,
+  row_rev: &RowRevision,
+) -> Option<GroupRowsNotificationPB> {
+  let mut changeset = GroupRowsNotificationPB::new(group.id.clone());
+  if cell_data.persons.iter().any(|person| person.id == group.id) && group.contains_row(&row_rev.id)
+  {
+    changeset.deleted_rows.push(row_rev.id.clone());
+    group.remove_row(&row_rev.id);
+  }
+
+  if changeset.is_empty() {
+    None
+  } else {
+    Some(changeset)
+  }
+}
+
 pub fn move_group_row(
   group: &mut Group,
   context: &mut MoveGroupRowContext,
@@ -118,7 +169,7 @@ pub fn move_group_row(
     // If the from_index is none which means the row is not belong to this group before and
     // it is moved from other groups.
     if from_index.is_none() {
-      let cell_rev = make_inserted_cell_rev(&group.id, field_rev);
+      let cell_rev = make_inserted_cell_rev(&group.id, row_rev, field_rev);
       if let Some(cell_rev) = cell_rev {
         tracing::debug!(
           "Update content of the cell in the row:{} to group:{}",
@@ -138,25 +189,36 @@ pub fn move_group_row(
   }
 }
 
-pub fn make_inserted_cell_rev(group_id: &str, field_rev: &FieldRevision) -> Option<CellRevision> {
+pub fn make_inserted_cell_rev(
+  group_id: &str,
+  row_rev: &RowRevision,
+  field_rev: &FieldRevision,
+) -> Option<CellRevision> {
   let field_type: FieldType = field_rev.ty.into();
   match field_type {
-    FieldType::SingleSelect => {
-      let cell_rev = insert_select_option_cell(vec![group_id.to_owned()], field_rev);
+    FieldType::SingleSelect | FieldType::MultiSelect => {
+      let parent_option_ids = parent_option_ids_in_cells(field_rev, &row_rev.cells);
+      let cell_rev =
+        insert_select_option_cell(vec![group_id.to_owned()], &parent_option_ids, field_rev);
       Some(cell_rev)
     },
-    FieldType::MultiSelect => {
-      let cell_rev = insert_select_option_cell(vec![group_id.to_owned()], field_rev);
+    FieldType::Person => {
+      let cell_rev = insert_person_cell(vec![group_id.to_owned()], field_rev);
       Some(cell_rev)
     },
     FieldType::Checkbox => {
       let cell_rev = insert_checkbox_cell(group_id == CHECK, field_rev);
       Some(cell_rev)
     },
-    FieldType::URL => {
-      let cell_rev = insert_url_cell(group_id.to_owned(), field_rev);
-      Some(cell_rev)
+    // The id of the group is the url of its rows, so it's within the limits
+    FieldType::URL => match insert_url_cell(group_id.to_owned(), field_rev, None) {
+      Ok(cell_rev) => Some(cell_rev),
+      Err(err) => {
+        tracing::error!("Insert the url cell failed: {:?}", err);
+        None
+      },
     },
+    FieldType::DateTime => make_date_group_cell(group_id, row_rev, field_rev),
     _ => {
       tracing::warn!("Unknown field type: {:?}", field_type);
       None
@@ -173,8 +235,20 @@ pub fn generate_select_option_groups(
     .map(|option| GeneratedGroupConfig {
       group_rev: GroupRevision::new(option.id.clone(), option.name.clone()),
       filter_content: option.id.clone(),
+      color: Some(option.color.clone()),
     })
     .collect();
 
   groups
 }
+
+pub fn generate_person_groups(members: &[PersonPB]) -> Vec<GeneratedGroupConfig> {
+  members
+    .iter()
+    .map(|member| GeneratedGroupConfig {
+      group_rev: GroupRevision::new(member.id.clone(), member.name.clone()),
+      filter_content: member.id.clone(),
+      color: None,
+    })
+    .collect()
+}
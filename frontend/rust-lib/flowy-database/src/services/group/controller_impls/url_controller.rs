@@ -166,9 +166,11 @@ impl GroupController for URLGroupController {
   ) {
     match self.group_ctx.get_group(group_id) {
       None => tracing::warn!("Can not find the group: {}", group_id),
-      Some((_, group)) => {
-        let cell_rev = insert_url_cell(group.id.clone(), field_rev);
-        row_rev.cells.insert(field_rev.id.clone(), cell_rev);
+      Some((_, group)) => match insert_url_cell(group.id.clone(), field_rev, None) {
+        Ok(cell_rev) => {
+          row_rev.cells.insert(field_rev.id.clone(), cell_rev);
+        },
+        Err(err) => tracing::error!("Insert the url cell failed: {:?}", err),
       },
     }
   }
@@ -201,6 +203,7 @@ impl GroupGenerator for URLGroupGenerator {
       .map(|cell| GeneratedGroupConfig {
         group_rev: make_group_from_url_cell(&cell),
         filter_content: cell.content,
+        color: None,
       })
       .collect();
 
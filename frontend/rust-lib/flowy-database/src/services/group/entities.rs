@@ -1,4 +1,5 @@
 use crate::entities::RowPB;
+use crate::services::field::SelectOptionColorPB;
 
 #[derive(Clone, PartialEq, Debug, Eq)]
 pub struct Group {
@@ -11,6 +12,9 @@ pub struct Group {
 
   /// [filter_content] is used to determine which group the cell belongs to.
   pub filter_content: String,
+
+  /// The color of the select option that the group is generated from
+  pub color: Option<SelectOptionColorPB>,
 }
 
 impl Group {
@@ -24,6 +28,7 @@ impl Group {
       name,
       rows: vec![],
       filter_content,
+      color: None,
     }
   }
 
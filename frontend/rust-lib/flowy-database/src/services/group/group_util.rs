@@ -2,9 +2,10 @@ use crate::entities::FieldType;
 use crate::services::group::configuration::GroupConfigurationReader;
 use crate::services::group::controller::GroupController;
 use crate::services::group::{
-  CheckboxGroupContext, CheckboxGroupController, DefaultGroupController, GroupConfigurationWriter,
-  MultiSelectGroupController, SelectOptionGroupContext, SingleSelectGroupController,
-  URLGroupContext, URLGroupController,
+  CheckboxGroupContext, CheckboxGroupController, DateGroupContext, DateGroupController,
+  DefaultGroupController, GroupConfigurationWriter, MultiSelectGroupController,
+  PersonGroupController, SelectOptionGroupContext, SingleSelectGroupController, URLGroupContext,
+  URLGroupController,
 };
 use database_model::{
   CheckboxGroupConfigurationRevision, DateGroupConfigurationRevision, FieldRevision,
@@ -78,6 +79,17 @@ where
       let controller = MultiSelectGroupController::new(&grouping_field_rev, configuration).await?;
       group_controller = Box::new(controller);
     },
+    FieldType::Person => {
+      let configuration = SelectOptionGroupContext::new(
+        view_id,
+        grouping_field_rev.clone(),
+        configuration_reader,
+        configuration_writer,
+      )
+      .await?;
+      let controller = PersonGroupController::new(&grouping_field_rev, configuration).await?;
+      group_controller = Box::new(controller);
+    },
     FieldType::Checkbox => {
       let configuration = CheckboxGroupContext::new(
         view_id,
@@ -100,6 +112,17 @@ where
       let controller = URLGroupController::new(&grouping_field_rev, configuration).await?;
       group_controller = Box::new(controller);
     },
+    FieldType::DateTime => {
+      let configuration = DateGroupContext::new(
+        view_id,
+        grouping_field_rev.clone(),
+        configuration_reader,
+        configuration_writer,
+      )
+      .await?;
+      let controller = DateGroupController::new(&grouping_field_rev, configuration).await?;
+      group_controller = Box::new(controller);
+    },
     _ => {
       group_controller = Box::new(DefaultGroupController::new(&grouping_field_rev));
     },
@@ -125,6 +148,8 @@ pub fn find_grouping_field(
       }
     })
     .collect::<Vec<Arc<FieldRevision>>>();
+  // The boards are grouped by the date only if there is no other groupable field
+  groupable_field_revs.sort_by_key(|field_rev| FieldType::from(field_rev.ty).is_date());
 
   if groupable_field_revs.is_empty() {
     // If there is not groupable fields then we use the primary field.
@@ -160,20 +185,28 @@ pub fn default_group_configuration(field_rev: &FieldRevision) -> GroupConfigurat
       NumberGroupConfigurationRevision::default(),
     )
     .unwrap(),
-    FieldType::DateTime => GroupConfigurationRevision::new(
+    FieldType::DateTime | FieldType::CreatedTime | FieldType::LastEditedTime => {
+      GroupConfigurationRevision::new(
+        field_id,
+        field_type_rev,
+        DateGroupConfigurationRevision::default(),
+      )
+      .unwrap()
+    },
+
+    FieldType::SingleSelect => GroupConfigurationRevision::new(
       field_id,
       field_type_rev,
-      DateGroupConfigurationRevision::default(),
+      SelectOptionGroupConfigurationRevision::default(),
     )
     .unwrap(),
-
-    FieldType::SingleSelect => GroupConfigurationRevision::new(
+    FieldType::MultiSelect => GroupConfigurationRevision::new(
       field_id,
       field_type_rev,
       SelectOptionGroupConfigurationRevision::default(),
     )
     .unwrap(),
-    FieldType::MultiSelect => GroupConfigurationRevision::new(
+    FieldType::Person => GroupConfigurationRevision::new(
       field_id,
       field_type_rev,
       SelectOptionGroupConfigurationRevision::default(),
@@ -197,6 +230,72 @@ pub fn default_group_configuration(field_rev: &FieldRevision) -> GroupConfigurat
       URLGroupConfigurationRevision::default(),
     )
     .unwrap(),
+    FieldType::Relation => GroupConfigurationRevision::new(
+      field_id,
+      field_type_rev,
+      TextGroupConfigurationRevision::default(),
+    )
+    .unwrap(),
+    FieldType::Rollup => GroupConfigurationRevision::new(
+      field_id,
+      field_type_rev,
+      TextGroupConfigurationRevision::default(),
+    )
+    .unwrap(),
+    FieldType::Formula => GroupConfigurationRevision::new(
+      field_id,
+      field_type_rev,
+      TextGroupConfigurationRevision::default(),
+    )
+    .unwrap(),
+    FieldType::Attachment => GroupConfigurationRevision::new(
+      field_id,
+      field_type_rev,
+      TextGroupConfigurationRevision::default(),
+    )
+    .unwrap(),
+    FieldType::Progress => GroupConfigurationRevision::new(
+      field_id,
+      field_type_rev,
+      NumberGroupConfigurationRevision::default(),
+    )
+    .unwrap(),
+    FieldType::AutoNumber => GroupConfigurationRevision::new(
+      field_id,
+      field_type_rev,
+      NumberGroupConfigurationRevision::default(),
+    )
+    .unwrap(),
+    FieldType::TimeTracking => GroupConfigurationRevision::new(
+      field_id,
+      field_type_rev,
+      NumberGroupConfigurationRevision::default(),
+    )
+    .unwrap(),
+    FieldType::Duration => GroupConfigurationRevision::new(
+      field_id,
+      field_type_rev,
+      NumberGroupConfigurationRevision::default(),
+    )
+    .unwrap(),
+    FieldType::Phone => GroupConfigurationRevision::new(
+      field_id,
+      field_type_rev,
+      TextGroupConfigurationRevision::default(),
+    )
+    .unwrap(),
+    FieldType::Email => GroupConfigurationRevision::new(
+      field_id,
+      field_type_rev,
+      TextGroupConfigurationRevision::default(),
+    )
+    .unwrap(),
+    FieldType::Location => GroupConfigurationRevision::new(
+      field_id,
+      field_type_rev,
+      TextGroupConfigurationRevision::default(),
+    )
+    .unwrap(),
   }
 }
 
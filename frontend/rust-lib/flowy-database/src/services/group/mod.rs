@@ -1,10 +1,12 @@
 mod action;
+mod board_layout;
 mod configuration;
 mod controller;
 mod controller_impls;
 mod entities;
 mod group_util;
 
+pub use board_layout::*;
 pub(crate) use configuration::*;
 pub(crate) use controller::*;
 pub(crate) use controller_impls::*;
@@ -0,0 +1,40 @@
+use flowy_error::{FlowyError, FlowyResult};
+use std::fs::File;
+
+/// Returns the header of the CSV file. Each column of the header will be imported as a field.
+pub(crate) fn read_csv_header(file_path: &str) -> FlowyResult<Vec<String>> {
+  let mut reader = make_csv_reader(file_path)?;
+  let header = reader
+    .headers()
+    .map_err(|e| FlowyError::invalid_data().context(e))?
+    .iter()
+    .map(|column| column.trim().to_owned())
+    .collect::<Vec<String>>();
+
+  if header.is_empty() {
+    return Err(FlowyError::invalid_data().context("The CSV file doesn't have a header"));
+  }
+  Ok(header)
+}
+
+/// Returns the records of the CSV file without the header. The records are read lazily, so
+/// the whole file doesn't need to be loaded into memory.
+pub(crate) fn read_csv_records(
+  file_path: &str,
+) -> FlowyResult<impl Iterator<Item = FlowyResult<Vec<String>>>> {
+  let reader = make_csv_reader(file_path)?;
+  let records = reader.into_records().map(|record| {
+    record
+      .map(|record| record.iter().map(|cell| cell.to_owned()).collect())
+      .map_err(|e| FlowyError::invalid_data().context(e))
+  });
+  Ok(records)
+}
+
+fn make_csv_reader(file_path: &str) -> FlowyResult<csv::Reader<File>> {
+  csv::ReaderBuilder::new()
+    .has_headers(true)
+    .flexible(true)
+    .from_path(file_path)
+    .map_err(|e| FlowyError::invalid_data().context(e))
+}
@@ -0,0 +1,306 @@
+mod csv;
+mod trello;
+
+use crate::entities::LayoutTypePB;
+use crate::manager::{create_new_database, DatabaseManager};
+use crate::services::field::{FieldBuilder, RichTextTypeOptionBuilder};
+use crate::services::import::csv::{read_csv_header, read_csv_records};
+use crate::services::import::trello::{
+  make_trello_database, make_trello_row, read_trello_board, trello_cards, TrelloFields,
+};
+use crate::services::persistence::import_checkpoint::{ImportCheckpoint, ImportType};
+use crate::services::row::RowRevisionBuilder;
+use database_model::{BuildDatabaseContext, RowRevision};
+use flowy_client_sync::client_database::DatabaseBuilder;
+use flowy_error::{FlowyError, FlowyResult};
+use nanoid::nanoid;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+
+/// The number of records that are committed to the database before the checkpoint is updated.
+const IMPORT_CHUNK_SIZE: usize = 100;
+
+/// A record of the imported file that becomes a row of the database.
+struct ImportRecord {
+  row_rev: RowRevision,
+  /// Written into the document of the row, e.g. the description of a Trello card
+  description: Option<String>,
+}
+
+/// Creates a grid from the header of the CSV file, each column becomes a text field. The
+/// records of the file are not imported yet, a checkpoint is saved instead and the records are
+/// imported chunk by chunk when calling [run_database_import].
+pub async fn create_database_from_csv(
+  view_id: &str,
+  name: String,
+  file_path: &str,
+  database_manager: Arc<DatabaseManager>,
+) -> FlowyResult<()> {
+  let header = read_csv_header(file_path)?;
+  let mut database_builder = DatabaseBuilder::new();
+  for (index, field_name) in header.iter().enumerate() {
+    let field_rev = FieldBuilder::new(RichTextTypeOptionBuilder::default())
+      .name(field_name)
+      .visibility(true)
+      .primary(index == 0)
+      .build();
+    database_builder.add_field(field_rev);
+  }
+  create_database_with_checkpoint(
+    view_id,
+    name,
+    LayoutTypePB::Grid,
+    ImportType::CSV,
+    file_path,
+    database_manager,
+    database_builder.build(),
+  )
+  .await
+}
+
+/// Creates a board from Trello's JSON export of a board. Like [create_database_from_csv], only
+/// the fields are created, the cards are imported chunk by chunk when calling
+/// [run_database_import]. The descriptions of the cards are written into the documents of the
+/// rows.
+pub async fn create_database_from_trello(
+  view_id: &str,
+  name: String,
+  file_path: &str,
+  database_manager: Arc<DatabaseManager>,
+) -> FlowyResult<()> {
+  let board = read_trello_board(file_path)?;
+  create_database_with_checkpoint(
+    view_id,
+    name,
+    LayoutTypePB::Board,
+    ImportType::TrelloJSON,
+    file_path,
+    database_manager,
+    make_trello_database(&board),
+  )
+  .await
+}
+
+async fn create_database_with_checkpoint(
+  view_id: &str,
+  name: String,
+  layout: LayoutTypePB,
+  import_type: ImportType,
+  file_path: &str,
+  database_manager: Arc<DatabaseManager>,
+  build_context: BuildDatabaseContext,
+) -> FlowyResult<()> {
+  // Save the checkpoint before creating the database. If the app is killed in between, the
+  // checkpoint will be dropped when resuming because the database doesn't exist.
+  let import_id = nanoid!(10);
+  let checkpoints = database_manager.import_checkpoints();
+  checkpoints.save(ImportCheckpoint {
+    import_id: import_id.clone(),
+    view_id: view_id.to_owned(),
+    import_type,
+    file_path: file_path.to_owned(),
+    next_row: 0,
+  })?;
+
+  let result = create_new_database(view_id, name, layout, database_manager, build_context).await;
+  if result.is_err() {
+    checkpoints.remove(&import_id)?;
+  }
+  result
+}
+
+/// Imports the remaining records of the view from its checkpoint. It does nothing if the view
+/// doesn't have an unfinished import or the import is already running.
+pub async fn run_database_import(
+  view_id: &str,
+  database_manager: Arc<DatabaseManager>,
+) -> FlowyResult<()> {
+  let checkpoint = match database_manager
+    .import_checkpoints()
+    .get_with_view(view_id)?
+  {
+    None => return Ok(()),
+    Some(checkpoint) => checkpoint,
+  };
+
+  if !database_manager
+    .running_imports
+    .lock()
+    .insert(checkpoint.import_id.clone())
+  {
+    return Ok(());
+  }
+  let result = import_records(&checkpoint, &database_manager).await;
+  database_manager
+    .running_imports
+    .lock()
+    .remove(&checkpoint.import_id);
+  result
+}
+
+/// Resumes the imports that were interrupted, e.g. the app was killed in the middle of an
+/// import. Each import continues from its last committed chunk.
+pub async fn resume_database_imports(database_manager: Arc<DatabaseManager>) -> FlowyResult<()> {
+  let checkpoints = database_manager.import_checkpoints().get_all()?;
+  for checkpoint in checkpoints {
+    tracing::info!(
+      "Resume importing {} from record {}",
+      checkpoint.file_path,
+      checkpoint.next_row
+    );
+    if let Err(err) = run_database_import(&checkpoint.view_id, database_manager.clone()).await {
+      tracing::error!(
+        "Resume importing {} failed: {:?}",
+        checkpoint.file_path,
+        err
+      );
+    }
+  }
+  Ok(())
+}
+
+async fn import_records(
+  checkpoint: &ImportCheckpoint,
+  database_manager: &Arc<DatabaseManager>,
+) -> FlowyResult<()> {
+  let checkpoints = database_manager.import_checkpoints();
+  if !database_manager.contains_database_view(&checkpoint.view_id) {
+    tracing::warn!("The database of the import was not created, drop the checkpoint");
+    return checkpoints.remove(&checkpoint.import_id);
+  }
+  if !Path::new(&checkpoint.file_path).exists() {
+    tracing::warn!(
+      "{} doesn't exist anymore, drop the checkpoint",
+      checkpoint.file_path
+    );
+    return checkpoints.remove(&checkpoint.import_id);
+  }
+
+  let editor = database_manager
+    .open_database_view(&checkpoint.view_id)
+    .await?;
+  let field_revs = editor.get_field_revs(None).await?;
+  let block_id = editor
+    .get_block_meta_revs()
+    .await?
+    .last()
+    .map(|block_meta_rev| block_meta_rev.block_id.clone())
+    .ok_or_else(|| FlowyError::internal().context("There is no block in this database"))?;
+  // The chunk might have been committed right before the app was killed, but the checkpoint
+  // wasn't updated. The rows that were already imported will be skipped.
+  let existing_row_ids = editor
+    .get_all_row_revs(&checkpoint.view_id)
+    .await?
+    .iter()
+    .map(|row_rev| row_rev.id.clone())
+    .collect::<HashSet<String>>();
+
+  let cell_size_limits = database_manager.cell_size_limits();
+  let board;
+  let mut records: Box<dyn Iterator<Item = FlowyResult<ImportRecord>> + Send + '_> =
+    match checkpoint.import_type {
+      ImportType::CSV => {
+        let records = read_csv_records(&checkpoint.file_path)?
+          .skip(checkpoint.next_row)
+          .map(|record| {
+            let cell_data_by_field_id = field_revs
+              .iter()
+              .zip(record?.into_iter())
+              .map(|(field_rev, cell_data)| (field_rev.id.clone(), cell_data))
+              .collect::<HashMap<String, String>>();
+            let row_rev = RowRevisionBuilder::new_with_data(
+              &block_id,
+              field_revs.clone(),
+              cell_data_by_field_id,
+              Some(cell_size_limits.clone()),
+            )
+            .try_build()?;
+            Ok(ImportRecord {
+              row_rev,
+              description: None,
+            })
+          });
+        Box::new(records)
+      },
+      ImportType::TrelloJSON => {
+        board = read_trello_board(&checkpoint.file_path)?;
+        let board = &board;
+        let fields = TrelloFields::from_field_revs(&field_revs)?;
+        let records = trello_cards(board)
+          .into_iter()
+          .skip(checkpoint.next_row)
+          .map(move |card| {
+            let row_rev = make_trello_row(
+              board,
+              card,
+              &block_id,
+              field_revs.clone(),
+              &fields,
+              cell_size_limits,
+            )?;
+            let description = Some(card.desc.clone()).filter(|desc| !desc.trim().is_empty());
+            Ok(ImportRecord {
+              row_rev,
+              description,
+            })
+          });
+        Box::new(records)
+      },
+    };
+
+  let mut next_row = checkpoint.next_row;
+  loop {
+    let chunk = records
+      .by_ref()
+      .take(IMPORT_CHUNK_SIZE)
+      .collect::<FlowyResult<Vec<ImportRecord>>>()?;
+    if chunk.is_empty() {
+      break;
+    }
+
+    let chunk_len = chunk.len();
+    let mut row_revs = vec![];
+    let mut descriptions = vec![];
+    for (offset, record) in chunk.into_iter().enumerate() {
+      let row_id = gen_import_row_id(&checkpoint.import_id, next_row + offset);
+      if let Some(description) = record.description {
+        descriptions.push((row_id.clone(), description));
+      }
+      if existing_row_ids.contains(&row_id) {
+        continue;
+      }
+      let mut row_rev = record.row_rev;
+      row_rev.id = row_id;
+      row_revs.push(row_rev);
+    }
+
+    if !row_revs.is_empty() {
+      editor.insert_rows(row_revs).await?;
+    }
+    // The documents of the rows that were committed before the app was killed might not be
+    // created yet.
+    for (row_id, description) in descriptions {
+      if database_manager.get_row_document_id(&row_id)?.is_none() {
+        database_manager
+          .create_row_document(&row_id, &description)
+          .await?;
+      }
+    }
+    next_row += chunk_len;
+    checkpoints.update_next_row(&checkpoint.import_id, next_row)?;
+  }
+
+  tracing::trace!(
+    "Finish importing {} records from {}",
+    next_row,
+    checkpoint.file_path
+  );
+  checkpoints.remove(&checkpoint.import_id)
+}
+
+/// The id of the imported row is derived from its position in the file, so a record that is
+/// imported again after resuming can be detected.
+fn gen_import_row_id(import_id: &str, index: usize) -> String {
+  format!("{}:{}", import_id, index)
+}
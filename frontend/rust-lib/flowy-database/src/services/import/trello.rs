@@ -0,0 +1,286 @@
+use crate::entities::FieldType;
+use crate::services::cell::CellSizeLimits;
+use crate::services::field::{
+  DateCellData, DateTypeOptionBuilder, FieldBuilder, MultiSelectTypeOptionBuilder,
+  RichTextTypeOptionBuilder, SelectOptionColorPB, SelectOptionPB, SingleSelectTypeOptionBuilder,
+  TimeFormat,
+};
+use crate::services::row::RowRevisionBuilder;
+use database_model::{BuildDatabaseContext, FieldRevision, RowRevision};
+use flowy_client_sync::client_database::DatabaseBuilder;
+use flowy_error::{FlowyError, FlowyResult};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The parts of Trello's JSON export of a board that are imported. The other properties of the
+/// export, e.g. the members and the actions, are ignored.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TrelloBoard {
+  #[serde(default)]
+  pub lists: Vec<TrelloList>,
+  #[serde(default)]
+  pub cards: Vec<TrelloCard>,
+  #[serde(default)]
+  pub labels: Vec<TrelloLabel>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TrelloList {
+  pub id: String,
+  #[serde(default)]
+  pub name: String,
+  #[serde(default)]
+  pub closed: bool,
+  #[serde(default)]
+  pub pos: f64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TrelloCard {
+  pub id: String,
+  #[serde(default)]
+  pub name: String,
+  #[serde(default)]
+  pub desc: String,
+  #[serde(default)]
+  pub id_list: String,
+  #[serde(default)]
+  pub id_labels: Vec<String>,
+  /// The due date in ISO 8601, e.g. 2023-03-14T09:56:02.000Z
+  #[serde(default)]
+  pub due: Option<String>,
+  #[serde(default)]
+  pub closed: bool,
+  #[serde(default)]
+  pub pos: f64,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TrelloLabel {
+  pub id: String,
+  #[serde(default)]
+  pub name: String,
+  #[serde(default)]
+  pub color: Option<String>,
+}
+
+pub(crate) fn read_trello_board(file_path: &str) -> FlowyResult<TrelloBoard> {
+  let content = std::fs::read_to_string(file_path)
+    .map_err(|e| FlowyError::invalid_data().context(format!("{}: {}", file_path, e)))?;
+  serde_json::from_str::<TrelloBoard>(&content)
+    .map_err(|e| FlowyError::invalid_data().context(format!("Invalid Trello board: {}", e)))
+}
+
+/// The fields of the board that the cards are imported into.
+pub(crate) struct TrelloFields {
+  name_field_id: String,
+  list_field_id: String,
+  label_field_id: String,
+  due_field_id: String,
+}
+
+impl TrelloFields {
+  /// Finds the fields that were created by [make_trello_database] in the board. The fields are
+  /// found by their types, so they are found again when resuming the import of the cards.
+  pub(crate) fn from_field_revs(field_revs: &[Arc<FieldRevision>]) -> FlowyResult<Self> {
+    let find_field_id = |is_field: &dyn Fn(&FieldRevision) -> bool| {
+      field_revs
+        .iter()
+        .find(|field_rev| is_field(field_rev))
+        .map(|field_rev| field_rev.id.clone())
+        .ok_or_else(|| {
+          FlowyError::field_record_not_found().context("The field of the board is missing")
+        })
+    };
+    let is_type = |field_rev: &FieldRevision, field_type: FieldType| {
+      FieldType::from(field_rev.ty) == field_type
+    };
+    Ok(Self {
+      name_field_id: find_field_id(&|field_rev| field_rev.is_primary)?,
+      list_field_id: find_field_id(&|field_rev| is_type(field_rev, FieldType::SingleSelect))?,
+      label_field_id: find_field_id(&|field_rev| is_type(field_rev, FieldType::MultiSelect))?,
+      due_field_id: find_field_id(&|field_rev| is_type(field_rev, FieldType::DateTime))?,
+    })
+  }
+}
+
+/// Builds the fields of the board from the Trello board, the cards are imported as rows
+/// afterwards. The lists become the options of the `List` field that the board is grouped by,
+/// and the labels become the options of the `Labels` field. The options keep the ids of the
+/// lists and the labels, so the cards can be mapped to them when resuming the import. The
+/// archived lists are skipped.
+pub(crate) fn make_trello_database(board: &TrelloBoard) -> BuildDatabaseContext {
+  let mut database_builder = DatabaseBuilder::new();
+  let name_field = FieldBuilder::new(RichTextTypeOptionBuilder::default())
+    .name("Name")
+    .visibility(true)
+    .primary(true)
+    .build();
+  database_builder.add_field(name_field);
+
+  // The board is grouped by the first select option field, so the lists become the groups
+  let mut list_type_option = SingleSelectTypeOptionBuilder::default();
+  for list in open_lists(board) {
+    let mut option = SelectOptionPB::new(&list.name);
+    option.id = list.id.clone();
+    list_type_option = list_type_option.add_option(option);
+  }
+  let list_field = FieldBuilder::new(list_type_option)
+    .name("List")
+    .visibility(true)
+    .build();
+  database_builder.add_field(list_field);
+
+  let mut label_type_option = MultiSelectTypeOptionBuilder::default();
+  for label in board.labels.iter() {
+    let color = label.color.as_deref().unwrap_or_default();
+    if let Some(name) = label_name(label) {
+      let mut option = SelectOptionPB::with_color(&name, select_option_color_from_trello(color));
+      option.id = label.id.clone();
+      label_type_option = label_type_option.add_option(option);
+    }
+  }
+  let label_field = FieldBuilder::new(label_type_option)
+    .name("Labels")
+    .visibility(true)
+    .build();
+  database_builder.add_field(label_field);
+
+  let due_type_option = DateTypeOptionBuilder::default().time_format(TimeFormat::TwentyFourHour);
+  let due_field = FieldBuilder::new(due_type_option)
+    .name("Due date")
+    .visibility(true)
+    .build();
+  database_builder.add_field(due_field);
+  database_builder.build()
+}
+
+/// Returns the cards that become the rows of the board, in the order of their lists. The
+/// archived cards and the cards of the archived lists are skipped. The order doesn't change
+/// between the reads of the same file, so the import can be resumed from the index of a card.
+pub(crate) fn trello_cards(board: &TrelloBoard) -> Vec<&TrelloCard> {
+  let list_index_by_id = open_lists(board)
+    .into_iter()
+    .enumerate()
+    .map(|(index, list)| (list.id.as_str(), index))
+    .collect::<HashMap<&str, usize>>();
+  let mut cards = board
+    .cards
+    .iter()
+    .filter(|card| !card.closed && list_index_by_id.contains_key(card.id_list.as_str()))
+    .collect::<Vec<&TrelloCard>>();
+  cards.sort_by(|a, b| {
+    list_index_by_id[a.id_list.as_str()]
+      .cmp(&list_index_by_id[b.id_list.as_str()])
+      .then(a.pos.total_cmp(&b.pos))
+  });
+  cards
+}
+
+/// Builds the row of the card. The labels without a name and color are skipped, like they are
+/// skipped by [make_trello_database].
+pub(crate) fn make_trello_row(
+  board: &TrelloBoard,
+  card: &TrelloCard,
+  block_id: &str,
+  field_revs: Vec<Arc<FieldRevision>>,
+  fields: &TrelloFields,
+  cell_size_limits: &CellSizeLimits,
+) -> FlowyResult<RowRevision> {
+  let mut row_builder = RowRevisionBuilder::new_with_data(
+    block_id,
+    field_revs,
+    HashMap::new(),
+    Some(cell_size_limits.clone()),
+  );
+  row_builder.insert_text_cell(&fields.name_field_id, card.name.clone());
+  row_builder.insert_select_option_cell(&fields.list_field_id, vec![card.id_list.clone()]);
+  let label_ids = card
+    .id_labels
+    .iter()
+    .filter(|label_id| {
+      board
+        .labels
+        .iter()
+        .any(|label| &label.id == *label_id && label_name(label).is_some())
+    })
+    .cloned()
+    .collect::<Vec<String>>();
+  if !label_ids.is_empty() {
+    row_builder.insert_select_option_cell(&fields.label_field_id, label_ids);
+  }
+  if let Some(timestamp) = card.due.as_deref().and_then(timestamp_from_trello_date) {
+    row_builder.insert_date_cell(
+      &fields.due_field_id,
+      DateCellData {
+        timestamp: Some(timestamp),
+        include_time: true,
+        recurrence: None,
+      },
+    );
+  }
+  row_builder.try_build()
+}
+
+fn open_lists(board: &TrelloBoard) -> Vec<&TrelloList> {
+  let mut lists = board
+    .lists
+    .iter()
+    .filter(|list| !list.closed)
+    .collect::<Vec<&TrelloList>>();
+  lists.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+  lists
+}
+
+/// Trello shows the labels without names by their colors
+fn label_name(label: &TrelloLabel) -> Option<String> {
+  let name = if label.name.is_empty() {
+    label_name_from_trello_color(label.color.as_deref().unwrap_or_default())
+  } else {
+    label.name.clone()
+  };
+  if name.is_empty() {
+    None
+  } else {
+    Some(name)
+  }
+}
+
+fn timestamp_from_trello_date(date: &str) -> Option<i64> {
+  chrono::DateTime::parse_from_rfc3339(date)
+    .ok()
+    .map(|date_time| date_time.timestamp())
+}
+
+/// Returns the readable name of Trello's color, e.g. "Dark green" for green_dark.
+fn label_name_from_trello_color(color: &str) -> String {
+  let name = match color.split_once('_') {
+    None => color.to_owned(),
+    Some((base, shade)) => format!("{} {}", shade, base),
+  };
+  let mut chars = name.chars();
+  match chars.next() {
+    None => name,
+    Some(first) => first.to_uppercase().chain(chars).collect(),
+  }
+}
+
+/// Trello's colors have shades, e.g. green_dark, which are mapped to the same color.
+fn select_option_color_from_trello(color: &str) -> SelectOptionColorPB {
+  match color.split('_').next().unwrap_or_default() {
+    "green" => SelectOptionColorPB::Green,
+    "yellow" => SelectOptionColorPB::Yellow,
+    "orange" => SelectOptionColorPB::Orange,
+    "red" => SelectOptionColorPB::Pink,
+    "pink" => SelectOptionColorPB::LightPink,
+    "blue" => SelectOptionColorPB::Blue,
+    "sky" => SelectOptionColorPB::Aqua,
+    "lime" => SelectOptionColorPB::Lime,
+    _ => SelectOptionColorPB::Purple,
+  }
+}
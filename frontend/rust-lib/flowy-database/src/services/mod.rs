@@ -1,12 +1,18 @@
 mod util;
 
+pub mod automation;
+pub mod calculation;
 pub mod cell;
 pub mod database;
 pub mod database_view;
 pub mod field;
 pub mod filter;
+pub mod formula;
 pub mod group;
+pub mod import;
 pub mod persistence;
+pub mod rollup;
 pub mod row;
 pub mod setting;
 pub mod sort;
+pub mod transaction;
@@ -0,0 +1,121 @@
+use crate::services::persistence::DatabaseDBConnection;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use flowy_error::FlowyResult;
+use flowy_sqlite::{
+  prelude::*,
+  schema::{automation_activity_table, automation_activity_table::dsl},
+};
+use lib_infra::util::timestamp;
+use nanoid::nanoid;
+use std::sync::Arc;
+
+/// Logs the executions of the automations on this device. The log is the activity feed of the
+/// automations, and it limits the retries of the failed executions. The dates that were fired
+/// are synced with the automations, see [database_model::AutomationRevision::fired_dates].
+pub struct AutomationActivities {
+  database: Arc<dyn DatabaseDBConnection>,
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[table_name = "automation_activity_table"]
+pub struct AutomationActivity {
+  pub id: String,
+  pub automation_id: String,
+  pub view_id: String,
+  pub row_id: String,
+  /// The date that triggered the automation, in the form of `%Y-%m-%d`
+  pub trigger_date: String,
+  /// Empty if the action succeeded
+  pub error: String,
+  /// The timestamp in seconds when the automation was executed
+  pub executed_at: i64,
+}
+
+impl AutomationActivities {
+  pub fn new(database: Arc<dyn DatabaseDBConnection>) -> Self {
+    Self { database }
+  }
+
+  pub fn add(
+    &self,
+    automation_id: &str,
+    view_id: &str,
+    row_id: &str,
+    trigger_date: &str,
+    error: Option<String>,
+  ) -> FlowyResult<AutomationActivity> {
+    let conn = self.database.get_db_connection()?;
+    let activity = AutomationActivity {
+      id: nanoid!(),
+      automation_id: automation_id.to_owned(),
+      view_id: view_id.to_owned(),
+      row_id: row_id.to_owned(),
+      trigger_date: trigger_date.to_owned(),
+      error: error.unwrap_or_default(),
+      executed_at: timestamp(),
+    };
+    let _ = diesel::insert_into(automation_activity_table::table)
+      .values(activity.clone())
+      .execute(&*conn)?;
+    Ok(activity)
+  }
+
+  /// Returns true if the automation was executed successfully for the date of the row on this
+  /// device. The failed executions are not counted, so they are retried.
+  pub fn is_executed(
+    &self,
+    automation_id: &str,
+    row_id: &str,
+    trigger_date: &str,
+  ) -> FlowyResult<bool> {
+    let conn = self.database.get_db_connection()?;
+    let count = dsl::automation_activity_table
+      .filter(automation_activity_table::automation_id.eq(automation_id))
+      .filter(automation_activity_table::row_id.eq(row_id))
+      .filter(automation_activity_table::trigger_date.eq(trigger_date))
+      .filter(automation_activity_table::error.eq(""))
+      .count()
+      .get_result::<i64>(&*conn)?;
+    Ok(count > 0)
+  }
+
+  /// Returns the number of the failed executions of the automation for the date of the row.
+  pub fn number_of_failures(
+    &self,
+    automation_id: &str,
+    row_id: &str,
+    trigger_date: &str,
+  ) -> FlowyResult<i64> {
+    let conn = self.database.get_db_connection()?;
+    let count = dsl::automation_activity_table
+      .filter(automation_activity_table::automation_id.eq(automation_id))
+      .filter(automation_activity_table::row_id.eq(row_id))
+      .filter(automation_activity_table::trigger_date.eq(trigger_date))
+      .filter(automation_activity_table::error.ne(""))
+      .count()
+      .get_result::<i64>(&*conn)?;
+    Ok(count)
+  }
+
+  /// Returns the latest activities of the view, the most recently executed comes first.
+  pub fn get_all(&self, view_id: &str, limit: i64) -> FlowyResult<Vec<AutomationActivity>> {
+    let conn = self.database.get_db_connection()?;
+    let activities = dsl::automation_activity_table
+      .filter(automation_activity_table::view_id.eq(view_id))
+      .order(automation_activity_table::executed_at.desc())
+      .limit(limit)
+      .load::<AutomationActivity>(&*conn)?;
+    Ok(activities)
+  }
+
+  /// Removes the activities of the deleted automation.
+  pub fn remove_with_automation(&self, automation_id: &str) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    diesel::delete(
+      dsl::automation_activity_table
+        .filter(automation_activity_table::automation_id.eq(automation_id)),
+    )
+    .execute(&*conn)?;
+    Ok(())
+  }
+}
@@ -0,0 +1,52 @@
+use crate::services::persistence::DatabaseDBConnection;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use flowy_error::FlowyResult;
+use flowy_sqlite::{
+  prelude::*,
+  schema::{automation_view_table, automation_view_table::dsl},
+};
+use std::sync::Arc;
+
+/// Keeps the ids of the views that have automations, so the views can be scanned without being
+/// opened by the user, e.g. right after the app starts.
+pub struct AutomationViews {
+  database: Arc<dyn DatabaseDBConnection>,
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[table_name = "automation_view_table"]
+#[primary_key(view_id)]
+struct AutomationViewTable {
+  view_id: String,
+}
+
+impl AutomationViews {
+  pub fn new(database: Arc<dyn DatabaseDBConnection>) -> Self {
+    Self { database }
+  }
+
+  pub fn insert(&self, view_id: &str) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    let _ = diesel::replace_into(automation_view_table::table)
+      .values(AutomationViewTable {
+        view_id: view_id.to_owned(),
+      })
+      .execute(&*conn)?;
+    Ok(())
+  }
+
+  pub fn remove(&self, view_id: &str) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    diesel::delete(dsl::automation_view_table.filter(automation_view_table::view_id.eq(view_id)))
+      .execute(&*conn)?;
+    Ok(())
+  }
+
+  pub fn get_all(&self) -> FlowyResult<Vec<String>> {
+    let conn = self.database.get_db_connection()?;
+    let view_ids = dsl::automation_view_table
+      .select(automation_view_table::view_id)
+      .load::<String>(&*conn)?;
+    Ok(view_ids)
+  }
+}
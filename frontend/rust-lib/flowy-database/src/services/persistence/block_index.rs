@@ -38,6 +38,16 @@ impl BlockRowIndexer {
       .execute(&*conn)?;
     Ok(())
   }
+
+  /// Removes the index of all the rows of the block.
+  pub fn remove_block(&self, block_id: &str) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    diesel::delete(
+      dsl::grid_block_index_table.filter(grid_block_index_table::block_id.eq(block_id)),
+    )
+    .execute(&*conn)?;
+    Ok(())
+  }
 }
 
 #[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
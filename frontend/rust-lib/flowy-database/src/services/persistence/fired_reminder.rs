@@ -0,0 +1,49 @@
+use crate::services::persistence::DatabaseDBConnection;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use flowy_error::FlowyResult;
+use flowy_sqlite::{
+  prelude::*,
+  schema::{fired_reminder_table, fired_reminder_table::dsl},
+};
+use lib_infra::util::timestamp;
+use std::sync::Arc;
+
+/// Keeps the ids of the reminders that were added to the inbox, so each reminder is only fired
+/// once on this device.
+pub struct FiredReminders {
+  database: Arc<dyn DatabaseDBConnection>,
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[table_name = "fired_reminder_table"]
+#[primary_key(reminder_id)]
+struct FiredReminderTable {
+  reminder_id: String,
+  fired_at: i64,
+}
+
+impl FiredReminders {
+  pub fn new(database: Arc<dyn DatabaseDBConnection>) -> Self {
+    Self { database }
+  }
+
+  pub fn insert(&self, reminder_id: &str) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    let _ = diesel::replace_into(fired_reminder_table::table)
+      .values(FiredReminderTable {
+        reminder_id: reminder_id.to_owned(),
+        fired_at: timestamp(),
+      })
+      .execute(&*conn)?;
+    Ok(())
+  }
+
+  pub fn is_fired(&self, reminder_id: &str) -> FlowyResult<bool> {
+    let conn = self.database.get_db_connection()?;
+    let count = dsl::fired_reminder_table
+      .filter(fired_reminder_table::reminder_id.eq(reminder_id))
+      .count()
+      .get_result::<i64>(&*conn)?;
+    Ok(count > 0)
+  }
+}
@@ -0,0 +1,140 @@
+use crate::services::persistence::DatabaseDBConnection;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use flowy_error::FlowyResult;
+use flowy_sqlite::{
+  prelude::*,
+  schema::{import_checkpoint_table, import_checkpoint_table::dsl},
+};
+use lib_infra::util::timestamp;
+use std::sync::Arc;
+
+/// Persists the progress of the imports. Each import writes a checkpoint after a chunk of
+/// rows was committed to the database, so an import that was interrupted by a crash or an app
+/// kill can be resumed from the last committed chunk.
+pub struct ImportCheckpoints {
+  database: Arc<dyn DatabaseDBConnection>,
+}
+
+impl ImportCheckpoints {
+  pub fn new(database: Arc<dyn DatabaseDBConnection>) -> Self {
+    Self { database }
+  }
+
+  pub fn save(&self, checkpoint: ImportCheckpoint) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    let record = ImportCheckpointRecord::from(checkpoint);
+    let _ = diesel::replace_into(import_checkpoint_table::table)
+      .values(record)
+      .execute(&*conn)?;
+    Ok(())
+  }
+
+  pub fn update_next_row(&self, import_id: &str, next_row: usize) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    diesel::update(
+      dsl::import_checkpoint_table.filter(import_checkpoint_table::import_id.eq(import_id)),
+    )
+    .set((
+      import_checkpoint_table::next_row.eq(next_row as i64),
+      import_checkpoint_table::timestamp.eq(timestamp()),
+    ))
+    .execute(&*conn)?;
+    Ok(())
+  }
+
+  pub fn remove(&self, import_id: &str) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    diesel::delete(
+      dsl::import_checkpoint_table.filter(import_checkpoint_table::import_id.eq(import_id)),
+    )
+    .execute(&*conn)?;
+    Ok(())
+  }
+
+  pub fn get_with_view(&self, view_id: &str) -> FlowyResult<Option<ImportCheckpoint>> {
+    let conn = self.database.get_db_connection()?;
+    let checkpoint = dsl::import_checkpoint_table
+      .filter(import_checkpoint_table::view_id.eq(view_id))
+      .load::<ImportCheckpointRecord>(&*conn)?
+      .into_iter()
+      .next()
+      .map(|record| record.into());
+    Ok(checkpoint)
+  }
+
+  pub fn get_all(&self) -> FlowyResult<Vec<ImportCheckpoint>> {
+    let conn = self.database.get_db_connection()?;
+    let checkpoints = dsl::import_checkpoint_table
+      .load::<ImportCheckpointRecord>(&*conn)?
+      .into_iter()
+      .map(|record| record.into())
+      .collect::<Vec<ImportCheckpoint>>();
+    Ok(checkpoints)
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum ImportType {
+  CSV = 0,
+  TrelloJSON = 1,
+}
+
+impl std::convert::TryFrom<i32> for ImportType {
+  type Error = ();
+
+  fn try_from(value: i32) -> Result<Self, Self::Error> {
+    match value {
+      0 => Ok(ImportType::CSV),
+      1 => Ok(ImportType::TrelloJSON),
+      _ => Err(()),
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportCheckpoint {
+  pub import_id: String,
+  pub view_id: String,
+  pub import_type: ImportType,
+  pub file_path: String,
+  /// The index of the first record in the file that hasn't been committed yet
+  pub next_row: usize,
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[table_name = "import_checkpoint_table"]
+#[primary_key(import_id)]
+struct ImportCheckpointRecord {
+  import_id: String,
+  view_id: String,
+  import_type: i32,
+  file_path: String,
+  next_row: i64,
+  timestamp: i64,
+}
+
+impl std::convert::From<ImportCheckpoint> for ImportCheckpointRecord {
+  fn from(checkpoint: ImportCheckpoint) -> Self {
+    Self {
+      import_id: checkpoint.import_id,
+      view_id: checkpoint.view_id,
+      import_type: checkpoint.import_type as i32,
+      file_path: checkpoint.file_path,
+      next_row: checkpoint.next_row as i64,
+      timestamp: timestamp(),
+    }
+  }
+}
+
+impl std::convert::From<ImportCheckpointRecord> for ImportCheckpoint {
+  fn from(record: ImportCheckpointRecord) -> Self {
+    Self {
+      import_id: record.import_id,
+      view_id: record.view_id,
+      import_type: ImportType::try_from(record.import_type).unwrap_or(ImportType::CSV),
+      file_path: record.file_path,
+      next_row: record.next_row.max(0) as usize,
+    }
+  }
+}
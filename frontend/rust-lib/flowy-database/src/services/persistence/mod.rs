@@ -2,11 +2,21 @@ use flowy_error::FlowyError;
 use flowy_sqlite::{ConnectionPool, DBConnection};
 use std::sync::Arc;
 
+pub mod automation_activity;
+pub mod automation_view;
 pub mod block_index;
 pub mod database_ref;
+pub mod fired_reminder;
+pub mod import_checkpoint;
 pub mod kv;
 pub mod migration;
+pub mod relation_link;
 pub mod rev_sqlite;
+pub mod row_display_value;
+pub mod row_document;
+pub mod row_mention;
+pub mod row_share;
+pub mod row_trash;
 
 pub trait DatabaseDBConnection: Send + Sync {
   fn get_db_pool(&self) -> Result<Arc<ConnectionPool>, FlowyError>;
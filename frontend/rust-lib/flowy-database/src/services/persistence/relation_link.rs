@@ -0,0 +1,120 @@
+use crate::services::persistence::DatabaseDBConnection;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_sqlite::{
+  prelude::*,
+  schema::{relation_link_table, relation_link_table::dsl},
+};
+use std::sync::Arc;
+
+/// The reverse index of the relation cells. Each link is a row id in a relation cell, so the
+/// cells that link a row can be found without opening all the databases.
+pub struct RelationLinkIndex {
+  database: Arc<dyn DatabaseDBConnection>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelationLink {
+  pub database_id: String,
+  pub field_id: String,
+  pub row_id: String,
+  pub linked_database_id: String,
+  pub linked_row_id: String,
+}
+
+impl RelationLinkIndex {
+  pub fn new(database: Arc<dyn DatabaseDBConnection>) -> Self {
+    Self { database }
+  }
+
+  /// Replaces the links of the rows of the database. The links of all the rows are replaced if
+  /// the `row_ids` is None.
+  pub fn replace_links(
+    &self,
+    database_id: &str,
+    row_ids: Option<&[String]>,
+    links: Vec<RelationLink>,
+  ) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    let records = links
+      .into_iter()
+      .map(RelationLinkRecord::from)
+      .collect::<Vec<_>>();
+    conn.immediate_transaction::<_, FlowyError, _>(|| {
+      match row_ids {
+        None => {
+          diesel::delete(dsl::relation_link_table.filter(dsl::database_id.eq(database_id)))
+            .execute(&*conn)?;
+        },
+        Some(row_ids) => {
+          diesel::delete(
+            dsl::relation_link_table
+              .filter(dsl::database_id.eq(database_id))
+              .filter(dsl::row_id.eq_any(row_ids)),
+          )
+          .execute(&*conn)?;
+        },
+      }
+      for record in records {
+        diesel::replace_into(relation_link_table::table)
+          .values(record)
+          .execute(&*conn)?;
+      }
+      Ok(())
+    })
+  }
+
+  /// Returns the links to the rows of the `linked_database_id`, or only to the `linked_row_ids`
+  /// if it's not None.
+  pub fn get_links(
+    &self,
+    linked_database_id: &str,
+    linked_row_ids: Option<&[String]>,
+  ) -> FlowyResult<Vec<RelationLink>> {
+    let conn = self.database.get_db_connection()?;
+    let mut query = dsl::relation_link_table
+      .filter(dsl::linked_database_id.eq(linked_database_id))
+      .into_boxed();
+    if let Some(linked_row_ids) = linked_row_ids {
+      query = query.filter(dsl::linked_row_id.eq_any(linked_row_ids));
+    }
+    let records = query.load::<RelationLinkRecord>(&*conn)?;
+    Ok(records.into_iter().map(RelationLink::from).collect())
+  }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[table_name = "relation_link_table"]
+struct RelationLinkRecord {
+  id: String,
+  database_id: String,
+  field_id: String,
+  row_id: String,
+  linked_database_id: String,
+  linked_row_id: String,
+}
+
+impl std::convert::From<RelationLink> for RelationLinkRecord {
+  fn from(link: RelationLink) -> Self {
+    Self {
+      id: format!("{}:{}:{}", link.field_id, link.row_id, link.linked_row_id),
+      database_id: link.database_id,
+      field_id: link.field_id,
+      row_id: link.row_id,
+      linked_database_id: link.linked_database_id,
+      linked_row_id: link.linked_row_id,
+    }
+  }
+}
+
+impl std::convert::From<RelationLinkRecord> for RelationLink {
+  fn from(record: RelationLinkRecord) -> Self {
+    Self {
+      database_id: record.database_id,
+      field_id: record.field_id,
+      row_id: record.row_id,
+      linked_database_id: record.linked_database_id,
+      linked_row_id: record.linked_row_id,
+    }
+  }
+}
@@ -0,0 +1,85 @@
+use crate::services::persistence::DatabaseDBConnection;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_sqlite::{
+  prelude::*,
+  schema::{row_display_value_table, row_display_value_table::dsl},
+};
+use std::sync::Arc;
+
+/// The display strings of the rows' primary cell. Each display value is saved with the hash of
+/// the primary cell and the primary field it was computed from, so a stale display value is
+/// detected by comparing the hashes when it's read.
+pub struct RowDisplayValueStorage {
+  database: Arc<dyn DatabaseDBConnection>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowDisplayValue {
+  pub row_id: String,
+  pub source_hash: u64,
+  pub display_value: String,
+}
+
+impl RowDisplayValueStorage {
+  pub fn new(database: Arc<dyn DatabaseDBConnection>) -> Self {
+    Self { database }
+  }
+
+  pub fn get_display_values(&self, row_ids: &[String]) -> FlowyResult<Vec<RowDisplayValue>> {
+    let conn = self.database.get_db_connection()?;
+    let records = dsl::row_display_value_table
+      .filter(dsl::row_id.eq_any(row_ids))
+      .load::<RowDisplayValueRecord>(&*conn)?;
+    Ok(records.into_iter().map(RowDisplayValue::from).collect())
+  }
+
+  pub fn save_display_values(
+    &self,
+    database_id: &str,
+    display_values: Vec<RowDisplayValue>,
+  ) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    conn.immediate_transaction::<_, FlowyError, _>(|| {
+      for display_value in display_values {
+        let record = RowDisplayValueRecord {
+          row_id: display_value.row_id,
+          database_id: database_id.to_owned(),
+          source_hash: display_value.source_hash as i64,
+          display_value: display_value.display_value,
+        };
+        diesel::replace_into(row_display_value_table::table)
+          .values(record)
+          .execute(&*conn)?;
+      }
+      Ok(())
+    })
+  }
+
+  pub fn delete_display_values(&self, database_id: &str) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    diesel::delete(dsl::row_display_value_table.filter(dsl::database_id.eq(database_id)))
+      .execute(&*conn)?;
+    Ok(())
+  }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[table_name = "row_display_value_table"]
+#[primary_key(row_id)]
+struct RowDisplayValueRecord {
+  row_id: String,
+  database_id: String,
+  source_hash: i64,
+  display_value: String,
+}
+
+impl std::convert::From<RowDisplayValueRecord> for RowDisplayValue {
+  fn from(record: RowDisplayValueRecord) -> Self {
+    Self {
+      row_id: record.row_id,
+      source_hash: record.source_hash as u64,
+      display_value: record.display_value,
+    }
+  }
+}
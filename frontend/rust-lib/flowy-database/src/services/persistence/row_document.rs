@@ -0,0 +1,58 @@
+use crate::services::persistence::DatabaseDBConnection;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use flowy_error::FlowyResult;
+use flowy_sqlite::{
+  prelude::*,
+  schema::{row_document_table, row_document_table::dsl},
+};
+use std::sync::Arc;
+
+/// Keeps the ids of the documents of the rows. The document keeps the long-form content of the
+/// row, e.g. the description of a card that was imported from Trello. Its id is generated when
+/// the document is created, so it's looked up here by the id of the row.
+pub struct RowDocumentIndex {
+  database: Arc<dyn DatabaseDBConnection>,
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[table_name = "row_document_table"]
+#[primary_key(row_id)]
+struct RowDocumentTable {
+  row_id: String,
+  document_id: String,
+}
+
+impl RowDocumentIndex {
+  pub fn new(database: Arc<dyn DatabaseDBConnection>) -> Self {
+    Self { database }
+  }
+
+  pub fn insert(&self, row_id: &str, document_id: &str) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    let _ = diesel::replace_into(row_document_table::table)
+      .values(RowDocumentTable {
+        row_id: row_id.to_owned(),
+        document_id: document_id.to_owned(),
+      })
+      .execute(&*conn)?;
+    Ok(())
+  }
+
+  pub fn remove(&self, row_id: &str) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    diesel::delete(dsl::row_document_table.filter(row_document_table::row_id.eq(row_id)))
+      .execute(&*conn)?;
+    Ok(())
+  }
+
+  pub fn get_document_id(&self, row_id: &str) -> FlowyResult<Option<String>> {
+    let conn = self.database.get_db_connection()?;
+    let document_id = dsl::row_document_table
+      .filter(row_document_table::row_id.eq(row_id))
+      .select(row_document_table::document_id)
+      .load::<String>(&*conn)?
+      .into_iter()
+      .next();
+    Ok(document_id)
+  }
+}
@@ -0,0 +1,111 @@
+use crate::services::persistence::DatabaseDBConnection;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use flowy_error::{FlowyError, FlowyResult};
+use flowy_sqlite::{
+  prelude::*,
+  schema::{row_mention_table, row_mention_table::dsl},
+};
+use std::sync::Arc;
+
+/// The reverse index of the mentions in the text cells. Each mention is the id of a row or a
+/// view in a text cell, so the cells that mention a row can be found without opening all the
+/// databases. It's the backlinks of the rows.
+pub struct RowMentionIndex {
+  database: Arc<dyn DatabaseDBConnection>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowMention {
+  pub database_id: String,
+  pub field_id: String,
+  pub row_id: String,
+  pub mentioned_id: String,
+}
+
+impl RowMentionIndex {
+  pub fn new(database: Arc<dyn DatabaseDBConnection>) -> Self {
+    Self { database }
+  }
+
+  /// Replaces the mentions of the rows of the database. The mentions of all the rows are
+  /// replaced if the `row_ids` is None.
+  pub fn replace_mentions(
+    &self,
+    database_id: &str,
+    row_ids: Option<&[String]>,
+    mentions: Vec<RowMention>,
+  ) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    let records = mentions
+      .into_iter()
+      .map(RowMentionRecord::from)
+      .collect::<Vec<_>>();
+    conn.immediate_transaction::<_, FlowyError, _>(|| {
+      match row_ids {
+        None => {
+          diesel::delete(dsl::row_mention_table.filter(dsl::database_id.eq(database_id)))
+            .execute(&*conn)?;
+        },
+        Some(row_ids) => {
+          diesel::delete(
+            dsl::row_mention_table
+              .filter(dsl::database_id.eq(database_id))
+              .filter(dsl::row_id.eq_any(row_ids)),
+          )
+          .execute(&*conn)?;
+        },
+      }
+      for record in records {
+        diesel::replace_into(row_mention_table::table)
+          .values(record)
+          .execute(&*conn)?;
+      }
+      Ok(())
+    })
+  }
+
+  /// Returns the mentions of the row or the view in all the databases.
+  pub fn get_mentions(&self, mentioned_id: &str) -> FlowyResult<Vec<RowMention>> {
+    let conn = self.database.get_db_connection()?;
+    let records = dsl::row_mention_table
+      .filter(dsl::mentioned_id.eq(mentioned_id))
+      .load::<RowMentionRecord>(&*conn)?;
+    Ok(records.into_iter().map(RowMention::from).collect())
+  }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[table_name = "row_mention_table"]
+struct RowMentionRecord {
+  id: String,
+  database_id: String,
+  field_id: String,
+  row_id: String,
+  mentioned_id: String,
+}
+
+impl std::convert::From<RowMention> for RowMentionRecord {
+  fn from(mention: RowMention) -> Self {
+    Self {
+      id: format!(
+        "{}:{}:{}",
+        mention.field_id, mention.row_id, mention.mentioned_id
+      ),
+      database_id: mention.database_id,
+      field_id: mention.field_id,
+      row_id: mention.row_id,
+      mentioned_id: mention.mentioned_id,
+    }
+  }
+}
+
+impl std::convert::From<RowMentionRecord> for RowMention {
+  fn from(record: RowMentionRecord) -> Self {
+    Self {
+      database_id: record.database_id,
+      field_id: record.field_id,
+      row_id: record.row_id,
+      mentioned_id: record.mentioned_id,
+    }
+  }
+}
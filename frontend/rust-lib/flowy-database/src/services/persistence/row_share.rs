@@ -0,0 +1,97 @@
+use crate::services::persistence::DatabaseDBConnection;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use flowy_error::FlowyResult;
+use flowy_sqlite::{
+  prelude::*,
+  schema::{row_share_table, row_share_table::dsl},
+};
+use lib_infra::util::timestamp;
+use nanoid::nanoid;
+use std::sync::Arc;
+
+/// Keeps the read-only links of the rows. A link stays active until it gets revoked.
+pub struct RowShares {
+  database: Arc<dyn DatabaseDBConnection>,
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[table_name = "row_share_table"]
+#[primary_key(share_id)]
+pub struct RowShare {
+  pub share_id: String,
+  pub database_id: String,
+  /// The view that the row was shared from, the shared row is rendered with its fields.
+  pub view_id: String,
+  pub row_id: String,
+  /// The timestamp in seconds when the row was shared
+  pub created_at: i64,
+}
+
+impl RowShares {
+  pub fn new(database: Arc<dyn DatabaseDBConnection>) -> Self {
+    Self { database }
+  }
+
+  pub fn add(&self, database_id: &str, view_id: &str, row_id: &str) -> FlowyResult<RowShare> {
+    let conn = self.database.get_db_connection()?;
+    let share = RowShare {
+      // The id is a part of the link, so it must not be guessable
+      share_id: nanoid!(),
+      database_id: database_id.to_owned(),
+      view_id: view_id.to_owned(),
+      row_id: row_id.to_owned(),
+      created_at: timestamp(),
+    };
+    let _ = diesel::insert_into(row_share_table::table)
+      .values(share.clone())
+      .execute(&*conn)?;
+    Ok(share)
+  }
+
+  /// Returns the number of the removed shares, it's 0 if the share doesn't exist.
+  pub fn remove(&self, share_id: &str) -> FlowyResult<usize> {
+    let conn = self.database.get_db_connection()?;
+    let count = diesel::delete(dsl::row_share_table.filter(row_share_table::share_id.eq(share_id)))
+      .execute(&*conn)?;
+    Ok(count)
+  }
+
+  /// Points the shares of the row in the database to the new id of the row. The links keep
+  /// working because they only contain the ids of the shares.
+  pub fn update_row_id(
+    &self,
+    database_id: &str,
+    row_id: &str,
+    new_row_id: &str,
+  ) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    diesel::update(
+      dsl::row_share_table
+        .filter(row_share_table::database_id.eq(database_id))
+        .filter(row_share_table::row_id.eq(row_id)),
+    )
+    .set(row_share_table::row_id.eq(new_row_id))
+    .execute(&*conn)?;
+    Ok(())
+  }
+
+  pub fn get(&self, share_id: &str) -> FlowyResult<Option<RowShare>> {
+    let conn = self.database.get_db_connection()?;
+    let share = dsl::row_share_table
+      .filter(row_share_table::share_id.eq(share_id))
+      .load::<RowShare>(&*conn)?
+      .into_iter()
+      .next();
+    Ok(share)
+  }
+
+  /// Returns the shares of the database, the most recently created share comes first.
+  pub fn get_all(&self, database_id: &str) -> FlowyResult<Vec<RowShare>> {
+    let conn = self.database.get_db_connection()?;
+    let shares = dsl::row_share_table
+      .filter(row_share_table::database_id.eq(database_id))
+      .order(row_share_table::created_at.desc())
+      .load::<RowShare>(&*conn)?;
+    Ok(shares)
+  }
+}
@@ -0,0 +1,184 @@
+use crate::services::persistence::DatabaseDBConnection;
+use database_model::RowRevision;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use flowy_sqlite::{
+  prelude::*,
+  schema::{row_trash_table, row_trash_table::dsl},
+};
+use lib_infra::util::timestamp;
+use std::sync::Arc;
+
+/// Keeps the rows that were deleted from the databases, so they can be restored until they
+/// get purged after the retention period.
+pub struct RowTrash {
+  database: Arc<dyn DatabaseDBConnection>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TrashedRow {
+  pub database_id: String,
+  pub row_rev: RowRevision,
+  /// The timestamp in seconds when the row was moved to the trash
+  pub deleted_at: i64,
+}
+
+impl RowTrash {
+  pub fn new(database: Arc<dyn DatabaseDBConnection>) -> Self {
+    Self { database }
+  }
+
+  pub fn add(&self, database_id: &str, row_rev: &RowRevision) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    let record = RowTrashRecord {
+      row_id: row_rev.id.clone(),
+      database_id: database_id.to_owned(),
+      data: serde_json::to_string(row_rev).map_err(internal_error)?,
+      deleted_at: timestamp(),
+    };
+    let _ = diesel::replace_into(row_trash_table::table)
+      .values(record)
+      .execute(&*conn)?;
+    Ok(())
+  }
+
+  /// Adds the rows in one transaction, so either all of them are added or none.
+  pub fn add_rows(&self, database_id: &str, row_revs: &[Arc<RowRevision>]) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    let deleted_at = timestamp();
+    conn.immediate_transaction::<_, FlowyError, _>(|| {
+      for row_rev in row_revs {
+        let record = RowTrashRecord {
+          row_id: row_rev.id.clone(),
+          database_id: database_id.to_owned(),
+          data: serde_json::to_string(row_rev.as_ref()).map_err(internal_error)?,
+          deleted_at,
+        };
+        let _ = diesel::replace_into(row_trash_table::table)
+          .values(record)
+          .execute(&*conn)?;
+      }
+      Ok(())
+    })
+  }
+
+  pub fn remove(&self, row_id: &str) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    diesel::delete(dsl::row_trash_table.filter(row_trash_table::row_id.eq(row_id)))
+      .execute(&*conn)?;
+    Ok(())
+  }
+
+  pub fn remove_rows(&self, row_ids: &[String]) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    diesel::delete(dsl::row_trash_table.filter(row_trash_table::row_id.eq_any(row_ids)))
+      .execute(&*conn)?;
+    Ok(())
+  }
+
+  /// Gives the trashed row a new id, e.g. a live row uses the same id. The row keeps the time
+  /// it was deleted.
+  pub fn update_row_id(&self, row_id: &str, new_row_id: &str) -> FlowyResult<()> {
+    let conn = self.database.get_db_connection()?;
+    conn.immediate_transaction::<_, FlowyError, _>(|| {
+      let record = dsl::row_trash_table
+        .filter(row_trash_table::row_id.eq(row_id))
+        .load::<RowTrashRecord>(&*conn)?
+        .into_iter()
+        .next();
+      if let Some(record) = record {
+        let mut row_rev =
+          serde_json::from_str::<RowRevision>(&record.data).map_err(internal_error)?;
+        row_rev.id = new_row_id.to_owned();
+        diesel::delete(dsl::row_trash_table.filter(row_trash_table::row_id.eq(row_id)))
+          .execute(&*conn)?;
+        let _ = diesel::insert_into(row_trash_table::table)
+          .values(RowTrashRecord {
+            row_id: new_row_id.to_owned(),
+            data: serde_json::to_string(&row_rev).map_err(internal_error)?,
+            ..record
+          })
+          .execute(&*conn)?;
+      }
+      Ok(())
+    })
+  }
+
+  pub fn get(&self, row_id: &str) -> FlowyResult<Option<TrashedRow>> {
+    let conn = self.database.get_db_connection()?;
+    let record = dsl::row_trash_table
+      .filter(row_trash_table::row_id.eq(row_id))
+      .load::<RowTrashRecord>(&*conn)?
+      .into_iter()
+      .next();
+    match record {
+      None => Ok(None),
+      Some(record) => Ok(Some(TrashedRow::try_from(record)?)),
+    }
+  }
+
+  /// Returns the trashed rows of the database, the most recently deleted row comes first.
+  pub fn get_all(&self, database_id: &str) -> FlowyResult<Vec<TrashedRow>> {
+    let conn = self.database.get_db_connection()?;
+    let records = dsl::row_trash_table
+      .filter(row_trash_table::database_id.eq(database_id))
+      .order(row_trash_table::deleted_at.desc())
+      .load::<RowTrashRecord>(&*conn)?;
+
+    let mut trashed_rows = vec![];
+    for record in records {
+      match TrashedRow::try_from(record) {
+        Ok(trashed_row) => trashed_rows.push(trashed_row),
+        Err(err) => tracing::error!("Deserialize the trashed row failed: {:?}", err),
+      }
+    }
+    Ok(trashed_rows)
+  }
+
+  /// Permanently deletes the rows that were moved to the trash before `deleted_before`.
+  /// Returns the purged rows, their attached files might need to be deleted.
+  pub fn purge(&self, deleted_before: i64) -> FlowyResult<Vec<TrashedRow>> {
+    let conn = self.database.get_db_connection()?;
+    let records = conn.immediate_transaction::<_, FlowyError, _>(|| {
+      let records = dsl::row_trash_table
+        .filter(row_trash_table::deleted_at.lt(deleted_before))
+        .load::<RowTrashRecord>(&*conn)?;
+      diesel::delete(dsl::row_trash_table.filter(row_trash_table::deleted_at.lt(deleted_before)))
+        .execute(&*conn)?;
+      Ok(records)
+    })?;
+
+    let mut purged_rows = vec![];
+    for record in records {
+      match TrashedRow::try_from(record) {
+        Ok(trashed_row) => purged_rows.push(trashed_row),
+        Err(err) => tracing::error!("Deserialize the trashed row failed: {:?}", err),
+      }
+    }
+    Ok(purged_rows)
+  }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[table_name = "row_trash_table"]
+#[primary_key(row_id)]
+struct RowTrashRecord {
+  row_id: String,
+  database_id: String,
+  /// The json string of the [RowRevision]
+  data: String,
+  deleted_at: i64,
+}
+
+impl std::convert::TryFrom<RowTrashRecord> for TrashedRow {
+  type Error = flowy_error::FlowyError;
+
+  fn try_from(record: RowTrashRecord) -> Result<Self, Self::Error> {
+    let row_rev = serde_json::from_str::<RowRevision>(&record.data).map_err(internal_error)?;
+    Ok(Self {
+      database_id: record.database_id,
+      row_rev,
+      deleted_at: record.deleted_at,
+    })
+  }
+}
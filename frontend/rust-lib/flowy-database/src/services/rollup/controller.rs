@@ -0,0 +1,189 @@
+use crate::entities::FieldType;
+use crate::manager::DatabaseManager;
+use crate::services::cell::TypeCellData;
+use crate::services::database::DatabaseEditor;
+use crate::services::field::{
+  RelationCellData, RelationTypeOptionPB, RollupCellData, RollupTypeOptionPB,
+};
+use database_model::FieldRevision;
+use flowy_error::FlowyResult;
+use parking_lot::Mutex;
+use std::collections::HashSet;
+use std::sync::{Arc, Weak};
+
+/// [RollupController] keeps the rollup cells of the databases up to date.
+///
+/// The changed databases are collected until the calculation task of the database gets run, see
+/// [CalculationController](crate::services::calculation::CalculationController). The task
+/// calculates the rollup cells that depend on the changed database again. A rollup depends on the database it
+/// belongs to, where its relation cells live, and on the related database, where the linked rows
+/// live. The databases that aren't opened but link the rows of the changed database are opened
+/// to calculate their rollups, they are found in the
+/// [RelationLinkIndex](crate::services::persistence::relation_link::RelationLinkIndex). Only
+/// the cells whose value changed are saved, so saving the rollup cells doesn't
+/// trigger the calculation endlessly.
+pub struct RollupController {
+  database_manager: Weak<DatabaseManager>,
+  /// The databases that are waiting to be processed
+  pending_database_ids: Mutex<HashSet<String>>,
+}
+
+impl RollupController {
+  pub(crate) fn new(database_manager: Weak<DatabaseManager>) -> Self {
+    Self {
+      database_manager,
+      pending_database_ids: Mutex::new(HashSet::new()),
+    }
+  }
+
+  /// Collects the database, the rollup cells that depend on it are calculated again.
+  pub(crate) fn did_change_database(&self, database_id: &str) {
+    self
+      .pending_database_ids
+      .lock()
+      .insert(database_id.to_owned());
+  }
+
+  #[tracing::instrument(name = "process_rollup_task", level = "trace", skip(self), err)]
+  pub(crate) async fn process(&self, database_id: &str) -> FlowyResult<()> {
+    if !self.pending_database_ids.lock().remove(database_id) {
+      return Ok(());
+    }
+    let database_manager = match self.database_manager.upgrade() {
+      None => return Ok(()),
+      Some(database_manager) => database_manager,
+    };
+
+    let mut editors = database_manager.get_opened_database_editors().await;
+    for linking_database_id in database_manager
+      .get_linking_database_ids(database_id)
+      .await?
+    {
+      if editors
+        .iter()
+        .any(|editor| editor.database_id == linking_database_id)
+      {
+        continue;
+      }
+      if let Some(editor) = database_manager
+        .get_database_editor_with_database_id(&linking_database_id)
+        .await?
+      {
+        editors.push(editor);
+      }
+    }
+
+    for editor in editors {
+      if let Err(err) = self
+        .calculate_rollups(&database_manager, &editor, database_id)
+        .await
+      {
+        tracing::error!(
+          "Calculate the rollups of database:{} failed: {:?}",
+          editor.database_id,
+          err
+        );
+      }
+    }
+    Ok(())
+  }
+
+  /// Calculates all the rollup cells of the editor's database right away instead of waiting for
+  /// the calculation task.
+  pub(crate) async fn recalculate(&self, editor: &Arc<DatabaseEditor>) -> FlowyResult<()> {
+    match self.database_manager.upgrade() {
+      None => Ok(()),
+      Some(database_manager) => {
+        self
+          .calculate_rollups(&database_manager, editor, &editor.database_id)
+          .await
+      },
+    }
+  }
+
+  /// Calculates the rollup cells of the editor's database that depend on the changed database.
+  async fn calculate_rollups(
+    &self,
+    database_manager: &DatabaseManager,
+    editor: &Arc<DatabaseEditor>,
+    changed_database_id: &str,
+  ) -> FlowyResult<()> {
+    let field_revs = editor.get_field_revs(None).await?;
+    for field_rev in field_revs.iter() {
+      if !FieldType::from(field_rev.ty).is_rollup() {
+        continue;
+      }
+
+      let type_option = RollupTypeOptionPB::from(field_rev.as_ref());
+      let related_database_id = match get_relation_field_rev(&field_revs, &type_option) {
+        None => continue,
+        Some(relation_field_rev) => {
+          RelationTypeOptionPB::from(relation_field_rev.as_ref()).database_id
+        },
+      };
+      if related_database_id.is_empty()
+        || (editor.database_id != changed_database_id && related_database_id != changed_database_id)
+      {
+        continue;
+      }
+
+      let related_editor = match database_manager
+        .get_database_editor_with_database_id(&related_database_id)
+        .await?
+      {
+        None => continue,
+        Some(related_editor) => related_editor,
+      };
+      if related_editor
+        .get_field_rev(&type_option.target_field_id)
+        .await
+        .is_none()
+      {
+        continue;
+      }
+
+      for block in editor.get_blocks(None).await? {
+        for row_rev in block.row_revs.iter() {
+          let get_type_cell_data = |field_id: &str| {
+            row_rev
+              .cells
+              .get(field_id)
+              .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+          };
+          let linked_row_ids = get_type_cell_data(&type_option.relation_field_id)
+            .filter(|type_cell_data| type_cell_data.is_relation())
+            .map(|type_cell_data| RelationCellData::from(type_cell_data.cell_str).row_ids)
+            .unwrap_or_default();
+          let values = if linked_row_ids.is_empty() {
+            vec![]
+          } else {
+            related_editor
+              .get_rollup_values(&linked_row_ids, &type_option.target_field_id)
+              .await?
+          };
+
+          let cell_str =
+            RollupCellData::from(type_option.calculation.calculate(&values)).to_string();
+          let old_cell_str = get_type_cell_data(&field_rev.id)
+            .filter(|type_cell_data| type_cell_data.is_rollup())
+            .map(|type_cell_data| type_cell_data.cell_str);
+          if old_cell_str.as_ref() != Some(&cell_str) {
+            editor
+              .update_computed_cell(&row_rev.id, field_rev, cell_str)
+              .await?;
+          }
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+fn get_relation_field_rev<'a>(
+  field_revs: &'a [Arc<FieldRevision>],
+  type_option: &RollupTypeOptionPB,
+) -> Option<&'a Arc<FieldRevision>> {
+  field_revs.iter().find(|field_rev| {
+    field_rev.id == type_option.relation_field_id && FieldType::from(field_rev.ty).is_relation()
+  })
+}
@@ -1,5 +1,7 @@
 mod row_builder;
+mod row_id_audit;
 mod row_loader;
 
 pub use row_builder::*;
+pub use row_id_audit::*;
 pub use row_loader::*;
@@ -1,12 +1,21 @@
 use crate::services::cell::{
-  insert_checkbox_cell, insert_date_cell, insert_number_cell, insert_select_option_cell,
-  insert_text_cell, insert_url_cell, FromCellString,
+  insert_checkbox_cell, insert_checklist_cell, insert_date_cell, insert_number_cell,
+  insert_person_cell, insert_relation_cell, insert_select_option_cell, insert_text_cell,
+  insert_url_cell, CellSizeLimits, FromCellString, TypeCellData,
 };
 
 use crate::entities::FieldType;
-use crate::services::field::{CheckboxCellData, DateCellData, SelectOptionIds};
+use crate::services::field::{
+  parent_option_ids_in_cells, select_type_option_from_field_rev, CheckboxCellData,
+  ChecklistCellData, ChecklistTypeOptionPB, CreatedTimeTypeOptionPB, DateCellData,
+  DurationCellData, EmailCellData, LastEditedTimeTypeOptionPB, Location, LocationCellData,
+  PersonCellData, PhoneCellData, PhoneTypeOptionPB, RelationCellData, SelectOptionIds,
+  TimeTrackingCellData, TypeOptionCellData,
+};
 use database_model::{gen_row_id, CellRevision, FieldRevision, RowRevision, DEFAULT_ROW_HEIGHT};
+use flowy_error::{FlowyError, FlowyResult};
 use indexmap::IndexMap;
+use lib_infra::util::timestamp;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -14,17 +23,23 @@ pub struct RowRevisionBuilder {
   block_id: String,
   field_rev_map: HashMap<String, Arc<FieldRevision>>,
   payload: CreateRowRevisionPayload,
+  cell_size_limits: Option<CellSizeLimits>,
+  /// The first error of inserting the cells, it's returned by [RowRevisionBuilder::try_build]
+  error: Option<FlowyError>,
 }
 
 impl RowRevisionBuilder {
   pub fn new(block_id: &str, fields: Vec<Arc<FieldRevision>>) -> Self {
-    Self::new_with_data(block_id, fields, Default::default())
+    Self::new_with_data(block_id, fields, Default::default(), None)
   }
 
+  /// cell_size_limits: The cells are not limited if it's None. Otherwise, the cells that exceed
+  /// the limits are not inserted and [RowRevisionBuilder::try_build] returns the error.
   pub fn new_with_data(
     block_id: &str,
     field_revs: Vec<Arc<FieldRevision>>,
     cell_data_by_field_id: HashMap<String, String>,
+    cell_size_limits: Option<CellSizeLimits>,
   ) -> Self {
     let field_rev_map = field_revs
       .iter()
@@ -43,6 +58,8 @@ impl RowRevisionBuilder {
       block_id,
       field_rev_map,
       payload,
+      cell_size_limits,
+      error: None,
     };
 
     for (field_id, cell_data) in cell_data_by_field_id {
@@ -50,7 +67,7 @@ impl RowRevisionBuilder {
         let field_type: FieldType = field_rev.ty.into();
         match field_type {
           FieldType::RichText => builder.insert_text_cell(&field_id, cell_data),
-          FieldType::Number => {
+          FieldType::Number | FieldType::Progress => {
             if let Ok(num) = cell_data.parse::<i64>() {
               builder.insert_number_cell(&field_id, num)
             }
@@ -73,11 +90,64 @@ impl RowRevisionBuilder {
           FieldType::URL => {
             builder.insert_url_cell(&field_id, cell_data);
           },
+          FieldType::Duration => {
+            if let Ok(duration) = DurationCellData::parse(&cell_data) {
+              builder.insert_text_cell(&field_id, duration.to_string());
+            }
+          },
+          FieldType::TimeTracking => {
+            if let Ok(DurationCellData(Some(seconds))) = DurationCellData::parse(&cell_data) {
+              let time_tracking = TimeTrackingCellData {
+                accumulated: seconds,
+                started_at: None,
+              };
+              builder.insert_text_cell(&field_id, time_tracking.to_string());
+            }
+          },
+          FieldType::Location => {
+            if let Some(location) = Location::parse(&cell_data) {
+              builder.insert_text_cell(&field_id, LocationCellData(Some(location)).to_string());
+            }
+          },
+          FieldType::Email => {
+            if let Ok(email) = EmailCellData::parse(&cell_data) {
+              builder.insert_text_cell(&field_id, email.to_string());
+            }
+          },
+          FieldType::Phone => {
+            let type_option = field_rev
+              .get_type_option::<PhoneTypeOptionPB>(field_rev.ty)
+              .unwrap_or_default();
+            if let Ok(phone) = PhoneCellData::parse(&cell_data, &type_option.default_country_code) {
+              builder.insert_text_cell(&field_id, phone.to_string());
+            }
+          },
           FieldType::Checklist => {
-            if let Ok(ids) = SelectOptionIds::from_cell_str(&cell_data) {
-              builder.insert_select_option_cell(&field_id, ids.into_inner());
+            let type_option = ChecklistTypeOptionPB::from(field_rev);
+            if let Ok(checklist) = type_option.decode_type_option_cell_str(cell_data) {
+              builder.insert_checklist_cell(&field_id, checklist);
+            }
+          },
+          FieldType::Relation => {
+            if let Ok(relation_cell_data) = RelationCellData::from_cell_str(&cell_data) {
+              builder.insert_relation_cell(&field_id, relation_cell_data.row_ids);
+            }
+          },
+          FieldType::Person => {
+            if let Ok(person_cell_data) = PersonCellData::from_cell_str(&cell_data) {
+              builder.insert_person_cell(&field_id, person_cell_data.user_ids);
             }
           },
+          // The rollup cells are calculated from the linked rows
+          FieldType::Rollup => {},
+          // The formula cells are calculated from the other cells of the row
+          FieldType::Formula => {},
+          // The created time and the last edited time cells are set when the row is built
+          FieldType::CreatedTime | FieldType::LastEditedTime => {},
+          // The numbers are given by the database when the row is inserted
+          FieldType::AutoNumber => {},
+          // The files are copied into the file storage when they are attached to the cells
+          FieldType::Attachment => {},
         }
       }
     }
@@ -87,11 +157,16 @@ impl RowRevisionBuilder {
   pub fn insert_text_cell(&mut self, field_id: &str, data: String) {
     match self.field_rev_map.get(&field_id.to_owned()) {
       None => tracing::warn!("Can't find the text field with id: {}", field_id),
-      Some(field_rev) => {
-        self
-          .payload
-          .cell_by_field_id
-          .insert(field_id.to_owned(), insert_text_cell(data, field_rev));
+      Some(field_rev) => match insert_text_cell(data, field_rev, self.cell_size_limits.as_ref()) {
+        Ok(cell_rev) => {
+          self
+            .payload
+            .cell_by_field_id
+            .insert(field_id.to_owned(), cell_rev);
+        },
+        Err(err) => {
+          self.error.get_or_insert(err);
+        },
       },
     }
   }
@@ -99,11 +174,16 @@ impl RowRevisionBuilder {
   pub fn insert_url_cell(&mut self, field_id: &str, data: String) {
     match self.field_rev_map.get(&field_id.to_owned()) {
       None => tracing::warn!("Can't find the url field with id: {}", field_id),
-      Some(field_rev) => {
-        self
-          .payload
-          .cell_by_field_id
-          .insert(field_id.to_owned(), insert_url_cell(data, field_rev));
+      Some(field_rev) => match insert_url_cell(data, field_rev, self.cell_size_limits.as_ref()) {
+        Ok(cell_rev) => {
+          self
+            .payload
+            .cell_by_field_id
+            .insert(field_id.to_owned(), cell_rev);
+        },
+        Err(err) => {
+          self.error.get_or_insert(err);
+        },
       },
     }
   }
@@ -144,18 +224,58 @@ impl RowRevisionBuilder {
     }
   }
 
+  /// The options are checked against the parent cell when the row is built, the parent cell
+  /// might be inserted after this one.
   pub fn insert_select_option_cell(&mut self, field_id: &str, option_ids: Vec<String>) {
     match self.field_rev_map.get(&field_id.to_owned()) {
       None => tracing::warn!("Can't find the select option field with id: {}", field_id),
       Some(field_rev) => {
+        let parent_option_ids =
+          parent_option_ids_in_cells(field_rev, &self.payload.cell_by_field_id);
         self.payload.cell_by_field_id.insert(
           field_id.to_owned(),
-          insert_select_option_cell(option_ids, field_rev),
+          insert_select_option_cell(option_ids, &parent_option_ids, field_rev),
         );
       },
     }
   }
 
+  pub fn insert_checklist_cell(&mut self, field_id: &str, cell_data: ChecklistCellData) {
+    match self.field_rev_map.get(&field_id.to_owned()) {
+      None => tracing::warn!("Can't find the checklist field with id: {}", field_id),
+      Some(_) => {
+        self
+          .payload
+          .cell_by_field_id
+          .insert(field_id.to_owned(), insert_checklist_cell(cell_data));
+      },
+    }
+  }
+
+  pub fn insert_relation_cell(&mut self, field_id: &str, row_ids: Vec<String>) {
+    match self.field_rev_map.get(&field_id.to_owned()) {
+      None => tracing::warn!("Can't find the relation field with id: {}", field_id),
+      Some(field_rev) => {
+        self.payload.cell_by_field_id.insert(
+          field_id.to_owned(),
+          insert_relation_cell(row_ids, field_rev),
+        );
+      },
+    }
+  }
+
+  pub fn insert_person_cell(&mut self, field_id: &str, user_ids: Vec<String>) {
+    match self.field_rev_map.get(&field_id.to_owned()) {
+      None => tracing::warn!("Can't find the person field with id: {}", field_id),
+      Some(field_rev) => {
+        self
+          .payload
+          .cell_by_field_id
+          .insert(field_id.to_owned(), insert_person_cell(user_ids, field_rev));
+      },
+    }
+  }
+
   #[allow(dead_code)]
   pub fn height(mut self, height: i32) -> Self {
     self.payload.height = height;
@@ -168,14 +288,105 @@ impl RowRevisionBuilder {
     self
   }
 
-  pub fn build(self) -> RowRevision {
+  /// Builds the row, or returns the first error of inserting the cells.
+  pub fn try_build(mut self) -> FlowyResult<RowRevision> {
+    match self.error.take() {
+      Some(err) => Err(err),
+      None => Ok(self.build()),
+    }
+  }
+
+  pub fn build(mut self) -> RowRevision {
+    let now = timestamp();
+    let mut cells = std::mem::take(&mut self.payload.cell_by_field_id);
+    self.drop_disallowed_options(&mut cells);
+    for field_rev in self.field_rev_map.values() {
+      let field_type: FieldType = field_rev.ty.into();
+      match field_type {
+        FieldType::CreatedTime => {
+          cells.insert(
+            field_rev.id.clone(),
+            CreatedTimeTypeOptionPB::cell_rev_from_timestamp(now),
+          );
+        },
+        FieldType::LastEditedTime => {
+          cells.insert(
+            field_rev.id.clone(),
+            LastEditedTimeTypeOptionPB::cell_rev_from_timestamp(now),
+          );
+        },
+        _ => {},
+      }
+    }
+
     RowRevision {
       id: self.payload.row_id,
       block_id: self.block_id,
-      cells: self.payload.cell_by_field_id,
+      cells,
       height: self.payload.height,
       visibility: self.payload.visibility,
+      created_at: now,
+      modified_at: now,
+    }
+  }
+}
+
+impl RowRevisionBuilder {
+  /// Drops the options of the select cells that can't be selected with the options of their
+  /// parent cells. The parents are checked before their children, so the options that a child
+  /// loses are not counted when checking its own children.
+  fn drop_disallowed_options(&self, cells: &mut IndexMap<String, CellRevision>) {
+    let mut dependent_field_revs = self
+      .field_rev_map
+      .values()
+      .filter(|field_rev| {
+        select_type_option_from_field_rev(field_rev)
+          .map(|type_option| type_option.dependency().is_some())
+          .unwrap_or(false)
+      })
+      .collect::<Vec<_>>();
+    dependent_field_revs.sort_by_key(|field_rev| self.dependency_depth(field_rev));
+
+    for field_rev in dependent_field_revs {
+      let option_ids = match cells
+        .get(&field_rev.id)
+        .and_then(|cell_rev| TypeCellData::try_from(cell_rev).ok())
+      {
+        None => continue,
+        Some(type_cell_data) => SelectOptionIds::from(type_cell_data.cell_str).into_inner(),
+      };
+      let parent_option_ids = parent_option_ids_in_cells(field_rev, cells);
+      cells.insert(
+        field_rev.id.clone(),
+        insert_select_option_cell(option_ids, &parent_option_ids, field_rev),
+      );
+    }
+  }
+
+  /// Returns the number of the fields above the field in its chain of dependencies.
+  fn dependency_depth(&self, field_rev: &FieldRevision) -> usize {
+    let mut depth = 0;
+    let mut current = field_rev.id.clone();
+    // A chain can't be longer than the number of the fields, it stops a cyclic chain.
+    while depth < self.field_rev_map.len() {
+      let parent_field_id = self
+        .field_rev_map
+        .get(&current)
+        .and_then(|field_rev| select_type_option_from_field_rev(field_rev).ok())
+        .and_then(|type_option| {
+          type_option
+            .dependency()
+            .map(|dependency| dependency.field_id.clone())
+        });
+      match parent_field_id {
+        None => break,
+        Some(parent_field_id) => {
+          depth += 1;
+          current = parent_field_id;
+        },
+      }
     }
+    depth
   }
 }
 
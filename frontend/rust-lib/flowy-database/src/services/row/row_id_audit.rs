@@ -0,0 +1,130 @@
+use crate::entities::{RowIdCollisionPB, RowIdCollisionReasonPB};
+use crate::services::row::DatabaseBlockRowRevision;
+use std::collections::{HashMap, HashSet};
+
+/// Scans the rows of the database for the ids that are used more than once. The rows that were
+/// created offline on different devices by the older versions might share their ids, because
+/// the ids were too short to be unique across the devices.
+///
+/// `get_indexed_block_id` returns the block that the row id is indexed to. Of the rows that have
+/// the same id in the database, the one in the indexed block keeps its id and the others are
+/// collisions. If the id is indexed to a block of another database, all of them are collisions.
+pub fn find_row_id_collisions<F>(
+  blocks: &[DatabaseBlockRowRevision],
+  get_indexed_block_id: F,
+) -> Vec<RowIdCollisionPB>
+where
+  F: Fn(&str) -> Option<String>,
+{
+  let block_ids = blocks
+    .iter()
+    .map(|block| block.block_id.as_str())
+    .collect::<HashSet<&str>>();
+  let mut row_ids = vec![];
+  let mut rows_by_id: HashMap<&str, Vec<(&str, usize)>> = HashMap::new();
+  for block in blocks {
+    for (index, row_rev) in block.row_revs.iter().enumerate() {
+      let rows = rows_by_id.entry(row_rev.id.as_str()).or_insert_with(|| {
+        row_ids.push(row_rev.id.as_str());
+        vec![]
+      });
+      rows.push((block.block_id.as_str(), index));
+    }
+  }
+
+  let mut collisions = vec![];
+  for row_id in row_ids {
+    let rows = &rows_by_id[row_id];
+    let indexed_block_id = get_indexed_block_id(row_id);
+    let (kept_row, reason) = match indexed_block_id.as_deref() {
+      Some(block_id) if !block_ids.contains(block_id) => {
+        (None, RowIdCollisionReasonPB::UsedByOtherDatabase)
+      },
+      indexed_block_id => {
+        let kept_row = rows
+          .iter()
+          .position(|(block_id, _)| Some(*block_id) == indexed_block_id)
+          .unwrap_or(0);
+        (Some(kept_row), RowIdCollisionReasonPB::DuplicatedInDatabase)
+      },
+    };
+
+    for (position, (block_id, index)) in rows.iter().enumerate() {
+      if Some(position) != kept_row {
+        collisions.push(RowIdCollisionPB {
+          row_id: row_id.to_owned(),
+          block_id: block_id.to_string(),
+          index: *index as i32,
+          reason: reason.clone(),
+        });
+      }
+    }
+  }
+  collisions
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use database_model::RowRevision;
+  use std::sync::Arc;
+
+  fn block(block_id: &str, row_ids: &[&str]) -> DatabaseBlockRowRevision {
+    let row_revs = row_ids
+      .iter()
+      .map(|row_id| {
+        let mut row_rev = RowRevision::new(block_id);
+        row_rev.id = row_id.to_string();
+        Arc::new(row_rev)
+      })
+      .collect();
+    DatabaseBlockRowRevision {
+      block_id: block_id.to_owned(),
+      row_revs,
+    }
+  }
+
+  #[test]
+  fn row_id_collisions_in_database_test() {
+    let blocks = vec![block("b1", &["r1", "r2", "r1"]), block("b2", &["r3", "r1"])];
+    let collisions = find_row_id_collisions(&blocks, |row_id| match row_id {
+      "r1" => Some("b2".to_owned()),
+      _ => Some("b1".to_owned()),
+    });
+    let collisions = collisions
+      .into_iter()
+      .map(|collision| (collision.block_id, collision.index, collision.reason))
+      .collect::<Vec<_>>();
+    // The row in the indexed block keeps its id
+    assert_eq!(
+      collisions,
+      vec![
+        (
+          "b1".to_owned(),
+          0,
+          RowIdCollisionReasonPB::DuplicatedInDatabase
+        ),
+        (
+          "b1".to_owned(),
+          2,
+          RowIdCollisionReasonPB::DuplicatedInDatabase
+        ),
+      ]
+    );
+  }
+
+  #[test]
+  fn row_id_collisions_with_other_database_test() {
+    let blocks = vec![block("b1", &["r1", "r2"])];
+    let collisions = find_row_id_collisions(&blocks, |row_id| match row_id {
+      "r1" => Some("other_block".to_owned()),
+      _ => None,
+    });
+    assert_eq!(collisions.len(), 1);
+    assert_eq!(collisions[0].row_id, "r1");
+    assert_eq!(
+      collisions[0].reason,
+      RowIdCollisionReasonPB::UsedByOtherDatabase
+    );
+  }
+}
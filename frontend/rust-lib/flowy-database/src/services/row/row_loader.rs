@@ -28,6 +28,7 @@ pub(crate) fn make_rows_from_row_revs(row_revs: &[Arc<RowRevision>]) -> Vec<RowP
     block_id: row_rev.block_id.clone(),
     id: row_rev.id.clone(),
     height: row_rev.height,
+    accessibility: None,
   };
 
   row_revs.iter().map(make_row).collect::<Vec<_>>()
@@ -1,3 +1,5 @@
 mod setting_builder;
+mod week_settings;
 
 pub use setting_builder::*;
+pub use week_settings::*;
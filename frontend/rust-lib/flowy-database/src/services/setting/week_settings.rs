@@ -0,0 +1,133 @@
+use chrono::{Datelike, Duration, NaiveDate};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// Monday to Friday, the bit 0 is Sunday
+const DEFAULT_WORKING_DAYS: u8 = 0b0111110;
+
+/// The first day of the week and the working days of the workspace. The weeks of the calendar
+/// events and the date filters start on the `first_day_of_week`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekSettings {
+  /// From 0 for Sunday to 6 for Saturday
+  pub first_day_of_week: u32,
+  /// The bit mask of the working days, the bit 0 is Sunday and the bit 6 is Saturday
+  pub working_days: u8,
+}
+
+impl std::default::Default for WeekSettings {
+  fn default() -> Self {
+    Self {
+      first_day_of_week: 0,
+      working_days: DEFAULT_WORKING_DAYS,
+    }
+  }
+}
+
+impl WeekSettings {
+  /// Creates the settings from the values of the workspace settings. The values out of range
+  /// fall back to the defaults.
+  pub fn new(first_day_of_week: i64, working_days: Option<i64>) -> Self {
+    let first_day_of_week = if (0..7).contains(&first_day_of_week) {
+      first_day_of_week as u32
+    } else {
+      0
+    };
+    let working_days = working_days
+      .filter(|working_days| (0..128).contains(working_days))
+      .map(|working_days| working_days as u8)
+      .unwrap_or(DEFAULT_WORKING_DAYS);
+    Self {
+      first_day_of_week,
+      working_days,
+    }
+  }
+
+  /// Returns the first day of the week that the `date` is in.
+  pub fn start_of_week(&self, date: NaiveDate) -> NaiveDate {
+    let weekday = date.weekday().num_days_from_sunday();
+    let days = (weekday + 7 - self.first_day_of_week) % 7;
+    date - Duration::days(days as i64)
+  }
+
+  /// Returns the first and the last day of the week that the `date` is in.
+  pub fn week_of(&self, date: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let start = self.start_of_week(date);
+    (start, start + Duration::days(6))
+  }
+
+  pub fn is_working_day(&self, date: NaiveDate) -> bool {
+    let weekday = date.weekday().num_days_from_sunday();
+    self.working_days & (1 << weekday) != 0
+  }
+}
+
+/// The week settings that are shared by all the database editors, so updating the settings of
+/// the workspace applies to the opened databases too.
+#[derive(Debug, Clone, Default)]
+pub struct SharedWeekSettings(Arc<RwLock<WeekSettings>>);
+
+impl SharedWeekSettings {
+  pub fn set(&self, settings: WeekSettings) {
+    *self.0.write() = settings;
+  }
+
+  pub fn get(&self) -> WeekSettings {
+    *self.0.read()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn week_settings_start_of_week_test() {
+    // Wednesday
+    let date = NaiveDate::from_ymd_opt(2023, 3, 15).unwrap();
+    let sunday = WeekSettings::default();
+    assert_eq!(
+      sunday.week_of(date),
+      (
+        NaiveDate::from_ymd_opt(2023, 3, 12).unwrap(),
+        NaiveDate::from_ymd_opt(2023, 3, 18).unwrap()
+      )
+    );
+
+    let monday = WeekSettings::new(1, None);
+    assert_eq!(
+      monday.start_of_week(date),
+      NaiveDate::from_ymd_opt(2023, 3, 13).unwrap()
+    );
+    let sunday_date = NaiveDate::from_ymd_opt(2023, 3, 19).unwrap();
+    assert_eq!(
+      monday.start_of_week(sunday_date),
+      NaiveDate::from_ymd_opt(2023, 3, 13).unwrap()
+    );
+
+    let thursday = WeekSettings::new(4, None);
+    assert_eq!(
+      thursday.start_of_week(date),
+      NaiveDate::from_ymd_opt(2023, 3, 9).unwrap()
+    );
+  }
+
+  #[test]
+  fn week_settings_working_days_test() {
+    let saturday = NaiveDate::from_ymd_opt(2023, 3, 18).unwrap();
+    let sunday = NaiveDate::from_ymd_opt(2023, 3, 19).unwrap();
+    let monday = NaiveDate::from_ymd_opt(2023, 3, 20).unwrap();
+    let settings = WeekSettings::default();
+    assert!(!settings.is_working_day(saturday));
+    assert!(!settings.is_working_day(sunday));
+    assert!(settings.is_working_day(monday));
+
+    // Sunday to Thursday
+    let settings = WeekSettings::new(0, Some(0b0011111));
+    assert!(!settings.is_working_day(saturday));
+    assert!(settings.is_working_day(sunday));
+
+    // Out of range
+    assert_eq!(WeekSettings::new(9, Some(256)), WeekSettings::default());
+  }
+}
@@ -159,6 +159,14 @@ impl SortController {
     });
   }
 
+  /// Replaces the sorts with the `sorts` and sorts all the rows right away instead of scheduling
+  /// a task.
+  pub async fn recompute(&mut self, sorts: Vec<Arc<SortRevision>>) -> FlowyResult<()> {
+    self.sorts = sorts;
+    self.row_index_cache.clear();
+    self.process(&SortEvent::SortDidChanged.to_string()).await
+  }
+
   pub async fn delete_all_sorts(&mut self) {
     self.sorts.clear();
     self
@@ -0,0 +1,165 @@
+use flowy_error::FlowyResult;
+use lib_infra::future::Fut;
+use std::future::Future;
+
+type Rollback = Fut<FlowyResult<()>>;
+
+/// Runs the steps of a composite operation, e.g. creating a view with its database and the
+/// rows of the template. Each step registers the rollback that undoes it. If a step fails,
+/// the steps that succeeded are rolled back in reverse order, so the operation either
+/// completes or leaves nothing behind.
+///
+/// The transaction that is dropped without being committed is rolled back in the background.
+pub struct Transaction {
+  name: String,
+  rollbacks: Vec<(String, Rollback)>,
+}
+
+impl Transaction {
+  pub fn new<T: Into<String>>(name: T) -> Self {
+    Self {
+      name: name.into(),
+      rollbacks: vec![],
+    }
+  }
+
+  /// Runs the `action` of the step. The `rollback` runs if one of the following steps fails,
+  /// it's discarded if the `action` fails.
+  pub async fn step<T, F>(&mut self, name: &str, action: F, rollback: Rollback) -> FlowyResult<T>
+  where
+    F: Future<Output = FlowyResult<T>>,
+  {
+    match action.await {
+      Ok(value) => {
+        self.rollbacks.push((name.to_owned(), rollback));
+        Ok(value)
+      },
+      Err(err) => {
+        tracing::error!("{}: {} failed: {:?}", self.name, name, err);
+        run_rollbacks(&self.name, std::mem::take(&mut self.rollbacks)).await;
+        Err(err)
+      },
+    }
+  }
+
+  /// Keeps the changes of all the steps.
+  pub fn commit(mut self) {
+    self.rollbacks.clear();
+  }
+
+  /// Undoes the steps that succeeded.
+  pub async fn rollback(mut self) {
+    run_rollbacks(&self.name, std::mem::take(&mut self.rollbacks)).await;
+  }
+}
+
+impl Drop for Transaction {
+  fn drop(&mut self) {
+    if self.rollbacks.is_empty() {
+      return;
+    }
+
+    let name = std::mem::take(&mut self.name);
+    let rollbacks = std::mem::take(&mut self.rollbacks);
+    match tokio::runtime::Handle::try_current() {
+      Ok(handle) => {
+        tracing::warn!("{} was not committed, rolling it back", name);
+        handle.spawn(async move { run_rollbacks(&name, rollbacks).await });
+      },
+      Err(_) => tracing::error!("{} was not committed and can't be rolled back", name),
+    }
+  }
+}
+
+/// A failed rollback doesn't stop the others, the objects it leaves behind are logged.
+async fn run_rollbacks(name: &str, mut rollbacks: Vec<(String, Rollback)>) {
+  while let Some((step, rollback)) = rollbacks.pop() {
+    if let Err(err) = rollback.await {
+      tracing::error!("{}: rollback {} failed: {:?}", name, step, err);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use flowy_error::FlowyError;
+  use lib_infra::future::to_fut;
+  use std::sync::{Arc, Mutex};
+
+  fn record(log: &Arc<Mutex<Vec<String>>>, s: &str) -> Rollback {
+    let log = log.clone();
+    let s = s.to_owned();
+    to_fut(async move {
+      log.lock().unwrap().push(s);
+      Ok(())
+    })
+  }
+
+  #[tokio::test]
+  async fn transaction_rollback_in_reverse_order_test() {
+    let log = Arc::new(Mutex::new(vec![]));
+    let mut transaction = Transaction::new("test");
+    transaction
+      .step("a", async { Ok(()) }, record(&log, "undo a"))
+      .await
+      .unwrap();
+    transaction
+      .step("b", async { Ok(()) }, record(&log, "undo b"))
+      .await
+      .unwrap();
+    let result = transaction
+      .step::<(), _>(
+        "c",
+        async { Err(FlowyError::internal()) },
+        record(&log, "undo c"),
+      )
+      .await;
+    assert!(result.is_err());
+    assert_eq!(*log.lock().unwrap(), vec!["undo b", "undo a"]);
+
+    // The steps were rolled back already
+    transaction.rollback().await;
+    assert_eq!(log.lock().unwrap().len(), 2);
+  }
+
+  #[tokio::test]
+  async fn transaction_commit_test() {
+    let log = Arc::new(Mutex::new(vec![]));
+    let mut transaction = Transaction::new("test");
+    let value = transaction
+      .step("a", async { Ok(1) }, record(&log, "undo a"))
+      .await
+      .unwrap();
+    assert_eq!(value, 1);
+    transaction.commit();
+    assert!(log.lock().unwrap().is_empty());
+  }
+
+  #[tokio::test]
+  async fn transaction_rollback_on_drop_test() {
+    let log = Arc::new(Mutex::new(vec![]));
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    let mut transaction = Transaction::new("test");
+    transaction
+      .step("a", async { Ok(()) }, record(&log, "undo a"))
+      .await
+      .unwrap();
+    transaction
+      .step(
+        "b",
+        async { Ok(()) },
+        to_fut(async move {
+          let _ = tx.send(());
+          Ok(())
+        }),
+      )
+      .await
+      .unwrap();
+    drop(transaction);
+
+    rx.await.unwrap();
+    tokio::task::yield_now().await;
+    assert_eq!(*log.lock().unwrap(), vec!["undo a"]);
+  }
+}
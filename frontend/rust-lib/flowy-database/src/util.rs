@@ -1,8 +1,16 @@
+#[cfg(feature = "dev")]
+use crate::entities::CreateRowParams;
 use crate::entities::FieldType;
+#[cfg(feature = "dev")]
+use crate::services::database::DatabaseEditor;
 use crate::services::field::*;
 use crate::services::row::RowRevisionBuilder;
 use database_model::{BuildDatabaseContext, CalendarLayoutSetting, LayoutRevision, LayoutSetting};
 use flowy_client_sync::client_database::DatabaseBuilder;
+#[cfg(feature = "dev")]
+use flowy_error::FlowyResult;
+#[cfg(feature = "dev")]
+use std::collections::HashMap;
 
 pub fn make_default_grid() -> BuildDatabaseContext {
   let mut database_builder = DatabaseBuilder::new();
@@ -241,3 +249,212 @@ pub fn make_default_board_2() -> BuildDatabaseContext {
 
   database_builder.build()
 }
+
+/// The words of the synthetic texts, they are repeated so that the search and the text filters
+/// have matches across the rows.
+#[cfg(feature = "dev")]
+const SYNTHETIC_WORDS: [&str; 12] = [
+  "alpha", "bravo", "charlie", "delta", "echo", "foxtrot", "golf", "hotel", "india", "juliett",
+  "kilo", "lima",
+];
+
+/// Returns the synthetic text of the `index`th item, e.g. a row or a paragraph.
+#[cfg(feature = "dev")]
+pub fn synthetic_text(index: usize) -> String {
+  (0..4)
+    .map(|offset| SYNTHETIC_WORDS[(index * 7 + offset * 5) % SYNTHETIC_WORDS.len()])
+    .collect::<Vec<&str>>()
+    .join(" ")
+}
+
+/// A grid with the fields of the common types that is used to test the performance of large
+/// databases. The grid is created without rows, the rows are inserted by [SyntheticGrid::insert_rows]
+/// after the database is created.
+#[cfg(feature = "dev")]
+pub struct SyntheticGrid {
+  build_context: BuildDatabaseContext,
+  text_field_id: String,
+  number_field_id: String,
+  date_field_id: String,
+  single_select_field_id: String,
+  status_option_ids: Vec<String>,
+  multi_select_field_id: String,
+  tag_option_ids: Vec<String>,
+  checkbox_field_id: String,
+  url_field_id: String,
+}
+
+#[cfg(feature = "dev")]
+impl SyntheticGrid {
+  pub fn new() -> Self {
+    let mut database_builder = DatabaseBuilder::new();
+    let text_field = FieldBuilder::new(RichTextTypeOptionBuilder::default())
+      .name("Name")
+      .visibility(true)
+      .primary(true)
+      .build();
+    let text_field_id = text_field.id.clone();
+    database_builder.add_field(text_field);
+
+    let number_field = FieldBuilder::from_field_type(&FieldType::Number)
+      .name("Amount")
+      .visibility(true)
+      .build();
+    let number_field_id = number_field.id.clone();
+    database_builder.add_field(number_field);
+
+    let date_field = FieldBuilder::from_field_type(&FieldType::DateTime)
+      .name("Due")
+      .visibility(true)
+      .build();
+    let date_field_id = date_field.id.clone();
+    database_builder.add_field(date_field);
+
+    let status_options = vec![
+      SelectOptionPB::with_color("To Do", SelectOptionColorPB::Purple),
+      SelectOptionPB::with_color("Doing", SelectOptionColorPB::Orange),
+      SelectOptionPB::with_color("Done", SelectOptionColorPB::Green),
+    ];
+    let status_option_ids = status_options
+      .iter()
+      .map(|option| option.id.clone())
+      .collect::<Vec<String>>();
+    let single_select = status_options.into_iter().fold(
+      SingleSelectTypeOptionBuilder::default(),
+      |builder, option| builder.add_option(option),
+    );
+    let single_select_field = FieldBuilder::new(single_select)
+      .name("Status")
+      .visibility(true)
+      .build();
+    let single_select_field_id = single_select_field.id.clone();
+    database_builder.add_field(single_select_field);
+
+    let tag_options = vec![
+      SelectOptionPB::with_color("Red", SelectOptionColorPB::Pink),
+      SelectOptionPB::with_color("Green", SelectOptionColorPB::Lime),
+      SelectOptionPB::with_color("Blue", SelectOptionColorPB::Blue),
+      SelectOptionPB::with_color("Gray", SelectOptionColorPB::Aqua),
+    ];
+    let tag_option_ids = tag_options
+      .iter()
+      .map(|option| option.id.clone())
+      .collect::<Vec<String>>();
+    let multi_select = tag_options.into_iter().fold(
+      MultiSelectTypeOptionBuilder::default(),
+      |builder, option| builder.add_option(option),
+    );
+    let multi_select_field = FieldBuilder::new(multi_select)
+      .name("Tags")
+      .visibility(true)
+      .build();
+    let multi_select_field_id = multi_select_field.id.clone();
+    database_builder.add_field(multi_select_field);
+
+    let checkbox_field = FieldBuilder::from_field_type(&FieldType::Checkbox)
+      .name("Done")
+      .visibility(true)
+      .build();
+    let checkbox_field_id = checkbox_field.id.clone();
+    database_builder.add_field(checkbox_field);
+
+    let url_field = FieldBuilder::from_field_type(&FieldType::URL)
+      .name("Link")
+      .visibility(true)
+      .build();
+    let url_field_id = url_field.id.clone();
+    database_builder.add_field(url_field);
+
+    Self {
+      build_context: database_builder.build(),
+      text_field_id,
+      number_field_id,
+      date_field_id,
+      single_select_field_id,
+      status_option_ids,
+      multi_select_field_id,
+      tag_option_ids,
+      checkbox_field_id,
+      url_field_id,
+    }
+  }
+
+  pub fn build_context(&self) -> BuildDatabaseContext {
+    self.build_context.clone()
+  }
+
+  /// Inserts `number_of_rows` rows into the view of the synthetic grid. The rows are created by
+  /// [DatabaseEditor::create_row], so they go through the row limit, the auto numbers and the
+  /// notifications just like the rows that are created by the user.
+  pub async fn insert_rows(
+    &self,
+    editor: &DatabaseEditor,
+    view_id: &str,
+    number_of_rows: usize,
+  ) -> FlowyResult<()> {
+    for index in 0..number_of_rows {
+      let params = CreateRowParams {
+        view_id: view_id.to_owned(),
+        start_row_id: None,
+        group_id: None,
+        swimlane_id: None,
+        cell_data_by_field_id: Some(self.row_cell_data(index)),
+      };
+      editor.create_row(params).await?;
+    }
+    Ok(())
+  }
+
+  /// Returns the cell data of the `index`th row in the format of [CreateRowParams]. The cells
+  /// are derived from the index of the row, so the same rows are generated every time.
+  fn row_cell_data(&self, index: usize) -> HashMap<String, String> {
+    // 2023-01-01 00:00:00 UTC
+    let start_timestamp = 1672531200;
+    let mut cell_data_by_field_id = HashMap::new();
+    cell_data_by_field_id.insert(self.text_field_id.clone(), synthetic_text(index));
+    cell_data_by_field_id.insert(
+      self.number_field_id.clone(),
+      ((index * 37) % 1000).to_string(),
+    );
+    let date_cell_data = DateCellData {
+      timestamp: Some(start_timestamp + (index % 365) as i64 * 24 * 60 * 60),
+      ..Default::default()
+    };
+    cell_data_by_field_id.insert(self.date_field_id.clone(), date_cell_data.to_string());
+
+    let status_option_id = &self.status_option_ids[index % self.status_option_ids.len()];
+    cell_data_by_field_id.insert(
+      self.single_select_field_id.clone(),
+      status_option_id.clone(),
+    );
+    let tag_ids = self
+      .tag_option_ids
+      .iter()
+      .enumerate()
+      .filter(|(tag_index, _)| (index >> tag_index) & 1 == 1)
+      .map(|(_, option_id)| option_id.clone())
+      .collect::<Vec<String>>();
+    if !tag_ids.is_empty() {
+      cell_data_by_field_id.insert(
+        self.multi_select_field_id.clone(),
+        SelectOptionIds::from(tag_ids).to_string(),
+      );
+    }
+    let is_check = if index % 3 == 0 { CHECK } else { UNCHECK };
+    cell_data_by_field_id.insert(self.checkbox_field_id.clone(), is_check.to_owned());
+    if index % 4 != 0 {
+      cell_data_by_field_id.insert(
+        self.url_field_id.clone(),
+        format!("https://example.com/{}", index),
+      );
+    }
+    cell_data_by_field_id
+  }
+}
+
+#[cfg(feature = "dev")]
+impl std::default::Default for SyntheticGrid {
+  fn default() -> Self {
+    Self::new()
+  }
+}
@@ -2,8 +2,16 @@ use crate::database::block_test::script::RowScript::*;
 use crate::database::block_test::script::{CreateRowScriptBuilder, DatabaseRowTest};
 use crate::database::mock_data::{COMPLETED, FACEBOOK, GOOGLE, PAUSED, TWITTER};
 use database_model::RowChangeset;
-use flowy_database::entities::FieldType;
-use flowy_database::services::field::{SELECTION_IDS_SEPARATOR, UNCHECK};
+use flowy_database::entities::{
+  CellIdParams, CreateRowParams, DatabaseChangePB, DatabaseChangeTypePB, FieldChangesetParams,
+  FieldType,
+};
+use flowy_database::services::field::{
+  make_text_mention, TextMentionKindPB, SELECTION_IDS_SEPARATOR, UNCHECK,
+};
+use flowy_error::ErrorCode;
+use std::time::Duration;
+use tokio::sync::broadcast;
 
 #[tokio::test]
 async fn grid_create_row_count_test() {
@@ -20,6 +28,43 @@ async fn grid_create_row_count_test() {
   test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn grid_create_row_exceeds_row_limit_test() {
+  let mut test = DatabaseRowTest::new().await;
+  test.sdk.database_manager.set_max_rows_per_database(7);
+  test
+    .run_scripts(vec![AssertRowCount(6), CreateEmptyRow, AssertRowCount(7)])
+    .await;
+
+  let params = CreateRowParams {
+    view_id: test.view_id.clone(),
+    start_row_id: None,
+    group_id: None,
+    swimlane_id: None,
+    cell_data_by_field_id: None,
+  };
+  let err = test.editor.create_row(params).await.unwrap_err();
+  assert_eq!(err.code, ErrorCode::RowLimitExceeded.value());
+
+  let row_rev = test.row_builder().build();
+  let err = test.editor.insert_rows(vec![row_rev]).await.unwrap_err();
+  assert_eq!(err.code, ErrorCode::RowLimitExceeded.value());
+
+  let row_id = test.row_revs.first().unwrap().id.clone();
+  let err = test
+    .editor
+    .duplicate_row(&test.view_id, &row_id)
+    .await
+    .unwrap_err();
+  assert_eq!(err.code, ErrorCode::RowLimitExceeded.value());
+
+  // Removing the limit allows creating rows again
+  test.sdk.database_manager.set_max_rows_per_database(0);
+  test
+    .run_scripts(vec![CreateEmptyRow, AssertRowCount(8)])
+    .await;
+}
+
 #[tokio::test]
 async fn grid_update_row() {
   let mut test = DatabaseRowTest::new().await;
@@ -28,6 +73,7 @@ async fn grid_update_row() {
     row_id: row_rev.id.clone(),
     height: None,
     visibility: None,
+    modified_at: None,
     cell_by_field_id: Default::default(),
   };
   let row_count = test.row_revs.len();
@@ -65,6 +111,283 @@ async fn grid_delete_row() {
   test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn grid_trash_and_restore_row_test() {
+  let mut test = DatabaseRowTest::new().await;
+  let row_count = test.row_revs.len();
+  let row = test.row_revs.first().unwrap().as_ref().clone();
+  let scripts = vec![
+    AssertTrashedRowCount(0),
+    TrashRow {
+      row_id: row.id.clone(),
+    },
+    AssertRowCount(row_count - 1),
+    AssertTrashedRowCount(1),
+    RestoreRow {
+      row_id: row.id.clone(),
+    },
+    AssertRowCount(row_count),
+    AssertTrashedRowCount(0),
+    AssertRow { expected_row: row },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_repair_trashed_row_id_collision_test() {
+  let mut test = DatabaseRowTest::new().await;
+  let row_count = test.row_revs.len();
+  let row = test.row_revs.first().unwrap().as_ref().clone();
+  test
+    .run_scripts(vec![TrashRow {
+      row_id: row.id.clone(),
+    }])
+    .await;
+  // A live row is created with the id of the trashed row
+  test.editor.insert_rows(vec![row.clone()]).await.unwrap();
+
+  let collisions = test
+    .sdk
+    .database_manager
+    .repair_row_id_collisions(&test.view_id)
+    .await
+    .unwrap();
+  assert!(collisions.is_empty());
+  let trashed_rows = test.editor.get_trashed_rows().await.unwrap();
+  assert_eq!(trashed_rows.len(), 1);
+  assert_ne!(trashed_rows[0].row_id, row.id);
+
+  test
+    .run_scripts(vec![
+      RestoreRow {
+        row_id: trashed_rows[0].row_id.clone(),
+      },
+      AssertRowCount(row_count + 1),
+      AssertTrashedRowCount(0),
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_trashed_row_primary_cell_test() {
+  let mut test = DatabaseRowTest::new().await;
+  let row_id = test.row_revs.first().unwrap().id.clone();
+  let primary_field_id = test
+    .field_revs
+    .iter()
+    .find(|field_rev| field_rev.is_primary)
+    .unwrap()
+    .id
+    .clone();
+  let primary_cell = test
+    .editor
+    .get_cell_display_str(&CellIdParams {
+      view_id: test.view_id.clone(),
+      field_id: primary_field_id,
+      row_id: row_id.clone(),
+    })
+    .await;
+  test
+    .run_scripts(vec![TrashRow {
+      row_id: row_id.clone(),
+    }])
+    .await;
+
+  let trashed_rows = test.editor.get_trashed_rows().await.unwrap();
+  assert_eq!(trashed_rows[0].row_id, row_id);
+  assert_eq!(trashed_rows[0].primary_cell, primary_cell);
+  assert!(trashed_rows[0].deleted_at > 0);
+}
+
+#[tokio::test]
+async fn grid_row_display_value_test() {
+  let mut test = DatabaseRowTest::new().await;
+  let row_id = test.row_revs.first().unwrap().id.clone();
+  let primary_field_id = test
+    .field_revs
+    .iter()
+    .find(|field_rev| field_rev.is_primary)
+    .unwrap()
+    .id
+    .clone();
+  let primary_cell = test
+    .editor
+    .get_cell_display_str(&CellIdParams {
+      view_id: test.view_id.clone(),
+      field_id: primary_field_id.clone(),
+      row_id: row_id.clone(),
+    })
+    .await;
+  test
+    .run_scripts(vec![AssertRowDisplayValue {
+      row_id: row_id.clone(),
+      expected: primary_cell,
+    }])
+    .await;
+
+  // The cached display value is refreshed after the primary cell is changed
+  test
+    .editor
+    .update_cell_with_changeset(&row_id, &primary_field_id, "renamed row".to_string())
+    .await
+    .unwrap();
+  test
+    .run_scripts(vec![AssertRowDisplayValue {
+      row_id: row_id.clone(),
+      expected: "renamed row".to_string(),
+    }])
+    .await;
+
+  // The display values that were dropped from memory are read from the disk
+  test.editor.trim_caches();
+  test
+    .run_scripts(vec![AssertRowDisplayValue {
+      row_id: row_id.clone(),
+      expected: "renamed row".to_string(),
+    }])
+    .await;
+
+  let display_values = test.editor.get_row_display_values(vec![]).await.unwrap();
+  assert_eq!(display_values.len(), test.row_revs.len());
+}
+
+#[tokio::test]
+async fn grid_row_mention_test() {
+  let test = DatabaseRowTest::new().await;
+  let mentioned_row_id = test.row_revs[0].id.clone();
+  let row_id = test.row_revs[1].id.clone();
+  let primary_field_id = test
+    .field_revs
+    .iter()
+    .find(|field_rev| field_rev.is_primary)
+    .unwrap()
+    .id
+    .clone();
+  let cell_id = CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: primary_field_id.clone(),
+    row_id: row_id.clone(),
+  };
+  let mentioned_row_name = test
+    .editor
+    .get_cell_display_str(&CellIdParams {
+      view_id: test.view_id.clone(),
+      field_id: primary_field_id.clone(),
+      row_id: mentioned_row_id.clone(),
+    })
+    .await;
+
+  // The mention is rendered with the current name of the row, not the stored one
+  let mention = make_text_mention(TextMentionKindPB::Row, &mentioned_row_id, "old name");
+  test
+    .editor
+    .update_cell_with_changeset(&row_id, &primary_field_id, format!("see {}", mention))
+    .await
+    .unwrap();
+  let cell_data = test
+    .editor
+    .get_text_cell_data(&cell_id, |_| None)
+    .await
+    .unwrap();
+  assert_eq!(cell_data.text, format!("see @{}", mentioned_row_name));
+  assert_eq!(cell_data.mentions.len(), 1);
+  assert!(cell_data.mentions[0].is_resolved);
+
+  let backlinks = test
+    .sdk
+    .database_manager
+    .get_row_backlinks(&mentioned_row_id)
+    .await
+    .unwrap();
+  assert_eq!(backlinks.len(), 1);
+  assert_eq!(backlinks[0].row_id, row_id);
+  assert_eq!(backlinks[0].field_id, primary_field_id);
+
+  // Removing the mention removes the backlink
+  test
+    .editor
+    .update_cell_with_changeset(&row_id, &primary_field_id, "see nothing".to_string())
+    .await
+    .unwrap();
+  let backlinks = test
+    .sdk
+    .database_manager
+    .get_row_backlinks(&mentioned_row_id)
+    .await
+    .unwrap();
+  assert!(backlinks.is_empty());
+
+  // The view is resolved with its name in the folder
+  let mention = make_text_mention(TextMentionKindPB::View, &test.view_id, "old name");
+  test
+    .editor
+    .update_cell_with_changeset(&row_id, &primary_field_id, format!("see {}", mention))
+    .await
+    .unwrap();
+  let cell_data = test
+    .sdk
+    .database_manager
+    .get_text_cell_data(&cell_id)
+    .await
+    .unwrap();
+  assert!(cell_data.mentions[0].is_resolved);
+  assert_ne!(cell_data.mentions[0].name, "old name");
+}
+
+#[tokio::test]
+async fn grid_row_share_test() {
+  let test = DatabaseRowTest::new().await;
+  let row_id = test.row_revs[0].id.clone();
+  let primary_field_rev = test
+    .field_revs
+    .iter()
+    .find(|field_rev| field_rev.is_primary)
+    .unwrap()
+    .clone();
+  let primary_cell = test
+    .editor
+    .get_cell_display_str(&CellIdParams {
+      view_id: test.view_id.clone(),
+      field_id: primary_field_rev.id.clone(),
+      row_id: row_id.clone(),
+    })
+    .await;
+
+  let share = test.editor.share_row(&test.view_id, &row_id).await.unwrap();
+  assert!(share.link.ends_with(&share.share_id));
+  // Sharing the row again returns the same link
+  let shared_again = test.editor.share_row(&test.view_id, &row_id).await.unwrap();
+  assert_eq!(shared_again.share_id, share.share_id);
+  let shares = test.editor.get_row_shares().await.unwrap();
+  assert_eq!(shares.len(), 1);
+
+  let shared_row = test.editor.get_shared_row(&share.share_id).await.unwrap();
+  assert_eq!(shared_row.share.row_id, row_id);
+  let primary_shared_cell = shared_row
+    .cells
+    .iter()
+    .find(|cell| cell.field_id == primary_field_rev.id)
+    .unwrap();
+  assert_eq!(primary_shared_cell.field_name, primary_field_rev.name);
+  assert_eq!(primary_shared_cell.display_value, primary_cell);
+
+  // The shared row comes with the document of the row
+  let database_manager = test.sdk.database_manager.clone();
+  database_manager
+    .bind_row_document(&row_id, "row_document")
+    .unwrap();
+  let shared_row = database_manager
+    .get_shared_row(&test.view_id, &share.share_id)
+    .await
+    .unwrap();
+  assert_eq!(shared_row.document_id.as_deref(), Some("row_document"));
+
+  test.editor.revoke_row_share(&share.share_id).unwrap();
+  assert!(test.editor.get_shared_row(&share.share_id).await.is_err());
+  assert!(test.editor.get_row_shares().await.unwrap().is_empty());
+  assert!(test.editor.revoke_row_share(&share.share_id).is_err());
+}
+
 #[tokio::test]
 async fn grid_row_add_cells_test() {
   let mut test = DatabaseRowTest::new().await;
@@ -133,3 +456,64 @@ async fn grid_row_insert_multi_select_test() {
   let scripts = builder.build();
   test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_row_and_field_changes_are_published_test() {
+  let test = DatabaseRowTest::new().await;
+  let mut change_rx = test.sdk.database_manager.subscribe_database_changes();
+
+  let params = CreateRowParams {
+    view_id: test.editor.database_id.clone(),
+    start_row_id: None,
+    group_id: None,
+    swimlane_id: None,
+    cell_data_by_field_id: None,
+  };
+  let row_pb = test.editor.create_row(params).await.unwrap();
+  let row_id = row_pb.row_id().to_owned();
+  let change = recv_database_change(&mut change_rx).await;
+  assert_eq!(change.ty, DatabaseChangeTypePB::RowCreated);
+  assert_eq!(change.database_id, test.editor.database_id);
+  assert_eq!(change.row_id, row_id);
+
+  let field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+  test
+    .editor
+    .update_cell(row_id.clone(), field_id.clone(), "hello".to_owned())
+    .await
+    .unwrap();
+  let change = recv_database_change(&mut change_rx).await;
+  assert_eq!(change.ty, DatabaseChangeTypePB::RowUpdated);
+  assert_eq!(change.row_id, row_id);
+  assert_eq!(change.field_ids, vec![field_id.clone()]);
+
+  test.editor.delete_row(&row_id).await.unwrap();
+  let change = recv_database_change(&mut change_rx).await;
+  assert_eq!(change.ty, DatabaseChangeTypePB::RowDeleted);
+  assert_eq!(change.row_id, row_id);
+
+  let params = FieldChangesetParams {
+    field_id: field_id.clone(),
+    view_id: test.view_id.clone(),
+    name: Some("Title".to_owned()),
+    desc: None,
+    field_type: None,
+    frozen: None,
+    visibility: None,
+    width: None,
+  };
+  test.editor.update_field(params).await.unwrap();
+  let change = recv_database_change(&mut change_rx).await;
+  assert_eq!(change.ty, DatabaseChangeTypePB::FieldUpdated);
+  assert!(change.row_id.is_empty());
+  assert_eq!(change.field_ids, vec![field_id]);
+}
+
+async fn recv_database_change(
+  change_rx: &mut broadcast::Receiver<DatabaseChangePB>,
+) -> DatabaseChangePB {
+  tokio::time::timeout(Duration::from_secs(2), change_rx.recv())
+    .await
+    .unwrap()
+    .unwrap()
+}
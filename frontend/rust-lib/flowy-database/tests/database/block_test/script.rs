@@ -25,6 +25,17 @@ pub enum RowScript {
   DeleteRows {
     row_ids: Vec<String>,
   },
+  TrashRow {
+    row_id: String,
+  },
+  RestoreRow {
+    row_id: String,
+  },
+  AssertTrashedRowCount(usize),
+  AssertRowDisplayValue {
+    row_id: String,
+    expected: String,
+  },
   AssertCell {
     row_id: String,
     field_id: String,
@@ -81,6 +92,7 @@ impl DatabaseRowTest {
           view_id: self.editor.database_id.clone(),
           start_row_id: None,
           group_id: None,
+          swimlane_id: None,
           cell_data_by_field_id: None,
         };
         let row_order = self.editor.create_row(params).await.unwrap();
@@ -112,6 +124,33 @@ impl DatabaseRowTest {
         self.row_revs = self.get_row_revs().await;
         self.block_meta_revs = self.editor.get_block_meta_revs().await.unwrap();
       },
+      RowScript::TrashRow { row_id } => {
+        self.editor.delete_row(&row_id).await.unwrap();
+        self.row_revs = self.get_row_revs().await;
+      },
+      RowScript::RestoreRow { row_id } => {
+        let row = self
+          .editor
+          .restore_row(&self.view_id, &row_id)
+          .await
+          .unwrap();
+        assert_eq!(row.id, row_id);
+        self.row_revs = self.get_row_revs().await;
+      },
+      RowScript::AssertTrashedRowCount(expected_count) => {
+        let trashed_rows = self.editor.get_trashed_rows().await.unwrap();
+        assert_eq!(trashed_rows.len(), expected_count);
+      },
+      RowScript::AssertRowDisplayValue { row_id, expected } => {
+        let display_values = self
+          .editor
+          .get_row_display_values(vec![row_id.clone()])
+          .await
+          .unwrap();
+        assert_eq!(display_values.len(), 1);
+        assert_eq!(display_values[0].row_id, row_id);
+        assert_eq!(display_values[0].display_value, expected);
+      },
       RowScript::AssertCell {
         row_id,
         field_id,
@@ -248,13 +287,14 @@ impl DatabaseRowTest {
           .get_cell_protobuf(&cell_id)
           .await
           .unwrap()
-          .parser::<SelectOptionCellDataParser>()
+          .parser::<ChecklistCellDataParser>()
           .unwrap();
 
         let s = cell_data
-          .select_options
+          .items
           .into_iter()
-          .map(|option| option.name)
+          .filter(|item| item.is_checked)
+          .map(|item| item.name)
           .collect::<Vec<String>>()
           .join(SELECTION_IDS_SEPARATOR);
 
@@ -282,6 +322,156 @@ impl DatabaseRowTest {
         assert_eq!(cell_data.content, expected);
         // assert_eq!(cell_data.url, expected);
       },
+      FieldType::AutoNumber => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<AutoNumberCellDataParser>()
+          .unwrap();
+        assert_eq!(cell_data.formatted, expected);
+      },
+      FieldType::TimeTracking => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<TimeTrackingCellDataParser>()
+          .unwrap();
+        assert_eq!(cell_data.formatted, expected);
+      },
+      FieldType::Duration => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<DurationCellDataParser>()
+          .unwrap();
+        assert_eq!(cell_data.formatted, expected);
+      },
+      FieldType::Email => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<EmailCellDataParser>()
+          .unwrap();
+        assert_eq!(cell_data.address, expected);
+      },
+      FieldType::Location => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<LocationCellDataParser>()
+          .unwrap();
+        assert_eq!(cell_data.formatted, expected);
+      },
+      FieldType::Phone => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<PhoneCellDataParser>()
+          .unwrap();
+        assert_eq!(cell_data.number, expected);
+      },
+      FieldType::Relation => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<RelationCellDataParser>()
+          .unwrap();
+
+        assert_eq!(cell_data.row_ids.join(","), expected);
+      },
+      FieldType::Person => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<PersonCellDataParser>()
+          .unwrap();
+
+        let user_ids = cell_data
+          .persons
+          .into_iter()
+          .map(|person| person.id)
+          .collect::<Vec<_>>();
+        assert_eq!(user_ids.join(","), expected);
+      },
+      FieldType::Attachment => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<AttachmentCellDataParser>()
+          .unwrap();
+
+        let attachment_ids = cell_data
+          .attachments
+          .into_iter()
+          .map(|attachment| attachment.id)
+          .collect::<Vec<_>>();
+        assert_eq!(attachment_ids.join(","), expected);
+      },
+      FieldType::Progress => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<ProgressCellDataParser>()
+          .unwrap();
+        let value = cell_data
+          .value
+          .map(|value| value.to_string())
+          .unwrap_or_default();
+        assert_eq!(value, expected);
+      },
+      FieldType::Rollup => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<TextCellDataParser>()
+          .unwrap();
+
+        assert_eq!(cell_data.as_ref(), &expected);
+      },
+      FieldType::Formula => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<FormulaCellDataParser>()
+          .unwrap();
+
+        assert_eq!(cell_data.content, expected);
+      },
+      FieldType::CreatedTime | FieldType::LastEditedTime => {
+        let cell_data = self
+          .editor
+          .get_cell_protobuf(&cell_id)
+          .await
+          .unwrap()
+          .parser::<DateCellDataParser>()
+          .unwrap();
+
+        assert_eq!(cell_data.date, expected);
+      },
     }
   }
 }
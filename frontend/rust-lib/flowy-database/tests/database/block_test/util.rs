@@ -1,8 +1,8 @@
 use database_model::{FieldRevision, RowRevision};
 use flowy_database::entities::FieldType;
 use flowy_database::services::field::{
-  ChecklistTypeOptionPB, DateCellChangeset, MultiSelectTypeOptionPB, SelectOptionPB,
-  SingleSelectTypeOptionPB,
+  ChecklistCellData, ChecklistItemPB, ChecklistTypeOptionPB, DateCellChangeset,
+  MultiSelectTypeOptionPB, SelectOptionPB, SingleSelectTypeOptionPB,
 };
 use flowy_database::services::row::RowRevisionBuilder;
 use std::sync::Arc;
@@ -38,12 +38,24 @@ impl DatabaseRowTestBuilder {
     number_field.id.clone()
   }
 
+  pub fn insert_progress_cell(&mut self, data: &str) -> String {
+    let progress_field = self.field_rev_with_type(&FieldType::Progress);
+    self
+      .inner_builder
+      .insert_text_cell(&progress_field.id, data.to_string());
+    progress_field.id.clone()
+  }
+
   pub fn insert_date_cell(&mut self, data: &str) -> String {
     let value = serde_json::to_string(&DateCellChangeset {
       date: Some(data.to_string()),
       time: None,
       is_utc: true,
       include_time: Some(false),
+      text: None,
+      locale: None,
+      recurrence: None,
+      clear_recurrence: false,
     })
     .unwrap();
     let date_field = self.field_rev_with_type(&FieldType::DateTime);
@@ -68,6 +80,18 @@ impl DatabaseRowTestBuilder {
     url_field.id.clone()
   }
 
+  pub fn insert_person_cell(&mut self, user_ids: Vec<&str>) -> String {
+    let person_field = self.field_rev_with_type(&FieldType::Person);
+    let user_ids = user_ids
+      .into_iter()
+      .map(|user_id| user_id.to_owned())
+      .collect::<Vec<_>>();
+    self
+      .inner_builder
+      .insert_person_cell(&person_field.id, user_ids);
+    person_field.id.clone()
+  }
+
   pub fn insert_single_select_cell<F>(&mut self, f: F) -> String
   where
     F: Fn(Vec<SelectOptionPB>) -> SelectOptionPB,
@@ -106,14 +130,22 @@ impl DatabaseRowTestBuilder {
   {
     let checklist_field = self.field_rev_with_type(&FieldType::Checklist);
     let type_option = ChecklistTypeOptionPB::from(&checklist_field);
-    let options = f(type_option.options);
-    let ops_ids = options
-      .iter()
-      .map(|option| option.id.clone())
-      .collect::<Vec<_>>();
+    let checked_options = f(type_option.options.clone());
+    let items = type_option
+      .options
+      .into_iter()
+      .map(|option| ChecklistItemPB {
+        is_checked: checked_options
+          .iter()
+          .any(|checked_option| checked_option.id == option.id),
+        id: option.id,
+        name: option.name,
+        due: None,
+      })
+      .collect();
     self
       .inner_builder
-      .insert_select_option_cell(&checklist_field.id, ops_ids);
+      .insert_checklist_cell(&checklist_field.id, ChecklistCellData { items });
 
     checklist_field.id.clone()
   }
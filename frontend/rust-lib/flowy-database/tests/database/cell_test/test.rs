@@ -1,12 +1,32 @@
 use crate::database::cell_test::script::CellScript::*;
 use crate::database::cell_test::script::DatabaseCellTest;
 use crate::database::field_test::util::make_date_cell_string;
-use flowy_database::entities::{CellChangesetPB, FieldType};
-use flowy_database::services::cell::ToCellChangesetString;
-use flowy_database::services::field::selection_type_option::SelectOptionCellChangeset;
+use crate::database::mock_data::make_test_grid;
+use bytes::Bytes;
+use database_model::{CellRevision, RowChangeset};
+use flowy_database::entities::{
+  AccessibilityRolePB, CellAccessibilityStatePB, CellAddressParams, CellChangesetPB, CellIdParams,
+  CellMoveDirectionPB, CreateRowParams, FieldChangesetParams, FieldType, InvalidCellPB,
+};
+use flowy_database::services::cell::{FromCellString, ToCellChangesetString, TypeCellData};
+use flowy_database::services::field::selection_type_option::{
+  SelectOptionCellChangeset, SelectOptionIds,
+};
 use flowy_database::services::field::{
-  ChecklistTypeOptionPB, MultiSelectTypeOptionPB, SingleSelectTypeOptionPB,
+  AttachFileParams, AttachmentCellChangeset, AttachmentIdParams, AttachmentPB,
+  ChecklistCellChangeset, ChecklistCellChangesetParams, ChecklistItemPB, DateCellData,
+  FormulaCellDataParser, FormulaTypeOptionPB, LastEditedTimeTypeOptionPB,
+  LocationCellChangesetParams, LocationCellDataParser, MoveChecklistItemParams,
+  MultiSelectTypeOptionPB, PersonCellChangeset, PersonTypeOptionPB, RelationCellChangeset,
+  RelationOnDeletePB, RelationTypeOptionPB, RollupCalculationPB, RollupTypeOptionPB,
+  SelectOptionDependencyPB, SelectOptionDependencyRulePB, SingleSelectTypeOptionPB,
+  TimeTrackingCellChangeset,
 };
+use flowy_error::{ErrorCode, ErrorRecovery};
+use flowy_test::helper::ViewTest;
+use lib_infra::util::timestamp;
+use std::collections::HashMap;
+use std::time::Duration;
 
 #[tokio::test]
 async fn grid_cell_update() {
@@ -36,13 +56,44 @@ async fn grid_cell_update() {
           SelectOptionCellChangeset::from_insert_option_id(&type_option.options.first().unwrap().id)
             .to_cell_changeset_str()
         },
-        FieldType::Checklist => {
-          let type_option = ChecklistTypeOptionPB::from(field_rev);
-          SelectOptionCellChangeset::from_insert_option_id(&type_option.options.first().unwrap().id)
-            .to_cell_changeset_str()
-        },
+        FieldType::Checklist => ChecklistCellChangeset {
+          insert_items: vec!["Write the tests".to_owned()],
+          ..Default::default()
+        }
+        .to_cell_changeset_str(),
         FieldType::Checkbox => "1".to_string(),
         FieldType::URL => "1".to_string(),
+        FieldType::Relation => {
+          RelationCellChangeset::from_inserted_row_ids(vec!["row_1".to_owned()])
+            .to_cell_changeset_str()
+        },
+        FieldType::Rollup => "1".to_string(),
+        FieldType::Formula => "1".to_string(),
+        FieldType::CreatedTime => "1".to_string(),
+        FieldType::LastEditedTime => "1".to_string(),
+        FieldType::Person => {
+          let type_option = PersonTypeOptionPB::from(field_rev);
+          PersonCellChangeset::from_inserted_user_ids(vec![type_option.members[0].id.clone()])
+            .to_cell_changeset_str()
+        },
+        FieldType::Attachment => {
+          AttachmentCellChangeset::from_inserted_attachments(vec![AttachmentPB {
+            id: "file_1.png".to_owned(),
+            name: "cat.png".to_owned(),
+            size: 1024,
+            created_at: 1647251762,
+          }])
+          .to_cell_changeset_str()
+        },
+        FieldType::Progress => "42".to_string(),
+        FieldType::Phone => "(415) 555-2671".to_string(),
+        FieldType::Email => "nathan@appflowy.io".to_string(),
+        FieldType::Duration => "1h 30m".to_string(),
+        FieldType::AutoNumber => "1".to_string(),
+        FieldType::TimeTracking => {
+          TimeTrackingCellChangeset::Adjust { seconds: 3600 }.to_cell_changeset_str()
+        },
+        FieldType::Location => "48.8584, 2.2945".to_string(),
       };
 
       scripts.push(UpdateCell {
@@ -51,6 +102,7 @@ async fn grid_cell_update() {
           row_id: row_rev.id.clone(),
           field_id: field_rev.id.clone(),
           type_cell_data: data,
+          base_type_cell_data: None,
         },
         is_err: false,
       });
@@ -101,3 +153,1257 @@ async fn url_cell_date_test() {
     }
   }
 }
+
+#[tokio::test]
+async fn update_cell_exceeding_size_limit_test() {
+  let test = DatabaseCellTest::new().await;
+  let row_id = test.row_revs.first().unwrap().id.clone();
+  let text_field = test.get_first_field_rev(FieldType::RichText);
+  let url_field = test.get_first_field_rev(FieldType::URL);
+
+  for field_rev in [text_field, url_field] {
+    let result = test
+      .editor
+      .update_cell_with_changeset(&row_id, &field_rev.id, "a".repeat(10001))
+      .await;
+    assert_eq!(
+      result.unwrap_err().code,
+      ErrorCode::CellDataTooLarge.value()
+    );
+
+    test
+      .editor
+      .update_cell_with_changeset(&row_id, &field_rev.id, "a".repeat(10000))
+      .await
+      .unwrap();
+  }
+}
+
+#[tokio::test]
+async fn create_row_exceeding_size_limit_test() {
+  let test = DatabaseCellTest::new().await;
+  let text_field = test.get_first_field_rev(FieldType::RichText);
+  let params = CreateRowParams {
+    view_id: test.view_id.clone(),
+    start_row_id: None,
+    group_id: None,
+    swimlane_id: None,
+    cell_data_by_field_id: Some(HashMap::from([(text_field.id.clone(), "a".repeat(10001))])),
+  };
+  let err = test.editor.create_row(params).await.unwrap_err();
+  assert_eq!(err.code, ErrorCode::CellDataTooLarge.value());
+  assert_eq!(
+    test
+      .editor
+      .get_all_row_revs(&test.view_id)
+      .await
+      .unwrap()
+      .len(),
+    test.row_revs.len()
+  );
+}
+
+#[tokio::test]
+async fn update_phone_cell_test() {
+  let test = DatabaseCellTest::new().await;
+  let row_id = test.row_revs.first().unwrap().id.clone();
+  let phone_field = test.get_first_field_rev(FieldType::Phone);
+  test
+    .editor
+    .update_cell_with_changeset(&row_id, &phone_field.id, "(415) 555-2671".to_owned())
+    .await
+    .unwrap();
+  let cell_rev = test
+    .editor
+    .get_cell_rev(&row_id, &phone_field.id)
+    .await
+    .unwrap()
+    .unwrap();
+  assert_eq!(
+    TypeCellData::try_from(&cell_rev).unwrap().cell_str,
+    "+14155552671"
+  );
+
+  // The invalid number is rejected instead of being saved
+  let result = test
+    .editor
+    .update_cell_with_changeset(&row_id, &phone_field.id, "call me".to_owned())
+    .await;
+  assert_eq!(
+    result.unwrap_err().code,
+    ErrorCode::InvalidPhoneNumber.value()
+  );
+}
+
+async fn last_edited_timestamp(
+  test: &DatabaseCellTest,
+  row_id: &str,
+  field_id: &str,
+) -> Option<i64> {
+  let cell_rev = test
+    .editor
+    .get_cell_rev(row_id, field_id)
+    .await
+    .unwrap()
+    .unwrap();
+  DateCellData::from_cell_str(&TypeCellData::try_from(&cell_rev).unwrap().cell_str)
+    .unwrap()
+    .timestamp
+}
+
+#[tokio::test]
+async fn update_cell_touches_last_edited_time_test() {
+  let test = DatabaseCellTest::new().await;
+  let row_id = test.row_revs.first().unwrap().id.clone();
+  let text_field = test.get_first_field_rev(FieldType::RichText);
+  let last_edited_time_field = test.get_first_field_rev(FieldType::LastEditedTime);
+  // Writing the computed cells doesn't touch the last edited time
+  let mut changeset = RowChangeset::new(row_id.clone());
+  changeset.cell_by_field_id.insert(
+    last_edited_time_field.id.clone(),
+    LastEditedTimeTypeOptionPB::cell_rev_from_timestamp(0),
+  );
+  test.editor.update_row(changeset).await.unwrap();
+  assert_eq!(
+    last_edited_timestamp(&test, &row_id, &last_edited_time_field.id).await,
+    None
+  );
+
+  test
+    .editor
+    .update_cell_with_changeset(&row_id, &text_field.id, "hello".to_owned())
+    .await
+    .unwrap();
+  assert!(
+    last_edited_timestamp(&test, &row_id, &last_edited_time_field.id)
+      .await
+      .is_some()
+  );
+}
+
+fn cell_address(
+  test: &DatabaseCellTest,
+  row_index: usize,
+  field_index: usize,
+) -> CellAddressParams {
+  CellAddressParams {
+    view_id: test.view_id.clone(),
+    row_index,
+    field_index,
+  }
+}
+
+#[tokio::test]
+async fn get_cell_by_address_test() {
+  let test = DatabaseCellTest::new().await;
+  let addressed_cell = test
+    .editor
+    .get_cell_by_address(cell_address(&test, 1, 2))
+    .await
+    .unwrap();
+  assert_eq!(addressed_cell.address.row_index, 1);
+  assert_eq!(addressed_cell.address.field_index, 2);
+  assert_eq!(addressed_cell.cell.row_id, test.row_revs[1].id);
+  assert_eq!(addressed_cell.cell.field_id, test.field_revs[2].id);
+
+  let err = test
+    .editor
+    .get_cell_by_address(cell_address(&test, test.row_revs.len(), 0))
+    .await
+    .unwrap_err();
+  assert_eq!(err.code, ErrorCode::OutOfBounds.value());
+}
+
+#[tokio::test]
+async fn cell_and_row_accessibility_test() {
+  let test = DatabaseCellTest::new().await;
+  let checkbox_field = test
+    .field_revs
+    .iter()
+    .find(|field_rev| FieldType::from(field_rev.ty).is_checkbox())
+    .unwrap()
+    .clone();
+  let cell = test
+    .editor
+    .get_cell(&CellIdParams {
+      view_id: test.view_id.clone(),
+      field_id: checkbox_field.id.clone(),
+      row_id: test.row_revs[0].id.clone(),
+    })
+    .await
+    .unwrap();
+  let accessibility = cell.accessibility.unwrap();
+  assert_eq!(accessibility.role, AccessibilityRolePB::Checkbox);
+  assert_eq!(accessibility.label, checkbox_field.name);
+  assert_eq!(accessibility.state, CellAccessibilityStatePB::Checked);
+  assert!(!accessibility.is_read_only);
+
+  let row = test
+    .editor
+    .get_row_with_accessibility(&test.row_revs[0].id)
+    .await
+    .unwrap()
+    .unwrap();
+  let accessibility = row.accessibility.unwrap();
+  let visible_field_count = test
+    .field_revs
+    .iter()
+    .filter(|field_rev| field_rev.visibility)
+    .count();
+  assert_eq!(accessibility.cells.len(), visible_field_count);
+  assert_eq!(accessibility.cells[0].label, "Name");
+  assert_eq!(accessibility.cells[0].value, "A");
+  assert_eq!(
+    accessibility.cells[0].state,
+    CellAccessibilityStatePB::Filled
+  );
+  assert!(accessibility.cells[0].is_announced_in_row);
+
+  // The empty primary cell is still announced
+  let row = test
+    .editor
+    .get_row_with_accessibility(&test.row_revs[1].id)
+    .await
+    .unwrap()
+    .unwrap();
+  let primary_cell = row.accessibility.unwrap().cells.remove(0);
+  assert_eq!(primary_cell.state, CellAccessibilityStatePB::Empty);
+  assert!(primary_cell.is_announced_in_row);
+}
+
+#[tokio::test]
+async fn get_adjacent_cell_test() {
+  let test = DatabaseCellTest::new().await;
+  let last_row_index = test.row_revs.len() - 1;
+  let last_field_index = test.field_revs.len() - 1;
+  let expectations = vec![
+    ((0, 0), CellMoveDirectionPB::Right, (0, 1)),
+    ((0, 0), CellMoveDirectionPB::Left, (0, 0)),
+    ((0, 0), CellMoveDirectionPB::Up, (0, 0)),
+    ((0, 0), CellMoveDirectionPB::Down, (1, 0)),
+    ((0, 0), CellMoveDirectionPB::Previous, (0, 0)),
+    (
+      (0, last_field_index),
+      CellMoveDirectionPB::Right,
+      (0, last_field_index),
+    ),
+    ((0, last_field_index), CellMoveDirectionPB::Next, (1, 0)),
+    ((1, 0), CellMoveDirectionPB::Previous, (0, last_field_index)),
+    (
+      (last_row_index, 0),
+      CellMoveDirectionPB::Down,
+      (last_row_index, 0),
+    ),
+    (
+      (last_row_index, last_field_index),
+      CellMoveDirectionPB::Next,
+      (last_row_index, last_field_index),
+    ),
+  ];
+
+  for ((row_index, field_index), direction, (expected_row_index, expected_field_index)) in
+    expectations
+  {
+    let addressed_cell = test
+      .editor
+      .get_adjacent_cell(cell_address(&test, row_index, field_index), direction)
+      .await
+      .unwrap();
+    assert_eq!(
+      addressed_cell.address.row_index as usize,
+      expected_row_index
+    );
+    assert_eq!(
+      addressed_cell.address.field_index as usize,
+      expected_field_index
+    );
+    assert_eq!(
+      addressed_cell.cell.row_id,
+      test.row_revs[expected_row_index].id
+    );
+    assert_eq!(
+      addressed_cell.cell.field_id,
+      test.field_revs[expected_field_index].id
+    );
+  }
+}
+
+#[tokio::test]
+async fn adjacent_cell_skips_hidden_field_test() {
+  let test = DatabaseCellTest::new().await;
+  test
+    .editor
+    .update_field(FieldChangesetParams {
+      field_id: test.field_revs[1].id.clone(),
+      view_id: test.view_id.clone(),
+      visibility: Some(false),
+      ..Default::default()
+    })
+    .await
+    .unwrap();
+
+  let addressed_cell = test
+    .editor
+    .get_adjacent_cell(cell_address(&test, 0, 0), CellMoveDirectionPB::Next)
+    .await
+    .unwrap();
+  assert_eq!(addressed_cell.address.field_index, 1);
+  assert_eq!(addressed_cell.cell.field_id, test.field_revs[2].id);
+}
+
+#[tokio::test]
+async fn repair_stale_cells_test() {
+  let test = DatabaseCellTest::new().await;
+  let row_id = test.row_revs[0].id.clone();
+  let date_field_id = test.get_first_field_rev(FieldType::DateTime).id.clone();
+  let checklist_field_id = test.get_first_field_rev(FieldType::Checklist).id.clone();
+
+  // The older versions saved the timestamp of the date cell directly, and saved the ids of the
+  // checked options in the checklist cell instead of its items.
+  let mut changeset = RowChangeset::new(row_id.clone());
+  changeset.cell_by_field_id.insert(
+    date_field_id.clone(),
+    CellRevision::new(TypeCellData::new("1647251762".to_owned(), FieldType::DateTime).to_json()),
+  );
+  changeset.cell_by_field_id.insert(
+    checklist_field_id.clone(),
+    CellRevision::new(TypeCellData::new("a,,b".to_owned(), FieldType::Checklist).to_json()),
+  );
+  test.editor.update_row(changeset).await.unwrap();
+
+  let is_invalid = |cells: &[InvalidCellPB], field_id: &str| {
+    cells
+      .iter()
+      .any(|cell| cell.row_id == row_id && cell.field_id == field_id)
+  };
+  let invalid_cells = test.editor.get_invalid_cells().await.unwrap();
+  assert!(is_invalid(&invalid_cells, &date_field_id));
+  assert!(is_invalid(&invalid_cells, &checklist_field_id));
+
+  let invalid_cells = test.editor.repair_invalid_cells().await.unwrap();
+  assert!(!is_invalid(&invalid_cells, &date_field_id));
+  assert!(!is_invalid(&invalid_cells, &checklist_field_id));
+}
+
+#[tokio::test]
+async fn conflicted_cell_rejects_changeset_test() {
+  let test = DatabaseCellTest::new().await;
+  let row_id = test.row_revs[0].id.clone();
+  let field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+  let params = CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: field_id.clone(),
+    row_id: row_id.clone(),
+  };
+
+  test.editor.mark_cell_conflicted(&row_id, &field_id);
+  assert!(test.editor.get_cell(&params).await.unwrap().is_conflicted);
+  let err = test
+    .editor
+    .update_cell(row_id.clone(), field_id.clone(), "hello world".to_owned())
+    .await
+    .unwrap_err();
+  assert_eq!(err.code, ErrorCode::CellIsConflicted.value());
+  assert_eq!(err.recovery, ErrorRecovery::Sync);
+
+  test.editor.resolve_cell_conflict(&row_id, &field_id);
+  assert!(!test.editor.get_cell(&params).await.unwrap().is_conflicted);
+  test
+    .editor
+    .update_cell(row_id, field_id, "hello world".to_owned())
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn outdated_base_cell_data_marks_cell_conflicted_test() {
+  let test = DatabaseCellTest::new().await;
+  let row_id = test.row_revs[0].id.clone();
+  let field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+  let params = CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: field_id.clone(),
+    row_id: row_id.clone(),
+  };
+  let base_type_cell_data = test
+    .editor
+    .get_cell_rev(&row_id, &field_id)
+    .await
+    .unwrap()
+    .map(|cell_rev| cell_rev.type_cell_data);
+
+  // The cell is changed after the base data was read
+  test
+    .editor
+    .update_cell(row_id.clone(), field_id.clone(), "hello".to_owned())
+    .await
+    .unwrap();
+  let err = test
+    .editor
+    .update_cell_from_base(
+      &row_id,
+      &field_id,
+      Some(base_type_cell_data.unwrap_or_default()),
+      "world".to_owned(),
+    )
+    .await
+    .unwrap_err();
+  assert_eq!(err.code, ErrorCode::CellIsConflicted.value());
+  assert!(test.editor.get_cell(&params).await.unwrap().is_conflicted);
+
+  test.editor.resolve_cell_conflict(&row_id, &field_id);
+  let base_type_cell_data = test
+    .editor
+    .get_cell_rev(&row_id, &field_id)
+    .await
+    .unwrap()
+    .map(|cell_rev| cell_rev.type_cell_data);
+  test
+    .editor
+    .update_cell_from_base(&row_id, &field_id, base_type_cell_data, "world".to_owned())
+    .await
+    .unwrap();
+  assert!(!test.editor.get_cell(&params).await.unwrap().is_conflicted);
+}
+
+#[tokio::test]
+async fn select_option_cell_creates_missing_options_test() {
+  let test = DatabaseCellTest::new().await;
+  let row_id = test.row_revs[0].id.clone();
+  let field_id = test.get_first_field_rev(FieldType::MultiSelect).id.clone();
+  let options = test.get_multi_select_type_option(&field_id);
+  let names = vec![options[0].name.clone(), "New option".to_owned()];
+
+  // The unknown names are dropped by default
+  let changeset = SelectOptionCellChangeset::from_insert_option_names(names.clone());
+  test
+    .editor
+    .update_select_option_cell(&test.view_id, &row_id, &field_id, changeset)
+    .await
+    .unwrap();
+  let type_option = get_multi_select_type_option(&test, &field_id).await;
+  assert_eq!(type_option.options.len(), options.len());
+  let option_ids = get_select_option_ids(&test, &row_id, &field_id).await;
+  assert!(option_ids.contains(&options[0].id));
+
+  test
+    .editor
+    .modify_field_rev(&test.view_id, &field_id, |field_rev| {
+      let mut type_option = field_rev
+        .get_type_option::<MultiSelectTypeOptionPB>(FieldType::MultiSelect.into())
+        .unwrap();
+      type_option.create_missing_options = true;
+      field_rev.insert_type_option(&type_option);
+      Ok(Some(()))
+    })
+    .await
+    .unwrap();
+  let changeset = SelectOptionCellChangeset::from_insert_option_names(names);
+  test
+    .editor
+    .update_select_option_cell(&test.view_id, &row_id, &field_id, changeset)
+    .await
+    .unwrap();
+  let type_option = get_multi_select_type_option(&test, &field_id).await;
+  assert_eq!(type_option.options.len(), options.len() + 1);
+  let new_option = type_option
+    .options
+    .iter()
+    .find(|option| option.name == "New option")
+    .unwrap();
+  let option_ids = get_select_option_ids(&test, &row_id, &field_id).await;
+  assert!(option_ids.contains(&new_option.id));
+}
+
+#[tokio::test]
+async fn select_option_cell_depends_on_another_field_test() {
+  let test = DatabaseCellTest::new().await;
+  let row_id = test.row_revs[0].id.clone();
+  let parent_field_id = test.get_first_field_rev(FieldType::SingleSelect).id.clone();
+  let parent_options = test.get_single_select_type_option(&parent_field_id).options;
+  let field_id = test.get_first_field_rev(FieldType::MultiSelect).id.clone();
+  let options = test.get_multi_select_type_option(&field_id);
+
+  let dependency = SelectOptionDependencyPB {
+    field_id: parent_field_id.clone(),
+    rules: vec![SelectOptionDependencyRulePB {
+      parent_option_id: parent_options[0].id.clone(),
+      option_ids: vec![options[0].id.clone()],
+    }],
+  };
+  test
+    .editor
+    .modify_field_rev(&test.view_id, &field_id, |field_rev| {
+      let mut type_option = field_rev
+        .get_type_option::<MultiSelectTypeOptionPB>(FieldType::MultiSelect.into())
+        .unwrap();
+      type_option.dependency = Some(dependency);
+      field_rev.insert_type_option(&type_option);
+      Ok(Some(()))
+    })
+    .await
+    .unwrap();
+  test
+    .editor
+    .update_cell_with_changeset(
+      &row_id,
+      &parent_field_id,
+      SelectOptionCellChangeset::from_insert_option_id(&parent_options[0].id),
+    )
+    .await
+    .unwrap();
+
+  let cell_data = test
+    .editor
+    .get_available_select_options(&row_id, &field_id)
+    .await
+    .unwrap();
+  assert_eq!(cell_data.options, vec![options[0].clone()]);
+
+  let changeset = SelectOptionCellChangeset::from_insert_option_id(&options[1].id);
+  let result = test
+    .editor
+    .update_select_option_cell(&test.view_id, &row_id, &field_id, changeset)
+    .await;
+  assert_eq!(
+    result.unwrap_err().code,
+    ErrorCode::SelectOptionNotAllowed.value()
+  );
+
+  let changeset = SelectOptionCellChangeset::from_insert_option_id(&options[0].id);
+  test
+    .editor
+    .update_select_option_cell(&test.view_id, &row_id, &field_id, changeset)
+    .await
+    .unwrap();
+  let option_ids = get_select_option_ids(&test, &row_id, &field_id).await;
+  assert!(option_ids.contains(&options[0].id));
+
+  // The option of the parent cell without a rule doesn't restrict the options
+  test
+    .editor
+    .update_cell_with_changeset(
+      &row_id,
+      &parent_field_id,
+      SelectOptionCellChangeset::from_insert_option_id(&parent_options[1].id),
+    )
+    .await
+    .unwrap();
+  let cell_data = test
+    .editor
+    .get_available_select_options(&row_id, &field_id)
+    .await
+    .unwrap();
+  assert_eq!(cell_data.options.len(), options.len());
+
+  // The options that are not allowed by the new parent option are removed from the cell
+  let changeset = SelectOptionCellChangeset::from_insert_option_id(&options[1].id);
+  test
+    .editor
+    .update_select_option_cell(&test.view_id, &row_id, &field_id, changeset)
+    .await
+    .unwrap();
+  test
+    .editor
+    .update_cell_with_changeset(
+      &row_id,
+      &parent_field_id,
+      SelectOptionCellChangeset::from_insert_option_id(&parent_options[0].id),
+    )
+    .await
+    .unwrap();
+  let option_ids = get_select_option_ids(&test, &row_id, &field_id).await;
+  assert_eq!(option_ids, vec![options[0].id.clone()]);
+}
+
+#[tokio::test]
+async fn relation_cell_links_rows_of_other_database_test() {
+  let test = DatabaseCellTest::new().await;
+  let view_data: Bytes = make_test_grid().into();
+  let related_view = ViewTest::new_grid_view(&test.sdk, view_data.to_vec()).await;
+  let related_view_id = related_view.view.id.clone();
+  let related_editor = test
+    .sdk
+    .database_manager
+    .open_database_view(&related_view_id)
+    .await
+    .unwrap();
+  let related_row_revs = related_editor
+    .get_all_row_revs(&related_view_id)
+    .await
+    .unwrap();
+  let related_primary_field_id = related_editor
+    .get_field_revs(None)
+    .await
+    .unwrap()
+    .into_iter()
+    .find(|field_rev| field_rev.is_primary)
+    .unwrap()
+    .id
+    .clone();
+
+  let field_id = test.get_first_field_rev(FieldType::Relation).id.clone();
+  let row_id = test.row_revs[0].id.clone();
+  let type_option = RelationTypeOptionPB {
+    database_id: related_editor.database_id.clone(),
+    ..Default::default()
+  };
+  test
+    .editor
+    .modify_field_rev(&test.view_id, &field_id, |field_rev| {
+      field_rev.insert_type_option(&type_option);
+      Ok(Some(()))
+    })
+    .await
+    .unwrap();
+
+  let linked_row_ids = vec![
+    related_row_revs[0].id.clone(),
+    related_row_revs[1].id.clone(),
+  ];
+  test
+    .editor
+    .update_cell_with_changeset(
+      &row_id,
+      &field_id,
+      RelationCellChangeset::from_inserted_row_ids(linked_row_ids.clone()),
+    )
+    .await
+    .unwrap();
+  let cell_id = CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: field_id.clone(),
+    row_id: row_id.clone(),
+  };
+  let related_rows = test
+    .sdk
+    .database_manager
+    .get_related_rows(&cell_id)
+    .await
+    .unwrap();
+  assert_eq!(related_rows.len(), 2);
+  for (related_row, linked_row_id) in related_rows.iter().zip(linked_row_ids.iter()) {
+    let primary_cell_str = related_editor
+      .get_cell_display_str(&CellIdParams {
+        view_id: related_view_id.clone(),
+        field_id: related_primary_field_id.clone(),
+        row_id: linked_row_id.clone(),
+      })
+      .await;
+    assert_eq!(&related_row.row_id, linked_row_id);
+    assert_eq!(related_row.display_value, primary_cell_str);
+  }
+
+  // Unlinks the first row
+  test
+    .editor
+    .update_cell_with_changeset(
+      &row_id,
+      &field_id,
+      RelationCellChangeset::from_removed_row_ids(vec![linked_row_ids[0].clone()]),
+    )
+    .await
+    .unwrap();
+  let related_rows = test
+    .sdk
+    .database_manager
+    .get_related_rows(&cell_id)
+    .await
+    .unwrap();
+  assert_eq!(related_rows.len(), 1);
+  assert_eq!(related_rows[0].row_id, linked_row_ids[1]);
+
+  // The rows that were deleted from the related database are skipped
+  related_editor.delete_row(&linked_row_ids[1]).await.unwrap();
+  let related_rows = test
+    .sdk
+    .database_manager
+    .get_related_rows(&cell_id)
+    .await
+    .unwrap();
+  assert!(related_rows.is_empty());
+}
+
+#[tokio::test]
+async fn relation_cell_on_delete_linked_row_test() {
+  let test = DatabaseCellTest::new().await;
+  let view_data: Bytes = make_test_grid().into();
+  let related_view = ViewTest::new_grid_view(&test.sdk, view_data.to_vec()).await;
+  let related_view_id = related_view.view.id.clone();
+  let database_manager = test.sdk.database_manager.clone();
+  let related_editor = database_manager
+    .open_database_view(&related_view_id)
+    .await
+    .unwrap();
+  let related_row_revs = related_editor
+    .get_all_row_revs(&related_view_id)
+    .await
+    .unwrap();
+
+  let field_id = test.get_first_field_rev(FieldType::Relation).id.clone();
+  let row_id = test.row_revs[0].id.clone();
+  let set_on_delete = |on_delete: RelationOnDeletePB| {
+    let type_option = RelationTypeOptionPB {
+      database_id: related_editor.database_id.clone(),
+      on_delete,
+    };
+    let editor = test.editor.clone();
+    let view_id = test.view_id.clone();
+    let field_id = field_id.clone();
+    async move {
+      editor
+        .modify_field_rev(&view_id, &field_id, |field_rev| {
+          field_rev.insert_type_option(&type_option);
+          Ok(Some(()))
+        })
+        .await
+        .unwrap();
+    }
+  };
+  set_on_delete(RelationOnDeletePB::Restrict).await;
+
+  let linked_row_ids = vec![
+    related_row_revs[0].id.clone(),
+    related_row_revs[1].id.clone(),
+  ];
+  test
+    .editor
+    .update_cell_with_changeset(
+      &row_id,
+      &field_id,
+      RelationCellChangeset::from_inserted_row_ids(linked_row_ids.clone()),
+    )
+    .await
+    .unwrap();
+
+  let dependents = database_manager
+    .get_relation_dependents(&related_editor.database_id, Some(&linked_row_ids[0]))
+    .await
+    .unwrap();
+  assert_eq!(dependents.len(), 1);
+  assert_eq!(dependents[0].database_id, test.editor.database_id);
+  assert_eq!(dependents[0].field_id, field_id);
+  assert_eq!(dependents[0].row_ids, vec![row_id.clone()]);
+
+  // The restricted relation field refuses to lose its linked row
+  let err = database_manager
+    .delete_row(&related_view_id, &linked_row_ids[0])
+    .await
+    .unwrap_err();
+  assert_eq!(err.code, ErrorCode::RelationDependentsExist.value());
+  assert!(related_editor
+    .get_row_rev(&linked_row_ids[0])
+    .await
+    .unwrap()
+    .is_some());
+
+  // None of the rows is deleted if any of them is restricted
+  let err = database_manager
+    .delete_rows(
+      &related_view_id,
+      vec![related_row_revs[2].id.clone(), linked_row_ids[0].clone()],
+    )
+    .await
+    .unwrap_err();
+  assert_eq!(err.code, ErrorCode::RelationDependentsExist.value());
+  assert!(related_editor
+    .get_row_rev(&related_row_revs[2].id)
+    .await
+    .unwrap()
+    .is_some());
+
+  // The trashed row is still linked by the cell, it's unlinked once it gets purged
+  set_on_delete(RelationOnDeletePB::Clear).await;
+  database_manager
+    .delete_row(&related_view_id, &linked_row_ids[0])
+    .await
+    .unwrap();
+  let (_, cell_data) = test
+    .editor
+    .get_relation_cell_data(&row_id, &field_id)
+    .await
+    .unwrap();
+  assert_eq!(cell_data.row_ids, linked_row_ids);
+
+  database_manager
+    .purge_trashed_rows(timestamp() + 1)
+    .await
+    .unwrap();
+  let (_, cell_data) = test
+    .editor
+    .get_relation_cell_data(&row_id, &field_id)
+    .await
+    .unwrap();
+  assert_eq!(cell_data.row_ids, vec![linked_row_ids[1].clone()]);
+
+  // All the rows of the database are unlinked when the database is deleted
+  database_manager
+    .unlink_relation_dependents(&related_editor.database_id, None)
+    .await
+    .unwrap();
+  let (_, cell_data) = test
+    .editor
+    .get_relation_cell_data(&row_id, &field_id)
+    .await
+    .unwrap();
+  assert!(cell_data.row_ids.is_empty());
+}
+
+#[tokio::test]
+async fn rollup_cell_sums_numbers_of_linked_rows_test() {
+  let test = DatabaseCellTest::new().await;
+  let view_data: Bytes = make_test_grid().into();
+  let related_view = ViewTest::new_grid_view(&test.sdk, view_data.to_vec()).await;
+  let related_editor = test
+    .sdk
+    .database_manager
+    .open_database_view(&related_view.view.id)
+    .await
+    .unwrap();
+  let related_row_revs = related_editor
+    .get_all_row_revs(&related_view.view.id)
+    .await
+    .unwrap();
+  let related_number_field_id = related_editor
+    .get_field_revs(None)
+    .await
+    .unwrap()
+    .into_iter()
+    .find(|field_rev| FieldType::from(field_rev.ty).is_number())
+    .unwrap()
+    .id
+    .clone();
+
+  let relation_field_id = test.get_first_field_rev(FieldType::Relation).id.clone();
+  let rollup_field_id = test.get_first_field_rev(FieldType::Rollup).id.clone();
+  let relation_type_option = RelationTypeOptionPB {
+    database_id: related_editor.database_id.clone(),
+    ..Default::default()
+  };
+  test
+    .editor
+    .modify_field_rev(&test.view_id, &relation_field_id, |field_rev| {
+      field_rev.insert_type_option(&relation_type_option);
+      Ok(Some(()))
+    })
+    .await
+    .unwrap();
+  let rollup_type_option = RollupTypeOptionPB {
+    relation_field_id: relation_field_id.clone(),
+    target_field_id: related_number_field_id.clone(),
+    calculation: RollupCalculationPB::Sum,
+  };
+  test
+    .editor
+    .modify_field_rev(&test.view_id, &rollup_field_id, |field_rev| {
+      field_rev.insert_type_option(&rollup_type_option);
+      Ok(Some(()))
+    })
+    .await
+    .unwrap();
+
+  let row_id = test.row_revs[0].id.clone();
+  let linked_row_ids = vec![
+    related_row_revs[0].id.clone(),
+    related_row_revs[1].id.clone(),
+  ];
+  for (linked_row_id, number) in linked_row_ids.iter().zip(["10", "5"]) {
+    related_editor
+      .update_cell(
+        linked_row_id.clone(),
+        related_number_field_id.clone(),
+        number.to_owned(),
+      )
+      .await
+      .unwrap();
+  }
+  test
+    .editor
+    .update_cell_with_changeset(
+      &row_id,
+      &relation_field_id,
+      RelationCellChangeset::from_inserted_row_ids(linked_row_ids.clone()),
+    )
+    .await
+    .unwrap();
+  let cell_id = CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: rollup_field_id.clone(),
+    row_id: row_id.clone(),
+  };
+  assert_cell_display_str_eventually(&test, &cell_id, "15").await;
+
+  // Changing the linked row calculates the rollup again
+  related_editor
+    .update_cell(
+      linked_row_ids[1].clone(),
+      related_number_field_id.clone(),
+      "7".to_owned(),
+    )
+    .await
+    .unwrap();
+  assert_cell_display_str_eventually(&test, &cell_id, "17").await;
+
+  // The rows without linked rows sum up to zero
+  let other_cell_id = CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: rollup_field_id.clone(),
+    row_id: test.row_revs[1].id.clone(),
+  };
+  assert_cell_display_str_eventually(&test, &other_cell_id, "0").await;
+}
+
+#[tokio::test]
+async fn grid_cell_formula_test() {
+  let test = DatabaseCellTest::new().await;
+  let number_field_id = test.get_first_field_rev(FieldType::Number).id.clone();
+  let formula_field_id = test.get_first_field_rev(FieldType::Formula).id.clone();
+  let set_expression = |expression: &str| {
+    let type_option = FormulaTypeOptionPB {
+      expression: expression.to_owned(),
+    };
+    test
+      .editor
+      .modify_field_rev(&test.view_id, &formula_field_id, move |field_rev| {
+        field_rev.insert_type_option(&type_option);
+        Ok(Some(()))
+      })
+  };
+  set_expression("{Price} * 2 + 1").await.unwrap();
+
+  let row_id = test.row_revs[0].id.clone();
+  let cell_id = CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: formula_field_id.clone(),
+    row_id: row_id.clone(),
+  };
+  assert_cell_display_str_eventually(&test, &cell_id, "3").await;
+
+  // Changing the referred cell calculates the formula of the row again
+  test
+    .editor
+    .update_cell(row_id.clone(), number_field_id.clone(), "5".to_owned())
+    .await
+    .unwrap();
+  assert_cell_display_str_eventually(&test, &cell_id, "11").await;
+
+  // The error of the formula is exposed in the cell
+  set_expression("{Cost} * 2").await.unwrap();
+  assert_cell_display_str_eventually(&test, &cell_id, "").await;
+  let cell_data = test
+    .editor
+    .get_cell_protobuf(&cell_id)
+    .await
+    .unwrap()
+    .parser::<FormulaCellDataParser>()
+    .unwrap();
+  assert_eq!(cell_data.error, "The field {Cost} doesn't exist");
+}
+
+#[tokio::test]
+async fn attachment_cell_attach_resolve_and_remove_file_test() {
+  let test = DatabaseCellTest::new().await;
+  let field_id = test.get_first_field_rev(FieldType::Attachment).id.clone();
+  let cell_id = || CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: field_id.clone(),
+    row_id: test.row_revs[0].id.clone(),
+  };
+  let file_path = std::env::temp_dir().join(format!("{}.txt", test.view_id));
+  std::fs::write(&file_path, "Hello AppFlowy").unwrap();
+
+  let attachment = test
+    .sdk
+    .database_manager
+    .attach_file(AttachFileParams {
+      cell_id: cell_id(),
+      file_path: file_path.to_str().unwrap().to_owned(),
+    })
+    .await
+    .unwrap();
+  assert_eq!(attachment.name, format!("{}.txt", test.view_id));
+  assert_eq!(attachment.size, 14);
+
+  let attachment_id = AttachmentIdParams {
+    cell_id: cell_id(),
+    attachment_id: attachment.id.clone(),
+  };
+  let resolved_attachment = test
+    .sdk
+    .database_manager
+    .resolve_attachment(attachment_id)
+    .await
+    .unwrap();
+  assert_eq!(resolved_attachment.attachment, attachment);
+  // The file is copied, so it's kept after the original file is deleted
+  std::fs::remove_file(&file_path).unwrap();
+  let stored_file_path = resolved_attachment.file_path;
+  assert_eq!(
+    std::fs::read_to_string(&stored_file_path).unwrap(),
+    "Hello AppFlowy"
+  );
+
+  let attachment_id = AttachmentIdParams {
+    cell_id: cell_id(),
+    attachment_id: attachment.id.clone(),
+  };
+  test
+    .sdk
+    .database_manager
+    .remove_attachment(attachment_id)
+    .await
+    .unwrap();
+  assert!(!std::path::Path::new(&stored_file_path).exists());
+  let attachment_id = AttachmentIdParams {
+    cell_id: cell_id(),
+    attachment_id: attachment.id,
+  };
+  let error = test
+    .sdk
+    .database_manager
+    .resolve_attachment(attachment_id)
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+}
+
+#[tokio::test]
+async fn attachment_cell_rejects_file_exceeding_max_attachment_size_test() {
+  let test = DatabaseCellTest::new().await;
+  let field_id = test.get_first_field_rev(FieldType::Attachment).id.clone();
+  let file_path = std::env::temp_dir().join(format!("{}-large.txt", test.view_id));
+  std::fs::write(&file_path, "Hello AppFlowy").unwrap();
+
+  test.sdk.database_manager.set_max_attachment_size(8);
+  let error = test
+    .sdk
+    .database_manager
+    .attach_file(AttachFileParams {
+      cell_id: CellIdParams {
+        view_id: test.view_id.clone(),
+        field_id,
+        row_id: test.row_revs[0].id.clone(),
+      },
+      file_path: file_path.to_str().unwrap().to_owned(),
+    })
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::AttachmentTooLarge.value());
+  test.sdk.database_manager.set_max_attachment_size(0);
+  std::fs::remove_file(&file_path).unwrap();
+}
+
+#[tokio::test]
+async fn checklist_cell_insert_update_delete_and_move_items_test() {
+  let test = DatabaseCellTest::new().await;
+  let row_id = test.row_revs[0].id.clone();
+  let field_id = test.get_first_field_rev(FieldType::Checklist).id.clone();
+  let cell_id = || CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: field_id.clone(),
+    row_id: row_id.clone(),
+  };
+  let changeset = |insert_items: Vec<&str>,
+                   update_items: Vec<ChecklistItemPB>,
+                   delete_item_ids: Vec<String>| ChecklistCellChangesetParams {
+    cell_id: cell_id(),
+    insert_items: insert_items
+      .into_iter()
+      .map(|name| name.to_owned())
+      .collect(),
+    update_items,
+    delete_item_ids,
+  };
+
+  // Uncheck all the items of the row before editing them
+  let items = test
+    .editor
+    .get_checklist_cell_data(&row_id, &field_id)
+    .await
+    .unwrap()
+    .items;
+  let update_items = items
+    .iter()
+    .map(|item| ChecklistItemPB {
+      is_checked: false,
+      ..item.clone()
+    })
+    .collect();
+  test
+    .editor
+    .update_checklist_cell(changeset(vec!["Write the tests"], update_items, vec![]))
+    .await
+    .unwrap();
+  let cell_data = test
+    .editor
+    .get_checklist_cell_data(&row_id, &field_id)
+    .await
+    .unwrap();
+  assert_eq!(cell_data.items.len(), items.len() + 1);
+  assert_eq!(cell_data.items.last().unwrap().name, "Write the tests");
+  assert_eq!(cell_data.percentage, 0.0);
+
+  // Check and rename the new item, and delete the first item
+  let new_item = ChecklistItemPB {
+    name: "Write more tests".to_owned(),
+    is_checked: true,
+    ..cell_data.items.last().unwrap().clone()
+  };
+  test
+    .editor
+    .update_checklist_cell(changeset(
+      vec![],
+      vec![new_item.clone()],
+      vec![items[0].id.clone()],
+    ))
+    .await
+    .unwrap();
+  let cell_data = test
+    .editor
+    .get_checklist_cell_data(&row_id, &field_id)
+    .await
+    .unwrap();
+  assert_eq!(cell_data.items.len(), items.len());
+  assert_eq!(cell_data.items.last().unwrap(), &new_item);
+  assert_eq!(cell_data.percentage, 1.0 / items.len() as f64);
+
+  // Move the new item to the top
+  test
+    .editor
+    .move_checklist_item(MoveChecklistItemParams {
+      cell_id: cell_id(),
+      from_item_id: new_item.id.clone(),
+      to_item_id: cell_data.items[0].id.clone(),
+    })
+    .await
+    .unwrap();
+  let cell_data = test
+    .editor
+    .get_checklist_cell_data(&row_id, &field_id)
+    .await
+    .unwrap();
+  assert_eq!(cell_data.items[0], new_item);
+
+  let unknown_item = ChecklistItemPB {
+    id: "unknown".to_owned(),
+    name: "Unknown".to_owned(),
+    is_checked: true,
+    due: None,
+  };
+  let error = test
+    .editor
+    .update_checklist_cell(changeset(vec![], vec![unknown_item], vec![]))
+    .await
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+
+  // The items of the other rows don't change
+  let other_cell_data = test
+    .editor
+    .get_checklist_cell_data(&test.row_revs[1].id, &field_id)
+    .await
+    .unwrap();
+  assert_eq!(other_cell_data.items.len(), items.len());
+  assert!(other_cell_data
+    .items
+    .iter()
+    .all(|item| item.name != new_item.name));
+}
+
+#[tokio::test]
+async fn location_cell_update_with_coordinates_and_address_test() {
+  let test = DatabaseCellTest::new().await;
+  let cell_id = CellIdParams {
+    view_id: test.view_id.clone(),
+    field_id: test.get_first_field_rev(FieldType::Location).id.clone(),
+    row_id: test.row_revs[0].id.clone(),
+  };
+  let database_manager = test.sdk.database_manager.clone();
+  database_manager
+    .update_location_cell(LocationCellChangesetParams {
+      cell_id: cell_id.clone(),
+      coordinates: Some((48.8584, 2.2945)),
+      address: "".to_owned(),
+    })
+    .await
+    .unwrap();
+  let cell_data = test
+    .editor
+    .get_cell_protobuf(&cell_id)
+    .await
+    .unwrap()
+    .parser::<LocationCellDataParser>()
+    .unwrap();
+  assert!(!cell_data.is_empty);
+  assert_eq!(cell_data.formatted, "48.85840, 2.29450");
+
+  // The addresses can't be geocoded without a geocoding provider
+  let result = database_manager
+    .update_location_cell(LocationCellChangesetParams {
+      cell_id: cell_id.clone(),
+      coordinates: None,
+      address: "Tour Eiffel, Paris".to_owned(),
+    })
+    .await;
+  assert!(result.is_err());
+
+  database_manager
+    .update_location_cell(LocationCellChangesetParams {
+      cell_id: cell_id.clone(),
+      coordinates: None,
+      address: "".to_owned(),
+    })
+    .await
+    .unwrap();
+  let cell_data = test
+    .editor
+    .get_cell_protobuf(&cell_id)
+    .await
+    .unwrap()
+    .parser::<LocationCellDataParser>()
+    .unwrap();
+  assert!(cell_data.is_empty);
+}
+
+/// The rollup and the formula cells are calculated in the background, so the cell is read until
+/// it has the expected value or the timeout is reached.
+async fn assert_cell_display_str_eventually(
+  test: &DatabaseCellTest,
+  cell_id: &CellIdParams,
+  expected: &str,
+) {
+  for _ in 0..20 {
+    if test.editor.get_cell_display_str(cell_id).await == expected {
+      return;
+    }
+    tokio::time::sleep(Duration::from_millis(100)).await;
+  }
+  assert_eq!(test.editor.get_cell_display_str(cell_id).await, expected);
+}
+
+async fn get_multi_select_type_option(
+  test: &DatabaseCellTest,
+  field_id: &str,
+) -> MultiSelectTypeOptionPB {
+  test
+    .editor
+    .get_field_rev(field_id)
+    .await
+    .unwrap()
+    .get_type_option::<MultiSelectTypeOptionPB>(FieldType::MultiSelect.into())
+    .unwrap()
+}
+
+async fn get_select_option_ids(
+  test: &DatabaseCellTest,
+  row_id: &str,
+  field_id: &str,
+) -> Vec<String> {
+  let cell_rev = test
+    .editor
+    .get_cell_rev(row_id, field_id)
+    .await
+    .unwrap()
+    .unwrap();
+  SelectOptionIds::from(TypeCellData::try_from(cell_rev).unwrap().cell_str).into_inner()
+}
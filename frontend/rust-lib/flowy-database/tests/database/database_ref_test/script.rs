@@ -1,6 +1,7 @@
 use crate::database::block_test::util::DatabaseRowTestBuilder;
 use crate::database::database_editor::DatabaseEditorTest;
 use database_model::RowRevision;
+use flowy_database::entities::FieldSchemaPB;
 use flowy_database::services::database::DatabaseEditor;
 use flowy_database::services::persistence::database_ref::{DatabaseInfo, DatabaseViewRef};
 use std::collections::HashMap;
@@ -26,6 +27,10 @@ pub enum LinkDatabaseTestScript {
   AssertNumberOfDatabase {
     expected: usize,
   },
+  AssertNumberOfDatabasesWithSchema {
+    fields: Vec<FieldSchemaPB>,
+    expected: usize,
+  },
 }
 
 pub struct LinkDatabaseTest {
@@ -121,6 +126,16 @@ impl LinkDatabaseTest {
         let databases = self.all_databases().await;
         assert_eq!(databases.len(), expected);
       },
+      LinkDatabaseTestScript::AssertNumberOfDatabasesWithSchema { fields, expected } => {
+        let databases = self
+          .inner
+          .sdk
+          .database_manager
+          .get_databases_with_schema(&fields)
+          .await
+          .unwrap();
+        assert_eq!(databases.len(), expected);
+      },
       LinkDatabaseTestScript::CreateRow { view_id, row_rev } => {
         let editor = self.get_database_editor(&view_id).await;
         let _ = editor.insert_rows(vec![row_rev]).await.unwrap();
@@ -1,5 +1,15 @@
+use crate::database::database_editor::DatabaseEditorTest;
 use crate::database::database_ref_test::script::LinkDatabaseTest;
 use crate::database::database_ref_test::script::LinkDatabaseTestScript::*;
+use crate::database::mock_data::make_test_grid;
+use bytes::Bytes;
+use flowy_database::entities::{FieldSchemaPB, FieldType, MergeDatabasesParams};
+use flowy_database::services::cell::TypeCellData;
+use flowy_database::services::field::{
+  SelectOptionCellChangeset, SelectOptionIds, SelectOptionPB, SingleSelectTypeOptionPB,
+};
+use flowy_database::util::make_default_grid;
+use flowy_test::helper::ViewTest;
 
 #[tokio::test]
 async fn number_of_database_test() {
@@ -101,3 +111,174 @@ async fn multiple_views_share_database_rows() {
     ])
     .await;
 }
+
+#[tokio::test]
+async fn database_with_same_schema_test() {
+  let mut test = LinkDatabaseTest::new().await;
+  let fields = make_default_grid()
+    .field_revs
+    .iter()
+    .map(|field_rev| FieldSchemaPB::from(field_rev.as_ref()))
+    .collect::<Vec<FieldSchemaPB>>();
+  let mut other_fields = fields.clone();
+  other_fields[0].field_type = Some(FieldType::Number);
+  let untyped_fields = fields
+    .iter()
+    .map(|field| FieldSchemaPB {
+      name: field.name.clone(),
+      field_type: None,
+    })
+    .collect::<Vec<FieldSchemaPB>>();
+
+  test
+    .run_scripts(vec![
+      AssertNumberOfDatabasesWithSchema {
+        fields: fields.clone(),
+        expected: 0,
+      },
+      CreateNewGrid,
+      AssertNumberOfDatabasesWithSchema {
+        fields: fields.clone(),
+        expected: 1,
+      },
+      AssertNumberOfDatabasesWithSchema {
+        fields: untyped_fields,
+        expected: 1,
+      },
+      AssertNumberOfDatabasesWithSchema {
+        fields: other_fields,
+        expected: 0,
+      },
+      CreateNewGrid,
+      AssertNumberOfDatabasesWithSchema {
+        fields,
+        expected: 2,
+      },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn merge_databases_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let view_data: Bytes = make_test_grid().into();
+  let source_view = ViewTest::new_grid_view(&test.sdk, view_data.to_vec()).await;
+  let source_view_id = source_view.view.id.clone();
+  let source_editor = test
+    .sdk
+    .database_manager
+    .open_database_view(&source_view_id)
+    .await
+    .unwrap();
+  let source_row_revs = source_editor
+    .get_all_row_revs(&source_view_id)
+    .await
+    .unwrap();
+
+  // The option that the target database doesn't have is added to it
+  let source_field_rev = source_editor
+    .get_field_revs(None)
+    .await
+    .unwrap()
+    .into_iter()
+    .find(|field_rev| FieldType::from(field_rev.ty) == FieldType::SingleSelect)
+    .unwrap();
+  let new_option = SelectOptionPB::new("Archived");
+  source_editor
+    .modify_field_rev(&source_view_id, &source_field_rev.id, |field_rev| {
+      let mut type_option = field_rev
+        .get_type_option::<SingleSelectTypeOptionPB>(FieldType::SingleSelect.into())
+        .unwrap();
+      type_option.options.push(new_option.clone());
+      field_rev.insert_type_option(&type_option);
+      Ok(Some(()))
+    })
+    .await
+    .unwrap();
+  source_editor
+    .update_cell_with_changeset(
+      &source_row_revs[0].id,
+      &source_field_rev.id,
+      SelectOptionCellChangeset::from_insert_option_id(&new_option.id),
+    )
+    .await
+    .unwrap();
+
+  let result = test
+    .sdk
+    .database_manager
+    .merge_databases(MergeDatabasesParams {
+      target_view_id: test.view_id.clone(),
+      source_view_id: source_view_id.clone(),
+      trash_source: true,
+    })
+    .await
+    .unwrap();
+  assert_eq!(result.merged_rows as usize, source_row_revs.len());
+  assert_eq!(result.added_options, 1);
+  assert!(result.conflicts.is_empty());
+
+  let row_revs = test.editor.get_all_row_revs(&test.view_id).await.unwrap();
+  assert_eq!(row_revs.len(), test.row_revs.len() + source_row_revs.len());
+  let field_id = test.get_first_field_rev(FieldType::SingleSelect).id.clone();
+  let type_option = test
+    .editor
+    .get_field_rev(&field_id)
+    .await
+    .unwrap()
+    .get_type_option::<SingleSelectTypeOptionPB>(FieldType::SingleSelect.into())
+    .unwrap();
+  let added_option = type_option
+    .options
+    .iter()
+    .find(|option| option.name == "Archived")
+    .unwrap();
+  let merged_row_rev = &row_revs[test.row_revs.len()];
+  let cell_str = TypeCellData::try_from(merged_row_rev.cells.get(&field_id).unwrap())
+    .unwrap()
+    .cell_str;
+  assert_eq!(
+    SelectOptionIds::from(cell_str).into_inner(),
+    vec![added_option.id.clone()]
+  );
+
+  // The source rows are moved to the trash
+  let source_row_revs = source_editor
+    .get_all_row_revs(&source_view_id)
+    .await
+    .unwrap();
+  assert!(source_row_revs.is_empty());
+
+  let result = test
+    .sdk
+    .database_manager
+    .merge_databases(MergeDatabasesParams {
+      target_view_id: test.view_id.clone(),
+      source_view_id: test.view_id.clone(),
+      trash_source: false,
+    })
+    .await;
+  assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn append_rows_to_database_with_same_schema_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let build_context = make_test_grid();
+  let number_of_rows = build_context
+    .blocks
+    .iter()
+    .map(|block| block.rows.len())
+    .sum::<usize>();
+  let result = test
+    .sdk
+    .database_manager
+    .append_rows_to_database(&test.editor.database_id, build_context)
+    .await
+    .unwrap();
+  assert_eq!(result.merged_rows as usize, number_of_rows);
+  assert!(result.conflicts.is_empty());
+
+  let row_revs = test.editor.get_all_row_revs(&test.view_id).await.unwrap();
+  assert_eq!(row_revs.len(), test.row_revs.len() + number_of_rows);
+}
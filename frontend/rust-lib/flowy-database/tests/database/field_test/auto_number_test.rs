@@ -0,0 +1,133 @@
+use crate::database::database_editor::DatabaseEditorTest;
+use database_model::{gen_row_id, RowChangeset, RowRevision};
+use flowy_database::entities::{CreateRowParams, FieldType};
+use flowy_database::services::cell::{FromCellString, TypeCellData};
+use flowy_database::services::field::{AutoNumberCellData, AutoNumberTypeOptionPB};
+use std::sync::Arc;
+
+fn get_number(row_rev: &RowRevision, field_id: &str) -> Option<i64> {
+  let cell_rev = row_rev.cells.get(field_id)?;
+  let type_cell_data = TypeCellData::try_from(cell_rev).ok()?;
+  AutoNumberCellData::from_cell_str(&type_cell_data.cell_str)
+    .ok()?
+    .0
+}
+
+async fn get_numbers(test: &DatabaseEditorTest, field_id: &str) -> Vec<i64> {
+  let row_revs = test.editor.get_all_row_revs(&test.view_id).await.unwrap();
+  row_revs
+    .iter()
+    .map(|row_rev| get_number(row_rev, field_id).unwrap())
+    .collect()
+}
+
+async fn get_last_row_rev(test: &DatabaseEditorTest) -> Arc<RowRevision> {
+  let row_revs = test.editor.get_all_row_revs(&test.view_id).await.unwrap();
+  row_revs.last().unwrap().clone()
+}
+
+#[tokio::test]
+async fn grid_auto_number_existing_rows_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let field_rev = test.get_first_field_rev(FieldType::AutoNumber).clone();
+
+  // The rows are numbered in the order they were created
+  let mut numbers = get_numbers(&test, &field_rev.id).await;
+  numbers.sort();
+  let expected = (1..=test.row_revs.len() as i64).collect::<Vec<i64>>();
+  assert_eq!(numbers, expected);
+}
+
+#[tokio::test]
+async fn grid_auto_number_new_rows_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let field_rev = test.get_first_field_rev(FieldType::AutoNumber).clone();
+  let row_count = test.row_revs.len() as i64;
+
+  let params = CreateRowParams {
+    view_id: test.view_id.clone(),
+    start_row_id: None,
+    group_id: None,
+    swimlane_id: None,
+    cell_data_by_field_id: None,
+  };
+  let row_pb = test.editor.create_row(params).await.unwrap();
+  let row_rev = test.editor.get_row_rev(&row_pb.id).await.unwrap().unwrap();
+  assert_eq!(get_number(&row_rev, &field_rev.id), Some(row_count + 1));
+
+  // The duplicated row gets a new number instead of the number of the original row
+  test
+    .editor
+    .duplicate_row(&test.view_id, &row_pb.id)
+    .await
+    .unwrap();
+  let numbers = get_numbers(&test, &field_rev.id).await;
+  assert!(numbers.contains(&(row_count + 2)));
+
+  // The imported row gets a new number even if its cell is copied from another row
+  let mut imported_row_rev = (*get_last_row_rev(&test).await).clone();
+  imported_row_rev.id = gen_row_id();
+  test
+    .editor
+    .insert_rows(vec![imported_row_rev])
+    .await
+    .unwrap();
+  let mut numbers = get_numbers(&test, &field_rev.id).await;
+  numbers.sort();
+  numbers.dedup();
+  assert_eq!(numbers.len() as i64, row_count + 3);
+  assert_eq!(numbers.last(), Some(&(row_count + 3)));
+}
+
+#[tokio::test]
+async fn grid_auto_number_read_only_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let field_rev = test.get_first_field_rev(FieldType::AutoNumber).clone();
+  let row_rev = test.row_revs.first().unwrap().clone();
+  let row_rev = test.editor.get_row_rev(&row_rev.id).await.unwrap().unwrap();
+  let number = get_number(&row_rev, &field_rev.id);
+
+  test
+    .editor
+    .update_cell(row_rev.id.clone(), field_rev.id.clone(), "100".to_owned())
+    .await
+    .unwrap();
+  let row_rev = test.editor.get_row_rev(&row_rev.id).await.unwrap().unwrap();
+  assert_eq!(get_number(&row_rev, &field_rev.id), number);
+}
+
+#[tokio::test]
+async fn grid_auto_number_reconcile_duplicated_numbers_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let field_rev = test.get_first_field_rev(FieldType::AutoNumber).clone();
+  let row_revs = test.editor.get_all_row_revs(&test.view_id).await.unwrap();
+  let first_number = get_number(&row_revs[0], &field_rev.id).unwrap();
+
+  // Another device gave the same number to the last row while both devices were offline
+  let mut changeset = RowChangeset::new(row_revs.last().unwrap().id.clone());
+  changeset.cell_by_field_id.insert(
+    field_rev.id.clone(),
+    AutoNumberTypeOptionPB::cell_rev_from_number(first_number),
+  );
+  test.editor.update_row(changeset).await.unwrap();
+
+  // The numbers are reconciled when the database is opened again
+  let database_manager = test.sdk.database_manager.clone();
+  database_manager
+    .close_database_view(&test.view_id)
+    .await
+    .unwrap();
+  let editor = database_manager
+    .open_database_view(&test.view_id)
+    .await
+    .unwrap();
+  let row_revs = editor.get_all_row_revs(&test.view_id).await.unwrap();
+  let mut numbers = row_revs
+    .iter()
+    .map(|row_rev| get_number(row_rev, &field_rev.id).unwrap())
+    .collect::<Vec<i64>>();
+  numbers.sort();
+  numbers.dedup();
+  assert_eq!(numbers.len(), row_revs.len());
+  assert!(numbers.contains(&first_number));
+}
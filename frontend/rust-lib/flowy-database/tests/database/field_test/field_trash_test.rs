@@ -0,0 +1,94 @@
+use crate::database::database_editor::DatabaseEditorTest;
+use database_model::RowRevision;
+use flowy_database::entities::{AlterSortParams, FieldType};
+use lib_infra::util::timestamp;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn grid_trash_and_restore_field_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let field_rev = test.get_first_field_rev(FieldType::URL).clone();
+  let field_index = test
+    .field_revs
+    .iter()
+    .position(|other| other.id == field_rev.id)
+    .unwrap();
+  let count_cells = |row_revs: &[Arc<RowRevision>]| {
+    row_revs
+      .iter()
+      .filter(|row_rev| row_rev.cells.contains_key(&field_rev.id))
+      .count()
+  };
+  let cell_count = count_cells(&test.row_revs);
+  assert!(cell_count > 0);
+
+  test.editor.delete_field(&field_rev.id).await.unwrap();
+  let field_revs = test.editor.get_field_revs(None).await.unwrap();
+  assert!(field_revs.iter().all(|other| other.id != field_rev.id));
+  let trashed_fields = test.editor.get_trashed_fields().await;
+  assert_eq!(trashed_fields.len(), 1);
+  assert_eq!(trashed_fields[0].field.id, field_rev.id);
+
+  // The field is restored at its position with its cells
+  let field = test.editor.restore_field(&field_rev.id).await.unwrap();
+  assert_eq!(field.id, field_rev.id);
+  let field_revs = test.editor.get_field_revs(None).await.unwrap();
+  assert_eq!(field_revs[field_index].id, field_rev.id);
+  let row_revs = test.editor.get_all_row_revs(&test.view_id).await.unwrap();
+  assert_eq!(count_cells(&row_revs), cell_count);
+  assert!(test.editor.get_trashed_fields().await.is_empty());
+}
+
+#[tokio::test]
+async fn grid_restore_field_with_its_sorts_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let field_rev = test.get_first_field_rev(FieldType::URL).clone();
+  test
+    .editor
+    .create_or_update_sort(AlterSortParams {
+      view_id: test.view_id.clone(),
+      field_id: field_rev.id.clone(),
+      sort_id: None,
+      field_type: field_rev.ty,
+      condition: 0,
+    })
+    .await
+    .unwrap();
+
+  // The sort of the trashed field is kept in the view, but it's not applied
+  test.editor.delete_field(&field_rev.id).await.unwrap();
+  let sorts = test.editor.get_all_sorts(&test.view_id).await.unwrap();
+  assert!(sorts.is_empty());
+
+  test.editor.restore_field(&field_rev.id).await.unwrap();
+  let sorts = test.editor.get_all_sorts(&test.view_id).await.unwrap();
+  assert_eq!(sorts.len(), 1);
+  assert_eq!(sorts[0].field_id, field_rev.id);
+}
+
+#[tokio::test]
+async fn grid_purge_trashed_field_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let field_rev = test.get_first_field_rev(FieldType::URL).clone();
+  test.editor.delete_field(&field_rev.id).await.unwrap();
+
+  // The field that was deleted after the time is kept
+  let purged = test
+    .editor
+    .purge_trashed_fields(timestamp() - 60)
+    .await
+    .unwrap();
+  assert_eq!(purged, 0);
+
+  let purged = test
+    .editor
+    .purge_trashed_fields(timestamp() + 1)
+    .await
+    .unwrap();
+  assert_eq!(purged, 1);
+  let row_revs = test.editor.get_all_row_revs(&test.view_id).await.unwrap();
+  assert!(row_revs
+    .iter()
+    .all(|row_rev| !row_rev.cells.contains_key(&field_rev.id)));
+  assert!(test.editor.restore_field(&field_rev.id).await.is_err());
+}
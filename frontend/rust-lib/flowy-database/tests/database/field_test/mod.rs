@@ -1,3 +1,6 @@
+mod auto_number_test;
+mod field_trash_test;
+mod schema_test;
 mod script;
 mod test;
 pub mod util;
@@ -0,0 +1,41 @@
+use crate::database::database_editor::DatabaseEditorTest;
+use flowy_database::entities::FieldType;
+
+#[tokio::test]
+async fn database_schema_describes_all_fields_test() {
+  let test = DatabaseEditorTest::new_grid().await;
+  let (json_schema, openapi) = test
+    .editor
+    .get_database_schema(&test.view_id, true)
+    .await
+    .unwrap();
+
+  let properties = json_schema["properties"].as_object().unwrap();
+  assert_eq!(properties.len(), test.field_revs.len());
+  for field_rev in &test.field_revs {
+    let property = &properties[&field_rev.id];
+    assert_eq!(property["title"], field_rev.name.as_str());
+    assert_eq!(
+      property["x-appflowy-field-type"],
+      format!("{:?}", FieldType::from(field_rev.ty))
+    );
+  }
+
+  let single_select_field = test.get_first_field_rev(FieldType::SingleSelect);
+  assert_eq!(
+    properties[&single_select_field.id]["enum"]
+      .as_array()
+      .unwrap()
+      .len(),
+    test
+      .get_single_select_type_option(&single_select_field.id)
+      .options
+      .len()
+  );
+
+  let openapi = openapi.unwrap();
+  assert_eq!(
+    openapi["components"]["schemas"]["Row"]["properties"],
+    json_schema["properties"]
+  );
+}
@@ -1,12 +1,17 @@
 use crate::database::database_editor::DatabaseEditorTest;
 use database_model::FieldRevision;
-use flowy_database::entities::{CreateFieldParams, FieldChangesetParams, FieldType};
+use flowy_database::entities::{
+  CreateFieldParams, FieldChangesetParams, FieldDefinitionPB, FieldType,
+};
 use flowy_database::services::cell::{stringify_cell_data, TypeCellData};
 
 pub enum FieldScript {
   CreateField {
     params: CreateFieldParams,
   },
+  CreateFields {
+    fields: Vec<FieldDefinitionPB>,
+  },
   UpdateField {
     changeset: FieldChangesetParams,
   },
@@ -36,6 +41,14 @@ pub enum FieldScript {
     from_field_type: FieldType,
     expected_content: String,
   },
+  AssertSelectOptionUsage {
+    field_id: String,
+    unused_option_names: Vec<String>,
+    number_of_dangling_refs: usize,
+  },
+  CleanupSelectOptions {
+    field_id: String,
+  },
 }
 
 pub struct DatabaseFieldTest {
@@ -74,6 +87,12 @@ impl DatabaseFieldTest {
         self.field_revs = self.editor.get_field_revs(None).await.unwrap();
         assert_eq!(self.field_count, self.field_revs.len());
       },
+      FieldScript::CreateFields { fields } => {
+        self.field_count += fields.len();
+        self.editor.create_new_field_revs(fields).await.unwrap();
+        self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+        assert_eq!(self.field_count, self.field_revs.len());
+      },
       FieldScript::UpdateField { changeset: change } => {
         self.editor.update_field(change).await.unwrap();
         self.field_revs = self.editor.get_field_revs(None).await.unwrap();
@@ -159,6 +178,33 @@ impl DatabaseFieldTest {
         );
         assert_eq!(content, expected_content);
       },
+      FieldScript::AssertSelectOptionUsage {
+        field_id,
+        unused_option_names,
+        number_of_dangling_refs,
+      } => {
+        let usage = self
+          .editor
+          .get_select_option_usage(&field_id)
+          .await
+          .unwrap();
+        let names = usage
+          .unused_options
+          .into_iter()
+          .map(|option| option.name)
+          .collect::<Vec<String>>();
+        assert_eq!(names, unused_option_names);
+        assert_eq!(usage.dangling_refs.len(), number_of_dangling_refs);
+      },
+      FieldScript::CleanupSelectOptions { field_id } => {
+        let usage = self
+          .editor
+          .cleanup_select_options(&self.view_id, &field_id)
+          .await
+          .unwrap();
+        assert!(usage.is_clean());
+        self.field_revs = self.editor.get_field_revs(None).await.unwrap();
+      },
     }
   }
 }
@@ -1,9 +1,10 @@
 use crate::database::field_test::script::DatabaseFieldTest;
 use crate::database::field_test::script::FieldScript::*;
 use crate::database::field_test::util::*;
+use crate::database::mock_data::{COMPLETED, GOOGLE, PAUSED, PLANNED};
 use bytes::Bytes;
-use flowy_database::entities::{FieldChangesetParams, FieldType};
-use flowy_database::services::field::selection_type_option::SelectOptionPB;
+use flowy_database::entities::{FieldChangesetParams, FieldDefinitionPB, FieldType};
+use flowy_database::services::field::selection_type_option::{SelectOptionColorPB, SelectOptionPB};
 use flowy_database::services::field::{gen_option_id, SingleSelectTypeOptionPB, CHECK, UNCHECK};
 
 #[tokio::test]
@@ -353,3 +354,175 @@ async fn grid_switch_from_number_to_text_test() {
 
   test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_select_option_usage_test() {
+  let mut test = DatabaseFieldTest::new().await;
+  let field_rev = test.get_first_field_rev(FieldType::SingleSelect).clone();
+  let scripts = vec![AssertSelectOptionUsage {
+    field_id: field_rev.id.clone(),
+    unused_option_names: vec![PAUSED.to_owned()],
+    number_of_dangling_refs: 0,
+  }];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_cleanup_select_options_test() {
+  let mut test = DatabaseFieldTest::new().await;
+  let field_rev = test.get_first_field_rev(FieldType::SingleSelect).clone();
+
+  // Delete the option without removing it from the cells that reference it
+  let mut single_select_type_option = test.get_single_select_type_option(&field_rev.id);
+  single_select_type_option
+    .options
+    .retain(|option| option.name != COMPLETED);
+  let bytes: Bytes = single_select_type_option.try_into().unwrap();
+  let scripts = vec![
+    UpdateTypeOption {
+      field_id: field_rev.id.clone(),
+      type_option: bytes.to_vec(),
+    },
+    AssertSelectOptionUsage {
+      field_id: field_rev.id.clone(),
+      unused_option_names: vec![PAUSED.to_owned()],
+      number_of_dangling_refs: 2,
+    },
+    CleanupSelectOptions {
+      field_id: field_rev.id.clone(),
+    },
+  ];
+  test.run_scripts(scripts).await;
+
+  let options = test.get_single_select_type_option(&field_rev.id).options;
+  assert_eq!(options.len(), 1);
+  assert_eq!(options[0].name, PLANNED);
+}
+
+#[tokio::test]
+async fn grid_import_select_options_test() {
+  let test = DatabaseFieldTest::new().await;
+  let single_select_field = test.get_first_field_rev(FieldType::SingleSelect).clone();
+  let multi_select_field = test.get_first_field_rev(FieldType::MultiSelect).clone();
+  let old_options = test.get_multi_select_type_option(&multi_select_field.id);
+  let google = old_options
+    .iter()
+    .find(|option| option.name == GOOGLE)
+    .unwrap()
+    .clone();
+
+  // The options are merged by name, ignoring the case and the surrounding whitespaces
+  let mut imported_options = test
+    .editor
+    .get_select_options(&single_select_field.id)
+    .await
+    .unwrap();
+  imported_options.push(SelectOptionPB::with_color(
+    " google ",
+    SelectOptionColorPB::Blue,
+  ));
+  let options = test
+    .editor
+    .import_select_options(
+      &test.view_id,
+      &multi_select_field.id,
+      imported_options.clone(),
+    )
+    .await
+    .unwrap();
+
+  let mut expected_names = imported_options
+    .iter()
+    .take(3)
+    .map(|option| option.name.clone())
+    .collect::<Vec<String>>();
+  expected_names.push(GOOGLE.to_owned());
+  expected_names.extend(
+    old_options
+      .iter()
+      .filter(|option| option.name != GOOGLE)
+      .map(|option| option.name.clone()),
+  );
+  let names = options
+    .iter()
+    .map(|option| option.name.clone())
+    .collect::<Vec<String>>();
+  assert_eq!(names, expected_names);
+  assert_ne!(options[0].id, imported_options[0].id);
+  assert_eq!(options[3].id, google.id);
+  assert_eq!(options[3].color, SelectOptionColorPB::Blue);
+}
+
+#[tokio::test]
+async fn grid_move_select_option_test() {
+  let test = DatabaseFieldTest::new().await;
+  let field_rev = test.get_first_field_rev(FieldType::SingleSelect).clone();
+  let old_options = test.get_single_select_type_option(&field_rev.id).options;
+  let options = test
+    .editor
+    .move_select_option(
+      &test.view_id,
+      &field_rev.id,
+      &old_options[0].id,
+      &old_options[2].id,
+    )
+    .await
+    .unwrap();
+  let ids = options
+    .iter()
+    .map(|option| option.id.clone())
+    .collect::<Vec<String>>();
+  assert_eq!(
+    ids,
+    vec![
+      old_options[1].id.clone(),
+      old_options[2].id.clone(),
+      old_options[0].id.clone()
+    ]
+  );
+
+  let result = test
+    .editor
+    .move_select_option(&test.view_id, &field_rev.id, &old_options[0].id, "unknown")
+    .await;
+  assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn grid_create_fields_test() {
+  let mut test = DatabaseFieldTest::new().await;
+  let field_count = test.field_count();
+  let (params, single_select_field_rev) = create_single_select_field(&test.view_id());
+  let scripts = vec![
+    CreateFields {
+      fields: vec![
+        FieldDefinitionPB {
+          name: "Status".to_string(),
+          field_type: FieldType::SingleSelect,
+          type_option_data: params.type_option_data,
+        },
+        FieldDefinitionPB {
+          name: "".to_string(),
+          field_type: FieldType::Checkbox,
+          type_option_data: None,
+        },
+      ],
+    },
+    AssertFieldTypeOptionEqual {
+      field_index: field_count,
+      expected_type_option_data: single_select_field_rev
+        .get_type_option_str(single_select_field_rev.ty)
+        .unwrap()
+        .to_owned(),
+    },
+  ];
+  test.run_scripts(scripts).await;
+
+  assert_eq!(test.field_revs[field_count].name, "Status");
+  assert_eq!(
+    test.field_revs[field_count + 1].name,
+    format!("Property {}", field_count + 2)
+  );
+  let field_type: FieldType = test.field_revs[field_count + 1].ty.into();
+  assert_eq!(field_type, FieldType::Checkbox);
+}
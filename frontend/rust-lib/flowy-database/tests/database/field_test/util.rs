@@ -65,6 +65,10 @@ pub fn make_date_cell_string(s: &str) -> String {
     time: None,
     is_utc: true,
     include_time: Some(false),
+    text: None,
+    locale: None,
+    recurrence: None,
+    clear_recurrence: false,
   })
   .unwrap()
 }
@@ -35,3 +35,38 @@ async fn grid_filter_checkbox_is_uncheck_test() {
   ];
   test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_checkbox_row_diagnostics_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreateCheckboxFilter {
+      condition: CheckboxFilterConditionPB::IsUnChecked,
+      changed: None,
+    },
+    // The first row is checked and the third row is unchecked
+    AssertRowDiagnostics {
+      row_index: 0,
+      is_visible: false,
+    },
+    AssertRowDiagnostics {
+      row_index: 2,
+      is_visible: true,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_checkbox_recompute_view_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreateCheckboxFilter {
+      condition: CheckboxFilterConditionPB::IsUnChecked,
+      changed: None,
+    },
+    RecomputeView,
+    AssertNumberOfVisibleRows { expected: 3 },
+  ];
+  test.run_scripts(scripts).await;
+}
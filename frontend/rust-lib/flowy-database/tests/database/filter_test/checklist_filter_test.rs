@@ -1,6 +1,14 @@
 use crate::database::filter_test::script::FilterScript::*;
 use crate::database::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
-use flowy_database::entities::ChecklistFilterConditionPB;
+use flowy_database::entities::{
+  AlterFilterParams, AlterFilterPayloadPB, CellIdParams, ChecklistFilterConditionPB,
+  ChecklistFilterPB, FieldType,
+};
+use flowy_database::services::field::{
+  ChecklistCellChangeset, ChecklistItemDueChangeset, ChecklistItemPB,
+};
+use flowy_error::ErrorCode;
+use lib_infra::util::timestamp;
 
 #[tokio::test]
 async fn grid_filter_checklist_is_incomplete_test() {
@@ -37,3 +45,187 @@ async fn grid_filter_checklist_is_complete_test() {
   ];
   test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_checklist_has_overdue_items_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let field_rev = test.get_first_field_rev(FieldType::Checklist).clone();
+  let type_option = test.get_checklist_type_option(&field_rev.id);
+  let item_id = type_option.options[0].id.clone();
+  // The due date is set in each row, 2022-03-14 09:56:02 UTC
+  for row_rev in test.row_revs.clone() {
+    let changeset = ChecklistItemDueChangeset {
+      cell_id: CellIdParams {
+        view_id: test.view_id.clone(),
+        field_id: field_rev.id.clone(),
+        row_id: row_rev.id.clone(),
+      },
+      item_id: item_id.clone(),
+      due: Some(1647251762),
+    };
+    test.editor.set_checklist_item_due(changeset).await.unwrap();
+  }
+
+  // The rows that didn't check the item are overdue
+  let expected = 5;
+  let row_count = test.row_revs.len();
+  let scripts = vec![
+    CreateChecklistFilter {
+      condition: ChecklistFilterConditionPB::HasOverdueItems,
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected },
+  ];
+  test.run_scripts(scripts).await;
+
+  let reminders = test
+    .editor
+    .get_checklist_reminders(&test.view_id)
+    .await
+    .unwrap();
+  assert!(reminders.iter().all(|reminder| reminder.item_id == item_id));
+  assert_eq!(
+    reminders
+      .iter()
+      .filter(|reminder| !reminder.is_completed)
+      .count(),
+    expected
+  );
+}
+
+#[tokio::test]
+async fn grid_filter_checklist_item_becomes_overdue_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let field_rev = test.get_first_field_rev(FieldType::Checklist).clone();
+  let type_option = test.get_checklist_type_option(&field_rev.id);
+  let item_id = type_option.options[0].id.clone();
+  let due = timestamp() + 1;
+  for row_rev in test.row_revs.clone() {
+    let changeset = ChecklistItemDueChangeset {
+      cell_id: CellIdParams {
+        view_id: test.view_id.clone(),
+        field_id: field_rev.id.clone(),
+        row_id: row_rev.id.clone(),
+      },
+      item_id: item_id.clone(),
+      due: Some(due),
+    };
+    test.editor.set_checklist_item_due(changeset).await.unwrap();
+  }
+
+  // None of the items is overdue yet
+  let row_count = test.row_revs.len();
+  let scripts = vec![
+    CreateChecklistFilter {
+      condition: ChecklistFilterConditionPB::HasOverdueItems,
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected: 0 },
+    Wait { millisecond: 2000 },
+  ];
+  test.run_scripts(scripts).await;
+
+  // The rows that didn't check the item show up after its due date passes
+  for row_rev in test.row_revs.clone() {
+    test.editor.did_pass_due_date(&row_rev.id).await;
+  }
+  let scripts = vec![
+    Wait { millisecond: 100 },
+    AssertNumberOfVisibleRows { expected: 5 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_checklist_is_complete_above_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  // Check two of the three items of the second row
+  let field_rev = test.get_first_field_rev(FieldType::Checklist).clone();
+  let type_option = test.get_checklist_type_option(&field_rev.id);
+  let row_id = test.row_revs[1].id.clone();
+  let update_items = type_option.options[0..2]
+    .iter()
+    .map(|option| ChecklistItemPB {
+      id: option.id.clone(),
+      name: option.name.clone(),
+      is_checked: true,
+      due: None,
+    })
+    .collect();
+  let changeset = ChecklistCellChangeset {
+    update_items,
+    ..Default::default()
+  };
+  test.update_cell(&field_rev.id, row_id, changeset).await;
+
+  let expected = 2;
+  let row_count = test.row_revs.len();
+  let scripts = vec![
+    CreateChecklistPercentageFilter {
+      condition: ChecklistFilterConditionPB::IsCompleteAbove,
+      percentage: 50,
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_checklist_is_complete_below_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let expected = 5;
+  let row_count = test.row_revs.len();
+  let scripts = vec![
+    CreateChecklistPercentageFilter {
+      condition: ChecklistFilterConditionPB::IsCompleteBelow,
+      percentage: 100,
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_checklist_has_unchecked_items_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let expected = 5;
+  let row_count = test.row_revs.len();
+  let scripts = vec![
+    CreateChecklistFilter {
+      condition: ChecklistFilterConditionPB::HasUncheckedItems,
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_checklist_invalid_percentage_test() {
+  let test = DatabaseFilterTest::new().await;
+  let field_rev = test.get_first_field_rev(FieldType::Checklist);
+  let filter = ChecklistFilterPB {
+    condition: ChecklistFilterConditionPB::IsCompleteAbove,
+    percentage: 150,
+  };
+  let payload = AlterFilterPayloadPB::new(&test.view_id, field_rev, filter);
+  let result: Result<AlterFilterParams, ErrorCode> = payload.try_into();
+  assert_eq!(result.unwrap_err(), ErrorCode::InvalidData);
+}
@@ -0,0 +1,76 @@
+use crate::database::filter_test::script::FilterScript::*;
+use crate::database::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
+use flowy_database::entities::{EmptyFilterConditionPB, FieldType};
+
+async fn assert_empty_filter(
+  field_type: FieldType,
+  condition: EmptyFilterConditionPB,
+  expected: usize,
+) {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_revs.len();
+  let scripts = vec![
+    CreateEmptyFilter {
+      field_type,
+      condition,
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_checkbox_is_empty_test() {
+  // The unchecked cells are empty
+  assert_empty_filter(FieldType::Checkbox, EmptyFilterConditionPB::CellIsEmpty, 3).await;
+}
+
+#[tokio::test]
+async fn grid_filter_number_is_empty_test() {
+  assert_empty_filter(FieldType::Number, EmptyFilterConditionPB::CellIsEmpty, 1).await;
+}
+
+#[tokio::test]
+async fn grid_filter_date_is_not_empty_test() {
+  assert_empty_filter(
+    FieldType::DateTime,
+    EmptyFilterConditionPB::CellIsNotEmpty,
+    6,
+  )
+  .await;
+}
+
+#[tokio::test]
+async fn grid_filter_single_select_is_not_empty_test() {
+  assert_empty_filter(
+    FieldType::SingleSelect,
+    EmptyFilterConditionPB::CellIsNotEmpty,
+    4,
+  )
+  .await;
+}
+
+#[tokio::test]
+async fn grid_filter_url_is_empty_test() {
+  assert_empty_filter(FieldType::URL, EmptyFilterConditionPB::CellIsEmpty, 5).await;
+}
+
+#[tokio::test]
+async fn grid_filter_checklist_is_not_empty_test() {
+  // Only the first row has checked items
+  assert_empty_filter(
+    FieldType::Checklist,
+    EmptyFilterConditionPB::CellIsNotEmpty,
+    1,
+  )
+  .await;
+}
+
+#[tokio::test]
+async fn grid_filter_text_is_empty_test() {
+  assert_empty_filter(FieldType::RichText, EmptyFilterConditionPB::CellIsEmpty, 1).await;
+}
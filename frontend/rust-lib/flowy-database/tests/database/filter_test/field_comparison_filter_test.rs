@@ -0,0 +1,79 @@
+use crate::database::filter_test::script::DatabaseFilterTest;
+use crate::database::filter_test::script::FilterScript::*;
+use flowy_database::entities::{AlterFilterPayloadPB, FieldComparisonConditionPB, FieldType};
+use flowy_database::services::field::FieldBuilder;
+
+/// Creates the "Estimate" number field, its cells are 3 in every row. The cells of the first
+/// number field are 1, 2, 3, 4, empty and 5.
+async fn create_estimate_field(test: &DatabaseFilterTest) -> String {
+  let field_rev = FieldBuilder::from_field_type(&FieldType::Number)
+    .name("Estimate")
+    .build();
+  let field_id = field_rev.id.clone();
+  test.editor.create_new_field_rev(field_rev).await.unwrap();
+  for row_rev in test.row_revs.iter() {
+    test
+      .editor
+      .update_cell_with_changeset(&row_rev.id, &field_id, "3".to_owned())
+      .await
+      .unwrap();
+  }
+  field_id
+}
+
+#[tokio::test]
+async fn grid_filter_number_less_than_other_field_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let estimate_field_id = create_estimate_field(&test).await;
+  let field_rev = test.get_first_field_rev(FieldType::Number);
+  let payload = AlterFilterPayloadPB::new_field_comparison(
+    &test.view_id(),
+    field_rev,
+    FieldComparisonConditionPB::LessThan,
+    &estimate_field_id,
+  );
+  let scripts = vec![
+    InsertFilter { payload },
+    AssertFilterCount { count: 1 },
+    AssertNumberOfVisibleRows { expected: 2 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_number_greater_than_or_equal_to_other_field_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let estimate_field_id = create_estimate_field(&test).await;
+  let field_rev = test.get_first_field_rev(FieldType::Number);
+  let payload = AlterFilterPayloadPB::new_field_comparison(
+    &test.view_id(),
+    field_rev,
+    FieldComparisonConditionPB::GreaterThanOrEqualTo,
+    &estimate_field_id,
+  );
+  // The row whose number cell is empty is hidden
+  let scripts = vec![
+    InsertFilter { payload },
+    AssertNumberOfVisibleRows { expected: 3 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_compare_fields_of_different_types_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let field_rev = test.get_first_field_rev(FieldType::Number);
+  let text_field_id = test.get_first_field_rev(FieldType::RichText).id.clone();
+  let payload = AlterFilterPayloadPB::new_field_comparison(
+    &test.view_id(),
+    field_rev,
+    FieldComparisonConditionPB::Equal,
+    &text_field_id,
+  );
+  // The filter that can't be applied is not saved
+  let scripts = vec![
+    AssertInsertFilterError { payload },
+    AssertFilterCount { count: 0 },
+  ];
+  test.run_scripts(scripts).await;
+}
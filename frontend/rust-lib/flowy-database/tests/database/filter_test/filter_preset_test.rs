@@ -0,0 +1,66 @@
+use crate::database::filter_test::script::FilterScript::*;
+use crate::database::filter_test::script::*;
+use flowy_database::entities::{FieldType, TextFilterConditionPB};
+use flowy_database::services::filter::FilterType;
+
+#[tokio::test]
+async fn grid_filter_preset_apply_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreateTextFilter {
+      condition: TextFilterConditionPB::TextIsEmpty,
+      content: "".to_string(),
+      changed: None,
+    },
+    AssertNumberOfVisibleRows { expected: 1 },
+    SaveFilterPreset {
+      name: "Empty names".to_string(),
+    },
+    AssertFilterPresetCount { count: 1 },
+  ];
+  test.run_scripts(scripts).await;
+
+  let filter = test.database_filters().await.pop().unwrap();
+  let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+  test
+    .run_scripts(vec![
+      DeleteFilter {
+        filter_id: filter.id,
+        filter_type: FilterType::from(&field_rev),
+        changed: None,
+      },
+      AssertFilterCount { count: 0 },
+      AssertNumberOfVisibleRows { expected: 6 },
+      ApplyFilterPreset {
+        name: "Empty names".to_string(),
+      },
+      AssertFilterCount { count: 1 },
+      AssertNumberOfVisibleRows { expected: 1 },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn grid_filter_preset_save_with_same_name_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreateTextFilter {
+      condition: TextFilterConditionPB::TextIsEmpty,
+      content: "".to_string(),
+      changed: None,
+    },
+    SaveFilterPreset {
+      name: "Names".to_string(),
+    },
+    SaveFilterPreset {
+      name: "Names".to_string(),
+    },
+    AssertFilterPresetCount { count: 1 },
+    DeleteFilterPreset {
+      name: "Names".to_string(),
+    },
+    AssertFilterPresetCount { count: 0 },
+    AssertFilterCount { count: 1 },
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -1,7 +1,12 @@
 mod checkbox_filter_test;
 mod checklist_filter_test;
 mod date_filter_test;
+mod empty_filter_test;
+mod field_comparison_filter_test;
+mod filter_preset_test;
 mod number_filter_test;
+mod row_meta_filter_test;
 mod script;
 mod select_option_filter_test;
+mod session_filter_test;
 mod text_filter_test;
@@ -1,6 +1,7 @@
 use crate::database::filter_test::script::FilterScript::*;
 use crate::database::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
-use flowy_database::entities::NumberFilterConditionPB;
+use flowy_database::entities::{FieldType, NumberFilterConditionPB, TextFilterConditionPB};
+use flowy_database::services::filter::FilterType;
 
 #[tokio::test]
 async fn grid_filter_number_is_equal_test() {
@@ -116,3 +117,96 @@ async fn grid_filter_number_is_not_empty_test() {
   ];
   test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_number_is_between_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_revs.len();
+  let expected = 3;
+  let scripts = vec![
+    CreateNumberRangeFilter {
+      condition: NumberFilterConditionPB::NumberIsBetween,
+      start: "$2".to_string(),
+      end: "$4".to_string(),
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_number_is_not_between_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_revs.len();
+  let expected = 2;
+  let scripts = vec![
+    CreateNumberRangeFilter {
+      condition: NumberFilterConditionPB::NumberIsNotBetween,
+      start: "2".to_string(),
+      end: "4".to_string(),
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count - expected,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_number_deleted_with_other_filter_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreateNumberFilter {
+      condition: NumberFilterConditionPB::GreaterThan,
+      content: "2".to_string(),
+      changed: None,
+    },
+    Wait { millisecond: 100 },
+    AssertFilteredRowCount {
+      visible: 3,
+      hidden: 3,
+    },
+    // The rows hidden by the number filter are not evaluated by the text filter until the
+    // number filter is deleted
+    CreateTextFilter {
+      condition: TextFilterConditionPB::Contains,
+      content: "A".to_string(),
+      changed: None,
+    },
+    Wait { millisecond: 100 },
+    AssertFilteredRowCount {
+      visible: 2,
+      hidden: 4,
+    },
+  ];
+  test.run_scripts(scripts).await;
+
+  let field_rev = test.get_first_field_rev(FieldType::Number).clone();
+  let filter = test
+    .database_filters()
+    .await
+    .into_iter()
+    .find(|filter| filter.field_id == field_rev.id)
+    .unwrap();
+  test
+    .run_scripts(vec![
+      DeleteFilter {
+        filter_id: filter.id,
+        filter_type: FilterType::from(&field_rev),
+        changed: None,
+      },
+      Wait { millisecond: 100 },
+      AssertFilteredRowCount {
+        visible: 4,
+        hidden: 2,
+      },
+      AssertNumberOfVisibleRows { expected: 4 },
+    ])
+    .await;
+}
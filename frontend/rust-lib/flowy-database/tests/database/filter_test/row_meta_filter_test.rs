@@ -0,0 +1,143 @@
+use crate::database::filter_test::script::FilterScript::*;
+use crate::database::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
+use flowy_database::entities::{DateFilterConditionPB, FieldChangesetParams, FieldType};
+use flowy_database::services::filter::{ROW_CREATED_AT_FIELD_ID, ROW_MODIFIED_AT_FIELD_ID};
+use lib_infra::util::timestamp;
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+#[tokio::test]
+async fn grid_filter_rows_created_in_last_7_days_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_revs.len();
+  let now = timestamp();
+  let scripts = vec![
+    CreateRowMetaFilter {
+      field_id: ROW_CREATED_AT_FIELD_ID.to_owned(),
+      condition: DateFilterConditionPB::DateWithIn,
+      start: Some(now - 7 * SECONDS_PER_DAY),
+      end: Some(now),
+      timestamp: None,
+      changed: None,
+    },
+    AssertFilterCount { count: 1 },
+    AssertNumberOfVisibleRows {
+      expected: row_count,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_rows_created_after_today_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_revs.len();
+  let scripts = vec![
+    CreateRowMetaFilter {
+      field_id: ROW_CREATED_AT_FIELD_ID.to_owned(),
+      condition: DateFilterConditionPB::DateAfter,
+      start: None,
+      end: None,
+      timestamp: Some(timestamp()),
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected: 0 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_rows_modified_before_today_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_revs.len();
+  let scripts = vec![
+    CreateRowMetaFilter {
+      field_id: ROW_MODIFIED_AT_FIELD_ID.to_owned(),
+      condition: DateFilterConditionPB::DateBefore,
+      start: None,
+      end: None,
+      timestamp: Some(timestamp()),
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: row_count,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected: 0 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_rows_created_after_today_after_field_update_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_revs.len();
+  let scripts = vec![CreateRowMetaFilter {
+    field_id: ROW_CREATED_AT_FIELD_ID.to_owned(),
+    condition: DateFilterConditionPB::DateAfter,
+    start: None,
+    end: None,
+    timestamp: Some(timestamp()),
+    changed: Some(FilterRowChanged {
+      showing_num_of_rows: 0,
+      hiding_num_of_rows: row_count,
+    }),
+  }];
+  test.run_scripts(scripts).await;
+
+  // Updating a field refreshes the filters of the view, the filter of the row metadata must keep
+  // reading the timestamps of the rows
+  let field_id = test.get_first_field_rev(FieldType::DateTime).id.clone();
+  let params = FieldChangesetParams {
+    field_id,
+    view_id: test.view_id(),
+    name: Some("Due".to_owned()),
+    ..Default::default()
+  };
+  test.editor.update_field(params).await.unwrap();
+
+  let scripts = vec![
+    AssertFilterCount { count: 1 },
+    AssertNumberOfVisibleRows { expected: 0 },
+    RecomputeView,
+    AssertNumberOfVisibleRows { expected: 0 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_rows_modified_in_last_7_days_after_cell_update_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let row_count = test.row_revs.len();
+  let row_id = test.row_revs[0].id.clone();
+  let now = timestamp();
+  let scripts = vec![
+    CreateRowMetaFilter {
+      field_id: ROW_MODIFIED_AT_FIELD_ID.to_owned(),
+      condition: DateFilterConditionPB::DateWithIn,
+      start: Some(now - 7 * SECONDS_PER_DAY),
+      end: Some(now + SECONDS_PER_DAY),
+      timestamp: None,
+      changed: None,
+    },
+    AssertNumberOfVisibleRows {
+      expected: row_count,
+    },
+    // Refiltering the updated row reads its new modified time
+    UpdateTextCell {
+      row_id,
+      text: "A".to_owned(),
+      changed: None,
+    },
+    AssertNumberOfVisibleRows {
+      expected: row_count,
+    },
+    RecomputeView,
+    AssertNumberOfVisibleRows {
+      expected: row_count,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -7,13 +7,13 @@ use std::time::Duration;
 use bytes::Bytes;
 use futures::TryFutureExt;
 use tokio::sync::broadcast::Receiver;
-use flowy_database::entities::{AlterFilterParams, AlterFilterPayloadPB, DeleteFilterParams, LayoutTypePB, DatabaseSettingChangesetParams, DatabaseViewSettingPB, RowPB, TextFilterConditionPB, FieldType, NumberFilterConditionPB, CheckboxFilterConditionPB, DateFilterConditionPB, DateFilterContentPB, SelectOptionConditionPB, TextFilterPB, NumberFilterPB, CheckboxFilterPB, DateFilterPB, SelectOptionFilterPB, CellChangesetPB, FilterPB, ChecklistFilterConditionPB, ChecklistFilterPB};
+use flowy_database::entities::{AlterFilterParams, AlterFilterPayloadPB, DeleteFilterParams, LayoutTypePB, DatabaseSettingChangesetParams, DatabaseViewSettingPB, RowPB, TextFilterConditionPB, FieldType, NumberFilterConditionPB, CheckboxFilterConditionPB, DateFilterConditionPB, DateFilterContentPB, SelectOptionConditionPB, TextFilterPB, NumberFilterPB, CheckboxFilterPB, DateFilterPB, SelectOptionFilterPB, CellChangesetPB, FilterPB, ChecklistFilterConditionPB, ChecklistFilterPB, RecomputeStageTypePB, EmptyFilterConditionPB, SaveFilterPresetParams, FilterPresetIdParams, SessionFilterIdParams};
 use flowy_database::services::field::{SelectOptionCellChangeset, SelectOptionIds};
 use flowy_database::services::setting::GridSettingChangesetBuilder;
 use database_model::{FieldRevision, FieldTypeRevision};
 use flowy_sqlite::schema::view_table::dsl::view_table;
 use flowy_database::services::cell::insert_select_option_cell;
-use flowy_database::services::filter::FilterType;
+use flowy_database::services::filter::{get_row_meta_field_rev, FilterType};
 use flowy_database::services::database_view::DatabaseViewChanged;
 use crate::database::database_editor::DatabaseEditorTest;
 
@@ -36,11 +36,23 @@ pub enum FilterScript {
     InsertFilter {
         payload: AlterFilterPayloadPB,
     },
+    AssertInsertFilterError {
+        payload: AlterFilterPayloadPB,
+    },
     CreateTextFilter {
         condition: TextFilterConditionPB,
         content: String,
         changed: Option<FilterRowChanged>,
     },
+    CreateCaseSensitiveTextFilter {
+        condition: TextFilterConditionPB,
+        content: String,
+        changed: Option<FilterRowChanged>,
+    },
+    AssertCreateTextFilterError {
+        condition: TextFilterConditionPB,
+        content: String,
+    },
     UpdateTextFilter {
         filter: FilterPB,
         condition: TextFilterConditionPB,
@@ -52,6 +64,12 @@ pub enum FilterScript {
         content: String,
         changed: Option<FilterRowChanged>,
     },
+    CreateNumberRangeFilter {
+        condition: NumberFilterConditionPB,
+        start: String,
+        end: String,
+        changed: Option<FilterRowChanged>,
+    },
     CreateCheckboxFilter {
         condition: CheckboxFilterConditionPB,
         changed: Option<FilterRowChanged>,
@@ -63,6 +81,14 @@ pub enum FilterScript {
         timestamp: Option<i64>,
         changed: Option<FilterRowChanged>,
     },
+    CreateRowMetaFilter {
+        field_id: String,
+        condition: DateFilterConditionPB,
+        start: Option<i64>,
+        end: Option<i64>,
+        timestamp: Option<i64>,
+        changed: Option<FilterRowChanged>,
+    },
     CreateMultiSelectFilter {
         condition: SelectOptionConditionPB,
         option_ids: Vec<String>,
@@ -76,9 +102,30 @@ pub enum FilterScript {
         condition: ChecklistFilterConditionPB,
         changed: Option<FilterRowChanged>,
     },
+    CreateChecklistPercentageFilter {
+        condition: ChecklistFilterConditionPB,
+        percentage: i64,
+        changed: Option<FilterRowChanged>,
+    },
+    CreateEmptyFilter {
+        field_type: FieldType,
+        condition: EmptyFilterConditionPB,
+        changed: Option<FilterRowChanged>,
+    },
+    CreateSessionTextFilter {
+        condition: TextFilterConditionPB,
+        content: String,
+        changed: Option<FilterRowChanged>,
+    },
+    SaveSessionFilter {
+        filter_id: String,
+    },
     AssertFilterCount {
         count: i32,
     },
+    AssertSessionFilterCount {
+        count: usize,
+    },
     DeleteFilter {
         filter_id: String,
         filter_type: FilterType,
@@ -92,6 +139,27 @@ pub enum FilterScript {
     AssertNumberOfVisibleRows {
         expected: usize,
     },
+    AssertFilteredRowCount {
+        visible: i32,
+        hidden: i32,
+    },
+    AssertRowDiagnostics {
+        row_index: usize,
+        is_visible: bool,
+    },
+    RecomputeView,
+    SaveFilterPreset {
+        name: String,
+    },
+    ApplyFilterPreset {
+        name: String,
+    },
+    DeleteFilterPreset {
+        name: String,
+    },
+    AssertFilterPresetCount {
+        count: usize,
+    },
     #[allow(dead_code)]
     AssertGridSetting {
         expected_setting: DatabaseViewSettingPB,
@@ -121,6 +189,11 @@ impl DatabaseFilterTest {
         self.editor.get_all_filters(&self.view_id).await.unwrap()
     }
 
+    async fn get_filter_preset_id(&self, name: &str) -> String {
+        let presets = self.editor.get_filter_presets(&self.view_id).await.unwrap();
+        presets.items.into_iter().find(|preset| preset.name == name).unwrap().id
+    }
+
     pub async fn run_scripts(&mut self, scripts: Vec<FilterScript>) {
         for script in scripts {
             self.run_script(script).await;
@@ -143,13 +216,18 @@ impl DatabaseFilterTest {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
                 self.insert_filter(payload).await;
             }
+            FilterScript::AssertInsertFilterError { payload } => {
+                let params: AlterFilterParams = payload.try_into().unwrap();
+                assert!(self.editor.create_or_update_filter(params).await.is_err());
+            }
             FilterScript::CreateTextFilter { condition, content, changed} => {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
                 self.assert_future_changed(changed).await;
                 let field_rev = self.get_first_field_rev(FieldType::RichText);
                 let text_filter= TextFilterPB {
                     condition,
-                    content
+                    content,
+                    case_sensitive: false,
                 };
                 let payload =
                     AlterFilterPayloadPB::new(
@@ -157,6 +235,38 @@ impl DatabaseFilterTest {
                         field_rev, text_filter);
                 self.insert_filter(payload).await;
             }
+            FilterScript::CreateSessionTextFilter { condition, content, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::RichText);
+                let text_filter= TextFilterPB {
+                    condition,
+                    content,
+                    case_sensitive: false,
+                };
+                let payload = AlterFilterPayloadPB::new(&self.view_id(), field_rev, text_filter);
+                let params: AlterFilterParams = payload.try_into().unwrap();
+                self.editor.insert_session_filter(params).await.unwrap();
+            }
+            FilterScript::SaveSessionFilter { filter_id } => {
+                let params = SessionFilterIdParams { view_id: self.view_id(), filter_id };
+                self.editor.save_session_filter(params).await.unwrap();
+            }
+            FilterScript::CreateCaseSensitiveTextFilter { condition, content, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::RichText);
+                let text_filter = TextFilterPB { condition, content, case_sensitive: true };
+                let payload = AlterFilterPayloadPB::new(&self.view_id(), field_rev, text_filter);
+                self.insert_filter(payload).await;
+            }
+            FilterScript::AssertCreateTextFilterError { condition, content } => {
+                let field_rev = self.get_first_field_rev(FieldType::RichText);
+                let text_filter = TextFilterPB { condition, content, case_sensitive: false };
+                let payload = AlterFilterPayloadPB::new(&self.view_id(), field_rev, text_filter);
+                let params: AlterFilterParams = payload.try_into().unwrap();
+                assert!(self.editor.create_or_update_filter(params).await.is_err());
+            }
             FilterScript::UpdateTextFilter { filter, condition, content, changed} => {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
                 self.assert_future_changed(changed).await;
@@ -166,7 +276,9 @@ impl DatabaseFilterTest {
                     filter_id: Some(filter.id),
                     field_type: filter.field_type.into(),
                     condition: condition as u8,
-                    content
+                    content,
+                    case_sensitive: false,
+                    other_field_id: None,
                 };
                 self.editor.create_or_update_filter(params).await.unwrap();
             }
@@ -176,7 +288,23 @@ impl DatabaseFilterTest {
                 let field_rev = self.get_first_field_rev(FieldType::Number);
                 let number_filter = NumberFilterPB {
                     condition,
-                    content
+                    content,
+                    end_content: None,
+                };
+                let payload =
+                    AlterFilterPayloadPB::new(
+                         &self.view_id(),
+                        field_rev, number_filter);
+                self.insert_filter(payload).await;
+            }
+            FilterScript::CreateNumberRangeFilter {condition, start, end, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::Number);
+                let number_filter = NumberFilterPB {
+                    condition,
+                    content: start,
+                    end_content: Some(end),
                 };
                 let payload =
                     AlterFilterPayloadPB::new(
@@ -184,6 +312,14 @@ impl DatabaseFilterTest {
                         field_rev, number_filter);
                 self.insert_filter(payload).await;
             }
+            FilterScript::CreateEmptyFilter { field_type, condition, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(field_type);
+                let payload =
+                    AlterFilterPayloadPB::new_empty_filter(&self.view_id(), field_rev, condition);
+                self.insert_filter(payload).await;
+            }
             FilterScript::CreateCheckboxFilter {condition, changed} => {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
                 self.assert_future_changed(changed).await;
@@ -203,13 +339,32 @@ impl DatabaseFilterTest {
                     condition,
                     start,
                     end,
-                    timestamp
+                    timestamp,
+                    days: None,
+                    first_day_of_week: None,
                 };
 
                 let payload =
                     AlterFilterPayloadPB::new( &self.view_id(), field_rev, date_filter);
                 self.insert_filter(payload).await;
             }
+            FilterScript::CreateRowMetaFilter { field_id, condition, start, end, timestamp, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = get_row_meta_field_rev(&field_id).unwrap();
+                let date_filter = DateFilterPB {
+                    condition,
+                    start,
+                    end,
+                    timestamp,
+                    days: None,
+                    first_day_of_week: None,
+                };
+
+                let payload =
+                    AlterFilterPayloadPB::new( &self.view_id(), &field_rev, date_filter);
+                self.insert_filter(payload).await;
+            }
             FilterScript::CreateMultiSelectFilter { condition, option_ids} => {
                 self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
                 let field_rev = self.get_first_field_rev(FieldType::MultiSelect);
@@ -232,15 +387,27 @@ impl DatabaseFilterTest {
                 self.assert_future_changed(changed).await;
                 let field_rev = self.get_first_field_rev(FieldType::Checklist);
                 // let type_option = self.get_checklist_type_option(&field_rev.id);
-                let filter = ChecklistFilterPB { condition };
+                let filter = ChecklistFilterPB { condition, percentage: 0 };
                 let payload =
                     AlterFilterPayloadPB::new(& self.view_id(),field_rev, filter);
                 self.insert_filter(payload).await;
             }
+            FilterScript::CreateChecklistPercentageFilter { condition, percentage, changed} => {
+                self.recv = Some(self.editor.subscribe_view_changed(&self.view_id()).await.unwrap());
+                self.assert_future_changed(changed).await;
+                let field_rev = self.get_first_field_rev(FieldType::Checklist);
+                let filter = ChecklistFilterPB { condition, percentage };
+                let payload = AlterFilterPayloadPB::new(&self.view_id(), field_rev, filter);
+                self.insert_filter(payload).await;
+            }
             FilterScript::AssertFilterCount { count } => {
                 let filters = self.editor.get_all_filters(&self.view_id).await.unwrap();
                 assert_eq!(count as usize, filters.len());
             }
+            FilterScript::AssertSessionFilterCount { count } => {
+                let filters = self.editor.get_all_filters(&self.view_id).await.unwrap();
+                assert_eq!(count, filters.iter().filter(|filter| filter.is_session).count());
+            }
             FilterScript::AssertFilterContent { filter_type: filter_id, condition, content} => {
                 let filter = self.editor.get_filters(&self.view_id, filter_id).await.unwrap().pop().unwrap();
                 assert_eq!(&filter.content, &content);
@@ -261,6 +428,48 @@ impl DatabaseFilterTest {
                 let grid = self.editor.get_database(&self.view_id()).await.unwrap();
                 assert_eq!(grid.rows.len(), expected);
             }
+            FilterScript::AssertFilteredRowCount { visible, hidden } => {
+                let row_count = self.editor.get_filtered_row_count(&self.view_id).await.unwrap();
+                assert_eq!(row_count.visible_row_count, visible);
+                assert_eq!(row_count.hidden_row_count, hidden);
+            }
+            FilterScript::AssertRowDiagnostics { row_index, is_visible } => {
+                let row_id = self.row_revs[row_index].id.clone();
+                let diagnostics = self.editor.get_row_diagnostics(&self.view_id(), &row_id).await.unwrap();
+                assert_eq!(diagnostics.is_visible, is_visible);
+                assert_eq!(diagnostics.index.is_some(), is_visible);
+                assert!(diagnostics.filters.iter().any(|filter| !filter.is_visible) != is_visible);
+            }
+            FilterScript::RecomputeView => {
+                let result = self.sdk.database_manager.recompute_view(&self.view_id()).await.unwrap();
+                let stages = result.stages.iter().map(|stage| stage.stage).collect::<Vec<_>>();
+                assert_eq!(stages, vec![
+                    RecomputeStageTypePB::Calculation,
+                    RecomputeStageTypePB::Filter,
+                    RecomputeStageTypePB::Sort,
+                    RecomputeStageTypePB::Group,
+                ]);
+                assert!(result.stages.iter().map(|stage| stage.elapsed_micros).sum::<i64>() <= result.elapsed_micros);
+            }
+            FilterScript::SaveFilterPreset { name } => {
+                let params = SaveFilterPresetParams { view_id: self.view_id(), name: name.clone() };
+                let preset = self.editor.save_filter_preset(params).await.unwrap();
+                assert_eq!(preset.name, name);
+            }
+            FilterScript::ApplyFilterPreset { name } => {
+                let preset_id = self.get_filter_preset_id(&name).await;
+                let params = FilterPresetIdParams { view_id: self.view_id(), preset_id };
+                self.editor.apply_filter_preset(params).await.unwrap();
+            }
+            FilterScript::DeleteFilterPreset { name } => {
+                let preset_id = self.get_filter_preset_id(&name).await;
+                let params = FilterPresetIdParams { view_id: self.view_id(), preset_id };
+                self.editor.delete_filter_preset(params).await.unwrap();
+            }
+            FilterScript::AssertFilterPresetCount { count } => {
+                let presets = self.editor.get_filter_presets(&self.view_id).await.unwrap();
+                assert_eq!(presets.items.len(), count);
+            }
             FilterScript::Wait { millisecond } => {
                 tokio::time::sleep(Duration::from_millis(millisecond)).await;
             }
@@ -1,6 +1,10 @@
 use crate::database::filter_test::script::FilterScript::*;
 use crate::database::filter_test::script::{DatabaseFilterTest, FilterRowChanged};
 use flowy_database::entities::{FieldType, SelectOptionConditionPB};
+use flowy_database::services::field::{
+  MultiSelectTypeOptionPB, SelectOptionIds, SelectOptionPB, SelectTypeOptionSharedAction,
+};
+use flowy_database::services::filter::FilterType;
 
 #[tokio::test]
 async fn grid_filter_multi_select_is_empty_test() {
@@ -135,3 +139,59 @@ async fn grid_filter_single_select_is_test2() {
   ];
   test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_multi_select_option_deleted_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let field_rev = test.get_first_field_rev(FieldType::MultiSelect).clone();
+  let options = test.get_multi_select_type_option(&field_rev.id);
+  let scripts = vec![
+    CreateMultiSelectFilter {
+      condition: SelectOptionConditionPB::OptionIs,
+      option_ids: vec![options[0].id.clone(), options[1].id.clone()],
+    },
+    AssertNumberOfVisibleRows { expected: 3 },
+  ];
+  test.run_scripts(scripts).await;
+
+  // The deleted option is removed from the filter
+  delete_multi_select_option(&test, &field_rev.id, options[0].clone()).await;
+  test
+    .run_scripts(vec![
+      AssertFilterCount { count: 1 },
+      AssertFilterContent {
+        filter_type: FilterType::from(&field_rev),
+        condition: SelectOptionConditionPB::OptionIs as u32,
+        content: SelectOptionIds::from(vec![options[1].id.clone()]).to_string(),
+      },
+    ])
+    .await;
+
+  // The filter is deleted after all of its options were deleted
+  delete_multi_select_option(&test, &field_rev.id, options[1].clone()).await;
+  test
+    .run_scripts(vec![
+      AssertFilterCount { count: 0 },
+      AssertNumberOfVisibleRows { expected: 6 },
+    ])
+    .await;
+}
+
+async fn delete_multi_select_option(
+  test: &DatabaseFilterTest,
+  field_id: &str,
+  option: SelectOptionPB,
+) {
+  test
+    .editor
+    .modify_field_rev(&test.view_id, field_id, |field_rev| {
+      let mut type_option = field_rev
+        .get_type_option::<MultiSelectTypeOptionPB>(FieldType::MultiSelect.into())
+        .unwrap();
+      type_option.delete_option(option);
+      field_rev.insert_type_option(&type_option);
+      Ok(Some(()))
+    })
+    .await
+    .unwrap();
+}
@@ -0,0 +1,90 @@
+use crate::database::filter_test::script::FilterScript::*;
+use crate::database::filter_test::script::*;
+use flowy_database::entities::{FieldType, TextFilterConditionPB};
+use flowy_database::services::filter::FilterType;
+
+#[tokio::test]
+async fn grid_session_filter_is_not_saved_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreateSessionTextFilter {
+      condition: TextFilterConditionPB::TextIsEmpty,
+      content: "".to_string(),
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: 5,
+      }),
+    },
+    AssertFilterCount { count: 1 },
+    AssertSessionFilterCount { count: 1 },
+    AssertNumberOfVisibleRows { expected: 1 },
+  ];
+  test.run_scripts(scripts).await;
+
+  let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+  let filter_type = FilterType::from(&field_rev);
+  let saved_filters = test
+    .editor
+    .get_filters(&test.view_id(), filter_type)
+    .await
+    .unwrap();
+  assert!(saved_filters.is_empty());
+}
+
+#[tokio::test]
+async fn grid_session_filter_save_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreateSessionTextFilter {
+      condition: TextFilterConditionPB::TextIsEmpty,
+      content: "".to_string(),
+      changed: None,
+    },
+    AssertNumberOfVisibleRows { expected: 1 },
+  ];
+  test.run_scripts(scripts).await;
+
+  let filter = test.database_filters().await.pop().unwrap();
+  test
+    .run_scripts(vec![
+      SaveSessionFilter {
+        filter_id: filter.id.clone(),
+      },
+      AssertFilterCount { count: 1 },
+      AssertSessionFilterCount { count: 0 },
+      AssertNumberOfVisibleRows { expected: 1 },
+    ])
+    .await;
+
+  let saved_filter = test.database_filters().await.pop().unwrap();
+  assert_eq!(saved_filter.id, filter.id);
+  assert!(!saved_filter.is_session);
+}
+
+#[tokio::test]
+async fn grid_session_filter_delete_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreateSessionTextFilter {
+      condition: TextFilterConditionPB::TextIsEmpty,
+      content: "".to_string(),
+      changed: None,
+    },
+    AssertNumberOfVisibleRows { expected: 1 },
+  ];
+  test.run_scripts(scripts).await;
+
+  let filter = test.database_filters().await.pop().unwrap();
+  let field_rev = test.get_first_field_rev(FieldType::RichText).clone();
+  test
+    .run_scripts(vec![
+      DeleteFilter {
+        filter_id: filter.id,
+        filter_type: FilterType::from(&field_rev),
+        changed: None,
+      },
+      AssertFilterCount { count: 0 },
+      AssertNumberOfVisibleRows { expected: 6 },
+    ])
+    .await;
+}
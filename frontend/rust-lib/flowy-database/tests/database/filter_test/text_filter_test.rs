@@ -1,7 +1,7 @@
 use crate::database::filter_test::script::FilterScript::*;
 use crate::database::filter_test::script::*;
 use flowy_database::entities::{
-  AlterFilterPayloadPB, FieldType, TextFilterConditionPB, TextFilterPB,
+  AlterFilterPayloadPB, CreateRowParams, FieldType, TextFilterConditionPB, TextFilterPB,
 };
 use flowy_database::services::filter::FilterType;
 
@@ -22,6 +22,91 @@ async fn grid_filter_text_is_empty_test() {
   test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn grid_filter_text_is_empty_row_count_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    AssertFilteredRowCount {
+      visible: 6,
+      hidden: 0,
+    },
+    CreateTextFilter {
+      condition: TextFilterConditionPB::TextIsEmpty,
+      content: "".to_string(),
+      changed: None,
+    },
+    AssertNumberOfVisibleRows { expected: 1 },
+    AssertFilteredRowCount {
+      visible: 1,
+      hidden: 5,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_text_row_count_after_row_changes_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreateTextFilter {
+      condition: TextFilterConditionPB::TextIsEmpty,
+      content: "".to_string(),
+      changed: None,
+    },
+    AssertFilteredRowCount {
+      visible: 1,
+      hidden: 5,
+    },
+  ];
+  test.run_scripts(scripts).await;
+
+  // The counts are kept up to date when the rows are deleted, inserted and filtered again
+  let visible_row_id = test
+    .editor
+    .get_database(&test.view_id())
+    .await
+    .unwrap()
+    .rows
+    .pop()
+    .unwrap()
+    .id;
+  let hidden_row_id = test
+    .row_revs
+    .iter()
+    .find(|row_rev| row_rev.id != visible_row_id)
+    .unwrap()
+    .id
+    .clone();
+  test.editor.delete_row(&hidden_row_id).await.unwrap();
+  test.editor.delete_row(&visible_row_id).await.unwrap();
+  let params = CreateRowParams {
+    view_id: test.view_id(),
+    start_row_id: None,
+    group_id: None,
+    swimlane_id: None,
+    cell_data_by_field_id: None,
+  };
+  let row_id = test.editor.create_row(params).await.unwrap().id;
+  let scripts = vec![
+    Wait { millisecond: 100 },
+    AssertFilteredRowCount {
+      visible: 1,
+      hidden: 4,
+    },
+    UpdateTextCell {
+      row_id,
+      text: "A".to_string(),
+      changed: None,
+    },
+    Wait { millisecond: 100 },
+    AssertFilteredRowCount {
+      visible: 0,
+      hidden: 5,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
 #[tokio::test]
 async fn grid_filter_text_is_not_empty_test() {
   let mut test = DatabaseFilterTest::new().await;
@@ -85,6 +170,38 @@ async fn grid_filter_contain_text_test() {
   test.run_scripts(scripts).await;
 }
 
+#[tokio::test]
+async fn grid_filter_contain_text_case_sensitive_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  // None of the rows contains the lowercase "a"
+  let scripts = vec![CreateCaseSensitiveTextFilter {
+    condition: TextFilterConditionPB::Contains,
+    content: "a".to_string(),
+    changed: Some(FilterRowChanged {
+      showing_num_of_rows: 0,
+      hiding_num_of_rows: 6,
+    }),
+  }];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_is_text_case_sensitive_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  let scripts = vec![
+    CreateCaseSensitiveTextFilter {
+      condition: TextFilterConditionPB::Is,
+      content: "AE".to_string(),
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: 4,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected: 2 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
 #[tokio::test]
 async fn grid_filter_contain_text_test2() {
   let mut test = DatabaseFilterTest::new().await;
@@ -195,6 +312,7 @@ async fn grid_filter_delete_test() {
   let text_filter = TextFilterPB {
     condition: TextFilterConditionPB::TextIsEmpty,
     content: "".to_string(),
+    case_sensitive: false,
   };
   let payload = AlterFilterPayloadPB::new(&test.view_id(), &field_rev, text_filter);
   let scripts = vec![
@@ -243,3 +361,55 @@ async fn grid_filter_update_empty_text_cell_test() {
   ];
   test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn grid_filter_text_matches_regex_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  // The patterns are case insensitive, the rows whose text is "A", "AE" and "AE" are visible
+  let scripts = vec![
+    CreateTextFilter {
+      condition: TextFilterConditionPB::MatchesRegex,
+      content: "^a".to_string(),
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: 3,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected: 3 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_text_matches_wildcard_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  // Only the row whose text is "DA" matches the whole pattern
+  let scripts = vec![
+    CreateTextFilter {
+      condition: TextFilterConditionPB::MatchesWildcard,
+      content: "?a".to_string(),
+      changed: Some(FilterRowChanged {
+        showing_num_of_rows: 0,
+        hiding_num_of_rows: 5,
+      }),
+    },
+    AssertNumberOfVisibleRows { expected: 1 },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_filter_text_invalid_regex_test() {
+  let mut test = DatabaseFilterTest::new().await;
+  // The filter with an invalid pattern is not saved
+  let scripts = vec![
+    AssertCreateTextFilterError {
+      condition: TextFilterConditionPB::MatchesRegex,
+      content: "(a".to_string(),
+    },
+    AssertFilterCount { count: 0 },
+    Wait { millisecond: 100 },
+    AssertNumberOfVisibleRows { expected: 6 },
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -0,0 +1,154 @@
+use crate::database::database_editor::DatabaseEditorTest;
+use chrono::Utc;
+use database_model::{AutomationActionRevision, AutomationTriggerRevision};
+use flowy_database::entities::{FieldType, MoveGroupRowParams, SaveAutomationParams};
+use flowy_database::services::field::DateCellChangeset;
+
+#[tokio::test]
+async fn group_automation_move_row_when_date_arrives_test() {
+  let test = DatabaseEditorTest::new_board().await;
+  let date_field = test.get_first_field_rev(FieldType::DateTime).clone();
+  let groups = test.editor.load_groups(&test.view_id).await.unwrap().items;
+  let row_id = groups[1].rows[0].id.clone();
+  let to_group_id = groups[3].group_id.clone();
+
+  let automation = test
+    .editor
+    .save_automation(SaveAutomationParams {
+      view_id: test.view_id.clone(),
+      automation_id: None,
+      name: "Start the due rows".to_owned(),
+      trigger: AutomationTriggerRevision::DateArrived {
+        field_id: date_field.id.clone(),
+        days_before: 0,
+      },
+      action: AutomationActionRevision::MoveToGroup {
+        group_id: to_group_id.clone(),
+      },
+      is_enabled: true,
+    })
+    .await
+    .unwrap();
+  let automations = test.editor.get_automations(&test.view_id).await.unwrap();
+  assert_eq!(automations.items.len(), 1);
+
+  // The dates of the mock rows are in the past, so only the row that is due today is moved.
+  // The automations are scanned at the same time as the date, so the test doesn't depend on
+  // when the background scan runs or on the day changing in between.
+  let now = Utc::now().timestamp();
+  let changeset = DateCellChangeset {
+    date: Some(now.to_string()),
+    time: None,
+    include_time: None,
+    is_utc: true,
+    text: None,
+    locale: None,
+    recurrence: None,
+    clear_recurrence: false,
+  };
+  test
+    .editor
+    .update_cell(row_id.clone(), date_field.id.clone(), changeset)
+    .await
+    .unwrap();
+  test
+    .sdk
+    .database_manager
+    .scan_automations_at(now)
+    .await
+    .unwrap();
+
+  let groups = test.editor.load_groups(&test.view_id).await.unwrap().items;
+  assert_eq!(groups[1].rows.len(), 1);
+  assert_eq!(groups[3].rows.len(), 2);
+  assert!(groups[3].rows.iter().any(|row| row.id == row_id));
+
+  let activities = test
+    .sdk
+    .database_manager
+    .get_automation_activities(&test.view_id, 10)
+    .unwrap();
+  assert_eq!(activities.len(), 1);
+  assert_eq!(activities[0].automation_id, automation.id);
+  assert_eq!(activities[0].row_id, row_id);
+  assert!(activities[0].error.is_empty());
+}
+
+#[tokio::test]
+async fn group_automation_fire_once_for_date_test() {
+  let test = DatabaseEditorTest::new_board().await;
+  let date_field = test.get_first_field_rev(FieldType::DateTime).clone();
+  let groups = test.editor.load_groups(&test.view_id).await.unwrap().items;
+  let row_id = groups[1].rows[0].id.clone();
+  let from_group_id = groups[1].group_id.clone();
+  let to_group_id = groups[3].group_id.clone();
+
+  test
+    .sdk
+    .database_manager
+    .save_automation(SaveAutomationParams {
+      view_id: test.view_id.clone(),
+      automation_id: None,
+      name: "Start the due rows".to_owned(),
+      trigger: AutomationTriggerRevision::DateArrived {
+        field_id: date_field.id.clone(),
+        days_before: 0,
+      },
+      action: AutomationActionRevision::MoveToGroup {
+        group_id: to_group_id.clone(),
+      },
+      is_enabled: true,
+    })
+    .await
+    .unwrap();
+  let now = Utc::now().timestamp();
+  let changeset = DateCellChangeset {
+    date: Some(now.to_string()),
+    time: None,
+    include_time: None,
+    is_utc: true,
+    text: None,
+    locale: None,
+    recurrence: None,
+    clear_recurrence: false,
+  };
+  test
+    .editor
+    .update_cell(row_id.clone(), date_field.id.clone(), changeset)
+    .await
+    .unwrap();
+  test
+    .sdk
+    .database_manager
+    .scan_automations_at(now)
+    .await
+    .unwrap();
+
+  // Moving the row back updates the row, the automation doesn't fire again for the same date
+  test
+    .editor
+    .move_group_row(MoveGroupRowParams {
+      view_id: test.view_id.clone(),
+      from_row_id: row_id.clone(),
+      to_group_id: from_group_id,
+      to_row_id: None,
+      to_swimlane_id: None,
+    })
+    .await
+    .unwrap();
+  test
+    .sdk
+    .database_manager
+    .scan_automations_at(now)
+    .await
+    .unwrap();
+
+  let groups = test.editor.load_groups(&test.view_id).await.unwrap().items;
+  assert!(groups[1].rows.iter().any(|row| row.id == row_id));
+  let activities = test
+    .sdk
+    .database_manager
+    .get_automation_activities(&test.view_id, 10)
+    .unwrap();
+  assert_eq!(activities.len(), 1);
+}
@@ -0,0 +1,41 @@
+use crate::database::database_editor::DatabaseEditorTest;
+use flowy_database::services::group::BoardLayout;
+use std::collections::HashMap;
+
+#[tokio::test]
+async fn board_layout_matches_groups_test() {
+  let test = DatabaseEditorTest::new_board().await;
+  let groups = test.editor.load_groups(&test.view_id).await.unwrap().items;
+  let layout = test
+    .editor
+    .get_board_layout(&test.view_id, &HashMap::new())
+    .await
+    .unwrap();
+
+  // The exported JSON can be read back by the rendering tools
+  let json = serde_json::to_string(&layout).unwrap();
+  let layout: BoardLayout = serde_json::from_str(&json).unwrap();
+
+  let visible_groups = groups
+    .iter()
+    .filter(|group| group.is_visible)
+    .collect::<Vec<_>>();
+  assert_eq!(layout.groups.len(), visible_groups.len());
+  for (layout_group, group) in layout.groups.iter().zip(visible_groups) {
+    assert_eq!(layout_group.id, group.group_id);
+    assert_eq!(layout_group.is_default, group.is_default);
+    // The board is grouped by the single select field, so the groups have the option colors
+    assert_eq!(layout_group.color.is_some(), !group.is_default);
+    let row_ids = layout_group
+      .cards
+      .iter()
+      .map(|card| card.row_id.as_str())
+      .collect::<Vec<_>>();
+    let group_row_ids = group
+      .rows
+      .iter()
+      .map(|row| row.id.as_str())
+      .collect::<Vec<_>>();
+    assert_eq!(row_ids, group_row_ids);
+  }
+}
@@ -0,0 +1,110 @@
+use crate::database::group_test::script::DatabaseGroupTest;
+use crate::database::group_test::script::GroupScript::*;
+use bytes::Bytes;
+use flowy_database::entities::FieldType;
+use flowy_database::services::field::DateTypeOptionPB;
+use flowy_database::services::setting::WeekSettings;
+
+#[tokio::test]
+async fn group_by_date_week_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let date_field = test.get_first_field_rev(FieldType::DateTime).clone();
+  // The weeks of the dates are computed in UTC, whatever the timezone of the device
+  let mut type_option = date_field
+    .get_type_option::<DateTypeOptionPB>(date_field.ty)
+    .unwrap();
+  type_option.timezone_id = "UTC".to_owned();
+  let bytes: Bytes = type_option.try_into().unwrap();
+  test
+    .editor
+    .update_field_type_option(&test.view_id, &date_field.id, bytes.to_vec(), None)
+    .await
+    .unwrap();
+
+  let scripts = vec![
+    GroupByField {
+      field_id: date_field.id.clone(),
+    },
+    // no status group, the weeks start on Sunday
+    AssertGroupCount(3),
+    // 2022/03/14 is in the week of 2022/03/13
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 3,
+    },
+    // 2022/11/13 and 2022/11/17
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+  assert_eq!(test.group_at_index(1).await.group_id, "2022-03-13");
+  assert_eq!(test.group_at_index(2).await.group_id, "2022-11-13");
+
+  // The weeks start on Monday, so 2022/11/13 is in the week before 2022/11/17
+  test
+    .sdk
+    .database_manager
+    .set_week_settings(WeekSettings::new(1, None))
+    .await;
+  let scripts = vec![
+    AssertGroupCount(4),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 3,
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 1,
+    },
+    AssertGroupRowCount {
+      group_index: 3,
+      row_count: 1,
+    },
+  ];
+  test.run_scripts(scripts).await;
+  assert_eq!(test.group_at_index(1).await.group_id, "2022-03-14");
+  assert_eq!(test.group_at_index(2).await.group_id, "2022-11-07");
+  assert_eq!(test.group_at_index(3).await.group_id, "2022-11-14");
+}
+
+#[tokio::test]
+async fn group_move_row_to_other_week_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let date_field = test.get_first_field_rev(FieldType::DateTime).clone();
+  let scripts = vec![
+    GroupByField {
+      field_id: date_field.id.clone(),
+    },
+    // Moving the row sets its date to the first day of the other week
+    MoveRow {
+      from_group_index: 2,
+      from_row_index: 0,
+      to_group_index: 1,
+      to_row_index: 0,
+    },
+    AssertGroupCount(3),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 4,
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 1,
+    },
+    // Moving the last row of the week deletes its group
+    MoveRow {
+      from_group_index: 2,
+      from_row_index: 0,
+      to_group_index: 1,
+      to_row_index: 0,
+    },
+    AssertGroupCount(2),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 5,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -1,3 +1,9 @@
+mod automation_group_test;
+mod board_layout_test;
+mod date_group_test;
+mod person_group_test;
+mod progress_group_test;
 mod script;
 mod test;
+mod time_tracking_group_test;
 mod url_group_test;
@@ -0,0 +1,73 @@
+use crate::database::group_test::script::DatabaseGroupTest;
+use crate::database::group_test::script::GroupScript::*;
+
+#[tokio::test]
+async fn group_group_by_person() {
+  let mut test = DatabaseGroupTest::new().await;
+  let person_field = test.get_person_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: person_field.id.clone(),
+    },
+    // no status group
+    AssertGroupRowCount {
+      group_index: 0,
+      row_count: 2,
+    },
+    // Alice
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 2,
+    },
+    // Bob
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 2,
+    },
+    AssertGroupCount(3),
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_move_row_to_another_person_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let person_field = test.get_person_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: person_field.id.clone(),
+    },
+    // The row that is assigned to Alice only is reassigned to Bob
+    UpdateGroupedCell {
+      from_group_index: 1,
+      row_index: 0,
+      to_group_index: 2,
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 1,
+    },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 3,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_create_row_in_person_group_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let person_field = test.get_person_field().await;
+  let scripts = vec![
+    GroupByField {
+      field_id: person_field.id.clone(),
+    },
+    CreateRow { group_index: 2 },
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 3,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -0,0 +1,42 @@
+use crate::database::database_editor::DatabaseEditorTest;
+use flowy_database::entities::FieldType;
+
+#[tokio::test]
+async fn group_progress_average_test() {
+  let test = DatabaseEditorTest::new_board().await;
+  let progress_field = test.get_first_field_rev(FieldType::Progress).clone();
+  let groups = test.editor.load_groups(&test.view_id).await.unwrap().items;
+  let group_progress = test
+    .editor
+    .get_group_progress(&test.view_id, &progress_field.id)
+    .await
+    .unwrap();
+  assert_eq!(group_progress.len(), groups.len());
+
+  let group_of_row = |row_id: &str| {
+    let group = groups
+      .iter()
+      .find(|group| group.rows.iter().any(|row| row.id == row_id))
+      .unwrap();
+    group_progress
+      .iter()
+      .find(|progress| progress.group_id == group.group_id)
+      .unwrap()
+  };
+
+  // The first two rows are 20% and 60%, the third one is 50% and the empty cells are skipped
+  let progress = group_of_row(&test.row_revs[0].id);
+  assert_eq!((progress.average, progress.count), (40.0, 2));
+  let progress = group_of_row(&test.row_revs[2].id);
+  assert_eq!((progress.average, progress.count), (50.0, 1));
+  let progress = group_of_row(&test.row_revs[4].id);
+  assert_eq!((progress.average, progress.count), (0.0, 0));
+
+  // Only the progress fields can be averaged
+  let number_field = test.get_first_field_rev(FieldType::Number).clone();
+  assert!(test
+    .editor
+    .get_group_progress(&test.view_id, &number_field.id)
+    .await
+    .is_err());
+}
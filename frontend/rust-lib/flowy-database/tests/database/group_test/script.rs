@@ -1,10 +1,11 @@
 use crate::database::database_editor::DatabaseEditorTest;
 use database_model::{FieldRevision, RowChangeset};
 use flowy_database::entities::{
-  CreateRowParams, FieldType, GroupPB, MoveGroupParams, MoveGroupRowParams, RowPB,
+  CreateRowParams, FieldType, GroupPB, MoveGroupParams, MoveGroupRowParams, RowPB, SwimlaneBoardPB,
+  SwimlaneFieldParams,
 };
 use flowy_database::services::cell::{
-  delete_select_option_cell, insert_select_option_cell, insert_url_cell,
+  delete_select_option_cell, insert_person_cell, insert_select_option_cell, insert_url_cell,
 };
 use flowy_database::services::field::{
   edit_single_select_type_option, SelectOptionPB, SelectTypeOptionSharedAction,
@@ -60,6 +61,24 @@ pub enum GroupScript {
   GroupByField {
     field_id: String,
   },
+  SetSwimlaneField {
+    field_id: Option<String>,
+  },
+  AssertSetSwimlaneFieldError {
+    field_id: String,
+  },
+  AssertSwimlaneCount(usize),
+  AssertSwimlaneCell {
+    swimlane_index: usize,
+    group_index: usize,
+    row_count: usize,
+  },
+  MoveRowToSwimlane {
+    from_group_index: usize,
+    from_row_index: usize,
+    to_group_index: usize,
+    to_swimlane_index: usize,
+  },
 }
 
 pub struct DatabaseGroupTest {
@@ -110,6 +129,7 @@ impl DatabaseGroupTest {
           from_row_id: from_row.id.clone(),
           to_group_id: to_group.group_id.clone(),
           to_row_id: Some(to_row.id.clone()),
+          to_swimlane_id: None,
         };
 
         self.editor.move_group_row(params).await.unwrap();
@@ -130,6 +150,7 @@ impl DatabaseGroupTest {
           view_id: self.view_id.clone(),
           start_row_id: None,
           group_id: Some(group.group_id.clone()),
+          swimlane_id: None,
           cell_data_by_field_id: None,
         };
         let _ = self.editor.create_row(params).await.unwrap();
@@ -167,12 +188,13 @@ impl DatabaseGroupTest {
         } else {
           match field_type {
             FieldType::SingleSelect => {
-              insert_select_option_cell(vec![to_group.group_id.clone()], &field_rev)
+              insert_select_option_cell(vec![to_group.group_id.clone()], &[], &field_rev)
             },
             FieldType::MultiSelect => {
-              insert_select_option_cell(vec![to_group.group_id.clone()], &field_rev)
+              insert_select_option_cell(vec![to_group.group_id.clone()], &[], &field_rev)
             },
             FieldType::URL => insert_url_cell(to_group.group_id.clone(), &field_rev),
+            FieldType::Person => insert_person_cell(vec![to_group.group_id.clone()], &field_rev),
             _ => {
               panic!("Unsupported group field type");
             },
@@ -243,9 +265,63 @@ impl DatabaseGroupTest {
           .await
           .unwrap();
       },
+      GroupScript::SetSwimlaneField { field_id } => {
+        let params = SwimlaneFieldParams {
+          view_id: self.view_id.clone(),
+          field_id,
+        };
+        self.editor.set_swimlane_field(params).await.unwrap();
+      },
+      GroupScript::AssertSetSwimlaneFieldError { field_id } => {
+        let params = SwimlaneFieldParams {
+          view_id: self.view_id.clone(),
+          field_id: Some(field_id),
+        };
+        assert!(self.editor.set_swimlane_field(params).await.is_err());
+      },
+      GroupScript::AssertSwimlaneCount(count) => {
+        assert_eq!(count, self.get_swimlanes().await.swimlanes.len());
+      },
+      GroupScript::AssertSwimlaneCell {
+        swimlane_index,
+        group_index,
+        row_count,
+      } => {
+        let board = self.get_swimlanes().await;
+        let swimlane = board.swimlanes.get(swimlane_index).unwrap();
+        let cell = swimlane.cells.get(group_index).unwrap();
+        assert_eq!(
+          cell.group_id,
+          self.group_at_index(group_index).await.group_id
+        );
+        assert_eq!(row_count, cell.rows.len());
+      },
+      GroupScript::MoveRowToSwimlane {
+        from_group_index,
+        from_row_index,
+        to_group_index,
+        to_swimlane_index,
+      } => {
+        let from_row = self.row_at_index(from_group_index, from_row_index).await;
+        let to_group = self.group_at_index(to_group_index).await;
+        let board = self.get_swimlanes().await;
+        let to_swimlane = board.swimlanes.get(to_swimlane_index).unwrap();
+        let params = MoveGroupRowParams {
+          view_id: self.view_id.clone(),
+          from_row_id: from_row.id,
+          to_group_id: to_group.group_id,
+          to_row_id: None,
+          to_swimlane_id: Some(to_swimlane.swimlane_id.clone()),
+        };
+        self.editor.move_group_row(params).await.unwrap();
+      },
     }
   }
 
+  pub async fn get_swimlanes(&self) -> SwimlaneBoardPB {
+    self.editor.get_swimlanes(&self.view_id).await.unwrap()
+  }
+
   pub async fn group_at_index(&self, index: usize) -> GroupPB {
     let groups = self.editor.load_groups(&self.view_id).await.unwrap().items;
     groups.get(index).unwrap().clone()
@@ -284,6 +360,15 @@ impl DatabaseGroupTest {
       .clone()
   }
 
+  pub async fn get_single_select_options(&self) -> Vec<SelectOptionPB> {
+    let single_select = self.get_single_select_field().await;
+    self
+      .editor
+      .get_select_options(&single_select.id)
+      .await
+      .unwrap()
+  }
+
   pub async fn edit_single_select_type_option(
     &self,
     action: impl FnOnce(&mut SingleSelectTypeOptionPB),
@@ -299,6 +384,32 @@ impl DatabaseGroupTest {
     .unwrap();
   }
 
+  pub async fn get_checkbox_field(&self) -> Arc<FieldRevision> {
+    self
+      .inner
+      .field_revs
+      .iter()
+      .find(|field_rev| {
+        let field_type: FieldType = field_rev.ty.into();
+        field_type.is_checkbox()
+      })
+      .unwrap()
+      .clone()
+  }
+
+  pub async fn get_person_field(&self) -> Arc<FieldRevision> {
+    self
+      .inner
+      .field_revs
+      .iter()
+      .find(|field_rev| {
+        let field_type: FieldType = field_rev.ty.into();
+        field_type.is_person()
+      })
+      .unwrap()
+      .clone()
+  }
+
   pub async fn get_url_field(&self) -> Arc<FieldRevision> {
     self
       .inner
@@ -1,7 +1,7 @@
 use crate::database::group_test::script::DatabaseGroupTest;
 use crate::database::group_test::script::GroupScript::*;
 
-use flowy_database::services::field::SelectOptionPB;
+use flowy_database::services::field::{SelectOptionColorPB, SelectOptionPB};
 
 #[tokio::test]
 async fn group_init_test() {
@@ -466,6 +466,122 @@ async fn group_insert_single_select_option_test() {
   assert_eq!(new_group.desc, new_option_name);
 }
 
+#[tokio::test]
+async fn group_rename_single_select_option_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let group = test.group_at_index(1).await;
+  let mut option = test
+    .get_single_select_options()
+    .await
+    .into_iter()
+    .find(|option| option.id == group.group_id)
+    .unwrap();
+  option.name = "Renamed option".to_owned();
+  let scripts = vec![
+    UpdateSingleSelectSelectOption {
+      inserted_options: vec![option],
+    },
+    AssertGroupCount(4),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+  let renamed_group = test.group_at_index(1).await;
+  assert_eq!(renamed_group.group_id, group.group_id);
+  assert_eq!(renamed_group.desc, "Renamed option");
+}
+
+#[tokio::test]
+async fn group_update_select_option_color_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let group = test.group_at_index(2).await;
+  let single_select = test.get_single_select_field().await;
+  let option = test
+    .editor
+    .update_select_option_color(
+      &test.view_id,
+      &single_select.id,
+      &group.group_id,
+      SelectOptionColorPB::Aqua,
+    )
+    .await
+    .unwrap();
+  assert_eq!(option.color, SelectOptionColorPB::Aqua);
+
+  let scripts = vec![
+    AssertGroupCount(4),
+    AssertGroupRowCount {
+      group_index: 2,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+  let recolored_group = test.group_at_index(2).await;
+  assert_eq!(recolored_group.group_id, group.group_id);
+  assert_eq!(recolored_group.color, Some(SelectOptionColorPB::Aqua));
+}
+
+#[tokio::test]
+async fn group_delete_single_select_option_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let group = test.group_at_index(1).await;
+  test
+    .edit_single_select_type_option(|type_option| {
+      type_option
+        .options
+        .retain(|option| option.id != group.group_id);
+    })
+    .await;
+
+  // The rows of the deleted option are moved to the default group
+  let scripts = vec![
+    AssertGroupCount(3),
+    AssertGroupRowCount {
+      group_index: 0,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+  assert_ne!(test.group_at_index(1).await.group_id, group.group_id);
+}
+
+#[tokio::test]
+async fn group_merge_select_options_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let target_group = test.group_at_index(1).await;
+  let merged_group = test.group_at_index(2).await;
+  let single_select = test.get_single_select_field().await;
+  let options = test
+    .editor
+    .merge_select_options_into(
+      &test.view_id,
+      &single_select.id,
+      &target_group.group_id,
+      vec![merged_group.group_id.clone()],
+    )
+    .await
+    .unwrap();
+  assert!(options
+    .iter()
+    .all(|option| option.id != merged_group.group_id));
+
+  let scripts = vec![
+    AssertGroupCount(3),
+    AssertGroupRowCount {
+      group_index: 0,
+      row_count: 0,
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 4,
+    },
+  ];
+  test.run_scripts(scripts).await;
+  assert_eq!(test.group_at_index(1).await.group_id, target_group.group_id);
+}
+
 #[tokio::test]
 async fn group_group_by_other_field() {
   let mut test = DatabaseGroupTest::new().await;
@@ -486,3 +602,126 @@ async fn group_group_by_other_field() {
   ];
   test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn group_swimlane_init_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let checkbox_field = test.get_checkbox_field().await;
+  let scripts = vec![
+    AssertSwimlaneCount(0),
+    SetSwimlaneField {
+      field_id: Some(checkbox_field.id.clone()),
+    },
+    AssertSwimlaneCount(2),
+    // The checked swimlane
+    AssertSwimlaneCell {
+      swimlane_index: 0,
+      group_index: 1,
+      row_count: 2,
+    },
+    AssertSwimlaneCell {
+      swimlane_index: 0,
+      group_index: 2,
+      row_count: 0,
+    },
+    // The unchecked swimlane
+    AssertSwimlaneCell {
+      swimlane_index: 1,
+      group_index: 2,
+      row_count: 2,
+    },
+    AssertSwimlaneCell {
+      swimlane_index: 1,
+      group_index: 3,
+      row_count: 1,
+    },
+    // The groups are not changed by the swimlanes
+    AssertGroupCount(4),
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 2,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_swimlane_move_row_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let checkbox_field = test.get_checkbox_field().await;
+  let scripts = vec![
+    SetSwimlaneField {
+      field_id: Some(checkbox_field.id.clone()),
+    },
+    // Move the row in the unchecked swimlane of group 3 to the checked swimlane of group 1
+    MoveRowToSwimlane {
+      from_group_index: 3,
+      from_row_index: 0,
+      to_group_index: 1,
+      to_swimlane_index: 0,
+    },
+    AssertSwimlaneCell {
+      swimlane_index: 0,
+      group_index: 1,
+      row_count: 3,
+    },
+    AssertSwimlaneCell {
+      swimlane_index: 1,
+      group_index: 3,
+      row_count: 0,
+    },
+    AssertGroupRowCount {
+      group_index: 1,
+      row_count: 3,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_swimlane_delete_row_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let checkbox_field = test.get_checkbox_field().await;
+  let scripts = vec![
+    SetSwimlaneField {
+      field_id: Some(checkbox_field.id.clone()),
+    },
+    DeleteRow {
+      group_index: 1,
+      row_index: 0,
+    },
+    AssertSwimlaneCell {
+      swimlane_index: 0,
+      group_index: 1,
+      row_count: 1,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn group_swimlane_field_test() {
+  let mut test = DatabaseGroupTest::new().await;
+  let single_select_field = test.get_single_select_field().await;
+  let checkbox_field = test.get_checkbox_field().await;
+  let scripts = vec![
+    // The swimlanes can't be grouped by the grouping field
+    AssertSetSwimlaneFieldError {
+      field_id: single_select_field.id.clone(),
+    },
+    SetSwimlaneField {
+      field_id: Some(checkbox_field.id.clone()),
+    },
+    SetSwimlaneField { field_id: None },
+    AssertSwimlaneCount(0),
+    SetSwimlaneField {
+      field_id: Some(checkbox_field.id.clone()),
+    },
+    // Grouping by the swimlane field removes the swimlanes
+    GroupByField {
+      field_id: checkbox_field.id.clone(),
+    },
+    AssertSwimlaneCount(0),
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -0,0 +1,68 @@
+use crate::database::database_editor::DatabaseEditorTest;
+use flowy_database::entities::FieldType;
+use flowy_database::services::field::TimeTrackingCellChangeset;
+
+#[tokio::test]
+async fn group_time_tracking_sum_test() {
+  let test = DatabaseEditorTest::new_board().await;
+  let time_tracking_field = test.get_first_field_rev(FieldType::TimeTracking).clone();
+  let (editor, row_revs) = (&test.editor, &test.row_revs);
+  let field_id = &time_tracking_field.id;
+  let update_cell = move |row_index: usize, changeset: TimeTrackingCellChangeset| {
+    editor.update_cell_with_changeset(&row_revs[row_index].id, field_id, changeset)
+  };
+  update_cell(0, TimeTrackingCellChangeset::Adjust { seconds: 3600 })
+    .await
+    .unwrap();
+  update_cell(1, TimeTrackingCellChangeset::Adjust { seconds: 1800 })
+    .await
+    .unwrap();
+  update_cell(2, TimeTrackingCellChangeset::Start { at: 1000 })
+    .await
+    .unwrap();
+  update_cell(2, TimeTrackingCellChangeset::Stop { at: 1600 })
+    .await
+    .unwrap();
+
+  let groups = test.editor.load_groups(&test.view_id).await.unwrap().items;
+  let sum = test
+    .editor
+    .get_time_tracking_sum(&test.view_id, &time_tracking_field.id)
+    .await
+    .unwrap();
+  assert_eq!(sum.total, 6000);
+  assert_eq!(sum.formatted, "1:40");
+  assert_eq!(sum.groups.len(), groups.len());
+
+  let group_sum_of_row = |row_id: &str| {
+    let group = groups
+      .iter()
+      .find(|group| group.rows.iter().any(|row| row.id == row_id))
+      .unwrap();
+    sum
+      .groups
+      .iter()
+      .find(|group_sum| group_sum.group_id == group.group_id)
+      .unwrap()
+  };
+
+  // The first two rows are in the same group
+  let group_sum = group_sum_of_row(&test.row_revs[0].id);
+  assert_eq!(
+    (group_sum.total, group_sum.formatted.as_str()),
+    (5400, "1:30")
+  );
+  let group_sum = group_sum_of_row(&test.row_revs[2].id);
+  assert_eq!(
+    (group_sum.total, group_sum.formatted.as_str()),
+    (600, "0:10")
+  );
+
+  // Only the time tracking fields can be summed
+  let number_field = test.get_first_field_rev(FieldType::Number).clone();
+  assert!(test
+    .editor
+    .get_time_tracking_sum(&test.view_id, &number_field.id)
+    .await
+    .is_err());
+}
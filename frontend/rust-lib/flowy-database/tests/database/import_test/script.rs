@@ -0,0 +1,148 @@
+use crate::database::database_editor::DatabaseEditorTest;
+use flowy_database::services::import::{
+  create_database_from_csv, resume_database_imports, run_database_import,
+};
+use flowy_database::services::persistence::import_checkpoint::{ImportCheckpoint, ImportType};
+use nanoid::nanoid;
+use std::io::Write;
+use std::path::PathBuf;
+
+pub enum ImportTestScript {
+  CreateDatabaseFromCSV,
+  RunImport,
+  ResumeImports,
+  /// Overwrites the checkpoint as if the app was killed after importing the first `next_row`
+  /// records
+  SaveCheckpoint {
+    next_row: usize,
+  },
+  AppendRecords {
+    count: usize,
+  },
+  AssertFieldNames {
+    expected: Vec<&'static str>,
+  },
+  AssertNumberOfRows {
+    expected: usize,
+  },
+  AssertCheckpoint {
+    next_row: Option<usize>,
+  },
+}
+
+pub struct DatabaseImportTest {
+  inner: DatabaseEditorTest,
+  view_id: String,
+  import_id: String,
+  file_path: PathBuf,
+  number_of_records: usize,
+}
+
+impl DatabaseImportTest {
+  pub async fn new(number_of_records: usize) -> Self {
+    let inner = DatabaseEditorTest::new_grid().await;
+    let view_id = nanoid!(10);
+    let file_path = std::env::temp_dir().join(format!("{}.csv", view_id));
+    std::fs::write(&file_path, "Name,Price,Notes\n").unwrap();
+    let mut test = Self {
+      inner,
+      view_id,
+      import_id: "".to_owned(),
+      file_path,
+      number_of_records: 0,
+    };
+    test.append_records(number_of_records);
+    test
+  }
+
+  pub async fn run_scripts(&mut self, scripts: Vec<ImportTestScript>) {
+    for script in scripts {
+      self.run_script(script).await;
+    }
+  }
+
+  pub async fn run_script(&mut self, script: ImportTestScript) {
+    let database_manager = self.inner.sdk.database_manager.clone();
+    match script {
+      ImportTestScript::CreateDatabaseFromCSV => {
+        create_database_from_csv(
+          &self.view_id,
+          "Imported grid".to_owned(),
+          self.file_path.to_str().unwrap(),
+          database_manager.clone(),
+        )
+        .await
+        .unwrap();
+        self.import_id = database_manager
+          .import_checkpoints()
+          .get_with_view(&self.view_id)
+          .unwrap()
+          .unwrap()
+          .import_id;
+      },
+      ImportTestScript::RunImport => {
+        run_database_import(&self.view_id, database_manager)
+          .await
+          .unwrap();
+      },
+      ImportTestScript::ResumeImports => {
+        resume_database_imports(database_manager).await.unwrap();
+      },
+      ImportTestScript::SaveCheckpoint { next_row } => {
+        database_manager
+          .import_checkpoints()
+          .save(ImportCheckpoint {
+            import_id: self.import_id.clone(),
+            view_id: self.view_id.clone(),
+            import_type: ImportType::CSV,
+            file_path: self.file_path.to_str().unwrap().to_owned(),
+            next_row,
+          })
+          .unwrap();
+      },
+      ImportTestScript::AppendRecords { count } => {
+        self.append_records(count);
+      },
+      ImportTestScript::AssertFieldNames { expected } => {
+        let editor = database_manager
+          .open_database_view(&self.view_id)
+          .await
+          .unwrap();
+        let names = editor
+          .get_field_revs(None)
+          .await
+          .unwrap()
+          .iter()
+          .map(|field_rev| field_rev.name.clone())
+          .collect::<Vec<String>>();
+        assert_eq!(names, expected);
+      },
+      ImportTestScript::AssertNumberOfRows { expected } => {
+        let editor = database_manager
+          .open_database_view(&self.view_id)
+          .await
+          .unwrap();
+        let rows = editor.get_all_row_revs(&self.view_id).await.unwrap();
+        assert_eq!(rows.len(), expected);
+      },
+      ImportTestScript::AssertCheckpoint { next_row } => {
+        let checkpoint = database_manager
+          .import_checkpoints()
+          .get_with_view(&self.view_id)
+          .unwrap();
+        assert_eq!(checkpoint.map(|checkpoint| checkpoint.next_row), next_row);
+      },
+    }
+  }
+
+  fn append_records(&mut self, count: usize) {
+    let mut file = std::fs::OpenOptions::new()
+      .append(true)
+      .open(&self.file_path)
+      .unwrap();
+    for index in self.number_of_records..self.number_of_records + count {
+      writeln!(file, "Item {},{},note {}", index, index, index).unwrap();
+    }
+    self.number_of_records += count;
+  }
+}
@@ -0,0 +1,198 @@
+use crate::database::database_editor::DatabaseEditorTest;
+use crate::database::import_test::script::DatabaseImportTest;
+use crate::database::import_test::script::ImportTestScript::*;
+use flowy_database::entities::CellIdParams;
+use flowy_database::services::import::{
+  create_database_from_trello, resume_database_imports, run_database_import,
+};
+use flowy_database::services::persistence::import_checkpoint::ImportCheckpoint;
+use nanoid::nanoid;
+
+#[tokio::test]
+async fn import_csv_test() {
+  let mut test = DatabaseImportTest::new(250).await;
+  test
+    .run_scripts(vec![
+      CreateDatabaseFromCSV,
+      AssertFieldNames {
+        expected: vec!["Name", "Price", "Notes"],
+      },
+      AssertNumberOfRows { expected: 0 },
+      AssertCheckpoint { next_row: Some(0) },
+      RunImport,
+      AssertNumberOfRows { expected: 250 },
+      AssertCheckpoint { next_row: None },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn resume_interrupted_import_test() {
+  let mut test = DatabaseImportTest::new(150).await;
+  test
+    .run_scripts(vec![
+      CreateDatabaseFromCSV,
+      // The app was killed before importing any records.
+      ResumeImports,
+      AssertNumberOfRows { expected: 150 },
+      // The app was killed after importing the first 150 records. The remaining records
+      // should be imported when resuming.
+      AppendRecords { count: 80 },
+      SaveCheckpoint { next_row: 150 },
+      ResumeImports,
+      AssertNumberOfRows { expected: 230 },
+      AssertCheckpoint { next_row: None },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn resume_import_skips_committed_rows_test() {
+  let mut test = DatabaseImportTest::new(250).await;
+  test
+    .run_scripts(vec![
+      CreateDatabaseFromCSV,
+      RunImport,
+      // The chunks were committed, but the app was killed before updating the checkpoint.
+      SaveCheckpoint { next_row: 100 },
+      RunImport,
+      AssertNumberOfRows { expected: 250 },
+      AssertCheckpoint { next_row: None },
+    ])
+    .await;
+}
+
+const TRELLO_BOARD: &str = r#"{
+  "name": "Roadmap",
+  "lists": [
+    { "id": "list_done", "name": "Done", "closed": false, "pos": 2048 },
+    { "id": "list_todo", "name": "To Do", "closed": false, "pos": 1024 },
+    { "id": "list_archived", "name": "Archived", "closed": true, "pos": 4096 }
+  ],
+  "labels": [
+    { "id": "label_bug", "name": "Bug", "color": "red" },
+    { "id": "label_green", "name": "", "color": "green_dark" }
+  ],
+  "cards": [
+    {
+      "id": "card_1", "name": "Release", "desc": "", "idList": "list_done",
+      "idLabels": [], "due": null, "closed": false, "pos": 1
+    },
+    {
+      "id": "card_2", "name": "Fix the crash", "desc": "Steps:\nOpen the board",
+      "idList": "list_todo", "idLabels": ["label_bug", "label_green"],
+      "due": "2022-03-14T09:56:02.000Z", "closed": false, "pos": 2
+    },
+    {
+      "id": "card_3", "name": "Write the docs", "desc": "", "idList": "list_todo",
+      "idLabels": [], "closed": false, "pos": 1
+    },
+    {
+      "id": "card_4", "name": "Old card", "desc": "", "idList": "list_todo",
+      "idLabels": [], "closed": true, "pos": 3
+    },
+    {
+      "id": "card_5", "name": "Card of the archived list", "desc": "", "idList": "list_archived",
+      "idLabels": [], "closed": false, "pos": 1
+    }
+  ]
+}"#;
+
+#[tokio::test]
+async fn import_trello_board_test() {
+  let test = DatabaseEditorTest::new_board().await;
+  let database_manager = test.sdk.database_manager.clone();
+  let view_id = nanoid!(10);
+  let file_path = std::env::temp_dir().join(format!("{}.json", view_id));
+  std::fs::write(&file_path, TRELLO_BOARD).unwrap();
+
+  create_database_from_trello(
+    &view_id,
+    "Roadmap".to_owned(),
+    file_path.to_str().unwrap(),
+    database_manager.clone(),
+  )
+  .await
+  .unwrap();
+  let checkpoint = database_manager
+    .import_checkpoints()
+    .get_with_view(&view_id)
+    .unwrap()
+    .unwrap();
+  run_database_import(&view_id, database_manager.clone())
+    .await
+    .unwrap();
+  assert!(database_manager
+    .import_checkpoints()
+    .get_with_view(&view_id)
+    .unwrap()
+    .is_none());
+
+  let editor = database_manager.open_database_view(&view_id).await.unwrap();
+  let field_revs = editor.get_field_revs(None).await.unwrap();
+  let field_names = field_revs
+    .iter()
+    .map(|field_rev| field_rev.name.as_str())
+    .collect::<Vec<&str>>();
+  assert_eq!(field_names, vec!["Name", "List", "Labels", "Due date"]);
+
+  // The cards are ordered by their lists, the archived cards and lists are skipped
+  let row_revs = editor.get_all_row_revs(&view_id).await.unwrap();
+  let display_strs = |field_index: usize| {
+    let editor = editor.clone();
+    let view_id = view_id.clone();
+    let field_id = field_revs[field_index].id.clone();
+    let row_ids = row_revs
+      .iter()
+      .map(|row_rev| row_rev.id.clone())
+      .collect::<Vec<String>>();
+    async move {
+      let mut display_strs = vec![];
+      for row_id in row_ids {
+        let cell_id = CellIdParams {
+          view_id: view_id.clone(),
+          field_id: field_id.clone(),
+          row_id,
+        };
+        display_strs.push(editor.get_cell_display_str(&cell_id).await);
+      }
+      display_strs
+    }
+  };
+  assert_eq!(
+    display_strs(0).await,
+    vec!["Write the docs", "Fix the crash", "Release"]
+  );
+  assert_eq!(display_strs(1).await, vec!["To Do", "To Do", "Done"]);
+  assert_eq!(display_strs(2).await, vec!["", "Bug,Dark green", ""]);
+  assert!(!display_strs(3).await[1].is_empty());
+
+  // The descriptions are written into the documents of the rows
+  let has_document = |row_id: &str| {
+    database_manager
+      .get_row_document_id(row_id)
+      .unwrap()
+      .is_some()
+  };
+  assert!(!has_document(&row_revs[0].id));
+  assert!(has_document(&row_revs[1].id));
+  assert!(!has_document(&row_revs[2].id));
+
+  // The app was killed after committing the cards, but before creating the document of the
+  // second card. The document is created when resuming, the cards are not imported again.
+  database_manager
+    .unbind_row_document(&row_revs[1].id)
+    .unwrap();
+  database_manager
+    .import_checkpoints()
+    .save(ImportCheckpoint {
+      next_row: 1,
+      ..checkpoint
+    })
+    .unwrap();
+  resume_database_imports(database_manager.clone())
+    .await
+    .unwrap();
+  assert_eq!(editor.get_all_row_revs(&view_id).await.unwrap().len(), 3);
+  assert!(has_document(&row_revs[1].id));
+}
@@ -1,21 +1,56 @@
 use crate::database::database_editor::DatabaseEditorTest;
-use database_model::{CalendarLayoutSetting, FieldRevision, LayoutRevision};
-use flowy_database::entities::FieldType;
+use database_model::{CalendarLayoutSetting, FieldRevision, GridLayoutSetting, LayoutRevision};
+use flowy_database::entities::{
+  CreateRowParams, DatabaseViewDescriptionParams, FieldType, LayoutSettingParams,
+};
+use flowy_database::services::field::{DateCellChangeset, RecurrenceRulePB};
 use std::sync::Arc;
 
 pub enum LayoutScript {
-  AssertCalendarLayoutSetting { expected: CalendarLayoutSetting },
+  AssertCalendarLayoutSetting {
+    expected: CalendarLayoutSetting,
+  },
   GetCalendarEvents,
+  CreateUnscheduledRow,
+  ShowUnscheduledEvents(bool),
+  AssertUnscheduledEventCount(usize),
+  ScheduleRow {
+    timestamp: i64,
+  },
+  RepeatRow {
+    recurrence: RecurrenceRulePB,
+  },
+  AssertRowOccurrences {
+    start: i64,
+    end: i64,
+    expected: usize,
+  },
+  SetFrozenColumnCount(i32),
+  AssertFrozenColumnCount(i32),
+  UpdateViewDescription(String),
+  AssertViewDescription(String),
 }
 
 pub struct DatabaseLayoutTest {
   database_test: DatabaseEditorTest,
+  row_id: Option<String>,
 }
 
 impl DatabaseLayoutTest {
+  pub async fn new_grid() -> Self {
+    let database_test = DatabaseEditorTest::new_grid().await;
+    Self {
+      database_test,
+      row_id: None,
+    }
+  }
+
   pub async fn new_calendar() -> Self {
     let database_test = DatabaseEditorTest::new_calendar().await;
-    Self { database_test }
+    Self {
+      database_test,
+      row_id: None,
+    }
   }
 
   pub async fn run_scripts(&mut self, scripts: Vec<LayoutScript>) {
@@ -57,7 +92,7 @@ impl DatabaseLayoutTest {
         let events = self
           .database_test
           .editor
-          .get_all_calendar_events(&self.database_test.view_id)
+          .get_all_calendar_events(&self.database_test.view_id, None)
           .await;
         assert_eq!(events.len(), 5);
 
@@ -81,6 +116,171 @@ impl DatabaseLayoutTest {
           }
         }
       },
+      LayoutScript::CreateUnscheduledRow => {
+        let params = CreateRowParams {
+          view_id: self.database_test.view_id.clone(),
+          ..Default::default()
+        };
+        let row = self.database_test.editor.create_row(params).await.unwrap();
+        self.row_id = Some(row.id);
+      },
+      LayoutScript::ShowUnscheduledEvents(show_unscheduled_events) => {
+        let view_id = self.database_test.view_id.clone();
+        let mut calendar_setting = self
+          .database_test
+          .editor
+          .get_layout_setting(&view_id, LayoutRevision::Calendar)
+          .await
+          .unwrap()
+          .calendar
+          .unwrap();
+        calendar_setting.show_unscheduled_events = show_unscheduled_events;
+        let layout_setting = LayoutSettingParams {
+          calendar: Some(calendar_setting),
+          grid: None,
+        };
+        self
+          .database_test
+          .editor
+          .set_layout_setting(&view_id, layout_setting)
+          .await
+          .unwrap();
+      },
+      LayoutScript::AssertUnscheduledEventCount(expected) => {
+        let events = self
+          .database_test
+          .editor
+          .get_all_calendar_events(&self.database_test.view_id, None)
+          .await;
+        let unscheduled_events = events
+          .into_iter()
+          .filter(|event| event.is_unscheduled)
+          .collect::<Vec<_>>();
+        assert_eq!(unscheduled_events.len(), expected);
+        for event in unscheduled_events {
+          assert_eq!(event.timestamp, 0);
+        }
+      },
+      LayoutScript::ScheduleRow { timestamp } => {
+        let row_id = self.row_id.clone().unwrap();
+        let event = self
+          .database_test
+          .editor
+          .schedule_row(&self.database_test.view_id, &row_id, timestamp)
+          .await
+          .unwrap();
+        assert_eq!(event.row_id, row_id);
+        assert_eq!(event.timestamp, timestamp);
+        assert!(!event.is_unscheduled);
+      },
+      LayoutScript::RepeatRow { recurrence } => {
+        let row_id = self.row_id.clone().unwrap();
+        let date_field = self.get_first_date_field().await;
+        let changeset = DateCellChangeset {
+          date: None,
+          time: None,
+          include_time: None,
+          is_utc: true,
+          text: None,
+          locale: None,
+          recurrence: Some(recurrence),
+          clear_recurrence: false,
+        };
+        self
+          .database_test
+          .editor
+          .update_cell(row_id, date_field.id.clone(), changeset)
+          .await
+          .unwrap();
+      },
+      LayoutScript::AssertRowOccurrences {
+        start,
+        end,
+        expected,
+      } => {
+        let row_id = self.row_id.clone().unwrap();
+        let occurrences = self
+          .database_test
+          .editor
+          .get_all_calendar_events(&self.database_test.view_id, Some((start, end)))
+          .await
+          .into_iter()
+          .filter(|event| event.row_id == row_id)
+          .collect::<Vec<_>>();
+        assert_eq!(occurrences.len(), expected);
+        for occurrence in occurrences {
+          assert!(occurrence.is_recurring);
+          assert!(start <= occurrence.timestamp && occurrence.timestamp <= end);
+        }
+      },
+      LayoutScript::SetFrozenColumnCount(frozen_column_count) => {
+        let layout_setting = LayoutSettingParams {
+          calendar: None,
+          grid: Some(GridLayoutSetting {
+            frozen_column_count,
+          }),
+        };
+        self
+          .database_test
+          .editor
+          .set_layout_setting(&self.database_test.view_id, layout_setting)
+          .await
+          .unwrap();
+      },
+      LayoutScript::AssertFrozenColumnCount(expected) => {
+        let view_id = self.database_test.view_id.clone();
+        let grid_setting = self
+          .database_test
+          .editor
+          .get_layout_setting(&view_id, LayoutRevision::Grid)
+          .await
+          .unwrap()
+          .grid
+          .unwrap();
+        assert_eq!(grid_setting.frozen_column_count, expected);
+
+        // The grid setting is included in the view's setting
+        let setting = self
+          .database_test
+          .editor
+          .get_setting(&view_id)
+          .await
+          .unwrap();
+        assert_eq!(
+          setting.layout_setting.grid.unwrap().frozen_column_count,
+          expected
+        );
+      },
+      LayoutScript::UpdateViewDescription(description) => {
+        let params = DatabaseViewDescriptionParams {
+          view_id: self.database_test.view_id.clone(),
+          description,
+        };
+        self
+          .database_test
+          .editor
+          .update_view_description(params)
+          .await
+          .unwrap();
+      },
+      LayoutScript::AssertViewDescription(expected) => {
+        let view_id = self.database_test.view_id.clone();
+        let description = self
+          .database_test
+          .editor
+          .get_view_description(&view_id)
+          .await
+          .unwrap();
+        assert_eq!(description, expected);
+
+        let setting = self
+          .database_test
+          .editor
+          .get_setting(&view_id)
+          .await
+          .unwrap();
+        assert_eq!(setting.description, expected);
+      },
     }
   }
 }
@@ -1,6 +1,7 @@
 use crate::database::layout_test::script::DatabaseLayoutTest;
 use crate::database::layout_test::script::LayoutScript::*;
 use database_model::CalendarLayoutSetting;
+use flowy_database::services::field::{RecurrenceFrequencyPB, RecurrenceRulePB};
 
 #[tokio::test]
 async fn calendar_initial_layout_setting_test() {
@@ -19,3 +20,83 @@ async fn calendar_get_events_test() {
   let scripts = vec![GetCalendarEvents];
   test.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn calendar_unscheduled_event_test() {
+  let mut test = DatabaseLayoutTest::new_calendar().await;
+  let scripts = vec![
+    AssertUnscheduledEventCount(0),
+    CreateUnscheduledRow,
+    // The unscheduled events are hidden by default
+    AssertUnscheduledEventCount(0),
+    ShowUnscheduledEvents(true),
+    AssertUnscheduledEventCount(1),
+    ScheduleRow {
+      timestamp: 1678090778,
+    },
+    AssertUnscheduledEventCount(0),
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn calendar_recurring_event_test() {
+  let mut test = DatabaseLayoutTest::new_calendar().await;
+  let day = 24 * 60 * 60;
+  let scripts = vec![
+    CreateUnscheduledRow,
+    ScheduleRow {
+      timestamp: 1678090778,
+    },
+    RepeatRow {
+      recurrence: RecurrenceRulePB {
+        frequency: RecurrenceFrequencyPB::Weekly,
+        interval: 1,
+        until: None,
+        count: Some(3),
+      },
+    },
+    AssertRowOccurrences {
+      start: 1678090778,
+      end: 1678090778 + 60 * day,
+      expected: 3,
+    },
+    // Only the second occurrence is in the window
+    AssertRowOccurrences {
+      start: 1678090778 + 5 * day,
+      end: 1678090778 + 10 * day,
+      expected: 1,
+    },
+    AssertRowOccurrences {
+      start: 1678090778 + 30 * day,
+      end: 1678090778 + 60 * day,
+      expected: 0,
+    },
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_frozen_column_count_test() {
+  let mut test = DatabaseLayoutTest::new_grid().await;
+  let scripts = vec![
+    AssertFrozenColumnCount(0),
+    SetFrozenColumnCount(2),
+    AssertFrozenColumnCount(2),
+  ];
+  test.run_scripts(scripts).await;
+}
+
+#[tokio::test]
+async fn grid_view_description_test() {
+  let mut test = DatabaseLayoutTest::new_grid().await;
+  let description = r#"[{"insert":"The tasks that are due this week\n"}]"#.to_owned();
+  let scripts = vec![
+    AssertViewDescription("".to_owned()),
+    UpdateViewDescription(description.clone()),
+    AssertViewDescription(description),
+    UpdateViewDescription("".to_owned()),
+    AssertViewDescription("".to_owned()),
+  ];
+  test.run_scripts(scripts).await;
+}
@@ -3,7 +3,8 @@
 // #![allow(unused_imports)]
 use crate::database::block_test::util::DatabaseRowTestBuilder;
 use crate::database::mock_data::{
-  COMPLETED, FACEBOOK, FIRST_THING, GOOGLE, PAUSED, PLANNED, SECOND_THING, THIRD_THING, TWITTER,
+  ALICE_ID, BOB_ID, COMPLETED, FACEBOOK, FIRST_THING, GOOGLE, PAUSED, PLANNED, SECOND_THING,
+  THIRD_THING, TWITTER,
 };
 
 use flowy_client_sync::client_database::DatabaseBuilder;
@@ -102,6 +103,119 @@ pub fn make_test_grid() -> BuildDatabaseContext {
           .build();
         database_builder.add_field(checklist_field);
       },
+      FieldType::Relation => {
+        // The related database is set by the tests that link rows
+        let relation = RelationTypeOptionBuilder::default();
+        let relation_field = FieldBuilder::new(relation)
+          .name("Related")
+          .visibility(true)
+          .build();
+        database_builder.add_field(relation_field);
+      },
+      FieldType::Rollup => {
+        // The relation and the target of the rollup are set by the tests that calculate it
+        let rollup = RollupTypeOptionBuilder::default();
+        let rollup_field = FieldBuilder::new(rollup)
+          .name("Rollup")
+          .visibility(true)
+          .build();
+        database_builder.add_field(rollup_field);
+      },
+      FieldType::Formula => {
+        // The expression of the formula is set by the tests that calculate it
+        let formula = FormulaTypeOptionBuilder::default();
+        let formula_field = FieldBuilder::new(formula)
+          .name("Formula")
+          .visibility(true)
+          .build();
+        database_builder.add_field(formula_field);
+      },
+      FieldType::CreatedTime => {
+        let created_time = CreatedTimeTypeOptionBuilder::default()
+          .date_format(DateFormat::US)
+          .time_format(TimeFormat::TwentyFourHour);
+        let created_time_field = FieldBuilder::new(created_time)
+          .name("Created time")
+          .visibility(true)
+          .build();
+        database_builder.add_field(created_time_field);
+      },
+      FieldType::LastEditedTime => {
+        let last_edited_time = LastEditedTimeTypeOptionBuilder::default()
+          .date_format(DateFormat::US)
+          .time_format(TimeFormat::TwentyFourHour);
+        let last_edited_time_field = FieldBuilder::new(last_edited_time)
+          .name("Last edited time")
+          .visibility(true)
+          .build();
+        database_builder.add_field(last_edited_time_field);
+      },
+      FieldType::Person => {
+        let person = PersonTypeOptionBuilder::default()
+          .add_member(PersonPB::new(ALICE_ID, "Alice"))
+          .add_member(PersonPB::new(BOB_ID, "Bob"));
+        let person_field = FieldBuilder::new(person)
+          .name("Assignee")
+          .visibility(true)
+          .build();
+        database_builder.add_field(person_field);
+      },
+      FieldType::Attachment => {
+        let attachment_field = FieldBuilder::new(AttachmentTypeOptionBuilder::default())
+          .name("Attachments")
+          .visibility(true)
+          .build();
+        database_builder.add_field(attachment_field);
+      },
+      FieldType::Progress => {
+        let progress_field = FieldBuilder::new(ProgressTypeOptionBuilder::default())
+          .name("Progress")
+          .visibility(true)
+          .build();
+        database_builder.add_field(progress_field);
+      },
+      FieldType::Phone => {
+        let phone_field = FieldBuilder::new(PhoneTypeOptionBuilder::default())
+          .name("Phone")
+          .visibility(true)
+          .build();
+        database_builder.add_field(phone_field);
+      },
+      FieldType::AutoNumber => {
+        let auto_number_field = FieldBuilder::new(AutoNumberTypeOptionBuilder::default())
+          .name("ID")
+          .visibility(true)
+          .build();
+        database_builder.add_field(auto_number_field);
+      },
+      FieldType::TimeTracking => {
+        let time_tracking_field = FieldBuilder::new(TimeTrackingTypeOptionBuilder::default())
+          .name("Time Tracked")
+          .visibility(true)
+          .build();
+        database_builder.add_field(time_tracking_field);
+      },
+      FieldType::Duration => {
+        let duration_field = FieldBuilder::new(DurationTypeOptionBuilder::default())
+          .name("Duration")
+          .visibility(true)
+          .build();
+        database_builder.add_field(duration_field);
+      },
+      FieldType::Email => {
+        let email_field = FieldBuilder::new(EmailTypeOptionBuilder::default())
+          .name("Email")
+          .visibility(true)
+          .build();
+        database_builder.add_field(email_field);
+      },
+      FieldType::Location => {
+        let location_field = FieldBuilder::new(LocationTypeOptionBuilder::default())
+          .name("Location")
+          .visibility(true)
+          .build();
+        database_builder.add_field(location_field);
+      },
     }
   }
 
@@ -17,3 +17,6 @@ pub const PAUSED: &str = "Paused";
 pub const FIRST_THING: &str = "Wake up at 6:00 am";
 pub const SECOND_THING: &str = "Get some coffee";
 pub const THIRD_THING: &str = "Start working";
+
+pub const ALICE_ID: &str = "user_alice";
+pub const BOB_ID: &str = "user_bob";
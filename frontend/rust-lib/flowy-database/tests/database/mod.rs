@@ -5,6 +5,7 @@ mod database_ref_test;
 mod field_test;
 mod filter_test;
 mod group_test;
+mod import_test;
 mod layout_test;
 mod snapshot_test;
 mod sort_test;
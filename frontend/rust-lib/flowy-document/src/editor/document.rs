@@ -45,11 +45,19 @@ pub(crate) fn make_tree_context() -> NodeTreeContext {
 }
 
 pub fn initial_document_content() -> String {
-  let delta = DeltaTextOperationBuilder::new().insert("").build();
-  let node_data = NodeDataBuilder::new("text").insert_delta(delta).build();
-  let editor_node = NodeDataBuilder::new("editor")
-    .add_node_data(node_data)
-    .build();
+  document_content_from_text("")
+}
+
+/// Returns the content of a document that contains the text, each line of the text becomes a
+/// text node.
+pub fn document_content_from_text(text: &str) -> String {
+  let mut editor_node = NodeDataBuilder::new("editor");
+  for line in text.lines().chain(text.is_empty().then_some("")) {
+    let delta = DeltaTextOperationBuilder::new().insert(line).build();
+    let node_data = NodeDataBuilder::new("text").insert_delta(delta).build();
+    editor_node = editor_node.add_node_data(node_data);
+  }
+  let editor_node = editor_node.build();
   let node_operation = NodeOperation::Insert {
     path: vec![0].into(),
     nodes: vec![editor_node],
@@ -113,11 +121,14 @@ impl RevisionMergeable for DocumentRevisionMergeable {
   }
 }
 
+/// The revisions that can't be read mean the stored document is corrupted.
 #[tracing::instrument(level = "trace", skip_all, err)]
 pub fn make_transaction_from_revisions(revisions: &[Revision]) -> FlowyResult<Transaction> {
   let mut transaction = Transaction::new();
   for revision in revisions {
-    transaction.compose(Transaction::from_bytes(&revision.bytes)?)?;
+    let revision_transaction = Transaction::from_bytes(&revision.bytes)
+      .map_err(|e| FlowyError::data_corrupted().context(e))?;
+    transaction.compose(revision_transaction)?;
   }
   Ok(transaction)
 }
@@ -112,6 +112,19 @@ impl DocumentEditor for Arc<AppFlowyDocumentEditor> {
 
   fn receive_ws_state(&self, _state: &WSConnectState) {}
 
+  async fn number_of_unacked_revisions(&self) -> usize {
+    self.rev_manager.number_of_unacked_revisions().await
+  }
+
+  async fn estimated_bytes(&self) -> usize {
+    let (ret, rx) = oneshot::channel::<usize>();
+    let _ = self
+      .command_sender
+      .send(Command::GetEstimatedBytes { ret })
+      .await;
+    rx.await.unwrap_or(0)
+  }
+
   fn compose_local_operations(&self, data: Bytes) -> FutureResult<(), FlowyError> {
     let this = self.clone();
     FutureResult::new(async move {
@@ -1,5 +1,6 @@
 #![allow(clippy::while_let_loop)]
 use crate::editor::document::Document;
+use crate::services::estimated_node_tree_bytes;
 use crate::DocumentUser;
 use async_stream::stream;
 use bytes::Bytes;
@@ -75,6 +76,10 @@ impl DocumentQueue {
         let content = self.document.read().await.get_content(pretty)?;
         let _ = ret.send(Ok(content));
       },
+      Command::GetEstimatedBytes { ret } => {
+        let bytes = estimated_node_tree_bytes(self.document.read().await.get_tree());
+        let _ = ret.send(bytes);
+      },
     }
     Ok(())
   }
@@ -104,4 +109,7 @@ pub enum Command {
     pretty: bool,
     ret: Ret<String>,
   },
+  GetEstimatedBytes {
+    ret: oneshot::Sender<usize>,
+  },
 }
@@ -1,4 +1,7 @@
 use crate::errors::ErrorCode;
+use crate::services::{
+  DocumentGarbage, DocumentLayoutSummary, PrintBlockKind, PrintPageLayout, PrintPagination,
+};
 use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
 use std::convert::TryInto;
 
@@ -130,3 +133,265 @@ pub struct ExportDataPB {
   #[pb(index = 2)]
   pub export_type: ExportType,
 }
+
+#[derive(Default, ProtoBuf)]
+pub struct DocumentGarbagePB {
+  #[pb(index = 1)]
+  pub document_id: String,
+
+  /// The timestamp in seconds when the view of the document was permanently deleted
+  #[pb(index = 2)]
+  pub deleted_at: i64,
+
+  #[pb(index = 3)]
+  pub bytes: i64,
+}
+
+impl std::convert::From<DocumentGarbage> for DocumentGarbagePB {
+  fn from(garbage: DocumentGarbage) -> Self {
+    Self {
+      document_id: garbage.document_id,
+      deleted_at: garbage.deleted_at,
+      bytes: garbage.bytes as i64,
+    }
+  }
+}
+
+/// The deleted documents that will be collected by the next garbage collection and the number
+/// of bytes that will be reclaimed.
+#[derive(Default, ProtoBuf)]
+pub struct DocumentGarbagePreviewPB {
+  #[pb(index = 1)]
+  pub items: Vec<DocumentGarbagePB>,
+
+  #[pb(index = 2)]
+  pub reclaimed_bytes: i64,
+}
+
+impl std::convert::From<Vec<DocumentGarbage>> for DocumentGarbagePreviewPB {
+  fn from(garbage: Vec<DocumentGarbage>) -> Self {
+    let reclaimed_bytes = garbage.iter().map(|item| item.bytes as i64).sum();
+    Self {
+      items: garbage.into_iter().map(DocumentGarbagePB::from).collect(),
+      reclaimed_bytes,
+    }
+  }
+}
+
+/// The page that the document is printed on, the sizes are in points.
+#[derive(Default, ProtoBuf, Clone)]
+pub struct PrintPageLayoutPB {
+  #[pb(index = 1)]
+  pub page_width: f64,
+
+  #[pb(index = 2)]
+  pub page_height: f64,
+
+  #[pb(index = 3)]
+  pub margin: f64,
+
+  #[pb(index = 4)]
+  pub font_size: f64,
+
+  #[pb(index = 5)]
+  pub line_height: f64,
+}
+
+impl std::convert::From<PrintPageLayoutPB> for PrintPageLayout {
+  fn from(layout: PrintPageLayoutPB) -> Self {
+    Self {
+      page_width: layout.page_width,
+      page_height: layout.page_height,
+      margin: layout.margin,
+      font_size: layout.font_size,
+      line_height: layout.line_height,
+    }
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct PrintPaginationPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// The A4 paper with one inch margins is used if the layout is not provided
+  #[pb(index = 2, one_of)]
+  pub page_layout: Option<PrintPageLayoutPB>,
+}
+
+#[derive(Debug)]
+pub struct PrintPaginationParams {
+  pub view_id: String,
+  pub page_layout: PrintPageLayout,
+}
+
+impl TryInto<PrintPaginationParams> for PrintPaginationPayloadPB {
+  type Error = ErrorCode;
+  fn try_into(self) -> Result<PrintPaginationParams, Self::Error> {
+    let page_layout = self
+      .page_layout
+      .map(PrintPageLayout::from)
+      .unwrap_or_default();
+    if page_layout.validate().is_err() {
+      return Err(ErrorCode::InvalidData);
+    }
+
+    Ok(PrintPaginationParams {
+      view_id: self.view_id,
+      page_layout,
+    })
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct PrintBlockPB {
+  /// The path of the block in the document, the indexes are separated by commas, e.g. `1,0`
+  #[pb(index = 1)]
+  pub path: String,
+
+  #[pb(index = 2)]
+  pub height: f64,
+
+  #[pb(index = 3)]
+  pub line_count: i64,
+}
+
+/// The page starts at the `start_line` of the block at `start_block`.
+#[derive(Default, ProtoBuf)]
+pub struct PrintPagePB {
+  #[pb(index = 1)]
+  pub start_block: i64,
+
+  #[pb(index = 2)]
+  pub start_line: i64,
+}
+
+/// The pagination hints of the document. The exporter and the print preview place the blocks
+/// on the pages as described here, so both of them break the pages at the same positions.
+#[derive(Default, ProtoBuf)]
+pub struct PrintPaginationPB {
+  #[pb(index = 1)]
+  pub blocks: Vec<PrintBlockPB>,
+
+  #[pb(index = 2)]
+  pub pages: Vec<PrintPagePB>,
+}
+
+impl std::convert::From<PrintPagination> for PrintPaginationPB {
+  fn from(pagination: PrintPagination) -> Self {
+    let blocks = pagination
+      .blocks
+      .into_iter()
+      .map(|(block, metrics)| PrintBlockPB {
+        path: path_string(&block.path),
+        height: metrics.height,
+        line_count: metrics.line_count as i64,
+      })
+      .collect();
+    let pages = pagination
+      .pages
+      .into_iter()
+      .map(|page| PrintPagePB {
+        start_block: page.start_block as i64,
+        start_line: page.start_line as i64,
+      })
+      .collect();
+    Self { blocks, pages }
+  }
+}
+
+/// Joins the indexes of the path with commas, e.g. `1,0`
+fn path_string(path: &[usize]) -> String {
+  path
+    .iter()
+    .map(|index| index.to_string())
+    .collect::<Vec<_>>()
+    .join(",")
+}
+
+#[derive(PartialEq, Eq, Debug, ProtoBuf_Enum, Clone)]
+pub enum LayoutBlockKindPB {
+  Text = 0,
+  Heading = 1,
+  Image = 2,
+  Divider = 3,
+  PageBreak = 4,
+}
+
+impl std::default::Default for LayoutBlockKindPB {
+  fn default() -> Self {
+    LayoutBlockKindPB::Text
+  }
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct LayoutBlockPB {
+  /// The path of the block in the document, the indexes are separated by commas, e.g. `1,0`
+  #[pb(index = 1)]
+  pub path: String,
+
+  #[pb(index = 2)]
+  pub kind: LayoutBlockKindPB,
+
+  /// The level of the heading, from 1 to 3. It's zero for the other blocks.
+  #[pb(index = 3)]
+  pub heading_level: i32,
+
+  #[pb(index = 4)]
+  pub text: String,
+
+  /// The height of the block in points
+  #[pb(index = 5)]
+  pub height: f64,
+}
+
+/// The blocks on the first screen of the document. They are shown until the document is
+/// loaded, so opening a large document doesn't show an empty page.
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct DocumentLayoutSummaryPB {
+  #[pb(index = 1)]
+  pub doc_id: String,
+
+  #[pb(index = 2)]
+  pub blocks: Vec<LayoutBlockPB>,
+
+  /// Whether the document has more blocks after the summary
+  #[pb(index = 3)]
+  pub has_more: bool,
+
+  /// False if the document has no cached summary yet, the summary is sent with the
+  /// `DidUpdateLayoutSummary` notification once the document is loaded.
+  #[pb(index = 4)]
+  pub is_cached: bool,
+}
+
+impl std::convert::From<DocumentLayoutSummary> for DocumentLayoutSummaryPB {
+  fn from(summary: DocumentLayoutSummary) -> Self {
+    let blocks = summary
+      .blocks
+      .into_iter()
+      .map(|block| {
+        let (kind, heading_level) = match block.kind {
+          PrintBlockKind::Text => (LayoutBlockKindPB::Text, 0),
+          PrintBlockKind::Heading(level) => (LayoutBlockKindPB::Heading, level as i32),
+          PrintBlockKind::Image => (LayoutBlockKindPB::Image, 0),
+          PrintBlockKind::Divider => (LayoutBlockKindPB::Divider, 0),
+          PrintBlockKind::PageBreak => (LayoutBlockKindPB::PageBreak, 0),
+        };
+        LayoutBlockPB {
+          path: path_string(&block.path),
+          kind,
+          heading_level,
+          text: block.text,
+          height: block.height,
+        }
+      })
+      .collect();
+    Self {
+      doc_id: summary.document_id,
+      blocks,
+      has_more: summary.has_more,
+      is_cached: true,
+    }
+  }
+}
@@ -1,7 +1,9 @@
 use crate::entities::{
-  DocumentDataPB, EditParams, EditPayloadPB, ExportDataPB, ExportParams, ExportPayloadPB,
-  OpenDocumentPayloadPB,
+  DocumentDataPB, DocumentGarbagePreviewPB, DocumentLayoutSummaryPB, EditParams, EditPayloadPB,
+  ExportDataPB, ExportParams, ExportPayloadPB, OpenDocumentPayloadPB, PrintPaginationPB,
+  PrintPaginationParams, PrintPaginationPayloadPB,
 };
+use crate::services::paginate_document;
 use crate::DocumentManager;
 use flowy_error::FlowyError;
 
@@ -44,3 +46,46 @@ pub(crate) async fn export_handler(
     export_type: params.export_type,
   })
 }
+
+#[tracing::instrument(level = "debug", skip(manager), err)]
+pub(crate) async fn preview_garbage_collection_handler(
+  manager: AFPluginState<Arc<DocumentManager>>,
+) -> DataResult<DocumentGarbagePreviewPB, FlowyError> {
+  let garbage = manager.preview_garbage().await?;
+  data_result_ok(DocumentGarbagePreviewPB::from(garbage))
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_print_pagination_handler(
+  data: AFPluginData<PrintPaginationPayloadPB>,
+  manager: AFPluginState<Arc<DocumentManager>>,
+) -> DataResult<PrintPaginationPB, FlowyError> {
+  let params: PrintPaginationParams = data.into_inner().try_into()?;
+  let editor = manager.open_document_editor(&params.view_id).await?;
+  let document_data = editor.export().await?;
+  let pagination = paginate_document(&document_data, &params.page_layout)?;
+  data_result_ok(PrintPaginationPB::from(pagination))
+}
+
+#[tracing::instrument(level = "debug", skip(data, manager), err)]
+pub(crate) async fn get_layout_summary_handler(
+  data: AFPluginData<OpenDocumentPayloadPB>,
+  manager: AFPluginState<Arc<DocumentManager>>,
+) -> DataResult<DocumentLayoutSummaryPB, FlowyError> {
+  let document_id = data.into_inner().document_id;
+  let summary = match manager.get_layout_summary(&document_id)? {
+    None => DocumentLayoutSummaryPB {
+      doc_id: document_id.clone(),
+      ..Default::default()
+    },
+    Some(summary) => DocumentLayoutSummaryPB::from(summary),
+  };
+
+  let manager = manager.get_ref().clone();
+  tokio::spawn(async move {
+    if let Err(err) = manager.reconcile_layout_summary(&document_id).await {
+      tracing::error!("Reconcile the layout summary failed: {:?}", err);
+    }
+  });
+  data_result_ok(summary)
+}
@@ -3,7 +3,7 @@ use crate::DocumentManager;
 use flowy_derive::{Flowy_Event, ProtoBuf_Enum};
 use lib_dispatch::prelude::AFPlugin;
 use std::sync::Arc;
-use strum_macros::Display;
+use strum_macros::{Display, EnumIter};
 
 pub fn init(document_manager: Arc<DocumentManager>) -> AFPlugin {
   let mut plugin = AFPlugin::new()
@@ -13,12 +13,26 @@ pub fn init(document_manager: Arc<DocumentManager>) -> AFPlugin {
   plugin = plugin
     .event(DocumentEvent::GetDocument, get_document_handler)
     .event(DocumentEvent::ApplyEdit, apply_edit_handler)
-    .event(DocumentEvent::ExportDocument, export_handler);
+    .event(DocumentEvent::ExportDocument, export_handler)
+    .event(
+      DocumentEvent::PreviewGarbageCollection,
+      preview_garbage_collection_handler,
+    )
+    .event(
+      DocumentEvent::GetPrintPagination,
+      get_print_pagination_handler,
+    )
+    .event(
+      DocumentEvent::GetDocumentLayoutSummary,
+      get_layout_summary_handler,
+    );
 
   plugin
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
+#[derive(
+  Clone, Copy, PartialEq, Eq, Debug, Display, Hash, EnumIter, ProtoBuf_Enum, Flowy_Event,
+)]
 #[event_err = "FlowyError"]
 pub enum DocumentEvent {
   #[event(input = "OpenDocumentPayloadPB", output = "DocumentDataPB")]
@@ -29,4 +43,20 @@ pub enum DocumentEvent {
 
   #[event(input = "ExportPayloadPB", output = "ExportDataPB")]
   ExportDocument = 2,
+
+  /// Returns the documents whose view was permanently deleted and that will be collected by the
+  /// next garbage collection.
+  #[event(output = "DocumentGarbagePreviewPB")]
+  PreviewGarbageCollection = 3,
+
+  /// Returns the height of each block and where each page starts when the document is
+  /// printed. The PDF exporter and the print preview use it to paginate the document.
+  #[event(input = "PrintPaginationPayloadPB", output = "PrintPaginationPB")]
+  GetPrintPagination = 4,
+
+  /// Returns the cached blocks on the first screen of the document, it's called before
+  /// `GetDocument` so a large document shows its content right away. The document is loaded in
+  /// the background and `DidUpdateLayoutSummary` is sent if its summary changed.
+  #[event(input = "OpenDocumentPayloadPB", output = "DocumentLayoutSummaryPB")]
+  GetDocumentLayoutSummary = 5,
 }
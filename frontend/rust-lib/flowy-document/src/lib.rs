@@ -2,6 +2,7 @@ pub mod entities;
 mod event_handler;
 pub mod event_map;
 pub mod manager;
+pub mod notification;
 
 pub mod editor;
 pub mod old_editor;
@@ -1,12 +1,19 @@
-use crate::editor::{initial_document_content, AppFlowyDocumentEditor, DocumentRevisionMergeable};
-use crate::entities::{DocumentVersionPB, EditParams};
+use crate::editor::{
+  document_content_from_text, initial_document_content, AppFlowyDocumentEditor,
+  DocumentRevisionMergeable,
+};
+use crate::entities::{DocumentLayoutSummaryPB, DocumentVersionPB, EditParams};
+use crate::notification::{send_notification, DocumentNotification};
 use crate::old_editor::editor::{DeltaDocumentEditor, DeltaDocumentRevisionMergeable};
 use crate::old_editor::snapshot::DeltaDocumentSnapshotPersistence;
 use crate::services::rev_sqlite::{
   SQLiteDeltaDocumentRevisionPersistence, SQLiteDocumentRevisionPersistence,
   SQLiteDocumentRevisionSnapshotPersistence,
 };
-use crate::services::DocumentPersistence;
+use crate::services::{
+  DocumentGarbage, DocumentGarbageCollector, DocumentLayoutCache, DocumentLayoutSummary,
+  DocumentPersistence,
+};
 use crate::{errors::FlowyError, DocumentCloudService};
 use bytes::Bytes;
 use document_model::document::DocumentId;
@@ -21,11 +28,13 @@ use lib_infra::async_trait::async_trait;
 use lib_infra::future::FutureResult;
 use lib_infra::ref_map::{RefCountHashMap, RefCountValue};
 use lib_infra::util::md5;
+use lib_ot::text_delta::DeltaTextOperationBuilder;
 use lib_ws::WSConnectState;
 use revision_model::Revision;
 use std::any::Any;
 use std::convert::TryFrom;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 use ws_model::ws_revision::ServerRevisionWSData;
 
@@ -55,6 +64,12 @@ pub trait DocumentEditor: Send + Sync {
 
   fn receive_ws_state(&self, state: &WSConnectState);
 
+  /// Returns the number of the local revisions that were not acked by the server yet
+  async fn number_of_unacked_revisions(&self) -> usize;
+
+  /// Returns the estimated bytes of the document in memory
+  async fn estimated_bytes(&self) -> usize;
+
   /// Receives the local operations made by the user input. The operations are encoded
   /// in binary format.
   fn compose_local_operations(&self, data: Bytes) -> FutureResult<(), FlowyError>;
@@ -73,12 +88,15 @@ pub trait DocumentEditor: Send + Sync {
 #[derive(Clone, Debug)]
 pub struct DocumentConfig {
   pub version: DocumentVersionPB,
+  /// The data of the document is kept for this period after its view was permanently deleted
+  pub gc_retention: Duration,
 }
 
 impl std::default::Default for DocumentConfig {
   fn default() -> Self {
     Self {
       version: DocumentVersionPB::V1,
+      gc_retention: Duration::from_secs(30 * 24 * 60 * 60),
     }
   }
 }
@@ -89,6 +107,8 @@ pub struct DocumentManager {
   editor_map: Arc<RwLock<RefCountHashMap<RefCountDocumentHandler>>>,
   user: Arc<dyn DocumentUser>,
   persistence: Arc<DocumentPersistence>,
+  gc: DocumentGarbageCollector,
+  layout_cache: DocumentLayoutCache,
   #[allow(dead_code)]
   config: DocumentConfig,
 }
@@ -106,7 +126,9 @@ impl DocumentManager {
       rev_web_socket,
       editor_map: Arc::new(RwLock::new(RefCountHashMap::new())),
       user: document_user,
-      persistence: Arc::new(DocumentPersistence::new(database)),
+      persistence: Arc::new(DocumentPersistence::new(database.clone())),
+      gc: DocumentGarbageCollector::new(database.clone()),
+      layout_cache: DocumentLayoutCache::new(database),
       config,
     }
   }
@@ -137,6 +159,13 @@ impl DocumentManager {
   pub async fn close_document_editor<T: AsRef<str>>(&self, editor_id: T) -> Result<(), FlowyError> {
     let editor_id = editor_id.as_ref();
     tracing::Span::current().record("editor_id", editor_id);
+    let editor = self.editor_map.read().await.get(editor_id);
+    if let Some(editor) = editor {
+      // The document is opened with the latest summary next time
+      if let Err(err) = self.save_layout_summary(editor_id, &editor.0).await {
+        tracing::error!("Save the layout summary of {} failed: {:?}", editor_id, err);
+      }
+    }
     self.editor_map.write().await.remove(editor_id).await;
     Ok(())
   }
@@ -146,6 +175,8 @@ impl DocumentManager {
     editor
       .compose_local_operations(Bytes::from(params.operations))
       .await?;
+    self.layout_cache.invalidate(&params.doc_id)?;
+    send_notification(&params.doc_id, DocumentNotification::DidUpdateDocument).send();
     Ok(())
   }
 
@@ -159,9 +190,118 @@ impl DocumentManager {
     // Maybe we could save the document to disk without creating the RevisionManager
     let rev_manager = self.make_rev_manager(&doc_id, db_pool)?;
     rev_manager.reset_object(revisions).await?;
+    self.layout_cache.invalidate(&doc_id)?;
     Ok(())
   }
 
+  /// Copies the content of the document into a new document, e.g. the row that the document
+  /// belongs to got a new id. Returns false if the document doesn't exist.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn duplicate_document(&self, doc_id: &str, new_doc_id: &str) -> FlowyResult<bool> {
+    let pool = self.persistence.database.db_pool()?;
+    let revisions = self
+      .make_rev_manager(doc_id, pool)?
+      .load_revisions()
+      .await?;
+    if revisions.is_empty() {
+      return Ok(false);
+    }
+
+    let editor = self.editor_map.read().await.get(doc_id);
+    let content = match editor {
+      Some(editor) => editor.0.duplicate().await?,
+      None => {
+        let editor = self.make_document_editor(doc_id).await?;
+        let content = editor.duplicate().await;
+        editor.close().await;
+        content?
+      },
+    };
+    let revision = Revision::initial_revision(new_doc_id, Bytes::from(content));
+    self.create_document(new_doc_id, vec![revision]).await?;
+    Ok(true)
+  }
+
+  /// Called when the view of the document was permanently deleted. The data of the document
+  /// will be collected after the retention period.
+  pub async fn did_delete_document(&self, doc_id: &str) -> FlowyResult<()> {
+    self.editor_map.write().await.remove(doc_id).await;
+    self.layout_cache.invalidate(doc_id)?;
+    self.gc.mark_deleted(doc_id)
+  }
+
+  /// Returns the cached summary of the first screen of the document, it's None if the document
+  /// was edited since the summary was saved.
+  pub fn get_layout_summary(&self, doc_id: &str) -> FlowyResult<Option<DocumentLayoutSummary>> {
+    self.layout_cache.get(doc_id)
+  }
+
+  /// Loads the document and generates its layout summary. The summary is saved and sent with
+  /// the `DidUpdateLayoutSummary` notification if it differs from the cached one.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub async fn reconcile_layout_summary(&self, doc_id: &str) -> FlowyResult<DocumentLayoutSummary> {
+    let cached_summary = self.layout_cache.get(doc_id)?;
+    let editor = self.editor_map.read().await.get(doc_id);
+    let summary = match editor {
+      Some(editor) => self.save_layout_summary(doc_id, &editor.0).await?,
+      None => {
+        // The document is only loaded to generate the summary. The editor is not registered,
+        // so the document can be opened by the user at the same time.
+        let editor = self.make_document_editor(doc_id).await?;
+        let summary = self.save_layout_summary(doc_id, &editor).await;
+        editor.close().await;
+        summary?
+      },
+    };
+    let is_changed = cached_summary
+      .map(|cached_summary| !cached_summary.is_same_layout(&summary))
+      .unwrap_or(true);
+    if is_changed {
+      send_notification(doc_id, DocumentNotification::DidUpdateLayoutSummary)
+        .payload(DocumentLayoutSummaryPB::from(summary.clone()))
+        .send();
+    }
+    Ok(summary)
+  }
+
+  /// Returns the deleted documents that will be collected by the next garbage collection.
+  pub async fn preview_garbage(&self) -> FlowyResult<Vec<DocumentGarbage>> {
+    self.gc.find_garbage(self.config.gc_retention)
+  }
+
+  /// Removes the data of the documents that were deleted before the retention period and
+  /// returns the collected documents.
+  #[tracing::instrument(level = "trace", skip_all, err)]
+  pub async fn collect_garbage(&self) -> FlowyResult<Vec<DocumentGarbage>> {
+    let garbage = self.gc.find_garbage(self.config.gc_retention)?;
+    if !garbage.is_empty() {
+      self.gc.collect(&garbage)?;
+      tracing::debug!("Collected {} deleted documents", garbage.len());
+    }
+    Ok(garbage)
+  }
+
+  /// Returns the number of the local revisions of the opened documents that were not acked by
+  /// the server yet.
+  pub async fn number_of_unacked_revisions(&self) -> usize {
+    let editors = self.editor_map.read().await.values();
+    let mut number = 0;
+    for editor in editors {
+      number += editor.number_of_unacked_revisions().await;
+    }
+    number
+  }
+
+  /// Returns the estimated bytes of the opened documents in memory.
+  pub async fn open_document_bytes(&self) -> usize {
+    let editors = self.editor_map.read().await.values();
+    let mut bytes = 0;
+    for editor in editors {
+      bytes += editor.estimated_bytes().await;
+    }
+    bytes
+  }
+
   pub async fn receive_ws_data(&self, data: Bytes) {
     let result: Result<ServerRevisionWSData, serde_json::Error> =
       ServerRevisionWSData::try_from(data);
@@ -172,9 +312,17 @@ impl DocumentManager {
           data.object_id,
           data.payload
         ),
-        Some(handler) => match handler.0.receive_ws_data(data).await {
-          Ok(_) => {},
-          Err(e) => tracing::error!("{}", e),
+        Some(handler) => {
+          let object_id = data.object_id.clone();
+          match handler.0.receive_ws_data(data).await {
+            // The remote revisions might change the first screen of the document
+            Ok(_) => {
+              if let Err(e) = self.layout_cache.invalidate(&object_id) {
+                tracing::error!("Invalidate the layout summary failed: {:?}", e);
+              }
+            },
+            Err(e) => tracing::error!("{}", e),
+          }
         },
       },
       Err(e) => {
@@ -189,9 +337,32 @@ impl DocumentManager {
       DocumentVersionPB::V1 => initial_document_content(),
     }
   }
+
+  /// Returns the content of a new document that contains the text, e.g. the description of a
+  /// card that was imported into a row.
+  pub fn document_content_from_text(&self, text: &str) -> String {
+    match self.config.version {
+      DocumentVersionPB::V0 => DeltaTextOperationBuilder::new()
+        .insert(&format!("{}\n", text))
+        .build()
+        .json_str(),
+      DocumentVersionPB::V1 => document_content_from_text(text),
+    }
+  }
 }
 
 impl DocumentManager {
+  async fn save_layout_summary(
+    &self,
+    doc_id: &str,
+    editor: &Arc<dyn DocumentEditor>,
+  ) -> FlowyResult<DocumentLayoutSummary> {
+    let content = editor.export().await?;
+    let summary = DocumentLayoutSummary::from_exported_content(doc_id, &content)?;
+    self.layout_cache.save(&summary)?;
+    Ok(summary)
+  }
+
   /// Returns the `DocumentEditor`
   ///
   /// # Arguments
@@ -224,6 +395,21 @@ impl DocumentManager {
   pub async fn init_document_editor(
     &self,
     doc_id: &str,
+  ) -> Result<Arc<dyn DocumentEditor>, FlowyError> {
+    let editor = self.make_document_editor(doc_id).await?;
+    self
+      .editor_map
+      .write()
+      .await
+      .insert(doc_id.to_string(), RefCountDocumentHandler(editor.clone()));
+    Ok(editor)
+  }
+
+  /// Creates a document editor that is not registered in the editor map. The caller closes
+  /// the editor when it's done with it.
+  async fn make_document_editor(
+    &self,
+    doc_id: &str,
   ) -> Result<Arc<dyn DocumentEditor>, FlowyError> {
     let pool = self.persistence.database.db_pool()?;
     let user = self.user.clone();
@@ -235,7 +421,7 @@ impl DocumentManager {
 
     match self.config.version {
       DocumentVersionPB::V0 => {
-        let rev_manager = self.make_delta_document_rev_manager(doc_id, pool.clone())?;
+        let rev_manager = self.make_delta_document_rev_manager(doc_id, pool)?;
         let editor: Arc<dyn DocumentEditor> = Arc::new(
           DeltaDocumentEditor::new(
             doc_id,
@@ -246,22 +432,12 @@ impl DocumentManager {
           )
           .await?,
         );
-        self
-          .editor_map
-          .write()
-          .await
-          .insert(doc_id.to_string(), RefCountDocumentHandler(editor.clone()));
         Ok(editor)
       },
       DocumentVersionPB::V1 => {
-        let rev_manager = self.make_document_rev_manager(doc_id, pool.clone())?;
+        let rev_manager = self.make_document_rev_manager(doc_id, pool)?;
         let editor: Arc<dyn DocumentEditor> =
           Arc::new(AppFlowyDocumentEditor::new(doc_id, user, rev_manager, cloud_service).await?);
-        self
-          .editor_map
-          .write()
-          .await
-          .insert(doc_id.to_string(), RefCountDocumentHandler(editor.clone()));
         Ok(editor)
       },
     }
@@ -0,0 +1,32 @@
+use flowy_derive::ProtoBuf_Enum;
+use flowy_notification::NotificationBuilder;
+pub const OBSERVABLE_CATEGORY: &str = "Document";
+
+#[derive(ProtoBuf_Enum, Debug)]
+pub enum DocumentNotification {
+  Unknown = 0,
+  /// Trigger after applying the local edits to the document
+  DidUpdateDocument = 1,
+  /// Trigger after the layout summary of the opened document was generated again, the
+  /// payload is `DocumentLayoutSummaryPB`
+  DidUpdateLayoutSummary = 2,
+  /// Trigger after applying the revisions of the other devices to the document
+  DidReceiveRemoteRevisions = 3,
+}
+
+impl std::default::Default for DocumentNotification {
+  fn default() -> Self {
+    DocumentNotification::Unknown
+  }
+}
+
+impl std::convert::From<DocumentNotification> for i32 {
+  fn from(notification: DocumentNotification) -> Self {
+    notification as i32
+  }
+}
+
+#[tracing::instrument(level = "trace")]
+pub(crate) fn send_notification(id: &str, ty: DocumentNotification) -> NotificationBuilder {
+  NotificationBuilder::new(id, ty, OBSERVABLE_CATEGORY)
+}
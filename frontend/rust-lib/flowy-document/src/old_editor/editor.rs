@@ -29,7 +29,6 @@ use ws_model::ws_revision::ServerRevisionWSData;
 
 pub struct DeltaDocumentEditor {
   pub doc_id: String,
-  #[allow(dead_code)]
   rev_manager: Arc<RevisionManager<Arc<ConnectionPool>>>,
   #[cfg(feature = "sync")]
   ws_manager: Arc<flowy_revision::RevisionWebSocketManager>,
@@ -191,6 +190,17 @@ impl DocumentEditor for Arc<DeltaDocumentEditor> {
     self.ws_manager.connect_state_changed(state.clone());
   }
 
+  async fn number_of_unacked_revisions(&self) -> usize {
+    self.rev_manager.number_of_unacked_revisions().await
+  }
+
+  async fn estimated_bytes(&self) -> usize {
+    let (ret, rx) = oneshot::channel::<usize>();
+    let msg = EditorCommand::GetEstimatedBytes { ret };
+    let _ = self.edit_cmd_tx.send(msg).await;
+    rx.await.unwrap_or(0)
+  }
+
   fn compose_local_operations(&self, data: Bytes) -> FutureResult<(), FlowyError> {
     let edit_cmd_tx = self.edit_cmd_tx.clone();
     FutureResult::new(async move {
@@ -1,5 +1,6 @@
 #![allow(clippy::while_let_loop)]
 use crate::old_editor::web_socket::DeltaDocumentResolveOperations;
+use crate::services::estimated_operations_bytes;
 use crate::DocumentUser;
 use async_stream::stream;
 use flowy_client_sync::{
@@ -179,6 +180,10 @@ impl EditDocumentQueue {
         let operations = self.document.read().await.get_operations().clone();
         let _ = ret.send(Ok(operations));
       },
+      EditorCommand::GetEstimatedBytes { ret } => {
+        let bytes = estimated_operations_bytes(self.document.read().await.get_operations());
+        let _ = ret.send(bytes);
+      },
     }
     Ok(())
   }
@@ -254,6 +259,9 @@ pub(crate) enum EditorCommand {
   GetOperations {
     ret: Ret<DeltaTextOperations>,
   },
+  GetEstimatedBytes {
+    ret: oneshot::Sender<usize>,
+  },
 }
 
 impl std::fmt::Debug for EditorCommand {
@@ -273,6 +281,7 @@ impl std::fmt::Debug for EditorCommand {
       EditorCommand::Redo { .. } => "Redo",
       EditorCommand::GetOperationsString { .. } => "StringifyOperations",
       EditorCommand::GetOperations { .. } => "ReadOperations",
+      EditorCommand::GetEstimatedBytes { .. } => "GetEstimatedBytes",
     };
     f.write_str(s)
   }
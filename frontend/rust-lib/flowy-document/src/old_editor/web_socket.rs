@@ -1,3 +1,4 @@
+use crate::notification::{send_notification, DocumentNotification};
 use crate::old_editor::queue::{EditorCommand, EditorCommandSender, TextTransformOperations};
 use crate::TEXT_BLOCK_SYNC_INTERVAL_IN_MILLIS;
 use bytes::Bytes;
@@ -58,7 +59,10 @@ pub(crate) async fn make_document_ws_manager(
     Arc::new(ws_data_provider.clone()),
     rev_manager,
   );
-  let ws_data_stream = Arc::new(DocumentRevisionWSDataStream::new(conflict_controller));
+  let ws_data_stream = Arc::new(DocumentRevisionWSDataStream::new(
+    &doc_id,
+    conflict_controller,
+  ));
   let ws_data_sink = Arc::new(DocumentWSDataSink(ws_data_provider));
   let ping_duration = Duration::from_millis(TEXT_BLOCK_SYNC_INTERVAL_IN_MILLIS);
   let ws_manager = Arc::new(RevisionWebSocketManager::new(
@@ -92,13 +96,15 @@ fn listen_document_ws_state(
 }
 
 pub(crate) struct DocumentRevisionWSDataStream {
+  doc_id: String,
   conflict_controller: Arc<DocumentConflictController>,
 }
 
 impl DocumentRevisionWSDataStream {
   #[allow(dead_code)]
-  pub fn new(conflict_controller: DocumentConflictController) -> Self {
+  pub fn new(doc_id: &str, conflict_controller: DocumentConflictController) -> Self {
     Self {
+      doc_id: doc_id.to_owned(),
       conflict_controller: Arc::new(conflict_controller),
     }
   }
@@ -106,8 +112,13 @@ impl DocumentRevisionWSDataStream {
 
 impl RevisionWSDataStream for DocumentRevisionWSDataStream {
   fn receive_push_revision(&self, revisions: Vec<Revision>) -> BoxResultFuture<(), FlowyError> {
+    let doc_id = self.doc_id.clone();
     let resolver = self.conflict_controller.clone();
-    Box::pin(async move { resolver.receive_revisions(revisions).await })
+    Box::pin(async move {
+      resolver.receive_revisions(revisions).await?;
+      send_notification(&doc_id, DocumentNotification::DidReceiveRemoteRevisions).send();
+      Ok(())
+    })
   }
 
   fn receive_ack(&self, rev_id: i64) -> BoxResultFuture<(), FlowyError> {
@@ -135,6 +146,10 @@ impl RevisionWebSocketSink for DocumentWSDataSink {
     let sink_provider = self.0.clone();
     FutureResult::new(async move { sink_provider.next().await })
   }
+
+  fn span(&self, data: &ClientRevisionWSData) -> tracing::Span {
+    self.0.span(data)
+  }
 }
 
 struct DocumentConflictResolver {
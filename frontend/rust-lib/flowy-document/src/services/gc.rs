@@ -0,0 +1,127 @@
+use crate::DocumentDatabase;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use flowy_error::{internal_error, FlowyError, FlowyResult};
+use flowy_sqlite::{
+  prelude::*,
+  schema::{deleted_document_table, document_rev_snapshot, document_rev_table, rev_table},
+};
+use lib_infra::util::timestamp;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Collects the data of the documents whose view was permanently deleted. The document is
+/// marked as deleted when its view gets deleted, and its revisions and snapshots are removed
+/// once the retention period has passed.
+pub struct DocumentGarbageCollector {
+  database: Arc<dyn DocumentDatabase>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DocumentGarbage {
+  pub document_id: String,
+  /// The timestamp in seconds when the view of the document was permanently deleted
+  pub deleted_at: i64,
+  /// The number of bytes that will be reclaimed by collecting the document
+  pub bytes: usize,
+}
+
+impl DocumentGarbageCollector {
+  pub fn new(database: Arc<dyn DocumentDatabase>) -> Self {
+    Self { database }
+  }
+
+  pub fn mark_deleted(&self, document_id: &str) -> FlowyResult<()> {
+    let pool = self.database.db_pool()?;
+    let conn = pool.get().map_err(internal_error)?;
+    let record = DeletedDocumentRecord {
+      document_id: document_id.to_owned(),
+      deleted_at: timestamp(),
+    };
+    let _ = diesel::replace_into(deleted_document_table::table)
+      .values(record)
+      .execute(&*conn)?;
+    Ok(())
+  }
+
+  /// Returns the documents that were deleted before the `retention` period.
+  pub fn find_garbage(&self, retention: Duration) -> FlowyResult<Vec<DocumentGarbage>> {
+    let pool = self.database.db_pool()?;
+    let conn = pool.get().map_err(internal_error)?;
+    let deleted_before = timestamp() - retention.as_secs() as i64;
+    let records = deleted_document_table::dsl::deleted_document_table
+      .filter(deleted_document_table::deleted_at.le(deleted_before))
+      .load::<DeletedDocumentRecord>(&*conn)?;
+
+    let mut garbage = vec![];
+    for record in records {
+      let bytes = document_data_size(&record.document_id, &conn)?;
+      garbage.push(DocumentGarbage {
+        document_id: record.document_id,
+        deleted_at: record.deleted_at,
+        bytes,
+      });
+    }
+    Ok(garbage)
+  }
+
+  /// Removes the revisions and the snapshots of the documents.
+  pub fn collect(&self, garbage: &[DocumentGarbage]) -> FlowyResult<()> {
+    let pool = self.database.db_pool()?;
+    let conn = pool.get().map_err(internal_error)?;
+    conn.immediate_transaction::<_, FlowyError, _>(|| {
+      for item in garbage {
+        let document_id = item.document_id.as_str();
+        diesel::delete(
+          document_rev_table::dsl::document_rev_table
+            .filter(document_rev_table::document_id.eq(document_id)),
+        )
+        .execute(&*conn)?;
+        diesel::delete(
+          document_rev_snapshot::dsl::document_rev_snapshot
+            .filter(document_rev_snapshot::object_id.eq(document_id)),
+        )
+        .execute(&*conn)?;
+        // The revisions of the legacy documents are stored in the rev_table
+        diesel::delete(rev_table::dsl::rev_table.filter(rev_table::doc_id.eq(document_id)))
+          .execute(&*conn)?;
+        diesel::delete(
+          deleted_document_table::dsl::deleted_document_table
+            .filter(deleted_document_table::document_id.eq(document_id)),
+        )
+        .execute(&*conn)?;
+      }
+      Ok(())
+    })
+  }
+}
+
+fn document_data_size(document_id: &str, conn: &SqliteConnection) -> FlowyResult<usize> {
+  let revisions = document_rev_table::dsl::document_rev_table
+    .filter(document_rev_table::document_id.eq(document_id))
+    .select(document_rev_table::data)
+    .load::<Vec<u8>>(conn)?;
+  let snapshots = document_rev_snapshot::dsl::document_rev_snapshot
+    .filter(document_rev_snapshot::object_id.eq(document_id))
+    .select(document_rev_snapshot::data)
+    .load::<Vec<u8>>(conn)?;
+  let legacy_revisions = rev_table::dsl::rev_table
+    .filter(rev_table::doc_id.eq(document_id))
+    .select(rev_table::data)
+    .load::<Vec<u8>>(conn)?;
+
+  let bytes = revisions
+    .iter()
+    .chain(snapshots.iter())
+    .chain(legacy_revisions.iter())
+    .map(|data| data.len())
+    .sum();
+  Ok(bytes)
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[table_name = "deleted_document_table"]
+#[primary_key(document_id)]
+struct DeletedDocumentRecord {
+  document_id: String,
+  deleted_at: i64,
+}
@@ -0,0 +1,221 @@
+use crate::services::{
+  blocks_from_exported_content, measure_block, PrintBlockKind, PrintPageLayout,
+};
+use crate::DocumentDatabase;
+use dashmap::DashSet;
+use diesel::result::OptionalExtension;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
+use flowy_error::{internal_error, FlowyResult};
+use flowy_sqlite::{prelude::*, schema::document_layout_cache_table};
+use lib_infra::util::timestamp;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// The height of the first screen in points. The summary covers a bit more than a screen, so
+/// the document can be scrolled a little before it's loaded.
+const SCREEN_HEIGHT: f64 = 1200.0;
+/// Limits the summary of the documents that start with many empty blocks
+const MAX_SUMMARY_BLOCKS: usize = 200;
+/// The text of a long block is truncated, the rest of it is not on the first screen anyway
+const MAX_BLOCK_TEXT_LEN: usize = 2000;
+
+/// A block on the first screen of the document, with the height it's rendered with.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DocumentLayoutBlock {
+  pub path: Vec<usize>,
+  pub kind: PrintBlockKind,
+  pub text: String,
+  /// The height of the block in points
+  pub height: f64,
+}
+
+/// The blocks on the first screen of the document. It's shown when the document is opened,
+/// while the document itself is still being loaded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentLayoutSummary {
+  pub document_id: String,
+  pub blocks: Vec<DocumentLayoutBlock>,
+  /// Whether the document has more blocks after the summary
+  pub has_more: bool,
+  /// The timestamp in seconds when the summary was generated
+  pub updated_at: i64,
+}
+
+impl DocumentLayoutSummary {
+  /// Generates the summary from the exported `content`, the blocks are measured with the
+  /// default page layout.
+  pub fn from_exported_content(document_id: &str, content: &str) -> FlowyResult<Self> {
+    let layout = PrintPageLayout::default();
+    let blocks = blocks_from_exported_content(content)?;
+    let number_of_blocks = blocks.len();
+    let mut summary_blocks = vec![];
+    let mut height = 0.0;
+    for block in blocks {
+      if height >= SCREEN_HEIGHT || summary_blocks.len() >= MAX_SUMMARY_BLOCKS {
+        break;
+      }
+      let metrics = measure_block(&block, &layout);
+      height += metrics.height;
+      summary_blocks.push(DocumentLayoutBlock {
+        path: block.path,
+        kind: block.kind,
+        text: block.text.chars().take(MAX_BLOCK_TEXT_LEN).collect(),
+        height: metrics.height,
+      });
+    }
+
+    Ok(Self {
+      document_id: document_id.to_owned(),
+      has_more: summary_blocks.len() < number_of_blocks,
+      blocks: summary_blocks,
+      updated_at: timestamp(),
+    })
+  }
+
+  /// Returns true if both summaries show the same blocks, regardless of when they were
+  /// generated.
+  pub fn is_same_layout(&self, other: &DocumentLayoutSummary) -> bool {
+    self.blocks == other.blocks && self.has_more == other.has_more
+  }
+}
+
+/// Persists the layout summary of each document. The summary is removed when the document
+/// gets edited and saved again when the document is closed or loaded.
+pub struct DocumentLayoutCache {
+  database: Arc<dyn DocumentDatabase>,
+  /// The documents whose summary was removed since it was last saved, the following edits
+  /// don't need to touch the disk.
+  invalidated: DashSet<String>,
+}
+
+impl DocumentLayoutCache {
+  pub fn new(database: Arc<dyn DocumentDatabase>) -> Self {
+    Self {
+      database,
+      invalidated: DashSet::new(),
+    }
+  }
+
+  pub fn get(&self, document_id: &str) -> FlowyResult<Option<DocumentLayoutSummary>> {
+    let pool = self.database.db_pool()?;
+    let conn = pool.get().map_err(internal_error)?;
+    let record = document_layout_cache_table::dsl::document_layout_cache_table
+      .filter(document_layout_cache_table::document_id.eq(document_id))
+      .first::<DocumentLayoutRecord>(&*conn)
+      .optional()?;
+
+    let record = match record {
+      None => return Ok(None),
+      Some(record) => record,
+    };
+    // The cache is generated again if it can't be read, e.g. it was written by another version
+    match serde_json::from_str::<Vec<DocumentLayoutBlock>>(&record.blocks) {
+      Ok(blocks) => Ok(Some(DocumentLayoutSummary {
+        document_id: record.document_id,
+        blocks,
+        has_more: record.has_more,
+        updated_at: record.updated_at,
+      })),
+      Err(err) => {
+        tracing::warn!(
+          "Read the layout summary of {} failed: {:?}",
+          document_id,
+          err
+        );
+        Ok(None)
+      },
+    }
+  }
+
+  pub fn save(&self, summary: &DocumentLayoutSummary) -> FlowyResult<()> {
+    let pool = self.database.db_pool()?;
+    let conn = pool.get().map_err(internal_error)?;
+    let record = DocumentLayoutRecord {
+      document_id: summary.document_id.clone(),
+      blocks: serde_json::to_string(&summary.blocks)?,
+      has_more: summary.has_more,
+      updated_at: summary.updated_at,
+    };
+    let _ = diesel::replace_into(document_layout_cache_table::table)
+      .values(record)
+      .execute(&*conn)?;
+    self.invalidated.remove(&summary.document_id);
+    Ok(())
+  }
+
+  /// Removes the summary of the document, it's called on each edit of the document.
+  pub fn invalidate(&self, document_id: &str) -> FlowyResult<()> {
+    if self.invalidated.contains(document_id) {
+      return Ok(());
+    }
+    let pool = self.database.db_pool()?;
+    let conn = pool.get().map_err(internal_error)?;
+    diesel::delete(
+      document_layout_cache_table::dsl::document_layout_cache_table
+        .filter(document_layout_cache_table::document_id.eq(document_id)),
+    )
+    .execute(&*conn)?;
+    self.invalidated.insert(document_id.to_owned());
+    Ok(())
+  }
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable, Associations)]
+#[table_name = "document_layout_cache_table"]
+#[primary_key(document_id)]
+struct DocumentLayoutRecord {
+  document_id: String,
+  blocks: String,
+  has_more: bool,
+  updated_at: i64,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn document(texts: &[&str]) -> String {
+    let children = texts
+      .iter()
+      .map(|text| format!(r#"{{"type":"text","delta":[{{"insert":"{}"}}]}}"#, text))
+      .collect::<Vec<_>>()
+      .join(",");
+    format!(
+      r#"{{"document":{{"type":"editor","children":[{}]}}}}"#,
+      children
+    )
+  }
+
+  #[test]
+  fn layout_summary_test() {
+    let summary =
+      DocumentLayoutSummary::from_exported_content("doc_1", &document(&["Hello", "world"]))
+        .unwrap();
+    assert_eq!(summary.blocks.len(), 2);
+    assert_eq!(summary.blocks[0].text, "Hello");
+    assert_eq!(summary.blocks[1].path, vec![1]);
+    assert!(summary.blocks[0].height > 0.0);
+    assert!(!summary.has_more);
+
+    let other =
+      DocumentLayoutSummary::from_exported_content("doc_1", &document(&["Hello", "world"]))
+        .unwrap();
+    assert!(summary.is_same_layout(&other));
+    let other =
+      DocumentLayoutSummary::from_exported_content("doc_1", &document(&["Hello"])).unwrap();
+    assert!(!summary.is_same_layout(&other));
+  }
+
+  #[test]
+  fn layout_summary_first_screen_test() {
+    let texts = vec!["line"; 1000];
+    let summary = DocumentLayoutSummary::from_exported_content("doc_1", &document(&texts)).unwrap();
+    assert!(summary.has_more);
+    assert!(summary.blocks.len() < texts.len());
+
+    let height = summary.blocks.iter().map(|block| block.height).sum::<f64>();
+    let last_height = summary.blocks.last().unwrap().height;
+    assert!(height >= SCREEN_HEIGHT);
+    assert!(height - last_height < SCREEN_HEIGHT);
+  }
+}
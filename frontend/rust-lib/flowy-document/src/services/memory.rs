@@ -0,0 +1,62 @@
+use lib_ot::core::{AttributeHashMap, Body, NodeId, NodeTree};
+use lib_ot::text_delta::{DeltaTextOperation, DeltaTextOperations};
+
+/// Returns the estimated bytes of the operations in memory, including the inserted text and
+/// the attributes they point to on the heap.
+pub(crate) fn estimated_operations_bytes(operations: &DeltaTextOperations) -> usize {
+  operations
+    .ops
+    .iter()
+    .map(|operation| {
+      let heap_bytes = match operation {
+        DeltaTextOperation::Delete(_) => 0,
+        DeltaTextOperation::Retain(retain) => estimated_attributes_bytes(&retain.attributes),
+        DeltaTextOperation::Insert(insert) => {
+          insert.s.0.capacity() + estimated_attributes_bytes(&insert.attributes)
+        },
+      };
+      std::mem::size_of::<DeltaTextOperation>() + heap_bytes
+    })
+    .sum()
+}
+
+/// Returns the estimated bytes of the nodes of the tree in memory, including their text.
+pub(crate) fn estimated_node_tree_bytes(tree: &NodeTree) -> usize {
+  estimated_node_bytes(tree, tree.root_node_id())
+}
+
+fn estimated_node_bytes(tree: &NodeTree, node_id: NodeId) -> usize {
+  let node_bytes = match tree.get_node(node_id) {
+    None => 0,
+    Some(node) => {
+      let body_bytes = match &node.body {
+        Body::Empty => 0,
+        Body::Delta(operations) => estimated_operations_bytes(operations),
+      };
+      std::mem::size_of_val(node)
+        + node.node_type.capacity()
+        + estimated_attributes_bytes(&node.attributes)
+        + body_bytes
+    },
+  };
+  let children_bytes = tree
+    .get_children_ids(node_id)
+    .into_iter()
+    .map(|child_id| estimated_node_bytes(tree, child_id))
+    .sum::<usize>();
+  node_bytes + children_bytes
+}
+
+fn estimated_attributes_bytes(attributes: &AttributeHashMap) -> usize {
+  attributes
+    .iter()
+    .map(|(key, value)| {
+      key.capacity()
+        + value
+          .value
+          .as_ref()
+          .map(|value| value.capacity())
+          .unwrap_or(0)
+    })
+    .sum()
+}
@@ -1,4 +1,12 @@
+mod gc;
+mod layout_cache;
+mod memory;
 mod migration;
 mod persistence;
+mod print;
 
+pub use gc::*;
+pub use layout_cache::*;
+pub(crate) use memory::*;
 pub use persistence::*;
+pub use print::*;
@@ -0,0 +1,528 @@
+use flowy_error::{FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The average width of a character relative to the font size. The lines of the text are
+/// estimated with it, the widths of the rendered characters vary with the font, so the rendered
+/// text might wrap into a few more or fewer lines.
+const CHAR_WIDTH_RATIO: f64 = 0.5;
+/// The height of an image relative to the width of the page content
+const IMAGE_ASPECT_RATIO: f64 = 0.5625;
+
+/// The page that the document is printed on. All the sizes are in points.
+#[derive(Debug, Clone)]
+pub struct PrintPageLayout {
+  pub page_width: f64,
+  pub page_height: f64,
+  pub margin: f64,
+  pub font_size: f64,
+  /// The height of a line of the body text
+  pub line_height: f64,
+}
+
+impl std::default::Default for PrintPageLayout {
+  /// The A4 paper with one inch margins
+  fn default() -> Self {
+    Self {
+      page_width: 595.0,
+      page_height: 842.0,
+      margin: 72.0,
+      font_size: 12.0,
+      line_height: 18.0,
+    }
+  }
+}
+
+impl PrintPageLayout {
+  pub fn validate(&self) -> FlowyResult<()> {
+    let values = [
+      self.page_width,
+      self.page_height,
+      self.font_size,
+      self.line_height,
+    ];
+    if values
+      .iter()
+      .any(|value| !value.is_finite() || *value <= 0.0)
+      || !self.margin.is_finite()
+      || self.margin < 0.0
+      || self.content_width() <= 0.0
+      || self.content_height() <= 0.0
+    {
+      return Err(FlowyError::invalid_data().context(format!("Invalid page layout: {:?}", self)));
+    }
+    Ok(())
+  }
+
+  fn content_width(&self) -> f64 {
+    self.page_width - 2.0 * self.margin
+  }
+
+  fn content_height(&self) -> f64 {
+    self.page_height - 2.0 * self.margin
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PrintBlockKind {
+  Text,
+  /// The level of the heading, starting from 1. The levels above 3 are printed like the third
+  /// level.
+  Heading(u8),
+  Image,
+  Divider,
+  /// Forces the next block to start on a new page
+  PageBreak,
+}
+
+impl PrintBlockKind {
+  /// The heading is rendered with a larger font, so it takes more space for each line.
+  fn font_scale(&self) -> f64 {
+    match self {
+      PrintBlockKind::Heading(1) => 2.0,
+      PrintBlockKind::Heading(2) => 1.5,
+      PrintBlockKind::Heading(_) => 1.25,
+      _ => 1.0,
+    }
+  }
+}
+
+/// A block of the document in the order it gets printed.
+#[derive(Debug, Clone)]
+pub struct PrintBlock {
+  /// The path of the node in the document. It's the index of the line for the documents
+  /// that are built from `Delta`.
+  pub path: Vec<usize>,
+  pub kind: PrintBlockKind,
+  pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintBlockMetrics {
+  pub height: f64,
+  /// The number of lines of the text blocks, the other blocks have one line
+  pub line_count: usize,
+}
+
+/// The position where a page starts. The page starts in the middle of the block if the
+/// `start_line` is not zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrintPageStart {
+  pub start_block: usize,
+  pub start_line: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct PrintPagination {
+  pub blocks: Vec<(PrintBlock, PrintBlockMetrics)>,
+  pub pages: Vec<PrintPageStart>,
+}
+
+/// Computes the pagination of the exported `content`. The content is either the JSON of the
+/// node tree or the JSON of the `Delta`, depending on the version of the document.
+pub fn paginate_document(content: &str, layout: &PrintPageLayout) -> FlowyResult<PrintPagination> {
+  layout.validate()?;
+  let blocks = blocks_from_exported_content(content)?;
+  let blocks = blocks
+    .into_iter()
+    .map(|block| {
+      let metrics = measure_block(&block, layout);
+      (block, metrics)
+    })
+    .collect::<Vec<_>>();
+  let pages = paginate_blocks(&blocks, layout);
+  Ok(PrintPagination { blocks, pages })
+}
+
+pub fn blocks_from_exported_content(content: &str) -> FlowyResult<Vec<PrintBlock>> {
+  let value: Value = serde_json::from_str(content)?;
+  let mut blocks = vec![];
+  match &value {
+    Value::Object(object) => {
+      let document = object
+        .get("document")
+        .ok_or_else(|| FlowyError::invalid_data().context("Missing the document node"))?;
+      collect_node_blocks(document, &mut vec![], &mut blocks)?;
+    },
+    Value::Array(operations) => collect_delta_blocks(operations, &mut blocks)?,
+    _ => return Err(FlowyError::invalid_data().context("Unexpected document content")),
+  }
+  Ok(blocks)
+}
+
+/// Flattens the children of the node depth first. The root node itself is not printed.
+fn collect_node_blocks(
+  node: &Value,
+  path: &mut Vec<usize>,
+  blocks: &mut Vec<PrintBlock>,
+) -> FlowyResult<()> {
+  let children = match node
+    .get("children")
+    .and_then(|children| children.as_array())
+  {
+    None => return Ok(()),
+    Some(children) => children,
+  };
+
+  for (index, child) in children.iter().enumerate() {
+    path.push(index);
+    let attributes = child.get("attributes");
+    let subtype = attributes
+      .and_then(|attributes| attributes.get("subtype"))
+      .and_then(|subtype| subtype.as_str());
+    let kind = match (child.get("type").and_then(|ty| ty.as_str()), subtype) {
+      (Some("image"), _) | (Some("cover"), _) => PrintBlockKind::Image,
+      (Some("divider"), _) => PrintBlockKind::Divider,
+      (Some("page_break"), _) => PrintBlockKind::PageBreak,
+      (_, Some("heading")) => {
+        let level = attributes
+          .and_then(|attributes| attributes.get("heading"))
+          .and_then(|heading| heading.as_str())
+          .and_then(|heading| heading.trim_start_matches('h').parse::<u64>().ok())
+          .unwrap_or(1);
+        heading_kind(level)?
+      },
+      _ => PrintBlockKind::Text,
+    };
+    let text = child
+      .get("delta")
+      .and_then(|delta| delta.as_array())
+      .map(|operations| {
+        operations
+          .iter()
+          .filter_map(|operation| operation.get("insert").and_then(|insert| insert.as_str()))
+          .collect::<String>()
+      })
+      .unwrap_or_default();
+
+    blocks.push(PrintBlock {
+      path: path.clone(),
+      kind,
+      text,
+    });
+    collect_node_blocks(child, path, blocks)?;
+    path.pop();
+  }
+  Ok(())
+}
+
+/// Each line of the `Delta` is a block, the attributes of the newline are the attributes of
+/// the line. An embedded image takes its own block.
+fn collect_delta_blocks(operations: &[Value], blocks: &mut Vec<PrintBlock>) -> FlowyResult<()> {
+  let mut text = String::new();
+  // The newline after the image ends the line of the image, it's not an empty line.
+  let mut is_embed_line = false;
+  for operation in operations {
+    let insert = match operation.get("insert") {
+      None => continue,
+      Some(insert) => insert,
+    };
+    match insert.as_str() {
+      None => {
+        if insert.get("image").is_some() {
+          if !text.is_empty() {
+            push_delta_block(PrintBlockKind::Text, std::mem::take(&mut text), blocks);
+          }
+          push_delta_block(PrintBlockKind::Image, String::new(), blocks);
+          is_embed_line = true;
+        }
+      },
+      Some(s) => {
+        let level = operation
+          .get("attributes")
+          .and_then(|attributes| attributes.get("header"))
+          .and_then(|header| header.as_u64());
+        let mut lines = s.split('\n').peekable();
+        while let Some(line) = lines.next() {
+          text.push_str(line);
+          // The last segment is not ended by a newline
+          if lines.peek().is_none() {
+            break;
+          }
+          if !(is_embed_line && text.is_empty()) {
+            let kind = match level {
+              Some(level) => heading_kind(level)?,
+              None => PrintBlockKind::Text,
+            };
+            push_delta_block(kind, std::mem::take(&mut text), blocks);
+          }
+          is_embed_line = false;
+        }
+      },
+    }
+  }
+
+  if !text.is_empty() {
+    push_delta_block(PrintBlockKind::Text, text, blocks);
+  }
+  Ok(())
+}
+
+/// The levels of the headings start from 1
+fn heading_kind(level: u64) -> FlowyResult<PrintBlockKind> {
+  match u8::try_from(level) {
+    Ok(level) if level > 0 => Ok(PrintBlockKind::Heading(level)),
+    _ => Err(FlowyError::invalid_data().context(format!("Invalid heading level: {}", level))),
+  }
+}
+
+fn push_delta_block(kind: PrintBlockKind, text: String, blocks: &mut Vec<PrintBlock>) {
+  let path = vec![blocks.len()];
+  blocks.push(PrintBlock { path, kind, text });
+}
+
+pub fn measure_block(block: &PrintBlock, layout: &PrintPageLayout) -> PrintBlockMetrics {
+  match block.kind {
+    PrintBlockKind::PageBreak => PrintBlockMetrics {
+      height: 0.0,
+      line_count: 0,
+    },
+    PrintBlockKind::Divider => PrintBlockMetrics {
+      height: layout.line_height,
+      line_count: 1,
+    },
+    PrintBlockKind::Image => PrintBlockMetrics {
+      height: (layout.content_width() * IMAGE_ASPECT_RATIO).min(layout.content_height()),
+      line_count: 1,
+    },
+    PrintBlockKind::Text | PrintBlockKind::Heading(_) => {
+      let scale = block.kind.font_scale();
+      let char_width = layout.font_size * scale * CHAR_WIDTH_RATIO;
+      let chars_per_line = ((layout.content_width() / char_width).floor() as usize).max(1);
+      let line_count = block
+        .text
+        .split('\n')
+        .map(|line| {
+          let chars = line.chars().count();
+          ((chars + chars_per_line - 1) / chars_per_line).max(1)
+        })
+        .sum::<usize>();
+      PrintBlockMetrics {
+        height: line_count as f64 * layout.line_height * scale,
+        line_count,
+      }
+    },
+  }
+}
+
+/// Places the blocks on the pages in order. The text blocks are broken between the lines if
+/// they don't fit the rest of the page, the other blocks are moved to the next page.
+pub fn paginate_blocks(
+  blocks: &[(PrintBlock, PrintBlockMetrics)],
+  layout: &PrintPageLayout,
+) -> Vec<PrintPageStart> {
+  let content_height = layout.content_height();
+  let mut pages = vec![PrintPageStart {
+    start_block: 0,
+    start_line: 0,
+  }];
+  let mut used = 0.0;
+
+  for (index, (block, metrics)) in blocks.iter().enumerate() {
+    match block.kind {
+      PrintBlockKind::PageBreak => {
+        if used > 0.0 && index + 1 < blocks.len() {
+          pages.push(PrintPageStart {
+            start_block: index + 1,
+            start_line: 0,
+          });
+          used = 0.0;
+        }
+      },
+      PrintBlockKind::Text | PrintBlockKind::Heading(_) => {
+        let line_height = metrics.height / metrics.line_count as f64;
+        let mut line = 0;
+        loop {
+          let remaining_lines = metrics.line_count - line;
+          let fit_lines = ((content_height - used) / line_height).floor().max(0.0) as usize;
+          if fit_lines >= remaining_lines {
+            used += remaining_lines as f64 * line_height;
+            break;
+          }
+
+          // Keep at least one line on each page even if the line is taller than the page
+          let placed_lines = if used == 0.0 {
+            fit_lines.max(1)
+          } else {
+            fit_lines
+          };
+          line += placed_lines;
+          if line >= metrics.line_count {
+            used += placed_lines as f64 * line_height;
+            break;
+          }
+          pages.push(PrintPageStart {
+            start_block: index,
+            start_line: line,
+          });
+          used = 0.0;
+        }
+      },
+      PrintBlockKind::Image | PrintBlockKind::Divider => {
+        if used > 0.0 && used + metrics.height > content_height {
+          pages.push(PrintPageStart {
+            start_block: index,
+            start_line: 0,
+          });
+          used = 0.0;
+        }
+        used += metrics.height;
+      },
+    }
+  }
+  pages
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::services::{paginate_document, PrintBlockKind, PrintPageLayout, PrintPageStart};
+
+  /// The content is 100 points high, so 10 lines of body text fit on a page and each line
+  /// has 20 characters.
+  fn small_layout() -> PrintPageLayout {
+    PrintPageLayout {
+      page_width: 140.0,
+      page_height: 120.0,
+      margin: 10.0,
+      font_size: 12.0,
+      line_height: 10.0,
+    }
+  }
+
+  fn text_node(text: &str) -> String {
+    format!(r#"{{"type":"text","delta":[{{"insert":"{}"}}]}}"#, text)
+  }
+
+  fn document(children: Vec<String>) -> String {
+    format!(
+      r#"{{"document":{{"type":"editor","children":[{}]}}}}"#,
+      children.join(",")
+    )
+  }
+
+  #[test]
+  fn node_document_blocks_test() {
+    let content = document(vec![
+      r#"{"type":"text","attributes":{"subtype":"heading","heading":"h2"},"delta":[{"insert":"Title"}]}"#.to_owned(),
+      r#"{"type":"text","delta":[{"insert":"Parent"}],"children":[{"type":"text","delta":[{"insert":"Child"}]}]}"#.to_owned(),
+      r#"{"type":"divider"}"#.to_owned(),
+    ]);
+    let pagination = paginate_document(&content, &small_layout()).unwrap();
+    let blocks = pagination
+      .blocks
+      .iter()
+      .map(|(block, _)| (block.path.clone(), block.kind.clone(), block.text.clone()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      blocks,
+      vec![
+        (vec![0], PrintBlockKind::Heading(2), "Title".to_owned()),
+        (vec![1], PrintBlockKind::Text, "Parent".to_owned()),
+        (vec![1, 0], PrintBlockKind::Text, "Child".to_owned()),
+        (vec![2], PrintBlockKind::Divider, "".to_owned()),
+      ]
+    );
+    assert_eq!(pagination.blocks[0].1.height, 15.0);
+    assert_eq!(pagination.pages.len(), 1);
+  }
+
+  #[test]
+  fn delta_document_blocks_test() {
+    let content = r#"[{"insert":"Title"},{"insert":"\n","attributes":{"header":1}},{"insert":"Hello\nWorld"},{"insert":{"image":"https://appflowy.io/logo.png"}},{"insert":"\n"}]"#;
+    let pagination = paginate_document(content, &small_layout()).unwrap();
+    let kinds = pagination
+      .blocks
+      .iter()
+      .map(|(block, _)| block.kind.clone())
+      .collect::<Vec<_>>();
+    assert_eq!(
+      kinds,
+      vec![
+        PrintBlockKind::Heading(1),
+        PrintBlockKind::Text,
+        PrintBlockKind::Text,
+        PrintBlockKind::Image,
+      ]
+    );
+  }
+
+  #[test]
+  fn long_text_is_broken_between_lines_test() {
+    // 25 lines of 20 characters
+    let content = document(vec![text_node(&"a".repeat(20 * 25))]);
+    let pagination = paginate_document(&content, &small_layout()).unwrap();
+    assert_eq!(pagination.blocks[0].1.line_count, 25);
+    assert_eq!(
+      pagination.pages,
+      vec![
+        PrintPageStart {
+          start_block: 0,
+          start_line: 0
+        },
+        PrintPageStart {
+          start_block: 0,
+          start_line: 10
+        },
+        PrintPageStart {
+          start_block: 0,
+          start_line: 20
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn image_and_page_break_start_new_page_test() {
+    // The image is 120 * 0.5625 = 67.5 points high, it doesn't fit after 4 lines.
+    let content = document(vec![
+      text_node(&"a".repeat(20 * 4)),
+      r#"{"type":"image"}"#.to_owned(),
+      r#"{"type":"page_break"}"#.to_owned(),
+      text_node("b"),
+    ]);
+    let pagination = paginate_document(&content, &small_layout()).unwrap();
+    assert_eq!(
+      pagination.pages,
+      vec![
+        PrintPageStart {
+          start_block: 0,
+          start_line: 0
+        },
+        PrintPageStart {
+          start_block: 1,
+          start_line: 0
+        },
+        PrintPageStart {
+          start_block: 3,
+          start_line: 0
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn invalid_page_layout_test() {
+    let layout = PrintPageLayout {
+      margin: 100.0,
+      ..small_layout()
+    };
+    assert!(paginate_document(&document(vec![]), &layout).is_err());
+
+    let layout = PrintPageLayout {
+      margin: f64::NAN,
+      ..small_layout()
+    };
+    assert!(paginate_document(&document(vec![]), &layout).is_err());
+  }
+
+  #[test]
+  fn invalid_heading_level_test() {
+    let content = document(vec![
+      r#"{"type":"text","attributes":{"subtype":"heading","heading":"h0"},"delta":[{"insert":"Title"}]}"#.to_owned(),
+    ]);
+    assert!(paginate_document(&content, &small_layout()).is_err());
+
+    let content = r#"[{"insert":"Title"},{"insert":"\n","attributes":{"header":0}}]"#;
+    assert!(paginate_document(content, &small_layout()).is_err());
+  }
+}
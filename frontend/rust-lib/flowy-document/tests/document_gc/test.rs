@@ -0,0 +1,42 @@
+use flowy_document::entities::DocumentVersionPB;
+use flowy_document::DocumentConfig;
+use flowy_test::helper::ViewTest;
+use flowy_test::FlowySDKTest;
+use std::time::Duration;
+
+async fn make_document_view(gc_retention: Duration) -> (FlowySDKTest, ViewTest) {
+  let sdk = FlowySDKTest::new_with_document_config(DocumentConfig {
+    version: DocumentVersionPB::V1,
+    gc_retention,
+  });
+  let _ = sdk.init_user().await;
+  let test = ViewTest::new_document_view(&sdk).await;
+  (sdk, test)
+}
+
+#[tokio::test]
+async fn collect_deleted_document_test() {
+  let (sdk, test) = make_document_view(Duration::ZERO).await;
+  let document_manager = sdk.document_manager.clone();
+  assert!(document_manager.preview_garbage().await.unwrap().is_empty());
+
+  test.delete_view_permanently().await;
+  let garbage = document_manager.preview_garbage().await.unwrap();
+  assert_eq!(garbage.len(), 1);
+  assert_eq!(garbage[0].document_id, test.view.id);
+  assert!(garbage[0].bytes > 0);
+
+  let collected = document_manager.collect_garbage().await.unwrap();
+  assert_eq!(collected.len(), 1);
+  assert!(document_manager.preview_garbage().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn keep_deleted_document_within_retention_test() {
+  let (sdk, test) = make_document_view(Duration::from_secs(60 * 60)).await;
+  let document_manager = sdk.document_manager.clone();
+  test.delete_view_permanently().await;
+
+  assert!(document_manager.preview_garbage().await.unwrap().is_empty());
+  assert!(document_manager.collect_garbage().await.unwrap().is_empty());
+}
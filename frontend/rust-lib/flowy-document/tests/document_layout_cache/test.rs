@@ -0,0 +1,67 @@
+use flowy_document::entities::{DocumentVersionPB, EditParams};
+use flowy_test::helper::ViewTest;
+use flowy_test::FlowySDKTest;
+
+#[tokio::test]
+async fn document_layout_summary_test() {
+  let sdk = FlowySDKTest::new(DocumentVersionPB::V1);
+  let _ = sdk.init_user().await;
+  let test = ViewTest::new_document_view(&sdk).await;
+  let document_manager = sdk.document_manager.clone();
+  let doc_id = test.view.id.clone();
+  assert!(document_manager
+    .get_layout_summary(&doc_id)
+    .unwrap()
+    .is_none());
+
+  let summary = document_manager
+    .reconcile_layout_summary(&doc_id)
+    .await
+    .unwrap();
+  assert_eq!(summary.blocks.len(), 1);
+  assert!(!summary.has_more);
+  let cached_summary = document_manager
+    .get_layout_summary(&doc_id)
+    .unwrap()
+    .unwrap();
+  assert!(cached_summary.is_same_layout(&summary));
+}
+
+#[tokio::test]
+async fn document_layout_summary_invalidate_test() {
+  let sdk = FlowySDKTest::new(DocumentVersionPB::V1);
+  let _ = sdk.init_user().await;
+  let test = ViewTest::new_document_view(&sdk).await;
+  let document_manager = sdk.document_manager.clone();
+  let doc_id = test.view.id.clone();
+  document_manager
+    .reconcile_layout_summary(&doc_id)
+    .await
+    .unwrap();
+
+  // The summary is removed after the document was edited
+  let _ = document_manager
+    .open_document_editor(&doc_id)
+    .await
+    .unwrap();
+  let params = EditParams {
+    doc_id: doc_id.clone(),
+    operations: r#"{"operations":[{"op":"update_text","path":[0,0],"delta":[{"insert":"Hello"}],"inverted":[{"delete":5}]}]}"#.to_owned(),
+  };
+  document_manager.apply_edit(params).await.unwrap();
+  assert!(document_manager
+    .get_layout_summary(&doc_id)
+    .unwrap()
+    .is_none());
+
+  // And saved again when the document is closed
+  document_manager
+    .close_document_editor(&doc_id)
+    .await
+    .unwrap();
+  let summary = document_manager
+    .get_layout_summary(&doc_id)
+    .unwrap()
+    .unwrap();
+  assert_eq!(summary.blocks[0].text, "Hello");
+}
@@ -1,3 +1,5 @@
+mod document_gc;
+mod document_layout_cache;
 mod editor;
 mod new_document;
 // mod old_document;
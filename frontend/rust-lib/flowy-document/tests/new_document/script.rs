@@ -1,6 +1,10 @@
 use flowy_document::editor::{AppFlowyDocumentEditor, Document, DocumentTransaction};
 
-use flowy_document::entities::DocumentVersionPB;
+use flowy_document::entities::{
+  DocumentVersionPB, PrintPageLayoutPB, PrintPaginationPB, PrintPaginationPayloadPB,
+};
+use flowy_document::event_map::DocumentEvent::GetPrintPagination;
+use flowy_test::event_builder::FolderEventBuilder;
 use flowy_test::helper::ViewTest;
 use flowy_test::FlowySDKTest;
 use lib_ot::core::{Changeset, NodeDataBuilder, NodeOperation, Path, Transaction};
@@ -32,10 +36,15 @@ pub enum EditScript {
   AssertPrettyContent {
     expected: &'static str,
   },
+  AssertPrintPageCount {
+    page_layout: PrintPageLayoutPB,
+    expected: usize,
+  },
 }
 
 pub struct DocumentEditorTest {
   pub sdk: FlowySDKTest,
+  pub view_id: String,
   pub editor: Arc<AppFlowyDocumentEditor>,
 }
 
@@ -59,7 +68,11 @@ impl DocumentEditorTest {
       Some(editor) => editor.clone(),
     };
 
-    Self { sdk, editor }
+    Self {
+      sdk,
+      view_id: test.view.id,
+      editor,
+    }
   }
 
   pub async fn run_scripts(&self, scripts: Vec<EditScript>) {
@@ -125,6 +138,22 @@ impl DocumentEditorTest {
         let content = self.editor.get_content(true).await.unwrap();
         assert_eq!(content, expected);
       },
+      EditScript::AssertPrintPageCount {
+        page_layout,
+        expected,
+      } => {
+        let payload = PrintPaginationPayloadPB {
+          view_id: self.view_id.clone(),
+          page_layout: Some(page_layout),
+        };
+        let pagination = FolderEventBuilder::new(self.sdk.clone())
+          .event(GetPrintPagination)
+          .payload(payload)
+          .async_send()
+          .await
+          .parse::<PrintPaginationPB>();
+        assert_eq!(pagination.pages.len(), expected);
+      },
     }
   }
 }
@@ -1,5 +1,6 @@
 use crate::new_document::script::DocumentEditorTest;
 use crate::new_document::script::EditScript::*;
+use flowy_document::entities::PrintPageLayoutPB;
 
 use lib_ot::text_delta::DeltaTextOperationBuilder;
 
@@ -162,3 +163,29 @@ async fn document_delete_node_test() {
 
   DocumentEditorTest::new().await.run_scripts(scripts).await;
 }
+
+#[tokio::test]
+async fn document_print_pagination_test() {
+  // Each page has 10 lines of 20 characters
+  let page_layout = PrintPageLayoutPB {
+    page_width: 140.0,
+    page_height: 120.0,
+    margin: 10.0,
+    font_size: 12.0,
+    line_height: 10.0,
+  };
+  let scripts = vec![
+    UpdateText {
+      path: vec![0, 0].into(),
+      delta: DeltaTextOperationBuilder::new()
+        .insert(&"a".repeat(20 * 25))
+        .build(),
+    },
+    AssertPrintPageCount {
+      page_layout,
+      expected: 3,
+    },
+  ];
+
+  DocumentEditorTest::new().await.run_scripts(scripts).await;
+}
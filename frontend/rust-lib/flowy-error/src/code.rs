@@ -189,10 +189,145 @@ pub enum ErrorCode {
 
   #[error("Only the date type can be used in calendar")]
   UnexpectedCalendarFieldType = 61,
+
+  #[error("The view is locked or shared as read-only")]
+  PermissionDenied = 62,
+
+  #[error("The cell data exceeds the size limit")]
+  CellDataTooLarge = 63,
+
+  #[error("A view with the same name already exists")]
+  ViewNameDuplicated = 64,
+
+  #[error("The passphrase of the archive is wrong")]
+  InvalidPassphrase = 65,
+
+  #[error("The name of the macro is empty")]
+  MacroNameIsEmpty = 66,
+
+  #[error("The cell is in conflict and can't be edited until the conflict is resolved")]
+  CellIsConflicted = 67,
+
+  #[error("The slug should only contain lowercase letters, digits and hyphens")]
+  ViewSlugInvalid = 68,
+
+  #[error("The slug is used by another view of the workspace")]
+  ViewSlugDuplicated = 69,
+
+  #[error("The file exceeds the max attachment size of the workspace")]
+  AttachmentTooLarge = 70,
+
+  #[error("The database reached the max number of rows of the workspace")]
+  RowLimitExceeded = 71,
+
+  #[error("The workspace reached its max storage size")]
+  WorkspaceSizeExceeded = 72,
+
+  #[error("The title of the notification is empty")]
+  InboxTitleIsEmpty = 73,
+
+  #[error("The phone number is invalid")]
+  InvalidPhoneNumber = 74,
+
+  #[error("The duration is invalid")]
+  InvalidDuration = 75,
+
+  #[error("The row or the database is linked by the relation cells of other rows")]
+  RelationDependentsExist = 76,
+
+  #[error("The option can't be selected with the options selected in the field it depends on")]
+  SelectOptionNotAllowed = 77,
+
+  #[error("The databases don't have any field with the same name and type")]
+  DatabaseSchemaNotMatch = 78,
+
+  #[error("The local revisions are in conflict with the remote ones")]
+  RevisionConflict = 79,
+
+  #[error("The stored data is corrupted and can't be read")]
+  DataCorrupted = 80,
 }
 
 impl ErrorCode {
   pub fn value(&self) -> i32 {
     self.clone() as i32
   }
+
+  /// Returns how the user can recover from the error. The UI offers the matching action, e.g.
+  /// a retry button, instead of only showing the message.
+  pub fn recovery(&self) -> ErrorRecovery {
+    match self {
+      ErrorCode::HttpServerConnectError
+      | ErrorCode::ConnectRefused
+      | ErrorCode::ConnectTimeout
+      | ErrorCode::ConnectClose
+      | ErrorCode::ConnectCancel
+      | ErrorCode::HttpError => ErrorRecovery::Retry,
+      ErrorCode::UserUnauthorized => ErrorRecovery::ReAuthenticate,
+      ErrorCode::CellIsConflicted | ErrorCode::RevisionConflict => ErrorRecovery::Sync,
+      ErrorCode::DataCorrupted => ErrorRecovery::DataCorruption,
+      _ => ErrorRecovery::None,
+    }
+  }
+}
+
+/// The machine-readable hint of [FlowyError](crate::FlowyError) about how to recover from it.
+#[derive(Debug, Clone, PartialEq, Eq, ProtoBuf_Enum)]
+#[repr(u8)]
+pub enum ErrorRecovery {
+  /// Nothing can be done besides fixing the input, e.g. the name of the view is empty
+  None = 0,
+  /// The request may succeed if it's sent again, e.g. the connection timed out
+  Retry = 1,
+  /// The user needs to sign in again
+  ReAuthenticate = 2,
+  /// The local data needs to be synced with the server before the request is sent again
+  Sync = 3,
+  /// The stored data can't be read, it needs to be restored from a backup or the server
+  DataCorruption = 4,
+}
+
+impl std::default::Default for ErrorRecovery {
+  fn default() -> Self {
+    ErrorRecovery::None
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::{ErrorCode, ErrorRecovery, FlowyError};
+
+  #[test]
+  fn error_code_recovery_test() {
+    assert_eq!(ErrorCode::ConnectTimeout.recovery(), ErrorRecovery::Retry);
+    assert_eq!(ErrorCode::HttpError.recovery(), ErrorRecovery::Retry);
+    assert_eq!(
+      ErrorCode::UserUnauthorized.recovery(),
+      ErrorRecovery::ReAuthenticate
+    );
+    assert_eq!(ErrorCode::RevisionConflict.recovery(), ErrorRecovery::Sync);
+    assert_eq!(
+      ErrorCode::DataCorrupted.recovery(),
+      ErrorRecovery::DataCorruption
+    );
+
+    // The invalid inputs can't be recovered from by the user, but by fixing the input
+    assert_eq!(ErrorCode::UserIdIsEmpty.recovery(), ErrorRecovery::None);
+    assert_eq!(ErrorCode::Serde.recovery(), ErrorRecovery::None);
+    assert_eq!(ErrorCode::ViewNameInvalid.recovery(), ErrorRecovery::None);
+  }
+
+  #[test]
+  fn flowy_error_recovery_test() {
+    let error = FlowyError::unauthorized();
+    assert_eq!(error.recovery, ErrorRecovery::ReAuthenticate);
+    assert!(!error.is_retryable());
+
+    let error = FlowyError::new(ErrorCode::ConnectTimeout, "timeout");
+    assert!(error.is_retryable());
+
+    let error = FlowyError::internal().with_recovery(ErrorRecovery::Retry);
+    assert_eq!(error.code, ErrorCode::Internal.value());
+    assert!(error.is_retryable());
+  }
 }
@@ -1,4 +1,4 @@
-use crate::code::ErrorCode;
+use crate::code::{ErrorCode, ErrorRecovery};
 use anyhow::Result;
 use flowy_derive::ProtoBuf;
 use std::fmt::Debug;
@@ -14,6 +14,17 @@ pub struct FlowyError {
 
   #[pb(index = 2)]
   pub msg: String,
+
+  /// The id of the event that returned this error. It's the `operation_id` field of the logs,
+  /// so the logs of the failed action can be found by it. Empty if the error was not returned
+  /// by an event.
+  #[pb(index = 3)]
+  pub operation_id: String,
+
+  /// How the user can recover from the error. It's the [ErrorCode::recovery] of the code unless
+  /// the place that returns the error knows better, see [FlowyError::with_recovery].
+  #[pb(index = 4)]
+  pub recovery: ErrorRecovery,
 }
 
 macro_rules! static_flowy_error {
@@ -30,6 +41,8 @@ impl FlowyError {
     Self {
       code: code.value() as i32,
       msg: msg.to_owned(),
+      operation_id: "".to_owned(),
+      recovery: code.recovery(),
     }
   }
   pub fn context<T: Debug>(mut self, error: T) -> Self {
@@ -37,10 +50,21 @@ impl FlowyError {
     self
   }
 
+  /// Overrides the recovery of the error code, e.g. an internal error that is known to be
+  /// temporary can be retried.
+  pub fn with_recovery(mut self, recovery: ErrorRecovery) -> Self {
+    self.recovery = recovery;
+    self
+  }
+
   pub fn is_record_not_found(&self) -> bool {
     self.code == ErrorCode::RecordNotFound.value()
   }
 
+  pub fn is_retryable(&self) -> bool {
+    self.recovery == ErrorRecovery::Retry
+  }
+
   static_flowy_error!(internal, ErrorCode::Internal);
   static_flowy_error!(record_not_found, ErrorCode::RecordNotFound);
   static_flowy_error!(workspace_name, ErrorCode::WorkspaceNameInvalid);
@@ -86,6 +110,26 @@ impl FlowyError {
     unexpect_calendar_field_type,
     ErrorCode::UnexpectedCalendarFieldType
   );
+  static_flowy_error!(permission_denied, ErrorCode::PermissionDenied);
+  static_flowy_error!(cell_data_too_large, ErrorCode::CellDataTooLarge);
+  static_flowy_error!(view_name_duplicated, ErrorCode::ViewNameDuplicated);
+  static_flowy_error!(invalid_passphrase, ErrorCode::InvalidPassphrase);
+  static_flowy_error!(macro_name_is_empty, ErrorCode::MacroNameIsEmpty);
+  static_flowy_error!(cell_is_conflicted, ErrorCode::CellIsConflicted);
+  static_flowy_error!(view_slug_duplicated, ErrorCode::ViewSlugDuplicated);
+  static_flowy_error!(attachment_too_large, ErrorCode::AttachmentTooLarge);
+  static_flowy_error!(row_limit_exceeded, ErrorCode::RowLimitExceeded);
+  static_flowy_error!(workspace_size_exceeded, ErrorCode::WorkspaceSizeExceeded);
+  static_flowy_error!(invalid_phone_number, ErrorCode::InvalidPhoneNumber);
+  static_flowy_error!(invalid_duration, ErrorCode::InvalidDuration);
+  static_flowy_error!(
+    relation_dependents_exist,
+    ErrorCode::RelationDependentsExist
+  );
+  static_flowy_error!(select_option_not_allowed, ErrorCode::SelectOptionNotAllowed);
+  static_flowy_error!(database_schema_not_match, ErrorCode::DatabaseSchemaNotMatch);
+  static_flowy_error!(revision_conflict, ErrorCode::RevisionConflict);
+  static_flowy_error!(data_corrupted, ErrorCode::DataCorrupted);
 }
 
 impl std::convert::From<ErrorCode> for FlowyError {
@@ -93,6 +137,8 @@ impl std::convert::From<ErrorCode> for FlowyError {
     FlowyError {
       code: code.value() as i32,
       msg: format!("{}", code),
+      operation_id: "".to_owned(),
+      recovery: code.recovery(),
     }
   }
 }
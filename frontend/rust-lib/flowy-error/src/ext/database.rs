@@ -1,4 +1,4 @@
-use crate::FlowyError;
+use crate::{ErrorRecovery, FlowyError};
 
 impl std::convert::From<flowy_sqlite::Error> for FlowyError {
   fn from(error: flowy_sqlite::Error) -> Self {
@@ -7,7 +7,10 @@ impl std::convert::From<flowy_sqlite::Error> for FlowyError {
 }
 
 impl std::convert::From<::r2d2::Error> for FlowyError {
+  /// The pool timed out waiting for a connection, e.g. all of them are busy.
   fn from(error: r2d2::Error) -> Self {
-    FlowyError::internal().context(error)
+    FlowyError::internal()
+      .context(error)
+      .with_recovery(ErrorRecovery::Retry)
   }
 }
@@ -1,10 +1,14 @@
 use crate::FlowyError;
 use bytes::Bytes;
-use lib_dispatch::prelude::{AFPluginEventResponse, ResponseBuilder};
+use lib_dispatch::prelude::{current_operation_id, AFPluginEventResponse, ResponseBuilder};
 use std::convert::TryInto;
 impl lib_dispatch::Error for FlowyError {
   fn as_response(&self) -> AFPluginEventResponse {
-    let bytes: Bytes = self.clone().try_into().unwrap();
+    let mut error = self.clone();
+    if error.operation_id.is_empty() {
+      error.operation_id = current_operation_id().unwrap_or_default();
+    }
+    let bytes: Bytes = error.try_into().unwrap();
     ResponseBuilder::Err().data(bytes).build()
   }
 }
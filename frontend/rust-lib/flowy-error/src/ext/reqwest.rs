@@ -1,8 +1,26 @@
-use crate::FlowyError;
-use reqwest::Error;
+use crate::{ErrorRecovery, FlowyError};
+use reqwest::{Error, StatusCode};
 
 impl std::convert::From<reqwest::Error> for FlowyError {
   fn from(error: Error) -> Self {
-    FlowyError::connection().context(error)
+    if error.status() == Some(StatusCode::UNAUTHORIZED) {
+      return FlowyError::unauthorized().context(error);
+    }
+
+    // Only the requests that didn't reach the server or failed on the server can be sent again
+    let is_retryable = error.is_timeout()
+      || error.is_connect()
+      || error
+        .status()
+        .map(|status| status.is_server_error())
+        .unwrap_or(false);
+    let recovery = if is_retryable {
+      ErrorRecovery::Retry
+    } else {
+      ErrorRecovery::None
+    };
+    FlowyError::connection()
+      .context(error)
+      .with_recovery(recovery)
   }
 }
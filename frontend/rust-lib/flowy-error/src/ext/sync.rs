@@ -1,4 +1,4 @@
-use crate::FlowyError;
+use crate::{ErrorRecovery, FlowyError};
 
 use flowy_client_sync::errors::ErrorCode;
 
@@ -6,7 +6,38 @@ impl std::convert::From<flowy_client_sync::errors::SyncError> for FlowyError {
   fn from(error: flowy_client_sync::errors::SyncError) -> Self {
     match error.code {
       ErrorCode::RecordNotFound => FlowyError::record_not_found().context(error.msg),
+      ErrorCode::RevisionConflict => FlowyError::revision_conflict().context(error.msg),
+      // The local revisions are missing, they can be pulled from the server again
+      ErrorCode::UnexpectedEmptyRevision => FlowyError::internal()
+        .context(error.msg)
+        .with_recovery(ErrorRecovery::Sync),
+      ErrorCode::SerdeError => FlowyError::serde().context(error.msg),
       _ => FlowyError::internal().context(error.msg),
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::{ErrorCode, ErrorRecovery, FlowyError};
+  use flowy_client_sync::errors::SyncError;
+
+  #[test]
+  fn sync_error_recovery_test() {
+    let error = FlowyError::from(SyncError::revision_conflict());
+    assert_eq!(error.code, ErrorCode::RevisionConflict.value());
+    assert_eq!(error.recovery, ErrorRecovery::Sync);
+
+    let error = FlowyError::from(SyncError::unexpected_empty_revision());
+    assert_eq!(error.code, ErrorCode::Internal.value());
+    assert_eq!(error.recovery, ErrorRecovery::Sync);
+
+    let error = FlowyError::from(SyncError::serde());
+    assert_eq!(error.code, ErrorCode::Serde.value());
+    assert_eq!(error.recovery, ErrorRecovery::None);
+
+    let error = FlowyError::from(SyncError::record_not_found());
+    assert_eq!(error.code, ErrorCode::RecordNotFound.value());
+    assert_eq!(error.recovery, ErrorRecovery::None);
+  }
+}
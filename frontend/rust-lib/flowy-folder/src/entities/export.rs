@@ -0,0 +1,65 @@
+use crate::errors::ErrorCode;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+#[derive(Eq, PartialEq, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum ExportScopePB {
+  /// The view, e.g. a database or a document
+  View = 0,
+  /// All the views of the workspace
+  Workspace = 1,
+}
+
+impl std::default::Default for ExportScopePB {
+  fn default() -> Self {
+    ExportScopePB::View
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ExportDataPayloadPB {
+  /// The id of the view, or the id of the workspace if the scope is [ExportScopePB::Workspace]
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub scope: ExportScopePB,
+
+  /// The path of the archive file. The archive is imported by the ImportData event with
+  /// [ImportTypePB::Archive](crate::entities::ImportTypePB::Archive)
+  #[pb(index = 3)]
+  pub file_path: String,
+
+  /// The archive is encrypted with the key derived from the passphrase if it's set
+  #[pb(index = 4, one_of)]
+  pub passphrase: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportDataParams {
+  pub id: String,
+  pub scope: ExportScopePB,
+  pub file_path: String,
+  pub passphrase: Option<String>,
+}
+
+impl TryInto<ExportDataParams> for ExportDataPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ExportDataParams, Self::Error> {
+    if self.id.trim().is_empty() || self.file_path.trim().is_empty() {
+      return Err(ErrorCode::UnexpectedEmptyString);
+    }
+    // An empty passphrase would encrypt the archive with a key that anyone can derive
+    if matches!(&self.passphrase, Some(passphrase) if passphrase.is_empty()) {
+      return Err(ErrorCode::InvalidPassphrase);
+    }
+
+    Ok(ExportDataParams {
+      id: self.id,
+      scope: self.scope,
+      file_path: self.file_path,
+      passphrase: self.passphrase,
+    })
+  }
+}
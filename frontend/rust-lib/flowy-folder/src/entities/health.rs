@@ -0,0 +1,72 @@
+use crate::entities::quota::QuotaUsagePB;
+use flowy_derive::ProtoBuf;
+
+/// The health report of the workspace. It's shown in the settings so that the user can tell
+/// why the data is not synced or the search results are outdated.
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct WorkspaceHealthPB {
+  /// The number of the local changes of the opened folder, documents and databases that were
+  /// not acked by the server yet. They are waiting for the connection if `is_connected` is
+  /// false.
+  #[pb(index = 1)]
+  pub sync_backlog: i64,
+
+  #[pb(index = 3)]
+  pub is_connected: bool,
+
+  /// The number of the background tasks that are waiting to be run
+  #[pb(index = 4)]
+  pub pending_tasks: i64,
+
+  /// The number of the background tasks that failed or timed out since the app was launched
+  #[pb(index = 5)]
+  pub failed_tasks: i64,
+
+  #[pb(index = 6)]
+  pub storage: QuotaUsagePB,
+
+  #[pb(index = 7)]
+  pub search_index: SearchIndexHealthPB,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct SearchIndexHealthPB {
+  /// False if the index was not opened yet
+  #[pb(index = 1)]
+  pub is_ready: bool,
+
+  #[pb(index = 2)]
+  pub number_of_views: i64,
+
+  /// The number of the changed views that are waiting to be indexed
+  #[pb(index = 3)]
+  pub pending_views: i64,
+
+  /// The timestamp in seconds of the last time the index was written. Zero if the index was
+  /// never written.
+  #[pb(index = 4)]
+  pub updated_at: i64,
+}
+
+/// The estimated bytes held in memory by the caches of the opened views and the search index.
+/// The estimates only count the data owned by the caches, the allocator's overhead is ignored.
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct MemoryReportPB {
+  /// The decoded cell data cached by the opened databases
+  #[pb(index = 1)]
+  pub cell_data_cache_bytes: i64,
+
+  /// The content of the opened documents
+  #[pb(index = 2)]
+  pub open_document_bytes: i64,
+
+  /// The filter results of the rows of the opened database views
+  #[pb(index = 3)]
+  pub filter_result_bytes: i64,
+
+  #[pb(index = 4)]
+  pub search_index_bytes: i64,
+
+  #[pb(index = 5)]
+  pub total_bytes: i64,
+}
@@ -0,0 +1,84 @@
+use crate::entities::parser::{app::AppIdentify, view::ViewName};
+use crate::entities::ViewLayoutTypePB;
+use crate::errors::ErrorCode;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use folder_model::gen_view_id;
+use std::convert::TryInto;
+
+#[derive(Eq, PartialEq, Hash, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum ImportTypePB {
+  CSV = 0,
+  /// The JSON export of a Trello board
+  TrelloJSON = 1,
+  /// The archive of the views that was created by the ExportData event. It's decrypted with the
+  /// passphrase of the payload if it's encrypted
+  Archive = 2,
+}
+
+impl std::default::Default for ImportTypePB {
+  fn default() -> Self {
+    ImportTypePB::CSV
+  }
+}
+
+impl ImportTypePB {
+  /// Returns the layout of the imported view. It's None for the archives, their views keep the
+  /// layouts they were exported with.
+  pub fn layout(&self) -> Option<ViewLayoutTypePB> {
+    match self {
+      ImportTypePB::CSV => Some(ViewLayoutTypePB::Grid),
+      ImportTypePB::TrelloJSON => Some(ViewLayoutTypePB::Board),
+      ImportTypePB::Archive => None,
+    }
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ImportPayloadPB {
+  #[pb(index = 1)]
+  pub belong_to_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  #[pb(index = 3)]
+  pub file_path: String,
+
+  #[pb(index = 4)]
+  pub import_type: ImportTypePB,
+
+  /// The passphrase of the encrypted archive
+  #[pb(index = 5, one_of)]
+  pub passphrase: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportParams {
+  pub belong_to_id: String,
+  pub name: String,
+  pub view_id: String,
+  pub file_path: String,
+  pub import_type: ImportTypePB,
+  pub passphrase: Option<String>,
+}
+
+impl TryInto<ImportParams> for ImportPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ImportParams, Self::Error> {
+    let belong_to_id = AppIdentify::parse(self.belong_to_id)?.0;
+    let name = ViewName::parse(self.name)?.0;
+    if self.file_path.trim().is_empty() {
+      return Err(ErrorCode::UnexpectedEmptyString);
+    }
+
+    Ok(ImportParams {
+      belong_to_id,
+      name,
+      view_id: gen_view_id(),
+      file_path: self.file_path,
+      import_type: self.import_type,
+      passphrase: self.passphrase,
+    })
+  }
+}
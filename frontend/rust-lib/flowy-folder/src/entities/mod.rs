@@ -1,10 +1,28 @@
 pub mod app;
+pub mod export;
+pub mod health;
+pub mod import;
+pub mod naming;
 mod parser;
+pub mod permission;
+pub mod quota;
+pub mod search;
+pub mod synthetic;
 pub mod trash;
 pub mod view;
 pub mod workspace;
+pub mod workspace_settings;
 
 pub use app::*;
+pub use export::*;
+pub use health::*;
+pub use import::*;
+pub use naming::*;
+pub use permission::*;
+pub use quota::*;
+pub use search::*;
+pub use synthetic::*;
 pub use trash::*;
 pub use view::*;
 pub use workspace::*;
+pub use workspace_settings::*;
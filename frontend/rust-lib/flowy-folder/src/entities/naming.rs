@@ -0,0 +1,83 @@
+use crate::entities::parser::workspace::WorkspaceIdentify;
+use crate::errors::ErrorCode;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+/// [ViewNamePolicyPB] decides what happens when a view is created or renamed with a name that
+/// is already used by another view under the same parent.
+#[derive(Eq, PartialEq, Hash, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum ViewNamePolicyPB {
+  /// The duplicate names are allowed.
+  AllowDuplicate = 0,
+  /// Creating or renaming a view with a duplicate name fails with the `ViewNameDuplicated` error.
+  Reject = 1,
+  /// The duplicate name gets a numbered suffix, e.g. "Untitled (1)".
+  AutoRename = 2,
+}
+
+impl std::default::Default for ViewNamePolicyPB {
+  fn default() -> Self {
+    ViewNamePolicyPB::AllowDuplicate
+  }
+}
+
+impl ViewNamePolicyPB {
+  pub fn value(&self) -> i64 {
+    *self as i64
+  }
+
+  pub fn from_value(value: i64) -> Self {
+    match value {
+      1 => ViewNamePolicyPB::Reject,
+      2 => ViewNamePolicyPB::AutoRename,
+      _ => ViewNamePolicyPB::AllowDuplicate,
+    }
+  }
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ViewNameSettingPB {
+  #[pb(index = 1)]
+  pub workspace_id: String,
+
+  #[pb(index = 2)]
+  pub policy: ViewNamePolicyPB,
+}
+
+pub struct ViewNameSettingParams {
+  pub workspace_id: String,
+  pub policy: ViewNamePolicyPB,
+}
+
+impl TryInto<ViewNameSettingParams> for ViewNameSettingPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ViewNameSettingParams, Self::Error> {
+    let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?.0;
+    Ok(ViewNameSettingParams {
+      workspace_id,
+      policy: self.policy,
+    })
+  }
+}
+
+/// The views under the same parent that share the same name.
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct DuplicateViewNamePB {
+  /// The id of the app or the view that the duplicate views belong to
+  #[pb(index = 1)]
+  pub belong_to_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  /// The ids of the duplicate views, ordered by their position in the parent
+  #[pb(index = 3)]
+  pub view_ids: Vec<String>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct RepeatedDuplicateViewNamePB {
+  #[pb(index = 1)]
+  pub items: Vec<DuplicateViewNamePB>,
+}
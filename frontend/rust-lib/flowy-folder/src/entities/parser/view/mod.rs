@@ -1,9 +1,11 @@
 mod view_desc;
 mod view_id;
 mod view_name;
+mod view_slug;
 mod view_thumbnail;
 
 pub use view_desc::*;
 pub use view_id::*;
 pub use view_name::*;
+pub use view_slug::*;
 pub use view_thumbnail::*;
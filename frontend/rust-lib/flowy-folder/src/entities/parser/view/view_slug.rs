@@ -0,0 +1,34 @@
+use crate::errors::ErrorCode;
+
+const MAX_SLUG_LEN: usize = 64;
+
+/// The slug is used in the links, so it only contains the lowercase ascii letters, digits and
+/// single hyphens between them, e.g. "product-roadmap-2023".
+#[derive(Debug)]
+pub struct ViewSlug(pub String);
+
+impl ViewSlug {
+  pub fn parse(s: String) -> Result<ViewSlug, ErrorCode> {
+    if s.is_empty() || s.len() > MAX_SLUG_LEN {
+      return Err(ErrorCode::ViewSlugInvalid);
+    }
+
+    let is_valid_part = |part: &str| {
+      !part.is_empty()
+        && part
+          .chars()
+          .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    };
+    if !s.split('-').all(is_valid_part) {
+      return Err(ErrorCode::ViewSlugInvalid);
+    }
+
+    Ok(Self(s))
+  }
+}
+
+impl AsRef<str> for ViewSlug {
+  fn as_ref(&self) -> &str {
+    &self.0
+  }
+}
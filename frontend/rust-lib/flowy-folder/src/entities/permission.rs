@@ -0,0 +1,73 @@
+use crate::entities::parser::view::ViewIdentify;
+use crate::errors::ErrorCode;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use std::convert::TryInto;
+
+#[derive(Eq, PartialEq, Hash, Debug, ProtoBuf_Enum, Clone, Copy)]
+pub enum ViewPermissionTypePB {
+  /// The view can be modified by the current user.
+  Editable = 0,
+  /// The view is locked by the user. It needs to be unlocked before modifying.
+  Locked = 1,
+  /// The view is shared with the current user, who can only read it.
+  SharedReadOnly = 2,
+}
+
+impl std::default::Default for ViewPermissionTypePB {
+  fn default() -> Self {
+    ViewPermissionTypePB::Editable
+  }
+}
+
+impl ViewPermissionTypePB {
+  pub fn can_modify(&self) -> bool {
+    matches!(self, ViewPermissionTypePB::Editable)
+  }
+
+  pub fn value(&self) -> i64 {
+    *self as i64
+  }
+
+  pub fn from_value(value: i64) -> Self {
+    match value {
+      1 => ViewPermissionTypePB::Locked,
+      2 => ViewPermissionTypePB::SharedReadOnly,
+      _ => ViewPermissionTypePB::Editable,
+    }
+  }
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ViewPermissionPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub permission: ViewPermissionTypePB,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct UpdateViewPermissionPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub permission: ViewPermissionTypePB,
+}
+
+pub struct UpdateViewPermissionParams {
+  pub view_id: String,
+  pub permission: ViewPermissionTypePB,
+}
+
+impl TryInto<UpdateViewPermissionParams> for UpdateViewPermissionPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<UpdateViewPermissionParams, Self::Error> {
+    let view_id = ViewIdentify::parse(self.view_id)?.0;
+    Ok(UpdateViewPermissionParams {
+      view_id,
+      permission: self.permission,
+    })
+  }
+}
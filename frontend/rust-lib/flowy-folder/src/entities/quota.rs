@@ -0,0 +1,47 @@
+use flowy_derive::ProtoBuf;
+
+/// The limits of the workspace. Zero means the limit is not enforced.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkspaceQuota {
+  /// The max size in bytes of the file that can be attached to the workspace, e.g. the
+  /// imported file
+  pub max_attachment_size: u64,
+
+  /// The max number of rows of each database
+  pub max_rows_per_database: usize,
+
+  /// The max size in bytes of the data that is stored by the workspace
+  pub max_workspace_size: u64,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct WorkspaceQuotaPB {
+  #[pb(index = 1)]
+  pub max_attachment_size: i64,
+
+  #[pb(index = 2)]
+  pub max_rows_per_database: i64,
+
+  #[pb(index = 3)]
+  pub max_workspace_size: i64,
+}
+
+impl std::convert::From<WorkspaceQuota> for WorkspaceQuotaPB {
+  fn from(quota: WorkspaceQuota) -> Self {
+    Self {
+      max_attachment_size: i64::try_from(quota.max_attachment_size).unwrap_or(i64::MAX),
+      max_rows_per_database: i64::try_from(quota.max_rows_per_database).unwrap_or(i64::MAX),
+      max_workspace_size: i64::try_from(quota.max_workspace_size).unwrap_or(i64::MAX),
+    }
+  }
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct QuotaUsagePB {
+  #[pb(index = 1)]
+  pub quota: WorkspaceQuotaPB,
+
+  /// The size in bytes of the data that is stored by the workspace
+  #[pb(index = 2)]
+  pub workspace_size: i64,
+}
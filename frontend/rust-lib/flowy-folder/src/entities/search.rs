@@ -0,0 +1,139 @@
+use crate::entities::parser::view::ViewIdentify;
+use crate::errors::ErrorCode;
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+const DEFAULT_SEARCH_LIMIT: usize = 20;
+
+#[derive(Default, ProtoBuf)]
+pub struct SearchPayloadPB {
+  #[pb(index = 1)]
+  pub query: String,
+
+  /// The max number of the results. The default limit will be used if it's not positive.
+  #[pb(index = 2)]
+  pub limit: i64,
+}
+
+pub struct SearchParams {
+  pub query: String,
+  pub limit: usize,
+}
+
+impl TryInto<SearchParams> for SearchPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<SearchParams, Self::Error> {
+    let limit = if self.limit > 0 {
+      self.limit as usize
+    } else {
+      DEFAULT_SEARCH_LIMIT
+    };
+
+    Ok(SearchParams {
+      query: self.query.trim().to_owned(),
+      limit,
+    })
+  }
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct SearchResultPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  /// The beginning of the view's content
+  #[pb(index = 3)]
+  pub preview: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct RepeatedSearchResultPB {
+  #[pb(index = 1)]
+  pub items: Vec<SearchResultPB>,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct QuickSwitchPayloadPB {
+  /// The recently visited views will be returned if the query is empty.
+  #[pb(index = 1)]
+  pub query: String,
+
+  /// The max number of the results. The default limit will be used if it's not positive.
+  #[pb(index = 2)]
+  pub limit: i64,
+}
+
+pub struct QuickSwitchParams {
+  pub query: String,
+  pub limit: usize,
+}
+
+impl TryInto<QuickSwitchParams> for QuickSwitchPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<QuickSwitchParams, Self::Error> {
+    let limit = if self.limit > 0 {
+      self.limit as usize
+    } else {
+      DEFAULT_SEARCH_LIMIT
+    };
+
+    Ok(QuickSwitchParams {
+      query: self.query.trim().to_owned(),
+      limit,
+    })
+  }
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct QuickSwitchItemPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub name: String,
+
+  /// The alias that matches the query. It's empty if the name of the view matches the query.
+  #[pb(index = 3)]
+  pub matched_alias: String,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct RepeatedQuickSwitchItemPB {
+  #[pb(index = 1)]
+  pub items: Vec<QuickSwitchItemPB>,
+}
+
+#[derive(Default, ProtoBuf, Clone, Debug, PartialEq, Eq)]
+pub struct ViewAliasesPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub aliases: Vec<String>,
+}
+
+impl TryInto<ViewAliasesParams> for ViewAliasesPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ViewAliasesParams, Self::Error> {
+    let view_id = ViewIdentify::parse(self.view_id)?.0;
+    let mut aliases: Vec<String> = vec![];
+    for alias in self.aliases {
+      let alias = alias.trim().to_owned();
+      if !alias.is_empty() && !aliases.contains(&alias) {
+        aliases.push(alias);
+      }
+    }
+    Ok(ViewAliasesParams { view_id, aliases })
+  }
+}
+
+pub struct ViewAliasesParams {
+  pub view_id: String,
+  pub aliases: Vec<String>,
+}
@@ -0,0 +1,57 @@
+use crate::{entities::parser::workspace::WorkspaceName, errors::*};
+use flowy_derive::ProtoBuf;
+use std::convert::TryInto;
+
+/// The max number of the documents or the databases that are generated at once
+pub const MAX_SYNTHETIC_VIEWS: u32 = 1000;
+
+/// The max number of the rows of each generated database
+pub const MAX_SYNTHETIC_ROWS: u32 = 100_000;
+
+/// Generates a workspace filled with synthetic documents and databases, it's used to test the
+/// performance of filtering, search indexing and sync at scale. Only available in the `dev`
+/// builds.
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct GenerateSyntheticWorkspacePayloadPB {
+  #[pb(index = 1)]
+  pub name: String,
+
+  #[pb(index = 2)]
+  pub number_of_documents: u32,
+
+  #[pb(index = 3)]
+  pub number_of_databases: u32,
+
+  /// The number of the rows of each database
+  #[pb(index = 4)]
+  pub number_of_rows: u32,
+}
+
+#[derive(Clone, Debug)]
+pub struct GenerateSyntheticWorkspaceParams {
+  pub name: String,
+  pub number_of_documents: usize,
+  pub number_of_databases: usize,
+  pub number_of_rows: usize,
+}
+
+impl TryInto<GenerateSyntheticWorkspaceParams> for GenerateSyntheticWorkspacePayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<GenerateSyntheticWorkspaceParams, Self::Error> {
+    let name = WorkspaceName::parse(self.name)?;
+    if self.number_of_documents > MAX_SYNTHETIC_VIEWS
+      || self.number_of_databases > MAX_SYNTHETIC_VIEWS
+      || self.number_of_rows > MAX_SYNTHETIC_ROWS
+    {
+      return Err(ErrorCode::OutOfBounds);
+    }
+
+    Ok(GenerateSyntheticWorkspaceParams {
+      name: name.0,
+      number_of_documents: self.number_of_documents as usize,
+      number_of_databases: self.number_of_databases as usize,
+      number_of_rows: self.number_of_rows as usize,
+    })
+  }
+}
@@ -1,7 +1,7 @@
 use crate::{
   entities::parser::{
     app::AppIdentify,
-    view::{ViewDesc, ViewIdentify, ViewName, ViewThumbnail},
+    view::{ViewDesc, ViewIdentify, ViewName, ViewSlug, ViewThumbnail},
   },
   errors::ErrorCode,
   impl_def_and_def_mut,
@@ -33,6 +33,15 @@ pub struct ViewPB {
 
   #[pb(index = 7)]
   pub layout: ViewLayoutTypePB,
+
+  /// The id of the user who edited the view last time, the `modified_time` is the time of
+  /// the edit.
+  #[pb(index = 8)]
+  pub last_edited_by: String,
+
+  /// The slug that identifies the view in the links. It's empty if the view has no slug.
+  #[pb(index = 9)]
+  pub slug: String,
 }
 
 impl std::convert::From<ViewRevision> for ViewPB {
@@ -45,6 +54,8 @@ impl std::convert::From<ViewRevision> for ViewPB {
       modified_time: rev.modified_time,
       create_time: rev.create_time,
       layout: rev.layout.into(),
+      last_edited_by: rev.last_edited_by,
+      slug: rev.slug,
     }
   }
 }
@@ -209,6 +220,55 @@ impl TryInto<CreateViewParams> for CreateViewPayloadPB {
   }
 }
 
+/// The name of the quick-created views that are created without a name
+pub const QUICK_CREATE_VIEW_NAME: &str = "Untitled";
+
+/// Creates a view with the defaults of the workspace settings, see [WorkspaceSettingsPB].
+///
+/// [WorkspaceSettingsPB]: crate::entities::WorkspaceSettingsPB
+#[derive(Default, ProtoBuf)]
+pub struct QuickCreateViewPayloadPB {
+  /// The name of the view, [QUICK_CREATE_VIEW_NAME] is used if it's empty
+  #[pb(index = 1)]
+  pub name: String,
+
+  /// The layout of the view, the `default_view_layout` of the settings is used if it's None
+  #[pb(index = 2, one_of)]
+  pub layout: Option<ViewLayoutTypePB>,
+
+  /// The app or view that is currently selected. It's only used if the views are quick-created
+  /// under the current parent.
+  #[pb(index = 3, one_of)]
+  pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct QuickCreateViewParams {
+  pub name: String,
+  pub layout: Option<ViewLayoutTypePB>,
+  pub parent_id: Option<String>,
+}
+
+impl TryInto<QuickCreateViewParams> for QuickCreateViewPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<QuickCreateViewParams, Self::Error> {
+    let name = match self.name.trim().is_empty() {
+      true => QUICK_CREATE_VIEW_NAME.to_owned(),
+      false => ViewName::parse(self.name)?.0,
+    };
+    let parent_id = match self.parent_id {
+      Some(parent_id) if !parent_id.is_empty() => Some(AppIdentify::parse(parent_id)?.0),
+      _ => None,
+    };
+    Ok(QuickCreateViewParams {
+      name,
+      layout: self.layout,
+      parent_id,
+    })
+  }
+}
+
 pub fn data_format_from_layout(layout: &ViewLayoutTypePB) -> ViewDataFormatPB {
   match layout {
     ViewLayoutTypePB::Document => ViewDataFormatPB::NodeFormat,
@@ -350,6 +410,146 @@ impl TryInto<MoveFolderItemParams> for MoveFolderItemPayloadPB {
   }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum ChildViewSortPB {
+  /// The order of the views in the folder
+  Manual = 0,
+  /// The recently edited views come first
+  LastEdited = 1,
+}
+
+impl std::default::Default for ChildViewSortPB {
+  fn default() -> Self {
+    ChildViewSortPB::Manual
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ReadChildViewsPayloadPB {
+  #[pb(index = 1)]
+  pub belong_to_id: String,
+
+  #[pb(index = 2)]
+  pub sort: ChildViewSortPB,
+}
+
+pub struct ReadChildViewsParams {
+  pub belong_to_id: String,
+  pub sort: ChildViewSortPB,
+}
+
+impl TryInto<ReadChildViewsParams> for ReadChildViewsPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ReadChildViewsParams, Self::Error> {
+    let belong_to_id = AppIdentify::parse(self.belong_to_id)?.0;
+    Ok(ReadChildViewsParams {
+      belong_to_id,
+      sort: self.sort,
+    })
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct UpdateViewSlugPayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// The slug is removed if it's None
+  #[pb(index = 2, one_of)]
+  pub slug: Option<String>,
+}
+
+pub struct UpdateViewSlugParams {
+  pub view_id: String,
+  pub slug: Option<String>,
+}
+
+impl TryInto<UpdateViewSlugParams> for UpdateViewSlugPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<UpdateViewSlugParams, Self::Error> {
+    let view_id = ViewIdentify::parse(self.view_id)?.0;
+    let slug = match self.slug {
+      None => None,
+      Some(slug) => Some(ViewSlug::parse(slug)?.0),
+    };
+    Ok(UpdateViewSlugParams { view_id, slug })
+  }
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct ResolveViewSlugPayloadPB {
+  /// The current workspace is used if it's None
+  #[pb(index = 1, one_of)]
+  pub workspace_id: Option<String>,
+
+  #[pb(index = 2)]
+  pub slug: String,
+}
+
+pub struct ResolveViewSlugParams {
+  pub workspace_id: Option<String>,
+  pub slug: String,
+}
+
+impl TryInto<ResolveViewSlugParams> for ResolveViewSlugPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<ResolveViewSlugParams, Self::Error> {
+    let slug = ViewSlug::parse(self.slug)?.0;
+    Ok(ResolveViewSlugParams {
+      workspace_id: self.workspace_id,
+      slug,
+    })
+  }
+}
+
+/// The state of the view that is only kept for the current user, e.g. the scroll anchor, the
+/// expanded rows or board groups, or the month shown by the calendar. The values are set by the
+/// client and restored when the view is opened again. The state is kept in the folder, so it's
+/// synced to the other devices of the user.
+#[derive(Default, ProtoBuf, Clone, Debug)]
+pub struct ViewStatePB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  #[pb(index = 2)]
+  pub values: HashMap<String, String>,
+}
+
+#[derive(Default, ProtoBuf)]
+pub struct UpdateViewStatePayloadPB {
+  #[pb(index = 1)]
+  pub view_id: String,
+
+  /// Inserts or replaces the values with the same keys, the other values are kept
+  #[pb(index = 2)]
+  pub values: HashMap<String, String>,
+
+  #[pb(index = 3)]
+  pub removed_keys: Vec<String>,
+}
+
+pub struct UpdateViewStateParams {
+  pub view_id: String,
+  pub values: HashMap<String, String>,
+  pub removed_keys: Vec<String>,
+}
+
+impl TryInto<UpdateViewStateParams> for UpdateViewStatePayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<UpdateViewStateParams, Self::Error> {
+    let view_id = ViewIdentify::parse(self.view_id)?.0;
+    Ok(UpdateViewStateParams {
+      view_id,
+      values: self.values,
+      removed_keys: self.removed_keys,
+    })
+  }
+}
+
 // impl<'de> Deserialize<'de> for ViewDataType {
 //     fn deserialize<D>(deserializer: D) -> Result<Self, <D as Deserializer<'de>>::Error>
 //     where
@@ -0,0 +1,150 @@
+use crate::entities::parser::workspace::WorkspaceIdentify;
+use crate::entities::{ViewLayoutTypePB, ViewPermissionTypePB};
+use crate::errors::ErrorCode;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use folder_model::WorkspaceSettingsRevision;
+use std::convert::TryInto;
+
+/// The settings that are shared by the members of the workspace. Unlike the user's preferences,
+/// they are saved in the folder and synced with the other members.
+#[derive(Default, ProtoBuf, Clone, Debug, PartialEq, Eq)]
+pub struct WorkspaceSettingsPB {
+  #[pb(index = 1)]
+  pub workspace_id: String,
+
+  #[pb(index = 2)]
+  pub default_database_layout: ViewLayoutTypePB,
+
+  /// The value of the database's `DateFormat`, the database's default is used if it's None
+  #[pb(index = 3, one_of)]
+  pub default_date_format: Option<i64>,
+
+  /// The value of the database's `TimeFormat`, the database's default is used if it's None
+  #[pb(index = 4, one_of)]
+  pub default_time_format: Option<i64>,
+
+  /// The value of the database's `NumberFormat`, the database's default is used if it's None
+  #[pb(index = 5, one_of)]
+  pub default_number_format: Option<i64>,
+
+  /// The days that the deleted items are kept in the trash. Zero means keeping them forever.
+  #[pb(index = 6)]
+  pub trash_retention_days: i64,
+
+  #[pb(index = 7)]
+  pub default_member_permission: ViewPermissionTypePB,
+
+  /// The layout of the views that are created by the `QuickCreateView` event without a layout
+  #[pb(index = 8)]
+  pub default_view_layout: ViewLayoutTypePB,
+
+  #[pb(index = 9)]
+  pub quick_create_location: QuickCreateLocationPB,
+
+  /// The app that collects the quick-created views, it's None until the first one is added
+  #[pb(index = 10, one_of)]
+  pub inbox_app_id: Option<String>,
+
+  /// The first day of the week, from 0 for Sunday to 6 for Saturday. It's used by the
+  /// databases, e.g. the date filters of this week.
+  #[pb(index = 11)]
+  pub first_day_of_week: i64,
+
+  /// The working days of the week as a bit mask, the bit 0 is Sunday and the bit 6 is Saturday.
+  /// Monday to Friday are the working days if it's None.
+  #[pb(index = 12, one_of)]
+  pub working_days: Option<i64>,
+}
+
+/// Where the views that are created by the `QuickCreateView` event are added
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ProtoBuf_Enum)]
+pub enum QuickCreateLocationPB {
+  /// Under the parent of the request, or the parent of the latest opened view if the request
+  /// has none.
+  CurrentParent = 0,
+  /// Under the inbox app of the workspace, it's created on demand.
+  Inbox = 1,
+}
+
+impl std::default::Default for QuickCreateLocationPB {
+  fn default() -> Self {
+    QuickCreateLocationPB::CurrentParent
+  }
+}
+
+impl QuickCreateLocationPB {
+  pub fn value(&self) -> i64 {
+    *self as i64
+  }
+
+  pub fn from_value(value: i64) -> Self {
+    match value {
+      1 => QuickCreateLocationPB::Inbox,
+      _ => QuickCreateLocationPB::CurrentParent,
+    }
+  }
+}
+
+impl WorkspaceSettingsPB {
+  pub fn new(workspace_id: String, settings: WorkspaceSettingsRevision) -> Self {
+    Self {
+      workspace_id,
+      default_database_layout: settings.default_database_layout.into(),
+      default_date_format: settings.default_date_format,
+      default_time_format: settings.default_time_format,
+      default_number_format: settings.default_number_format,
+      trash_retention_days: settings.trash_retention_days,
+      default_member_permission: ViewPermissionTypePB::from_value(
+        settings.default_member_permission,
+      ),
+      default_view_layout: settings.default_view_layout.into(),
+      quick_create_location: QuickCreateLocationPB::from_value(settings.quick_create_location),
+      inbox_app_id: settings.inbox_app_id,
+      first_day_of_week: settings.first_day_of_week,
+      working_days: settings.working_days,
+    }
+  }
+}
+
+pub struct WorkspaceSettingsParams {
+  pub workspace_id: String,
+  pub settings: WorkspaceSettingsRevision,
+}
+
+impl TryInto<WorkspaceSettingsParams> for WorkspaceSettingsPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<WorkspaceSettingsParams, Self::Error> {
+    let workspace_id = WorkspaceIdentify::parse(self.workspace_id)?.0;
+    let is_negative = |format: Option<i64>| format.map(|value| value < 0).unwrap_or(false);
+    if self.trash_retention_days < 0
+      || is_negative(self.default_date_format)
+      || is_negative(self.default_time_format)
+      || is_negative(self.default_number_format)
+      || !(0..7).contains(&self.first_day_of_week)
+      || !self
+        .working_days
+        .map(|working_days| (0..128).contains(&working_days))
+        .unwrap_or(true)
+    {
+      return Err(ErrorCode::InvalidData);
+    }
+
+    Ok(WorkspaceSettingsParams {
+      workspace_id,
+      settings: WorkspaceSettingsRevision {
+        default_database_layout: self.default_database_layout.into(),
+        default_date_format: self.default_date_format,
+        default_time_format: self.default_time_format,
+        default_number_format: self.default_number_format,
+        trash_retention_days: self.trash_retention_days,
+        default_member_permission: self.default_member_permission.value(),
+        default_view_layout: self.default_view_layout.into(),
+        quick_create_location: self.quick_create_location.value(),
+        inbox_app_id: self.inbox_app_id.filter(|app_id| !app_id.is_empty()),
+        first_day_of_week: self.first_day_of_week,
+        working_days: self.working_days,
+      },
+    })
+  }
+}
@@ -1,6 +1,7 @@
 use crate::{
   entities::{
     app::{AppIdPB, CreateAppParams, UpdateAppParams},
+    quota::WorkspaceQuota,
     trash::RepeatedTrashIdPB,
     view::{CreateViewParams, RepeatedViewIdPB, UpdateViewParams, ViewIdPB},
     workspace::{CreateWorkspaceParams, UpdateWorkspaceParams, WorkspaceIdPB},
@@ -8,8 +9,9 @@ use crate::{
   errors::FlowyError,
   manager::FolderManager,
   services::{
-    app::event_handler::*, trash::event_handler::*, view::event_handler::*,
-    workspace::event_handler::*,
+    app::event_handler::*, health::event_handler::*, permission::event_handler::*,
+    quota::event_handler::*, search::event_handler::*, trash::event_handler::*,
+    view::event_handler::*, workspace::event_handler::*,
   },
 };
 use flowy_derive::{Flowy_Event, ProtoBuf_Enum};
@@ -18,13 +20,14 @@ use folder_model::{AppRevision, TrashRevision, ViewRevision, WorkspaceRevision};
 use lib_dispatch::prelude::*;
 use lib_infra::future::FutureResult;
 use std::sync::Arc;
-use strum_macros::Display;
+use strum_macros::{Display, EnumIter};
 
 pub trait WorkspaceDeps: WorkspaceUser + WorkspaceDatabase {}
 
 pub trait WorkspaceUser: Send + Sync {
   fn user_id(&self) -> Result<String, FlowyError>;
   fn token(&self) -> Result<String, FlowyError>;
+  fn user_dir(&self) -> Result<String, FlowyError>;
 }
 
 pub trait WorkspaceDatabase: Send + Sync {
@@ -44,6 +47,10 @@ pub fn init(folder: Arc<FolderManager>) -> AFPlugin {
     .state(folder.app_controller.clone())
     .state(folder.view_controller.clone())
     .state(folder.trash_controller.clone())
+    .state(folder.permission_controller.clone())
+    .state(folder.search_controller.clone())
+    .state(folder.quota_controller.clone())
+    .state(folder.health_controller.clone())
     .state(folder.clone());
 
   // Workspace
@@ -55,7 +62,15 @@ pub fn init(folder: Arc<FolderManager>) -> AFPlugin {
     )
     .event(FolderEvent::ReadWorkspaces, read_workspaces_handler)
     .event(FolderEvent::OpenWorkspace, open_workspace_handler)
-    .event(FolderEvent::ReadWorkspaceApps, read_workspace_apps_handler);
+    .event(FolderEvent::ReadWorkspaceApps, read_workspace_apps_handler)
+    .event(
+      FolderEvent::ReadWorkspaceSettings,
+      read_workspace_settings_handler,
+    )
+    .event(
+      FolderEvent::UpdateWorkspaceSettings,
+      update_workspace_settings_handler,
+    );
 
   // App
   plugin = plugin
@@ -72,8 +87,76 @@ pub fn init(folder: Arc<FolderManager>) -> AFPlugin {
     .event(FolderEvent::DeleteView, delete_view_handler)
     .event(FolderEvent::DuplicateView, duplicate_view_handler)
     .event(FolderEvent::SetLatestView, set_latest_view_handler)
+    .event(FolderEvent::ReadViewState, read_view_state_handler)
+    .event(FolderEvent::UpdateViewState, update_view_state_handler)
     .event(FolderEvent::CloseView, close_view_handler)
-    .event(FolderEvent::MoveItem, move_item_handler);
+    .event(FolderEvent::ReadChildViews, read_child_views_handler)
+    .event(FolderEvent::UpdateViewSlug, update_view_slug_handler)
+    .event(FolderEvent::ResolveViewSlug, resolve_view_slug_handler)
+    .event(FolderEvent::QuickCreateView, quick_create_view_handler)
+    .event(FolderEvent::MoveItem, move_item_handler)
+    .event(FolderEvent::ImportData, import_data_handler)
+    .event(FolderEvent::ExportData, export_data_handler)
+    .event(
+      FolderEvent::ReadViewNameSetting,
+      read_view_name_setting_handler,
+    )
+    .event(
+      FolderEvent::UpdateViewNameSetting,
+      update_view_name_setting_handler,
+    )
+    .event(
+      FolderEvent::ReadDuplicateViewNames,
+      read_duplicate_view_names_handler,
+    )
+    .event(
+      FolderEvent::RenameDuplicateViews,
+      rename_duplicate_views_handler,
+    );
+
+  // Permission
+  plugin = plugin
+    .event(
+      FolderEvent::ReadViewPermission,
+      read_view_permission_handler,
+    )
+    .event(
+      FolderEvent::UpdateViewPermission,
+      update_view_permission_handler,
+    );
+
+  // Search
+  plugin = plugin
+    .event(FolderEvent::Search, search_handler)
+    .event(
+      FolderEvent::RebuildSearchIndex,
+      rebuild_search_index_handler,
+    )
+    .event(FolderEvent::QuickSwitch, quick_switch_handler)
+    .event(FolderEvent::ReadViewAliases, read_view_aliases_handler)
+    .event(FolderEvent::UpdateViewAliases, update_view_aliases_handler);
+
+  // Quota
+  plugin = plugin.event(FolderEvent::GetQuotaUsage, get_quota_usage_handler);
+
+  // Health
+  plugin = plugin
+    .event(
+      FolderEvent::GetWorkspaceHealth,
+      get_workspace_health_handler,
+    )
+    .event(FolderEvent::GetMemoryReport, get_memory_report_handler)
+    .event(FolderEvent::TrimCaches, trim_caches_handler);
+
+  // Synthetic data
+  #[cfg(feature = "dev")]
+  {
+    use crate::services::synthetic::event_handler::generate_synthetic_workspace_handler;
+    plugin = plugin.event(
+      FolderEvent::GenerateSyntheticWorkspace,
+      generate_synthetic_workspace_handler,
+    );
+  }
 
   // Trash
   plugin = plugin
@@ -86,7 +169,9 @@ pub fn init(folder: Arc<FolderManager>) -> AFPlugin {
   plugin
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
+#[derive(
+  Clone, Copy, PartialEq, Eq, Debug, Display, Hash, EnumIter, ProtoBuf_Enum, Flowy_Event,
+)]
 #[event_err = "FlowyError"]
 pub enum FolderEvent {
   /// Create a new workspace
@@ -113,6 +198,16 @@ pub enum FolderEvent {
   #[event(input = "WorkspaceIdPB", output = "RepeatedAppPB")]
   ReadWorkspaceApps = 5,
 
+  /// Return the settings that are shared by the members of the workspace, e.g. the default
+  /// database layout. The current workspace is used if the id is empty
+  #[event(input = "WorkspaceIdPB", output = "WorkspaceSettingsPB")]
+  ReadWorkspaceSettings = 6,
+
+  /// Replace the settings of the workspace. The settings are saved in the folder, so the other
+  /// members receive the `DidUpdateWorkspaceSettings` notification after syncing
+  #[event(input = "WorkspaceSettingsPB", output = "WorkspaceSettingsPB")]
+  UpdateWorkspaceSettings = 7,
+
   /// Create a new app
   #[event(input = "CreateAppPayloadPB", output = "AppPB")]
   CreateApp = 101,
@@ -154,6 +249,25 @@ pub enum FolderEvent {
   #[event(input = "ViewIdPB")]
   CloseView = 206,
 
+  /// Return the views that belong to the app. They can be sorted by the time they were edited,
+  /// e.g. showing the recently updated views
+  #[event(input = "ReadChildViewsPayloadPB", output = "RepeatedViewPB")]
+  ReadChildViews = 207,
+
+  /// Set or remove the slug of the view. The slug is unique in the workspace and it's used in
+  /// the links of the view, so the links survive renaming the view
+  #[event(input = "UpdateViewSlugPayloadPB", output = "ViewPB")]
+  UpdateViewSlug = 208,
+
+  /// Return the view that the slug points to, it's used to open the links of the views
+  #[event(input = "ResolveViewSlugPayloadPB", output = "ViewPB")]
+  ResolveViewSlug = 209,
+
+  /// Create a view with the default layout and under the location that are configured in the
+  /// workspace settings, it's used by the "new page" shortcut
+  #[event(input = "QuickCreateViewPayloadPB", output = "ViewPB")]
+  QuickCreateView = 210,
+
   #[event()]
   CopyLink = 220,
 
@@ -161,10 +275,101 @@ pub enum FolderEvent {
   #[event(input = "ViewIdPB")]
   SetLatestView = 221,
 
+  /// Return the state of the view that is kept for the current user, like the scroll anchor or
+  /// the expanded rows. It's used to restore the view when it's opened again
+  #[event(input = "ViewIdPB", output = "ViewStatePB")]
+  ReadViewState = 222,
+
+  /// Insert, replace or remove the values of the view state. Returns the updated state
+  #[event(input = "UpdateViewStatePayloadPB", output = "ViewStatePB")]
+  UpdateViewState = 223,
+
   /// Move the view or app to another place
   #[event(input = "MoveFolderItemPayloadPB")]
   MoveItem = 230,
 
+  /// Create a view with the data of the file. The records of the file are imported in the
+  /// background and the progress is saved, so an interrupted import resumes on the next launch.
+  /// The views of an archive written by [FolderEvent::ExportData] are created in the app, the
+  /// passphrase is required if the archive is encrypted.
+  #[event(input = "ImportPayloadPB", output = "ViewPB")]
+  ImportData = 231,
+
+  /// Return the policy that is applied when a view of the workspace is created or renamed with
+  /// the name of one of its siblings. The current workspace is used if the id is empty
+  #[event(input = "WorkspaceIdPB", output = "ViewNameSettingPB")]
+  ReadViewNameSetting = 232,
+
+  #[event(input = "ViewNameSettingPB")]
+  UpdateViewNameSetting = 233,
+
+  /// Return the views of the workspace that have the same name under the same parent
+  #[event(input = "WorkspaceIdPB", output = "RepeatedDuplicateViewNamePB")]
+  ReadDuplicateViewNames = 234,
+
+  /// Append a number to the names of the duplicate views, e.g. "Untitled (1)". Returns the
+  /// renamed views
+  #[event(input = "WorkspaceIdPB", output = "RepeatedViewPB")]
+  RenameDuplicateViews = 235,
+
+  /// Write the view, or all the views of the workspace, into an archive file. The archive is
+  /// encrypted if the passphrase is set
+  #[event(input = "ExportDataPayloadPB")]
+  ExportData = 236,
+
+  /// Return the permission of the view
+  #[event(input = "ViewIdPB", output = "ViewPermissionPB")]
+  ReadViewPermission = 240,
+
+  /// Lock, unlock the view or mark it as shared read-only. The mutating events on the
+  /// views that are not editable will be rejected by the `ViewPermissionInterceptor`.
+  #[event(input = "UpdateViewPermissionPayloadPB")]
+  UpdateViewPermission = 241,
+
+  /// Search the views by their names and contents
+  #[event(input = "SearchPayloadPB", output = "RepeatedSearchResultPB")]
+  Search = 250,
+
+  /// Drop the search index and index all the views again
+  #[event()]
+  RebuildSearchIndex = 251,
+
+  /// Fuzzy match the names and aliases of the views, the recently visited views are ranked higher
+  #[event(input = "QuickSwitchPayloadPB", output = "RepeatedQuickSwitchItemPB")]
+  QuickSwitch = 252,
+
+  /// Return the aliases of the view
+  #[event(input = "ViewIdPB", output = "ViewAliasesPB")]
+  ReadViewAliases = 253,
+
+  /// Replace the aliases of the view. The aliases are used by the quick switcher
+  #[event(input = "ViewAliasesPB")]
+  UpdateViewAliases = 254,
+
+  /// Return the quota of the workspace and how much of it is used
+  #[event(output = "QuotaUsagePB")]
+  GetQuotaUsage = 260,
+
+  /// Return the health report of the workspace, including the sync backlog, the failed
+  /// background tasks, the storage usage and the freshness of the search index
+  #[event(output = "WorkspaceHealthPB")]
+  GetWorkspaceHealth = 270,
+
+  /// Return the estimated bytes held in memory by the cell data caches, the opened documents,
+  /// the filter results and the search index
+  #[event(output = "MemoryReportPB")]
+  GetMemoryReport = 271,
+
+  /// Drop the caches that can be rebuilt on demand. It's called when the OS signals memory
+  /// pressure, e.g. `didReceiveMemoryWarning` on iOS or `onTrimMemory` on Android.
+  #[event()]
+  TrimCaches = 272,
+
+  /// Generate a workspace with the synthetic documents and databases for performance testing.
+  /// The handler is only registered in the builds with the `dev` feature.
+  #[event(input = "GenerateSyntheticWorkspacePayloadPB", output = "WorkspacePB")]
+  GenerateSyntheticWorkspace = 280,
+
   /// Read the trash that was deleted by the user
   #[event(output = "RepeatedTrashPB")]
   ReadTrash = 300,
@@ -247,4 +452,9 @@ pub trait FolderCouldServiceV1: Send + Sync {
   fn delete_trash(&self, token: &str, params: RepeatedTrashIdPB) -> FutureResult<(), FlowyError>;
 
   fn read_trash(&self, token: &str) -> FutureResult<Vec<TrashRevision>, FlowyError>;
+
+  // Quota
+  /// Returns None if the quota of the workspace is not managed by the cloud service, then
+  /// the local quota is used.
+  fn read_workspace_quota(&self, token: &str) -> FutureResult<Option<WorkspaceQuota>, FlowyError>;
 }
@@ -1,5 +1,11 @@
-use crate::entities::view::ViewDataFormatPB;
-use crate::entities::{ViewLayoutTypePB, ViewPB, WorkspacePB};
+use crate::entities::app::{AppIdPB, CreateAppParams};
+use crate::entities::view::{
+  data_format_from_layout, CreateViewParams, QuickCreateViewParams, ViewDataFormatPB,
+};
+use crate::entities::{
+  ImportTypePB, QuickCreateLocationPB, ViewLayoutTypePB, ViewPB, WorkspacePB, WorkspaceQuota,
+  WorkspaceSettingsPB, WorkspaceSettingsParams,
+};
 use crate::services::folder_editor::FolderRevisionMergeable;
 use crate::{
   entities::workspace::RepeatedWorkspacePB,
@@ -7,8 +13,9 @@ use crate::{
   event_map::{FolderCouldServiceV1, WorkspaceDatabase, WorkspaceUser},
   notification::{send_notification, FolderNotification},
   services::{
-    folder_editor::FolderEditor, persistence::FolderPersistence, set_current_workspace,
-    AppController, TrashController, ViewController, WorkspaceController,
+    folder_editor::FolderEditor, persistence::FolderPersistence, search::SearchIndexTaskHandler,
+    set_current_workspace, AppController, HealthController, PermissionController, QuotaController,
+    SearchController, TrashController, ViewController, WorkspaceController,
   },
 };
 use bytes::Bytes;
@@ -17,7 +24,8 @@ use flowy_error::FlowyError;
 use flowy_revision::{
   RevisionManager, RevisionPersistence, RevisionPersistenceConfiguration, RevisionWebSocket,
 };
-use folder_model::user_default;
+use flowy_task::TaskDispatcher;
+use folder_model::{gen_view_id, user_default, ViewRevision};
 use lazy_static::lazy_static;
 use lib_infra::future::FutureResult;
 
@@ -26,9 +34,10 @@ use crate::services::persistence::rev_sqlite::{
 };
 use crate::services::{clear_current_workspace, get_current_workspace};
 use flowy_client_sync::client_folder::FolderPad;
-use std::convert::TryFrom;
+use std::convert::{TryFrom, TryInto};
+use std::path::Path;
 use std::{collections::HashMap, fmt::Formatter, sync::Arc};
-use tokio::sync::RwLock as TokioRwLock;
+use tokio::sync::{broadcast, RwLock as TokioRwLock};
 use ws_model::ws_revision::ServerRevisionWSData;
 lazy_static! {
   static ref INIT_FOLDER_FLAG: TokioRwLock<HashMap<String, bool>> =
@@ -69,6 +78,10 @@ pub struct FolderManager {
   pub(crate) app_controller: Arc<AppController>,
   pub(crate) view_controller: Arc<ViewController>,
   pub(crate) trash_controller: Arc<TrashController>,
+  pub(crate) permission_controller: Arc<PermissionController>,
+  pub(crate) search_controller: Arc<SearchController>,
+  pub(crate) quota_controller: Arc<QuotaController>,
+  pub(crate) health_controller: Arc<HealthController>,
   web_socket: Arc<dyn RevisionWebSocket>,
   pub(crate) folder_editor: Arc<TokioRwLock<Option<Arc<FolderEditor>>>>,
 }
@@ -80,6 +93,9 @@ impl FolderManager {
     database: Arc<dyn WorkspaceDatabase>,
     data_processors: ViewDataProcessorMap,
     web_socket: Arc<dyn RevisionWebSocket>,
+    task_scheduler: Arc<TokioRwLock<TaskDispatcher>>,
+    quota: WorkspaceQuota,
+    archiver: Arc<dyn ViewArchiver>,
   ) -> Self {
     if let Ok(user_id) = user.user_id() {
       // Reset the flag if the folder manager gets initialized, otherwise,
@@ -102,12 +118,26 @@ impl FolderManager {
       user.clone(),
     ));
 
+    let search_controller = Arc::new(SearchController::new(
+      user.clone(),
+      persistence.clone(),
+      trash_controller.clone(),
+      data_processors.clone(),
+      task_scheduler.clone(),
+    ));
+    task_scheduler
+      .write()
+      .await
+      .register_handler(SearchIndexTaskHandler::new(search_controller.clone()));
+
     let view_controller = Arc::new(ViewController::new(
       user.clone(),
       persistence.clone(),
       cloud_service.clone(),
       trash_controller.clone(),
-      data_processors,
+      search_controller.clone(),
+      data_processors.clone(),
+      archiver,
     ));
 
     let app_controller = Arc::new(AppController::new(
@@ -124,6 +154,22 @@ impl FolderManager {
       cloud_service.clone(),
     ));
 
+    let permission_controller = Arc::new(PermissionController::new(persistence.clone()));
+    let quota_controller = Arc::new(QuotaController::new(
+      user.clone(),
+      cloud_service.clone(),
+      quota,
+    ));
+
+    let health_controller = Arc::new(HealthController::new(
+      folder_editor.clone(),
+      data_processors,
+      task_scheduler.clone(),
+      search_controller.clone(),
+      quota_controller.clone(),
+      web_socket.clone(),
+    ));
+
     Self {
       user,
       persistence,
@@ -131,6 +177,10 @@ impl FolderManager {
       app_controller,
       view_controller,
       trash_controller,
+      permission_controller,
+      search_controller,
+      quota_controller,
+      health_controller,
       web_socket,
       folder_editor,
     }
@@ -205,10 +255,53 @@ impl FolderManager {
 
     self.app_controller.initialize()?;
     self.view_controller.initialize()?;
+    self.search_controller.initialize().await?;
+    self.quota_controller.initialize().await?;
     write_guard.insert(user_id.to_owned(), true);
     Ok(())
   }
 
+  /// Starts indexing the views of the workspace for searching, the index is rebuilt in the
+  /// background if it's empty. The folder must be initialized first.
+  pub async fn start_search_index(&self) {
+    self.search_controller.start_indexing().await;
+  }
+
+  /// Returns the quota of the workspace, it's read from the cloud service when the folder
+  /// gets initialized.
+  pub fn get_workspace_quota(&self) -> WorkspaceQuota {
+    self.quota_controller.read_quota()
+  }
+
+  /// Returns an error if the workspace reached its max size.
+  pub fn check_workspace_size(&self) -> FlowyResult<()> {
+    self.quota_controller.check_workspace_size()
+  }
+
+  /// Adds the size of the file that was stored outside the folder, e.g. attached to a cell, to
+  /// the size of the workspace.
+  pub fn did_store_file(&self, file_size: u64) {
+    self.quota_controller.did_add_workspace_data(file_size)
+  }
+
+  /// Returns the settings of the current workspace.
+  pub async fn get_workspace_settings(&self) -> FlowyResult<WorkspaceSettingsPB> {
+    self
+      .workspace_controller
+      .read_workspace_settings(None)
+      .await
+  }
+
+  /// Returns the name of the view, or None if the view is not found or it's in the trash.
+  pub async fn get_view_name(&self, view_id: &str) -> Option<String> {
+    let view_rev = self.view_controller.read_view(view_id).await.ok()?;
+    Some(view_rev.name)
+  }
+
+  pub fn subscribe_workspace_settings(&self) -> broadcast::Receiver<WorkspaceSettingsPB> {
+    self.workspace_controller.subscribe_workspace_settings()
+  }
+
   pub async fn get_current_workspace(&self) -> FlowyResult<WorkspacePB> {
     let user_id = self.user.user_id()?;
     let workspace_id = get_current_workspace(&user_id)?;
@@ -247,8 +340,114 @@ impl FolderManager {
     clear_current_workspace(user_id);
     *self.folder_editor.write().await = None;
   }
+
+  /// Records the last editor of the view and schedules the view to be re-indexed for searching.
+  /// It's called when the content of the view was changed outside the folder, for example,
+  /// editing the document or the database.
+  ///
+  /// The `edited_by` is the author of the change. It's None if the change came from the
+  /// revisions of the other devices, whose author is unknown here. The last editor is kept then,
+  /// the device that made the change records it in the synced folder.
+  pub async fn did_update_view_content(&self, view_id: &str, edited_by: Option<&str>) {
+    if let Some(edited_by) = edited_by {
+      let _ = self
+        .view_controller
+        .did_update_view_content(view_id, edited_by)
+        .await;
+    }
+    self.search_controller.did_update_view(view_id).await;
+  }
+
+  /// Creates a view with the defaults of the current workspace's settings. The view is added
+  /// under the inbox app or under the current parent, see [QuickCreateLocationPB].
+  #[tracing::instrument(level = "debug", skip(self, params), err)]
+  pub(crate) async fn quick_create_view(
+    &self,
+    params: QuickCreateViewParams,
+  ) -> FlowyResult<ViewRevision> {
+    let settings = self
+      .workspace_controller
+      .read_workspace_settings(None)
+      .await?;
+    let belong_to_id = match settings.quick_create_location {
+      QuickCreateLocationPB::Inbox => self.get_or_create_inbox_app(&settings).await?,
+      QuickCreateLocationPB::CurrentParent => {
+        match self.current_parent_id(params.parent_id).await {
+          Some(parent_id) => parent_id,
+          None => self.get_or_create_inbox_app(&settings).await?,
+        }
+      },
+    };
+    let layout = params
+      .layout
+      .unwrap_or_else(|| settings.default_view_layout.clone());
+    let params = CreateViewParams {
+      belong_to_id,
+      name: params.name,
+      desc: "".to_owned(),
+      thumbnail: "".to_owned(),
+      data_format: data_format_from_layout(&layout),
+      layout,
+      view_id: gen_view_id(),
+      initial_data: vec![],
+      ext: HashMap::new(),
+    };
+    self.view_controller.create_view_from_params(params).await
+  }
+
+  /// Returns the parent of the request, or the parent of the latest opened view, or the first
+  /// app of the workspace. None if the workspace has no apps.
+  async fn current_parent_id(&self, parent_id: Option<String>) -> Option<String> {
+    if parent_id.is_some() {
+      return parent_id;
+    }
+    if let Ok(Some(view_rev)) = self.view_controller.latest_visit_view().await {
+      return Some(view_rev.app_id);
+    }
+    self
+      .workspace_controller
+      .read_current_workspace_apps()
+      .await
+      .ok()?
+      .into_iter()
+      .next()
+      .map(|app_rev| app_rev.id)
+  }
+
+  /// Returns the id of the inbox app of the workspace. The app is created and saved in the
+  /// settings if the workspace has none yet or if it was deleted.
+  async fn get_or_create_inbox_app(&self, settings: &WorkspaceSettingsPB) -> FlowyResult<String> {
+    if let Some(app_id) = settings.inbox_app_id.as_ref() {
+      let app = self.app_controller.read_app(AppIdPB::new(app_id)).await;
+      if matches!(app, Ok(Some(_))) {
+        return Ok(app_id.clone());
+      }
+    }
+
+    let app = self
+      .app_controller
+      .create_app_from_params(CreateAppParams {
+        workspace_id: settings.workspace_id.clone(),
+        name: INBOX_APP_NAME.to_owned(),
+        desc: "".to_owned(),
+        color_style: Default::default(),
+      })
+      .await?;
+    let params: WorkspaceSettingsParams = WorkspaceSettingsPB {
+      inbox_app_id: Some(app.id.clone()),
+      ..settings.clone()
+    }
+    .try_into()?;
+    self
+      .workspace_controller
+      .update_workspace_settings(params)
+      .await?;
+    Ok(app.id)
+  }
 }
 
+const INBOX_APP_NAME: &str = "Inbox";
+
 struct DefaultFolderBuilder();
 impl DefaultFolderBuilder {
   async fn build<F: Fn() -> (ViewDataFormatPB, Bytes)>(
@@ -295,10 +494,16 @@ pub trait ViewDataProcessor {
   /// the backend
   fn close_view(&self, view_id: &str) -> FutureResult<(), FlowyError>;
 
+  /// Called after the view was permanently deleted, e.g. the view was removed from the trash.
+  fn delete_view(&self, view_id: &str) -> FutureResult<(), FlowyError>;
+
   /// Gets the data of the this view.
   /// For example, the data can be used to duplicate the view.
   fn get_view_data(&self, view: &ViewPB) -> FutureResult<Bytes, FlowyError>;
 
+  /// Gets the plain text of this view that will be indexed for searching.
+  fn get_view_search_content(&self, view_id: &str) -> FutureResult<String, FlowyError>;
+
   /// Create a view with the pre-defined data.
   /// For example, the initial data of the grid/calendar/kanban board when
   /// you create a new view.
@@ -323,7 +528,80 @@ pub trait ViewDataProcessor {
     ext: HashMap<String, String>,
   ) -> FutureResult<(), FlowyError>;
 
+  /// Create a view with the data of the file. The processor that doesn't support importing
+  /// the given type of file should return an error.
+  fn import_from_file_path(
+    &self,
+    view_id: &str,
+    name: &str,
+    file_path: &str,
+    import_type: ImportTypePB,
+  ) -> FutureResult<(), FlowyError>;
+
+  /// Create a view filled with `size` synthetic items, e.g. the paragraphs of a document or
+  /// the rows of a database. It's used to generate large workspaces for performance testing.
+  #[cfg(feature = "dev")]
+  fn create_view_with_synthetic_data(
+    &self,
+    _user_id: &str,
+    _view_id: &str,
+    _name: &str,
+    layout: ViewLayoutTypePB,
+    _size: usize,
+  ) -> FutureResult<(), FlowyError> {
+    FutureResult::new(async move {
+      let msg = format!("Can't generate the synthetic data of {:?}", layout);
+      Err(FlowyError::internal().context(msg))
+    })
+  }
+
   fn data_types(&self) -> Vec<ViewDataFormatPB>;
+
+  /// Returns the number of the local revisions of the opened views that were not acked by the
+  /// server yet. The data that is not synced with the server returns zero.
+  fn number_of_unacked_revisions(&self) -> FutureResult<usize, FlowyError> {
+    FutureResult::new(async { Ok(0) })
+  }
+
+  /// Returns the estimated bytes held in memory by the opened views of this processor.
+  fn memory_usage(&self) -> FutureResult<ViewDataMemoryUsage, FlowyError> {
+    FutureResult::new(async { Ok(ViewDataMemoryUsage::default()) })
+  }
+
+  /// Drops the caches that can be rebuilt on demand. The opened views must keep working after
+  /// the caches were dropped.
+  fn trim_caches(&self) -> FutureResult<(), FlowyError> {
+    FutureResult::new(async { Ok(()) })
+  }
+}
+
+/// The estimated bytes held in memory by the opened views of a [ViewDataProcessor].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ViewDataMemoryUsage {
+  pub cell_data_cache_bytes: usize,
+  pub open_document_bytes: usize,
+  pub filter_result_bytes: usize,
+}
+
+/// Packs the directory of the exported views into a single archive file, and unpacks it when
+/// the archive is imported. The archive is encrypted with the key derived from the passphrase
+/// if it's set.
+pub trait ViewArchiver: Send + Sync {
+  fn write_archive(
+    &self,
+    dir: &Path,
+    archive_path: &Path,
+    passphrase: Option<&str>,
+  ) -> FlowyResult<()>;
+
+  /// Returns [FlowyError::invalid_passphrase] if the archive is encrypted and the passphrase is
+  /// missing or wrong.
+  fn read_archive(
+    &self,
+    archive_path: &Path,
+    dir: &Path,
+    passphrase: Option<&str>,
+  ) -> FlowyResult<()>;
 }
 
 pub type ViewDataProcessorMap =
@@ -15,6 +15,9 @@ pub(crate) enum FolderNotification {
   DidUpdateWorkspaceApps = 4,
   /// Trigger when the settings of the workspace are changed. The changes including the latest visiting view, etc
   DidUpdateWorkspaceSetting = 5,
+  /// Trigger when the shared settings of the workspace, e.g. the default database layout, are
+  /// changed locally or by the other members
+  DidUpdateWorkspaceSettings = 6,
   /// Trigger when the properties including rename,update description of the app are changed
   DidUpdateApp = 20,
   /// Trigger when the properties including rename,update description of the view are changed
@@ -27,6 +30,8 @@ pub(crate) enum FolderNotification {
   DidMoveViewToTrash = 33,
   /// Trigger when the number of trash is changed
   DidUpdateTrash = 34,
+  /// Trigger when the permission of the view is changed
+  DidUpdateViewPermission = 35,
 }
 
 impl std::default::Default for FolderNotification {
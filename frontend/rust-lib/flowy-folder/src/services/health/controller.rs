@@ -0,0 +1,121 @@
+use crate::{
+  entities::health::{MemoryReportPB, WorkspaceHealthPB},
+  errors::FlowyResult,
+  manager::{ViewDataMemoryUsage, ViewDataProcessor, ViewDataProcessorMap},
+  services::{folder_editor::FolderEditor, QuotaController, SearchController},
+};
+use flowy_revision::RevisionWebSocket;
+use flowy_task::TaskDispatcher;
+use lib_ws::WSConnectState;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::RwLock as TokioRwLock;
+
+/// [HealthController] collects the state of the sync, the background tasks, the storage and the
+/// search index into one [WorkspaceHealthPB], so the user can find out what goes wrong without
+/// reading the logs.
+pub struct HealthController {
+  folder_editor: Arc<TokioRwLock<Option<Arc<FolderEditor>>>>,
+  data_processors: ViewDataProcessorMap,
+  task_scheduler: Arc<TokioRwLock<TaskDispatcher>>,
+  search_controller: Arc<SearchController>,
+  quota_controller: Arc<QuotaController>,
+  web_socket: Arc<dyn RevisionWebSocket>,
+}
+
+impl HealthController {
+  pub(crate) fn new(
+    folder_editor: Arc<TokioRwLock<Option<Arc<FolderEditor>>>>,
+    data_processors: ViewDataProcessorMap,
+    task_scheduler: Arc<TokioRwLock<TaskDispatcher>>,
+    search_controller: Arc<SearchController>,
+    quota_controller: Arc<QuotaController>,
+    web_socket: Arc<dyn RevisionWebSocket>,
+  ) -> Self {
+    Self {
+      folder_editor,
+      data_processors,
+      task_scheduler,
+      search_controller,
+      quota_controller,
+      web_socket,
+    }
+  }
+
+  pub(crate) async fn read_workspace_health(&self) -> FlowyResult<WorkspaceHealthPB> {
+    let sync_backlog = self.number_of_unacked_revisions().await? as i64;
+    let is_connected = self.web_socket.connect_state().await == WSConnectState::Connected;
+
+    let (pending_tasks, failed_tasks) = {
+      let task_scheduler = self.task_scheduler.read().await;
+      (
+        task_scheduler.number_of_pending_tasks() as i64,
+        task_scheduler.number_of_failed_tasks() as i64,
+      )
+    };
+
+    Ok(WorkspaceHealthPB {
+      sync_backlog,
+      is_connected,
+      pending_tasks,
+      failed_tasks,
+      storage: self.quota_controller.read_quota_usage()?,
+      search_index: self.search_controller.read_index_health().await,
+    })
+  }
+
+  pub(crate) async fn read_memory_report(&self) -> FlowyResult<MemoryReportPB> {
+    let mut usage = ViewDataMemoryUsage::default();
+    for processor in self.unique_data_processors() {
+      let processor_usage = processor.memory_usage().await?;
+      usage.cell_data_cache_bytes += processor_usage.cell_data_cache_bytes;
+      usage.open_document_bytes += processor_usage.open_document_bytes;
+      usage.filter_result_bytes += processor_usage.filter_result_bytes;
+    }
+    let search_index_bytes = self.search_controller.estimated_index_bytes().await;
+
+    let total_bytes = usage.cell_data_cache_bytes
+      + usage.open_document_bytes
+      + usage.filter_result_bytes
+      + search_index_bytes;
+    Ok(MemoryReportPB {
+      cell_data_cache_bytes: usage.cell_data_cache_bytes as i64,
+      open_document_bytes: usage.open_document_bytes as i64,
+      filter_result_bytes: usage.filter_result_bytes as i64,
+      search_index_bytes: search_index_bytes as i64,
+      total_bytes: total_bytes as i64,
+    })
+  }
+
+  /// Drops the caches of the opened views. The search index is kept, otherwise the search
+  /// would stop working until the index is rebuilt.
+  pub(crate) async fn trim_caches(&self) -> FlowyResult<()> {
+    for processor in self.unique_data_processors() {
+      processor.trim_caches().await?;
+    }
+    Ok(())
+  }
+
+  async fn number_of_unacked_revisions(&self) -> FlowyResult<usize> {
+    let mut number = match self.folder_editor.read().await.clone() {
+      None => 0,
+      Some(editor) => editor.rev_manager().number_of_unacked_revisions().await,
+    };
+
+    for processor in self.unique_data_processors() {
+      number += processor.number_of_unacked_revisions().await?;
+    }
+    Ok(number)
+  }
+
+  /// The same processor is registered for each of its data formats, so it's returned once.
+  fn unique_data_processors(&self) -> Vec<Arc<dyn ViewDataProcessor + Send + Sync>> {
+    let mut visited = HashSet::new();
+    self
+      .data_processors
+      .values()
+      .filter(|processor| visited.insert(Arc::as_ptr(processor) as *const u8))
+      .cloned()
+      .collect()
+  }
+}
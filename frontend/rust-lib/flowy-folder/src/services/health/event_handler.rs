@@ -0,0 +1,29 @@
+use crate::{
+  entities::health::{MemoryReportPB, WorkspaceHealthPB},
+  errors::FlowyError,
+  services::HealthController,
+};
+use lib_dispatch::prelude::{data_result_ok, AFPluginState, DataResult};
+use std::sync::Arc;
+
+pub(crate) async fn get_workspace_health_handler(
+  controller: AFPluginState<Arc<HealthController>>,
+) -> DataResult<WorkspaceHealthPB, FlowyError> {
+  let health = controller.read_workspace_health().await?;
+  data_result_ok(health)
+}
+
+pub(crate) async fn get_memory_report_handler(
+  controller: AFPluginState<Arc<HealthController>>,
+) -> DataResult<MemoryReportPB, FlowyError> {
+  let report = controller.read_memory_report().await?;
+  data_result_ok(report)
+}
+
+#[tracing::instrument(level = "debug", skip(controller), err)]
+pub(crate) async fn trim_caches_handler(
+  controller: AFPluginState<Arc<HealthController>>,
+) -> Result<(), FlowyError> {
+  controller.trim_caches().await?;
+  Ok(())
+}
@@ -1,11 +1,21 @@
 pub(crate) use app::controller::*;
+pub(crate) use health::controller::*;
+pub(crate) use permission::controller::*;
+pub(crate) use quota::controller::*;
+pub(crate) use search::controller::*;
 pub(crate) use trash::controller::*;
 pub(crate) use view::controller::*;
 pub(crate) use workspace::controller::*;
 
 pub(crate) mod app;
 pub mod folder_editor;
+pub mod health;
+pub mod permission;
 pub(crate) mod persistence;
+pub mod quota;
+pub mod search;
+#[cfg(feature = "dev")]
+pub(crate) mod synthetic;
 pub(crate) mod trash;
 pub(crate) mod view;
 mod web_socket;
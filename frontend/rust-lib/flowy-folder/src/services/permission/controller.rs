@@ -0,0 +1,100 @@
+use crate::{
+  entities::permission::{UpdateViewPermissionParams, ViewPermissionPB, ViewPermissionTypePB},
+  errors::{FlowyError, FlowyResult},
+  notification::{send_notification, FolderNotification},
+  services::persistence::FolderPersistence,
+};
+use flowy_sqlite::kv::KV;
+use std::sync::Arc;
+
+const VIEW_PERMISSION_PREFIX: &str = "view_permission";
+
+/// [PermissionController] keeps the permission of each view. The permission is stored in the
+/// view of the folder, so it's synced with the other devices. The views without a permission are
+/// treated as editable.
+///
+/// The permissions were stored in the [KV] store before, they are moved to the folder when they
+/// are read.
+pub struct PermissionController {
+  persistence: Arc<FolderPersistence>,
+}
+
+impl PermissionController {
+  pub fn new(persistence: Arc<FolderPersistence>) -> Self {
+    Self { persistence }
+  }
+
+  pub async fn read_view_permission(&self, view_id: &str) -> FlowyResult<ViewPermissionPB> {
+    let key = view_permission_key(view_id);
+    let permission = self
+      .persistence
+      .begin_transaction(|transaction| {
+        let view_rev = transaction.read_view(view_id)?;
+        if view_rev.permission != 0 {
+          return Ok(view_rev.permission);
+        }
+
+        match KV::get_int(&key) {
+          None => Ok(0),
+          Some(permission) => {
+            transaction.update_view_permission(view_id, permission)?;
+            let _ = KV::remove(&key);
+            Ok(permission)
+          },
+        }
+      })
+      .await?;
+
+    Ok(ViewPermissionPB {
+      view_id: view_id.to_owned(),
+      permission: ViewPermissionTypePB::from_value(permission),
+    })
+  }
+
+  #[tracing::instrument(level = "debug", skip(self, params), fields(view_id = %params.view_id), err)]
+  pub async fn update_view_permission(
+    &self,
+    params: UpdateViewPermissionParams,
+  ) -> FlowyResult<()> {
+    let view_id = params.view_id.clone();
+    self
+      .persistence
+      .begin_transaction(|transaction| {
+        transaction.update_view_permission(&params.view_id, params.permission.value())?;
+        let _ = KV::remove(&view_permission_key(&params.view_id));
+        Ok(())
+      })
+      .await?;
+
+    let view_permission = self.read_view_permission(&view_id).await?;
+    send_notification(&view_id, FolderNotification::DidUpdateViewPermission)
+      .payload(view_permission)
+      .send();
+    Ok(())
+  }
+
+  /// Returns [FlowyError::permission_denied] if the view can't be modified. The views that can't
+  /// be found are passed through, the handler of the event will report the error.
+  pub async fn check_view_modifiable(&self, view_id: &str) -> FlowyResult<()> {
+    let permission = match self.read_view_permission(view_id).await {
+      Ok(view_permission) => view_permission.permission,
+      Err(e) if e.is_record_not_found() => return Ok(()),
+      Err(e) => return Err(e),
+    };
+
+    if permission.can_modify() {
+      Ok(())
+    } else {
+      tracing::warn!(
+        "Reject modifying the view:{} with permission: {:?}",
+        view_id,
+        permission
+      );
+      Err(FlowyError::permission_denied())
+    }
+  }
+}
+
+fn view_permission_key(view_id: &str) -> String {
+  format!("{}:{}", VIEW_PERMISSION_PREFIX, view_id)
+}
@@ -0,0 +1,29 @@
+use crate::{
+  entities::{
+    permission::{UpdateViewPermissionParams, UpdateViewPermissionPayloadPB, ViewPermissionPB},
+    view::ViewIdPB,
+  },
+  errors::FlowyError,
+  services::PermissionController,
+};
+use lib_dispatch::prelude::{data_result_ok, AFPluginData, AFPluginState, DataResult};
+use std::{convert::TryInto, sync::Arc};
+
+pub(crate) async fn read_view_permission_handler(
+  data: AFPluginData<ViewIdPB>,
+  controller: AFPluginState<Arc<PermissionController>>,
+) -> DataResult<ViewPermissionPB, FlowyError> {
+  let view_id: ViewIdPB = data.into_inner();
+  let view_permission = controller.read_view_permission(&view_id.value).await?;
+  data_result_ok(view_permission)
+}
+
+#[tracing::instrument(level = "debug", skip(data, controller), err)]
+pub(crate) async fn update_view_permission_handler(
+  data: AFPluginData<UpdateViewPermissionPayloadPB>,
+  controller: AFPluginState<Arc<PermissionController>>,
+) -> Result<(), FlowyError> {
+  let params: UpdateViewPermissionParams = data.into_inner().try_into()?;
+  controller.update_view_permission(params).await?;
+  Ok(())
+}
@@ -0,0 +1,88 @@
+use crate::{
+  entities::view::{
+    MoveFolderItemPayloadPB, MoveFolderItemType, RepeatedViewIdPB, UpdateViewPayloadPB,
+  },
+  event_map::FolderEvent,
+  manager::FolderManager,
+  services::PermissionController,
+};
+use futures::future::BoxFuture;
+use lib_dispatch::prelude::{
+  AFPluginData, AFPluginEvent, AFPluginFromBytes, AFPluginInterceptor, AFPluginRequest,
+  DispatchError, Payload,
+};
+use std::{collections::HashMap, convert::TryFrom, sync::Arc};
+
+/// Returns the ids of the views that will be modified by the event.
+pub type ViewIdsFromPayload = fn(&Payload) -> Vec<String>;
+
+/// [ViewPermissionInterceptor] checks the permission of the views before the mutating events
+/// reach their handlers. The events that are not guarded will be passed through.
+///
+/// The folder events are guarded by default. Other plugins can guard their events by calling
+/// [ViewPermissionInterceptor::guard] with a function that extracts the view ids from the payload.
+pub struct ViewPermissionInterceptor {
+  permission_controller: Arc<PermissionController>,
+  guarded_events: HashMap<AFPluginEvent, ViewIdsFromPayload>,
+}
+
+impl ViewPermissionInterceptor {
+  pub fn new(folder_manager: &FolderManager) -> Self {
+    Self {
+      permission_controller: folder_manager.permission_controller.clone(),
+      guarded_events: HashMap::new(),
+    }
+    .guard(FolderEvent::UpdateView, |payload| {
+      parse_payload::<UpdateViewPayloadPB>(payload)
+        .map(|payload| vec![payload.view_id])
+        .unwrap_or_default()
+    })
+    .guard(FolderEvent::DeleteView, |payload| {
+      parse_payload::<RepeatedViewIdPB>(payload)
+        .map(|payload| payload.items)
+        .unwrap_or_default()
+    })
+    .guard(FolderEvent::MoveItem, |payload| {
+      match parse_payload::<MoveFolderItemPayloadPB>(payload) {
+        Some(payload) if matches!(payload.ty, MoveFolderItemType::MoveView) => {
+          vec![payload.item_id]
+        },
+        _ => vec![],
+      }
+    })
+  }
+
+  pub fn guard<E: Into<AFPluginEvent>>(mut self, event: E, f: ViewIdsFromPayload) -> Self {
+    self.guarded_events.insert(event.into(), f);
+    self
+  }
+}
+
+impl AFPluginInterceptor for ViewPermissionInterceptor {
+  fn intercept(&self, request: &AFPluginRequest) -> BoxFuture<'static, Result<(), DispatchError>> {
+    let view_ids = match self.guarded_events.get(&request.event) {
+      None => vec![],
+      Some(view_ids_from_payload) => view_ids_from_payload(request.get_payload()),
+    };
+    let permission_controller = self.permission_controller.clone();
+    Box::pin(async move {
+      for view_id in view_ids {
+        permission_controller
+          .check_view_modifiable(&view_id)
+          .await?;
+      }
+      Ok(())
+    })
+  }
+}
+
+/// Parses the payload of the request. Returns None if the payload is empty or invalid, the
+/// handler of the event will report the error.
+pub fn parse_payload<T>(payload: &Payload) -> Option<T>
+where
+  T: AFPluginFromBytes,
+{
+  AFPluginData::<T>::try_from(payload)
+    .ok()
+    .map(|data| data.into_inner())
+}
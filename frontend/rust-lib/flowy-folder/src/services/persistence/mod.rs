@@ -13,8 +13,11 @@ use flowy_client_sync::client_folder::{FolderOperationsBuilder, FolderPad};
 use flowy_error::{FlowyError, FlowyResult};
 use flowy_revision_persistence::{RevisionDiskCache, RevisionState, SyncRecord};
 use flowy_sqlite::ConnectionPool;
-use folder_model::{AppRevision, TrashRevision, ViewRevision, WorkspaceRevision};
+use folder_model::{
+  AppRevision, TrashRevision, ViewRevision, WorkspaceRevision, WorkspaceSettingsRevision,
+};
 use revision_model::Revision;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 pub use version_1::{
@@ -29,6 +32,11 @@ pub trait FolderPersistenceTransaction {
     workspace_id: Option<String>,
   ) -> FlowyResult<Vec<WorkspaceRevision>>;
   fn update_workspace(&self, changeset: WorkspaceChangeset) -> FlowyResult<()>;
+  fn update_workspace_settings(
+    &self,
+    workspace_id: &str,
+    settings: WorkspaceSettingsRevision,
+  ) -> FlowyResult<()>;
   fn delete_workspace(&self, workspace_id: &str) -> FlowyResult<()>;
 
   fn create_app(&self, app_rev: AppRevision) -> FlowyResult<()>;
@@ -42,6 +50,15 @@ pub trait FolderPersistenceTransaction {
   fn read_view(&self, view_id: &str) -> FlowyResult<ViewRevision>;
   fn read_views(&self, belong_to_id: &str) -> FlowyResult<Vec<ViewRevision>>;
   fn update_view(&self, changeset: ViewChangeset) -> FlowyResult<()>;
+  fn update_view_last_edited(
+    &self,
+    view_id: &str,
+    modified_time: i64,
+    edited_by: &str,
+  ) -> FlowyResult<()>;
+  fn update_view_slug(&self, view_id: &str, slug: &str) -> FlowyResult<()>;
+  fn update_view_permission(&self, view_id: &str, permission: i64) -> FlowyResult<()>;
+  fn update_view_state(&self, view_id: &str, state: HashMap<String, String>) -> FlowyResult<()>;
   fn delete_view(&self, view_id: &str) -> FlowyResult<ViewRevision>;
   fn move_view(&self, view_id: &str, from: usize, to: usize) -> FlowyResult<()>;
 
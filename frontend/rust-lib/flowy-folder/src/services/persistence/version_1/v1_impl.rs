@@ -8,7 +8,10 @@ use crate::services::persistence::{
 };
 use flowy_error::FlowyResult;
 use flowy_sqlite::DBConnection;
-use folder_model::{AppRevision, TrashRevision, ViewRevision, WorkspaceRevision};
+use folder_model::{
+  AppRevision, TrashRevision, ViewRevision, WorkspaceRevision, WorkspaceSettingsRevision,
+};
+use std::collections::HashMap;
 
 /// V1Transaction is deprecated since version 0.0.2 version
 pub struct V1Transaction<'a>(pub &'a DBConnection);
@@ -36,6 +39,14 @@ impl<'a> FolderPersistenceTransaction for V1Transaction<'a> {
     WorkspaceTableSql::update_workspace(changeset, self.0)
   }
 
+  fn update_workspace_settings(
+    &self,
+    _workspace_id: &str,
+    _settings: WorkspaceSettingsRevision,
+  ) -> FlowyResult<()> {
+    Ok(())
+  }
+
   fn delete_workspace(&self, workspace_id: &str) -> FlowyResult<()> {
     WorkspaceTableSql::delete_workspace(workspace_id, self.0)
   }
@@ -97,6 +108,34 @@ impl<'a> FolderPersistenceTransaction for V1Transaction<'a> {
     Ok(())
   }
 
+  /// The `view_table` has no column for the editor, only the `modified_time` is updated.
+  fn update_view_last_edited(
+    &self,
+    view_id: &str,
+    modified_time: i64,
+    _edited_by: &str,
+  ) -> FlowyResult<()> {
+    let changeset = ViewChangeset {
+      id: view_id.to_owned(),
+      modified_time,
+      ..Default::default()
+    };
+    ViewTableSql::update_view(changeset, self.0)?;
+    Ok(())
+  }
+
+  fn update_view_slug(&self, _view_id: &str, _slug: &str) -> FlowyResult<()> {
+    Ok(())
+  }
+
+  fn update_view_permission(&self, _view_id: &str, _permission: i64) -> FlowyResult<()> {
+    Ok(())
+  }
+
+  fn update_view_state(&self, _view_id: &str, _state: HashMap<String, String>) -> FlowyResult<()> {
+    Ok(())
+  }
+
   fn delete_view(&self, view_id: &str) -> FlowyResult<ViewRevision> {
     let view_revision: ViewRevision = ViewTableSql::read_view(view_id, self.0)?.into();
     ViewTableSql::delete_view(view_id, self.0)?;
@@ -156,6 +195,14 @@ where
     (**self).update_workspace(changeset)
   }
 
+  fn update_workspace_settings(
+    &self,
+    workspace_id: &str,
+    settings: WorkspaceSettingsRevision,
+  ) -> FlowyResult<()> {
+    (**self).update_workspace_settings(workspace_id, settings)
+  }
+
   fn delete_workspace(&self, workspace_id: &str) -> FlowyResult<()> {
     (**self).delete_workspace(workspace_id)
   }
@@ -200,6 +247,27 @@ where
     (**self).update_view(changeset)
   }
 
+  fn update_view_last_edited(
+    &self,
+    view_id: &str,
+    modified_time: i64,
+    edited_by: &str,
+  ) -> FlowyResult<()> {
+    (**self).update_view_last_edited(view_id, modified_time, edited_by)
+  }
+
+  fn update_view_slug(&self, view_id: &str, slug: &str) -> FlowyResult<()> {
+    (**self).update_view_slug(view_id, slug)
+  }
+
+  fn update_view_permission(&self, view_id: &str, permission: i64) -> FlowyResult<()> {
+    (**self).update_view_permission(view_id, permission)
+  }
+
+  fn update_view_state(&self, view_id: &str, state: HashMap<String, String>) -> FlowyResult<()> {
+    (**self).update_view_state(view_id, state)
+  }
+
   fn delete_view(&self, view_id: &str) -> FlowyResult<ViewRevision> {
     (**self).delete_view(view_id)
   }
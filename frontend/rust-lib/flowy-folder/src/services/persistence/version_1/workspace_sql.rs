@@ -98,6 +98,7 @@ impl std::convert::From<WorkspaceTable> for WorkspaceRevision {
       apps: vec![],
       modified_time: table.modified_time,
       create_time: table.create_time,
+      settings: Default::default(),
     }
   }
 }
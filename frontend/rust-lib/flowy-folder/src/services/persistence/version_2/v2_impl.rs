@@ -3,7 +3,10 @@ use crate::services::{
   persistence::{AppChangeset, FolderPersistenceTransaction, ViewChangeset, WorkspaceChangeset},
 };
 use flowy_error::{FlowyError, FlowyResult};
-use folder_model::{AppRevision, TrashRevision, ViewRevision, WorkspaceRevision};
+use folder_model::{
+  AppRevision, TrashRevision, ViewRevision, WorkspaceRevision, WorkspaceSettingsRevision,
+};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 impl FolderPersistenceTransaction for FolderEditor {
@@ -35,6 +38,21 @@ impl FolderPersistenceTransaction for FolderEditor {
     Ok(())
   }
 
+  fn update_workspace_settings(
+    &self,
+    workspace_id: &str,
+    settings: WorkspaceSettingsRevision,
+  ) -> FlowyResult<()> {
+    if let Some(change) = self
+      .folder
+      .write()
+      .update_workspace_settings(workspace_id, settings)?
+    {
+      self.apply_change(change)?;
+    }
+    Ok(())
+  }
+
   fn delete_workspace(&self, workspace_id: &str) -> FlowyResult<()> {
     if let Some(change) = self.folder.write().delete_workspace(workspace_id)? {
       self.apply_change(change)?;
@@ -124,6 +142,48 @@ impl FolderPersistenceTransaction for FolderEditor {
     Ok(())
   }
 
+  fn update_view_last_edited(
+    &self,
+    view_id: &str,
+    modified_time: i64,
+    edited_by: &str,
+  ) -> FlowyResult<()> {
+    if let Some(change) =
+      self
+        .folder
+        .write()
+        .update_view_last_edited(view_id, modified_time, edited_by)?
+    {
+      self.apply_change(change)?;
+    }
+    Ok(())
+  }
+
+  fn update_view_slug(&self, view_id: &str, slug: &str) -> FlowyResult<()> {
+    if let Some(change) = self.folder.write().update_view_slug(view_id, slug)? {
+      self.apply_change(change)?;
+    }
+    Ok(())
+  }
+
+  fn update_view_permission(&self, view_id: &str, permission: i64) -> FlowyResult<()> {
+    if let Some(change) = self
+      .folder
+      .write()
+      .update_view_permission(view_id, permission)?
+    {
+      self.apply_change(change)?;
+    }
+    Ok(())
+  }
+
+  fn update_view_state(&self, view_id: &str, state: HashMap<String, String>) -> FlowyResult<()> {
+    if let Some(change) = self.folder.write().update_view_state(view_id, state)? {
+      self.apply_change(change)?;
+    }
+    Ok(())
+  }
+
   fn delete_view(&self, view_id: &str) -> FlowyResult<ViewRevision> {
     let view = self.folder.read().read_view(view_id)?;
     if let Some(change) = self.folder.write().delete_view(&view.app_id, view_id)? {
@@ -179,6 +239,14 @@ where
     (**self).update_workspace(changeset)
   }
 
+  fn update_workspace_settings(
+    &self,
+    workspace_id: &str,
+    settings: WorkspaceSettingsRevision,
+  ) -> FlowyResult<()> {
+    (**self).update_workspace_settings(workspace_id, settings)
+  }
+
   fn delete_workspace(&self, workspace_id: &str) -> FlowyResult<()> {
     (**self).delete_workspace(workspace_id)
   }
@@ -223,6 +291,27 @@ where
     (**self).update_view(changeset)
   }
 
+  fn update_view_last_edited(
+    &self,
+    view_id: &str,
+    modified_time: i64,
+    edited_by: &str,
+  ) -> FlowyResult<()> {
+    (**self).update_view_last_edited(view_id, modified_time, edited_by)
+  }
+
+  fn update_view_slug(&self, view_id: &str, slug: &str) -> FlowyResult<()> {
+    (**self).update_view_slug(view_id, slug)
+  }
+
+  fn update_view_permission(&self, view_id: &str, permission: i64) -> FlowyResult<()> {
+    (**self).update_view_permission(view_id, permission)
+  }
+
+  fn update_view_state(&self, view_id: &str, state: HashMap<String, String>) -> FlowyResult<()> {
+    (**self).update_view_state(view_id, state)
+  }
+
   fn delete_view(&self, view_id: &str) -> FlowyResult<ViewRevision> {
     (**self).delete_view(view_id)
   }
@@ -0,0 +1,197 @@
+use crate::{
+  entities::quota::{QuotaUsagePB, WorkspaceQuota},
+  errors::{FlowyError, FlowyResult},
+  event_map::{FolderCouldServiceV1, WorkspaceUser},
+};
+use parking_lot::RwLock;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// The cached size of the workspace is computed again in the background after this duration.
+const WORKSPACE_SIZE_TTL: Duration = Duration::from_secs(60);
+
+/// [QuotaController] enforces the limits of the workspace. The quota is read from the cloud
+/// service when the folder gets initialized, the local quota is used if the cloud service
+/// doesn't provide one.
+///
+/// Walking the directory of the user is too slow to do for each check, so the size of the
+/// workspace is cached. The files that are known to be added are added to the cached size right
+/// away, the other changes, e.g. the growth of the database files, are picked up by computing
+/// the size again once the cache is older than [WORKSPACE_SIZE_TTL].
+pub struct QuotaController {
+  user: Arc<dyn WorkspaceUser>,
+  cloud_service: Arc<dyn FolderCouldServiceV1>,
+  local_quota: WorkspaceQuota,
+  quota: RwLock<WorkspaceQuota>,
+  workspace_size: Arc<RwLock<Option<CachedWorkspaceSize>>>,
+  is_computing_size: Arc<AtomicBool>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct CachedWorkspaceSize {
+  size: u64,
+  computed_at: Instant,
+}
+
+impl QuotaController {
+  pub(crate) fn new(
+    user: Arc<dyn WorkspaceUser>,
+    cloud_service: Arc<dyn FolderCouldServiceV1>,
+    local_quota: WorkspaceQuota,
+  ) -> Self {
+    Self {
+      user,
+      cloud_service,
+      quota: RwLock::new(local_quota.clone()),
+      local_quota,
+      workspace_size: Arc::new(RwLock::new(None)),
+      is_computing_size: Arc::new(AtomicBool::new(false)),
+    }
+  }
+
+  pub(crate) async fn initialize(&self) -> FlowyResult<()> {
+    let token = self.user.token()?;
+    let quota = match self.cloud_service.read_workspace_quota(&token).await {
+      Ok(Some(quota)) => quota,
+      Ok(None) => self.local_quota.clone(),
+      Err(e) => {
+        tracing::error!("Read the quota of the workspace failed: {:?}", e);
+        self.local_quota.clone()
+      },
+    };
+    tracing::debug!("The quota of the workspace: {:?}", quota);
+    *self.quota.write() = quota;
+    // The size of the workspace of the previous user is outdated
+    *self.workspace_size.write() = None;
+    Ok(())
+  }
+
+  pub fn read_quota(&self) -> WorkspaceQuota {
+    self.quota.read().clone()
+  }
+
+  pub(crate) fn read_quota_usage(&self) -> FlowyResult<QuotaUsagePB> {
+    let workspace_size = self.workspace_size()?;
+    Ok(QuotaUsagePB {
+      quota: self.read_quota().into(),
+      workspace_size: i64::try_from(workspace_size).unwrap_or(i64::MAX),
+    })
+  }
+
+  /// Returns [FlowyError::attachment_too_large] if the file at `file_path` exceeds the max
+  /// attachment size.
+  pub(crate) fn check_attachment_size(&self, file_path: &str) -> FlowyResult<()> {
+    let max_attachment_size = self.quota.read().max_attachment_size;
+    if max_attachment_size == 0 {
+      return Ok(());
+    }
+
+    let file_size = std::fs::metadata(file_path)
+      .map_err(|e| FlowyError::record_not_found().context(e))?
+      .len();
+    if file_size > max_attachment_size {
+      let msg = format!(
+        "The file should not be more than {} bytes, but it is {} bytes",
+        max_attachment_size, file_size
+      );
+      return Err(FlowyError::attachment_too_large().context(msg));
+    }
+    Ok(())
+  }
+
+  /// Returns [FlowyError::workspace_size_exceeded] if the data of the workspace reached the
+  /// max workspace size.
+  pub(crate) fn check_workspace_size(&self) -> FlowyResult<()> {
+    let max_workspace_size = self.quota.read().max_workspace_size;
+    if max_workspace_size == 0 {
+      return Ok(());
+    }
+
+    let workspace_size = self.workspace_size()?;
+    if workspace_size >= max_workspace_size {
+      let msg = format!(
+        "The workspace uses {} bytes of its {} bytes",
+        workspace_size, max_workspace_size
+      );
+      return Err(FlowyError::workspace_size_exceeded().context(msg));
+    }
+    Ok(())
+  }
+
+  /// Adds the size of the data that was added to the workspace to its cached size.
+  pub(crate) fn did_add_workspace_data(&self, size: u64) {
+    if let Some(cached) = self.workspace_size.write().as_mut() {
+      cached.size = cached.size.saturating_add(size);
+    }
+  }
+
+  /// The data of the workspace is stored in the directory of the user. The size is computed
+  /// when it's read the first time, the outdated size is returned while it's computed again.
+  fn workspace_size(&self) -> FlowyResult<u64> {
+    let cached = *self.workspace_size.read();
+    match cached {
+      None => {
+        let size = compute_workspace_size(&self.user.user_dir()?)?;
+        *self.workspace_size.write() = Some(CachedWorkspaceSize {
+          size,
+          computed_at: Instant::now(),
+        });
+        Ok(size)
+      },
+      Some(cached) => {
+        if cached.computed_at.elapsed() >= WORKSPACE_SIZE_TTL {
+          self.compute_workspace_size_in_background();
+        }
+        Ok(cached.size)
+      },
+    }
+  }
+
+  fn compute_workspace_size_in_background(&self) {
+    if self.is_computing_size.swap(true, Ordering::SeqCst) {
+      return;
+    }
+
+    let user_dir = match self.user.user_dir() {
+      Ok(user_dir) => user_dir,
+      Err(_) => {
+        self.is_computing_size.store(false, Ordering::SeqCst);
+        return;
+      },
+    };
+    let workspace_size = self.workspace_size.clone();
+    let is_computing_size = self.is_computing_size.clone();
+    tokio::task::spawn_blocking(move || {
+      match compute_workspace_size(&user_dir) {
+        Ok(size) => {
+          *workspace_size.write() = Some(CachedWorkspaceSize {
+            size,
+            computed_at: Instant::now(),
+          })
+        },
+        Err(e) => tracing::error!("Compute the size of the workspace failed: {:?}", e),
+      }
+      is_computing_size.store(false, Ordering::SeqCst);
+    });
+  }
+}
+
+fn compute_workspace_size(user_dir: &str) -> FlowyResult<u64> {
+  dir_size(Path::new(user_dir)).map_err(|e| FlowyError::internal().context(e))
+}
+
+fn dir_size(path: &Path) -> std::io::Result<u64> {
+  let mut size = 0;
+  for entry in std::fs::read_dir(path)? {
+    let entry = entry?;
+    let metadata = entry.metadata()?;
+    if metadata.is_dir() {
+      size += dir_size(&entry.path())?;
+    } else {
+      size += metadata.len();
+    }
+  }
+  Ok(size)
+}
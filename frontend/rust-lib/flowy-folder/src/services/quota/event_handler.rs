@@ -0,0 +1,10 @@
+use crate::{entities::quota::QuotaUsagePB, errors::FlowyError, services::QuotaController};
+use lib_dispatch::prelude::{data_result_ok, AFPluginState, DataResult};
+use std::sync::Arc;
+
+pub(crate) async fn get_quota_usage_handler(
+  controller: AFPluginState<Arc<QuotaController>>,
+) -> DataResult<QuotaUsagePB, FlowyError> {
+  let usage = controller.read_quota_usage()?;
+  data_result_ok(usage)
+}
@@ -0,0 +1,446 @@
+use crate::entities::trash::TrashType;
+use crate::entities::{
+  QuickSwitchItemPB, QuickSwitchParams, RepeatedQuickSwitchItemPB, RepeatedSearchResultPB,
+  SearchIndexHealthPB, SearchParams, ViewAliasesPB, ViewAliasesParams,
+};
+use crate::manager::ViewDataProcessorMap;
+use crate::services::search::fuzzy::{fuzzy_score, normalize_query};
+use crate::services::search::index::{IndexedView, SearchIndex};
+use crate::services::search::recent::{recency_bonus, RecentViewTracker};
+use crate::services::search::SEARCH_INDEX_HANDLER_ID;
+use crate::{
+  errors::{internal_error, FlowyError, FlowyResult},
+  event_map::WorkspaceUser,
+  services::{persistence::FolderPersistence, TrashController, TrashEvent},
+};
+use flowy_sqlite::kv::KV;
+use flowy_task::{QualityOfService, Task, TaskContent, TaskDispatcher};
+use folder_model::ViewRevision;
+use futures::{FutureExt, StreamExt};
+use parking_lot::Mutex;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+
+const VIEW_ALIASES_PREFIX: &str = "view_aliases";
+/// The changes of the index are written to disk at most once in this duration. The whole index
+/// is written each time, which is too expensive to do for every edit of the documents.
+const FLUSH_INDEX_DELAY: Duration = Duration::from_secs(3);
+/// The bonus of the most recently visited view in the quick switcher.
+const MAX_RECENCY_BONUS: i64 = 30;
+
+/// [SearchController] keeps the search index of the current user up to date.
+///
+/// The changes of the views are indexed incrementally by the tasks scheduled in the
+/// [TaskDispatcher]. The whole index can be rebuilt by calling [SearchController::rebuild_index],
+/// it also happens automatically if the index on disk is corrupted.
+///
+/// The quick switcher matches the names and aliases of the indexed views in memory, so it doesn't
+/// need to touch the disk except for reading the trash.
+#[derive(Clone)]
+pub struct SearchController {
+  user: Arc<dyn WorkspaceUser>,
+  persistence: Arc<FolderPersistence>,
+  trash_controller: Arc<TrashController>,
+  data_processors: ViewDataProcessorMap,
+  task_scheduler: Arc<RwLock<TaskDispatcher>>,
+  index: Arc<RwLock<Option<SearchIndex>>>,
+  /// The views that are waiting to be indexed. A view will only be scheduled once no matter
+  /// how many times it was changed before the task gets run.
+  pending_view_ids: Arc<Mutex<HashSet<String>>>,
+  is_flush_scheduled: Arc<AtomicBool>,
+  recent_views: Arc<RecentViewTracker>,
+}
+
+impl SearchController {
+  pub(crate) fn new(
+    user: Arc<dyn WorkspaceUser>,
+    persistence: Arc<FolderPersistence>,
+    trash_controller: Arc<TrashController>,
+    data_processors: ViewDataProcessorMap,
+    task_scheduler: Arc<RwLock<TaskDispatcher>>,
+  ) -> Self {
+    Self {
+      persistence,
+      trash_controller,
+      data_processors,
+      task_scheduler,
+      index: Arc::new(RwLock::new(None)),
+      pending_view_ids: Arc::new(Mutex::new(HashSet::new())),
+      is_flush_scheduled: Arc::new(AtomicBool::new(false)),
+      recent_views: Arc::new(RecentViewTracker::new(user.clone())),
+      user,
+    }
+  }
+
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub(crate) async fn initialize(&self) -> FlowyResult<()> {
+    let index_dir = self.index_dir()?;
+    let index = match SearchIndex::open(&index_dir) {
+      Ok(index) => index,
+      Err(e) => {
+        tracing::error!(
+          "Open search index failed: {:?}, the index will be rebuilt",
+          e
+        );
+        SearchIndex::remove(&index_dir)?;
+        SearchIndex::open(&index_dir)?
+      },
+    };
+    *self.index.write().await = Some(index);
+    self.listen_trash_can_event();
+    Ok(())
+  }
+
+  /// Rebuilds the index in the background if it's empty, e.g. it was just created or it was
+  /// corrupted. It's called once the folder of the signed in user is initialized.
+  pub(crate) async fn start_indexing(&self) {
+    let need_rebuild = match self.index.read().await.as_ref() {
+      None => return,
+      Some(index) => index.is_empty(),
+    };
+    if need_rebuild {
+      let controller = self.clone();
+      tokio::spawn(async move {
+        if let Err(e) = controller.rebuild_index().await {
+          tracing::error!("Rebuild search index failed: {:?}", e);
+        }
+      });
+    }
+  }
+
+  /// Schedules a task to index the view. It's called when the name or the content of the
+  /// view was changed.
+  pub async fn did_update_view(&self, view_id: &str) {
+    if !self.pending_view_ids.lock().insert(view_id.to_owned()) {
+      return;
+    }
+
+    let task_id = self.task_scheduler.read().await.next_task_id();
+    let task = Task::new(
+      SEARCH_INDEX_HANDLER_ID,
+      task_id,
+      TaskContent::Text(view_id.to_owned()),
+      QualityOfService::Background,
+    );
+    self.task_scheduler.write().await.add_task(task);
+  }
+
+  #[tracing::instrument(name = "process_search_index_task", level = "trace", skip(self), err)]
+  pub(crate) async fn process(&self, view_id: &str) -> FlowyResult<()> {
+    self.pending_view_ids.lock().remove(view_id);
+    if self.index.read().await.is_none() {
+      return Ok(());
+    }
+
+    let view_rev = match self
+      .persistence
+      .begin_transaction(|transaction| transaction.read_view(view_id))
+      .await
+    {
+      Ok(view_rev) => view_rev,
+      Err(_) => {
+        // The view was deleted or doesn't belong to the current user.
+        return self.remove_views(vec![view_id.to_owned()]).await;
+      },
+    };
+
+    let indexed_view = self.make_indexed_view(&view_rev).await;
+    if let Some(index) = self.index.write().await.as_mut() {
+      index.upsert(indexed_view);
+    }
+    self.schedule_flush();
+    Ok(())
+  }
+
+  /// Writes the index to disk after [FLUSH_INDEX_DELAY], the changes in the meantime are
+  /// written together.
+  fn schedule_flush(&self) {
+    if self.is_flush_scheduled.swap(true, Ordering::SeqCst) {
+      return;
+    }
+
+    let controller = self.clone();
+    tokio::spawn(async move {
+      tokio::time::sleep(FLUSH_INDEX_DELAY).await;
+      // Reset the flag before flushing, the changes made during the flush schedule another one.
+      controller.is_flush_scheduled.store(false, Ordering::SeqCst);
+      if let Some(index) = controller.index.read().await.as_ref() {
+        if let Err(e) = index.flush() {
+          tracing::error!("Flush search index failed: {:?}", e);
+        }
+      }
+    });
+  }
+
+  pub(crate) async fn read_index_health(&self) -> SearchIndexHealthPB {
+    let pending_views = self.pending_view_ids.lock().len() as i64;
+    match self.index.read().await.as_ref() {
+      None => SearchIndexHealthPB {
+        pending_views,
+        ..Default::default()
+      },
+      Some(index) => SearchIndexHealthPB {
+        is_ready: true,
+        number_of_views: index.len() as i64,
+        pending_views,
+        updated_at: index.updated_at().unwrap_or(0),
+      },
+    }
+  }
+
+  pub(crate) async fn estimated_index_bytes(&self) -> usize {
+    self
+      .index
+      .read()
+      .await
+      .as_ref()
+      .map(|index| index.estimated_bytes())
+      .unwrap_or(0)
+  }
+
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub(crate) async fn rebuild_index(&self) -> FlowyResult<()> {
+    let user_id = self.user.user_id()?;
+    let view_revs = self
+      .persistence
+      .begin_transaction(|transaction| {
+        let mut view_revs = vec![];
+        let mut stack = transaction
+          .read_workspaces(&user_id, None)?
+          .into_iter()
+          .flat_map(|workspace| workspace.apps)
+          .flat_map(|app| app.belongings)
+          .collect::<Vec<ViewRevision>>();
+        while let Some(mut view_rev) = stack.pop() {
+          stack.append(&mut view_rev.belongings);
+          view_revs.push(view_rev);
+        }
+        Ok(view_revs)
+      })
+      .await?;
+
+    let mut indexed_views = Vec::with_capacity(view_revs.len());
+    for view_rev in view_revs.iter() {
+      indexed_views.push(self.make_indexed_view(view_rev).await);
+    }
+
+    match self.index.write().await.as_mut() {
+      None => Err(FlowyError::internal().context("The search index is not initialized")),
+      Some(index) => {
+        index.clear();
+        indexed_views
+          .into_iter()
+          .for_each(|indexed_view| index.upsert(indexed_view));
+        tracing::trace!("{} views were indexed", index.len());
+        index.flush()
+      },
+    }
+  }
+
+  pub(crate) async fn search(&self, params: SearchParams) -> FlowyResult<RepeatedSearchResultPB> {
+    let trash_ids = self
+      .persistence
+      .begin_transaction(|transaction| self.trash_controller.read_trash_ids(&transaction))
+      .await?
+      .into_iter()
+      .collect::<HashSet<String>>();
+
+    let items = match self.index.read().await.as_ref() {
+      None => vec![],
+      Some(index) => index.search(&params.query, params.limit, &trash_ids),
+    };
+    Ok(RepeatedSearchResultPB { items })
+  }
+
+  /// Matches the query against the names and the aliases of the views. The recently visited
+  /// views are ranked higher. Returns the recently visited views if the query is empty.
+  pub(crate) async fn quick_switch(
+    &self,
+    params: QuickSwitchParams,
+  ) -> FlowyResult<RepeatedQuickSwitchItemPB> {
+    let trash_ids = self
+      .persistence
+      .begin_transaction(|transaction| self.trash_controller.read_trash_ids(&transaction))
+      .await?
+      .into_iter()
+      .collect::<HashSet<String>>();
+    let recent_view_ids = self.recent_views.recent_view_ids()?;
+
+    let index = self.index.read().await;
+    let index = match index.as_ref() {
+      None => return Ok(RepeatedQuickSwitchItemPB::default()),
+      Some(index) => index,
+    };
+
+    let query = normalize_query(&params.query);
+    if query.is_empty() {
+      let items = recent_view_ids
+        .iter()
+        .filter(|view_id| !trash_ids.contains(*view_id))
+        .flat_map(|view_id| index.get_view(view_id))
+        .take(params.limit)
+        .map(|view| QuickSwitchItemPB {
+          view_id: view.view_id.clone(),
+          name: view.name.clone(),
+          matched_alias: "".to_owned(),
+        })
+        .collect();
+      return Ok(RepeatedQuickSwitchItemPB { items });
+    }
+
+    let recent_positions = recent_view_ids
+      .iter()
+      .enumerate()
+      .map(|(position, view_id)| (view_id.as_str(), position))
+      .collect::<HashMap<&str, usize>>();
+    let mut matches = index
+      .views()
+      .filter(|view| !trash_ids.contains(&view.view_id))
+      .flat_map(|view| {
+        let mut best_match = fuzzy_score(&query, &view.name).map(|score| (score, ""));
+        for alias in view.aliases.iter() {
+          if let Some(score) = fuzzy_score(&query, alias) {
+            if best_match
+              .map(|(best_score, _)| score > best_score)
+              .unwrap_or(true)
+            {
+              best_match = Some((score, alias.as_str()));
+            }
+          }
+        }
+        best_match.map(|(score, matched_alias)| {
+          let position = recent_positions.get(view.view_id.as_str()).cloned();
+          let score = score + recency_bonus(position, MAX_RECENCY_BONUS);
+          (score, view, matched_alias)
+        })
+      })
+      .collect::<Vec<_>>();
+    matches.sort_by(|(left_score, left, _), (right_score, right, _)| {
+      right_score
+        .cmp(left_score)
+        .then_with(|| left.name.cmp(&right.name))
+    });
+
+    let items = matches
+      .into_iter()
+      .take(params.limit)
+      .map(|(_, view, matched_alias)| QuickSwitchItemPB {
+        view_id: view.view_id.clone(),
+        name: view.name.clone(),
+        matched_alias: matched_alias.to_owned(),
+      })
+      .collect();
+    Ok(RepeatedQuickSwitchItemPB { items })
+  }
+
+  /// Records the view as the most recently visited view.
+  pub(crate) fn did_visit_view(&self, view_id: &str) -> FlowyResult<()> {
+    self.recent_views.visit(view_id)
+  }
+
+  pub(crate) fn read_view_aliases(&self, view_id: &str) -> ViewAliasesPB {
+    ViewAliasesPB {
+      view_id: view_id.to_owned(),
+      aliases: read_view_aliases(view_id),
+    }
+  }
+
+  pub(crate) async fn update_view_aliases(&self, params: ViewAliasesParams) -> FlowyResult<()> {
+    let key = view_aliases_key(&params.view_id);
+    if params.aliases.is_empty() {
+      let _ = KV::remove(&key);
+    } else {
+      let s = serde_json::to_string(&params.aliases).map_err(internal_error)?;
+      KV::set_str(&key, s);
+    }
+    self.did_update_view(&params.view_id).await;
+    Ok(())
+  }
+
+  async fn remove_views(&self, view_ids: Vec<String>) -> FlowyResult<()> {
+    self.recent_views.remove_views(&view_ids)?;
+    if let Some(index) = self.index.write().await.as_mut() {
+      let mut is_changed = false;
+      for view_id in view_ids {
+        if index.contains(&view_id) {
+          index.remove_view(&view_id);
+          is_changed = true;
+        }
+      }
+      if is_changed {
+        self.schedule_flush();
+      }
+    }
+    Ok(())
+  }
+
+  async fn make_indexed_view(&self, view_rev: &ViewRevision) -> IndexedView {
+    let content = match self
+      .data_processors
+      .get(&view_rev.data_format.clone().into())
+    {
+      None => String::new(),
+      Some(processor) => processor
+        .get_view_search_content(&view_rev.id)
+        .await
+        .unwrap_or_else(|e| {
+          tracing::error!("Get the content of view:{} failed: {:?}", view_rev.id, e);
+          String::new()
+        }),
+    };
+    IndexedView::new(
+      &view_rev.id,
+      &view_rev.name,
+      read_view_aliases(&view_rev.id),
+      &view_rev.desc,
+      &content,
+    )
+  }
+
+  fn index_dir(&self) -> FlowyResult<String> {
+    let user_dir = self.user.user_dir()?;
+    Ok(format!("{}/search_index", user_dir))
+  }
+
+  fn listen_trash_can_event(&self) {
+    let mut rx = self.trash_controller.subscribe();
+    let controller = self.clone();
+    tokio::spawn(async move {
+      loop {
+        let mut stream = Box::pin(rx.recv().into_stream().filter_map(|result| async move {
+          match result {
+            Ok(event) => event.select(TrashType::TrashView),
+            Err(_e) => None,
+          }
+        }));
+
+        if let Some(TrashEvent::Delete(identifiers, _)) = stream.next().await {
+          let view_ids = identifiers
+            .items
+            .into_iter()
+            .map(|identifier| identifier.id)
+            .collect::<Vec<String>>();
+          view_ids.iter().for_each(|view_id| {
+            let _ = KV::remove(&view_aliases_key(view_id));
+          });
+          if let Err(e) = controller.remove_views(view_ids).await {
+            tracing::error!("Remove the deleted views from search index failed: {:?}", e);
+          }
+        }
+      }
+    });
+  }
+}
+
+fn read_view_aliases(view_id: &str) -> Vec<String> {
+  KV::get_str(&view_aliases_key(view_id))
+    .and_then(|s| serde_json::from_str::<Vec<String>>(&s).ok())
+    .unwrap_or_default()
+}
+
+fn view_aliases_key(view_id: &str) -> String {
+  format!("{}:{}", VIEW_ALIASES_PREFIX, view_id)
+}
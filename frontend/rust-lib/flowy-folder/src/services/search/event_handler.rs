@@ -0,0 +1,55 @@
+use crate::{
+  entities::search::{
+    QuickSwitchParams, QuickSwitchPayloadPB, RepeatedQuickSwitchItemPB, RepeatedSearchResultPB,
+    SearchParams, SearchPayloadPB, ViewAliasesPB, ViewAliasesParams,
+  },
+  entities::view::ViewIdPB,
+  errors::FlowyError,
+  services::search::SearchController,
+};
+use lib_dispatch::prelude::{data_result_ok, AFPluginData, AFPluginState, DataResult};
+use std::{convert::TryInto, sync::Arc};
+
+pub(crate) async fn search_handler(
+  data: AFPluginData<SearchPayloadPB>,
+  controller: AFPluginState<Arc<SearchController>>,
+) -> DataResult<RepeatedSearchResultPB, FlowyError> {
+  let params: SearchParams = data.into_inner().try_into()?;
+  let results = controller.search(params).await?;
+  data_result_ok(results)
+}
+
+#[tracing::instrument(level = "debug", skip(controller), err)]
+pub(crate) async fn rebuild_search_index_handler(
+  controller: AFPluginState<Arc<SearchController>>,
+) -> Result<(), FlowyError> {
+  controller.rebuild_index().await?;
+  Ok(())
+}
+
+pub(crate) async fn quick_switch_handler(
+  data: AFPluginData<QuickSwitchPayloadPB>,
+  controller: AFPluginState<Arc<SearchController>>,
+) -> DataResult<RepeatedQuickSwitchItemPB, FlowyError> {
+  let params: QuickSwitchParams = data.into_inner().try_into()?;
+  let items = controller.quick_switch(params).await?;
+  data_result_ok(items)
+}
+
+pub(crate) async fn read_view_aliases_handler(
+  data: AFPluginData<ViewIdPB>,
+  controller: AFPluginState<Arc<SearchController>>,
+) -> DataResult<ViewAliasesPB, FlowyError> {
+  let view_id: ViewIdPB = data.into_inner();
+  data_result_ok(controller.read_view_aliases(&view_id.value))
+}
+
+#[tracing::instrument(level = "debug", skip(data, controller), err)]
+pub(crate) async fn update_view_aliases_handler(
+  data: AFPluginData<ViewAliasesPB>,
+  controller: AFPluginState<Arc<SearchController>>,
+) -> Result<(), FlowyError> {
+  let params: ViewAliasesParams = data.into_inner().try_into()?;
+  controller.update_view_aliases(params).await?;
+  Ok(())
+}
@@ -0,0 +1,63 @@
+const MATCH_SCORE: i64 = 1;
+const CONSECUTIVE_BONUS: i64 = 5;
+const WORD_START_BONUS: i64 = 8;
+const PREFIX_BONUS: i64 = 20;
+const EXACT_BONUS: i64 = 50;
+const MAX_LEADING_GAP_PENALTY: i64 = 5;
+
+/// Returns the score of matching the `query` against the `target`, or `None` if the characters of
+/// the query don't appear in the target in order. The comparison is case-insensitive.
+///
+/// The matched characters that are consecutive or at the beginning of a word weigh more, so
+/// "gp" prefers "Grid Planning" over "Group".
+pub(crate) fn fuzzy_score(query: &[char], target: &str) -> Option<i64> {
+  if query.is_empty() {
+    return Some(0);
+  }
+
+  let target = target.to_lowercase().chars().collect::<Vec<char>>();
+  let mut score = 0;
+  let mut query_index = 0;
+  let mut last_matched: Option<usize> = None;
+  for (target_index, c) in target.iter().enumerate() {
+    if query_index == query.len() {
+      break;
+    }
+    if *c != query[query_index] {
+      continue;
+    }
+
+    score += MATCH_SCORE;
+    match last_matched {
+      Some(last) if last + 1 == target_index => score += CONSECUTIVE_BONUS,
+      None => score -= (target_index as i64).min(MAX_LEADING_GAP_PENALTY),
+      _ => {},
+    }
+    if target_index == 0 || !target[target_index - 1].is_alphanumeric() {
+      score += WORD_START_BONUS;
+    }
+    last_matched = Some(target_index);
+    query_index += 1;
+  }
+
+  if query_index != query.len() {
+    return None;
+  }
+
+  if target.starts_with(query) {
+    score += PREFIX_BONUS;
+    if target.len() == query.len() {
+      score += EXACT_BONUS;
+    }
+  }
+  Some(score)
+}
+
+/// Lowercases the query and drops the whitespaces.
+pub(crate) fn normalize_query(query: &str) -> Vec<char> {
+  query
+    .to_lowercase()
+    .chars()
+    .filter(|c| !c.is_whitespace())
+    .collect()
+}
@@ -0,0 +1,303 @@
+use crate::entities::SearchResultPB;
+use crate::errors::{internal_error, FlowyError, FlowyResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Bump the version if the format of the index file is changed. The index with different version
+/// will be dropped and rebuilt.
+const SEARCH_INDEX_VERSION: u32 = 1;
+const SEARCH_INDEX_FILE: &str = "search_index.json";
+const PREVIEW_LEN: usize = 120;
+/// The terms in the view's name and aliases weigh more than the terms in its content.
+const NAME_TERM_WEIGHT: u32 = 5;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexedView {
+  pub view_id: String,
+  pub name: String,
+  pub preview: String,
+  #[serde(default)]
+  pub aliases: Vec<String>,
+  /// The frequency of each term of the view, the terms in the name are weighted.
+  terms: HashMap<String, u32>,
+}
+
+impl IndexedView {
+  pub fn new(view_id: &str, name: &str, aliases: Vec<String>, desc: &str, content: &str) -> Self {
+    let mut terms: HashMap<String, u32> = HashMap::new();
+    let alias_terms = aliases.iter().flat_map(|alias| tokenize(alias));
+    for term in tokenize(name).chain(alias_terms) {
+      *terms.entry(term).or_default() += NAME_TERM_WEIGHT;
+    }
+    for term in tokenize(desc).chain(tokenize(content)) {
+      *terms.entry(term).or_default() += 1;
+    }
+
+    let preview = content
+      .split_whitespace()
+      .collect::<Vec<&str>>()
+      .join(" ")
+      .chars()
+      .take(PREVIEW_LEN)
+      .collect();
+
+    Self {
+      view_id: view_id.to_owned(),
+      name: name.to_owned(),
+      preview,
+      aliases,
+      terms,
+    }
+  }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct SearchIndexData {
+  version: u32,
+  views: HashMap<String, IndexedView>,
+}
+
+/// [SearchIndex] is an inverted index that maps the terms to the views containing them.
+///
+/// The indexed views are persisted as a json file under the user's directory. The inverted
+/// table is built in memory when the index is opened.
+pub struct SearchIndex {
+  path: PathBuf,
+  data: SearchIndexData,
+  /// Sorted by term, so the terms starting with the same prefix can be found by a range scan.
+  inverted: BTreeMap<String, HashSet<String>>,
+}
+
+impl SearchIndex {
+  /// Opens the index located in `dir`, an empty index will be created if it doesn't exist.
+  /// Returns an error if the index file is corrupted or outdated.
+  pub fn open(dir: &str) -> FlowyResult<Self> {
+    let dir = Path::new(dir);
+    if !dir.exists() {
+      std::fs::create_dir_all(dir)?;
+    }
+
+    let path = dir.join(SEARCH_INDEX_FILE);
+    let data = if path.exists() {
+      let bytes = std::fs::read(&path)?;
+      let data = serde_json::from_slice::<SearchIndexData>(&bytes).map_err(|e| {
+        FlowyError::internal().context(format!("The search index is corrupted: {}", e))
+      })?;
+      if data.version != SEARCH_INDEX_VERSION {
+        return Err(FlowyError::internal().context(format!(
+          "The search index version {} is outdated",
+          data.version
+        )));
+      }
+      data
+    } else {
+      SearchIndexData {
+        version: SEARCH_INDEX_VERSION,
+        views: HashMap::new(),
+      }
+    };
+
+    let mut index = Self {
+      path,
+      data,
+      inverted: BTreeMap::new(),
+    };
+    let views = index
+      .data
+      .views
+      .values()
+      .cloned()
+      .collect::<Vec<IndexedView>>();
+    views.iter().for_each(|view| index.insert_terms(view));
+    Ok(index)
+  }
+
+  /// Removes the index file in `dir`. It's used to drop the corrupted index.
+  pub fn remove(dir: &str) -> FlowyResult<()> {
+    let path = Path::new(dir).join(SEARCH_INDEX_FILE);
+    if path.exists() {
+      std::fs::remove_file(path)?;
+    }
+    Ok(())
+  }
+
+  pub fn contains(&self, view_id: &str) -> bool {
+    self.data.views.contains_key(view_id)
+  }
+
+  pub fn len(&self) -> usize {
+    self.data.views.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.data.views.is_empty()
+  }
+
+  /// Returns the estimated bytes of the indexed views and the inverted table in memory.
+  pub fn estimated_bytes(&self) -> usize {
+    let view_bytes = self
+      .data
+      .views
+      .iter()
+      .map(|(view_id, view)| {
+        let aliases_bytes = view.aliases.iter().map(|alias| alias.len()).sum::<usize>();
+        let terms_bytes = view
+          .terms
+          .keys()
+          .map(|term| term.len() + std::mem::size_of::<u32>())
+          .sum::<usize>();
+        view_id.len()
+          + view.view_id.len()
+          + view.name.len()
+          + view.preview.len()
+          + aliases_bytes
+          + terms_bytes
+      })
+      .sum::<usize>();
+    let inverted_bytes = self
+      .inverted
+      .iter()
+      .map(|(term, view_ids)| term.len() + view_ids.iter().map(|id| id.len()).sum::<usize>())
+      .sum::<usize>();
+    view_bytes + inverted_bytes
+  }
+
+  pub fn views(&self) -> impl Iterator<Item = &IndexedView> {
+    self.data.views.values()
+  }
+
+  pub fn get_view(&self, view_id: &str) -> Option<&IndexedView> {
+    self.data.views.get(view_id)
+  }
+
+  pub fn upsert(&mut self, view: IndexedView) {
+    self.remove_view(&view.view_id);
+    self.insert_terms(&view);
+    self.data.views.insert(view.view_id.clone(), view);
+  }
+
+  pub fn remove_view(&mut self, view_id: &str) {
+    if let Some(view) = self.data.views.remove(view_id) {
+      for term in view.terms.keys() {
+        if let Some(view_ids) = self.inverted.get_mut(term) {
+          view_ids.remove(view_id);
+          if view_ids.is_empty() {
+            self.inverted.remove(term);
+          }
+        }
+      }
+    }
+  }
+
+  pub fn clear(&mut self) {
+    self.data.views.clear();
+    self.inverted.clear();
+  }
+
+  /// Returns the views that contain all the terms of the query. The last term of the query is
+  /// treated as a prefix, so the views can be found while the user is still typing.
+  pub fn search(
+    &self,
+    query: &str,
+    limit: usize,
+    excluded_ids: &HashSet<String>,
+  ) -> Vec<SearchResultPB> {
+    let query_terms = tokenize(query).collect::<Vec<String>>();
+    if query_terms.is_empty() {
+      return vec![];
+    }
+
+    let mut scores: Option<HashMap<&str, u32>> = None;
+    for (index, query_term) in query_terms.iter().enumerate() {
+      let is_prefix = index == query_terms.len() - 1;
+      let term_scores = self.score_term(query_term, is_prefix);
+      scores = Some(match scores {
+        None => term_scores,
+        Some(scores) => scores
+          .into_iter()
+          .flat_map(|(view_id, score)| {
+            term_scores
+              .get(view_id)
+              .map(|term_score| (view_id, score + term_score))
+          })
+          .collect(),
+      });
+    }
+
+    let mut scores = scores.unwrap_or_default().into_iter().collect::<Vec<_>>();
+    scores.sort_by(|(left_id, left_score), (right_id, right_score)| {
+      right_score
+        .cmp(left_score)
+        .then_with(|| left_id.cmp(right_id))
+    });
+
+    scores
+      .into_iter()
+      .filter(|(view_id, _)| !excluded_ids.contains(*view_id))
+      .take(limit)
+      .flat_map(|(view_id, _)| self.data.views.get(view_id))
+      .map(|view| SearchResultPB {
+        view_id: view.view_id.clone(),
+        name: view.name.clone(),
+        preview: view.preview.clone(),
+      })
+      .collect()
+  }
+
+  /// Returns the timestamp in seconds of the last time the index was flushed to disk.
+  pub fn updated_at(&self) -> Option<i64> {
+    let modified = std::fs::metadata(&self.path).ok()?.modified().ok()?;
+    let duration = modified.duration_since(std::time::UNIX_EPOCH).ok()?;
+    Some(duration.as_secs() as i64)
+  }
+
+  /// Writes the index to a temporary file first and then renames it, so the index file is not
+  /// left half-written if the application gets killed.
+  pub fn flush(&self) -> FlowyResult<()> {
+    let bytes = serde_json::to_vec(&self.data).map_err(internal_error)?;
+    let tmp_path = self.path.with_extension("tmp");
+    std::fs::write(&tmp_path, bytes)?;
+    std::fs::rename(&tmp_path, &self.path)?;
+    Ok(())
+  }
+
+  fn insert_terms(&mut self, view: &IndexedView) {
+    for term in view.terms.keys() {
+      self
+        .inverted
+        .entry(term.clone())
+        .or_default()
+        .insert(view.view_id.clone());
+    }
+  }
+
+  fn score_term(&self, query_term: &str, is_prefix: bool) -> HashMap<&str, u32> {
+    let mut scores: HashMap<&str, u32> = HashMap::new();
+    let matched_terms = self
+      .inverted
+      .range(query_term.to_owned()..)
+      .take_while(|(term, _)| {
+        if is_prefix {
+          term.starts_with(query_term)
+        } else {
+          term.as_str() == query_term
+        }
+      });
+
+    for (term, view_ids) in matched_terms {
+      for view_id in view_ids {
+        if let Some(view) = self.data.views.get(view_id) {
+          let frequency = view.terms.get(term).cloned().unwrap_or_default();
+          *scores.entry(view.view_id.as_str()).or_default() += frequency;
+        }
+      }
+    }
+    scores
+  }
+}
+
+fn tokenize(s: &str) -> impl Iterator<Item = String> + '_ {
+  s.unicode_words().map(|word| word.to_lowercase())
+}
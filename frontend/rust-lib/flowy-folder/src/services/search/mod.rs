@@ -0,0 +1,11 @@
+pub mod controller;
+pub mod event_handler;
+mod fuzzy;
+pub mod index;
+pub mod recent;
+mod task;
+
+pub use controller::*;
+pub use task::*;
+
+pub(crate) const SEARCH_INDEX_HANDLER_ID: &str = "search_index";
@@ -0,0 +1,109 @@
+use crate::{errors::FlowyResult, event_map::WorkspaceUser};
+use flowy_sqlite::kv::KV;
+use lib_infra::util::timestamp;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+const RECENT_VIEWS_PREFIX: &str = "recent_views";
+const MAX_RECENT_VIEWS: usize = 50;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecentView {
+  pub view_id: String,
+  pub visited_at: i64,
+}
+
+/// [RecentViewTracker] records the views visited by the current user, the most recently visited
+/// view comes first. The records are cached in memory and persisted in the [KV] store.
+pub struct RecentViewTracker {
+  user: Arc<dyn WorkspaceUser>,
+  /// The user id and the recent views of that user.
+  cache: RwLock<Option<(String, Vec<RecentView>)>>,
+}
+
+impl RecentViewTracker {
+  pub fn new(user: Arc<dyn WorkspaceUser>) -> Self {
+    Self {
+      user,
+      cache: RwLock::new(None),
+    }
+  }
+
+  pub fn visit(&self, view_id: &str) -> FlowyResult<()> {
+    let user_id = self.user.user_id()?;
+    let mut recent_views = self.read_recent_views(&user_id);
+    recent_views.retain(|recent_view| recent_view.view_id != view_id);
+    recent_views.insert(
+      0,
+      RecentView {
+        view_id: view_id.to_owned(),
+        visited_at: timestamp(),
+      },
+    );
+    recent_views.truncate(MAX_RECENT_VIEWS);
+    self.save_recent_views(user_id, recent_views);
+    Ok(())
+  }
+
+  pub fn remove_views(&self, view_ids: &[String]) -> FlowyResult<()> {
+    let user_id = self.user.user_id()?;
+    let mut recent_views = self.read_recent_views(&user_id);
+    let len = recent_views.len();
+    recent_views.retain(|recent_view| !view_ids.contains(&recent_view.view_id));
+    if recent_views.len() != len {
+      self.save_recent_views(user_id, recent_views);
+    }
+    Ok(())
+  }
+
+  /// Returns the ids of the recently visited views, the most recently visited view comes first.
+  pub fn recent_view_ids(&self) -> FlowyResult<Vec<String>> {
+    let user_id = self.user.user_id()?;
+    Ok(
+      self
+        .read_recent_views(&user_id)
+        .into_iter()
+        .map(|recent_view| recent_view.view_id)
+        .collect(),
+    )
+  }
+
+  fn read_recent_views(&self, user_id: &str) -> Vec<RecentView> {
+    if let Some((cached_user_id, recent_views)) = self.cache.read().as_ref() {
+      if cached_user_id == user_id {
+        return recent_views.clone();
+      }
+    }
+
+    let recent_views = KV::get_str(&recent_views_key(user_id))
+      .and_then(|s| serde_json::from_str::<Vec<RecentView>>(&s).ok())
+      .unwrap_or_default();
+    *self.cache.write() = Some((user_id.to_owned(), recent_views.clone()));
+    recent_views
+  }
+
+  fn save_recent_views(&self, user_id: String, recent_views: Vec<RecentView>) {
+    match serde_json::to_string(&recent_views) {
+      Ok(s) => KV::set_str(&recent_views_key(&user_id), s),
+      Err(e) => tracing::error!("Serialize the recent views failed: {:?}", e),
+    }
+    *self.cache.write() = Some((user_id, recent_views));
+  }
+}
+
+fn recent_views_key(user_id: &str) -> String {
+  format!("{}:{}", RECENT_VIEWS_PREFIX, user_id)
+}
+
+/// Returns the bonus of the view according to its position in the recent views. The more
+/// recently the view was visited, the bigger the bonus is.
+pub(crate) fn recency_bonus(position: Option<usize>, max_bonus: i64) -> i64 {
+  match position {
+    None => 0,
+    Some(position) => {
+      let position = position.min(MAX_RECENT_VIEWS) as i64;
+      max_bonus * (MAX_RECENT_VIEWS as i64 - position) / MAX_RECENT_VIEWS as i64
+    },
+  }
+}
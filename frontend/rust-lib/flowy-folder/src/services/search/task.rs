@@ -0,0 +1,37 @@
+use crate::services::search::{SearchController, SEARCH_INDEX_HANDLER_ID};
+use flowy_task::{TaskContent, TaskHandler};
+use lib_infra::future::BoxResultFuture;
+use std::sync::Arc;
+
+pub struct SearchIndexTaskHandler {
+  search_controller: Arc<SearchController>,
+}
+
+impl SearchIndexTaskHandler {
+  pub fn new(search_controller: Arc<SearchController>) -> Self {
+    Self { search_controller }
+  }
+}
+
+impl TaskHandler for SearchIndexTaskHandler {
+  fn handler_id(&self) -> &str {
+    SEARCH_INDEX_HANDLER_ID
+  }
+
+  fn handler_name(&self) -> &str {
+    "SearchIndexTaskHandler"
+  }
+
+  fn run(&self, content: TaskContent) -> BoxResultFuture<(), anyhow::Error> {
+    let search_controller = self.search_controller.clone();
+    Box::pin(async move {
+      if let TaskContent::Text(view_id) = content {
+        search_controller
+          .process(&view_id)
+          .await
+          .map_err(anyhow::Error::from)?;
+      }
+      Ok(())
+    })
+  }
+}
@@ -0,0 +1,18 @@
+use crate::entities::{
+  GenerateSyntheticWorkspaceParams, GenerateSyntheticWorkspacePayloadPB, WorkspacePB,
+};
+use crate::errors::FlowyError;
+use crate::manager::FolderManager;
+use crate::services::synthetic::generate_synthetic_workspace;
+use lib_dispatch::prelude::{data_result_ok, AFPluginData, AFPluginState, DataResult};
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(level = "debug", skip(data, folder), err)]
+pub(crate) async fn generate_synthetic_workspace_handler(
+  data: AFPluginData<GenerateSyntheticWorkspacePayloadPB>,
+  folder: AFPluginState<Arc<FolderManager>>,
+) -> DataResult<WorkspacePB, FlowyError> {
+  let params: GenerateSyntheticWorkspaceParams = data.into_inner().try_into()?;
+  let workspace = generate_synthetic_workspace(&folder, params).await?;
+  data_result_ok(workspace)
+}
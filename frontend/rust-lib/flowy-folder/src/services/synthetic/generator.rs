@@ -0,0 +1,61 @@
+use crate::entities::{
+  CreateAppParams, CreateWorkspaceParams, GenerateSyntheticWorkspaceParams, ViewLayoutTypePB,
+  WorkspacePB,
+};
+use crate::errors::FlowyResult;
+use crate::manager::FolderManager;
+
+const SYNTHETIC_APP_NAME: &str = "Synthetic data";
+const SYNTHETIC_DOCUMENT_PARAGRAPHS: usize = 20;
+
+/// Generates a workspace with the synthetic documents and databases through the same
+/// controllers and processors as the views created by the user, so the generated workspace is
+/// indexed and synced like any other one.
+#[tracing::instrument(level = "debug", skip(folder), err)]
+pub(crate) async fn generate_synthetic_workspace(
+  folder: &FolderManager,
+  params: GenerateSyntheticWorkspaceParams,
+) -> FlowyResult<WorkspacePB> {
+  let workspace_rev = folder
+    .workspace_controller
+    .create_workspace_from_params(CreateWorkspaceParams {
+      name: params.name,
+      desc: "".to_owned(),
+    })
+    .await?;
+  let mut app = folder
+    .app_controller
+    .create_app_from_params(CreateAppParams {
+      workspace_id: workspace_rev.id.clone(),
+      name: SYNTHETIC_APP_NAME.to_owned(),
+      desc: "".to_owned(),
+      color_style: Default::default(),
+    })
+    .await?;
+
+  let documents = (0..params.number_of_documents).map(|index| {
+    (
+      format!("Document {}", index + 1),
+      ViewLayoutTypePB::Document,
+      SYNTHETIC_DOCUMENT_PARAGRAPHS,
+    )
+  });
+  let databases = (0..params.number_of_databases).map(|index| {
+    (
+      format!("Database {}", index + 1),
+      ViewLayoutTypePB::Grid,
+      params.number_of_rows,
+    )
+  });
+  for (name, layout, size) in documents.chain(databases) {
+    let view_rev = folder
+      .view_controller
+      .create_synthetic_view(&app.id, &name, layout, size)
+      .await?;
+    app.belongings.items.push(view_rev.into());
+  }
+
+  let mut workspace: WorkspacePB = workspace_rev.into();
+  workspace.apps.items = vec![app];
+  Ok(workspace)
+}
@@ -0,0 +1,4 @@
+pub mod event_handler;
+mod generator;
+
+pub(crate) use generator::*;
@@ -1,17 +1,26 @@
 pub use crate::entities::view::ViewDataFormatPB;
-use crate::entities::{AppPB, DeletedViewPB, ViewLayoutTypePB};
-use crate::manager::{ViewDataProcessor, ViewDataProcessorMap};
+use crate::entities::{
+  AppPB, ChildViewSortPB, DeletedViewPB, DuplicateViewNamePB, RepeatedDuplicateViewNamePB,
+  UpdateViewStateParams, ViewLayoutTypePB, ViewNamePolicyPB, ViewNameSettingPB,
+  ViewNameSettingParams, ViewStatePB,
+};
+use crate::manager::{ViewArchiver, ViewDataProcessor, ViewDataProcessorMap};
 use crate::{
   entities::{
     trash::{RepeatedTrashIdPB, TrashType},
-    view::{CreateViewParams, UpdateViewParams, ViewPB},
+    view::{
+      data_format_from_layout, CreateViewParams, UpdateViewParams, UpdateViewSlugParams, ViewPB,
+    },
+    ExportDataParams, ExportScopePB, ImportParams, ImportTypePB,
   },
-  errors::{FlowyError, FlowyResult},
+  errors::{internal_error, FlowyError, FlowyResult},
   event_map::{FolderCouldServiceV1, WorkspaceUser},
   notification::{send_notification, FolderNotification},
   services::{
+    get_current_workspace,
     persistence::{FolderPersistence, FolderPersistenceTransaction, ViewChangeset},
-    TrashController, TrashEvent,
+    view::naming::{find_duplicate_names, is_same_name, make_unique_name},
+    SearchController, TrashController, TrashEvent,
   },
 };
 use bytes::Bytes;
@@ -20,16 +29,32 @@ use folder_model::{gen_view_id, ViewRevision};
 use futures::{FutureExt, StreamExt};
 use lib_infra::util::timestamp;
 use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::{collections::HashSet, sync::Arc};
 
 const LATEST_VIEW_ID: &str = "latest_view_id";
+const VIEW_NAME_POLICY_PREFIX: &str = "view_name_policy";
+const VIEW_STATE_PREFIX: &str = "view_state";
+/// The view state is meant for the small values, like the scroll anchor or the expanded rows.
+const MAX_VIEW_STATE_LEN: usize = 16 * 1024;
+/// The edits of the same user within this many seconds are recorded only once.
+const LAST_EDITED_THROTTLE_SECONDS: i64 = 60;
+/// Each exported view is saved in the directory named after its position in the archive, with
+/// the revision of the view and its data.
+const EXPORTED_VIEW_FILE: &str = "view.json";
+const EXPORTED_VIEW_DATA_FILE: &str = "data";
 
 pub struct ViewController {
   user: Arc<dyn WorkspaceUser>,
   cloud_service: Arc<dyn FolderCouldServiceV1>,
   persistence: Arc<FolderPersistence>,
   trash_controller: Arc<TrashController>,
+  search_controller: Arc<SearchController>,
   data_processors: ViewDataProcessorMap,
+  archiver: Arc<dyn ViewArchiver>,
+  /// Serializes the updates of the view states, see [ViewController::update_view_state]
+  view_state_lock: tokio::sync::Mutex<()>,
 }
 
 impl ViewController {
@@ -38,14 +63,19 @@ impl ViewController {
     persistence: Arc<FolderPersistence>,
     cloud_service: Arc<dyn FolderCouldServiceV1>,
     trash_controller: Arc<TrashController>,
+    search_controller: Arc<SearchController>,
     data_processors: ViewDataProcessorMap,
+    archiver: Arc<dyn ViewArchiver>,
   ) -> Self {
     Self {
       user,
       cloud_service,
       persistence,
       trash_controller,
+      search_controller,
       data_processors,
+      archiver,
+      view_state_lock: Default::default(),
     }
   }
 
@@ -57,8 +87,11 @@ impl ViewController {
   #[tracing::instrument(level = "trace", skip(self, params), fields(name = %params.name), err)]
   pub(crate) async fn create_view_from_params(
     &self,
-    params: CreateViewParams,
+    mut params: CreateViewParams,
   ) -> Result<ViewRevision, FlowyError> {
+    params.name = self
+      .resolve_view_name(&params.belong_to_id, &params.name, None)
+      .await?;
     let processor = self.get_data_processor(params.data_format.clone())?;
     let user_id = self.user.user_id()?;
     match params.initial_data.is_empty() {
@@ -90,6 +123,244 @@ impl ViewController {
       },
     }
 
+    // Discards the data of the view if the view can't be saved, otherwise the data is left
+    // behind without any view referring to it.
+    let view_id = params.view_id.clone();
+    match self.save_new_view(params).await {
+      Ok(view_rev) => Ok(view_rev),
+      Err(err) => {
+        if let Err(delete_err) = processor.delete_view(&view_id).await {
+          tracing::error!(
+            "Delete the data of view {} failed: {:?}",
+            view_id,
+            delete_err
+          );
+        }
+        Err(err)
+      },
+    }
+  }
+
+  /// Creates a view with the data of the file. The processor might import the data in the
+  /// background, so the view is saved as soon as the processor prepared it.
+  #[tracing::instrument(level = "debug", skip(self, params), fields(name = %params.name), err)]
+  pub(crate) async fn import_view(
+    &self,
+    mut params: ImportParams,
+  ) -> Result<ViewRevision, FlowyError> {
+    let layout = match params.import_type.layout() {
+      None => return self.import_archive(params).await,
+      Some(layout) => layout,
+    };
+    params.name = self
+      .resolve_view_name(&params.belong_to_id, &params.name, None)
+      .await?;
+    let data_format = data_format_from_layout(&layout);
+    let processor = self.get_data_processor(data_format.clone())?;
+    processor
+      .import_from_file_path(
+        &params.view_id,
+        &params.name,
+        &params.file_path,
+        params.import_type,
+      )
+      .await?;
+
+    self
+      .save_new_view(CreateViewParams {
+        belong_to_id: params.belong_to_id,
+        name: params.name,
+        desc: "".to_string(),
+        thumbnail: "".to_string(),
+        data_format,
+        layout,
+        view_id: params.view_id,
+        initial_data: vec![],
+        ext: HashMap::default(),
+      })
+      .await
+  }
+
+  /// Creates the views of the archive that was written by [ViewController::export_data] in the
+  /// app. The view keeps the name of the payload if it's the only view of the archive. Returns
+  /// the first view of the archive.
+  async fn import_archive(&self, params: ImportParams) -> Result<ViewRevision, FlowyError> {
+    debug_assert_eq!(params.import_type, ImportTypePB::Archive);
+    let import_dir = std::env::temp_dir().join(format!("appflowy_import_{}", params.view_id));
+    let archiver = self.archiver.clone();
+    let archive_path = PathBuf::from(&params.file_path);
+    let passphrase = params.passphrase.clone();
+    let dir = import_dir.clone();
+    let result = tokio::task::spawn_blocking(move || {
+      archiver.read_archive(&archive_path, &dir, passphrase.as_deref())
+    })
+    .await
+    .map_err(internal_error)
+    .and_then(|result| result);
+    let result = match result {
+      Ok(_) => self.create_views_from_export_dir(&import_dir, params).await,
+      Err(err) => Err(err),
+    };
+    let _ = fs::remove_dir_all(&import_dir);
+    result
+  }
+
+  async fn create_views_from_export_dir(
+    &self,
+    dir: &Path,
+    params: ImportParams,
+  ) -> Result<ViewRevision, FlowyError> {
+    let mut view_dirs = fs::read_dir(dir)?
+      .map(|entry| {
+        let entry = entry?;
+        let index = entry.file_name().to_string_lossy().parse::<usize>().ok();
+        Ok((index, entry.path()))
+      })
+      .collect::<Result<Vec<_>, std::io::Error>>()?
+      .into_iter()
+      .flat_map(|(index, path)| Some((index?, path)))
+      .collect::<Vec<_>>();
+    view_dirs.sort_by_key(|(index, _)| *index);
+    let is_single_view = view_dirs.len() == 1;
+
+    let mut imported_view_rev = None;
+    for (_, view_dir) in view_dirs {
+      let view_rev = fs::read(view_dir.join(EXPORTED_VIEW_FILE))
+        .map_err(FlowyError::from)
+        .and_then(|bytes| {
+          serde_json::from_slice::<ViewRevision>(&bytes)
+            .map_err(|e| FlowyError::invalid_data().context(e))
+        })?;
+      let view_data = fs::read(view_dir.join(EXPORTED_VIEW_DATA_FILE))?;
+      let (view_id, name) = match imported_view_rev {
+        None if is_single_view => (params.view_id.clone(), params.name.clone()),
+        None => (params.view_id.clone(), view_rev.name),
+        Some(_) => (gen_view_id(), view_rev.name),
+      };
+      let create_params = CreateViewParams {
+        belong_to_id: params.belong_to_id.clone(),
+        name,
+        desc: view_rev.desc,
+        thumbnail: view_rev.thumbnail,
+        data_format: view_rev.data_format.into(),
+        layout: view_rev.layout.into(),
+        initial_data: view_data,
+        view_id,
+        ext: Default::default(),
+      };
+      let view_rev = self.create_view_from_params(create_params).await?;
+      imported_view_rev.get_or_insert(view_rev);
+    }
+    imported_view_rev
+      .ok_or_else(|| FlowyError::invalid_data().context("The archive doesn't contain any view"))
+  }
+
+  /// Writes the view, or all the views of the workspace, into an archive file. The archive is
+  /// encrypted if the passphrase is set, see [ViewArchiver]. The data of the views is collected
+  /// in a temporary directory that is streamed into the archive.
+  #[tracing::instrument(level = "debug", skip(self, params), fields(id = %params.id), err)]
+  pub(crate) async fn export_data(&self, params: ExportDataParams) -> Result<(), FlowyError> {
+    let view_revs = match params.scope {
+      ExportScopePB::View => vec![self.read_view(&params.id).await?],
+      ExportScopePB::Workspace => {
+        let trash_controller = self.trash_controller.clone();
+        self
+          .persistence
+          .begin_transaction(|transaction| {
+            let mut view_revs = vec![];
+            for app_rev in transaction.read_workspace_apps(&params.id)? {
+              view_revs.extend(read_belonging_views_on_local(
+                &app_rev.id,
+                trash_controller.clone(),
+                &transaction,
+              )?);
+            }
+            Ok(view_revs)
+          })
+          .await?
+      },
+    };
+
+    let export_dir = std::env::temp_dir().join(format!("appflowy_export_{}", gen_view_id()));
+    let result = match self.write_export_dir(&export_dir, view_revs).await {
+      Ok(_) => {
+        let archiver = self.archiver.clone();
+        let dir = export_dir.clone();
+        tokio::task::spawn_blocking(move || {
+          archiver.write_archive(
+            &dir,
+            Path::new(&params.file_path),
+            params.passphrase.as_deref(),
+          )
+        })
+        .await
+        .map_err(internal_error)
+        .and_then(|result| result)
+      },
+      Err(err) => Err(err),
+    };
+    let _ = fs::remove_dir_all(&export_dir);
+    result
+  }
+
+  async fn write_export_dir(
+    &self,
+    dir: &Path,
+    view_revs: Vec<ViewRevision>,
+  ) -> Result<(), FlowyError> {
+    fs::create_dir_all(dir)?;
+    for (index, view_rev) in view_revs.into_iter().enumerate() {
+      let processor = self.get_data_processor(view_rev.data_format.clone())?;
+      let view_data = processor.get_view_data(&view_rev.clone().into()).await?;
+      let view_dir = dir.join(index.to_string());
+      fs::create_dir_all(&view_dir)?;
+      let view_json = serde_json::to_vec(&view_rev).map_err(internal_error)?;
+      fs::write(view_dir.join(EXPORTED_VIEW_FILE), view_json)?;
+      fs::write(view_dir.join(EXPORTED_VIEW_DATA_FILE), view_data)?;
+    }
+    Ok(())
+  }
+
+  /// Creates a view that is filled with `size` synthetic items by the processor of the
+  /// `layout`, see [ViewDataProcessor::create_view_with_synthetic_data].
+  #[cfg(feature = "dev")]
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub(crate) async fn create_synthetic_view(
+    &self,
+    belong_to_id: &str,
+    name: &str,
+    layout: ViewLayoutTypePB,
+    size: usize,
+  ) -> Result<ViewRevision, FlowyError> {
+    let data_format = data_format_from_layout(&layout);
+    let processor = self.get_data_processor(data_format.clone())?;
+    let user_id = self.user.user_id()?;
+    let view_id = gen_view_id();
+    processor
+      .create_view_with_synthetic_data(&user_id, &view_id, name, layout.clone(), size)
+      .await?;
+
+    let params = CreateViewParams {
+      belong_to_id: belong_to_id.to_owned(),
+      name: name.to_owned(),
+      desc: "".to_string(),
+      thumbnail: "".to_string(),
+      data_format,
+      layout,
+      view_id: view_id.clone(),
+      initial_data: vec![],
+      ext: HashMap::default(),
+    };
+    match self.save_new_view(params).await {
+      Ok(view_rev) => Ok(view_rev),
+      Err(err) => {
+        let _ = processor.delete_view(&view_id).await;
+        Err(err)
+      },
+    }
+  }
+
+  async fn save_new_view(&self, params: CreateViewParams) -> Result<ViewRevision, FlowyError> {
     let trash_controller = self.trash_controller.clone();
     let view_rev = self
       .persistence
@@ -112,6 +383,7 @@ impl ViewController {
       })
       .await?;
 
+    self.search_controller.did_update_view(&view_rev.id).await;
     Ok(view_rev)
   }
 
@@ -177,6 +449,7 @@ impl ViewController {
   #[tracing::instrument(level = "trace", skip(self), err)]
   pub(crate) fn set_latest_view(&self, view_id: &str) -> Result<(), FlowyError> {
     KV::set_str(LATEST_VIEW_ID, view_id.to_owned());
+    self.search_controller.did_visit_view(view_id)?;
     Ok(())
   }
 
@@ -285,11 +558,126 @@ impl ViewController {
       .await
   }
 
+  /// Returns the views that belong to the app, they are sorted by the `sort`.
+  #[tracing::instrument(level = "trace", skip(self), err)]
+  pub(crate) async fn read_child_views(
+    &self,
+    belong_to_id: &str,
+    sort: ChildViewSortPB,
+  ) -> Result<Vec<ViewRevision>, FlowyError> {
+    let mut view_revs = self.read_views_belong_to(belong_to_id).await?;
+    if sort == ChildViewSortPB::LastEdited {
+      // The sort is stable, so the views that were edited at the same time keep their order.
+      view_revs.sort_by(|left, right| right.modified_time.cmp(&left.modified_time));
+    }
+    Ok(view_revs)
+  }
+
+  /// Records the `edited_by` as the last editor of the view when its content was changed. The
+  /// edits of the same user are throttled, since the document and the database are updated much
+  /// more often than the folder needs to know.
+  #[tracing::instrument(level = "trace", skip(self))]
+  pub(crate) async fn did_update_view_content(
+    &self,
+    view_id: &str,
+    edited_by: &str,
+  ) -> FlowyResult<()> {
+    let modified_time = timestamp();
+    self
+      .persistence
+      .begin_transaction(|transaction| {
+        let view_rev = transaction.read_view(view_id)?;
+        if view_rev.last_edited_by == edited_by
+          && modified_time - view_rev.modified_time < LAST_EDITED_THROTTLE_SECONDS
+        {
+          return Ok(());
+        }
+
+        transaction.update_view_last_edited(view_id, modified_time, edited_by)?;
+        let view: ViewPB = transaction.read_view(view_id)?.into();
+        send_notification(view_id, FolderNotification::DidUpdateView)
+          .payload(view)
+          .send();
+        notify_views_changed(
+          &view_rev.app_id,
+          self.trash_controller.clone(),
+          &transaction,
+        )
+      })
+      .await
+  }
+
+  /// Sets or removes the slug of the view. The slug is unique in the workspace, including the
+  /// views in the trash, so restoring a view never breaks the links of the other views.
+  #[tracing::instrument(level = "debug", skip(self, params), err)]
+  pub(crate) async fn update_view_slug(
+    &self,
+    params: UpdateViewSlugParams,
+  ) -> Result<ViewRevision, FlowyError> {
+    let slug = params.slug.unwrap_or_default();
+    self
+      .persistence
+      .begin_transaction(|transaction| {
+        let view_rev = transaction.read_view(&params.view_id)?;
+        if !slug.is_empty() {
+          let workspace_id = transaction.read_app(&view_rev.app_id)?.workspace_id;
+          let used_by_other_view = find_view_by_slug(&workspace_id, &slug, &transaction)?
+            .map(|other| other.id != view_rev.id)
+            .unwrap_or(false);
+          if used_by_other_view {
+            return Err(
+              FlowyError::view_slug_duplicated()
+                .context(format!("The slug: {} is used by another view", slug)),
+            );
+          }
+        }
+
+        transaction.update_view_slug(&view_rev.id, &slug)?;
+        let view_rev = transaction.read_view(&view_rev.id)?;
+        let view: ViewPB = view_rev.clone().into();
+        send_notification(&view_rev.id, FolderNotification::DidUpdateView)
+          .payload(view)
+          .send();
+        Ok(view_rev)
+      })
+      .await
+  }
+
+  /// Returns the view that the slug points to. The views in the trash can't be resolved.
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub(crate) async fn resolve_view_slug(
+    &self,
+    workspace_id: &str,
+    slug: &str,
+  ) -> Result<ViewRevision, FlowyError> {
+    self
+      .persistence
+      .begin_transaction(|transaction| {
+        let trash_ids = self.trash_controller.read_trash_ids(&transaction)?;
+        find_view_by_slug(workspace_id, slug, &transaction)?
+          .filter(|view_rev| !trash_ids.contains(&view_rev.id))
+          .ok_or_else(|| {
+            FlowyError::record_not_found().context(format!("Can't find the view of {}", slug))
+          })
+      })
+      .await
+  }
+
   #[tracing::instrument(level = "debug", skip(self, params), err)]
   pub(crate) async fn update_view(
     &self,
-    params: UpdateViewParams,
+    mut params: UpdateViewParams,
   ) -> Result<ViewRevision, FlowyError> {
+    if let Some(name) = params.name.take() {
+      let view_rev = self
+        .persistence
+        .begin_transaction(|transaction| transaction.read_view(&params.view_id))
+        .await?;
+      let name = self
+        .resolve_view_name(&view_rev.app_id, &name, Some(&view_rev.id))
+        .await?;
+      params.name = Some(name);
+    }
     let changeset = ViewChangeset::new(params.clone());
     let view_id = changeset.id.clone();
     let view_rev = self
@@ -311,9 +699,232 @@ impl ViewController {
       .await?;
 
     let _ = self.update_view_on_server(params);
+    self.search_controller.did_update_view(&view_rev.id).await;
     Ok(view_rev)
   }
 
+  pub(crate) fn read_view_name_setting(&self, workspace_id: &str) -> ViewNameSettingPB {
+    let policy = KV::get_int(&view_name_policy_key(workspace_id))
+      .map(ViewNamePolicyPB::from_value)
+      .unwrap_or_default();
+    ViewNameSettingPB {
+      workspace_id: workspace_id.to_owned(),
+      policy,
+    }
+  }
+
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub(crate) fn update_view_name_setting(&self, params: ViewNameSettingParams) {
+    let key = view_name_policy_key(&params.workspace_id);
+    match params.policy {
+      ViewNamePolicyPB::AllowDuplicate => {
+        let _ = KV::remove(&key);
+      },
+      policy => KV::set_int(&key, policy.value()),
+    }
+  }
+
+  /// Returns the state of the view that is kept for the current user.
+  pub(crate) async fn read_view_state(&self, view_id: &str) -> FlowyResult<ViewStatePB> {
+    let _guard = self.view_state_lock.lock().await;
+    Ok(ViewStatePB {
+      view_id: view_id.to_owned(),
+      values: self.read_view_state_values(view_id).await?,
+    })
+  }
+
+  /// Updates the state of the view. The state is read and saved under the lock, so the
+  /// concurrent updates of the same view don't drop each other's values.
+  #[tracing::instrument(level = "debug", skip_all, err)]
+  pub(crate) async fn update_view_state(
+    &self,
+    params: UpdateViewStateParams,
+  ) -> FlowyResult<ViewStatePB> {
+    let _guard = self.view_state_lock.lock().await;
+    let mut values = self.read_view_state_values(&params.view_id).await?;
+    for removed_key in params.removed_keys {
+      values.remove(&removed_key);
+    }
+    values.extend(params.values);
+
+    let s = serde_json::to_string(&values).map_err(internal_error)?;
+    if s.len() > MAX_VIEW_STATE_LEN {
+      return Err(FlowyError::invalid_data().context(format!(
+        "The state of the view:{} exceeds {} bytes",
+        params.view_id, MAX_VIEW_STATE_LEN
+      )));
+    }
+    self
+      .persistence
+      .begin_transaction(|transaction| {
+        transaction.update_view_state(&params.view_id, values.clone())
+      })
+      .await?;
+    Ok(ViewStatePB {
+      view_id: params.view_id,
+      values,
+    })
+  }
+
+  /// Reads the state of the view from the folder. The state that was kept on this device before
+  /// the state was synced with the folder is moved into the folder.
+  async fn read_view_state_values(&self, view_id: &str) -> FlowyResult<HashMap<String, String>> {
+    let key = view_state_key(&self.user.user_id()?, view_id);
+    self
+      .persistence
+      .begin_transaction(|transaction| {
+        // The deleted view has no state
+        let view_rev = match transaction.read_view(view_id) {
+          Err(e) if e.is_record_not_found() => return Ok(HashMap::new()),
+          result => result?,
+        };
+        if !view_rev.state.is_empty() {
+          return Ok(view_rev.state);
+        }
+
+        let values = KV::get_str(&key)
+          .and_then(|s| serde_json::from_str::<HashMap<String, String>>(&s).ok())
+          .unwrap_or_default();
+        if !values.is_empty() {
+          transaction.update_view_state(view_id, values.clone())?;
+          let _ = KV::remove(&key);
+        }
+        Ok(values)
+      })
+      .await
+  }
+
+  /// Returns the id of the current workspace if the `workspace_id` is None.
+  pub(crate) fn workspace_id_or_current(
+    &self,
+    workspace_id: Option<String>,
+  ) -> FlowyResult<String> {
+    match workspace_id {
+      Some(workspace_id) => Ok(workspace_id),
+      None => get_current_workspace(&self.user.user_id()?),
+    }
+  }
+
+  /// Returns the views of the workspace that share the same name with their siblings.
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub(crate) async fn read_duplicate_view_names(
+    &self,
+    workspace_id: &str,
+  ) -> FlowyResult<RepeatedDuplicateViewNamePB> {
+    let user_id = self.user.user_id()?;
+    let items = self
+      .persistence
+      .begin_transaction(|transaction| {
+        let trash_ids = self.trash_controller.read_trash_ids(&transaction)?;
+        let is_not_trash = |view_rev: &ViewRevision| !trash_ids.contains(&view_rev.id);
+        let mut items = vec![];
+        let mut stack = vec![];
+        for workspace_rev in transaction.read_workspaces(&user_id, Some(workspace_id.to_owned()))? {
+          for app_rev in workspace_rev.apps {
+            if trash_ids.contains(&app_rev.id) {
+              continue;
+            }
+            stack.push((app_rev.id, app_rev.belongings));
+          }
+        }
+        while let Some((belong_to_id, mut view_revs)) = stack.pop() {
+          view_revs.retain(is_not_trash);
+          items.extend(find_duplicate_names(&belong_to_id, &view_revs));
+          for view_rev in view_revs {
+            if !view_rev.belongings.is_empty() {
+              stack.push((view_rev.id, view_rev.belongings));
+            }
+          }
+        }
+        Ok(items)
+      })
+      .await?;
+    Ok(RepeatedDuplicateViewNamePB { items })
+  }
+
+  /// Renames the duplicate views of the workspace by appending a number to their names. The
+  /// first view of each duplicate group keeps its name. Returns the renamed views.
+  #[tracing::instrument(level = "debug", skip(self), err)]
+  pub(crate) async fn rename_duplicate_views(
+    &self,
+    workspace_id: &str,
+  ) -> FlowyResult<Vec<ViewRevision>> {
+    let duplicates = self.read_duplicate_view_names(workspace_id).await?;
+    let mut renamed_views = vec![];
+    for DuplicateViewNamePB {
+      belong_to_id,
+      view_ids,
+      ..
+    } in duplicates.items
+    {
+      let mut sibling_names = self
+        .read_views_belong_to(&belong_to_id)
+        .await?
+        .into_iter()
+        .map(|view_rev| view_rev.name)
+        .collect::<Vec<String>>();
+      for view_id in view_ids.into_iter().skip(1) {
+        let view_rev = self.read_view(&view_id).await?;
+        let name = make_unique_name(&view_rev.name, sibling_names.iter().map(|s| s.as_str()));
+        sibling_names.push(name.clone());
+        let view_rev = self
+          .update_view(UpdateViewParams {
+            view_id,
+            name: Some(name),
+            desc: None,
+            thumbnail: None,
+          })
+          .await?;
+        renamed_views.push(view_rev);
+      }
+    }
+    Ok(renamed_views)
+  }
+
+  /// Applies the [ViewNamePolicyPB] to the name of the view that is going to be created or
+  /// renamed. The `view_id` is the id of the renamed view, it's excluded from the siblings.
+  async fn resolve_view_name(
+    &self,
+    belong_to_id: &str,
+    name: &str,
+    view_id: Option<&str>,
+  ) -> FlowyResult<String> {
+    let workspace_id = self
+      .persistence
+      .begin_transaction(|transaction| transaction.read_app(belong_to_id))
+      .await?
+      .workspace_id;
+    let policy = self.read_view_name_setting(&workspace_id).policy;
+    if policy == ViewNamePolicyPB::AllowDuplicate {
+      return Ok(name.to_owned());
+    }
+
+    let sibling_names = self
+      .read_views_belong_to(belong_to_id)
+      .await?
+      .into_iter()
+      .filter(|view_rev| Some(view_rev.id.as_str()) != view_id)
+      .map(|view_rev| view_rev.name)
+      .collect::<Vec<String>>();
+    if !sibling_names
+      .iter()
+      .any(|sibling_name| is_same_name(sibling_name, name))
+    {
+      return Ok(name.to_owned());
+    }
+
+    match policy {
+      ViewNamePolicyPB::Reject => Err(
+        FlowyError::view_name_duplicated()
+          .context(format!("The name: {} is used by another view", name)),
+      ),
+      _ => Ok(make_unique_name(
+        name,
+        sibling_names.iter().map(|s| s.as_str()),
+      )),
+    }
+  }
+
   pub(crate) async fn latest_visit_view(&self) -> FlowyResult<Option<ViewRevision>> {
     match KV::get_str(LATEST_VIEW_ID) {
       None => Ok(None),
@@ -347,6 +958,7 @@ impl ViewController {
 
   fn listen_trash_can_event(&self) {
     let mut rx = self.trash_controller.subscribe();
+    let user = self.user.clone();
     let persistence = self.persistence.clone();
     let data_processors = self.data_processors.clone();
     let trash_controller = self.trash_controller.clone();
@@ -361,6 +973,7 @@ impl ViewController {
 
         if let Some(event) = stream.next().await {
           handle_trash_event(
+            user.clone(),
             persistence.clone(),
             data_processors.clone(),
             trash_controller.clone(),
@@ -399,8 +1012,9 @@ impl ViewController {
   }
 }
 
-#[tracing::instrument(level = "trace", skip(persistence, data_processors, trash_can))]
+#[tracing::instrument(level = "trace", skip(user, persistence, data_processors, trash_can))]
 async fn handle_trash_event(
+  user: Arc<dyn WorkspaceUser>,
   persistence: Arc<FolderPersistence>,
   data_processors: ViewDataProcessorMap,
   trash_can: Arc<TrashController>,
@@ -452,11 +1066,20 @@ async fn handle_trash_event(
           })
           .await?;
 
+        // The state that was not moved into the folder yet is kept per user on this device, only
+        // the state of the current user is known here
+        if let Ok(user_id) = user.user_id() {
+          for view in views.iter() {
+            let _ = KV::remove(&view_state_key(&user_id, &view.id));
+          }
+        }
+
         for view in views {
           let data_type = view.data_format.clone().into();
           match get_data_processor(data_processors.clone(), &data_type) {
             Ok(processor) => {
               processor.close_view(&view.id).await?;
+              processor.delete_view(&view.id).await?;
             },
             Err(e) => tracing::error!("{}", e),
           }
@@ -523,6 +1146,27 @@ fn notify_views_changed<'a>(
   Ok(())
 }
 
+fn view_name_policy_key(workspace_id: &str) -> String {
+  format!("{}:{}", VIEW_NAME_POLICY_PREFIX, workspace_id)
+}
+
+fn view_state_key(user_id: &str, view_id: &str) -> String {
+  format!("{}:{}:{}", VIEW_STATE_PREFIX, user_id, view_id)
+}
+
+fn find_view_by_slug<'a>(
+  workspace_id: &str,
+  slug: &str,
+  transaction: &'a (dyn FolderPersistenceTransaction + 'a),
+) -> FlowyResult<Option<ViewRevision>> {
+  let view_rev = transaction
+    .read_workspace_apps(workspace_id)?
+    .into_iter()
+    .flat_map(|app_rev| app_rev.belongings)
+    .find(|view_rev| view_rev.slug == slug);
+  Ok(view_rev)
+}
+
 fn read_belonging_views_on_local<'a>(
   belong_to_id: &str,
   trash_controller: Arc<TrashController>,
@@ -3,14 +3,21 @@ use crate::manager::FolderManager;
 use crate::services::{notify_workspace_setting_did_change, AppController};
 use crate::{
   entities::{
+    export::{ExportDataParams, ExportDataPayloadPB},
+    import::{ImportParams, ImportPayloadPB},
+    naming::{RepeatedDuplicateViewNamePB, ViewNameSettingPB, ViewNameSettingParams},
     trash::TrashPB,
     view::{
-      CreateViewParams, CreateViewPayloadPB, RepeatedViewIdPB, UpdateViewParams,
-      UpdateViewPayloadPB, ViewIdPB, ViewPB,
+      CreateViewParams, CreateViewPayloadPB, QuickCreateViewParams, QuickCreateViewPayloadPB,
+      ReadChildViewsParams, ReadChildViewsPayloadPB, RepeatedViewIdPB, RepeatedViewPB,
+      ResolveViewSlugParams, ResolveViewSlugPayloadPB, UpdateViewParams, UpdateViewPayloadPB,
+      UpdateViewSlugParams, UpdateViewSlugPayloadPB, UpdateViewStateParams,
+      UpdateViewStatePayloadPB, ViewIdPB, ViewPB, ViewStatePB,
     },
+    workspace::WorkspaceIdPB,
   },
   errors::FlowyError,
-  services::{TrashController, ViewController},
+  services::{QuotaController, TrashController, ViewController},
 };
 use folder_model::TrashRevision;
 use lib_dispatch::prelude::{data_result_ok, AFPluginData, AFPluginState, DataResult};
@@ -19,12 +26,47 @@ use std::{convert::TryInto, sync::Arc};
 pub(crate) async fn create_view_handler(
   data: AFPluginData<CreateViewPayloadPB>,
   controller: AFPluginState<Arc<ViewController>>,
+  quota_controller: AFPluginState<Arc<QuotaController>>,
 ) -> DataResult<ViewPB, FlowyError> {
   let params: CreateViewParams = data.into_inner().try_into()?;
+  quota_controller.check_workspace_size()?;
   let view_rev = controller.create_view_from_params(params).await?;
   data_result_ok(view_rev.into())
 }
 
+#[tracing::instrument(level = "debug", skip(data, folder, quota_controller), err)]
+pub(crate) async fn quick_create_view_handler(
+  data: AFPluginData<QuickCreateViewPayloadPB>,
+  folder: AFPluginState<Arc<FolderManager>>,
+  quota_controller: AFPluginState<Arc<QuotaController>>,
+) -> DataResult<ViewPB, FlowyError> {
+  let params: QuickCreateViewParams = data.into_inner().try_into()?;
+  quota_controller.check_workspace_size()?;
+  let view_rev = folder.quick_create_view(params).await?;
+  data_result_ok(view_rev.into())
+}
+
+pub(crate) async fn import_data_handler(
+  data: AFPluginData<ImportPayloadPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+  quota_controller: AFPluginState<Arc<QuotaController>>,
+) -> DataResult<ViewPB, FlowyError> {
+  let params: ImportParams = data.into_inner().try_into()?;
+  quota_controller.check_attachment_size(&params.file_path)?;
+  quota_controller.check_workspace_size()?;
+  let view_rev = controller.import_view(params).await?;
+  data_result_ok(view_rev.into())
+}
+
+pub(crate) async fn export_data_handler(
+  data: AFPluginData<ExportDataPayloadPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+) -> Result<(), FlowyError> {
+  let params: ExportDataParams = data.into_inner().try_into()?;
+  controller.export_data(params).await?;
+  Ok(())
+}
+
 pub(crate) async fn read_view_handler(
   data: AFPluginData<ViewIdPB>,
   controller: AFPluginState<Arc<ViewController>>,
@@ -34,6 +76,41 @@ pub(crate) async fn read_view_handler(
   data_result_ok(view_rev.into())
 }
 
+#[tracing::instrument(level = "debug", skip(data, controller), err)]
+pub(crate) async fn read_child_views_handler(
+  data: AFPluginData<ReadChildViewsPayloadPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+) -> DataResult<RepeatedViewPB, FlowyError> {
+  let params: ReadChildViewsParams = data.into_inner().try_into()?;
+  let view_revs = controller
+    .read_child_views(&params.belong_to_id, params.sort)
+    .await?;
+  data_result_ok(view_revs.into())
+}
+
+#[tracing::instrument(level = "debug", skip(data, controller), err)]
+pub(crate) async fn update_view_slug_handler(
+  data: AFPluginData<UpdateViewSlugPayloadPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+) -> DataResult<ViewPB, FlowyError> {
+  let params: UpdateViewSlugParams = data.into_inner().try_into()?;
+  let view_rev = controller.update_view_slug(params).await?;
+  data_result_ok(view_rev.into())
+}
+
+#[tracing::instrument(level = "debug", skip(data, controller), err)]
+pub(crate) async fn resolve_view_slug_handler(
+  data: AFPluginData<ResolveViewSlugPayloadPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+) -> DataResult<ViewPB, FlowyError> {
+  let params: ResolveViewSlugParams = data.into_inner().try_into()?;
+  let workspace_id = controller.workspace_id_or_current(params.workspace_id)?;
+  let view_rev = controller
+    .resolve_view_slug(&workspace_id, &params.slug)
+    .await?;
+  data_result_ok(view_rev.into())
+}
+
 #[tracing::instrument(level = "debug", skip(data, controller), err)]
 pub(crate) async fn update_view_handler(
   data: AFPluginData<UpdateViewPayloadPB>,
@@ -80,6 +157,26 @@ pub(crate) async fn set_latest_view_handler(
   Ok(())
 }
 
+#[tracing::instrument(level = "debug", skip(data, controller), err)]
+pub(crate) async fn read_view_state_handler(
+  data: AFPluginData<ViewIdPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+) -> DataResult<ViewStatePB, FlowyError> {
+  let view_id: ViewIdPB = data.into_inner();
+  let view_state = controller.read_view_state(&view_id.value).await?;
+  data_result_ok(view_state)
+}
+
+#[tracing::instrument(level = "debug", skip(data, controller), err)]
+pub(crate) async fn update_view_state_handler(
+  data: AFPluginData<UpdateViewStatePayloadPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+) -> DataResult<ViewStatePB, FlowyError> {
+  let params: UpdateViewStateParams = data.into_inner().try_into()?;
+  let view_state = controller.update_view_state(params).await?;
+  data_result_ok(view_state)
+}
+
 pub(crate) async fn close_view_handler(
   data: AFPluginData<ViewIdPB>,
   controller: AFPluginState<Arc<ViewController>>,
@@ -115,8 +212,52 @@ pub(crate) async fn move_item_handler(
 pub(crate) async fn duplicate_view_handler(
   data: AFPluginData<ViewPB>,
   controller: AFPluginState<Arc<ViewController>>,
+  quota_controller: AFPluginState<Arc<QuotaController>>,
 ) -> Result<(), FlowyError> {
   let view: ViewPB = data.into_inner();
+  quota_controller.check_workspace_size()?;
   controller.duplicate_view(view).await?;
   Ok(())
 }
+
+pub(crate) async fn read_view_name_setting_handler(
+  data: AFPluginData<WorkspaceIdPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+) -> DataResult<ViewNameSettingPB, FlowyError> {
+  let workspace_id = controller.workspace_id_or_current(data.into_inner().value)?;
+  data_result_ok(controller.read_view_name_setting(&workspace_id))
+}
+
+#[tracing::instrument(level = "debug", skip(data, controller), err)]
+pub(crate) async fn update_view_name_setting_handler(
+  data: AFPluginData<ViewNameSettingPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+) -> Result<(), FlowyError> {
+  let params: ViewNameSettingParams = data.into_inner().try_into()?;
+  controller.update_view_name_setting(params);
+  Ok(())
+}
+
+pub(crate) async fn read_duplicate_view_names_handler(
+  data: AFPluginData<WorkspaceIdPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+) -> DataResult<RepeatedDuplicateViewNamePB, FlowyError> {
+  let workspace_id = controller.workspace_id_or_current(data.into_inner().value)?;
+  let duplicates = controller.read_duplicate_view_names(&workspace_id).await?;
+  data_result_ok(duplicates)
+}
+
+#[tracing::instrument(level = "debug", skip(data, controller), err)]
+pub(crate) async fn rename_duplicate_views_handler(
+  data: AFPluginData<WorkspaceIdPB>,
+  controller: AFPluginState<Arc<ViewController>>,
+) -> DataResult<RepeatedViewPB, FlowyError> {
+  let workspace_id = controller.workspace_id_or_current(data.into_inner().value)?;
+  let items = controller
+    .rename_duplicate_views(&workspace_id)
+    .await?
+    .into_iter()
+    .map(|view_rev| view_rev.into())
+    .collect();
+  data_result_ok(RepeatedViewPB { items })
+}
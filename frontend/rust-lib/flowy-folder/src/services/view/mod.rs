@@ -1,2 +1,3 @@
 pub mod controller;
 pub mod event_handler;
+mod naming;
@@ -0,0 +1,126 @@
+use crate::entities::DuplicateViewNamePB;
+use folder_model::ViewRevision;
+use std::collections::{HashMap, HashSet};
+
+/// Returns the names that are used by more than one of the sibling views. The names are
+/// compared after trimming the whitespaces and ignoring the case.
+pub(crate) fn find_duplicate_names(
+  belong_to_id: &str,
+  sibling_views: &[ViewRevision],
+) -> Vec<DuplicateViewNamePB> {
+  let mut duplicates: Vec<DuplicateViewNamePB> = vec![];
+  let mut index_by_name: HashMap<String, usize> = HashMap::new();
+  let mut first_view_by_name: HashMap<String, &ViewRevision> = HashMap::new();
+  for view_rev in sibling_views {
+    let key = normalize_name(&view_rev.name);
+    if let Some(index) = index_by_name.get(&key) {
+      duplicates[*index].view_ids.push(view_rev.id.clone());
+      continue;
+    }
+
+    match first_view_by_name.get(&key) {
+      None => {
+        first_view_by_name.insert(key, view_rev);
+      },
+      Some(first_view) => {
+        index_by_name.insert(key, duplicates.len());
+        duplicates.push(DuplicateViewNamePB {
+          belong_to_id: belong_to_id.to_owned(),
+          name: first_view.name.clone(),
+          view_ids: vec![first_view.id.clone(), view_rev.id.clone()],
+        });
+      },
+    }
+  }
+  duplicates
+}
+
+/// Returns the `name` if none of the `existing_names` is the same, otherwise appends the
+/// smallest number that makes it unique, e.g. "Untitled (2)".
+pub(crate) fn make_unique_name<'a, T>(name: &str, existing_names: T) -> String
+where
+  T: IntoIterator<Item = &'a str>,
+{
+  let existing_names = existing_names
+    .into_iter()
+    .map(normalize_name)
+    .collect::<HashSet<String>>();
+  if !existing_names.contains(&normalize_name(name)) {
+    return name.to_owned();
+  }
+
+  let base_name = strip_number_suffix(name);
+  let mut number = 1;
+  loop {
+    let candidate = format!("{} ({})", base_name, number);
+    if !existing_names.contains(&normalize_name(&candidate)) {
+      return candidate;
+    }
+    number += 1;
+  }
+}
+
+pub(crate) fn is_same_name(left: &str, right: &str) -> bool {
+  normalize_name(left) == normalize_name(right)
+}
+
+fn normalize_name(name: &str) -> String {
+  name.trim().to_lowercase()
+}
+
+/// Removes the " (n)" suffix that was added by [make_unique_name].
+fn strip_number_suffix(name: &str) -> &str {
+  let trimmed = name.trim_end();
+  if let Some(prefix) = trimmed.strip_suffix(')') {
+    if let Some(position) = prefix.rfind(" (") {
+      let number = &prefix[position + 2..];
+      if !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()) {
+        return &trimmed[..position];
+      }
+    }
+  }
+  trimmed
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn make_unique_name_test() {
+    assert_eq!(make_unique_name("Doc", vec!["Grid"]), "Doc");
+    assert_eq!(make_unique_name("Doc", vec!["doc "]), "Doc (1)");
+    assert_eq!(make_unique_name("Doc", vec!["Doc", "Doc (1)"]), "Doc (2)");
+    assert_eq!(
+      make_unique_name("Doc (1)", vec!["Doc", "Doc (1)"]),
+      "Doc (2)"
+    );
+    assert_eq!(make_unique_name("Q (a)", vec!["Q (a)"]), "Q (a) (1)");
+  }
+
+  #[test]
+  fn find_duplicate_names_test() {
+    let views = ["A", "b", "a", "B ", "c", "A"]
+      .iter()
+      .enumerate()
+      .map(|(index, name)| {
+        ViewRevision::new(
+          index.to_string(),
+          "app".to_owned(),
+          name.to_string(),
+          "".to_owned(),
+          Default::default(),
+          Default::default(),
+          0,
+          0,
+        )
+      })
+      .collect::<Vec<ViewRevision>>();
+    let duplicates = find_duplicate_names("app", &views);
+    assert_eq!(duplicates.len(), 2);
+    assert_eq!(duplicates[0].name, "A");
+    assert_eq!(duplicates[0].view_ids, vec!["0", "2", "5"]);
+    assert_eq!(duplicates[1].name, "b");
+    assert_eq!(duplicates[1].view_ids, vec!["1", "3"]);
+  }
+}
@@ -1,4 +1,4 @@
-use crate::services::FOLDER_SYNC_INTERVAL_IN_MILLIS;
+use crate::services::{notify_workspace_settings_changes, FOLDER_SYNC_INTERVAL_IN_MILLIS};
 use bytes::Bytes;
 use flowy_client_sync::client_folder::{FolderOperations, FolderPad};
 use flowy_client_sync::make_operations_from_revisions;
@@ -75,6 +75,10 @@ impl RevisionWebSocketSink for FolderWSDataSink {
     let sink_provider = self.0.clone();
     FutureResult::new(async move { sink_provider.next().await })
   }
+
+  fn span(&self, data: &ClientRevisionWSData) -> tracing::Span {
+    self.0.span(data)
+  }
 }
 
 struct FolderConflictResolver {
@@ -89,7 +93,10 @@ impl ConflictResolver<FolderResolveOperations> for FolderConflictResolver {
     let operations = operations.into_inner();
     let folder_pad = self.folder_pad.clone();
     Box::pin(async move {
-      let md5 = folder_pad.write().compose_remote_operations(operations)?;
+      let mut folder_pad = folder_pad.write();
+      let old_settings = folder_pad.read_workspace_settings();
+      let md5 = folder_pad.compose_remote_operations(operations)?;
+      notify_workspace_settings_changes(old_settings, folder_pad.read_workspace_settings());
       Ok(md5.into())
     })
   }
@@ -126,7 +133,10 @@ impl ConflictResolver<FolderResolveOperations> for FolderConflictResolver {
   ) -> BoxResultFuture<RevisionMD5, FlowyError> {
     let folder_pad = self.folder_pad.clone();
     Box::pin(async move {
-      let md5 = folder_pad.write().reset_folder(operations.into_inner())?;
+      let mut folder_pad = folder_pad.write();
+      let old_settings = folder_pad.read_workspace_settings();
+      let md5 = folder_pad.reset_folder(operations.into_inner())?;
+      notify_workspace_settings_changes(old_settings, folder_pad.read_workspace_settings());
       Ok(md5.into())
     })
   }
@@ -1,4 +1,5 @@
 use crate::entities::workspace::*;
+use crate::entities::{WorkspaceSettingsPB, WorkspaceSettingsParams};
 use crate::manager::FolderManager;
 use crate::{
   errors::*,
@@ -10,15 +11,18 @@ use crate::{
   },
 };
 use flowy_sqlite::kv::KV;
-use folder_model::{AppRevision, WorkspaceRevision};
+use folder_model::{AppRevision, WorkspaceRevision, WorkspaceSettingsRevision};
 use lib_dispatch::prelude::ToBytes;
+use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::sync::broadcast;
 
 pub struct WorkspaceController {
   pub user: Arc<dyn WorkspaceUser>,
   persistence: Arc<FolderPersistence>,
   pub(crate) trash_controller: Arc<TrashController>,
   cloud_service: Arc<dyn FolderCouldServiceV1>,
+  settings_notify: broadcast::Sender<WorkspaceSettingsPB>,
 }
 
 impl WorkspaceController {
@@ -28,14 +32,22 @@ impl WorkspaceController {
     trash_can: Arc<TrashController>,
     cloud_service: Arc<dyn FolderCouldServiceV1>,
   ) -> Self {
+    let (settings_notify, _) = broadcast::channel(10);
     Self {
       user,
       persistence,
       trash_controller: trash_can,
       cloud_service,
+      settings_notify,
     }
   }
 
+  /// Subscribes to the settings of the workspaces that are updated on this device or opened. The
+  /// settings may belong to a workspace other than the current one.
+  pub fn subscribe_workspace_settings(&self) -> broadcast::Receiver<WorkspaceSettingsPB> {
+    self.settings_notify.subscribe()
+  }
+
   pub(crate) async fn create_workspace_from_params(
     &self,
     params: CreateWorkspaceParams,
@@ -107,12 +119,60 @@ impl WorkspaceController {
         .begin_transaction(|transaction| self.read_workspace(workspace_id, &user_id, &transaction))
         .await?;
       set_current_workspace(&user_id, &workspace.id);
+      // The settings of the opened workspace replace the ones of the previous workspace
+      match self
+        .read_workspace_settings(Some(workspace.id.clone()))
+        .await
+      {
+        Ok(settings) => {
+          let _ = self.settings_notify.send(settings);
+        },
+        Err(err) => tracing::error!("Read the workspace settings failed: {:?}", err),
+      }
       Ok(workspace)
     } else {
       Err(FlowyError::workspace_id().context("Opened workspace id should not be empty"))
     }
   }
 
+  pub(crate) async fn read_workspace_settings(
+    &self,
+    workspace_id: Option<String>,
+  ) -> Result<WorkspaceSettingsPB, FlowyError> {
+    let user_id = self.user.user_id()?;
+    let workspace_id = match workspace_id {
+      Some(workspace_id) => workspace_id,
+      None => get_current_workspace(&user_id)?,
+    };
+    let settings = self
+      .persistence
+      .begin_transaction(|transaction| {
+        read_workspace_settings(&workspace_id, &user_id, &transaction)
+      })
+      .await?;
+    Ok(WorkspaceSettingsPB::new(workspace_id, settings))
+  }
+
+  #[tracing::instrument(level = "debug", skip(self, params), err)]
+  pub(crate) async fn update_workspace_settings(
+    &self,
+    params: WorkspaceSettingsParams,
+  ) -> Result<WorkspaceSettingsPB, FlowyError> {
+    let user_id = self.user.user_id()?;
+    let workspace_id = params.workspace_id;
+    let settings = self
+      .persistence
+      .begin_transaction(|transaction| {
+        transaction.update_workspace_settings(&workspace_id, params.settings)?;
+        read_workspace_settings(&workspace_id, &user_id, &transaction)
+      })
+      .await?;
+    let settings = WorkspaceSettingsPB::new(workspace_id, settings);
+    send_workspace_settings_notification(settings.clone());
+    let _ = self.settings_notify.send(settings.clone());
+    Ok(settings)
+  }
+
   pub(crate) async fn read_current_workspace_apps(&self) -> Result<Vec<AppRevision>, FlowyError> {
     let user_id = self.user.user_id()?;
     let workspace_id = get_current_workspace(&user_id)?;
@@ -250,6 +310,42 @@ pub async fn notify_workspace_setting_did_change(
   Ok(())
 }
 
+fn read_workspace_settings<'a>(
+  workspace_id: &str,
+  user_id: &str,
+  transaction: &'a (dyn FolderPersistenceTransaction + 'a),
+) -> Result<WorkspaceSettingsRevision, FlowyError> {
+  transaction
+    .read_workspaces(user_id, Some(workspace_id.to_owned()))?
+    .pop()
+    .map(|workspace_rev| workspace_rev.settings)
+    .ok_or_else(|| {
+      FlowyError::record_not_found().context(format!("{} workspace not found", workspace_id))
+    })
+}
+
+/// Notifies the workspaces whose settings are different from the `old_settings`. It's used
+/// after applying the changes that are made by the other members.
+pub(crate) fn notify_workspace_settings_changes(
+  old_settings: HashMap<String, WorkspaceSettingsRevision>,
+  new_settings: HashMap<String, WorkspaceSettingsRevision>,
+) {
+  for (workspace_id, settings) in new_settings {
+    if old_settings.get(&workspace_id) != Some(&settings) {
+      send_workspace_settings_notification(WorkspaceSettingsPB::new(workspace_id, settings));
+    }
+  }
+}
+
+fn send_workspace_settings_notification(settings: WorkspaceSettingsPB) {
+  send_notification(
+    &settings.workspace_id,
+    FolderNotification::DidUpdateWorkspaceSettings,
+  )
+  .payload(settings)
+  .send();
+}
+
 /// The [CURRENT_WORKSPACE] represents as the current workspace that opened by the
 /// user. Only one workspace can be opened at a time.
 const CURRENT_WORKSPACE: &str = "current-workspace";
@@ -2,6 +2,7 @@ use crate::entities::{
   app::RepeatedAppPB,
   view::ViewPB,
   workspace::{RepeatedWorkspacePB, WorkspaceIdPB, WorkspaceSettingPB, *},
+  workspace_settings::{WorkspaceSettingsPB, WorkspaceSettingsParams},
 };
 use crate::{
   errors::FlowyError,
@@ -74,6 +75,26 @@ pub(crate) async fn read_workspaces_handler(
   data_result_ok(workspaces)
 }
 
+#[tracing::instrument(level = "debug", skip(data, controller), err)]
+pub(crate) async fn read_workspace_settings_handler(
+  data: AFPluginData<WorkspaceIdPB>,
+  controller: AFPluginState<Arc<WorkspaceController>>,
+) -> DataResult<WorkspaceSettingsPB, FlowyError> {
+  let params: WorkspaceIdPB = data.into_inner();
+  let settings = controller.read_workspace_settings(params.value).await?;
+  data_result_ok(settings)
+}
+
+#[tracing::instrument(level = "debug", skip(data, controller), err)]
+pub(crate) async fn update_workspace_settings_handler(
+  data: AFPluginData<WorkspaceSettingsPB>,
+  controller: AFPluginState<Arc<WorkspaceController>>,
+) -> DataResult<WorkspaceSettingsPB, FlowyError> {
+  let params: WorkspaceSettingsParams = data.into_inner().try_into()?;
+  let settings = controller.update_workspace_settings(params).await?;
+  data_result_ok(settings)
+}
+
 #[tracing::instrument(level = "debug", skip(folder), err)]
 pub async fn read_cur_workspace_handler(
   folder: AFPluginState<Arc<FolderManager>>,
@@ -1,8 +1,21 @@
 use crate::script::{invalid_workspace_name_test_case, FolderScript::*, FolderTest};
+use flowy_core::access_token::{issue_access_token, revoke_access_token};
+use flowy_error::ErrorRecovery;
+use flowy_folder::entities::app::{AppPB, CreateAppPayloadPB};
 use flowy_folder::entities::view::ViewDataFormatPB;
-use flowy_folder::entities::workspace::CreateWorkspacePayloadPB;
+use flowy_folder::entities::workspace::{
+  CreateWorkspacePayloadPB, RepeatedWorkspacePB, WorkspaceIdPB, WorkspacePB,
+};
+use flowy_folder::entities::{
+  ChildViewSortPB, QuickCreateLocationPB, UpdateViewStatePayloadPB, ViewLayoutTypePB,
+  ViewNamePolicyPB, ViewPermissionTypePB, WorkspaceQuota, WorkspaceSettingsPB, MAX_SYNTHETIC_ROWS,
+};
+use flowy_folder::errors::{ErrorCode, FlowyError};
+use flowy_folder::event_map::FolderEvent;
 use flowy_revision_persistence::RevisionState;
 use flowy_test::{event_builder::*, FlowySDKTest};
+use lib_dispatch::prelude::{AFPluginRequest, PermissionScope, ToBytes};
+use std::collections::HashMap;
 
 #[tokio::test]
 async fn workspace_read_all() {
@@ -83,6 +96,159 @@ async fn workspace_create_with_invalid_name() {
   }
 }
 
+#[tokio::test]
+async fn workspace_event_scopes() {
+  let test = FolderTest::new().await;
+  let read_workspace = vec![PermissionScope::read("workspace")];
+  let workspaces = FolderEventBuilder::new(test.sdk.clone())
+    .event(FolderEvent::ReadWorkspaces)
+    .payload(WorkspaceIdPB::new(None))
+    .scopes(read_workspace.clone())
+    .async_send()
+    .await
+    .parse::<RepeatedWorkspacePB>();
+  assert!(!workspaces.items.is_empty());
+
+  // Creating an app requires the write:workspace scope
+  let request = || CreateAppPayloadPB {
+    workspace_id: test.workspace.id.clone(),
+    name: "Scoped app".to_owned(),
+    desc: "".to_owned(),
+    color_style: Default::default(),
+  };
+  let error = FolderEventBuilder::new(test.sdk.clone())
+    .event(FolderEvent::CreateApp)
+    .payload(request())
+    .scopes(read_workspace)
+    .async_send()
+    .await
+    .error();
+  assert_eq!(error.code, ErrorCode::PermissionDenied.value());
+
+  let app = FolderEventBuilder::new(test.sdk.clone())
+    .event(FolderEvent::CreateApp)
+    .payload(request())
+    .scopes(vec![PermissionScope::write("workspace")])
+    .async_send()
+    .await
+    .parse::<AppPB>();
+  assert_eq!(app.name, "Scoped app");
+
+  // Creating a workspace requires the admin:workspace scope
+  let request = CreateWorkspacePayloadPB {
+    name: "Scoped workspace".to_owned(),
+    desc: "".to_owned(),
+  };
+  let error = FolderEventBuilder::new(test.sdk.clone())
+    .event(FolderEvent::CreateWorkspace)
+    .payload(request)
+    .scopes(vec![
+      PermissionScope::write("workspace"),
+      PermissionScope::write("database"),
+    ])
+    .async_send()
+    .await
+    .error();
+  assert_eq!(error.code, ErrorCode::PermissionDenied.value());
+}
+
+#[tokio::test]
+async fn workspace_access_token_scopes() {
+  let test = FolderTest::new().await;
+  let request = || {
+    AFPluginRequest::new(FolderEvent::ReadWorkspaces)
+      .payload(WorkspaceIdPB::new(None).into_bytes().unwrap())
+  };
+  let token = issue_access_token(&[PermissionScope::read("workspace")]).unwrap();
+  let workspaces = test
+    .sdk
+    .dispatch_with_token(&token, request())
+    .await
+    .parse::<RepeatedWorkspacePB, FlowyError>()
+    .unwrap()
+    .unwrap();
+  assert!(!workspaces.items.is_empty());
+
+  // The scopes of the token are applied to the request
+  let create_workspace = AFPluginRequest::new(FolderEvent::CreateWorkspace).payload(
+    CreateWorkspacePayloadPB {
+      name: "Token workspace".to_owned(),
+      desc: "".to_owned(),
+    }
+    .into_bytes()
+    .unwrap(),
+  );
+  let error = test
+    .sdk
+    .dispatch_with_token(&token, create_workspace)
+    .await
+    .parse::<WorkspacePB, FlowyError>()
+    .unwrap()
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::PermissionDenied.value());
+
+  revoke_access_token(&token);
+  let error = test
+    .sdk
+    .dispatch_with_token(&token, request())
+    .await
+    .parse::<RepeatedWorkspacePB, FlowyError>()
+    .unwrap()
+    .unwrap_err();
+  assert_eq!(error.code, ErrorCode::UserUnauthorized.value());
+}
+
+#[tokio::test]
+async fn workspace_update_settings() {
+  let mut test = FolderTest::new().await;
+  let workspace_id = test.workspace.id.clone();
+  test
+    .run_scripts(vec![AssertWorkspaceSettings(WorkspaceSettingsPB {
+      workspace_id: workspace_id.clone(),
+      ..Default::default()
+    })])
+    .await;
+
+  let settings = WorkspaceSettingsPB {
+    workspace_id,
+    default_database_layout: ViewLayoutTypePB::Board,
+    default_date_format: Some(2),
+    default_time_format: None,
+    default_number_format: Some(1),
+    trash_retention_days: 30,
+    default_member_permission: ViewPermissionTypePB::SharedReadOnly,
+    default_view_layout: ViewLayoutTypePB::Grid,
+    quick_create_location: QuickCreateLocationPB::Inbox,
+    inbox_app_id: None,
+    first_day_of_week: 1,
+    working_days: Some(0b0011110),
+  };
+  test
+    .run_scripts(vec![
+      UpdateWorkspaceSettings {
+        settings: settings.clone(),
+        error: None,
+      },
+      AssertWorkspaceSettings(settings.clone()),
+      UpdateWorkspaceSettings {
+        settings: WorkspaceSettingsPB {
+          trash_retention_days: -1,
+          ..settings.clone()
+        },
+        error: Some(ErrorCode::InvalidData),
+      },
+      UpdateWorkspaceSettings {
+        settings: WorkspaceSettingsPB {
+          first_day_of_week: 7,
+          ..settings.clone()
+        },
+        error: Some(ErrorCode::InvalidData),
+      },
+      AssertWorkspaceSettings(settings),
+    ])
+    .await;
+}
+
 #[tokio::test]
 #[should_panic]
 async fn app_delete() {
@@ -178,6 +344,664 @@ async fn view_update() {
   assert_eq!(test.view.name, new_name);
 }
 
+#[tokio::test]
+async fn view_update_when_locked() {
+  let mut test = FolderTest::new().await;
+  let view = test.view.clone();
+  let new_name = "Locked view".to_owned();
+
+  test
+    .run_scripts(vec![
+      UpdateViewPermission(ViewPermissionTypePB::Locked),
+      AssertUpdateViewError {
+        name: Some(new_name.clone()),
+        error: ErrorCode::PermissionDenied,
+      },
+      ReadView(view.id.clone()),
+      AssertView(view.clone()),
+      UpdateViewPermission(ViewPermissionTypePB::Editable),
+      UpdateView {
+        name: Some(new_name.clone()),
+        desc: None,
+      },
+      ReadView(view.id),
+    ])
+    .await;
+  assert_eq!(test.view.name, new_name);
+}
+
+#[tokio::test]
+async fn view_detect_and_rename_duplicate_names() {
+  let mut test = FolderTest::new().await;
+  let name = test.view.name.clone();
+  test
+    .run_scripts(vec![
+      AssertDuplicateViewNames(vec![]),
+      CreateView {
+        name: name.clone(),
+        desc: "".to_owned(),
+        data_type: ViewDataFormatPB::DeltaFormat,
+      },
+      CreateView {
+        name: name.clone(),
+        desc: "".to_owned(),
+        data_type: ViewDataFormatPB::DeltaFormat,
+      },
+      AssertDuplicateViewNames(vec![name.clone()]),
+      RenameDuplicateViews,
+      AssertDuplicateViewNames(vec![]),
+      ReadApp(test.app.id.clone()),
+    ])
+    .await;
+
+  let names = test
+    .app
+    .belongings
+    .iter()
+    .map(|view| view.name.clone())
+    .collect::<Vec<String>>();
+  assert_eq!(
+    names,
+    vec![
+      name.clone(),
+      format!("{} (1)", name),
+      format!("{} (2)", name)
+    ]
+  );
+}
+
+#[tokio::test]
+async fn view_auto_rename_duplicate_name() {
+  let mut test = FolderTest::new().await;
+  let name = test.view.name.clone();
+  test
+    .run_scripts(vec![
+      UpdateViewNamePolicy(ViewNamePolicyPB::AutoRename),
+      CreateView {
+        name: name.clone(),
+        desc: "".to_owned(),
+        data_type: ViewDataFormatPB::DeltaFormat,
+      },
+    ])
+    .await;
+  assert_eq!(test.view.name, format!("{} (1)", name));
+
+  test
+    .run_scripts(vec![
+      UpdateView {
+        name: Some(name.clone()),
+        desc: None,
+      },
+      ReadView(test.view.id.clone()),
+    ])
+    .await;
+  assert_eq!(test.view.name, format!("{} (1)", name));
+}
+
+#[tokio::test]
+async fn view_reject_duplicate_name() {
+  let mut test = FolderTest::new().await;
+  let name = test.view.name.clone();
+  test
+    .run_scripts(vec![
+      UpdateViewNamePolicy(ViewNamePolicyPB::Reject),
+      CreateView {
+        name: "Another view".to_owned(),
+        desc: "".to_owned(),
+        data_type: ViewDataFormatPB::DeltaFormat,
+      },
+      AssertUpdateViewError {
+        name: Some(name),
+        error: ErrorCode::ViewNameDuplicated,
+      },
+      UpdateView {
+        name: Some("Another view".to_owned()),
+        desc: None,
+      },
+      AssertDuplicateViewNames(vec![]),
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn search_view_after_rebuilding_index() {
+  let mut test = FolderTest::new().await;
+  test
+    .run_scripts(vec![
+      CreateView {
+        name: "Weekly planning".to_owned(),
+        desc: "".to_owned(),
+        data_type: ViewDataFormatPB::DeltaFormat,
+      },
+      RebuildSearchIndex,
+      AssertSearchResults {
+        query: "weekly".to_owned(),
+        expected_names: vec!["Weekly planning".to_owned()],
+      },
+      // The last term of the query is used as a prefix
+      AssertSearchResults {
+        query: "Weekly PLAN".to_owned(),
+        expected_names: vec!["Weekly planning".to_owned()],
+      },
+      AssertSearchResults {
+        query: "monthly".to_owned(),
+        expected_names: vec![],
+      },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn search_view_after_updating_view() {
+  let mut test = FolderTest::new().await;
+  test
+    .run_scripts(vec![
+      UpdateView {
+        name: Some("Product roadmap".to_owned()),
+        desc: None,
+      },
+      WaitForSearchIndexing,
+      AssertSearchResults {
+        query: "roadmap".to_owned(),
+        expected_names: vec!["Product roadmap".to_owned()],
+      },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn search_view_excludes_trash() {
+  let mut test = FolderTest::new().await;
+  test
+    .run_scripts(vec![
+      RebuildSearchIndex,
+      AssertSearchResults {
+        query: "folder view".to_owned(),
+        expected_names: vec!["Folder View".to_owned()],
+      },
+      DeleteView,
+      AssertSearchResults {
+        query: "folder view".to_owned(),
+        expected_names: vec![],
+      },
+      RestoreViewFromTrash,
+      AssertSearchResults {
+        query: "folder view".to_owned(),
+        expected_names: vec!["Folder View".to_owned()],
+      },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn quick_switch_fuzzy_match_view_name() {
+  let mut test = FolderTest::new().await;
+  test
+    .run_scripts(vec![
+      CreateView {
+        name: "Group notes".to_owned(),
+        desc: "".to_owned(),
+        data_type: ViewDataFormatPB::DeltaFormat,
+      },
+      CreateView {
+        name: "Grid Planning".to_owned(),
+        desc: "".to_owned(),
+        data_type: ViewDataFormatPB::DeltaFormat,
+      },
+      RebuildSearchIndex,
+      // The characters at the beginning of the words weigh more
+      AssertQuickSwitchResults {
+        query: "gp".to_owned(),
+        expected_names: vec!["Grid Planning".to_owned(), "Group notes".to_owned()],
+      },
+      AssertQuickSwitchResults {
+        query: "grpnotes".to_owned(),
+        expected_names: vec!["Group notes".to_owned()],
+      },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn view_last_edited_test() {
+  let mut test = FolderTest::new().await;
+  test
+    .run_scripts(vec![
+      CreateView {
+        name: "Roadmap".to_owned(),
+        desc: "".to_owned(),
+        data_type: ViewDataFormatPB::DeltaFormat,
+      },
+      EditViewContent,
+      AssertViewLastEditedByCurrentUser,
+      AssertChildViews {
+        sort: ChildViewSortPB::Manual,
+        expected_names: vec!["Folder View".to_owned(), "Roadmap".to_owned()],
+      },
+      AssertChildViews {
+        sort: ChildViewSortPB::LastEdited,
+        expected_names: vec!["Roadmap".to_owned(), "Folder View".to_owned()],
+      },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn view_quick_create_test() {
+  let mut test = FolderTest::new().await;
+  let app_id = test.app.id.clone();
+  test
+    .run_scripts(vec![QuickCreateView {
+      name: "".to_owned(),
+      layout: None,
+      parent_id: Some(app_id.clone()),
+    }])
+    .await;
+  assert_eq!(test.view.app_id, app_id);
+  assert_eq!(test.view.name, "Untitled");
+  assert_eq!(test.view.layout, ViewLayoutTypePB::Document);
+
+  let settings = WorkspaceSettingsPB {
+    workspace_id: test.workspace.id.clone(),
+    default_view_layout: ViewLayoutTypePB::Board,
+    quick_create_location: QuickCreateLocationPB::Inbox,
+    ..Default::default()
+  };
+  test
+    .run_scripts(vec![
+      UpdateWorkspaceSettings {
+        settings: settings.clone(),
+        error: None,
+      },
+      QuickCreateView {
+        name: "Ideas".to_owned(),
+        layout: None,
+        parent_id: Some(app_id.clone()),
+      },
+    ])
+    .await;
+  let inbox_app_id = test.view.app_id.clone();
+  assert_ne!(inbox_app_id, app_id);
+  assert_eq!(test.view.layout, ViewLayoutTypePB::Board);
+
+  // The inbox is reused by the next views and saved in the settings
+  test
+    .run_scripts(vec![
+      QuickCreateView {
+        name: "Tasks".to_owned(),
+        layout: Some(ViewLayoutTypePB::Grid),
+        parent_id: None,
+      },
+      AssertWorkspaceSettings(WorkspaceSettingsPB {
+        inbox_app_id: Some(inbox_app_id.clone()),
+        ..settings
+      }),
+    ])
+    .await;
+  assert_eq!(test.view.app_id, inbox_app_id);
+  assert_eq!(test.view.layout, ViewLayoutTypePB::Grid);
+}
+
+#[tokio::test]
+async fn view_slug_test() {
+  let mut test = FolderTest::new().await;
+  test
+    .run_scripts(vec![
+      UpdateViewSlug(Some("roadmap-2023".to_owned())),
+      AssertResolveViewSlug {
+        slug: "roadmap-2023".to_owned(),
+        expected_name: Some("Folder View".to_owned()),
+      },
+      UpdateView {
+        name: Some("Roadmap".to_owned()),
+        desc: None,
+      },
+      AssertResolveViewSlug {
+        slug: "roadmap-2023".to_owned(),
+        expected_name: Some("Roadmap".to_owned()),
+      },
+      AssertUpdateViewSlugError {
+        slug: "Roadmap 2023".to_owned(),
+        error: ErrorCode::ViewSlugInvalid,
+      },
+      CreateView {
+        name: "Backlog".to_owned(),
+        desc: "".to_owned(),
+        data_type: ViewDataFormatPB::DeltaFormat,
+      },
+      AssertUpdateViewSlugError {
+        slug: "roadmap-2023".to_owned(),
+        error: ErrorCode::ViewSlugDuplicated,
+      },
+      UpdateViewSlug(Some("backlog".to_owned())),
+      UpdateViewSlug(None),
+      AssertResolveViewSlug {
+        slug: "backlog".to_owned(),
+        expected_name: None,
+      },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn quick_switch_ranks_recent_view_higher() {
+  let mut test = FolderTest::new().await;
+  test
+    .run_scripts(vec![
+      CreateView {
+        name: "Roadmap 2023".to_owned(),
+        desc: "".to_owned(),
+        data_type: ViewDataFormatPB::DeltaFormat,
+      },
+      CreateView {
+        name: "Roadmap 2024".to_owned(),
+        desc: "".to_owned(),
+        data_type: ViewDataFormatPB::DeltaFormat,
+      },
+      RebuildSearchIndex,
+      AssertQuickSwitchResults {
+        query: "roadmap".to_owned(),
+        expected_names: vec!["Roadmap 2023".to_owned(), "Roadmap 2024".to_owned()],
+      },
+      SetLatestView,
+      AssertQuickSwitchResults {
+        query: "roadmap".to_owned(),
+        expected_names: vec!["Roadmap 2024".to_owned(), "Roadmap 2023".to_owned()],
+      },
+      // Returns the recently visited views if the query is empty. The "Read me" view is
+      // visited when the default workspace gets created.
+      AssertQuickSwitchResults {
+        query: "".to_owned(),
+        expected_names: vec!["Roadmap 2024".to_owned(), "Read me".to_owned()],
+      },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn quick_switch_match_view_alias() {
+  let mut test = FolderTest::new().await;
+  test
+    .run_scripts(vec![
+      CreateView {
+        name: "Meeting notes".to_owned(),
+        desc: "".to_owned(),
+        data_type: ViewDataFormatPB::DeltaFormat,
+      },
+      RebuildSearchIndex,
+      AssertQuickSwitchResults {
+        query: "standup".to_owned(),
+        expected_names: vec![],
+      },
+      UpdateViewAliases(vec!["Daily standup".to_owned()]),
+      WaitForSearchIndexing,
+      AssertQuickSwitchResults {
+        query: "standup".to_owned(),
+        expected_names: vec!["Meeting notes".to_owned()],
+      },
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn view_state_update_test() {
+  let mut test = FolderTest::new().await;
+  let values = |pairs: &[(&str, &str)]| {
+    pairs
+      .iter()
+      .map(|(key, value)| (key.to_string(), value.to_string()))
+      .collect::<HashMap<String, String>>()
+  };
+  test
+    .run_scripts(vec![
+      AssertViewState(HashMap::new()),
+      UpdateViewState {
+        values: values(&[("scroll_anchor", "row_1"), ("calendar_month", "2023-02")]),
+        removed_keys: vec![],
+      },
+      AssertViewState(values(&[
+        ("scroll_anchor", "row_1"),
+        ("calendar_month", "2023-02"),
+      ])),
+      // The values that are not updated are kept
+      UpdateViewState {
+        values: values(&[("expanded_groups", "todo,done")]),
+        removed_keys: vec!["calendar_month".to_owned()],
+      },
+      AssertViewState(values(&[
+        ("scroll_anchor", "row_1"),
+        ("expanded_groups", "todo,done"),
+      ])),
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn view_state_concurrent_update_test() {
+  let mut test = FolderTest::new().await;
+  let update_view_state = |key: &str| {
+    let request = UpdateViewStatePayloadPB {
+      view_id: test.view.id.clone(),
+      values: HashMap::from([(key.to_owned(), "1".to_owned())]),
+      removed_keys: vec![],
+    };
+    FolderEventBuilder::new(test.sdk.clone())
+      .event(FolderEvent::UpdateViewState)
+      .payload(request)
+      .async_send()
+  };
+  // Each update keeps the values of the other one
+  futures::future::join(
+    update_view_state("scroll_anchor"),
+    update_view_state("calendar_month"),
+  )
+  .await;
+  test
+    .run_scripts(vec![AssertViewState(HashMap::from([
+      ("scroll_anchor".to_owned(), "1".to_owned()),
+      ("calendar_month".to_owned(), "1".to_owned()),
+    ]))])
+    .await;
+}
+
+#[tokio::test]
+async fn view_state_removed_with_view_test() {
+  let mut test = FolderTest::new().await;
+  let values = HashMap::from([("scroll_anchor".to_owned(), "row_1".to_owned())]);
+  test
+    .run_scripts(vec![
+      UpdateViewState {
+        values: values.clone(),
+        removed_keys: vec![],
+      },
+      AssertViewState(values),
+      DeleteView,
+      DeleteAllTrash,
+      AssertViewState(HashMap::new()),
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn import_csv_as_grid_view() {
+  let mut test = FolderTest::new().await;
+  let file_path = std::env::temp_dir().join(format!("{}.csv", test.app.id));
+  std::fs::write(&file_path, "Name,Price\nApple,1\nBanana,2\n").unwrap();
+  test
+    .run_scripts(vec![ImportData {
+      name: "Fruits".to_owned(),
+      file_path: file_path.to_str().unwrap().to_owned(),
+    }])
+    .await;
+
+  let view = test.view.clone();
+  assert_eq!(view.name, "Fruits");
+  assert_eq!(view.layout, ViewLayoutTypePB::Grid);
+  test
+    .run_scripts(vec![ReadView(view.id.clone()), AssertView(view)])
+    .await;
+}
+
+#[tokio::test]
+async fn export_and_import_encrypted_archive_test() {
+  let mut test = FolderTest::new().await;
+  let file_path = std::env::temp_dir().join(format!("{}.archive", test.view.id));
+  let file_path = file_path.to_str().unwrap().to_owned();
+  let view = test.view.clone();
+  test
+    .run_scripts(vec![
+      ExportData {
+        file_path: file_path.clone(),
+        passphrase: Some("correct horse".to_owned()),
+      },
+      AssertImportArchiveError {
+        file_path: file_path.clone(),
+        passphrase: Some("wrong horse".to_owned()),
+        error: ErrorCode::InvalidPassphrase,
+      },
+      AssertImportArchiveError {
+        file_path: file_path.clone(),
+        passphrase: None,
+        error: ErrorCode::InvalidPassphrase,
+      },
+      ImportArchive {
+        name: "Restored".to_owned(),
+        file_path: file_path.clone(),
+        passphrase: Some("correct horse".to_owned()),
+      },
+    ])
+    .await;
+
+  let imported_view = test.view.clone();
+  assert_ne!(imported_view.id, view.id);
+  assert_eq!(imported_view.name, "Restored");
+  assert_eq!(imported_view.layout, view.layout);
+  test
+    .run_scripts(vec![
+      ReadView(imported_view.id.clone()),
+      AssertView(imported_view),
+    ])
+    .await;
+  let _ = std::fs::remove_file(file_path);
+}
+
+#[tokio::test]
+async fn import_file_exceeds_attachment_size_test() {
+  let quota = WorkspaceQuota {
+    max_attachment_size: 16,
+    ..Default::default()
+  };
+  let mut test = FolderTest::new_with_workspace_quota(quota).await;
+  let file_path = std::env::temp_dir().join(format!("{}.csv", test.app.id));
+  std::fs::write(&file_path, "Name,Price\nApple,1\nBanana,2\n").unwrap();
+  test
+    .run_scripts(vec![AssertImportDataError {
+      file_path: file_path.to_str().unwrap().to_owned(),
+      error: ErrorCode::AttachmentTooLarge,
+    }])
+    .await;
+}
+
+#[tokio::test]
+async fn create_view_exceeds_workspace_size_test() {
+  let quota = WorkspaceQuota {
+    max_workspace_size: 1,
+    ..Default::default()
+  };
+  let mut test = FolderTest::new_with_workspace_quota(quota).await;
+  test
+    .run_scripts(vec![AssertCreateViewError {
+      error: ErrorCode::WorkspaceSizeExceeded,
+      recovery: ErrorRecovery::None,
+    }])
+    .await;
+}
+
+#[tokio::test]
+async fn quota_usage_test() {
+  let quota = WorkspaceQuota {
+    max_attachment_size: 1024,
+    max_rows_per_database: 100,
+    max_workspace_size: 1024 * 1024 * 1024,
+  };
+  let mut test = FolderTest::new_with_workspace_quota(quota.clone()).await;
+  test.run_scripts(vec![AssertQuotaUsage(quota)]).await;
+}
+
+#[tokio::test]
+async fn workspace_health_test() {
+  let mut test = FolderTest::new().await;
+  test
+    .run_scripts(vec![
+      CreateView {
+        name: "Weekly planning".to_owned(),
+        desc: "".to_owned(),
+        data_type: ViewDataFormatPB::DeltaFormat,
+      },
+      RebuildSearchIndex,
+      WaitForSearchIndexing,
+      AssertWorkspaceHealthy,
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn memory_report_test() {
+  let mut test = FolderTest::new().await;
+  test
+    .run_scripts(vec![
+      CreateView {
+        name: "Weekly planning".to_owned(),
+        desc: "".to_owned(),
+        data_type: ViewDataFormatPB::DeltaFormat,
+      },
+      RebuildSearchIndex,
+      WaitForSearchIndexing,
+      AssertMemoryReport,
+      TrimCaches,
+    ])
+    .await;
+}
+
+#[tokio::test]
+async fn generate_synthetic_workspace_test() {
+  let mut test = FolderTest::new().await;
+  test
+    .run_scripts(vec![GenerateSyntheticWorkspace {
+      number_of_documents: 3,
+      number_of_databases: 2,
+      number_of_rows: 50,
+    }])
+    .await;
+
+  let views = test.app.belongings.items.clone();
+  assert_eq!(views.len(), 5);
+  assert_eq!(
+    views
+      .iter()
+      .filter(|view| view.layout == ViewLayoutTypePB::Document)
+      .count(),
+    3
+  );
+  assert_eq!(
+    views
+      .iter()
+      .filter(|view| view.layout == ViewLayoutTypePB::Grid)
+      .count(),
+    2
+  );
+  let app_id = test.app.id.clone();
+  test.run_scripts(vec![ReadApp(app_id)]).await;
+  assert_eq!(test.app.belongings.items.len(), 5);
+
+  test
+    .run_scripts(vec![AssertGenerateSyntheticWorkspaceError {
+      number_of_rows: MAX_SYNTHETIC_ROWS + 1,
+      error: ErrorCode::OutOfBounds,
+    }])
+    .await;
+}
+
 #[tokio::test]
 #[should_panic]
 async fn view_delete() {
@@ -1,3 +1,4 @@
+use flowy_error::ErrorRecovery;
 use flowy_folder::entities::view::{RepeatedViewIdPB, ViewIdPB};
 use flowy_folder::entities::workspace::WorkspaceIdPB;
 use flowy_folder::entities::{
@@ -5,7 +6,13 @@ use flowy_folder::entities::{
   trash::{RepeatedTrashPB, TrashIdPB, TrashType},
   view::{CreateViewPayloadPB, UpdateViewPayloadPB},
   workspace::{CreateWorkspacePayloadPB, RepeatedWorkspacePB},
-  ViewLayoutTypePB,
+  ChildViewSortPB, ExportDataPayloadPB, ExportScopePB, GenerateSyntheticWorkspacePayloadPB,
+  ImportPayloadPB, ImportTypePB, MemoryReportPB, QuickCreateViewPayloadPB, QuickSwitchPayloadPB,
+  QuotaUsagePB, ReadChildViewsPayloadPB, RepeatedDuplicateViewNamePB, RepeatedQuickSwitchItemPB,
+  RepeatedSearchResultPB, ResolveViewSlugPayloadPB, SearchPayloadPB, UpdateViewPermissionPayloadPB,
+  UpdateViewSlugPayloadPB, UpdateViewStatePayloadPB, ViewAliasesPB, ViewLayoutTypePB,
+  ViewNamePolicyPB, ViewNameSettingPB, ViewPermissionTypePB, ViewStatePB, WorkspaceHealthPB,
+  WorkspaceQuota, WorkspaceSettingsPB,
 };
 use flowy_folder::entities::{
   app::{AppPB, RepeatedAppPB},
@@ -18,6 +25,7 @@ use flowy_folder::{errors::ErrorCode, services::folder_editor::FolderEditor};
 use flowy_revision::REVISION_WRITE_INTERVAL_IN_MILLIS;
 use flowy_revision_persistence::RevisionState;
 use flowy_test::{event_builder::*, FlowySDKTest};
+use std::collections::HashMap;
 use std::{sync::Arc, time::Duration};
 use tokio::time::sleep;
 
@@ -31,6 +39,21 @@ pub enum FolderScript {
   // AssertWorkspaceRevisionJson(String),
   AssertWorkspace(WorkspacePB),
   ReadWorkspace(Option<String>),
+  UpdateWorkspaceSettings {
+    settings: WorkspaceSettingsPB,
+    error: Option<ErrorCode>,
+  },
+  AssertWorkspaceSettings(WorkspaceSettingsPB),
+  /// Generates the workspace and replaces the current workspace and app with the generated ones
+  GenerateSyntheticWorkspace {
+    number_of_documents: u32,
+    number_of_databases: u32,
+    number_of_rows: u32,
+  },
+  AssertGenerateSyntheticWorkspaceError {
+    number_of_rows: u32,
+    error: ErrorCode,
+  },
 
   // App
   CreateApp {
@@ -54,12 +77,94 @@ pub enum FolderScript {
   },
   AssertView(ViewPB),
   ReadView(String),
+  ImportData {
+    name: String,
+    file_path: String,
+  },
+  AssertImportDataError {
+    file_path: String,
+    error: ErrorCode,
+  },
+  ExportData {
+    file_path: String,
+    passphrase: Option<String>,
+  },
+  ImportArchive {
+    name: String,
+    file_path: String,
+    passphrase: Option<String>,
+  },
+  AssertImportArchiveError {
+    file_path: String,
+    passphrase: Option<String>,
+    error: ErrorCode,
+  },
+  AssertCreateViewError {
+    error: ErrorCode,
+    recovery: ErrorRecovery,
+  },
+  QuickCreateView {
+    name: String,
+    layout: Option<ViewLayoutTypePB>,
+    parent_id: Option<String>,
+  },
+  /// Asserts the quota of the workspace and that some of its storage is used
+  AssertQuotaUsage(WorkspaceQuota),
+  /// Asserts that the search index is up to date and that no background task failed
+  AssertWorkspaceHealthy,
+  /// Asserts that the search index is counted in the memory report
+  AssertMemoryReport,
+  /// Trims the caches and asserts that the cell data caches are empty
+  TrimCaches,
   UpdateView {
     name: Option<String>,
     desc: Option<String>,
   },
   DeleteView,
   DeleteViews(Vec<String>),
+  UpdateViewPermission(ViewPermissionTypePB),
+  AssertUpdateViewError {
+    name: Option<String>,
+    error: ErrorCode,
+  },
+  UpdateViewNamePolicy(ViewNamePolicyPB),
+  AssertDuplicateViewNames(Vec<String>),
+  RenameDuplicateViews,
+  /// Simulates editing the document or the database of the current view
+  EditViewContent,
+  AssertViewLastEditedByCurrentUser,
+  AssertChildViews {
+    sort: ChildViewSortPB,
+    expected_names: Vec<String>,
+  },
+  UpdateViewSlug(Option<String>),
+  AssertUpdateViewSlugError {
+    slug: String,
+    error: ErrorCode,
+  },
+  AssertResolveViewSlug {
+    slug: String,
+    expected_name: Option<String>,
+  },
+  UpdateViewState {
+    values: HashMap<String, String>,
+    removed_keys: Vec<String>,
+  },
+  AssertViewState(HashMap<String, String>),
+
+  // Search
+  RebuildSearchIndex,
+  WaitForSearchIndexing,
+  AssertSearchResults {
+    query: String,
+    expected_names: Vec<String>,
+  },
+  SetLatestView,
+  UpdateViewAliases(Vec<String>),
+  AssertQuickSwitchResults {
+    query: String,
+    expected_names: Vec<String>,
+  },
 
   // Trash
   RestoreAppFromTrash,
@@ -89,7 +194,14 @@ pub struct FolderTest {
 
 impl FolderTest {
   pub async fn new() -> Self {
-    let sdk = FlowySDKTest::default();
+    Self::new_with_sdk(FlowySDKTest::default()).await
+  }
+
+  pub async fn new_with_workspace_quota(quota: WorkspaceQuota) -> Self {
+    Self::new_with_sdk(FlowySDKTest::new_with_workspace_quota(quota)).await
+  }
+
+  async fn new_with_sdk(sdk: FlowySDKTest) -> Self {
     let _ = sdk.init_user().await;
     let mut workspace = create_workspace(&sdk, "FolderWorkspace", "Folder test workspace").await;
     let mut app = create_app(&sdk, &workspace.id, "Folder App", "Folder test app").await;
@@ -155,6 +267,28 @@ impl FolderTest {
         let workspace = read_workspace(sdk, workspace_id).await.pop().unwrap();
         self.workspace = workspace;
       },
+      FolderScript::UpdateWorkspaceSettings { settings, error } => {
+        let builder = FolderEventBuilder::new(sdk.clone())
+          .event(UpdateWorkspaceSettings)
+          .payload(settings)
+          .async_send()
+          .await;
+        match error {
+          None => {
+            builder.parse::<WorkspaceSettingsPB>();
+          },
+          Some(error) => assert_eq!(builder.error().code, error.value()),
+        }
+      },
+      FolderScript::AssertWorkspaceSettings(expected) => {
+        let settings = FolderEventBuilder::new(sdk.clone())
+          .event(ReadWorkspaceSettings)
+          .payload(WorkspaceIdPB::new(None))
+          .async_send()
+          .await
+          .parse::<WorkspaceSettingsPB>();
+        assert_eq!(settings, expected);
+      },
       FolderScript::CreateApp { name, desc } => {
         let app = create_app(sdk, &self.workspace.id, &name, &desc).await;
         self.app = app;
@@ -191,6 +325,214 @@ impl FolderTest {
         let view = create_view(sdk, &self.app.id, &name, &desc, layout).await;
         self.view = view;
       },
+      FolderScript::ImportData { name, file_path } => {
+        let view = import_data(
+          sdk,
+          &self.app.id,
+          &name,
+          &file_path,
+          ImportTypePB::CSV,
+          None,
+        )
+        .await;
+        self.view = view;
+      },
+      FolderScript::ExportData {
+        file_path,
+        passphrase,
+      } => {
+        let request = ExportDataPayloadPB {
+          id: self.view.id.clone(),
+          scope: ExportScopePB::View,
+          file_path,
+          passphrase,
+        };
+        FolderEventBuilder::new(sdk.clone())
+          .event(ExportData)
+          .payload(request)
+          .async_send()
+          .await;
+      },
+      FolderScript::ImportArchive {
+        name,
+        file_path,
+        passphrase,
+      } => {
+        let view = import_data(
+          sdk,
+          &self.app.id,
+          &name,
+          &file_path,
+          ImportTypePB::Archive,
+          passphrase,
+        )
+        .await;
+        self.view = view;
+      },
+      FolderScript::AssertImportArchiveError {
+        file_path,
+        passphrase,
+        error,
+      } => {
+        let request = ImportPayloadPB {
+          belong_to_id: self.app.id.clone(),
+          name: "Imported".to_owned(),
+          file_path,
+          import_type: ImportTypePB::Archive,
+          passphrase,
+        };
+        let flowy_error = FolderEventBuilder::new(sdk.clone())
+          .event(ImportData)
+          .payload(request)
+          .async_send()
+          .await
+          .error();
+        assert_eq!(flowy_error.code, error.value());
+      },
+      FolderScript::AssertImportDataError { file_path, error } => {
+        let request = ImportPayloadPB {
+          belong_to_id: self.app.id.clone(),
+          name: "Imported".to_owned(),
+          file_path,
+          import_type: ImportTypePB::CSV,
+          passphrase: None,
+        };
+        let flowy_error = FolderEventBuilder::new(sdk.clone())
+          .event(ImportData)
+          .payload(request)
+          .async_send()
+          .await
+          .error();
+        assert_eq!(flowy_error.code, error.value());
+      },
+      FolderScript::GenerateSyntheticWorkspace {
+        number_of_documents,
+        number_of_databases,
+        number_of_rows,
+      } => {
+        let request = GenerateSyntheticWorkspacePayloadPB {
+          name: "Synthetic workspace".to_owned(),
+          number_of_documents,
+          number_of_databases,
+          number_of_rows,
+        };
+        let workspace = FolderEventBuilder::new(sdk.clone())
+          .event(GenerateSyntheticWorkspace)
+          .payload(request)
+          .async_send()
+          .await
+          .parse::<WorkspacePB>();
+        self.app = workspace.apps.items[0].clone();
+        self.workspace = workspace;
+      },
+      FolderScript::AssertGenerateSyntheticWorkspaceError {
+        number_of_rows,
+        error,
+      } => {
+        let request = GenerateSyntheticWorkspacePayloadPB {
+          name: "Synthetic workspace".to_owned(),
+          number_of_documents: 0,
+          number_of_databases: 1,
+          number_of_rows,
+        };
+        let flowy_error = FolderEventBuilder::new(sdk.clone())
+          .event(GenerateSyntheticWorkspace)
+          .payload(request)
+          .async_send()
+          .await
+          .error();
+        assert_eq!(flowy_error.code, error.value());
+      },
+      FolderScript::QuickCreateView {
+        name,
+        layout,
+        parent_id,
+      } => {
+        let request = QuickCreateViewPayloadPB {
+          name,
+          layout,
+          parent_id,
+        };
+        self.view = FolderEventBuilder::new(sdk.clone())
+          .event(QuickCreateView)
+          .payload(request)
+          .async_send()
+          .await
+          .parse::<ViewPB>();
+      },
+      FolderScript::AssertCreateViewError { error, recovery } => {
+        let request = CreateViewPayloadPB {
+          belong_to_id: self.app.id.clone(),
+          name: "Created".to_owned(),
+          desc: "".to_owned(),
+          thumbnail: None,
+          layout: ViewLayoutTypePB::Document,
+          initial_data: vec![],
+          ext: Default::default(),
+        };
+        let flowy_error = FolderEventBuilder::new(sdk.clone())
+          .event(CreateView)
+          .payload(request)
+          .async_send()
+          .await
+          .error();
+        assert_eq!(flowy_error.code, error.value());
+        // The error can be traced back to the logs of the event
+        assert!(!flowy_error.operation_id.is_empty());
+        assert_eq!(flowy_error.recovery, recovery);
+      },
+      FolderScript::AssertQuotaUsage(quota) => {
+        let usage = FolderEventBuilder::new(sdk.clone())
+          .event(GetQuotaUsage)
+          .async_send()
+          .await
+          .parse::<QuotaUsagePB>();
+        assert_eq!(
+          usage.quota.max_attachment_size,
+          quota.max_attachment_size as i64
+        );
+        assert_eq!(
+          usage.quota.max_rows_per_database,
+          quota.max_rows_per_database as i64
+        );
+        assert_eq!(
+          usage.quota.max_workspace_size,
+          quota.max_workspace_size as i64
+        );
+        assert!(usage.workspace_size > 0);
+      },
+      FolderScript::AssertWorkspaceHealthy => {
+        let health = FolderEventBuilder::new(sdk.clone())
+          .event(GetWorkspaceHealth)
+          .async_send()
+          .await
+          .parse::<WorkspaceHealthPB>();
+        assert_eq!(health.failed_tasks, 0);
+        assert!(health.storage.workspace_size > 0);
+        assert!(health.search_index.is_ready);
+        assert!(health.search_index.number_of_views > 0);
+        assert_eq!(health.search_index.pending_views, 0);
+        assert!(health.search_index.updated_at > 0);
+      },
+      FolderScript::AssertMemoryReport => {
+        let report = read_memory_report(sdk).await;
+        assert!(report.search_index_bytes > 0);
+        assert_eq!(
+          report.total_bytes,
+          report.cell_data_cache_bytes
+            + report.open_document_bytes
+            + report.filter_result_bytes
+            + report.search_index_bytes
+        );
+      },
+      FolderScript::TrimCaches => {
+        FolderEventBuilder::new(sdk.clone())
+          .event(TrimCaches)
+          .async_send()
+          .await;
+        let report = read_memory_report(sdk).await;
+        assert_eq!(report.cell_data_cache_bytes, 0);
+      },
       FolderScript::AssertView(view) => {
         assert_eq!(self.view, view, "View not equal");
       },
@@ -207,6 +549,212 @@ impl FolderTest {
       FolderScript::DeleteViews(view_ids) => {
         delete_view(sdk, view_ids).await;
       },
+      FolderScript::UpdateViewPermission(permission) => {
+        update_view_permission(sdk, &self.view.id, permission).await;
+      },
+      FolderScript::AssertUpdateViewError { name, error } => {
+        let request = UpdateViewPayloadPB {
+          view_id: self.view.id.clone(),
+          name,
+          desc: None,
+          thumbnail: None,
+        };
+        let flowy_error = FolderEventBuilder::new(sdk.clone())
+          .event(UpdateView)
+          .payload(request)
+          .async_send()
+          .await
+          .error();
+        assert_eq!(flowy_error.code, error.value());
+      },
+      FolderScript::UpdateViewNamePolicy(policy) => {
+        let request = ViewNameSettingPB {
+          workspace_id: self.workspace.id.clone(),
+          policy,
+        };
+        FolderEventBuilder::new(sdk.clone())
+          .event(UpdateViewNameSetting)
+          .payload(request)
+          .async_send()
+          .await;
+      },
+      FolderScript::AssertDuplicateViewNames(expected_names) => {
+        let names = FolderEventBuilder::new(sdk.clone())
+          .event(ReadDuplicateViewNames)
+          .payload(WorkspaceIdPB::new(Some(self.workspace.id.clone())))
+          .async_send()
+          .await
+          .parse::<RepeatedDuplicateViewNamePB>()
+          .items
+          .into_iter()
+          .map(|duplicate| duplicate.name)
+          .collect::<Vec<String>>();
+        assert_eq!(names, expected_names);
+      },
+      FolderScript::RenameDuplicateViews => {
+        FolderEventBuilder::new(sdk.clone())
+          .event(RenameDuplicateViews)
+          .payload(WorkspaceIdPB::new(Some(self.workspace.id.clone())))
+          .async_send()
+          .await;
+      },
+      FolderScript::RebuildSearchIndex => {
+        FolderEventBuilder::new(sdk.clone())
+          .event(RebuildSearchIndex)
+          .async_send()
+          .await;
+      },
+      FolderScript::WaitForSearchIndexing => {
+        // The views are indexed by the background tasks, so we wait here.
+        sleep(Duration::from_millis(1000)).await;
+      },
+      FolderScript::AssertSearchResults {
+        query,
+        expected_names,
+      } => {
+        let names = search(sdk, &query)
+          .await
+          .items
+          .into_iter()
+          .map(|result| result.name)
+          .collect::<Vec<String>>();
+        assert_eq!(names, expected_names);
+      },
+      FolderScript::SetLatestView => {
+        FolderEventBuilder::new(sdk.clone())
+          .event(SetLatestView)
+          .payload(ViewIdPB::from(self.view.id.as_str()))
+          .async_send()
+          .await;
+      },
+      FolderScript::EditViewContent => {
+        // The modified time is in seconds, wait for it to be different from the creation time.
+        sleep(Duration::from_millis(1100)).await;
+        let user_id = sdk.user_session.user_id().unwrap();
+        sdk
+          .folder_manager
+          .did_update_view_content(&self.view.id, Some(&user_id))
+          .await;
+      },
+      FolderScript::AssertViewLastEditedByCurrentUser => {
+        let view = read_view(sdk, &self.view.id).await;
+        assert_eq!(view.last_edited_by, sdk.user_session.user_id().unwrap());
+        assert!(view.modified_time > view.create_time);
+      },
+      FolderScript::AssertChildViews {
+        sort,
+        expected_names,
+      } => {
+        let request = ReadChildViewsPayloadPB {
+          belong_to_id: self.app.id.clone(),
+          sort,
+        };
+        let names = FolderEventBuilder::new(sdk.clone())
+          .event(ReadChildViews)
+          .payload(request)
+          .async_send()
+          .await
+          .parse::<RepeatedViewPB>()
+          .items
+          .into_iter()
+          .map(|view| view.name)
+          .collect::<Vec<String>>();
+        assert_eq!(names, expected_names);
+      },
+      FolderScript::UpdateViewSlug(slug) => {
+        let request = UpdateViewSlugPayloadPB {
+          view_id: self.view.id.clone(),
+          slug: slug.clone(),
+        };
+        let view = FolderEventBuilder::new(sdk.clone())
+          .event(UpdateViewSlug)
+          .payload(request)
+          .async_send()
+          .await
+          .parse::<ViewPB>();
+        assert_eq!(view.slug, slug.unwrap_or_default());
+      },
+      FolderScript::AssertUpdateViewSlugError { slug, error } => {
+        let request = UpdateViewSlugPayloadPB {
+          view_id: self.view.id.clone(),
+          slug: Some(slug),
+        };
+        let flowy_error = FolderEventBuilder::new(sdk.clone())
+          .event(UpdateViewSlug)
+          .payload(request)
+          .async_send()
+          .await
+          .error();
+        assert_eq!(flowy_error.code, error.value());
+      },
+      FolderScript::AssertResolveViewSlug {
+        slug,
+        expected_name,
+      } => {
+        let request = ResolveViewSlugPayloadPB {
+          workspace_id: None,
+          slug,
+        };
+        let response = FolderEventBuilder::new(sdk.clone())
+          .event(ResolveViewSlug)
+          .payload(request)
+          .async_send()
+          .await;
+        match expected_name {
+          None => assert_eq!(response.error().code, ErrorCode::RecordNotFound.value()),
+          Some(name) => assert_eq!(response.parse::<ViewPB>().name, name),
+        }
+      },
+      FolderScript::UpdateViewState {
+        values,
+        removed_keys,
+      } => {
+        let request = UpdateViewStatePayloadPB {
+          view_id: self.view.id.clone(),
+          values,
+          removed_keys,
+        };
+        FolderEventBuilder::new(sdk.clone())
+          .event(UpdateViewState)
+          .payload(request)
+          .async_send()
+          .await;
+      },
+      FolderScript::AssertViewState(expected_values) => {
+        let request = ViewIdPB {
+          value: self.view.id.clone(),
+        };
+        let view_state = FolderEventBuilder::new(sdk.clone())
+          .event(ReadViewState)
+          .payload(request)
+          .async_send()
+          .await
+          .parse::<ViewStatePB>();
+        assert_eq!(view_state.values, expected_values);
+      },
+      FolderScript::UpdateViewAliases(aliases) => {
+        let request = ViewAliasesPB {
+          view_id: self.view.id.clone(),
+          aliases,
+        };
+        FolderEventBuilder::new(sdk.clone())
+          .event(UpdateViewAliases)
+          .payload(request)
+          .async_send()
+          .await;
+      },
+      FolderScript::AssertQuickSwitchResults {
+        query,
+        expected_names,
+      } => {
+        let names = quick_switch(sdk, &query)
+          .await
+          .items
+          .into_iter()
+          .map(|item| item.name)
+          .collect::<Vec<String>>();
+        assert_eq!(names, expected_names);
+      },
       FolderScript::RestoreAppFromTrash => {
         restore_app_from_trash(sdk, &self.app.id).await;
       },
@@ -390,6 +938,29 @@ pub async fn create_view(
     .parse::<ViewPB>()
 }
 
+pub async fn import_data(
+  sdk: &FlowySDKTest,
+  app_id: &str,
+  name: &str,
+  file_path: &str,
+  import_type: ImportTypePB,
+  passphrase: Option<String>,
+) -> ViewPB {
+  let request = ImportPayloadPB {
+    belong_to_id: app_id.to_string(),
+    name: name.to_string(),
+    file_path: file_path.to_string(),
+    import_type,
+    passphrase,
+  };
+  FolderEventBuilder::new(sdk.clone())
+    .event(ImportData)
+    .payload(request)
+    .async_send()
+    .await
+    .parse::<ViewPB>()
+}
+
 pub async fn read_view(sdk: &FlowySDKTest, view_id: &str) -> ViewPB {
   let view_id: ViewIdPB = view_id.into();
   FolderEventBuilder::new(sdk.clone())
@@ -400,6 +971,14 @@ pub async fn read_view(sdk: &FlowySDKTest, view_id: &str) -> ViewPB {
     .parse::<ViewPB>()
 }
 
+pub async fn read_memory_report(sdk: &FlowySDKTest) -> MemoryReportPB {
+  FolderEventBuilder::new(sdk.clone())
+    .event(GetMemoryReport)
+    .async_send()
+    .await
+    .parse::<MemoryReportPB>()
+}
+
 pub async fn update_view(
   sdk: &FlowySDKTest,
   view_id: &str,
@@ -419,6 +998,22 @@ pub async fn update_view(
     .await;
 }
 
+pub async fn update_view_permission(
+  sdk: &FlowySDKTest,
+  view_id: &str,
+  permission: ViewPermissionTypePB,
+) {
+  let request = UpdateViewPermissionPayloadPB {
+    view_id: view_id.to_string(),
+    permission,
+  };
+  FolderEventBuilder::new(sdk.clone())
+    .event(UpdateViewPermission)
+    .payload(request)
+    .async_send()
+    .await;
+}
+
 pub async fn delete_view(sdk: &FlowySDKTest, view_ids: Vec<String>) {
   let request = RepeatedViewIdPB { items: view_ids };
   FolderEventBuilder::new(sdk.clone())
@@ -428,6 +1023,32 @@ pub async fn delete_view(sdk: &FlowySDKTest, view_ids: Vec<String>) {
     .await;
 }
 
+pub async fn search(sdk: &FlowySDKTest, query: &str) -> RepeatedSearchResultPB {
+  let request = SearchPayloadPB {
+    query: query.to_owned(),
+    limit: 0,
+  };
+  FolderEventBuilder::new(sdk.clone())
+    .event(Search)
+    .payload(request)
+    .async_send()
+    .await
+    .parse::<RepeatedSearchResultPB>()
+}
+
+pub async fn quick_switch(sdk: &FlowySDKTest, query: &str) -> RepeatedQuickSwitchItemPB {
+  let request = QuickSwitchPayloadPB {
+    query: query.to_owned(),
+    limit: 0,
+  };
+  FolderEventBuilder::new(sdk.clone())
+    .event(QuickSwitch)
+    .payload(request)
+    .async_send()
+    .await
+    .parse::<RepeatedQuickSwitchItemPB>()
+}
+
 pub async fn read_trash(sdk: &FlowySDKTest) -> RepeatedTrashPB {
   FolderEventBuilder::new(sdk.clone())
     .event(ReadTrash)
@@ -0,0 +1,50 @@
+use flowy_derive::ProtoBuf;
+use lib_dispatch::prelude::{EventAuditRecord, StatusCode};
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct EventAuditRecordPB {
+  #[pb(index = 1)]
+  pub event: String,
+
+  #[pb(index = 2)]
+  pub payload_size: i64,
+
+  #[pb(index = 3)]
+  pub duration_in_micros: i64,
+
+  #[pb(index = 4)]
+  pub is_ok: bool,
+
+  #[pb(index = 5)]
+  pub timestamp: i64,
+
+  #[pb(index = 6)]
+  pub operation_id: String,
+}
+
+impl std::convert::From<EventAuditRecord> for EventAuditRecordPB {
+  fn from(record: EventAuditRecord) -> Self {
+    Self {
+      event: record.event,
+      payload_size: record.payload_size as i64,
+      duration_in_micros: record.duration.as_micros() as i64,
+      is_ok: record.status_code == StatusCode::Ok,
+      timestamp: record.timestamp,
+      operation_id: record.operation_id,
+    }
+  }
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct RepeatedEventAuditRecordPB {
+  #[pb(index = 1)]
+  pub items: Vec<EventAuditRecordPB>,
+}
+
+impl std::convert::From<Vec<EventAuditRecord>> for RepeatedEventAuditRecordPB {
+  fn from(records: Vec<EventAuditRecord>) -> Self {
+    Self {
+      items: records.into_iter().map(|record| record.into()).collect(),
+    }
+  }
+}
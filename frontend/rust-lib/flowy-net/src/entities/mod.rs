@@ -1,2 +1,8 @@
+mod event_audit;
 mod network_state;
+mod readiness;
+mod sync_statistics;
+pub use event_audit::*;
 pub use network_state::*;
+pub use readiness::*;
+pub use sync_statistics::*;
@@ -0,0 +1,34 @@
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use strum_macros::EnumIter;
+
+/// The subsystems of the core that are initialized in the background after the core is
+/// created. The events of a subsystem wait until it's ready.
+#[derive(ProtoBuf_Enum, Debug, Clone, Copy, Hash, Eq, PartialEq, EnumIter)]
+pub enum CoreSubsystemPB {
+  User = 0,
+  Folder = 1,
+  Document = 2,
+  Database = 3,
+  WebSocket = 4,
+}
+
+impl std::default::Default for CoreSubsystemPB {
+  fn default() -> Self {
+    CoreSubsystemPB::User
+  }
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone, PartialEq, Eq)]
+pub struct SubsystemReadinessPB {
+  #[pb(index = 1)]
+  pub subsystem: CoreSubsystemPB,
+
+  #[pb(index = 2)]
+  pub is_ready: bool,
+}
+
+#[derive(ProtoBuf, Debug, Default, Clone)]
+pub struct RepeatedSubsystemReadinessPB {
+  #[pb(index = 1)]
+  pub items: Vec<SubsystemReadinessPB>,
+}
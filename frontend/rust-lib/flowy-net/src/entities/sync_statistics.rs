@@ -0,0 +1,34 @@
+use flowy_derive::ProtoBuf;
+
+/// The statistics of the messages that were synced with the server since the current session
+/// was started. A session lasts until another user connects the web socket, see
+/// [SyncStatistics](crate::sync_statistics::SyncStatistics).
+#[derive(ProtoBuf, Debug, Default, Clone, PartialEq, Eq)]
+pub struct SyncStatisticsPB {
+  /// The timestamp in seconds of when the session was started. Zero if the web socket was never
+  /// connected.
+  #[pb(index = 1)]
+  pub started_at: i64,
+
+  #[pb(index = 2)]
+  pub messages_sent: i64,
+
+  #[pb(index = 3)]
+  pub messages_received: i64,
+
+  #[pb(index = 4)]
+  pub bytes_sent: i64,
+
+  #[pb(index = 5)]
+  pub bytes_received: i64,
+
+  /// The number of the failed connect attempts that were retried plus the number of the
+  /// messages that were sent again because no reply was received
+  #[pb(index = 6)]
+  pub retries: i64,
+
+  /// The average time between sending a message and receiving the reply. Zero if no reply was
+  /// received yet.
+  #[pb(index = 7)]
+  pub average_latency_in_millis: i64,
+}
@@ -1,20 +1,52 @@
 use crate::handlers::*;
+use crate::readiness::CoreReadiness;
+use crate::sync_statistics::SyncStatistics;
 use flowy_client_ws::FlowyWebSocketConnect;
 use flowy_derive::{Flowy_Event, ProtoBuf_Enum};
 use lib_dispatch::prelude::*;
 use std::sync::Arc;
-use strum_macros::Display;
+use strum_macros::{Display, EnumIter};
 
-pub fn init(ws_conn: Arc<FlowyWebSocketConnect>) -> AFPlugin {
+pub fn init(
+  ws_conn: Arc<FlowyWebSocketConnect>,
+  audit_log: EventAuditLog,
+  sync_statistics: SyncStatistics,
+  readiness: CoreReadiness,
+) -> AFPlugin {
   AFPlugin::new()
     .name("Flowy-Network")
     .state(ws_conn)
+    .state(audit_log)
+    .state(sync_statistics)
+    .state(readiness)
     .event(NetworkEvent::UpdateNetworkType, update_network_ty)
+    .event(NetworkEvent::DumpEventAuditLog, dump_event_audit_log)
+    .event(NetworkEvent::GetSyncStatistics, get_sync_statistics)
+    .event(NetworkEvent::GetCoreReadiness, get_core_readiness)
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
+#[derive(
+  Clone, Copy, PartialEq, Eq, Debug, Display, Hash, EnumIter, ProtoBuf_Enum, Flowy_Event,
+)]
 #[event_err = "FlowyError"]
 pub enum NetworkEvent {
   #[event(input = "NetworkStatePB")]
   UpdateNetworkType = 0,
+
+  /// Return the last N dispatched events. The list is empty unless the event audit log is
+  /// enabled in the `AppFlowyCoreConfig`.
+  #[event(output = "RepeatedEventAuditRecordPB")]
+  DumpEventAuditLog = 1,
+
+  /// Return the number of the messages and the bytes that were synced with the server since
+  /// the current session was started. The statistics are also sent periodically by the
+  /// `DidUpdateSyncStatistics` notification while they keep changing.
+  #[event(output = "SyncStatisticsPB")]
+  GetSyncStatistics = 2,
+
+  /// Return whether each subsystem of the core finished its initialization. The core is
+  /// initialized in the background, and the `DidUpdateCoreReadiness` notification is sent when
+  /// a subsystem becomes ready.
+  #[event(output = "RepeatedSubsystemReadinessPB")]
+  GetCoreReadiness = 3,
 }
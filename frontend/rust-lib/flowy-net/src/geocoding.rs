@@ -0,0 +1,83 @@
+use crate::request::HttpRequestBuilder;
+use flowy_error::FlowyError;
+use lib_infra::future::FutureResult;
+use reqwest::Url;
+use serde::Deserialize;
+
+const NOMINATIM_URL: &str = "https://nominatim.openstreetmap.org";
+
+/// The place that is found by the [GeocodingProvider]. The coordinates are in degrees.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GeocodedLocation {
+  pub latitude: f64,
+  pub longitude: f64,
+  /// The full address of the place, e.g. "Tour Eiffel, 5, Avenue Anatole France, Paris"
+  pub address: String,
+}
+
+/// Finds the coordinates of the addresses. The application doesn't geocode the addresses unless
+/// a provider is set, because the addresses are sent to the provider.
+pub trait GeocodingProvider: Send + Sync {
+  /// Returns the best match of the address, or None if the address is not found.
+  fn geocode(&self, address: &str) -> FutureResult<Option<GeocodedLocation>, FlowyError>;
+}
+
+/// Geocodes the addresses with a service that implements the search API of
+/// [Nominatim](https://nominatim.org/release-docs/latest/api/Search/), e.g. the one of the
+/// OpenStreetMap.
+pub struct NominatimGeocodingProvider {
+  base_url: String,
+}
+
+impl NominatimGeocodingProvider {
+  pub fn new(base_url: &str) -> Self {
+    Self {
+      base_url: base_url.trim_end_matches('/').to_owned(),
+    }
+  }
+}
+
+impl std::default::Default for NominatimGeocodingProvider {
+  fn default() -> Self {
+    Self::new(NOMINATIM_URL)
+  }
+}
+
+#[derive(Deserialize)]
+struct NominatimPlace {
+  lat: String,
+  lon: String,
+  display_name: String,
+}
+
+impl GeocodingProvider for NominatimGeocodingProvider {
+  fn geocode(&self, address: &str) -> FutureResult<Option<GeocodedLocation>, FlowyError> {
+    let url = Url::parse_with_params(
+      &format!("{}/search", self.base_url),
+      &[("q", address), ("format", "json"), ("limit", "1")],
+    );
+    FutureResult::new(async move {
+      let url = url.map_err(|e| FlowyError::invalid_data().context(e))?;
+      let places = HttpRequestBuilder::new()
+        .get(url.as_str())
+        // The usage policy of the OpenStreetMap requires identifying the application
+        .header("user-agent", "AppFlowy")
+        .json_response::<Vec<NominatimPlace>>()
+        .await?;
+      let place = match places.into_iter().next() {
+        None => return Ok(None),
+        Some(place) => place,
+      };
+      let latitude = place.lat.parse::<f64>();
+      let longitude = place.lon.parse::<f64>();
+      match (latitude, longitude) {
+        (Ok(latitude), Ok(longitude)) => Ok(Some(GeocodedLocation {
+          latitude,
+          longitude,
+          address: place.display_name,
+        })),
+        _ => Err(FlowyError::internal().context("The geocoded coordinates are invalid")),
+      }
+    })
+  }
+}
@@ -1,7 +1,13 @@
-use crate::entities::NetworkStatePB;
+use crate::entities::{
+  NetworkStatePB, RepeatedEventAuditRecordPB, RepeatedSubsystemReadinessPB, SyncStatisticsPB,
+};
+use crate::readiness::CoreReadiness;
+use crate::sync_statistics::SyncStatistics;
 use flowy_client_ws::{FlowyWebSocketConnect, NetworkType};
 use flowy_error::FlowyError;
-use lib_dispatch::prelude::{AFPluginData, AFPluginState};
+use lib_dispatch::prelude::{
+  data_result_ok, AFPluginData, AFPluginState, DataResult, EventAuditLog,
+};
 use std::sync::Arc;
 
 #[tracing::instrument(level = "debug", skip(data, ws_manager))]
@@ -13,3 +19,24 @@ pub async fn update_network_ty(
   ws_manager.update_network_type(network_type);
   Ok(())
 }
+
+#[tracing::instrument(level = "debug", skip(audit_log))]
+pub async fn dump_event_audit_log(
+  audit_log: AFPluginState<EventAuditLog>,
+) -> DataResult<RepeatedEventAuditRecordPB, FlowyError> {
+  data_result_ok(audit_log.records().into())
+}
+
+#[tracing::instrument(level = "debug", skip(sync_statistics))]
+pub async fn get_sync_statistics(
+  sync_statistics: AFPluginState<SyncStatistics>,
+) -> DataResult<SyncStatisticsPB, FlowyError> {
+  data_result_ok(sync_statistics.snapshot())
+}
+
+#[tracing::instrument(level = "debug", skip(readiness))]
+pub async fn get_core_readiness(
+  readiness: AFPluginState<CoreReadiness>,
+) -> DataResult<RepeatedSubsystemReadinessPB, FlowyError> {
+  data_result_ok(readiness.snapshot())
+}
@@ -3,6 +3,7 @@ use crate::response::HttpResponse;
 use flowy_client_network_config::ClientServerConfiguration;
 use flowy_error::FlowyError;
 use flowy_folder::entities::{
+  quota::WorkspaceQuota,
   trash::RepeatedTrashIdPB,
   view::{CreateViewParams, RepeatedViewIdPB, UpdateViewParams, ViewIdPB},
   workspace::{CreateWorkspaceParams, UpdateWorkspaceParams, WorkspaceIdPB},
@@ -190,6 +191,11 @@ impl FolderCouldServiceV1 for FolderHttpCloudService {
       Ok(repeated_trash)
     })
   }
+
+  fn read_workspace_quota(&self, _token: &str) -> FutureResult<Option<WorkspaceQuota>, FlowyError> {
+    // The server doesn't provide the quota of the workspace yet, so the local quota is used.
+    FutureResult::new(async { Ok(None) })
+  }
 }
 
 #[allow(dead_code)]
@@ -1,10 +1,14 @@
 pub mod entities;
 pub mod event_map;
+pub mod geocoding;
 mod handlers;
 pub mod http_server;
 pub mod local_server;
+pub mod notification;
 pub mod protobuf;
+pub mod readiness;
 mod request;
 mod response;
+pub mod sync_statistics;
 
 pub use flowy_client_network_config::{get_client_server_configuration, ClientServerConfiguration};
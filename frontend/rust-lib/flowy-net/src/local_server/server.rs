@@ -9,6 +9,7 @@ use flowy_document::DocumentCloudService;
 use flowy_error::{internal_error, FlowyError};
 use flowy_folder::entities::{
   app::{AppIdPB, CreateAppParams, UpdateAppParams},
+  quota::WorkspaceQuota,
   trash::RepeatedTrashIdPB,
   view::{CreateViewParams, RepeatedViewIdPB, UpdateViewParams, ViewIdPB},
   workspace::{CreateWorkspaceParams, UpdateWorkspaceParams, WorkspaceIdPB},
@@ -287,6 +288,7 @@ impl FolderCouldServiceV1 for LocalServer {
       apps: vec![],
       modified_time: time,
       create_time: time,
+      settings: Default::default(),
     };
 
     FutureResult::new(async { Ok(workspace) })
@@ -393,6 +395,10 @@ impl FolderCouldServiceV1 for LocalServer {
   fn read_trash(&self, _token: &str) -> FutureResult<Vec<TrashRevision>, FlowyError> {
     FutureResult::new(async { Ok(vec![]) })
   }
+
+  fn read_workspace_quota(&self, _token: &str) -> FutureResult<Option<WorkspaceQuota>, FlowyError> {
+    FutureResult::new(async { Ok(None) })
+  }
 }
 
 impl UserCloudService for LocalServer {
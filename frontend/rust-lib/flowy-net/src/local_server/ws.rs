@@ -67,10 +67,24 @@ impl FlowyRawWebSocket for LocalWebSocket {
     Box::pin(async move { subscribe })
   }
 
+  fn connect_state(&self) -> BoxFuture<WSConnectState> {
+    // The local server is always reachable once the user started connecting
+    let state = match self.user_id.read().is_some() {
+      true => WSConnectState::Connected,
+      false => WSConnectState::Init,
+    };
+    Box::pin(async move { state })
+  }
+
   fn reconnect(&self, _count: usize) -> FutureResult<(), WSErrorCode> {
     FutureResult::new(async { Ok(()) })
   }
 
+  fn connect_attempts(&self) -> usize {
+    // Nothing is connected, the messages go to the local server directly
+    0
+  }
+
   fn add_msg_receiver(&self, receiver: Arc<dyn WSMessageReceiver>) -> Result<(), WSErrorCode> {
     tracing::trace!("Local web socket add ws receiver: {:?}", receiver.source());
     self.receivers.insert(receiver.source(), receiver);
@@ -0,0 +1,30 @@
+use flowy_derive::ProtoBuf_Enum;
+use flowy_notification::NotificationBuilder;
+pub const OBSERVABLE_CATEGORY: &str = "Network";
+
+#[derive(ProtoBuf_Enum, Debug)]
+pub enum NetworkNotification {
+  Unknown = 0,
+  /// Trigger periodically while the sync statistics keep changing, the payload is
+  /// `SyncStatisticsPB`
+  DidUpdateSyncStatistics = 1,
+  /// Trigger when a subsystem of the core becomes ready, the payload is `SubsystemReadinessPB`
+  DidUpdateCoreReadiness = 2,
+}
+
+impl std::default::Default for NetworkNotification {
+  fn default() -> Self {
+    NetworkNotification::Unknown
+  }
+}
+
+impl std::convert::From<NetworkNotification> for i32 {
+  fn from(notification: NetworkNotification) -> Self {
+    notification as i32
+  }
+}
+
+#[tracing::instrument(level = "trace")]
+pub(crate) fn send_notification(id: &str, ty: NetworkNotification) -> NotificationBuilder {
+  NotificationBuilder::new(id, ty, OBSERVABLE_CATEGORY)
+}
@@ -0,0 +1,134 @@
+use crate::entities::{CoreSubsystemPB, RepeatedSubsystemReadinessPB, SubsystemReadinessPB};
+use crate::notification::{send_notification, NetworkNotification};
+use std::collections::HashSet;
+use std::sync::Arc;
+use strum::IntoEnumIterator;
+use tokio::sync::watch;
+
+const CORE_READINESS_ID: &str = "core_readiness";
+
+/// [CoreReadiness] keeps track of the subsystems of the core that finished their initialization.
+///
+/// The core only builds the managers when it's created. The user session and the web socket are
+/// initialized in the background afterwards, the managers are initialized by the first event of
+/// their subsystem. The events of a subsystem wait for it with [CoreReadiness::wait_until_ready]
+/// instead of blocking the creation of the core.
+#[derive(Clone)]
+pub struct CoreReadiness(Arc<watch::Sender<HashSet<CoreSubsystemPB>>>);
+
+impl std::default::Default for CoreReadiness {
+  fn default() -> Self {
+    let (tx, _) = watch::channel(HashSet::new());
+    Self(Arc::new(tx))
+  }
+}
+
+impl CoreReadiness {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn is_ready(&self, subsystem: CoreSubsystemPB) -> bool {
+    self.0.borrow().contains(&subsystem)
+  }
+
+  /// Marks the subsystem as ready and sends the [NetworkNotification::DidUpdateCoreReadiness]
+  /// notification. It does nothing if the subsystem is ready already.
+  pub fn mark_ready(&self, subsystem: CoreSubsystemPB) {
+    let is_modified = self
+      .0
+      .send_if_modified(|ready_subsystems| ready_subsystems.insert(subsystem));
+    if is_modified {
+      tracing::debug!("{:?} is ready", subsystem);
+      notify_readiness(subsystem, true);
+    }
+  }
+
+  /// Marks the subsystem as not ready, e.g. the user signed out and the managers have to be
+  /// initialized again for the next user. It does nothing if the subsystem is not ready.
+  pub fn reset(&self, subsystem: CoreSubsystemPB) {
+    let is_modified = self
+      .0
+      .send_if_modified(|ready_subsystems| ready_subsystems.remove(&subsystem));
+    if is_modified {
+      tracing::debug!("{:?} is not ready", subsystem);
+      notify_readiness(subsystem, false);
+    }
+  }
+
+  pub async fn wait_until_ready(&self, subsystem: CoreSubsystemPB) {
+    let mut rx = self.0.subscribe();
+    loop {
+      if rx.borrow().contains(&subsystem) {
+        return;
+      }
+      if rx.changed().await.is_err() {
+        return;
+      }
+    }
+  }
+
+  pub fn snapshot(&self) -> RepeatedSubsystemReadinessPB {
+    let ready_subsystems = self.0.borrow();
+    RepeatedSubsystemReadinessPB {
+      items: CoreSubsystemPB::iter()
+        .map(|subsystem| SubsystemReadinessPB {
+          subsystem,
+          is_ready: ready_subsystems.contains(&subsystem),
+        })
+        .collect(),
+    }
+  }
+}
+
+fn notify_readiness(subsystem: CoreSubsystemPB, is_ready: bool) {
+  send_notification(
+    CORE_READINESS_ID,
+    NetworkNotification::DidUpdateCoreReadiness,
+  )
+  .payload(SubsystemReadinessPB {
+    subsystem,
+    is_ready,
+  })
+  .send();
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use futures_util::FutureExt;
+
+  #[test]
+  fn core_readiness_mark_ready_test() {
+    let readiness = CoreReadiness::new();
+    assert!(readiness
+      .wait_until_ready(CoreSubsystemPB::Folder)
+      .now_or_never()
+      .is_none());
+
+    readiness.mark_ready(CoreSubsystemPB::Folder);
+    readiness.mark_ready(CoreSubsystemPB::Folder);
+    assert!(readiness.is_ready(CoreSubsystemPB::Folder));
+    assert!(!readiness.is_ready(CoreSubsystemPB::Database));
+    assert!(readiness
+      .wait_until_ready(CoreSubsystemPB::Folder)
+      .now_or_never()
+      .is_some());
+
+    let ready_subsystems = readiness
+      .snapshot()
+      .items
+      .into_iter()
+      .filter(|item| item.is_ready)
+      .map(|item| item.subsystem)
+      .collect::<Vec<_>>();
+    assert_eq!(ready_subsystems, vec![CoreSubsystemPB::Folder]);
+
+    readiness.reset(CoreSubsystemPB::Folder);
+    assert!(!readiness.is_ready(CoreSubsystemPB::Folder));
+    assert!(readiness
+      .wait_until_ready(CoreSubsystemPB::Folder)
+      .now_or_never()
+      .is_none());
+  }
+}
@@ -0,0 +1,329 @@
+use crate::entities::SyncStatisticsPB;
+use crate::notification::{send_notification, NetworkNotification};
+use flowy_client_ws::{FlowyRawWebSocket, FlowyWebSocket, WSErrorCode};
+use futures_util::future::BoxFuture;
+use lib_infra::future::FutureResult;
+use lib_infra::util::timestamp;
+use lib_ws::{WSChannel, WSConnectState, WSMessageReceiver, WebSocketRawMessage};
+use parking_lot::Mutex;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::Receiver;
+
+const SYNC_STATISTICS_ID: &str = "sync_statistics";
+
+/// [SyncStatistics] counts the messages that are sent and received through the web socket during
+/// the current session.
+///
+/// A session starts when a user connects the web socket and lasts until another user connects
+/// it. The web socket is reconnected many times during a session, e.g. when the app is relaunched
+/// or the network comes back, and the statistics keep counting across these reconnects. So the
+/// retries of a session include the failed connect attempts of every reconnect.
+///
+/// The latency is measured on each channel from sending a message to receiving the next message
+/// of the same channel, which is the ack of the revisions most of the time.
+#[derive(Clone, Default)]
+pub struct SyncStatistics(Arc<Mutex<SyncStatisticsInner>>);
+
+#[derive(Default)]
+struct SyncStatisticsInner {
+  /// The user of the current session
+  user_id: Option<String>,
+  started_at: i64,
+  messages_sent: i64,
+  messages_received: i64,
+  bytes_sent: i64,
+  bytes_received: i64,
+  retries: i64,
+  total_latency: Duration,
+  number_of_latencies: u32,
+  /// The time of the first message that was sent on each channel and not answered yet
+  pending_by_channel: HashMap<WSChannel, Instant>,
+  /// The hash of the last message that was sent on each channel and not answered yet
+  last_sent_by_channel: HashMap<WSChannel, u64>,
+  /// Set when the statistics were changed since they were notified last time
+  is_changed: bool,
+}
+
+impl SyncStatistics {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Starts a new session if the `user_id` is not the user of the current session, see
+  /// [SyncStatistics].
+  pub fn start_session(&self, user_id: &str) {
+    let mut inner = self.0.lock();
+    if inner.user_id.as_deref() != Some(user_id) {
+      *inner = SyncStatisticsInner {
+        user_id: Some(user_id.to_owned()),
+        started_at: timestamp(),
+        is_changed: true,
+        ..Default::default()
+      };
+    }
+  }
+
+  pub fn snapshot(&self) -> SyncStatisticsPB {
+    let inner = self.0.lock();
+    let average_latency_in_millis = match inner.number_of_latencies {
+      0 => 0,
+      number => (inner.total_latency / number).as_millis() as i64,
+    };
+    SyncStatisticsPB {
+      started_at: inner.started_at,
+      messages_sent: inner.messages_sent,
+      messages_received: inner.messages_received,
+      bytes_sent: inner.bytes_sent,
+      bytes_received: inner.bytes_received,
+      retries: inner.retries,
+      average_latency_in_millis,
+    }
+  }
+
+  /// Sends the [NetworkNotification::DidUpdateSyncStatistics] notification if the statistics
+  /// were changed since the last time. It's called periodically, so the notifications are not
+  /// sent for every message.
+  pub fn notify_if_changed(&self) {
+    let is_changed = std::mem::take(&mut self.0.lock().is_changed);
+    if is_changed {
+      send_notification(
+        SYNC_STATISTICS_ID,
+        NetworkNotification::DidUpdateSyncStatistics,
+      )
+      .payload(self.snapshot())
+      .send();
+    }
+  }
+
+  fn did_send(&self, msg: &WebSocketRawMessage) {
+    let mut hasher = DefaultHasher::new();
+    msg.data.hash(&mut hasher);
+    let data_hash = hasher.finish();
+
+    let mut inner = self.0.lock();
+    inner.messages_sent += 1;
+    inner.bytes_sent += msg.data.len() as i64;
+    // The revisions are sent again until they are acked, so sending the same message again
+    // before any reply is a retry
+    let last_sent = inner
+      .last_sent_by_channel
+      .insert(msg.channel.clone(), data_hash);
+    if last_sent == Some(data_hash) {
+      inner.retries += 1;
+    }
+    inner
+      .pending_by_channel
+      .entry(msg.channel.clone())
+      .or_insert_with(Instant::now);
+    inner.is_changed = true;
+  }
+
+  fn did_receive(&self, msg: &WebSocketRawMessage) {
+    let mut inner = self.0.lock();
+    inner.messages_received += 1;
+    inner.bytes_received += msg.data.len() as i64;
+    inner.last_sent_by_channel.remove(&msg.channel);
+    if let Some(sent_at) = inner.pending_by_channel.remove(&msg.channel) {
+      inner.total_latency += sent_at.elapsed();
+      inner.number_of_latencies += 1;
+    }
+    inner.is_changed = true;
+  }
+
+  fn did_retry(&self, retries: usize) {
+    if retries == 0 {
+      return;
+    }
+    let mut inner = self.0.lock();
+    inner.retries += retries as i64;
+    inner.is_changed = true;
+  }
+}
+
+/// Wraps the web socket to count the messages that go through it, see [SyncStatistics].
+pub struct MeteredWebSocket {
+  inner: Arc<dyn FlowyRawWebSocket>,
+  statistics: SyncStatistics,
+}
+
+impl MeteredWebSocket {
+  pub fn new(inner: Arc<dyn FlowyRawWebSocket>, statistics: SyncStatistics) -> Self {
+    Self { inner, statistics }
+  }
+}
+
+impl FlowyRawWebSocket for MeteredWebSocket {
+  fn initialize(&self) -> FutureResult<(), WSErrorCode> {
+    self.inner.initialize()
+  }
+
+  fn start_connect(&self, addr: String, user_id: String) -> FutureResult<(), WSErrorCode> {
+    self.statistics.start_session(&user_id);
+    let inner = self.inner.clone();
+    let statistics = self.statistics.clone();
+    FutureResult::new(async move {
+      let attempts = inner.connect_attempts();
+      let result = inner.start_connect(addr, user_id).await;
+      // The first attempt is the connect itself, the others are the retries
+      statistics.did_retry((inner.connect_attempts() - attempts).saturating_sub(1));
+      result
+    })
+  }
+
+  fn stop_connect(&self) -> FutureResult<(), WSErrorCode> {
+    self.inner.stop_connect()
+  }
+
+  fn subscribe_connect_state(&self) -> BoxFuture<Receiver<WSConnectState>> {
+    self.inner.subscribe_connect_state()
+  }
+
+  fn connect_state(&self) -> BoxFuture<WSConnectState> {
+    self.inner.connect_state()
+  }
+
+  fn reconnect(&self, count: usize) -> FutureResult<(), WSErrorCode> {
+    let inner = self.inner.clone();
+    let statistics = self.statistics.clone();
+    FutureResult::new(async move {
+      let attempts = inner.connect_attempts();
+      let result = inner.reconnect(count).await;
+      statistics.did_retry(inner.connect_attempts() - attempts);
+      result
+    })
+  }
+
+  fn connect_attempts(&self) -> usize {
+    self.inner.connect_attempts()
+  }
+
+  fn add_msg_receiver(&self, receiver: Arc<dyn WSMessageReceiver>) -> Result<(), WSErrorCode> {
+    self.inner.add_msg_receiver(Arc::new(MeteredReceiver {
+      inner: receiver,
+      statistics: self.statistics.clone(),
+    }))
+  }
+
+  fn ws_msg_sender(&self) -> FutureResult<Option<Arc<dyn FlowyWebSocket>>, WSErrorCode> {
+    let sender = self.inner.ws_msg_sender();
+    let statistics = self.statistics.clone();
+    FutureResult::new(async move {
+      let sender = sender.await?.map(|sender| {
+        let sender: Arc<dyn FlowyWebSocket> = Arc::new(MeteredSender {
+          inner: sender,
+          statistics,
+        });
+        sender
+      });
+      Ok(sender)
+    })
+  }
+}
+
+struct MeteredSender {
+  inner: Arc<dyn FlowyWebSocket>,
+  statistics: SyncStatistics,
+}
+
+impl FlowyWebSocket for MeteredSender {
+  fn send(&self, msg: WebSocketRawMessage) -> Result<(), WSErrorCode> {
+    self.statistics.did_send(&msg);
+    self.inner.send(msg)
+  }
+}
+
+struct MeteredReceiver {
+  inner: Arc<dyn WSMessageReceiver>,
+  statistics: SyncStatistics,
+}
+
+impl WSMessageReceiver for MeteredReceiver {
+  fn source(&self) -> WSChannel {
+    self.inner.source()
+  }
+
+  fn receive_message(&self, msg: WebSocketRawMessage) {
+    self.statistics.did_receive(&msg);
+    self.inner.receive_message(msg);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn message(channel: WSChannel, len: usize) -> WebSocketRawMessage {
+    WebSocketRawMessage {
+      channel,
+      data: vec![0; len],
+    }
+  }
+
+  #[test]
+  fn sync_statistics_count_messages_test() {
+    let statistics = SyncStatistics::new();
+    statistics.start_session("user_1");
+    statistics.did_send(&message(WSChannel::Document, 10));
+    statistics.did_send(&message(WSChannel::Document, 5));
+    statistics.did_receive(&message(WSChannel::Document, 3));
+    // No message was sent on this channel, so the latency is not measured
+    statistics.did_receive(&message(WSChannel::Folder, 7));
+
+    let snapshot = statistics.snapshot();
+    assert!(snapshot.started_at > 0);
+    assert_eq!(snapshot.messages_sent, 2);
+    assert_eq!(snapshot.bytes_sent, 15);
+    assert_eq!(snapshot.messages_received, 2);
+    assert_eq!(snapshot.bytes_received, 10);
+    assert_eq!(snapshot.retries, 0);
+    assert_eq!(statistics.0.lock().number_of_latencies, 1);
+  }
+
+  #[test]
+  fn sync_statistics_count_retries_test() {
+    let statistics = SyncStatistics::new();
+    statistics.start_session("user_1");
+    statistics.did_retry(2);
+    statistics.did_retry(0);
+    assert_eq!(statistics.snapshot().retries, 2);
+
+    // The unacked message is sent again
+    statistics.did_send(&message(WSChannel::Document, 10));
+    statistics.did_send(&message(WSChannel::Document, 10));
+    assert_eq!(statistics.snapshot().retries, 3);
+
+    // The same message after the reply is a new message
+    statistics.did_receive(&message(WSChannel::Document, 3));
+    statistics.did_send(&message(WSChannel::Document, 10));
+    // Other messages and other channels are not retries
+    statistics.did_send(&message(WSChannel::Document, 5));
+    statistics.did_send(&message(WSChannel::Folder, 5));
+    assert_eq!(statistics.snapshot().retries, 3);
+  }
+
+  #[test]
+  fn sync_statistics_session_test() {
+    let statistics = SyncStatistics::new();
+    statistics.start_session("user_1");
+    let started_at = statistics.snapshot().started_at;
+    statistics.did_send(&message(WSChannel::Document, 10));
+    statistics.did_retry(1);
+
+    // Reconnecting the same user keeps the session
+    statistics.start_session("user_1");
+    let snapshot = statistics.snapshot();
+    assert_eq!(snapshot.started_at, started_at);
+    assert_eq!(snapshot.messages_sent, 1);
+    assert_eq!(snapshot.retries, 1);
+
+    // Another user starts a new session
+    statistics.start_session("user_2");
+    let snapshot = statistics.snapshot();
+    assert_eq!(snapshot.messages_sent, 0);
+    assert_eq!(snapshot.retries, 0);
+  }
+}
@@ -5,30 +5,64 @@ use crate::entities::SubscribeObject;
 use bytes::Bytes;
 use lazy_static::lazy_static;
 use lib_dispatch::prelude::ToBytes;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::RwLock;
 
 lazy_static! {
-  static ref NOTIFICATION_SENDER: RwLock<Vec<Box<dyn NotificationSender>>> = RwLock::new(vec![]);
+  static ref NOTIFICATION_SENDER: RwLock<Vec<(NotificationSenderId, Box<dyn NotificationSender>)>> =
+    RwLock::new(vec![]);
+  static ref NOTIFICATION_FILTER: RwLock<Option<Box<dyn NotificationFilter>>> = RwLock::new(None);
 }
 
-pub fn register_notification_sender<T: NotificationSender>(sender: T) {
+static NEXT_SENDER_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Identifies a registered sender, it's used to unregister the sender.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotificationSenderId(u64);
+
+pub fn register_notification_sender<T: NotificationSender>(sender: T) -> NotificationSenderId {
+  let id = NotificationSenderId(NEXT_SENDER_ID.fetch_add(1, Ordering::SeqCst));
   let box_sender = Box::new(sender);
   match NOTIFICATION_SENDER.write() {
-    Ok(mut write_guard) => write_guard.push(box_sender),
+    Ok(mut write_guard) => write_guard.push((id, box_sender)),
     Err(err) => tracing::error!("Failed to push notification sender: {:?}", err),
   }
+  id
+}
+
+/// Removes the sender, it doesn't receive the notifications sent afterwards.
+pub fn unregister_notification_sender(id: NotificationSenderId) {
+  match NOTIFICATION_SENDER.write() {
+    Ok(mut write_guard) => write_guard.retain(|(sender_id, _)| *sender_id != id),
+    Err(err) => tracing::error!("Failed to remove notification sender: {:?}", err),
+  }
 }
 
 pub trait NotificationSender: Send + Sync + 'static {
   fn send_subject(&self, subject: SubscribeObject) -> Result<(), String>;
 }
 
+/// Sets the filter that decides whether the notifications with a category are sent, e.g. the
+/// notification preferences of the user. It replaces the filter that was set before.
+pub fn set_notification_filter<T: NotificationFilter>(filter: T) {
+  match NOTIFICATION_FILTER.write() {
+    Ok(mut write_guard) => *write_guard = Some(Box::new(filter)),
+    Err(err) => tracing::error!("Failed to set notification filter: {:?}", err),
+  }
+}
+
+pub trait NotificationFilter: Send + Sync + 'static {
+  /// Returns true if the notifications of the category shouldn't be sent at the moment
+  fn is_muted(&self, category: i32) -> bool;
+}
+
 pub struct NotificationBuilder {
   id: String,
   payload: Option<Bytes>,
   error: Option<Bytes>,
   source: String,
   ty: i32,
+  category: Option<i32>,
 }
 
 impl NotificationBuilder {
@@ -39,9 +73,17 @@ impl NotificationBuilder {
       payload: None,
       error: None,
       source: source.to_owned(),
+      category: None,
     }
   }
 
+  /// Sets the category of the notification. The notification isn't sent if its category is
+  /// muted by the filter, see [set_notification_filter].
+  pub fn category<T: Into<i32>>(mut self, category: T) -> Self {
+    self.category = Some(category.into());
+    self
+  }
+
   pub fn payload<T>(mut self, payload: T) -> Self
   where
     T: ToBytes,
@@ -70,6 +112,13 @@ impl NotificationBuilder {
   }
 
   pub fn send(self) {
+    if let Some(category) = self.category {
+      if is_muted(category) {
+        tracing::trace!("The notification of the category {} is muted", category);
+        return;
+      }
+    }
+
     let payload = self.payload.map(|bytes| bytes.to_vec());
     let error = self.error.map(|bytes| bytes.to_vec());
     let subject = SubscribeObject {
@@ -81,7 +130,7 @@ impl NotificationBuilder {
     };
 
     match NOTIFICATION_SENDER.read() {
-      Ok(read_guard) => read_guard.iter().for_each(|sender| {
+      Ok(read_guard) => read_guard.iter().for_each(|(_, sender)| {
         if let Err(e) = sender.send_subject(subject.clone()) {
           tracing::error!("Post notification failed: {}", e);
         }
@@ -92,3 +141,16 @@ impl NotificationBuilder {
     }
   }
 }
+
+fn is_muted(category: i32) -> bool {
+  match NOTIFICATION_FILTER.read() {
+    Ok(read_guard) => read_guard
+      .as_ref()
+      .map(|filter| filter.is_muted(category))
+      .unwrap_or(false),
+    Err(err) => {
+      tracing::error!("Read notification filter failed: {}", err);
+      false
+    },
+  }
+}
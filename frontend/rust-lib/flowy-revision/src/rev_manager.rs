@@ -4,6 +4,7 @@ use crate::{
   RevisionSnapshotPersistence, WSDataProviderDataSource,
 };
 use bytes::Bytes;
+use dashmap::DashMap;
 use flowy_error::{internal_error, FlowyError, FlowyResult};
 use lib_infra::future::FutureResult;
 use lib_infra::util::md5;
@@ -85,6 +86,9 @@ pub struct RevisionManager<Connection> {
   #[cfg(feature = "flowy_unit_test")]
   rev_ack_notifier: tokio::sync::broadcast::Sender<i64>,
   rev_queue: RevCommandSender,
+  /// The spans of the operations that made the local revisions, by revision id. They are kept
+  /// until the revisions are acked, so the revisions are sent to the server within them.
+  rev_spans: Arc<DashMap<i64, tracing::Span>>,
 }
 
 impl<Connection: 'static> RevisionManager<Connection> {
@@ -111,11 +115,13 @@ impl<Connection: 'static> RevisionManager<Connection> {
       rev_compress.clone(),
     );
     let (rev_queue, receiver) = mpsc::channel(1000);
+    let rev_spans = Arc::new(DashMap::new());
     let queue = RevisionQueue::new(
       object_id.to_owned(),
       rev_id_counter.clone(),
       rev_persistence.clone(),
       rev_compress.clone(),
+      rev_spans.clone(),
       receiver,
     );
     tokio::spawn(queue.run());
@@ -129,6 +135,7 @@ impl<Connection: 'static> RevisionManager<Connection> {
       #[cfg(feature = "flowy_unit_test")]
       rev_ack_notifier: tokio::sync::broadcast::channel(1).0,
       rev_queue,
+      rev_spans,
     }
   }
 
@@ -265,6 +272,7 @@ impl<Connection: 'static> RevisionManager<Connection> {
         data,
         object_md5,
         ret,
+        span: tracing::Span::current(),
       })
       .await
       .map_err(internal_error)?;
@@ -273,6 +281,7 @@ impl<Connection: 'static> RevisionManager<Connection> {
 
   #[tracing::instrument(level = "debug", skip(self), err)]
   pub async fn ack_revision(&self, rev_id: i64) -> Result<(), FlowyError> {
+    self.rev_spans.remove(&rev_id);
     if self.rev_persistence.ack_revision(rev_id).await.is_ok() {
       #[cfg(feature = "flowy_unit_test")]
       let _ = self.rev_ack_notifier.send(rev_id);
@@ -299,6 +308,11 @@ impl<Connection: 'static> RevisionManager<Connection> {
     self.rev_persistence.number_of_sync_records()
   }
 
+  /// Returns the number of the local revisions that were not acked by the server yet.
+  pub async fn number_of_unacked_revisions(&self) -> usize {
+    self.rev_persistence.number_of_unacked_records().await
+  }
+
   pub fn number_of_revisions_in_disk(&self) -> usize {
     self.rev_persistence.number_of_records_in_disk()
   }
@@ -338,6 +352,14 @@ impl<Connection: 'static> WSDataProviderDataSource for Arc<RevisionManager<Conne
   fn current_rev_id(&self) -> i64 {
     self.rev_id()
   }
+
+  fn revision_span(&self, rev_id: i64) -> tracing::Span {
+    self
+      .rev_spans
+      .get(&rev_id)
+      .map(|span| span.clone())
+      .unwrap_or_else(tracing::Span::none)
+  }
 }
 
 #[cfg(feature = "flowy_unit_test")]
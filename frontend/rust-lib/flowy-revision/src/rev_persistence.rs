@@ -230,6 +230,11 @@ where
     self.sync_seq.read().await.next_rev_id()
   }
 
+  /// Returns the number of the revisions that are waiting to be acked by the server.
+  pub(crate) async fn number_of_unacked_records(&self) -> usize {
+    self.sync_seq.read().await.len()
+  }
+
   pub(crate) fn number_of_sync_records(&self) -> usize {
     self.memory_cache.number_of_sync_records()
   }
@@ -463,6 +468,10 @@ impl DeferSyncSequence {
     self.rev_ids.front().cloned()
   }
 
+  fn len(&self) -> usize {
+    self.rev_ids.len()
+  }
+
   fn clear(&mut self) {
     self.merge_start = None;
     self.merge_length = 0;
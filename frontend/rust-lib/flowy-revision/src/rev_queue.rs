@@ -2,12 +2,14 @@
 use crate::{RevIdCounter, RevisionMergeable, RevisionPersistence};
 use async_stream::stream;
 use bytes::Bytes;
+use dashmap::DashMap;
 use flowy_error::FlowyError;
 use futures::stream::StreamExt;
 use revision_model::Revision;
 use std::sync::Arc;
 use tokio::sync::mpsc::{Receiver, Sender};
 use tokio::sync::oneshot;
+use tracing::Instrument;
 
 #[derive(Debug)]
 pub(crate) enum RevisionCommand {
@@ -15,15 +17,22 @@ pub(crate) enum RevisionCommand {
     data: Bytes,
     object_md5: String,
     ret: Ret<i64>,
+    /// The span of the caller, so that the revision is traced with the operation that made it
+    span: tracing::Span,
   },
 }
 
+/// The number of the spans of the unacked revisions that are kept. The revisions of the objects
+/// that aren't synced are never acked, so the oldest spans are dropped.
+const MAX_REV_SPANS: usize = 100;
+
 /// [RevisionQueue] is used to keep the [RevisionCommand] processing in order.
 pub(crate) struct RevisionQueue<Connection> {
   object_id: String,
   rev_id_counter: Arc<RevIdCounter>,
   rev_persistence: Arc<RevisionPersistence<Connection>>,
   rev_compress: Arc<dyn RevisionMergeable>,
+  rev_spans: Arc<DashMap<i64, tracing::Span>>,
   receiver: Option<RevCommandReceiver>,
 }
 
@@ -36,6 +45,7 @@ where
     rev_id_counter: Arc<RevIdCounter>,
     rev_persistence: Arc<RevisionPersistence<Connection>>,
     rev_compress: Arc<dyn RevisionMergeable>,
+    rev_spans: Arc<DashMap<i64, tracing::Span>>,
     receiver: RevCommandReceiver,
   ) -> Self {
     Self {
@@ -43,6 +53,7 @@ where
       rev_id_counter,
       rev_persistence,
       rev_compress,
+      rev_spans,
       receiver: Some(receiver),
     }
   }
@@ -77,6 +88,7 @@ where
         data,
         object_md5: data_md5,
         ret,
+        span,
       } => {
         let base_rev_id = self.rev_id_counter.value();
         let rev_id = self.rev_id_counter.next_id();
@@ -85,9 +97,17 @@ where
         let new_rev_id = self
           .rev_persistence
           .add_local_revision(revision, &self.rev_compress)
+          .instrument(span.clone())
           .await?;
 
         self.rev_id_counter.set(new_rev_id);
+        self.rev_spans.insert(new_rev_id, span);
+        if self.rev_spans.len() > MAX_REV_SPANS {
+          let oldest_rev_id = self.rev_spans.iter().map(|entry| *entry.key()).min();
+          if let Some(oldest_rev_id) = oldest_rev_id {
+            self.rev_spans.remove(&oldest_rev_id);
+          }
+        }
         let _ = ret.send(Ok(new_rev_id));
       },
     }
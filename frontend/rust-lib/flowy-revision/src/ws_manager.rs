@@ -15,8 +15,10 @@ use tokio::{
   },
   time::{interval, Duration},
 };
+use tracing::Instrument;
 use ws_model::ws_revision::{
-  ClientRevisionWSData, NewDocumentUser, ServerRevisionWSData, WSRevisionPayload,
+  ClientRevisionWSData, ClientRevisionWSDataType, NewDocumentUser, ServerRevisionWSData,
+  WSRevisionPayload,
 };
 
 // The consumer consumes the messages pushed by the web socket.
@@ -31,12 +33,19 @@ pub trait RevisionWSDataStream: Send + Sync {
 // server.
 pub trait RevisionWebSocketSink: Send + Sync {
   fn next(&self) -> FutureResult<Option<ClientRevisionWSData>, FlowyError>;
+
+  /// Returns the span of the operation that made the revisions of the data. The data is sent
+  /// within it, so the sync messages are traced with the operation id of the user action.
+  fn span(&self, _data: &ClientRevisionWSData) -> tracing::Span {
+    tracing::Span::none()
+  }
 }
 
 pub type WSStateReceiver = tokio::sync::broadcast::Receiver<WSConnectState>;
 pub trait RevisionWebSocket: Send + Sync + 'static {
   fn send(&self, data: ClientRevisionWSData) -> BoxResultFuture<(), FlowyError>;
   fn subscribe_state_changed(&self) -> BoxFuture<WSStateReceiver>;
+  fn connect_state(&self) -> BoxFuture<WSConnectState>;
 }
 
 pub struct RevisionWebSocketManager {
@@ -305,14 +314,19 @@ impl RevisionWSSink {
         Ok(())
       },
       Some(data) => {
-        tracing::trace!(
-          "[{}]: send {}:{}-{:?}",
-          self,
-          data.object_id,
-          data.rev_id,
-          data.ty
-        );
-        self.rev_web_socket.send(data).await
+        let span = self.provider.span(&data);
+        async {
+          tracing::trace!(
+            "[{}]: send {}:{}-{:?}",
+            self,
+            data.object_id,
+            data.rev_id,
+            data.ty
+          );
+          self.rev_web_socket.send(data).await
+        }
+        .instrument(span)
+        .await
       },
     }
   }
@@ -347,6 +361,9 @@ pub trait WSDataProviderDataSource: Send + Sync {
   fn next_revision(&self) -> FutureResult<Option<Revision>, FlowyError>;
   fn ack_revision(&self, rev_id: i64) -> FutureResult<(), FlowyError>;
   fn current_rev_id(&self) -> i64;
+  /// Returns the span of the operation that made the local revision, or a disabled span if the
+  /// revision was acked or made before the app was launched.
+  fn revision_span(&self, rev_id: i64) -> tracing::Span;
 }
 
 #[derive(Clone)]
@@ -367,6 +384,15 @@ impl WSDataProvider {
     }
   }
 
+  /// Returns the span of the operation that made the revisions of the data, see
+  /// [RevisionWebSocketSink::span].
+  pub fn span(&self, data: &ClientRevisionWSData) -> tracing::Span {
+    match data.ty {
+      ClientRevisionWSDataType::ClientPushRev => self.data_source.revision_span(data.rev_id),
+      ClientRevisionWSDataType::ClientPing => tracing::Span::none(),
+    }
+  }
+
   pub async fn push_data(&self, data: ClientRevisionWSData) {
     self.rev_ws_data_list.write().await.push_back(data);
   }
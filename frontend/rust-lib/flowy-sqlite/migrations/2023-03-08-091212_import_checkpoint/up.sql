@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE import_checkpoint_table (
+ import_id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+ view_id TEXT NOT NULL DEFAULT '',
+ import_type INTEGER NOT NULL DEFAULT 0,
+ file_path TEXT NOT NULL DEFAULT '',
+ next_row BIGINT NOT NULL DEFAULT 0,
+ timestamp BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+CREATE TABLE deleted_document_table (
+ document_id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+ deleted_at BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
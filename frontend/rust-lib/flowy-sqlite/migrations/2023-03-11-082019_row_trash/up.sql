@@ -0,0 +1,7 @@
+-- Your SQL goes here
+CREATE TABLE row_trash_table (
+ row_id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+ database_id TEXT NOT NULL DEFAULT '',
+ data TEXT NOT NULL DEFAULT '',
+ deleted_at BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
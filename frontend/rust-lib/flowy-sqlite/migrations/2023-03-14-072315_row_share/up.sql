@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE row_share_table (
+ share_id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+ database_id TEXT NOT NULL DEFAULT '',
+ view_id TEXT NOT NULL DEFAULT '',
+ row_id TEXT NOT NULL DEFAULT '',
+ created_at BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE inbox_table (
+ id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+ kind INTEGER NOT NULL DEFAULT 0,
+ title TEXT NOT NULL DEFAULT '',
+ body TEXT NOT NULL DEFAULT '',
+ object_id TEXT NOT NULL DEFAULT '',
+ is_read Boolean NOT NULL DEFAULT false,
+ created_at BIGINT NOT NULL DEFAULT 0
+);
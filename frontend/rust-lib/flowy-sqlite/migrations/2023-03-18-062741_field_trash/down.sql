@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE field_trash_table;
\ No newline at end of file
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+CREATE TABLE document_layout_cache_table (
+ document_id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+ blocks TEXT NOT NULL DEFAULT '',
+ has_more Boolean NOT NULL DEFAULT false,
+ updated_at BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE automation_activity_table (
+ id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+ automation_id TEXT NOT NULL DEFAULT '',
+ view_id TEXT NOT NULL DEFAULT '',
+ row_id TEXT NOT NULL DEFAULT '',
+ trigger_date TEXT NOT NULL DEFAULT '',
+ error TEXT NOT NULL DEFAULT '',
+ executed_at BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE relation_link_table (
+ id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+ database_id TEXT NOT NULL DEFAULT '',
+ field_id TEXT NOT NULL DEFAULT '',
+ row_id TEXT NOT NULL DEFAULT '',
+ linked_database_id TEXT NOT NULL DEFAULT '',
+ linked_row_id TEXT NOT NULL DEFAULT ''
+);
+CREATE INDEX relation_link_linked_database_idx ON relation_link_table (linked_database_id, linked_row_id);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+CREATE TABLE automation_view_table (
+ view_id TEXT NOT NULL PRIMARY KEY DEFAULT ''
+);
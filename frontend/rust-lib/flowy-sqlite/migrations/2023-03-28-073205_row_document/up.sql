@@ -0,0 +1,5 @@
+-- Your SQL goes here
+CREATE TABLE row_document_table (
+ row_id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+ document_id TEXT NOT NULL DEFAULT ''
+);
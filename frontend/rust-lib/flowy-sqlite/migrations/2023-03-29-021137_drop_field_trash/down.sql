@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+CREATE TABLE field_trash_table (
+ field_id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+ database_id TEXT NOT NULL DEFAULT '',
+ data TEXT NOT NULL DEFAULT '',
+ field_index INTEGER NOT NULL DEFAULT 0,
+ deleted_at BIGINT NOT NULL DEFAULT 0
+);
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+-- The trashed fields are kept in the databases, so they're synced
+DROP TABLE field_trash_table;
\ No newline at end of file
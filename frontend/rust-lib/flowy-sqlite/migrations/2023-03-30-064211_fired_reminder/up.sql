@@ -0,0 +1,5 @@
+-- Your SQL goes here
+CREATE TABLE fired_reminder_table (
+ reminder_id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+ fired_at BIGINT NOT NULL DEFAULT 0
+);
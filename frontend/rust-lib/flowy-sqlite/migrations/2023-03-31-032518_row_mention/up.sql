@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE row_mention_table (
+ id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+ database_id TEXT NOT NULL DEFAULT '',
+ field_id TEXT NOT NULL DEFAULT '',
+ row_id TEXT NOT NULL DEFAULT '',
+ mentioned_id TEXT NOT NULL DEFAULT ''
+);
+CREATE INDEX row_mention_mentioned_idx ON row_mention_table (mentioned_id);
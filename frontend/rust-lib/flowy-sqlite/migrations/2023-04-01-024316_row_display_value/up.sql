@@ -0,0 +1,8 @@
+-- Your SQL goes here
+CREATE TABLE row_display_value_table (
+ row_id TEXT NOT NULL PRIMARY KEY DEFAULT '',
+ database_id TEXT NOT NULL DEFAULT '',
+ source_hash BIGINT NOT NULL DEFAULT 0,
+ display_value TEXT NOT NULL DEFAULT ''
+);
+CREATE INDEX row_display_value_database_idx ON row_display_value_table (database_id);
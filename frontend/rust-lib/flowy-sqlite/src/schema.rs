@@ -15,6 +15,24 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    automation_activity_table (id) {
+        id -> Text,
+        automation_id -> Text,
+        view_id -> Text,
+        row_id -> Text,
+        trigger_date -> Text,
+        error -> Text,
+        executed_at -> BigInt,
+    }
+}
+
+diesel::table! {
+    automation_view_table (view_id) {
+        view_id -> Text,
+    }
+}
+
 diesel::table! {
     database_refs (ref_id) {
         ref_id -> Text,
@@ -25,6 +43,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    deleted_document_table (document_id) {
+        document_id -> Text,
+        deleted_at -> BigInt,
+    }
+}
+
+diesel::table! {
+    document_layout_cache_table (document_id) {
+        document_id -> Text,
+        blocks -> Text,
+        has_more -> Bool,
+        updated_at -> BigInt,
+    }
+}
+
 diesel::table! {
     document_rev_snapshot (snapshot_id) {
         snapshot_id -> Text,
@@ -47,6 +81,13 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    fired_reminder_table (reminder_id) {
+        reminder_id -> Text,
+        fired_at -> BigInt,
+    }
+}
+
 diesel::table! {
     folder_rev_snapshot (snapshot_id) {
         snapshot_id -> Text,
@@ -109,6 +150,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    import_checkpoint_table (import_id) {
+        import_id -> Text,
+        view_id -> Text,
+        import_type -> Integer,
+        file_path -> Text,
+        next_row -> BigInt,
+        timestamp -> BigInt,
+    }
+}
+
+diesel::table! {
+    inbox_table (id) {
+        id -> Text,
+        kind -> Integer,
+        title -> Text,
+        body -> Text,
+        object_id -> Text,
+        is_read -> Bool,
+        created_at -> BigInt,
+    }
+}
+
 diesel::table! {
     kv_table (key) {
         key -> Text,
@@ -116,6 +180,17 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    relation_link_table (id) {
+        id -> Text,
+        database_id -> Text,
+        field_id -> Text,
+        row_id -> Text,
+        linked_database_id -> Text,
+        linked_row_id -> Text,
+    }
+}
+
 diesel::table! {
     rev_snapshot (id) {
         id -> Integer,
@@ -137,6 +212,51 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    row_display_value_table (row_id) {
+        row_id -> Text,
+        database_id -> Text,
+        source_hash -> BigInt,
+        display_value -> Text,
+    }
+}
+
+diesel::table! {
+    row_document_table (row_id) {
+        row_id -> Text,
+        document_id -> Text,
+    }
+}
+
+diesel::table! {
+    row_mention_table (id) {
+        id -> Text,
+        database_id -> Text,
+        field_id -> Text,
+        row_id -> Text,
+        mentioned_id -> Text,
+    }
+}
+
+diesel::table! {
+    row_share_table (share_id) {
+        share_id -> Text,
+        database_id -> Text,
+        view_id -> Text,
+        row_id -> Text,
+        created_at -> BigInt,
+    }
+}
+
+diesel::table! {
+    row_trash_table (row_id) {
+        row_id -> Text,
+        database_id -> Text,
+        data -> Text,
+        deleted_at -> BigInt,
+    }
+}
+
 diesel::table! {
     trash_table (id) {
         id -> Text,
@@ -190,18 +310,31 @@ diesel::table! {
 
 diesel::allow_tables_to_appear_in_same_query!(
   app_table,
+  automation_activity_table,
+  automation_view_table,
   database_refs,
+  deleted_document_table,
+  document_layout_cache_table,
   document_rev_snapshot,
   document_rev_table,
+  fired_reminder_table,
   folder_rev_snapshot,
   grid_block_index_table,
   grid_meta_rev_table,
   grid_rev_snapshot,
   grid_rev_table,
   grid_view_rev_table,
+  import_checkpoint_table,
+  inbox_table,
   kv_table,
+  relation_link_table,
   rev_snapshot,
   rev_table,
+  row_display_value_table,
+  row_document_table,
+  row_mention_table,
+  row_share_table,
+  row_trash_table,
   trash_table,
   user_table,
   view_table,
@@ -11,12 +11,15 @@ use std::time::Duration;
 
 use tokio::sync::{watch, RwLock};
 use tokio::time::interval;
+use tracing::Instrument;
 
 pub struct TaskDispatcher {
   queue: TaskQueue,
   store: TaskStore,
   timeout: Duration,
   handlers: HashMap<String, Arc<dyn TaskHandler>>,
+  /// The number of the tasks that failed or timed out since the dispatcher was created.
+  number_of_failed_tasks: usize,
 
   notifier: watch::Sender<bool>,
   pub(crate) notifier_rx: Option<watch::Receiver<bool>>,
@@ -30,6 +33,7 @@ impl TaskDispatcher {
       store: TaskStore::new(),
       timeout,
       handlers: HashMap::new(),
+      number_of_failed_tasks: 0,
       notifier,
       notifier_rx: Some(notifier_rx),
     }
@@ -75,7 +79,8 @@ impl TaskDispatcher {
     if let Some(handler) = self.handlers.get(&task.handler_id) {
       task.set_state(TaskState::Processing);
       tracing::trace!("{} task is running", handler.handler_name(),);
-      match tokio::time::timeout(self.timeout, handler.run(content)).await {
+      let fut = handler.run(content).instrument(task.span.clone());
+      match tokio::time::timeout(self.timeout, fut).await {
         Ok(result) => match result {
           Ok(_) => {
             tracing::trace!("{} task is done", handler.handler_name(),);
@@ -84,11 +89,13 @@ impl TaskDispatcher {
           Err(e) => {
             tracing::error!("{} task is failed: {:?}", handler.handler_name(), e);
             task.set_state(TaskState::Failure);
+            self.number_of_failed_tasks += 1;
           },
         },
         Err(e) => {
           tracing::error!("{} task is timeout: {:?}", handler.handler_name(), e);
           task.set_state(TaskState::Timeout);
+          self.number_of_failed_tasks += 1;
         },
       }
     } else {
@@ -122,6 +129,16 @@ impl TaskDispatcher {
     }
   }
 
+  /// Returns the number of the tasks that are waiting to be run.
+  pub fn number_of_pending_tasks(&self) -> usize {
+    self.store.len()
+  }
+
+  /// Returns the number of the tasks that failed or timed out.
+  pub fn number_of_failed_tasks(&self) -> usize {
+    self.number_of_failed_tasks
+  }
+
   pub fn next_task_id(&self) -> TaskId {
     self.store.next_task_id()
   }
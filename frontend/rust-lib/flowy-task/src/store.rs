@@ -33,6 +33,10 @@ impl TaskStore {
     self.tasks.get(task_id)
   }
 
+  pub(crate) fn len(&self) -> usize {
+    self.tasks.len()
+  }
+
   pub(crate) fn clear(&mut self) {
     let tasks = mem::take(&mut self.tasks);
     tasks.into_values().for_each(|mut task| {
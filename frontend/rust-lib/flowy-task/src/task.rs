@@ -96,6 +96,9 @@ pub struct Task {
   state: TaskState,
   pub ret: Option<Sender<TaskResult>>,
   pub recv: Option<Receiver<TaskResult>>,
+  /// The span in which the task was created. The task runs inside it, so its logs carry the
+  /// operation id of the event that scheduled it.
+  pub(crate) span: tracing::Span,
 }
 
 impl Task {
@@ -118,6 +121,7 @@ impl Task {
       ret: Some(ret),
       recv: Some(recv),
       state: TaskState::Pending,
+      span: tracing::Span::current(),
     }
   }
 
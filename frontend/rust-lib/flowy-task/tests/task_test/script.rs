@@ -38,6 +38,10 @@ pub enum SearchScript {
     execute_order: Vec<u32>,
     rets: Vec<Receiver<TaskResult>>,
   },
+  AssertNumberOfTasks {
+    pending: usize,
+    failed: usize,
+  },
 }
 
 pub struct SearchTest {
@@ -122,6 +126,11 @@ impl SearchTest {
         }
         assert_eq!(execute_order, orders);
       },
+      SearchScript::AssertNumberOfTasks { pending, failed } => {
+        let scheduler = self.scheduler.read().await;
+        assert_eq!(scheduler.number_of_pending_tasks(), pending);
+        assert_eq!(scheduler.number_of_failed_tasks(), failed);
+      },
     }
   }
 }
@@ -89,3 +89,27 @@ async fn task_run_timeout_test() {
   let result = ret.await.unwrap();
   assert_eq!(result.state, TaskState::Timeout);
 }
+
+#[tokio::test]
+async fn task_run_timeout_is_counted_as_failed_test() {
+  let test = SearchTest::new().await;
+  let (task, ret) = make_timeout_task(test.next_task_id().await);
+  test
+    .run_scripts(vec![
+      AssertNumberOfTasks {
+        pending: 0,
+        failed: 0,
+      },
+      AddTask { task },
+    ])
+    .await;
+
+  let result = ret.await.unwrap();
+  assert_eq!(result.state, TaskState::Timeout);
+  test
+    .run_scripts(vec![AssertNumberOfTasks {
+      pending: 0,
+      failed: 1,
+    }])
+    .await;
+}
@@ -67,6 +67,14 @@ where
     self
   }
 
+  /// Sends the request as a scoped request, e.g. the request authenticated with a personal
+  /// access token.
+  pub fn scopes(mut self, scopes: Vec<PermissionScope>) -> Self {
+    let request = self.get_request();
+    self.context.request = Some(request.scopes(scopes));
+    self
+  }
+
   pub fn sync_send(mut self) -> Self {
     let request = self.get_request();
     let resp = AFPluginDispatcher::sync_send(self.dispatch(), request);
@@ -53,6 +53,22 @@ impl ViewTest {
   pub async fn new_document_view(sdk: &FlowySDKTest) -> Self {
     Self::new(sdk, ViewLayoutTypePB::Document, vec![]).await
   }
+
+  /// Moves the view to the trash and then empties the trash.
+  pub async fn delete_view_permanently(&self) {
+    let payload = RepeatedViewIdPB {
+      items: vec![self.view.id.clone()],
+    };
+    FolderEventBuilder::new(self.sdk.clone())
+      .event(DeleteView)
+      .payload(payload)
+      .async_send()
+      .await;
+    FolderEventBuilder::new(self.sdk.clone())
+      .event(DeleteAllTrash)
+      .async_send()
+      .await;
+  }
 }
 
 async fn create_workspace(sdk: &FlowySDKTest, name: &str, desc: &str) -> WorkspacePB {
@@ -5,8 +5,11 @@ use crate::helper::*;
 
 use flowy_core::{AppFlowyCore, AppFlowyCoreConfig};
 use flowy_document::entities::DocumentVersionPB;
+use flowy_document::DocumentConfig;
+use flowy_folder::entities::WorkspaceQuota;
 use flowy_net::get_client_server_configuration;
 use flowy_user::entities::UserProfilePB;
+use lib_dispatch::prelude::{EventRecorder, EventReplayer, ReplayedEvent};
 use nanoid::nanoid;
 
 pub mod prelude {
@@ -35,10 +38,37 @@ impl std::default::Default for FlowySDKTest {
 
 impl FlowySDKTest {
   pub fn new(document_version: DocumentVersionPB) -> Self {
+    Self::new_with_document_config(DocumentConfig {
+      version: document_version,
+      ..Default::default()
+    })
+  }
+
+  pub fn new_with_document_config(document_config: DocumentConfig) -> Self {
+    Self::new_with_config(|mut config| {
+      config.document = document_config;
+      config
+    })
+  }
+
+  /// Records all the dispatched events of the session into the `recorder`.
+  pub fn new_with_event_recorder(recorder: EventRecorder) -> Self {
+    Self::new_with_config(|config| config.with_event_recorder(recorder))
+  }
+
+  /// Enforces the `quota` as the local quota of the workspace.
+  pub fn new_with_workspace_quota(quota: WorkspaceQuota) -> Self {
+    Self::new_with_config(|config| config.with_workspace_quota(quota))
+  }
+
+  fn new_with_config<F>(f: F) -> Self
+  where
+    F: FnOnce(AppFlowyCoreConfig) -> AppFlowyCoreConfig,
+  {
     let server_config = get_client_server_configuration().unwrap();
-    let config = AppFlowyCoreConfig::new(&root_dir(), nanoid!(6), server_config)
-      .with_document_version(document_version)
-      .log_filter("info", vec![]);
+    let config =
+      AppFlowyCoreConfig::new(&root_dir(), nanoid!(6), server_config).log_filter("info", vec![]);
+    let config = f(config);
     let sdk = std::thread::spawn(|| AppFlowyCore::new(config))
       .join()
       .unwrap();
@@ -56,6 +86,11 @@ impl FlowySDKTest {
     context.user_profile
   }
 
+  /// Replays the recorded events against this instance.
+  pub async fn replay(&self, replayer: EventReplayer) -> Vec<ReplayedEvent> {
+    replayer.replay(self.inner.dispatcher()).await
+  }
+
   pub fn document_version(&self) -> DocumentVersionPB {
     self.inner.config.document.version.clone()
   }
@@ -0,0 +1,195 @@
+use crate::errors::ErrorCode;
+use flowy_derive::{ProtoBuf, ProtoBuf_Enum};
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+
+/// What the notification in the inbox is about.
+#[derive(ProtoBuf_Enum, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InboxNotificationKindPB {
+  Reminder = 0,
+  Mention = 1,
+  Comment = 2,
+  Share = 3,
+  SyncError = 4,
+}
+
+impl std::default::Default for InboxNotificationKindPB {
+  fn default() -> Self {
+    InboxNotificationKindPB::Reminder
+  }
+}
+
+impl std::convert::From<i32> for InboxNotificationKindPB {
+  fn from(value: i32) -> Self {
+    match value {
+      1 => InboxNotificationKindPB::Mention,
+      2 => InboxNotificationKindPB::Comment,
+      3 => InboxNotificationKindPB::Share,
+      4 => InboxNotificationKindPB::SyncError,
+      _ => InboxNotificationKindPB::Reminder,
+    }
+  }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct InboxNotificationPB {
+  #[pb(index = 1)]
+  pub id: String,
+
+  #[pb(index = 2)]
+  pub kind: InboxNotificationKindPB,
+
+  #[pb(index = 3)]
+  pub title: String,
+
+  #[pb(index = 4)]
+  pub body: String,
+
+  /// The id of the object that the notification links to, e.g. the view or the row
+  #[pb(index = 5)]
+  pub object_id: String,
+
+  #[pb(index = 6)]
+  pub is_read: bool,
+
+  /// The timestamp in seconds when the notification was added
+  #[pb(index = 7)]
+  pub created_at: i64,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedInboxNotificationPB {
+  #[pb(index = 1)]
+  pub items: Vec<InboxNotificationPB>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct AddInboxNotificationPayloadPB {
+  #[pb(index = 1)]
+  pub kind: InboxNotificationKindPB,
+
+  #[pb(index = 2)]
+  pub title: String,
+
+  #[pb(index = 3)]
+  pub body: String,
+
+  #[pb(index = 4)]
+  pub object_id: String,
+}
+
+pub struct AddInboxNotificationParams {
+  pub kind: InboxNotificationKindPB,
+  pub title: String,
+  pub body: String,
+  pub object_id: String,
+}
+
+impl TryInto<AddInboxNotificationParams> for AddInboxNotificationPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<AddInboxNotificationParams, Self::Error> {
+    let title = self.title.trim().to_owned();
+    if title.is_empty() {
+      return Err(ErrorCode::InboxTitleIsEmpty);
+    }
+    Ok(AddInboxNotificationParams {
+      kind: self.kind,
+      title,
+      body: self.body,
+      object_id: self.object_id,
+    })
+  }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct GetInboxNotificationsPayloadPB {
+  #[pb(index = 1)]
+  pub unread_only: bool,
+}
+
+/// The ids of the notifications, all of the notifications are included if it's empty.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct InboxNotificationIdsPB {
+  #[pb(index = 1)]
+  pub ids: Vec<String>,
+}
+
+/// The number of the unread notifications that is shown on the badge of the inbox.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct InboxBadgePB {
+  #[pb(index = 1)]
+  pub unread_count: i64,
+}
+
+#[derive(ProtoBuf, Serialize, Deserialize, Default, Debug, Clone)]
+pub struct NotificationCategoryPreferencePB {
+  #[pb(index = 1)]
+  pub kind: InboxNotificationKindPB,
+
+  /// The muted notifications are only kept in the inbox, they are not shown when they arrive
+  #[pb(index = 2)]
+  pub is_muted: bool,
+}
+
+/// Decides which of the arriving notifications are shown. The notifications are always kept
+/// in the inbox, whether they are shown or not.
+#[derive(ProtoBuf, Serialize, Deserialize, Default, Debug, Clone)]
+pub struct NotificationPreferencesPB {
+  /// The categories that are not listed are not muted
+  #[pb(index = 1)]
+  pub categories: Vec<NotificationCategoryPreferencePB>,
+
+  /// Mutes all of the categories until it's turned off
+  #[pb(index = 2)]
+  pub do_not_disturb: bool,
+
+  #[pb(index = 3)]
+  pub quiet_hours_enabled: bool,
+
+  /// The minute of the day in the local time when the quiet hours start
+  #[pb(index = 4)]
+  pub quiet_hours_start: i32,
+
+  /// The minute of the day in the local time when the quiet hours end. The quiet hours span
+  /// midnight if it's before the start.
+  #[pb(index = 5)]
+  pub quiet_hours_end: i32,
+}
+
+impl NotificationPreferencesPB {
+  /// Returns true if the notification of the `kind` that arrives at the `minute` of the day
+  /// should only be kept in the inbox.
+  pub fn is_muted(&self, kind: InboxNotificationKindPB, minute: i32) -> bool {
+    if self.do_not_disturb {
+      return true;
+    }
+    let is_category_muted = self
+      .categories
+      .iter()
+      .any(|category| category.kind == kind && category.is_muted);
+    is_category_muted || self.is_quiet_at(minute)
+  }
+
+  pub fn validate(&self) -> Result<(), ErrorCode> {
+    let is_minute_of_day = |minute: i32| (0..MINUTES_PER_DAY).contains(&minute);
+    if !is_minute_of_day(self.quiet_hours_start) || !is_minute_of_day(self.quiet_hours_end) {
+      return Err(ErrorCode::InvalidData);
+    }
+    Ok(())
+  }
+
+  fn is_quiet_at(&self, minute: i32) -> bool {
+    if !self.quiet_hours_enabled {
+      return false;
+    }
+    let (start, end) = (self.quiet_hours_start, self.quiet_hours_end);
+    if start <= end {
+      start <= minute && minute < end
+    } else {
+      minute >= start || minute < end
+    }
+  }
+}
+
+const MINUTES_PER_DAY: i32 = 24 * 60;
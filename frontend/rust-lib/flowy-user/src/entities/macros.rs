@@ -0,0 +1,104 @@
+use crate::errors::ErrorCode;
+use flowy_derive::ProtoBuf;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+/// A dispatched event of the macro. The `payload` is the protobuf bytes of the event's input,
+/// a string field whose value is `{{slot_name}}` will be filled when running the macro.
+#[derive(ProtoBuf, Serialize, Deserialize, Default, Debug, Clone)]
+pub struct MacroStepPB {
+  #[pb(index = 1)]
+  pub event: String,
+
+  #[pb(index = 2)]
+  pub payload: Vec<u8>,
+}
+
+#[derive(ProtoBuf, Serialize, Deserialize, Default, Debug, Clone)]
+pub struct MacroPB {
+  #[pb(index = 1)]
+  pub name: String,
+
+  #[pb(index = 2)]
+  pub steps: Vec<MacroStepPB>,
+
+  /// The names of the slots that should be passed in when running the macro
+  #[pb(index = 3)]
+  #[serde(default)]
+  pub slots: Vec<String>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedMacroPB {
+  #[pb(index = 1)]
+  pub items: Vec<MacroPB>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct MacroNamePB {
+  #[pb(index = 1)]
+  pub name: String,
+}
+
+impl TryInto<String> for MacroNamePB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<String, Self::Error> {
+    parse_macro_name(self.name)
+  }
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RunMacroPayloadPB {
+  #[pb(index = 1)]
+  pub name: String,
+
+  /// The value of each slot, keyed by the slot name
+  #[pb(index = 2)]
+  pub args: HashMap<String, String>,
+}
+
+pub struct RunMacroParams {
+  pub name: String,
+  pub args: HashMap<String, String>,
+}
+
+impl TryInto<RunMacroParams> for RunMacroPayloadPB {
+  type Error = ErrorCode;
+
+  fn try_into(self) -> Result<RunMacroParams, Self::Error> {
+    Ok(RunMacroParams {
+      name: parse_macro_name(self.name)?,
+      args: self.args,
+    })
+  }
+}
+
+/// The response of one step of the macro. The `payload` is the output of the event if it
+/// succeeded, otherwise it's the error.
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct MacroStepResultPB {
+  #[pb(index = 1)]
+  pub event: String,
+
+  #[pb(index = 2)]
+  pub is_ok: bool,
+
+  #[pb(index = 3)]
+  pub payload: Vec<u8>,
+}
+
+#[derive(ProtoBuf, Default, Debug, Clone)]
+pub struct RepeatedMacroStepResultPB {
+  #[pb(index = 1)]
+  pub items: Vec<MacroStepResultPB>,
+}
+
+pub(crate) fn parse_macro_name(name: String) -> Result<String, ErrorCode> {
+  let name = name.trim();
+  if name.is_empty() {
+    return Err(ErrorCode::MacroNameIsEmpty);
+  }
+  Ok(name.to_owned())
+}
@@ -1,7 +1,11 @@
 pub use auth::*;
+pub use inbox::*;
+pub use macros::*;
 pub use user_profile::*;
 pub use user_setting::*;
 
 pub mod auth;
+mod inbox;
+mod macros;
 mod user_profile;
 mod user_setting;
@@ -1,5 +1,9 @@
 use crate::entities::UserProfilePB;
-use crate::{errors::FlowyError, handlers::*, services::UserSession};
+use crate::{
+  errors::FlowyError,
+  handlers::*,
+  services::{InboxService, MacroService, UserSession},
+};
 use lib_dispatch::prelude::*;
 
 use lib_infra::future::{Fut, FutureResult};
@@ -8,10 +12,16 @@ use user_model::{
   SignInParams, SignInResponse, SignUpParams, SignUpResponse, UpdateUserProfileParams, UserProfile,
 };
 
-pub fn init(user_session: Arc<UserSession>) -> AFPlugin {
+pub fn init(
+  user_session: Arc<UserSession>,
+  macro_service: Arc<MacroService>,
+  inbox_service: Arc<InboxService>,
+) -> AFPlugin {
   AFPlugin::new()
     .name("Flowy-User")
     .state(user_session)
+    .state(macro_service)
+    .state(inbox_service)
     .event(UserEvent::SignIn, sign_in)
     .event(UserEvent::SignUp, sign_up)
     .event(UserEvent::InitUser, init_user_handler)
@@ -22,6 +32,40 @@ pub fn init(user_session: Arc<UserSession>) -> AFPlugin {
     .event(UserEvent::SetAppearanceSetting, set_appearance_setting)
     .event(UserEvent::GetAppearanceSetting, get_appearance_setting)
     .event(UserEvent::GetUserSetting, get_user_setting)
+    .event(
+      UserEvent::StartMacroRecording,
+      start_macro_recording_handler,
+    )
+    .event(UserEvent::StopMacroRecording, stop_macro_recording_handler)
+    .event(UserEvent::SaveMacro, save_macro_handler)
+    .event(UserEvent::GetMacros, get_macros_handler)
+    .event(UserEvent::DeleteMacro, delete_macro_handler)
+    .event(UserEvent::RunMacro, run_macro_handler)
+    .event(
+      UserEvent::AddInboxNotification,
+      add_inbox_notification_handler,
+    )
+    .event(
+      UserEvent::GetInboxNotifications,
+      get_inbox_notifications_handler,
+    )
+    .event(
+      UserEvent::MarkInboxNotificationsRead,
+      mark_inbox_notifications_read_handler,
+    )
+    .event(
+      UserEvent::ClearInboxNotifications,
+      clear_inbox_notifications_handler,
+    )
+    .event(UserEvent::GetInboxBadge, get_inbox_badge_handler)
+    .event(
+      UserEvent::GetNotificationPreferences,
+      get_notification_preferences_handler,
+    )
+    .event(
+      UserEvent::SetNotificationPreferences,
+      set_notification_preferences_handler,
+    )
 }
 
 pub trait UserStatusCallback: Send + Sync + 'static {
@@ -45,9 +89,11 @@ pub trait UserCloudService: Send + Sync {
 
 use flowy_derive::{Flowy_Event, ProtoBuf_Enum};
 use flowy_error::FlowyResult;
-use strum_macros::Display;
+use strum_macros::{Display, EnumIter};
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug, Display, Hash, ProtoBuf_Enum, Flowy_Event)]
+#[derive(
+  Clone, Copy, PartialEq, Eq, Debug, Display, Hash, EnumIter, ProtoBuf_Enum, Flowy_Event,
+)]
 #[event_err = "FlowyError"]
 pub enum UserEvent {
   /// Logging into an account using a register email and password
@@ -89,4 +135,62 @@ pub enum UserEvent {
   /// Get the settings of the user, such as the user storage folder
   #[event(output = "UserSettingPB")]
   GetUserSetting = 9,
+
+  /// Start recording the dispatched events into a macro with the given name
+  #[event(input = "MacroNamePB")]
+  StartMacroRecording = 10,
+
+  /// Stop the recording and save the recorded macro
+  #[event(output = "MacroPB")]
+  StopMacroRecording = 11,
+
+  /// Save the macro, e.g. after turning some values of its payloads into slots
+  #[event(input = "MacroPB", output = "MacroPB")]
+  SaveMacro = 12,
+
+  /// Get the macros of the current user
+  #[event(output = "RepeatedMacroPB")]
+  GetMacros = 13,
+
+  /// Delete the macro with the given name
+  #[event(input = "MacroNamePB")]
+  DeleteMacro = 14,
+
+  /// Dispatch the events of the macro one by one with the values of its slots
+  #[event(input = "RunMacroPayloadPB", output = "RepeatedMacroStepResultPB")]
+  RunMacro = 15,
+
+  /// Add a notification to the inbox of the current user
+  #[event(
+    input = "AddInboxNotificationPayloadPB",
+    output = "InboxNotificationPB"
+  )]
+  AddInboxNotification = 16,
+
+  /// Get the notifications in the inbox, the most recent one comes first
+  #[event(
+    input = "GetInboxNotificationsPayloadPB",
+    output = "RepeatedInboxNotificationPB"
+  )]
+  GetInboxNotifications = 17,
+
+  /// Mark the notifications as read, all of them are marked if no id is given
+  #[event(input = "InboxNotificationIdsPB")]
+  MarkInboxNotificationsRead = 18,
+
+  /// Remove the notifications from the inbox, all of them are removed if no id is given
+  #[event(input = "InboxNotificationIdsPB")]
+  ClearInboxNotifications = 19,
+
+  /// Get the number of the unread notifications
+  #[event(output = "InboxBadgePB")]
+  GetInboxBadge = 20,
+
+  /// Get the preferences that decide which of the arriving notifications are shown
+  #[event(output = "NotificationPreferencesPB")]
+  GetNotificationPreferences = 21,
+
+  /// Set the muted categories and the quiet hours of the notifications
+  #[event(input = "NotificationPreferencesPB")]
+  SetNotificationPreferences = 22,
 }
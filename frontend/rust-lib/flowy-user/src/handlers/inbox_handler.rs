@@ -0,0 +1,70 @@
+use crate::entities::{
+  AddInboxNotificationParams, AddInboxNotificationPayloadPB, GetInboxNotificationsPayloadPB,
+  InboxBadgePB, InboxNotificationIdsPB, InboxNotificationPB, NotificationPreferencesPB,
+  RepeatedInboxNotificationPB,
+};
+use crate::{errors::FlowyError, services::InboxService};
+use lib_dispatch::prelude::*;
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(level = "debug", skip(data, service), err)]
+pub async fn add_inbox_notification_handler(
+  data: AFPluginData<AddInboxNotificationPayloadPB>,
+  service: AFPluginState<Arc<InboxService>>,
+) -> DataResult<InboxNotificationPB, FlowyError> {
+  let params: AddInboxNotificationParams = data.into_inner().try_into()?;
+  let notification = service.add_notification(params)?;
+  data_result_ok(notification)
+}
+
+#[tracing::instrument(level = "debug", skip(data, service), err)]
+pub async fn get_inbox_notifications_handler(
+  data: AFPluginData<GetInboxNotificationsPayloadPB>,
+  service: AFPluginState<Arc<InboxService>>,
+) -> DataResult<RepeatedInboxNotificationPB, FlowyError> {
+  let items = service.get_notifications(data.into_inner().unread_only)?;
+  data_result_ok(RepeatedInboxNotificationPB { items })
+}
+
+#[tracing::instrument(level = "debug", skip(data, service), err)]
+pub async fn mark_inbox_notifications_read_handler(
+  data: AFPluginData<InboxNotificationIdsPB>,
+  service: AFPluginState<Arc<InboxService>>,
+) -> Result<(), FlowyError> {
+  service.mark_read(&data.into_inner().ids)?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, service), err)]
+pub async fn clear_inbox_notifications_handler(
+  data: AFPluginData<InboxNotificationIdsPB>,
+  service: AFPluginState<Arc<InboxService>>,
+) -> Result<(), FlowyError> {
+  service.clear(&data.into_inner().ids)?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(service), err)]
+pub async fn get_inbox_badge_handler(
+  service: AFPluginState<Arc<InboxService>>,
+) -> DataResult<InboxBadgePB, FlowyError> {
+  let badge = service.get_badge()?;
+  data_result_ok(badge)
+}
+
+#[tracing::instrument(level = "debug", skip(service), err)]
+pub async fn get_notification_preferences_handler(
+  service: AFPluginState<Arc<InboxService>>,
+) -> DataResult<NotificationPreferencesPB, FlowyError> {
+  let preferences = service.get_preferences()?;
+  data_result_ok(preferences)
+}
+
+#[tracing::instrument(level = "debug", skip(data, service), err)]
+pub async fn set_notification_preferences_handler(
+  data: AFPluginData<NotificationPreferencesPB>,
+  service: AFPluginState<Arc<InboxService>>,
+) -> Result<(), FlowyError> {
+  service.set_preferences(data.into_inner())?;
+  Ok(())
+}
@@ -0,0 +1,64 @@
+use crate::entities::{
+  parse_macro_name, MacroNamePB, MacroPB, RepeatedMacroPB, RepeatedMacroStepResultPB,
+  RunMacroParams, RunMacroPayloadPB,
+};
+use crate::{errors::FlowyError, services::MacroService};
+use lib_dispatch::prelude::*;
+use std::{convert::TryInto, sync::Arc};
+
+#[tracing::instrument(level = "debug", skip(data, service), err)]
+pub async fn start_macro_recording_handler(
+  data: AFPluginData<MacroNamePB>,
+  service: AFPluginState<Arc<MacroService>>,
+) -> Result<(), FlowyError> {
+  let name: String = data.into_inner().try_into()?;
+  service.start_recording(name)?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(service), err)]
+pub async fn stop_macro_recording_handler(
+  service: AFPluginState<Arc<MacroService>>,
+) -> DataResult<MacroPB, FlowyError> {
+  let user_macro = service.stop_recording()?;
+  data_result_ok(user_macro)
+}
+
+#[tracing::instrument(level = "debug", skip(data, service), err)]
+pub async fn save_macro_handler(
+  data: AFPluginData<MacroPB>,
+  service: AFPluginState<Arc<MacroService>>,
+) -> DataResult<MacroPB, FlowyError> {
+  let mut user_macro = data.into_inner();
+  user_macro.name = parse_macro_name(user_macro.name)?;
+  let user_macro = service.save_macro(user_macro)?;
+  data_result_ok(user_macro)
+}
+
+#[tracing::instrument(level = "debug", skip(service), err)]
+pub async fn get_macros_handler(
+  service: AFPluginState<Arc<MacroService>>,
+) -> DataResult<RepeatedMacroPB, FlowyError> {
+  let items = service.get_macros()?;
+  data_result_ok(RepeatedMacroPB { items })
+}
+
+#[tracing::instrument(level = "debug", skip(data, service), err)]
+pub async fn delete_macro_handler(
+  data: AFPluginData<MacroNamePB>,
+  service: AFPluginState<Arc<MacroService>>,
+) -> Result<(), FlowyError> {
+  let name: String = data.into_inner().try_into()?;
+  service.delete_macro(&name)?;
+  Ok(())
+}
+
+#[tracing::instrument(level = "debug", skip(data, service), err)]
+pub async fn run_macro_handler(
+  data: AFPluginData<RunMacroPayloadPB>,
+  service: AFPluginState<Arc<MacroService>>,
+) -> DataResult<RepeatedMacroStepResultPB, FlowyError> {
+  let params: RunMacroParams = data.into_inner().try_into()?;
+  let items = service.run_macro(params).await?;
+  data_result_ok(RepeatedMacroStepResultPB { items })
+}
@@ -1,5 +1,9 @@
 mod auth_handler;
+mod inbox_handler;
+mod macro_handler;
 mod user_handler;
 
 pub use auth_handler::*;
+pub use inbox_handler::*;
+pub use macro_handler::*;
 pub use user_handler::*;
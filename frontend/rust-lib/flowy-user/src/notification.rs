@@ -7,6 +7,10 @@ pub(crate) enum UserNotification {
   Unknown = 0,
   DidUserSignIn = 1,
   DidUpdateUserProfile = 2,
+  /// The number of the unread notifications in the inbox may have changed
+  DidUpdateInboxBadge = 3,
+  /// A notification arrived that is not muted by the notification preferences
+  DidReceiveInboxNotification = 4,
 }
 
 impl std::default::Default for UserNotification {
@@ -0,0 +1,212 @@
+use crate::entities::{
+  AddInboxNotificationParams, InboxBadgePB, InboxNotificationKindPB, InboxNotificationPB,
+  NotificationPreferencesPB,
+};
+use crate::errors::FlowyResult;
+use crate::notification::{send_notification, UserNotification};
+use crate::services::UserSession;
+use chrono::{Local, Timelike};
+use flowy_notification::NotificationFilter;
+use flowy_sqlite::{
+  kv::KV,
+  query_dsl::*,
+  schema::{inbox_table, inbox_table::dsl},
+  ExpressionMethods,
+};
+use lib_infra::id_gen::gen_id;
+use lib_infra::util::timestamp;
+use std::sync::Arc;
+
+const NOTIFICATION_PREFERENCES_KEY: &str = "notification_preferences";
+
+/// Keeps the notifications of the current user, such as the fired reminders, the mentions, the
+/// comments and the shares, until they get cleared. The badge of the inbox is notified whenever
+/// the number of the unread notifications may have changed.
+///
+/// The arriving notification is shown unless it's muted by the [NotificationPreferencesPB] of
+/// the user, the muted notification is only kept in the inbox. The preferences are checked when
+/// the notification is dispatched, see [InboxNotificationFilter].
+pub struct InboxService {
+  user_session: Arc<UserSession>,
+}
+
+#[derive(PartialEq, Clone, Debug, Queryable, Identifiable, Insertable)]
+#[table_name = "inbox_table"]
+struct InboxTable {
+  id: String,
+  kind: i32,
+  title: String,
+  body: String,
+  object_id: String,
+  is_read: bool,
+  created_at: i64,
+}
+
+impl std::convert::From<InboxTable> for InboxNotificationPB {
+  fn from(table: InboxTable) -> Self {
+    Self {
+      id: table.id,
+      kind: InboxNotificationKindPB::from(table.kind),
+      title: table.title,
+      body: table.body,
+      object_id: table.object_id,
+      is_read: table.is_read,
+      created_at: table.created_at,
+    }
+  }
+}
+
+impl InboxService {
+  pub fn new(user_session: Arc<UserSession>) -> Self {
+    Self { user_session }
+  }
+
+  pub fn add_notification(
+    &self,
+    params: AddInboxNotificationParams,
+  ) -> FlowyResult<InboxNotificationPB> {
+    let kind = params.kind;
+    let table = InboxTable {
+      id: gen_id(),
+      kind: params.kind as i32,
+      title: params.title,
+      body: params.body,
+      object_id: params.object_id,
+      is_read: false,
+      created_at: timestamp(),
+    };
+    let _ = diesel::insert_into(inbox_table::table)
+      .values(table.clone())
+      .execute(&*self.user_session.db_connection()?)?;
+    self.notify_badge()?;
+
+    let notification = InboxNotificationPB::from(table);
+    send_notification(
+      &self.user_session.user_id()?,
+      UserNotification::DidReceiveInboxNotification,
+    )
+    .category(kind as i32)
+    .payload(notification.clone())
+    .send();
+    Ok(notification)
+  }
+
+  /// Returns the notifications, the most recently added notification comes first.
+  pub fn get_notifications(&self, unread_only: bool) -> FlowyResult<Vec<InboxNotificationPB>> {
+    let conn = self.user_session.db_connection()?;
+    let mut query = dsl::inbox_table.into_boxed();
+    if unread_only {
+      query = query.filter(inbox_table::is_read.eq(false));
+    }
+    let notifications = query
+      .order(inbox_table::created_at.desc())
+      .load::<InboxTable>(&*conn)?
+      .into_iter()
+      .map(InboxNotificationPB::from)
+      .collect();
+    Ok(notifications)
+  }
+
+  /// Marks the notifications as read, all of the notifications are marked if `ids` is empty.
+  pub fn mark_read(&self, ids: &[String]) -> FlowyResult<()> {
+    let conn = self.user_session.db_connection()?;
+    if ids.is_empty() {
+      let _ = diesel::update(dsl::inbox_table)
+        .set(inbox_table::is_read.eq(true))
+        .execute(&*conn)?;
+    } else {
+      let _ = diesel::update(dsl::inbox_table.filter(inbox_table::id.eq_any(ids)))
+        .set(inbox_table::is_read.eq(true))
+        .execute(&*conn)?;
+    }
+    self.notify_badge()
+  }
+
+  /// Removes the notifications, all of the notifications are removed if `ids` is empty.
+  pub fn clear(&self, ids: &[String]) -> FlowyResult<()> {
+    let conn = self.user_session.db_connection()?;
+    if ids.is_empty() {
+      let _ = diesel::delete(dsl::inbox_table).execute(&*conn)?;
+    } else {
+      let _ =
+        diesel::delete(dsl::inbox_table.filter(inbox_table::id.eq_any(ids))).execute(&*conn)?;
+    }
+    self.notify_badge()
+  }
+
+  pub fn get_badge(&self) -> FlowyResult<InboxBadgePB> {
+    let unread_count = dsl::inbox_table
+      .filter(inbox_table::is_read.eq(false))
+      .count()
+      .get_result::<i64>(&*self.user_session.db_connection()?)?;
+    Ok(InboxBadgePB { unread_count })
+  }
+
+  pub fn get_preferences(&self) -> FlowyResult<NotificationPreferencesPB> {
+    get_preferences(&self.user_session)
+  }
+
+  pub fn set_preferences(&self, preferences: NotificationPreferencesPB) -> FlowyResult<()> {
+    preferences.validate()?;
+    let s = serde_json::to_string(&preferences)?;
+    KV::set_str(&preferences_key(&self.user_session)?, s);
+    Ok(())
+  }
+
+  /// Returns the filter that applies the notification preferences of the current user to the
+  /// dispatched notifications, see [flowy_notification::set_notification_filter].
+  pub fn notification_filter(&self) -> InboxNotificationFilter {
+    InboxNotificationFilter {
+      user_session: self.user_session.clone(),
+    }
+  }
+
+  fn notify_badge(&self) -> FlowyResult<()> {
+    let badge = self.get_badge()?;
+    send_notification(
+      &self.user_session.user_id()?,
+      UserNotification::DidUpdateInboxBadge,
+    )
+    .payload(badge)
+    .send();
+    Ok(())
+  }
+}
+
+/// Mutes the notifications whose category is an [InboxNotificationKindPB] by the notification
+/// preferences of the current user, including the quiet hours. The muted notifications are only
+/// kept in the inbox.
+pub struct InboxNotificationFilter {
+  user_session: Arc<UserSession>,
+}
+
+impl NotificationFilter for InboxNotificationFilter {
+  fn is_muted(&self, category: i32) -> bool {
+    match get_preferences(&self.user_session) {
+      Ok(preferences) => {
+        let now = Local::now();
+        let minute = (now.hour() * 60 + now.minute()) as i32;
+        preferences.is_muted(InboxNotificationKindPB::from(category), minute)
+      },
+      Err(err) => {
+        tracing::error!("Read the notification preferences failed: {:?}", err);
+        false
+      },
+    }
+  }
+}
+
+fn get_preferences(user_session: &UserSession) -> FlowyResult<NotificationPreferencesPB> {
+  match KV::get_str(&preferences_key(user_session)?) {
+    None => Ok(NotificationPreferencesPB::default()),
+    Some(s) => Ok(serde_json::from_str(&s)?),
+  }
+}
+
+fn preferences_key(user_session: &UserSession) -> FlowyResult<String> {
+  Ok(format!(
+    "{}:{}",
+    NOTIFICATION_PREFERENCES_KEY,
+    user_session.user_id()?
+  ))
+}
@@ -0,0 +1,388 @@
+use crate::entities::{MacroPB, MacroStepPB, MacroStepResultPB, RunMacroParams};
+use crate::errors::{FlowyError, FlowyResult};
+use crate::event_map::UserEvent;
+use crate::services::UserSession;
+use flowy_error::internal_error;
+use flowy_sqlite::kv::KV;
+use futures::future::BoxFuture;
+use lib_dispatch::prelude::{
+  AFPluginDispatcher, AFPluginEvent, AFPluginEventResponse, AFPluginInterceptor, AFPluginRequest,
+  DispatchError, StatusCode,
+};
+use parking_lot::{Mutex, RwLock};
+use protobuf::well_known_types::Empty;
+use protobuf::{Message, UnknownFields};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::{Arc, Weak};
+
+const MACROS_CACHE_KEY: &str = "user_macros";
+
+/// The events that manage the macros are never recorded into a macro.
+const MACRO_EVENTS: [UserEvent; 6] = [
+  UserEvent::StartMacroRecording,
+  UserEvent::StopMacroRecording,
+  UserEvent::SaveMacro,
+  UserEvent::GetMacros,
+  UserEvent::DeleteMacro,
+  UserEvent::RunMacro,
+];
+
+/// Records a named sequence of events and replays it as one command. The macros are saved per
+/// user.
+///
+/// A string field of the recorded payloads can be turned into a slot by setting its value to
+/// `{{slot_name}}`, the value of the slot is passed in when running the macro. The slot
+/// `{{$step.field}}` is filled with the string field of a previous step's output, e.g.
+/// `{{$0.1}}` is the field with index 1 of the first step's output. It's used to chain the
+/// events, such as opening the row that was created by the previous step.
+pub struct MacroService {
+  user_session: Arc<UserSession>,
+  dispatcher: RwLock<Option<Weak<AFPluginDispatcher>>>,
+  recording: Arc<Mutex<Option<MacroPB>>>,
+}
+
+impl MacroService {
+  pub fn new(user_session: Arc<UserSession>) -> Self {
+    Self {
+      user_session,
+      dispatcher: RwLock::new(None),
+      recording: Arc::new(Mutex::new(None)),
+    }
+  }
+
+  /// The dispatcher is constructed after the plugins, so it's set once it's ready.
+  pub fn set_dispatcher(&self, dispatcher: &Arc<AFPluginDispatcher>) {
+    *self.dispatcher.write() = Some(Arc::downgrade(dispatcher));
+  }
+
+  /// The interceptor that records the dispatched events while recording. Only the events that
+  /// `is_mutating_event` returns true for are recorded.
+  pub fn recorder<F>(&self, is_mutating_event: F) -> MacroRecorder
+  where
+    F: Fn(&AFPluginEvent) -> bool + Send + Sync + 'static,
+  {
+    MacroRecorder {
+      recording: self.recording.clone(),
+      is_mutating_event: Box::new(is_mutating_event),
+    }
+  }
+
+  pub fn start_recording(&self, name: String) -> FlowyResult<()> {
+    let mut recording = self.recording.lock();
+    if let Some(user_macro) = recording.as_ref() {
+      return Err(
+        FlowyError::invalid_data().context(format!("Macro {} is recording", user_macro.name)),
+      );
+    }
+    *recording = Some(MacroPB {
+      name,
+      ..Default::default()
+    });
+    Ok(())
+  }
+
+  /// Stops the recording and saves the recorded macro.
+  pub fn stop_recording(&self) -> FlowyResult<MacroPB> {
+    let user_macro = self
+      .recording
+      .lock()
+      .take()
+      .ok_or_else(|| FlowyError::invalid_data().context("No macro is recording"))?;
+    self.save_macro(user_macro)
+  }
+
+  /// Saves the macro, the macro with the same name will be replaced.
+  pub fn save_macro(&self, mut user_macro: MacroPB) -> FlowyResult<MacroPB> {
+    user_macro.slots = collect_slots(&user_macro.steps);
+    let mut macros = self.get_macros()?;
+    match macros.iter_mut().find(|item| item.name == user_macro.name) {
+      None => macros.push(user_macro.clone()),
+      Some(item) => *item = user_macro.clone(),
+    }
+    self.set_macros(&macros)?;
+    Ok(user_macro)
+  }
+
+  pub fn get_macros(&self) -> FlowyResult<Vec<MacroPB>> {
+    match KV::get_str(&self.cache_key()?) {
+      None => Ok(vec![]),
+      Some(s) => Ok(serde_json::from_str(&s)?),
+    }
+  }
+
+  pub fn delete_macro(&self, name: &str) -> FlowyResult<()> {
+    let mut macros = self.get_macros()?;
+    let len = macros.len();
+    macros.retain(|item| item.name != name);
+    if macros.len() == len {
+      return Err(FlowyError::record_not_found().context(format!("Can't find macro {}", name)));
+    }
+    self.set_macros(&macros)
+  }
+
+  /// Dispatches the events of the macro one by one. It stops at the first failed event, the
+  /// result of each dispatched event is returned.
+  pub async fn run_macro(&self, params: RunMacroParams) -> FlowyResult<Vec<MacroStepResultPB>> {
+    let dispatcher = self
+      .dispatcher
+      .read()
+      .as_ref()
+      .and_then(Weak::upgrade)
+      .ok_or_else(|| FlowyError::internal().context("The dispatcher is not ready"))?;
+    let user_macro = self
+      .get_macros()?
+      .into_iter()
+      .find(|item| item.name == params.name)
+      .ok_or_else(|| {
+        FlowyError::record_not_found().context(format!("Can't find macro {}", params.name))
+      })?;
+
+    let mut outputs: Vec<Vec<u8>> = vec![];
+    let mut results = vec![];
+    for step in user_macro.steps {
+      let fill = |slot: &str| slot_value(slot, &params.args, &outputs);
+      let payload = match fill_slots(&step.payload, &fill)? {
+        Some(payload) => payload,
+        None => step.payload,
+      };
+
+      let mut request = AFPluginRequest::new(AFPluginEvent(step.event.clone()));
+      if !payload.is_empty() {
+        request = request.payload(payload);
+      }
+      let response = AFPluginDispatcher::async_send(dispatcher.clone(), request).await;
+      let is_ok = response.status_code == StatusCode::Ok;
+      let output = response.payload.to_vec();
+      results.push(MacroStepResultPB {
+        event: step.event,
+        is_ok,
+        payload: output.clone(),
+      });
+      if !is_ok {
+        break;
+      }
+      outputs.push(output);
+    }
+    Ok(results)
+  }
+
+  fn set_macros(&self, macros: &[MacroPB]) -> FlowyResult<()> {
+    let s = serde_json::to_string(macros)?;
+    KV::set_str(&self.cache_key()?, s);
+    Ok(())
+  }
+
+  fn cache_key(&self) -> FlowyResult<String> {
+    Ok(format!(
+      "{}:{}",
+      MACROS_CACHE_KEY,
+      self.user_session.user_id()?
+    ))
+  }
+}
+
+/// Appends the events that changed the data to the recording macro once they succeeded. The
+/// events that only read the data and the failed events are not replayed.
+pub struct MacroRecorder {
+  recording: Arc<Mutex<Option<MacroPB>>>,
+  is_mutating_event: Box<dyn Fn(&AFPluginEvent) -> bool + Send + Sync>,
+}
+
+impl AFPluginInterceptor for MacroRecorder {
+  fn intercept(&self, _request: &AFPluginRequest) -> BoxFuture<'static, Result<(), DispatchError>> {
+    Box::pin(async { Ok(()) })
+  }
+
+  fn did_handle(&self, request: &AFPluginRequest, response: &AFPluginEventResponse) {
+    if response.status_code != StatusCode::Ok {
+      return;
+    }
+    if let Some(user_macro) = self.recording.lock().as_mut() {
+      let is_macro_event = MACRO_EVENTS
+        .iter()
+        .any(|event| AFPluginEvent::from(*event) == request.event);
+      if !is_macro_event && (self.is_mutating_event)(&request.event) {
+        user_macro.steps.push(MacroStepPB {
+          event: request.event.0.clone(),
+          payload: request.get_payload().clone().to_vec(),
+        });
+      }
+    }
+  }
+}
+
+fn slot_value(
+  slot: &str,
+  args: &HashMap<String, String>,
+  outputs: &[Vec<u8>],
+) -> FlowyResult<Vec<u8>> {
+  let not_found = || FlowyError::invalid_data().context(format!("Missing the slot {}", slot));
+  match slot.strip_prefix('$') {
+    None => args
+      .get(slot)
+      .map(|value| value.as_bytes().to_vec())
+      .ok_or_else(not_found),
+    Some(output_slot) => {
+      let (step, field) = output_slot.split_once('.').ok_or_else(not_found)?;
+      let step = step.parse::<usize>().map_err(|_| not_found())?;
+      let field = field.parse::<u32>().map_err(|_| not_found())?;
+      outputs
+        .get(step)
+        .and_then(|output| find_field(output, field))
+        .ok_or_else(not_found)
+    },
+  }
+}
+
+/// Returns the names of the slots that should be passed in when running the macro.
+fn collect_slots(steps: &[MacroStepPB]) -> Vec<String> {
+  let slots = RefCell::new(Vec::<String>::new());
+  let collect = |slot: &str| -> FlowyResult<Vec<u8>> {
+    let mut slots = slots.borrow_mut();
+    if !slot.starts_with('$') && !slots.iter().any(|item| item == slot) {
+      slots.push(slot.to_owned());
+    }
+    Ok(vec![])
+  };
+  for step in steps {
+    let _ = fill_slots(&step.payload, &collect);
+  }
+  slots.into_inner()
+}
+
+/// Replaces the length-delimited fields whose value is `{{slot_name}}` with the value of the
+/// slot, the nested messages are filled too. The messages are decoded as [Empty], so all their
+/// fields are kept as the unknown fields no matter which message the payload is.
+///
+/// Returns None if the bytes are not a protobuf message or they don't contain any slot.
+fn fill_slots(
+  message: &[u8],
+  slot_value: &dyn Fn(&str) -> FlowyResult<Vec<u8>>,
+) -> FlowyResult<Option<Vec<u8>>> {
+  let message = match Empty::parse_from_bytes(message) {
+    Ok(message) => message,
+    Err(_) => return Ok(None),
+  };
+
+  let mut is_filled = false;
+  let mut fields = UnknownFields::new();
+  for (number, values) in message.get_unknown_fields().iter() {
+    for value in &values.varint {
+      fields.add_varint(number, *value);
+    }
+    for value in &values.fixed32 {
+      fields.add_fixed32(number, *value);
+    }
+    for value in &values.fixed64 {
+      fields.add_fixed64(number, *value);
+    }
+    for value in &values.length_delimited {
+      let filled_value = match slot_name(value) {
+        Some(slot) => Some(slot_value(slot)?),
+        None => fill_slots(value, slot_value)?,
+      };
+      match filled_value {
+        None => fields.add_length_delimited(number, value.clone()),
+        Some(filled_value) => {
+          is_filled = true;
+          fields.add_length_delimited(number, filled_value);
+        },
+      }
+    }
+  }
+
+  if !is_filled {
+    return Ok(None);
+  }
+  let mut filled_message = Empty::new();
+  *filled_message.mut_unknown_fields() = fields;
+  let bytes = filled_message.write_to_bytes().map_err(internal_error)?;
+  Ok(Some(bytes))
+}
+
+fn slot_name(value: &[u8]) -> Option<&str> {
+  let value = std::str::from_utf8(value).ok()?;
+  let slot = value.strip_prefix("{{")?.strip_suffix("}}")?.trim();
+  if slot.is_empty() || slot.contains(['{', '}']) {
+    return None;
+  }
+  Some(slot)
+}
+
+/// Returns the value of the first length-delimited field with the number in the message.
+fn find_field(message: &[u8], number: u32) -> Option<Vec<u8>> {
+  Empty::parse_from_bytes(message)
+    .ok()?
+    .get_unknown_fields()
+    .get(number)?
+    .length_delimited
+    .first()
+    .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn message(varints: &[(u32, u64)], strings: &[(u32, &[u8])]) -> Vec<u8> {
+    let mut message = Empty::new();
+    for (number, value) in varints {
+      message.mut_unknown_fields().add_varint(*number, *value);
+    }
+    for (number, value) in strings {
+      message
+        .mut_unknown_fields()
+        .add_length_delimited(*number, value.to_vec());
+    }
+    message.write_to_bytes().unwrap()
+  }
+
+  #[test]
+  fn fill_nested_slot_test() {
+    let nested = message(&[(2, 7)], &[(1, b"{{tag}}")]);
+    let payload = message(&[], &[(1, b"view"), (3, &nested)]);
+    let args = HashMap::from([("tag".to_owned(), "a long tag name".to_owned())]);
+    let filled = fill_slots(&payload, &|slot| slot_value(slot, &args, &[]))
+      .unwrap()
+      .unwrap();
+
+    assert_eq!(find_field(&filled, 1).unwrap(), b"view");
+    let filled_nested = Empty::parse_from_bytes(&find_field(&filled, 3).unwrap()).unwrap();
+    let fields = filled_nested.get_unknown_fields();
+    assert_eq!(
+      fields.get(1).unwrap().length_delimited,
+      vec![b"a long tag name".to_vec()]
+    );
+    assert_eq!(fields.get(2).unwrap().varint, vec![7]);
+    assert_eq!(
+      collect_slots(&[MacroStepPB {
+        event: "CreateRow".to_owned(),
+        payload,
+      }]),
+      vec!["tag".to_owned()]
+    );
+  }
+
+  #[test]
+  fn fill_slot_with_previous_output_test() {
+    let output = message(&[(2, 1)], &[(1, b"row_id")]);
+    let payload = message(&[], &[(2, b"{{$0.1}}")]);
+    let filled = fill_slots(&payload, &|slot| {
+      slot_value(slot, &HashMap::new(), &[output.clone()])
+    })
+    .unwrap()
+    .unwrap();
+    assert_eq!(filled, message(&[], &[(2, b"row_id")]));
+
+    let missing = fill_slots(&payload, &|slot| slot_value(slot, &HashMap::new(), &[]));
+    assert!(missing.is_err());
+  }
+
+  #[test]
+  fn payload_without_slots_is_not_changed_test() {
+    let payload = message(&[(2, 1)], &[(1, b"view")]);
+    let filled = fill_slots(&payload, &|slot| slot_value(slot, &HashMap::new(), &[])).unwrap();
+    assert!(filled.is_none());
+  }
+}
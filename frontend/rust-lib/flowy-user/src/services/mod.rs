@@ -1,3 +1,7 @@
 pub mod database;
+mod inbox;
+mod macros;
 mod user_session;
+pub use inbox::*;
+pub use macros::*;
 pub use user_session::*;
@@ -58,13 +58,16 @@ impl UserSession {
     }
   }
 
+  /// The callback is set before the current session gets initialized, so the user events don't
+  /// need to wait until the managers are initialized.
   pub async fn init<C: UserStatusCallback + 'static>(&self, user_status_callback: C) {
+    let user_status_callback = Arc::new(user_status_callback);
+    *self.user_status_callback.write().await = Some(user_status_callback.clone());
     if let Ok(session) = self.get_session() {
       let _ = user_status_callback
         .did_sign_in(&session.token, &session.user_id)
         .await;
     }
-    *self.user_status_callback.write().await = Some(Arc::new(user_status_callback));
   }
 
   pub fn db_connection(&self) -> Result<DBConnection, FlowyError> {
@@ -0,0 +1,176 @@
+use flowy_test::{event_builder::UserModuleEventBuilder, FlowySDKTest};
+use flowy_user::entities::{
+  AddInboxNotificationPayloadPB, GetInboxNotificationsPayloadPB, InboxBadgePB,
+  InboxNotificationIdsPB, InboxNotificationKindPB, InboxNotificationPB,
+  NotificationCategoryPreferencePB, NotificationPreferencesPB, RepeatedInboxNotificationPB,
+};
+use flowy_user::errors::ErrorCode;
+use flowy_user::event_map::UserEvent::*;
+
+async fn add_notification(
+  sdk: &FlowySDKTest,
+  kind: InboxNotificationKindPB,
+  title: &str,
+) -> InboxNotificationPB {
+  UserModuleEventBuilder::new(sdk.clone())
+    .event(AddInboxNotification)
+    .payload(AddInboxNotificationPayloadPB {
+      kind,
+      title: title.to_owned(),
+      body: "".to_owned(),
+      object_id: "row_1".to_owned(),
+    })
+    .async_send()
+    .await
+    .parse::<InboxNotificationPB>()
+}
+
+async fn get_notifications(sdk: &FlowySDKTest, unread_only: bool) -> Vec<InboxNotificationPB> {
+  UserModuleEventBuilder::new(sdk.clone())
+    .event(GetInboxNotifications)
+    .payload(GetInboxNotificationsPayloadPB { unread_only })
+    .async_send()
+    .await
+    .parse::<RepeatedInboxNotificationPB>()
+    .items
+}
+
+async fn get_unread_count(sdk: &FlowySDKTest) -> i64 {
+  UserModuleEventBuilder::new(sdk.clone())
+    .event(GetInboxBadge)
+    .async_send()
+    .await
+    .parse::<InboxBadgePB>()
+    .unread_count
+}
+
+#[tokio::test]
+async fn inbox_mark_read_test() {
+  let sdk = FlowySDKTest::default();
+  let _ = sdk.init_user().await;
+  let mention = add_notification(&sdk, InboxNotificationKindPB::Mention, "Mentioned").await;
+  let _ = add_notification(&sdk, InboxNotificationKindPB::Share, "Shared").await;
+  assert_eq!(get_unread_count(&sdk).await, 2);
+
+  let _ = UserModuleEventBuilder::new(sdk.clone())
+    .event(MarkInboxNotificationsRead)
+    .payload(InboxNotificationIdsPB {
+      ids: vec![mention.id.clone()],
+    })
+    .async_send()
+    .await;
+  assert_eq!(get_unread_count(&sdk).await, 1);
+  let unread = get_notifications(&sdk, true).await;
+  assert_eq!(unread.len(), 1);
+  assert_eq!(unread[0].kind, InboxNotificationKindPB::Share);
+  let notifications = get_notifications(&sdk, false).await;
+  assert_eq!(notifications.len(), 2);
+  assert!(
+    notifications
+      .iter()
+      .find(|notification| notification.id == mention.id)
+      .unwrap()
+      .is_read
+  );
+
+  // All of the notifications are marked without the ids
+  let _ = UserModuleEventBuilder::new(sdk.clone())
+    .event(MarkInboxNotificationsRead)
+    .payload(InboxNotificationIdsPB { ids: vec![] })
+    .async_send()
+    .await;
+  assert_eq!(get_unread_count(&sdk).await, 0);
+}
+
+#[tokio::test]
+async fn inbox_clear_test() {
+  let sdk = FlowySDKTest::default();
+  let _ = sdk.init_user().await;
+  let reminder = add_notification(&sdk, InboxNotificationKindPB::Reminder, "Due today").await;
+  let _ = add_notification(&sdk, InboxNotificationKindPB::Comment, "Replied").await;
+
+  let _ = UserModuleEventBuilder::new(sdk.clone())
+    .event(ClearInboxNotifications)
+    .payload(InboxNotificationIdsPB {
+      ids: vec![reminder.id],
+    })
+    .async_send()
+    .await;
+  let notifications = get_notifications(&sdk, false).await;
+  assert_eq!(notifications.len(), 1);
+  assert_eq!(notifications[0].title, "Replied");
+  assert_eq!(get_unread_count(&sdk).await, 1);
+
+  let _ = UserModuleEventBuilder::new(sdk.clone())
+    .event(ClearInboxNotifications)
+    .payload(InboxNotificationIdsPB { ids: vec![] })
+    .async_send()
+    .await;
+  assert!(get_notifications(&sdk, false).await.is_empty());
+}
+
+#[tokio::test]
+async fn inbox_add_notification_without_title_test() {
+  let sdk = FlowySDKTest::default();
+  let _ = sdk.init_user().await;
+  let error = UserModuleEventBuilder::new(sdk.clone())
+    .event(AddInboxNotification)
+    .payload(AddInboxNotificationPayloadPB {
+      title: " ".to_owned(),
+      ..Default::default()
+    })
+    .async_send()
+    .await
+    .error();
+  assert_eq!(error.code, ErrorCode::InboxTitleIsEmpty.value());
+}
+
+#[tokio::test]
+async fn notification_preferences_test() {
+  let sdk = FlowySDKTest::default();
+  let _ = sdk.init_user().await;
+  let preferences = NotificationPreferencesPB {
+    categories: vec![NotificationCategoryPreferencePB {
+      kind: InboxNotificationKindPB::SyncError,
+      is_muted: true,
+    }],
+    quiet_hours_enabled: true,
+    // 22:00 - 07:00
+    quiet_hours_start: 22 * 60,
+    quiet_hours_end: 7 * 60,
+    ..Default::default()
+  };
+  let _ = UserModuleEventBuilder::new(sdk.clone())
+    .event(SetNotificationPreferences)
+    .payload(preferences)
+    .async_send()
+    .await
+    .assert_success();
+  let preferences = UserModuleEventBuilder::new(sdk.clone())
+    .event(GetNotificationPreferences)
+    .async_send()
+    .await
+    .parse::<NotificationPreferencesPB>();
+
+  let noon = 12 * 60;
+  assert!(preferences.is_muted(InboxNotificationKindPB::SyncError, noon));
+  assert!(!preferences.is_muted(InboxNotificationKindPB::Mention, noon));
+  assert!(preferences.is_muted(InboxNotificationKindPB::Mention, 23 * 60));
+  assert!(preferences.is_muted(InboxNotificationKindPB::Mention, 6 * 60));
+  assert!(!preferences.is_muted(InboxNotificationKindPB::Mention, 7 * 60));
+
+  // The muted notifications are still kept in the inbox
+  let _ = add_notification(&sdk, InboxNotificationKindPB::SyncError, "Sync failed").await;
+  assert_eq!(get_unread_count(&sdk).await, 1);
+
+  let error = UserModuleEventBuilder::new(sdk.clone())
+    .event(SetNotificationPreferences)
+    .payload(NotificationPreferencesPB {
+      quiet_hours_start: 24 * 60,
+      ..Default::default()
+    })
+    .async_send()
+    .await
+    .error();
+  assert_eq!(error.code, ErrorCode::InvalidData.value());
+}
@@ -0,0 +1,134 @@
+use bytes::Bytes;
+use flowy_folder::entities::workspace::{CreateWorkspacePayloadPB, WorkspaceIdPB};
+use flowy_folder::event_map::FolderEvent;
+use flowy_test::{
+  event_builder::{FolderEventBuilder, UserModuleEventBuilder},
+  FlowySDKTest,
+};
+use flowy_user::entities::{
+  MacroNamePB, MacroPB, MacroStepPB, RepeatedMacroPB, RepeatedMacroStepResultPB, RunMacroPayloadPB,
+  UpdateUserProfilePayloadPB, UserProfilePB,
+};
+use flowy_user::errors::ErrorCode;
+use flowy_user::event_map::UserEvent::*;
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+fn macro_name(name: &str) -> MacroNamePB {
+  MacroNamePB {
+    name: name.to_owned(),
+  }
+}
+
+#[tokio::test]
+async fn macro_record_test() {
+  let sdk = FlowySDKTest::default();
+  let _ = sdk.init_user().await;
+  let _ = UserModuleEventBuilder::new(sdk.clone())
+    .event(StartMacroRecording)
+    .payload(macro_name("rename"))
+    .sync_send()
+    .assert_success();
+  let _ = FolderEventBuilder::new(sdk.clone())
+    .event(FolderEvent::CreateWorkspace)
+    .payload(CreateWorkspacePayloadPB {
+      name: "recorded".to_owned(),
+      desc: "".to_owned(),
+    })
+    .sync_send()
+    .assert_success();
+  // The events that only read the data are not recorded
+  let _ = FolderEventBuilder::new(sdk.clone())
+    .event(FolderEvent::ReadWorkspaces)
+    .payload(WorkspaceIdPB::new(None))
+    .sync_send()
+    .assert_success();
+  // The failed events are not recorded
+  let _ = FolderEventBuilder::new(sdk.clone())
+    .event(FolderEvent::CreateWorkspace)
+    .payload(CreateWorkspacePayloadPB {
+      name: "".to_owned(),
+      desc: "".to_owned(),
+    })
+    .sync_send()
+    .error();
+  let user_macro = UserModuleEventBuilder::new(sdk.clone())
+    .event(StopMacroRecording)
+    .sync_send()
+    .parse::<MacroPB>();
+  assert_eq!(user_macro.steps.len(), 1);
+  assert_eq!(
+    user_macro.steps[0].event,
+    FolderEvent::CreateWorkspace.to_string()
+  );
+
+  let macros = UserModuleEventBuilder::new(sdk.clone())
+    .event(GetMacros)
+    .sync_send()
+    .parse::<RepeatedMacroPB>();
+  assert_eq!(macros.items.len(), 1);
+
+  let _ = UserModuleEventBuilder::new(sdk.clone())
+    .event(DeleteMacro)
+    .payload(macro_name("rename"))
+    .sync_send()
+    .assert_success();
+  let macros = UserModuleEventBuilder::new(sdk)
+    .event(GetMacros)
+    .sync_send()
+    .parse::<RepeatedMacroPB>();
+  assert!(macros.items.is_empty());
+}
+
+#[tokio::test]
+async fn macro_run_with_slot_test() {
+  let sdk = FlowySDKTest::default();
+  let user = sdk.init_user().await;
+  let payload: Bytes = UpdateUserProfilePayloadPB::new(&user.id)
+    .name("{{name}}")
+    .try_into()
+    .unwrap();
+  let user_macro = UserModuleEventBuilder::new(sdk.clone())
+    .event(SaveMacro)
+    .payload(MacroPB {
+      name: "rename".to_owned(),
+      steps: vec![MacroStepPB {
+        event: UpdateUserProfile.to_string(),
+        payload: payload.to_vec(),
+      }],
+      slots: vec![],
+    })
+    .sync_send()
+    .parse::<MacroPB>();
+  assert_eq!(user_macro.slots, vec!["name".to_owned()]);
+
+  let results = UserModuleEventBuilder::new(sdk.clone())
+    .event(RunMacro)
+    .payload(RunMacroPayloadPB {
+      name: "rename".to_owned(),
+      args: HashMap::from([("name".to_owned(), "macro".to_owned())]),
+    })
+    .async_send()
+    .await
+    .parse::<RepeatedMacroStepResultPB>();
+  assert_eq!(results.items.len(), 1);
+  assert!(results.items[0].is_ok);
+
+  let user_profile = UserModuleEventBuilder::new(sdk.clone())
+    .event(GetUserProfile)
+    .sync_send()
+    .parse::<UserProfilePB>();
+  assert_eq!(user_profile.name, "macro");
+
+  // The value of the slot is not passed in
+  let error = UserModuleEventBuilder::new(sdk)
+    .event(RunMacro)
+    .payload(RunMacroPayloadPB {
+      name: "rename".to_owned(),
+      args: HashMap::new(),
+    })
+    .async_send()
+    .await
+    .error();
+  assert_eq!(error.code, ErrorCode::InvalidData.value());
+}
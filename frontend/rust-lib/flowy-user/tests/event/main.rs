@@ -1,3 +1,5 @@
 mod auth_test;
 mod helper;
+mod inbox_test;
+mod macro_test;
 mod user_profile_test;
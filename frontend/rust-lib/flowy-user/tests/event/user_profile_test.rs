@@ -1,7 +1,10 @@
 use crate::helper::*;
 use flowy_test::{event_builder::UserModuleEventBuilder, FlowySDKTest};
 use flowy_user::entities::{UpdateUserProfilePayloadPB, UserProfilePB};
-use flowy_user::{errors::ErrorCode, event_map::UserEvent::*};
+use flowy_user::{
+  errors::{ErrorCode, ErrorRecovery},
+  event_map::UserEvent::*,
+};
 use nanoid::nanoid;
 
 // use serial_test::*;
@@ -14,7 +17,12 @@ async fn user_profile_get_failed() {
     .assert_error()
     .async_send()
     .await;
-  assert!(result.user_profile().is_none())
+  assert!(result.user_profile().is_none());
+
+  // The user needs to sign in
+  let error = result.error();
+  assert_eq!(error.code, ErrorCode::UserUnauthorized.value());
+  assert_eq!(error.recovery, ErrorRecovery::ReAuthenticate);
 }
 
 #[tokio::test]
@@ -0,0 +1,76 @@
+use crate::response::StatusCode;
+use std::{
+  collections::VecDeque,
+  sync::{Arc, Mutex},
+  time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[derive(Clone, Debug)]
+pub struct EventAuditRecord {
+  /// The id of the request, it's recorded as the `operation_id` of the logs of the event.
+  pub operation_id: String,
+  pub event: String,
+  pub payload_size: usize,
+  pub duration: Duration,
+  pub status_code: StatusCode,
+  /// The unix timestamp, in seconds, when the event was dispatched.
+  pub timestamp: i64,
+}
+
+/// [EventAuditLog] is a ring buffer that keeps the last N events dispatched by the
+/// `AFPluginDispatcher`. The oldest record will be dropped when the buffer is full.
+///
+/// It's opt-in. The records can be dumped and attached to the bug reports.
+#[derive(Clone)]
+pub struct EventAuditLog {
+  capacity: usize,
+  records: Arc<Mutex<VecDeque<EventAuditRecord>>>,
+}
+
+impl EventAuditLog {
+  /// Creates a new [EventAuditLog]. Nothing will be recorded if the `capacity` is zero.
+  pub fn new(capacity: usize) -> Self {
+    Self {
+      capacity,
+      records: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+    }
+  }
+
+  pub fn is_enabled(&self) -> bool {
+    self.capacity > 0
+  }
+
+  pub(crate) fn record(&self, record: EventAuditRecord) {
+    if !self.is_enabled() {
+      return;
+    }
+
+    if let Ok(mut records) = self.records.lock() {
+      if records.len() >= self.capacity {
+        records.pop_front();
+      }
+      records.push_back(record);
+    }
+  }
+
+  /// Returns the records, the oldest one first.
+  pub fn records(&self) -> Vec<EventAuditRecord> {
+    match self.records.lock() {
+      Ok(records) => records.iter().cloned().collect(),
+      Err(_) => vec![],
+    }
+  }
+
+  pub fn clear(&self) {
+    if let Ok(mut records) = self.records.lock() {
+      records.clear();
+    }
+  }
+}
+
+pub(crate) fn timestamp() -> i64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_secs() as i64)
+    .unwrap_or_default()
+}
@@ -1,7 +1,11 @@
 use crate::runtime::AFPluginRuntime;
 use crate::{
+  audit::{timestamp, EventAuditLog, EventAuditRecord},
   errors::{DispatchError, Error, InternalError},
+  interceptor::{AFPluginInterceptor, AFPluginInterceptors},
   module::{as_plugin_map, AFPlugin, AFPluginMap, AFPluginRequest},
+  operation::with_operation_id,
+  replay::{EventRecord, EventRecorder},
   response::AFPluginEventResponse,
   service::{AFPluginServiceFactory, Service},
 };
@@ -9,11 +13,15 @@ use derivative::*;
 use futures_core::future::BoxFuture;
 use futures_util::task::Context;
 use pin_project::pin_project;
-use std::{future::Future, sync::Arc};
+use std::{future::Future, sync::Arc, time::Instant};
 use tokio::macros::support::{Pin, Poll};
+use tracing::Instrument;
 
 pub struct AFPluginDispatcher {
   plugins: AFPluginMap,
+  interceptors: AFPluginInterceptors,
+  audit_log: Option<EventAuditLog>,
+  recorder: Option<EventRecorder>,
   runtime: AFPluginRuntime,
 }
 
@@ -26,10 +34,35 @@ impl AFPluginDispatcher {
     tracing::trace!("{}", plugin_info(&plugins));
     AFPluginDispatcher {
       plugins: as_plugin_map(plugins),
+      interceptors: Arc::new(vec![]),
+      audit_log: None,
+      recorder: None,
       runtime,
     }
   }
 
+  /// Records each dispatched event into the [EventAuditLog].
+  pub fn audit_log(mut self, audit_log: EventAuditLog) -> Self {
+    if audit_log.is_enabled() {
+      self.audit_log = Some(audit_log);
+    }
+    self
+  }
+
+  /// Records each dispatched event and its response into the [EventRecorder], the records can
+  /// be replayed by the `EventReplayer`.
+  pub fn recorder(mut self, recorder: EventRecorder) -> Self {
+    self.recorder = Some(recorder);
+    self
+  }
+
+  /// Registers the interceptors that will be called, in the order of the vector, before each
+  /// event reaches its handler. It replaces the interceptors that were registered before.
+  pub fn interceptors(mut self, interceptors: Vec<Arc<dyn AFPluginInterceptor>>) -> Self {
+    self.interceptors = Arc::new(interceptors);
+    self
+  }
+
   pub fn async_send<Req>(
     dispatch: Arc<AFPluginDispatcher>,
     request: Req,
@@ -51,18 +84,58 @@ impl AFPluginDispatcher {
   {
     let request: AFPluginRequest = request.into();
     let plugins = dispatch.plugins.clone();
-    let service = Box::new(DispatchService { plugins });
+    let interceptors = dispatch.interceptors.clone();
+    let service = Box::new(DispatchService {
+      plugins,
+      interceptors,
+    });
     tracing::trace!("Async event: {:?}", &request.event);
+    let audit_log = dispatch.audit_log.clone();
+    let operation_id = request.id.clone();
+    let event = request.event.0.clone();
+    // Everything that the handler logs, including the tasks and the revisions it creates, is
+    // recorded within this span, so a single user action can be traced by its operation id.
+    let span = tracing::info_span!("event", operation_id = %operation_id, event = %event);
+    let payload_size = request.get_payload().len();
+    let recorder = dispatch
+      .recorder
+      .clone()
+      .map(|recorder| (recorder, event.clone(), request.get_payload().clone()));
     let service_ctx = DispatchContext {
       request,
       callback: Some(Box::new(callback)),
     };
-    let join_handle = dispatch.runtime.spawn(async move {
-      service.call(service_ctx).await.unwrap_or_else(|e| {
-        tracing::error!("Dispatch runtime error: {:?}", e);
-        InternalError::Other(format!("{:?}", e)).as_response()
-      })
-    });
+    let fut = async move {
+      let start = Instant::now();
+      let response = with_operation_id(operation_id.clone(), service.call(service_ctx))
+        .await
+        .unwrap_or_else(|e| {
+          tracing::error!("Dispatch runtime error: {:?}", e);
+          InternalError::Other(format!("{:?}", e)).as_response()
+        });
+
+      if let Some(audit_log) = audit_log {
+        audit_log.record(EventAuditRecord {
+          operation_id,
+          event,
+          payload_size,
+          duration: start.elapsed(),
+          status_code: response.status_code.clone(),
+          timestamp: timestamp(),
+        });
+      }
+
+      if let Some((recorder, event, payload)) = recorder {
+        recorder.record(EventRecord {
+          event,
+          payload: payload.to_vec(),
+          status_code: response.status_code.clone(),
+          response: response.payload.clone().to_vec(),
+        });
+      }
+      response
+    };
+    let join_handle = dispatch.runtime.spawn(fut.instrument(span));
 
     DispatchFuture {
       fut: Box::pin(async move {
@@ -131,6 +204,7 @@ impl DispatchContext {
 
 pub(crate) struct DispatchService {
   pub(crate) plugins: AFPluginMap,
+  pub(crate) interceptors: AFPluginInterceptors,
 }
 
 impl Service<DispatchContext> for DispatchService {
@@ -144,17 +218,26 @@ impl Service<DispatchContext> for DispatchService {
   )]
   fn call(&self, ctx: DispatchContext) -> Self::Future {
     let module_map = self.plugins.clone();
+    let interceptors = self.interceptors.clone();
     let (request, callback) = ctx.into_parts();
 
     Box::pin(async move {
-      let result = {
+      let result: Result<AFPluginEventResponse, DispatchError> = async {
+        for interceptor in interceptors.iter() {
+          interceptor.intercept(&request).await?;
+        }
+
         // print_module_map_info(&module_map);
         match module_map.get(&request.event) {
           Some(module) => {
             tracing::trace!("Handle event: {:?} by {:?}", &request.event, module.name);
             let fut = module.new_service(());
-            let service_fut = fut.await?.call(request);
-            service_fut.await
+            let service_fut = fut.await?.call(request.clone());
+            let response = service_fut.await?;
+            for interceptor in interceptors.iter() {
+              interceptor.did_handle(&request, &response);
+            }
+            Ok(response)
           },
           None => {
             let msg = format!("Can not find the event handler. {:?}", request);
@@ -162,7 +245,8 @@ impl Service<DispatchContext> for DispatchService {
             Err(InternalError::HandleNotFound(msg).into())
           },
         }
-      };
+      }
+      .await;
 
       let response = result.unwrap_or_else(|e| e.into());
       tracing::trace!("Dispatch result: {:?}", response);
@@ -0,0 +1,19 @@
+use crate::{errors::DispatchError, module::AFPluginRequest, response::AFPluginEventResponse};
+use futures_core::future::BoxFuture;
+use std::sync::Arc;
+
+/// An interceptor gets called before the event reaches its handler.
+///
+/// Each interceptor registered to the `AFPluginDispatcher` inspects the incoming
+/// [AFPluginRequest]. Returning an error stops the dispatching and the error will be
+/// converted into the response of the event. So the handler of the event never get called.
+///
+pub trait AFPluginInterceptor: Send + Sync {
+  fn intercept(&self, request: &AFPluginRequest) -> BoxFuture<'static, Result<(), DispatchError>>;
+
+  /// Called after the handler of the request returned its response. It's only called for the
+  /// requests that passed all the interceptors.
+  fn did_handle(&self, _request: &AFPluginRequest, _response: &AFPluginEventResponse) {}
+}
+
+pub type AFPluginInterceptors = Arc<Vec<Arc<dyn AFPluginInterceptor>>>;
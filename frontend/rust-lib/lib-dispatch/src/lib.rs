@@ -1,3 +1,4 @@
+mod audit;
 mod errors;
 mod module;
 mod request;
@@ -8,6 +9,10 @@ pub mod util;
 mod byte_trait;
 mod data;
 mod dispatcher;
+mod interceptor;
+mod operation;
+mod replay;
+mod scope;
 
 #[macro_use]
 pub mod macros;
@@ -16,7 +21,9 @@ pub mod runtime;
 pub use errors::Error;
 
 pub mod prelude {
+  pub use crate::service::LARGE_PAYLOAD_THRESHOLD;
   pub use crate::{
-    byte_trait::*, data::*, dispatcher::*, errors::*, module::*, request::*, response::*,
+    audit::*, byte_trait::*, data::*, dispatcher::*, errors::*, interceptor::*, module::*,
+    operation::current_operation_id, replay::*, request::*, response::*, scope::*,
   };
 }
@@ -3,6 +3,7 @@ use crate::{
   module::{container::AFPluginStateMap, AFPluginState},
   request::{payload::Payload, AFPluginEventRequest, FromAFPluginRequest},
   response::{AFPluginEventResponse, AFPluginResponder},
+  scope::PermissionScope,
   service::{
     factory, AFPluginHandler, AFPluginHandlerService, AFPluginServiceFactory, BoxService,
     BoxServiceFactory, Service, ServiceRequest, ServiceResponse,
@@ -99,7 +100,7 @@ impl AFPlugin {
     T: FromAFPluginRequest + 'static + Send + Sync,
     <T as FromAFPluginRequest>::Future: Sync + Send,
     R: Future + 'static + Send + Sync,
-    R::Output: AFPluginResponder + 'static,
+    R::Output: AFPluginResponder + Send + 'static,
     E: Eq + Hash + Debug + Clone + Display,
   {
     let event: AFPluginEvent = event.into();
@@ -131,6 +132,9 @@ pub struct AFPluginRequest {
   pub id: String,
   pub event: AFPluginEvent,
   pub(crate) payload: Payload,
+  /// The scopes granted to the sender of the request, e.g. the scopes of the personal access
+  /// token that the request was authenticated with. None if the request is sent by the app.
+  pub(crate) scopes: Option<Arc<Vec<PermissionScope>>>,
 }
 
 impl AFPluginRequest {
@@ -142,6 +146,7 @@ impl AFPluginRequest {
       id: nanoid!(6),
       event: event.into(),
       payload: Payload::None,
+      scopes: None,
     }
   }
 
@@ -152,6 +157,20 @@ impl AFPluginRequest {
     self.payload = payload.into();
     self
   }
+
+  pub fn get_payload(&self) -> &Payload {
+    &self.payload
+  }
+
+  /// Limits the request to the events that are granted by the `scopes`.
+  pub fn scopes(mut self, scopes: Vec<PermissionScope>) -> Self {
+    self.scopes = Some(Arc::new(scopes));
+    self
+  }
+
+  pub fn get_scopes(&self) -> Option<&[PermissionScope]> {
+    self.scopes.as_ref().map(|scopes| scopes.as_slice())
+  }
 }
 
 impl std::fmt::Display for AFPluginRequest {
@@ -190,7 +209,9 @@ impl Service<AFPluginRequest> for AFPluginService {
   type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
 
   fn call(&self, request: AFPluginRequest) -> Self::Future {
-    let AFPluginRequest { id, event, payload } = request;
+    let AFPluginRequest {
+      id, event, payload, ..
+    } = request;
     let states = self.states.clone();
     let request = AFPluginEventRequest::new(id, event, states);
 
@@ -0,0 +1,21 @@
+use std::future::Future;
+
+tokio::task_local! {
+  static OPERATION_ID: String;
+}
+
+/// Returns the id of the event that is being handled by the current task. It's the same id as
+/// the `operation_id` field of the event's span, so the errors returned to the frontend can be
+/// matched with the logs.
+///
+/// Returns None outside of the dispatcher, e.g. in the tasks spawned by the handlers.
+pub fn current_operation_id() -> Option<String> {
+  OPERATION_ID.try_with(|id| id.clone()).ok()
+}
+
+pub(crate) async fn with_operation_id<F>(operation_id: String, f: F) -> F::Output
+where
+  F: Future,
+{
+  OPERATION_ID.scope(operation_id, f).await
+}
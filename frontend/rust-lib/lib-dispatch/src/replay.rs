@@ -0,0 +1,128 @@
+use crate::{
+  dispatcher::AFPluginDispatcher,
+  module::{AFPluginEvent, AFPluginRequest},
+  response::{AFPluginEventResponse, StatusCode},
+};
+use std::sync::{Arc, Mutex};
+
+/// A request/response pair that was recorded by the [EventRecorder].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "use_serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventRecord {
+  pub event: String,
+  pub payload: Vec<u8>,
+  pub status_code: StatusCode,
+  pub response: Vec<u8>,
+}
+
+impl EventRecord {
+  /// Returns a new request that has the same event and payload as the recorded one.
+  pub fn request(&self) -> AFPluginRequest {
+    let request = AFPluginRequest::new(AFPluginEvent(self.event.clone()));
+    if self.payload.is_empty() {
+      request
+    } else {
+      request.payload(self.payload.clone())
+    }
+  }
+}
+
+/// [EventRecorder] keeps every event dispatched by the `AFPluginDispatcher` along with its
+/// response. Unlike the `EventAuditLog`, nothing is dropped, so it's only meant to be used for
+/// recording the sessions that are replayed by the [EventReplayer] in the integration tests.
+#[derive(Clone, Default)]
+pub struct EventRecorder {
+  records: Arc<Mutex<Vec<EventRecord>>>,
+}
+
+impl EventRecorder {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub(crate) fn record(&self, record: EventRecord) {
+    if let Ok(mut records) = self.records.lock() {
+      records.push(record);
+    }
+  }
+
+  /// Returns the records in the order they were dispatched.
+  pub fn records(&self) -> Vec<EventRecord> {
+    match self.records.lock() {
+      Ok(records) => records.clone(),
+      Err(_) => vec![],
+    }
+  }
+
+  pub fn clear(&self) {
+    if let Ok(mut records) = self.records.lock() {
+      records.clear();
+    }
+  }
+}
+
+/// The response of a replayed [EventRecord].
+#[derive(Debug)]
+pub struct ReplayedEvent {
+  pub record: EventRecord,
+  pub response: AFPluginEventResponse,
+}
+
+impl ReplayedEvent {
+  pub fn is_status_code_matched(&self) -> bool {
+    self.record.status_code == self.response.status_code
+  }
+
+  /// Returns true if the replayed response is exactly the same as the recorded one. The responses
+  /// that contain the generated ids or timestamps will never match.
+  pub fn is_response_matched(&self) -> bool {
+    self.is_status_code_matched() && self.record.response == self.response.payload.clone().to_vec()
+  }
+}
+
+type RequestMapper =
+  Box<dyn Fn(&EventRecord, &[ReplayedEvent]) -> AFPluginRequest + Send + Sync + 'static>;
+
+/// Dispatches the recorded events one by one, in the order they were recorded.
+///
+/// The records are usually replayed against a fresh `AppFlowyCore`, where the objects created by
+/// the events have different ids. Use [EventReplayer::map_request] to replace the recorded ids
+/// with the ones returned by the previous replayed events.
+pub struct EventReplayer {
+  records: Vec<EventRecord>,
+  request_mapper: Option<RequestMapper>,
+}
+
+impl EventReplayer {
+  pub fn new(records: Vec<EventRecord>) -> Self {
+    Self {
+      records,
+      request_mapper: None,
+    }
+  }
+
+  /// Builds the request of each record with the `mapper` instead of [EventRecord::request]. The
+  /// mapper receives the events that have been replayed so far.
+  pub fn map_request<F>(mut self, mapper: F) -> Self
+  where
+    F: Fn(&EventRecord, &[ReplayedEvent]) -> AFPluginRequest + Send + Sync + 'static,
+  {
+    self.request_mapper = Some(Box::new(mapper));
+    self
+  }
+
+  /// Replays all the records and returns their responses. The replay doesn't stop at the events
+  /// whose response differs from the recorded one, the caller decides which ones matter.
+  pub async fn replay(self, dispatch: Arc<AFPluginDispatcher>) -> Vec<ReplayedEvent> {
+    let mut replayed_events: Vec<ReplayedEvent> = Vec::with_capacity(self.records.len());
+    for record in self.records {
+      let request = match &self.request_mapper {
+        None => record.request(),
+        Some(mapper) => mapper(&record, &replayed_events),
+      };
+      let response = AFPluginDispatcher::async_send(dispatch.clone(), request).await;
+      replayed_events.push(ReplayedEvent { record, response });
+    }
+    replayed_events
+  }
+}
@@ -18,6 +18,17 @@ impl Payload {
       Payload::Bytes(bytes) => bytes.to_vec(),
     }
   }
+
+  pub fn len(&self) -> usize {
+    match self {
+      Payload::None => 0,
+      Payload::Bytes(bytes) => bytes.len(),
+    }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
 }
 
 impl std::fmt::Debug for Payload {
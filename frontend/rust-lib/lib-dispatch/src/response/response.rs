@@ -9,7 +9,10 @@ use derivative::*;
 use std::{convert::TryFrom, fmt, fmt::Formatter};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-#[cfg_attr(feature = "use_serde", derive(serde_repr::Serialize_repr))]
+#[cfg_attr(
+  feature = "use_serde",
+  derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr)
+)]
 #[repr(u8)]
 pub enum StatusCode {
   Ok = 0,
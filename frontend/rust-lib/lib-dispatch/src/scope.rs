@@ -0,0 +1,78 @@
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum ScopeAccess {
+  Read,
+  Write,
+  Admin,
+}
+
+impl ScopeAccess {
+  fn as_str(&self) -> &'static str {
+    match self {
+      ScopeAccess::Read => "read",
+      ScopeAccess::Write => "write",
+      ScopeAccess::Admin => "admin",
+    }
+  }
+}
+
+/// A permission scope in the form of `access:resource`, e.g. `read:database`.
+///
+/// The requests that carry scopes, such as the requests authenticated with a personal access
+/// token, can only dispatch the events that are granted by their scopes. The requests without
+/// scopes are sent by the app itself and they are not limited.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PermissionScope {
+  pub access: ScopeAccess,
+  pub resource: String,
+}
+
+impl PermissionScope {
+  pub fn new(access: ScopeAccess, resource: &str) -> Self {
+    Self {
+      access,
+      resource: resource.to_owned(),
+    }
+  }
+
+  pub fn read(resource: &str) -> Self {
+    Self::new(ScopeAccess::Read, resource)
+  }
+
+  pub fn write(resource: &str) -> Self {
+    Self::new(ScopeAccess::Write, resource)
+  }
+
+  pub fn admin(resource: &str) -> Self {
+    Self::new(ScopeAccess::Admin, resource)
+  }
+
+  /// Parses the scope from `access:resource`. Returns None if the access is unknown or the
+  /// resource is empty.
+  pub fn parse(s: &str) -> Option<Self> {
+    let (access, resource) = s.trim().split_once(':')?;
+    let access = match access {
+      "read" => ScopeAccess::Read,
+      "write" => ScopeAccess::Write,
+      "admin" => ScopeAccess::Admin,
+      _ => return None,
+    };
+    if resource.is_empty() {
+      return None;
+    }
+    Some(Self::new(access, resource))
+  }
+
+  /// Returns true if this scope grants the `required` one. The scope grants the scopes of the
+  /// same resource with the same or lower access, e.g. `write:database` grants `read:database`.
+  pub fn grants(&self, required: &PermissionScope) -> bool {
+    self.resource == required.resource && self.access >= required.access
+  }
+}
+
+impl fmt::Display for PermissionScope {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}:{}", self.access.as_str(), self.resource)
+  }
+}
@@ -2,20 +2,29 @@ use std::{
   future::Future,
   marker::PhantomData,
   pin::Pin,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+  },
   task::{Context, Poll},
 };
 
 use futures_core::ready;
 use pin_project::pin_project;
+use tokio::task::JoinHandle;
 
 use crate::{
-  errors::DispatchError,
+  errors::{DispatchError, InternalError},
   request::{payload::Payload, AFPluginEventRequest, FromAFPluginRequest},
   response::{AFPluginEventResponse, AFPluginResponder},
   service::{AFPluginServiceFactory, Service, ServiceRequest, ServiceResponse},
   util::ready::*,
 };
 
+/// The responses whose payload is expected to be larger than this size are encoded on the
+/// blocking thread pool, so encoding them doesn't stall the other tasks of the dispatcher.
+pub const LARGE_PAYLOAD_THRESHOLD: usize = 256 * 1024;
+
 /// A closure that is run every time for the specified plugin event
 pub trait AFPluginHandler<T, R>: Clone + 'static + Sync + Send
 where
@@ -33,6 +42,10 @@ where
   R::Output: AFPluginResponder,
 {
   handler: H,
+  /// The size of the last payload that was returned by the handler. The size of a response is
+  /// only known after encoding it, so the last one is used to predict whether the next response
+  /// is going to be a large one.
+  payload_size_hint: Arc<AtomicUsize>,
   _phantom: PhantomData<(T, R)>,
 }
 
@@ -46,6 +59,7 @@ where
   pub fn new(handler: H) -> Self {
     Self {
       handler,
+      payload_size_hint: Arc::new(AtomicUsize::new(0)),
       _phantom: PhantomData,
     }
   }
@@ -61,6 +75,7 @@ where
   fn clone(&self) -> Self {
     Self {
       handler: self.handler.clone(),
+      payload_size_hint: self.payload_size_hint.clone(),
       _phantom: PhantomData,
     }
   }
@@ -71,7 +86,7 @@ where
   F: AFPluginHandler<T, R>,
   T: FromAFPluginRequest,
   R: Future + Send + Sync,
-  R::Output: AFPluginResponder,
+  R::Output: AFPluginResponder + Send + 'static,
 {
   type Response = ServiceResponse;
   type Error = DispatchError;
@@ -89,7 +104,7 @@ where
   H: AFPluginHandler<T, R>,
   T: FromAFPluginRequest,
   R: Future + Sync + Send,
-  R::Output: AFPluginResponder,
+  R::Output: AFPluginResponder + Send + 'static,
 {
   type Response = ServiceResponse;
   type Error = DispatchError;
@@ -98,7 +113,12 @@ where
   fn call(&self, req: ServiceRequest) -> Self::Future {
     let (req, mut payload) = req.into_parts();
     let fut = T::from_request(&req, &mut payload);
-    HandlerServiceFuture::Extract(fut, Some(req), self.handler.clone())
+    HandlerServiceFuture::Extract(
+      fut,
+      Some(req),
+      self.handler.clone(),
+      self.payload_size_hint.clone(),
+    )
   }
 }
 
@@ -110,8 +130,17 @@ where
   R: Future + Sync + Send,
   R::Output: AFPluginResponder,
 {
-  Extract(#[pin] T::Future, Option<AFPluginEventRequest>, H),
-  Handle(#[pin] R, Option<AFPluginEventRequest>),
+  Extract(
+    #[pin] T::Future,
+    Option<AFPluginEventRequest>,
+    H,
+    Arc<AtomicUsize>,
+  ),
+  Handle(#[pin] R, Option<AFPluginEventRequest>, Arc<AtomicUsize>),
+  Encode(
+    #[pin] JoinHandle<(AFPluginEventRequest, AFPluginEventResponse)>,
+    Arc<AtomicUsize>,
+  ),
 }
 
 impl<F, T, R> Future for HandlerServiceFuture<F, T, R>
@@ -119,18 +148,18 @@ where
   F: AFPluginHandler<T, R>,
   T: FromAFPluginRequest,
   R: Future + Sync + Send,
-  R::Output: AFPluginResponder,
+  R::Output: AFPluginResponder + Send + 'static,
 {
   type Output = Result<ServiceResponse, DispatchError>;
 
   fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
     loop {
       match self.as_mut().project() {
-        HandlerServiceProj::Extract(fut, req, handle) => {
+        HandlerServiceProj::Extract(fut, req, handle, size_hint) => {
           match ready!(fut.poll(cx)) {
             Ok(params) => {
               let fut = handle.call(params);
-              let state = HandlerServiceFuture::Handle(fut, req.take());
+              let state = HandlerServiceFuture::Handle(fut, req.take(), size_hint.clone());
               self.as_mut().set(state);
             },
             Err(err) => {
@@ -141,10 +170,27 @@ where
             },
           };
         },
-        HandlerServiceProj::Handle(fut, req) => {
+        HandlerServiceProj::Handle(fut, req, size_hint) => {
           let result = ready!(fut.poll(cx));
           let req = req.take().unwrap();
+          if size_hint.load(Ordering::Relaxed) >= LARGE_PAYLOAD_THRESHOLD {
+            if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+              let encoding = runtime.spawn_blocking(move || {
+                let resp = result.respond_to(&req);
+                (req, resp)
+              });
+              let state = HandlerServiceFuture::Encode(encoding, size_hint.clone());
+              self.as_mut().set(state);
+              continue;
+            }
+          }
           let resp = result.respond_to(&req);
+          size_hint.store(resp.payload.len(), Ordering::Relaxed);
+          return Poll::Ready(Ok(ServiceResponse::new(req, resp)));
+        },
+        HandlerServiceProj::Encode(encoding, size_hint) => {
+          let (req, resp) = ready!(encoding.poll(cx)).map_err(InternalError::from)?;
+          size_hint.store(resp.payload.len(), Ordering::Relaxed);
           return Poll::Ready(Ok(ServiceResponse::new(req, resp)));
         },
       }
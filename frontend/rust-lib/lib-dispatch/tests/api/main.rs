@@ -1 +1,2 @@
 mod module;
+mod scope;
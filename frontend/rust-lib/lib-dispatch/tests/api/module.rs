@@ -1,3 +1,4 @@
+use futures_util::future::BoxFuture;
 use lib_dispatch::prelude::*;
 use lib_dispatch::runtime::tokio_default_runtime;
 use std::sync::Arc;
@@ -23,3 +24,181 @@ async fn test() {
 
   std::mem::forget(dispatch);
 }
+
+struct RejectInterceptor {
+  event: AFPluginEvent,
+}
+
+impl AFPluginInterceptor for RejectInterceptor {
+  fn intercept(&self, request: &AFPluginRequest) -> BoxFuture<'static, Result<(), DispatchError>> {
+    let result = if request.event == self.event {
+      Err(DispatchError::from("rejected".to_string()))
+    } else {
+      Ok(())
+    };
+    Box::pin(async move { result })
+  }
+}
+
+#[tokio::test]
+async fn interceptor_test() {
+  let runtime = tokio_default_runtime().unwrap();
+  let dispatch = Arc::new(
+    AFPluginDispatcher::construct(runtime, || {
+      vec![AFPlugin::new().event("1", hello).event("2", hello)]
+    })
+    .interceptors(vec![Arc::new(RejectInterceptor { event: "2".into() })]),
+  );
+
+  let resp = AFPluginDispatcher::async_send(dispatch.clone(), AFPluginRequest::new("1")).await;
+  assert_eq!(resp.status_code, StatusCode::Ok);
+
+  let resp = AFPluginDispatcher::async_send(dispatch.clone(), AFPluginRequest::new("2")).await;
+  assert_eq!(resp.status_code, StatusCode::Err);
+
+  std::mem::forget(dispatch);
+}
+
+#[tokio::test]
+async fn audit_log_test() {
+  let runtime = tokio_default_runtime().unwrap();
+  let audit_log = EventAuditLog::new(2);
+  let dispatch = Arc::new(
+    AFPluginDispatcher::construct(runtime, || vec![AFPlugin::new().event("1", hello)])
+      .audit_log(audit_log.clone()),
+  );
+
+  for _ in 0..3 {
+    let request = AFPluginRequest::new("1").payload("hello");
+    let _ = AFPluginDispatcher::async_send(dispatch.clone(), request).await;
+  }
+  let _ = AFPluginDispatcher::async_send(dispatch.clone(), AFPluginRequest::new("2")).await;
+
+  let records = audit_log.records();
+  assert_eq!(records.len(), 2);
+  assert_eq!(records[0].event, "1");
+  assert_eq!(records[0].payload_size, 5);
+  assert_eq!(records[0].status_code, StatusCode::Ok);
+  assert_eq!(records[1].event, "2");
+  assert_eq!(records[1].status_code, StatusCode::Err);
+
+  std::mem::forget(dispatch);
+}
+
+/// A payload over the threshold that records whether it was encoded on the worker thread that
+/// ran its handler. The last byte of the payload is 1 if it was encoded on another thread.
+pub struct LargePayload {
+  handler_thread: std::thread::ThreadId,
+}
+
+impl AFPluginResponder for LargePayload {
+  fn respond_to(self, req: &AFPluginEventRequest) -> AFPluginEventResponse {
+    let is_off_worker = std::thread::current().id() != self.handler_thread;
+    let mut payload = "a".repeat(LARGE_PAYLOAD_THRESHOLD);
+    payload.push(if is_off_worker { '1' } else { '0' });
+    payload.respond_to(req)
+  }
+}
+
+pub async fn large_payload() -> LargePayload {
+  LargePayload {
+    handler_thread: std::thread::current().id(),
+  }
+}
+
+#[tokio::test]
+async fn large_payload_test() {
+  let runtime = tokio_default_runtime().unwrap();
+  let dispatch = Arc::new(AFPluginDispatcher::construct(runtime, || {
+    vec![AFPlugin::new().event("1", large_payload)]
+  }));
+
+  // The size of the first response is unknown before encoding it, so it's encoded inline. The
+  // next ones are predicted to be large and encoded on the blocking thread pool.
+  for expected_last_byte in [b'0', b'1', b'1'] {
+    let resp = AFPluginDispatcher::async_send(dispatch.clone(), AFPluginRequest::new("1")).await;
+    assert_eq!(resp.status_code, StatusCode::Ok);
+    let payload = match resp.payload {
+      Payload::Bytes(bytes) => bytes,
+      Payload::None => panic!("The payload should not be empty"),
+    };
+    assert_eq!(payload.len(), LARGE_PAYLOAD_THRESHOLD + 1);
+    assert_eq!(payload.last(), Some(&expected_last_byte));
+  }
+
+  std::mem::forget(dispatch);
+}
+
+pub async fn echo(data: String) -> String {
+  data
+}
+
+#[tokio::test]
+async fn record_and_replay_test() {
+  let recorder = EventRecorder::new();
+  let dispatch = Arc::new(
+    AFPluginDispatcher::construct(tokio_default_runtime().unwrap(), || {
+      vec![AFPlugin::new().event("1", hello).event("2", echo)]
+    })
+    .recorder(recorder.clone()),
+  );
+  for request in [
+    AFPluginRequest::new("1"),
+    AFPluginRequest::new("2").payload("hello"),
+    AFPluginRequest::new("3"),
+  ] {
+    let _ = AFPluginDispatcher::async_send(dispatch.clone(), request).await;
+  }
+  let records = recorder.records();
+  assert_eq!(records.len(), 3);
+  assert_eq!(records[1].payload, b"hello".to_vec());
+  assert_eq!(records[2].status_code, StatusCode::Err);
+
+  // Replays the session against a fresh dispatcher
+  let fresh_dispatch = Arc::new(AFPluginDispatcher::construct(
+    tokio_default_runtime().unwrap(),
+    || vec![AFPlugin::new().event("1", hello).event("2", echo)],
+  ));
+  let replayed_events = EventReplayer::new(records.clone())
+    .replay(fresh_dispatch.clone())
+    .await;
+  assert!(replayed_events[0].is_response_matched());
+  assert!(replayed_events[1].is_response_matched());
+  // The error message contains the id of the request
+  assert!(replayed_events[2].is_status_code_matched());
+
+  // Modifies the payload of the replayed requests
+  let replayed_events = EventReplayer::new(records)
+    .map_request(|record, _| record.request().payload("world"))
+    .replay(fresh_dispatch.clone())
+    .await;
+  assert!(replayed_events[0].is_response_matched());
+  assert!(!replayed_events[1].is_response_matched());
+  assert!(replayed_events[1].is_status_code_matched());
+
+  std::mem::forget(dispatch);
+  std::mem::forget(fresh_dispatch);
+}
+
+pub async fn read_operation_id() -> String {
+  current_operation_id().unwrap_or_default()
+}
+
+#[tokio::test]
+async fn operation_id_test() {
+  let runtime = tokio_default_runtime().unwrap();
+  let dispatch = Arc::new(AFPluginDispatcher::construct(runtime, || {
+    vec![AFPlugin::new().event("1", read_operation_id)]
+  }));
+
+  let request = AFPluginRequest::new("1");
+  let operation_id = request.id.clone();
+  let resp = AFPluginDispatcher::async_send(dispatch.clone(), request).await;
+  assert_eq!(resp.status_code, StatusCode::Ok);
+  assert_eq!(resp.payload.to_vec(), operation_id.into_bytes());
+
+  // There is no operation outside of the dispatcher
+  assert!(current_operation_id().is_none());
+
+  std::mem::forget(dispatch);
+}
@@ -0,0 +1,29 @@
+use lib_dispatch::prelude::*;
+
+#[test]
+fn permission_scope_parse_test() {
+  let scope = PermissionScope::parse("read:database").unwrap();
+  assert_eq!(scope, PermissionScope::read("database"));
+  assert_eq!(scope.to_string(), "read:database");
+  assert_eq!(
+    PermissionScope::parse(" admin:workspace ").unwrap(),
+    PermissionScope::admin("workspace")
+  );
+
+  assert!(PermissionScope::parse("database").is_none());
+  assert!(PermissionScope::parse("delete:database").is_none());
+  assert!(PermissionScope::parse("write:").is_none());
+}
+
+#[test]
+fn permission_scope_grants_test() {
+  let write_database = PermissionScope::write("database");
+  assert!(write_database.grants(&PermissionScope::read("database")));
+  assert!(write_database.grants(&PermissionScope::write("database")));
+  assert!(!write_database.grants(&PermissionScope::admin("database")));
+  assert!(!write_database.grants(&PermissionScope::read("document")));
+
+  let admin_workspace = PermissionScope::admin("workspace");
+  assert!(admin_workspace.grants(&PermissionScope::write("workspace")));
+  assert!(!admin_workspace.grants(&PermissionScope::write("database")));
+}
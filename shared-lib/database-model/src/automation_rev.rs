@@ -0,0 +1,51 @@
+use indexmap::IndexMap;
+use nanoid::nanoid;
+use serde::{Deserialize, Serialize};
+
+pub fn gen_database_automation_id() -> String {
+  nanoid!(6)
+}
+
+/// An automation of the database view. The action is fired once for each row that matches the
+/// trigger, e.g. moving the row to the 'Doing' group when its due date arrives.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AutomationRevision {
+  pub id: String,
+  pub name: String,
+  pub trigger: AutomationTriggerRevision,
+  pub action: AutomationActionRevision,
+  #[serde(default = "DEFAULT_IS_ENABLED")]
+  pub is_enabled: bool,
+
+  /// The timestamp in seconds when the automation was created. The rows whose trigger dates are
+  /// before the day it was created are not fired. It's 0 for the automations that were created
+  /// before it was added, they only fire on the trigger date itself.
+  #[serde(default)]
+  pub created_at: i64,
+
+  /// The dates that the action was fired for, by the ids of the rows. The automation is synced
+  /// with the view, so the other devices don't fire the action again for the same date.
+  #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+  pub fired_dates: IndexMap<String, String>,
+}
+
+const DEFAULT_IS_ENABLED: fn() -> bool = || true;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum AutomationTriggerRevision {
+  /// Fires when the date of the cell of the date field arrives. The `days_before` fires the
+  /// action the given number of days before the date instead.
+  DateArrived {
+    field_id: String,
+    #[serde(default)]
+    days_before: i64,
+  },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum AutomationActionRevision {
+  /// Moves the row to the group of the grouping field of the view.
+  MoveToGroup { group_id: String },
+}
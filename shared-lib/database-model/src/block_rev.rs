@@ -1,11 +1,14 @@
 use indexmap::IndexMap;
-use nanoid::nanoid;
+use lib_infra::id_gen::gen_id;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
+/// The rows are created offline on different devices, their ids are generated to make the
+/// collisions across the devices unlikely, see [IdGenerator](lib_infra::id_gen::IdGenerator).
 pub fn gen_row_id() -> String {
-  nanoid!(6)
+  gen_id()
 }
 
 pub const DEFAULT_ROW_HEIGHT: i32 = 42;
@@ -28,24 +31,48 @@ pub struct RowRevision {
   pub cells: IndexMap<FieldId, CellRevision>,
   pub height: i32,
   pub visibility: bool,
+  /// The timestamp, in seconds, when the row was created. It's zero if the row was created
+  /// before the timestamp was recorded.
+  #[serde(default, skip_serializing_if = "is_zero")]
+  pub created_at: i64,
+  /// The timestamp, in seconds, when the row was modified last time.
+  #[serde(default, skip_serializing_if = "is_zero")]
+  pub modified_at: i64,
 }
 
 impl RowRevision {
   pub fn new(block_id: &str) -> Self {
+    let now = timestamp();
     Self {
       id: gen_row_id(),
       block_id: block_id.to_owned(),
       cells: Default::default(),
       height: DEFAULT_ROW_HEIGHT,
       visibility: true,
+      created_at: now,
+      modified_at: now,
     }
   }
 }
+
+fn is_zero(value: &i64) -> bool {
+  *value == 0
+}
+
+fn timestamp() -> i64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_secs() as i64)
+    .unwrap_or_default()
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct RowChangeset {
   pub row_id: String,
   pub height: Option<i32>,
   pub visibility: Option<bool>,
+  /// The modified time of the row, it's only applied if the row was changed by this changeset.
+  pub modified_at: Option<i64>,
   // Contains the key/value changes represents as the update of the cells. For example,
   // if there is one cell was changed, then the `cell_by_field_id` will only have one key/value.
   pub cell_by_field_id: HashMap<FieldId, CellRevision>,
@@ -57,6 +84,7 @@ impl RowChangeset {
       row_id,
       height: None,
       visibility: None,
+      modified_at: None,
       cell_by_field_id: Default::default(),
     }
   }
@@ -25,6 +25,10 @@ pub struct DatabaseRevision {
   pub database_id: String,
   pub fields: Vec<Arc<FieldRevision>>,
   pub blocks: Vec<Arc<DatabaseBlockMetaRevision>>,
+  /// The deleted fields that can be restored until they get purged. They're kept in the
+  /// database, so the trash is synced with the other devices.
+  #[serde(default)]
+  pub trashed_fields: Vec<Arc<TrashedFieldRevision>>,
 }
 
 impl DatabaseRevision {
@@ -33,6 +37,7 @@ impl DatabaseRevision {
       database_id: database_id.to_owned(),
       fields: vec![],
       blocks: vec![],
+      trashed_fields: vec![],
     }
   }
 
@@ -45,10 +50,24 @@ impl DatabaseRevision {
       database_id: database_id.to_owned(),
       fields: field_revs,
       blocks: block_metas.into_iter().map(Arc::new).collect(),
+      trashed_fields: vec![],
     }
   }
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TrashedFieldRevision {
+  pub field: FieldRevision,
+  /// The position of the field in the database when it was deleted
+  pub index: usize,
+  /// The timestamp in seconds when the field was moved to the trash
+  pub deleted_at: i64,
+  /// The views that were grouped by the field, they're grouped by it again when the field is
+  /// restored
+  #[serde(default)]
+  pub grouped_view_ids: Vec<String>,
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DatabaseBlockMetaRevision {
   pub block_id: String,
@@ -232,6 +251,12 @@ pub struct CalendarLayoutSetting {
   pub show_weekends: bool,
   pub show_week_numbers: bool,
   pub layout_field_id: String,
+  /// Shows the rows whose date cell is empty in the unscheduled lane of the calendar
+  #[serde(default = "default_show_unscheduled_events")]
+  pub show_unscheduled_events: bool,
+  /// Shows the checklist items that have a due date as the events of their rows
+  #[serde(default)]
+  pub show_checklist_items: bool,
 }
 
 impl CalendarLayoutSetting {
@@ -242,10 +267,19 @@ impl CalendarLayoutSetting {
       show_weekends: DEFAULT_SHOW_WEEKENDS,
       show_week_numbers: DEFAULT_SHOW_WEEK_NUMBERS,
       layout_field_id,
+      show_unscheduled_events: DEFAULT_SHOW_UNSCHEDULED_EVENTS,
+      show_checklist_items: false,
     }
   }
 }
 
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct GridLayoutSetting {
+  /// The number of the leading columns that stay visible when the grid is scrolled horizontally
+  #[serde(default)]
+  pub frozen_column_count: i32,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Default, Serialize_repr, Deserialize_repr)]
 #[repr(u8)]
 pub enum CalendarLayout {
@@ -258,3 +292,8 @@ pub enum CalendarLayout {
 pub const DEFAULT_FIRST_DAY_OF_WEEK: i32 = 0;
 pub const DEFAULT_SHOW_WEEKENDS: bool = true;
 pub const DEFAULT_SHOW_WEEK_NUMBERS: bool = true;
+pub const DEFAULT_SHOW_UNSCHEDULED_EVENTS: bool = false;
+
+fn default_show_unscheduled_events() -> bool {
+  DEFAULT_SHOW_UNSCHEDULED_EVENTS
+}
@@ -9,4 +9,21 @@ pub struct FilterRevision {
   pub condition: u8,
   #[serde(default)]
   pub content: String,
+  /// Only used by the text filters, they ignore the case of the text by default.
+  #[serde(default)]
+  pub case_sensitive: bool,
+  /// Set if the filter compares the cell with the cell of this field in the same row instead of
+  /// the `content`.
+  #[serde(default)]
+  pub other_field_id: Option<String>,
+}
+
+/// A named set of filters of the database view. Applying the preset replaces the filters of
+/// the view with the filters of the preset.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+pub struct FilterPresetRevision {
+  pub id: String,
+  pub name: String,
+  #[serde(default)]
+  pub filters: Vec<FilterRevision>,
 }
@@ -1,3 +1,4 @@
+mod automation_rev;
 mod block_rev;
 mod database_rev;
 mod filter_rev;
@@ -6,6 +7,7 @@ mod setting_rev;
 mod sort_rev;
 mod view_rev;
 
+pub use automation_rev::*;
 pub use block_rev::*;
 pub use database_rev::*;
 pub use filter_rev::*;
@@ -19,6 +19,10 @@ pub fn gen_database_sort_id() -> String {
   nanoid!(6)
 }
 
+pub fn gen_database_filter_preset_id() -> String {
+  nanoid!(6)
+}
+
 pub type FilterConfiguration = Configuration<FilterRevision>;
 
 pub type GroupConfiguration = Configuration<GroupConfigurationRevision>;
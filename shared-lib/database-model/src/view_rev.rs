@@ -1,12 +1,15 @@
-use crate::{FilterConfiguration, GroupConfiguration, SortConfiguration};
+use crate::{
+  AutomationRevision, FilterConfiguration, FilterPresetRevision, GroupConfiguration,
+  GroupConfigurationRevision, SortConfiguration,
+};
 use indexmap::IndexMap;
-use nanoid::nanoid;
+use lib_infra::id_gen::gen_id;
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
 
 #[allow(dead_code)]
 pub fn gen_grid_view_id() -> String {
-  nanoid!(6)
+  gen_id()
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize_repr, Deserialize_repr)]
@@ -40,6 +43,11 @@ pub struct DatabaseViewRevision {
   #[serde(default)]
   pub name: String,
 
+  /// The text that explains what the view represents, e.g. the purpose of its filters. It's
+  /// the rich text serialized by the client.
+  #[serde(default)]
+  pub description: String,
+
   #[serde(default = "DEFAULT_BASE_VALUE")]
   pub is_base: bool,
 
@@ -54,8 +62,22 @@ pub struct DatabaseViewRevision {
   #[serde(default)]
   pub groups: GroupConfiguration,
 
+  /// The group configuration of the second grouping field. The rows of each group are split
+  /// into the swimlanes by this field, the board shows the groups as the columns and the
+  /// swimlanes as the rows.
+  #[serde(default)]
+  pub swimlane: Option<GroupConfigurationRevision>,
+
   #[serde(default)]
   pub sorts: SortConfiguration,
+
+  /// The filter presets saved by the user, see [FilterPresetRevision].
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub filter_presets: Vec<FilterPresetRevision>,
+
+  /// The automations of the view, see [AutomationRevision].
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub automations: Vec<AutomationRevision>,
 }
 
 const DEFAULT_BASE_VALUE: fn() -> bool = || true;
@@ -74,10 +96,14 @@ impl DatabaseViewRevision {
       layout,
       is_base,
       name,
+      description: "".to_owned(),
       layout_settings: Default::default(),
       filters: Default::default(),
       groups: Default::default(),
+      swimlane: None,
       sorts: Default::default(),
+      filter_presets: vec![],
+      automations: vec![],
     }
   }
 
@@ -20,7 +20,11 @@ pub trait FlowyRawWebSocket: Send + Sync {
   fn start_connect(&self, addr: String, user_id: String) -> FutureResult<(), WSErrorCode>;
   fn stop_connect(&self) -> FutureResult<(), WSErrorCode>;
   fn subscribe_connect_state(&self) -> BoxFuture<broadcast::Receiver<WSConnectState>>;
+  fn connect_state(&self) -> BoxFuture<WSConnectState>;
   fn reconnect(&self, count: usize) -> FutureResult<(), WSErrorCode>;
+  /// Returns the number of times the connection was tried, including the retries inside each
+  /// [Self::start_connect] and [Self::reconnect].
+  fn connect_attempts(&self) -> usize;
   fn add_msg_receiver(&self, receiver: Arc<dyn WSMessageReceiver>) -> Result<(), WSErrorCode>;
   fn ws_msg_sender(&self) -> FutureResult<Option<Arc<dyn FlowyWebSocket>>, WSErrorCode>;
 }
@@ -124,6 +128,12 @@ impl FlowyWebSocketConnect {
     self.inner.subscribe_connect_state().await
   }
 
+  /// Returns the current state of the connection. The changes of the state are received by
+  /// subscribing, see [Self::subscribe_websocket_state].
+  pub async fn websocket_state(&self) -> WSConnectState {
+    self.inner.connect_state().await
+  }
+
   pub fn subscribe_network_ty(&self) -> broadcast::Receiver<NetworkType> {
     self.status_notifier.subscribe()
   }
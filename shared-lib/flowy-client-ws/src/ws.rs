@@ -33,6 +33,11 @@ impl FlowyRawWebSocket for Arc<WSController> {
     Box::pin(async move { cloned_ws.subscribe_state().await })
   }
 
+  fn connect_state(&self) -> BoxFuture<WSConnectState> {
+    let cloned_ws = self.clone();
+    Box::pin(async move { cloned_ws.connect_state().await })
+  }
+
   fn reconnect(&self, count: usize) -> FutureResult<(), WSErrorCode> {
     let cloned_ws = self.clone();
     FutureResult::new(async move {
@@ -41,6 +46,10 @@ impl FlowyRawWebSocket for Arc<WSController> {
     })
   }
 
+  fn connect_attempts(&self) -> usize {
+    WSController::connect_attempts(self)
+  }
+
   fn add_msg_receiver(&self, receiver: Arc<dyn WSMessageReceiver>) -> Result<(), WSErrorCode> {
     self
       .add_ws_message_receiver(receiver)
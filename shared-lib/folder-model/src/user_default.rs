@@ -19,6 +19,7 @@ pub fn create_default_workspace() -> WorkspaceRevision {
     apps,
     modified_time: time.timestamp(),
     create_time: time.timestamp(),
+    settings: Default::default(),
   }
 }
 
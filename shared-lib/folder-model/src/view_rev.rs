@@ -1,9 +1,12 @@
 use crate::{TrashRevision, TrashTypeRevision};
-use nanoid::nanoid;
+use lib_infra::id_gen::gen_id;
 use serde::{Deserialize, Serialize};
 use serde_repr::*;
+use std::collections::HashMap;
+/// The views are created offline on different devices, see
+/// [IdGenerator](lib_infra::id_gen::IdGenerator).
 pub fn gen_view_id() -> String {
-  format!("v:{}", nanoid!(10))
+  format!("v:{}", gen_id())
 }
 
 #[derive(Default, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -29,6 +32,26 @@ pub struct ViewRevision {
   #[serde(default)]
   pub modified_time: i64,
 
+  /// The id of the user who edited the view last time. It's empty if the view was never edited
+  /// after it was created.
+  #[serde(default, skip_serializing_if = "String::is_empty")]
+  pub last_edited_by: String,
+
+  /// The human-readable id of the view that is unique in the workspace. It's used in the links
+  /// of the view, so the links still work after the view is renamed.
+  #[serde(default, skip_serializing_if = "String::is_empty")]
+  pub slug: String,
+
+  /// The permission of the view, 0 means that the view is editable. It's kept in the folder so
+  /// the lock of the view is synced to the other devices of the user.
+  #[serde(default, skip_serializing_if = "is_zero")]
+  pub permission: i64,
+
+  /// The state of the view for the user of the folder, e.g. the scroll position or the expanded
+  /// rows. It's kept in the folder so the state is synced to the other devices of the user.
+  #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+  pub state: HashMap<String, String>,
+
   #[serde(default)]
   pub create_time: i64,
 
@@ -64,6 +87,10 @@ impl ViewRevision {
       version: 0,
       belongings: vec![],
       modified_time,
+      last_edited_by: "".to_string(),
+      slug: "".to_string(),
+      permission: 0,
+      state: HashMap::new(),
       create_time,
       ext_data: "".to_string(),
       thumbnail: "".to_string(),
@@ -72,6 +99,10 @@ impl ViewRevision {
   }
 }
 
+fn is_zero(value: &i64) -> bool {
+  *value == 0
+}
+
 const DEFAULT_PLUGIN_TYPE: fn() -> ViewLayoutTypeRevision = || ViewLayoutTypeRevision::Document;
 
 impl std::convert::From<ViewRevision> for TrashRevision {
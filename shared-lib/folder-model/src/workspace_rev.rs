@@ -1,4 +1,4 @@
-use crate::AppRevision;
+use crate::{AppRevision, ViewLayoutTypeRevision};
 use nanoid::nanoid;
 use serde::{Deserialize, Serialize};
 pub fn gen_workspace_id() -> String {
@@ -19,4 +19,70 @@ pub struct WorkspaceRevision {
 
   #[serde(default)]
   pub create_time: i64,
+
+  #[serde(default, skip_serializing_if = "WorkspaceSettingsRevision::is_default")]
+  pub settings: WorkspaceSettingsRevision,
+}
+
+/// The settings that are shared by the members of the workspace. They are saved in the folder,
+/// so they are synced with the other changes of the workspace.
+#[derive(Default, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceSettingsRevision {
+  /// The layout of the database views that are created without specifying one
+  #[serde(default)]
+  pub default_database_layout: ViewLayoutTypeRevision,
+
+  /// The value of the database's `DateFormat` that is used by the new date fields. The
+  /// database's default is used if it's None.
+  #[serde(default)]
+  pub default_date_format: Option<i64>,
+
+  /// The value of the database's `TimeFormat` that is used by the new date fields. The
+  /// database's default is used if it's None.
+  #[serde(default)]
+  pub default_time_format: Option<i64>,
+
+  /// The value of the database's `NumberFormat` that is used by the new number fields. The
+  /// database's default is used if it's None.
+  #[serde(default)]
+  pub default_number_format: Option<i64>,
+
+  /// The days that the deleted items are kept in the trash. Zero means keeping them forever.
+  #[serde(default)]
+  pub trash_retention_days: i64,
+
+  /// The value of the view permission that the new members get
+  #[serde(default)]
+  pub default_member_permission: i64,
+
+  /// The layout of the views that are quick-created without specifying one, e.g. by the "new
+  /// page" button
+  #[serde(default)]
+  pub default_view_layout: ViewLayoutTypeRevision,
+
+  /// The value of the location where the quick-created views are added, the current parent or
+  /// the inbox app
+  #[serde(default)]
+  pub quick_create_location: i64,
+
+  /// The app that collects the quick-created views. It's created with the first view that is
+  /// added to it.
+  #[serde(default)]
+  pub inbox_app_id: Option<String>,
+
+  /// The first day of the week that the dates are grouped and filtered by, from 0 for Sunday
+  /// to 6 for Saturday
+  #[serde(default)]
+  pub first_day_of_week: i64,
+
+  /// The working days of the week as a bit mask, the bit 0 is Sunday and the bit 6 is Saturday.
+  /// Monday to Friday are the working days if it's None.
+  #[serde(default)]
+  pub working_days: Option<i64>,
+}
+
+impl WorkspaceSettingsRevision {
+  pub fn is_default(&self) -> bool {
+    self == &Self::default()
+  }
 }
@@ -0,0 +1,150 @@
+use rand::Rng;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const ID_LEN: usize = 26;
+const DEVICE_BITS: u32 = 24;
+const RANDOM_BITS: u32 = 56;
+const MAX_RANDOM: u64 = (1 << RANDOM_BITS) - 1;
+const MAX_TIMESTAMP: u64 = (1 << 48) - 1;
+
+static ID_GENERATOR: Mutex<Option<IdGenerator>> = Mutex::new(None);
+
+/// Generates the ids of the objects that are created offline and synced later, e.g. the rows
+/// and the views. The id is a ULID: 48 bits of the milliseconds since the epoch followed by
+/// 80 bits of entropy, encoded as 26 Crockford's base32 characters.
+///
+/// The entropy is made of 24 bits derived from the device id and 56 random bits. Two devices
+/// generate the same id only if their device bits collide, which is unlikely but possible
+/// because the device id is hashed into 24 bits, and their random bits collide in the same
+/// millisecond. So the ids are very unlikely to collide, not guaranteed to be unique; the
+/// collisions are repaired by the row id audit. The ids of the same generator are monotonic:
+/// the random bits are incremented if the clock doesn't move.
+pub struct IdGenerator {
+  device_entropy: u64,
+  last_timestamp: u64,
+  last_random: u64,
+}
+
+impl IdGenerator {
+  pub fn new(device_id: &str) -> Self {
+    Self {
+      device_entropy: device_entropy(device_id),
+      last_timestamp: 0,
+      last_random: 0,
+    }
+  }
+
+  pub fn next_id(&mut self) -> String {
+    self.next_id_with_timestamp(timestamp_millis())
+  }
+
+  fn next_id_with_timestamp(&mut self, timestamp: u64) -> String {
+    let timestamp = timestamp.min(MAX_TIMESTAMP);
+    if timestamp > self.last_timestamp {
+      self.last_timestamp = timestamp;
+      // Leaves room in the random bits for the following ids of the same millisecond
+      self.last_random = rand::thread_rng().gen_range(0..=MAX_RANDOM >> 1);
+    } else if self.last_random < MAX_RANDOM {
+      self.last_random += 1;
+    } else {
+      self.last_timestamp = (self.last_timestamp + 1).min(MAX_TIMESTAMP);
+      self.last_random = 0;
+    }
+
+    let value = (self.last_timestamp as u128) << (DEVICE_BITS + RANDOM_BITS)
+      | (self.device_entropy as u128) << RANDOM_BITS
+      | self.last_random as u128;
+    encode(value)
+  }
+}
+
+/// Sets the device id that is mixed into the ids returned by [gen_id]. It should be called once
+/// when the application starts, with an id that is persisted on the device.
+pub fn set_device_id(device_id: &str) {
+  *ID_GENERATOR.lock().unwrap() = Some(IdGenerator::new(device_id));
+}
+
+/// Returns a new id that is very unlikely to be generated by another device, see [IdGenerator].
+/// A random device id is used until [set_device_id] is called.
+pub fn gen_id() -> String {
+  let mut generator = ID_GENERATOR.lock().unwrap();
+  generator
+    .get_or_insert_with(|| {
+      let device_id = rand::thread_rng().gen::<u64>().to_string();
+      IdGenerator::new(&device_id)
+    })
+    .next_id()
+}
+
+/// Returns true if the `id` was generated by the [IdGenerator]. The ids generated by the older
+/// versions are shorter and much more likely to collide across devices.
+pub fn is_generated_id(id: &str) -> bool {
+  id.len() == ID_LEN
+    && id.as_bytes()[0] <= b'7'
+    && id.bytes().all(|b| CROCKFORD_ALPHABET.contains(&b))
+}
+
+fn device_entropy(device_id: &str) -> u64 {
+  let digest = md5::compute(device_id);
+  u64::from_be_bytes([0, 0, 0, 0, 0, digest[0], digest[1], digest[2]])
+}
+
+fn timestamp_millis() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map(|duration| duration.as_millis() as u64)
+    .unwrap_or_default()
+}
+
+fn encode(mut value: u128) -> String {
+  let mut bytes = [0; ID_LEN];
+  for byte in bytes.iter_mut().rev() {
+    *byte = CROCKFORD_ALPHABET[(value & 0x1f) as usize];
+    value >>= 5;
+  }
+  String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::HashSet;
+
+  #[test]
+  fn id_gen_format_test() {
+    let id = IdGenerator::new("device").next_id();
+    assert_eq!(id.len(), ID_LEN);
+    assert!(is_generated_id(&id));
+    assert!(is_generated_id(&gen_id()));
+    assert!(!is_generated_id("a1b2c3"));
+  }
+
+  #[test]
+  fn id_gen_monotonic_test() {
+    let mut generator = IdGenerator::new("device");
+    let ids = (0..1000)
+      .map(|_| generator.next_id_with_timestamp(1647251762000))
+      .collect::<Vec<_>>();
+    let mut sorted_ids = ids.clone();
+    sorted_ids.sort();
+    assert_eq!(ids, sorted_ids);
+    assert_eq!(ids.iter().collect::<HashSet<_>>().len(), ids.len());
+
+    // The clock goes backwards
+    let id = generator.next_id_with_timestamp(1647251761000);
+    assert!(&id > ids.last().unwrap());
+  }
+
+  #[test]
+  fn id_gen_device_entropy_test() {
+    let mut generator = IdGenerator::new("device_a");
+    let mut other_generator = IdGenerator::new("device_b");
+    let id = generator.next_id_with_timestamp(1647251762000);
+    let other_id = other_generator.next_id_with_timestamp(1647251762000);
+    // The same millisecond, but the device parts are different
+    assert_eq!(id[..10], other_id[..10]);
+    assert_ne!(id[10..15], other_id[10..15]);
+  }
+}
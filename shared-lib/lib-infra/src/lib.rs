@@ -1,4 +1,5 @@
 pub mod future;
+pub mod id_gen;
 pub mod ref_map;
 pub mod retry;
 pub mod util;
@@ -13,7 +13,10 @@ use std::{
   fmt::Formatter,
   future::Future,
   pin::Pin,
-  sync::Arc,
+  sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+  },
   task::{Context, Poll},
   time::Duration,
 };
@@ -37,6 +40,7 @@ pub struct WSController {
   addr: Arc<RwLock<Option<String>>>,
   sender: Arc<RwLock<Option<Arc<WSSender>>>>,
   conn_state_notify: Arc<RwLock<WSConnectStateNotifier>>,
+  connect_attempts: Arc<AtomicUsize>,
 }
 
 impl std::fmt::Display for WSController {
@@ -52,6 +56,7 @@ impl std::default::Default for WSController {
       addr: Arc::new(RwLock::new(None)),
       sender: Arc::new(RwLock::new(None)),
       conn_state_notify: Arc::new(RwLock::new(WSConnectStateNotifier::default())),
+      connect_attempts: Arc::new(AtomicUsize::new(0)),
     }
   }
 }
@@ -112,6 +117,7 @@ impl WSController {
     let action = WSConnectAction {
       addr,
       handlers: self.handlers.clone(),
+      attempts: self.connect_attempts.clone(),
     };
     let retry = Retry::new(strategy, action);
     conn_state_notify.update_state(WSConnectState::Connecting);
@@ -174,10 +180,20 @@ impl WSController {
     self.connect(addr, strategy).await
   }
 
+  /// Returns the number of times the connection was tried since the controller was created.
+  /// Each [Self::start] or [Self::retry] tries up to the number of times of its strategy.
+  pub fn connect_attempts(&self) -> usize {
+    self.connect_attempts.load(Ordering::SeqCst)
+  }
+
   pub async fn subscribe_state(&self) -> broadcast::Receiver<WSConnectState> {
     self.conn_state_notify.read().await.notify.subscribe()
   }
 
+  pub async fn connect_state(&self) -> WSConnectState {
+    self.conn_state_notify.read().await.conn_state.clone()
+  }
+
   pub async fn ws_message_sender(&self) -> Result<Option<Arc<WSSender>>, WSError> {
     let sender = self.sender.read().await.clone();
     match sender {
@@ -291,6 +307,7 @@ impl WSSender {
 struct WSConnectAction {
   addr: String,
   handlers: Handlers,
+  attempts: Arc<AtomicUsize>,
 }
 
 impl Action for WSConnectAction {
@@ -299,6 +316,7 @@ impl Action for WSConnectAction {
   type Error = WSError;
 
   fn run(&mut self) -> Self::Future {
+    self.attempts.fetch_add(1, Ordering::SeqCst);
     let addr = self.addr.clone();
     let handlers = self.handlers.clone();
     Box::pin(WSConnectActionFut::new(addr, handlers))